@@ -33,6 +33,10 @@ pub enum ArtifactEvent {
     ArtifactValidationFailed(ArtifactValidationFailed),
     /// Se ha detectado un artefacto duplicado (mismo contenido hash)
     DuplicateArtifactDetected(DuplicateArtifactDetected),
+    /// Se ha completado la descarga de un artefacto
+    ArtifactDownloadCompleted(ArtifactDownloadCompleted),
+    /// Los bytes servidos no coinciden con el hash registrado al subirlos
+    DownloadIntegrityFailure(DownloadIntegrityFailure),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,3 +96,41 @@ pub struct DuplicateArtifactDetected {
     /// Momento de la detección
     pub at: OffsetDateTime,
 }
+
+/// Evento de descarga completada de un artefacto
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtifactDownloadCompleted {
+    pub artifact_hrn: String,
+    /// Bytes entregados: los del rango si se pidió uno
+    pub size_in_bytes: u64,
+    /// Solo se sirvió un rango del artefacto
+    pub partial: bool,
+    /// Desde la petición hasta entregar el último byte o emitir la URL presignada
+    pub duration_ms: u64,
+    pub delivery: DownloadDelivery,
+    pub at: OffsetDateTime,
+}
+
+/// Forma en que se entregó una descarga
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadDelivery {
+    /// El servidor envió el contenido hasta el final
+    Streamed,
+    /// Se emitió una URL presignada; el almacenamiento sirve los bytes sin
+    /// pasar por el servidor, así que la descarga cuenta como completada al
+    /// emitir la URL
+    PresignedUrlIssued,
+}
+
+/// Evento de fallo de integridad al servir un artefacto (bit-rot en el
+/// almacenamiento)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadIntegrityFailure {
+    pub artifact_hrn: String,
+    /// Hash SHA-256 registrado al subir el artefacto
+    pub expected_hash: String,
+    /// Hash SHA-256 de los bytes servidos
+    pub actual_hash: String,
+    pub at: OffsetDateTime,
+}
@@ -35,6 +35,16 @@ pub struct PhysicalArtifact {
     /// Tipo MIME detectado del contenido (ej. `application/java-archive`, `application/octet-stream`).
     pub mime_type: String,
 
+    /// Número de `PackageVersion` que referencian este blob. Cuando llega a cero
+    /// el blob puede eliminarse del backend de almacenamiento.
+    /// Los registros anteriores al contador tienen al menos una referencia.
+    #[serde(default = "default_reference_count")]
+    pub reference_count: u64,
+
     /// Información de auditoría y ciclo de vida (útil para la recolección de basura).
     pub lifecycle: Lifecycle,
 }
+
+fn default_reference_count() -> u64 {
+    1
+}
@@ -0,0 +1,216 @@
+use async_trait::async_trait;
+use aws_config::SdkConfig;
+use aws_sdk_s3::{presigning::PresigningConfig, Client as S3Client};
+use bytes::Bytes;
+use lapin::{options::BasicPublishOptions, BasicProperties, Channel};
+use mongodb::{bson::doc, Client as MongoClient, Database};
+use serde_json::to_string;
+use std::time::Duration;
+
+use super::dto::DownloadableArtifact;
+use super::error::DownloadArtifactError;
+use super::ports::{
+    ArtifactContentReader, DownloadEventPublisher, DownloadableArtifactLookup, PortResult,
+    PresignedUrlGenerator,
+};
+use crate::domain::{
+    events::ArtifactEvent, package_version::PackageVersion, physical_artifact::PhysicalArtifact,
+};
+
+/// Content type served when the physical artifact has none recorded
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+// --- DownloadableArtifactLookup: MongoDB ---
+pub struct MongoDownloadableArtifactLookup {
+    db: Database,
+}
+
+impl MongoDownloadableArtifactLookup {
+    pub fn new_with_client(client: MongoClient) -> Self {
+        Self {
+            db: client.database("hodei"),
+        }
+    }
+
+    pub async fn new(connection_string: &str, db_name: &str) -> mongodb::error::Result<Self> {
+        let client = MongoClient::with_uri_str(connection_string).await?;
+        Ok(Self {
+            db: client.database(db_name),
+        })
+    }
+}
+
+#[async_trait]
+impl DownloadableArtifactLookup for MongoDownloadableArtifactLookup {
+    async fn find_downloadable(
+        &self,
+        artifact_id: &str,
+    ) -> PortResult<Option<DownloadableArtifact>> {
+        let package_version = self
+            .db
+            .collection::<PackageVersion>("package_versions")
+            .find_one(doc! { "hrn": artifact_id }, None)
+            .await
+            .map_err(|e| DownloadArtifactError::RepositoryError(e.to_string()))?;
+        let Some(package_version) = package_version else {
+            return Ok(None);
+        };
+        let Some(reference) = package_version.artifacts.first() else {
+            return Ok(None);
+        };
+
+        let content_hash = reference.content_hash.value.clone();
+        let physical_artifact = self
+            .db
+            .collection::<PhysicalArtifact>("physical_artifacts")
+            .find_one(doc! { "content_hash.value": &content_hash }, None)
+            .await
+            .map_err(|e| DownloadArtifactError::RepositoryError(e.to_string()))?;
+        let content_type = physical_artifact
+            .map(|artifact| artifact.mime_type)
+            .filter(|mime_type| !mime_type.is_empty())
+            .unwrap_or_else(|| DEFAULT_CONTENT_TYPE.to_string());
+
+        let coordinates = &package_version.coordinates;
+        Ok(Some(DownloadableArtifact {
+            artifact_hrn: package_version.hrn.to_string(),
+            content_hash,
+            file_name: format!("{}-{}", coordinates.name, coordinates.version),
+            content_type,
+            size_in_bytes: reference.size_in_bytes,
+        }))
+    }
+}
+
+// --- ArtifactContentReader: S3 ---
+pub struct S3ArtifactContentReader {
+    client: S3Client,
+    bucket_name: String,
+}
+
+impl S3ArtifactContentReader {
+    pub fn new(sdk_config: &SdkConfig, bucket_name: String) -> Self {
+        Self {
+            client: S3Client::new(sdk_config),
+            bucket_name,
+        }
+    }
+
+    async fn get_object(&self, content_hash: &str, range: Option<String>) -> PortResult<Bytes> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(content_hash)
+            .set_range(range)
+            .send()
+            .await
+            .map_err(|e| DownloadArtifactError::StorageError(e.to_string()))?;
+        let data = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| DownloadArtifactError::StorageError(e.to_string()))?;
+        Ok(data.into_bytes())
+    }
+}
+
+#[async_trait]
+impl ArtifactContentReader for S3ArtifactContentReader {
+    async fn read(&self, content_hash: &str) -> PortResult<Bytes> {
+        self.get_object(content_hash, None).await
+    }
+
+    async fn read_range(&self, content_hash: &str, start: u64, end: u64) -> PortResult<Bytes> {
+        self.get_object(content_hash, Some(format!("bytes={}-{}", start, end)))
+            .await
+    }
+}
+
+// --- PresignedUrlGenerator: S3 ---
+pub struct S3PresignedUrlGenerator {
+    client: S3Client,
+    bucket_name: String,
+}
+
+impl S3PresignedUrlGenerator {
+    pub fn new(sdk_config: &SdkConfig, bucket_name: String) -> Self {
+        Self {
+            client: S3Client::new(sdk_config),
+            bucket_name,
+        }
+    }
+}
+
+#[async_trait]
+impl PresignedUrlGenerator for S3PresignedUrlGenerator {
+    async fn presign_download(
+        &self,
+        content_hash: &str,
+        file_name: &str,
+        expires_in: Duration,
+    ) -> PortResult<String> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .map_err(|e| DownloadArtifactError::StorageError(e.to_string()))?;
+        // Una URL presignada de GetObject solo sirve para descargar; S3 devuelve
+        // el blob, guardado bajo su hash, como adjunto con el nombre del artefacto
+        let request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(content_hash)
+            .response_content_disposition(content_disposition(file_name))
+            .presigned(presigning_config)
+            .await
+            .map_err(|e| DownloadArtifactError::StorageError(e.to_string()))?;
+        Ok(request.uri().to_string())
+    }
+}
+
+/// `Content-Disposition` de adjunto, sin comillas ni barras que rompan la cabecera
+fn content_disposition(file_name: &str) -> String {
+    let file_name = file_name.replace(['"', '\\', '/'], "_");
+    format!("attachment; filename=\"{}\"", file_name)
+}
+
+// --- DownloadEventPublisher: RabbitMQ ---
+pub struct RabbitMqDownloadEventPublisher {
+    channel: Channel,
+    exchange: String,
+}
+
+impl RabbitMqDownloadEventPublisher {
+    /// Publishes to `exchange`, which must already be declared
+    pub fn new(channel: Channel, exchange: &str) -> Self {
+        Self {
+            channel,
+            exchange: exchange.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl DownloadEventPublisher for RabbitMqDownloadEventPublisher {
+    async fn publish(&self, event: &ArtifactEvent) -> PortResult<()> {
+        let payload =
+            to_string(event).map_err(|e| DownloadArtifactError::EventError(e.to_string()))?;
+
+        let routing_key = match event {
+            ArtifactEvent::DownloadIntegrityFailure(_) => "artifact.download_integrity_failure",
+            _ => "artifact.downloaded",
+        };
+
+        self.channel
+            .basic_publish(
+                &self.exchange,
+                routing_key,
+                BasicPublishOptions::default(),
+                payload.as_bytes(),
+                BasicProperties::default(),
+            )
+            .await
+            .map_err(|e| DownloadArtifactError::EventError(e.to_string()))?;
+
+        Ok(())
+    }
+}
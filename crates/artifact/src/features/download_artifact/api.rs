@@ -0,0 +1,283 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use bytes::Bytes;
+use futures::Stream;
+use sha2::{Digest, Sha256};
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use time::OffsetDateTime;
+use tracing::{error, info};
+
+use super::{
+    dto::{ByteRange, GetArtifactParams, GetArtifactQuery, GetArtifactResponse},
+    error::DownloadArtifactError,
+    use_case::DownloadArtifactUseCase,
+};
+use crate::domain::events::{
+    ArtifactDownloadCompleted, DownloadDelivery, DownloadIntegrityFailure,
+};
+
+/// Tamaño de los trozos en que se envía el cuerpo de una descarga
+const DOWNLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Punto de entrada API para descargas de artefactos
+pub struct DownloadArtifactEndpoint {
+    use_case: Arc<DownloadArtifactUseCase>,
+}
+
+impl DownloadArtifactEndpoint {
+    pub fn new(use_case: Arc<DownloadArtifactUseCase>) -> Self {
+        Self { use_case }
+    }
+
+    /// GET /artifacts/{id}
+    ///
+    /// Acepta un único rango `Range` (206 Partial Content) para poder reanudar
+    /// descargas cortadas. Varios rangos o uno mal formado se ignoran y se
+    /// sirve el artefacto completo; un rango fuera del artefacto es un 416.
+    ///
+    /// Con `presigned=true` responde con una URL presignada, de solo descarga,
+    /// cuya validez es `expiresIn` recortado al máximo configurado.
+    ///
+    /// La descarga completada se publica cuando el cuerpo llega a su final;
+    /// una descarga cortada por el cliente no se publica.
+    ///
+    /// Con la verificación de integridad activada, una descarga completa se
+    /// hashea según sale y se compara con el hash registrado al subirla. Las
+    /// descargas por URL presignada van directas al almacenamiento y quedan
+    /// sin verificar.
+    pub async fn handle_get_artifact(
+        &self,
+        Path(artifact_id): Path<String>,
+        Query(params): Query<GetArtifactParams>,
+        headers: HeaderMap,
+    ) -> Result<Response, DownloadArtifactError> {
+        let started_at = Instant::now();
+        info!("Processing download of artifact {}", artifact_id);
+
+        let range = headers
+            .get(header::RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(ByteRange::parse);
+
+        let response = self
+            .use_case
+            .execute(GetArtifactQuery {
+                artifact_id,
+                range,
+                use_presigned_url: params.presigned,
+                expires_in: params.expires_in,
+            })
+            .await
+            .map_err(|e| {
+                error!("Failed to download artifact: {}", e);
+                e
+            })?;
+
+        let artifact = match response {
+            GetArtifactResponse::Content(artifact) => artifact,
+            GetArtifactResponse::PresignedUrl(presigned_url) => {
+                return Ok(Json(presigned_url).into_response())
+            }
+        };
+
+        let mut response_headers = HeaderMap::new();
+        insert_header(
+            &mut response_headers,
+            header::CONTENT_TYPE,
+            &artifact.content_type,
+        );
+        insert_header(
+            &mut response_headers,
+            header::CONTENT_LENGTH,
+            &artifact.content.len().to_string(),
+        );
+        insert_header(&mut response_headers, header::ACCEPT_RANGES, "bytes");
+
+        let status = match artifact.content_range {
+            Some(content_range) => {
+                insert_header(
+                    &mut response_headers,
+                    header::CONTENT_RANGE,
+                    &content_range.header_value(),
+                );
+                StatusCode::PARTIAL_CONTENT
+            }
+            None => StatusCode::OK,
+        };
+
+        let artifact_hrn = artifact.artifact_hrn;
+        let failed_artifact_hrn = artifact_hrn.clone();
+        let size_in_bytes = artifact.content.len() as u64;
+        let partial = artifact.content_range.is_some();
+        let use_case = self.use_case.clone();
+        let mut body = CompletionStream::new(
+            artifact.content,
+            Box::new(move || {
+                use_case.publish_download_completed(ArtifactDownloadCompleted {
+                    artifact_hrn,
+                    size_in_bytes,
+                    partial,
+                    duration_ms: started_at.elapsed().as_millis() as u64,
+                    delivery: DownloadDelivery::Streamed,
+                    at: OffsetDateTime::now_utc(),
+                })
+            }),
+        );
+
+        if let Some(expected_hash) = artifact.expected_sha256 {
+            let use_case = self.use_case.clone();
+            let artifact_hrn = failed_artifact_hrn;
+            body = body.verify(
+                expected_hash.clone(),
+                Box::new(move |actual_hash| {
+                    error!(
+                        %artifact_hrn,
+                        %expected_hash,
+                        %actual_hash,
+                        "Stored artifact failed integrity check"
+                    );
+                    use_case.publish_integrity_failure(DownloadIntegrityFailure {
+                        artifact_hrn,
+                        expected_hash,
+                        actual_hash,
+                        at: OffsetDateTime::now_utc(),
+                    })
+                }),
+            );
+        }
+
+        Ok((status, response_headers, Body::from_stream(body)).into_response())
+    }
+}
+
+/// Cuerpo de una descarga que avisa cuando se ha enviado entero
+///
+/// El aviso se da al señalar el final del cuerpo (EOF), después de entregar
+/// el último trozo. Un cuerpo que se suelta antes, porque el cliente cortó la
+/// conexión, no avisa.
+///
+/// Con un hash esperado, los trozos se van hasheando según salen y el último
+/// solo se entrega si el hash coincide: si no, el cuerpo termina con un error,
+/// el cliente ve la transferencia abortada y se avisa del fallo de integridad
+/// en lugar de la descarga completada.
+struct CompletionStream {
+    remaining: Bytes,
+    on_complete: Option<Box<dyn FnOnce() + Send>>,
+    integrity_check: Option<IntegrityCheck>,
+}
+
+/// Verificación SHA-256 en streaming del contenido servido
+struct IntegrityCheck {
+    hasher: Sha256,
+    expected: String,
+    on_failure: Box<dyn FnOnce(String) + Send>,
+}
+
+impl CompletionStream {
+    fn new(content: Bytes, on_complete: Box<dyn FnOnce() + Send>) -> Self {
+        Self {
+            remaining: content,
+            on_complete: Some(on_complete),
+            integrity_check: None,
+        }
+    }
+
+    /// Verificar el contenido contra `expected`; `on_failure` recibe el hash real
+    fn verify(mut self, expected: String, on_failure: Box<dyn FnOnce(String) + Send>) -> Self {
+        self.integrity_check = Some(IntegrityCheck {
+            hasher: Sha256::new(),
+            expected,
+            on_failure,
+        });
+        self
+    }
+
+    /// Cerrar la verificación pendiente; un error si el hash no coincide
+    fn finish_integrity_check(&mut self) -> Option<io::Error> {
+        let check = self.integrity_check.take()?;
+        let actual = hex::encode(check.hasher.finalize());
+        if check.expected.eq_ignore_ascii_case(&actual) {
+            return None;
+        }
+
+        self.on_complete = None;
+        (check.on_failure)(actual);
+        Some(io::Error::other("SHA-256 mismatch on download"))
+    }
+}
+
+impl Stream for CompletionStream {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.remaining.is_empty() {
+            // Solo queda una verificación pendiente si el contenido está vacío
+            if let Some(error) = self.finish_integrity_check() {
+                return Poll::Ready(Some(Err(error)));
+            }
+            if let Some(on_complete) = self.on_complete.take() {
+                on_complete();
+            }
+            return Poll::Ready(None);
+        }
+
+        let chunk_len = self.remaining.len().min(DOWNLOAD_CHUNK_SIZE);
+        let chunk = self.remaining.split_to(chunk_len);
+        if let Some(check) = self.integrity_check.as_mut() {
+            check.hasher.update(&chunk);
+        }
+        if self.remaining.is_empty()
+            && let Some(error) = self.finish_integrity_check()
+        {
+            return Poll::Ready(Some(Err(error)));
+        }
+
+        Poll::Ready(Some(Ok(chunk)))
+    }
+}
+
+fn insert_header(headers: &mut HeaderMap, name: header::HeaderName, value: &str) {
+    match HeaderValue::from_str(value) {
+        Ok(value) => {
+            headers.insert(name, value);
+        }
+        Err(e) => error!("Invalid value for header {}: {}", name, e),
+    }
+}
+
+/// Implementación de IntoResponse para DownloadArtifactError
+impl IntoResponse for DownloadArtifactError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            DownloadArtifactError::NotFound(_) => StatusCode::NOT_FOUND,
+            DownloadArtifactError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            DownloadArtifactError::RangeNotSatisfiable(_) => StatusCode::RANGE_NOT_SATISFIABLE,
+            DownloadArtifactError::StorageError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            DownloadArtifactError::RepositoryError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            DownloadArtifactError::EventError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        let body = Json(serde_json::json!({
+            "error": self.to_string(),
+        }));
+
+        let mut response = (status, body).into_response();
+        if let DownloadArtifactError::RangeNotSatisfiable(total) = self {
+            insert_header(
+                response.headers_mut(),
+                header::CONTENT_RANGE,
+                &format!("bytes */{}", total),
+            );
+        }
+        response
+    }
+}
@@ -0,0 +1,78 @@
+use aws_config::SdkConfig;
+use lapin::Channel;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{
+    adapter::{
+        MongoDownloadableArtifactLookup, RabbitMqDownloadEventPublisher, S3ArtifactContentReader,
+        S3PresignedUrlGenerator,
+    },
+    api::DownloadArtifactEndpoint,
+    ports::{
+        ArtifactContentReader, DownloadEventPublisher, DownloadableArtifactLookup,
+        PresignedUrlGenerator,
+    },
+    use_case::DownloadArtifactUseCase,
+};
+
+/// Contenedor de inyección de dependencias para la descarga de artefactos
+pub struct DownloadArtifactDIContainer {
+    pub endpoint: Arc<DownloadArtifactEndpoint>,
+}
+
+impl DownloadArtifactDIContainer {
+    /// Constructor flexible que acepta cualquier implementación de los puertos
+    pub fn new(
+        lookup: Arc<dyn DownloadableArtifactLookup>,
+        content_reader: Arc<dyn ArtifactContentReader>,
+        url_generator: Arc<dyn PresignedUrlGenerator>,
+        event_publisher: Arc<dyn DownloadEventPublisher>,
+        max_presigned_url_expiry: Duration,
+        verify_integrity: bool,
+    ) -> Self {
+        let use_case = Arc::new(
+            DownloadArtifactUseCase::new(lookup, content_reader, url_generator, event_publisher)
+                .with_max_presigned_url_expiry(max_presigned_url_expiry)
+                .with_integrity_verification(verify_integrity),
+        );
+        let endpoint = Arc::new(DownloadArtifactEndpoint::new(use_case));
+
+        Self { endpoint }
+    }
+
+    /// Método de conveniencia para producción
+    ///
+    /// `verify_integrity` activa la verificación de las descargas contra el
+    /// hash registrado al subirlas, que añade latencia a cada descarga.
+    pub fn for_production(
+        mongo_client: mongodb::Client,
+        sdk_config: &SdkConfig,
+        bucket_name: String,
+        event_channel: Channel,
+        event_exchange: &str,
+        max_presigned_url_expiry: Duration,
+        verify_integrity: bool,
+    ) -> Self {
+        let lookup: Arc<dyn DownloadableArtifactLookup> = Arc::new(
+            MongoDownloadableArtifactLookup::new_with_client(mongo_client),
+        );
+        let content_reader: Arc<dyn ArtifactContentReader> = Arc::new(
+            S3ArtifactContentReader::new(sdk_config, bucket_name.clone()),
+        );
+        let url_generator: Arc<dyn PresignedUrlGenerator> =
+            Arc::new(S3PresignedUrlGenerator::new(sdk_config, bucket_name));
+        let event_publisher: Arc<dyn DownloadEventPublisher> = Arc::new(
+            RabbitMqDownloadEventPublisher::new(event_channel, event_exchange),
+        );
+
+        Self::new(
+            lookup,
+            content_reader,
+            url_generator,
+            event_publisher,
+            max_presigned_url_expiry,
+            verify_integrity,
+        )
+    }
+}
@@ -0,0 +1,150 @@
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Consulta para descargar un artefacto
+#[derive(Debug, Clone)]
+pub struct GetArtifactQuery {
+    /// HRN de la versión de paquete, el que devuelve la subida
+    pub artifact_id: String,
+    /// Rango pedido con la cabecera `Range`; `None` para el artefacto completo
+    pub range: Option<ByteRange>,
+    /// Devolver una URL presignada en lugar del contenido
+    pub use_presigned_url: bool,
+    /// Validez pedida para la URL presignada, en segundos
+    pub expires_in: Option<u64>,
+}
+
+/// Parámetros de consulta de `GET /artifacts/{id}`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetArtifactParams {
+    #[serde(default)]
+    pub presigned: bool,
+    /// Validez pedida para la URL presignada, en segundos
+    pub expires_in: Option<u64>,
+}
+
+/// Resultado de una descarga: el contenido o una URL presignada para bajarlo
+#[derive(Debug, Clone)]
+pub enum GetArtifactResponse {
+    Content(ArtifactContent),
+    PresignedUrl(PresignedUrl),
+}
+
+/// Artefacto descargable al que apunta un id
+#[derive(Debug, Clone)]
+pub struct DownloadableArtifact {
+    pub artifact_hrn: String,
+    /// Clave del blob en el almacenamiento
+    pub content_hash: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_in_bytes: u64,
+}
+
+/// Contenido servido de un artefacto
+#[derive(Debug, Clone)]
+pub struct ArtifactContent {
+    pub artifact_hrn: String,
+    pub file_name: String,
+    pub content_type: String,
+    /// Bytes servidos: los del rango si se pidió uno
+    pub content: Bytes,
+    /// Rango servido, para responder 206 Partial Content
+    pub content_range: Option<ContentRange>,
+    /// SHA-256 contra el que verificar el contenido mientras se sirve
+    pub expected_sha256: Option<String>,
+}
+
+/// URL presignada para descargar un artefacto directamente del almacenamiento
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresignedUrl {
+    pub url: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub expires_at: OffsetDateTime,
+    /// Validez efectiva en segundos, ya recortada al máximo configurado
+    pub expires_in: u64,
+}
+
+/// Un único rango de bytes de la cabecera `Range`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteRange {
+    /// `bytes=start-end` o `bytes=start-`, con `end` inclusivo
+    FromStart { start: u64, end: Option<u64> },
+    /// `bytes=-length`: los últimos `length` bytes
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /// Interpretar una cabecera `Range`
+    ///
+    /// Devuelve `None` para lo que no sea un único rango de bytes bien formado
+    /// (varios rangos, otra unidad, sintaxis inválida): la cabecera se ignora
+    /// y se sirve el artefacto completo.
+    pub fn parse(header: &str) -> Option<Self> {
+        let spec = header.trim().strip_prefix("bytes=")?.trim();
+        if spec.contains(',') {
+            return None;
+        }
+        let (start, end) = spec.split_once('-')?;
+        let (start, end) = (start.trim(), end.trim());
+
+        if start.is_empty() {
+            return end.parse().ok().map(ByteRange::Suffix);
+        }
+        let start: u64 = start.parse().ok()?;
+        let end = if end.is_empty() {
+            None
+        } else {
+            let end: u64 = end.parse().ok()?;
+            if end < start {
+                return None;
+            }
+            Some(end)
+        };
+        Some(ByteRange::FromStart { start, end })
+    }
+
+    /// Resolver el rango contra el tamaño del artefacto
+    ///
+    /// Devuelve `None` si el rango no es satisfacible (416): empieza más allá
+    /// del final, pide un sufijo vacío o el artefacto está vacío. Un final
+    /// más allá del tamaño se recorta al último byte.
+    pub fn resolve(self, total: u64) -> Option<ContentRange> {
+        let last = total.checked_sub(1)?;
+        let (start, end) = match self {
+            ByteRange::FromStart { start, end } => {
+                if start > last {
+                    return None;
+                }
+                (start, end.map_or(last, |end| end.min(last)))
+            }
+            ByteRange::Suffix(0) => return None,
+            ByteRange::Suffix(length) => (total.saturating_sub(length), last),
+        };
+        Some(ContentRange { start, end, total })
+    }
+}
+
+/// Rango servido de un artefacto, con posiciones inclusivas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentRange {
+    pub start: u64,
+    pub end: u64,
+    /// Tamaño del artefacto completo
+    pub total: u64,
+}
+
+impl ContentRange {
+    /// Número de bytes del rango
+    pub fn length(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Valor de la cabecera `Content-Range`
+    pub fn header_value(&self) -> String {
+        format!("bytes {}-{}/{}", self.start, self.end, self.total)
+    }
+}
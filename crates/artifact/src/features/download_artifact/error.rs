@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DownloadArtifactError {
+    #[error("Artifact not found: {0}")]
+    NotFound(String),
+
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+
+    /// Rango fuera del artefacto; lleva el tamaño para `Content-Range: bytes */{total}`
+    #[error("Range not satisfiable for an artifact of {0} bytes")]
+    RangeNotSatisfiable(u64),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+
+    #[error("Event publishing error: {0}")]
+    EventError(String),
+}
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use super::dto::DownloadableArtifact;
+use super::error::DownloadArtifactError;
+use super::ports::{
+    ArtifactContentReader, DownloadEventPublisher, DownloadableArtifactLookup, PortResult,
+    PresignedUrlGenerator,
+};
+use crate::domain::events::ArtifactEvent;
+
+#[derive(Default)]
+pub struct MockDownloadableArtifactLookup {
+    pub artifacts: Mutex<HashMap<String, DownloadableArtifact>>,
+}
+
+impl MockDownloadableArtifactLookup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&self, artifact_id: &str, artifact: DownloadableArtifact) {
+        self.artifacts
+            .lock()
+            .unwrap()
+            .insert(artifact_id.to_string(), artifact);
+    }
+}
+
+#[async_trait]
+impl DownloadableArtifactLookup for MockDownloadableArtifactLookup {
+    async fn find_downloadable(
+        &self,
+        artifact_id: &str,
+    ) -> PortResult<Option<DownloadableArtifact>> {
+        Ok(self.artifacts.lock().unwrap().get(artifact_id).cloned())
+    }
+}
+
+#[derive(Default)]
+pub struct MockArtifactContentReader {
+    pub blobs: Mutex<HashMap<String, Bytes>>,
+}
+
+impl MockArtifactContentReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_blob(&self, content_hash: &str, content: Bytes) {
+        self.blobs
+            .lock()
+            .unwrap()
+            .insert(content_hash.to_string(), content);
+    }
+}
+
+#[async_trait]
+impl ArtifactContentReader for MockArtifactContentReader {
+    async fn read(&self, content_hash: &str) -> PortResult<Bytes> {
+        self.blobs
+            .lock()
+            .unwrap()
+            .get(content_hash)
+            .cloned()
+            .ok_or_else(|| DownloadArtifactError::StorageError(format!("No blob {}", content_hash)))
+    }
+
+    async fn read_range(&self, content_hash: &str, start: u64, end: u64) -> PortResult<Bytes> {
+        let content = self.read(content_hash).await?;
+        if end >= content.len() as u64 {
+            return Err(DownloadArtifactError::StorageError(format!(
+                "Range {}-{} out of bounds",
+                start, end
+            )));
+        }
+        Ok(content.slice(start as usize..=end as usize))
+    }
+}
+
+#[derive(Default)]
+pub struct MockPresignedUrlGenerator {
+    /// `(content_hash, file_name, expires_in)` of every URL issued
+    pub issued: Mutex<Vec<(String, String, Duration)>>,
+}
+
+impl MockPresignedUrlGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PresignedUrlGenerator for MockPresignedUrlGenerator {
+    async fn presign_download(
+        &self,
+        content_hash: &str,
+        file_name: &str,
+        expires_in: Duration,
+    ) -> PortResult<String> {
+        self.issued.lock().unwrap().push((
+            content_hash.to_string(),
+            file_name.to_string(),
+            expires_in,
+        ));
+        Ok(format!(
+            "https://storage.example/{}?X-Amz-Expires={}",
+            content_hash,
+            expires_in.as_secs()
+        ))
+    }
+}
+
+#[derive(Default)]
+pub struct MockDownloadEventPublisher {
+    pub events: Mutex<Vec<ArtifactEvent>>,
+    published: Notify,
+}
+
+impl MockDownloadEventPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait until at least `count` events have been published
+    pub async fn wait_for(&self, count: usize) -> Vec<ArtifactEvent> {
+        loop {
+            let published = self.published.notified();
+            {
+                let events = self.events.lock().unwrap();
+                if events.len() >= count {
+                    return events.clone();
+                }
+            }
+            published.await;
+        }
+    }
+}
+
+#[async_trait]
+impl DownloadEventPublisher for MockDownloadEventPublisher {
+    async fn publish(&self, event: &ArtifactEvent) -> PortResult<()> {
+        self.events.lock().unwrap().push(event.clone());
+        self.published.notify_waiters();
+        Ok(())
+    }
+}
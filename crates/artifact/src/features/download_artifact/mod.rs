@@ -0,0 +1,24 @@
+pub mod adapter;
+pub mod api;
+pub mod di;
+pub mod dto;
+pub mod error;
+pub mod ports;
+pub mod use_case;
+
+pub mod mocks;
+
+#[cfg(test)]
+mod use_case_test;
+
+// Expose only the public parts of the feature.
+pub use api::DownloadArtifactEndpoint;
+pub use di::DownloadArtifactDIContainer;
+pub use dto::{
+    ArtifactContent, ByteRange, ContentRange, GetArtifactQuery, GetArtifactResponse, PresignedUrl,
+};
+pub use error::DownloadArtifactError;
+pub use ports::{
+    ArtifactContentReader, DownloadEventPublisher, DownloadableArtifactLookup,
+    PresignedUrlGenerator,
+};
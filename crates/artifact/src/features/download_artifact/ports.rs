@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::time::Duration;
+
+use super::dto::DownloadableArtifact;
+use super::error::DownloadArtifactError;
+use crate::domain::events::ArtifactEvent;
+
+pub type PortResult<T> = Result<T, DownloadArtifactError>;
+
+/// Port for resolving the artifact a download id points to
+#[async_trait]
+pub trait DownloadableArtifactLookup: Send + Sync {
+    /// Find the artifact of a package version; `None` if there is none
+    async fn find_downloadable(
+        &self,
+        artifact_id: &str,
+    ) -> PortResult<Option<DownloadableArtifact>>;
+}
+
+/// Port for reading artifact content from storage
+#[async_trait]
+pub trait ArtifactContentReader: Send + Sync {
+    /// Read the whole blob stored under `content_hash`
+    async fn read(&self, content_hash: &str) -> PortResult<Bytes>;
+
+    /// Read bytes `start..=end` of the blob stored under `content_hash`
+    async fn read_range(&self, content_hash: &str, start: u64, end: u64) -> PortResult<Bytes>;
+}
+
+/// Port for issuing presigned download URLs
+#[async_trait]
+pub trait PresignedUrlGenerator: Send + Sync {
+    /// Presign a GET of the blob stored under `content_hash`, valid for
+    /// `expires_in`, that downloads it as an attachment named `file_name`
+    async fn presign_download(
+        &self,
+        content_hash: &str,
+        file_name: &str,
+        expires_in: Duration,
+    ) -> PortResult<String>;
+}
+
+/// Port for publishing download events
+#[async_trait]
+pub trait DownloadEventPublisher: Send + Sync {
+    async fn publish(&self, event: &ArtifactEvent) -> PortResult<()>;
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use time::OffsetDateTime;
+use tracing::{info, instrument, warn};
+
+use super::dto::{
+    ArtifactContent, DownloadableArtifact, GetArtifactQuery, GetArtifactResponse, PresignedUrl,
+};
+use super::error::DownloadArtifactError;
+use super::ports::{
+    ArtifactContentReader, DownloadEventPublisher, DownloadableArtifactLookup, PortResult,
+    PresignedUrlGenerator,
+};
+use crate::domain::events::{
+    ArtifactDownloadCompleted, ArtifactEvent, DownloadDelivery, DownloadIntegrityFailure,
+};
+
+/// Validez de una URL presignada cuando la consulta no pide otra
+pub const DEFAULT_PRESIGNED_URL_EXPIRY: Duration = Duration::from_secs(3600);
+
+/// Validez máxima de una URL presignada si no se configura otra
+pub const DEFAULT_MAX_PRESIGNED_URL_EXPIRY: Duration = Duration::from_secs(24 * 3600);
+
+/// Caso de uso para descargar un artefacto: completo, un rango de bytes o
+/// mediante una URL presignada
+pub struct DownloadArtifactUseCase {
+    lookup: Arc<dyn DownloadableArtifactLookup>,
+    content_reader: Arc<dyn ArtifactContentReader>,
+    url_generator: Arc<dyn PresignedUrlGenerator>,
+    event_publisher: Arc<dyn DownloadEventPublisher>,
+    max_presigned_url_expiry: Duration,
+    verify_integrity: bool,
+}
+
+impl DownloadArtifactUseCase {
+    pub fn new(
+        lookup: Arc<dyn DownloadableArtifactLookup>,
+        content_reader: Arc<dyn ArtifactContentReader>,
+        url_generator: Arc<dyn PresignedUrlGenerator>,
+        event_publisher: Arc<dyn DownloadEventPublisher>,
+    ) -> Self {
+        Self {
+            lookup,
+            content_reader,
+            url_generator,
+            event_publisher,
+            max_presigned_url_expiry: DEFAULT_MAX_PRESIGNED_URL_EXPIRY,
+            verify_integrity: false,
+        }
+    }
+
+    /// Validez máxima de las URL presignadas; una validez pedida por encima se
+    /// recorta a este máximo
+    pub fn with_max_presigned_url_expiry(mut self, max_presigned_url_expiry: Duration) -> Self {
+        self.max_presigned_url_expiry = max_presigned_url_expiry;
+        self
+    }
+
+    /// Verificar el contenido servido contra el hash registrado al subirlo
+    ///
+    /// Se hashea según sale, lo que añade latencia a cada descarga. Solo se
+    /// verifican las descargas completas servidas por este servidor: ni los
+    /// rangos, que no cubren el artefacto entero, ni las URL presignadas,
+    /// cuyos bytes sirve el almacenamiento directamente.
+    pub fn with_integrity_verification(mut self, enabled: bool) -> Self {
+        self.verify_integrity = enabled;
+        self
+    }
+
+    #[instrument(skip(self), fields(artifact_id = %query.artifact_id))]
+    pub async fn execute(&self, query: GetArtifactQuery) -> PortResult<GetArtifactResponse> {
+        let started_at = Instant::now();
+        let artifact = self
+            .lookup
+            .find_downloadable(&query.artifact_id)
+            .await?
+            .ok_or_else(|| DownloadArtifactError::NotFound(query.artifact_id.clone()))?;
+
+        if query.use_presigned_url {
+            let presigned_url = self.presign(&artifact, query.expires_in).await?;
+            self.publish_download_completed(ArtifactDownloadCompleted {
+                artifact_hrn: artifact.artifact_hrn,
+                size_in_bytes: artifact.size_in_bytes,
+                partial: false,
+                duration_ms: started_at.elapsed().as_millis() as u64,
+                delivery: DownloadDelivery::PresignedUrlIssued,
+                at: OffsetDateTime::now_utc(),
+            });
+            return Ok(GetArtifactResponse::PresignedUrl(presigned_url));
+        }
+
+        let total = artifact.size_in_bytes;
+        let content_range = match query.range {
+            Some(range) => Some(range.resolve(total).ok_or_else(|| {
+                warn!(?range, total, "Requested range not satisfiable");
+                DownloadArtifactError::RangeNotSatisfiable(total)
+            })?),
+            None => None,
+        };
+
+        // S3 sirve el rango directamente, sin leer el blob completo
+        let content = match content_range {
+            Some(range) => {
+                self.content_reader
+                    .read_range(&artifact.content_hash, range.start, range.end)
+                    .await?
+            }
+            None => self.content_reader.read(&artifact.content_hash).await?,
+        };
+
+        info!(
+            artifact_hrn = %artifact.artifact_hrn,
+            bytes = content.len(),
+            partial = content_range.is_some(),
+            "Serving artifact download"
+        );
+
+        Ok(GetArtifactResponse::Content(ArtifactContent {
+            artifact_hrn: artifact.artifact_hrn,
+            file_name: artifact.file_name,
+            content_type: artifact.content_type,
+            content,
+            content_range,
+            expected_sha256: (self.verify_integrity && content_range.is_none())
+                .then_some(artifact.content_hash),
+        }))
+    }
+
+    /// Publicar una descarga completada sin retrasar la respuesta
+    ///
+    /// La publicación corre en su propia tarea; si falla solo se registra,
+    /// porque la respuesta ya se ha entregado.
+    pub fn publish_download_completed(&self, event: ArtifactDownloadCompleted) {
+        self.publish(ArtifactEvent::ArtifactDownloadCompleted(event));
+    }
+
+    /// Publicar un fallo de integridad sin retrasar la respuesta
+    pub fn publish_integrity_failure(&self, event: DownloadIntegrityFailure) {
+        self.publish(ArtifactEvent::DownloadIntegrityFailure(event));
+    }
+
+    fn publish(&self, event: ArtifactEvent) {
+        let event_publisher = self.event_publisher.clone();
+        tokio::spawn(async move {
+            if let Err(e) = event_publisher.publish(&event).await {
+                warn!(error = %e, ?event, "Failed to publish download event");
+            }
+        });
+    }
+
+    /// Emitir una URL presignada con la validez pedida, recortada al máximo
+    async fn presign(
+        &self,
+        artifact: &DownloadableArtifact,
+        expires_in: Option<u64>,
+    ) -> PortResult<PresignedUrl> {
+        let expires_in = match expires_in {
+            Some(0) => {
+                return Err(DownloadArtifactError::BadRequest(
+                    "expiresIn must be at least 1 second".to_string(),
+                ))
+            }
+            Some(seconds) if Duration::from_secs(seconds) > self.max_presigned_url_expiry => {
+                warn!(
+                    requested_secs = seconds,
+                    max_secs = self.max_presigned_url_expiry.as_secs(),
+                    "Presigned URL expiry clamped to the configured maximum"
+                );
+                self.max_presigned_url_expiry
+            }
+            Some(seconds) => Duration::from_secs(seconds),
+            None => DEFAULT_PRESIGNED_URL_EXPIRY.min(self.max_presigned_url_expiry),
+        };
+
+        let url = self
+            .url_generator
+            .presign_download(&artifact.content_hash, &artifact.file_name, expires_in)
+            .await?;
+
+        info!(
+            artifact_hrn = %artifact.artifact_hrn,
+            expires_in_secs = expires_in.as_secs(),
+            "Issued presigned download URL"
+        );
+
+        Ok(PresignedUrl {
+            url,
+            expires_at: OffsetDateTime::now_utc() + expires_in,
+            expires_in: expires_in.as_secs(),
+        })
+    }
+}
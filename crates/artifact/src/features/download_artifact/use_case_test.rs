@@ -0,0 +1,510 @@
+use crate::domain::events::{ArtifactDownloadCompleted, ArtifactEvent, DownloadDelivery};
+use crate::features::download_artifact::dto::{
+    ArtifactContent, DownloadableArtifact, GetArtifactParams,
+};
+use crate::features::download_artifact::mocks::{
+    MockArtifactContentReader, MockDownloadEventPublisher, MockDownloadableArtifactLookup,
+    MockPresignedUrlGenerator,
+};
+use crate::features::download_artifact::{
+    use_case::DownloadArtifactUseCase, ByteRange, DownloadArtifactEndpoint, DownloadArtifactError,
+    GetArtifactQuery, GetArtifactResponse, PresignedUrl,
+};
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use std::time::Duration;
+
+const ARTIFACT_ID: &str = "hrn:hodei:artifact::default:package-version/my-app/1.0.0";
+
+fn use_case_with(content: &'static [u8]) -> Arc<DownloadArtifactUseCase> {
+    Arc::new(use_case_presigning_with(
+        content,
+        Arc::new(MockPresignedUrlGenerator::new()),
+    ))
+}
+
+fn use_case_presigning_with(
+    content: &'static [u8],
+    url_generator: Arc<MockPresignedUrlGenerator>,
+) -> DownloadArtifactUseCase {
+    use_case_from(
+        content,
+        url_generator,
+        Arc::new(MockDownloadEventPublisher::new()),
+    )
+}
+
+fn use_case_publishing_to(
+    content: &'static [u8],
+    event_publisher: Arc<MockDownloadEventPublisher>,
+) -> Arc<DownloadArtifactUseCase> {
+    Arc::new(use_case_from(
+        content,
+        Arc::new(MockPresignedUrlGenerator::new()),
+        event_publisher,
+    ))
+}
+
+fn use_case_from(
+    content: &'static [u8],
+    url_generator: Arc<MockPresignedUrlGenerator>,
+    event_publisher: Arc<MockDownloadEventPublisher>,
+) -> DownloadArtifactUseCase {
+    use_case_storing(content, "abc123", url_generator, event_publisher)
+}
+
+/// Caso de uso con `content` guardado bajo `content_hash`, que puede no ser
+/// su hash real para simular un blob corrompido
+fn use_case_storing(
+    content: &'static [u8],
+    content_hash: &str,
+    url_generator: Arc<MockPresignedUrlGenerator>,
+    event_publisher: Arc<MockDownloadEventPublisher>,
+) -> DownloadArtifactUseCase {
+    let lookup = Arc::new(MockDownloadableArtifactLookup::new());
+    lookup.add(
+        ARTIFACT_ID,
+        DownloadableArtifact {
+            artifact_hrn: ARTIFACT_ID.to_string(),
+            content_hash: content_hash.to_string(),
+            file_name: "my-app-1.0.0".to_string(),
+            content_type: "application/java-archive".to_string(),
+            size_in_bytes: content.len() as u64,
+        },
+    );
+    let reader = Arc::new(MockArtifactContentReader::new());
+    reader.add_blob(content_hash, Bytes::from_static(content));
+
+    DownloadArtifactUseCase::new(lookup, reader, url_generator, event_publisher)
+}
+
+fn query(range: Option<ByteRange>) -> GetArtifactQuery {
+    GetArtifactQuery {
+        artifact_id: ARTIFACT_ID.to_string(),
+        range,
+        use_presigned_url: false,
+        expires_in: None,
+    }
+}
+
+fn presigned_query(expires_in: Option<u64>) -> GetArtifactQuery {
+    GetArtifactQuery {
+        use_presigned_url: true,
+        expires_in,
+        ..query(None)
+    }
+}
+
+async fn download(use_case: &DownloadArtifactUseCase, query: GetArtifactQuery) -> ArtifactContent {
+    match use_case.execute(query).await.unwrap() {
+        GetArtifactResponse::Content(content) => content,
+        other => panic!("expected content, got {:?}", other),
+    }
+}
+
+async fn presign(use_case: &DownloadArtifactUseCase, expires_in: Option<u64>) -> PresignedUrl {
+    match use_case.execute(presigned_query(expires_in)).await.unwrap() {
+        GetArtifactResponse::PresignedUrl(presigned_url) => presigned_url,
+        other => panic!("expected a presigned URL, got {:?}", other),
+    }
+}
+
+async fn get_with(
+    content: &'static [u8],
+    params: GetArtifactParams,
+    range: Option<&str>,
+) -> Response {
+    let endpoint = DownloadArtifactEndpoint::new(use_case_with(content));
+    request(&endpoint, params, range).await
+}
+
+async fn request(
+    endpoint: &DownloadArtifactEndpoint,
+    params: GetArtifactParams,
+    range: Option<&str>,
+) -> Response {
+    let mut headers = HeaderMap::new();
+    if let Some(range) = range {
+        headers.insert("range", HeaderValue::from_str(range).unwrap());
+    }
+    endpoint
+        .handle_get_artifact(Path(ARTIFACT_ID.to_string()), Query(params), headers)
+        .await
+        .into_response()
+}
+
+async fn get(content: &'static [u8], range: Option<&str>) -> Response {
+    get_with(content, GetArtifactParams::default(), range).await
+}
+
+async fn published_download(
+    event_publisher: &MockDownloadEventPublisher,
+) -> ArtifactDownloadCompleted {
+    let events = tokio::time::timeout(Duration::from_secs(1), event_publisher.wait_for(1))
+        .await
+        .expect("download completed event not published");
+    match &events[..] {
+        [ArtifactEvent::ArtifactDownloadCompleted(event)] => event.clone(),
+        other => panic!("expected one download completed event, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_byte_range_parse() {
+    assert_eq!(
+        ByteRange::parse("bytes=0-499"),
+        Some(ByteRange::FromStart {
+            start: 0,
+            end: Some(499)
+        })
+    );
+    assert_eq!(
+        ByteRange::parse("bytes=500-"),
+        Some(ByteRange::FromStart {
+            start: 500,
+            end: None
+        })
+    );
+    assert_eq!(ByteRange::parse("bytes=-200"), Some(ByteRange::Suffix(200)));
+
+    // Se ignoran: varios rangos, otra unidad y rangos mal formados
+    assert_eq!(ByteRange::parse("bytes=0-1,5-9"), None);
+    assert_eq!(ByteRange::parse("items=0-1"), None);
+    assert_eq!(ByteRange::parse("bytes=9-5"), None);
+    assert_eq!(ByteRange::parse("bytes=abc"), None);
+}
+
+#[test]
+fn test_byte_range_resolve() {
+    let range = ByteRange::FromStart {
+        start: 2,
+        end: Some(5),
+    }
+    .resolve(10)
+    .unwrap();
+    assert_eq!(range.header_value(), "bytes 2-5/10");
+    assert_eq!(range.length(), 4);
+
+    // El final se recorta al tamaño del artefacto
+    let range = ByteRange::FromStart {
+        start: 8,
+        end: Some(100),
+    }
+    .resolve(10)
+    .unwrap();
+    assert_eq!((range.start, range.end), (8, 9));
+    let range = ByteRange::Suffix(100).resolve(10).unwrap();
+    assert_eq!((range.start, range.end), (0, 9));
+    let range = ByteRange::Suffix(3).resolve(10).unwrap();
+    assert_eq!((range.start, range.end), (7, 9));
+
+    // No satisfacibles
+    assert_eq!(
+        ByteRange::FromStart {
+            start: 10,
+            end: None
+        }
+        .resolve(10),
+        None
+    );
+    assert_eq!(ByteRange::Suffix(0).resolve(10), None);
+    assert_eq!(
+        ByteRange::FromStart {
+            start: 0,
+            end: None
+        }
+        .resolve(0),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_download_serves_whole_artifact() {
+    let content = download(&use_case_with(b"0123456789"), query(None)).await;
+
+    assert_eq!(&content.content[..], b"0123456789");
+    assert_eq!(content.content_type, "application/java-archive");
+    assert!(content.content_range.is_none());
+}
+
+#[tokio::test]
+async fn test_download_range_serves_partial_content() {
+    let range = ByteRange::FromStart {
+        start: 2,
+        end: Some(5),
+    };
+    let content = download(&use_case_with(b"0123456789"), query(Some(range))).await;
+
+    assert_eq!(&content.content[..], b"2345");
+    assert_eq!(
+        content.content_range.unwrap().header_value(),
+        "bytes 2-5/10"
+    );
+}
+
+#[tokio::test]
+async fn test_download_range_past_end_is_not_satisfiable() {
+    let range = ByteRange::FromStart {
+        start: 10,
+        end: None,
+    };
+    let result = use_case_with(b"0123456789")
+        .execute(query(Some(range)))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DownloadArtifactError::RangeNotSatisfiable(10))
+    ));
+}
+
+#[tokio::test]
+async fn test_download_of_unknown_artifact_is_not_found() {
+    let mut unknown = query(None);
+    unknown.artifact_id = "hrn:hodei:artifact::default:package-version/other/1.0.0".to_string();
+
+    let result = use_case_with(b"0123456789").execute(unknown).await;
+
+    assert!(matches!(result, Err(DownloadArtifactError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_single_range_is_partial_content() {
+    let response = get(b"0123456789", Some("bytes=-3")).await;
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()["content-range"], "bytes 7-9/10");
+    assert_eq!(response.headers()["content-length"], "3");
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"789");
+}
+
+#[tokio::test]
+async fn test_multiple_ranges_serve_full_artifact() {
+    let response = get(b"0123456789", Some("bytes=0-1,4-5")).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["accept-ranges"], "bytes");
+    assert!(response.headers().get("content-range").is_none());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"0123456789");
+}
+
+#[tokio::test]
+async fn test_range_past_end_is_not_satisfiable() {
+    let response = get(b"0123456789", Some("bytes=10-20")).await;
+
+    assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(response.headers()["content-range"], "bytes */10");
+}
+
+#[tokio::test]
+async fn test_presigned_url_uses_the_requested_expiry() {
+    let url_generator = Arc::new(MockPresignedUrlGenerator::new());
+    let use_case = use_case_presigning_with(b"0123456789", url_generator.clone());
+
+    let presigned_url = presign(&use_case, Some(600)).await;
+
+    assert_eq!(presigned_url.expires_in, 600);
+    let issued = url_generator.issued.lock().unwrap();
+    assert_eq!(
+        issued[..],
+        [(
+            "abc123".to_string(),
+            "my-app-1.0.0".to_string(),
+            Duration::from_secs(600)
+        )]
+    );
+}
+
+#[tokio::test]
+async fn test_presigned_url_expiry_beyond_the_maximum_is_clamped() {
+    let url_generator = Arc::new(MockPresignedUrlGenerator::new());
+    let use_case = use_case_presigning_with(b"0123456789", url_generator.clone())
+        .with_max_presigned_url_expiry(Duration::from_secs(1800));
+
+    let presigned_url = presign(&use_case, Some(7 * 24 * 3600)).await;
+    // Sin validez pedida se usa la de por defecto, también recortada al máximo
+    let default_url = presign(&use_case, None).await;
+
+    assert_eq!(presigned_url.expires_in, 1800);
+    assert_eq!(default_url.expires_in, 1800);
+    let issued = url_generator.issued.lock().unwrap();
+    assert!(issued
+        .iter()
+        .all(|(_, _, expires_in)| *expires_in == Duration::from_secs(1800)));
+}
+
+#[tokio::test]
+async fn test_presigned_url_with_zero_expiry_is_a_bad_request() {
+    let result = use_case_with(b"0123456789")
+        .execute(presigned_query(Some(0)))
+        .await;
+
+    assert!(matches!(result, Err(DownloadArtifactError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn test_presigned_request_returns_the_url_as_json() {
+    let params = GetArtifactParams {
+        presigned: true,
+        expires_in: Some(120),
+    };
+    let response = get_with(b"0123456789", params, None).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["expiresIn"], 120);
+    assert!(body["url"].as_str().unwrap().contains("abc123"));
+    assert!(body["expiresAt"].is_string());
+}
+
+/// Varios trozos de `DOWNLOAD_CHUNK_SIZE`, para poder cortar a mitad de cuerpo
+static LARGE_ARTIFACT: [u8; 3 * 64 * 1024] = [7; 3 * 64 * 1024];
+
+#[tokio::test]
+async fn test_download_is_published_once_the_body_reaches_its_end() {
+    let event_publisher = Arc::new(MockDownloadEventPublisher::new());
+    let endpoint = DownloadArtifactEndpoint::new(use_case_publishing_to(
+        b"0123456789",
+        event_publisher.clone(),
+    ));
+
+    let response = request(&endpoint, GetArtifactParams::default(), Some("bytes=-3")).await;
+    assert!(event_publisher.events.lock().unwrap().is_empty());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    assert_eq!(&body[..], b"789");
+    let event = published_download(&event_publisher).await;
+    assert_eq!(event.artifact_hrn, ARTIFACT_ID);
+    assert_eq!(event.size_in_bytes, 3);
+    assert!(event.partial);
+    assert_eq!(event.delivery, DownloadDelivery::Streamed);
+}
+
+#[tokio::test]
+async fn test_interrupted_download_is_not_published() {
+    let event_publisher = Arc::new(MockDownloadEventPublisher::new());
+    let endpoint = DownloadArtifactEndpoint::new(use_case_publishing_to(
+        &LARGE_ARTIFACT,
+        event_publisher.clone(),
+    ));
+
+    let response = request(&endpoint, GetArtifactParams::default(), None).await;
+    let mut body = response.into_body().into_data_stream();
+    let first_chunk = body.next().await.unwrap().unwrap();
+    // El cliente corta la conexión tras el primer trozo
+    drop(body);
+    tokio::task::yield_now().await;
+
+    assert!(first_chunk.len() < LARGE_ARTIFACT.len());
+    assert!(event_publisher.events.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_presigned_download_is_published_when_the_url_is_issued() {
+    let event_publisher = Arc::new(MockDownloadEventPublisher::new());
+    let use_case = use_case_publishing_to(b"0123456789", event_publisher.clone());
+
+    presign(&use_case, Some(600)).await;
+
+    let event = published_download(&event_publisher).await;
+    assert_eq!(event.size_in_bytes, 10);
+    assert!(!event.partial);
+    assert_eq!(event.delivery, DownloadDelivery::PresignedUrlIssued);
+}
+
+fn verifying_endpoint(
+    content: &'static [u8],
+    content_hash: &str,
+    event_publisher: Arc<MockDownloadEventPublisher>,
+) -> DownloadArtifactEndpoint {
+    let use_case = use_case_storing(
+        content,
+        content_hash,
+        Arc::new(MockPresignedUrlGenerator::new()),
+        event_publisher,
+    )
+    .with_integrity_verification(true);
+    DownloadArtifactEndpoint::new(Arc::new(use_case))
+}
+
+fn sha256(content: &[u8]) -> String {
+    hex::encode(Sha256::digest(content))
+}
+
+#[tokio::test]
+async fn test_verified_download_with_matching_hash_completes() {
+    let event_publisher = Arc::new(MockDownloadEventPublisher::new());
+    let endpoint = verifying_endpoint(
+        &LARGE_ARTIFACT,
+        &sha256(&LARGE_ARTIFACT),
+        event_publisher.clone(),
+    );
+
+    let response = request(&endpoint, GetArtifactParams::default(), None).await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+
+    assert_eq!(body.len(), LARGE_ARTIFACT.len());
+    let event = published_download(&event_publisher).await;
+    assert_eq!(event.delivery, DownloadDelivery::Streamed);
+}
+
+#[tokio::test]
+async fn test_corrupted_download_fails_integrity_check() {
+    let event_publisher = Arc::new(MockDownloadEventPublisher::new());
+    // Hash de otro contenido: los bytes guardados se han corrompido
+    let expected_hash = sha256(b"original content");
+    let endpoint = verifying_endpoint(&LARGE_ARTIFACT, &expected_hash, event_publisher.clone());
+
+    let response = request(&endpoint, GetArtifactParams::default(), None).await;
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await;
+
+    assert!(body.is_err());
+    let events = tokio::time::timeout(Duration::from_secs(1), event_publisher.wait_for(1))
+        .await
+        .expect("integrity failure not published");
+    match &events[..] {
+        [ArtifactEvent::DownloadIntegrityFailure(failure)] => {
+            assert_eq!(failure.artifact_hrn, ARTIFACT_ID);
+            assert_eq!(failure.expected_hash, expected_hash);
+            assert_eq!(failure.actual_hash, sha256(&LARGE_ARTIFACT));
+        }
+        other => panic!("expected one integrity failure, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_ranges_are_served_unverified() {
+    let use_case = use_case_storing(
+        b"0123456789",
+        "not-the-real-hash",
+        Arc::new(MockPresignedUrlGenerator::new()),
+        Arc::new(MockDownloadEventPublisher::new()),
+    )
+    .with_integrity_verification(true);
+
+    let whole = download(&use_case, query(None)).await;
+    let range = download(&use_case, query(Some(ByteRange::Suffix(3)))).await;
+
+    assert_eq!(whole.expected_sha256.as_deref(), Some("not-the-real-hash"));
+    assert_eq!(range.expected_sha256, None);
+}
@@ -1,4 +1,5 @@
 pub mod content_type_detection;
+pub mod download_artifact;
 pub mod extract_metadata;
 pub mod upload_artifact;
 pub mod upload_artifact_chunks;
@@ -11,16 +11,26 @@ use lapin::{
     ConnectionProperties,
     ExchangeKind,
 };
-use mongodb::{bson::doc, Client as MongoClient, Database};
+use mongodb::{
+    bson::{doc, Document},
+    options::{FindOneAndUpdateOptions, ReturnDocument},
+    Client as MongoClient, Database,
+};
+use repository::domain::RepositoryError;
+use repository::features::enforce_repository_quota::EnforceRepositoryQuotaUseCase;
+use repository::{QuotaReservation, ReserveQuotaCommand};
 use serde_json::to_string;
 use sha2::Digest;
+use shared::hrn::RepositoryId;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tokio::io::AsyncWriteExt;
 
 use super::error::UploadArtifactError;
 use super::ports::ArtifactValidator;
 use super::ports::{
     ArtifactRepository, ArtifactStorage, ChunkedUploadStorage, EventPublisher, PortResult,
+    RepositoryQuotaPort,
 };
 use crate::domain::{
     events::ArtifactEvent, package_version::PackageVersion, physical_artifact::PhysicalArtifact,
@@ -76,6 +86,34 @@ impl ArtifactStorage for S3ArtifactStorage {
 
         Ok(format!("s3://{}/{}", self.bucket_name, content_hash))
     }
+
+    async fn download(&self, content_hash: &str) -> PortResult<Bytes> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(content_hash)
+            .send()
+            .await
+            .map_err(|e| UploadArtifactError::StorageError(e.to_string()))?;
+        let data = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| UploadArtifactError::StorageError(e.to_string()))?;
+        Ok(data.into_bytes())
+    }
+
+    async fn delete(&self, content_hash: &str) -> PortResult<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket_name)
+            .key(content_hash)
+            .send()
+            .await
+            .map_err(|e| UploadArtifactError::StorageError(e.to_string()))?;
+        Ok(())
+    }
 }
 
 // --- ArtifactRepository: MongoDB ---
@@ -141,6 +179,75 @@ impl ArtifactRepository for MongoDbRepository {
             None => Ok(None),
         }
     }
+
+    async fn increment_reference_count(&self, hash: &str) -> PortResult<Option<u64>> {
+        let collection = self.db.collection::<Document>("physical_artifacts");
+        // Only live records can gain references; one released to zero is about to go
+        let filter = doc! { "content_hash.value": hash, "$or": referenced_filter() };
+        let options = FindOneAndUpdateOptions::builder()
+            .return_document(ReturnDocument::After)
+            .build();
+        let updated = collection
+            .find_one_and_update(filter, add_to_reference_count(1), options)
+            .await
+            .map_err(|e| UploadArtifactError::RepositoryError(e.to_string()))?;
+        updated.map(|doc| reference_count_of(&doc)).transpose()
+    }
+
+    async fn decrement_reference_count(&self, hash: &str) -> PortResult<u64> {
+        let collection = self.db.collection::<Document>("physical_artifacts");
+        let filter = doc! { "content_hash.value": hash, "$or": referenced_filter() };
+        let options = FindOneAndUpdateOptions::builder()
+            .return_document(ReturnDocument::After)
+            .build();
+        let updated = collection
+            .find_one_and_update(filter, add_to_reference_count(-1), options)
+            .await
+            .map_err(|e| UploadArtifactError::RepositoryError(e.to_string()))?;
+        match updated {
+            Some(doc) => reference_count_of(&doc),
+            None => Err(UploadArtifactError::NotFound(format!(
+                "No referenced physical artifact with hash {}",
+                hash
+            ))),
+        }
+    }
+
+    async fn delete_unreferenced_physical_artifact(&self, hash: &str) -> PortResult<bool> {
+        let collection = self.db.collection::<Document>("physical_artifacts");
+        let filter = doc! { "content_hash.value": hash, "reference_count": 0_i64 };
+        let result = collection
+            .delete_one(filter, None)
+            .await
+            .map_err(|e| UploadArtifactError::RepositoryError(e.to_string()))?;
+        Ok(result.deleted_count > 0)
+    }
+}
+
+/// Records with references left; those stored before reference counting
+/// have no `reference_count` and hold one
+fn referenced_filter() -> Vec<Document> {
+    vec![
+        doc! { "reference_count": { "$gt": 0 } },
+        doc! { "reference_count": { "$exists": false } },
+    ]
+}
+
+/// Pipeline update adding `delta` to the count, a missing one counting as one
+fn add_to_reference_count(delta: i64) -> Vec<Document> {
+    vec![doc! {
+        "$set": {
+            "reference_count": { "$add": [{ "$ifNull": ["$reference_count", 1_i64] }, delta] }
+        }
+    }]
+}
+
+fn reference_count_of(doc: &Document) -> PortResult<u64> {
+    let count = doc
+        .get_i64("reference_count")
+        .or_else(|_| doc.get_i32("reference_count").map(i64::from))
+        .map_err(|e| UploadArtifactError::RepositoryError(e.to_string()))?;
+    Ok(count.max(0) as u64)
 }
 
 // --- EventPublisher: RabbitMQ ---
@@ -195,6 +302,46 @@ impl EventPublisher for RabbitMqEventPublisher {
     }
 }
 
+// --- RepositoryQuotaPort: cuotas del crate repository ---
+pub struct EnforceRepositoryQuotaAdapter {
+    use_case: Arc<EnforceRepositoryQuotaUseCase>,
+}
+
+impl EnforceRepositoryQuotaAdapter {
+    pub fn new(use_case: Arc<EnforceRepositoryQuotaUseCase>) -> Self {
+        Self { use_case }
+    }
+}
+
+#[async_trait]
+impl RepositoryQuotaPort for EnforceRepositoryQuotaAdapter {
+    async fn reserve(
+        &self,
+        repository_id: &RepositoryId,
+        size_bytes: u64,
+    ) -> PortResult<QuotaReservation> {
+        self.use_case
+            .reserve(ReserveQuotaCommand {
+                repository_hrn: repository_id.to_string(),
+                size_bytes,
+            })
+            .await
+            .map_err(|e| match e {
+                RepositoryError::QuotaExceeded(msg) => UploadArtifactError::QuotaExceeded(msg),
+                RepositoryError::RepositoryNotFound(msg) => UploadArtifactError::NotFound(msg),
+                other => UploadArtifactError::RepositoryError(other.to_string()),
+            })
+    }
+
+    fn complete(&self, reservation: &QuotaReservation) {
+        self.use_case.complete(reservation);
+    }
+
+    fn cancel(&self, reservation: &QuotaReservation) {
+        self.use_case.cancel(reservation);
+    }
+}
+
 // Validador por defecto (no-op)
 pub struct NoopArtifactValidator;
 
@@ -261,6 +408,21 @@ impl ArtifactStorage for LocalFsArtifactStorage {
         }
         Ok(format!("file://{}", dst.display()))
     }
+
+    async fn download(&self, content_hash: &str) -> PortResult<Bytes> {
+        let content = tokio::fs::read(self.target_path(content_hash))
+            .await
+            .map_err(|e| UploadArtifactError::StorageError(e.to_string()))?;
+        Ok(Bytes::from(content))
+    }
+
+    async fn delete(&self, content_hash: &str) -> PortResult<()> {
+        match tokio::fs::remove_file(self.target_path(content_hash)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(UploadArtifactError::StorageError(e.to_string())),
+        }
+    }
 }
 
 // --- ChunkedUploadStorage: Local filesystem ---
@@ -3,14 +3,14 @@ use std::sync::Arc;
 
 use super::{
     adapter::{
-        LocalFsChunkedUploadStorage, MongoDbRepository, NoopArtifactValidator,
-        RabbitMqEventPublisher, S3ArtifactStorage,
+        EnforceRepositoryQuotaAdapter, LocalFsChunkedUploadStorage, MongoDbRepository,
+        NoopArtifactValidator, RabbitMqEventPublisher, S3ArtifactStorage,
     },
     ports::{
         ArtifactRepository, ArtifactStorage, ArtifactValidator, ChunkedUploadStorage,
-        EventPublisher, VersionValidator,
+        EventPublisher, RepositoryQuotaPort, VersionValidator,
     },
-    use_case::UploadArtifactUseCase,
+    use_case::{ReleasePhysicalArtifactUseCase, UploadArtifactUseCase},
 };
 
 use crate::features::content_type_detection::{
@@ -22,6 +22,7 @@ use aws_config::SdkConfig;
 /// The Dependency Injection container for the Upload Artifact feature.
 pub struct UploadArtifactDIContainer {
     pub use_case: Arc<UploadArtifactUseCase>,
+    pub release_use_case: Arc<ReleasePhysicalArtifactUseCase>,
 }
 
 impl UploadArtifactDIContainer {
@@ -29,6 +30,7 @@ impl UploadArtifactDIContainer {
     pub fn new(
         repository: Arc<dyn ArtifactRepository + Send + Sync>,
         storage: Arc<dyn ArtifactStorage + Send + Sync>,
+        quota: Arc<dyn RepositoryQuotaPort + Send + Sync>,
         publisher: Arc<dyn EventPublisher + Send + Sync>,
         _chunked_storage: Arc<dyn ChunkedUploadStorage + Send + Sync>, // ya no se usa aquí
         validator: Arc<dyn ArtifactValidator + Send + Sync>,
@@ -36,15 +38,23 @@ impl UploadArtifactDIContainer {
         version_validator: Arc<dyn VersionValidator + Send + Sync>,
         content_type_service: Arc<ContentTypeDetectionUseCase>,
     ) -> Self {
+        let release_use_case = Arc::new(ReleasePhysicalArtifactUseCase::new(
+            repository.clone(),
+            storage.clone(),
+        ));
         let use_case = Arc::new(UploadArtifactUseCase::new(
             repository,
             storage,
+            quota,
             publisher.clone(),
             validator,
             version_validator,
             content_type_service,
         ));
-        Self { use_case }
+        Self {
+            use_case,
+            release_use_case,
+        }
     }
 
     /// Convenience function for wiring up production dependencies.
@@ -55,7 +65,14 @@ impl UploadArtifactDIContainer {
         _rabbit_conn: lapin::Connection,
         _upload_dir: PathBuf,
     ) -> Self {
-        let repository = Arc::new(MongoDbRepository::new_with_client(mongo_client));
+        let repository = Arc::new(MongoDbRepository::new_with_client(mongo_client.clone()));
+        // Cuotas de repositorio, sobre la misma base de datos que las versiones de paquete
+        let quota = Arc::new(EnforceRepositoryQuotaAdapter::new(
+            ::repository::EnforceRepositoryQuotaFeature::for_production(
+                mongo_client.database("hodei"),
+            )
+            .use_case,
+        ));
         let storage = Arc::new(S3ArtifactStorage::new(
             config,
             "hodei-artifacts".to_string(),
@@ -95,6 +112,7 @@ impl UploadArtifactDIContainer {
         Self::new(
             repository,
             storage,
+            quota,
             publisher,
             chunk_storage,
             validator,
@@ -31,4 +31,7 @@ pub enum UploadArtifactError {
 
     #[error("Conflict: {0}")]
     Conflict(String),
+
+    #[error("Repository quota exceeded: {0}")]
+    QuotaExceeded(String),
 }
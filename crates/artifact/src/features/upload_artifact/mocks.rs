@@ -2,13 +2,15 @@ use super::dto::UploadArtifactCommand;
 use super::error::UploadArtifactError;
 use super::ports::{
     ArtifactRepository, ArtifactStorage, ArtifactValidator, EventPublisher, ParsedVersion,
-    PortResult, VersionValidator,
+    PortResult, RepositoryQuotaPort, VersionValidator,
 };
 use crate::domain::events::ArtifactEvent;
 use crate::domain::package_version::PackageVersion;
 use crate::domain::physical_artifact::PhysicalArtifact;
 use async_trait::async_trait;
 use bytes::Bytes;
+use repository::QuotaReservation;
+use shared::hrn::RepositoryId;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
@@ -58,6 +60,34 @@ impl ArtifactRepository for MockArtifactRepository {
         Ok(())
     }
 
+    async fn increment_reference_count(&self, hash: &str) -> PortResult<Option<u64>> {
+        let mut artifacts = self.physical_artifacts.lock().unwrap();
+        Ok(artifacts
+            .iter_mut()
+            .find(|a| a.content_hash.value == hash && a.reference_count > 0)
+            .map(|a| {
+                a.reference_count += 1;
+                a.reference_count
+            }))
+    }
+
+    async fn decrement_reference_count(&self, hash: &str) -> PortResult<u64> {
+        let mut artifacts = self.physical_artifacts.lock().unwrap();
+        let artifact = artifacts
+            .iter_mut()
+            .find(|a| a.content_hash.value == hash && a.reference_count > 0)
+            .ok_or_else(|| UploadArtifactError::NotFound(hash.to_string()))?;
+        artifact.reference_count -= 1;
+        Ok(artifact.reference_count)
+    }
+
+    async fn delete_unreferenced_physical_artifact(&self, hash: &str) -> PortResult<bool> {
+        let mut artifacts = self.physical_artifacts.lock().unwrap();
+        let before = artifacts.len();
+        artifacts.retain(|a| !(a.content_hash.value == hash && a.reference_count == 0));
+        Ok(artifacts.len() < before)
+    }
+
     async fn save_package_version(&self, package_version: &PackageVersion) -> PortResult<()> {
         self.package_versions
             .lock()
@@ -71,6 +101,7 @@ impl ArtifactRepository for MockArtifactRepository {
 pub struct MockArtifactStorage {
     pub uploads: Arc<Mutex<Vec<(String, Bytes)>>>,
     pub should_fail_upload: Arc<Mutex<bool>>,
+    pub should_fail_delete: Arc<Mutex<bool>>,
 }
 
 impl MockArtifactStorage {
@@ -98,6 +129,118 @@ impl ArtifactStorage for MockArtifactStorage {
         let content = tokio::fs::read(path).await.unwrap();
         self.upload(Bytes::from(content), content_hash).await
     }
+
+    async fn download(&self, content_hash: &str) -> PortResult<Bytes> {
+        self.uploads
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|(hash, _)| hash == content_hash)
+            .map(|(_, content)| content.clone())
+            .ok_or_else(|| UploadArtifactError::NotFound(content_hash.to_string()))
+    }
+
+    async fn delete(&self, content_hash: &str) -> PortResult<()> {
+        if *self.should_fail_delete.lock().unwrap() {
+            return Err(UploadArtifactError::StorageError(
+                "Mock delete failed".to_string(),
+            ));
+        }
+        self.uploads
+            .lock()
+            .unwrap()
+            .retain(|(hash, _)| hash != content_hash);
+        Ok(())
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct MockRepositoryQuota {
+    /// Total bytes the repository accepts; `None` for no limit
+    pub max_size_bytes: Mutex<Option<u64>>,
+    pub in_flight: Mutex<Vec<QuotaReservation>>,
+    pub completed: Mutex<Vec<QuotaReservation>>,
+    pub cancelled: Mutex<Vec<QuotaReservation>>,
+}
+
+impl MockRepositoryQuota {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_size_bytes(max_size_bytes: u64) -> Self {
+        let quota = Self::default();
+        *quota.max_size_bytes.lock().unwrap() = Some(max_size_bytes);
+        quota
+    }
+
+    fn used_bytes(&self) -> u64 {
+        let in_flight: u64 = self
+            .in_flight
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| r.size_bytes)
+            .sum();
+        let completed: u64 = self
+            .completed
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| r.size_bytes)
+            .sum();
+        in_flight + completed
+    }
+
+    fn take_in_flight(&self, reservation: &QuotaReservation) -> Option<QuotaReservation> {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        let position = in_flight.iter().position(|r| r.id == reservation.id)?;
+        Some(in_flight.remove(position))
+    }
+}
+
+#[async_trait]
+impl RepositoryQuotaPort for MockRepositoryQuota {
+    async fn reserve(
+        &self,
+        repository_id: &RepositoryId,
+        size_bytes: u64,
+    ) -> PortResult<QuotaReservation> {
+        let max_size_bytes = *self.max_size_bytes.lock().unwrap();
+        if let Some(max_size_bytes) = max_size_bytes
+            && self.used_bytes() + size_bytes > max_size_bytes
+        {
+            return Err(UploadArtifactError::QuotaExceeded(format!(
+                "{} bytes do not fit in {}",
+                size_bytes, repository_id
+            )));
+        }
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+        let reservation = QuotaReservation {
+            id: in_flight.len() as u64
+                + self.completed.lock().unwrap().len() as u64
+                + self.cancelled.lock().unwrap().len() as u64
+                + 1,
+            repository_hrn: repository_id.to_string(),
+            size_bytes,
+        };
+        in_flight.push(reservation.clone());
+        Ok(reservation)
+    }
+
+    fn complete(&self, reservation: &QuotaReservation) {
+        if let Some(reservation) = self.take_in_flight(reservation) {
+            self.completed.lock().unwrap().push(reservation);
+        }
+    }
+
+    fn cancel(&self, reservation: &QuotaReservation) {
+        if let Some(reservation) = self.take_in_flight(reservation) {
+            self.cancelled.lock().unwrap().push(reservation);
+        }
+    }
 }
 
 #[derive(Default, Debug)]
@@ -19,3 +19,4 @@ pub use error::UploadArtifactError;
 pub use ports::{
     ArtifactRepository, ArtifactStorage, ArtifactValidator, ChunkedUploadStorage, EventPublisher,
 };
+pub use use_case::ReleasePhysicalArtifactUseCase;
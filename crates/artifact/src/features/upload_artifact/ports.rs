@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use bytes::Bytes;
-use shared::hrn::Hrn;
+use repository::QuotaReservation;
+use shared::hrn::{Hrn, RepositoryId};
 use std::path::{Path, PathBuf};
 
 use super::dto::UploadArtifactCommand;
@@ -19,6 +20,21 @@ pub trait ArtifactRepository: Send + Sync {
         hash: &str,
     ) -> PortResult<Option<PhysicalArtifact>>;
     async fn save_physical_artifact(&self, artifact: &PhysicalArtifact) -> PortResult<()>;
+
+    /// Atomically add a reference to the physical artifact with this hash.
+    /// Returns the new count, or `None` if no record with references is left.
+    /// A record stored before reference counting counts as one reference.
+    async fn increment_reference_count(&self, hash: &str) -> PortResult<Option<u64>>;
+
+    /// Atomically drop a reference to the physical artifact with this hash
+    /// and return the remaining count; `NotFound` if it has none left.
+    /// A record stored before reference counting counts as one reference.
+    async fn decrement_reference_count(&self, hash: &str) -> PortResult<u64>;
+
+    /// Remove the physical artifact record only if nothing references it.
+    /// Returns `true` if the record was removed.
+    async fn delete_unreferenced_physical_artifact(&self, hash: &str) -> PortResult<bool>;
+
     async fn save_package_version(&self, package_version: &PackageVersion) -> PortResult<()>;
 
     /// Update package metadata and dependencies for an existing package version
@@ -40,6 +56,30 @@ pub trait ArtifactRepository: Send + Sync {
 pub trait ArtifactStorage: Send + Sync {
     async fn upload(&self, content: Bytes, content_hash: &str) -> PortResult<String>;
     async fn upload_from_path(&self, path: &Path, content_hash: &str) -> PortResult<String>;
+
+    /// Read back the blob stored under this hash, to verify content on a hash match
+    async fn download(&self, content_hash: &str) -> PortResult<Bytes>;
+
+    /// Remove the blob stored under this hash
+    async fn delete(&self, content_hash: &str) -> PortResult<()>;
+}
+
+/// Port for the quota of the repository an artifact is uploaded to
+#[async_trait]
+pub trait RepositoryQuotaPort: Send + Sync {
+    /// Hold `size_bytes` of the repository's quota before anything is written;
+    /// `QuotaExceeded` if the upload does not fit
+    async fn reserve(
+        &self,
+        repository_id: &RepositoryId,
+        size_bytes: u64,
+    ) -> PortResult<QuotaReservation>;
+
+    /// The package version is persisted and now counts as repository usage
+    fn complete(&self, reservation: &QuotaReservation);
+
+    /// The upload failed; release what it held
+    fn cancel(&self, reservation: &QuotaReservation);
 }
 
 #[async_trait]
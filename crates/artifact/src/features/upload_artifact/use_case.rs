@@ -18,9 +18,10 @@ use crate::features::upload_artifact::{
     error::UploadArtifactError,
     ports::{
         ArtifactRepository, ArtifactStorage, ArtifactValidator, EventPublisher, PortResult,
-        VersionValidator,
+        RepositoryQuotaPort, VersionValidator,
     },
 };
+use repository::QuotaReservation;
 use shared::{
     enums::{ArtifactRole, ArtifactType, HashAlgorithm},
     hrn::{Hrn, OrganizationId, PhysicalArtifactId, RepositoryId, UserId},
@@ -31,6 +32,7 @@ use shared::{
 pub struct UploadArtifactUseCase {
     repository: Arc<dyn ArtifactRepository>,
     storage: Arc<dyn ArtifactStorage>,
+    quota: Arc<dyn RepositoryQuotaPort>,
     event_publisher: Arc<dyn EventPublisher>,
     validator: Arc<dyn ArtifactValidator>,
     version_validator: Arc<dyn VersionValidator>,
@@ -41,6 +43,7 @@ impl UploadArtifactUseCase {
     pub fn new(
         repository: Arc<dyn ArtifactRepository>,
         storage: Arc<dyn ArtifactStorage>,
+        quota: Arc<dyn RepositoryQuotaPort>,
         event_publisher: Arc<dyn EventPublisher>,
         validator: Arc<dyn ArtifactValidator>,
         version_validator: Arc<dyn VersionValidator>,
@@ -49,6 +52,7 @@ impl UploadArtifactUseCase {
         Self {
             repository,
             storage,
+            quota,
             event_publisher,
             validator,
             version_validator,
@@ -60,6 +64,17 @@ impl UploadArtifactUseCase {
         &self,
         command: UploadArtifactCommand,
         content: Bytes,
+    ) -> PortResult<UploadArtifactResponse> {
+        let reservation = self.reserve_quota(&command).await?;
+        let result = self.store(command, content).await;
+        self.settle_quota(&reservation, &result);
+        result
+    }
+
+    async fn store(
+        &self,
+        command: UploadArtifactCommand,
+        content: Bytes,
     ) -> PortResult<UploadArtifactResponse> {
         tracing::info!("Executing use case");
 
@@ -125,33 +140,13 @@ impl UploadArtifactUseCase {
         let detected_mime_type = content_type_result.detected_mime_type;
         tracing::debug!("Detected MIME type: {}", detected_mime_type);
 
-        // 2. Check for existing physical artifact
+        // 2. Reuse the existing physical artifact if its content matches
         let physical_artifact_hrn = match self
-            .repository
-            .find_physical_artifact_by_hash(&content_hash_str)
-            .await
+            .reuse_existing_blob(&content_hash_str, &content, &command)
+            .await?
         {
-            Ok(Some(existing)) => {
-                tracing::debug!("Found existing physical artifact");
-
-                // Publish DuplicateArtifactDetected event
-                tracing::debug!("Publishing DuplicateArtifactDetected event");
-                let duplicate_event =
-                    ArtifactEvent::DuplicateArtifactDetected(DuplicateArtifactDetected {
-                        content_hash: content_hash_str.clone(),
-                        existing_physical_artifact_hrn: existing.hrn.to_string(),
-                        new_package_coordinates: command.coordinates.clone(),
-                        size_in_bytes: command.content_length,
-                        at: OffsetDateTime::now_utc(),
-                    });
-
-                if let Err(e) = self.event_publisher.publish(&duplicate_event).await {
-                    tracing::warn!(error = %e, "Failed to publish DuplicateArtifactDetected event");
-                }
-
-                existing.hrn
-            }
-            Ok(None) => {
+            Some(existing_hrn) => existing_hrn,
+            None => {
                 tracing::debug!("Creating new physical artifact");
                 // 3. Upload to storage if it's a new artifact
                 let storage_location = self
@@ -178,6 +173,7 @@ impl UploadArtifactUseCase {
                     checksums: std::collections::HashMap::new(),
                     storage_location,
                     mime_type: detected_mime_type.clone(),
+                    reference_count: 1,
                     lifecycle: Lifecycle::new(Hrn("hrn:hodei:iam::system:user/system".to_string())),
                 };
                 self.repository
@@ -190,27 +186,10 @@ impl UploadArtifactUseCase {
                 tracing::debug!("Saved new physical artifact");
                 new_physical_artifact_hrn.0
             }
-            Err(e) => {
-                tracing::error!("Repository find physical artifact error: {:?}", e);
-                return Err(e);
-            }
         };
 
         // 5. Create and save the package version
-        let org_name = command
-            .coordinates
-            .namespace
-            .clone()
-            .unwrap_or("default".to_string());
-        tracing::debug!("Org name: {}", org_name);
-        let org_id = OrganizationId::new(&org_name).map_err(|e| {
-            tracing::error!("OrganizationId creation error: {:?}", e);
-            UploadArtifactError::RepositoryError(e.to_string())
-        })?;
-        let repo_id = RepositoryId::new(&org_id, "default").map_err(|e| {
-            tracing::error!("RepositoryId creation error: {:?}", e);
-            UploadArtifactError::RepositoryError(e.to_string())
-        })?;
+        let (org_id, repo_id) = Self::target_repository(&command)?;
         let hrn_str = format!(
             "{}/package-version/{}/{}",
             repo_id.0.as_str(),
@@ -288,6 +267,20 @@ impl UploadArtifactUseCase {
         command: UploadArtifactCommand,
         temp_file_path: &Path,
         precomputed_checksum: Option<String>,
+    ) -> PortResult<UploadArtifactResponse> {
+        let reservation = self.reserve_quota(&command).await?;
+        let result = self
+            .store_from_temp_file(command, temp_file_path, precomputed_checksum)
+            .await;
+        self.settle_quota(&reservation, &result);
+        result
+    }
+
+    async fn store_from_temp_file(
+        &self,
+        command: UploadArtifactCommand,
+        temp_file_path: &Path,
+        precomputed_checksum: Option<String>,
     ) -> PortResult<UploadArtifactResponse> {
         tracing::info!("Executing use case from temp file");
 
@@ -369,33 +362,13 @@ impl UploadArtifactUseCase {
         let detected_mime_type = content_type_result.detected_mime_type;
         tracing::debug!("Detected MIME type: {}", detected_mime_type);
 
-        // 1. Check for existing physical artifact
+        // 1. Reuse the existing physical artifact if its content matches
         let physical_artifact_hrn = match self
-            .repository
-            .find_physical_artifact_by_hash(&content_hash_str)
-            .await
+            .reuse_existing_blob(&content_hash_str, &Bytes::from(file_content), &command)
+            .await?
         {
-            Ok(Some(existing)) => {
-                tracing::debug!("Found existing physical artifact");
-
-                // Publish DuplicateArtifactDetected event
-                tracing::debug!("Publishing DuplicateArtifactDetected event");
-                let duplicate_event =
-                    ArtifactEvent::DuplicateArtifactDetected(DuplicateArtifactDetected {
-                        content_hash: content_hash_str.clone(),
-                        existing_physical_artifact_hrn: existing.hrn.to_string(),
-                        new_package_coordinates: command.coordinates.clone(),
-                        size_in_bytes: command.content_length,
-                        at: OffsetDateTime::now_utc(),
-                    });
-
-                if let Err(e) = self.event_publisher.publish(&duplicate_event).await {
-                    tracing::warn!(error = %e, "Failed to publish DuplicateArtifactDetected event");
-                }
-
-                existing.hrn
-            }
-            Ok(None) => {
+            Some(existing_hrn) => existing_hrn,
+            None => {
                 tracing::debug!("Creating new physical artifact from temp file");
                 // 2. Upload to storage if it's a new artifact
                 let storage_location = self
@@ -423,6 +396,7 @@ impl UploadArtifactUseCase {
                     checksums: std::collections::HashMap::new(),
                     storage_location,
                     mime_type: detected_mime_type.clone(),
+                    reference_count: 1,
                     lifecycle: Lifecycle::new(Hrn("hrn:hodei:iam::system:user/system".to_string())),
                 };
                 self.repository
@@ -435,27 +409,10 @@ impl UploadArtifactUseCase {
                 tracing::debug!("Saved new physical artifact");
                 new_physical_artifact_hrn.0
             }
-            Err(e) => {
-                tracing::error!("Repository find physical artifact error: {:?}", e);
-                return Err(e);
-            }
         };
 
         // 4. Create and save the package version (same as in execute)
-        let org_name = command
-            .coordinates
-            .namespace
-            .clone()
-            .unwrap_or("default".to_string());
-        tracing::debug!("Org name: {}", org_name);
-        let org_id = OrganizationId::new(&org_name).map_err(|e| {
-            tracing::error!("OrganizationId creation error: {:?}", e);
-            UploadArtifactError::RepositoryError(e.to_string())
-        })?;
-        let repo_id = RepositoryId::new(&org_id, "default").map_err(|e| {
-            tracing::error!("RepositoryId creation error: {:?}", e);
-            UploadArtifactError::RepositoryError(e.to_string())
-        })?;
+        let (org_id, repo_id) = Self::target_repository(&command)?;
         let hrn_str = format!(
             "{}/package-version/{}/{}",
             repo_id.0.as_str(),
@@ -523,4 +480,205 @@ impl UploadArtifactUseCase {
             url: None,
         })
     }
+
+    /// Reuse the stored blob for this hash if its content matches the upload,
+    /// adding a reference to it.
+    ///
+    /// Returns `None` when no blob is stored for the hash, so the caller
+    /// stores a new one, and `Conflict` when the stored one is being released.
+    /// Organización y repositorio en los que se publica el artefacto
+    fn target_repository(
+        command: &UploadArtifactCommand,
+    ) -> PortResult<(OrganizationId, RepositoryId)> {
+        let org_name = command
+            .coordinates
+            .namespace
+            .clone()
+            .unwrap_or("default".to_string());
+        tracing::debug!("Org name: {}", org_name);
+        let org_id = OrganizationId::new(&org_name).map_err(|e| {
+            tracing::error!("OrganizationId creation error: {:?}", e);
+            UploadArtifactError::RepositoryError(e.to_string())
+        })?;
+        let repo_id = RepositoryId::new(&org_id, "default").map_err(|e| {
+            tracing::error!("RepositoryId creation error: {:?}", e);
+            UploadArtifactError::RepositoryError(e.to_string())
+        })?;
+        Ok((org_id, repo_id))
+    }
+
+    /// Reservar la cuota del repositorio antes de escribir nada en el
+    /// almacenamiento, para que una subida que no cabe no deje un blob huérfano
+    async fn reserve_quota(&self, command: &UploadArtifactCommand) -> PortResult<QuotaReservation> {
+        let (_, repository_id) = Self::target_repository(command)?;
+        self.quota
+            .reserve(&repository_id, command.content_length)
+            .await
+            .map_err(|e| {
+                tracing::warn!(repository = %repository_id, error = %e, "Upload rejected by repository quota");
+                e
+            })
+    }
+
+    /// Confirmar la reserva si la subida terminó bien; si no, liberarla
+    fn settle_quota<T>(&self, reservation: &QuotaReservation, result: &PortResult<T>) {
+        match result {
+            Ok(_) => self.quota.complete(reservation),
+            Err(_) => self.quota.cancel(reservation),
+        }
+    }
+
+    async fn reuse_existing_blob(
+        &self,
+        content_hash: &str,
+        content: &Bytes,
+        command: &UploadArtifactCommand,
+    ) -> PortResult<Option<Hrn>> {
+        let existing = match self
+            .repository
+            .find_physical_artifact_by_hash(content_hash)
+            .await
+        {
+            Ok(Some(existing)) => existing,
+            Ok(None) => return Ok(None),
+            Err(e) => {
+                tracing::error!("Repository find physical artifact error: {:?}", e);
+                return Err(e);
+            }
+        };
+        tracing::debug!("Found existing physical artifact");
+        // A record at zero references is being released: its blob may
+        // already be gone, and one stored now under the same key would be
+        // deleted with it
+        if existing.reference_count == 0 {
+            return Err(Self::released_meanwhile(content_hash));
+        }
+
+        // A matching hash alone does not prove matching content: compare the
+        // full bytes before sharing the blob
+        let stored = self.storage.download(content_hash).await.map_err(|e| {
+            tracing::error!("Storage download error: {:?}", e);
+            e
+        })?;
+        if stored != *content {
+            tracing::error!(
+                content_hash,
+                "Hash collision: stored blob differs from upload"
+            );
+            return Err(UploadArtifactError::Conflict(format!(
+                "Content hash collision for {}: stored blob differs from uploaded content",
+                content_hash
+            )));
+        }
+
+        let Some(reference_count) = self
+            .repository
+            .increment_reference_count(content_hash)
+            .await?
+        else {
+            tracing::debug!("Existing physical artifact was released before it could be reused");
+            return Err(Self::released_meanwhile(content_hash));
+        };
+        tracing::debug!(
+            reference_count,
+            "Added reference to existing physical artifact"
+        );
+
+        // Publish DuplicateArtifactDetected event
+        tracing::debug!("Publishing DuplicateArtifactDetected event");
+        let duplicate_event = ArtifactEvent::DuplicateArtifactDetected(DuplicateArtifactDetected {
+            content_hash: content_hash.to_string(),
+            existing_physical_artifact_hrn: existing.hrn.to_string(),
+            new_package_coordinates: command.coordinates.clone(),
+            size_in_bytes: command.content_length,
+            at: OffsetDateTime::now_utc(),
+        });
+
+        if let Err(e) = self.event_publisher.publish(&duplicate_event).await {
+            tracing::warn!(error = %e, "Failed to publish DuplicateArtifactDetected event");
+        }
+
+        Ok(Some(existing.hrn))
+    }
+
+    /// The upload raced the release of the blob it would reuse; once the
+    /// release finishes the same upload stores the blob anew
+    fn released_meanwhile(content_hash: &str) -> UploadArtifactError {
+        UploadArtifactError::Conflict(format!(
+            "Physical artifact {} is being released, retry the upload",
+            content_hash
+        ))
+    }
+}
+
+/// Releases one package version's reference to a deduplicated blob, deleting
+/// the blob once nothing references it anymore.
+///
+/// A record whose count reaches zero is a tombstone: uploads no longer reuse
+/// it nor store the blob again, so the blob is deleted first and the record
+/// after it. If the blob deletion fails the tombstone stays, and releasing
+/// the hash again finishes the deletion.
+pub struct ReleasePhysicalArtifactUseCase {
+    repository: Arc<dyn ArtifactRepository>,
+    storage: Arc<dyn ArtifactStorage>,
+}
+
+impl ReleasePhysicalArtifactUseCase {
+    pub fn new(repository: Arc<dyn ArtifactRepository>, storage: Arc<dyn ArtifactStorage>) -> Self {
+        Self {
+            repository,
+            storage,
+        }
+    }
+
+    /// Returns the number of references left on the blob
+    pub async fn execute(&self, content_hash: &str) -> PortResult<u64> {
+        tracing::info!(content_hash, "Releasing physical artifact reference");
+
+        let remaining = match self
+            .repository
+            .decrement_reference_count(content_hash)
+            .await
+        {
+            Ok(remaining) => remaining,
+            Err(UploadArtifactError::NotFound(_)) if self.is_tombstone(content_hash).await? => {
+                tracing::info!(
+                    content_hash,
+                    "Resuming deletion of released physical artifact"
+                );
+                0
+            }
+            Err(e) => return Err(e),
+        };
+        if remaining > 0 {
+            tracing::debug!(
+                remaining,
+                "Physical artifact still referenced, keeping blob"
+            );
+            return Ok(remaining);
+        }
+
+        self.storage.delete(content_hash).await.map_err(|e| {
+            tracing::error!("Storage delete error: {:?}", e);
+            e
+        })?;
+        if self
+            .repository
+            .delete_unreferenced_physical_artifact(content_hash)
+            .await?
+        {
+            tracing::debug!("Deleted unreferenced physical artifact");
+        }
+
+        Ok(0)
+    }
+
+    /// Whether an earlier release left the record at zero references
+    async fn is_tombstone(&self, content_hash: &str) -> PortResult<bool> {
+        Ok(self
+            .repository
+            .find_physical_artifact_by_hash(content_hash)
+            .await?
+            .is_some_and(|artifact| artifact.reference_count == 0))
+    }
 }
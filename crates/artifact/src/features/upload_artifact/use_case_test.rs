@@ -1,9 +1,12 @@
 use crate::domain::package_version::PackageCoordinates;
 use crate::features::upload_artifact::mocks::{
     MockArtifactRepository, MockArtifactStorage, MockArtifactValidator, MockEventPublisher,
-    MockVersionValidator,
+    MockRepositoryQuota, MockVersionValidator,
+};
+use crate::features::upload_artifact::{
+    use_case::{ReleasePhysicalArtifactUseCase, UploadArtifactUseCase},
+    UploadArtifactCommand, UploadArtifactError,
 };
-use crate::features::upload_artifact::{use_case::UploadArtifactUseCase, UploadArtifactCommand};
 use bytes::Bytes;
 use std::sync::Arc;
 
@@ -28,6 +31,7 @@ async fn test_upload_with_various_valid_version_formats() {
     let use_case = UploadArtifactUseCase::new(
         repo.clone(),
         storage.clone(),
+        Arc::new(MockRepositoryQuota::new()),
         publisher.clone(),
         validator.clone(),
         version_validator,
@@ -111,6 +115,7 @@ async fn test_upload_with_edge_case_versions() {
     let use_case = UploadArtifactUseCase::new(
         repo.clone(),
         storage.clone(),
+        Arc::new(MockRepositoryQuota::new()),
         publisher.clone(),
         validator.clone(),
         version_validator,
@@ -167,3 +172,250 @@ async fn test_upload_with_edge_case_versions() {
     let expected_events = edge_cases.len() * 2 - 1;
     assert_eq!(publisher.events.lock().unwrap().len(), expected_events);
 }
+
+fn build_use_case(
+    repo: Arc<MockArtifactRepository>,
+    storage: Arc<MockArtifactStorage>,
+) -> UploadArtifactUseCase {
+    build_use_case_with(
+        repo,
+        storage,
+        Arc::new(MockRepositoryQuota::new()),
+        Arc::new(MockEventPublisher::new()),
+    )
+}
+
+fn build_use_case_with(
+    repo: Arc<MockArtifactRepository>,
+    storage: Arc<MockArtifactStorage>,
+    quota: Arc<MockRepositoryQuota>,
+    publisher: Arc<MockEventPublisher>,
+) -> UploadArtifactUseCase {
+    use crate::features::content_type_detection::{
+        mocks::MockContentTypeDetector, ContentTypeDetectionUseCase,
+    };
+    let content_type_detector = Arc::new(MockContentTypeDetector::new());
+    let content_type_service = Arc::new(ContentTypeDetectionUseCase::new(content_type_detector));
+
+    UploadArtifactUseCase::new(
+        repo,
+        storage,
+        quota,
+        publisher,
+        Arc::new(MockArtifactValidator::new()),
+        Arc::new(MockVersionValidator::new()),
+        content_type_service,
+    )
+}
+
+fn command_for(name: &str) -> UploadArtifactCommand {
+    UploadArtifactCommand {
+        coordinates: PackageCoordinates {
+            namespace: Some("example".to_string()),
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            qualifiers: Default::default(),
+        },
+        file_name: "test.bin".to_string(),
+        content_length: 12,
+    }
+}
+
+fn sha256_hex(content: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(Sha256::digest(content))
+}
+
+#[tokio::test]
+async fn test_duplicate_upload_shares_blob_and_counts_references() {
+    let repo = Arc::new(MockArtifactRepository::new());
+    let storage = Arc::new(MockArtifactStorage::new());
+    let use_case = build_use_case(repo.clone(), storage.clone());
+    let content = Bytes::from_static(b"test content");
+
+    use_case
+        .execute(command_for("first"), content.clone())
+        .await
+        .unwrap();
+    use_case
+        .execute(command_for("second"), content.clone())
+        .await
+        .unwrap();
+
+    // The blob is stored once and referenced by both package versions
+    assert_eq!(storage.uploads.lock().unwrap().len(), 1);
+    let artifacts = repo.physical_artifacts.lock().unwrap();
+    assert_eq!(artifacts.len(), 1);
+    assert_eq!(artifacts[0].reference_count, 2);
+}
+
+#[tokio::test]
+async fn test_hash_collision_with_different_content_is_not_deduplicated() {
+    let repo = Arc::new(MockArtifactRepository::new());
+    let storage = Arc::new(MockArtifactStorage::new());
+    let use_case = build_use_case(repo.clone(), storage.clone());
+    let content = Bytes::from_static(b"test content");
+
+    use_case
+        .execute(command_for("original"), content.clone())
+        .await
+        .unwrap();
+    // Simulate a collision: the blob stored under the hash holds other bytes
+    storage.uploads.lock().unwrap()[0].1 = Bytes::from_static(b"other bytes!");
+
+    let result = use_case
+        .execute(command_for("colliding"), content.clone())
+        .await;
+
+    assert!(matches!(result, Err(UploadArtifactError::Conflict(_))));
+    assert_eq!(repo.count_package_versions().await, 1);
+    assert_eq!(
+        repo.physical_artifacts.lock().unwrap()[0].reference_count,
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_release_keeps_blob_until_last_reference_is_gone() {
+    let repo = Arc::new(MockArtifactRepository::new());
+    let storage = Arc::new(MockArtifactStorage::new());
+    let use_case = build_use_case(repo.clone(), storage.clone());
+    let release = ReleasePhysicalArtifactUseCase::new(repo.clone(), storage.clone());
+    let content = Bytes::from_static(b"test content");
+    let hash = sha256_hex(&content);
+
+    use_case
+        .execute(command_for("first"), content.clone())
+        .await
+        .unwrap();
+    use_case
+        .execute(command_for("second"), content.clone())
+        .await
+        .unwrap();
+
+    // Releasing one reference must not remove a blob the other still uses
+    assert_eq!(release.execute(&hash).await.unwrap(), 1);
+    assert_eq!(storage.uploads.lock().unwrap().len(), 1);
+    assert_eq!(repo.count_physical_artifacts().await, 1);
+
+    assert_eq!(release.execute(&hash).await.unwrap(), 0);
+    assert!(storage.uploads.lock().unwrap().is_empty());
+    assert_eq!(repo.count_physical_artifacts().await, 0);
+
+    // Nothing is left to release
+    assert!(matches!(
+        release.execute(&hash).await,
+        Err(UploadArtifactError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_upload_after_full_release_stores_blob_again() {
+    let repo = Arc::new(MockArtifactRepository::new());
+    let storage = Arc::new(MockArtifactStorage::new());
+    let use_case = build_use_case(repo.clone(), storage.clone());
+    let release = ReleasePhysicalArtifactUseCase::new(repo.clone(), storage.clone());
+    let content = Bytes::from_static(b"test content");
+
+    use_case
+        .execute(command_for("first"), content.clone())
+        .await
+        .unwrap();
+    release.execute(&sha256_hex(&content)).await.unwrap();
+
+    use_case
+        .execute(command_for("second"), content.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(storage.uploads.lock().unwrap().len(), 1);
+    let artifacts = repo.physical_artifacts.lock().unwrap();
+    assert_eq!(artifacts.len(), 1);
+    assert_eq!(artifacts[0].reference_count, 1);
+}
+
+#[tokio::test]
+async fn test_failed_blob_delete_leaves_a_tombstone_until_release_is_retried() {
+    let repo = Arc::new(MockArtifactRepository::new());
+    let storage = Arc::new(MockArtifactStorage::new());
+    let use_case = build_use_case(repo.clone(), storage.clone());
+    let release = ReleasePhysicalArtifactUseCase::new(repo.clone(), storage.clone());
+    let content = Bytes::from_static(b"test content");
+    let hash = sha256_hex(&content);
+
+    use_case
+        .execute(command_for("first"), content.clone())
+        .await
+        .unwrap();
+    *storage.should_fail_delete.lock().unwrap() = true;
+
+    // The blob goes before the record, so a failed delete keeps both
+    assert!(matches!(
+        release.execute(&hash).await,
+        Err(UploadArtifactError::StorageError(_))
+    ));
+    assert_eq!(storage.uploads.lock().unwrap().len(), 1);
+    assert_eq!(repo.count_physical_artifacts().await, 1);
+
+    // The record at zero references is not revived by an upload
+    let result = use_case
+        .execute(command_for("second"), content.clone())
+        .await;
+    assert!(matches!(result, Err(UploadArtifactError::Conflict(_))));
+
+    // Releasing again finishes the deletion
+    *storage.should_fail_delete.lock().unwrap() = false;
+    assert_eq!(release.execute(&hash).await.unwrap(), 0);
+    assert!(storage.uploads.lock().unwrap().is_empty());
+    assert_eq!(repo.count_physical_artifacts().await, 0);
+}
+
+#[tokio::test]
+async fn test_upload_over_quota_is_rejected_before_storage_write() {
+    let repo = Arc::new(MockArtifactRepository::new());
+    let storage = Arc::new(MockArtifactStorage::new());
+    // Cabe la primera subida de 12 bytes, no la segunda
+    let quota = Arc::new(MockRepositoryQuota::with_max_size_bytes(20));
+    let use_case = build_use_case_with(
+        repo.clone(),
+        storage.clone(),
+        quota.clone(),
+        Arc::new(MockEventPublisher::new()),
+    );
+
+    use_case
+        .execute(command_for("first"), Bytes::from_static(b"test content"))
+        .await
+        .unwrap();
+    let result = use_case
+        .execute(command_for("second"), Bytes::from_static(b"more content"))
+        .await;
+
+    assert!(matches!(result, Err(UploadArtifactError::QuotaExceeded(_))));
+    assert_eq!(storage.uploads.lock().unwrap().len(), 1);
+    assert_eq!(repo.count_package_versions().await, 1);
+    assert_eq!(quota.completed.lock().unwrap().len(), 1);
+    assert!(quota.in_flight.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_upload_releases_its_quota_reservation() {
+    let quota = Arc::new(MockRepositoryQuota::with_max_size_bytes(12));
+    let publisher = Arc::new(MockEventPublisher::new());
+    *publisher.should_fail_publish.lock().unwrap() = true;
+    let use_case = build_use_case_with(
+        Arc::new(MockArtifactRepository::new()),
+        Arc::new(MockArtifactStorage::new()),
+        quota.clone(),
+        publisher,
+    );
+
+    let result = use_case
+        .execute(command_for("first"), Bytes::from_static(b"test content"))
+        .await;
+
+    assert!(result.is_err());
+    assert!(quota.in_flight.lock().unwrap().is_empty());
+    assert!(quota.completed.lock().unwrap().is_empty());
+    assert_eq!(quota.cancelled.lock().unwrap().len(), 1);
+}
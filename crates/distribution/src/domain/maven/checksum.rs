@@ -0,0 +1,94 @@
+// crates/distribution/src/domain/maven/checksum.rs
+
+//! Checksums Maven - Ficheros `.sha1` y `.md5` que acompañan a cada artefacto
+
+use md5::Md5;
+use sha1::{Digest, Sha1};
+use std::fmt;
+
+/// Algoritmo de checksum soportado por el layout Maven
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    Sha1,
+    Md5,
+}
+
+impl ChecksumAlgorithm {
+    /// Todos los algoritmos que se generan al subir un artefacto
+    pub const ALL: [ChecksumAlgorithm; 2] = [ChecksumAlgorithm::Sha1, ChecksumAlgorithm::Md5];
+
+    /// Extensión del fichero de checksum, sin el punto
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Sha1 => "sha1",
+            ChecksumAlgorithm::Md5 => "md5",
+        }
+    }
+
+    /// Algoritmo correspondiente a una extensión (`sha1`, `md5`)
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "sha1" => Some(ChecksumAlgorithm::Sha1),
+            "md5" => Some(ChecksumAlgorithm::Md5),
+            _ => None,
+        }
+    }
+
+    /// Digest hexadecimal en minúsculas, el formato que escriben los clientes Maven
+    pub fn compute(&self, content: &[u8]) -> String {
+        match self {
+            ChecksumAlgorithm::Sha1 => hex::encode(Sha1::digest(content)),
+            ChecksumAlgorithm::Md5 => hex::encode(Md5::digest(content)),
+        }
+    }
+
+    /// Comprobar un checksum subido por un cliente contra el contenido
+    ///
+    /// Algunos clientes escriben `<digest>  <fichero>`, así que solo se compara
+    /// el primer token, sin distinguir mayúsculas.
+    pub fn matches(&self, checksum_file: &str, content: &[u8]) -> bool {
+        checksum_file
+            .split_whitespace()
+            .next()
+            .is_some_and(|digest| digest.eq_ignore_ascii_case(&self.compute(content)))
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_known_digests() {
+        assert_eq!(
+            ChecksumAlgorithm::Sha1.compute(b"abc"),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            ChecksumAlgorithm::Md5.compute(b"abc"),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+    }
+
+    #[test]
+    fn test_matches_ignores_case_and_filename() {
+        let upload = "A9993E364706816ABA3E25717850C26C9CD0D89D  my-app-1.0.0.jar\n";
+        assert!(ChecksumAlgorithm::Sha1.matches(upload, b"abc"));
+        assert!(!ChecksumAlgorithm::Sha1.matches(upload, b"abd"));
+        assert!(!ChecksumAlgorithm::Sha1.matches("", b"abc"));
+    }
+
+    #[test]
+    fn test_extension_round_trip() {
+        for algorithm in ChecksumAlgorithm::ALL {
+            assert_eq!(ChecksumAlgorithm::from_extension(algorithm.extension()), Some(algorithm));
+        }
+        assert_eq!(ChecksumAlgorithm::from_extension("sha256"), None);
+    }
+}
@@ -2,6 +2,7 @@
 
 //! Value Objects para coordenadas Maven - Dominio puro sin dependencias externas
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
@@ -18,10 +19,12 @@ pub enum MavenValidationError {
     InvalidClassifier(String),
     #[error("Invalid extension: {0}")]
     InvalidExtension(String),
+    #[error("Invalid repository path: {0}")]
+    InvalidPath(String),
 }
 
 /// Coordenadas Maven - Value Object inmutable
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct MavenCoordinates {
     pub group_id: String,
     pub artifact_id: String,
@@ -126,7 +129,7 @@ impl MavenCoordinates {
             }
             let classifier = parts[..parts.len() - 1].join(".");
             let extension = parts[parts.len() - 1];
-            (Some(classifier.as_str()), extension)
+            (Some(classifier), extension)
         } else if remaining.starts_with('.') {
             // Sin clasificador
             let parts: Vec<&str> = remaining[1..].split('.').collect();
@@ -138,9 +141,9 @@ impl MavenCoordinates {
             return Err(MavenValidationError::InvalidArtifactId("Invalid filename format".to_string()));
         };
         
-        let mut coordinates = Self::new(group_id, artifact_id, version)?;
+        let mut coordinates = Self::new(&group_id, artifact_id, version)?;
         if let Some(classifier) = classifier {
-            coordinates = coordinates.with_classifier(classifier)?;
+            coordinates = coordinates.with_classifier(&classifier)?;
         }
         coordinates = coordinates.with_extension(extension)?;
         
@@ -191,7 +194,6 @@ impl MavenCoordinates {
         
         Ok(())
     }
-    }
     
     pub fn validate_artifact_id(artifact_id: &str) -> Result<(), MavenValidationError> {
         if artifact_id.is_empty() {
@@ -260,6 +262,7 @@ impl MavenCoordinates {
         }
         
         Ok(())
+    }
 }
 
 impl fmt::Display for MavenCoordinates {
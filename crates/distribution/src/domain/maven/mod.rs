@@ -6,11 +6,19 @@
 //! específicas del ecosistema Maven. Es completamente síncrono y no tiene
 //! dependencias de infraestructura.
 
+pub mod checksum;
 pub mod coordinates;
 pub mod metadata;
+pub mod repository_path;
+pub mod snapshot;
 pub mod validation;
 
 // Re-exportar componentes del dominio
+pub use checksum::ChecksumAlgorithm;
 pub use coordinates::{MavenCoordinates, MavenValidationError};
 pub use metadata::{MavenMetadata, MavenVersion};
+pub use repository_path::{
+    MAVEN_METADATA_FILE, MavenArtifactFile, MavenRepositoryPath, MavenResource,
+};
+pub use snapshot::{SnapshotBuild, SnapshotFile, SnapshotMetadata};
 pub use validation::{validate_maven_coordinates, validate_maven_version};
\ No newline at end of file
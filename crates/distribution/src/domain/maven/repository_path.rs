@@ -0,0 +1,348 @@
+// crates/distribution/src/domain/maven/repository_path.rs
+
+//! Paths del layout de repositorio Maven
+//!
+//! Un path de repositorio apunta a un artefacto, a un `maven-metadata.xml`
+//! o al fichero de checksum (`.sha1`, `.md5`) de cualquiera de los dos:
+//!
+//! - `com/example/my-app/1.0.0/my-app-1.0.0.jar`
+//! - `com/example/my-app/1.0-SNAPSHOT/my-app-1.0-20240101.120000-3-sources.jar`
+//! - `com/example/my-app/maven-metadata.xml`
+//! - `com/example/my-app/1.0-SNAPSHOT/maven-metadata.xml.sha1`
+
+use crate::domain::maven::checksum::ChecksumAlgorithm;
+use crate::domain::maven::coordinates::{MavenCoordinates, MavenValidationError};
+use crate::domain::maven::snapshot::{SnapshotBuild, base_version};
+
+/// Nombre del fichero de metadata Maven
+pub const MAVEN_METADATA_FILE: &str = "maven-metadata.xml";
+
+/// Un fichero de artefacto concreto
+///
+/// `coordinates.version` es siempre la versión del directorio, por ejemplo
+/// `1.0-SNAPSHOT`. Un fichero SNAPSHOT con marca de tiempo lleva además su
+/// build; sin build, el fichero es el nombre no único (`my-app-1.0-SNAPSHOT.jar`)
+/// que los clientes piden para obtener el último build.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MavenArtifactFile {
+    pub coordinates: MavenCoordinates,
+    pub snapshot_build: Option<SnapshotBuild>,
+}
+
+impl MavenArtifactFile {
+    pub fn new(coordinates: MavenCoordinates) -> Self {
+        Self {
+            coordinates,
+            snapshot_build: None,
+        }
+    }
+
+    /// El mismo fichero en un build SNAPSHOT concreto
+    pub fn with_build(mut self, build: SnapshotBuild) -> Self {
+        self.snapshot_build = Some(build);
+        self
+    }
+
+    /// Un SNAPSHOT pedido por su nombre no único, pendiente de resolver
+    pub fn is_unresolved_snapshot(&self) -> bool {
+        self.coordinates.is_snapshot() && self.snapshot_build.is_none()
+    }
+
+    /// La versión que aparece en el nombre del fichero
+    pub fn file_version(&self) -> String {
+        match &self.snapshot_build {
+            Some(build) => build.file_version(&self.coordinates.version),
+            None => self.coordinates.version.clone(),
+        }
+    }
+
+    pub fn filename(&self) -> String {
+        let coordinates = &self.coordinates;
+        match &coordinates.classifier {
+            Some(classifier) => format!(
+                "{}-{}-{}.{}",
+                coordinates.artifact_id,
+                self.file_version(),
+                classifier,
+                coordinates.extension
+            ),
+            None => format!(
+                "{}-{}.{}",
+                coordinates.artifact_id,
+                self.file_version(),
+                coordinates.extension
+            ),
+        }
+    }
+
+    /// Path dentro del repositorio, en el directorio de la versión
+    pub fn to_path(&self) -> String {
+        format!("{}/{}", version_directory(&self.coordinates), self.filename())
+    }
+}
+
+/// El recurso al que apunta un path, sin contar el checksum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MavenResource {
+    Artifact(MavenArtifactFile),
+    /// `maven-metadata.xml` de un artefacto (`version: None`) o de una
+    /// versión SNAPSHOT
+    Metadata {
+        group_id: String,
+        artifact_id: String,
+        version: Option<String>,
+    },
+}
+
+impl MavenResource {
+    pub fn to_path(&self) -> String {
+        match self {
+            MavenResource::Artifact(file) => file.to_path(),
+            MavenResource::Metadata {
+                group_id,
+                artifact_id,
+                version,
+            } => {
+                let mut path = format!("{}/{}", group_id.replace('.', "/"), artifact_id);
+                if let Some(version) = version {
+                    path.push('/');
+                    path.push_str(version);
+                }
+                format!("{}/{}", path, MAVEN_METADATA_FILE)
+            }
+        }
+    }
+}
+
+/// Un path de repositorio ya interpretado
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MavenRepositoryPath {
+    pub resource: MavenResource,
+    /// Presente si el path pide el checksum del recurso y no el recurso
+    pub checksum: Option<ChecksumAlgorithm>,
+}
+
+impl MavenRepositoryPath {
+    /// Interpretar un path del layout Maven
+    pub fn parse(path: &str) -> Result<Self, MavenValidationError> {
+        let path = path.trim_start_matches('/');
+
+        let (path, checksum) = match path.rsplit_once('.') {
+            Some((inner, extension)) => match ChecksumAlgorithm::from_extension(extension) {
+                Some(algorithm) => (inner, Some(algorithm)),
+                None => (path, None),
+            },
+            None => (path, None),
+        };
+
+        let segments: Vec<&str> = path.split('/').collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(MavenValidationError::InvalidPath(path.to_string()));
+        }
+
+        let resource = if segments.last() == Some(&MAVEN_METADATA_FILE) {
+            Self::parse_metadata(&segments[..segments.len() - 1])?
+        } else {
+            MavenResource::Artifact(Self::parse_artifact(&segments)?)
+        };
+
+        Ok(Self { resource, checksum })
+    }
+
+    /// Path del fichero que se pide, checksum incluido
+    pub fn to_path(&self) -> String {
+        match self.checksum {
+            Some(algorithm) => format!("{}.{}", self.resource.to_path(), algorithm.extension()),
+            None => self.resource.to_path(),
+        }
+    }
+
+    /// `g/a` o, si el último directorio es una versión SNAPSHOT, `g/a/v`
+    ///
+    /// Solo las versiones SNAPSHOT tienen metadata propia, así que un
+    /// directorio terminado en `-SNAPSHOT` no puede ser un artifactId.
+    fn parse_metadata(directories: &[&str]) -> Result<MavenResource, MavenValidationError> {
+        let (directories, version) = match directories.split_last() {
+            Some((last, rest)) if last.ends_with("-SNAPSHOT") => (rest, Some(last.to_string())),
+            _ => (directories, None),
+        };
+        let Some((artifact_id, group)) = directories.split_last() else {
+            return Err(MavenValidationError::InvalidPath(MAVEN_METADATA_FILE.to_string()));
+        };
+        if group.is_empty() {
+            return Err(MavenValidationError::InvalidPath(format!(
+                "{}/{}",
+                artifact_id, MAVEN_METADATA_FILE
+            )));
+        }
+
+        let group_id = group.join(".");
+        MavenCoordinates::validate_group_id(&group_id)?;
+        MavenCoordinates::validate_artifact_id(artifact_id)?;
+        if let Some(version) = &version {
+            MavenCoordinates::validate_version(version)?;
+        }
+
+        Ok(MavenResource::Metadata {
+            group_id,
+            artifact_id: artifact_id.to_string(),
+            version,
+        })
+    }
+
+    fn parse_artifact(segments: &[&str]) -> Result<MavenArtifactFile, MavenValidationError> {
+        let path = segments.join("/");
+        if segments.len() < 4 {
+            return Err(MavenValidationError::InvalidPath(path));
+        }
+        let n = segments.len();
+        let group_id = segments[..n - 3].join(".");
+        let (artifact_id, version, filename) = (segments[n - 3], segments[n - 2], segments[n - 1]);
+
+        let rest = filename
+            .strip_prefix(artifact_id)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .ok_or_else(|| MavenValidationError::InvalidPath(path.clone()))?;
+
+        // Versión del directorio tal cual, o build SNAPSHOT con marca de tiempo
+        let (snapshot_build, rest) = if let Some(rest) = rest.strip_prefix(version) {
+            (None, rest)
+        } else if version.ends_with("-SNAPSHOT") {
+            let after_base = rest
+                .strip_prefix(base_version(version))
+                .and_then(|rest| rest.strip_prefix('-'))
+                .ok_or_else(|| MavenValidationError::InvalidPath(path.clone()))?;
+            let (build, rest) = SnapshotBuild::parse_prefix(after_base)
+                .ok_or_else(|| MavenValidationError::InvalidPath(path.clone()))?;
+            (Some(build), rest)
+        } else {
+            return Err(MavenValidationError::InvalidPath(path));
+        };
+
+        // `-clasificador.extensión` o `.extensión`
+        let (classifier, extension) = match rest.split_once('.') {
+            Some(("", extension)) => (None, extension),
+            Some((classifier, extension)) => match classifier.strip_prefix('-') {
+                Some(classifier) => (Some(classifier), extension),
+                None => return Err(MavenValidationError::InvalidPath(path)),
+            },
+            None => return Err(MavenValidationError::InvalidPath(path)),
+        };
+
+        let mut coordinates =
+            MavenCoordinates::new(&group_id, artifact_id, version)?.with_extension(extension)?;
+        if let Some(classifier) = classifier {
+            coordinates = coordinates.with_classifier(classifier)?;
+        }
+
+        Ok(MavenArtifactFile {
+            coordinates,
+            snapshot_build,
+        })
+    }
+}
+
+/// `com/example/my-app/1.0.0`
+fn version_directory(coordinates: &MavenCoordinates) -> String {
+    format!(
+        "{}/{}/{}",
+        coordinates.group_id.replace('.', "/"),
+        coordinates.artifact_id,
+        coordinates.version
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn artifact(path: &str) -> MavenArtifactFile {
+        match MavenRepositoryPath::parse(path).unwrap().resource {
+            MavenResource::Artifact(file) => file,
+            other => panic!("expected an artifact, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_release_artifact() {
+        let file = artifact("com/example/my-app/1.0.0/my-app-1.0.0.jar");
+        assert_eq!(file.coordinates.group_id, "com.example");
+        assert_eq!(file.coordinates.artifact_id, "my-app");
+        assert_eq!(file.coordinates.version, "1.0.0");
+        assert_eq!(file.coordinates.extension, "jar");
+        assert_eq!(file.snapshot_build, None);
+    }
+
+    #[test]
+    fn test_parse_classifier() {
+        let file = artifact("/com/example/my-app/1.0.0/my-app-1.0.0-sources.jar");
+        assert_eq!(file.coordinates.classifier.as_deref(), Some("sources"));
+        assert_eq!(file.to_path(), "com/example/my-app/1.0.0/my-app-1.0.0-sources.jar");
+    }
+
+    #[test]
+    fn test_parse_timestamped_snapshot() {
+        let path = "com/example/my-app/1.0-SNAPSHOT/my-app-1.0-20240101.120000-3-tests.jar";
+        let file = artifact(path);
+        assert_eq!(file.coordinates.version, "1.0-SNAPSHOT");
+        assert_eq!(file.coordinates.classifier.as_deref(), Some("tests"));
+        let build = file.snapshot_build.unwrap();
+        assert_eq!(build.timestamp, datetime!(2024-01-01 12:00:00 UTC));
+        assert_eq!(build.build_number, 3);
+        assert_eq!(file.to_path(), path);
+    }
+
+    #[test]
+    fn test_parse_non_unique_snapshot() {
+        let file = artifact("com/example/my-app/1.0-SNAPSHOT/my-app-1.0-SNAPSHOT.pom");
+        assert!(file.is_unresolved_snapshot());
+        assert_eq!(file.coordinates.extension, "pom");
+    }
+
+    #[test]
+    fn test_parse_checksums() {
+        let parsed =
+            MavenRepositoryPath::parse("com/example/my-app/1.0.0/my-app-1.0.0.jar.sha1").unwrap();
+        assert_eq!(parsed.checksum, Some(ChecksumAlgorithm::Sha1));
+        assert!(matches!(parsed.resource, MavenResource::Artifact(_)));
+
+        let parsed =
+            MavenRepositoryPath::parse("com/example/my-app/maven-metadata.xml.md5").unwrap();
+        assert_eq!(parsed.checksum, Some(ChecksumAlgorithm::Md5));
+        assert_eq!(parsed.to_path(), "com/example/my-app/maven-metadata.xml.md5");
+    }
+
+    #[test]
+    fn test_parse_metadata_levels() {
+        let parsed = MavenRepositoryPath::parse("com/example/my-app/maven-metadata.xml").unwrap();
+        assert_eq!(
+            parsed.resource,
+            MavenResource::Metadata {
+                group_id: "com.example".to_string(),
+                artifact_id: "my-app".to_string(),
+                version: None,
+            }
+        );
+
+        let parsed =
+            MavenRepositoryPath::parse("com/example/my-app/1.0-SNAPSHOT/maven-metadata.xml")
+                .unwrap();
+        assert_eq!(
+            parsed.resource,
+            MavenResource::Metadata {
+                group_id: "com.example".to_string(),
+                artifact_id: "my-app".to_string(),
+                version: Some("1.0-SNAPSHOT".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rejects_mismatched_filename() {
+        assert!(MavenRepositoryPath::parse("com/example/my-app/1.0.0/other-1.0.0.jar").is_err());
+        assert!(MavenRepositoryPath::parse("com/example/my-app/1.0.0/my-app-2.0.0.jar").is_err());
+        assert!(MavenRepositoryPath::parse("my-app/1.0.0/my-app-1.0.0.jar").is_err());
+        assert!(MavenRepositoryPath::parse("my-app/maven-metadata.xml").is_err());
+    }
+}
@@ -0,0 +1,257 @@
+// crates/distribution/src/domain/maven/snapshot.rs
+
+//! Builds SNAPSHOT con marca de tiempo
+//!
+//! Maven 3 nunca publica `my-app-1.0-SNAPSHOT.jar` tal cual: cada despliegue
+//! sube `my-app-1.0-20240101.120000-3.jar`, donde `20240101.120000` es la
+//! marca de tiempo UTC del despliegue y `3` el número de build. El
+//! `maven-metadata.xml` del directorio de la versión dice qué build es el
+//! último para cada clasificador y extensión, y así los clientes resuelven
+//! `1.0-SNAPSHOT` al fichero concreto.
+
+use std::collections::BTreeMap;
+use time::macros::format_description;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+/// Sufijo de las versiones SNAPSHOT
+pub const SNAPSHOT_SUFFIX: &str = "-SNAPSHOT";
+
+/// Un build concreto de una versión SNAPSHOT
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SnapshotBuild {
+    /// Marca de tiempo UTC del despliegue, con precisión de segundos
+    pub timestamp: OffsetDateTime,
+    pub build_number: u32,
+}
+
+impl SnapshotBuild {
+    pub fn new(timestamp: OffsetDateTime, build_number: u32) -> Self {
+        Self {
+            timestamp: timestamp.replace_nanosecond(0).unwrap_or(timestamp),
+            build_number,
+        }
+    }
+
+    /// El build que sigue a `previous`, desplegado en `now`
+    pub fn next(previous: Option<&SnapshotBuild>, now: OffsetDateTime) -> Self {
+        Self::new(now, previous.map_or(1, |build| build.build_number + 1))
+    }
+
+    /// `20240101.120000`, tal como aparece en nombres de fichero y en `<timestamp>`
+    pub fn timestamp_string(&self) -> String {
+        let format = format_description!("[year][month][day].[hour][minute][second]");
+        self.timestamp.format(&format).unwrap_or_default()
+    }
+
+    /// `20240101120000`, el formato de `<updated>` y `<lastUpdated>`
+    pub fn updated_string(&self) -> String {
+        let format = format_description!("[year][month][day][hour][minute][second]");
+        self.timestamp.format(&format).unwrap_or_default()
+    }
+
+    /// La versión con la que se nombran los ficheros de este build
+    ///
+    /// `1.0-SNAPSHOT` pasa a ser `1.0-20240101.120000-3`.
+    pub fn file_version(&self, snapshot_version: &str) -> String {
+        format!(
+            "{}-{}-{}",
+            base_version(snapshot_version),
+            self.timestamp_string(),
+            self.build_number
+        )
+    }
+
+    /// Reconocer un build al principio de `rest`, que sigue a la versión base
+    /// en un nombre de fichero
+    ///
+    /// Para `1.0-SNAPSHOT` y el fichero `my-app-1.0-20240101.120000-3-sources.jar`,
+    /// `rest` es `20240101.120000-3-sources.jar`; devuelve el build y lo que
+    /// queda detrás (`-sources.jar`).
+    pub fn parse_prefix(rest: &str) -> Option<(SnapshotBuild, &str)> {
+        let timestamp = rest.get(..15)?;
+        let format = format_description!("[year][month][day].[hour][minute][second]");
+        let timestamp = PrimitiveDateTime::parse(timestamp, &format).ok()?.assume_utc();
+
+        let after_timestamp = rest[15..].strip_prefix('-')?;
+        let digits = after_timestamp
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after_timestamp.len());
+        let build_number = after_timestamp[..digits].parse().ok()?;
+
+        Some((Self::new(timestamp, build_number), &after_timestamp[digits..]))
+    }
+}
+
+/// `1.0-SNAPSHOT` sin el sufijo; las versiones release se devuelven tal cual
+pub fn base_version(version: &str) -> &str {
+    version.strip_suffix(SNAPSHOT_SUFFIX).unwrap_or(version)
+}
+
+/// Un fichero desplegado dentro de una versión SNAPSHOT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotFile {
+    pub classifier: Option<String>,
+    pub extension: String,
+    pub build: SnapshotBuild,
+}
+
+/// El `maven-metadata.xml` del directorio de una versión SNAPSHOT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotMetadata {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    /// El último build de cada par (clasificador, extensión)
+    pub files: BTreeMap<(Option<String>, String), SnapshotBuild>,
+}
+
+impl SnapshotMetadata {
+    pub fn new(group_id: String, artifact_id: String, version: String) -> Self {
+        Self {
+            group_id,
+            artifact_id,
+            version,
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Registrar un fichero; solo cuenta si es más nuevo que el ya conocido
+    pub fn add_file(&mut self, file: SnapshotFile) {
+        let latest = self
+            .files
+            .entry((file.classifier, file.extension))
+            .or_insert(file.build);
+        if file.build > *latest {
+            *latest = file.build;
+        }
+    }
+
+    /// El último build de cualquier fichero
+    pub fn latest_build(&self) -> Option<SnapshotBuild> {
+        self.files.values().max().copied()
+    }
+
+    /// El último build del fichero con ese clasificador y extensión
+    pub fn latest_build_for(
+        &self,
+        classifier: Option<&str>,
+        extension: &str,
+    ) -> Option<SnapshotBuild> {
+        self.files
+            .get(&(classifier.map(str::to_string), extension.to_string()))
+            .copied()
+    }
+
+    /// Generar el XML, con `<snapshot>` y `<snapshotVersions>` (modelo 1.1.0)
+    pub fn to_xml(&self) -> String {
+        let snapshot = self
+            .latest_build()
+            .map(|build| {
+                format!(
+                    "    <snapshot>\n      <timestamp>{}</timestamp>\n      <buildNumber>{}</buildNumber>\n    </snapshot>\n    <lastUpdated>{}</lastUpdated>\n",
+                    build.timestamp_string(),
+                    build.build_number,
+                    build.updated_string()
+                )
+            })
+            .unwrap_or_default();
+
+        let snapshot_versions = self
+            .files
+            .iter()
+            .map(|((classifier, extension), build)| {
+                let classifier = classifier
+                    .as_ref()
+                    .map(|c| format!("\n        <classifier>{}</classifier>", c))
+                    .unwrap_or_default();
+                format!(
+                    "      <snapshotVersion>{}\n        <extension>{}</extension>\n        <value>{}</value>\n        <updated>{}</updated>\n      </snapshotVersion>",
+                    classifier,
+                    extension,
+                    build.file_version(&self.version),
+                    build.updated_string()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata modelVersion="1.1.0">
+  <groupId>{}</groupId>
+  <artifactId>{}</artifactId>
+  <version>{}</version>
+  <versioning>
+{}    <snapshotVersions>
+{}
+    </snapshotVersions>
+  </versioning>
+</metadata>"#,
+            self.group_id, self.artifact_id, self.version, snapshot, snapshot_versions
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    fn build(number: u32) -> SnapshotBuild {
+        SnapshotBuild::new(datetime!(2024-01-01 12:00:00 UTC), number)
+    }
+
+    #[test]
+    fn test_file_version() {
+        assert_eq!(build(3).file_version("1.0-SNAPSHOT"), "1.0-20240101.120000-3");
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        let (parsed, rest) = SnapshotBuild::parse_prefix("20240101.120000-3-sources.jar").unwrap();
+        assert_eq!(parsed, build(3));
+        assert_eq!(rest, "-sources.jar");
+
+        assert!(SnapshotBuild::parse_prefix("SNAPSHOT.jar").is_none());
+        assert!(SnapshotBuild::parse_prefix("20240101.120000.jar").is_none());
+    }
+
+    #[test]
+    fn test_next_build_numbers() {
+        let now = datetime!(2024-01-02 08:30:00 UTC);
+        assert_eq!(SnapshotBuild::next(None, now).build_number, 1);
+        assert_eq!(SnapshotBuild::next(Some(&build(3)), now).build_number, 4);
+    }
+
+    #[test]
+    fn test_metadata_keeps_latest_build_per_file() {
+        let mut metadata = SnapshotMetadata::new(
+            "com.example".to_string(),
+            "my-app".to_string(),
+            "1.0-SNAPSHOT".to_string(),
+        );
+        for number in [1, 3, 2] {
+            metadata.add_file(SnapshotFile {
+                classifier: None,
+                extension: "jar".to_string(),
+                build: build(number),
+            });
+        }
+        metadata.add_file(SnapshotFile {
+            classifier: Some("sources".to_string()),
+            extension: "jar".to_string(),
+            build: build(2),
+        });
+
+        assert_eq!(metadata.latest_build(), Some(build(3)));
+        assert_eq!(metadata.latest_build_for(Some("sources"), "jar"), Some(build(2)));
+
+        let xml = metadata.to_xml();
+        assert!(xml.contains("<version>1.0-SNAPSHOT</version>"));
+        assert!(xml.contains("<timestamp>20240101.120000</timestamp>"));
+        assert!(xml.contains("<buildNumber>3</buildNumber>"));
+        assert!(xml.contains("<value>1.0-20240101.120000-3</value>"));
+        assert!(xml.contains("<classifier>sources</classifier>"));
+        assert!(xml.contains("<value>1.0-20240101.120000-2</value>"));
+    }
+}
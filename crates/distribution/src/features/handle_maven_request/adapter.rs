@@ -1,3 +1,723 @@
 // crates/distribution/src/features/handle_maven_request/adapter.rs
 
-// Placeholder for the adapter implementation
\ No newline at end of file
+//! Adaptadores de infraestructura para el feature Handle Maven Request
+//!
+//! Implementaciones concretas de los puertos definidos en este feature:
+//! artefactos y checksums en S3 con el layout Maven, índice de versiones y
+//! builds SNAPSHOT en MongoDB y permisos con Cedar.
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+use time::OffsetDateTime;
+use tracing::{info, warn, error, debug, instrument};
+use crate::domain::maven::checksum::ChecksumAlgorithm;
+use crate::domain::maven::coordinates::MavenCoordinates;
+use crate::domain::maven::metadata::MavenVersion;
+use crate::domain::maven::repository_path::{MavenArtifactFile, MavenResource};
+use crate::domain::maven::snapshot::{SnapshotBuild, SnapshotFile};
+use super::ports::{
+    MavenArtifactReader, MavenArtifactWriter, MavenMetadataStore, MavenRepositoryManager,
+    MavenPermissionChecker, MavenReadError, MavenWriteError, ArtifactMetadata, RepositoryInfo,
+};
+
+/// Colección con las versiones desplegadas de cada artefacto
+const VERSIONS_COLLECTION: &str = "maven_versions";
+/// Colección con los ficheros de cada build SNAPSHOT
+const SNAPSHOT_FILES_COLLECTION: &str = "maven_snapshot_files";
+/// Colección de repositorios
+const REPOSITORIES_COLLECTION: &str = "repositories";
+
+/// Clave S3 de un fichero del repositorio: `{base_path}/maven/{repository_id}/{path}`
+fn object_key(base_path: &str, repository_id: &str, path: &str) -> String {
+    format!("{}/maven/{}/{}", base_path, repository_id, path)
+}
+
+/// Clave S3 del checksum de un artefacto, junto a él
+fn checksum_key(base_path: &str, repository_id: &str, file: &MavenArtifactFile, algorithm: ChecksumAlgorithm) -> String {
+    format!("{}.{}", object_key(base_path, repository_id, &file.to_path()), algorithm.extension())
+}
+
+/// Content type de un artefacto según su extensión
+fn content_type_for(extension: &str) -> &'static str {
+    match extension {
+        "jar" | "war" | "ear" => "application/java-archive",
+        "pom" | "xml" => "application/xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Adaptador de producción para leer artefactos Maven desde S3
+pub struct S3MavenArtifactReader {
+    s3_client: Arc<dyn S3Client>,
+    bucket_name: String,
+    base_path: String,
+}
+
+impl S3MavenArtifactReader {
+    pub fn new(s3_client: Arc<dyn S3Client>, bucket_name: String, base_path: String) -> Self {
+        Self {
+            s3_client,
+            bucket_name,
+            base_path,
+        }
+    }
+
+    fn artifact_key(&self, file: &MavenArtifactFile, repository_id: &str) -> String {
+        object_key(&self.base_path, repository_id, &file.to_path())
+    }
+
+    async fn get_object(&self, key: &str) -> Result<S3ObjectData, MavenReadError> {
+        match self.s3_client.get_object(&self.bucket_name, key).await {
+            Ok(object_data) => Ok(object_data),
+            Err(S3Error::NotFound) => {
+                warn!(key = %key, "Maven object not found in S3");
+                Err(MavenReadError::NotFound(key.to_string()))
+            }
+            Err(S3Error::PermissionDenied) => {
+                error!(key = %key, "Permission denied reading Maven object from S3");
+                Err(MavenReadError::PermissionDenied(key.to_string()))
+            }
+            Err(e) => {
+                error!(key = %key, error = %e, "Error reading Maven object from S3");
+                Err(MavenReadError::StorageError(format!("S3 error: {}", e)))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl MavenArtifactReader for S3MavenArtifactReader {
+    #[instrument(
+        name = "s3.maven.read_artifact",
+        skip(self, file),
+        fields(
+            artifact.path = %file.to_path(),
+            repository.id = %repository_id,
+            bucket = %self.bucket_name
+        )
+    )]
+    async fn read_artifact(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<Vec<u8>, MavenReadError> {
+        let key = self.artifact_key(file, repository_id);
+        let object_data = self.get_object(&key).await?;
+
+        info!(
+            artifact_path = %file.to_path(),
+            content_length = object_data.content.len(),
+            "Successfully read Maven artifact from S3"
+        );
+
+        Ok(object_data.content)
+    }
+
+    #[instrument(
+        name = "s3.maven.read_artifact_metadata",
+        skip(self, file),
+        fields(
+            artifact.path = %file.to_path(),
+            repository.id = %repository_id,
+            bucket = %self.bucket_name
+        )
+    )]
+    async fn read_artifact_metadata(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<ArtifactMetadata, MavenReadError> {
+        let key = self.artifact_key(file, repository_id);
+        let object_data = self.get_object(&key).await?;
+
+        Ok(ArtifactMetadata {
+            content_length: object_data.content.len(),
+            content_type: object_data.content_type,
+            last_modified: object_data
+                .last_modified
+                .and_then(|t| t.format(&Rfc2822).ok())
+                .unwrap_or_default(),
+            etag: object_data.etag.unwrap_or_default(),
+        })
+    }
+
+    #[instrument(
+        name = "s3.maven.artifact_exists",
+        skip(self, file),
+        fields(
+            artifact.path = %file.to_path(),
+            repository.id = %repository_id,
+            bucket = %self.bucket_name
+        )
+    )]
+    async fn artifact_exists(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<bool, MavenReadError> {
+        let key = self.artifact_key(file, repository_id);
+        self.s3_client.object_exists(&self.bucket_name, &key).await
+            .map_err(|e| {
+                error!(key = %key, error = %e, "Error checking Maven artifact existence in S3");
+                MavenReadError::StorageError(format!("S3 error: {}", e))
+            })
+    }
+
+    #[instrument(
+        name = "s3.maven.read_checksum",
+        skip(self, file),
+        fields(
+            artifact.path = %file.to_path(),
+            algorithm = %algorithm,
+            repository.id = %repository_id
+        )
+    )]
+    async fn read_checksum(&self, file: &MavenArtifactFile, algorithm: ChecksumAlgorithm, repository_id: &str) -> Result<String, MavenReadError> {
+        let key = checksum_key(&self.base_path, repository_id, file, algorithm);
+        let object_data = self.get_object(&key).await?;
+
+        String::from_utf8(object_data.content)
+            .map(|checksum| checksum.trim().to_string())
+            .map_err(|_| MavenReadError::StorageError(format!("Checksum is not valid UTF-8: {}", key)))
+    }
+}
+
+/// Adaptador de producción para escribir artefactos Maven en S3
+pub struct S3MavenArtifactWriter {
+    s3_client: Arc<dyn S3Client>,
+    bucket_name: String,
+    base_path: String,
+}
+
+impl S3MavenArtifactWriter {
+    pub fn new(s3_client: Arc<dyn S3Client>, bucket_name: String, base_path: String) -> Self {
+        Self {
+            s3_client,
+            bucket_name,
+            base_path,
+        }
+    }
+
+    async fn put_object(&self, key: &str, content: &[u8], content_type: &str) -> Result<(), MavenWriteError> {
+        self.s3_client.put_object(&self.bucket_name, key, content, content_type).await
+            .map_err(|e| match e {
+                S3Error::PermissionDenied => {
+                    error!(key = %key, "Permission denied writing Maven object to S3");
+                    MavenWriteError::PermissionDenied(key.to_string())
+                }
+                e => {
+                    error!(key = %key, error = %e, "Error writing Maven object to S3");
+                    MavenWriteError::StorageError(format!("S3 error: {}", e))
+                }
+            })
+    }
+}
+
+#[async_trait]
+impl MavenArtifactWriter for S3MavenArtifactWriter {
+    #[instrument(
+        name = "s3.maven.write_artifact",
+        skip(self, file, content),
+        fields(
+            artifact.path = %file.to_path(),
+            repository.id = %repository_id,
+            content_length = content.len(),
+            bucket = %self.bucket_name
+        )
+    )]
+    async fn write_artifact(&self, file: &MavenArtifactFile, content: &[u8], repository_id: &str, overwrite: bool) -> Result<(), MavenWriteError> {
+        let key = object_key(&self.base_path, repository_id, &file.to_path());
+
+        // Las releases son inmutables salvo que se pida sobrescribir
+        if !overwrite && file.coordinates.is_release() {
+            let exists = self.s3_client.object_exists(&self.bucket_name, &key).await
+                .map_err(|e| MavenWriteError::StorageError(format!("S3 error: {}", e)))?;
+            if exists {
+                warn!(key = %key, "Refusing to overwrite Maven release");
+                return Err(MavenWriteError::OverwriteNotAllowed(file.to_path()));
+            }
+        }
+
+        self.put_object(&key, content, content_type_for(&file.coordinates.extension)).await?;
+
+        info!(
+            artifact_path = %file.to_path(),
+            size_bytes = content.len(),
+            "Successfully wrote Maven artifact to S3"
+        );
+
+        Ok(())
+    }
+
+    #[instrument(
+        name = "s3.maven.write_artifact_metadata",
+        skip(self, file, metadata),
+        fields(
+            artifact.path = %file.to_path(),
+            repository.id = %repository_id
+        )
+    )]
+    async fn write_artifact_metadata(&self, file: &MavenArtifactFile, metadata: &ArtifactMetadata, repository_id: &str) -> Result<(), MavenWriteError> {
+        // S3 guarda los headers con el objeto; no hay nada más que escribir
+        debug!(
+            artifact_path = %file.to_path(),
+            content_type = %metadata.content_type,
+            "Maven artifact metadata is stored with the S3 object"
+        );
+        Ok(())
+    }
+
+    #[instrument(
+        name = "s3.maven.write_checksum",
+        skip(self, file, checksum),
+        fields(
+            artifact.path = %file.to_path(),
+            algorithm = %algorithm,
+            repository.id = %repository_id
+        )
+    )]
+    async fn write_checksum(&self, file: &MavenArtifactFile, algorithm: ChecksumAlgorithm, checksum: &str, repository_id: &str) -> Result<(), MavenWriteError> {
+        let key = checksum_key(&self.base_path, repository_id, file, algorithm);
+        self.put_object(&key, checksum.as_bytes(), "text/plain").await
+    }
+}
+
+/// Adaptador de producción para el índice de versiones Maven en MongoDB
+pub struct MongoMavenMetadataStore {
+    mongo_client: Arc<dyn MongoClient>,
+    database_name: String,
+}
+
+impl MongoMavenMetadataStore {
+    pub fn new(mongo_client: Arc<dyn MongoClient>, database_name: String) -> Self {
+        Self {
+            mongo_client,
+            database_name,
+        }
+    }
+
+    fn version_from_document(document: &Value) -> Option<MavenVersion> {
+        let version = document.get("version")?.as_str()?.to_string();
+        let last_updated = document
+            .get("last_updated")
+            .and_then(Value::as_str)
+            .and_then(|t| OffsetDateTime::parse(t, &Rfc3339).ok());
+        Some(match last_updated {
+            Some(timestamp) => MavenVersion::new(version).with_timestamp(timestamp),
+            None => MavenVersion::new(version),
+        })
+    }
+
+    fn snapshot_file_from_document(document: &Value) -> Option<SnapshotFile> {
+        let timestamp = document.get("timestamp")?.as_str()?;
+        let build_number = document.get("build_number")?.as_u64()?;
+        let (build, _) = SnapshotBuild::parse_prefix(&format!("{}-{}", timestamp, build_number))?;
+        Some(SnapshotFile {
+            classifier: document.get("classifier").and_then(Value::as_str).map(str::to_string),
+            extension: document.get("extension")?.as_str()?.to_string(),
+            build,
+        })
+    }
+}
+
+#[async_trait]
+impl MavenMetadataStore for MongoMavenMetadataStore {
+    #[instrument(
+        name = "mongo.maven.record_version",
+        skip(self, coordinates),
+        fields(
+            repository.id = %repository_id,
+            coordinates = %coordinates
+        )
+    )]
+    async fn record_version(&self, repository_id: &str, coordinates: &MavenCoordinates) -> Result<(), MavenWriteError> {
+        let filter = json!({
+            "repository_id": repository_id,
+            "group_id": coordinates.group_id,
+            "artifact_id": coordinates.artifact_id,
+            "version": coordinates.version,
+        });
+        let mut document = filter.clone();
+        document["last_updated"] = json!(OffsetDateTime::now_utc().format(&Rfc3339).unwrap_or_default());
+
+        self.mongo_client.upsert(&self.database_name, VERSIONS_COLLECTION, filter, document).await
+            .map_err(|e| {
+                error!(error = %e, "Error recording Maven version in MongoDB");
+                MavenWriteError::StorageError(format!("MongoDB error: {}", e))
+            })
+    }
+
+    #[instrument(
+        name = "mongo.maven.list_versions",
+        skip(self),
+        fields(
+            repository.id = %repository_id
+        )
+    )]
+    async fn list_versions(&self, repository_id: &str, group_id: &str, artifact_id: &str) -> Result<Vec<MavenVersion>, MavenReadError> {
+        let filter = json!({
+            "repository_id": repository_id,
+            "group_id": group_id,
+            "artifact_id": artifact_id,
+        });
+
+        let documents = self.mongo_client.find(&self.database_name, VERSIONS_COLLECTION, filter).await
+            .map_err(|e| MavenReadError::StorageError(format!("MongoDB error: {}", e)))?;
+
+        Ok(documents.iter().filter_map(Self::version_from_document).collect())
+    }
+
+    #[instrument(
+        name = "mongo.maven.record_snapshot_file",
+        skip(self, coordinates, file),
+        fields(
+            repository.id = %repository_id,
+            coordinates = %coordinates,
+            build = %file.build.file_version(&coordinates.version)
+        )
+    )]
+    async fn record_snapshot_file(&self, repository_id: &str, coordinates: &MavenCoordinates, file: &SnapshotFile) -> Result<(), MavenWriteError> {
+        let filter = json!({
+            "repository_id": repository_id,
+            "group_id": coordinates.group_id,
+            "artifact_id": coordinates.artifact_id,
+            "version": coordinates.version,
+            "classifier": file.classifier,
+            "extension": file.extension,
+            "timestamp": file.build.timestamp_string(),
+            "build_number": file.build.build_number,
+        });
+
+        self.mongo_client.upsert(&self.database_name, SNAPSHOT_FILES_COLLECTION, filter.clone(), filter).await
+            .map_err(|e| {
+                error!(error = %e, "Error recording Maven SNAPSHOT file in MongoDB");
+                MavenWriteError::StorageError(format!("MongoDB error: {}", e))
+            })
+    }
+
+    #[instrument(
+        name = "mongo.maven.list_snapshot_files",
+        skip(self),
+        fields(
+            repository.id = %repository_id
+        )
+    )]
+    async fn list_snapshot_files(&self, repository_id: &str, group_id: &str, artifact_id: &str, version: &str) -> Result<Vec<SnapshotFile>, MavenReadError> {
+        let filter = json!({
+            "repository_id": repository_id,
+            "group_id": group_id,
+            "artifact_id": artifact_id,
+            "version": version,
+        });
+
+        let documents = self.mongo_client.find(&self.database_name, SNAPSHOT_FILES_COLLECTION, filter).await
+            .map_err(|e| MavenReadError::StorageError(format!("MongoDB error: {}", e)))?;
+
+        Ok(documents.iter().filter_map(Self::snapshot_file_from_document).collect())
+    }
+}
+
+/// Adaptador de producción para gestión de repositorios Maven
+pub struct MongoMavenRepositoryManager {
+    mongo_client: Arc<dyn MongoClient>,
+    database_name: String,
+}
+
+impl MongoMavenRepositoryManager {
+    pub fn new(mongo_client: Arc<dyn MongoClient>, database_name: String) -> Self {
+        Self {
+            mongo_client,
+            database_name,
+        }
+    }
+
+    async fn find_repository(&self, repository_id: &str) -> Result<Option<Value>, MavenReadError> {
+        let filter = json!({ "id": repository_id, "format": "maven" });
+        self.mongo_client.find_one(&self.database_name, REPOSITORIES_COLLECTION, filter).await
+            .map_err(|e| MavenReadError::StorageError(format!("MongoDB error: {}", e)))
+    }
+}
+
+#[async_trait]
+impl MavenRepositoryManager for MongoMavenRepositoryManager {
+    #[instrument(
+        name = "mongo.maven.repository_exists",
+        skip(self),
+        fields(
+            repository.id = %repository_id
+        )
+    )]
+    async fn repository_exists(&self, repository_id: &str) -> Result<bool, MavenReadError> {
+        Ok(self.find_repository(repository_id).await?.is_some())
+    }
+
+    #[instrument(
+        name = "mongo.maven.get_repository_info",
+        skip(self),
+        fields(
+            repository.id = %repository_id
+        )
+    )]
+    async fn get_repository_info(&self, repository_id: &str) -> Result<RepositoryInfo, MavenReadError> {
+        let document = self.find_repository(repository_id).await?
+            .ok_or_else(|| MavenReadError::RepositoryNotFound(repository_id.to_string()))?;
+
+        let text = |field: &str, default: &str| {
+            document.get(field).and_then(Value::as_str).unwrap_or(default).to_string()
+        };
+        let flag = |field: &str| document.get(field).and_then(Value::as_bool).unwrap_or(true);
+
+        Ok(RepositoryInfo {
+            id: repository_id.to_string(),
+            name: text("name", repository_id),
+            repository_type: text("repository_type", "hosted"),
+            allow_snapshots: flag("allow_snapshots"),
+            allow_releases: flag("allow_releases"),
+        })
+    }
+}
+
+/// Adaptador de producción para control de permisos Maven con Cedar
+pub struct CedarMavenPermissionChecker {
+    cedar_engine: Arc<dyn CedarEngine>,
+}
+
+impl CedarMavenPermissionChecker {
+    pub fn new(cedar_engine: Arc<dyn CedarEngine>) -> Self {
+        Self {
+            cedar_engine,
+        }
+    }
+
+    /// Recurso Cedar: `{repository_id}/{path}`
+    fn resource_id(repository_id: &str, resource: &MavenResource) -> String {
+        format!("{}/{}", repository_id, resource.to_path())
+    }
+}
+
+#[async_trait]
+impl MavenPermissionChecker for CedarMavenPermissionChecker {
+    #[instrument(
+        name = "cedar.maven.can_read",
+        skip(self, resource),
+        fields(
+            user.id = %user_id,
+            repository.id = %repository_id,
+            resource = %resource.to_path()
+        )
+    )]
+    async fn can_read(&self, user_id: &str, repository_id: &str, resource: &MavenResource) -> Result<bool, MavenReadError> {
+        self.cedar_engine.is_authorized(user_id, "maven:read", &Self::resource_id(repository_id, resource)).await
+            .map_err(|e| MavenReadError::PermissionDenied(format!("Cedar evaluation failed: {}", e)))
+    }
+
+    #[instrument(
+        name = "cedar.maven.can_write",
+        skip(self, resource),
+        fields(
+            user.id = %user_id,
+            repository.id = %repository_id,
+            resource = %resource.to_path()
+        )
+    )]
+    async fn can_write(&self, user_id: &str, repository_id: &str, resource: &MavenResource) -> Result<bool, MavenWriteError> {
+        self.cedar_engine.is_authorized(user_id, "maven:write", &Self::resource_id(repository_id, resource)).await
+            .map_err(|e| MavenWriteError::PermissionDenied(format!("Cedar evaluation failed: {}", e)))
+    }
+}
+
+/// Trait para cliente S3 (para testing y mocking)
+#[async_trait]
+pub trait S3Client: Send + Sync {
+    async fn get_object(&self, bucket: &str, key: &str) -> Result<S3ObjectData, S3Error>;
+    async fn put_object(&self, bucket: &str, key: &str, content: &[u8], content_type: &str) -> Result<(), S3Error>;
+    async fn object_exists(&self, bucket: &str, key: &str) -> Result<bool, S3Error>;
+}
+
+/// Datos de objeto S3
+#[derive(Debug, Clone)]
+pub struct S3ObjectData {
+    pub content: Vec<u8>,
+    pub content_type: String,
+    pub last_modified: Option<OffsetDateTime>,
+    pub etag: Option<String>,
+}
+
+/// Errores de S3
+#[derive(Debug, thiserror::Error)]
+pub enum S3Error {
+    #[error("Object not found")]
+    NotFound,
+    #[error("Permission denied")]
+    PermissionDenied,
+    #[error("Network error: {0}")]
+    NetworkError(String),
+    #[error("Storage error: {0}")]
+    StorageError(String),
+}
+
+/// Trait para cliente MongoDB (para testing y mocking)
+///
+/// Los documentos y filtros se pasan como JSON.
+#[async_trait]
+pub trait MongoClient: Send + Sync {
+    async fn find_one(&self, database: &str, collection: &str, filter: Value) -> Result<Option<Value>, MongoError>;
+    async fn find(&self, database: &str, collection: &str, filter: Value) -> Result<Vec<Value>, MongoError>;
+    /// Insertar `document`, o reemplazar el que coincida con `filter`
+    async fn upsert(&self, database: &str, collection: &str, filter: Value, document: Value) -> Result<(), MongoError>;
+}
+
+/// Errores de MongoDB
+#[derive(Debug, thiserror::Error)]
+pub enum MongoError {
+    #[error("Connection error: {0}")]
+    ConnectionError(String),
+    #[error("Query error: {0}")]
+    QueryError(String),
+}
+
+/// Trait para motor Cedar (para testing y mocking)
+#[async_trait]
+pub trait CedarEngine: Send + Sync {
+    async fn is_authorized(&self, principal: &str, action: &str, resource: &str) -> Result<bool, String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use time::macros::datetime;
+
+    /// Mock S3 client para testing
+    pub struct MockS3Client {
+        objects: Mutex<HashMap<String, S3ObjectData>>,
+    }
+
+    impl MockS3Client {
+        pub fn new() -> Self {
+            Self {
+                objects: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl S3Client for MockS3Client {
+        async fn get_object(&self, bucket: &str, key: &str) -> Result<S3ObjectData, S3Error> {
+            let full_key = format!("{}:{}", bucket, key);
+            self.objects.lock().unwrap()
+                .get(&full_key)
+                .cloned()
+                .ok_or(S3Error::NotFound)
+        }
+
+        async fn put_object(&self, bucket: &str, key: &str, content: &[u8], content_type: &str) -> Result<(), S3Error> {
+            let full_key = format!("{}:{}", bucket, key);
+            let data = S3ObjectData {
+                content: content.to_vec(),
+                content_type: content_type.to_string(),
+                last_modified: Some(OffsetDateTime::now_utc()),
+                etag: Some(format!("\"{}\"", full_key)),
+            };
+            self.objects.lock().unwrap().insert(full_key, data);
+            Ok(())
+        }
+
+        async fn object_exists(&self, bucket: &str, key: &str) -> Result<bool, S3Error> {
+            let full_key = format!("{}:{}", bucket, key);
+            Ok(self.objects.lock().unwrap().contains_key(&full_key))
+        }
+    }
+
+    /// Mock MongoDB client que compara filtros campo a campo
+    pub struct MockMongoClient {
+        collections: Mutex<HashMap<String, Vec<Value>>>,
+    }
+
+    impl MockMongoClient {
+        pub fn new() -> Self {
+            Self {
+                collections: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn matches(document: &Value, filter: &Value) -> bool {
+            filter.as_object().is_some_and(|fields| {
+                fields.iter().all(|(field, value)| document.get(field) == Some(value))
+            })
+        }
+    }
+
+    #[async_trait]
+    impl MongoClient for MockMongoClient {
+        async fn find_one(&self, database: &str, collection: &str, filter: Value) -> Result<Option<Value>, MongoError> {
+            Ok(self.find(database, collection, filter).await?.into_iter().next())
+        }
+
+        async fn find(&self, database: &str, collection: &str, filter: Value) -> Result<Vec<Value>, MongoError> {
+            let key = format!("{}.{}", database, collection);
+            Ok(self.collections.lock().unwrap()
+                .get(&key)
+                .map(|documents| documents.iter().filter(|d| Self::matches(d, &filter)).cloned().collect())
+                .unwrap_or_default())
+        }
+
+        async fn upsert(&self, database: &str, collection: &str, filter: Value, document: Value) -> Result<(), MongoError> {
+            let key = format!("{}.{}", database, collection);
+            let mut collections = self.collections.lock().unwrap();
+            let documents = collections.entry(key).or_default();
+            documents.retain(|d| !Self::matches(d, &filter));
+            documents.push(document);
+            Ok(())
+        }
+    }
+
+    fn release_file() -> MavenArtifactFile {
+        MavenArtifactFile::new(MavenCoordinates::new("com.example", "my-app", "1.0.0").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_s3_maven_artifact_round_trip() {
+        let s3_client = Arc::new(MockS3Client::new());
+        let writer = S3MavenArtifactWriter::new(s3_client.clone(), "test-bucket".to_string(), "artifacts".to_string());
+        let reader = S3MavenArtifactReader::new(s3_client.clone(), "test-bucket".to_string(), "artifacts".to_string());
+
+        let file = release_file();
+        writer.write_artifact(&file, b"jar content", "maven-repo", false).await.unwrap();
+        writer.write_checksum(&file, ChecksumAlgorithm::Sha1, "abc123", "maven-repo").await.unwrap();
+
+        assert!(s3_client.objects.lock().unwrap().contains_key(
+            "test-bucket:artifacts/maven/maven-repo/com/example/my-app/1.0.0/my-app-1.0.0.jar.sha1"
+        ));
+        assert_eq!(reader.read_artifact(&file, "maven-repo").await.unwrap(), b"jar content");
+        assert_eq!(reader.read_checksum(&file, ChecksumAlgorithm::Sha1, "maven-repo").await.unwrap(), "abc123");
+
+        let metadata = reader.read_artifact_metadata(&file, "maven-repo").await.unwrap();
+        assert_eq!(metadata.content_type, "application/java-archive");
+    }
+
+    #[tokio::test]
+    async fn test_s3_maven_release_is_not_overwritten() {
+        let s3_client = Arc::new(MockS3Client::new());
+        let writer = S3MavenArtifactWriter::new(s3_client, "test-bucket".to_string(), "artifacts".to_string());
+
+        let file = release_file();
+        writer.write_artifact(&file, b"first", "maven-repo", false).await.unwrap();
+
+        let result = writer.write_artifact(&file, b"second", "maven-repo", false).await;
+        assert!(matches!(result, Err(MavenWriteError::OverwriteNotAllowed(_))));
+        assert!(writer.write_artifact(&file, b"second", "maven-repo", true).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_mongo_metadata_store_round_trip() {
+        let store = MongoMavenMetadataStore::new(Arc::new(MockMongoClient::new()), "test-db".to_string());
+        let coordinates = MavenCoordinates::new("com.example", "my-app", "1.0-SNAPSHOT").unwrap();
+
+        store.record_version("maven-repo", &coordinates).await.unwrap();
+        store.record_version("maven-repo", &coordinates).await.unwrap();
+        let versions = store.list_versions("maven-repo", "com.example", "my-app").await.unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].version, "1.0-SNAPSHOT");
+
+        let file = SnapshotFile {
+            classifier: Some("sources".to_string()),
+            extension: "jar".to_string(),
+            build: SnapshotBuild::new(datetime!(2024-01-01 12:00:00 UTC), 7),
+        };
+        store.record_snapshot_file("maven-repo", &coordinates, &file).await.unwrap();
+        let files = store.list_snapshot_files("maven-repo", "com.example", "my-app", "1.0-SNAPSHOT").await.unwrap();
+        assert_eq!(files, vec![file]);
+    }
+}
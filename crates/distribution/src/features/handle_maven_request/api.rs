@@ -1,3 +1,509 @@
 // crates/distribution/src/features/handle_maven_request/api.rs
 
-// Placeholder for the API implementation
\ No newline at end of file
+//! API endpoint para el feature Handle Maven Request
+//!
+//! Punto de entrada HTTP para el layout de repositorio Maven:
+//! - GET /maven/{path} - Descargar artefacto, `maven-metadata.xml` o checksum
+//! - PUT /maven/{path} - Desplegar artefacto, checksum o metadata
+//! - HEAD /maven/{path} - Verificar existencia
+//!
+//! Los SNAPSHOT pedidos por su nombre no único se sirven desde su último build.
+
+use axum::{
+    extract::{Path, Extension},
+    http::{StatusCode, HeaderMap, HeaderValue},
+    response::{Response, IntoResponse},
+    body::Body,
+};
+use std::sync::Arc;
+use tracing::{info, warn, error, instrument};
+use crate::domain::maven::coordinates::MavenValidationError;
+use crate::domain::maven::repository_path::{MavenRepositoryPath, MavenResource};
+use super::use_case::{
+    HandleMavenGetArtifactUseCase, HandleMavenGetMetadataUseCase, HandleMavenGetChecksumUseCase,
+    HandleMavenPutArtifactUseCase, HandleMavenPutChecksumUseCase, HandleMavenPutMetadataUseCase,
+    HandleMavenHeadArtifactUseCase, MavenGetError, MavenPutError, MavenHeadError,
+};
+use super::dto::{
+    MavenGetArtifactRequest, MavenGetMetadataRequest, MavenGetChecksumRequest,
+    MavenPutArtifactRequest, MavenPutChecksumRequest, MavenPutMetadataRequest,
+    MavenHeadArtifactRequest,
+};
+
+/// Tamaño máximo de un artefacto subido
+const MAX_ARTIFACT_SIZE: usize = 512 * 1024 * 1024;
+
+/// Estado compartido del API endpoint
+#[derive(Clone)]
+pub struct MavenRequestHandler {
+    get_artifact_use_case: Arc<HandleMavenGetArtifactUseCase>,
+    get_metadata_use_case: Arc<HandleMavenGetMetadataUseCase>,
+    get_checksum_use_case: Arc<HandleMavenGetChecksumUseCase>,
+    put_artifact_use_case: Arc<HandleMavenPutArtifactUseCase>,
+    put_checksum_use_case: Arc<HandleMavenPutChecksumUseCase>,
+    put_metadata_use_case: Arc<HandleMavenPutMetadataUseCase>,
+    head_artifact_use_case: Arc<HandleMavenHeadArtifactUseCase>,
+}
+
+impl MavenRequestHandler {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        get_artifact_use_case: Arc<HandleMavenGetArtifactUseCase>,
+        get_metadata_use_case: Arc<HandleMavenGetMetadataUseCase>,
+        get_checksum_use_case: Arc<HandleMavenGetChecksumUseCase>,
+        put_artifact_use_case: Arc<HandleMavenPutArtifactUseCase>,
+        put_checksum_use_case: Arc<HandleMavenPutChecksumUseCase>,
+        put_metadata_use_case: Arc<HandleMavenPutMetadataUseCase>,
+        head_artifact_use_case: Arc<HandleMavenHeadArtifactUseCase>,
+    ) -> Self {
+        Self {
+            get_artifact_use_case,
+            get_metadata_use_case,
+            get_checksum_use_case,
+            put_artifact_use_case,
+            put_checksum_use_case,
+            put_metadata_use_case,
+            head_artifact_use_case,
+        }
+    }
+
+    /// Manejar GET de cualquier path del repositorio
+    #[instrument(
+        name = "maven.api.get",
+        skip(self),
+        fields(
+            path = %path,
+            repository.id = %repository_id
+        )
+    )]
+    pub async fn handle_get(
+        &self,
+        Path(path): Path<String>,
+        Extension(repository_id): Extension<String>,
+        Extension(user_id): Extension<String>,
+    ) -> Result<Response<Body>, MavenApiError> {
+        info!(path = %path, user_id = %user_id, "Processing Maven download request");
+
+        let parsed = MavenRepositoryPath::parse(&path)?;
+
+        if let Some(algorithm) = parsed.checksum {
+            let response = self.get_checksum_use_case.execute(MavenGetChecksumRequest {
+                resource: parsed.resource,
+                algorithm,
+                repository_id,
+                user_id,
+            }).await?;
+
+            return Ok(text_response(StatusCode::OK, response.checksum));
+        }
+
+        match parsed.resource {
+            MavenResource::Metadata { group_id, artifact_id, version } => {
+                let response = self.get_metadata_use_case.execute(MavenGetMetadataRequest {
+                    group_id,
+                    artifact_id,
+                    version,
+                    repository_id,
+                    user_id,
+                }).await?;
+
+                Ok(Response::builder()
+                    .status(StatusCode::OK)
+                    .header("Content-Type", response.content_type)
+                    .header("Content-Length", response.content.len())
+                    .body(Body::from(response.content))
+                    .unwrap())
+            }
+            MavenResource::Artifact(file) => {
+                let response = self.get_artifact_use_case.execute(MavenGetArtifactRequest {
+                    file,
+                    repository_id,
+                    user_id,
+                }).await?;
+
+                let mut headers = HeaderMap::new();
+                insert_header(&mut headers, "Content-Type", &response.content_type);
+                insert_header(&mut headers, "Content-Length", &response.content_length.to_string());
+                if let Some(ref etag) = response.etag {
+                    insert_header(&mut headers, "ETag", etag);
+                }
+                if let Some(ref last_modified) = response.last_modified {
+                    insert_header(&mut headers, "Last-Modified", last_modified);
+                }
+
+                info!(
+                    artifact_path = %response.artifact_path,
+                    content_length = response.content_length,
+                    "Successfully processed Maven download"
+                );
+
+                Ok((StatusCode::OK, headers, Body::from(response.content)).into_response())
+            }
+        }
+    }
+
+    /// Manejar PUT de cualquier path del repositorio
+    #[instrument(
+        name = "maven.api.put",
+        skip(self, headers, body),
+        fields(
+            path = %path,
+            repository.id = %repository_id
+        )
+    )]
+    pub async fn handle_put(
+        &self,
+        Path(path): Path<String>,
+        headers: HeaderMap,
+        Extension(repository_id): Extension<String>,
+        Extension(user_id): Extension<String>,
+        body: Body,
+    ) -> Result<Response<Body>, MavenApiError> {
+        info!(path = %path, user_id = %user_id, "Processing Maven deploy request");
+
+        let parsed = MavenRepositoryPath::parse(&path)?;
+
+        let content = axum::body::to_bytes(body, MAX_ARTIFACT_SIZE)
+            .await
+            .map_err(|e| MavenApiError::BadRequest(format!("Failed to read request body: {}", e)))?;
+
+        match (parsed.resource, parsed.checksum) {
+            (MavenResource::Metadata { group_id, artifact_id, version }, _) => {
+                // La metadata y sus checksums los genera el servidor
+                let response = self.put_metadata_use_case.execute(MavenPutMetadataRequest {
+                    resource: MavenResource::Metadata { group_id, artifact_id, version },
+                    repository_id,
+                    user_id,
+                }).await?;
+
+                Ok(text_response(StatusCode::CREATED, response.message))
+            }
+            (MavenResource::Artifact(file), Some(algorithm)) => {
+                let checksum = String::from_utf8(content.to_vec())
+                    .map_err(|_| MavenApiError::BadRequest("Checksum is not valid UTF-8".to_string()))?;
+
+                let response = self.put_checksum_use_case.execute(MavenPutChecksumRequest {
+                    file,
+                    algorithm,
+                    checksum,
+                    repository_id,
+                    user_id,
+                }).await?;
+
+                Ok(text_response(StatusCode::CREATED, response.checksum_path))
+            }
+            (MavenResource::Artifact(file), None) => {
+                let content_type = headers
+                    .get("content-type")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+
+                let overwrite = headers
+                    .get("x-overwrite")
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v == "true")
+                    .unwrap_or(false);
+
+                let response = self.put_artifact_use_case.execute(MavenPutArtifactRequest {
+                    file,
+                    content: content.to_vec(),
+                    content_type,
+                    repository_id,
+                    user_id,
+                    overwrite,
+                }).await?;
+
+                let response_body = serde_json::to_string(&response)
+                    .map_err(|e| MavenApiError::InternalServerError(format!("Failed to serialize response: {}", e)))?;
+
+                info!(
+                    artifact_path = %response.artifact_path,
+                    size_bytes = response.size_bytes,
+                    "Successfully deployed Maven artifact"
+                );
+
+                Ok(Response::builder()
+                    .status(StatusCode::CREATED)
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(response_body))
+                    .unwrap())
+            }
+        }
+    }
+
+    /// Manejar HEAD de cualquier path del repositorio
+    ///
+    /// Metadata y checksums se generan igual que en GET y se devuelven sin cuerpo.
+    #[instrument(
+        name = "maven.api.head",
+        skip(self),
+        fields(
+            path = %path,
+            repository.id = %repository_id
+        )
+    )]
+    pub async fn handle_head(
+        &self,
+        Path(path): Path<String>,
+        Extension(repository_id): Extension<String>,
+        Extension(user_id): Extension<String>,
+    ) -> Result<Response<Body>, MavenApiError> {
+        let parsed = MavenRepositoryPath::parse(&path)?;
+
+        let file = match parsed {
+            MavenRepositoryPath { resource: MavenResource::Artifact(file), checksum: None } => file,
+            _ => {
+                let response = self.handle_get(Path(path), Extension(repository_id), Extension(user_id)).await?;
+                let (parts, _) = response.into_parts();
+                return Ok(Response::from_parts(parts, Body::empty()));
+            }
+        };
+
+        let response = self.head_artifact_use_case.execute(MavenHeadArtifactRequest {
+            file,
+            repository_id,
+            user_id,
+        }).await?;
+
+        if !response.exists {
+            warn!(path = %path, "Maven artifact not found");
+            return Ok(Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::empty())
+                .unwrap());
+        }
+
+        let mut headers = HeaderMap::new();
+        if let Some(content_length) = response.content_length {
+            insert_header(&mut headers, "Content-Length", &content_length.to_string());
+        }
+        if let Some(ref etag) = response.etag {
+            insert_header(&mut headers, "ETag", etag);
+        }
+        if let Some(ref last_modified) = response.last_modified {
+            insert_header(&mut headers, "Last-Modified", last_modified);
+        }
+
+        Ok((StatusCode::OK, headers).into_response())
+    }
+}
+
+fn insert_header(headers: &mut HeaderMap, name: &'static str, value: &str) {
+    match HeaderValue::from_str(value) {
+        Ok(value) => {
+            headers.insert(name, value);
+        }
+        Err(_) => warn!(header = name, "Skipping header with an invalid value"),
+    }
+}
+
+fn text_response(status: StatusCode, body: String) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "text/plain")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+/// Errores del API Maven
+#[derive(Debug, thiserror::Error)]
+pub enum MavenApiError {
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Internal server error: {0}")]
+    InternalServerError(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
+}
+
+impl From<MavenValidationError> for MavenApiError {
+    fn from(error: MavenValidationError) -> Self {
+        MavenApiError::BadRequest(error.to_string())
+    }
+}
+
+impl From<super::ports::MavenReadError> for MavenApiError {
+    fn from(error: super::ports::MavenReadError) -> Self {
+        match error {
+            super::ports::MavenReadError::NotFound(_) |
+            super::ports::MavenReadError::RepositoryNotFound(_) => {
+                MavenApiError::NotFound(error.to_string())
+            }
+            super::ports::MavenReadError::PermissionDenied(_) => {
+                MavenApiError::Forbidden(error.to_string())
+            }
+            super::ports::MavenReadError::StorageError(_) => {
+                MavenApiError::StorageError(error.to_string())
+            }
+        }
+    }
+}
+
+impl From<super::ports::MavenWriteError> for MavenApiError {
+    fn from(error: super::ports::MavenWriteError) -> Self {
+        match error {
+            super::ports::MavenWriteError::AlreadyExists(_) |
+            super::ports::MavenWriteError::OverwriteNotAllowed(_) => {
+                MavenApiError::Conflict(error.to_string())
+            }
+            super::ports::MavenWriteError::RepositoryNotFound(_) => {
+                MavenApiError::NotFound(error.to_string())
+            }
+            super::ports::MavenWriteError::PermissionDenied(_) => {
+                MavenApiError::Forbidden(error.to_string())
+            }
+            super::ports::MavenWriteError::WriteFailed(_) |
+            super::ports::MavenWriteError::StorageError(_) => {
+                MavenApiError::StorageError(error.to_string())
+            }
+        }
+    }
+}
+
+impl From<MavenGetError> for MavenApiError {
+    fn from(error: MavenGetError) -> Self {
+        match error {
+            MavenGetError::InvalidCoordinates(_) => MavenApiError::BadRequest(error.to_string()),
+            MavenGetError::RepositoryNotFound(_) |
+            MavenGetError::ArtifactNotFound(_) |
+            MavenGetError::MetadataNotFound(_) => MavenApiError::NotFound(error.to_string()),
+            MavenGetError::PermissionDenied => MavenApiError::Forbidden(error.to_string()),
+            MavenGetError::ReadFailed(e) => MavenApiError::from(e),
+        }
+    }
+}
+
+impl From<MavenPutError> for MavenApiError {
+    fn from(error: MavenPutError) -> Self {
+        match error {
+            MavenPutError::InvalidCoordinates(_) |
+            MavenPutError::InvalidContentType(_) |
+            MavenPutError::SnapshotsNotAllowed |
+            MavenPutError::ReleasesNotAllowed |
+            MavenPutError::ChecksumMismatch { .. } => MavenApiError::BadRequest(error.to_string()),
+            MavenPutError::RepositoryNotFound(_) |
+            MavenPutError::ArtifactNotFound(_) => MavenApiError::NotFound(error.to_string()),
+            MavenPutError::PermissionDenied => MavenApiError::Forbidden(error.to_string()),
+            MavenPutError::ReadFailed(e) => MavenApiError::from(e),
+            MavenPutError::WriteFailed(e) => MavenApiError::from(e),
+        }
+    }
+}
+
+impl From<MavenHeadError> for MavenApiError {
+    fn from(error: MavenHeadError) -> Self {
+        match error {
+            MavenHeadError::InvalidCoordinates(_) => MavenApiError::BadRequest(error.to_string()),
+            MavenHeadError::RepositoryNotFound(_) => MavenApiError::NotFound(error.to_string()),
+            MavenHeadError::PermissionDenied => MavenApiError::Forbidden(error.to_string()),
+            MavenHeadError::ReadFailed(e) => MavenApiError::from(e),
+        }
+    }
+}
+
+impl IntoResponse for MavenApiError {
+    fn into_response(self) -> Response<Body> {
+        let status = match &self {
+            MavenApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            MavenApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            MavenApiError::Forbidden(_) => StatusCode::FORBIDDEN,
+            MavenApiError::Conflict(_) => StatusCode::CONFLICT,
+            MavenApiError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            MavenApiError::StorageError(_) => StatusCode::SERVICE_UNAVAILABLE,
+        };
+
+        if status.is_server_error() {
+            error!(error = %self, "Maven request failed");
+        }
+
+        let error_response = serde_json::json!({
+            "error": {
+                "message": self.to_string(),
+                "type": format!("{:?}", self)
+            }
+        });
+
+        let body = serde_json::to_string(&error_response)
+            .unwrap_or_else(|_| r#"{"error":{"message":"Internal server error","type":"InternalServerError"}}"#.to_string());
+
+        Response::builder()
+            .status(status)
+            .header("Content-Type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::handle_maven_request::ports::test::{
+        MockMavenArtifactReader, MockMavenArtifactWriter, MockMavenMetadataStore,
+        MockMavenRepositoryManager, MockMavenPermissionChecker,
+    };
+
+    fn handler() -> MavenRequestHandler {
+        let reader = Arc::new(MockMavenArtifactReader::new());
+        let writer = Arc::new(MockMavenArtifactWriter::new());
+        let store = Arc::new(MockMavenMetadataStore::new());
+        let repositories = Arc::new(MockMavenRepositoryManager::new());
+        let permissions = Arc::new(MockMavenPermissionChecker::new());
+
+        MavenRequestHandler::new(
+            Arc::new(HandleMavenGetArtifactUseCase::new(reader.clone(), store.clone(), repositories.clone(), permissions.clone())),
+            Arc::new(HandleMavenGetMetadataUseCase::new(store.clone(), repositories.clone(), permissions.clone())),
+            Arc::new(HandleMavenGetChecksumUseCase::new(reader.clone(), store.clone(), repositories.clone(), permissions.clone())),
+            Arc::new(HandleMavenPutArtifactUseCase::new(writer, store.clone(), repositories.clone(), permissions.clone())),
+            Arc::new(HandleMavenPutChecksumUseCase::new(reader.clone(), repositories.clone(), permissions.clone())),
+            Arc::new(HandleMavenPutMetadataUseCase::new(repositories.clone(), permissions.clone())),
+            Arc::new(HandleMavenHeadArtifactUseCase::new(reader, store, repositories, permissions)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_invalid_path_is_bad_request() {
+        let result = handler().handle_get(
+            Path("my-app/1.0.0/my-app-1.0.0.jar".to_string()),
+            Extension("test-repo".to_string()),
+            Extension("test-user".to_string()),
+        ).await;
+
+        assert!(matches!(result, Err(MavenApiError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_put_metadata_is_accepted_without_storing() {
+        let response = handler().handle_put(
+            Path("com/example/my-app/maven-metadata.xml".to_string()),
+            HeaderMap::new(),
+            Extension("test-repo".to_string()),
+            Extension("test-user".to_string()),
+            Body::from("<metadata/>"),
+        ).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_missing_artifact_head_is_not_found() {
+        let response = handler().handle_head(
+            Path("com/example/my-app/1.0.0/my-app-1.0.0.jar".to_string()),
+            Extension("test-repo".to_string()),
+            Extension("test-user".to_string()),
+        ).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -1,3 +1,183 @@
 // crates/distribution/src/features/handle_maven_request/di.rs
 
-// Placeholder for the dependency injection implementation
\ No newline at end of file
+//! Contenedor de inyección de dependencias para el feature Handle Maven Request
+//!
+//! Proporciona configuración flexible de dependencias para diferentes entornos.
+
+use std::sync::Arc;
+use super::ports::{
+    MavenArtifactReader, MavenArtifactWriter, MavenMetadataStore, MavenRepositoryManager,
+    MavenPermissionChecker,
+};
+use super::use_case::{
+    HandleMavenGetArtifactUseCase, HandleMavenGetMetadataUseCase, HandleMavenGetChecksumUseCase,
+    HandleMavenPutArtifactUseCase, HandleMavenPutChecksumUseCase, HandleMavenPutMetadataUseCase,
+    HandleMavenHeadArtifactUseCase,
+};
+use super::api::MavenRequestHandler;
+
+/// Contenedor de DI para el feature Handle Maven Request
+pub struct HandleMavenRequestDIContainer {
+    pub get_artifact_use_case: Arc<HandleMavenGetArtifactUseCase>,
+    pub get_metadata_use_case: Arc<HandleMavenGetMetadataUseCase>,
+    pub get_checksum_use_case: Arc<HandleMavenGetChecksumUseCase>,
+    pub put_artifact_use_case: Arc<HandleMavenPutArtifactUseCase>,
+    pub put_checksum_use_case: Arc<HandleMavenPutChecksumUseCase>,
+    pub put_metadata_use_case: Arc<HandleMavenPutMetadataUseCase>,
+    pub head_artifact_use_case: Arc<HandleMavenHeadArtifactUseCase>,
+    pub request_handler: MavenRequestHandler,
+}
+
+impl HandleMavenRequestDIContainer {
+    /// Constructor flexible que acepta cualquier implementación de los puertos
+    pub fn new(
+        artifact_reader: Arc<dyn MavenArtifactReader>,
+        artifact_writer: Arc<dyn MavenArtifactWriter>,
+        metadata_store: Arc<dyn MavenMetadataStore>,
+        repository_manager: Arc<dyn MavenRepositoryManager>,
+        permission_checker: Arc<dyn MavenPermissionChecker>,
+    ) -> Self {
+        let get_artifact_use_case = Arc::new(HandleMavenGetArtifactUseCase::new(
+            artifact_reader.clone(),
+            metadata_store.clone(),
+            repository_manager.clone(),
+            permission_checker.clone(),
+        ));
+
+        let get_metadata_use_case = Arc::new(HandleMavenGetMetadataUseCase::new(
+            metadata_store.clone(),
+            repository_manager.clone(),
+            permission_checker.clone(),
+        ));
+
+        let get_checksum_use_case = Arc::new(HandleMavenGetChecksumUseCase::new(
+            artifact_reader.clone(),
+            metadata_store.clone(),
+            repository_manager.clone(),
+            permission_checker.clone(),
+        ));
+
+        let put_artifact_use_case = Arc::new(HandleMavenPutArtifactUseCase::new(
+            artifact_writer,
+            metadata_store.clone(),
+            repository_manager.clone(),
+            permission_checker.clone(),
+        ));
+
+        let put_checksum_use_case = Arc::new(HandleMavenPutChecksumUseCase::new(
+            artifact_reader.clone(),
+            repository_manager.clone(),
+            permission_checker.clone(),
+        ));
+
+        let put_metadata_use_case = Arc::new(HandleMavenPutMetadataUseCase::new(
+            repository_manager.clone(),
+            permission_checker.clone(),
+        ));
+
+        let head_artifact_use_case = Arc::new(HandleMavenHeadArtifactUseCase::new(
+            artifact_reader,
+            metadata_store,
+            repository_manager,
+            permission_checker,
+        ));
+
+        let request_handler = MavenRequestHandler::new(
+            get_artifact_use_case.clone(),
+            get_metadata_use_case.clone(),
+            get_checksum_use_case.clone(),
+            put_artifact_use_case.clone(),
+            put_checksum_use_case.clone(),
+            put_metadata_use_case.clone(),
+            head_artifact_use_case.clone(),
+        );
+
+        Self {
+            get_artifact_use_case,
+            get_metadata_use_case,
+            get_checksum_use_case,
+            put_artifact_use_case,
+            put_checksum_use_case,
+            put_metadata_use_case,
+            head_artifact_use_case,
+            request_handler,
+        }
+    }
+
+    /// Método de conveniencia para producción con S3, MongoDB y Cedar
+    pub fn for_production(
+        s3_client: Arc<dyn super::adapter::S3Client>,
+        mongo_client: Arc<dyn super::adapter::MongoClient>,
+        cedar_engine: Arc<dyn super::adapter::CedarEngine>,
+        bucket_name: String,
+        database_name: String,
+        base_path: String,
+    ) -> Self {
+        let artifact_reader: Arc<dyn MavenArtifactReader> = Arc::new(
+            super::adapter::S3MavenArtifactReader::new(
+                s3_client.clone(),
+                bucket_name.clone(),
+                base_path.clone(),
+            )
+        );
+
+        let artifact_writer: Arc<dyn MavenArtifactWriter> = Arc::new(
+            super::adapter::S3MavenArtifactWriter::new(
+                s3_client,
+                bucket_name,
+                base_path,
+            )
+        );
+
+        let metadata_store: Arc<dyn MavenMetadataStore> = Arc::new(
+            super::adapter::MongoMavenMetadataStore::new(
+                mongo_client.clone(),
+                database_name.clone(),
+            )
+        );
+
+        let repository_manager: Arc<dyn MavenRepositoryManager> = Arc::new(
+            super::adapter::MongoMavenRepositoryManager::new(
+                mongo_client,
+                database_name,
+            )
+        );
+
+        let permission_checker: Arc<dyn MavenPermissionChecker> = Arc::new(
+            super::adapter::CedarMavenPermissionChecker::new(cedar_engine)
+        );
+
+        Self::new(
+            artifact_reader,
+            artifact_writer,
+            metadata_store,
+            repository_manager,
+            permission_checker,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ports::test::{
+        MockMavenArtifactReader, MockMavenArtifactWriter, MockMavenMetadataStore,
+        MockMavenRepositoryManager, MockMavenPermissionChecker,
+    };
+
+    #[test]
+    fn test_di_container_creation() {
+        let container = HandleMavenRequestDIContainer::new(
+            Arc::new(MockMavenArtifactReader::new()),
+            Arc::new(MockMavenArtifactWriter::new()),
+            Arc::new(MockMavenMetadataStore::new()),
+            Arc::new(MockMavenRepositoryManager::new()),
+            Arc::new(MockMavenPermissionChecker::new()),
+        );
+
+        // Cada use case lo comparten el contenedor y el request handler
+        assert_eq!(Arc::strong_count(&container.get_artifact_use_case), 2);
+        assert_eq!(Arc::strong_count(&container.put_artifact_use_case), 2);
+        assert_eq!(Arc::strong_count(&container.head_artifact_use_case), 2);
+    }
+}
@@ -5,18 +5,25 @@
 //! Estos DTOs son específicos de este feature y no son compartidos con otros features.
 
 use serde::{Serialize, Deserialize};
+use crate::domain::maven::checksum::ChecksumAlgorithm;
 use crate::domain::maven::coordinates::MavenCoordinates;
+use crate::domain::maven::repository_path::{MavenArtifactFile, MavenResource};
 
 /// Request para obtener un artefacto Maven
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Un SNAPSHOT pedido por su nombre no único se resuelve al último build.
+#[derive(Debug, Clone)]
 pub struct MavenGetArtifactRequest {
-    pub coordinates: MavenCoordinates,
+    pub file: MavenArtifactFile,
     pub repository_id: String,
+    pub user_id: String,
 }
 
 /// Response para obtener un artefacto Maven
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MavenGetArtifactResponse {
+    /// Path del fichero servido, ya resuelto si era un SNAPSHOT
+    pub artifact_path: String,
     pub content: Vec<u8>,
     pub content_type: String,
     pub content_length: usize,
@@ -25,29 +32,107 @@ pub struct MavenGetArtifactResponse {
 }
 
 /// Request para subir un artefacto Maven
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub struct MavenPutArtifactRequest {
-    pub coordinates: MavenCoordinates,
+    pub file: MavenArtifactFile,
     pub content: Vec<u8>,
     pub content_type: String,
     pub repository_id: String,
+    pub user_id: String,
     pub overwrite: bool,
 }
 
 /// Response para subir un artefacto Maven
+///
+/// Incluye los checksums calculados por el servidor, que son los que se
+/// guardan junto al artefacto.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MavenPutArtifactResponse {
     pub success: bool,
     pub message: String,
     pub artifact_path: String,
     pub size_bytes: usize,
+    pub sha1: String,
+    pub md5: String,
 }
 
-/// Request para verificar la existencia de un artefacto
+/// Request para subir el fichero `.sha1` o `.md5` de un artefacto
+///
+/// El servidor ya guardó sus propios checksums al subir el artefacto; el del
+/// cliente solo se valida contra ellos.
+#[derive(Debug, Clone)]
+pub struct MavenPutChecksumRequest {
+    pub file: MavenArtifactFile,
+    pub algorithm: ChecksumAlgorithm,
+    pub checksum: String,
+    pub repository_id: String,
+    pub user_id: String,
+}
+
+/// Response para subir un checksum
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenPutChecksumResponse {
+    pub verified: bool,
+    pub checksum_path: String,
+}
+
+/// Request para subir un `maven-metadata.xml`
+///
+/// La metadata la genera el servidor, así que el contenido se descarta.
+#[derive(Debug, Clone)]
+pub struct MavenPutMetadataRequest {
+    pub resource: MavenResource,
+    pub repository_id: String,
+    pub user_id: String,
+}
+
+/// Response para subir un `maven-metadata.xml`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenPutMetadataResponse {
+    pub accepted: bool,
+    pub message: String,
+}
+
+/// Request para obtener un `maven-metadata.xml` generado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenGetMetadataRequest {
+    pub group_id: String,
+    pub artifact_id: String,
+    /// Versión SNAPSHOT para la metadata de su directorio; `None` para la del artefacto
+    pub version: Option<String>,
+    pub repository_id: String,
+    pub user_id: String,
+}
+
+/// Response con el `maven-metadata.xml` generado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenGetMetadataResponse {
+    pub content: String,
+    pub content_type: String,
+}
+
+/// Request para obtener el checksum de un artefacto o de su metadata
+#[derive(Debug, Clone)]
+pub struct MavenGetChecksumRequest {
+    pub resource: MavenResource,
+    pub algorithm: ChecksumAlgorithm,
+    pub repository_id: String,
+    pub user_id: String,
+}
+
+/// Response con el checksum, en el formato de los ficheros `.sha1`/`.md5`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MavenGetChecksumResponse {
+    pub checksum: String,
+    pub algorithm: String,
+}
+
+/// Request para verificar la existencia de un artefacto
+#[derive(Debug, Clone)]
 pub struct MavenHeadArtifactRequest {
-    pub coordinates: MavenCoordinates,
+    pub file: MavenArtifactFile,
     pub repository_id: String,
+    pub user_id: String,
 }
 
 /// Response para verificar la existencia de un artefacto
@@ -90,11 +175,12 @@ mod tests {
     fn test_maven_get_artifact_request() {
         let coordinates = MavenCoordinates::new("com.example", "my-app", "1.0.0").unwrap();
         let request = MavenGetArtifactRequest {
-            coordinates: coordinates.clone(),
+            file: MavenArtifactFile::new(coordinates),
             repository_id: "maven-central".to_string(),
+            user_id: "test-user".to_string(),
         };
         
-        assert_eq!(request.coordinates.group_id, "com.example");
+        assert_eq!(request.file.coordinates.group_id, "com.example");
         assert_eq!(request.repository_id, "maven-central");
     }
     
@@ -105,6 +191,8 @@ mod tests {
             message: "Artifact uploaded successfully".to_string(),
             artifact_path: "com/example/my-app/1.0.0/my-app-1.0.0.jar".to_string(),
             size_bytes: 1024,
+            sha1: "da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string(),
+            md5: "d41d8cd98f00b204e9800998ecf8427e".to_string(),
         };
         
         assert!(response.success);
@@ -2,7 +2,9 @@
 
 //! Feature: Handle Maven Request
 //! 
-//! Responsabilidad: Procesar requests HTTP Maven (GET/PUT) para artefactos
+//! Responsabilidad: Procesar requests HTTP Maven (GET/PUT/HEAD) para artefactos,
+//! `maven-metadata.xml` y checksums `.sha1`/`.md5`, incluidos los builds
+//! SNAPSHOT con marca de tiempo
 //! 
 //! Este feature es completamente independiente con sus propios puertos segregados
 //! siguiendo el principio de segregación de interfaces (ISP).
@@ -15,4 +17,6 @@ pub mod api;
 pub mod di;
 
 // Solo exportar lo necesario al exterior
-pub use dto::{MavenGetArtifactRequest, MavenGetArtifactResponse, MavenPutArtifactRequest, MavenPutArtifactResponse};
\ No newline at end of file
+pub use dto::{MavenGetArtifactRequest, MavenGetArtifactResponse, MavenPutArtifactRequest, MavenPutArtifactResponse};
+pub use api::{MavenApiError, MavenRequestHandler};
+pub use di::HandleMavenRequestDIContainer;
\ No newline at end of file
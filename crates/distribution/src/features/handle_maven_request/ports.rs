@@ -6,9 +6,11 @@
 //! Cada feature define sus PROPIOS puertos, incluso si son similares a otros features.
 
 use async_trait::async_trait;
-use std::sync::Arc;
+use crate::domain::maven::checksum::ChecksumAlgorithm;
 use crate::domain::maven::coordinates::MavenCoordinates;
-use super::dto::{MavenGetArtifactRequest, MavenGetArtifactResponse, MavenPutArtifactRequest, MavenPutArtifactResponse};
+use crate::domain::maven::metadata::MavenVersion;
+use crate::domain::maven::repository_path::{MavenArtifactFile, MavenResource};
+use crate::domain::maven::snapshot::SnapshotFile;
 
 /// Error de lectura específico de este feature
 #[derive(Debug, thiserror::Error)]
@@ -42,30 +44,54 @@ pub enum MavenWriteError {
 
 /// Puerto para leer artefactos Maven - INTERFAZ SEGREGADA
 #[async_trait]
-pub trait MavenArtifactReader {
+pub trait MavenArtifactReader: Send + Sync {
     /// Leer un artefacto Maven completo
-    async fn read_artifact(&self, coordinates: &MavenCoordinates, repository_id: &str) -> Result<Vec<u8>, MavenReadError>;
+    async fn read_artifact(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<Vec<u8>, MavenReadError>;
     
     /// Leer metadata de un artefacto (headers HTTP)
-    async fn read_artifact_metadata(&self, coordinates: &MavenCoordinates, repository_id: &str) -> Result<ArtifactMetadata, MavenReadError>;
+    async fn read_artifact_metadata(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<ArtifactMetadata, MavenReadError>;
     
     /// Verificar si un artefacto existe
-    async fn artifact_exists(&self, coordinates: &MavenCoordinates, repository_id: &str) -> Result<bool, MavenReadError>;
+    async fn artifact_exists(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<bool, MavenReadError>;
+    
+    /// Leer el checksum guardado junto a un artefacto
+    async fn read_checksum(&self, file: &MavenArtifactFile, algorithm: ChecksumAlgorithm, repository_id: &str) -> Result<String, MavenReadError>;
 }
 
 /// Puerto para escribir artefactos Maven - INTERFAZ SEGREGADA
 #[async_trait]
-pub trait MavenArtifactWriter {
+pub trait MavenArtifactWriter: Send + Sync {
     /// Escribir un artefacto Maven completo
-    async fn write_artifact(&self, coordinates: &MavenCoordinates, content: &[u8], repository_id: &str, overwrite: bool) -> Result<(), MavenWriteError>;
+    async fn write_artifact(&self, file: &MavenArtifactFile, content: &[u8], repository_id: &str, overwrite: bool) -> Result<(), MavenWriteError>;
     
     /// Escribir metadata de un artefacto
-    async fn write_artifact_metadata(&self, coordinates: &MavenCoordinates, metadata: &ArtifactMetadata, repository_id: &str) -> Result<(), MavenWriteError>;
+    async fn write_artifact_metadata(&self, file: &MavenArtifactFile, metadata: &ArtifactMetadata, repository_id: &str) -> Result<(), MavenWriteError>;
+    
+    /// Guardar el checksum de un artefacto junto a él (`.sha1`, `.md5`)
+    async fn write_checksum(&self, file: &MavenArtifactFile, algorithm: ChecksumAlgorithm, checksum: &str, repository_id: &str) -> Result<(), MavenWriteError>;
+}
+
+/// Puerto para el índice de versiones y builds SNAPSHOT - INTERFAZ SEGREGADA
+///
+/// Es la fuente de la que se genera `maven-metadata.xml`.
+#[async_trait]
+pub trait MavenMetadataStore: Send + Sync {
+    /// Registrar una versión desplegada de un artefacto
+    async fn record_version(&self, repository_id: &str, coordinates: &MavenCoordinates) -> Result<(), MavenWriteError>;
+    
+    /// Versiones desplegadas de un artefacto
+    async fn list_versions(&self, repository_id: &str, group_id: &str, artifact_id: &str) -> Result<Vec<MavenVersion>, MavenReadError>;
+    
+    /// Registrar un fichero desplegado en un build SNAPSHOT
+    async fn record_snapshot_file(&self, repository_id: &str, coordinates: &MavenCoordinates, file: &SnapshotFile) -> Result<(), MavenWriteError>;
+    
+    /// Ficheros desplegados en una versión SNAPSHOT, de todos sus builds
+    async fn list_snapshot_files(&self, repository_id: &str, group_id: &str, artifact_id: &str, version: &str) -> Result<Vec<SnapshotFile>, MavenReadError>;
 }
 
 /// Puerto para gestionar repositorios Maven - INTERFAZ SEGREGADA
 #[async_trait]
-pub trait MavenRepositoryManager {
+pub trait MavenRepositoryManager: Send + Sync {
     /// Verificar si un repositorio existe
     async fn repository_exists(&self, repository_id: &str) -> Result<bool, MavenReadError>;
     
@@ -75,12 +101,12 @@ pub trait MavenRepositoryManager {
 
 /// Puerto para gestionar permisos - INTERFAZ SEGREGADA
 #[async_trait]
-pub trait MavenPermissionChecker {
+pub trait MavenPermissionChecker: Send + Sync {
     /// Verificar si el usuario tiene permiso para leer
-    async fn can_read(&self, user_id: &str, repository_id: &str, coordinates: &MavenCoordinates) -> Result<bool, MavenReadError>;
+    async fn can_read(&self, user_id: &str, repository_id: &str, resource: &MavenResource) -> Result<bool, MavenReadError>;
     
     /// Verificar si el usuario tiene permiso para escribir
-    async fn can_write(&self, user_id: &str, repository_id: &str, coordinates: &MavenCoordinates) -> Result<bool, MavenWriteError>;
+    async fn can_write(&self, user_id: &str, repository_id: &str, resource: &MavenResource) -> Result<bool, MavenWriteError>;
 }
 
 /// Metadata de un artefacto para headers HTTP
@@ -108,11 +134,13 @@ pub mod test {
     use super::*;
     use std::collections::HashMap;
     use std::sync::Mutex;
+    use time::OffsetDateTime;
     
     /// Mock para MavenArtifactReader
     pub struct MockMavenArtifactReader {
         pub artifacts: Mutex<HashMap<String, Vec<u8>>>,
         pub metadata: Mutex<HashMap<String, ArtifactMetadata>>,
+        pub checksums: Mutex<HashMap<String, String>>,
     }
     
     impl MockMavenArtifactReader {
@@ -120,52 +148,68 @@ pub mod test {
             Self {
                 artifacts: Mutex::new(HashMap::new()),
                 metadata: Mutex::new(HashMap::new()),
+                checksums: Mutex::new(HashMap::new()),
             }
         }
         
-        pub fn add_artifact(&self, coordinates: &MavenCoordinates, repository_id: &str, content: Vec<u8>) {
-            let key = format!("{}:{}", repository_id, coordinates.to_path());
-            self.artifacts.lock().unwrap().insert(key.clone(), content);
+        pub fn add_artifact(&self, file: &MavenArtifactFile, repository_id: &str, content: Vec<u8>) {
+            let key = format!("{}:{}", repository_id, file.to_path());
+            for algorithm in ChecksumAlgorithm::ALL {
+                self.checksums.lock().unwrap().insert(
+                    format!("{}.{}", key, algorithm.extension()),
+                    algorithm.compute(&content),
+                );
+            }
             
             // Agregar metadata por defecto
             let metadata = ArtifactMetadata {
-                content_length: 1024,
+                content_length: content.len(),
                 content_type: "application/java-archive".to_string(),
                 last_modified: "2024-01-01T00:00:00Z".to_string(),
                 etag: "etag123".to_string(),
             };
-            self.metadata.lock().unwrap().insert(key, metadata);
+            self.metadata.lock().unwrap().insert(key.clone(), metadata);
+            self.artifacts.lock().unwrap().insert(key, content);
         }
     }
     
     #[async_trait]
     impl MavenArtifactReader for MockMavenArtifactReader {
-        async fn read_artifact(&self, coordinates: &MavenCoordinates, repository_id: &str) -> Result<Vec<u8>, MavenReadError> {
-            let key = format!("{}:{}", repository_id, coordinates.to_path());
+        async fn read_artifact(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<Vec<u8>, MavenReadError> {
+            let key = format!("{}:{}", repository_id, file.to_path());
             self.artifacts.lock().unwrap()
                 .get(&key)
                 .cloned()
                 .ok_or_else(|| MavenReadError::NotFound(key))
         }
         
-        async fn read_artifact_metadata(&self, coordinates: &MavenCoordinates, repository_id: &str) -> Result<ArtifactMetadata, MavenReadError> {
-            let key = format!("{}:{}", repository_id, coordinates.to_path());
+        async fn read_artifact_metadata(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<ArtifactMetadata, MavenReadError> {
+            let key = format!("{}:{}", repository_id, file.to_path());
             self.metadata.lock().unwrap()
                 .get(&key)
                 .cloned()
                 .ok_or_else(|| MavenReadError::NotFound(key))
         }
         
-        async fn artifact_exists(&self, coordinates: &MavenCoordinates, repository_id: &str) -> Result<bool, MavenReadError> {
-            let key = format!("{}:{}", repository_id, coordinates.to_path());
+        async fn artifact_exists(&self, file: &MavenArtifactFile, repository_id: &str) -> Result<bool, MavenReadError> {
+            let key = format!("{}:{}", repository_id, file.to_path());
             Ok(self.artifacts.lock().unwrap().contains_key(&key))
         }
+        
+        async fn read_checksum(&self, file: &MavenArtifactFile, algorithm: ChecksumAlgorithm, repository_id: &str) -> Result<String, MavenReadError> {
+            let key = format!("{}:{}.{}", repository_id, file.to_path(), algorithm.extension());
+            self.checksums.lock().unwrap()
+                .get(&key)
+                .cloned()
+                .ok_or_else(|| MavenReadError::NotFound(key))
+        }
     }
     
     /// Mock para MavenArtifactWriter
     pub struct MockMavenArtifactWriter {
         pub artifacts: Mutex<HashMap<String, Vec<u8>>>,
         pub metadata: Mutex<HashMap<String, ArtifactMetadata>>,
+        pub checksums: Mutex<HashMap<String, String>>,
         pub allow_overwrite: bool,
     }
     
@@ -174,6 +218,7 @@ pub mod test {
             Self {
                 artifacts: Mutex::new(HashMap::new()),
                 metadata: Mutex::new(HashMap::new()),
+                checksums: Mutex::new(HashMap::new()),
                 allow_overwrite: true,
             }
         }
@@ -185,13 +230,11 @@ pub mod test {
     
     #[async_trait]
     impl MavenArtifactWriter for MockMavenArtifactWriter {
-        async fn write_artifact(&self, coordinates: &MavenCoordinates, content: &[u8], repository_id: &str, overwrite: bool) -> Result<(), MavenWriteError> {
-            let key = format!("{}:{}", repository_id, coordinates.to_path());
+        async fn write_artifact(&self, file: &MavenArtifactFile, content: &[u8], repository_id: &str, overwrite: bool) -> Result<(), MavenWriteError> {
+            let key = format!("{}:{}", repository_id, file.to_path());
             
-            if !overwrite && !self.allow_overwrite {
-                if self.artifacts.lock().unwrap().contains_key(&key) {
-                    return Err(MavenWriteError::OverwriteNotAllowed(key));
-                }
+            if !overwrite && !self.allow_overwrite && self.artifacts.lock().unwrap().contains_key(&key) {
+                return Err(MavenWriteError::OverwriteNotAllowed(key));
             }
             
             self.artifacts.lock().unwrap().insert(key.clone(), content.to_vec());
@@ -208,11 +251,61 @@ pub mod test {
             Ok(())
         }
         
-        async fn write_artifact_metadata(&self, coordinates: &MavenCoordinates, metadata: &ArtifactMetadata, repository_id: &str) -> Result<(), MavenWriteError> {
-            let key = format!("{}:{}", repository_id, coordinates.to_path());
+        async fn write_artifact_metadata(&self, file: &MavenArtifactFile, metadata: &ArtifactMetadata, repository_id: &str) -> Result<(), MavenWriteError> {
+            let key = format!("{}:{}", repository_id, file.to_path());
             self.metadata.lock().unwrap().insert(key, metadata.clone());
             Ok(())
         }
+        
+        async fn write_checksum(&self, file: &MavenArtifactFile, algorithm: ChecksumAlgorithm, checksum: &str, repository_id: &str) -> Result<(), MavenWriteError> {
+            let key = format!("{}:{}.{}", repository_id, file.to_path(), algorithm.extension());
+            self.checksums.lock().unwrap().insert(key, checksum.to_string());
+            Ok(())
+        }
+    }
+    
+    /// Mock para MavenMetadataStore
+    pub struct MockMavenMetadataStore {
+        pub versions: Mutex<HashMap<String, Vec<MavenVersion>>>,
+        pub snapshot_files: Mutex<HashMap<String, Vec<SnapshotFile>>>,
+    }
+    
+    impl MockMavenMetadataStore {
+        pub fn new() -> Self {
+            Self {
+                versions: Mutex::new(HashMap::new()),
+                snapshot_files: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+    
+    #[async_trait]
+    impl MavenMetadataStore for MockMavenMetadataStore {
+        async fn record_version(&self, repository_id: &str, coordinates: &MavenCoordinates) -> Result<(), MavenWriteError> {
+            let key = format!("{}:{}:{}", repository_id, coordinates.group_id, coordinates.artifact_id);
+            let mut versions = self.versions.lock().unwrap();
+            let versions = versions.entry(key).or_default();
+            if !versions.iter().any(|v| v.version == coordinates.version) {
+                versions.push(MavenVersion::new(coordinates.version.clone()));
+            }
+            Ok(())
+        }
+        
+        async fn list_versions(&self, repository_id: &str, group_id: &str, artifact_id: &str) -> Result<Vec<MavenVersion>, MavenReadError> {
+            let key = format!("{}:{}:{}", repository_id, group_id, artifact_id);
+            Ok(self.versions.lock().unwrap().get(&key).cloned().unwrap_or_default())
+        }
+        
+        async fn record_snapshot_file(&self, repository_id: &str, coordinates: &MavenCoordinates, file: &SnapshotFile) -> Result<(), MavenWriteError> {
+            let key = format!("{}:{}:{}:{}", repository_id, coordinates.group_id, coordinates.artifact_id, coordinates.version);
+            self.snapshot_files.lock().unwrap().entry(key).or_default().push(file.clone());
+            Ok(())
+        }
+        
+        async fn list_snapshot_files(&self, repository_id: &str, group_id: &str, artifact_id: &str, version: &str) -> Result<Vec<SnapshotFile>, MavenReadError> {
+            let key = format!("{}:{}:{}:{}", repository_id, group_id, artifact_id, version);
+            Ok(self.snapshot_files.lock().unwrap().get(&key).cloned().unwrap_or_default())
+        }
     }
     
     /// Mock para MavenRepositoryManager
@@ -280,11 +373,11 @@ pub mod test {
     
     #[async_trait]
     impl MavenPermissionChecker for MockMavenPermissionChecker {
-        async fn can_read(&self, _user_id: &str, _repository_id: &str, _coordinates: &MavenCoordinates) -> Result<bool, MavenReadError> {
+        async fn can_read(&self, _user_id: &str, _repository_id: &str, _resource: &MavenResource) -> Result<bool, MavenReadError> {
             Ok(self.allow_read)
         }
         
-        async fn can_write(&self, _user_id: &str, _repository_id: &str, _coordinates: &MavenCoordinates) -> Result<bool, MavenWriteError> {
+        async fn can_write(&self, _user_id: &str, _repository_id: &str, _resource: &MavenResource) -> Result<bool, MavenWriteError> {
             if self.allow_write {
                 Ok(true)
             } else {
@@ -1,27 +1,36 @@
 // crates/distribution/src/features/handle_maven_request/use_case.rs
 
 //! Use cases específicos para el feature Handle Maven Request
-//! 
+//!
 //! Cada use case contiene la lógica de negocio pura para una operación específica.
 
 use std::sync::Arc;
-use async_trait::async_trait;
-use tracing::{info, instrument, error};
+use time::OffsetDateTime;
+use tracing::{debug, info, instrument, error, warn};
+use crate::domain::maven::checksum::ChecksumAlgorithm;
+use crate::domain::maven::coordinates::MavenValidationError;
+use crate::domain::maven::metadata::MavenMetadata;
+use crate::domain::maven::repository_path::{MavenArtifactFile, MavenResource};
+use crate::domain::maven::snapshot::{SnapshotBuild, SnapshotFile, SnapshotMetadata};
 use crate::domain::maven::validation::validate_maven_coordinates;
 use super::dto::{
-    MavenGetArtifactRequest, MavenGetArtifactResponse, 
+    MavenGetArtifactRequest, MavenGetArtifactResponse,
     MavenPutArtifactRequest, MavenPutArtifactResponse,
+    MavenPutChecksumRequest, MavenPutChecksumResponse,
+    MavenPutMetadataRequest, MavenPutMetadataResponse,
+    MavenGetMetadataRequest, MavenGetMetadataResponse,
+    MavenGetChecksumRequest, MavenGetChecksumResponse,
     MavenHeadArtifactRequest, MavenHeadArtifactResponse,
-    MavenListArtifactsRequest, MavenListArtifactsResponse
 };
 use super::ports::{
-    MavenArtifactReader, MavenArtifactWriter, MavenRepositoryManager, 
+    MavenArtifactReader, MavenArtifactWriter, MavenMetadataStore, MavenRepositoryManager,
     MavenPermissionChecker, MavenReadError, MavenWriteError
 };
 
 /// Use case para obtener un artefacto Maven
 pub struct HandleMavenGetArtifactUseCase {
     artifact_reader: Arc<dyn MavenArtifactReader>,
+    metadata_store: Arc<dyn MavenMetadataStore>,
     repository_manager: Arc<dyn MavenRepositoryManager>,
     permission_checker: Arc<dyn MavenPermissionChecker>,
 }
@@ -29,58 +38,70 @@ pub struct HandleMavenGetArtifactUseCase {
 impl HandleMavenGetArtifactUseCase {
     pub fn new(
         artifact_reader: Arc<dyn MavenArtifactReader>,
+        metadata_store: Arc<dyn MavenMetadataStore>,
         repository_manager: Arc<dyn MavenRepositoryManager>,
         permission_checker: Arc<dyn MavenPermissionChecker>,
     ) -> Self {
         Self {
             artifact_reader,
+            metadata_store,
             repository_manager,
             permission_checker,
         }
     }
-    
+
     #[instrument(skip(self), fields(
-        group_id = %request.coordinates.group_id,
-        artifact_id = %request.coordinates.artifact_id,
-        version = %request.coordinates.version,
+        group_id = %request.file.coordinates.group_id,
+        artifact_id = %request.file.coordinates.artifact_id,
+        version = %request.file.coordinates.version,
         repository_id = %request.repository_id
     ))]
     pub async fn execute(&self, request: MavenGetArtifactRequest) -> Result<MavenGetArtifactResponse, MavenGetError> {
         info!("Processing Maven GET artifact request");
-        
+
         // 1. Validar coordenadas (lógica de negocio pura)
-        validate_maven_coordinates(&request.coordinates)
-            .map_err(|e| MavenGetError::InvalidCoordinates(e))?;
-        
+        validate_maven_coordinates(&request.file.coordinates)
+            .map_err(MavenGetError::InvalidCoordinates)?;
+
         // 2. Verificar que el repositorio existe
         if !self.repository_manager.repository_exists(&request.repository_id).await? {
             error!("Repository not found: {}", request.repository_id);
             return Err(MavenGetError::RepositoryNotFound(request.repository_id));
         }
-        
+
         // 3. Verificar permisos de lectura
-        if !self.permission_checker.can_read("system", &request.repository_id, &request.coordinates).await? {
+        let resource = MavenResource::Artifact(request.file.clone());
+        if !self.permission_checker.can_read(&request.user_id, &request.repository_id, &resource).await? {
             error!("Permission denied for reading artifact");
             return Err(MavenGetError::PermissionDenied);
         }
-        
-        // 4. Verificar que el artefacto existe
-        if !self.artifact_reader.artifact_exists(&request.coordinates, &request.repository_id).await? {
-            error!("Artifact not found: {}", request.coordinates);
-            return Err(MavenGetError::ArtifactNotFound(request.coordinates.to_string()));
-        }
-        
-        // 5. Leer el artefacto
-        let content = self.artifact_reader.read_artifact(&request.coordinates, &request.repository_id).await
-            .map_err(|e| MavenGetError::ReadFailed(e))?;
-        
-        // 6. Leer metadata para headers HTTP
-        let metadata = self.artifact_reader.read_artifact_metadata(&request.coordinates, &request.repository_id).await
-            .map_err(|e| MavenGetError::ReadFailed(e))?;
-        
+
+        // 4. Resolver SNAPSHOT no únicos al último build
+        let file = resolve_snapshot(
+            self.metadata_store.as_ref(),
+            self.artifact_reader.as_ref(),
+            request.file,
+            &request.repository_id,
+        ).await?;
+
+        // 5. Verificar que el artefacto existe
+        if !self.artifact_reader.artifact_exists(&file, &request.repository_id).await? {
+            error!("Artifact not found: {}", file.to_path());
+            return Err(MavenGetError::ArtifactNotFound(file.to_path()));
+        }
+
+        // 6. Leer el artefacto
+        let content = self.artifact_reader.read_artifact(&file, &request.repository_id).await
+            .map_err(MavenGetError::ReadFailed)?;
+
+        // 7. Leer metadata para headers HTTP
+        let metadata = self.artifact_reader.read_artifact_metadata(&file, &request.repository_id).await
+            .map_err(MavenGetError::ReadFailed)?;
+
         info!("Successfully retrieved artifact: {} bytes", content.len());
-        
+
         Ok(MavenGetArtifactResponse {
+            artifact_path: file.to_path(),
             content,
             content_type: metadata.content_type,
             content_length: metadata.content_length,
@@ -90,9 +111,169 @@ impl HandleMavenGetArtifactUseCase {
     }
 }
 
+/// Use case para obtener el `maven-metadata.xml` de un artefacto o de una versión SNAPSHOT
+///
+/// La metadata no se guarda: se genera a partir de las versiones y builds
+/// registrados al subir artefactos.
+pub struct HandleMavenGetMetadataUseCase {
+    metadata_store: Arc<dyn MavenMetadataStore>,
+    repository_manager: Arc<dyn MavenRepositoryManager>,
+    permission_checker: Arc<dyn MavenPermissionChecker>,
+}
+
+impl HandleMavenGetMetadataUseCase {
+    pub fn new(
+        metadata_store: Arc<dyn MavenMetadataStore>,
+        repository_manager: Arc<dyn MavenRepositoryManager>,
+        permission_checker: Arc<dyn MavenPermissionChecker>,
+    ) -> Self {
+        Self {
+            metadata_store,
+            repository_manager,
+            permission_checker,
+        }
+    }
+
+    #[instrument(skip(self), fields(
+        group_id = %request.group_id,
+        artifact_id = %request.artifact_id,
+        version = ?request.version,
+        repository_id = %request.repository_id
+    ))]
+    pub async fn execute(&self, request: MavenGetMetadataRequest) -> Result<MavenGetMetadataResponse, MavenGetError> {
+        info!("Processing Maven GET metadata request");
+
+        if !self.repository_manager.repository_exists(&request.repository_id).await? {
+            error!("Repository not found: {}", request.repository_id);
+            return Err(MavenGetError::RepositoryNotFound(request.repository_id));
+        }
+
+        let resource = MavenResource::Metadata {
+            group_id: request.group_id.clone(),
+            artifact_id: request.artifact_id.clone(),
+            version: request.version.clone(),
+        };
+        if !self.permission_checker.can_read(&request.user_id, &request.repository_id, &resource).await? {
+            error!("Permission denied for reading metadata");
+            return Err(MavenGetError::PermissionDenied);
+        }
+
+        let content = generate_metadata_xml(
+            self.metadata_store.as_ref(),
+            &request.repository_id,
+            &request.group_id,
+            &request.artifact_id,
+            request.version.as_deref(),
+        ).await?
+        .ok_or_else(|| MavenGetError::MetadataNotFound(resource.to_path()))?;
+
+        Ok(MavenGetMetadataResponse {
+            content,
+            content_type: "application/xml".to_string(),
+        })
+    }
+}
+
+/// Use case para obtener el fichero `.sha1` o `.md5` de un artefacto o de su metadata
+pub struct HandleMavenGetChecksumUseCase {
+    artifact_reader: Arc<dyn MavenArtifactReader>,
+    metadata_store: Arc<dyn MavenMetadataStore>,
+    repository_manager: Arc<dyn MavenRepositoryManager>,
+    permission_checker: Arc<dyn MavenPermissionChecker>,
+}
+
+impl HandleMavenGetChecksumUseCase {
+    pub fn new(
+        artifact_reader: Arc<dyn MavenArtifactReader>,
+        metadata_store: Arc<dyn MavenMetadataStore>,
+        repository_manager: Arc<dyn MavenRepositoryManager>,
+        permission_checker: Arc<dyn MavenPermissionChecker>,
+    ) -> Self {
+        Self {
+            artifact_reader,
+            metadata_store,
+            repository_manager,
+            permission_checker,
+        }
+    }
+
+    #[instrument(skip(self), fields(
+        path = %request.resource.to_path(),
+        algorithm = %request.algorithm,
+        repository_id = %request.repository_id
+    ))]
+    pub async fn execute(&self, request: MavenGetChecksumRequest) -> Result<MavenGetChecksumResponse, MavenGetError> {
+        info!("Processing Maven GET checksum request");
+
+        if !self.repository_manager.repository_exists(&request.repository_id).await? {
+            error!("Repository not found: {}", request.repository_id);
+            return Err(MavenGetError::RepositoryNotFound(request.repository_id));
+        }
+
+        if !self.permission_checker.can_read(&request.user_id, &request.repository_id, &request.resource).await? {
+            error!("Permission denied for reading checksum");
+            return Err(MavenGetError::PermissionDenied);
+        }
+
+        let checksum = match request.resource {
+            MavenResource::Artifact(file) => {
+                validate_maven_coordinates(&file.coordinates)
+                    .map_err(MavenGetError::InvalidCoordinates)?;
+                let file = resolve_snapshot(
+                    self.metadata_store.as_ref(),
+                    self.artifact_reader.as_ref(),
+                    file,
+                    &request.repository_id,
+                ).await?;
+                self.artifact_checksum(&file, request.algorithm, &request.repository_id).await?
+            }
+            MavenResource::Metadata { group_id, artifact_id, version } => {
+                // La metadata se genera en cada petición, así que su checksum también
+                let content = generate_metadata_xml(
+                    self.metadata_store.as_ref(),
+                    &request.repository_id,
+                    &group_id,
+                    &artifact_id,
+                    version.as_deref(),
+                ).await?
+                .ok_or_else(|| MavenGetError::MetadataNotFound(format!("{}:{}", group_id, artifact_id)))?;
+                request.algorithm.compute(content.as_bytes())
+            }
+        };
+
+        Ok(MavenGetChecksumResponse {
+            checksum,
+            algorithm: request.algorithm.to_string(),
+        })
+    }
+
+    /// El checksum guardado al subir el artefacto, o calculado si no se guardó
+    async fn artifact_checksum(
+        &self,
+        file: &MavenArtifactFile,
+        algorithm: ChecksumAlgorithm,
+        repository_id: &str,
+    ) -> Result<String, MavenGetError> {
+        match self.artifact_reader.read_checksum(file, algorithm, repository_id).await {
+            Ok(checksum) => Ok(checksum),
+            Err(MavenReadError::NotFound(_)) => {
+                if !self.artifact_reader.artifact_exists(file, repository_id).await? {
+                    return Err(MavenGetError::ArtifactNotFound(file.to_path()));
+                }
+                warn!("No stored {} checksum for {}, computing it", algorithm, file.to_path());
+                let content = self.artifact_reader.read_artifact(file, repository_id).await
+                    .map_err(MavenGetError::ReadFailed)?;
+                Ok(algorithm.compute(&content))
+            }
+            Err(e) => Err(MavenGetError::ReadFailed(e)),
+        }
+    }
+}
+
 /// Use case para subir un artefacto Maven
 pub struct HandleMavenPutArtifactUseCase {
     artifact_writer: Arc<dyn MavenArtifactWriter>,
+    metadata_store: Arc<dyn MavenMetadataStore>,
     repository_manager: Arc<dyn MavenRepositoryManager>,
     permission_checker: Arc<dyn MavenPermissionChecker>,
 }
@@ -100,76 +281,119 @@ pub struct HandleMavenPutArtifactUseCase {
 impl HandleMavenPutArtifactUseCase {
     pub fn new(
         artifact_writer: Arc<dyn MavenArtifactWriter>,
+        metadata_store: Arc<dyn MavenMetadataStore>,
         repository_manager: Arc<dyn MavenRepositoryManager>,
         permission_checker: Arc<dyn MavenPermissionChecker>,
     ) -> Self {
         Self {
             artifact_writer,
+            metadata_store,
             repository_manager,
             permission_checker,
         }
     }
-    
+
     #[instrument(skip(self), fields(
-        group_id = %request.coordinates.group_id,
-        artifact_id = %request.coordinates.artifact_id,
-        version = %request.coordinates.version,
+        group_id = %request.file.coordinates.group_id,
+        artifact_id = %request.file.coordinates.artifact_id,
+        version = %request.file.coordinates.version,
         repository_id = %request.repository_id,
         content_length = request.content.len()
     ))]
     pub async fn execute(&self, request: MavenPutArtifactRequest) -> Result<MavenPutArtifactResponse, MavenPutError> {
         info!("Processing Maven PUT artifact request");
-        
+
+        let coordinates = &request.file.coordinates;
+
         // 1. Validar coordenadas (lógica de negocio pura)
-        validate_maven_coordinates(&request.coordinates)
-            .map_err(|e| MavenPutError::InvalidCoordinates(e))?;
-        
+        validate_maven_coordinates(coordinates)
+            .map_err(MavenPutError::InvalidCoordinates)?;
+
         // 2. Validar content type
         self.validate_content_type(&request.content_type)?;
-        
+
         // 3. Verificar que el repositorio existe
         if !self.repository_manager.repository_exists(&request.repository_id).await? {
             error!("Repository not found: {}", request.repository_id);
             return Err(MavenPutError::RepositoryNotFound(request.repository_id));
         }
-        
+
         // 4. Verificar permisos de escritura
-        if !self.permission_checker.can_write("system", &request.repository_id, &request.coordinates).await? {
+        let resource = MavenResource::Artifact(request.file.clone());
+        if !self.permission_checker.can_write(&request.user_id, &request.repository_id, &resource).await? {
             error!("Permission denied for writing artifact");
             return Err(MavenPutError::PermissionDenied);
         }
-        
+
         // 5. Verificar políticas del repositorio (ej: snapshots permitidos)
         let repo_info = self.repository_manager.get_repository_info(&request.repository_id).await?;
-        if request.coordinates.is_snapshot() && !repo_info.allow_snapshots {
+        if coordinates.is_snapshot() && !repo_info.allow_snapshots {
             error!("Snapshots not allowed in repository: {}", request.repository_id);
             return Err(MavenPutError::SnapshotsNotAllowed);
         }
-        
-        if request.coordinates.is_release() && !repo_info.allow_releases {
+
+        if coordinates.is_release() && !repo_info.allow_releases {
             error!("Releases not allowed in repository: {}", request.repository_id);
             return Err(MavenPutError::ReleasesNotAllowed);
         }
-        
-        // 6. Escribir el artefacto
+
+        // 6. Un SNAPSHOT subido con su nombre no único pasa a ser un build nuevo
+        let file = if request.file.is_unresolved_snapshot() {
+            let metadata = snapshot_metadata(
+                self.metadata_store.as_ref(),
+                &request.repository_id,
+                &coordinates.group_id,
+                &coordinates.artifact_id,
+                &coordinates.version,
+            ).await?;
+            let build = SnapshotBuild::next(
+                metadata.latest_build().as_ref(),
+                OffsetDateTime::now_utc(),
+            );
+            debug!("Assigning SNAPSHOT build {}", build.file_version(&coordinates.version));
+            request.file.clone().with_build(build)
+        } else {
+            request.file.clone()
+        };
+
+        // 7. Escribir el artefacto
         self.artifact_writer.write_artifact(
-            &request.coordinates,
+            &file,
             &request.content,
             &request.repository_id,
             request.overwrite
         ).await
-        .map_err(|e| MavenPutError::WriteFailed(e))?;
-        
+        .map_err(MavenPutError::WriteFailed)?;
+
+        // 8. Guardar los checksums calculados por el servidor
+        let sha1 = ChecksumAlgorithm::Sha1.compute(&request.content);
+        let md5 = ChecksumAlgorithm::Md5.compute(&request.content);
+        self.artifact_writer.write_checksum(&file, ChecksumAlgorithm::Sha1, &sha1, &request.repository_id).await?;
+        self.artifact_writer.write_checksum(&file, ChecksumAlgorithm::Md5, &md5, &request.repository_id).await?;
+
+        // 9. Registrar la versión y el build para generar maven-metadata.xml
+        self.metadata_store.record_version(&request.repository_id, &file.coordinates).await?;
+        if let Some(build) = file.snapshot_build {
+            let snapshot_file = SnapshotFile {
+                classifier: file.coordinates.classifier.clone(),
+                extension: file.coordinates.extension.clone(),
+                build,
+            };
+            self.metadata_store.record_snapshot_file(&request.repository_id, &file.coordinates, &snapshot_file).await?;
+        }
+
         info!("Successfully uploaded artifact: {} bytes", request.content.len());
-        
+
         Ok(MavenPutArtifactResponse {
             success: true,
-            message: format!("Artifact {} uploaded successfully", request.coordinates),
-            artifact_path: request.coordinates.to_path(),
+            message: format!("Artifact {} uploaded successfully", file.coordinates),
+            artifact_path: file.to_path(),
             size_bytes: request.content.len(),
+            sha1,
+            md5,
         })
     }
-    
+
     fn validate_content_type(&self, content_type: &str) -> Result<(), MavenPutError> {
         let valid_types = [
             "application/java-archive",
@@ -177,19 +401,139 @@ impl HandleMavenPutArtifactUseCase {
             "text/xml",
             "application/octet-stream",
         ];
-        
+
         if !valid_types.contains(&content_type) {
             error!("Invalid content type: {}", content_type);
             return Err(MavenPutError::InvalidContentType(content_type.to_string()));
         }
-        
+
         Ok(())
     }
 }
 
+/// Use case para subir el checksum de un artefacto
+///
+/// Los clientes Maven suben `.sha1` y `.md5` después del artefacto. Se
+/// comprueban contra el contenido ya guardado, para detectar subidas corruptas.
+pub struct HandleMavenPutChecksumUseCase {
+    artifact_reader: Arc<dyn MavenArtifactReader>,
+    repository_manager: Arc<dyn MavenRepositoryManager>,
+    permission_checker: Arc<dyn MavenPermissionChecker>,
+}
+
+impl HandleMavenPutChecksumUseCase {
+    pub fn new(
+        artifact_reader: Arc<dyn MavenArtifactReader>,
+        repository_manager: Arc<dyn MavenRepositoryManager>,
+        permission_checker: Arc<dyn MavenPermissionChecker>,
+    ) -> Self {
+        Self {
+            artifact_reader,
+            repository_manager,
+            permission_checker,
+        }
+    }
+
+    #[instrument(skip(self), fields(
+        path = %request.file.to_path(),
+        algorithm = %request.algorithm,
+        repository_id = %request.repository_id
+    ))]
+    pub async fn execute(&self, request: MavenPutChecksumRequest) -> Result<MavenPutChecksumResponse, MavenPutError> {
+        info!("Processing Maven PUT checksum request");
+
+        validate_maven_coordinates(&request.file.coordinates)
+            .map_err(MavenPutError::InvalidCoordinates)?;
+
+        if !self.repository_manager.repository_exists(&request.repository_id).await? {
+            error!("Repository not found: {}", request.repository_id);
+            return Err(MavenPutError::RepositoryNotFound(request.repository_id));
+        }
+
+        let resource = MavenResource::Artifact(request.file.clone());
+        if !self.permission_checker.can_write(&request.user_id, &request.repository_id, &resource).await? {
+            error!("Permission denied for writing checksum");
+            return Err(MavenPutError::PermissionDenied);
+        }
+
+        let checksum_path = format!("{}.{}", request.file.to_path(), request.algorithm.extension());
+        let content = match self.artifact_reader.read_artifact(&request.file, &request.repository_id).await {
+            Ok(content) => content,
+            Err(MavenReadError::NotFound(_)) => {
+                warn!("Checksum uploaded before its artifact: {}", checksum_path);
+                return Err(MavenPutError::ArtifactNotFound(request.file.to_path()));
+            }
+            Err(e) => return Err(MavenPutError::ReadFailed(e)),
+        };
+
+        if !request.algorithm.matches(&request.checksum, &content) {
+            let expected = request.algorithm.compute(&content);
+            error!("Checksum mismatch for {}: expected {}", request.file.to_path(), expected);
+            return Err(MavenPutError::ChecksumMismatch {
+                path: checksum_path,
+                expected,
+                actual: request.checksum.trim().to_string(),
+            });
+        }
+
+        info!("Checksum verified: {}", checksum_path);
+
+        Ok(MavenPutChecksumResponse {
+            verified: true,
+            checksum_path,
+        })
+    }
+}
+
+/// Use case para subir un `maven-metadata.xml`
+///
+/// `mvn deploy` sube su propia metadata tras cada artefacto. El servidor la
+/// genera a partir de lo desplegado, así que solo se comprueban permisos y se
+/// acepta la subida sin guardarla.
+pub struct HandleMavenPutMetadataUseCase {
+    repository_manager: Arc<dyn MavenRepositoryManager>,
+    permission_checker: Arc<dyn MavenPermissionChecker>,
+}
+
+impl HandleMavenPutMetadataUseCase {
+    pub fn new(
+        repository_manager: Arc<dyn MavenRepositoryManager>,
+        permission_checker: Arc<dyn MavenPermissionChecker>,
+    ) -> Self {
+        Self {
+            repository_manager,
+            permission_checker,
+        }
+    }
+
+    #[instrument(skip(self), fields(
+        path = %request.resource.to_path(),
+        repository_id = %request.repository_id
+    ))]
+    pub async fn execute(&self, request: MavenPutMetadataRequest) -> Result<MavenPutMetadataResponse, MavenPutError> {
+        if !self.repository_manager.repository_exists(&request.repository_id).await? {
+            error!("Repository not found: {}", request.repository_id);
+            return Err(MavenPutError::RepositoryNotFound(request.repository_id));
+        }
+
+        if !self.permission_checker.can_write(&request.user_id, &request.repository_id, &request.resource).await? {
+            error!("Permission denied for writing metadata");
+            return Err(MavenPutError::PermissionDenied);
+        }
+
+        debug!("Ignoring client metadata, it is generated by the server");
+
+        Ok(MavenPutMetadataResponse {
+            accepted: true,
+            message: "maven-metadata.xml is generated by the server".to_string(),
+        })
+    }
+}
+
 /// Use case para verificar la existencia de un artefacto (HEAD request)
 pub struct HandleMavenHeadArtifactUseCase {
     artifact_reader: Arc<dyn MavenArtifactReader>,
+    metadata_store: Arc<dyn MavenMetadataStore>,
     repository_manager: Arc<dyn MavenRepositoryManager>,
     permission_checker: Arc<dyn MavenPermissionChecker>,
 }
@@ -197,47 +541,56 @@ pub struct HandleMavenHeadArtifactUseCase {
 impl HandleMavenHeadArtifactUseCase {
     pub fn new(
         artifact_reader: Arc<dyn MavenArtifactReader>,
+        metadata_store: Arc<dyn MavenMetadataStore>,
         repository_manager: Arc<dyn MavenRepositoryManager>,
         permission_checker: Arc<dyn MavenPermissionChecker>,
     ) -> Self {
         Self {
             artifact_reader,
+            metadata_store,
             repository_manager,
             permission_checker,
         }
     }
-    
+
     #[instrument(skip(self), fields(
-        group_id = %request.coordinates.group_id,
-        artifact_id = %request.coordinates.artifact_id,
-        version = %request.coordinates.version,
+        group_id = %request.file.coordinates.group_id,
+        artifact_id = %request.file.coordinates.artifact_id,
+        version = %request.file.coordinates.version,
         repository_id = %request.repository_id
     ))]
     pub async fn execute(&self, request: MavenHeadArtifactRequest) -> Result<MavenHeadArtifactResponse, MavenHeadError> {
         info!("Processing Maven HEAD artifact request");
-        
+
         // 1. Validar coordenadas
-        validate_maven_coordinates(&request.coordinates)
-            .map_err(|e| MavenHeadError::InvalidCoordinates(e))?;
-        
+        validate_maven_coordinates(&request.file.coordinates)
+            .map_err(MavenHeadError::InvalidCoordinates)?;
+
         // 2. Verificar que el repositorio existe
         if !self.repository_manager.repository_exists(&request.repository_id).await? {
             return Err(MavenHeadError::RepositoryNotFound(request.repository_id));
         }
-        
+
         // 3. Verificar permisos de lectura
-        if !self.permission_checker.can_read("system", &request.repository_id, &request.coordinates).await? {
+        let resource = MavenResource::Artifact(request.file.clone());
+        if !self.permission_checker.can_read(&request.user_id, &request.repository_id, &resource).await? {
             return Err(MavenHeadError::PermissionDenied);
         }
-        
-        // 4. Verificar existencia del artefacto
-        let exists = self.artifact_reader.artifact_exists(&request.coordinates, &request.repository_id).await?;
-        
+
+        // 4. Verificar existencia del artefacto, resolviendo SNAPSHOT no únicos
+        let file = resolve_snapshot(
+            self.metadata_store.as_ref(),
+            self.artifact_reader.as_ref(),
+            request.file,
+            &request.repository_id,
+        ).await?;
+        let exists = self.artifact_reader.artifact_exists(&file, &request.repository_id).await?;
+
         if exists {
             // Obtener metadata para headers
-            let metadata = self.artifact_reader.read_artifact_metadata(&request.coordinates, &request.repository_id).await
-                .map_err(|e| MavenHeadError::ReadFailed(e))?;
-            
+            let metadata = self.artifact_reader.read_artifact_metadata(&file, &request.repository_id).await
+                .map_err(MavenHeadError::ReadFailed)?;
+
             Ok(MavenHeadArtifactResponse {
                 exists: true,
                 content_length: Some(metadata.content_length),
@@ -255,15 +608,97 @@ impl HandleMavenHeadArtifactUseCase {
     }
 }
 
+/// Resolver un SNAPSHOT pedido por su nombre no único al fichero de su último build
+///
+/// Si no hay builds registrados, o el último no está en el almacenamiento, se
+/// devuelve el fichero tal cual, por si se subió con el nombre no único.
+async fn resolve_snapshot(
+    metadata_store: &dyn MavenMetadataStore,
+    artifact_reader: &dyn MavenArtifactReader,
+    file: MavenArtifactFile,
+    repository_id: &str,
+) -> Result<MavenArtifactFile, MavenReadError> {
+    if !file.is_unresolved_snapshot() {
+        return Ok(file);
+    }
+
+    let coordinates = &file.coordinates;
+    let metadata = snapshot_metadata(
+        metadata_store,
+        repository_id,
+        &coordinates.group_id,
+        &coordinates.artifact_id,
+        &coordinates.version,
+    ).await?;
+
+    let Some(build) = metadata.latest_build_for(coordinates.classifier.as_deref(), &coordinates.extension) else {
+        return Ok(file);
+    };
+    let resolved = file.clone().with_build(build);
+    if artifact_reader.artifact_exists(&resolved, repository_id).await? {
+        debug!("Resolved {} to {}", file.to_path(), resolved.to_path());
+        Ok(resolved)
+    } else {
+        warn!("Latest SNAPSHOT build missing from storage: {}", resolved.to_path());
+        Ok(file)
+    }
+}
+
+/// La metadata de una versión SNAPSHOT, con el último build de cada fichero
+async fn snapshot_metadata(
+    metadata_store: &dyn MavenMetadataStore,
+    repository_id: &str,
+    group_id: &str,
+    artifact_id: &str,
+    version: &str,
+) -> Result<SnapshotMetadata, MavenReadError> {
+    let mut metadata = SnapshotMetadata::new(
+        group_id.to_string(),
+        artifact_id.to_string(),
+        version.to_string(),
+    );
+    for file in metadata_store.list_snapshot_files(repository_id, group_id, artifact_id, version).await? {
+        metadata.add_file(file);
+    }
+    Ok(metadata)
+}
+
+/// Generar `maven-metadata.xml`; `None` si no hay nada desplegado
+async fn generate_metadata_xml(
+    metadata_store: &dyn MavenMetadataStore,
+    repository_id: &str,
+    group_id: &str,
+    artifact_id: &str,
+    version: Option<&str>,
+) -> Result<Option<String>, MavenReadError> {
+    match version {
+        Some(version) => {
+            let metadata = snapshot_metadata(metadata_store, repository_id, group_id, artifact_id, version).await?;
+            Ok((!metadata.files.is_empty()).then(|| metadata.to_xml()))
+        }
+        None => {
+            let versions = metadata_store.list_versions(repository_id, group_id, artifact_id).await?;
+            if versions.is_empty() {
+                return Ok(None);
+            }
+            let mut metadata = MavenMetadata::new(group_id.to_string(), artifact_id.to_string());
+            metadata.add_versions(versions);
+            Ok(Some(metadata.to_xml()))
+        }
+    }
+}
+
 /// Errores específicos del feature Handle Maven Request
 #[derive(Debug, thiserror::Error)]
 pub enum MavenGetError {
     #[error("Invalid coordinates: {0}")]
-    InvalidCoordinates(crate::domain::maven::coordinates::MavenValidationError),
+    InvalidCoordinates(MavenValidationError),
     #[error("Repository not found: {0}")]
     RepositoryNotFound(String),
     #[error("Artifact not found: {0}")]
     ArtifactNotFound(String),
+    #[error("Metadata not found: {0}")]
+    MetadataNotFound(String),
     #[error("Permission denied")]
     PermissionDenied,
     #[error("Read failed: {0}")]
@@ -273,7 +708,7 @@ pub enum MavenGetError {
 #[derive(Debug, thiserror::Error)]
 pub enum MavenPutError {
     #[error("Invalid coordinates: {0}")]
-    InvalidCoordinates(crate::domain::maven::coordinates::MavenValidationError),
+    InvalidCoordinates(MavenValidationError),
     #[error("Invalid content type: {0}")]
     InvalidContentType(String),
     #[error("Repository not found: {0}")]
@@ -284,6 +719,16 @@ pub enum MavenPutError {
     SnapshotsNotAllowed,
     #[error("Releases not allowed in repository")]
     ReleasesNotAllowed,
+    #[error("Artifact not found: {0}")]
+    ArtifactNotFound(String),
+    #[error("Checksum mismatch for {path}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("Read failed: {0}")]
+    ReadFailed(MavenReadError),
     #[error("Write failed: {0}")]
     WriteFailed(MavenWriteError),
 }
@@ -291,7 +736,7 @@ pub enum MavenPutError {
 #[derive(Debug, thiserror::Error)]
 pub enum MavenHeadError {
     #[error("Invalid coordinates: {0}")]
-    InvalidCoordinates(crate::domain::maven::coordinates::MavenValidationError),
+    InvalidCoordinates(MavenValidationError),
     #[error("Repository not found: {0}")]
     RepositoryNotFound(String),
     #[error("Permission denied")]
@@ -313,6 +758,12 @@ impl From<MavenReadError> for MavenHeadError {
     }
 }
 
+impl From<MavenReadError> for MavenPutError {
+    fn from(error: MavenReadError) -> Self {
+        MavenPutError::ReadFailed(error)
+    }
+}
+
 impl From<MavenWriteError> for MavenPutError {
     fn from(error: MavenWriteError) -> Self {
         MavenPutError::WriteFailed(error)
@@ -322,71 +773,216 @@ impl From<MavenWriteError> for MavenPutError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::ports;
     use crate::domain::maven::coordinates::MavenCoordinates;
-    
+    use time::macros::datetime;
+
+    fn release_file() -> MavenArtifactFile {
+        MavenArtifactFile::new(MavenCoordinates::new("com.example", "my-app", "1.0.0").unwrap())
+    }
+
+    fn snapshot_file() -> MavenArtifactFile {
+        MavenArtifactFile::new(MavenCoordinates::new("com.example", "my-app", "1.0-SNAPSHOT").unwrap())
+    }
+
     #[tokio::test]
     async fn test_get_artifact_use_case_success() {
         // Arrange
         let artifact_reader = Arc::new(ports::test::MockMavenArtifactReader::new());
+        let metadata_store = Arc::new(ports::test::MockMavenMetadataStore::new());
         let repository_manager = Arc::new(ports::test::MockMavenRepositoryManager::new());
         let permission_checker = Arc::new(ports::test::MockMavenPermissionChecker::new());
-        
-        let coordinates = MavenCoordinates::new("com.example", "my-app", "1.0.0").unwrap();
+
+        let file = release_file();
         let content = b"test jar content".to_vec();
-        artifact_reader.add_artifact(&coordinates, "test-repo", content.clone());
-        
+        artifact_reader.add_artifact(&file, "test-repo", content.clone());
+
         let use_case = HandleMavenGetArtifactUseCase::new(
             artifact_reader,
+            metadata_store,
             repository_manager,
             permission_checker,
         );
-        
+
         let request = MavenGetArtifactRequest {
-            coordinates,
+            file,
             repository_id: "test-repo".to_string(),
+            user_id: "test-user".to_string(),
         };
-        
+
         // Act
         let result = use_case.execute(request).await;
-        
+
         // Assert
         assert!(result.is_ok());
         let response = result.unwrap();
         assert_eq!(response.content, content);
         assert_eq!(response.content_length, content.len());
     }
-    
+
     #[tokio::test]
     async fn test_put_artifact_use_case_success() {
         // Arrange
         let artifact_writer = Arc::new(ports::test::MockMavenArtifactWriter::new());
+        let metadata_store = Arc::new(ports::test::MockMavenMetadataStore::new());
         let repository_manager = Arc::new(ports::test::MockMavenRepositoryManager::new());
         let permission_checker = Arc::new(ports::test::MockMavenPermissionChecker::new());
-        
+
         let use_case = HandleMavenPutArtifactUseCase::new(
-            artifact_writer,
+            artifact_writer.clone(),
+            metadata_store.clone(),
             repository_manager,
             permission_checker,
         );
-        
-        let coordinates = MavenCoordinates::new("com.example", "my-app", "1.0.0").unwrap();
+
         let content = b"test jar content".to_vec();
-        
+
         let request = MavenPutArtifactRequest {
-            coordinates: coordinates.clone(),
+            file: release_file(),
             content: content.clone(),
             content_type: "application/java-archive".to_string(),
             repository_id: "test-repo".to_string(),
+            user_id: "test-user".to_string(),
             overwrite: false,
         };
-        
+
         // Act
         let result = use_case.execute(request).await;
-        
+
         // Assert
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.success);
         assert_eq!(response.size_bytes, content.len());
+        assert_eq!(response.sha1, ChecksumAlgorithm::Sha1.compute(&content));
+
+        let stored_md5 = artifact_writer.checksums.lock().unwrap()
+            .get("test-repo:com/example/my-app/1.0.0/my-app-1.0.0.jar.md5")
+            .cloned();
+        assert_eq!(stored_md5, Some(response.md5));
+        let versions = metadata_store.list_versions("test-repo", "com.example", "my-app").await.unwrap();
+        assert_eq!(versions.len(), 1);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_put_snapshot_assigns_next_build() {
+        let metadata_store = Arc::new(ports::test::MockMavenMetadataStore::new());
+        let previous = SnapshotBuild::new(datetime!(2024-01-01 12:00:00 UTC), 3);
+        metadata_store.record_snapshot_file(
+            "test-repo",
+            &snapshot_file().coordinates,
+            &SnapshotFile { classifier: None, extension: "jar".to_string(), build: previous },
+        ).await.unwrap();
+
+        let use_case = HandleMavenPutArtifactUseCase::new(
+            Arc::new(ports::test::MockMavenArtifactWriter::new()),
+            metadata_store.clone(),
+            Arc::new(ports::test::MockMavenRepositoryManager::new()),
+            Arc::new(ports::test::MockMavenPermissionChecker::new()),
+        );
+
+        let response = use_case.execute(MavenPutArtifactRequest {
+            file: snapshot_file(),
+            content: b"snapshot".to_vec(),
+            content_type: "application/java-archive".to_string(),
+            repository_id: "test-repo".to_string(),
+            user_id: "test-user".to_string(),
+            overwrite: false,
+        }).await.unwrap();
+
+        assert!(response.artifact_path.ends_with("-4.jar"));
+        let files = metadata_store.list_snapshot_files("test-repo", "com.example", "my-app", "1.0-SNAPSHOT").await.unwrap();
+        assert_eq!(files.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_non_unique_snapshot_serves_latest_build() {
+        let artifact_reader = Arc::new(ports::test::MockMavenArtifactReader::new());
+        let metadata_store = Arc::new(ports::test::MockMavenMetadataStore::new());
+
+        for (number, content) in [(1, b"first".to_vec()), (2, b"second".to_vec())] {
+            let build = SnapshotBuild::new(datetime!(2024-01-01 12:00:00 UTC), number);
+            artifact_reader.add_artifact(&snapshot_file().with_build(build), "test-repo", content);
+            metadata_store.record_snapshot_file(
+                "test-repo",
+                &snapshot_file().coordinates,
+                &SnapshotFile { classifier: None, extension: "jar".to_string(), build },
+            ).await.unwrap();
+        }
+
+        let use_case = HandleMavenGetArtifactUseCase::new(
+            artifact_reader,
+            metadata_store,
+            Arc::new(ports::test::MockMavenRepositoryManager::new()),
+            Arc::new(ports::test::MockMavenPermissionChecker::new()),
+        );
+
+        let response = use_case.execute(MavenGetArtifactRequest {
+            file: snapshot_file(),
+            repository_id: "test-repo".to_string(),
+            user_id: "test-user".to_string(),
+        }).await.unwrap();
+
+        assert_eq!(response.content, b"second");
+        assert_eq!(
+            response.artifact_path,
+            "com/example/my-app/1.0-SNAPSHOT/my-app-1.0-20240101.120000-2.jar"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_put_checksum_rejects_mismatch() {
+        let artifact_reader = Arc::new(ports::test::MockMavenArtifactReader::new());
+        artifact_reader.add_artifact(&release_file(), "test-repo", b"test jar content".to_vec());
+
+        let use_case = HandleMavenPutChecksumUseCase::new(
+            artifact_reader,
+            Arc::new(ports::test::MockMavenRepositoryManager::new()),
+            Arc::new(ports::test::MockMavenPermissionChecker::new()),
+        );
+
+        let request = |checksum: String| MavenPutChecksumRequest {
+            file: release_file(),
+            algorithm: ChecksumAlgorithm::Sha1,
+            checksum,
+            repository_id: "test-repo".to_string(),
+            user_id: "test-user".to_string(),
+        };
+
+        let valid = ChecksumAlgorithm::Sha1.compute(b"test jar content");
+        assert!(use_case.execute(request(valid)).await.unwrap().verified);
+
+        let result = use_case.execute(request("0".repeat(40))).await;
+        assert!(matches!(result, Err(MavenPutError::ChecksumMismatch { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_get_metadata_lists_recorded_versions() {
+        let metadata_store = Arc::new(ports::test::MockMavenMetadataStore::new());
+        for version in ["1.0.0", "1.1.0"] {
+            let coordinates = MavenCoordinates::new("com.example", "my-app", version).unwrap();
+            metadata_store.record_version("test-repo", &coordinates).await.unwrap();
+        }
+
+        let use_case = HandleMavenGetMetadataUseCase::new(
+            metadata_store,
+            Arc::new(ports::test::MockMavenRepositoryManager::new()),
+            Arc::new(ports::test::MockMavenPermissionChecker::new()),
+        );
+
+        let request = |artifact_id: &str| MavenGetMetadataRequest {
+            group_id: "com.example".to_string(),
+            artifact_id: artifact_id.to_string(),
+            version: None,
+            repository_id: "test-repo".to_string(),
+            user_id: "test-user".to_string(),
+        };
+
+        let response = use_case.execute(request("my-app")).await.unwrap();
+        assert!(response.content.contains("<version>1.0.0</version>"));
+        assert!(response.content.contains("<version>1.1.0</version>"));
+
+        let result = use_case.execute(request("other-app")).await;
+        assert!(matches!(result, Err(MavenGetError::MetadataNotFound(_))));
+    }
+}
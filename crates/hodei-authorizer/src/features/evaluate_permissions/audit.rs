@@ -0,0 +1,201 @@
+//! Audit trail for authorization decisions
+//!
+//! Every decision made by [`EvaluatePermissionsUseCase`](super::EvaluatePermissionsUseCase)
+//! can be published as an [`AuthorizationEvaluated`] domain event, which the
+//! kernel's generic audit handler stores like any other event. Publishing goes
+//! through an [`AuthorizationAuditPublisher`] that hands the event off without
+//! waiting, so auditing never adds latency to the response.
+//!
+//! Allow decisions vastly outnumber denies, so [`DecisionAuditMode`] controls
+//! which decisions are recorded; by default only denies are.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use kernel::Hrn;
+use kernel::application::ports::event_bus::{DomainEvent, EventPublisher};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::dto::{AuthorizationDecision, AuthorizationRequest, AuthorizationResponse};
+use super::ports::AuthorizationAuditPublisher;
+
+/// Which authorization decisions are published to the audit trail
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionAuditMode {
+    /// Nothing is published
+    Disabled,
+    /// Only deny decisions are published
+    #[default]
+    DeniesOnly,
+    /// Every decision is published
+    All,
+}
+
+impl DecisionAuditMode {
+    pub fn should_audit(&self, decision: &AuthorizationDecision) -> bool {
+        match self {
+            DecisionAuditMode::Disabled => false,
+            DecisionAuditMode::DeniesOnly => *decision == AuthorizationDecision::Deny,
+            DecisionAuditMode::All => true,
+        }
+    }
+}
+
+/// Event emitted when an authorization decision has been made
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationEvaluated {
+    /// HRN of the principal that requested access
+    pub principal_hrn: Hrn,
+    /// Action that was requested
+    pub action: String,
+    /// HRN of the resource that was accessed
+    pub resource_hrn: Hrn,
+    /// Outcome of the evaluation
+    pub decision: AuthorizationDecision,
+    /// Identifiers of the policies that determined the decision
+    pub determining_policies: Vec<String>,
+    /// Annotations of the determining policies, keyed by policy identifier
+    pub policy_annotations: HashMap<String, HashMap<String, String>>,
+    /// Reason given for the decision
+    pub reason: String,
+    /// Whether SCPs were skipped because the organization boundary was unavailable
+    pub degraded: bool,
+    /// Whether the decision was served from the decision cache
+    pub from_cache: bool,
+    /// Time taken to reach the decision, in milliseconds
+    pub latency_ms: u64,
+    /// Timestamp when the decision was made
+    #[serde(with = "time::serde::rfc3339")]
+    pub evaluated_at: time::OffsetDateTime,
+}
+
+impl AuthorizationEvaluated {
+    pub fn new(
+        request: &AuthorizationRequest,
+        response: &AuthorizationResponse,
+        latency_ms: u64,
+        from_cache: bool,
+    ) -> Self {
+        Self {
+            principal_hrn: request.principal.clone(),
+            action: request.action.clone(),
+            resource_hrn: request.resource.clone(),
+            decision: response.decision.clone(),
+            determining_policies: response.determining_policies.clone(),
+            policy_annotations: response.policy_annotations.clone(),
+            reason: response.reason.clone(),
+            degraded: response.degraded,
+            from_cache,
+            latency_ms,
+            evaluated_at: time::OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+impl DomainEvent for AuthorizationEvaluated {
+    fn event_type(&self) -> &'static str {
+        "authorizer.authorization.evaluated"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.principal_hrn.to_string())
+    }
+}
+
+/// Audit publisher that forwards events to an event bus on a spawned task
+pub struct EventBusAuditPublisher<P> {
+    publisher: Arc<P>,
+}
+
+impl<P> EventBusAuditPublisher<P> {
+    pub fn new(publisher: Arc<P>) -> Self {
+        Self { publisher }
+    }
+}
+
+impl<P: EventPublisher + 'static> AuthorizationAuditPublisher for EventBusAuditPublisher<P> {
+    fn publish(&self, event: AuthorizationEvaluated) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            warn!(
+                principal = %event.principal_hrn,
+                action = %event.action,
+                "No async runtime available, authorization audit event dropped"
+            );
+            return;
+        };
+
+        let publisher = self.publisher.clone();
+        runtime.spawn(async move {
+            if let Err(e) = publisher.publish(event).await {
+                warn!(error = %e, "Failed to publish authorization audit event");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kernel::InMemoryEventBus;
+    use kernel::application::ports::event_bus::EventBus;
+    use kernel::infrastructure::{AuditEventHandler, AuditLogStore};
+    use std::time::Duration;
+
+    fn hrn(resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "aws".to_string(),
+            "test".to_string(),
+            "default".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    #[test]
+    fn audit_mode_filters_decisions() {
+        let allow = AuthorizationDecision::Allow;
+        let deny = AuthorizationDecision::Deny;
+
+        assert!(!DecisionAuditMode::Disabled.should_audit(&deny));
+        assert!(DecisionAuditMode::DeniesOnly.should_audit(&deny));
+        assert!(!DecisionAuditMode::DeniesOnly.should_audit(&allow));
+        assert!(DecisionAuditMode::All.should_audit(&allow));
+    }
+
+    #[tokio::test]
+    async fn events_reach_the_audit_log() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let store = Arc::new(AuditLogStore::new());
+        let _subscription = bus
+            .subscribe::<AuthorizationEvaluated, _>(Arc::new(AuditEventHandler::new(store.clone())))
+            .await
+            .unwrap();
+
+        let request = AuthorizationRequest::new(
+            hrn("user", "alice"),
+            "delete".to_string(),
+            hrn("bucket", "artifacts"),
+        );
+        let mut response =
+            AuthorizationResponse::deny(vec!["policy-1".to_string()], "Denied by IAM".to_string());
+        response.policy_annotations = HashMap::from([(
+            "policy-1".to_string(),
+            HashMap::from([("ticket".to_string(), "SEC-1234".to_string())]),
+        )]);
+        EventBusAuditPublisher::new(bus.clone())
+            .publish(AuthorizationEvaluated::new(&request, &response, 3, false));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let logs = store.all().await;
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].event_type, "authorizer.authorization.evaluated");
+        assert_eq!(logs[0].event_data["decision"], "Deny");
+        assert_eq!(logs[0].event_data["determining_policies"][0], "policy-1");
+        assert_eq!(
+            logs[0].event_data["policy_annotations"]["policy-1"]["ticket"],
+            "SEC-1234"
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Circuit breaker for cross-context evaluator calls
+//!
+//! The IAM and SCP evaluators call into other bounded contexts. When one of
+//! them becomes slow or unavailable, waiting on every request stalls the whole
+//! authorizer. The breaker counts consecutive failures (including calls that
+//! time out or exceed the slow-call threshold); once the threshold is reached
+//! it opens and rejects calls immediately. After `open_duration` a single
+//! probe call is let through (half-open): success closes the breaker, failure
+//! opens it again.
+//!
+//! Rejected calls surface as evaluator errors, so for SCPs the configured
+//! [`ScpFailurePolicy`](super::ScpFailurePolicy) decides the outcome.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use kernel::application::ports::authorization::{
+    AuthorizationError, EvaluationDecision, EvaluationRequest, IamPolicyEvaluator, ScpEvaluator,
+};
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// Circuit breaker settings
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the breaker
+    pub failure_threshold: u32,
+    /// Calls slower than this count as failures even if they succeed
+    pub slow_call_threshold: Duration,
+    /// Calls are abandoned (and count as failures) after this long
+    pub call_timeout: Duration,
+    /// How long the breaker stays open before probing the dependency
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            slow_call_threshold: Duration::from_millis(500),
+            call_timeout: Duration::from_secs(2),
+            open_duration: Duration::from_secs(30),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold.max(1);
+        self
+    }
+
+    pub fn with_slow_call_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_call_threshold = threshold;
+        self
+    }
+
+    pub fn with_call_timeout(mut self, timeout: Duration) -> Self {
+        self.call_timeout = timeout;
+        self
+    }
+
+    pub fn with_open_duration(mut self, duration: Duration) -> Self {
+        self.open_duration = duration;
+        self
+    }
+}
+
+/// Circuit breaker states
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CircuitState {
+    /// Calls flow normally
+    Closed,
+    /// Calls are rejected without reaching the dependency
+    Open,
+    /// One probe call is allowed to test recovery
+    HalfOpen,
+}
+
+/// Point-in-time view of a breaker, for metrics and health endpoints
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CircuitBreakerMetrics {
+    pub name: String,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    /// Calls rejected while the breaker was open
+    pub rejected_calls: u64,
+    /// Number of times the breaker has opened
+    pub times_opened: u64,
+}
+
+/// Errors returned by [`CircuitBreaker::call`]
+#[derive(Debug, Error)]
+pub enum CircuitBreakerError<E> {
+    #[error("Circuit breaker '{name}' is open")]
+    Open { name: String },
+
+    #[error("Call through circuit breaker '{name}' timed out after {timeout:?}")]
+    Timeout { name: String, timeout: Duration },
+
+    #[error(transparent)]
+    Inner(E),
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+    rejected_calls: u64,
+    times_opened: u64,
+}
+
+/// Thread-safe circuit breaker guarding one dependency
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    name: String,
+    config: CircuitBreakerConfig,
+    inner: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(name: impl Into<String>, config: CircuitBreakerConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+            inner: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_in_flight: false,
+                rejected_calls: 0,
+                times_opened: 0,
+            }),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Current state; an open breaker whose open duration elapsed reports half-open
+    pub fn state(&self) -> CircuitState {
+        let mut inner = self.inner.lock().unwrap();
+        self.refresh(&mut inner);
+        inner.state
+    }
+
+    pub fn metrics(&self) -> CircuitBreakerMetrics {
+        let mut inner = self.inner.lock().unwrap();
+        self.refresh(&mut inner);
+        CircuitBreakerMetrics {
+            name: self.name.clone(),
+            state: inner.state,
+            consecutive_failures: inner.consecutive_failures,
+            rejected_calls: inner.rejected_calls,
+            times_opened: inner.times_opened,
+        }
+    }
+
+    /// Run `call` through the breaker
+    pub async fn call<T, E, F, Fut>(&self, call: F) -> Result<T, CircuitBreakerError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        if !self.try_acquire() {
+            return Err(CircuitBreakerError::Open {
+                name: self.name.clone(),
+            });
+        }
+
+        let started = Instant::now();
+        match tokio::time::timeout(self.config.call_timeout, call()).await {
+            Ok(Ok(value)) => {
+                if started.elapsed() > self.config.slow_call_threshold {
+                    self.record_failure("slow call");
+                } else {
+                    self.record_success();
+                }
+                Ok(value)
+            }
+            Ok(Err(error)) => {
+                self.record_failure("call failed");
+                Err(CircuitBreakerError::Inner(error))
+            }
+            Err(_) => {
+                self.record_failure("call timed out");
+                Err(CircuitBreakerError::Timeout {
+                    name: self.name.clone(),
+                    timeout: self.config.call_timeout,
+                })
+            }
+        }
+    }
+
+    /// Move an expired open breaker to half-open
+    fn refresh(&self, inner: &mut BreakerState) {
+        if inner.state == CircuitState::Open
+            && inner
+                .opened_at
+                .is_some_and(|opened_at| opened_at.elapsed() >= self.config.open_duration)
+        {
+            inner.state = CircuitState::HalfOpen;
+            inner.probe_in_flight = false;
+            info!(breaker = %self.name, "Circuit breaker half-open, probing dependency");
+        }
+    }
+
+    fn try_acquire(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        self.refresh(&mut inner);
+
+        match inner.state {
+            CircuitState::Closed => true,
+            CircuitState::HalfOpen if !inner.probe_in_flight => {
+                inner.probe_in_flight = true;
+                true
+            }
+            CircuitState::HalfOpen | CircuitState::Open => {
+                inner.rejected_calls += 1;
+                false
+            }
+        }
+    }
+
+    fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.state == CircuitState::HalfOpen {
+            info!(breaker = %self.name, "Circuit breaker closed, dependency recovered");
+        }
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.probe_in_flight = false;
+    }
+
+    fn record_failure(&self, cause: &str) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        inner.probe_in_flight = false;
+
+        let should_open = match inner.state {
+            CircuitState::HalfOpen => true,
+            CircuitState::Closed => inner.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::Open => false,
+        };
+
+        if should_open {
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(Instant::now());
+            inner.times_opened += 1;
+            warn!(
+                breaker = %self.name,
+                cause = cause,
+                consecutive_failures = inner.consecutive_failures,
+                "Circuit breaker opened"
+            );
+        }
+    }
+}
+
+fn to_authorization_error(error: CircuitBreakerError<AuthorizationError>) -> AuthorizationError {
+    match error {
+        CircuitBreakerError::Inner(error) => error,
+        other => AuthorizationError::EvaluationFailed(other.to_string()),
+    }
+}
+
+/// [`ScpEvaluator`] decorator that calls the inner evaluator through a breaker
+pub struct CircuitBreakingScpEvaluator {
+    inner: Arc<dyn ScpEvaluator>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakingScpEvaluator {
+    pub fn new(inner: Arc<dyn ScpEvaluator>, breaker: Arc<CircuitBreaker>) -> Self {
+        Self { inner, breaker }
+    }
+
+    pub fn breaker(&self) -> Arc<CircuitBreaker> {
+        self.breaker.clone()
+    }
+}
+
+#[async_trait]
+impl ScpEvaluator for CircuitBreakingScpEvaluator {
+    async fn evaluate_scps(
+        &self,
+        request: EvaluationRequest,
+    ) -> Result<EvaluationDecision, AuthorizationError> {
+        self.breaker
+            .call(|| self.inner.evaluate_scps(request))
+            .await
+            .map_err(to_authorization_error)
+    }
+}
+
+/// [`IamPolicyEvaluator`] decorator that calls the inner evaluator through a breaker
+pub struct CircuitBreakingIamEvaluator {
+    inner: Arc<dyn IamPolicyEvaluator>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakingIamEvaluator {
+    pub fn new(inner: Arc<dyn IamPolicyEvaluator>, breaker: Arc<CircuitBreaker>) -> Self {
+        Self { inner, breaker }
+    }
+
+    pub fn breaker(&self) -> Arc<CircuitBreaker> {
+        self.breaker.clone()
+    }
+}
+
+#[async_trait]
+impl IamPolicyEvaluator for CircuitBreakingIamEvaluator {
+    async fn evaluate_iam_policies(
+        &self,
+        request: EvaluationRequest,
+    ) -> Result<EvaluationDecision, AuthorizationError> {
+        self.breaker
+            .call(|| self.inner.evaluate_iam_policies(request))
+            .await
+            .map_err(to_authorization_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig::new()
+            .with_failure_threshold(2)
+            .with_slow_call_threshold(Duration::from_millis(50))
+            .with_call_timeout(Duration::from_millis(100))
+            .with_open_duration(Duration::from_millis(50))
+    }
+
+    async fn fail(breaker: &CircuitBreaker) -> Result<(), CircuitBreakerError<&'static str>> {
+        breaker.call(|| async { Err("down") }).await
+    }
+
+    async fn succeed(breaker: &CircuitBreaker) -> Result<(), CircuitBreakerError<&'static str>> {
+        breaker.call(|| async { Ok(()) }).await
+    }
+
+    #[tokio::test]
+    async fn test_opens_after_consecutive_failures_and_short_circuits() {
+        let breaker = CircuitBreaker::new("scp", config());
+        let calls = AtomicUsize::new(0);
+
+        for _ in 0..2 {
+            assert!(matches!(
+                fail(&breaker).await,
+                Err(CircuitBreakerError::Inner("down"))
+            ));
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let result: Result<(), CircuitBreakerError<&str>> = breaker
+            .call(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+        assert!(matches!(result, Err(CircuitBreakerError::Open { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        let metrics = breaker.metrics();
+        assert_eq!(metrics.rejected_calls, 1);
+        assert_eq!(metrics.times_opened, 1);
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_failure_count() {
+        let breaker = CircuitBreaker::new("iam", config());
+
+        fail(&breaker).await.unwrap_err();
+        succeed(&breaker).await.unwrap();
+        fail(&breaker).await.unwrap_err();
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe_closes_or_reopens() {
+        let breaker = CircuitBreaker::new("scp", config());
+        fail(&breaker).await.unwrap_err();
+        fail(&breaker).await.unwrap_err();
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        fail(&breaker).await.unwrap_err();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        succeed(&breaker).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.metrics().times_opened, 2);
+    }
+
+    #[tokio::test]
+    async fn test_slow_and_timed_out_calls_count_as_failures() {
+        let breaker = CircuitBreaker::new("scp", config());
+
+        let slow: Result<(), CircuitBreakerError<&str>> = breaker
+            .call(|| async {
+                tokio::time::sleep(Duration::from_millis(60)).await;
+                Ok(())
+            })
+            .await;
+        assert!(slow.is_ok());
+
+        let timed_out: Result<(), CircuitBreakerError<&str>> = breaker
+            .call(|| async {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                Ok(())
+            })
+            .await;
+        assert!(matches!(
+            timed_out,
+            Err(CircuitBreakerError::Timeout { .. })
+        ));
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+}
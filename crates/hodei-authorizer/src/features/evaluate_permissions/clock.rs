@@ -0,0 +1,70 @@
+//! Clock implementations for the authorizer
+//!
+//! The authorizer reads the time once per evaluation through a [`Clock`] and
+//! exposes it to policies as `context.current_time`. Production uses
+//! [`SystemClock`]; tests pin the time with [`FixedClock`].
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use time::OffsetDateTime;
+
+use super::ports::Clock;
+
+/// Clock backed by the system wall clock
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+/// Clock that always returns the same instant until moved explicitly
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<OffsetDateTime>,
+}
+
+impl FixedClock {
+    pub fn new(now: OffsetDateTime) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Move the clock to `now`
+    pub fn set(&self, now: OffsetDateTime) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> OffsetDateTime {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_clock_only_moves_when_told() {
+        let start = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        let clock = FixedClock::new(start);
+        assert_eq!(clock.now(), clock.now());
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(clock.now().unix_timestamp(), 1_700_000_060);
+
+        clock.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
@@ -0,0 +1,202 @@
+//! Eviction of cached authorization decisions
+//!
+//! [`EvaluatePermissionsUseCase`](super::EvaluatePermissionsUseCase) caches
+//! each decision for five minutes. A change to the principal itself must not
+//! wait that long: [`DecisionCacheInvalidationHandler`] drops every decision
+//! cached for a user as soon as its status or attributes change, so a
+//! suspended user is denied on its next request and attribute conditions
+//! are evaluated against the new values.
+//!
+//! A group's attributes reach every member, and the cache can't tell who the
+//! members are, so a [`GroupAttributesChanged`] drops every decision.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kernel::application::ports::event_bus::{EventEnvelope, EventHandler};
+use kernel::{GroupAttributesChanged, UserAttributesChanged, UserStatusChanged};
+use tracing::debug;
+
+use super::ports::AuthorizationCache;
+
+/// Event handler that evicts a principal's decisions from an
+/// [`AuthorizationCache`]
+pub struct DecisionCacheInvalidationHandler<C> {
+    cache: Arc<C>,
+}
+
+impl<C> DecisionCacheInvalidationHandler<C> {
+    pub fn new(cache: Arc<C>) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl<C: AuthorizationCache + 'static> EventHandler<UserStatusChanged>
+    for DecisionCacheInvalidationHandler<C>
+{
+    fn name(&self) -> &'static str {
+        "decision-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<UserStatusChanged>) -> anyhow::Result<()> {
+        let user_hrn = &envelope.event.user_hrn;
+        debug!(
+            %user_hrn,
+            status = %envelope.event.status,
+            "User status changed, evicting cached decisions"
+        );
+        self.cache.invalidate_principal(user_hrn).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: AuthorizationCache + 'static> EventHandler<UserAttributesChanged>
+    for DecisionCacheInvalidationHandler<C>
+{
+    fn name(&self) -> &'static str {
+        "decision-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<UserAttributesChanged>) -> anyhow::Result<()> {
+        let user_hrn = &envelope.event.user_hrn;
+        debug!(
+            %user_hrn,
+            changed_keys = ?envelope.event.changed_keys(),
+            "User attributes changed, evicting cached decisions"
+        );
+        self.cache.invalidate_principal(user_hrn).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<C: AuthorizationCache + 'static> EventHandler<GroupAttributesChanged>
+    for DecisionCacheInvalidationHandler<C>
+{
+    fn name(&self) -> &'static str {
+        "decision-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<GroupAttributesChanged>) -> anyhow::Result<()> {
+        debug!(
+            group_hrn = %envelope.event.group_hrn,
+            changed_keys = ?envelope.event.changed_keys(),
+            "Group attributes changed, evicting every cached decision"
+        );
+        self.cache.invalidate_all().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::evaluate_permissions::dto::AuthorizationResponse;
+    use crate::features::evaluate_permissions::mocks::MockAuthorizationCache;
+    use kernel::application::ports::event_bus::{EventBus, EventPublisher, Subscription};
+    use kernel::{AttributeChange, Hrn, InMemoryEventBus, PrincipalStatus};
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn iam(resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "aws".to_string(),
+            "iam".to_string(),
+            "default".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn user(id: &str) -> Hrn {
+        iam("User", id)
+    }
+
+    /// A cache holding an allow for alice and one for bob, with its handler
+    /// subscribed to every event that evicts decisions
+    async fn subscribed_cache() -> (
+        Arc<MockAuthorizationCache>,
+        InMemoryEventBus,
+        Vec<Arc<dyn Subscription>>,
+        [String; 2],
+    ) {
+        let keys = [
+            format!("auth:{}:read:bucket", user("alice")),
+            format!("auth:{}:read:bucket", user("bob")),
+        ];
+        let allow =
+            AuthorizationResponse::allow(vec!["read-all".to_string()], "permitted".to_string());
+        let cache = Arc::new(
+            MockAuthorizationCache::new()
+                .with_response(&keys[0], allow.clone())
+                .with_response(&keys[1], allow),
+        );
+        let bus = InMemoryEventBus::new();
+        let handler = Arc::new(DecisionCacheInvalidationHandler::new(cache.clone()));
+        let subscriptions = vec![
+            bus.subscribe::<UserStatusChanged, _>(handler.clone())
+                .await
+                .unwrap(),
+            bus.subscribe::<UserAttributesChanged, _>(handler.clone())
+                .await
+                .unwrap(),
+            bus.subscribe::<GroupAttributesChanged, _>(handler)
+                .await
+                .unwrap(),
+        ];
+        (cache, bus, subscriptions, keys)
+    }
+
+    #[tokio::test]
+    async fn status_change_evicts_only_the_users_decisions() {
+        let (cache, bus, _subscriptions, [alice_key, bob_key]) = subscribed_cache().await;
+
+        bus.publish(UserStatusChanged {
+            user_hrn: user("alice"),
+            previous_status: PrincipalStatus::Active,
+            status: PrincipalStatus::Suspended,
+        })
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        assert!(!cache.contains(&alice_key));
+        assert!(cache.contains(&bob_key));
+    }
+
+    #[tokio::test]
+    async fn attribute_change_evicts_only_the_users_decisions() {
+        let (cache, bus, _subscriptions, [alice_key, bob_key]) = subscribed_cache().await;
+        let change = AttributeChange::between("department", None, Some(json!("finance")));
+
+        bus.publish(UserAttributesChanged::new(
+            user("alice"),
+            change.into_iter().collect(),
+        ))
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        assert!(!cache.contains(&alice_key));
+        assert!(cache.contains(&bob_key));
+    }
+
+    #[tokio::test]
+    async fn group_attribute_change_evicts_every_decision() {
+        let (cache, bus, _subscriptions, [alice_key, bob_key]) = subscribed_cache().await;
+        let change = AttributeChange::between("clearance", None, Some(json!(3)));
+
+        bus.publish(GroupAttributesChanged::new(
+            iam("Group", "auditors"),
+            change.into_iter().collect(),
+        ))
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        assert!(!cache.contains(&alice_key));
+        assert!(!cache.contains(&bob_key));
+    }
+}
@@ -1,12 +1,21 @@
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::features::evaluate_permissions::ScpFailurePolicy;
+use crate::features::evaluate_permissions::audit::DecisionAuditMode;
 use crate::features::evaluate_permissions::dto::AuthorizationResponse;
 use crate::features::evaluate_permissions::error::EvaluatePermissionsResult;
 use crate::features::evaluate_permissions::ports::{
-    AuthorizationCache, AuthorizationLogger, AuthorizationMetrics,
+    AuthorizationAuditPublisher, AuthorizationCache, AuthorizationLogger, AuthorizationMetrics,
+    Clock, EntityResolverPort,
 };
+use crate::features::evaluate_permissions::request_cache::RequestScopedEntityResolver;
+use crate::features::evaluate_permissions::resource_hierarchy::ResourceAncestryResolver;
 use crate::features::evaluate_permissions::use_case::EvaluatePermissionsUseCase;
 use async_trait::async_trait;
+use hodei_iam::features::evaluate_iam_policies::ports::PolicyFinderPort;
+use hodei_iam::features::evaluate_iam_policies::use_case::EvaluateIamPoliciesUseCase;
+use hodei_policies::features::build_schema::ports::SchemaStoragePort;
 use kernel::Hrn;
 use kernel::application::ports::authorization::{IamPolicyEvaluator, ScpEvaluator};
 
@@ -39,6 +48,10 @@ impl AuthorizationCache for DummyCache {
     async fn invalidate_resource(&self, _resource_hrn: &Hrn) -> EvaluatePermissionsResult<()> {
         Ok(())
     }
+
+    async fn invalidate_all(&self) -> EvaluatePermissionsResult<()> {
+        Ok(())
+    }
 }
 
 /// Dependency injection container for the evaluate permissions feature
@@ -53,6 +66,12 @@ pub struct EvaluatePermissionsContainer<CACHE, LOGGER, METRICS> {
     cache: Option<CACHE>,
     logger: LOGGER,
     metrics: METRICS,
+
+    scp_failure_policy: ScpFailurePolicy,
+    resource_hierarchy: Option<ResourceAncestryResolver>,
+    decision_audit: Option<(Arc<dyn AuthorizationAuditPublisher>, DecisionAuditMode)>,
+    clock: Option<Arc<dyn Clock>>,
+    layer_timeout: Option<Duration>,
 }
 
 impl<CACHE, LOGGER, METRICS> EvaluatePermissionsContainer<CACHE, LOGGER, METRICS>
@@ -75,18 +94,72 @@ where
             cache,
             logger,
             metrics,
+            scp_failure_policy: ScpFailurePolicy::default(),
+            resource_hierarchy: None,
+            decision_audit: None,
+            clock: None,
+            layer_timeout: None,
         }
     }
 
+    /// Set the behaviour when SCPs can't be evaluated (fail-closed by default)
+    pub fn with_scp_failure_policy(mut self, policy: ScpFailurePolicy) -> Self {
+        self.scp_failure_policy = policy;
+        self
+    }
+
+    /// Resolve resource ancestors before evaluation (container-based permissions)
+    pub fn with_resource_hierarchy(mut self, resolver: ResourceAncestryResolver) -> Self {
+        self.resource_hierarchy = Some(resolver);
+        self
+    }
+
+    /// Publish authorization decisions to the audit trail
+    pub fn with_decision_audit(
+        mut self,
+        publisher: Arc<dyn AuthorizationAuditPublisher>,
+        mode: DecisionAuditMode,
+    ) -> Self {
+        self.decision_audit = Some((publisher, mode));
+        self
+    }
+
+    /// Replace the system clock used for `context.current_time`
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Bound the time each policy layer may take
+    pub fn with_layer_timeout(mut self, timeout: Duration) -> Self {
+        self.layer_timeout = Some(timeout);
+        self
+    }
+
     /// Build the EvaluatePermissionsUseCase with all dependencies injected
     pub fn build_use_case(self) -> EvaluatePermissionsUseCase<CACHE, LOGGER, METRICS> {
-        EvaluatePermissionsUseCase::new(
+        let mut use_case = EvaluatePermissionsUseCase::new(
             self.iam_evaluator,
             self.scp_evaluator,
             self.cache,
             self.logger,
             self.metrics,
         )
+        .with_scp_failure_policy(self.scp_failure_policy);
+
+        if let Some(resolver) = self.resource_hierarchy {
+            use_case = use_case.with_resource_hierarchy(resolver);
+        }
+        if let Some((publisher, mode)) = self.decision_audit {
+            use_case = use_case.with_decision_audit(publisher, mode);
+        }
+        if let Some(clock) = self.clock {
+            use_case = use_case.with_clock(clock);
+        }
+        if let Some(timeout) = self.layer_timeout {
+            use_case = use_case.with_layer_timeout(timeout);
+        }
+        use_case
     }
 }
 
@@ -97,6 +170,11 @@ pub struct EvaluatePermissionsContainerBuilder<CACHE, LOGGER, METRICS> {
     cache: Option<CACHE>,
     logger: Option<LOGGER>,
     metrics: Option<METRICS>,
+    scp_failure_policy: ScpFailurePolicy,
+    resource_hierarchy: Option<ResourceAncestryResolver>,
+    decision_audit: Option<(Arc<dyn AuthorizationAuditPublisher>, DecisionAuditMode)>,
+    clock: Option<Arc<dyn Clock>>,
+    layer_timeout: Option<Duration>,
 }
 
 impl<CACHE, LOGGER, METRICS> EvaluatePermissionsContainerBuilder<CACHE, LOGGER, METRICS>
@@ -113,6 +191,11 @@ where
             cache: None,
             logger: None,
             metrics: None,
+            scp_failure_policy: ScpFailurePolicy::default(),
+            resource_hierarchy: None,
+            decision_audit: None,
+            clock: None,
+            layer_timeout: None,
         }
     }
 
@@ -146,15 +229,64 @@ where
         self
     }
 
+    /// Set the behaviour when SCPs can't be evaluated (optional, fail-closed by default)
+    pub fn with_scp_failure_policy(mut self, policy: ScpFailurePolicy) -> Self {
+        self.scp_failure_policy = policy;
+        self
+    }
+
+    /// Set the resource ancestry resolver (optional, no hierarchy by default)
+    pub fn with_resource_hierarchy(mut self, resolver: ResourceAncestryResolver) -> Self {
+        self.resource_hierarchy = Some(resolver);
+        self
+    }
+
+    /// Publish authorization decisions to the audit trail (optional, off by default)
+    pub fn with_decision_audit(
+        mut self,
+        publisher: Arc<dyn AuthorizationAuditPublisher>,
+        mode: DecisionAuditMode,
+    ) -> Self {
+        self.decision_audit = Some((publisher, mode));
+        self
+    }
+
+    /// Set the clock used for `context.current_time` (optional, system clock by default)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Set the time each policy layer may take (optional, 5 seconds by default)
+    pub fn with_layer_timeout(mut self, timeout: Duration) -> Self {
+        self.layer_timeout = Some(timeout);
+        self
+    }
+
     /// Build the container
     pub fn build(self) -> Result<EvaluatePermissionsContainer<CACHE, LOGGER, METRICS>, String> {
-        Ok(EvaluatePermissionsContainer::new(
+        let mut container = EvaluatePermissionsContainer::new(
             self.iam_evaluator.ok_or("IAM evaluator is required")?,
             self.scp_evaluator.ok_or("SCP evaluator is required")?,
             self.cache,
             self.logger.ok_or("Logger is required")?,
             self.metrics.ok_or("Metrics is required")?,
-        ))
+        )
+        .with_scp_failure_policy(self.scp_failure_policy);
+
+        if let Some(resolver) = self.resource_hierarchy {
+            container = container.with_resource_hierarchy(resolver);
+        }
+        if let Some((publisher, mode)) = self.decision_audit {
+            container = container.with_decision_audit(publisher, mode);
+        }
+        if let Some(clock) = self.clock {
+            container = container.with_clock(clock);
+        }
+        if let Some(timeout) = self.layer_timeout {
+            container = container.with_layer_timeout(timeout);
+        }
+        Ok(container)
     }
 }
 
@@ -193,6 +325,28 @@ pub mod factories {
         )
     }
 
+    /// Create the IAM layer on a request-scoped entity resolver
+    ///
+    /// Principals and resources are resolved through `entities`, so within
+    /// one [`execute`](EvaluatePermissionsUseCase::execute) call the IAM
+    /// layer reuses what any other layer given the same resolver already
+    /// fetched, and vice versa.
+    pub fn create_iam_evaluator<R>(
+        policy_finder: Arc<dyn PolicyFinderPort>,
+        entities: Arc<RequestScopedEntityResolver<R>>,
+        schema_storage: Arc<dyn SchemaStoragePort>,
+    ) -> Arc<dyn IamPolicyEvaluator>
+    where
+        R: EntityResolverPort + 'static,
+    {
+        Arc::new(EvaluateIamPoliciesUseCase::new(
+            policy_finder,
+            entities.clone(),
+            entities,
+            schema_storage,
+        ))
+    }
+
     /// Create a container with cache
     pub fn create_with_cache<CACHE, LOGGER, METRICS>(
         iam_evaluator: Arc<dyn IamPolicyEvaluator>,
@@ -222,6 +376,7 @@ mod tests {
     use crate::features::evaluate_permissions::mocks::{
         MockAuthorizationCache, MockAuthorizationLogger, MockAuthorizationMetrics,
     };
+    use std::collections::HashMap;
 
     // Mock implementation of the EffectivePoliciesQueryPort (shared kernel) trait
     struct MockEffectivePoliciesQueryService;
@@ -239,6 +394,7 @@ mod tests {
             Ok(kernel::application::ports::EffectivePoliciesResult {
                 policies: PolicySet::new(),
                 policy_count: 0,
+                principal_status: kernel::PrincipalStatus::Active,
             })
         }
     }
@@ -269,6 +425,9 @@ mod tests {
                     resource_hrn: request.resource_hrn,
                     decision: true,
                     reason: "Test IAM evaluator always allows".to_string(),
+                    determining_policies: vec![],
+                    policy_annotations: HashMap::new(),
+                    principal_status: kernel::PrincipalStatus::Active,
                 })
             }
         }
@@ -291,6 +450,9 @@ mod tests {
                     resource_hrn: request.resource_hrn,
                     decision: true,
                     reason: "Test SCP evaluator always allows".to_string(),
+                    determining_policies: vec![],
+                    policy_annotations: HashMap::new(),
+                    principal_status: kernel::PrincipalStatus::Active,
                 })
             }
         }
@@ -1,4 +1,4 @@
-use ::kernel::Hrn;
+use ::kernel::{Hrn, PrincipalStatus};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -38,10 +38,21 @@ pub struct AuthorizationResponse {
     pub decision: AuthorizationDecision,
     /// Policies that determined the decision
     pub determining_policies: Vec<String>,
+    /// Annotations of the determining policies, keyed by policy ID
+    #[serde(default)]
+    pub policy_annotations: HashMap<String, HashMap<String, String>>,
     /// Reason for the decision
     pub reason: String,
     /// Whether the decision was explicit or implicit
     pub explicit: bool,
+    /// Whether the decision was made without SCPs because the organization
+    /// boundary could not be resolved
+    #[serde(default)]
+    pub degraded: bool,
+    /// Status of the principal when it was denied for being suspended or
+    /// deactivated rather than by a policy; `None` for every other decision
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inactive_principal: Option<PrincipalStatus>,
 }
 
 /// Authorization decision outcomes
@@ -110,8 +121,11 @@ impl AuthorizationResponse {
         Self {
             decision: AuthorizationDecision::Allow,
             determining_policies: policies,
+            policy_annotations: HashMap::new(),
             reason,
             explicit: true,
+            degraded: false,
+            inactive_principal: None,
         }
     }
 
@@ -120,8 +134,11 @@ impl AuthorizationResponse {
         Self {
             decision: AuthorizationDecision::Deny,
             determining_policies: policies,
+            policy_annotations: HashMap::new(),
             reason,
             explicit: true,
+            degraded: false,
+            inactive_principal: None,
         }
     }
 
@@ -130,8 +147,25 @@ impl AuthorizationResponse {
         Self {
             decision: AuthorizationDecision::Deny,
             determining_policies: vec![],
+            policy_annotations: HashMap::new(),
             reason,
             explicit: false,
+            degraded: false,
+            inactive_principal: None,
+        }
+    }
+
+    /// Create a deny response for a suspended or deactivated principal,
+    /// whose policies were not evaluated
+    pub fn inactive_principal(status: PrincipalStatus) -> Self {
+        Self {
+            decision: AuthorizationDecision::Deny,
+            determining_policies: vec![],
+            policy_annotations: HashMap::new(),
+            reason: format!("Principal is {}", status),
+            explicit: false,
+            degraded: false,
+            inactive_principal: Some(status),
         }
     }
 }
@@ -0,0 +1,455 @@
+//! Short-lived cache for resolved resource entities
+//!
+//! Resolving a resource entity goes through the owning bounded context on
+//! every authorization request, even though the same resources are checked
+//! over and over. [`CachingEntityResolver`] wraps any [`EntityResolverPort`]
+//! and keeps resolved entities for a short TTL, keyed by HRN.
+//!
+//! This is deliberately separate from the decision cache: a cached entity is
+//! only an input to evaluation, so policy changes still take effect
+//! immediately. Entries are dropped early when a [`ResourceChanged`] event is
+//! published, and when IAM announces that a user's or group's attributes
+//! changed ([`UserAttributesChanged`], [`GroupAttributesChanged`]), so
+//! attribute-based conditions see the new values on the next request (see
+//! [`EntityCacheInvalidationHandler`]). Only the changed entity is evicted.
+//!
+//! Concurrent misses for the same HRN share a single fetch.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use kernel::application::ports::event_bus::{DomainEvent, EventEnvelope, EventHandler};
+use kernel::{
+    AttributeName, AttributeType, AttributeValue, GroupAttributesChanged, HodeiEntity, Hrn,
+    UserAttributesChanged,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+use super::ports::{EntityResolverError, EntityResolverPort};
+
+/// How long a resolved entity is reused when no TTL is configured
+pub const DEFAULT_ENTITY_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Event published when a resource's attributes or hierarchy change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceChanged {
+    /// HRN of the resource that changed
+    pub resource_hrn: Hrn,
+    /// Timestamp when the change happened
+    #[serde(with = "time::serde::rfc3339")]
+    pub changed_at: time::OffsetDateTime,
+}
+
+impl ResourceChanged {
+    pub fn new(resource_hrn: Hrn) -> Self {
+        Self {
+            resource_hrn,
+            changed_at: time::OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+impl DomainEvent for ResourceChanged {
+    fn event_type(&self) -> &'static str {
+        "resources.resource.changed"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.resource_hrn.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedEntity {
+    entity: Arc<dyn HodeiEntity>,
+    resolved_at: Instant,
+}
+
+/// Slot for one HRN; empty while the first fetch is in flight
+type EntitySlot = Arc<OnceCell<CachedEntity>>;
+
+/// Entity resolver decorator that caches resolved entities for a short TTL
+pub struct CachingEntityResolver<R> {
+    inner: R,
+    ttl: Duration,
+    entries: Mutex<HashMap<Hrn, EntitySlot>>,
+}
+
+impl<R: EntityResolverPort> CachingEntityResolver<R> {
+    pub fn new(inner: R) -> Self {
+        Self::with_ttl(inner, DEFAULT_ENTITY_CACHE_TTL)
+    }
+
+    pub fn with_ttl(inner: R, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Drop the cached entity for `hrn`, if any
+    ///
+    /// A fetch already in flight still completes for the requests waiting on
+    /// it, but its result is not reused afterwards.
+    pub fn invalidate(&self, hrn: &Hrn) {
+        if self.entries.lock().unwrap().remove(hrn).is_some() {
+            debug!(resource_hrn = %hrn, "Invalidated cached resource entity");
+        }
+    }
+
+    /// Drop every cached entity
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Number of HRNs currently cached or being fetched
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Slot to read `hrn` from, replacing it when the cached entity is stale
+    fn slot(&self, hrn: &Hrn) -> EntitySlot {
+        let mut entries = self.entries.lock().unwrap();
+        let fresh = entries.get(hrn).filter(|slot| {
+            slot.get()
+                .is_none_or(|cached| cached.resolved_at.elapsed() < self.ttl)
+        });
+
+        match fresh {
+            Some(slot) => slot.clone(),
+            None => {
+                let slot = EntitySlot::default();
+                entries.insert(hrn.clone(), slot.clone());
+                slot
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<R: EntityResolverPort> EntityResolverPort for CachingEntityResolver<R> {
+    async fn resolve(&self, hrn: &Hrn) -> Result<Box<dyn HodeiEntity>, EntityResolverError> {
+        let slot = self.slot(hrn);
+        let cached = slot
+            .get_or_try_init(|| async {
+                debug!(resource_hrn = %hrn, "Resolving resource entity (cache miss)");
+                let entity = self.inner.resolve(hrn).await?;
+                Ok::<_, EntityResolverError>(CachedEntity {
+                    entity: Arc::from(entity),
+                    resolved_at: Instant::now(),
+                })
+            })
+            .await?;
+
+        Ok(Box::new(SharedEntity(cached.entity.clone())))
+    }
+
+    async fn resolve_batch(
+        &self,
+        hrns: &[Hrn],
+    ) -> Result<Vec<Box<dyn HodeiEntity>>, EntityResolverError> {
+        let mut entities = Vec::with_capacity(hrns.len());
+        let mut missing = Vec::new();
+
+        for hrn in hrns {
+            match self.resolve(hrn).await {
+                Ok(entity) => entities.push(entity),
+                Err(EntityResolverError::NotFound(hrn)) => missing.push(hrn),
+                Err(e) => return Err(e),
+            }
+        }
+
+        if missing.is_empty() {
+            Ok(entities)
+        } else {
+            Err(EntityResolverError::BatchResolutionFailed(missing))
+        }
+    }
+}
+
+/// Cached entity handed out to callers, which expect an owned box
+#[derive(Debug)]
+pub(super) struct SharedEntity(pub(super) Arc<dyn HodeiEntity>);
+
+impl HodeiEntity for SharedEntity {
+    fn hrn(&self) -> &Hrn {
+        self.0.hrn()
+    }
+
+    fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+        self.0.attributes()
+    }
+
+    fn parent_hrns(&self) -> Vec<Hrn> {
+        self.0.parent_hrns()
+    }
+
+    fn cedar_attributes(&self) -> Option<Vec<(String, AttributeType)>> {
+        self.0.cedar_attributes()
+    }
+}
+
+/// Event handler that evicts entities from a [`CachingEntityResolver`]
+/// when their resource changes
+pub struct EntityCacheInvalidationHandler<R> {
+    cache: Arc<CachingEntityResolver<R>>,
+}
+
+impl<R> EntityCacheInvalidationHandler<R> {
+    pub fn new(cache: Arc<CachingEntityResolver<R>>) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl<R: EntityResolverPort + 'static> EventHandler<ResourceChanged>
+    for EntityCacheInvalidationHandler<R>
+{
+    fn name(&self) -> &'static str {
+        "entity-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<ResourceChanged>) -> anyhow::Result<()> {
+        self.cache.invalidate(&envelope.event.resource_hrn);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: EntityResolverPort + 'static> EventHandler<UserAttributesChanged>
+    for EntityCacheInvalidationHandler<R>
+{
+    fn name(&self) -> &'static str {
+        "entity-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<UserAttributesChanged>) -> anyhow::Result<()> {
+        self.cache.invalidate(&envelope.event.user_hrn);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: EntityResolverPort + 'static> EventHandler<GroupAttributesChanged>
+    for EntityCacheInvalidationHandler<R>
+{
+    fn name(&self) -> &'static str {
+        "entity-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<GroupAttributesChanged>) -> anyhow::Result<()> {
+        self.cache.invalidate(&envelope.event.group_hrn);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kernel::InMemoryEventBus;
+    use kernel::application::ports::event_bus::{EventBus, EventPublisher};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct TestEntity {
+        hrn: Hrn,
+        version: usize,
+    }
+
+    impl HodeiEntity for TestEntity {
+        fn hrn(&self) -> &Hrn {
+            &self.hrn
+        }
+
+        fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+            let mut attributes = HashMap::new();
+            attributes.insert(
+                AttributeName::new("version").unwrap(),
+                AttributeValue::long(self.version as i64),
+            );
+            attributes
+        }
+    }
+
+    /// Resolver that counts fetches and takes a while to answer
+    #[derive(Default)]
+    struct CountingResolver {
+        fetches: AtomicUsize,
+    }
+
+    impl CountingResolver {
+        fn fetches(&self) -> usize {
+            self.fetches.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl EntityResolverPort for CountingResolver {
+        async fn resolve(&self, hrn: &Hrn) -> Result<Box<dyn HodeiEntity>, EntityResolverError> {
+            let version = self.fetches.fetch_add(1, Ordering::SeqCst) + 1;
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            if hrn.resource_id() == "missing" {
+                return Err(EntityResolverError::NotFound(hrn.clone()));
+            }
+            Ok(Box::new(TestEntity {
+                hrn: hrn.clone(),
+                version,
+            }))
+        }
+
+        async fn resolve_batch(
+            &self,
+            hrns: &[Hrn],
+        ) -> Result<Vec<Box<dyn HodeiEntity>>, EntityResolverError> {
+            let mut entities = Vec::new();
+            for hrn in hrns {
+                entities.push(self.resolve(hrn).await?);
+            }
+            Ok(entities)
+        }
+    }
+
+    fn bucket(id: &str) -> Hrn {
+        Hrn::new(
+            "aws".to_string(),
+            "s3".to_string(),
+            "default".to_string(),
+            "bucket".to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn version_of(entity: &dyn HodeiEntity) -> AttributeValue {
+        entity.attributes()[&AttributeName::new("version").unwrap()].clone()
+    }
+
+    #[tokio::test]
+    async fn reuses_entities_within_ttl() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = CachingEntityResolver::new(inner.clone());
+
+        let first = cache.resolve(&bucket("a")).await.unwrap();
+        let second = cache.resolve(&bucket("a")).await.unwrap();
+
+        assert_eq!(inner.fetches(), 1);
+        assert_eq!(first.hrn(), second.hrn());
+        assert_eq!(version_of(second.as_ref()), AttributeValue::long(1));
+    }
+
+    #[tokio::test]
+    async fn refetches_after_ttl() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = CachingEntityResolver::with_ttl(inner.clone(), Duration::from_millis(10));
+
+        cache.resolve(&bucket("a")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(15)).await;
+        let refreshed = cache.resolve(&bucket("a")).await.unwrap();
+
+        assert_eq!(inner.fetches(), 2);
+        assert_eq!(version_of(refreshed.as_ref()), AttributeValue::long(2));
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_share_one_fetch() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = Arc::new(CachingEntityResolver::new(inner.clone()));
+
+        let lookups = (0..8).map(|_| {
+            let cache = cache.clone();
+            tokio::spawn(async move { cache.resolve(&bucket("a")).await.map(|_| ()) })
+        });
+        for lookup in lookups.collect::<Vec<_>>() {
+            lookup.await.unwrap().unwrap();
+        }
+
+        assert_eq!(inner.fetches(), 1);
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = CachingEntityResolver::new(inner.clone());
+
+        for _ in 0..2 {
+            assert!(matches!(
+                cache.resolve(&bucket("missing")).await,
+                Err(EntityResolverError::NotFound(_))
+            ));
+        }
+        assert_eq!(inner.fetches(), 2);
+    }
+
+    #[tokio::test]
+    async fn resource_changed_event_invalidates_entry() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = Arc::new(CachingEntityResolver::new(inner.clone()));
+        let bus = InMemoryEventBus::new();
+        let _subscription = bus
+            .subscribe::<ResourceChanged, _>(Arc::new(EntityCacheInvalidationHandler::new(
+                cache.clone(),
+            )))
+            .await
+            .unwrap();
+
+        cache.resolve(&bucket("a")).await.unwrap();
+        cache.resolve(&bucket("b")).await.unwrap();
+        bus.publish(ResourceChanged::new(bucket("a")))
+            .await
+            .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        let changed = cache.resolve(&bucket("a")).await.unwrap();
+        cache.resolve(&bucket("b")).await.unwrap();
+
+        assert_eq!(inner.fetches(), 3);
+        assert_eq!(version_of(changed.as_ref()), AttributeValue::long(3));
+    }
+
+    #[tokio::test]
+    async fn attribute_change_event_invalidates_only_that_principal() {
+        let inner = Arc::new(CountingResolver::default());
+        let cache = Arc::new(CachingEntityResolver::new(inner.clone()));
+        let bus = InMemoryEventBus::new();
+        let _subscription = bus
+            .subscribe::<UserAttributesChanged, _>(Arc::new(EntityCacheInvalidationHandler::new(
+                cache.clone(),
+            )))
+            .await
+            .unwrap();
+        let user = |id: &str| {
+            Hrn::new(
+                "hodei".to_string(),
+                "iam".to_string(),
+                "default".to_string(),
+                "User".to_string(),
+                id.to_string(),
+            )
+        };
+
+        cache.resolve(&user("alice")).await.unwrap();
+        cache.resolve(&user("bob")).await.unwrap();
+        bus.publish(UserAttributesChanged::new(user("alice"), Vec::new()))
+            .await
+            .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        let changed = cache.resolve(&user("alice")).await.unwrap();
+        cache.resolve(&user("bob")).await.unwrap();
+
+        assert_eq!(inner.fetches(), 3);
+        assert_eq!(version_of(changed.as_ref()), AttributeValue::long(3));
+    }
+}
@@ -1,14 +1,16 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
+use crate::features::evaluate_permissions::audit::AuthorizationEvaluated;
 use crate::features::evaluate_permissions::dto::{
     AuthorizationDecision, AuthorizationRequest, AuthorizationResponse,
 };
 use crate::features::evaluate_permissions::error::EvaluatePermissionsResult;
 use crate::features::evaluate_permissions::ports::{
-    AuthorizationCache, AuthorizationLogger, AuthorizationMetrics,
+    AuthorizationAuditPublisher, AuthorizationCache, AuthorizationLogger, AuthorizationMetrics,
 };
-use ::kernel::Hrn;
+use ::kernel::{Hrn, PrincipalStatus};
 use kernel::application::ports::authorization::{
     AuthorizationError, EvaluationDecision, EvaluationRequest, IamPolicyEvaluator, ScpEvaluator,
 };
@@ -26,6 +28,11 @@ impl MockAuthorizationCache {
         }
     }
 
+    /// Whether a response is cached under `cache_key`
+    pub fn contains(&self, cache_key: &str) -> bool {
+        self.responses.lock().unwrap().contains_key(cache_key)
+    }
+
     pub fn with_response(self, cache_key: &str, response: AuthorizationResponse) -> Self {
         let mut responses = self.responses.lock().unwrap();
         responses.insert(cache_key.to_string(), response);
@@ -55,13 +62,22 @@ impl AuthorizationCache for MockAuthorizationCache {
         Ok(())
     }
 
-    async fn invalidate_principal(&self, _principal_hrn: &Hrn) -> EvaluatePermissionsResult<()> {
+    async fn invalidate_principal(&self, principal_hrn: &Hrn) -> EvaluatePermissionsResult<()> {
+        // Keys are "auth:{principal}:{action}:{resource}"
+        let prefix = format!("auth:{}:", principal_hrn);
+        let mut responses = self.responses.lock().unwrap();
+        responses.retain(|cache_key, _| !cache_key.starts_with(&prefix));
         Ok(())
     }
 
     async fn invalidate_resource(&self, _resource_hrn: &Hrn) -> EvaluatePermissionsResult<()> {
         Ok(())
     }
+
+    async fn invalidate_all(&self) -> EvaluatePermissionsResult<()> {
+        self.responses.lock().unwrap().clear();
+        Ok(())
+    }
 }
 
 /// Mock Authorization Logger for testing
@@ -104,6 +120,28 @@ impl AuthorizationLogger for MockAuthorizationLogger {
     }
 }
 
+/// Mock audit publisher that records published events
+#[derive(Debug, Default, Clone)]
+pub struct MockAuthorizationAuditPublisher {
+    events: Arc<Mutex<Vec<AuthorizationEvaluated>>>,
+}
+
+impl MockAuthorizationAuditPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_published_events(&self) -> Vec<AuthorizationEvaluated> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl AuthorizationAuditPublisher for MockAuthorizationAuditPublisher {
+    fn publish(&self, event: AuthorizationEvaluated) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
 /// Mock Authorization Metrics for testing
 #[derive(Debug, Default, Clone)]
 pub struct MockAuthorizationMetrics {
@@ -148,10 +186,11 @@ impl AuthorizationMetrics for MockAuthorizationMetrics {
 // Mock Evaluators for New Architecture
 // ============================================================================
 
-/// Mock SCP Evaluator that can be configured to allow or deny
+/// Mock SCP Evaluator that can be configured to allow, deny or fail
 #[derive(Debug, Clone)]
 pub struct MockScpEvaluator {
     should_deny: bool,
+    should_fail: bool,
 }
 
 impl Default for MockScpEvaluator {
@@ -162,11 +201,25 @@ impl Default for MockScpEvaluator {
 
 impl MockScpEvaluator {
     pub fn new() -> Self {
-        Self { should_deny: false }
+        Self {
+            should_deny: false,
+            should_fail: false,
+        }
     }
 
     pub fn with_deny() -> Self {
-        Self { should_deny: true }
+        Self {
+            should_deny: true,
+            should_fail: false,
+        }
+    }
+
+    /// Simulates an unreachable organization boundary provider
+    pub fn unavailable() -> Self {
+        Self {
+            should_deny: false,
+            should_fail: true,
+        }
     }
 }
 
@@ -176,6 +229,12 @@ impl ScpEvaluator for MockScpEvaluator {
         &self,
         request: EvaluationRequest,
     ) -> Result<EvaluationDecision, AuthorizationError> {
+        if self.should_fail {
+            return Err(AuthorizationError::EvaluationFailed(
+                "organizations service unavailable".to_string(),
+            ));
+        }
+
         Ok(EvaluationDecision {
             principal_hrn: request.principal_hrn,
             action_name: request.action_name,
@@ -186,14 +245,20 @@ impl ScpEvaluator for MockScpEvaluator {
             } else {
                 "Allowed by SCP mock".to_string()
             },
+            determining_policies: vec![],
+            policy_annotations: HashMap::new(),
+            principal_status: PrincipalStatus::Active,
         })
     }
 }
 
-/// Mock IAM Policy Evaluator that can be configured to allow or deny
+/// Mock IAM Policy Evaluator that can be configured to allow, deny or fail
 #[derive(Debug, Clone)]
 pub struct MockIamPolicyEvaluator {
     should_deny: bool,
+    should_fail: bool,
+    determining_policy: Option<(String, HashMap<String, String>)>,
+    principal_status: PrincipalStatus,
 }
 
 impl Default for MockIamPolicyEvaluator {
@@ -204,11 +269,46 @@ impl Default for MockIamPolicyEvaluator {
 
 impl MockIamPolicyEvaluator {
     pub fn new() -> Self {
-        Self { should_deny: false }
+        Self {
+            should_deny: false,
+            should_fail: false,
+            determining_policy: None,
+            principal_status: PrincipalStatus::Active,
+        }
     }
 
     pub fn with_deny() -> Self {
-        Self { should_deny: true }
+        Self {
+            should_deny: true,
+            should_fail: false,
+            determining_policy: None,
+            principal_status: PrincipalStatus::Active,
+        }
+    }
+
+    /// Simulates an unreachable IAM policy provider
+    pub fn unavailable() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Report the principal as `status`; a non-active principal is denied
+    /// without policies, as the IAM evaluator does
+    pub fn with_principal_status(mut self, status: PrincipalStatus) -> Self {
+        self.principal_status = status;
+        self
+    }
+
+    /// Report `policy_id`, carrying `annotations`, as the determining policy
+    pub fn with_determining_policy(
+        mut self,
+        policy_id: impl Into<String>,
+        annotations: HashMap<String, String>,
+    ) -> Self {
+        self.determining_policy = Some((policy_id.into(), annotations));
+        self
     }
 }
 
@@ -218,6 +318,25 @@ impl IamPolicyEvaluator for MockIamPolicyEvaluator {
         &self,
         request: EvaluationRequest,
     ) -> Result<EvaluationDecision, AuthorizationError> {
+        if self.should_fail {
+            return Err(AuthorizationError::EvaluationFailed(
+                "IAM policy provider unavailable".to_string(),
+            ));
+        }
+
+        if !self.principal_status.is_active() {
+            return Ok(EvaluationDecision {
+                principal_hrn: request.principal_hrn,
+                action_name: request.action_name,
+                resource_hrn: request.resource_hrn,
+                decision: false,
+                reason: format!("Principal is {}", self.principal_status),
+                determining_policies: vec![],
+                policy_annotations: HashMap::new(),
+                principal_status: self.principal_status,
+            });
+        }
+
         Ok(EvaluationDecision {
             principal_hrn: request.principal_hrn,
             action_name: request.action_name,
@@ -228,6 +347,13 @@ impl IamPolicyEvaluator for MockIamPolicyEvaluator {
             } else {
                 "Allowed by IAM mock".to_string()
             },
+            determining_policies: self
+                .determining_policy
+                .iter()
+                .map(|(id, _)| id.clone())
+                .collect(),
+            policy_annotations: self.determining_policy.clone().into_iter().collect(),
+            principal_status: self.principal_status,
         })
     }
 }
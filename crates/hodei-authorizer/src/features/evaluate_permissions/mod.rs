@@ -12,6 +12,14 @@
 //! - `error`: Error types specific to authorization evaluation
 //! - `ports`: Interfaces for cross-context dependencies (cache, logger, metrics, etc.)
 //! - `use_case`: Core authorization evaluation logic
+//! - `audit`: Audit events for authorization decisions
+//! - `clock`: System and fixed clocks for time-based policies
+//! - `circuit_breaker`: Circuit breaker decorators for the cross-context evaluators
+//! - `decision_cache`: Eviction of cached decisions when a principal changes
+//! - `entity_cache`: Short-TTL cache for resolved resource entities
+//! - `policy_cache`: Read-through cache for principals' effective IAM policies
+//! - `request_cache`: Entities resolved once per request, shared across layers
+//! - `resource_hierarchy`: Ancestor resolution for container-based permissions
 //! - `di`: Dependency injection container and factories
 //! - `mocks`: Mock implementations for testing
 //!
@@ -45,22 +53,59 @@
 //! ```
 
 pub mod adapter;
+pub mod audit;
+pub mod circuit_breaker;
+pub mod clock;
+pub mod decision_cache;
 pub mod di;
 pub mod dto;
+pub mod entity_cache;
 pub mod error;
 pub mod mocks;
+pub mod policy_cache;
 pub mod ports;
+pub mod request_cache;
+pub mod resource_hierarchy;
 pub mod use_case;
 
+use std::collections::HashSet;
+
 // Re-export main types for easier access
 pub use dto::{
     AuthorizationContext, AuthorizationDecision, AuthorizationRequest, AuthorizationResponse,
     PolicyImpact,
 };
 
+pub use audit::{AuthorizationEvaluated, DecisionAuditMode, EventBusAuditPublisher};
+
+pub use circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerMetrics, CircuitBreakingIamEvaluator,
+    CircuitBreakingScpEvaluator, CircuitState,
+};
+
+pub use decision_cache::DecisionCacheInvalidationHandler;
+
+pub use entity_cache::{
+    CachingEntityResolver, DEFAULT_ENTITY_CACHE_TTL, EntityCacheInvalidationHandler, ResourceChanged,
+};
+
+pub use policy_cache::{
+    CachingEffectivePoliciesQuery, DEFAULT_POLICY_CACHE_TTL, PolicyAttached,
+    PolicyCacheInvalidationHandler, PolicyDetached,
+};
+
+pub use request_cache::{RequestScopedEntityResolver, with_request_scope};
+
+pub use clock::{FixedClock, SystemClock};
+
 pub use error::{EvaluatePermissionsError, EvaluatePermissionsResult};
 
-pub use ports::{AuthorizationCache, AuthorizationLogger, AuthorizationMetrics};
+pub use ports::{
+    AuthorizationAuditPublisher, AuthorizationCache, AuthorizationLogger, AuthorizationMetrics, Clock,
+    ResourceHierarchyError, ResourceHierarchyPort,
+};
+
+pub use resource_hierarchy::{DEFAULT_MAX_HIERARCHY_DEPTH, ResourceAncestryResolver};
 
 pub use use_case::EvaluatePermissionsUseCase;
 
@@ -70,6 +115,39 @@ pub use di::{EvaluatePermissionsContainer, EvaluatePermissionsContainerBuilder,
 #[cfg(test)]
 pub use mocks::{MockAuthorizationCache, MockAuthorizationLogger, MockAuthorizationMetrics};
 
+/// What to do when SCPs can't be evaluated because the organization boundary
+/// provider is unavailable
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ScpFailurePolicy {
+    /// Deny every request (default)
+    #[default]
+    FailClosed,
+    /// Skip the SCP layer for these actions only; IAM policies still apply.
+    /// Every other action is denied as with `FailClosed`.
+    FailOpen { safe_actions: HashSet<String> },
+}
+
+impl ScpFailurePolicy {
+    /// Fail open for the given actions (e.g. critical reads)
+    pub fn fail_open_for<I, S>(safe_actions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::FailOpen {
+            safe_actions: safe_actions.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Whether `action` may be evaluated without SCPs
+    pub fn allows_skipping_scps(&self, action: &str) -> bool {
+        match self {
+            Self::FailClosed => false,
+            Self::FailOpen { safe_actions } => safe_actions.contains(action),
+        }
+    }
+}
+
 /// Feature version and metadata
 pub const FEATURE_VERSION: &str = "1.0.0";
 pub const FEATURE_NAME: &str = "evaluate_permissions";
@@ -85,8 +163,10 @@ pub struct EvaluatePermissionsConfig {
     pub detailed_logging: bool,
     /// Enable/disable metrics collection
     pub metrics_enabled: bool,
-    /// Maximum evaluation time in milliseconds
+    /// Maximum time each policy layer (IAM, SCPs) may take, in milliseconds
     pub max_evaluation_time_ms: u64,
+    /// Behaviour when the organization boundary (SCPs) can't be resolved
+    pub scp_failure_policy: ScpFailurePolicy,
 }
 
 impl Default for EvaluatePermissionsConfig {
@@ -97,6 +177,7 @@ impl Default for EvaluatePermissionsConfig {
             detailed_logging: true,
             metrics_enabled: true,
             max_evaluation_time_ms: 5000, // 5 seconds
+            scp_failure_policy: ScpFailurePolicy::FailClosed,
         }
     }
 }
@@ -136,6 +217,12 @@ impl EvaluatePermissionsConfig {
         self.max_evaluation_time_ms = time_ms;
         self
     }
+
+    /// Set the behaviour when SCPs can't be resolved
+    pub fn with_scp_failure_policy(mut self, policy: ScpFailurePolicy) -> Self {
+        self.scp_failure_policy = policy;
+        self
+    }
 }
 
 /// Utility functions for the evaluate permissions feature
@@ -148,6 +235,11 @@ pub mod utils {
         Duration::from_secs(config.cache_ttl_secs)
     }
 
+    /// Convert the configured evaluation time to the per-layer timeout
+    pub fn layer_timeout(config: &EvaluatePermissionsConfig) -> Duration {
+        Duration::from_millis(config.max_evaluation_time_ms)
+    }
+
     /// Generate a cache key for authorization requests
     pub fn generate_cache_key(request: &AuthorizationRequest) -> String {
         format!(
@@ -221,6 +313,7 @@ mod feature_tests {
         assert!(config.detailed_logging);
         assert!(config.metrics_enabled);
         assert_eq!(config.max_evaluation_time_ms, 5000);
+        assert_eq!(config.scp_failure_policy, ScpFailurePolicy::FailClosed);
     }
 
     #[test]
@@ -239,6 +332,15 @@ mod feature_tests {
         assert_eq!(config.max_evaluation_time_ms, 10000);
     }
 
+    #[test]
+    fn test_scp_failure_policy() {
+        assert!(!ScpFailurePolicy::FailClosed.allows_skipping_scps("read"));
+
+        let policy = ScpFailurePolicy::fail_open_for(["read", "list"]);
+        assert!(policy.allows_skipping_scps("read"));
+        assert!(!policy.allows_skipping_scps("delete"));
+    }
+
     #[test]
     fn test_utils_generate_cache_key() {
         let principal = create_test_hrn("user", "alice");
@@ -287,4 +389,11 @@ mod feature_tests {
         let duration = utils::ttl_to_duration(&config);
         assert_eq!(duration, Duration::from_secs(120));
     }
+
+    #[test]
+    fn test_utils_layer_timeout() {
+        let config = EvaluatePermissionsConfig::new().with_max_evaluation_time(250);
+        let timeout = utils::layer_timeout(&config);
+        assert_eq!(timeout, Duration::from_millis(250));
+    }
 }
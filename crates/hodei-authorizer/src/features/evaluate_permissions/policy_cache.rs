@@ -0,0 +1,682 @@
+//! Read-through cache for effective IAM policies
+//!
+//! Resolving a principal's effective policies walks its group memberships and
+//! every policy attached along the way, and happens on every authorization.
+//! [`CachingEffectivePoliciesQuery`] wraps any [`EffectivePoliciesQueryPort`]
+//! and keeps each principal's policy set for a TTL, keyed by principal HRN.
+//!
+//! Changes are applied through events rather than waiting for the TTL (see
+//! [`PolicyCacheInvalidationHandler`]):
+//!
+//! - [`PolicyDetached`] evicts every principal whose cached set contains the
+//!   policy, so a detached policy stops applying on the next request no matter
+//!   whether it reached the principal directly or through a group.
+//! - [`GroupMembershipChanged`] evicts the user that joined or left the group.
+//!   When the member is itself a group, every user nested below it is
+//!   affected, so every entry is dropped.
+//! - [`PolicyAttached`] evicts the principal it was attached to. When it was
+//!   attached to a group the cache can't tell who the members are (the port
+//!   only returns policies), so every entry is dropped instead.
+//! - [`UserStatusChanged`] evicts the user, whose cached set was resolved
+//!   under its previous status.
+//! - [`UserAttributesChanged`] evicts the user, and [`GroupAttributesChanged`]
+//!   drops every entry, so the next request resolves the principal as it is
+//!   now rather than as it was when its set was cached.
+//!
+//! Concurrent misses for the same principal share a single fetch.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use cedar_policy::PolicyId;
+use kernel::application::ports::event_bus::{DomainEvent, EventEnvelope, EventHandler};
+use kernel::application::ports::{
+    EffectivePoliciesQuery, EffectivePoliciesQueryPort, EffectivePoliciesResult,
+};
+use kernel::{
+    GroupAttributesChanged, GroupMembershipChanged, Hrn, UserAttributesChanged, UserStatusChanged,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+/// How long a principal's policy set is reused when no TTL is configured
+pub const DEFAULT_POLICY_CACHE_TTL: Duration = Duration::from_secs(30);
+
+type QueryError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Event published when an IAM policy is attached to a user or group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyAttached {
+    /// Identifier of the policy, as it appears in effective policy sets
+    pub policy_id: String,
+    /// HRN of the user or group the policy was attached to
+    pub target_hrn: Hrn,
+    /// Timestamp when the policy was attached
+    #[serde(with = "time::serde::rfc3339")]
+    pub attached_at: time::OffsetDateTime,
+}
+
+impl PolicyAttached {
+    pub fn new(policy_id: impl Into<String>, target_hrn: Hrn) -> Self {
+        Self {
+            policy_id: policy_id.into(),
+            target_hrn,
+            attached_at: time::OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+impl DomainEvent for PolicyAttached {
+    fn event_type(&self) -> &'static str {
+        "iam.policy.attached"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.target_hrn.to_string())
+    }
+}
+
+/// Event published when an IAM policy is detached from a user or group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyDetached {
+    /// Identifier of the policy, as it appears in effective policy sets
+    pub policy_id: String,
+    /// HRN of the user or group the policy was detached from
+    pub target_hrn: Hrn,
+    /// Timestamp when the policy was detached
+    #[serde(with = "time::serde::rfc3339")]
+    pub detached_at: time::OffsetDateTime,
+}
+
+impl PolicyDetached {
+    pub fn new(policy_id: impl Into<String>, target_hrn: Hrn) -> Self {
+        Self {
+            policy_id: policy_id.into(),
+            target_hrn,
+            detached_at: time::OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+impl DomainEvent for PolicyDetached {
+    fn event_type(&self) -> &'static str {
+        "iam.policy.detached"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.target_hrn.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedPolicies {
+    result: EffectivePoliciesResult,
+    fetched_at: Instant,
+}
+
+/// Slot for one principal; empty while the first fetch is in flight
+type PolicySlot = Arc<OnceCell<CachedPolicies>>;
+
+/// Effective policies decorator that caches each principal's policy set
+pub struct CachingEffectivePoliciesQuery<Q> {
+    inner: Q,
+    ttl: Duration,
+    entries: Mutex<HashMap<String, PolicySlot>>,
+}
+
+impl<Q: EffectivePoliciesQueryPort> CachingEffectivePoliciesQuery<Q> {
+    pub fn new(inner: Q) -> Self {
+        Self::with_ttl(inner, DEFAULT_POLICY_CACHE_TTL)
+    }
+
+    pub fn with_ttl(inner: Q, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Drop the cached policy set of `principal`, if any
+    ///
+    /// A fetch already in flight still completes for the requests waiting on
+    /// it, but its result is not reused afterwards.
+    pub fn invalidate_principal(&self, principal: &Hrn) {
+        if self
+            .entries
+            .lock()
+            .unwrap()
+            .remove(&principal.to_string())
+            .is_some()
+        {
+            debug!(principal_hrn = %principal, "Invalidated cached effective policies");
+        }
+    }
+
+    /// Drop every cached policy set containing `policy_id`
+    ///
+    /// Fetches still in flight are dropped too, since they may have read the
+    /// policy before the change.
+    pub fn invalidate_policy(&self, policy_id: &str) {
+        let id = PolicyId::new(policy_id);
+        let mut entries = self.entries.lock().unwrap();
+        let before = entries.len();
+        entries.retain(|_, slot| {
+            slot.get()
+                .is_some_and(|cached| cached.result.policies.policy(&id).is_none())
+        });
+        debug!(
+            policy_id,
+            evicted = before - entries.len(),
+            "Invalidated cached effective policies containing policy"
+        );
+    }
+
+    /// Drop every cached policy set
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Number of principals currently cached or being fetched
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Slot to read `principal` from, replacing it when the cached set is stale
+    fn slot(&self, principal: &str) -> PolicySlot {
+        let mut entries = self.entries.lock().unwrap();
+        let fresh = entries.get(principal).filter(|slot| {
+            slot.get()
+                .is_none_or(|cached| cached.fetched_at.elapsed() < self.ttl)
+        });
+
+        match fresh {
+            Some(slot) => slot.clone(),
+            None => {
+                let slot = PolicySlot::default();
+                entries.insert(principal.to_string(), slot.clone());
+                slot
+            }
+        }
+    }
+}
+
+/// Cache key for a principal given as a string
+///
+/// Parsed HRNs are keyed by their canonical form so that invalidation by
+/// [`Hrn`] finds them.
+fn cache_key(principal_hrn: &str) -> String {
+    Hrn::from_string(principal_hrn)
+        .map(|hrn| hrn.to_string())
+        .unwrap_or_else(|| principal_hrn.to_string())
+}
+
+#[async_trait]
+impl<Q: EffectivePoliciesQueryPort> EffectivePoliciesQueryPort
+    for CachingEffectivePoliciesQuery<Q>
+{
+    async fn get_effective_policies(
+        &self,
+        query: EffectivePoliciesQuery,
+    ) -> Result<EffectivePoliciesResult, QueryError> {
+        let key = cache_key(&query.principal_hrn);
+        let slot = self.slot(&key);
+        let cached = slot
+            .get_or_try_init(|| async {
+                debug!(principal_hrn = %key, "Resolving effective policies (cache miss)");
+                let result = self.inner.get_effective_policies(query).await?;
+                Ok::<_, QueryError>(CachedPolicies {
+                    result,
+                    fetched_at: Instant::now(),
+                })
+            })
+            .await?;
+
+        Ok(cached.result.clone())
+    }
+}
+
+/// Event handler that evicts policy sets from a
+/// [`CachingEffectivePoliciesQuery`] when attachments, memberships or user
+/// status change
+pub struct PolicyCacheInvalidationHandler<Q> {
+    cache: Arc<CachingEffectivePoliciesQuery<Q>>,
+}
+
+impl<Q> PolicyCacheInvalidationHandler<Q> {
+    pub fn new(cache: Arc<CachingEffectivePoliciesQuery<Q>>) -> Self {
+        Self { cache }
+    }
+}
+
+fn is_group(hrn: &Hrn) -> bool {
+    hrn.resource_type().eq_ignore_ascii_case("group")
+}
+
+#[async_trait]
+impl<Q: EffectivePoliciesQueryPort + 'static> EventHandler<PolicyAttached>
+    for PolicyCacheInvalidationHandler<Q>
+{
+    fn name(&self) -> &'static str {
+        "policy-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<PolicyAttached>) -> anyhow::Result<()> {
+        let target = &envelope.event.target_hrn;
+        if is_group(target) {
+            debug!(group_hrn = %target, "Policy attached to group, clearing effective policies cache");
+            self.cache.clear();
+        } else {
+            self.cache.invalidate_principal(target);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Q: EffectivePoliciesQueryPort + 'static> EventHandler<PolicyDetached>
+    for PolicyCacheInvalidationHandler<Q>
+{
+    fn name(&self) -> &'static str {
+        "policy-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<PolicyDetached>) -> anyhow::Result<()> {
+        self.cache.invalidate_policy(&envelope.event.policy_id);
+        self.cache.invalidate_principal(&envelope.event.target_hrn);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Q: EffectivePoliciesQueryPort + 'static> EventHandler<GroupMembershipChanged>
+    for PolicyCacheInvalidationHandler<Q>
+{
+    fn name(&self) -> &'static str {
+        "policy-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<GroupMembershipChanged>) -> anyhow::Result<()> {
+        let member = &envelope.event.member_hrn;
+        if is_group(member) {
+            debug!(group_hrn = %member, "Nested group membership changed, clearing effective policies cache");
+            self.cache.clear();
+        } else {
+            self.cache.invalidate_principal(member);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Q: EffectivePoliciesQueryPort + 'static> EventHandler<UserStatusChanged>
+    for PolicyCacheInvalidationHandler<Q>
+{
+    fn name(&self) -> &'static str {
+        "policy-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<UserStatusChanged>) -> anyhow::Result<()> {
+        self.cache.invalidate_principal(&envelope.event.user_hrn);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Q: EffectivePoliciesQueryPort + 'static> EventHandler<UserAttributesChanged>
+    for PolicyCacheInvalidationHandler<Q>
+{
+    fn name(&self) -> &'static str {
+        "policy-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<UserAttributesChanged>) -> anyhow::Result<()> {
+        self.cache.invalidate_principal(&envelope.event.user_hrn);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Q: EffectivePoliciesQueryPort + 'static> EventHandler<GroupAttributesChanged>
+    for PolicyCacheInvalidationHandler<Q>
+{
+    fn name(&self) -> &'static str {
+        "policy-cache-invalidation"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<GroupAttributesChanged>) -> anyhow::Result<()> {
+        debug!(
+            group_hrn = %envelope.event.group_hrn,
+            "Group attributes changed, clearing effective policies cache"
+        );
+        self.cache.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cedar_policy::{Policy, PolicySet};
+    use kernel::InMemoryEventBus;
+    use kernel::application::ports::event_bus::{EventBus, EventPublisher, Subscription};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// IAM port backed by a principal -> policy ids map, counting fetches
+    #[derive(Default)]
+    struct CountingIam {
+        policies: Mutex<HashMap<String, Vec<&'static str>>>,
+        fetches: AtomicUsize,
+    }
+
+    impl CountingIam {
+        fn grant(&self, principal: &Hrn, policy_ids: &[&'static str]) {
+            self.policies
+                .lock()
+                .unwrap()
+                .insert(principal.to_string(), policy_ids.to_vec());
+        }
+
+        fn fetches(&self) -> usize {
+            self.fetches.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl EffectivePoliciesQueryPort for CountingIam {
+        async fn get_effective_policies(
+            &self,
+            query: EffectivePoliciesQuery,
+        ) -> Result<EffectivePoliciesResult, QueryError> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let ids = self
+                .policies
+                .lock()
+                .unwrap()
+                .get(&query.principal_hrn)
+                .cloned()
+                .ok_or("principal not found")?;
+
+            let mut policies = PolicySet::new();
+            for id in &ids {
+                let policy = Policy::parse(
+                    Some(PolicyId::new(*id)),
+                    "permit(principal, action, resource);",
+                )?;
+                policies.add(policy)?;
+            }
+            Ok(EffectivePoliciesResult {
+                policies,
+                policy_count: ids.len(),
+                principal_status: kernel::PrincipalStatus::Active,
+            })
+        }
+    }
+
+    fn iam(resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "aws".to_string(),
+            "iam".to_string(),
+            "default".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn query(principal: &Hrn) -> EffectivePoliciesQuery {
+        EffectivePoliciesQuery {
+            principal_hrn: principal.to_string(),
+        }
+    }
+
+    async fn subscribed(
+        cache: &Arc<CachingEffectivePoliciesQuery<Arc<CountingIam>>>,
+    ) -> (InMemoryEventBus, Vec<Arc<dyn Subscription>>) {
+        let bus = InMemoryEventBus::new();
+        let handler = Arc::new(PolicyCacheInvalidationHandler::new(cache.clone()));
+        let subscriptions = vec![
+            bus.subscribe::<PolicyAttached, _>(handler.clone())
+                .await
+                .unwrap(),
+            bus.subscribe::<PolicyDetached, _>(handler.clone())
+                .await
+                .unwrap(),
+            bus.subscribe::<GroupMembershipChanged, _>(handler.clone())
+                .await
+                .unwrap(),
+            bus.subscribe::<UserStatusChanged, _>(handler.clone())
+                .await
+                .unwrap(),
+            bus.subscribe::<UserAttributesChanged, _>(handler.clone())
+                .await
+                .unwrap(),
+            bus.subscribe::<GroupAttributesChanged, _>(handler)
+                .await
+                .unwrap(),
+        ];
+        (bus, subscriptions)
+    }
+
+    #[tokio::test]
+    async fn reuses_policy_sets_within_ttl() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        inner.grant(&alice, &["read-all"]);
+        let cache = CachingEffectivePoliciesQuery::new(inner.clone());
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        let second = cache.get_effective_policies(query(&alice)).await.unwrap();
+
+        assert_eq!(inner.fetches(), 1);
+        assert_eq!(second.policy_count, 1);
+    }
+
+    #[tokio::test]
+    async fn refetches_after_ttl_and_does_not_cache_errors() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        inner.grant(&alice, &["read-all"]);
+        let cache =
+            CachingEffectivePoliciesQuery::with_ttl(inner.clone(), Duration::from_millis(10));
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(15)).await;
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        assert_eq!(inner.fetches(), 2);
+
+        let ghost = iam("User", "ghost");
+        for _ in 0..2 {
+            assert!(cache.get_effective_policies(query(&ghost)).await.is_err());
+        }
+        assert_eq!(inner.fetches(), 4);
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_share_one_fetch() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        inner.grant(&alice, &["read-all"]);
+        let cache = Arc::new(CachingEffectivePoliciesQuery::new(inner.clone()));
+
+        let lookups = (0..8).map(|_| {
+            let cache = cache.clone();
+            let query = query(&alice);
+            tokio::spawn(async move { cache.get_effective_policies(query).await.map(|_| ()) })
+        });
+        for lookup in lookups.collect::<Vec<_>>() {
+            lookup.await.unwrap().unwrap();
+        }
+
+        assert_eq!(inner.fetches(), 1);
+    }
+
+    #[tokio::test]
+    async fn detached_policy_evicts_only_principals_holding_it() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        let bob = iam("User", "bob");
+        inner.grant(&alice, &["read-all", "deploy"]);
+        inner.grant(&bob, &["read-all"]);
+        let cache = Arc::new(CachingEffectivePoliciesQuery::new(inner.clone()));
+        let (bus, _subscriptions) = subscribed(&cache).await;
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+
+        // "deploy" reached alice through a group
+        inner.grant(&alice, &["read-all"]);
+        bus.publish(PolicyDetached::new("deploy", iam("Group", "deployers")))
+            .await
+            .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        let alice_policies = cache.get_effective_policies(query(&alice)).await.unwrap();
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+
+        assert_eq!(inner.fetches(), 3);
+        assert_eq!(alice_policies.policy_count, 1);
+        assert!(
+            alice_policies
+                .policies
+                .policy(&PolicyId::new("deploy"))
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn membership_change_evicts_the_user() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        let bob = iam("User", "bob");
+        inner.grant(&alice, &["read-all"]);
+        inner.grant(&bob, &["read-all"]);
+        let cache = Arc::new(CachingEffectivePoliciesQuery::new(inner.clone()));
+        let (bus, _subscriptions) = subscribed(&cache).await;
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+        bus.publish(GroupMembershipChanged::joined(
+            alice.clone(),
+            iam("Group", "devs"),
+        ))
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        assert_eq!(cache.len(), 1);
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+        assert_eq!(inner.fetches(), 2);
+    }
+
+    #[tokio::test]
+    async fn status_change_evicts_the_user() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        let bob = iam("User", "bob");
+        inner.grant(&alice, &["read-all"]);
+        inner.grant(&bob, &["read-all"]);
+        let cache = Arc::new(CachingEffectivePoliciesQuery::new(inner.clone()));
+        let (bus, _subscriptions) = subscribed(&cache).await;
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+        bus.publish(UserStatusChanged {
+            user_hrn: alice.clone(),
+            previous_status: kernel::PrincipalStatus::Active,
+            status: kernel::PrincipalStatus::Suspended,
+        })
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn attribute_changes_evict_the_user_or_everything() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        let bob = iam("User", "bob");
+        inner.grant(&alice, &["read-all"]);
+        inner.grant(&bob, &["read-all"]);
+        let cache = Arc::new(CachingEffectivePoliciesQuery::new(inner.clone()));
+        let (bus, _subscriptions) = subscribed(&cache).await;
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+        bus.publish(UserAttributesChanged::new(alice.clone(), Vec::new()))
+            .await
+            .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+        assert_eq!(cache.len(), 1);
+
+        bus.publish(GroupAttributesChanged::new(
+            iam("Group", "devs"),
+            Vec::new(),
+        ))
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn nested_group_membership_change_clears_everything() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        let bob = iam("User", "bob");
+        inner.grant(&alice, &["read-all"]);
+        inner.grant(&bob, &["read-all"]);
+        let cache = Arc::new(CachingEffectivePoliciesQuery::new(inner.clone()));
+        let (bus, _subscriptions) = subscribed(&cache).await;
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+        bus.publish(GroupMembershipChanged::joined(
+            iam("Group", "backend"),
+            iam("Group", "engineering"),
+        ))
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn attachment_to_group_clears_everything() {
+        let inner = Arc::new(CountingIam::default());
+        let alice = iam("User", "alice");
+        let bob = iam("User", "bob");
+        inner.grant(&alice, &["read-all"]);
+        inner.grant(&bob, &["read-all"]);
+        let cache = Arc::new(CachingEffectivePoliciesQuery::new(inner.clone()));
+        let (bus, _subscriptions) = subscribed(&cache).await;
+
+        cache.get_effective_policies(query(&alice)).await.unwrap();
+        cache.get_effective_policies(query(&bob)).await.unwrap();
+        bus.publish(PolicyAttached::new("deploy", alice.clone()))
+            .await
+            .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+        assert_eq!(cache.len(), 1);
+
+        bus.publish(PolicyAttached::new("deploy", iam("Group", "devs")))
+            .await
+            .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+        assert!(cache.is_empty());
+    }
+}
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use cedar_policy::PolicySet;
 use std::sync::Arc;
 
+use crate::features::evaluate_permissions::audit::AuthorizationEvaluated;
 use crate::features::evaluate_permissions::dto::{AuthorizationRequest, AuthorizationResponse};
 use crate::features::evaluate_permissions::error::EvaluatePermissionsResult;
 use kernel::Hrn;
@@ -47,6 +48,9 @@ pub trait AuthorizationCache: Send + Sync {
     ) -> EvaluatePermissionsResult<()>;
     async fn invalidate_principal(&self, principal_hrn: &Hrn) -> EvaluatePermissionsResult<()>;
     async fn invalidate_resource(&self, resource_hrn: &Hrn) -> EvaluatePermissionsResult<()>;
+    /// Drop every cached decision, for changes whose affected principals
+    /// the cache can't tell apart
+    async fn invalidate_all(&self) -> EvaluatePermissionsResult<()>;
 }
 
 #[async_trait]
@@ -74,6 +78,10 @@ impl<T: AuthorizationCache> AuthorizationCache for Arc<T> {
     async fn invalidate_resource(&self, resource_hrn: &Hrn) -> EvaluatePermissionsResult<()> {
         (**self).invalidate_resource(resource_hrn).await
     }
+
+    async fn invalidate_all(&self) -> EvaluatePermissionsResult<()> {
+        (**self).invalidate_all().await
+    }
 }
 
 /// Trait for logging authorization decisions and errors
@@ -141,6 +149,27 @@ impl<T: AuthorizationMetrics> AuthorizationMetrics for Arc<T> {
     }
 }
 
+/// Source of the current time for an evaluation
+///
+/// Read once per evaluation, so every layer sees the same `current_time`.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> time::OffsetDateTime;
+}
+
+/// Trait for publishing authorization decisions to the audit trail
+///
+/// Called on the response path, so implementations must hand the event off
+/// (spawned task, outbox, channel) and return without waiting for delivery.
+pub trait AuthorizationAuditPublisher: Send + Sync {
+    fn publish(&self, event: AuthorizationEvaluated);
+}
+
+impl<T: AuthorizationAuditPublisher> AuthorizationAuditPublisher for Arc<T> {
+    fn publish(&self, event: AuthorizationEvaluated) {
+        (**self).publish(event)
+    }
+}
+
 /// Trait for resolving Hodei entities from HRNs
 ///
 /// This trait provides a way to obtain real entity implementations
@@ -191,6 +220,42 @@ pub enum EntityResolverError {
     BatchResolutionFailed(Vec<Hrn>),
 }
 
+/// Trait for resolving the containers a resource lives in
+///
+/// Implemented by the context that owns the resource (e.g. an artifact
+/// belongs to a repository). Only direct parents are returned; the authorizer
+/// walks the chain itself.
+#[async_trait]
+pub trait ResourceHierarchyPort: Send + Sync {
+    /// Direct parents of a resource; empty for top-level resources
+    async fn parents_of(&self, resource: &Hrn) -> Result<Vec<Hrn>, ResourceHierarchyError>;
+}
+
+#[async_trait]
+impl<T: ResourceHierarchyPort> ResourceHierarchyPort for Arc<T> {
+    async fn parents_of(&self, resource: &Hrn) -> Result<Vec<Hrn>, ResourceHierarchyError> {
+        (**self).parents_of(resource).await
+    }
+}
+
+/// Errors that can occur while resolving a resource hierarchy
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ResourceHierarchyError {
+    #[error("Hierarchy lookup failed for {hrn}: {message}")]
+    LookupFailed { hrn: Hrn, message: String },
+    #[error("Cycle in resource hierarchy: {}", format_hrn_path(.0))]
+    Cycle(Vec<Hrn>),
+    #[error("Resource hierarchy of {resource} is deeper than {max_depth} levels")]
+    DepthExceeded { resource: Hrn, max_depth: usize },
+}
+
+fn format_hrn_path(path: &[Hrn]) -> String {
+    path.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
 /// Errors related to authorization ports
 #[derive(Debug, thiserror::Error)]
 pub enum AuthorizationError {
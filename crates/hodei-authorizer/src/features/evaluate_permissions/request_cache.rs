@@ -0,0 +1,318 @@
+//! Entities resolved once per authorization request
+//!
+//! The IAM and SCP layers resolve their entities independently, so a single
+//! authorization request can fetch the same account or resource more than
+//! once. [`RequestScopedEntityResolver`] wraps any [`EntityResolverPort`] and
+//! dedupes those lookups within one request: the first layer to ask for an
+//! HRN fetches it, every later lookup in the same request reuses it.
+//!
+//! The cache lives only as long as [`with_request_scope`] runs its future;
+//! [`EvaluatePermissionsUseCase::execute`](super::EvaluatePermissionsUseCase::execute)
+//! opens one scope per call, so nothing leaks from one request to the next.
+//! Outside a scope the resolver passes every lookup through.
+//!
+//! The resolver also implements the principal and resource resolver ports
+//! of the IAM layer, so the evaluator built by
+//! [`factories::create_iam_evaluator`](super::di::factories::create_iam_evaluator)
+//! shares its lookups with every other layer given the same resolver.
+//!
+//! Unlike [`CachingEntityResolver`](super::CachingEntityResolver) there is no
+//! TTL and no invalidation: a request sees the entity as it was when first
+//! resolved.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use hodei_iam::features::evaluate_iam_policies::ports::{
+    EntityResolverError as IamEntityResolverError, PrincipalResolverPort, ResourceResolverPort,
+};
+use kernel::{HodeiEntity, Hrn};
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+use super::entity_cache::SharedEntity;
+use super::ports::{EntityResolverError, EntityResolverPort};
+
+/// Slot for one HRN; empty while the first fetch is in flight
+type RequestSlot = Arc<OnceCell<Arc<dyn HodeiEntity>>>;
+
+/// Entities resolved so far in the current request
+#[derive(Default)]
+struct RequestEntities {
+    entries: Mutex<HashMap<Hrn, RequestSlot>>,
+}
+
+tokio::task_local! {
+    static REQUEST_ENTITIES: RequestEntities;
+}
+
+/// Run `future` with its own request-scoped entity cache
+///
+/// The cache is dropped when `future` completes. A scope opened inside
+/// another one shares the outer cache.
+pub async fn with_request_scope<F: Future>(future: F) -> F::Output {
+    if REQUEST_ENTITIES.try_with(|_| ()).is_ok() {
+        return future.await;
+    }
+    REQUEST_ENTITIES
+        .scope(RequestEntities::default(), future)
+        .await
+}
+
+/// Slot to read `hrn` from in the current request, `None` outside a scope
+fn request_slot(hrn: &Hrn) -> Option<RequestSlot> {
+    REQUEST_ENTITIES
+        .try_with(|entities| {
+            entities
+                .entries
+                .lock()
+                .unwrap()
+                .entry(hrn.clone())
+                .or_default()
+                .clone()
+        })
+        .ok()
+}
+
+/// Entity resolver decorator that resolves each HRN at most once per request
+pub struct RequestScopedEntityResolver<R> {
+    inner: R,
+}
+
+impl<R: EntityResolverPort> RequestScopedEntityResolver<R> {
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Entity at `hrn`, fetched on the first lookup of the current request
+    async fn resolve_shared(&self, hrn: &Hrn) -> Result<SharedEntity, EntityResolverError> {
+        let Some(slot) = request_slot(hrn) else {
+            return self
+                .inner
+                .resolve(hrn)
+                .await
+                .map(|e| SharedEntity(e.into()));
+        };
+
+        let entity = slot
+            .get_or_try_init(|| async {
+                debug!(hrn = %hrn, "Resolving entity (first lookup in request)");
+                self.inner.resolve(hrn).await.map(Arc::from)
+            })
+            .await?;
+
+        Ok(SharedEntity(entity.clone()))
+    }
+}
+
+#[async_trait]
+impl<R: EntityResolverPort> EntityResolverPort for RequestScopedEntityResolver<R> {
+    async fn resolve(&self, hrn: &Hrn) -> Result<Box<dyn HodeiEntity>, EntityResolverError> {
+        Ok(Box::new(self.resolve_shared(hrn).await?))
+    }
+
+    async fn resolve_batch(
+        &self,
+        hrns: &[Hrn],
+    ) -> Result<Vec<Box<dyn HodeiEntity>>, EntityResolverError> {
+        let mut entities = Vec::with_capacity(hrns.len());
+        let mut missing = Vec::new();
+
+        for hrn in hrns {
+            match self.resolve(hrn).await {
+                Ok(entity) => entities.push(entity),
+                Err(EntityResolverError::NotFound(hrn)) => missing.push(hrn),
+                Err(e) => return Err(e),
+            }
+        }
+
+        if missing.is_empty() {
+            Ok(entities)
+        } else {
+            Err(EntityResolverError::BatchResolutionFailed(missing))
+        }
+    }
+}
+
+#[async_trait]
+impl<R: EntityResolverPort> PrincipalResolverPort for RequestScopedEntityResolver<R> {
+    async fn resolve_principal(
+        &self,
+        principal_hrn: &Hrn,
+    ) -> Result<Box<dyn HodeiEntity + Send>, IamEntityResolverError> {
+        let entity = self.resolve_shared(principal_hrn).await;
+        Ok(Box::new(entity.map_err(to_iam_error)?))
+    }
+}
+
+#[async_trait]
+impl<R: EntityResolverPort> ResourceResolverPort for RequestScopedEntityResolver<R> {
+    async fn resolve_resource(
+        &self,
+        resource_hrn: &Hrn,
+    ) -> Result<Box<dyn HodeiEntity + Send>, IamEntityResolverError> {
+        let entity = self.resolve_shared(resource_hrn).await;
+        Ok(Box::new(entity.map_err(to_iam_error)?))
+    }
+}
+
+fn to_iam_error(error: EntityResolverError) -> IamEntityResolverError {
+    match error {
+        EntityResolverError::NotFound(hrn) => {
+            IamEntityResolverError::EntityNotFound(hrn.to_string())
+        }
+        EntityResolverError::InvalidType(message) => {
+            IamEntityResolverError::UnsupportedEntityType(message)
+        }
+        other => IamEntityResolverError::RepositoryError(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kernel::{AttributeName, AttributeValue};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct TestEntity {
+        hrn: Hrn,
+    }
+
+    impl HodeiEntity for TestEntity {
+        fn hrn(&self) -> &Hrn {
+            &self.hrn
+        }
+
+        fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+            HashMap::new()
+        }
+    }
+
+    #[derive(Default)]
+    struct CountingResolver {
+        fetches: AtomicUsize,
+    }
+
+    impl CountingResolver {
+        fn fetches(&self) -> usize {
+            self.fetches.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl EntityResolverPort for CountingResolver {
+        async fn resolve(&self, hrn: &Hrn) -> Result<Box<dyn HodeiEntity>, EntityResolverError> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            if hrn.resource_id() == "missing" {
+                return Err(EntityResolverError::NotFound(hrn.clone()));
+            }
+            Ok(Box::new(TestEntity { hrn: hrn.clone() }))
+        }
+
+        async fn resolve_batch(
+            &self,
+            hrns: &[Hrn],
+        ) -> Result<Vec<Box<dyn HodeiEntity>>, EntityResolverError> {
+            let mut entities = Vec::new();
+            for hrn in hrns {
+                entities.push(self.resolve(hrn).await?);
+            }
+            Ok(entities)
+        }
+    }
+
+    fn account(id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "organizations".to_string(),
+            "default".to_string(),
+            "Account".to_string(),
+            id.to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn resolves_each_entity_once_per_request() {
+        let inner = Arc::new(CountingResolver::default());
+        let resolver = RequestScopedEntityResolver::new(inner.clone());
+
+        with_request_scope(async {
+            resolver.resolve(&account("a")).await.unwrap();
+            resolver.resolve(&account("a")).await.unwrap();
+            let batch = resolver
+                .resolve_batch(&[account("a"), account("b")])
+                .await
+                .unwrap();
+            assert_eq!(batch[1].hrn(), &account("b"));
+        })
+        .await;
+
+        assert_eq!(inner.fetches(), 2);
+    }
+
+    #[tokio::test]
+    async fn nothing_is_shared_between_requests() {
+        let inner = Arc::new(CountingResolver::default());
+        let resolver = RequestScopedEntityResolver::new(inner.clone());
+
+        for _ in 0..2 {
+            with_request_scope(async {
+                resolver.resolve(&account("a")).await.unwrap();
+                // A nested scope is part of the same request
+                with_request_scope(resolver.resolve(&account("a")))
+                    .await
+                    .unwrap();
+            })
+            .await;
+        }
+
+        assert_eq!(inner.fetches(), 2);
+    }
+
+    #[tokio::test]
+    async fn iam_layer_ports_share_the_request_cache() {
+        let inner = Arc::new(CountingResolver::default());
+        let resolver = RequestScopedEntityResolver::new(inner.clone());
+
+        with_request_scope(async {
+            // The IAM layer resolves what another layer already fetched
+            resolver.resolve(&account("a")).await.unwrap();
+            let principal = resolver.resolve_principal(&account("a")).await.unwrap();
+            let resource = resolver.resolve_resource(&account("b")).await.unwrap();
+            assert_eq!(principal.hrn(), &account("a"));
+            assert_eq!(resource.hrn(), &account("b"));
+
+            assert!(matches!(
+                resolver.resolve_resource(&account("missing")).await,
+                Err(IamEntityResolverError::EntityNotFound(_))
+            ));
+        })
+        .await;
+
+        assert_eq!(inner.fetches(), 3);
+    }
+
+    #[tokio::test]
+    async fn passes_through_outside_a_request_and_does_not_cache_errors() {
+        let inner = Arc::new(CountingResolver::default());
+        let resolver = RequestScopedEntityResolver::new(inner.clone());
+
+        resolver.resolve(&account("a")).await.unwrap();
+        resolver.resolve(&account("a")).await.unwrap();
+        assert_eq!(inner.fetches(), 2);
+
+        with_request_scope(async {
+            for _ in 0..2 {
+                assert!(matches!(
+                    resolver.resolve(&account("missing")).await,
+                    Err(EntityResolverError::NotFound(_))
+                ));
+            }
+        })
+        .await;
+        assert_eq!(inner.fetches(), 4);
+    }
+}
@@ -0,0 +1,223 @@
+//! Resource hierarchy resolution
+//!
+//! Policies are often written against a container (`resource in
+//! Repository::"libs"`) and must apply to everything inside it. The owning
+//! context only knows a resource's direct parents, so
+//! [`ResourceAncestryResolver`] walks the chain through a
+//! [`ResourceHierarchyPort`] and returns every ancestor. The evaluators then
+//! register those ancestors as Cedar parents of the resource.
+//!
+//! A resource may have several parents, so the same ancestor can be reached
+//! along different paths; that is fine. A resource that turns out to be its
+//! own ancestor is a cycle and fails resolution, as does a chain deeper than
+//! the configured bound.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use kernel::Hrn;
+
+use super::ports::{ResourceHierarchyError, ResourceHierarchyPort};
+
+/// Deepest ancestor chain followed when no bound is configured
+pub const DEFAULT_MAX_HIERARCHY_DEPTH: usize = 16;
+
+/// Resolves all ancestors of a resource with cycle detection and a depth bound
+#[derive(Clone)]
+pub struct ResourceAncestryResolver {
+    port: Arc<dyn ResourceHierarchyPort>,
+    max_depth: usize,
+}
+
+impl ResourceAncestryResolver {
+    pub fn new(port: Arc<dyn ResourceHierarchyPort>) -> Self {
+        Self {
+            port,
+            max_depth: DEFAULT_MAX_HIERARCHY_DEPTH,
+        }
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// All ancestors of `resource`, each listed once, in depth-first order
+    pub async fn ancestors(&self, resource: &Hrn) -> Result<Vec<Hrn>, ResourceHierarchyError> {
+        let mut ancestors = Vec::new();
+        let mut seen = HashSet::new();
+        // Current chain from the resource to the ancestor being expanded, and
+        // the parents of each link still to visit
+        let mut path = vec![resource.clone()];
+        let mut pending = vec![self.port.parents_of(resource).await?.into_iter()];
+
+        while let Some(parents) = pending.last_mut() {
+            let Some(parent) = parents.next() else {
+                pending.pop();
+                path.pop();
+                continue;
+            };
+
+            if let Some(start) = path.iter().position(|hrn| *hrn == parent) {
+                let mut cycle = path[start..].to_vec();
+                cycle.push(parent);
+                return Err(ResourceHierarchyError::Cycle(cycle));
+            }
+            if !seen.insert(parent.clone()) {
+                continue;
+            }
+            if path.len() > self.max_depth {
+                return Err(ResourceHierarchyError::DepthExceeded {
+                    resource: resource.clone(),
+                    max_depth: self.max_depth,
+                });
+            }
+
+            let grandparents = self.port.parents_of(&parent).await?;
+            ancestors.push(parent.clone());
+            path.push(parent);
+            pending.push(grandparents.into_iter());
+        }
+
+        Ok(ancestors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+
+    /// Hierarchy backed by a child -> parents map
+    #[derive(Default)]
+    struct MapHierarchy {
+        parents: HashMap<Hrn, Vec<Hrn>>,
+    }
+
+    impl MapHierarchy {
+        fn with(mut self, child: &Hrn, parents: &[&Hrn]) -> Self {
+            self.parents.insert(
+                child.clone(),
+                parents.iter().map(|p| (*p).clone()).collect(),
+            );
+            self
+        }
+    }
+
+    #[async_trait]
+    impl ResourceHierarchyPort for MapHierarchy {
+        async fn parents_of(&self, resource: &Hrn) -> Result<Vec<Hrn>, ResourceHierarchyError> {
+            if resource.resource_id() == "broken" {
+                return Err(ResourceHierarchyError::LookupFailed {
+                    hrn: resource.clone(),
+                    message: "backend unavailable".to_string(),
+                });
+            }
+            Ok(self.parents.get(resource).cloned().unwrap_or_default())
+        }
+    }
+
+    fn hrn(resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "aws".to_string(),
+            "artifact".to_string(),
+            "default".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn resolver(hierarchy: MapHierarchy) -> ResourceAncestryResolver {
+        ResourceAncestryResolver::new(Arc::new(hierarchy))
+    }
+
+    #[tokio::test]
+    async fn resolves_transitive_ancestors() {
+        let artifact = hrn("package", "lodash");
+        let repository = hrn("repository", "libs");
+        let organization = hrn("organization", "acme");
+        let hierarchy = MapHierarchy::default()
+            .with(&artifact, &[&repository])
+            .with(&repository, &[&organization]);
+
+        let ancestors = resolver(hierarchy).ancestors(&artifact).await.unwrap();
+
+        assert_eq!(ancestors, vec![repository, organization]);
+    }
+
+    #[tokio::test]
+    async fn shared_ancestors_are_not_cycles() {
+        let artifact = hrn("package", "lodash");
+        let libs = hrn("repository", "libs");
+        let mirror = hrn("repository", "mirror");
+        let organization = hrn("organization", "acme");
+        let hierarchy = MapHierarchy::default()
+            .with(&artifact, &[&libs, &mirror])
+            .with(&libs, &[&organization])
+            .with(&mirror, &[&organization]);
+
+        let ancestors = resolver(hierarchy).ancestors(&artifact).await.unwrap();
+
+        assert_eq!(ancestors, vec![libs, organization, mirror]);
+    }
+
+    #[tokio::test]
+    async fn detects_cycles() {
+        let artifact = hrn("package", "lodash");
+        let a = hrn("folder", "a");
+        let b = hrn("folder", "b");
+        let hierarchy = MapHierarchy::default()
+            .with(&artifact, &[&a])
+            .with(&a, &[&b])
+            .with(&b, &[&a]);
+
+        let error = resolver(hierarchy).ancestors(&artifact).await.unwrap_err();
+
+        assert!(
+            matches!(error, ResourceHierarchyError::Cycle(ref path) if *path == vec![a.clone(), b, a])
+        );
+    }
+
+    #[tokio::test]
+    async fn bounds_the_depth() {
+        let folders: Vec<Hrn> = (0..5).map(|i| hrn("folder", &i.to_string())).collect();
+        let hierarchy = folders
+            .windows(2)
+            .fold(MapHierarchy::default(), |hierarchy, pair| {
+                hierarchy.with(&pair[0], &[&pair[1]])
+            });
+        let resolver = resolver(hierarchy);
+
+        assert_eq!(
+            resolver
+                .clone()
+                .with_max_depth(4)
+                .ancestors(&folders[0])
+                .await
+                .unwrap()
+                .len(),
+            4
+        );
+        assert!(matches!(
+            resolver.with_max_depth(3).ancestors(&folders[0]).await,
+            Err(ResourceHierarchyError::DepthExceeded { max_depth: 3, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn propagates_lookup_failures() {
+        let artifact = hrn("package", "lodash");
+        let broken = hrn("repository", "broken");
+        let hierarchy = MapHierarchy::default().with(&artifact, &[&broken]);
+
+        assert!(matches!(
+            resolver(hierarchy).ancestors(&artifact).await,
+            Err(ResourceHierarchyError::LookupFailed { .. })
+        ));
+    }
+}
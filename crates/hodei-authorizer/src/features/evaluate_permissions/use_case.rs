@@ -1,7 +1,11 @@
+use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Instant;
-use tracing::{info, instrument, warn};
+use std::time::{Duration, Instant};
+use tracing::{Instrument, info, info_span, instrument, warn};
 
+use crate::features::evaluate_permissions::ScpFailurePolicy;
+use crate::features::evaluate_permissions::audit::{AuthorizationEvaluated, DecisionAuditMode};
+use crate::features::evaluate_permissions::clock::SystemClock;
 use crate::features::evaluate_permissions::dto::{
     AuthorizationDecision, AuthorizationRequest, AuthorizationResponse,
 };
@@ -9,12 +13,18 @@ use crate::features::evaluate_permissions::error::{
     EvaluatePermissionsError, EvaluatePermissionsResult,
 };
 use crate::features::evaluate_permissions::ports::{
-    AuthorizationCache, AuthorizationLogger, AuthorizationMetrics,
+    AuthorizationAuditPublisher, AuthorizationCache, AuthorizationLogger, AuthorizationMetrics,
+    Clock,
 };
+use crate::features::evaluate_permissions::request_cache::with_request_scope;
+use crate::features::evaluate_permissions::resource_hierarchy::ResourceAncestryResolver;
 use kernel::application::ports::authorization::{
-    EvaluationRequest, IamPolicyEvaluator, ScpEvaluator,
+    AuthorizationError, EvaluationDecision, EvaluationRequest, IamPolicyEvaluator, ScpEvaluator,
 };
 
+/// How long each policy layer may take unless configured otherwise
+pub const DEFAULT_LAYER_TIMEOUT: Duration = Duration::from_millis(5000);
+
 /// Use case for evaluating authorization permissions with multi-layer security
 ///
 /// This implementation follows the Single Responsibility Principle:
@@ -31,6 +41,22 @@ pub struct EvaluatePermissionsUseCase<CACHE, LOGGER, METRICS> {
     cache: Option<CACHE>,
     logger: LOGGER,
     metrics: METRICS,
+
+    // Degradation when the organization boundary can't be resolved
+    scp_failure_policy: ScpFailurePolicy,
+
+    // Container chain of the resource, registered as Cedar parents
+    resource_hierarchy: Option<ResourceAncestryResolver>,
+
+    // Audit trail of decisions
+    audit_publisher: Option<Arc<dyn AuthorizationAuditPublisher>>,
+    audit_mode: DecisionAuditMode,
+
+    // Time source for `context.current_time`
+    clock: Arc<dyn Clock>,
+
+    // Longest wait for each policy layer
+    layer_timeout: Duration,
 }
 
 impl<CACHE, LOGGER, METRICS> EvaluatePermissionsUseCase<CACHE, LOGGER, METRICS>
@@ -53,11 +79,65 @@ where
             cache,
             logger,
             metrics,
+            scp_failure_policy: ScpFailurePolicy::default(),
+            resource_hierarchy: None,
+            audit_publisher: None,
+            audit_mode: DecisionAuditMode::default(),
+            clock: Arc::new(SystemClock),
+            layer_timeout: DEFAULT_LAYER_TIMEOUT,
         }
     }
 
+    /// Set the behaviour when SCPs can't be evaluated (fail-closed by default)
+    pub fn with_scp_failure_policy(mut self, policy: ScpFailurePolicy) -> Self {
+        self.scp_failure_policy = policy;
+        self
+    }
+
+    /// Resolve resource ancestors so policies on a container apply to its children
+    pub fn with_resource_hierarchy(mut self, resolver: ResourceAncestryResolver) -> Self {
+        self.resource_hierarchy = Some(resolver);
+        self
+    }
+
+    /// Publish decisions selected by `mode` as `AuthorizationEvaluated` events
+    pub fn with_decision_audit(
+        mut self,
+        publisher: Arc<dyn AuthorizationAuditPublisher>,
+        mode: DecisionAuditMode,
+    ) -> Self {
+        self.audit_publisher = Some(publisher);
+        self.audit_mode = mode;
+        self
+    }
+
+    /// Replace the system clock (e.g. with a `FixedClock` in tests)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Bound the time each policy layer may take
+    ///
+    /// A layer that doesn't answer in time counts as unavailable: a slow SCP
+    /// layer follows the SCP failure policy, a slow IAM layer fails the
+    /// evaluation.
+    pub fn with_layer_timeout(mut self, timeout: Duration) -> Self {
+        self.layer_timeout = timeout;
+        self
+    }
+
     /// Evaluate authorization request with multi-layer security
-    #[instrument(skip(self), fields(principal = %request.principal, resource = %request.resource, action = %request.action))]
+    #[instrument(
+        name = "evaluate_permissions",
+        skip_all,
+        fields(
+            correlation_id = kernel::current_correlation_id().as_deref(),
+            principal = %request.principal,
+            resource = %request.resource,
+            action = %request.action
+        )
+    )]
     pub async fn execute(
         &self,
         request: AuthorizationRequest,
@@ -67,21 +147,29 @@ where
         // Generate cache key and check cache first
         let cache_key = self.generate_cache_key(&request);
         if let Some(ref cache) = self.cache {
-            if let Ok(Some(cached_response)) = cache.get(&cache_key).await {
+            if let Ok(Some(cached_response)) = cache
+                .get(&cache_key)
+                .instrument(info_span!("cache_lookup"))
+                .await
+            {
                 info!("Authorization decision served from cache");
                 self.metrics.record_cache_hit(true).await?;
+                let latency_ms = start_time.elapsed().as_millis() as u64;
+                self.audit_decision(&request, &cached_response, latency_ms, true);
                 return Ok(cached_response);
             }
             self.metrics.record_cache_hit(false).await?;
         }
 
-        // Execute the evaluation
-        let result = self.evaluate_authorization(&request).await;
+        // Execute the evaluation; entities resolved by one layer are reused
+        // by the others until the evaluation ends
+        let result = with_request_scope(self.evaluate_authorization(&request)).await;
         let evaluation_time_ms = start_time.elapsed().as_millis() as u64;
 
         // Log and record metrics
         match &result {
             Ok(response) => {
+                self.audit_decision(&request, response, evaluation_time_ms, false);
                 self.logger.log_decision(&request, response).await?;
                 self.metrics
                     .record_decision(&response.decision, evaluation_time_ms)
@@ -95,8 +183,13 @@ where
             }
         }
 
-        // Cache the result if successful
-        if let (Ok(response), Some(cache)) = (&result, &self.cache) {
+        // Cache the result if successful; degraded decisions are not cached so
+        // the full evaluation applies as soon as the organization boundary is
+        // back, nor are inactive-principal denials so reinstating takes effect
+        if let (Ok(response), Some(cache)) = (&result, &self.cache)
+            && !response.degraded
+            && response.inactive_principal.is_none()
+        {
             let ttl = std::time::Duration::from_secs(300); // 5 minutes cache
             if let Err(cache_error) = cache.put(&cache_key, response, ttl).await {
                 warn!("Failed to cache authorization decision: {}", cache_error);
@@ -113,50 +206,117 @@ where
     ) -> EvaluatePermissionsResult<AuthorizationResponse> {
         info!("Starting multi-layer authorization evaluation (orchestration)");
 
-        // Convert to kernel's EvaluationRequest (zero-copy)
-        let eval_request = EvaluationRequest {
-            principal_hrn: request.principal.clone(),
-            action_name: request.action.clone(),
-            resource_hrn: request.resource.clone(),
+        // Resolve the containers of the resource before any policy is evaluated
+        let resource_ancestors = match &self.resource_hierarchy {
+            Some(resolver) => resolver
+                .ancestors(&request.resource)
+                .instrument(info_span!("resource_hierarchy"))
+                .await
+                .map_err(|e| {
+                    EvaluatePermissionsError::EntityResolutionError(format!(
+                        "Failed to resolve resource hierarchy: {}",
+                        e
+                    ))
+                })?,
+            None => Vec::new(),
         };
+
+        // Convert to kernel's EvaluationRequest; the clock is read once so
+        // SCPs and IAM policies see the same `current_time`
+        let eval_request = EvaluationRequest::new(
+            request.principal.clone(),
+            request.action.clone(),
+            request.resource.clone(),
+        )
+        .with_resource_ancestors(resource_ancestors)
+        .with_context(evaluation_context(request, self.clock.now()));
+
+        // Step 1: Evaluate both layers concurrently; they don't depend on
+        // each other, so the request waits for the slower one, not for both.
+        // Each is bounded on its own, so a stalled layer doesn't hold back
+        // the other
+        info!("Evaluating SCPs and IAM policies concurrently");
+        let (scp_result, iam_result) = tokio::join!(
+            self.within_layer_timeout(
+                "SCP",
+                self.org_evaluator.evaluate_scps(eval_request.clone()),
+            )
+            .instrument(info_span!("scp_evaluation")),
+            self.within_layer_timeout(
+                "IAM",
+                self.iam_evaluator.evaluate_iam_policies(eval_request),
+            )
+            .instrument(info_span!("iam_evaluation")),
+        );
+
+        // Step 2: Apply SCPs first (higher precedence in evaluation - deny
+        // overrides), whatever the outcome of the IAM layer
+        let degraded = match scp_result {
+            Ok(scp_decision) => {
+                // If SCP explicitly denies, return deny decision immediately
+                if !scp_decision.decision {
+                    info!("Access denied by SCP policy");
+                    return Ok(AuthorizationResponse {
+                        decision: AuthorizationDecision::Deny,
+                        determining_policies: scp_decision.determining_policies,
+                        policy_annotations: scp_decision.policy_annotations,
+                        reason: scp_decision.reason,
+                        explicit: true,
+                        degraded: false,
+                        inactive_principal: None,
+                    });
+                }
+                false
+            }
+            Err(e)
+                if self
+                    .scp_failure_policy
+                    .allows_skipping_scps(&request.action) =>
+            {
+                warn!(
+                    action = %request.action,
+                    error = %e,
+                    "SCPs unavailable, failing open for safe action (IAM policies still apply)"
+                );
+                true
+            }
+            Err(e) => {
+                warn!(
+                    action = %request.action,
+                    error = %e,
+                    "SCPs unavailable, failing closed"
+                );
+                return Ok(AuthorizationResponse {
+                    decision: AuthorizationDecision::Deny,
+                    determining_policies: vec![],
+                    policy_annotations: HashMap::new(),
+                    reason: format!("Organization boundary could not be resolved: {}", e),
+                    explicit: false,
+                    degraded: true,
+                    inactive_principal: None,
+                });
+            }
         };
 
-        // Step 1: Evaluate SCPs first (higher precedence in evaluation - deny overrides)
-        info!("Evaluating SCPs for resource");
-        let scp_decision = self
-            .org_evaluator
-            .evaluate_scps(eval_request.clone())
-            .await
-            .map_err(|e| {
-                EvaluatePermissionsError::OrganizationBoundaryProviderError(format!(
-                    "Failed to evaluate SCPs: {}",
-                    e
-                ))
-            })?;
-
-        // If SCP explicitly denies, return deny decision immediately
-        if !scp_decision.decision {
-            info!("Access denied by SCP policy");
-            return Ok(AuthorizationResponse {
-                decision: AuthorizationDecision::Deny,
-                determining_policies: vec![],
-                reason: scp_decision.reason,
-                explicit: true,
-            });
-        }
+        // Step 3: Apply IAM policies
+        let iam_decision = iam_result.map_err(|e| {
+            EvaluatePermissionsError::IamPolicyProviderError(format!(
+                "Failed to evaluate IAM policies: {}",
+                e
+            ))
+        })?;
 
-        // Step 2: Evaluate IAM policies
-        info!("Evaluating IAM policies for principal");
-        let iam_decision = self
-            .iam_evaluator
-            .evaluate_iam_policies(eval_request)
-            .await
-            .map_err(|e| {
-                EvaluatePermissionsError::IamPolicyProviderError(format!(
-                    "Failed to evaluate IAM policies: {}",
-                    e
-                ))
-            })?;
+        // A suspended or deactivated principal has no policies applied; say so
+        // instead of reporting an implicit deny by policy
+        if !iam_decision.principal_status.is_active() {
+            info!(
+                status = %iam_decision.principal_status,
+                "Access denied: principal is not active"
+            );
+            return Ok(AuthorizationResponse::inactive_principal(
+                iam_decision.principal_status,
+            ));
+        }
 
         info!(
             "Authorization evaluation completed: {:?}",
@@ -169,12 +329,56 @@ where
             } else {
                 AuthorizationDecision::Deny
             },
-            determining_policies: vec![],
-            reason: iam_decision.reason,
+            determining_policies: iam_decision.determining_policies,
+            policy_annotations: iam_decision.policy_annotations,
+            reason: if degraded {
+                format!(
+                    "{} (SCPs skipped: organization boundary unavailable)",
+                    iam_decision.reason
+                )
+            } else {
+                iam_decision.reason
+            },
             explicit: true,
+            degraded,
+            inactive_principal: None,
         })
     }
 
+    /// Run a layer's evaluation, turning a timeout into an evaluation failure
+    async fn within_layer_timeout(
+        &self,
+        layer: &str,
+        evaluation: impl Future<Output = Result<EvaluationDecision, AuthorizationError>>,
+    ) -> Result<EvaluationDecision, AuthorizationError> {
+        tokio::time::timeout(self.layer_timeout, evaluation)
+            .await
+            .unwrap_or_else(|_| {
+                Err(AuthorizationError::EvaluationFailed(format!(
+                    "{} layer timed out after {} ms",
+                    layer,
+                    self.layer_timeout.as_millis()
+                )))
+            })
+    }
+
+    /// Hand the decision to the audit publisher if the audit mode selects it
+    fn audit_decision(
+        &self,
+        request: &AuthorizationRequest,
+        response: &AuthorizationResponse,
+        latency_ms: u64,
+        from_cache: bool,
+    ) {
+        if let Some(publisher) = &self.audit_publisher
+            && self.audit_mode.should_audit(&response.decision)
+        {
+            publisher.publish(AuthorizationEvaluated::new(
+                request, response, latency_ms, from_cache,
+            ));
+        }
+    }
+
     fn generate_cache_key(&self, request: &AuthorizationRequest) -> String {
         format!(
             "auth:{}:{}:{}",
@@ -182,3 +386,850 @@ where
         )
     }
 }
+
+/// Attributes exposed to policies as `context.*`
+///
+/// Times are Unix timestamps in seconds. `current_time` always comes from the
+/// authorizer's clock and can't be overridden by the caller.
+fn evaluation_context(
+    request: &AuthorizationRequest,
+    now: time::OffsetDateTime,
+) -> HashMap<String, serde_json::Value> {
+    let mut context = HashMap::new();
+
+    if let Some(request_context) = &request.context {
+        context.extend(
+            request_context
+                .additional_context
+                .iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        if let Some(source_ip) = &request_context.source_ip {
+            context.insert("source_ip".to_string(), source_ip.clone().into());
+        }
+        if let Some(user_agent) = &request_context.user_agent {
+            context.insert("user_agent".to_string(), user_agent.clone().into());
+        }
+        if let Some(request_time) = request_context.request_time {
+            context.insert(
+                "request_time".to_string(),
+                request_time.unix_timestamp().into(),
+            );
+        }
+    }
+
+    context.insert("current_time".to_string(), now.unix_timestamp().into());
+    context
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::evaluate_permissions::mocks::{
+        MockAuthorizationAuditPublisher, MockAuthorizationCache, MockAuthorizationLogger,
+        MockAuthorizationMetrics, MockIamPolicyEvaluator, MockScpEvaluator,
+    };
+    use crate::features::evaluate_permissions::ports::{
+        EntityResolverError, EntityResolverPort, ResourceHierarchyError, ResourceHierarchyPort,
+    };
+    use crate::features::evaluate_permissions::request_cache::RequestScopedEntityResolver;
+    use kernel::Hrn;
+
+    fn use_case(
+        iam: MockIamPolicyEvaluator,
+        scp: MockScpEvaluator,
+        cache: MockAuthorizationCache,
+    ) -> EvaluatePermissionsUseCase<
+        MockAuthorizationCache,
+        MockAuthorizationLogger,
+        MockAuthorizationMetrics,
+    > {
+        EvaluatePermissionsUseCase::new(
+            Arc::new(iam),
+            Arc::new(scp),
+            Some(cache),
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        )
+    }
+
+    fn request(action: &str) -> AuthorizationRequest {
+        let hrn = |resource_type: &str, resource_id: &str| {
+            Hrn::new(
+                "aws".to_string(),
+                "test".to_string(),
+                "default".to_string(),
+                resource_type.to_string(),
+                resource_id.to_string(),
+            )
+        };
+        AuthorizationRequest::new(
+            hrn("user", "alice"),
+            action.to_string(),
+            hrn("bucket", "artifacts"),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_response_carries_determining_policy_annotations() {
+        let annotations = HashMap::from([("ticket".to_string(), "SEC-1234".to_string())]);
+        let use_case = use_case(
+            MockIamPolicyEvaluator::new().with_determining_policy("allow-read", annotations),
+            MockScpEvaluator::new(),
+            MockAuthorizationCache::new(),
+        );
+
+        let response = use_case.execute(request("read")).await.unwrap();
+
+        assert_eq!(
+            response.determining_policies,
+            vec!["allow-read".to_string()]
+        );
+        assert_eq!(
+            response.policy_annotations["allow-read"]["ticket"],
+            "SEC-1234"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scp_outage_fails_closed_by_default() {
+        let use_case = use_case(
+            MockIamPolicyEvaluator::new(),
+            MockScpEvaluator::unavailable(),
+            MockAuthorizationCache::new(),
+        );
+
+        let response = use_case.execute(request("read")).await.unwrap();
+
+        assert_eq!(response.decision, AuthorizationDecision::Deny);
+        assert!(response.degraded);
+    }
+
+    #[tokio::test]
+    async fn test_scp_outage_fails_open_for_safe_actions_only() {
+        let use_case = use_case(
+            MockIamPolicyEvaluator::new(),
+            MockScpEvaluator::unavailable(),
+            MockAuthorizationCache::new(),
+        )
+        .with_scp_failure_policy(ScpFailurePolicy::fail_open_for(["read"]));
+
+        let read = use_case.execute(request("read")).await.unwrap();
+        assert_eq!(read.decision, AuthorizationDecision::Allow);
+        assert!(read.degraded);
+        assert!(read.reason.contains("SCPs skipped"));
+
+        let delete = use_case.execute(request("delete")).await.unwrap();
+        assert_eq!(delete.decision, AuthorizationDecision::Deny);
+        assert!(delete.degraded);
+    }
+
+    #[tokio::test]
+    async fn test_iam_still_applies_when_scps_are_skipped() {
+        let use_case = use_case(
+            MockIamPolicyEvaluator::with_deny(),
+            MockScpEvaluator::unavailable(),
+            MockAuthorizationCache::new(),
+        )
+        .with_scp_failure_policy(ScpFailurePolicy::fail_open_for(["read"]));
+
+        let response = use_case.execute(request("read")).await.unwrap();
+
+        assert_eq!(response.decision, AuthorizationDecision::Deny);
+        assert!(response.degraded);
+    }
+
+    #[tokio::test]
+    async fn test_degraded_decisions_are_not_cached() {
+        let cache = MockAuthorizationCache::new();
+        let use_case = use_case(
+            MockIamPolicyEvaluator::new(),
+            MockScpEvaluator::unavailable(),
+            cache.clone(),
+        )
+        .with_scp_failure_policy(ScpFailurePolicy::fail_open_for(["read"]));
+
+        let request = request("read");
+        let cache_key = use_case.generate_cache_key(&request);
+        use_case.execute(request).await.unwrap();
+
+        assert!(cache.get(&cache_key).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_suspended_principal_is_denied_apart_from_policy_denials() {
+        let cache = MockAuthorizationCache::new();
+        let suspended_use_case = use_case(
+            MockIamPolicyEvaluator::new().with_principal_status(kernel::PrincipalStatus::Suspended),
+            MockScpEvaluator::new(),
+            cache.clone(),
+        );
+
+        let cache_key = suspended_use_case.generate_cache_key(&request("read"));
+        let suspended = suspended_use_case.execute(request("read")).await.unwrap();
+
+        assert_eq!(suspended.decision, AuthorizationDecision::Deny);
+        assert_eq!(
+            suspended.inactive_principal,
+            Some(kernel::PrincipalStatus::Suspended)
+        );
+        assert_eq!(suspended.reason, "Principal is suspended");
+        assert!(cache.get(&cache_key).await.unwrap().is_none());
+
+        let denied = use_case(
+            MockIamPolicyEvaluator::with_deny(),
+            MockScpEvaluator::new(),
+            MockAuthorizationCache::new(),
+        )
+        .execute(request("read"))
+        .await
+        .unwrap();
+        assert_eq!(denied.decision, AuthorizationDecision::Deny);
+        assert_eq!(denied.inactive_principal, None);
+    }
+
+    #[tokio::test]
+    async fn test_open_scp_breaker_respects_failure_policy() {
+        use crate::features::evaluate_permissions::circuit_breaker::{
+            CircuitBreaker, CircuitBreakerConfig, CircuitBreakingScpEvaluator, CircuitState,
+        };
+
+        let breaker = Arc::new(CircuitBreaker::new(
+            "scp",
+            CircuitBreakerConfig::new().with_failure_threshold(1),
+        ));
+        let scp = CircuitBreakingScpEvaluator::new(
+            Arc::new(MockScpEvaluator::unavailable()),
+            breaker.clone(),
+        );
+        let use_case = EvaluatePermissionsUseCase::new(
+            Arc::new(MockIamPolicyEvaluator::new()),
+            Arc::new(scp),
+            None::<MockAuthorizationCache>,
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        )
+        .with_scp_failure_policy(ScpFailurePolicy::fail_open_for(["read"]));
+
+        use_case.execute(request("read")).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // Short-circuited calls go through the same degradation path
+        let read = use_case.execute(request("read")).await.unwrap();
+        assert_eq!(read.decision, AuthorizationDecision::Allow);
+        assert!(read.degraded);
+
+        let write = use_case.execute(request("write")).await.unwrap();
+        assert_eq!(write.decision, AuthorizationDecision::Deny);
+        assert!(write.degraded);
+        assert_eq!(breaker.metrics().rejected_calls, 2);
+    }
+
+    #[tokio::test]
+    async fn test_healthy_scps_are_not_degraded() {
+        let use_case = use_case(
+            MockIamPolicyEvaluator::new(),
+            MockScpEvaluator::new(),
+            MockAuthorizationCache::new(),
+        );
+
+        let response = use_case.execute(request("read")).await.unwrap();
+
+        assert_eq!(response.decision, AuthorizationDecision::Allow);
+        assert!(!response.degraded);
+    }
+
+    /// Evaluator for both layers that only answers once both were called,
+    /// so a request completes only if the layers run concurrently
+    struct RendezvousEvaluator {
+        barrier: tokio::sync::Barrier,
+    }
+
+    impl RendezvousEvaluator {
+        async fn decide(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            self.barrier.wait().await;
+            Ok(
+                kernel::application::ports::authorization::EvaluationDecision {
+                    principal_hrn: request.principal_hrn,
+                    action_name: request.action_name,
+                    resource_hrn: request.resource_hrn,
+                    decision: true,
+                    reason: String::new(),
+                    determining_policies: vec![],
+                    policy_annotations: HashMap::new(),
+                    principal_status: kernel::PrincipalStatus::Active,
+                },
+            )
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl IamPolicyEvaluator for RendezvousEvaluator {
+        async fn evaluate_iam_policies(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            self.decide(request).await
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ScpEvaluator for RendezvousEvaluator {
+        async fn evaluate_scps(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            self.decide(request).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_layers_are_evaluated_concurrently() {
+        let evaluator = Arc::new(RendezvousEvaluator {
+            barrier: tokio::sync::Barrier::new(2),
+        });
+        let use_case: EvaluatePermissionsUseCase<
+            MockAuthorizationCache,
+            MockAuthorizationLogger,
+            MockAuthorizationMetrics,
+        > = EvaluatePermissionsUseCase::new(
+            evaluator.clone(),
+            evaluator,
+            None,
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        );
+
+        let response = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            use_case.execute(request("read")),
+        )
+        .await
+        .expect("layers were evaluated one after the other")
+        .unwrap();
+
+        assert_eq!(response.decision, AuthorizationDecision::Allow);
+    }
+
+    #[tokio::test]
+    async fn test_scp_deny_wins_over_iam_outage() {
+        let use_case = use_case(
+            MockIamPolicyEvaluator::unavailable(),
+            MockScpEvaluator::with_deny(),
+            MockAuthorizationCache::new(),
+        );
+
+        let response = use_case.execute(request("read")).await.unwrap();
+
+        assert_eq!(response.decision, AuthorizationDecision::Deny);
+        assert!(response.explicit);
+        assert!(!response.degraded);
+    }
+
+    #[tokio::test]
+    async fn test_iam_outage_fails_even_when_scps_are_skipped() {
+        let use_case = use_case(
+            MockIamPolicyEvaluator::unavailable(),
+            MockScpEvaluator::unavailable(),
+            MockAuthorizationCache::new(),
+        )
+        .with_scp_failure_policy(ScpFailurePolicy::fail_open_for(["read"]));
+
+        let result = use_case.execute(request("read")).await;
+
+        assert!(matches!(
+            result,
+            Err(EvaluatePermissionsError::IamPolicyProviderError(_))
+        ));
+    }
+
+    /// Evaluator for both layers that never answers
+    struct StalledEvaluator;
+
+    #[async_trait::async_trait]
+    impl IamPolicyEvaluator for StalledEvaluator {
+        async fn evaluate_iam_policies(
+            &self,
+            _request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            std::future::pending().await
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ScpEvaluator for StalledEvaluator {
+        async fn evaluate_scps(
+            &self,
+            _request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stalled_scps_follow_the_failure_policy() {
+        let use_case: EvaluatePermissionsUseCase<
+            MockAuthorizationCache,
+            MockAuthorizationLogger,
+            MockAuthorizationMetrics,
+        > = EvaluatePermissionsUseCase::new(
+            Arc::new(MockIamPolicyEvaluator::new()),
+            Arc::new(StalledEvaluator),
+            None,
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        )
+        .with_layer_timeout(std::time::Duration::from_millis(20))
+        .with_scp_failure_policy(ScpFailurePolicy::fail_open_for(["read"]));
+
+        let safe = use_case.execute(request("read")).await.unwrap();
+        assert_eq!(safe.decision, AuthorizationDecision::Allow);
+        assert!(safe.degraded);
+
+        let unsafe_action = use_case.execute(request("delete")).await.unwrap();
+        assert_eq!(unsafe_action.decision, AuthorizationDecision::Deny);
+        assert!(unsafe_action.reason.contains("timed out"));
+    }
+
+    fn stalled_iam_use_case(
+        scp: MockScpEvaluator,
+    ) -> EvaluatePermissionsUseCase<
+        MockAuthorizationCache,
+        MockAuthorizationLogger,
+        MockAuthorizationMetrics,
+    > {
+        EvaluatePermissionsUseCase::new(
+            Arc::new(StalledEvaluator),
+            Arc::new(scp),
+            None,
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        )
+        .with_layer_timeout(std::time::Duration::from_millis(20))
+    }
+
+    #[tokio::test]
+    async fn test_stalled_iam_layer_does_not_hide_an_scp_deny() {
+        let use_case = stalled_iam_use_case(MockScpEvaluator::with_deny());
+
+        let response = use_case.execute(request("read")).await.unwrap();
+
+        assert_eq!(response.decision, AuthorizationDecision::Deny);
+        assert!(response.explicit);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_iam_layer_fails_the_evaluation() {
+        let use_case = stalled_iam_use_case(MockScpEvaluator::new());
+
+        let result = use_case.execute(request("read")).await;
+
+        assert!(matches!(
+            result,
+            Err(EvaluatePermissionsError::IamPolicyProviderError(ref message)) if message.contains("timed out")
+        ));
+    }
+
+    /// IAM evaluator with a single `permit ... resource in <container>` policy
+    struct ContainerPolicyEvaluator {
+        container: Hrn,
+    }
+
+    #[async_trait::async_trait]
+    impl IamPolicyEvaluator for ContainerPolicyEvaluator {
+        async fn evaluate_iam_policies(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            let decision = request.resource_ancestors.contains(&self.container);
+            Ok(
+                kernel::application::ports::authorization::EvaluationDecision {
+                    principal_hrn: request.principal_hrn,
+                    action_name: request.action_name,
+                    resource_hrn: request.resource_hrn,
+                    decision,
+                    reason: String::new(),
+                    determining_policies: vec![],
+                    policy_annotations: HashMap::new(),
+                    principal_status: kernel::PrincipalStatus::Active,
+                },
+            )
+        }
+    }
+
+    /// Every resource lives in the `libs` repository, which lives in itself
+    /// when `cyclic` is set
+    struct RepositoryHierarchy {
+        cyclic: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl ResourceHierarchyPort for RepositoryHierarchy {
+        async fn parents_of(&self, resource: &Hrn) -> Result<Vec<Hrn>, ResourceHierarchyError> {
+            if *resource == repository() && !self.cyclic {
+                return Ok(Vec::new());
+            }
+            Ok(vec![repository()])
+        }
+    }
+
+    fn repository() -> Hrn {
+        Hrn::new(
+            "aws".to_string(),
+            "test".to_string(),
+            "default".to_string(),
+            "repository".to_string(),
+            "libs".to_string(),
+        )
+    }
+
+    fn container_use_case(
+        cyclic: bool,
+    ) -> EvaluatePermissionsUseCase<
+        MockAuthorizationCache,
+        MockAuthorizationLogger,
+        MockAuthorizationMetrics,
+    > {
+        EvaluatePermissionsUseCase::new(
+            Arc::new(ContainerPolicyEvaluator {
+                container: repository(),
+            }),
+            Arc::new(MockScpEvaluator::new()),
+            None,
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        )
+        .with_resource_hierarchy(ResourceAncestryResolver::new(Arc::new(
+            RepositoryHierarchy { cyclic },
+        )))
+    }
+
+    #[tokio::test]
+    async fn test_container_policies_apply_to_children() {
+        let response = container_use_case(false)
+            .execute(request("read"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.decision, AuthorizationDecision::Allow);
+    }
+
+    #[tokio::test]
+    async fn test_hierarchy_cycles_fail_evaluation() {
+        let result = container_use_case(true).execute(request("read")).await;
+
+        assert!(matches!(
+            result,
+            Err(EvaluatePermissionsError::EntityResolutionError(ref message)) if message.contains("Cycle")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_only_denies_are_audited_by_default() {
+        let audit = MockAuthorizationAuditPublisher::new();
+        let allowing = use_case(
+            MockIamPolicyEvaluator::new(),
+            MockScpEvaluator::new(),
+            MockAuthorizationCache::new(),
+        )
+        .with_decision_audit(Arc::new(audit.clone()), DecisionAuditMode::default());
+        let denying = use_case(
+            MockIamPolicyEvaluator::with_deny(),
+            MockScpEvaluator::new(),
+            MockAuthorizationCache::new(),
+        )
+        .with_decision_audit(Arc::new(audit.clone()), DecisionAuditMode::default());
+
+        allowing.execute(request("read")).await.unwrap();
+        denying.execute(request("delete")).await.unwrap();
+
+        let events = audit.get_published_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, "delete");
+        assert_eq!(events[0].decision, AuthorizationDecision::Deny);
+        assert!(!events[0].from_cache);
+    }
+
+    #[tokio::test]
+    async fn test_all_decisions_are_audited_including_cache_hits() {
+        let audit = MockAuthorizationAuditPublisher::new();
+        let use_case = use_case(
+            MockIamPolicyEvaluator::new(),
+            MockScpEvaluator::new(),
+            MockAuthorizationCache::new(),
+        )
+        .with_decision_audit(Arc::new(audit.clone()), DecisionAuditMode::All);
+
+        use_case.execute(request("read")).await.unwrap();
+        use_case.execute(request("read")).await.unwrap();
+
+        let events = audit.get_published_events();
+        assert_eq!(events.len(), 2);
+        assert!(
+            events
+                .iter()
+                .all(|e| e.decision == AuthorizationDecision::Allow)
+        );
+        assert!(events[1].from_cache);
+    }
+
+    /// Evaluator that allows only during the given hour (UTC) and records
+    /// the `current_time` it was given
+    #[derive(Default)]
+    struct BusinessHoursEvaluator {
+        seen: std::sync::Mutex<Vec<serde_json::Value>>,
+    }
+
+    impl BusinessHoursEvaluator {
+        fn decide(
+            &self,
+            request: EvaluationRequest,
+        ) -> kernel::application::ports::authorization::EvaluationDecision {
+            let current_time = request.context["current_time"].clone();
+            self.seen.lock().unwrap().push(current_time.clone());
+            let hour = current_time.as_i64().unwrap() % 86_400 / 3_600;
+            kernel::application::ports::authorization::EvaluationDecision {
+                principal_hrn: request.principal_hrn,
+                action_name: request.action_name,
+                resource_hrn: request.resource_hrn,
+                decision: (9..17).contains(&hour),
+                reason: String::new(),
+                determining_policies: vec![],
+                policy_annotations: HashMap::new(),
+                principal_status: kernel::PrincipalStatus::Active,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl IamPolicyEvaluator for BusinessHoursEvaluator {
+        async fn evaluate_iam_policies(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            Ok(self.decide(request))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ScpEvaluator for BusinessHoursEvaluator {
+        async fn evaluate_scps(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            let mut decision = self.decide(request);
+            decision.decision = true;
+            Ok(decision)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_injected_clock_drives_time_based_policies() {
+        use crate::features::evaluate_permissions::clock::FixedClock;
+
+        // 2023-11-14 10:00:00 UTC
+        let morning = time::OffsetDateTime::from_unix_timestamp(1_699_956_000).unwrap();
+        let clock = Arc::new(FixedClock::new(morning));
+        let evaluator = Arc::new(BusinessHoursEvaluator::default());
+        let use_case: EvaluatePermissionsUseCase<
+            MockAuthorizationCache,
+            MockAuthorizationLogger,
+            MockAuthorizationMetrics,
+        > = EvaluatePermissionsUseCase::new(
+            evaluator.clone(),
+            evaluator.clone(),
+            None,
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        )
+        .with_clock(clock.clone());
+
+        let allowed = use_case.execute(request("read")).await.unwrap();
+        assert_eq!(allowed.decision, AuthorizationDecision::Allow);
+
+        clock.advance(std::time::Duration::from_secs(10 * 3_600));
+        let denied = use_case.execute(request("read")).await.unwrap();
+        assert_eq!(denied.decision, AuthorizationDecision::Deny);
+
+        // SCPs and IAM saw the same instant within each evaluation
+        let seen = evaluator.seen.lock().unwrap().clone();
+        assert_eq!(
+            seen,
+            vec![
+                serde_json::json!(1_699_956_000),
+                serde_json::json!(1_699_956_000),
+                serde_json::json!(1_699_992_000),
+                serde_json::json!(1_699_992_000),
+            ]
+        );
+    }
+
+    #[derive(Debug)]
+    struct HrnEntity(Hrn);
+
+    impl kernel::HodeiEntity for HrnEntity {
+        fn hrn(&self) -> &Hrn {
+            &self.0
+        }
+
+        fn attributes(&self) -> HashMap<kernel::AttributeName, kernel::AttributeValue> {
+            HashMap::new()
+        }
+    }
+
+    /// Resolver that counts fetches, shared by both layers of [`ResolvingEvaluator`]
+    #[derive(Default)]
+    struct CountingResolver {
+        fetches: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl EntityResolverPort for CountingResolver {
+        async fn resolve(
+            &self,
+            hrn: &Hrn,
+        ) -> Result<Box<dyn kernel::HodeiEntity>, EntityResolverError> {
+            self.fetches
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(Box::new(HrnEntity(hrn.clone())))
+        }
+
+        async fn resolve_batch(
+            &self,
+            hrns: &[Hrn],
+        ) -> Result<Vec<Box<dyn kernel::HodeiEntity>>, EntityResolverError> {
+            Err(EntityResolverError::BatchResolutionFailed(hrns.to_vec()))
+        }
+    }
+
+    /// IAM and SCP layer that resolve the principal and resource before
+    /// allowing
+    struct ResolvingEvaluator {
+        resolver: RequestScopedEntityResolver<Arc<CountingResolver>>,
+    }
+
+    impl ResolvingEvaluator {
+        async fn decide(
+            &self,
+            request: EvaluationRequest,
+        ) -> kernel::application::ports::authorization::EvaluationDecision {
+            self.resolver.resolve(&request.resource_hrn).await.unwrap();
+            self.resolver.resolve(&request.principal_hrn).await.unwrap();
+            kernel::application::ports::authorization::EvaluationDecision {
+                principal_hrn: request.principal_hrn,
+                action_name: request.action_name,
+                resource_hrn: request.resource_hrn,
+                decision: true,
+                reason: String::new(),
+                determining_policies: vec![],
+                policy_annotations: HashMap::new(),
+                principal_status: kernel::PrincipalStatus::Active,
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl IamPolicyEvaluator for ResolvingEvaluator {
+        async fn evaluate_iam_policies(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            Ok(self.decide(request).await)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ScpEvaluator for ResolvingEvaluator {
+        async fn evaluate_scps(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<
+            kernel::application::ports::authorization::EvaluationDecision,
+            kernel::application::ports::authorization::AuthorizationError,
+        > {
+            Ok(self.decide(request).await)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_layers_share_entity_lookups_within_a_request() {
+        let fetched = Arc::new(CountingResolver::default());
+        let evaluator = Arc::new(ResolvingEvaluator {
+            resolver: RequestScopedEntityResolver::new(fetched.clone()),
+        });
+        let use_case: EvaluatePermissionsUseCase<
+            MockAuthorizationCache,
+            MockAuthorizationLogger,
+            MockAuthorizationMetrics,
+        > = EvaluatePermissionsUseCase::new(
+            evaluator.clone(),
+            evaluator,
+            None,
+            MockAuthorizationLogger::new(),
+            MockAuthorizationMetrics::new(),
+        );
+        let fetches = || fetched.fetches.load(std::sync::atomic::Ordering::SeqCst);
+
+        // The SCP layer fetches both entities, the IAM layer reuses them
+        use_case.execute(request("read")).await.unwrap();
+        assert_eq!(fetches(), 2);
+
+        // A new request fetches them again
+        use_case.execute(request("read")).await.unwrap();
+        assert_eq!(fetches(), 4);
+    }
+
+    #[test]
+    fn test_caller_context_cannot_override_current_time() {
+        let mut additional_context = HashMap::new();
+        additional_context.insert("current_time".to_string(), serde_json::json!(0));
+        additional_context.insert("mfa".to_string(), serde_json::json!(true));
+        let mut request = request("read");
+        request.context = Some(
+            crate::features::evaluate_permissions::dto::AuthorizationContext {
+                source_ip: Some("10.0.0.1".to_string()),
+                user_agent: None,
+                request_time: None,
+                additional_context,
+            },
+        );
+        let now = time::OffsetDateTime::from_unix_timestamp(1_699_956_000).unwrap();
+
+        let context = evaluation_context(&request, now);
+
+        assert_eq!(context["current_time"], serde_json::json!(1_699_956_000));
+        assert_eq!(context["mfa"], serde_json::json!(true));
+        assert_eq!(context["source_ip"], serde_json::json!("10.0.0.1"));
+    }
+}
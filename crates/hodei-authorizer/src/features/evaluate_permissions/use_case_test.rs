@@ -28,6 +28,7 @@ mod tests {
             Ok(EffectivePoliciesResult {
                 policies: PolicySet::new(),
                 policy_count: 0,
+                principal_status: kernel::PrincipalStatus::Active,
             })
         }
     }
@@ -49,6 +50,7 @@ mod tests {
             Ok(EffectivePoliciesResult {
                 policies: policy_set,
                 policy_count: 1,
+                principal_status: kernel::PrincipalStatus::Active,
             })
         }
     }
@@ -70,6 +72,7 @@ mod tests {
             Ok(EffectivePoliciesResult {
                 policies: policy_set,
                 policy_count: 1,
+                principal_status: kernel::PrincipalStatus::Active,
             })
         }
     }
@@ -95,6 +95,11 @@ impl AccountRepository for InMemoryAccountRepository {
         let hrn_str = hrn.to_string();
         Ok(self.accounts.lock().unwrap().get(&hrn_str).cloned())
     }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), AccountRepositoryError> {
+        self.accounts.lock().unwrap().remove(&hrn.to_string());
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
@@ -131,6 +136,11 @@ impl OuRepository for InMemoryOuRepository {
         let hrn_str = hrn.to_string();
         Ok(self.ous.lock().unwrap().get(&hrn_str).cloned())
     }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), OuRepositoryError> {
+        self.ous.lock().unwrap().remove(&hrn.to_string());
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -181,6 +191,7 @@ fn create_test_ou(id: &str, parent_hrn: Hrn) -> OrganizationalUnit {
         child_ous: Default::default(),
         child_accounts: Default::default(),
         attached_scps: Default::default(),
+        tags: Default::default(),
     }
 }
 
@@ -0,0 +1,115 @@
+//! Effective policy resolution against the in-memory IAM repository
+//!
+//! Drives the policy cache through the kernel port with real group and
+//! attachment resolution behind it, instead of a canned policy set.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use hodei_authorizer::features::evaluate_permissions::{
+    CachingEffectivePoliciesQuery, PolicyCacheInvalidationHandler, PolicyDetached,
+};
+use hodei_iam::infrastructure::in_memory::InMemoryIamRepository;
+use kernel::application::ports::event_bus::{EventBus, EventPublisher};
+use kernel::application::ports::{EffectivePoliciesQuery, EffectivePoliciesQueryPort};
+use kernel::domain::{HodeiPolicy, PolicyId};
+use kernel::{GroupMembershipChanged, Hrn, InMemoryEventBus};
+
+fn iam(resource_type: &str, id: &str) -> Hrn {
+    Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+}
+
+/// alice is in devs; alice has `read-own`, devs has `deploy`
+fn repository() -> InMemoryIamRepository {
+    let repository = InMemoryIamRepository::new();
+    repository.add_user(iam("User", "alice"), "Alice", "alice@example.com");
+    repository.add_group(iam("Group", "devs"), "Developers");
+    repository
+        .add_user_to_group(&iam("User", "alice"), &iam("Group", "devs"))
+        .unwrap();
+    for id in ["read-own", "deploy"] {
+        repository.add_policy(HodeiPolicy::new(
+            PolicyId::new(id),
+            "permit(principal, action, resource);".to_string(),
+        ));
+    }
+    repository
+        .attach_policy("read-own", &iam("User", "alice"))
+        .unwrap();
+    repository
+        .attach_policy("deploy", &iam("Group", "devs"))
+        .unwrap();
+    repository
+}
+
+async fn policy_ids<Q: EffectivePoliciesQueryPort>(port: &Q, principal: &Hrn) -> Vec<String> {
+    let result = port
+        .get_effective_policies(EffectivePoliciesQuery {
+            principal_hrn: principal.to_string(),
+        })
+        .await
+        .unwrap();
+    let mut ids: Vec<String> = result
+        .policies
+        .policies()
+        .map(|policy| policy.id().to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn cache_serves_direct_and_group_policies() {
+    let repository = repository();
+    let cache = CachingEffectivePoliciesQuery::new(repository.effective_policies_port());
+
+    assert_eq!(
+        policy_ids(&cache, &iam("User", "alice")).await,
+        ["deploy", "read-own"]
+    );
+}
+
+#[tokio::test]
+async fn events_make_repository_changes_visible() {
+    let repository = repository();
+    let alice = iam("User", "alice");
+    let devs = iam("Group", "devs");
+    let cache = Arc::new(CachingEffectivePoliciesQuery::new(
+        repository.effective_policies_port(),
+    ));
+    let bus = InMemoryEventBus::new();
+    let handler = Arc::new(PolicyCacheInvalidationHandler::new(cache.clone()));
+    let _detached = bus
+        .subscribe::<PolicyDetached, _>(handler.clone())
+        .await
+        .unwrap();
+    let _membership = bus
+        .subscribe::<GroupMembershipChanged, _>(handler)
+        .await
+        .unwrap();
+
+    assert_eq!(policy_ids(&*cache, &alice).await, ["deploy", "read-own"]);
+
+    // Without an event the cached set is still served
+    repository.detach_policy("read-own", &alice);
+    assert_eq!(policy_ids(&*cache, &alice).await, ["deploy", "read-own"]);
+
+    bus.publish(PolicyDetached::new("read-own", alice.clone()))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(policy_ids(&*cache, &alice).await, ["deploy"]);
+
+    repository.remove_user_from_group(&alice, &devs);
+    bus.publish(GroupMembershipChanged::joined(alice.clone(), devs))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(policy_ids(&*cache, &alice).await.is_empty());
+}
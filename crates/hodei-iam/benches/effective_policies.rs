@@ -0,0 +1,184 @@
+//! Effective-policy resolution over growing group hierarchies
+//!
+//! The principal belongs to `fanout` groups, each the bottom of a chain of
+//! `depth` nested groups, and every group has a few policies attached. The
+//! finders answer from memory so only the resolution itself is timed.
+//!
+//! ```text
+//! cargo bench -p hodei-iam --bench effective_policies
+//! ```
+
+use async_trait::async_trait;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use hodei_iam::features::get_effective_policies::dto::{GroupLookupDto, UserLookupDto};
+use hodei_iam::get_effective_policies::{
+    GetEffectivePoliciesError, GetEffectivePoliciesQuery, GetEffectivePoliciesUseCase,
+    GroupFinderPort, PolicyFinderPort, UserFinderPort,
+};
+use kernel::Hrn;
+use kernel::domain::policy::{HodeiPolicy, PolicyId};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+const POLICIES_PER_PRINCIPAL: usize = 5;
+
+/// Users, groups and attachments of one generated hierarchy
+#[derive(Default)]
+struct Hierarchy {
+    users: HashMap<String, UserLookupDto>,
+    user_groups: HashMap<String, Vec<GroupLookupDto>>,
+    parent_groups: HashMap<String, Vec<GroupLookupDto>>,
+    policies: HashMap<String, Vec<HodeiPolicy>>,
+}
+
+impl Hierarchy {
+    fn generate(fanout: usize, depth: usize) -> (Self, String) {
+        let mut hierarchy = Self::default();
+        let user_hrn = hrn("user", "alice");
+        hierarchy.users.insert(
+            user_hrn.clone(),
+            UserLookupDto::new(user_hrn.clone(), "alice", "alice@example.com"),
+        );
+        hierarchy.attach(&user_hrn);
+
+        for branch in 0..fanout {
+            let chain: Vec<GroupLookupDto> = (0..depth)
+                .map(|level| {
+                    let name = format!("group-{}-{}", branch, level);
+                    GroupLookupDto::new(hrn("group", &name), name)
+                })
+                .collect();
+            for (level, group) in chain.iter().enumerate() {
+                hierarchy.attach(&group.hrn);
+                if let Some(parent) = chain.get(level + 1) {
+                    hierarchy
+                        .parent_groups
+                        .insert(group.hrn.clone(), vec![parent.clone()]);
+                }
+            }
+            hierarchy
+                .user_groups
+                .entry(user_hrn.clone())
+                .or_default()
+                .push(chain[0].clone());
+        }
+
+        (hierarchy, user_hrn)
+    }
+
+    fn attach(&mut self, principal_hrn: &str) {
+        let policies = (0..POLICIES_PER_PRINCIPAL)
+            .map(|i| {
+                HodeiPolicy::new(
+                    PolicyId::new(format!("{}-policy-{}", principal_hrn, i)),
+                    "permit(principal, action, resource);".to_string(),
+                )
+            })
+            .collect();
+        self.policies.insert(principal_hrn.to_string(), policies);
+    }
+}
+
+#[async_trait]
+impl UserFinderPort for Hierarchy {
+    async fn find_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<UserLookupDto>, GetEffectivePoliciesError> {
+        Ok(self.users.get(&hrn.to_string()).cloned())
+    }
+}
+
+#[async_trait]
+impl GroupFinderPort for Hierarchy {
+    async fn find_groups_by_user_hrn(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError> {
+        Ok(self
+            .user_groups
+            .get(&user_hrn.to_string())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn find_parent_groups(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError> {
+        Ok(self
+            .parent_groups
+            .get(&group_hrn.to_string())
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+#[async_trait]
+impl PolicyFinderPort for Hierarchy {
+    async fn find_policies_by_principal(
+        &self,
+        principal_hrn: &Hrn,
+    ) -> Result<Vec<HodeiPolicy>, GetEffectivePoliciesError> {
+        Ok(self
+            .policies
+            .get(&principal_hrn.to_string())
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+fn hrn(resource_type: &str, id: &str) -> String {
+    Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "bench".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+    .to_string()
+}
+
+fn effective_policies(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("GetEffectivePoliciesUseCase::execute/fanout_x_depth");
+
+    for (fanout, depth) in [(1, 1), (10, 1), (100, 1), (10, 4), (10, 8), (100, 8)] {
+        let (hierarchy, user_hrn) = Hierarchy::generate(fanout, depth);
+        let hierarchy = Arc::new(hierarchy);
+        let use_case =
+            GetEffectivePoliciesUseCase::new(hierarchy.clone(), hierarchy.clone(), hierarchy);
+        let expected = POLICIES_PER_PRINCIPAL * (1 + fanout * depth);
+
+        group.throughput(Throughput::Elements((fanout * depth) as u64));
+        group.bench_function(
+            BenchmarkId::from_parameter(format!("{}x{}", fanout, depth)),
+            |b| {
+                b.to_async(&runtime).iter(|| async {
+                    let query = GetEffectivePoliciesQuery {
+                        principal_hrn: user_hrn.clone(),
+                    };
+                    let response = use_case.execute(black_box(query)).await.unwrap();
+                    assert_eq!(response.policies.len(), expected);
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn config() -> Criterion {
+    Criterion::default()
+        .measurement_time(Duration::from_secs(5))
+        .noise_threshold(0.03)
+}
+
+criterion_group! {
+    name = benches;
+    config = config();
+    targets = effective_policies
+}
+criterion_main!(benches);
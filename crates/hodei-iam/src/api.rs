@@ -44,27 +44,265 @@ pub mod create_group {
 // ============================================================================
 pub mod add_user_to_group {
     pub use crate::features::add_user_to_group::dto::{
-        AddUserToGroupCommand, GroupLookupDto, UserLookupDto, UserPersistenceDto,
+        AddUserToGroupCommand, GroupLookupDto, GroupMembershipChanged, UserLookupDto,
+        UserPersistenceDto,
     };
     pub use crate::features::add_user_to_group::error::AddUserToGroupError;
     pub use crate::features::add_user_to_group::ports::{
         AddUserToGroupUseCasePort, GroupFinder, UserFinder, UserGroupPersister,
+        UserMembershipEventPort,
     };
     pub use crate::features::add_user_to_group::use_case::AddUserToGroupUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
+}
+
+// ============================================================================
+// FEATURE: add_group_to_group
+// ============================================================================
+pub mod add_group_to_group {
+    pub use crate::features::add_group_to_group::dto::{
+        AddGroupToGroupCommand, GroupMembershipChanged,
+    };
+    pub use crate::features::add_group_to_group::error::AddGroupToGroupError;
+    pub use crate::features::add_group_to_group::ports::{
+        AddGroupToGroupUseCasePort, GroupHierarchyEventPort, GroupHierarchyPort,
+    };
+    pub use crate::features::add_group_to_group::use_case::AddGroupToGroupUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
+}
+
+// ============================================================================
+// FEATURE: list_group_members
+// ============================================================================
+pub mod list_group_members {
+    pub use crate::features::list_group_members::dto::{
+        GroupMember, GroupMemberSummary, ListGroupMembersQuery, ListGroupMembersResponse,
+    };
+    pub use crate::features::list_group_members::error::ListGroupMembersError;
+    pub use crate::features::list_group_members::ports::{
+        GroupMemberFinderPort, ListGroupMembersUseCasePort,
+    };
+    pub use crate::features::list_group_members::use_case::ListGroupMembersUseCase;
+}
+
+// ============================================================================
+// FEATURE: list_principals_with_access
+// ============================================================================
+pub mod list_principals_with_access {
+    pub use crate::features::list_principals_with_access::dto::{
+        AccessIndexReplay, AccessLevel, GroupMembershipChanged, IndexFreshness, IndexStatus,
+        ListPrincipalsWithAccessQuery, ListPrincipalsWithAccessResponse, PolicyAttachmentChanged,
+        PolicyDeleted, PolicyStored, PrincipalAccess,
+    };
+    pub use crate::features::list_principals_with_access::error::ListPrincipalsWithAccessError;
+    pub use crate::features::list_principals_with_access::ports::{
+        AccessIndexPort, ListPrincipalsWithAccessUseCasePort,
+    };
+    pub use crate::features::list_principals_with_access::projection::AccessIndexProjection;
+    pub use crate::features::list_principals_with_access::use_case::ListPrincipalsWithAccessUseCase;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::list_principals_with_access::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: set_user_status
+// ============================================================================
+pub mod set_user_status {
+    pub use crate::features::set_user_status::dto::{
+        SetUserStatusCommand, SetUserStatusResponse, UserStatusChanged,
+    };
+    pub use crate::features::set_user_status::error::SetUserStatusError;
+    pub use crate::features::set_user_status::ports::{
+        SetUserStatusUseCasePort, UserStatusEventPort, UserStatusPort,
+    };
+    pub use crate::features::set_user_status::use_case::SetUserStatusUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
+    pub use kernel::PrincipalStatus;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::set_user_status::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: update_user_attributes
+// ============================================================================
+pub mod update_user_attributes {
+    pub use crate::features::update_user_attributes::dto::{
+        AttributeChange, UpdateUserAttributesCommand, UpdateUserAttributesResponse,
+        UserAttributes, UserAttributesChanged,
+    };
+    pub use crate::features::update_user_attributes::error::UpdateUserAttributesError;
+    pub use crate::features::update_user_attributes::ports::{
+        UpdateUserAttributesUseCasePort, UserAttributesEventPort, UserAttributesPort,
+    };
+    pub use crate::features::update_user_attributes::use_case::UpdateUserAttributesUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::update_user_attributes::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: update_group_attributes
+// ============================================================================
+pub mod update_group_attributes {
+    pub use crate::features::update_group_attributes::dto::{
+        AttributeChange, GroupAttributes, GroupAttributesChanged, UpdateGroupAttributesCommand,
+        UpdateGroupAttributesResponse,
+    };
+    pub use crate::features::update_group_attributes::error::UpdateGroupAttributesError;
+    pub use crate::features::update_group_attributes::ports::{
+        GroupAttributesEventPort, GroupAttributesPort, UpdateGroupAttributesUseCasePort,
+    };
+    pub use crate::features::update_group_attributes::use_case::UpdateGroupAttributesUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::update_group_attributes::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: export_iam_state
+// ============================================================================
+pub mod export_iam_state {
+    pub use crate::features::export_iam_state::dto::{
+        ExportIamStateQuery, GroupRecord, IAM_STATE_FORMAT_VERSION, IamStateDocument,
+        PolicyRecord, UserRecord,
+    };
+    pub use crate::features::export_iam_state::error::ExportIamStateError;
+    pub use crate::features::export_iam_state::ports::{
+        ExportIamStateUseCasePort, IamStateReaderPort,
+    };
+    pub use crate::features::export_iam_state::use_case::ExportIamStateUseCase;
+}
+
+// ============================================================================
+// FEATURE: import_iam_state
+// ============================================================================
+pub mod import_iam_state {
+    pub use crate::features::import_iam_state::dto::{
+        ConflictStrategy, EntityChanges, GroupMembershipChanged, IamStateChanges,
+        ImportIamStateCommand, ImportIamStateReport, PolicyAttachmentChanged, PolicyStored,
+    };
+    pub use crate::features::import_iam_state::error::ImportIamStateError;
+    pub use crate::features::import_iam_state::ports::{
+        IamStateEventPort, IamStateStorePort, ImportIamStateUseCasePort,
+    };
+    pub use crate::features::import_iam_state::use_case::ImportIamStateUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
+}
+
+// ============================================================================
+// FEATURE: import_policies
+// ============================================================================
+pub mod import_policies {
+    pub use crate::features::import_policies::dto::{
+        ConflictStrategy, ImportPoliciesCommand, ImportPoliciesReport, PolicyConflict, PolicyFile,
+        PolicyImportAction, PolicyImportFailure, PolicyImportOutcome,
+    };
+    pub use crate::features::import_policies::error::ImportPoliciesError;
+    pub use crate::features::import_policies::ports::{
+        ImportPoliciesUseCasePort, PolicyImportStorePort,
+    };
+    pub use crate::features::import_policies::use_case::ImportPoliciesUseCase;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::import_policies::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: policy_walk
+// ============================================================================
+pub mod policy_walk {
+    pub use crate::features::policy_walk::dto::StoredPolicy;
+    pub use crate::features::policy_walk::error::PolicyWalkError;
+    pub use crate::features::policy_walk::ports::PolicyWalkStorePort;
+    pub use crate::features::policy_walk::walk::PolicyWalk;
+}
+
+// ============================================================================
+// FEATURE: revalidate_policies
+// ============================================================================
+pub mod revalidate_policies {
+    pub use crate::features::revalidate_policies::alerts::EventBusPolicyDisabledAlerts;
+    pub use crate::features::revalidate_policies::dto::{
+        DisabledPolicy, PolicyDisabled, RevalidationConfig, RevalidationReport, SchemaChanged,
+        StoredPolicy,
+    };
+    pub use crate::features::revalidate_policies::error::RevalidatePoliciesError;
+    pub use crate::features::revalidate_policies::ports::{
+        PolicyDisabledAlertPort, PolicyRevalidationStorePort, PolicyWalkStorePort,
+        SchemaChangeRevalidatorPort,
+    };
+    pub use crate::features::revalidate_policies::use_case::SchemaChangeRevalidator;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::revalidate_policies::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: find_dangling_references
+// ============================================================================
+pub mod find_dangling_references {
+    pub use crate::features::find_dangling_references::dto::{
+        DanglingPolicy, DanglingReferencesReport, EntityExistence, FindDanglingReferencesQuery,
+        UnverifiedPolicy,
+    };
+    pub use crate::features::find_dangling_references::error::FindDanglingReferencesError;
+    pub use crate::features::find_dangling_references::ports::{
+        EntityExistencePort, FindDanglingReferencesPort, PolicyWalkStorePort,
+    };
+    pub use crate::features::find_dangling_references::use_case::FindDanglingReferencesUseCase;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::find_dangling_references::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: search_policies
+// ============================================================================
+pub mod search_policies {
+    pub use crate::features::search_policies::dto::{
+        PolicySearchMatch, SearchPoliciesQuery, SearchPoliciesResponse,
+    };
+    pub use crate::features::search_policies::error::SearchPoliciesError;
+    pub use crate::features::search_policies::ports::{PolicyWalkStorePort, SearchPoliciesPort};
+    pub use crate::features::search_policies::use_case::SearchPoliciesUseCase;
+
+    // Re-export factories for DI
+    pub mod factories {
+        pub use crate::features::search_policies::factories::*;
+    }
 }
 
 // ============================================================================
 // FEATURE: create_policy
 // ============================================================================
 pub mod create_policy {
-    pub use crate::features::create_policy::dto::{CreatePolicyCommand, PolicyView};
+    pub use crate::features::create_policy::dto::{CreatePolicyCommand, PolicyStored, PolicyView};
     pub use crate::features::create_policy::error::CreatePolicyError;
     pub use crate::features::create_policy::ports::{
-        CreatePolicyPort, CreatePolicyUseCasePort, PolicyValidationError, PolicyValidator,
-        ValidationResult,
+        CreatePolicyEventPort, CreatePolicyPort, CreatePolicyUseCasePort, PolicyValidationError,
+        PolicyValidator, ValidationResult,
     };
     pub use crate::features::create_policy::use_case::CreatePolicyUseCase;
     pub use crate::features::create_policy::validator::CedarPolicyValidator;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
     
     // Re-export factories for DI
     pub mod factories {
@@ -82,6 +320,18 @@ pub mod get_policy {
     pub use crate::features::get_policy::use_case::GetPolicyUseCase;
 }
 
+// ============================================================================
+// FEATURE: get_policies
+// ============================================================================
+pub mod get_policies {
+    pub use crate::features::get_policies::dto::{
+        GetPoliciesQuery, GetPoliciesResponse, PolicyView,
+    };
+    pub use crate::features::get_policies::error::GetPoliciesError;
+    pub use crate::features::get_policies::ports::{GetPoliciesUseCasePort, PolicyBatchReader};
+    pub use crate::features::get_policies::use_case::GetPoliciesUseCase;
+}
+
 // ============================================================================
 // FEATURE: list_policies
 // ============================================================================
@@ -98,22 +348,25 @@ pub mod list_policies {
 // FEATURE: update_policy
 // ============================================================================
 pub mod update_policy {
-    pub use crate::features::update_policy::dto::{PolicyView, UpdatePolicyCommand};
+    pub use crate::features::update_policy::dto::{PolicyStored, PolicyView, UpdatePolicyCommand};
     pub use crate::features::update_policy::error::UpdatePolicyError;
     pub use crate::features::update_policy::ports::{
-        PolicyValidationError, PolicyValidator, UpdatePolicyPort, ValidationResult,
+        PolicyValidationError, PolicyValidator, UpdatePolicyEventPort, UpdatePolicyPort,
+        ValidationResult,
     };
     pub use crate::features::update_policy::use_case::UpdatePolicyUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
 }
 
 // ============================================================================
 // FEATURE: delete_policy
 // ============================================================================
 pub mod delete_policy {
-    pub use crate::features::delete_policy::dto::DeletePolicyCommand;
+    pub use crate::features::delete_policy::dto::{DeletePolicyCommand, PolicyDeleted};
     pub use crate::features::delete_policy::error::DeletePolicyError;
-    pub use crate::features::delete_policy::ports::DeletePolicyPort;
+    pub use crate::features::delete_policy::ports::{DeletePolicyEventPort, DeletePolicyPort};
     pub use crate::features::delete_policy::use_case::DeletePolicyUseCase;
+    pub use crate::infrastructure::event_bus::EventBusIamEvents;
 }
 
 // ============================================================================
@@ -122,6 +375,7 @@ pub mod delete_policy {
 pub mod register_iam_schema {
     // Direct exports for convenience
     pub use crate::features::register_iam_schema::error::RegisterIamSchemaError;
+    pub use crate::features::register_iam_schema::fragment::IamSchemaFragmentProvider;
     pub use crate::features::register_iam_schema::use_case::RegisterIamSchemaUseCase;
     
     // Re-export as submodules for path compatibility
@@ -151,6 +405,7 @@ pub mod evaluate_iam_policies {
 // FEATURE: get_effective_policies
 // ============================================================================
 pub mod get_effective_policies {
+    pub use crate::features::get_effective_policies::adapter::GetEffectivePoliciesAdapter;
     pub use crate::features::get_effective_policies::dto::{
         EffectivePoliciesResponse, GetEffectivePoliciesQuery,
     };
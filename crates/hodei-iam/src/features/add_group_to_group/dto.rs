@@ -0,0 +1,44 @@
+//! Data Transfer Objects for add_group_to_group feature
+
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use kernel::{HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Event published when the child group joins the parent, shared with the
+/// contexts that react to membership changes
+pub use kernel::GroupMembershipChanged;
+
+/// Command to make one group a member of another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGroupToGroupCommand {
+    /// Group that becomes a member
+    pub child_group_hrn: String,
+    /// Group it becomes a member of
+    pub parent_group_hrn: String,
+}
+
+impl TenantScoped for AddGroupToGroupCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.child_group_hrn);
+        visitor.hrn_str(&self.parent_group_hrn);
+    }
+}
+
+impl ActionTrait for AddGroupToGroupCommand {
+    fn name() -> &'static str {
+        "AddGroupToGroup"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("iam").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Iam::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Iam::Group".to_string()
+    }
+}
@@ -0,0 +1,28 @@
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+/// Errors that can occur when nesting a group inside another
+#[derive(Debug, Error)]
+pub enum AddGroupToGroupError {
+    #[error("Invalid group HRN: {0}")]
+    InvalidGroupHrn(String),
+
+    #[error("Group not found: {0}")]
+    GroupNotFound(String),
+
+    #[error("Adding {child} to {parent} would make {child} its own ancestor")]
+    CycleDetected { child: String, parent: String },
+
+    #[error("Adding {child} to {parent} would nest groups more than {max_depth} levels deep")]
+    NestingTooDeep {
+        child: String,
+        parent: String,
+        max_depth: usize,
+    },
+
+    #[error("Failed to save group: {0}")]
+    PersistenceError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
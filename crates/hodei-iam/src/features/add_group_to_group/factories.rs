@@ -0,0 +1,36 @@
+//! Factory for creating the AddGroupToGroup use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::add_group_to_group::ports::{AddGroupToGroupUseCasePort, GroupHierarchyPort};
+use crate::features::add_group_to_group::use_case::AddGroupToGroupUseCase;
+
+/// Create the AddGroupToGroup use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `hierarchy` - Port for reading and writing group nesting
+///
+/// # Returns
+///
+/// Arc<dyn AddGroupToGroupUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let group_repo = Arc::new(SurrealGroupAdapter::new(db));
+///
+/// let add_group_to_group = create_add_group_to_group_use_case(group_repo);
+/// ```
+pub fn create_add_group_to_group_use_case(
+    hierarchy: Arc<dyn GroupHierarchyPort>,
+) -> Arc<dyn AddGroupToGroupUseCasePort> {
+    info!("Creating AddGroupToGroup use case");
+    Arc::new(AddGroupToGroupUseCase::new(hierarchy))
+}
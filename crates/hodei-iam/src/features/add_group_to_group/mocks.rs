@@ -0,0 +1,104 @@
+//! Mock implementations for testing Add Group To Group feature
+
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::dto::GroupMembershipChanged;
+use super::error::AddGroupToGroupError;
+use super::ports::{GroupHierarchyEventPort, GroupHierarchyPort};
+
+/// Mock GroupHierarchyPort for testing
+///
+/// Counts saves so tests can check that rejected or no-op nestings are not
+/// written.
+pub struct MockGroupHierarchyPort {
+    parents: Mutex<HashMap<Hrn, Vec<Hrn>>>,
+    saves: Mutex<usize>,
+    should_fail: bool,
+}
+
+impl MockGroupHierarchyPort {
+    /// Create a mock with no groups
+    pub fn new() -> Self {
+        Self {
+            parents: Mutex::new(HashMap::new()),
+            saves: Mutex::new(0),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose saves fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a group that is a member of `parents`
+    pub fn with_group(self, group_hrn: Hrn, parents: Vec<Hrn>) -> Self {
+        self.parents.lock().unwrap().insert(group_hrn, parents);
+        self
+    }
+
+    /// Current parent groups of a group
+    pub fn parents(&self, group_hrn: &Hrn) -> Option<Vec<Hrn>> {
+        self.parents.lock().unwrap().get(group_hrn).cloned()
+    }
+
+    /// Number of saves made
+    pub fn saves(&self) -> usize {
+        *self.saves.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl GroupHierarchyPort for MockGroupHierarchyPort {
+    async fn find_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<Vec<Hrn>>, AddGroupToGroupError> {
+        Ok(self.parents(group_hrn))
+    }
+
+    async fn save_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+        parent_group_hrns: &[Hrn],
+    ) -> Result<(), AddGroupToGroupError> {
+        if self.should_fail {
+            return Err(AddGroupToGroupError::PersistenceError(
+                "Mock failure".to_string(),
+            ));
+        }
+        *self.saves.lock().unwrap() += 1;
+        match self.parents.lock().unwrap().get_mut(group_hrn) {
+            Some(current) => {
+                *current = parent_group_hrns.to_vec();
+                Ok(())
+            }
+            None => Err(AddGroupToGroupError::GroupNotFound(group_hrn.to_string())),
+        }
+    }
+}
+
+/// Mock GroupHierarchyEventPort recording every event
+#[derive(Default)]
+pub struct MockGroupHierarchyEventPort {
+    events: Mutex<Vec<GroupMembershipChanged>>,
+}
+
+impl MockGroupHierarchyEventPort {
+    pub fn events(&self) -> Vec<GroupMembershipChanged> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl GroupHierarchyEventPort for MockGroupHierarchyEventPort {
+    async fn publish(&self, event: GroupMembershipChanged) {
+        self.events.lock().unwrap().push(event);
+    }
+}
@@ -0,0 +1,34 @@
+//! add_group_to_group Feature (Vertical Slice)
+//!
+//! This module implements group nesting for IAM following VSA: a group can be
+//! a member of another group (e.g. "Backend" inside "Engineering"), and its
+//! members inherit the policies of every ancestor group through
+//! get_effective_policies.
+//!
+//! A group can never become its own ancestor, and chains are limited to
+//! `MAX_GROUP_NESTING_DEPTH` levels, so effective-policy resolution always
+//! terminates on a bounded walk.
+//!
+//! Structure:
+//! - dto.rs              -> Command DTO
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interface (ISP)
+//! - use_case.rs         -> Core business logic (AddGroupToGroupUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::AddGroupToGroupCommand;
+pub use error::AddGroupToGroupError;
+pub use ports::{AddGroupToGroupUseCasePort, GroupHierarchyEventPort, GroupHierarchyPort};
+pub use use_case::AddGroupToGroupUseCase;
@@ -0,0 +1,71 @@
+use super::dto::{AddGroupToGroupCommand, GroupMembershipChanged};
+use super::error::AddGroupToGroupError;
+use async_trait::async_trait;
+use kernel::Hrn;
+
+/// Port for reading and writing the groups a group is a member of
+///
+/// This port abstracts group hierarchy persistence.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the add_group_to_group feature.
+#[async_trait]
+pub trait GroupHierarchyPort: Send + Sync {
+    /// Find the groups a group is directly a member of
+    ///
+    /// # Arguments
+    /// * `group_hrn` - The HRN of the group
+    ///
+    /// # Returns
+    /// * `Ok(Some(Vec<Hrn>))` with the parent groups if the group was found
+    /// * `Ok(None)` if no group with that HRN exists
+    /// * `Err(AddGroupToGroupError)` if there was an error during lookup
+    async fn find_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<Vec<Hrn>>, AddGroupToGroupError>;
+
+    /// Save the groups an existing group is directly a member of
+    ///
+    /// Only the parent groups are written; every other field is left as it is.
+    ///
+    /// # Arguments
+    /// * `group_hrn` - The HRN of the group
+    /// * `parent_group_hrns` - The complete list of its parent groups
+    ///
+    /// # Returns
+    /// * `Ok(())` if the parent groups were saved
+    /// * `Err(AddGroupToGroupError::GroupNotFound)` if the group does not exist
+    /// * `Err(AddGroupToGroupError)` if there was an error saving them
+    async fn save_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+        parent_group_hrns: &[Hrn],
+    ) -> Result<(), AddGroupToGroupError>;
+}
+
+/// Port for announcing nested groups
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the change.
+#[async_trait]
+pub trait GroupHierarchyEventPort: Send + Sync {
+    async fn publish(&self, event: GroupMembershipChanged);
+}
+
+/// Port for the AddGroupToGroup use case
+///
+/// This port defines the contract for executing the add group to group use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait AddGroupToGroupUseCasePort: Send + Sync {
+    /// Execute the add group to group use case
+    ///
+    /// # Arguments
+    /// * `command` - The command with the child and parent group HRNs
+    ///
+    /// # Returns
+    /// * `Ok(())` if the child group is now a member of the parent group
+    /// * `Err(AddGroupToGroupError)` if the nesting was rejected or failed
+    async fn execute(&self, command: AddGroupToGroupCommand) -> Result<(), AddGroupToGroupError>;
+}
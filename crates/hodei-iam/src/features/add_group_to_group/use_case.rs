@@ -0,0 +1,189 @@
+use super::dto::{AddGroupToGroupCommand, GroupMembershipChanged};
+use super::error::AddGroupToGroupError;
+use super::ports::{AddGroupToGroupUseCasePort, GroupHierarchyEventPort, GroupHierarchyPort};
+use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
+use async_trait::async_trait;
+use kernel::{Hrn, TenantContext};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{Instrument, info, info_span, instrument, warn};
+
+/// Use case for making one group a member of another
+///
+/// This use case orchestrates the nesting:
+/// 1. Validates and parses the HRNs
+/// 2. Finds both groups
+/// 3. Walks the parent group's ancestors, rejecting the nesting if the child
+///    is among them (a cycle) or if the chain would grow past
+///    `MAX_GROUP_NESTING_DEPTH` levels
+/// 4. Persists the child's updated parent groups
+/// 5. Publishes a [`GroupMembershipChanged`] event for the child joining
+///
+/// Only the chain above the child is checked here: groups nested below the
+/// child are not known to this use case, so get_effective_policies enforces
+/// the same limit when it walks the full chain from a user.
+pub struct AddGroupToGroupUseCase {
+    hierarchy: Arc<dyn GroupHierarchyPort>,
+    events: Option<Arc<dyn GroupHierarchyEventPort>>,
+}
+
+impl AddGroupToGroupUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `hierarchy` - Implementation of GroupHierarchyPort for group nesting
+    pub fn new(hierarchy: Arc<dyn GroupHierarchyPort>) -> Self {
+        Self {
+            hierarchy,
+            events: None,
+        }
+    }
+
+    /// Publish an event for every group nested in another
+    pub fn with_events(mut self, events: Arc<dyn GroupHierarchyEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Execute the add group to group use case
+    ///
+    /// Adding a group to a group it is already a member of succeeds without
+    /// writing anything.
+    ///
+    /// # Arguments
+    /// * `cmd` - AddGroupToGroupCommand containing the child and parent HRNs
+    ///
+    /// # Returns
+    /// * Ok(()) if the child group is now a member of the parent group
+    /// * Err(AddGroupToGroupError) if there was an error
+    #[instrument(name = "add_group_to_group", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        child = %cmd.child_group_hrn,
+        parent = %cmd.parent_group_hrn
+    ))]
+    pub async fn execute(&self, cmd: AddGroupToGroupCommand) -> Result<(), AddGroupToGroupError> {
+        let child_hrn = Hrn::from_string(&cmd.child_group_hrn)
+            .ok_or_else(|| AddGroupToGroupError::InvalidGroupHrn(cmd.child_group_hrn.clone()))?;
+        let parent_hrn = Hrn::from_string(&cmd.parent_group_hrn)
+            .ok_or_else(|| AddGroupToGroupError::InvalidGroupHrn(cmd.parent_group_hrn.clone()))?;
+
+        let mut child_parents = self
+            .hierarchy
+            .find_parent_group_hrns(&child_hrn)
+            .await?
+            .ok_or_else(|| AddGroupToGroupError::GroupNotFound(cmd.child_group_hrn.clone()))?;
+        let grandparents = self
+            .hierarchy
+            .find_parent_group_hrns(&parent_hrn)
+            .await?
+            .ok_or_else(|| AddGroupToGroupError::GroupNotFound(cmd.parent_group_hrn.clone()))?;
+
+        if child_parents.contains(&parent_hrn) {
+            return Ok(());
+        }
+
+        self.ensure_can_nest(&child_hrn, &parent_hrn, grandparents)
+            .instrument(info_span!("validation"))
+            .await?;
+
+        child_parents.push(parent_hrn.clone());
+        self.hierarchy
+            .save_parent_group_hrns(&child_hrn, &child_parents)
+            .instrument(info_span!("persistence"))
+            .await?;
+
+        info!(
+            child = %cmd.child_group_hrn,
+            parent = %cmd.parent_group_hrn,
+            "Group added to group"
+        );
+        if let Some(events) = &self.events {
+            events
+                .publish(GroupMembershipChanged::joined(child_hrn, parent_hrn))
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Walk up from `parent_hrn` one level at a time, failing if `child_hrn`
+    /// is reached or if the chain from the child would get too long
+    ///
+    /// `grandparents` are the parent's own parent groups, already looked up.
+    /// Each ancestor is visited once, and a group missing from the store
+    /// just ends its branch.
+    async fn ensure_can_nest(
+        &self,
+        child_hrn: &Hrn,
+        parent_hrn: &Hrn,
+        grandparents: Vec<Hrn>,
+    ) -> Result<(), AddGroupToGroupError> {
+        let cycle = || AddGroupToGroupError::CycleDetected {
+            child: child_hrn.to_string(),
+            parent: parent_hrn.to_string(),
+        };
+        if child_hrn == parent_hrn {
+            return Err(cycle());
+        }
+
+        let mut visited: HashSet<Hrn> = HashSet::from([parent_hrn.clone()]);
+        let mut level: Vec<Hrn> = grandparents
+            .into_iter()
+            .filter(|hrn| visited.insert(hrn.clone()))
+            .collect();
+        // The child is one level, the parent another
+        let mut depth = 2;
+
+        while !level.is_empty() {
+            if level.contains(child_hrn) {
+                warn!(child = %child_hrn, parent = %parent_hrn, "Group nesting cycle rejected");
+                return Err(cycle());
+            }
+
+            depth += 1;
+            if depth > MAX_GROUP_NESTING_DEPTH {
+                warn!(child = %child_hrn, parent = %parent_hrn, "Group nesting too deep");
+                return Err(AddGroupToGroupError::NestingTooDeep {
+                    child: child_hrn.to_string(),
+                    parent: parent_hrn.to_string(),
+                    max_depth: MAX_GROUP_NESTING_DEPTH,
+                });
+            }
+
+            let mut next_level = Vec::new();
+            for group_hrn in &level {
+                let parents = self
+                    .hierarchy
+                    .find_parent_group_hrns(group_hrn)
+                    .await?
+                    .unwrap_or_default();
+                next_level.extend(
+                    parents
+                        .into_iter()
+                        .filter(|hrn| visited.insert(hrn.clone())),
+                );
+            }
+            level = next_level;
+        }
+
+        Ok(())
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// Both HRNs must belong to the tenant, otherwise the command fails with
+    /// `CrossTenantAccess` before any lookup.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        cmd: AddGroupToGroupCommand,
+    ) -> Result<(), AddGroupToGroupError> {
+        tenant.run(cmd, |cmd| self.execute(cmd)).await
+    }
+}
+
+#[async_trait]
+impl AddGroupToGroupUseCasePort for AddGroupToGroupUseCase {
+    async fn execute(&self, command: AddGroupToGroupCommand) -> Result<(), AddGroupToGroupError> {
+        self.execute(command).await
+    }
+}
@@ -0,0 +1,267 @@
+//! Unit tests for add_group_to_group use case
+//!
+//! These tests verify the behavior of the AddGroupToGroupUseCase in isolation,
+//! using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use kernel::{Hrn, TenantContext};
+
+    use crate::features::add_group_to_group::{
+        dto::{AddGroupToGroupCommand, GroupMembershipChanged},
+        error::AddGroupToGroupError,
+        mocks::{MockGroupHierarchyEventPort, MockGroupHierarchyPort},
+        use_case::AddGroupToGroupUseCase,
+    };
+    use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn group_in(account: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            account.to_string(),
+            "Group".to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn group(id: &str) -> Hrn {
+        group_in("account123", id)
+    }
+
+    fn command(child: &Hrn, parent: &Hrn) -> AddGroupToGroupCommand {
+        AddGroupToGroupCommand {
+            child_group_hrn: child.to_string(),
+            parent_group_hrn: parent.to_string(),
+        }
+    }
+
+    /// `g1` is in `g2`, ... up to `g{levels}`, plus a standalone `new`
+    fn chain(levels: usize) -> MockGroupHierarchyPort {
+        let mut port = MockGroupHierarchyPort::new().with_group(group("new"), vec![]);
+        for level in 1..=levels {
+            let parents = if level < levels {
+                vec![group(&format!("g{}", level + 1))]
+            } else {
+                vec![]
+            };
+            port = port.with_group(group(&format!("g{level}")), parents);
+        }
+        port
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_add_group_to_group() {
+        let port = Arc::new(
+            MockGroupHierarchyPort::new()
+                .with_group(group("backend"), vec![])
+                .with_group(group("engineering"), vec![]),
+        );
+        let use_case = AddGroupToGroupUseCase::new(port.clone());
+
+        use_case
+            .execute(command(&group("backend"), &group("engineering")))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            port.parents(&group("backend")),
+            Some(vec![group("engineering")])
+        );
+        assert_eq!(port.parents(&group("engineering")), Some(vec![]));
+    }
+
+    #[tokio::test]
+    async fn test_existing_membership_is_not_saved_again() {
+        let port = Arc::new(
+            MockGroupHierarchyPort::new()
+                .with_group(group("backend"), vec![group("engineering")])
+                .with_group(group("engineering"), vec![]),
+        );
+        let use_case = AddGroupToGroupUseCase::new(port.clone());
+
+        use_case
+            .execute(command(&group("backend"), &group("engineering")))
+            .await
+            .unwrap();
+
+        assert_eq!(port.saves(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_group_cannot_join_itself() {
+        let port = Arc::new(MockGroupHierarchyPort::new().with_group(group("backend"), vec![]));
+        let use_case = AddGroupToGroupUseCase::new(port.clone());
+
+        let result = use_case
+            .execute(command(&group("backend"), &group("backend")))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AddGroupToGroupError::CycleDetected { .. })
+        ));
+        assert_eq!(port.saves(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_group_cannot_join_its_descendant() {
+        // g1 is in g2 is in g3; putting g3 in g1 would close the loop
+        let port = Arc::new(chain(3));
+        let use_case = AddGroupToGroupUseCase::new(port.clone());
+
+        let result = use_case.execute(command(&group("g3"), &group("g1"))).await;
+
+        match result {
+            Err(AddGroupToGroupError::CycleDetected { child, parent }) => {
+                assert_eq!(child, group("g3").to_string());
+                assert_eq!(parent, group("g1").to_string());
+            }
+            other => panic!("Expected CycleDetected, got {:?}", other),
+        }
+        assert_eq!(port.parents(&group("g3")), Some(vec![]));
+    }
+
+    #[tokio::test]
+    async fn test_nesting_up_to_the_depth_limit() {
+        // `new` plus a chain of MAX - 1 groups above it is exactly MAX levels
+        let port = Arc::new(chain(MAX_GROUP_NESTING_DEPTH - 1));
+        let use_case = AddGroupToGroupUseCase::new(port.clone());
+
+        use_case
+            .execute(command(&group("new"), &group("g1")))
+            .await
+            .unwrap();
+
+        assert_eq!(port.parents(&group("new")), Some(vec![group("g1")]));
+    }
+
+    #[tokio::test]
+    async fn test_nesting_beyond_the_depth_limit_is_rejected() {
+        let port = Arc::new(chain(MAX_GROUP_NESTING_DEPTH));
+        let use_case = AddGroupToGroupUseCase::new(port.clone());
+
+        let result = use_case.execute(command(&group("new"), &group("g1"))).await;
+
+        assert!(matches!(
+            result,
+            Err(AddGroupToGroupError::NestingTooDeep { max_depth, .. })
+                if max_depth == MAX_GROUP_NESTING_DEPTH
+        ));
+        assert_eq!(port.saves(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_groups_are_not_found() {
+        let port = Arc::new(MockGroupHierarchyPort::new().with_group(group("backend"), vec![]));
+        let use_case = AddGroupToGroupUseCase::new(port);
+
+        let missing_parent = use_case
+            .execute(command(&group("backend"), &group("missing")))
+            .await;
+        let missing_child = use_case
+            .execute(command(&group("missing"), &group("backend")))
+            .await;
+
+        assert!(matches!(
+            missing_parent,
+            Err(AddGroupToGroupError::GroupNotFound(hrn)) if hrn == group("missing").to_string()
+        ));
+        assert!(matches!(
+            missing_child,
+            Err(AddGroupToGroupError::GroupNotFound(hrn)) if hrn == group("missing").to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_group_hrn() {
+        let use_case = AddGroupToGroupUseCase::new(Arc::new(MockGroupHierarchyPort::new()));
+
+        let result = use_case
+            .execute(AddGroupToGroupCommand {
+                child_group_hrn: "not-an-hrn".to_string(),
+                parent_group_hrn: group("engineering").to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AddGroupToGroupError::InvalidGroupHrn(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_persistence_error() {
+        let port = MockGroupHierarchyPort::failing()
+            .with_group(group("backend"), vec![])
+            .with_group(group("engineering"), vec![]);
+        let use_case = AddGroupToGroupUseCase::new(Arc::new(port));
+
+        let result = use_case
+            .execute(command(&group("backend"), &group("engineering")))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AddGroupToGroupError::PersistenceError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_for_tenant_rejects_other_tenants_groups() {
+        let foreign = group_in("other-account", "admins");
+        let port = Arc::new(
+            MockGroupHierarchyPort::new()
+                .with_group(group("backend"), vec![])
+                .with_group(foreign.clone(), vec![]),
+        );
+        let use_case = AddGroupToGroupUseCase::new(port.clone());
+        let tenant = TenantContext::new("account123");
+
+        let result = use_case
+            .execute_for_tenant(&tenant, command(&group("backend"), &foreign))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AddGroupToGroupError::CrossTenantAccess(_))
+        ));
+        assert_eq!(port.saves(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_nesting_is_published_once() {
+        let port = Arc::new(
+            MockGroupHierarchyPort::new()
+                .with_group(group("backend"), vec![])
+                .with_group(group("engineering"), vec![]),
+        );
+        let events = Arc::new(MockGroupHierarchyEventPort::default());
+        let use_case = AddGroupToGroupUseCase::new(port).with_events(events.clone());
+
+        for _ in 0..2 {
+            use_case
+                .execute(command(&group("backend"), &group("engineering")))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            events.events(),
+            vec![GroupMembershipChanged::joined(
+                group("backend"),
+                group("engineering")
+            )]
+        );
+    }
+}
@@ -3,6 +3,11 @@
 use serde::{Deserialize, Serialize};
 use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
+use kernel::{HrnVisitor, TenantScoped};
+
+/// Event published when the user joins the group, shared with the contexts
+/// that react to membership changes
+pub use kernel::GroupMembershipChanged;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddUserToGroupCommand {
@@ -10,6 +15,13 @@ pub struct AddUserToGroupCommand {
     pub group_hrn: String,
 }
 
+impl TenantScoped for AddUserToGroupCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.user_hrn);
+        visitor.hrn_str(&self.group_hrn);
+    }
+}
+
 impl ActionTrait for AddUserToGroupCommand {
     fn name() -> &'static str {
         "AddUserToGroup"
@@ -1,3 +1,4 @@
+use kernel::CrossTenantAccess;
 use thiserror::Error;
 
 /// Errors that can occur when adding a user to a group
@@ -17,4 +18,7 @@ pub enum AddUserToGroupError {
 
     #[error("Failed to save user: {0}")]
     PersistenceError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
-use super::dto::{AddUserToGroupCommand, GroupLookupDto, UserLookupDto, UserPersistenceDto};
+use super::dto::{
+    AddUserToGroupCommand, GroupLookupDto, GroupMembershipChanged, UserLookupDto,
+    UserPersistenceDto,
+};
 use super::error::AddUserToGroupError;
 use async_trait::async_trait;
 use kernel::Hrn;
@@ -65,6 +68,15 @@ pub trait UserGroupPersister: Send + Sync {
     async fn save_user(&self, user_dto: &UserPersistenceDto) -> Result<(), AddUserToGroupError>;
 }
 
+/// Port for announcing new group members
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the change.
+#[async_trait]
+pub trait UserMembershipEventPort: Send + Sync {
+    async fn publish(&self, event: GroupMembershipChanged);
+}
+
 /// Port for the AddUserToGroup use case
 ///
 /// This port defines the contract for executing the add user to group use case.
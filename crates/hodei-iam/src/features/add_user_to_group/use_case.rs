@@ -1,9 +1,12 @@
-use super::dto::{AddUserToGroupCommand, UserPersistenceDto};
+use super::dto::{AddUserToGroupCommand, GroupMembershipChanged, UserPersistenceDto};
 use super::error::AddUserToGroupError;
-use super::ports::{AddUserToGroupUseCasePort, GroupFinder, UserFinder, UserGroupPersister};
+use super::ports::{
+    AddUserToGroupUseCasePort, GroupFinder, UserFinder, UserGroupPersister, UserMembershipEventPort,
+};
 use async_trait::async_trait;
-use kernel::Hrn;
+use kernel::{Hrn, TenantContext};
 use std::sync::Arc;
+use tracing::{Instrument, info_span, instrument};
 
 /// Use case for adding a user to a group
 ///
@@ -12,10 +15,13 @@ use std::sync::Arc;
 /// 2. Finds the user and group
 /// 3. Adds the user to the group
 /// 4. Persists the updated user
+/// 5. Publishes a [`GroupMembershipChanged`] event if the user was not yet a
+///    member
 pub struct AddUserToGroupUseCase {
     user_finder: Arc<dyn UserFinder>,
     group_finder: Arc<dyn GroupFinder>,
     user_persister: Arc<dyn UserGroupPersister>,
+    events: Option<Arc<dyn UserMembershipEventPort>>,
 }
 
 impl AddUserToGroupUseCase {
@@ -34,9 +40,16 @@ impl AddUserToGroupUseCase {
             user_finder,
             group_finder,
             user_persister,
+            events: None,
         }
     }
 
+    /// Publish an event for every user that joins a group
+    pub fn with_events(mut self, events: Arc<dyn UserMembershipEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Execute the add user to group use case
     ///
     /// # Arguments
@@ -45,6 +58,11 @@ impl AddUserToGroupUseCase {
     /// # Returns
     /// * Ok(()) if the user was successfully added to the group
     /// * Err(AddUserToGroupError) if there was an error
+    #[instrument(name = "add_user_to_group", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        principal = %cmd.user_hrn,
+        group = %cmd.group_hrn
+    ))]
     pub async fn execute(&self, cmd: AddUserToGroupCommand) -> Result<(), AddUserToGroupError> {
         // Parse and validate HRNs
         let user_hrn = Hrn::from_string(&cmd.user_hrn)
@@ -69,7 +87,8 @@ impl AddUserToGroupUseCase {
 
         // Add user to group by creating updated DTO
         let mut updated_group_hrns = user_dto.group_hrns.clone();
-        if !updated_group_hrns.contains(&group_hrn.to_string()) {
+        let joined = !updated_group_hrns.contains(&group_hrn.to_string());
+        if joined {
             updated_group_hrns.push(group_hrn.to_string());
         }
 
@@ -83,10 +102,31 @@ impl AddUserToGroupUseCase {
         };
 
         // Persist the updated user
-        self.user_persister.save_user(&updated_user_dto).await?;
+        self.user_persister
+            .save_user(&updated_user_dto)
+            .instrument(info_span!("persistence"))
+            .await?;
+
+        if let Some(events) = self.events.as_ref().filter(|_| joined) {
+            events
+                .publish(GroupMembershipChanged::joined(user_hrn, group_hrn))
+                .await;
+        }
 
         Ok(())
     }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// Both HRNs must belong to the tenant, otherwise the command fails with
+    /// `CrossTenantAccess` before any lookup.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        cmd: AddUserToGroupCommand,
+    ) -> Result<(), AddUserToGroupError> {
+        tenant.run(cmd, |cmd| self.execute(cmd)).await
+    }
 }
 
 #[async_trait]
@@ -1,14 +1,17 @@
 #[cfg(test)]
 mod tests {
     use super::super::dto::{
-        AddUserToGroupCommand, GroupLookupDto, UserLookupDto, UserPersistenceDto,
+        AddUserToGroupCommand, GroupLookupDto, GroupMembershipChanged, UserLookupDto,
+        UserPersistenceDto,
     };
     use super::super::error::AddUserToGroupError;
-    use super::super::ports::{GroupFinder, UserFinder, UserGroupPersister};
+    use super::super::ports::{
+        GroupFinder, UserFinder, UserGroupPersister, UserMembershipEventPort,
+    };
     use super::super::use_case::AddUserToGroupUseCase;
     use crate::internal::domain::{Group, User};
     use kernel::Hrn;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
     // Mock implementation of UserFinder
     struct MockUserFinder {
@@ -75,6 +78,19 @@ mod tests {
         }
     }
 
+    // Mock implementation of UserMembershipEventPort recording every event
+    #[derive(Default)]
+    struct MockUserMembershipEventPort {
+        events: Mutex<Vec<GroupMembershipChanged>>,
+    }
+
+    #[async_trait::async_trait]
+    impl UserMembershipEventPort for MockUserMembershipEventPort {
+        async fn publish(&self, event: GroupMembershipChanged) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
     #[tokio::test]
     async fn test_add_user_to_group_success() {
         // Arrange
@@ -320,4 +336,60 @@ mod tests {
             _ => panic!("Expected PersistenceError"),
         }
     }
+
+    #[tokio::test]
+    async fn test_add_user_to_group_publishes_new_memberships_only() {
+        let user_hrn = Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            "User".to_string(),
+            "test-user".to_string(),
+        );
+        let group_hrn = Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            "Group".to_string(),
+            "test-group".to_string(),
+        );
+        let group_finder = Arc::new(MockGroupFinder {
+            group: Some(GroupLookupDto {
+                hrn: group_hrn.to_string(),
+                name: "Test Group".to_string(),
+                tags: vec![],
+            }),
+            should_fail: false,
+        });
+        let events = Arc::new(MockUserMembershipEventPort::default());
+
+        for group_hrns in [vec![], vec![group_hrn.to_string()]] {
+            let user_finder = Arc::new(MockUserFinder {
+                user: Some(UserLookupDto {
+                    group_hrns,
+                    ..UserLookupDto::new(user_hrn.to_string(), "Test User", "test@example.com")
+                }),
+                should_fail: false,
+            });
+            let use_case = AddUserToGroupUseCase::new(
+                user_finder,
+                group_finder.clone(),
+                Arc::new(MockUserGroupPersister { should_fail: false }),
+            )
+            .with_events(events.clone());
+
+            use_case
+                .execute(AddUserToGroupCommand {
+                    user_hrn: user_hrn.to_string(),
+                    group_hrn: group_hrn.to_string(),
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            *events.events.lock().unwrap(),
+            vec![GroupMembershipChanged::joined(user_hrn, group_hrn)]
+        );
+    }
 }
@@ -5,6 +5,7 @@ use crate::internal::domain::Group;
 use async_trait::async_trait;
 use kernel::HrnGenerator;
 use std::sync::Arc;
+use tracing::{Instrument, info_span, instrument};
 
 /// Use case for creating a new group
 ///
@@ -39,6 +40,10 @@ impl CreateGroupUseCase {
     /// # Returns
     /// * Ok(GroupView) if the group was created successfully
     /// * Err(CreateGroupError) if there was an error
+    #[instrument(name = "create_group", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        group_name = %cmd.group_name
+    ))]
     pub async fn execute(&self, cmd: CreateGroupCommand) -> Result<GroupView, CreateGroupError> {
         // Generate a unique HRN using the HRN generator
         let hrn = self.hrn_generator.new_group_hrn(&cmd.group_name);
@@ -54,7 +59,10 @@ impl CreateGroupUseCase {
             name: group.name.clone(),
             tags: group.tags.clone(),
         };
-        self.persister.save_group(&group_dto).await?;
+        self.persister
+            .save_group(&group_dto)
+            .instrument(info_span!("persistence", group = %hrn))
+            .await?;
 
         // Return the view
         Ok(GroupView {
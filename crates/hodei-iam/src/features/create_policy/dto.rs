@@ -6,9 +6,14 @@
 
 use kernel::Hrn;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
 
+/// Event published for the created policy, shared with the contexts that
+/// react to policy changes
+pub use kernel::PolicyStored;
+
 /// Command to create a new IAM policy
 ///
 /// This command contains all the information needed to create a new policy.
@@ -98,6 +103,10 @@ pub struct PolicyView {
     /// Optional description of the policy
     pub description: Option<String>,
 
+    /// Annotations declared in the policy content, e.g. `@ticket("SEC-1234")`
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+
     /// Timestamp when the policy was created
     pub created_at: chrono::DateTime<chrono::Utc>,
 
@@ -152,6 +161,7 @@ mod tests {
             id: Hrn::from_string("hrn:hodei:iam::test:policy/test-policy").unwrap(),
             content: "permit(principal, action, resource);".to_string(),
             description: Some("Test".to_string()),
+            annotations: HashMap::new(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -4,14 +4,17 @@
 //! by the create_policy use case. These mocks are used in unit tests to
 //! verify the use case logic without requiring real infrastructure.
 
-use crate::features::create_policy::dto::CreatePolicyCommand;
+use crate::features::create_policy::dto::{CreatePolicyCommand, PolicyStored};
 use crate::features::create_policy::error::CreatePolicyError;
-use crate::features::create_policy::ports::{CreatePolicyPort, PolicyValidator};
+use crate::features::create_policy::ports::{
+    CreatePolicyEventPort, CreatePolicyPort, PolicyValidator,
+};
 use async_trait::async_trait;
 use hodei_policies::features::validate_policy::dto::{
     ValidatePolicyCommand, ValidationResult as PoliciesValidationResult,
 };
 use hodei_policies::features::validate_policy::error::ValidatePolicyError;
+use hodei_policies::features::validate_policy::policy_annotations;
 use kernel::domain::policy::{HodeiPolicy, PolicyId};
 use std::sync::{Arc, Mutex};
 
@@ -80,7 +83,7 @@ impl MockPolicyValidator {
 impl PolicyValidator for MockPolicyValidator {
     async fn validate(
         &self,
-        _command: ValidatePolicyCommand,
+        command: ValidatePolicyCommand,
     ) -> Result<PoliciesValidationResult, ValidatePolicyError> {
         // Increment call counter
         *self.call_count.lock().unwrap() += 1;
@@ -98,7 +101,18 @@ impl PolicyValidator for MockPolicyValidator {
         // Build validation errors (convert to Vec<String>)
         let errors = self.validation_errors.clone();
 
-        Ok(PoliciesValidationResult { is_valid, errors })
+        // Report annotations like the real validator when the content parses
+        let annotations = if is_valid {
+            policy_annotations(&command.content).unwrap_or_default()
+        } else {
+            Default::default()
+        };
+
+        Ok(PoliciesValidationResult {
+            is_valid,
+            errors,
+            annotations,
+        })
     }
 }
 
@@ -241,6 +255,27 @@ impl CreatePolicyPort for MockCreatePolicyPort {
     }
 }
 
+/// Mock CreatePolicyEventPort recording every event
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct MockCreatePolicyEventPort {
+    events: Mutex<Vec<PolicyStored>>,
+}
+
+impl MockCreatePolicyEventPort {
+    #[allow(dead_code)]
+    pub fn events(&self) -> Vec<PolicyStored> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl CreatePolicyEventPort for MockCreatePolicyEventPort {
+    async fn publish(&self, event: PolicyStored) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,7 +49,10 @@ mod use_case_test;
 // ---------------------------------------------------------------------------
 pub use dto::{CreatePolicyCommand, PolicyView};
 pub use error::CreatePolicyError;
-pub use ports::{CreatePolicyPort, PolicyValidationError, PolicyValidator, ValidationResult};
+pub use ports::{
+    CreatePolicyEventPort, CreatePolicyPort, PolicyValidationError, PolicyValidator,
+    ValidationResult,
+};
 pub use use_case::CreatePolicyUseCase;
 pub use validator::CedarPolicyValidator;
 // ---------------------------------------------------------------------------
@@ -15,7 +15,7 @@
 //! This ensures that implementations and consumers of this port are not forced to depend
 //! on operations they don't need.
 
-use crate::features::create_policy::dto::CreatePolicyCommand;
+use crate::features::create_policy::dto::{CreatePolicyCommand, PolicyStored};
 use crate::features::create_policy::error::CreatePolicyError;
 use async_trait::async_trait;
 // use hodei_policies::features::validate_policy::ValidatePolicyPort; // Temporarily disabled - unused
@@ -125,6 +125,15 @@ pub trait CreatePolicyPort: Send + Sync {
     async fn create(&self, command: CreatePolicyCommand) -> Result<HodeiPolicy, CreatePolicyError>;
 }
 
+/// Port for announcing created policies
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the change.
+#[async_trait]
+pub trait CreatePolicyEventPort: Send + Sync {
+    async fn publish(&self, event: PolicyStored);
+}
+
 /// Port for the CreatePolicy use case
 ///
 /// This trait represents the public interface of the CreatePolicy use case.
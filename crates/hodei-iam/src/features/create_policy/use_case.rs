@@ -8,29 +8,32 @@
 //!
 //! 1. Receive `CreatePolicyCommand` from the caller
 //! 2. Validate policy content through `PolicyValidator` port
-//! 3. If valid, persist through `CreatePolicyPort`
-//! 4. Return `PolicyView` DTO with created policy details
+//! 3. If valid, persist through `CreatePolicyPort`, in Cedar syntax
+//! 4. Publish a `PolicyStored` event for the created policy
+//! 5. Return `PolicyView` DTO with created policy details
 //!
 //! # Dependencies
 //!
 //! - `PolicyValidator`: Abstract port for Cedar policy validation
 //! - `CreatePolicyPort`: Abstract port for policy persistence (ISP - only create)
 
-use crate::features::create_policy::dto::{CreatePolicyCommand, PolicyView};
+use crate::features::create_policy::dto::{CreatePolicyCommand, PolicyStored, PolicyView};
 use crate::features::create_policy::error::CreatePolicyError;
 use crate::features::create_policy::ports::{
-    CreatePolicyPort, CreatePolicyUseCasePort, PolicyValidator,
+    CreatePolicyEventPort, CreatePolicyPort, CreatePolicyUseCasePort, PolicyValidator,
 };
 use async_trait::async_trait;
+use hodei_policies::features::validate_policy::to_cedar_syntax;
 use std::sync::Arc;
-use tracing::{info, instrument, warn};
+use tracing::{Instrument, info, info_span, instrument, warn};
 
 /// Use case for creating IAM policies
 ///
 /// This use case orchestrates the policy creation process:
 /// 1. Validates the Cedar policy syntax and semantics
 /// 2. Persists the policy if validation succeeds
-/// 3. Returns a view of the created policy
+/// 3. Publishes a [`PolicyStored`] event for it
+/// 4. Returns a view of the created policy
 ///
 /// # Architecture Note
 ///
@@ -42,6 +45,9 @@ pub struct CreatePolicyUseCase {
 
     /// Port for validating Cedar policy content
     validator: Arc<dyn PolicyValidator>,
+
+    /// Port announcing created policies
+    events: Option<Arc<dyn CreatePolicyEventPort>>,
 }
 
 impl CreatePolicyUseCase {
@@ -58,9 +64,16 @@ impl CreatePolicyUseCase {
         Self {
             policy_port,
             validator,
+            events: None,
         }
     }
 
+    /// Publish an event for every created policy
+    pub fn with_events(mut self, events: Arc<dyn CreatePolicyEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Execute the create policy use case (internal implementation)
     ///
     /// # Arguments
@@ -77,7 +90,10 @@ impl CreatePolicyUseCase {
     /// - `CreatePolicyError::InvalidPolicyContent` - Policy fails Cedar validation
     /// - `CreatePolicyError::PolicyAlreadyExists` - Policy ID already in use
     /// - `CreatePolicyError::RepositoryError` - Database or storage failure
-    #[instrument(skip(self, command), fields(policy_id = %command.policy_id))]
+    #[instrument(name = "create_policy", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        policy_id = %command.policy_id
+    ))]
     async fn execute_impl(
         &self,
         command: CreatePolicyCommand,
@@ -111,6 +127,7 @@ impl CreatePolicyUseCase {
         let validation_result = self
             .validator
             .validate(validation_command)
+            .instrument(info_span!("validation"))
             .await
             .map_err(|e| CreatePolicyError::ValidationFailed(e.to_string()))?;
 
@@ -122,13 +139,30 @@ impl CreatePolicyUseCase {
             let error_messages = validation_result.errors.join(", ");
             return Err(CreatePolicyError::InvalidPolicyContent(error_messages));
         }
+        let annotations = validation_result.annotations;
+
+        // Cedar JSON policies are stored in Cedar syntax, like any other
+        command.policy_content = to_cedar_syntax(&command.policy_content)
+            .map_err(|e| CreatePolicyError::InvalidPolicyContent(e.to_string()))?;
 
         info!("Policy validation successful, persisting policy");
 
         // Create the policy through the port
-        let policy = self.policy_port.create(command.clone()).await?;
+        let policy = self
+            .policy_port
+            .create(command.clone())
+            .instrument(info_span!("persistence"))
+            .await?;
 
         info!("Policy created successfully: {}", policy.id());
+        if let Some(events) = &self.events {
+            events
+                .publish(PolicyStored {
+                    policy_id: policy.id().to_string(),
+                    content: policy.content().to_string(),
+                })
+                .await;
+        }
 
         // Convert to view DTO
         let now = chrono::Utc::now();
@@ -146,6 +180,7 @@ impl CreatePolicyUseCase {
             id: policy_hrn,
             content: policy.content().to_string(),
             description: command.description.clone(),
+            annotations,
             created_at: now,
             updated_at: now,
         };
@@ -183,6 +218,25 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_create_policy_surfaces_annotations() {
+        let policy_port = Arc::new(MockCreatePolicyPort::new());
+        let validator = Arc::new(MockPolicyValidator::new());
+        let use_case = CreatePolicyUseCase::new(policy_port, validator);
+
+        let content = r#"@ticket("SEC-1234")
+permit(principal, action, resource);"#;
+        let command = CreatePolicyCommand {
+            policy_id: "test-policy".to_string(),
+            policy_content: content.to_string(),
+            description: None,
+        };
+
+        let view = use_case.execute(command).await.unwrap();
+        assert_eq!(view.content, to_cedar_syntax(content).unwrap());
+        assert_eq!(view.annotations["ticket"], "SEC-1234");
+    }
+
     #[tokio::test]
     async fn test_create_policy_empty_content() {
         let policy_port = Arc::new(MockCreatePolicyPort::new());
@@ -4,12 +4,13 @@
 //! They use mocked dependencies to isolate the use case logic.
 
 use crate::features::create_policy::{
-    dto::CreatePolicyCommand,
+    dto::{CreatePolicyCommand, PolicyStored},
     error::CreatePolicyError,
-    mocks::{MockCreatePolicyPort, MockPolicyValidator},
+    mocks::{MockCreatePolicyEventPort, MockCreatePolicyPort, MockPolicyValidator},
     ports::CreatePolicyUseCasePort,
     use_case::CreatePolicyUseCase,
 };
+use hodei_policies::features::validate_policy::{PolicySyntax, parse_policy, to_cedar_syntax};
 use std::sync::Arc;
 
 /// Test that a policy can be created successfully with valid input
@@ -33,7 +34,10 @@ async fn test_create_policy_success() {
     // Assert
     assert!(result.is_ok());
     let view = result.unwrap();
-    assert_eq!(view.content, r#"permit(principal, action, resource);"#);
+    assert_eq!(
+        view.content,
+        to_cedar_syntax("permit(principal, action, resource);").unwrap()
+    );
     assert_eq!(view.description, Some("Test policy description".to_string()));
     assert!(view.id.to_string().contains("TestPolicy"));
 }
@@ -163,7 +167,10 @@ async fn test_create_policy_minimal_fields() {
     // Assert
     assert!(result.is_ok());
     let view = result.unwrap();
-    assert_eq!(view.content, r#"permit(principal, action, resource);"#);
+    assert_eq!(
+        view.content,
+        to_cedar_syntax("permit(principal, action, resource);").unwrap()
+    );
     assert_eq!(view.description, None);
     assert!(view.id.to_string().contains("MinimalPolicy"));
 }
@@ -215,3 +222,54 @@ async fn test_policy_id_validation() {
         assert!(result.is_err());
     }
 }
+
+/// Test that a Cedar JSON policy is stored in Cedar syntax
+#[tokio::test]
+async fn test_create_policy_stores_json_policy_in_cedar_syntax() {
+    let mock_port = Arc::new(MockCreatePolicyPort::new());
+    let mock_validator = Arc::new(MockPolicyValidator::new());
+    let use_case = CreatePolicyUseCase::new(mock_port, mock_validator);
+
+    let cmd = CreatePolicyCommand {
+        policy_id: "JsonPolicy".to_string(),
+        policy_content: r#"{"effect":"permit","principal":{"op":"All"},"action":{"op":"All"},"resource":{"op":"All"},"conditions":[]}"#.to_string(),
+        description: None,
+    };
+
+    let view = use_case.execute(cmd).await.unwrap();
+
+    assert!(view.content.starts_with("permit("));
+    let (_, syntax) = parse_policy(&view.content).unwrap();
+    assert_eq!(syntax, PolicySyntax::Cedar);
+}
+
+/// Test that a created policy is published, and a rejected one is not
+#[tokio::test]
+async fn test_create_policy_publishes_stored_policy() {
+    let events = Arc::new(MockCreatePolicyEventPort::default());
+    let use_case = CreatePolicyUseCase::new(
+        Arc::new(MockCreatePolicyPort::with_existing_policies(vec![
+            "existing".to_string(),
+        ])),
+        Arc::new(MockPolicyValidator::new()),
+    )
+    .with_events(events.clone());
+
+    for policy_id in [" allow-all ", "existing"] {
+        let _ = use_case
+            .execute(CreatePolicyCommand {
+                policy_id: policy_id.to_string(),
+                policy_content: "permit(principal, action, resource);".to_string(),
+                description: None,
+            })
+            .await;
+    }
+
+    assert_eq!(
+        events.events(),
+        vec![PolicyStored {
+            policy_id: "allow-all".to_string(),
+            content: to_cedar_syntax("permit(principal, action, resource);").unwrap(),
+        }]
+    );
+}
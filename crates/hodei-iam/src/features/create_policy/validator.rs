@@ -11,6 +11,8 @@ use hodei_policies::features::validate_policy::dto::{
     ValidatePolicyCommand, ValidationResult as PoliciesValidationResult,
 };
 use hodei_policies::features::validate_policy::error::ValidatePolicyError;
+use hodei_policies::features::validate_policy::policy_annotations;
+use std::collections::HashMap;
 
 /// Cedar-based policy validator
 ///
@@ -39,21 +41,28 @@ impl PolicyValidator for CedarPolicyValidator {
     ) -> Result<PoliciesValidationResult, ValidatePolicyError> {
         debug!("Validating policy syntax");
 
-        // For now, do basic syntax validation since we don't have the hodei-policies API yet
-        // In a proper implementation, we would depend on hodei_policies for validation
         if command.content.trim().is_empty() {
             return Ok(PoliciesValidationResult {
                 is_valid: false,
                 errors: vec!["Policy content cannot be empty".to_string()],
+                annotations: HashMap::new(),
             });
         }
 
-        // For now, assume valid if not empty - in a real implementation,
-        // we would call into the hodei-policies crate
-        Ok(PoliciesValidationResult {
-            is_valid: true,
-            errors: vec![],
-        })
+        // Parsing the policy is what extracts its annotations; Cedar rejects
+        // malformed policies and repeated annotation keys alike
+        match policy_annotations(&command.content) {
+            Ok(annotations) => Ok(PoliciesValidationResult {
+                is_valid: true,
+                errors: vec![],
+                annotations,
+            }),
+            Err(e) => Ok(PoliciesValidationResult {
+                is_valid: false,
+                errors: vec![e.to_string()],
+                annotations: HashMap::new(),
+            }),
+        }
     }
 }
 
@@ -84,10 +93,49 @@ mod tests {
 
         let result = validator.validate(command).await.unwrap();
 
-        // Current implementation treats non-empty content as valid
-        // until proper Cedar validation is implemented
+        assert!(!result.is_valid);
+        assert!(!result.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_valid_policy_keeps_annotations() {
+        let validator = CedarPolicyValidator::new();
+        let command = ValidatePolicyCommand {
+            content: r#"@ticket("SEC-1234") permit(principal, action, resource);"#.to_string(),
+        };
+
+        let result = validator.validate(command).await.unwrap();
+
         assert!(result.is_valid);
-        assert!(result.errors.is_empty());
+        assert_eq!(result.annotations["ticket"], "SEC-1234");
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_annotation_is_invalid() {
+        let validator = CedarPolicyValidator::new();
+        let command = ValidatePolicyCommand {
+            content: r#"@ticket("A") @ticket("B") permit(principal, action, resource);"#
+                .to_string(),
+        };
+
+        let result = validator.validate(command).await.unwrap();
+
+        assert!(!result.is_valid);
+        assert!(result.annotations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_json_policy_is_valid() {
+        let validator = CedarPolicyValidator::new();
+        let command = ValidatePolicyCommand {
+            content: r#"{"effect":"forbid","principal":{"op":"All"},"action":{"op":"All"},"resource":{"op":"All"},"conditions":[],"annotations":{"ticket":"SEC-1"}}"#
+                .to_string(),
+        };
+
+        let result = validator.validate(command).await.unwrap();
+
+        assert!(result.is_valid);
+        assert_eq!(result.annotations["ticket"], "SEC-1");
     }
 
     #[tokio::test]
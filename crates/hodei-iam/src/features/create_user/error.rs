@@ -6,6 +6,12 @@ pub enum CreateUserError {
     #[error("Failed to save user: {0}")]
     PersistenceError(String),
     
+    #[error("Invalid email address: {0}")]
+    InvalidEmail(String),
+    
+    #[error("Email address is already used by user {0}")]
+    EmailAlreadyExists(String),
+    
     #[error("Invalid command data: {0}")]
     InvalidCommand(String),
     
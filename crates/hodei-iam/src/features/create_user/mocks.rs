@@ -16,6 +16,8 @@ pub struct MockCreateUserPort {
     pub should_fail: bool,
     /// The user that was saved (for inspection in tests)
     pub saved_user_dto: Option<UserPersistenceDto>,
+    /// Users already stored, returned by `find_by_email`
+    pub existing_users: Vec<UserPersistenceDto>,
 }
 
 #[async_trait]
@@ -34,6 +36,22 @@ impl CreateUserPort for MockCreateUserPort {
             Ok(())
         }
     }
+
+    async fn find_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<UserPersistenceDto>, super::error::CreateUserError> {
+        if self.should_fail {
+            return Err(super::error::CreateUserError::PersistenceError(
+                "Mock failure".to_string(),
+            ));
+        }
+        Ok(self
+            .existing_users
+            .iter()
+            .find(|user| user.email == email)
+            .cloned())
+    }
 }
 
 #[allow(dead_code)]
@@ -43,6 +61,7 @@ impl MockCreateUserPort {
         Self {
             should_fail: false,
             saved_user_dto: None,
+            existing_users: Vec::new(),
         }
     }
 
@@ -51,8 +70,15 @@ impl MockCreateUserPort {
         Self {
             should_fail: true,
             saved_user_dto: None,
+            existing_users: Vec::new(),
         }
     }
+
+    /// Add a user that is already stored
+    pub fn with_existing_user(mut self, user: UserPersistenceDto) -> Self {
+        self.existing_users.push(user);
+        self
+    }
 }
 
 /// Mock implementation of HrnGenerator for testing
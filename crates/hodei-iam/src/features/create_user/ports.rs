@@ -19,6 +19,23 @@ pub trait CreateUserPort: Send + Sync {
     /// * `Ok(())` if the user was saved successfully
     /// * `Err(CreateUserError)` if there was an error saving the user
     async fn save_user(&self, user_dto: &UserPersistenceDto) -> Result<(), CreateUserError>;
+
+    /// Find the user registered with an email address
+    ///
+    /// The use case passes the address already normalized (domain lowercased)
+    /// and stores it in that form, so an exact match is enough.
+    ///
+    /// # Arguments
+    /// * `email` - The normalized email address to look up
+    ///
+    /// # Returns
+    /// * `Ok(Some(UserPersistenceDto))` if a user has that email
+    /// * `Ok(None)` if no user has it
+    /// * `Err(CreateUserError)` if the lookup failed
+    async fn find_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<UserPersistenceDto>, CreateUserError>;
 }
 
 /// Port for the CreateUser use case
@@ -2,17 +2,26 @@ use super::dto::{CreateUserCommand, UserPersistenceDto, UserView};
 use super::error::CreateUserError;
 use super::ports::{CreateUserPort, CreateUserUseCasePort};
 use crate::internal::domain::User;
+use crate::internal::domain::email::{normalize_email, validate_email};
 use async_trait::async_trait;
-use kernel::HrnGenerator;
+use kernel::{HrnGenerator, Redacted};
 use std::sync::Arc;
+use tracing::{Instrument, info_span, instrument};
 
 /// Use case for creating a new user
 ///
 /// This use case orchestrates the user creation process:
-/// 1. Generates a new HRN for the user
-/// 2. Creates a User entity
-/// 3. Persists the user through the port
-/// 4. Returns a UserView DTO
+/// 1. Validates the email address
+/// 2. Rejects the email if another user already has it
+/// 3. Generates a new HRN for the user
+/// 4. Creates a User entity
+/// 5. Persists the user through the port
+/// 6. Returns a UserView DTO
+///
+/// Emails are compared and stored with the domain lowercased. The lookup in
+/// step 2 is not race-free on its own: two concurrent requests can both pass
+/// it, so persistence adapters must also enforce uniqueness (the SurrealDB
+/// adapter defines a unique index on `email`).
 pub struct CreateUserUseCase {
     persister: Arc<dyn CreateUserPort>,
     hrn_generator: Arc<dyn HrnGenerator>,
@@ -38,13 +47,33 @@ impl CreateUserUseCase {
     ///
     /// # Returns
     /// * Ok(UserView) if the user was created successfully
-    /// * Err(CreateUserError) if there was an error
+    /// * Err(CreateUserError::InvalidEmail) if the email address is malformed
+    /// * Err(CreateUserError::EmailAlreadyExists) if another user has the email
+    /// * Err(CreateUserError) if there was any other error
+    #[instrument(name = "create_user", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        user_name = %cmd.name,
+        email = %Redacted(&cmd.email)
+    ))]
     pub async fn execute(&self, cmd: CreateUserCommand) -> Result<UserView, CreateUserError> {
+        // Reject malformed addresses before anything is generated or stored
+        let email = async {
+            validate_email(&cmd.email).map_err(|e| CreateUserError::InvalidEmail(e.to_string()))?;
+            let email = normalize_email(&cmd.email);
+
+            if let Some(existing) = self.persister.find_by_email(&email).await? {
+                return Err(CreateUserError::EmailAlreadyExists(existing.hrn));
+            }
+            Ok(email)
+        }
+        .instrument(info_span!("validation"))
+        .await?;
+
         // Generate a unique HRN using the HRN generator
         let hrn = self.hrn_generator.new_user_hrn(&cmd.name);
 
         // Create the user domain entity
-        let mut user = User::new(hrn.clone(), cmd.name, cmd.email);
+        let mut user = User::new(hrn.clone(), cmd.name, email);
         user.tags = cmd.tags;
 
         // Convert to DTO and persist the user
@@ -55,7 +84,10 @@ impl CreateUserUseCase {
             group_hrns: user.group_hrns.iter().map(|hrn| hrn.to_string()).collect(),
             tags: user.tags.clone(),
         };
-        self.persister.save_user(&user_dto).await?;
+        self.persister
+            .save_user(&user_dto)
+            .instrument(info_span!("persistence", user = %hrn))
+            .await?;
 
         // Return the view
         Ok(UserView {
@@ -4,7 +4,7 @@
 //! They use mocked dependencies to isolate the use case logic.
 
 use crate::features::create_user::{
-    dto::CreateUserCommand,
+    dto::{CreateUserCommand, UserPersistenceDto},
     error::CreateUserError,
     mocks::{MockCreateUserPort, MockHrnGenerator},   
     use_case::CreateUserUseCase,
@@ -109,9 +109,87 @@ async fn test_create_user_empty_name() {
     assert_eq!(view.tags, vec!["admin".to_string()]);
 }
 
-/// Test that user creation works with invalid email (no validation in current implementation)
+/// Test that user creation is rejected with a malformed email before persisting
 #[tokio::test]
 async fn test_create_user_invalid_email() {
+    // Setup - a failing port would surface as PersistenceError if it were reached
+    let mock_port = Arc::new(MockCreateUserPort::failing());
+    let mock_hrn_generator = Arc::new(MockHrnGenerator::new(Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        "User".to_string(),
+        "test-user-123".to_string(),
+    )));
+
+    let use_case = CreateUserUseCase::new(mock_port, mock_hrn_generator);
+
+    for email in ["invalid-email", "alice@", "@example.com", "alice@localhost"] {
+        // Execute
+        let cmd = CreateUserCommand {
+            name: "John Doe".to_string(),
+            email: email.to_string(),
+            tags: vec!["admin".to_string()],
+        };
+
+        let result = use_case.execute(cmd).await;
+
+        // Assert
+        match result {
+            Err(CreateUserError::InvalidEmail(reason)) => assert!(!reason.is_empty()),
+            other => panic!("Expected InvalidEmail for '{}', got {:?}", email, other),
+        }
+    }
+}
+
+/// Test that an email already in use is rejected with the HRN of its owner
+#[tokio::test]
+async fn test_create_user_duplicate_email() {
+    // Setup
+    let existing_hrn = "hrn:hodei:iam::default:User/existing-user";
+    let mock_port = Arc::new(MockCreateUserPort::new().with_existing_user(
+        UserPersistenceDto::new(existing_hrn, "Existing", "john.doe@example.com"),
+    ));
+    let mock_hrn_generator = Arc::new(MockHrnGenerator::new(Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        "User".to_string(),
+        "test-user-123".to_string(),
+    )));
+
+    let use_case = CreateUserUseCase::new(mock_port, mock_hrn_generator);
+
+    // The domain part is compared case-insensitively
+    for email in ["john.doe@example.com", "john.doe@EXAMPLE.com"] {
+        // Execute
+        let cmd = CreateUserCommand {
+            name: "John Doe".to_string(),
+            email: email.to_string(),
+            tags: vec![],
+        };
+
+        let result = use_case.execute(cmd).await;
+
+        // Assert
+        match result {
+            Err(CreateUserError::EmailAlreadyExists(hrn)) => assert_eq!(hrn, existing_hrn),
+            other => panic!("Expected EmailAlreadyExists for '{}', got {:?}", email, other),
+        }
+    }
+
+    // The local part is case-sensitive, so this is a different address
+    let cmd = CreateUserCommand {
+        name: "John Doe".to_string(),
+        email: "John.Doe@example.com".to_string(),
+        tags: vec![],
+    };
+    assert!(use_case.execute(cmd).await.is_ok());
+}
+
+/// Test that the email is returned with its domain lowercased
+#[tokio::test]
+async fn test_create_user_normalizes_email_domain() {
     // Setup
     let mock_port = Arc::new(MockCreateUserPort::new());
     let mock_hrn_generator = Arc::new(MockHrnGenerator::new(Hrn::new(
@@ -127,18 +205,14 @@ async fn test_create_user_invalid_email() {
     // Execute
     let cmd = CreateUserCommand {
         name: "John Doe".to_string(),
-        email: "invalid-email".to_string(),
-        tags: vec!["admin".to_string()],
+        email: "John.Doe@Example.COM".to_string(),
+        tags: vec![],
     };
 
-    let result = use_case.execute(cmd).await;
+    let view = use_case.execute(cmd).await.unwrap();
 
-    // Assert - current implementation doesn't validate email format
-    assert!(result.is_ok());
-    let view = result.unwrap();
-    assert_eq!(view.name, "John Doe");
-    assert_eq!(view.email, "invalid-email");
-    assert_eq!(view.tags, vec!["admin".to_string()]);
+    // Assert
+    assert_eq!(view.email, "John.Doe@example.com");
 }
 
 /// Test that user creation works with minimal required fields
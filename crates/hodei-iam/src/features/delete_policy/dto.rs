@@ -8,6 +8,10 @@ use serde::{Deserialize, Serialize};
 use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
 
+/// Event published for the deleted policy, shared with the contexts that
+/// react to policy changes
+pub use kernel::PolicyDeleted;
+
 /// Command to delete an existing IAM policy
 ///
 /// This command contains the information needed to identify and delete a policy.
@@ -4,8 +4,9 @@
 //! DeletePolicyUseCase, allowing for isolated unit testing without
 //! requiring real infrastructure (databases, etc.)
 
+use crate::features::delete_policy::dto::PolicyDeleted;
 use crate::features::delete_policy::error::DeletePolicyError;
-use crate::features::delete_policy::ports::DeletePolicyPort;
+use crate::features::delete_policy::ports::{DeletePolicyEventPort, DeletePolicyPort};
 use async_trait::async_trait;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
@@ -246,6 +247,25 @@ impl DeletePolicyPort for MockDeletePolicyPort {
     }
 }
 
+/// Mock DeletePolicyEventPort recording every event
+#[derive(Default)]
+pub struct MockDeletePolicyEventPort {
+    events: Mutex<Vec<PolicyDeleted>>,
+}
+
+impl MockDeletePolicyEventPort {
+    pub fn events(&self) -> Vec<PolicyDeleted> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl DeletePolicyEventPort for MockDeletePolicyEventPort {
+    async fn publish(&self, event: PolicyDeleted) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,7 +30,7 @@ mod use_case_test;
 /// Public API for the delete_policy feature
 pub use dto::DeletePolicyCommand;
 pub use error::DeletePolicyError;
-pub use ports::DeletePolicyPort;
+pub use ports::{DeletePolicyEventPort, DeletePolicyPort};
 pub use use_case::DeletePolicyUseCase;
 
 // ---------------------------------------------------------------------------
@@ -18,6 +18,7 @@
 //! This is part of the refactored segregated architecture where each CRUD operation
 //! has its own dedicated port instead of a monolithic "PolicyRepository" trait.
 
+use crate::features::delete_policy::dto::PolicyDeleted;
 use crate::features::delete_policy::error::DeletePolicyError;
 use async_trait::async_trait;
 
@@ -110,6 +111,15 @@ pub trait DeletePolicyPort: Send + Sync {
     async fn delete(&self, policy_id: &str) -> Result<(), DeletePolicyError>;
 }
 
+/// Port for announcing deleted policies
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the change.
+#[async_trait]
+pub trait DeletePolicyEventPort: Send + Sync {
+    async fn publish(&self, event: PolicyDeleted);
+}
+
 /// Port for the DeletePolicy use case
 ///
 /// This port defines the contract for executing the delete policy use case.
@@ -10,26 +10,30 @@
 //! 2. Validate policy ID (not empty)
 //! 3. Optionally check if policy is in use (future enhancement)
 //! 4. Delete the policy through `DeletePolicyPort`
-//! 5. Return success or appropriate error
+//! 5. Publish a `PolicyDeleted` event
+//! 6. Return success or appropriate error
 //!
 //! # Dependencies
 //!
 //! - `DeletePolicyPort`: Abstract port for policy deletion (ISP - only delete)
 //! - `DeletePolicyUseCasePort`: Port for executing the use case
 
-use crate::features::delete_policy::dto::DeletePolicyCommand;
+use crate::features::delete_policy::dto::{DeletePolicyCommand, PolicyDeleted};
 use crate::features::delete_policy::error::DeletePolicyError;
-use crate::features::delete_policy::ports::{DeletePolicyPort, DeletePolicyUseCasePort};
+use crate::features::delete_policy::ports::{
+    DeletePolicyEventPort, DeletePolicyPort, DeletePolicyUseCasePort,
+};
 use async_trait::async_trait;
 use std::sync::Arc;
-use tracing::{info, instrument, warn};
+use tracing::{Instrument, info, info_span, instrument, warn};
 
 /// Use case for deleting IAM policies
 ///
 /// This use case orchestrates the policy deletion process:
 /// 1. Validates the policy ID
 /// 2. Deletes the policy through the port
-/// 3. Returns success or appropriate error
+/// 3. Publishes a [`PolicyDeleted`] event
+/// 4. Returns success or appropriate error
 ///
 /// # Example
 ///
@@ -52,6 +56,9 @@ use tracing::{info, instrument, warn};
 pub struct DeletePolicyUseCase {
     /// Port for deleting policies (only delete operation)
     policy_port: Arc<dyn DeletePolicyPort>,
+
+    /// Port announcing deleted policies
+    events: Option<Arc<dyn DeletePolicyEventPort>>,
 }
 
 impl DeletePolicyUseCase {
@@ -67,7 +74,16 @@ impl DeletePolicyUseCase {
     /// let use_case = DeletePolicyUseCase::new(Arc::new(policy_port));
     /// ```
     pub fn new(policy_port: Arc<dyn DeletePolicyPort>) -> Self {
-        Self { policy_port }
+        Self {
+            policy_port,
+            events: None,
+        }
+    }
+
+    /// Publish an event for every deleted policy
+    pub fn with_events(mut self, events: Arc<dyn DeletePolicyEventPort>) -> Self {
+        self.events = Some(events);
+        self
     }
 
     /// Execute the delete policy use case
@@ -100,7 +116,10 @@ impl DeletePolicyUseCase {
     /// use_case.execute(command).await?;
     /// println!("Policy deleted");
     /// ```
-    #[instrument(skip(self, command), fields(policy_id = %command.policy_id))]
+    #[instrument(name = "delete_policy", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        policy_id = %command.policy_id
+    ))]
     pub async fn execute(&self, command: DeletePolicyCommand) -> Result<(), DeletePolicyError> {
         let mut command = command;
 
@@ -133,6 +152,7 @@ impl DeletePolicyUseCase {
         info!("Deleting policy from storage");
         self.policy_port
             .delete(&command.policy_id)
+            .instrument(info_span!("persistence"))
             .await
             .map_err(|e| {
                 warn!("Policy deletion failed: {}", e);
@@ -140,6 +160,13 @@ impl DeletePolicyUseCase {
             })?;
 
         info!("Policy deleted successfully: {}", command.policy_id);
+        if let Some(events) = &self.events {
+            events
+                .publish(PolicyDeleted {
+                    policy_id: command.policy_id,
+                })
+                .await;
+        }
         Ok(())
     }
 }
@@ -4,7 +4,9 @@
 //! They use mocked dependencies to isolate the use case logic.
 
 use crate::features::delete_policy::{
-    dto::DeletePolicyCommand, error::DeletePolicyError, mocks::MockDeletePolicyPort,
+    dto::{DeletePolicyCommand, PolicyDeleted},
+    error::DeletePolicyError,
+    mocks::{MockDeletePolicyEventPort, MockDeletePolicyPort},
     use_case::DeletePolicyUseCase,
 };
 use std::sync::Arc;
@@ -208,3 +210,27 @@ async fn test_delete_policy_in_use() {
         _ => panic!("Expected PolicyInUse"),
     }
 }
+
+/// Test that a deleted policy is published, and a failed deletion is not
+#[tokio::test]
+async fn test_delete_policy_publishes_deleted_policy() {
+    let mock_port = Arc::new(MockDeletePolicyPort::new());
+    mock_port.add_policy("test-policy".to_string());
+    let events = Arc::new(MockDeletePolicyEventPort::default());
+    let use_case = DeletePolicyUseCase::new(mock_port).with_events(events.clone());
+
+    for policy_id in [" test-policy ", "missing-policy"] {
+        let _ = use_case
+            .execute(DeletePolicyCommand {
+                policy_id: policy_id.to_string(),
+            })
+            .await;
+    }
+
+    assert_eq!(
+        events.events(),
+        vec![PolicyDeleted {
+            policy_id: "test-policy".to_string(),
+        }]
+    );
+}
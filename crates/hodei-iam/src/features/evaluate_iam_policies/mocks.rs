@@ -5,8 +5,8 @@
 //! requiring real infrastructure.
 
 use async_trait::async_trait;
-use kernel::Hrn;
 use kernel::domain::policy::HodeiPolicySet;
+use kernel::{Hrn, PrincipalStatus};
 
 use super::ports::{PolicyFinderError, PolicyFinderPort};
 
@@ -35,6 +35,8 @@ pub struct MockPolicyFinder {
     policy_set: Option<HodeiPolicySet>,
     /// Error to return (if set)
     error: Option<String>,
+    /// Status reported for every principal
+    principal_status: PrincipalStatus,
 }
 
 impl MockPolicyFinder {
@@ -47,6 +49,7 @@ impl MockPolicyFinder {
         Self {
             policy_set: Some(policy_set),
             error: None,
+            principal_status: PrincipalStatus::Active,
         }
     }
 
@@ -59,6 +62,7 @@ impl MockPolicyFinder {
         Self {
             policy_set: None,
             error: Some(error),
+            principal_status: PrincipalStatus::Active,
         }
     }
 
@@ -66,6 +70,12 @@ impl MockPolicyFinder {
     pub fn empty() -> Self {
         Self::new(HodeiPolicySet::default())
     }
+
+    /// Report every principal with the given lifecycle status
+    pub fn with_principal_status(mut self, status: PrincipalStatus) -> Self {
+        self.principal_status = status;
+        self
+    }
 }
 
 #[async_trait]
@@ -80,6 +90,17 @@ impl PolicyFinderPort for MockPolicyFinder {
 
         Ok(self.policy_set.clone().unwrap_or_default())
     }
+
+    async fn principal_status(
+        &self,
+        _principal_hrn: &Hrn,
+    ) -> Result<PrincipalStatus, PolicyFinderError> {
+        if let Some(error_msg) = &self.error {
+            return Err(PolicyFinderError::RepositoryError(error_msg.clone()));
+        }
+
+        Ok(self.principal_status)
+    }
 }
 
 #[cfg(test)]
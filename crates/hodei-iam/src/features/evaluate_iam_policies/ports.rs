@@ -6,7 +6,7 @@
 
 use async_trait::async_trait;
 use kernel::domain::HodeiPolicySet;
-use kernel::{HodeiEntity, Hrn};
+use kernel::{HodeiEntity, Hrn, PrincipalStatus};
 
 /// Port for finding and retrieving IAM policies
 ///
@@ -54,6 +54,22 @@ pub trait PolicyFinderPort: Send + Sync {
         &self,
         principal_hrn: &Hrn,
     ) -> Result<HodeiPolicySet, PolicyFinderError>;
+
+    /// Get the lifecycle status of a principal
+    ///
+    /// A principal that is not active is denied without evaluating policies.
+    /// Finders that do not track status report every principal as active.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyFinderError` on the same conditions as
+    /// [`get_effective_policies`](Self::get_effective_policies).
+    async fn principal_status(
+        &self,
+        _principal_hrn: &Hrn,
+    ) -> Result<PrincipalStatus, PolicyFinderError> {
+        Ok(PrincipalStatus::Active)
+    }
 }
 
 /// Errors that can occur during policy retrieval
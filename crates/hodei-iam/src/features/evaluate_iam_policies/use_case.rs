@@ -21,13 +21,15 @@
 //! This ensures zero coupling to Cedar and respects bounded context boundaries.
 
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{debug, info, instrument, warn};
+use tracing::{Instrument, debug, info, info_span, instrument, warn};
 
 use kernel::application::ports::authorization::{
     AuthorizationError, EvaluationDecision as KernelEvaluationDecision,
     EvaluationRequest as KernelEvaluationRequest, IamPolicyEvaluator,
 };
+use kernel::{PrincipalStatus, TenantContext};
 
 use super::ports::{
     EntityResolverError, PolicyFinderError, PolicyFinderPort, PrincipalResolverPort,
@@ -92,13 +94,30 @@ impl EvaluateIamPoliciesUseCase {
             policies_evaluator: EvaluatePoliciesUseCase::new(schema_storage),
         }
     }
+
+    /// Evaluate IAM policies for a request made on behalf of `tenant`
+    ///
+    /// The principal, the resource and every resource ancestor must belong to
+    /// the tenant; otherwise the evaluation fails with `CrossTenantAccess`
+    /// instead of producing a decision.
+    pub async fn evaluate_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        request: KernelEvaluationRequest,
+    ) -> Result<KernelEvaluationDecision, AuthorizationError> {
+        tenant
+            .run(request, |request| self.evaluate_iam_policies(request))
+            .await
+    }
 }
 
 #[async_trait]
 impl IamPolicyEvaluator for EvaluateIamPoliciesUseCase {
     #[instrument(
-        skip(self, request),
+        name = "evaluate_iam_policies",
+        skip_all,
         fields(
+            correlation_id = kernel::current_correlation_id().as_deref(),
             principal_hrn = %request.principal_hrn,
             action = %request.action_name,
             resource_hrn = %request.resource_hrn
@@ -110,11 +129,36 @@ impl IamPolicyEvaluator for EvaluateIamPoliciesUseCase {
     ) -> Result<KernelEvaluationDecision, AuthorizationError> {
         info!("Starting IAM policy evaluation");
 
+        // A suspended or deactivated principal is denied before any policy
+        // is looked at; the status tells callers it was not a policy denial
+        let principal_status = self
+            .policy_finder
+            .principal_status(&request.principal_hrn)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to retrieve principal status");
+                Self::map_policy_finder_error(e)
+            })?;
+        if !principal_status.is_active() {
+            warn!(status = %principal_status, "Principal is not active, denying");
+            return Ok(KernelEvaluationDecision {
+                principal_hrn: request.principal_hrn.clone(),
+                action_name: request.action_name.clone(),
+                resource_hrn: request.resource_hrn.clone(),
+                decision: false,
+                reason: format!("Principal is {}", principal_status),
+                determining_policies: vec![],
+                policy_annotations: HashMap::new(),
+                principal_status,
+            });
+        }
+
         // Step 1: Retrieve effective IAM policies for the principal
         debug!("Retrieving effective policies for principal");
         let policy_set = self
             .policy_finder
             .get_effective_policies(&request.principal_hrn)
+            .instrument(info_span!("policy_fetch"))
             .await
             .map_err(|e| {
                 warn!(error = %e, "Failed to retrieve policies");
@@ -135,6 +179,9 @@ impl IamPolicyEvaluator for EvaluateIamPoliciesUseCase {
                 resource_hrn: request.resource_hrn.clone(),
                 decision: false,
                 reason: "No IAM policies found for principal (implicit deny)".to_string(),
+                determining_policies: vec![],
+                policy_annotations: HashMap::new(),
+                principal_status: PrincipalStatus::Active,
             });
         }
 
@@ -165,15 +212,19 @@ impl IamPolicyEvaluator for EvaluateIamPoliciesUseCase {
         debug!("Resource entity resolved successfully");
 
         // Step 4: Build authorization request for hodei-policies
+        let resource_with_ancestors = ResourceWithAncestors {
+            entity: resource_entity.as_ref(),
+            ancestors: &request.resource_ancestors,
+        };
         let principal_ref = principal_entity.as_ref();
-        let resource_ref = resource_entity.as_ref();
+        let resource_ref: &dyn kernel::HodeiEntity = &resource_with_ancestors;
         let entities: Vec<&dyn kernel::HodeiEntity> = vec![principal_ref, resource_ref];
 
         let auth_request = AuthorizationRequest {
             principal: principal_ref,
             action: &request.action_name,
             resource: resource_ref,
-            context: None, // TODO: Support context if needed
+            context: (!request.context.is_empty()).then(|| request.context.clone()),
         };
 
         let evaluate_command = EvaluatePoliciesCommand::new(auth_request, &policy_set, &entities);
@@ -183,6 +234,7 @@ impl IamPolicyEvaluator for EvaluateIamPoliciesUseCase {
         let evaluation_result = self
             .policies_evaluator
             .execute(evaluate_command)
+            .instrument(info_span!("evaluation"))
             .await
             .map_err(|e| {
                 warn!(error = %e, "Policy evaluation failed");
@@ -213,10 +265,47 @@ impl IamPolicyEvaluator for EvaluateIamPoliciesUseCase {
             resource_hrn: request.resource_hrn.clone(),
             decision,
             reason,
+            determining_policies: evaluation_result.determining_policies,
+            policy_annotations: evaluation_result.policy_annotations,
+            principal_status: PrincipalStatus::Active,
         })
     }
 }
 
+/// Resource entity with the ancestors supplied by the caller added as parents
+///
+/// The resource's own context only knows its direct parents; the authorizer
+/// resolves the full container chain, which Cedar needs for `resource in ...`.
+#[derive(Debug)]
+struct ResourceWithAncestors<'a> {
+    entity: &'a dyn kernel::HodeiEntity,
+    ancestors: &'a [kernel::Hrn],
+}
+
+impl kernel::HodeiEntity for ResourceWithAncestors<'_> {
+    fn hrn(&self) -> &kernel::Hrn {
+        self.entity.hrn()
+    }
+
+    fn attributes(&self) -> HashMap<kernel::AttributeName, kernel::AttributeValue> {
+        self.entity.attributes()
+    }
+
+    fn parent_hrns(&self) -> Vec<kernel::Hrn> {
+        let mut parents = self.entity.parent_hrns();
+        for ancestor in self.ancestors {
+            if !parents.contains(ancestor) {
+                parents.push(ancestor.clone());
+            }
+        }
+        parents
+    }
+
+    fn cedar_attributes(&self) -> Option<Vec<(String, kernel::AttributeType)>> {
+        self.entity.cedar_attributes()
+    }
+}
+
 impl EvaluateIamPoliciesUseCase {
     /// Map PolicyFinderError to AuthorizationError
     fn map_policy_finder_error(error: PolicyFinderError) -> AuthorizationError {
@@ -492,6 +581,8 @@ mod tests {
             principal_hrn: Hrn::from_string("hrn:hodei:iam::account123:user/alice").unwrap(),
             action_name: "Read".to_string(),
             resource_hrn: Hrn::from_string("hrn:hodei:artifact::account123:artifact/doc1").unwrap(),
+            resource_ancestors: Vec::new(),
+            context: HashMap::new(),
         };
 
         // Act
@@ -532,6 +623,8 @@ mod tests {
             principal_hrn: Hrn::from_string("hrn:hodei:iam::account123:user/alice").unwrap(),
             action_name: "Read".to_string(),
             resource_hrn: Hrn::from_string("hrn:hodei:artifact::account123:artifact/doc1").unwrap(),
+            resource_ancestors: Vec::new(),
+            context: HashMap::new(),
         };
 
         // Act
@@ -567,6 +660,8 @@ mod tests {
             principal_hrn: Hrn::from_string("hrn:hodei:iam::account123:user/alice").unwrap(),
             action_name: "Read".to_string(),
             resource_hrn: Hrn::from_string("hrn:hodei:artifact::account123:artifact/doc1").unwrap(),
+            resource_ancestors: Vec::new(),
+            context: HashMap::new(),
         };
 
         // Act
@@ -603,6 +698,8 @@ mod tests {
             principal_hrn: Hrn::from_string("hrn:hodei:iam::account123:user/alice").unwrap(),
             action_name: "Read".to_string(),
             resource_hrn: Hrn::from_string("hrn:hodei:artifact::account123:artifact/doc1").unwrap(),
+            resource_ancestors: Vec::new(),
+            context: HashMap::new(),
         };
 
         // Act
@@ -639,6 +736,8 @@ mod tests {
             principal_hrn: Hrn::from_string("hrn:hodei:iam::account123:user/alice").unwrap(),
             action_name: "Read".to_string(),
             resource_hrn: Hrn::from_string("hrn:hodei:artifact::account123:artifact/doc1").unwrap(),
+            resource_ancestors: Vec::new(),
+            context: HashMap::new(),
         };
 
         // Act
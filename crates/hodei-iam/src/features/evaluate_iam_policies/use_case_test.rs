@@ -8,10 +8,11 @@ use crate::features::evaluate_iam_policies::{
     ports::{PrincipalResolverPort, ResourceResolverPort},
     use_case::EvaluateIamPoliciesUseCase,
 };
-use kernel::IamPolicyEvaluator;
 use kernel::application::ports::authorization::{AuthorizationError, EvaluationRequest};
 use kernel::domain::policy::{HodeiPolicy, HodeiPolicySet};
 use kernel::domain::{Hrn, PolicyId};
+use kernel::{IamPolicyEvaluator, PrincipalStatus};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 // Mock implementations for testing
@@ -193,6 +194,8 @@ async fn test_evaluate_iam_policies_success() {
             "Resource".to_string(),
             "test-resource".to_string(),
         ),
+        resource_ancestors: Vec::new(),
+        context: HashMap::new(),
     };
 
     let result = use_case.evaluate_iam_policies(request).await;
@@ -236,6 +239,8 @@ async fn test_evaluate_iam_policies_finder_error() {
             "Resource".to_string(),
             "test-resource".to_string(),
         ),
+        resource_ancestors: Vec::new(),
+        context: HashMap::new(),
     };
 
     let result = use_case.evaluate_iam_policies(request).await;
@@ -281,6 +286,8 @@ async fn test_evaluate_iam_policies_empty_policy_set() {
             "Resource".to_string(),
             "test-resource".to_string(),
         ),
+        resource_ancestors: Vec::new(),
+        context: HashMap::new(),
     };
 
     let result = use_case.evaluate_iam_policies(request).await;
@@ -292,3 +299,56 @@ async fn test_evaluate_iam_policies_empty_policy_set() {
     assert!(!decision.decision);
     assert!(decision.reason.contains("No IAM policies"));
 }
+
+/// Test that a suspended principal is denied without evaluating its policies
+#[tokio::test]
+async fn test_evaluate_iam_policies_suspended_principal() {
+    // Setup - the permit policy would allow an active principal
+    let policy = HodeiPolicy::new(
+        PolicyId::new("allow-all"),
+        "permit(principal, action, resource);".to_string(),
+    );
+    let mock_policy_finder = Arc::new(
+        MockPolicyFinder::new(HodeiPolicySet::new(vec![policy]))
+            .with_principal_status(PrincipalStatus::Suspended),
+    );
+    let mock_principal_resolver = Arc::new(MockPrincipalResolver);
+    let mock_resource_resolver = Arc::new(MockResourceResolver);
+    let mock_schema_storage = Arc::new(MockSchemaStorage);
+
+    let use_case = EvaluateIamPoliciesUseCase::new(
+        mock_policy_finder,
+        mock_principal_resolver,
+        mock_resource_resolver,
+        mock_schema_storage,
+    );
+
+    // Execute
+    let request = EvaluationRequest {
+        principal_hrn: Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "default".to_string(),
+            "User".to_string(),
+            "test-user".to_string(),
+        ),
+        action_name: "read".to_string(),
+        resource_hrn: Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "default".to_string(),
+            "Resource".to_string(),
+            "test-resource".to_string(),
+        ),
+        resource_ancestors: Vec::new(),
+        context: HashMap::new(),
+    };
+
+    let decision = use_case.evaluate_iam_policies(request).await.unwrap();
+
+    // Assert
+    assert!(!decision.decision);
+    assert_eq!(decision.principal_status, PrincipalStatus::Suspended);
+    assert_eq!(decision.reason, "Principal is suspended");
+    assert!(decision.determining_policies.is_empty());
+}
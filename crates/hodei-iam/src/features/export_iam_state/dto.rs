@@ -0,0 +1,105 @@
+//! Data Transfer Objects for export_iam_state feature
+//!
+//! The records below are the IAM state document format shared with
+//! import_iam_state. HRNs are written as strings so the document is plain,
+//! diffable JSON.
+
+use chrono::{DateTime, Utc};
+use kernel::PrincipalStatus;
+use serde::{Deserialize, Serialize};
+
+/// Version of the IAM state document format written by this build
+///
+/// Bump it whenever a change to the records would make an older build
+/// misread a document; import rejects any other version.
+pub const IAM_STATE_FORMAT_VERSION: u32 = 1;
+
+/// Query to export the IAM state
+///
+/// The export always covers every user, group and policy; there are no
+/// options yet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportIamStateQuery {}
+
+/// A user and the groups it belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserRecord {
+    pub hrn: String,
+    pub name: String,
+    pub email: String,
+    #[serde(default)]
+    pub status: PrincipalStatus,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// HRNs of the groups the user is a direct member of
+    #[serde(default)]
+    pub group_hrns: Vec<String>,
+}
+
+/// A group and the groups it is nested in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupRecord {
+    pub hrn: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// HRNs of the groups this group is a direct member of
+    #[serde(default)]
+    pub parent_group_hrns: Vec<String>,
+}
+
+/// A policy and the principals it is attached to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyRecord {
+    pub id: String,
+    /// Cedar policy text
+    pub content: String,
+    /// HRNs of the users and groups the policy applies to
+    #[serde(default)]
+    pub attached_principals: Vec<String>,
+}
+
+impl UserRecord {
+    /// The record with its lists sorted, so equal states compare equal
+    pub fn normalized(mut self) -> Self {
+        self.tags.sort();
+        self.group_hrns.sort();
+        self
+    }
+}
+
+impl GroupRecord {
+    /// The record with its lists sorted, so equal states compare equal
+    pub fn normalized(mut self) -> Self {
+        self.tags.sort();
+        self.parent_group_hrns.sort();
+        self
+    }
+}
+
+impl PolicyRecord {
+    /// The record with its list sorted, so equal states compare equal
+    pub fn normalized(mut self) -> Self {
+        self.attached_principals.sort();
+        self
+    }
+}
+
+/// The whole IAM state: every entity with its relationships
+///
+/// Relationships are recorded where the domain keeps them: memberships on
+/// the member, attachments on the policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IamStateDocument {
+    /// Format of this document; see [`IAM_STATE_FORMAT_VERSION`]
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    /// Users, ordered by HRN
+    pub users: Vec<UserRecord>,
+    /// Groups, ordered by HRN
+    pub groups: Vec<GroupRecord>,
+    /// Policies, ordered by ID
+    pub policies: Vec<PolicyRecord>,
+}
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Errors that can occur when exporting the IAM state
+#[derive(Debug, Error)]
+pub enum ExportIamStateError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
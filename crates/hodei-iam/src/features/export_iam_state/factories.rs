@@ -0,0 +1,36 @@
+//! Factory for creating the ExportIamState use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::export_iam_state::ports::{ExportIamStateUseCasePort, IamStateReaderPort};
+use crate::features::export_iam_state::use_case::ExportIamStateUseCase;
+
+/// Create the ExportIamState use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `reader` - Port for reading the IAM state
+///
+/// # Returns
+///
+/// Arc<dyn ExportIamStateUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let state_repo = Arc::new(SurrealIamStateAdapter::new(db));
+///
+/// let export_iam_state = create_export_iam_state_use_case(state_repo);
+/// ```
+pub fn create_export_iam_state_use_case(
+    reader: Arc<dyn IamStateReaderPort>,
+) -> Arc<dyn ExportIamStateUseCasePort> {
+    info!("Creating ExportIamState use case");
+    Arc::new(ExportIamStateUseCase::new(reader))
+}
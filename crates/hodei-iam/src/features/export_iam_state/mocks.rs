@@ -0,0 +1,80 @@
+//! Mock implementations for testing Export IAM State feature
+
+use async_trait::async_trait;
+
+use super::dto::{GroupRecord, PolicyRecord, UserRecord};
+use super::error::ExportIamStateError;
+use super::ports::IamStateReaderPort;
+
+/// Mock IamStateReaderPort for testing
+pub struct MockIamStateReaderPort {
+    users: Vec<UserRecord>,
+    groups: Vec<GroupRecord>,
+    policies: Vec<PolicyRecord>,
+    should_fail: bool,
+}
+
+impl MockIamStateReaderPort {
+    /// Create a mock with an empty state
+    pub fn new() -> Self {
+        Self {
+            users: Vec::new(),
+            groups: Vec::new(),
+            policies: Vec::new(),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose reads fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a user record
+    pub fn with_user(mut self, user: UserRecord) -> Self {
+        self.users.push(user);
+        self
+    }
+
+    /// Add a group record
+    pub fn with_group(mut self, group: GroupRecord) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Add a policy record
+    pub fn with_policy(mut self, policy: PolicyRecord) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    fn check(&self) -> Result<(), ExportIamStateError> {
+        if self.should_fail {
+            return Err(ExportIamStateError::RepositoryError(
+                "Mock read failure".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl IamStateReaderPort for MockIamStateReaderPort {
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ExportIamStateError> {
+        self.check()?;
+        Ok(self.users.clone())
+    }
+
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ExportIamStateError> {
+        self.check()?;
+        Ok(self.groups.clone())
+    }
+
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ExportIamStateError> {
+        self.check()?;
+        Ok(self.policies.clone())
+    }
+}
@@ -0,0 +1,33 @@
+//! export_iam_state Feature (Vertical Slice)
+//!
+//! This module implements exporting the whole IAM state following VSA, for
+//! backups and for moving IAM data between environments. The result is a
+//! versioned document of every user, group and policy with their
+//! memberships and attachments, which import_iam_state reads back.
+//!
+//! Structure:
+//! - dto.rs              -> Query DTO and the state document format
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interface (ISP)
+//! - use_case.rs         -> Core business logic (ExportIamStateUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{
+    ExportIamStateQuery, GroupRecord, IAM_STATE_FORMAT_VERSION, IamStateDocument, PolicyRecord,
+    UserRecord,
+};
+pub use error::ExportIamStateError;
+pub use ports::{ExportIamStateUseCasePort, IamStateReaderPort};
+pub use use_case::ExportIamStateUseCase;
@@ -0,0 +1,52 @@
+use super::dto::{ExportIamStateQuery, GroupRecord, IamStateDocument, PolicyRecord, UserRecord};
+use super::error::ExportIamStateError;
+use async_trait::async_trait;
+
+/// Port for reading the whole IAM state
+///
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the export_iam_state feature.
+#[async_trait]
+pub trait IamStateReaderPort: Send + Sync {
+    /// Read every user
+    ///
+    /// # Returns
+    /// * `Ok(Vec<UserRecord>)` with all users, in any order
+    /// * `Err(ExportIamStateError)` if there was an error during lookup
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ExportIamStateError>;
+
+    /// Read every group
+    ///
+    /// # Returns
+    /// * `Ok(Vec<GroupRecord>)` with all groups, in any order
+    /// * `Err(ExportIamStateError)` if there was an error during lookup
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ExportIamStateError>;
+
+    /// Read every policy with its attachments
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PolicyRecord>)` with all policies, in any order
+    /// * `Err(ExportIamStateError)` if there was an error during lookup
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ExportIamStateError>;
+}
+
+/// Port for the ExportIamState use case
+///
+/// This port defines the contract for executing the export IAM state use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait ExportIamStateUseCasePort: Send + Sync {
+    /// Execute the export IAM state use case
+    ///
+    /// # Arguments
+    /// * `query` - The export query
+    ///
+    /// # Returns
+    /// * `Ok(IamStateDocument)` with the whole IAM state
+    /// * `Err(ExportIamStateError)` if the state could not be read
+    async fn execute(
+        &self,
+        query: ExportIamStateQuery,
+    ) -> Result<IamStateDocument, ExportIamStateError>;
+}
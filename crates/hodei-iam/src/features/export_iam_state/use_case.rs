@@ -0,0 +1,104 @@
+use super::dto::{
+    ExportIamStateQuery, GroupRecord, IAM_STATE_FORMAT_VERSION, IamStateDocument, PolicyRecord,
+    UserRecord,
+};
+use super::error::ExportIamStateError;
+use super::ports::{ExportIamStateUseCasePort, IamStateReaderPort};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Use case for exporting the whole IAM state
+///
+/// This use case orchestrates the export:
+/// 1. Reads every user, group and policy
+/// 2. Sorts the records, and the lists inside them, by HRN or ID
+/// 3. Stamps the document with the format version and export time
+///
+/// Sorting makes two exports of the same state identical apart from the
+/// timestamp, so dumps can be diffed and kept under version control.
+///
+/// The export spans every tenant; it is an operator task, not one exposed
+/// to tenants.
+pub struct ExportIamStateUseCase {
+    reader: Arc<dyn IamStateReaderPort>,
+}
+
+impl ExportIamStateUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `reader` - Implementation of IamStateReaderPort for reading the state
+    pub fn new(reader: Arc<dyn IamStateReaderPort>) -> Self {
+        Self { reader }
+    }
+
+    /// Execute the export IAM state use case
+    ///
+    /// # Arguments
+    /// * `_query` - ExportIamStateQuery (no options yet)
+    ///
+    /// # Returns
+    /// * Ok(IamStateDocument) with the whole IAM state
+    /// * Err(ExportIamStateError) if there was an error
+    #[instrument(name = "export_iam_state", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref()
+    ))]
+    pub async fn execute(
+        &self,
+        _query: ExportIamStateQuery,
+    ) -> Result<IamStateDocument, ExportIamStateError> {
+        let mut users: Vec<UserRecord> = self
+            .reader
+            .read_users()
+            .await?
+            .into_iter()
+            .map(UserRecord::normalized)
+            .collect();
+        users.sort_by(|a, b| a.hrn.cmp(&b.hrn));
+
+        let mut groups: Vec<GroupRecord> = self
+            .reader
+            .read_groups()
+            .await?
+            .into_iter()
+            .map(GroupRecord::normalized)
+            .collect();
+        groups.sort_by(|a, b| a.hrn.cmp(&b.hrn));
+
+        let mut policies: Vec<PolicyRecord> = self
+            .reader
+            .read_policies()
+            .await?
+            .into_iter()
+            .map(PolicyRecord::normalized)
+            .collect();
+        policies.sort_by(|a, b| a.id.cmp(&b.id));
+
+        info!(
+            users = users.len(),
+            groups = groups.len(),
+            policies = policies.len(),
+            "IAM state exported"
+        );
+
+        Ok(IamStateDocument {
+            format_version: IAM_STATE_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            users,
+            groups,
+            policies,
+        })
+    }
+}
+
+#[async_trait]
+impl ExportIamStateUseCasePort for ExportIamStateUseCase {
+    async fn execute(
+        &self,
+        query: ExportIamStateQuery,
+    ) -> Result<IamStateDocument, ExportIamStateError> {
+        self.execute(query).await
+    }
+}
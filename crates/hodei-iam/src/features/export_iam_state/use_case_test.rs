@@ -0,0 +1,160 @@
+//! Unit tests for export_iam_state use case
+//!
+//! These tests verify the behavior of the ExportIamStateUseCase in isolation,
+//! using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use kernel::{Hrn, PrincipalStatus};
+
+    use crate::features::export_iam_state::{
+        dto::{
+            ExportIamStateQuery, GroupRecord, IAM_STATE_FORMAT_VERSION, IamStateDocument,
+            PolicyRecord, UserRecord,
+        },
+        error::ExportIamStateError,
+        mocks::MockIamStateReaderPort,
+        use_case::ExportIamStateUseCase,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn hrn(resource_type: &str, id: &str) -> String {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+        .to_string()
+    }
+
+    fn user(id: &str, groups: &[&str]) -> UserRecord {
+        UserRecord {
+            hrn: hrn("User", id),
+            name: id.to_string(),
+            email: format!("{id}@example.com"),
+            status: PrincipalStatus::Active,
+            tags: vec![],
+            group_hrns: groups.iter().map(|group| hrn("Group", group)).collect(),
+        }
+    }
+
+    fn group(id: &str, parents: &[&str]) -> GroupRecord {
+        GroupRecord {
+            hrn: hrn("Group", id),
+            name: id.to_string(),
+            description: None,
+            tags: vec![],
+            parent_group_hrns: parents.iter().map(|parent| hrn("Group", parent)).collect(),
+        }
+    }
+
+    fn policy(id: &str, principals: Vec<String>) -> PolicyRecord {
+        PolicyRecord {
+            id: id.to_string(),
+            content: "permit(principal, action, resource);".to_string(),
+            attached_principals: principals,
+        }
+    }
+
+    async fn export(reader: MockIamStateReaderPort) -> IamStateDocument {
+        ExportIamStateUseCase::new(Arc::new(reader))
+            .execute(ExportIamStateQuery::default())
+            .await
+            .unwrap()
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_export_stamps_the_format_version() {
+        let document = export(MockIamStateReaderPort::new()).await;
+
+        assert_eq!(document.format_version, IAM_STATE_FORMAT_VERSION);
+        assert!(document.users.is_empty());
+        assert!(document.groups.is_empty());
+        assert!(document.policies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_export_sorts_records_and_their_lists() {
+        let reader = MockIamStateReaderPort::new()
+            .with_user(user("bob", &["ops", "devs"]))
+            .with_user(user("alice", &[]))
+            .with_group(group("ops", &[]))
+            .with_group(group("devs", &[]))
+            .with_policy(policy(
+                "deploy",
+                vec![hrn("User", "bob"), hrn("Group", "devs")],
+            ))
+            .with_policy(policy("audit", vec![]));
+
+        let document = export(reader).await;
+
+        let users: Vec<&str> = document.users.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(users, ["alice", "bob"]);
+        assert_eq!(
+            document.users[1].group_hrns,
+            [hrn("Group", "devs"), hrn("Group", "ops")]
+        );
+        let groups: Vec<&str> = document.groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(groups, ["devs", "ops"]);
+        let policies: Vec<&str> = document.policies.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(policies, ["audit", "deploy"]);
+        assert_eq!(
+            document.policies[1].attached_principals,
+            [hrn("Group", "devs"), hrn("User", "bob")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_of_the_same_state_is_stable() {
+        let reader = || {
+            MockIamStateReaderPort::new()
+                .with_user(user("alice", &["devs"]))
+                .with_group(group("devs", &[]))
+        };
+
+        let mut first = export(reader()).await;
+        let second = export(reader()).await;
+        first.exported_at = second.exported_at;
+
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn test_document_round_trips_through_json() {
+        let reader = MockIamStateReaderPort::new()
+            .with_user(user("alice", &["devs"]))
+            .with_group(group("devs", &["engineering"]))
+            .with_group(group("engineering", &[]))
+            .with_policy(policy("deploy", vec![hrn("Group", "devs")]));
+        let document = export(reader).await;
+
+        let json = serde_json::to_string(&document).unwrap();
+        let parsed: IamStateDocument = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, document);
+        assert!(json.contains(&format!("\"format_version\":{IAM_STATE_FORMAT_VERSION}")));
+    }
+
+    #[tokio::test]
+    async fn test_export_fails_when_the_state_cannot_be_read() {
+        let result = ExportIamStateUseCase::new(Arc::new(MockIamStateReaderPort::failing()))
+            .execute(ExportIamStateQuery::default())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ExportIamStateError::RepositoryError(_))
+        ));
+    }
+}
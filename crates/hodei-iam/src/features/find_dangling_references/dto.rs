@@ -0,0 +1,66 @@
+//! Data Transfer Objects for find_dangling_references feature
+
+use hodei_policies::features::match_policy::references::EntityReference;
+use kernel::Hrn;
+use serde::{Deserialize, Serialize};
+
+/// Policies read, and entities checked, per batch
+pub const DEFAULT_REFERENCE_BATCH_SIZE: usize = 100;
+
+/// Query to find the policies naming entities that no longer exist
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindDanglingReferencesQuery {
+    /// Whether to check disabled policies too
+    #[serde(default)]
+    pub include_disabled: bool,
+}
+
+impl FindDanglingReferencesQuery {
+    pub fn including_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+}
+
+/// What an entity store can tell about the entities asked about
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntityExistence {
+    /// Entities of the types the store holds that don't exist
+    pub missing: Vec<EntityReference>,
+    /// Entities of types the store doesn't hold, so it can't tell
+    pub unknown: Vec<EntityReference>,
+}
+
+/// A policy naming entities that no longer exist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DanglingPolicy {
+    pub policy_hrn: Hrn,
+    pub disabled: bool,
+    /// The entities the policy names that don't exist, in order
+    pub missing: Vec<EntityReference>,
+}
+
+/// A policy naming entities of types no entity store holds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnverifiedPolicy {
+    pub policy_hrn: Hrn,
+    pub disabled: bool,
+    /// The entities that could not be checked, in order
+    pub unverified: Vec<EntityReference>,
+}
+
+/// Result of scanning the stored policies for dangling references
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DanglingReferencesReport {
+    /// Policies with at least one dangling reference, in ID order
+    pub dangling: Vec<DanglingPolicy>,
+    /// Policies scanned
+    pub scanned: usize,
+    /// Distinct entities checked for existence
+    pub entities_checked: usize,
+    /// Policies that could not be parsed, and so could not be scanned
+    pub unparsable: Vec<Hrn>,
+    /// Policies naming entities no store could check, in ID order; they
+    /// may dangle too
+    pub unverified: Vec<UnverifiedPolicy>,
+}
@@ -0,0 +1,20 @@
+use crate::features::policy_walk::error::PolicyWalkError;
+use thiserror::Error;
+
+/// Errors that can occur when looking for dangling references
+#[derive(Debug, Error)]
+pub enum FindDanglingReferencesError {
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<PolicyWalkError> for FindDanglingReferencesError {
+    fn from(e: PolicyWalkError) -> Self {
+        match e {
+            PolicyWalkError::RepositoryError(message) => Self::RepositoryError(message),
+        }
+    }
+}
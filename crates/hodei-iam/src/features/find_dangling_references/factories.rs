@@ -0,0 +1,41 @@
+//! Factory for creating the FindDanglingReferencesUseCase
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::find_dangling_references::ports::{
+    EntityExistencePort, FindDanglingReferencesPort, PolicyWalkStorePort,
+};
+use crate::features::find_dangling_references::use_case::FindDanglingReferencesUseCase;
+
+/// Create the FindDanglingReferencesUseCase with injected dependencies
+///
+/// # Arguments
+///
+/// * `store` - Port for reading the stored policies
+/// * `entities` - Port checking which referenced entities exist
+///
+/// # Returns
+///
+/// Arc<dyn FindDanglingReferencesPort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let finder = create_find_dangling_references_use_case(policy_adapter, iam_repository);
+/// let report = finder
+///     .find_dangling(FindDanglingReferencesQuery::default())
+///     .await?;
+/// ```
+pub fn create_find_dangling_references_use_case(
+    store: Arc<dyn PolicyWalkStorePort>,
+    entities: Arc<dyn EntityExistencePort>,
+) -> Arc<dyn FindDanglingReferencesPort> {
+    info!("Creating FindDanglingReferencesUseCase");
+    Arc::new(FindDanglingReferencesUseCase::new(store, entities))
+}
@@ -0,0 +1,65 @@
+//! Mock implementations for testing Find Dangling References feature
+
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::references::EntityReference;
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+use super::dto::EntityExistence;
+use super::error::FindDanglingReferencesError;
+use super::ports::EntityExistencePort;
+
+/// Mock EntityExistencePort holding the entities of a few types
+///
+/// Records every call, so tests can check the checks are batched.
+pub struct MockEntityExistence {
+    types: BTreeSet<String>,
+    existing: BTreeSet<EntityReference>,
+    calls: Mutex<Vec<Vec<EntityReference>>>,
+}
+
+impl MockEntityExistence {
+    /// A store of the `Iam::User` and `Iam::Group` types holding `entities`
+    pub fn with_entities<'a>(entities: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        Self::holding(&["Iam::User", "Iam::Group"], entities)
+    }
+
+    /// A store of the entity `types` holding `entities`
+    pub fn holding<'a>(
+        types: &[&str],
+        entities: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        Self {
+            types: types.iter().map(ToString::to_string).collect(),
+            existing: entities
+                .into_iter()
+                .map(|(entity_type, id)| EntityReference::new(entity_type, id))
+                .collect(),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The references asked about, one entry per call
+    pub fn calls(&self) -> Vec<Vec<EntityReference>> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl EntityExistencePort for MockEntityExistence {
+    async fn check_entities(
+        &self,
+        references: &[EntityReference],
+    ) -> Result<EntityExistence, FindDanglingReferencesError> {
+        self.calls.lock().unwrap().push(references.to_vec());
+        let mut existence = EntityExistence::default();
+        for entity in references {
+            if !self.types.contains(&entity.entity_type) {
+                existence.unknown.push(entity.clone());
+            } else if !self.existing.contains(entity) {
+                existence.missing.push(entity.clone());
+            }
+        }
+        Ok(existence)
+    }
+}
@@ -0,0 +1,42 @@
+//! find_dangling_references Feature (Vertical Slice)
+//!
+//! This module implements finding the policies that name deleted entities,
+//! following VSA. Policies outlive the users, groups and resources they
+//! were written for, and a rule about an entity that no longer exists is
+//! dead weight at best. Instead of waiting for someone to notice:
+//!
+//! - each policy is parsed by hodei-policies for the entities it names
+//! - references to entity types (`principal is Iam::User`) can't dangle and
+//!   are ignored; references to instances (`Iam::User::"alice"`) are checked
+//! - existence is checked once per batch of policies, for the entities not
+//!   seen in earlier batches, rather than once per reference
+//! - each entity store answers for the types it holds; references to types
+//!   no store holds are reported as unverified, not assumed to exist
+//! - the report lists each policy with the entities it names that are gone
+//!
+//! Structure:
+//! - dto.rs              -> Query, policy & report DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interfaces (ISP)
+//! - use_case.rs         -> Core business logic (FindDanglingReferencesUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{
+    DanglingPolicy, DanglingReferencesReport, EntityExistence, FindDanglingReferencesQuery,
+    UnverifiedPolicy,
+};
+pub use error::FindDanglingReferencesError;
+pub use ports::{EntityExistencePort, FindDanglingReferencesPort};
+pub use use_case::FindDanglingReferencesUseCase;
@@ -0,0 +1,40 @@
+use super::dto::{DanglingReferencesReport, EntityExistence, FindDanglingReferencesQuery};
+use super::error::FindDanglingReferencesError;
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::references::EntityReference;
+
+/// Re-export the shared policy walk port, read in batches
+pub use crate::features::policy_walk::ports::PolicyWalkStorePort;
+
+/// Port for checking which referenced entities exist
+///
+/// Called once per batch with every entity the batch names that earlier
+/// batches didn't, so implementations can answer with a single lookup.
+#[async_trait]
+pub trait EntityExistencePort: Send + Sync {
+    /// Which of `references` don't exist
+    ///
+    /// A store answers only for the entity types it holds; references to
+    /// any other type come back as unknown, for another store to check.
+    async fn check_entities(
+        &self,
+        references: &[EntityReference],
+    ) -> Result<EntityExistence, FindDanglingReferencesError>;
+}
+
+/// Port for the FindDanglingReferences use case
+///
+/// This port defines the contract for scanning policies for references to
+/// deleted entities.
+#[async_trait]
+pub trait FindDanglingReferencesPort: Send + Sync {
+    /// Find the policies naming entities that no longer exist
+    ///
+    /// # Returns
+    /// * `Ok(DanglingReferencesReport)` with the dangling policies
+    /// * `Err(FindDanglingReferencesError)` if the scan stopped early
+    async fn find_dangling(
+        &self,
+        query: FindDanglingReferencesQuery,
+    ) -> Result<DanglingReferencesReport, FindDanglingReferencesError>;
+}
@@ -0,0 +1,205 @@
+use super::dto::{
+    DEFAULT_REFERENCE_BATCH_SIZE, DanglingPolicy, DanglingReferencesReport,
+    FindDanglingReferencesQuery, UnverifiedPolicy,
+};
+use super::error::FindDanglingReferencesError;
+use super::ports::{EntityExistencePort, FindDanglingReferencesPort, PolicyWalkStorePort};
+use crate::features::policy_walk::walk::PolicyWalk;
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::references::{EntityReference, PolicyReferences};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
+
+/// Finds the policies naming entities that no longer exist
+///
+/// The scan:
+/// 1. Walks the stored policies in batches of `batch_size`, skipping
+///    disabled ones unless asked for
+/// 2. Collects the entity instances each policy names; entity types and
+///    actions can't dangle and are left out
+/// 3. Asks the entity stores, once per batch, about the entities no
+///    earlier batch named, and remembers the answers; each store is asked
+///    about the entities of types the stores before it don't hold
+/// 4. Reports each policy with the entities it names that are missing
+///
+/// References to types no store holds can't be checked; their policies are
+/// reported as unverified rather than assumed fine. Policies that cannot be
+/// parsed are reported rather than failing the scan.
+pub struct FindDanglingReferencesUseCase {
+    store: Arc<dyn PolicyWalkStorePort>,
+    entities: Vec<Arc<dyn EntityExistencePort>>,
+    batch_size: usize,
+}
+
+/// What the entity stores said about an entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Existence {
+    Found,
+    Missing,
+    /// No store holds the entity's type
+    Unknown,
+}
+
+impl FindDanglingReferencesUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `store` - Implementation of PolicyWalkStorePort for reading policies
+    /// * `entities` - Implementation of EntityExistencePort checking the referenced entities
+    pub fn new(
+        store: Arc<dyn PolicyWalkStorePort>,
+        entities: Arc<dyn EntityExistencePort>,
+    ) -> Self {
+        Self {
+            store,
+            entities: vec![entities],
+            batch_size: DEFAULT_REFERENCE_BATCH_SIZE,
+        }
+    }
+
+    /// Also check entities with `entities`, for the types the stores added
+    /// before don't hold (e.g. the resources of another bounded context)
+    pub fn with_entity_store(mut self, entities: Arc<dyn EntityExistencePort>) -> Self {
+        self.entities.push(entities);
+        self
+    }
+
+    /// Read and check `batch_size` policies at a time
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Execute the scan
+    ///
+    /// # Arguments
+    /// * `query` - Which policies to scan
+    ///
+    /// # Returns
+    /// * Ok(DanglingReferencesReport) with the dangling policies
+    /// * Err(FindDanglingReferencesError) if the scan stopped early
+    #[instrument(name = "find_dangling_references", skip(self), fields(
+        correlation_id = kernel::current_correlation_id().as_deref()
+    ))]
+    pub async fn execute(
+        &self,
+        query: FindDanglingReferencesQuery,
+    ) -> Result<DanglingReferencesReport, FindDanglingReferencesError> {
+        if self.batch_size == 0 {
+            return Err(FindDanglingReferencesError::InvalidQuery(
+                "Batch size must be at least 1".to_string(),
+            ));
+        }
+
+        let mut report = DanglingReferencesReport::default();
+        let mut existence: HashMap<EntityReference, Existence> = HashMap::new();
+        let mut walk = PolicyWalk::new(self.store.as_ref(), self.batch_size);
+        while let Some(batch) = walk.next_batch().await? {
+            let mut referencing = Vec::new();
+            for policy in batch {
+                if policy.is_disabled() && !query.include_disabled {
+                    continue;
+                }
+                report.scanned += 1;
+                match PolicyReferences::from_policy(&policy.content) {
+                    Ok(references) => referencing.push((policy, references.entities)),
+                    Err(e) => {
+                        warn!(policy_id = %policy.id, error = %e, "Policy could not be scanned");
+                        report.unparsable.push(policy.hrn);
+                    }
+                }
+            }
+
+            self.check_existence(&referencing, &mut existence).await?;
+            for (policy, entities) in referencing {
+                let with = |wanted: Existence| -> Vec<EntityReference> {
+                    entities
+                        .iter()
+                        .filter(|entity| existence.get(*entity) == Some(&wanted))
+                        .cloned()
+                        .collect()
+                };
+                let missing = with(Existence::Missing);
+                let unverified = with(Existence::Unknown);
+                let disabled = policy.is_disabled();
+                if !unverified.is_empty() {
+                    report.unverified.push(UnverifiedPolicy {
+                        policy_hrn: policy.hrn.clone(),
+                        disabled,
+                        unverified,
+                    });
+                }
+                if !missing.is_empty() {
+                    debug!(policy_id = %policy.id, missing = missing.len(), "Dangling policy");
+                    report.dangling.push(DanglingPolicy {
+                        policy_hrn: policy.hrn,
+                        disabled,
+                        missing,
+                    });
+                }
+            }
+            debug!(scanned = report.scanned, "Dangling reference batch done");
+        }
+
+        report.entities_checked = existence.len();
+        info!(
+            scanned = report.scanned,
+            entities_checked = report.entities_checked,
+            dangling = report.dangling.len(),
+            unverified = report.unverified.len(),
+            "Policies scanned for dangling references"
+        );
+        Ok(report)
+    }
+
+    /// Check the entities of `referencing` not checked yet, in one call per
+    /// store
+    async fn check_existence<P>(
+        &self,
+        referencing: &[(P, Vec<EntityReference>)],
+        existence: &mut HashMap<EntityReference, Existence>,
+    ) -> Result<(), FindDanglingReferencesError> {
+        let mut unchecked: Vec<EntityReference> = referencing
+            .iter()
+            .flat_map(|(_, entities)| entities)
+            .filter(|entity| !existence.contains_key(*entity))
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        for store in &self.entities {
+            if unchecked.is_empty() {
+                return Ok(());
+            }
+            let answer = store.check_entities(&unchecked).await?;
+            let missing: BTreeSet<EntityReference> = answer.missing.into_iter().collect();
+            let unknown: BTreeSet<EntityReference> = answer.unknown.into_iter().collect();
+            for entity in std::mem::take(&mut unchecked) {
+                if unknown.contains(&entity) {
+                    unchecked.push(entity);
+                } else if missing.contains(&entity) {
+                    existence.insert(entity, Existence::Missing);
+                } else {
+                    existence.insert(entity, Existence::Found);
+                }
+            }
+        }
+
+        for entity in unchecked {
+            existence.insert(entity, Existence::Unknown);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FindDanglingReferencesPort for FindDanglingReferencesUseCase {
+    async fn find_dangling(
+        &self,
+        query: FindDanglingReferencesQuery,
+    ) -> Result<DanglingReferencesReport, FindDanglingReferencesError> {
+        self.execute(query).await
+    }
+}
@@ -0,0 +1,245 @@
+//! Unit tests for find_dangling_references use case
+//!
+//! These tests verify the behavior of the FindDanglingReferencesUseCase in
+//! isolation, using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use hodei_policies::features::match_policy::references::EntityReference;
+
+    use crate::features::find_dangling_references::{
+        dto::{DanglingReferencesReport, FindDanglingReferencesQuery},
+        error::FindDanglingReferencesError,
+        mocks::MockEntityExistence,
+        use_case::FindDanglingReferencesUseCase,
+    };
+    use crate::features::policy_walk::mocks::MockPolicyWalkStore;
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn store() -> Arc<MockPolicyWalkStore> {
+        Arc::new(
+            MockPolicyWalkStore::new()
+                .with_policy(
+                    "alice-admin",
+                    r#"permit(principal == Iam::User::"alice", action, resource);"#,
+                )
+                .with_policy(
+                    "bob-and-devs",
+                    r#"permit(principal in Iam::Group::"devs", action, resource)
+                       when { context.approver == Iam::User::"bob" };"#,
+                )
+                .with_policy(
+                    "every-user",
+                    r#"permit(principal is Iam::User, action == Iam::Action::"GetUser", resource);"#,
+                )
+                .with_policy(
+                    "external",
+                    r#"permit(principal == Iam::User::"alice", action, resource == S3::Bucket::"gone");"#,
+                )
+                .with_disabled_policy(
+                    "legacy",
+                    r#"permit(principal == Iam::User::"carol", action, resource);"#,
+                ),
+        )
+    }
+
+    /// alice and the devs group exist; bob and carol were deleted
+    fn entities() -> Arc<MockEntityExistence> {
+        Arc::new(MockEntityExistence::with_entities([
+            ("Iam::User", "alice"),
+            ("Iam::Group", "devs"),
+        ]))
+    }
+
+    fn dangling(report: &DanglingReferencesReport) -> Vec<(String, Vec<String>)> {
+        report
+            .dangling
+            .iter()
+            .map(|policy| {
+                (
+                    policy.policy_hrn.resource_id().to_string(),
+                    policy.missing.iter().map(ToString::to_string).collect(),
+                )
+            })
+            .collect()
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_reports_policies_naming_deleted_entities() {
+        let use_case = FindDanglingReferencesUseCase::new(store(), entities());
+
+        let report = use_case
+            .execute(FindDanglingReferencesQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            dangling(&report),
+            vec![(
+                "bob-and-devs".to_string(),
+                vec![r#"Iam::User::"bob""#.to_string()]
+            )]
+        );
+        assert_eq!(report.scanned, 4);
+        assert!(report.unparsable.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_entities_of_types_no_store_holds_are_unverified() {
+        let use_case = FindDanglingReferencesUseCase::new(store(), entities());
+
+        let report = use_case
+            .execute(FindDanglingReferencesQuery::default())
+            .await
+            .unwrap();
+
+        // The bucket is not an IAM entity, so it is neither found nor missing
+        assert_eq!(report.unverified.len(), 1);
+        assert_eq!(report.unverified[0].policy_hrn.resource_id(), "external");
+        assert_eq!(
+            report.unverified[0].unverified,
+            vec![EntityReference::new("S3::Bucket", "gone")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_types_are_checked_by_the_next_store() {
+        let buckets = Arc::new(MockEntityExistence::holding(&["S3::Bucket"], []));
+        let use_case = FindDanglingReferencesUseCase::new(store(), entities())
+            .with_entity_store(buckets.clone());
+
+        let report = use_case
+            .execute(FindDanglingReferencesQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            buckets.calls(),
+            vec![vec![EntityReference::new("S3::Bucket", "gone")]]
+        );
+        assert!(report.unverified.is_empty());
+        assert_eq!(
+            dangling(&report),
+            vec![
+                (
+                    "bob-and-devs".to_string(),
+                    vec![r#"Iam::User::"bob""#.to_string()]
+                ),
+                (
+                    "external".to_string(),
+                    vec![r#"S3::Bucket::"gone""#.to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_entity_types_and_actions_are_never_checked() {
+        let entities = entities();
+        let use_case = FindDanglingReferencesUseCase::new(store(), entities.clone());
+
+        use_case
+            .execute(FindDanglingReferencesQuery::default())
+            .await
+            .unwrap();
+
+        // `principal is Iam::User` and `Iam::Action::"GetUser"` name no instance
+        let mut checked: Vec<EntityReference> = entities.calls().concat();
+        checked.sort();
+        assert_eq!(
+            checked,
+            vec![
+                EntityReference::new("Iam::Group", "devs"),
+                EntityReference::new("Iam::User", "alice"),
+                EntityReference::new("Iam::User", "bob"),
+                EntityReference::new("S3::Bucket", "gone"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_existence_is_checked_once_per_batch_and_entity() {
+        let entities = entities();
+        let use_case =
+            FindDanglingReferencesUseCase::new(store(), entities.clone()).with_batch_size(2);
+
+        let report = use_case
+            .execute(FindDanglingReferencesQuery::default())
+            .await
+            .unwrap();
+
+        // Batches: [alice-admin, bob-and-devs], [every-user, external], [legacy]
+        assert_eq!(
+            entities.calls(),
+            vec![
+                vec![
+                    EntityReference::new("Iam::Group", "devs"),
+                    EntityReference::new("Iam::User", "alice"),
+                    EntityReference::new("Iam::User", "bob"),
+                ],
+                // alice was checked with the first batch
+                vec![EntityReference::new("S3::Bucket", "gone")],
+            ]
+        );
+        assert_eq!(report.entities_checked, 4);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_policies_are_checked_on_request() {
+        let use_case = FindDanglingReferencesUseCase::new(store(), entities());
+
+        let report = use_case
+            .execute(FindDanglingReferencesQuery::default().including_disabled())
+            .await
+            .unwrap();
+
+        assert_eq!(report.scanned, 5);
+        let legacy = report
+            .dangling
+            .iter()
+            .find(|policy| policy.policy_hrn.resource_id() == "legacy")
+            .unwrap();
+        assert!(legacy.disabled);
+        assert_eq!(
+            legacy.missing,
+            vec![EntityReference::new("Iam::User", "carol")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unparsable_policies_are_reported() {
+        let store = Arc::new(MockPolicyWalkStore::new().with_policy("broken", "permit("));
+        let use_case = FindDanglingReferencesUseCase::new(store, entities());
+
+        let report = use_case
+            .execute(FindDanglingReferencesQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(report.unparsable.len(), 1);
+        assert!(report.dangling.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_zero_batch_size_is_rejected() {
+        let use_case = FindDanglingReferencesUseCase::new(store(), entities()).with_batch_size(0);
+
+        let result = use_case
+            .execute(FindDanglingReferencesQuery::default())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(FindDanglingReferencesError::InvalidQuery(_))
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+//! Shared-kernel adapter for the get_effective_policies feature
+//!
+//! Other bounded contexts, the authorizer in particular, obtain a principal's
+//! IAM policies through the kernel's [`EffectivePoliciesQueryPort`].
+//! [`GetEffectivePoliciesAdapter`] answers that port with
+//! [`GetEffectivePoliciesUseCase`], so the direct and group-inherited
+//! resolution is the same whichever finder ports back it: SurrealDB in
+//! production or
+//! [`InMemoryIamRepository`](crate::infrastructure::in_memory::InMemoryIamRepository)
+//! in tests.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kernel::application::ports::{
+    EffectivePoliciesQuery, EffectivePoliciesQueryPort, EffectivePoliciesResult,
+};
+
+use crate::features::get_effective_policies::dto::GetEffectivePoliciesQuery;
+use crate::features::get_effective_policies::ports::{
+    GroupFinderPort, PolicyFinderPort, UserFinderPort,
+};
+use crate::features::get_effective_policies::use_case::GetEffectivePoliciesUseCase;
+
+/// Adapter that implements the kernel's `EffectivePoliciesQueryPort` using
+/// the effective-policies use case over the given repository ports
+pub struct GetEffectivePoliciesAdapter<U, G, P>
+where
+    U: UserFinderPort + 'static,
+    G: GroupFinderPort + 'static,
+    P: PolicyFinderPort + 'static,
+{
+    user_finder: Arc<U>,
+    group_finder: Arc<G>,
+    policy_finder: Arc<P>,
+}
+
+impl<U, G, P> GetEffectivePoliciesAdapter<U, G, P>
+where
+    U: UserFinderPort + 'static,
+    G: GroupFinderPort + 'static,
+    P: PolicyFinderPort + 'static,
+{
+    /// Create a new adapter instance
+    pub fn new(user_finder: Arc<U>, group_finder: Arc<G>, policy_finder: Arc<P>) -> Self {
+        Self {
+            user_finder,
+            group_finder,
+            policy_finder,
+        }
+    }
+
+    /// The use case over this adapter's ports; building it only clones `Arc`s
+    fn use_case(&self) -> GetEffectivePoliciesUseCase {
+        GetEffectivePoliciesUseCase::new(
+            self.user_finder.clone(),
+            self.group_finder.clone(),
+            self.policy_finder.clone(),
+        )
+    }
+}
+
+#[async_trait]
+impl<U, G, P> EffectivePoliciesQueryPort for GetEffectivePoliciesAdapter<U, G, P>
+where
+    U: UserFinderPort + 'static,
+    G: GroupFinderPort + 'static,
+    P: PolicyFinderPort + 'static,
+{
+    async fn get_effective_policies(
+        &self,
+        query: EffectivePoliciesQuery,
+    ) -> Result<EffectivePoliciesResult, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self
+            .use_case()
+            .execute(GetEffectivePoliciesQuery {
+                principal_hrn: query.principal_hrn,
+            })
+            .await?;
+        if !response.principal_status.is_active() {
+            return Ok(EffectivePoliciesResult::inactive(response.principal_status));
+        }
+        EffectivePoliciesResult::from_policy_set(&response.policies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::get_effective_policies::dto::UserLookupDto;
+    use crate::features::get_effective_policies::error::GetEffectivePoliciesError;
+    use crate::features::get_effective_policies::mocks::{
+        MockGroupFinderPort, MockPolicyFinderPort, MockUserFinderPort,
+    };
+    use kernel::Hrn;
+    use kernel::domain::{HodeiPolicy, PolicyId};
+
+    type MockAdapter =
+        GetEffectivePoliciesAdapter<MockUserFinderPort, MockGroupFinderPort, MockPolicyFinderPort>;
+
+    fn alice() -> String {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "default".to_string(),
+            "User".to_string(),
+            "alice".to_string(),
+        )
+        .to_string()
+    }
+
+    fn adapter(users: MockUserFinderPort, policies: Vec<HodeiPolicy>) -> MockAdapter {
+        GetEffectivePoliciesAdapter::new(
+            Arc::new(users),
+            Arc::new(MockGroupFinderPort::new()),
+            Arc::new(MockPolicyFinderPort::new().with_policies(policies)),
+        )
+    }
+
+    #[tokio::test]
+    async fn converts_the_response_to_the_agnostic_result() {
+        let users = MockUserFinderPort::new().with_user(UserLookupDto::new(
+            alice(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+        ));
+        let policy = HodeiPolicy::new(
+            PolicyId::new("read-own"),
+            "permit(principal, action, resource);".to_string(),
+        );
+
+        let result = adapter(users, vec![policy])
+            .get_effective_policies(EffectivePoliciesQuery {
+                principal_hrn: alice(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.policy_count, 1);
+        let ids: Vec<String> = result
+            .policies
+            .policies()
+            .map(|policy| policy.id().to_string())
+            .collect();
+        assert_eq!(ids, ["read-own"]);
+    }
+
+    #[tokio::test]
+    async fn passes_use_case_errors_through() {
+        let error = adapter(MockUserFinderPort::new(), vec![])
+            .get_effective_policies(EffectivePoliciesQuery {
+                principal_hrn: alice(),
+            })
+            .await
+            .unwrap_err();
+
+        assert!(error.downcast_ref::<GetEffectivePoliciesError>().is_some());
+    }
+}
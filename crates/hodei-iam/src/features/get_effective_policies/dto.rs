@@ -7,6 +7,7 @@ use kernel::domain::policy::HodeiPolicySet;
 use serde::{Deserialize, Serialize};
 use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
+use kernel::{HrnVisitor, PrincipalStatus, TenantScoped};
 
 /// Data Transfer Object for user lookup operations
 ///
@@ -19,10 +20,11 @@ pub struct UserLookupDto {
     pub email: String,
     pub group_hrns: Vec<String>,
     pub tags: Vec<String>,
+    pub status: PrincipalStatus,
 }
 
 impl UserLookupDto {
-    /// Create a new UserLookupDto
+    /// Create a new UserLookupDto for an active user
     pub fn new(hrn: impl Into<String>, name: impl Into<String>, email: impl Into<String>) -> Self {
         Self {
             hrn: hrn.into(),
@@ -30,8 +32,15 @@ impl UserLookupDto {
             email: email.into(),
             group_hrns: Vec::new(),
             tags: Vec::new(),
+            status: PrincipalStatus::Active,
         }
     }
+
+    /// Set the user's lifecycle status
+    pub fn with_status(mut self, status: PrincipalStatus) -> Self {
+        self.status = status;
+        self
+    }
 }
 
 /// Data Transfer Object for group lookup operations
@@ -63,6 +72,12 @@ pub struct GetEffectivePoliciesQuery {
     pub principal_hrn: String,
 }
 
+impl TenantScoped for GetEffectivePoliciesQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.principal_hrn);
+    }
+}
+
 impl ActionTrait for GetEffectivePoliciesQuery {
     fn name() -> &'static str {
         "GetEffectivePolicies"
@@ -94,6 +109,11 @@ pub struct EffectivePoliciesResponse {
 
     /// HRN of the principal (for logging/debugging)
     pub principal_hrn: String,
+
+    /// Lifecycle status of the principal; a suspended or deactivated
+    /// principal has no effective policies
+    #[serde(default)]
+    pub principal_status: PrincipalStatus,
 }
 
 impl EffectivePoliciesResponse {
@@ -102,6 +122,16 @@ impl EffectivePoliciesResponse {
         Self {
             policies,
             principal_hrn: principal_hrn.into(),
+            principal_status: PrincipalStatus::Active,
+        }
+    }
+
+    /// Create the empty response for a principal that is not active
+    pub fn inactive(principal_hrn: impl Into<String>, principal_status: PrincipalStatus) -> Self {
+        Self {
+            policies: HodeiPolicySet::default(),
+            principal_hrn: principal_hrn.into(),
+            principal_status,
         }
     }
 }
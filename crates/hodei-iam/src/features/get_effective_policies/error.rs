@@ -1,3 +1,4 @@
+use kernel::CrossTenantAccess;
 use thiserror::Error;
 
 /// Errores específicos del caso de uso GetEffectivePoliciesForPrincipal
@@ -15,6 +16,9 @@ pub enum GetEffectivePoliciesError {
     #[error("Group not found: {0}")]
     GroupNotFound(String),
 
+    #[error("Group {group} is more than {max_depth} nesting levels above the principal")]
+    GroupNestingTooDeep { group: String, max_depth: usize },
+
     #[error("Policy not found: {0}")]
     PolicyNotFound(String),
 
@@ -26,6 +30,9 @@ pub enum GetEffectivePoliciesError {
 
     #[error("Internal error: {0}")]
     InternalError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
 
 /// Tipo Result específico para este caso de uso
@@ -4,6 +4,8 @@
 //! They allow tests to control the behavior of external dependencies
 //! without requiring real infrastructure (databases, services, etc.).
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
 use crate::features::get_effective_policies::{
@@ -60,6 +62,7 @@ impl UserFinderPort for MockUserFinderPort {
 #[allow(dead_code)]
 pub struct MockGroupFinderPort {
     groups: Vec<GroupLookupDto>,
+    parents: HashMap<String, Vec<GroupLookupDto>>,
     should_fail: bool,
 }
 
@@ -68,6 +71,7 @@ impl MockGroupFinderPort {
     pub fn new() -> Self {
         Self {
             groups: Vec::new(),
+            parents: HashMap::new(),
             should_fail: false,
         }
     }
@@ -77,6 +81,16 @@ impl MockGroupFinderPort {
         self
     }
 
+    /// Make `child_hrn` a direct member of each of `parents`
+    pub fn with_parent_groups(
+        mut self,
+        child_hrn: impl Into<String>,
+        parents: Vec<GroupLookupDto>,
+    ) -> Self {
+        self.parents.insert(child_hrn.into(), parents);
+        self
+    }
+
     pub fn with_failure(mut self) -> Self {
         self.should_fail = true;
         self
@@ -96,12 +110,29 @@ impl GroupFinderPort for MockGroupFinderPort {
         }
         Ok(self.groups.clone())
     }
+
+    async fn find_parent_groups(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError> {
+        if self.should_fail {
+            return Err(GetEffectivePoliciesError::RepositoryError(
+                "Mock group finder failure".to_string(),
+            ));
+        }
+        Ok(self
+            .parents
+            .get(&group_hrn.to_string())
+            .cloned()
+            .unwrap_or_default())
+    }
 }
 
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct MockPolicyFinderPort {
     policies: Vec<HodeiPolicy>,
+    by_principal: HashMap<String, Vec<HodeiPolicy>>,
     should_fail: bool,
 }
 
@@ -110,6 +141,7 @@ impl MockPolicyFinderPort {
     pub fn new() -> Self {
         Self {
             policies: Vec::new(),
+            by_principal: HashMap::new(),
             should_fail: false,
         }
     }
@@ -119,6 +151,16 @@ impl MockPolicyFinderPort {
         self
     }
 
+    /// Return `policies` for `principal_hrn` instead of the shared ones
+    pub fn with_principal_policies(
+        mut self,
+        principal_hrn: impl Into<String>,
+        policies: Vec<HodeiPolicy>,
+    ) -> Self {
+        self.by_principal.insert(principal_hrn.into(), policies);
+        self
+    }
+
     pub fn with_failure(mut self) -> Self {
         self.should_fail = true;
         self
@@ -129,13 +171,17 @@ impl MockPolicyFinderPort {
 impl PolicyFinderPort for MockPolicyFinderPort {
     async fn find_policies_by_principal(
         &self,
-        _principal_hrn: &Hrn,
+        principal_hrn: &Hrn,
     ) -> Result<Vec<HodeiPolicy>, GetEffectivePoliciesError> {
         if self.should_fail {
             return Err(GetEffectivePoliciesError::RepositoryError(
                 "Mock policy finder failure".to_string(),
             ));
         }
-        Ok(self.policies.clone())
+        Ok(self
+            .by_principal
+            .get(&principal_hrn.to_string())
+            .unwrap_or(&self.policies)
+            .clone())
     }
 }
@@ -8,18 +8,20 @@
 //! - error.rs            -> Feature-specific error types
 //! - ports.rs            -> Segregated interface definitions (ISP)
 //! - use_case.rs         -> Core business logic (GetEffectivePoliciesUseCase)
+//! - adapter.rs          -> Kernel EffectivePoliciesQueryPort adapter
 //! - di.rs               -> Dependency Injection helpers
 //! - mocks.rs            -> Test-only mock implementations of ports
 //! - use_case_test.rs    -> Unit tests for the use case
 //!
 //! Re-exports below intentionally expose ONLY what the application layer needs:
 //! - Query / Response DTOs
-//! - Use case
+//! - Use case and its kernel port adapter
 //! - Error and Port traits
 //!
 //! Internal mocks remain private (or test-gated) to avoid leaking test utilities
 //! across crate boundaries.
 
+pub mod adapter;
 pub mod dto;
 pub mod error;
 pub mod ports;
@@ -33,6 +35,7 @@ mod use_case_test;
 // ---------------------------------------------------------------------------
 // PUBLIC RE-EXPORTS (Feature API Surface)
 // ---------------------------------------------------------------------------
+pub use adapter::GetEffectivePoliciesAdapter;
 pub use dto::{EffectivePoliciesResponse, GetEffectivePoliciesQuery};
 pub use error::{GetEffectivePoliciesError, GetEffectivePoliciesResult};
 pub use ports::{GroupFinderPort, PolicyFinderPort, UserFinderPort};
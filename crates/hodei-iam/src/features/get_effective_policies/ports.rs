@@ -51,6 +51,24 @@ pub trait GroupFinderPort: Send + Sync {
         &self,
         user_hrn: &Hrn,
     ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError>;
+
+    /// Find the groups that a group is directly a member of
+    ///
+    /// The use case calls this repeatedly to walk nested groups up to their
+    /// ancestors. Stores without group nesting can rely on the default,
+    /// which reports no parent groups.
+    ///
+    /// # Arguments
+    /// * `group_hrn` - The HRN of the child group
+    ///
+    /// # Returns
+    /// A vector of the group's direct parent groups, or an error if lookup fails
+    async fn find_parent_groups(
+        &self,
+        _group_hrn: &Hrn,
+    ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError> {
+        Ok(Vec::new())
+    }
 }
 
 /// Port for finding policy documents associated with a principal
@@ -3,9 +3,13 @@
 //! This use case implements the business logic for retrieving all policies that
 //! apply to a given principal (user or service account), including:
 //! - Direct policies attached to the principal
-//! - Policies inherited from groups
+//! - Policies inherited from groups, and from every group those groups are
+//!   nested in, up to [`MAX_GROUP_NESTING_DEPTH`] levels
 //! - Policies from assumed roles (future)
 //!
+//! A suspended or deactivated principal has no effective policies; the
+//! response carries its status so callers can tell why.
+//!
 //! # Architecture
 //!
 //! This follows the Vertical Slice Architecture (VSA) pattern:
@@ -14,7 +18,7 @@
 //! - Does NOT expose internal entities to consumers
 
 use crate::features::get_effective_policies::dto::{
-    EffectivePoliciesResponse, GetEffectivePoliciesQuery,
+    EffectivePoliciesResponse, GetEffectivePoliciesQuery, GroupLookupDto,
 };
 use crate::features::get_effective_policies::error::{
     GetEffectivePoliciesError, GetEffectivePoliciesResult,
@@ -22,11 +26,13 @@ use crate::features::get_effective_policies::error::{
 use crate::features::get_effective_policies::ports::{
     GroupFinderPort, PolicyFinderPort, UserFinderPort,
 };
+use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
+use kernel::TenantContext;
 use kernel::domain::Hrn;
 use kernel::domain::policy::HodeiPolicySet;
 use std::collections::HashSet;
 use std::sync::Arc;
-use tracing::{debug, info, warn};
+use tracing::{debug, info, instrument, warn};
 
 /// Use case for obtaining effective IAM policies for a principal
 ///
@@ -37,7 +43,7 @@ use tracing::{debug, info, warn};
 /// - Resolve the principal (User or ServiceAccount)
 /// - Get groups to which the principal belongs
 /// - Collect direct policies from the principal
-/// - Collect policies from all groups
+/// - Collect policies from all groups and their ancestor groups
 /// - Return all policies as a HodeiPolicySet
 ///
 pub struct GetEffectivePoliciesUseCase {
@@ -75,14 +81,25 @@ impl GetEffectivePoliciesUseCase {
     /// 2. Find the user/service-account
     /// 3. Get groups to which the principal belongs
     /// 4. Collect direct policies from the principal
-    /// 5. Collect policies from all groups
+    /// 5. Collect policies from all groups, then from their parent groups,
+    ///    level by level, visiting each group once
     /// 6. Return all policies as a HodeiPolicySet
     ///
+    /// # Errors
+    /// `GroupNestingTooDeep` if some ancestor group is more than
+    /// [`MAX_GROUP_NESTING_DEPTH`] levels above the principal. Resolution
+    /// fails rather than stopping there, since a policy it skipped could be
+    /// a `forbid`.
+    ///
     /// # Arguments
     /// * `query` - Query containing the principal HRN
     ///
     /// # Returns
     /// A response containing all effective policies as a HodeiPolicySet
+    #[instrument(name = "get_effective_policies", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        principal = %query.principal_hrn
+    ))]
     pub async fn execute(
         &self,
         query: GetEffectivePoliciesQuery,
@@ -141,6 +158,20 @@ impl GetEffectivePoliciesUseCase {
             "Found principal"
         );
 
+        // A suspended or deactivated principal keeps its groups and
+        // attachments, but none of its policies apply
+        if !user.status.is_active() {
+            info!(
+                principal = %query.principal_hrn,
+                status = %user.status,
+                "Principal is not active, no effective policies"
+            );
+            return Ok(EffectivePoliciesResponse::inactive(
+                query.principal_hrn,
+                user.status,
+            ));
+        }
+
         // Step 3: Get groups to which the principal belongs
         let groups =
             self.group_finder
@@ -182,30 +213,73 @@ impl GetEffectivePoliciesUseCase {
             }
         }
 
-        // Step 5: Collect policies from all groups
-        for group in &groups {
-            let group_policies = self
-                .policy_finder
-                .find_policies_by_principal(&Hrn::from_string(&group.hrn).ok_or_else(|| {
+        // Step 5: Collect policies from all groups, then walk up the nested
+        // groups one level at a time. A group reachable through several paths,
+        // or through a cycle in stored data, is only visited once.
+        let mut visited_groups: HashSet<String> = HashSet::new();
+        let mut level: Vec<GroupLookupDto> = groups
+            .into_iter()
+            .filter(|group| visited_groups.insert(group.hrn.clone()))
+            .collect();
+        let mut depth = 1;
+
+        while !level.is_empty() {
+            let mut next_level = Vec::new();
+
+            for group in &level {
+                let group_hrn = Hrn::from_string(&group.hrn).ok_or_else(|| {
                     GetEffectivePoliciesError::InvalidPrincipalHrn(group.hrn.clone())
-                })?)
-                .await
-                .map_err(|e| GetEffectivePoliciesError::RepositoryError(e.to_string()))?;
+                })?;
+                let group_policies = self
+                    .policy_finder
+                    .find_policies_by_principal(&group_hrn)
+                    .await
+                    .map_err(|e| GetEffectivePoliciesError::RepositoryError(e.to_string()))?;
 
-            debug!(
-                group_name = %group.name,
-                group_hrn = %group.hrn,
-                policy_count = group_policies.len(),
-                "Found policies for group"
-            );
+                debug!(
+                    group_name = %group.name,
+                    group_hrn = %group.hrn,
+                    depth,
+                    policy_count = group_policies.len(),
+                    "Found policies for group"
+                );
 
-            // Add group policies to the set
-            for policy in group_policies {
-                let policy_id = policy.id().to_string();
-                if policy_ids.insert(policy_id) {
-                    effective_policies.add(policy);
+                // Add group policies to the set
+                for policy in group_policies {
+                    let policy_id = policy.id().to_string();
+                    if policy_ids.insert(policy_id) {
+                        effective_policies.add(policy);
+                    }
                 }
+
+                let parents = self
+                    .group_finder
+                    .find_parent_groups(&group_hrn)
+                    .await
+                    .map_err(|e| GetEffectivePoliciesError::RepositoryError(e.to_string()))?;
+                next_level.extend(
+                    parents
+                        .into_iter()
+                        .filter(|parent| visited_groups.insert(parent.hrn.clone())),
+                );
             }
+
+            if depth == MAX_GROUP_NESTING_DEPTH && !next_level.is_empty() {
+                let too_deep = &next_level[0];
+                warn!(
+                    principal = %query.principal_hrn,
+                    group = %too_deep.hrn,
+                    max_depth = MAX_GROUP_NESTING_DEPTH,
+                    "Group nesting is too deep"
+                );
+                return Err(GetEffectivePoliciesError::GroupNestingTooDeep {
+                    group: too_deep.hrn.clone(),
+                    max_depth: MAX_GROUP_NESTING_DEPTH,
+                });
+            }
+
+            level = next_level;
+            depth += 1;
         }
 
         info!(
@@ -219,4 +293,16 @@ impl GetEffectivePoliciesUseCase {
             query.principal_hrn,
         ))
     }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// Fails with `CrossTenantAccess` when the principal belongs to another
+    /// tenant, before anything is looked up.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: GetEffectivePoliciesQuery,
+    ) -> GetEffectivePoliciesResult<EffectivePoliciesResponse> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
 }
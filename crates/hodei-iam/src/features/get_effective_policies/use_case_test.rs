@@ -10,11 +10,12 @@ mod tests {
     use kernel::domain::{HodeiPolicy, Hrn, PolicyId};
 
     use crate::features::get_effective_policies::{
-        dto::{GetEffectivePoliciesQuery, GroupLookupDto, UserLookupDto},
+        dto::{EffectivePoliciesResponse, GetEffectivePoliciesQuery, GroupLookupDto, UserLookupDto},
         error::GetEffectivePoliciesError,
         mocks::{MockGroupFinderPort, MockPolicyFinderPort, MockUserFinderPort},
         use_case::GetEffectivePoliciesUseCase,
     };
+    use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
 
     // ============================================================================
     // Helper Functions
@@ -450,4 +451,194 @@ mod tests {
         assert_eq!(response.policies.len(), 1);
         assert!(response.policies.contains(&policy));
     }
+
+    // ============================================================================
+    // Nested Groups
+    // ============================================================================
+
+    fn group_dto(id: &str) -> GroupLookupDto {
+        GroupLookupDto::new(group_hrn(id).to_string(), id.to_string())
+    }
+
+    fn group_hrn(id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            "Group".to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn named_policy(id: &str) -> HodeiPolicy {
+        HodeiPolicy::new(
+            PolicyId::new(id.to_string()),
+            "permit(principal, action, resource);".to_string(),
+        )
+    }
+
+    fn policy_ids(response: &EffectivePoliciesResponse) -> Vec<String> {
+        response
+            .policies
+            .policies()
+            .iter()
+            .map(|policy| policy.id().to_string())
+            .collect()
+    }
+
+    /// The user is in `g1`, `g1` is in `g2`, ... up to `g{levels}`; each
+    /// group has a policy named after it
+    fn nested_chain(levels: usize) -> GetEffectivePoliciesUseCase {
+        let mut groups = MockGroupFinderPort::new().with_groups(vec![group_dto("g1")]);
+        let mut policies = MockPolicyFinderPort::new();
+        for level in 1..=levels {
+            let id = format!("g{level}");
+            if level < levels {
+                groups = groups.with_parent_groups(
+                    group_hrn(&id).to_string(),
+                    vec![group_dto(&format!("g{}", level + 1))],
+                );
+            }
+            policies = policies
+                .with_principal_policies(group_hrn(&id).to_string(), vec![named_policy(&id)]);
+        }
+
+        GetEffectivePoliciesUseCase::new(
+            Arc::new(MockUserFinderPort::new().with_user(create_test_user_dto())),
+            Arc::new(groups),
+            Arc::new(policies),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_effective_policies_inherits_from_ancestor_groups() {
+        // Arrange: alice is in backend, backend is in engineering
+        let groups = MockGroupFinderPort::new()
+            .with_groups(vec![group_dto("backend")])
+            .with_parent_groups(
+                group_hrn("backend").to_string(),
+                vec![group_dto("engineering")],
+            );
+        let policies = MockPolicyFinderPort::new()
+            .with_principal_policies(
+                create_test_user_hrn().to_string(),
+                vec![named_policy("own")],
+            )
+            .with_principal_policies(
+                group_hrn("backend").to_string(),
+                vec![named_policy("deploy-backend")],
+            )
+            .with_principal_policies(
+                group_hrn("engineering").to_string(),
+                vec![named_policy("read-repos")],
+            );
+
+        let use_case = GetEffectivePoliciesUseCase::new(
+            Arc::new(MockUserFinderPort::new().with_user(create_test_user_dto())),
+            Arc::new(groups),
+            Arc::new(policies),
+        );
+
+        // Act
+        let response = use_case.execute(create_test_query()).await.unwrap();
+
+        // Assert: direct first, then nearest group outward
+        assert_eq!(
+            policy_ids(&response),
+            ["own", "deploy-backend", "read-repos"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_effective_policies_deduplicates_policies_from_shared_ancestors() {
+        // Arrange: alice is in backend and frontend, both are in engineering,
+        // and engineering's policy is also attached to frontend
+        let groups = MockGroupFinderPort::new()
+            .with_groups(vec![group_dto("backend"), group_dto("frontend")])
+            .with_parent_groups(
+                group_hrn("backend").to_string(),
+                vec![group_dto("engineering")],
+            )
+            .with_parent_groups(
+                group_hrn("frontend").to_string(),
+                vec![group_dto("engineering")],
+            );
+        let policies = MockPolicyFinderPort::new()
+            .with_principal_policies(
+                group_hrn("frontend").to_string(),
+                vec![named_policy("read-repos")],
+            )
+            .with_principal_policies(
+                group_hrn("engineering").to_string(),
+                vec![named_policy("read-repos"), named_policy("wiki")],
+            );
+
+        let use_case = GetEffectivePoliciesUseCase::new(
+            Arc::new(MockUserFinderPort::new().with_user(create_test_user_dto())),
+            Arc::new(groups),
+            Arc::new(policies),
+        );
+
+        // Act
+        let response = use_case.execute(create_test_query()).await.unwrap();
+
+        // Assert
+        assert_eq!(policy_ids(&response), ["read-repos", "wiki"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_effective_policies_terminates_on_stored_cycle() {
+        // Arrange: a cycle written around the use case's checks
+        let groups = MockGroupFinderPort::new()
+            .with_groups(vec![group_dto("a")])
+            .with_parent_groups(group_hrn("a").to_string(), vec![group_dto("b")])
+            .with_parent_groups(group_hrn("b").to_string(), vec![group_dto("a")]);
+        let policies = MockPolicyFinderPort::new()
+            .with_principal_policies(group_hrn("a").to_string(), vec![named_policy("pa")])
+            .with_principal_policies(group_hrn("b").to_string(), vec![named_policy("pb")]);
+
+        let use_case = GetEffectivePoliciesUseCase::new(
+            Arc::new(MockUserFinderPort::new().with_user(create_test_user_dto())),
+            Arc::new(groups),
+            Arc::new(policies),
+        );
+
+        // Act
+        let response = use_case.execute(create_test_query()).await.unwrap();
+
+        // Assert
+        assert_eq!(policy_ids(&response), ["pa", "pb"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_effective_policies_walks_up_to_the_depth_limit() {
+        // Act
+        let response = nested_chain(MAX_GROUP_NESTING_DEPTH)
+            .execute(create_test_query())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.policies.len(), MAX_GROUP_NESTING_DEPTH);
+    }
+
+    #[tokio::test]
+    async fn test_get_effective_policies_rejects_nesting_beyond_the_limit() {
+        // Act
+        let result = nested_chain(MAX_GROUP_NESTING_DEPTH + 1)
+            .execute(create_test_query())
+            .await;
+
+        // Assert
+        match result.unwrap_err() {
+            GetEffectivePoliciesError::GroupNestingTooDeep { group, max_depth } => {
+                assert_eq!(
+                    group,
+                    group_hrn(&format!("g{}", MAX_GROUP_NESTING_DEPTH + 1)).to_string()
+                );
+                assert_eq!(max_depth, MAX_GROUP_NESTING_DEPTH);
+            }
+            other => panic!("Expected GroupNestingTooDeep error, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,71 @@
+//! DTOs for Get Policies feature
+
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use kernel::{Hrn, HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Query para obtener varias políticas IAM por sus HRNs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPoliciesQuery {
+    /// HRNs de las políticas a obtener; los duplicados se ignoran
+    pub policy_hrns: Vec<Hrn>,
+}
+
+impl TenantScoped for GetPoliciesQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.nested(&self.policy_hrns);
+    }
+}
+
+impl ActionTrait for GetPoliciesQuery {
+    fn name() -> &'static str {
+        "GetPolicies"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("iam").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Iam::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Iam::Policy".to_string()
+    }
+}
+
+/// Vista de una política IAM
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PolicyView {
+    /// HRN único de la política
+    pub hrn: Hrn,
+
+    /// Nombre de la política
+    pub name: String,
+
+    /// Contenido de la política en formato Cedar
+    pub content: String,
+
+    /// Descripción opcional de la política
+    pub description: Option<String>,
+
+    /// Anotaciones declaradas en el contenido, p. ej. `@ticket("SEC-1234")`
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+}
+
+/// Resultado de obtener varias políticas
+///
+/// Ambas listas siguen el orden de la primera aparición de cada HRN en la
+/// query, de modo que el cliente puede correlacionarlas con su entrada.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GetPoliciesResponse {
+    /// Políticas encontradas
+    pub policies: Vec<PolicyView>,
+
+    /// HRNs solicitados que no corresponden a ninguna política
+    pub not_found: Vec<Hrn>,
+}
@@ -0,0 +1,19 @@
+//! Error types for Get Policies feature
+
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum GetPoliciesError {
+    /// Error al acceder al repositorio
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+
+    /// Error de validación de un HRN
+    #[error("Invalid HRN: {0}")]
+    InvalidHrn(String),
+
+    /// Algún HRN pertenece a otro tenant
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
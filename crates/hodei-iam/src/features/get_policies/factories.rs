@@ -0,0 +1,36 @@
+//! Factory for creating the GetPolicies use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::get_policies::ports::{GetPoliciesUseCasePort, PolicyBatchReader};
+use crate::features::get_policies::use_case::GetPoliciesUseCase;
+
+/// Create the GetPolicies use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `policy_reader` - Port for reading policies in batch
+///
+/// # Returns
+///
+/// Arc<dyn GetPoliciesUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let policy_reader = Arc::new(SurrealPolicyAdapter::new(db));
+///
+/// let get_policies = create_get_policies_use_case(policy_reader);
+/// ```
+pub fn create_get_policies_use_case(
+    policy_reader: Arc<dyn PolicyBatchReader>,
+) -> Arc<dyn GetPoliciesUseCasePort> {
+    info!("Creating GetPolicies use case");
+    Arc::new(GetPoliciesUseCase::new(policy_reader))
+}
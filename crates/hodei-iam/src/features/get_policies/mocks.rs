@@ -0,0 +1,65 @@
+//! Mock implementations for testing Get Policies feature
+
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::dto::PolicyView;
+use super::error::GetPoliciesError;
+use super::ports::PolicyBatchReader;
+
+/// Mock PolicyBatchReader for testing
+///
+/// Records the HRNs of every call so tests can check round-trips.
+pub struct MockPolicyBatchReader {
+    policies: HashMap<Hrn, PolicyView>,
+    calls: Mutex<Vec<Vec<Hrn>>>,
+    should_fail: bool,
+}
+
+impl MockPolicyBatchReader {
+    /// Create a mock reader with the given policies
+    pub fn with_policies(policies: Vec<PolicyView>) -> Self {
+        Self {
+            policies: policies
+                .into_iter()
+                .map(|policy| (policy.hrn.clone(), policy))
+                .collect(),
+            calls: Mutex::new(Vec::new()),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock reader whose lookups fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::with_policies(Vec::new())
+        }
+    }
+
+    /// HRNs requested by each call, in call order
+    pub fn calls(&self) -> Vec<Vec<Hrn>> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl PolicyBatchReader for MockPolicyBatchReader {
+    async fn get_by_hrns(&self, hrns: &[Hrn]) -> Result<Vec<PolicyView>, GetPoliciesError> {
+        self.calls.lock().unwrap().push(hrns.to_vec());
+        if self.should_fail {
+            return Err(GetPoliciesError::RepositoryError(
+                "Mock repository error".to_string(),
+            ));
+        }
+
+        // Reverse order, so callers can't rely on the reader's ordering
+        Ok(hrns
+            .iter()
+            .rev()
+            .filter_map(|hrn| self.policies.get(hrn).cloned())
+            .collect())
+    }
+}
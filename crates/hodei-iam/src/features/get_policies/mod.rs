@@ -0,0 +1,29 @@
+//! get_policies Feature (Vertical Slice)
+//!
+//! This module implements the batch Get Policies feature for IAM following VSA.
+//! It resolves a list of policy HRNs with a single repository call instead of
+//! one `GetPolicyQuery` per HRN.
+//!
+//! Structure:
+//! - dto.rs              -> Query, View & Response DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interface (ISP)
+//! - use_case.rs         -> Core business logic (GetPoliciesUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{GetPoliciesQuery, GetPoliciesResponse, PolicyView};
+pub use error::GetPoliciesError;
+pub use ports::{GetPoliciesUseCasePort, PolicyBatchReader};
+pub use use_case::GetPoliciesUseCase;
@@ -0,0 +1,60 @@
+//! Ports (interfaces) for Get Policies feature
+//!
+//! Following Interface Segregation Principle (ISP),
+//! this feature defines only the minimal port it needs.
+
+use async_trait::async_trait;
+use kernel::{Hrn, TenantContext};
+
+use super::dto::{GetPoliciesQuery, GetPoliciesResponse, PolicyView};
+use super::error::GetPoliciesError;
+
+/// Port for reading several policies by HRN in one round-trip
+#[async_trait]
+pub trait PolicyBatchReader: Send + Sync {
+    /// Get the policies matching `hrns`
+    ///
+    /// # Arguments
+    ///
+    /// * `hrns` - The HRNs of the policies to retrieve, without duplicates
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PolicyView>)` - The policies that exist, in any order; HRNs
+    ///   with no policy are simply absent
+    /// * `Err(GetPoliciesError)` - If the repository could not be read
+    async fn get_by_hrns(&self, hrns: &[Hrn]) -> Result<Vec<PolicyView>, GetPoliciesError>;
+}
+
+/// Port for the GetPolicies use case
+///
+/// This port defines the contract for executing the get policies use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait GetPoliciesUseCasePort: Send + Sync {
+    /// Execute the get policies use case
+    ///
+    /// # Arguments
+    /// * `query` - The get policies query containing the policy HRNs
+    ///
+    /// # Returns
+    /// * `Ok(GetPoliciesResponse)` with the found policies and the missing HRNs
+    /// * `Err(GetPoliciesError)` if any HRN is invalid or the lookup failed
+    async fn execute(
+        &self,
+        query: GetPoliciesQuery,
+    ) -> Result<GetPoliciesResponse, GetPoliciesError>;
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// Fails with `CrossTenantAccess`, without reading any policy, if one of
+    /// the requested policies belongs to another tenant.
+    async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: GetPoliciesQuery,
+    ) -> Result<GetPoliciesResponse, GetPoliciesError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
+}
@@ -0,0 +1,103 @@
+//! Use Case: Get Policies
+
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, info, instrument};
+
+use super::dto::{GetPoliciesQuery, GetPoliciesResponse, PolicyView};
+use super::error::GetPoliciesError;
+use super::ports::{GetPoliciesUseCasePort, PolicyBatchReader};
+use kernel::Hrn;
+
+/// Caso de uso: Obtener varias políticas IAM por sus HRNs
+pub struct GetPoliciesUseCase {
+    reader: Arc<dyn PolicyBatchReader>,
+}
+
+impl GetPoliciesUseCase {
+    /// Crea una nueva instancia del caso de uso
+    pub fn new(reader: Arc<dyn PolicyBatchReader>) -> Self {
+        Self { reader }
+    }
+
+    /// Ejecuta el caso de uso
+    ///
+    /// Consulta el repositorio una sola vez con los HRNs sin duplicados y
+    /// devuelve las políticas y los HRNs no encontrados en el orden de la query.
+    #[instrument(name = "get_policies", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        policies = query.policy_hrns.len()
+    ))]
+    pub async fn execute(
+        &self,
+        query: GetPoliciesQuery,
+    ) -> Result<GetPoliciesResponse, GetPoliciesError> {
+        info!("Getting {} policies", query.policy_hrns.len());
+
+        // Validar que todos los HRNs sean de tipo Policy
+        if let Some(hrn) = query
+            .policy_hrns
+            .iter()
+            .find(|hrn| hrn.resource_type() != "Policy")
+        {
+            return Err(GetPoliciesError::InvalidHrn(format!(
+                "Expected Policy HRN, got: {}",
+                hrn.resource_type()
+            )));
+        }
+
+        // Eliminar duplicados conservando el orden de la primera aparición
+        let mut seen = HashSet::new();
+        let hrns: Vec<Hrn> = query
+            .policy_hrns
+            .into_iter()
+            .filter(|hrn| seen.insert(hrn.clone()))
+            .collect();
+
+        if hrns.is_empty() {
+            return Ok(GetPoliciesResponse {
+                policies: Vec::new(),
+                not_found: Vec::new(),
+            });
+        }
+
+        let mut found: HashMap<Hrn, PolicyView> = self
+            .reader
+            .get_by_hrns(&hrns)
+            .await?
+            .into_iter()
+            .map(|policy| (policy.hrn.clone(), policy))
+            .collect();
+
+        let mut policies = Vec::with_capacity(found.len());
+        let mut not_found = Vec::new();
+        for hrn in hrns {
+            match found.remove(&hrn) {
+                Some(policy) => policies.push(policy),
+                None => not_found.push(hrn),
+            }
+        }
+
+        debug!(
+            found = policies.len(),
+            not_found = not_found.len(),
+            "Policies retrieved"
+        );
+
+        Ok(GetPoliciesResponse {
+            policies,
+            not_found,
+        })
+    }
+}
+
+#[async_trait]
+impl GetPoliciesUseCasePort for GetPoliciesUseCase {
+    async fn execute(
+        &self,
+        query: GetPoliciesQuery,
+    ) -> Result<GetPoliciesResponse, GetPoliciesError> {
+        self.execute(query).await
+    }
+}
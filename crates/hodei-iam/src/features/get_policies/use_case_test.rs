@@ -0,0 +1,168 @@
+//! Unit tests for get_policies use case
+//!
+//! These tests verify the behavior of the GetPoliciesUseCase in isolation,
+//! using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use kernel::{Hrn, TenantContext};
+
+    use crate::features::get_policies::{
+        dto::{GetPoliciesQuery, PolicyView},
+        error::GetPoliciesError,
+        mocks::MockPolicyBatchReader,
+        ports::GetPoliciesUseCasePort,
+        use_case::GetPoliciesUseCase,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn policy_hrn(id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            "Policy".to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn policy_view(id: &str) -> PolicyView {
+        PolicyView {
+            hrn: policy_hrn(id),
+            name: id.to_string(),
+            content: "permit(principal, action, resource);".to_string(),
+            description: None,
+            annotations: HashMap::new(),
+        }
+    }
+
+    fn setup(ids: &[&str]) -> (Arc<MockPolicyBatchReader>, GetPoliciesUseCase) {
+        let reader = Arc::new(MockPolicyBatchReader::with_policies(
+            ids.iter().map(|id| policy_view(id)).collect(),
+        ));
+        let use_case = GetPoliciesUseCase::new(reader.clone());
+        (reader, use_case)
+    }
+
+    fn query(ids: &[&str]) -> GetPoliciesQuery {
+        GetPoliciesQuery {
+            policy_hrns: ids.iter().map(|id| policy_hrn(id)).collect(),
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_get_policies_in_input_order_with_one_lookup() {
+        // Arrange
+        let (reader, use_case) = setup(&["a", "b", "c"]);
+
+        // Act
+        let response = use_case
+            .execute(query(&["c", "missing", "a"]))
+            .await
+            .unwrap();
+
+        // Assert
+        let names: Vec<&str> = response.policies.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["c", "a"]);
+        assert_eq!(response.not_found, vec![policy_hrn("missing")]);
+        assert_eq!(reader.calls().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_policies_deduplicates_hrns() {
+        // Arrange
+        let (reader, use_case) = setup(&["a", "b"]);
+
+        // Act
+        let response = use_case
+            .execute(query(&["b", "a", "b", "gone", "gone"]))
+            .await
+            .unwrap();
+
+        // Assert
+        let names: Vec<&str> = response.policies.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["b", "a"]);
+        assert_eq!(response.not_found, vec![policy_hrn("gone")]);
+        assert_eq!(
+            reader.calls(),
+            vec![vec![policy_hrn("b"), policy_hrn("a"), policy_hrn("gone")]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_policies_empty_query_skips_repository() {
+        // Arrange
+        let (reader, use_case) = setup(&["a"]);
+
+        // Act
+        let response = use_case.execute(query(&[])).await.unwrap();
+
+        // Assert
+        assert!(response.policies.is_empty());
+        assert!(response.not_found.is_empty());
+        assert!(reader.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_policies_rejects_non_policy_hrn() {
+        // Arrange
+        let (reader, use_case) = setup(&["a"]);
+        let mut query = query(&["a"]);
+        query.policy_hrns.push(Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            "User".to_string(),
+            "alice".to_string(),
+        ));
+
+        // Act
+        let result = use_case.execute(query).await;
+
+        // Assert
+        match result.unwrap_err() {
+            GetPoliciesError::InvalidHrn(msg) => assert!(msg.contains("User")),
+            other => panic!("Expected InvalidHrn error, got {:?}", other),
+        }
+        assert!(reader.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_policies_propagates_repository_error() {
+        // Arrange
+        let use_case = GetPoliciesUseCase::new(Arc::new(MockPolicyBatchReader::failing()));
+
+        // Act
+        let result = use_case.execute(query(&["a"])).await;
+
+        // Assert
+        assert!(matches!(result, Err(GetPoliciesError::RepositoryError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_policies_for_tenant_rejects_foreign_hrn() {
+        // Arrange
+        let (reader, use_case) = setup(&["a"]);
+        let tenant = TenantContext::new("other-account");
+
+        // Act
+        let result = use_case.execute_for_tenant(&tenant, query(&["a"])).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(GetPoliciesError::CrossTenantAccess(_))
+        ));
+        assert!(reader.calls().is_empty());
+    }
+}
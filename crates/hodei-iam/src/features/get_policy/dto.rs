@@ -1,7 +1,8 @@
 //! DTOs for Get Policy feature
 
-use kernel::Hrn;
+use kernel::{Hrn, HrnVisitor, TenantScoped};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
 
@@ -12,6 +13,12 @@ pub struct GetPolicyQuery {
     pub policy_hrn: Hrn,
 }
 
+impl TenantScoped for GetPolicyQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.policy_hrn);
+    }
+}
+
 impl ActionTrait for GetPolicyQuery {
     fn name() -> &'static str {
         "GetPolicy"
@@ -44,5 +51,9 @@ pub struct PolicyView {
 
     /// Descripción opcional de la política
     pub description: Option<String>,
+
+    /// Anotaciones declaradas en el contenido, p. ej. `@ticket("SEC-1234")`
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
 }
 
@@ -1,5 +1,6 @@
 //! Error types for Get Policy feature
 
+use kernel::CrossTenantAccess;
 use thiserror::Error;
 
 #[derive(Debug, Error, Clone, PartialEq)]
@@ -15,5 +16,9 @@ pub enum GetPolicyError {
     /// Error de validación del HRN
     #[error("Invalid HRN: {0}")]
     InvalidHrn(String),
+
+    /// El HRN pertenece a otro tenant
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
 
@@ -43,6 +43,7 @@ mod tests {
     use super::*;
     use crate::features::get_policy::dto::{GetPolicyQuery, PolicyView};
     use crate::features::get_policy::mocks::MockPolicyReader;
+    use std::collections::HashMap;
 
     #[tokio::test]
     async fn test_factory_creates_use_case() {
@@ -57,6 +58,7 @@ mod tests {
             name: "test-policy".to_string(),
             content: "permit(principal, action, resource);".to_string(),
             description: None,
+            annotations: HashMap::new(),
         };
         let policy_reader: Arc<dyn PolicyReader> = Arc::new(MockPolicyReader::with_policy(policy));
 
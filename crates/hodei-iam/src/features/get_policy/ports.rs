@@ -4,7 +4,7 @@
 //! this feature defines only the minimal port it needs.
 
 use async_trait::async_trait;
-use kernel::Hrn;
+use kernel::{Hrn, TenantContext};
 
 use super::dto::{GetPolicyQuery, PolicyView};
 use super::error::GetPolicyError;
@@ -41,4 +41,16 @@ pub trait GetPolicyUseCasePort: Send + Sync {
     /// * `Ok(PolicyView)` if the policy was found successfully
     /// * `Err(GetPolicyError)` if there was an error getting the policy
     async fn execute(&self, query: GetPolicyQuery) -> Result<PolicyView, GetPolicyError>;
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// Fails with `CrossTenantAccess`, without reading the policy, if the
+    /// policy belongs to another tenant.
+    async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: GetPolicyQuery,
+    ) -> Result<PolicyView, GetPolicyError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
 }
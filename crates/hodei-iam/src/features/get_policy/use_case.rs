@@ -2,7 +2,7 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, instrument};
 
 use super::dto::{GetPolicyQuery, PolicyView};
 use super::error::GetPolicyError;
@@ -21,6 +21,10 @@ impl GetPolicyUseCase {
     }
 
     /// Ejecuta el caso de uso
+    #[instrument(name = "get_policy", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        resource = %query.policy_hrn
+    ))]
     pub async fn execute(&self, query: GetPolicyQuery) -> Result<PolicyView, GetPolicyError> {
         info!("Getting policy: {}", query.policy_hrn);
 
@@ -5,6 +5,7 @@
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::sync::Arc;
 
     use kernel::Hrn;
@@ -37,6 +38,7 @@ mod tests {
             name: "Test Policy".to_string(),
             content: "permit(principal, action, resource);".to_string(),
             description: Some("A test policy for unit testing".to_string()),
+            annotations: HashMap::new(),
         }
     }
 
@@ -135,6 +137,7 @@ mod tests {
             name: "Policy 1".to_string(),
             content: "permit(principal, action, resource);".to_string(),
             description: Some("First policy".to_string()),
+            annotations: HashMap::new(),
         };
 
         let policy2 = PolicyView {
@@ -148,6 +151,7 @@ mod tests {
             name: "Policy 2".to_string(),
             content: "forbid(principal, action, resource);".to_string(),
             description: Some("Second policy".to_string()),
+            annotations: HashMap::new(),
         };
 
         let reader = MockPolicyReader::with_policies(vec![policy1.clone(), policy2.clone()]);
@@ -186,6 +190,7 @@ mod tests {
             name: "Policy Without Description".to_string(),
             content: "permit(principal, action, resource);".to_string(),
             description: None,
+            annotations: HashMap::new(),
         };
 
         let reader = MockPolicyReader::with_policy(policy_view.clone());
@@ -252,6 +257,7 @@ mod tests {
                 resource.owner == principal
             };"#.to_string(),
             description: Some("A complex policy with conditions".to_string()),
+            annotations: HashMap::new(),
         };
 
         let reader = MockPolicyReader::with_policy(complex_policy.clone());
@@ -0,0 +1,118 @@
+//! Data Transfer Objects for import_iam_state feature
+//!
+//! The document format itself is defined by export_iam_state.
+
+use serde::{Deserialize, Serialize};
+
+use crate::features::export_iam_state::dto::{
+    GroupRecord, IamStateDocument, PolicyRecord, UserRecord,
+};
+
+/// Events published for what an import changes, shared with the contexts
+/// that react to policy and membership changes
+pub use kernel::{GroupMembershipChanged, PolicyAttachmentChanged, PolicyStored};
+
+/// What to do with a record whose HRN (or policy ID) already exists with
+/// different content
+///
+/// Records identical to the stored ones are never conflicts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Keep the stored record and report the imported one as skipped
+    Skip,
+    /// Replace the stored record, relationships included
+    Overwrite,
+    /// Reject the whole import, listing every conflicting record
+    #[default]
+    Fail,
+}
+
+/// Command to import an IAM state document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportIamStateCommand {
+    pub document: IamStateDocument,
+    #[serde(default)]
+    pub on_conflict: ConflictStrategy,
+    /// Validate and report the changes without writing them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl ImportIamStateCommand {
+    /// Import `document`, failing on conflicts
+    pub fn new(document: IamStateDocument) -> Self {
+        Self {
+            document,
+            on_conflict: ConflictStrategy::default(),
+            dry_run: false,
+        }
+    }
+
+    /// Resolve conflicts with `strategy` instead
+    pub fn on_conflict(mut self, strategy: ConflictStrategy) -> Self {
+        self.on_conflict = strategy;
+        self
+    }
+
+    /// Only report what the import would change
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+}
+
+/// What an import did, or would do, to one kind of record
+///
+/// Each list holds user or group HRNs, or policy IDs, in document order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityChanges {
+    /// Records that did not exist
+    pub created: Vec<String>,
+    /// Existing records replaced under [`ConflictStrategy::Overwrite`]
+    pub updated: Vec<String>,
+    /// Existing records kept under [`ConflictStrategy::Skip`]
+    pub skipped: Vec<String>,
+    /// Existing records identical to the imported ones
+    pub unchanged: Vec<String>,
+}
+
+impl EntityChanges {
+    /// Whether any record is created or updated
+    pub fn has_changes(&self) -> bool {
+        !self.created.is_empty() || !self.updated.is_empty()
+    }
+}
+
+/// Result of an import
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportIamStateReport {
+    /// Whether this was a dry run, in which case nothing was written
+    pub dry_run: bool,
+    pub users: EntityChanges,
+    pub groups: EntityChanges,
+    pub policies: EntityChanges,
+}
+
+impl ImportIamStateReport {
+    /// Whether any record is created or updated
+    pub fn has_changes(&self) -> bool {
+        self.users.has_changes() || self.groups.has_changes() || self.policies.has_changes()
+    }
+}
+
+/// The records an import writes: each one created, or replacing the stored
+/// record with the same HRN or policy ID
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IamStateChanges {
+    pub users: Vec<UserRecord>,
+    pub groups: Vec<GroupRecord>,
+    pub policies: Vec<PolicyRecord>,
+}
+
+impl IamStateChanges {
+    /// Whether there is nothing to write
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty() && self.groups.is_empty() && self.policies.is_empty()
+    }
+}
@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+/// Errors that can occur when importing an IAM state document
+///
+/// Every error is raised before anything is written, except
+/// `RepositoryError` from applying the changes, which the store rolls back.
+#[derive(Debug, Error)]
+pub enum ImportIamStateError {
+    #[error("Unsupported IAM state format version {found}; this build reads version {supported}")]
+    UnsupportedFormatVersion { found: u32, supported: u32 },
+
+    #[error("Malformed IAM state document: {0}")]
+    MalformedDocument(String),
+
+    #[error("Invalid IAM state document: {}", .0.join("; "))]
+    InvalidDocument(Vec<String>),
+
+    #[error("Records already exist with different content: {}", .0.join(", "))]
+    Conflicts(Vec<String>),
+
+    #[error("Group nesting cycle through {0}")]
+    CycleDetected(String),
+
+    #[error("Group {group} would be nested more than {max_depth} levels deep")]
+    NestingTooDeep { group: String, max_depth: usize },
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
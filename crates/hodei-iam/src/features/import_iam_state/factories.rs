@@ -0,0 +1,36 @@
+//! Factory for creating the ImportIamState use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::import_iam_state::ports::{IamStateStorePort, ImportIamStateUseCasePort};
+use crate::features::import_iam_state::use_case::ImportIamStateUseCase;
+
+/// Create the ImportIamState use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `store` - Port for reading and writing the IAM state
+///
+/// # Returns
+///
+/// Arc<dyn ImportIamStateUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let state_repo = Arc::new(SurrealIamStateAdapter::new(db));
+///
+/// let import_iam_state = create_import_iam_state_use_case(state_repo);
+/// ```
+pub fn create_import_iam_state_use_case(
+    store: Arc<dyn IamStateStorePort>,
+) -> Arc<dyn ImportIamStateUseCasePort> {
+    info!("Creating ImportIamState use case");
+    Arc::new(ImportIamStateUseCase::new(store))
+}
@@ -0,0 +1,113 @@
+//! Mock implementations for testing Import IAM State feature
+
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+use super::dto::{GroupMembershipChanged, IamStateChanges, PolicyAttachmentChanged, PolicyStored};
+use super::error::ImportIamStateError;
+use super::ports::{IamStateEventPort, IamStateStorePort};
+use crate::features::export_iam_state::dto::{GroupRecord, PolicyRecord, UserRecord};
+
+/// Mock IamStateStorePort for testing
+///
+/// Records every applied change set, so tests can check what was written
+/// and that dry runs and rejected imports write nothing.
+pub struct MockIamStateStorePort {
+    users: Vec<UserRecord>,
+    groups: Vec<GroupRecord>,
+    policies: Vec<PolicyRecord>,
+    applied: Mutex<Vec<IamStateChanges>>,
+    should_fail: bool,
+}
+
+impl MockIamStateStorePort {
+    /// Create a mock with an empty state
+    pub fn new() -> Self {
+        Self {
+            users: Vec::new(),
+            groups: Vec::new(),
+            policies: Vec::new(),
+            applied: Mutex::new(Vec::new()),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose writes fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a stored user record
+    pub fn with_user(mut self, user: UserRecord) -> Self {
+        self.users.push(user);
+        self
+    }
+
+    /// Add a stored group record
+    pub fn with_group(mut self, group: GroupRecord) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// Add a stored policy record
+    pub fn with_policy(mut self, policy: PolicyRecord) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    /// The change sets applied so far
+    pub fn applied(&self) -> Vec<IamStateChanges> {
+        self.applied.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl IamStateStorePort for MockIamStateStorePort {
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ImportIamStateError> {
+        Ok(self.users.clone())
+    }
+
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ImportIamStateError> {
+        Ok(self.groups.clone())
+    }
+
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportIamStateError> {
+        Ok(self.policies.clone())
+    }
+
+    async fn apply_changes(&self, changes: &IamStateChanges) -> Result<(), ImportIamStateError> {
+        if self.should_fail {
+            return Err(ImportIamStateError::RepositoryError(
+                "Mock write failure".to_string(),
+            ));
+        }
+        self.applied.lock().unwrap().push(changes.clone());
+        Ok(())
+    }
+}
+
+/// Mock IamStateEventPort recording every event
+#[derive(Default)]
+pub struct MockIamStateEventPort {
+    pub stored: Mutex<Vec<PolicyStored>>,
+    pub attachments: Mutex<Vec<PolicyAttachmentChanged>>,
+    pub memberships: Mutex<Vec<GroupMembershipChanged>>,
+}
+
+#[async_trait]
+impl IamStateEventPort for MockIamStateEventPort {
+    async fn publish_policy_stored(&self, event: PolicyStored) {
+        self.stored.lock().unwrap().push(event);
+    }
+
+    async fn publish_attachment_changed(&self, event: PolicyAttachmentChanged) {
+        self.attachments.lock().unwrap().push(event);
+    }
+
+    async fn publish_membership_changed(&self, event: GroupMembershipChanged) {
+        self.memberships.lock().unwrap().push(event);
+    }
+}
@@ -0,0 +1,37 @@
+//! import_iam_state Feature (Vertical Slice)
+//!
+//! This module implements importing a document written by export_iam_state
+//! following VSA. The document is validated as a whole and against the
+//! stored state, then written in one all-or-nothing step:
+//!
+//! - documents from another format version are rejected outright
+//! - records that already exist with different content are skipped,
+//!   overwritten or make the import fail, as the command asks
+//! - a dry run reports what would be created, updated, skipped or left
+//!   unchanged without writing anything
+//!
+//! Structure:
+//! - dto.rs              -> Command, conflict strategy & report DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interface (ISP)
+//! - use_case.rs         -> Core business logic (ImportIamStateUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{
+    ConflictStrategy, EntityChanges, IamStateChanges, ImportIamStateCommand, ImportIamStateReport,
+};
+pub use error::ImportIamStateError;
+pub use ports::{IamStateEventPort, IamStateStorePort, ImportIamStateUseCasePort};
+pub use use_case::ImportIamStateUseCase;
@@ -0,0 +1,80 @@
+use super::dto::{
+    GroupMembershipChanged, IamStateChanges, ImportIamStateCommand, ImportIamStateReport,
+    PolicyAttachmentChanged, PolicyStored,
+};
+use super::error::ImportIamStateError;
+use crate::features::export_iam_state::dto::{GroupRecord, PolicyRecord, UserRecord};
+use async_trait::async_trait;
+
+/// Port for reading the stored IAM state and writing an import into it
+///
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the import_iam_state feature.
+#[async_trait]
+pub trait IamStateStorePort: Send + Sync {
+    /// Read every user
+    ///
+    /// # Returns
+    /// * `Ok(Vec<UserRecord>)` with all users, in any order
+    /// * `Err(ImportIamStateError)` if there was an error during lookup
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ImportIamStateError>;
+
+    /// Read every group
+    ///
+    /// # Returns
+    /// * `Ok(Vec<GroupRecord>)` with all groups, in any order
+    /// * `Err(ImportIamStateError)` if there was an error during lookup
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ImportIamStateError>;
+
+    /// Read every policy with its attachments
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PolicyRecord>)` with all policies, in any order
+    /// * `Err(ImportIamStateError)` if there was an error during lookup
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportIamStateError>;
+
+    /// Write every record in `changes`, creating it or replacing the stored
+    /// record with the same HRN or policy ID
+    ///
+    /// All-or-nothing: if any write fails, none of them may remain.
+    ///
+    /// # Arguments
+    /// * `changes` - The validated records to write
+    ///
+    /// # Returns
+    /// * `Ok(())` if every record was written
+    /// * `Err(ImportIamStateError)` if nothing was written
+    async fn apply_changes(&self, changes: &IamStateChanges) -> Result<(), ImportIamStateError>;
+}
+
+/// Port for announcing what an import changed
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the change.
+#[async_trait]
+pub trait IamStateEventPort: Send + Sync {
+    async fn publish_policy_stored(&self, event: PolicyStored);
+    async fn publish_attachment_changed(&self, event: PolicyAttachmentChanged);
+    async fn publish_membership_changed(&self, event: GroupMembershipChanged);
+}
+
+/// Port for the ImportIamState use case
+///
+/// This port defines the contract for executing the import IAM state use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait ImportIamStateUseCasePort: Send + Sync {
+    /// Execute the import IAM state use case
+    ///
+    /// # Arguments
+    /// * `command` - The document, conflict strategy and dry-run flag
+    ///
+    /// # Returns
+    /// * `Ok(ImportIamStateReport)` with what was, or would be, changed
+    /// * `Err(ImportIamStateError)` if the document was rejected
+    async fn execute(
+        &self,
+        command: ImportIamStateCommand,
+    ) -> Result<ImportIamStateReport, ImportIamStateError>;
+}
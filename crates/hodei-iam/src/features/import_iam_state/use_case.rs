@@ -0,0 +1,532 @@
+use super::dto::{
+    ConflictStrategy, EntityChanges, GroupMembershipChanged, IamStateChanges,
+    ImportIamStateCommand, ImportIamStateReport, PolicyAttachmentChanged, PolicyStored,
+};
+use super::error::ImportIamStateError;
+use super::ports::{IamStateEventPort, IamStateStorePort, ImportIamStateUseCasePort};
+use crate::features::export_iam_state::dto::{
+    GroupRecord, IAM_STATE_FORMAT_VERSION, IamStateDocument, PolicyRecord, UserRecord,
+};
+use crate::internal::domain::email::{normalize_email, validate_email};
+use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
+use async_trait::async_trait;
+use kernel::Hrn;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{Instrument, info, info_span, instrument, warn};
+
+/// Use case for importing an IAM state document
+///
+/// This use case orchestrates the import:
+/// 1. Checks the document's format version
+/// 2. Validates the document on its own: HRNs, duplicates and emails
+/// 3. Compares each record with the stored one to find creations, conflicts
+///    and unchanged records, resolving conflicts with the command's strategy
+/// 4. Validates the state the import would leave: every membership and
+///    attachment resolves, emails stay unique, and group nesting stays
+///    acyclic and within the depth limit
+/// 5. Writes all the changes at once, unless this is a dry run
+/// 6. Publishes an event for every policy whose content changed, every
+///    attachment and every group membership gained or lost
+///
+/// Imports only create and replace records; nothing missing from the
+/// document is deleted. Like the export, the import spans every tenant and
+/// is an operator task.
+pub struct ImportIamStateUseCase {
+    store: Arc<dyn IamStateStorePort>,
+    events: Option<Arc<dyn IamStateEventPort>>,
+}
+
+impl ImportIamStateUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `store` - Implementation of IamStateStorePort for reading and writing the state
+    pub fn new(store: Arc<dyn IamStateStorePort>) -> Self {
+        Self {
+            store,
+            events: None,
+        }
+    }
+
+    /// Publish events for what every import changes
+    pub fn with_events(mut self, events: Arc<dyn IamStateEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Parse a JSON state document, checking its format version first
+    ///
+    /// The version is read on its own before the rest of the document, so a
+    /// dump from an incompatible build is reported as such rather than as
+    /// whatever field it fails to parse on.
+    ///
+    /// # Errors
+    /// * `UnsupportedFormatVersion` if the document has another format version
+    /// * `MalformedDocument` if it is not a valid state document
+    pub fn parse_document(json: &str) -> Result<IamStateDocument, ImportIamStateError> {
+        #[derive(Deserialize)]
+        struct VersionProbe {
+            format_version: u32,
+        }
+
+        let probe: VersionProbe = serde_json::from_str(json)
+            .map_err(|e| ImportIamStateError::MalformedDocument(e.to_string()))?;
+        check_format_version(probe.format_version)?;
+        serde_json::from_str(json)
+            .map_err(|e| ImportIamStateError::MalformedDocument(e.to_string()))
+    }
+
+    /// Execute the import IAM state use case
+    ///
+    /// # Arguments
+    /// * `cmd` - ImportIamStateCommand with the document, conflict strategy and dry-run flag
+    ///
+    /// # Returns
+    /// * Ok(ImportIamStateReport) with what was, or on a dry run would be, changed
+    /// * Err(ImportIamStateError) if the document was rejected; nothing is written
+    #[instrument(name = "import_iam_state", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        dry_run = cmd.dry_run,
+        on_conflict = ?cmd.on_conflict
+    ))]
+    pub async fn execute(
+        &self,
+        cmd: ImportIamStateCommand,
+    ) -> Result<ImportIamStateReport, ImportIamStateError> {
+        let document = cmd.document;
+        check_format_version(document.format_version)?;
+
+        let problems = info_span!("validation").in_scope(|| validate_document(&document));
+        if !problems.is_empty() {
+            warn!(problems = problems.len(), "IAM state document rejected");
+            return Err(ImportIamStateError::InvalidDocument(problems));
+        }
+
+        let mut users = keyed(self.store.read_users().await?, |user| {
+            let user = user.normalized();
+            (user.hrn.clone(), user)
+        });
+        let mut groups = keyed(self.store.read_groups().await?, |group| {
+            let group = group.normalized();
+            (group.hrn.clone(), group)
+        });
+        let policies = keyed(self.store.read_policies().await?, |policy| {
+            let policy = policy.normalized();
+            (policy.id.clone(), policy)
+        });
+
+        let mut report = ImportIamStateReport {
+            dry_run: cmd.dry_run,
+            ..Default::default()
+        };
+        let mut changes = IamStateChanges::default();
+        let mut conflicts = Vec::new();
+        let mut planner = Planner {
+            strategy: cmd.on_conflict,
+            conflicts: &mut conflicts,
+        };
+        planner.plan(
+            document.users.into_iter().map(UserRecord::normalized),
+            &users,
+            |user| &user.hrn,
+            &mut report.users,
+            &mut changes.users,
+        );
+        planner.plan(
+            document.groups.into_iter().map(GroupRecord::normalized),
+            &groups,
+            |group| &group.hrn,
+            &mut report.groups,
+            &mut changes.groups,
+        );
+        planner.plan(
+            document.policies.into_iter().map(PolicyRecord::normalized),
+            &policies,
+            |policy| &policy.id,
+            &mut report.policies,
+            &mut changes.policies,
+        );
+        if !conflicts.is_empty() {
+            warn!(conflicts = conflicts.len(), "IAM state import conflicts");
+            return Err(ImportIamStateError::Conflicts(conflicts));
+        }
+
+        let events = ImportEvents::between(&changes, &users, &groups, &policies);
+
+        // The state the import would leave behind
+        users.extend(changes.users.iter().map(|u| (u.hrn.clone(), u.clone())));
+        groups.extend(changes.groups.iter().map(|g| (g.hrn.clone(), g.clone())));
+
+        let problems = check_references(&changes, &users, &groups);
+        if !problems.is_empty() {
+            warn!(problems = problems.len(), "IAM state document rejected");
+            return Err(ImportIamStateError::InvalidDocument(problems));
+        }
+        check_group_nesting(&changes.groups, &groups)?;
+
+        if cmd.dry_run {
+            info!(changes = report.has_changes(), "IAM state import dry run");
+            return Ok(report);
+        }
+        if !changes.is_empty() {
+            self.store
+                .apply_changes(&changes)
+                .instrument(info_span!("persistence"))
+                .await?;
+        }
+        info!(
+            users = changes.users.len(),
+            groups = changes.groups.len(),
+            policies = changes.policies.len(),
+            "IAM state imported"
+        );
+        if let Some(port) = &self.events {
+            events.publish(port.as_ref()).await;
+        }
+        Ok(report)
+    }
+}
+
+/// What an import changes, as the events announcing it
+#[derive(Default)]
+struct ImportEvents {
+    stored: Vec<PolicyStored>,
+    attachments: Vec<PolicyAttachmentChanged>,
+    memberships: Vec<GroupMembershipChanged>,
+}
+
+impl ImportEvents {
+    /// The events for writing `changes` over the stored records
+    fn between(
+        changes: &IamStateChanges,
+        users: &HashMap<String, UserRecord>,
+        groups: &HashMap<String, GroupRecord>,
+        policies: &HashMap<String, PolicyRecord>,
+    ) -> Self {
+        let mut events = Self::default();
+        for policy in &changes.policies {
+            let stored = policies.get(&policy.id);
+            if stored.is_none_or(|stored| stored.content != policy.content) {
+                events.stored.push(PolicyStored {
+                    policy_id: policy.id.clone(),
+                    content: policy.content.clone(),
+                });
+            }
+            let before = stored.map_or(&[][..], |stored| &stored.attached_principals);
+            events
+                .attachments
+                .extend(added_and_removed(before, &policy.attached_principals).map(
+                    |(principal_hrn, attached)| PolicyAttachmentChanged {
+                        policy_id: policy.id.clone(),
+                        principal_hrn,
+                        attached,
+                    },
+                ));
+        }
+        for user in &changes.users {
+            let before = users.get(&user.hrn).map_or(&[][..], |u| &u.group_hrns);
+            events.add_memberships(&user.hrn, before, &user.group_hrns);
+        }
+        for group in &changes.groups {
+            let before = groups
+                .get(&group.hrn)
+                .map_or(&[][..], |g| &g.parent_group_hrns);
+            events.add_memberships(&group.hrn, before, &group.parent_group_hrns);
+        }
+        events
+    }
+
+    fn add_memberships(&mut self, member: &str, before: &[String], after: &[String]) {
+        let Some(member_hrn) = Hrn::from_string(member) else {
+            return;
+        };
+        self.memberships
+            .extend(added_and_removed(before, after).map(|(group_hrn, joined)| {
+                if joined {
+                    GroupMembershipChanged::joined(member_hrn.clone(), group_hrn)
+                } else {
+                    GroupMembershipChanged::left(member_hrn.clone(), group_hrn)
+                }
+            }));
+    }
+
+    /// Publish the policies first, so attachments refer to known content
+    async fn publish(self, port: &dyn IamStateEventPort) {
+        for event in self.stored {
+            port.publish_policy_stored(event).await;
+        }
+        for event in self.attachments {
+            port.publish_attachment_changed(event).await;
+        }
+        for event in self.memberships {
+            port.publish_membership_changed(event).await;
+        }
+    }
+}
+
+/// HRNs only in `after` paired with `true`, then HRNs only in `before`
+/// paired with `false`; unparseable HRNs are skipped
+fn added_and_removed<'a>(
+    before: &'a [String],
+    after: &'a [String],
+) -> impl Iterator<Item = (Hrn, bool)> + 'a {
+    let added = after.iter().filter(|hrn| !before.contains(hrn));
+    let removed = before.iter().filter(|hrn| !after.contains(hrn));
+    added
+        .map(|hrn| (hrn, true))
+        .chain(removed.map(|hrn| (hrn, false)))
+        .filter_map(|(hrn, added)| Some((Hrn::from_string(hrn)?, added)))
+}
+
+fn check_format_version(found: u32) -> Result<(), ImportIamStateError> {
+    if found != IAM_STATE_FORMAT_VERSION {
+        warn!(found, "IAM state document with unsupported format version");
+        return Err(ImportIamStateError::UnsupportedFormatVersion {
+            found,
+            supported: IAM_STATE_FORMAT_VERSION,
+        });
+    }
+    Ok(())
+}
+
+fn keyed<R>(records: Vec<R>, entry: impl Fn(R) -> (String, R)) -> HashMap<String, R> {
+    records.into_iter().map(entry).collect()
+}
+
+/// Sorts imported records into creations, replacements and conflicts
+struct Planner<'a> {
+    strategy: ConflictStrategy,
+    /// Conflicting keys, collected under [`ConflictStrategy::Fail`]
+    conflicts: &'a mut Vec<String>,
+}
+
+impl Planner<'_> {
+    fn plan<R: PartialEq>(
+        &mut self,
+        incoming: impl Iterator<Item = R>,
+        stored: &HashMap<String, R>,
+        key: fn(&R) -> &String,
+        report: &mut EntityChanges,
+        writes: &mut Vec<R>,
+    ) {
+        for record in incoming {
+            let id = key(&record).clone();
+            match stored.get(&id) {
+                None => {
+                    report.created.push(id);
+                    writes.push(record);
+                }
+                Some(existing) if *existing == record => report.unchanged.push(id),
+                Some(_) => match self.strategy {
+                    ConflictStrategy::Skip => report.skipped.push(id),
+                    ConflictStrategy::Overwrite => {
+                        report.updated.push(id);
+                        writes.push(record);
+                    }
+                    ConflictStrategy::Fail => self.conflicts.push(id),
+                },
+            }
+        }
+    }
+}
+
+/// Problems with the document on its own, in document order
+fn validate_document(document: &IamStateDocument) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let mut seen = HashSet::new();
+    for user in &document.users {
+        if let Some(problem) = hrn_problem(&user.hrn, &["User"]) {
+            problems.push(format!("user {problem}"));
+        }
+        if !seen.insert(user.hrn.as_str()) {
+            problems.push(format!("user {} appears more than once", user.hrn));
+        }
+        if let Err(e) = validate_email(&user.email) {
+            problems.push(format!("user {}: {e}", user.hrn));
+        }
+        for group_hrn in &user.group_hrns {
+            if let Some(problem) = hrn_problem(group_hrn, &["Group"]) {
+                problems.push(format!("user {}: group {problem}", user.hrn));
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    for group in &document.groups {
+        if let Some(problem) = hrn_problem(&group.hrn, &["Group"]) {
+            problems.push(format!("group {problem}"));
+        }
+        if !seen.insert(group.hrn.as_str()) {
+            problems.push(format!("group {} appears more than once", group.hrn));
+        }
+        for parent_hrn in &group.parent_group_hrns {
+            if let Some(problem) = hrn_problem(parent_hrn, &["Group"]) {
+                problems.push(format!("group {}: parent group {problem}", group.hrn));
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    for policy in &document.policies {
+        if policy.id.trim().is_empty() {
+            problems.push("policy with an empty ID".to_string());
+        }
+        if !seen.insert(policy.id.as_str()) {
+            problems.push(format!("policy {} appears more than once", policy.id));
+        }
+        if policy.content.trim().is_empty() {
+            problems.push(format!("policy {} has no content", policy.id));
+        }
+        for principal_hrn in &policy.attached_principals {
+            if let Some(problem) = hrn_problem(principal_hrn, &["User", "Group"]) {
+                problems.push(format!("policy {}: principal {problem}", policy.id));
+            }
+        }
+    }
+
+    problems
+}
+
+/// Why `hrn` is not a valid HRN of one of the `resource_types`, if it is not
+fn hrn_problem(hrn: &str, resource_types: &[&str]) -> Option<String> {
+    match Hrn::from_string(hrn) {
+        None => Some(format!("'{hrn}' is not a valid HRN")),
+        Some(parsed)
+            if !resource_types
+                .iter()
+                .any(|expected| parsed.resource_type().eq_ignore_ascii_case(expected)) =>
+        {
+            Some(format!(
+                "'{hrn}' is not a {} HRN",
+                resource_types.join(" or ")
+            ))
+        }
+        Some(_) => None,
+    }
+}
+
+/// Problems with the relationships of the written records in the merged state
+fn check_references(
+    changes: &IamStateChanges,
+    users: &HashMap<String, UserRecord>,
+    groups: &HashMap<String, GroupRecord>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    for user in &changes.users {
+        for group_hrn in &user.group_hrns {
+            if !groups.contains_key(group_hrn) {
+                problems.push(format!(
+                    "user {}: group {group_hrn} does not exist",
+                    user.hrn
+                ));
+            }
+        }
+    }
+    for group in &changes.groups {
+        for parent_hrn in &group.parent_group_hrns {
+            if !groups.contains_key(parent_hrn) {
+                problems.push(format!(
+                    "group {}: parent group {parent_hrn} does not exist",
+                    group.hrn
+                ));
+            }
+        }
+    }
+    for policy in &changes.policies {
+        for principal_hrn in &policy.attached_principals {
+            if !users.contains_key(principal_hrn) && !groups.contains_key(principal_hrn) {
+                problems.push(format!(
+                    "policy {}: principal {principal_hrn} does not exist",
+                    policy.id
+                ));
+            }
+        }
+    }
+
+    // Sorted so the reported pairs do not depend on map order
+    let written: HashSet<&str> = changes.users.iter().map(|u| u.hrn.as_str()).collect();
+    let mut by_email: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for user in users.values() {
+        by_email
+            .entry(normalize_email(&user.email))
+            .or_default()
+            .push(&user.hrn);
+    }
+    for (email, mut hrns) in by_email {
+        if hrns.len() > 1 && hrns.iter().any(|hrn| written.contains(hrn)) {
+            hrns.sort_unstable();
+            problems.push(format!("email {email} is used by {}", hrns.join(" and ")));
+        }
+    }
+
+    problems
+}
+
+/// Check that no written group ends up in a nesting cycle or too deep
+fn check_group_nesting(
+    written: &[GroupRecord],
+    groups: &HashMap<String, GroupRecord>,
+) -> Result<(), ImportIamStateError> {
+    let mut levels = HashMap::new();
+    for group in written {
+        nesting_level(&group.hrn, groups, &mut levels, &mut Vec::new())?;
+    }
+    Ok(())
+}
+
+/// Number of groups in the longest chain from `group_hrn` up through its parents
+///
+/// `path` holds the groups being resolved, so meeting one again is a cycle;
+/// it never grows past the depth limit, which bounds the recursion.
+fn nesting_level<'a>(
+    group_hrn: &'a str,
+    groups: &'a HashMap<String, GroupRecord>,
+    levels: &mut HashMap<&'a str, usize>,
+    path: &mut Vec<&'a str>,
+) -> Result<usize, ImportIamStateError> {
+    if let Some(&level) = levels.get(group_hrn) {
+        return Ok(level);
+    }
+    if path.contains(&group_hrn) {
+        warn!(group = %group_hrn, "Imported group nesting has a cycle");
+        return Err(ImportIamStateError::CycleDetected(group_hrn.to_string()));
+    }
+    let too_deep = |group: &str| ImportIamStateError::NestingTooDeep {
+        group: group.to_string(),
+        max_depth: MAX_GROUP_NESTING_DEPTH,
+    };
+    if path.len() >= MAX_GROUP_NESTING_DEPTH {
+        return Err(too_deep(path[0]));
+    }
+
+    path.push(group_hrn);
+    let parents = groups
+        .get(group_hrn)
+        .map(|group| group.parent_group_hrns.as_slice())
+        .unwrap_or_default();
+    let mut level = 1;
+    for parent_hrn in parents {
+        level = level.max(1 + nesting_level(parent_hrn, groups, levels, path)?);
+    }
+    path.pop();
+
+    if level > MAX_GROUP_NESTING_DEPTH {
+        return Err(too_deep(group_hrn));
+    }
+    levels.insert(group_hrn, level);
+    Ok(level)
+}
+
+#[async_trait]
+impl ImportIamStateUseCasePort for ImportIamStateUseCase {
+    async fn execute(
+        &self,
+        command: ImportIamStateCommand,
+    ) -> Result<ImportIamStateReport, ImportIamStateError> {
+        self.execute(command).await
+    }
+}
@@ -0,0 +1,508 @@
+//! Unit tests for import_iam_state use case
+//!
+//! These tests verify the behavior of the ImportIamStateUseCase in isolation,
+//! using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use kernel::{Hrn, PrincipalStatus};
+
+    use crate::features::export_iam_state::dto::{
+        GroupRecord, IAM_STATE_FORMAT_VERSION, IamStateDocument, PolicyRecord, UserRecord,
+    };
+    use crate::features::import_iam_state::{
+        dto::{
+            ConflictStrategy, GroupMembershipChanged, ImportIamStateCommand, ImportIamStateReport,
+            PolicyAttachmentChanged, PolicyStored,
+        },
+        error::ImportIamStateError,
+        mocks::{MockIamStateEventPort, MockIamStateStorePort},
+        use_case::ImportIamStateUseCase,
+    };
+    use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn hrn(resource_type: &str, id: &str) -> String {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+        .to_string()
+    }
+
+    fn user(id: &str, groups: &[&str]) -> UserRecord {
+        UserRecord {
+            hrn: hrn("User", id),
+            name: id.to_string(),
+            email: format!("{id}@example.com"),
+            status: PrincipalStatus::Active,
+            tags: vec![],
+            group_hrns: groups.iter().map(|group| hrn("Group", group)).collect(),
+        }
+    }
+
+    fn group(id: &str, parents: &[&str]) -> GroupRecord {
+        GroupRecord {
+            hrn: hrn("Group", id),
+            name: id.to_string(),
+            description: None,
+            tags: vec![],
+            parent_group_hrns: parents.iter().map(|parent| hrn("Group", parent)).collect(),
+        }
+    }
+
+    fn policy(id: &str, principals: Vec<String>) -> PolicyRecord {
+        PolicyRecord {
+            id: id.to_string(),
+            content: "permit(principal, action, resource);".to_string(),
+            attached_principals: principals,
+        }
+    }
+
+    fn document(
+        users: Vec<UserRecord>,
+        groups: Vec<GroupRecord>,
+        policies: Vec<PolicyRecord>,
+    ) -> IamStateDocument {
+        IamStateDocument {
+            format_version: IAM_STATE_FORMAT_VERSION,
+            exported_at: Utc::now(),
+            users,
+            groups,
+            policies,
+        }
+    }
+
+    /// alice in devs, devs nested in engineering, `deploy` attached to devs
+    fn team_document() -> IamStateDocument {
+        document(
+            vec![user("alice", &["devs"])],
+            vec![group("devs", &["engineering"]), group("engineering", &[])],
+            vec![policy("deploy", vec![hrn("Group", "devs")])],
+        )
+    }
+
+    async fn import(
+        store: &Arc<MockIamStateStorePort>,
+        command: ImportIamStateCommand,
+    ) -> Result<ImportIamStateReport, ImportIamStateError> {
+        ImportIamStateUseCase::new(store.clone())
+            .execute(command)
+            .await
+    }
+
+    fn invalid_document_problems(
+        result: Result<ImportIamStateReport, ImportIamStateError>,
+    ) -> Vec<String> {
+        match result {
+            Err(ImportIamStateError::InvalidDocument(problems)) => problems,
+            other => panic!("expected InvalidDocument, got {other:?}"),
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_import_into_empty_store_creates_everything() {
+        let store = Arc::new(MockIamStateStorePort::new());
+
+        let report = import(&store, ImportIamStateCommand::new(team_document()))
+            .await
+            .unwrap();
+
+        assert!(!report.dry_run);
+        assert_eq!(report.users.created, [hrn("User", "alice")]);
+        assert_eq!(
+            report.groups.created,
+            [hrn("Group", "devs"), hrn("Group", "engineering")]
+        );
+        assert_eq!(report.policies.created, ["deploy"]);
+        let applied = store.applied();
+        assert_eq!(applied.len(), 1);
+        assert_eq!(applied[0].users, team_document().users);
+        assert_eq!(applied[0].groups.len(), 2);
+        assert_eq!(applied[0].policies, team_document().policies);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_changes_without_writing() {
+        let store = Arc::new(MockIamStateStorePort::new().with_group(group("devs", &[])));
+
+        let report = import(
+            &store,
+            ImportIamStateCommand::new(team_document())
+                .on_conflict(ConflictStrategy::Overwrite)
+                .dry_run(),
+        )
+        .await
+        .unwrap();
+
+        assert!(report.dry_run);
+        assert!(report.has_changes());
+        assert_eq!(report.groups.created, [hrn("Group", "engineering")]);
+        assert_eq!(report.groups.updated, [hrn("Group", "devs")]);
+        assert!(store.applied().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_identical_records_are_unchanged_not_conflicts() {
+        // Stored lists in another order are still the same record
+        let mut stored_alice = user("alice", &["devs", "ops"]);
+        stored_alice.group_hrns.reverse();
+        let store = Arc::new(
+            MockIamStateStorePort::new()
+                .with_user(stored_alice)
+                .with_group(group("devs", &[]))
+                .with_group(group("ops", &[])),
+        );
+        let document = document(
+            vec![user("alice", &["devs", "ops"])],
+            vec![group("devs", &[])],
+            vec![],
+        );
+
+        let report = import(&store, ImportIamStateCommand::new(document))
+            .await
+            .unwrap();
+
+        assert_eq!(report.users.unchanged, [hrn("User", "alice")]);
+        assert_eq!(report.groups.unchanged, [hrn("Group", "devs")]);
+        assert!(!report.has_changes());
+        assert!(store.applied().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_conflicts_fail_by_default_and_list_every_record() {
+        let mut renamed = group("devs", &[]);
+        renamed.name = "Developers".to_string();
+        let store = Arc::new(
+            MockIamStateStorePort::new()
+                .with_group(renamed)
+                .with_policy(policy("deploy", vec![])),
+        );
+
+        let result = import(&store, ImportIamStateCommand::new(team_document())).await;
+
+        match result {
+            Err(ImportIamStateError::Conflicts(conflicts)) => {
+                assert_eq!(conflicts, [hrn("Group", "devs"), "deploy".to_string()]);
+            }
+            other => panic!("expected Conflicts, got {other:?}"),
+        }
+        assert!(store.applied().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_skip_keeps_the_stored_records() {
+        let store = Arc::new(
+            MockIamStateStorePort::new()
+                .with_group(group("devs", &[]))
+                .with_policy(policy("deploy", vec![])),
+        );
+
+        let report = import(
+            &store,
+            ImportIamStateCommand::new(team_document()).on_conflict(ConflictStrategy::Skip),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.groups.skipped, [hrn("Group", "devs")]);
+        assert_eq!(report.policies.skipped, ["deploy"]);
+        let applied = store.applied();
+        assert_eq!(applied[0].groups, [group("engineering", &[])]);
+        assert!(applied[0].policies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_overwrite_replaces_the_stored_records() {
+        let store = Arc::new(MockIamStateStorePort::new().with_policy(policy("deploy", vec![])));
+
+        let report = import(
+            &store,
+            ImportIamStateCommand::new(team_document()).on_conflict(ConflictStrategy::Overwrite),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.policies.updated, ["deploy"]);
+        assert_eq!(
+            store.applied()[0].policies[0].attached_principals,
+            [hrn("Group", "devs")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_publishes_what_changed() {
+        let hrn_of =
+            |resource_type: &str, id: &str| Hrn::from_string(&hrn(resource_type, id)).unwrap();
+        let store = Arc::new(
+            MockIamStateStorePort::new()
+                .with_user(user("alice", &["ops"]))
+                .with_group(group("ops", &[]))
+                .with_group(group("devs", &["engineering"]))
+                .with_group(group("engineering", &[]))
+                .with_policy(policy("deploy", vec![hrn("Group", "ops")])),
+        );
+        let events = Arc::new(MockIamStateEventPort::default());
+
+        ImportIamStateUseCase::new(store)
+            .with_events(events.clone())
+            .execute(
+                ImportIamStateCommand::new(team_document())
+                    .on_conflict(ConflictStrategy::Overwrite),
+            )
+            .await
+            .unwrap();
+
+        // The content of deploy and the nesting of devs did not change
+        assert!(events.stored.lock().unwrap().is_empty());
+        assert_eq!(
+            *events.attachments.lock().unwrap(),
+            [
+                PolicyAttachmentChanged {
+                    policy_id: "deploy".to_string(),
+                    principal_hrn: hrn_of("Group", "devs"),
+                    attached: true,
+                },
+                PolicyAttachmentChanged {
+                    policy_id: "deploy".to_string(),
+                    principal_hrn: hrn_of("Group", "ops"),
+                    attached: false,
+                },
+            ]
+        );
+        assert_eq!(
+            *events.memberships.lock().unwrap(),
+            [
+                GroupMembershipChanged::joined(hrn_of("User", "alice"), hrn_of("Group", "devs")),
+                GroupMembershipChanged::left(hrn_of("User", "alice"), hrn_of("Group", "ops")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_publishes_new_policies_but_not_dry_runs() {
+        let store = Arc::new(MockIamStateStorePort::new());
+        let events = Arc::new(MockIamStateEventPort::default());
+        let use_case = ImportIamStateUseCase::new(store).with_events(events.clone());
+
+        use_case
+            .execute(ImportIamStateCommand::new(team_document()).dry_run())
+            .await
+            .unwrap();
+        assert!(events.stored.lock().unwrap().is_empty());
+
+        use_case
+            .execute(ImportIamStateCommand::new(team_document()))
+            .await
+            .unwrap();
+        assert_eq!(
+            *events.stored.lock().unwrap(),
+            [PolicyStored {
+                policy_id: "deploy".to_string(),
+                content: "permit(principal, action, resource);".to_string(),
+            }]
+        );
+        assert_eq!(events.attachments.lock().unwrap().len(), 1);
+        assert_eq!(events.memberships.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_other_format_versions_are_rejected() {
+        let store = Arc::new(MockIamStateStorePort::new());
+        let mut document = team_document();
+        document.format_version = IAM_STATE_FORMAT_VERSION + 1;
+
+        let result = import(&store, ImportIamStateCommand::new(document)).await;
+
+        assert!(matches!(
+            result,
+            Err(ImportIamStateError::UnsupportedFormatVersion { found, supported })
+                if found == IAM_STATE_FORMAT_VERSION + 1 && supported == IAM_STATE_FORMAT_VERSION
+        ));
+        assert!(store.applied().is_empty());
+    }
+
+    #[test]
+    fn test_parse_document_checks_the_version_before_the_fields() {
+        let future = format!(
+            r#"{{"format_version": {}, "principals": []}}"#,
+            IAM_STATE_FORMAT_VERSION + 1
+        );
+        assert!(matches!(
+            ImportIamStateUseCase::parse_document(&future),
+            Err(ImportIamStateError::UnsupportedFormatVersion { .. })
+        ));
+
+        let current = format!(r#"{{"format_version": {IAM_STATE_FORMAT_VERSION}}}"#);
+        assert!(matches!(
+            ImportIamStateUseCase::parse_document(&current),
+            Err(ImportIamStateError::MalformedDocument(_))
+        ));
+
+        let json = serde_json::to_string(&team_document()).unwrap();
+        assert_eq!(
+            ImportIamStateUseCase::parse_document(&json).unwrap().users,
+            team_document().users
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_records_are_all_reported() {
+        let store = Arc::new(MockIamStateStorePort::new());
+        let mut bad_email = user("bob", &[]);
+        bad_email.email = "bob".to_string();
+        let document = document(
+            vec![user("alice", &[]), user("alice", &[]), bad_email],
+            vec![GroupRecord {
+                hrn: hrn("User", "not-a-group"),
+                ..group("devs", &[])
+            }],
+            vec![policy("deploy", vec!["nonsense".to_string()])],
+        );
+
+        let problems =
+            invalid_document_problems(import(&store, ImportIamStateCommand::new(document)).await);
+
+        assert_eq!(problems.len(), 4, "{problems:?}");
+        assert!(problems[0].contains("appears more than once"));
+        assert!(problems[1].contains("must contain '@'"));
+        assert!(problems[2].contains("is not a Group HRN"));
+        assert!(problems[3].contains("is not a valid HRN"));
+        assert!(store.applied().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_references_must_resolve_in_the_document_or_the_store() {
+        let store = Arc::new(MockIamStateStorePort::new().with_group(group("ops", &[])));
+        let document = document(
+            vec![user("alice", &["ops", "ghosts"])],
+            vec![],
+            vec![policy("deploy", vec![hrn("User", "bob")])],
+        );
+
+        let problems =
+            invalid_document_problems(import(&store, ImportIamStateCommand::new(document)).await);
+
+        assert_eq!(
+            problems,
+            [
+                format!(
+                    "user {}: group {} does not exist",
+                    hrn("User", "alice"),
+                    hrn("Group", "ghosts")
+                ),
+                format!(
+                    "policy deploy: principal {} does not exist",
+                    hrn("User", "bob")
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_emails_must_stay_unique() {
+        let mut taken = user("bob", &[]);
+        taken.email = "alice@EXAMPLE.com".to_string();
+        let store = Arc::new(MockIamStateStorePort::new().with_user(taken));
+
+        let problems = invalid_document_problems(
+            import(
+                &store,
+                ImportIamStateCommand::new(document(vec![user("alice", &[])], vec![], vec![])),
+            )
+            .await,
+        );
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("email alice@example.com is used by"));
+    }
+
+    #[tokio::test]
+    async fn test_cycles_through_stored_groups_are_rejected() {
+        // The stored engineering group is nested in devs, which the document
+        // nests in engineering
+        let store = Arc::new(
+            MockIamStateStorePort::new()
+                .with_group(group("devs", &[]))
+                .with_group(group("engineering", &["devs"])),
+        );
+        let document = document(vec![], vec![group("devs", &["engineering"])], vec![]);
+
+        let result = import(
+            &store,
+            ImportIamStateCommand::new(document).on_conflict(ConflictStrategy::Overwrite),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ImportIamStateError::CycleDetected(_))));
+        assert!(store.applied().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_nesting_is_limited_to_the_maximum_depth() {
+        let chain = |length: usize| -> Vec<GroupRecord> {
+            (0..length)
+                .map(|i| {
+                    let parent = format!("g{}", i + 1);
+                    let parents: Vec<&str> = if i + 1 < length {
+                        vec![&parent]
+                    } else {
+                        vec![]
+                    };
+                    group(&format!("g{i}"), &parents)
+                })
+                .collect()
+        };
+        let store = Arc::new(MockIamStateStorePort::new());
+
+        let deepest = document(vec![], chain(MAX_GROUP_NESTING_DEPTH), vec![]);
+        assert!(
+            import(&store, ImportIamStateCommand::new(deepest).dry_run())
+                .await
+                .is_ok()
+        );
+
+        let too_deep = document(vec![], chain(MAX_GROUP_NESTING_DEPTH + 1), vec![]);
+        let result = import(&store, ImportIamStateCommand::new(too_deep)).await;
+        assert!(matches!(
+            result,
+            Err(ImportIamStateError::NestingTooDeep { group, max_depth })
+                if group == hrn("Group", "g0") && max_depth == MAX_GROUP_NESTING_DEPTH
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_write_failures_are_reported() {
+        let store = Arc::new(MockIamStateStorePort::failing());
+
+        let result = import(&store, ImportIamStateCommand::new(team_document())).await;
+
+        assert!(matches!(
+            result,
+            Err(ImportIamStateError::RepositoryError(_))
+        ));
+    }
+
+    #[test]
+    fn test_command_defaults_to_failing_on_conflicts() {
+        let json = serde_json::json!({ "document": team_document() });
+
+        let command: ImportIamStateCommand = serde_json::from_value(json).unwrap();
+
+        assert_eq!(command.on_conflict, ConflictStrategy::Fail);
+        assert!(!command.dry_run);
+    }
+}
@@ -0,0 +1,172 @@
+//! Data Transfer Objects for import_policies feature
+
+use serde::{Deserialize, Serialize};
+
+/// Extension of Cedar policy files, dropped from their name to get the policy ID
+pub const POLICY_FILE_EXTENSION: &str = ".cedar";
+
+/// A named policy document, typically a `.cedar` file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyFile {
+    /// File name or path; its last segment, without `.cedar`, is the policy ID
+    pub name: String,
+    /// Policy text, in Cedar or Cedar JSON syntax
+    pub content: String,
+}
+
+impl PolicyFile {
+    pub fn new(name: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            content: content.into(),
+        }
+    }
+
+    /// The policy ID the file is imported under, barring a rename
+    ///
+    /// `teams/payments/read-invoices.cedar` is imported as `read-invoices`.
+    pub fn policy_id(&self) -> String {
+        let file_name = self.name.rsplit(['/', '\\']).next().unwrap_or_default();
+        file_name
+            .strip_suffix(POLICY_FILE_EXTENSION)
+            .unwrap_or(file_name)
+            .trim()
+            .to_string()
+    }
+}
+
+/// What to do with a policy whose ID is already taken by a stored policy, or
+/// by an earlier file of the same import, with different content
+///
+/// Policies identical to the one holding the ID are never conflicts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Keep the policy holding the ID and report the imported one as skipped
+    #[default]
+    Skip,
+    /// Replace the policy holding the ID; stored attachments are kept
+    Overwrite,
+    /// Import the policy under the first free ID `<id>-2`, `<id>-3`...
+    Rename,
+}
+
+/// Command to import a batch of policy files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportPoliciesCommand {
+    pub policies: Vec<PolicyFile>,
+    #[serde(default)]
+    pub on_conflict: ConflictStrategy,
+    /// Validate and report what would happen without writing
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl ImportPoliciesCommand {
+    /// Import `policies`, skipping conflicting ones
+    pub fn new(policies: Vec<PolicyFile>) -> Self {
+        Self {
+            policies,
+            on_conflict: ConflictStrategy::default(),
+            dry_run: false,
+        }
+    }
+
+    /// Resolve conflicts with `strategy` instead
+    pub fn on_conflict(mut self, strategy: ConflictStrategy) -> Self {
+        self.on_conflict = strategy;
+        self
+    }
+
+    /// Only report what the import would do
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+}
+
+/// What an import did, or would do, with one file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyImportAction {
+    /// Imported under a free ID
+    Created,
+    /// Replaced the policy holding the ID
+    Overwritten,
+    /// Imported under a new ID because its own was taken
+    Renamed,
+    /// Not imported because its ID was taken
+    Skipped,
+    /// Identical to the stored policy with the same ID
+    Unchanged,
+}
+
+/// Who held a policy ID an imported file conflicted with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "with", rename_all = "snake_case")]
+pub enum PolicyConflict {
+    /// A stored policy
+    Existing,
+    /// An earlier file of the same import
+    Batch { name: String },
+}
+
+/// Outcome of one valid file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyImportOutcome {
+    /// Name of the file
+    pub name: String,
+    /// ID the policy was, or would be, imported under; for skipped files, the
+    /// ID that was taken
+    pub policy_id: String,
+    pub action: PolicyImportAction,
+    /// What the file's ID conflicted with, if anything
+    pub conflict: Option<PolicyConflict>,
+}
+
+/// A file that failed validation, with every reason
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyImportFailure {
+    pub name: String,
+    pub errors: Vec<String>,
+}
+
+impl std::fmt::Display for PolicyImportFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.errors.join(", "))
+    }
+}
+
+/// Result of an import
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportPoliciesReport {
+    /// Whether this was a dry run, in which case nothing was written
+    pub dry_run: bool,
+    /// Outcome of every valid file, in command order
+    pub outcomes: Vec<PolicyImportOutcome>,
+    /// Files that failed validation; only a dry run reports any, a real
+    /// import fails instead
+    pub failures: Vec<PolicyImportFailure>,
+}
+
+impl ImportPoliciesReport {
+    /// Number of files with the given outcome
+    pub fn count(&self, action: PolicyImportAction) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.action == action)
+            .count()
+    }
+
+    /// Whether any policy is written
+    pub fn has_changes(&self) -> bool {
+        self.outcomes.iter().any(|outcome| {
+            matches!(
+                outcome.action,
+                PolicyImportAction::Created
+                    | PolicyImportAction::Overwritten
+                    | PolicyImportAction::Renamed
+            )
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+use super::dto::PolicyImportFailure;
+
+/// Errors that can occur when importing policy files
+///
+/// Every error is raised before anything is written, except
+/// `RepositoryError` from writing the policies, which the store rolls back.
+#[derive(Debug, Error)]
+pub enum ImportPoliciesError {
+    #[error("No policy files to import")]
+    NothingToImport,
+
+    #[error("Invalid policy files: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    InvalidPolicies(Vec<PolicyImportFailure>),
+
+    #[error("Policy validation service error: {0}")]
+    ValidationFailed(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
@@ -0,0 +1,41 @@
+//! Factory for creating the ImportPolicies use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::import_policies::ports::{
+    ImportPoliciesUseCasePort, PolicyImportStorePort, PolicyValidator,
+};
+use crate::features::import_policies::use_case::ImportPoliciesUseCase;
+
+/// Create the ImportPolicies use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `store` - Port for reading and writing policies
+/// * `validator` - Port for validating policy content
+///
+/// # Returns
+///
+/// Arc<dyn ImportPoliciesUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let state_repo = Arc::new(SurrealIamStateAdapter::new(db));
+/// let validator = Arc::new(ValidatePolicyUseCase::new());
+///
+/// let import_policies = create_import_policies_use_case(state_repo, validator);
+/// ```
+pub fn create_import_policies_use_case(
+    store: Arc<dyn PolicyImportStorePort>,
+    validator: Arc<dyn PolicyValidator>,
+) -> Arc<dyn ImportPoliciesUseCasePort> {
+    info!("Creating ImportPolicies use case");
+    Arc::new(ImportPoliciesUseCase::new(store, validator))
+}
@@ -0,0 +1,96 @@
+//! Mock implementations for testing Import Policies feature
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::error::ImportPoliciesError;
+use super::ports::PolicyImportStorePort;
+use crate::features::create_policy::ports::{
+    PolicyValidationError, PolicyValidator, ValidationResult,
+};
+use crate::features::export_iam_state::dto::PolicyRecord;
+use hodei_policies::features::validate_policy::dto::ValidatePolicyCommand;
+use hodei_policies::features::validate_policy::parse_policy;
+
+/// Mock PolicyValidator for testing
+///
+/// Checks the policy syntax with Cedar, without a schema, so tests can mix
+/// valid and invalid files.
+pub struct MockPolicyValidator;
+
+#[async_trait]
+impl PolicyValidator for MockPolicyValidator {
+    async fn validate(
+        &self,
+        command: ValidatePolicyCommand,
+    ) -> Result<ValidationResult, PolicyValidationError> {
+        let errors: Vec<String> = parse_policy(&command.content)
+            .err()
+            .map(|e| e.to_string())
+            .into_iter()
+            .collect();
+        Ok(ValidationResult {
+            is_valid: errors.is_empty(),
+            errors,
+            annotations: HashMap::new(),
+        })
+    }
+}
+
+/// Mock PolicyImportStorePort for testing
+///
+/// Records every write, so tests can check what was written and that dry
+/// runs and rejected imports write nothing.
+pub struct MockPolicyImportStorePort {
+    policies: Vec<PolicyRecord>,
+    written: Mutex<Vec<Vec<PolicyRecord>>>,
+    should_fail: bool,
+}
+
+impl MockPolicyImportStorePort {
+    /// Create a mock with no stored policies
+    pub fn new() -> Self {
+        Self {
+            policies: Vec::new(),
+            written: Mutex::new(Vec::new()),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose writes fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a stored policy record
+    pub fn with_policy(mut self, policy: PolicyRecord) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    /// The batches written so far
+    pub fn written(&self) -> Vec<Vec<PolicyRecord>> {
+        self.written.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl PolicyImportStorePort for MockPolicyImportStorePort {
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportPoliciesError> {
+        Ok(self.policies.clone())
+    }
+
+    async fn write_policies(&self, policies: &[PolicyRecord]) -> Result<(), ImportPoliciesError> {
+        if self.should_fail {
+            return Err(ImportPoliciesError::RepositoryError(
+                "Mock write failure".to_string(),
+            ));
+        }
+        self.written.lock().unwrap().push(policies.to_vec());
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+//! import_policies Feature (Vertical Slice)
+//!
+//! This module implements importing a batch of policy files following VSA.
+//! Every file is validated first, then the batch is written in one
+//! all-or-nothing step:
+//!
+//! - a file's policy ID is its name without the `.cedar` extension
+//! - if any file is invalid, nothing is imported and every invalid file is
+//!   reported with all its errors
+//! - a file whose ID is taken, by a stored policy or an earlier file, with
+//!   different content is skipped, overwrites it or is renamed, as the
+//!   command asks
+//! - a dry run reports what each file would do, and the invalid files,
+//!   without writing anything
+//!
+//! Structure:
+//! - dto.rs              -> Command, conflict strategy & report DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interfaces (ISP)
+//! - use_case.rs         -> Core business logic (ImportPoliciesUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{
+    ConflictStrategy, ImportPoliciesCommand, ImportPoliciesReport, PolicyConflict, PolicyFile,
+    PolicyImportAction, PolicyImportFailure, PolicyImportOutcome,
+};
+pub use error::ImportPoliciesError;
+pub use ports::{ImportPoliciesUseCasePort, PolicyImportStorePort};
+pub use use_case::ImportPoliciesUseCase;
@@ -0,0 +1,52 @@
+use super::dto::{ImportPoliciesCommand, ImportPoliciesReport};
+use super::error::ImportPoliciesError;
+use crate::features::export_iam_state::dto::PolicyRecord;
+use async_trait::async_trait;
+
+/// Re-export the policy validation port used by create_policy
+pub use crate::features::create_policy::ports::PolicyValidator;
+
+/// Port for reading the stored policies and writing an import
+///
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the import_policies feature.
+#[async_trait]
+pub trait PolicyImportStorePort: Send + Sync {
+    /// Read every policy with its attachments
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PolicyRecord>)` with all policies, in any order
+    /// * `Err(ImportPoliciesError)` if there was an error during lookup
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportPoliciesError>;
+
+    /// Write every policy, creating it or replacing the stored policy with
+    /// the same ID, attachments included
+    ///
+    /// All-or-nothing: if any write fails, none of them may remain.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every policy was written
+    /// * `Err(ImportPoliciesError)` if nothing was written
+    async fn write_policies(&self, policies: &[PolicyRecord]) -> Result<(), ImportPoliciesError>;
+}
+
+/// Port for the ImportPolicies use case
+///
+/// This port defines the contract for executing the import policies use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait ImportPoliciesUseCasePort: Send + Sync {
+    /// Execute the import policies use case
+    ///
+    /// # Arguments
+    /// * `command` - The policy files, conflict strategy and dry-run flag
+    ///
+    /// # Returns
+    /// * `Ok(ImportPoliciesReport)` with what was, or would be, imported
+    /// * `Err(ImportPoliciesError)` if the import was rejected
+    async fn execute(
+        &self,
+        command: ImportPoliciesCommand,
+    ) -> Result<ImportPoliciesReport, ImportPoliciesError>;
+}
@@ -0,0 +1,278 @@
+use super::dto::{
+    ConflictStrategy, ImportPoliciesCommand, ImportPoliciesReport, PolicyConflict, PolicyFile,
+    PolicyImportAction, PolicyImportFailure, PolicyImportOutcome,
+};
+use super::error::ImportPoliciesError;
+use super::ports::{ImportPoliciesUseCasePort, PolicyImportStorePort, PolicyValidator};
+use crate::features::export_iam_state::dto::PolicyRecord;
+use async_trait::async_trait;
+use hodei_policies::features::validate_policy::dto::ValidatePolicyCommand;
+use hodei_policies::features::validate_policy::to_cedar_syntax;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{Instrument, info, info_span, instrument, warn};
+
+/// Use case for importing a batch of policy files
+///
+/// This use case orchestrates the import:
+/// 1. Validates every file, collecting all the reasons each one is invalid
+/// 2. Rejects the whole import if any file is invalid, unless this is a
+///    dry run, which reports them instead
+/// 3. Resolves each file's ID against the stored policies and the earlier
+///    files of the batch, applying the command's conflict strategy
+/// 4. Writes every policy at once, unless this is a dry run
+///
+/// Policies are stored in Cedar syntax, as create_policy does; new policies
+/// start with no attachments.
+pub struct ImportPoliciesUseCase {
+    store: Arc<dyn PolicyImportStorePort>,
+    validator: Arc<dyn PolicyValidator>,
+}
+
+impl ImportPoliciesUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `store` - Implementation of PolicyImportStorePort for reading and writing policies
+    /// * `validator` - Implementation of PolicyValidator for checking policy content
+    pub fn new(store: Arc<dyn PolicyImportStorePort>, validator: Arc<dyn PolicyValidator>) -> Self {
+        Self { store, validator }
+    }
+
+    /// Execute the import policies use case
+    ///
+    /// # Arguments
+    /// * `cmd` - ImportPoliciesCommand with the files, conflict strategy and dry-run flag
+    ///
+    /// # Returns
+    /// * Ok(ImportPoliciesReport) with what was, or on a dry run would be, imported
+    /// * Err(ImportPoliciesError) if the import was rejected; nothing is written
+    #[instrument(name = "import_policies", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        files = cmd.policies.len(),
+        dry_run = cmd.dry_run,
+        on_conflict = ?cmd.on_conflict
+    ))]
+    pub async fn execute(
+        &self,
+        cmd: ImportPoliciesCommand,
+    ) -> Result<ImportPoliciesReport, ImportPoliciesError> {
+        if cmd.policies.is_empty() {
+            return Err(ImportPoliciesError::NothingToImport);
+        }
+
+        let mut valid = Vec::new();
+        let mut failures = Vec::new();
+        for file in cmd.policies {
+            match self
+                .check(&file)
+                .instrument(info_span!("validation", file = %file.name))
+                .await?
+            {
+                Ok(content) => valid.push((file, content)),
+                Err(errors) => failures.push(PolicyImportFailure {
+                    name: file.name,
+                    errors,
+                }),
+            }
+        }
+        if !failures.is_empty() && !cmd.dry_run {
+            warn!(invalid = failures.len(), "Policy import rejected");
+            return Err(ImportPoliciesError::InvalidPolicies(failures));
+        }
+
+        let stored = self.store.read_policies().await?;
+        let mut planner = Planner::new(cmd.on_conflict, stored);
+        let outcomes = valid
+            .into_iter()
+            .map(|(file, content)| planner.plan(file, content))
+            .collect();
+        let report = ImportPoliciesReport {
+            dry_run: cmd.dry_run,
+            outcomes,
+            failures,
+        };
+
+        if cmd.dry_run {
+            info!(changes = report.has_changes(), "Policy import dry run");
+            return Ok(report);
+        }
+        if !planner.writes.is_empty() {
+            self.store
+                .write_policies(&planner.writes)
+                .instrument(info_span!("persistence"))
+                .await?;
+        }
+        info!(written = planner.writes.len(), "Policies imported");
+        Ok(report)
+    }
+
+    /// Validate a file, returning its content in Cedar syntax or every
+    /// reason it is invalid
+    ///
+    /// Only a failure of the validation service itself is an error.
+    async fn check(
+        &self,
+        file: &PolicyFile,
+    ) -> Result<Result<String, Vec<String>>, ImportPoliciesError> {
+        let mut errors = Vec::new();
+        if file.policy_id().is_empty() {
+            errors.push("File name does not give a policy ID".to_string());
+        }
+        if file.content.trim().is_empty() {
+            errors.push("Policy content cannot be empty".to_string());
+            return Ok(Err(errors));
+        }
+
+        let result = self
+            .validator
+            .validate(ValidatePolicyCommand {
+                content: file.content.clone(),
+            })
+            .await
+            .map_err(|e| ImportPoliciesError::ValidationFailed(e.to_string()))?;
+        if !result.is_valid || !result.errors.is_empty() {
+            errors.extend(result.errors);
+            if errors.is_empty() {
+                errors.push("Policy is invalid".to_string());
+            }
+        }
+        if !errors.is_empty() {
+            return Ok(Err(errors));
+        }
+
+        Ok(to_cedar_syntax(&file.content).map_err(|e| vec![e.to_string()]))
+    }
+}
+
+#[async_trait]
+impl ImportPoliciesUseCasePort for ImportPoliciesUseCase {
+    async fn execute(
+        &self,
+        command: ImportPoliciesCommand,
+    ) -> Result<ImportPoliciesReport, ImportPoliciesError> {
+        self.execute(command).await
+    }
+}
+
+/// Who holds a policy ID while planning
+enum Holder {
+    Stored,
+    /// A file of the batch, with the index of its write
+    Batch {
+        name: String,
+        write: usize,
+    },
+}
+
+/// Resolves each file's ID and collects the policies to write
+struct Planner {
+    strategy: ConflictStrategy,
+    stored: HashMap<String, PolicyRecord>,
+    taken: HashMap<String, Holder>,
+    writes: Vec<PolicyRecord>,
+}
+
+impl Planner {
+    fn new(strategy: ConflictStrategy, stored: Vec<PolicyRecord>) -> Self {
+        let stored: HashMap<_, _> = stored
+            .into_iter()
+            .map(|policy| (policy.id.clone(), policy))
+            .collect();
+        let taken = stored
+            .keys()
+            .map(|id| (id.clone(), Holder::Stored))
+            .collect();
+        Self {
+            strategy,
+            stored,
+            taken,
+            writes: Vec::new(),
+        }
+    }
+
+    fn plan(&mut self, file: PolicyFile, content: String) -> PolicyImportOutcome {
+        let policy_id = file.policy_id();
+        let outcome = |policy_id, action, conflict| PolicyImportOutcome {
+            name: file.name.clone(),
+            policy_id,
+            action,
+            conflict,
+        };
+
+        let (current, write, conflict) = match self.taken.get(&policy_id) {
+            None => {
+                self.create(policy_id.clone(), content, &file.name);
+                return outcome(policy_id, PolicyImportAction::Created, None);
+            }
+            Some(Holder::Stored) => (&self.stored[&policy_id], None, PolicyConflict::Existing),
+            Some(Holder::Batch { name, write }) => (
+                &self.writes[*write],
+                Some(*write),
+                PolicyConflict::Batch { name: name.clone() },
+            ),
+        };
+        if current.content == content {
+            let action = match conflict {
+                PolicyConflict::Existing => PolicyImportAction::Unchanged,
+                PolicyConflict::Batch { .. } => PolicyImportAction::Skipped,
+            };
+            return outcome(policy_id, action, Some(conflict));
+        }
+
+        match self.strategy {
+            ConflictStrategy::Skip => {
+                outcome(policy_id, PolicyImportAction::Skipped, Some(conflict))
+            }
+            ConflictStrategy::Overwrite => {
+                let record = PolicyRecord {
+                    id: policy_id.clone(),
+                    content,
+                    attached_principals: current.attached_principals.clone(),
+                };
+                // A later file replaces the earlier one's write
+                let write = match write {
+                    Some(write) => {
+                        self.writes[write] = record;
+                        write
+                    }
+                    None => {
+                        self.writes.push(record);
+                        self.writes.len() - 1
+                    }
+                };
+                self.taken.insert(
+                    policy_id.clone(),
+                    Holder::Batch {
+                        name: file.name.clone(),
+                        write,
+                    },
+                );
+                outcome(policy_id, PolicyImportAction::Overwritten, Some(conflict))
+            }
+            ConflictStrategy::Rename => {
+                let renamed = (2..)
+                    .map(|n| format!("{}-{}", policy_id, n))
+                    .find(|id| !self.taken.contains_key(id))
+                    .expect("a free policy ID");
+                self.create(renamed.clone(), content, &file.name);
+                outcome(renamed, PolicyImportAction::Renamed, Some(conflict))
+            }
+        }
+    }
+
+    fn create(&mut self, id: String, content: String, name: &str) {
+        self.taken.insert(
+            id.clone(),
+            Holder::Batch {
+                name: name.to_string(),
+                write: self.writes.len(),
+            },
+        );
+        self.writes.push(PolicyRecord {
+            id,
+            content,
+            attached_principals: Vec::new(),
+        });
+    }
+}
@@ -0,0 +1,316 @@
+//! Unit tests for import_policies use case
+//!
+//! These tests verify the behavior of the ImportPoliciesUseCase in isolation,
+//! using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::features::export_iam_state::dto::PolicyRecord;
+    use crate::features::import_policies::{
+        dto::{
+            ConflictStrategy, ImportPoliciesCommand, ImportPoliciesReport, PolicyConflict,
+            PolicyFile, PolicyImportAction,
+        },
+        error::ImportPoliciesError,
+        mocks::{MockPolicyImportStorePort, MockPolicyValidator},
+        use_case::ImportPoliciesUseCase,
+    };
+    use hodei_policies::features::validate_policy::to_cedar_syntax;
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    const READ: &str = "permit(principal, action, resource);";
+    const DENY: &str = "forbid(principal, action, resource);";
+
+    /// Policy as the store holds it, in the Cedar syntax policies are rendered to
+    fn stored(id: &str, content: &str, principals: &[&str]) -> PolicyRecord {
+        PolicyRecord {
+            id: id.to_string(),
+            content: to_cedar_syntax(content).unwrap(),
+            attached_principals: principals.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn use_case(store: Arc<MockPolicyImportStorePort>) -> ImportPoliciesUseCase {
+        ImportPoliciesUseCase::new(store, Arc::new(MockPolicyValidator))
+    }
+
+    fn actions(report: &ImportPoliciesReport) -> Vec<(String, PolicyImportAction)> {
+        report
+            .outcomes
+            .iter()
+            .map(|outcome| (outcome.policy_id.clone(), outcome.action))
+            .collect()
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_files_are_imported_under_their_name() {
+        let store = Arc::new(MockPolicyImportStorePort::new());
+
+        let report = use_case(store.clone())
+            .execute(ImportPoliciesCommand::new(vec![
+                PolicyFile::new("policies/read.cedar", READ),
+                PolicyFile::new("deny", DENY),
+            ]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actions(&report),
+            vec![
+                ("read".to_string(), PolicyImportAction::Created),
+                ("deny".to_string(), PolicyImportAction::Created),
+            ]
+        );
+        assert_eq!(
+            store.written(),
+            vec![vec![stored("read", READ, &[]), stored("deny", DENY, &[])]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conflicts_are_skipped_by_default() {
+        let store = Arc::new(
+            MockPolicyImportStorePort::new()
+                .with_policy(stored("read", READ, &[]))
+                .with_policy(stored("deny", READ, &[])),
+        );
+
+        let report = use_case(store.clone())
+            .execute(ImportPoliciesCommand::new(vec![
+                PolicyFile::new("read.cedar", READ),
+                PolicyFile::new("deny.cedar", DENY),
+            ]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actions(&report),
+            vec![
+                ("read".to_string(), PolicyImportAction::Unchanged),
+                ("deny".to_string(), PolicyImportAction::Skipped),
+            ]
+        );
+        assert_eq!(report.outcomes[1].conflict, Some(PolicyConflict::Existing));
+        assert!(!report.has_changes());
+        assert!(store.written().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reformatted_policy_is_unchanged() {
+        let store =
+            Arc::new(MockPolicyImportStorePort::new().with_policy(stored("read", READ, &[])));
+
+        let report = use_case(store.clone())
+            .execute(ImportPoliciesCommand::new(vec![PolicyFile::new(
+                "read.cedar",
+                "// Everyone may read\npermit(\n  principal,\n  action,\n  resource\n);",
+            )]))
+            .await
+            .unwrap();
+
+        assert_eq!(report.count(PolicyImportAction::Unchanged), 1);
+        assert!(store.written().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_overwrite_keeps_the_attachments() {
+        let store = Arc::new(MockPolicyImportStorePort::new().with_policy(stored(
+            "deny",
+            READ,
+            &["hrn:user"],
+        )));
+
+        let report = use_case(store.clone())
+            .execute(
+                ImportPoliciesCommand::new(vec![PolicyFile::new("deny.cedar", DENY)])
+                    .on_conflict(ConflictStrategy::Overwrite),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.count(PolicyImportAction::Overwritten), 1);
+        assert_eq!(
+            store.written(),
+            vec![vec![stored("deny", DENY, &["hrn:user"])]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rename_picks_an_id_free_in_store_and_batch() {
+        let store = Arc::new(
+            MockPolicyImportStorePort::new()
+                .with_policy(stored("read", DENY, &[]))
+                .with_policy(stored("read-2", DENY, &[])),
+        );
+
+        let report = use_case(store.clone())
+            .execute(
+                ImportPoliciesCommand::new(vec![
+                    PolicyFile::new("a/read.cedar", READ),
+                    PolicyFile::new(
+                        "b/read.cedar",
+                        "permit(principal, action, resource) when { true };",
+                    ),
+                ])
+                .on_conflict(ConflictStrategy::Rename),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actions(&report),
+            vec![
+                ("read-3".to_string(), PolicyImportAction::Renamed),
+                ("read-4".to_string(), PolicyImportAction::Renamed),
+            ]
+        );
+        assert_eq!(report.outcomes[0].conflict, Some(PolicyConflict::Existing));
+        assert_eq!(store.written()[0].len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_within_the_batch_are_conflicts() {
+        let store = Arc::new(MockPolicyImportStorePort::new());
+
+        let report = use_case(store.clone())
+            .execute(
+                ImportPoliciesCommand::new(vec![
+                    PolicyFile::new("a/read.cedar", READ),
+                    PolicyFile::new("b/read.cedar", READ),
+                    PolicyFile::new("c/read.cedar", DENY),
+                ])
+                .on_conflict(ConflictStrategy::Overwrite),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actions(&report),
+            vec![
+                ("read".to_string(), PolicyImportAction::Created),
+                ("read".to_string(), PolicyImportAction::Skipped),
+                ("read".to_string(), PolicyImportAction::Overwritten),
+            ]
+        );
+        assert_eq!(
+            report.outcomes[2].conflict,
+            Some(PolicyConflict::Batch {
+                name: "a/read.cedar".to_string()
+            })
+        );
+        assert_eq!(store.written(), vec![vec![stored("read", DENY, &[])]]);
+    }
+
+    #[tokio::test]
+    async fn test_json_policies_are_stored_in_cedar_syntax() {
+        let store = Arc::new(MockPolicyImportStorePort::new());
+        let policy = serde_json::json!({
+            "effect": "permit",
+            "principal": { "op": "All" },
+            "action": { "op": "All" },
+            "resource": { "op": "All" },
+            "conditions": []
+        });
+
+        use_case(store.clone())
+            .execute(ImportPoliciesCommand::new(vec![PolicyFile::new(
+                "open.cedar",
+                policy.to_string(),
+            )]))
+            .await
+            .unwrap();
+
+        let written = &store.written()[0][0];
+        assert!(written.content.starts_with("permit"));
+    }
+
+    #[tokio::test]
+    async fn test_any_invalid_file_rejects_the_whole_import() {
+        let store = Arc::new(MockPolicyImportStorePort::new());
+
+        let result = use_case(store.clone())
+            .execute(ImportPoliciesCommand::new(vec![
+                PolicyFile::new("read.cedar", READ),
+                PolicyFile::new("broken.cedar", "permit(principal"),
+                PolicyFile::new(".cedar", "  "),
+            ]))
+            .await;
+
+        match result {
+            Err(ImportPoliciesError::InvalidPolicies(failures)) => {
+                let names: Vec<_> = failures.iter().map(|f| f.name.as_str()).collect();
+                assert_eq!(names, vec!["broken.cedar", ".cedar"]);
+                assert_eq!(failures[1].errors.len(), 2);
+            }
+            other => panic!("expected InvalidPolicies, got {:?}", other),
+        }
+        assert!(store.written().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_reports_without_writing() {
+        let store =
+            Arc::new(MockPolicyImportStorePort::new().with_policy(stored("read", DENY, &[])));
+
+        let report = use_case(store.clone())
+            .execute(
+                ImportPoliciesCommand::new(vec![
+                    PolicyFile::new("read.cedar", READ),
+                    PolicyFile::new("new.cedar", READ),
+                    PolicyFile::new("broken.cedar", "permit(principal"),
+                ])
+                .on_conflict(ConflictStrategy::Overwrite)
+                .dry_run(),
+            )
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(
+            actions(&report),
+            vec![
+                ("read".to_string(), PolicyImportAction::Overwritten),
+                ("new".to_string(), PolicyImportAction::Created),
+            ]
+        );
+        assert_eq!(report.failures.len(), 1);
+        assert!(store.written().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_write_is_reported() {
+        let store = Arc::new(MockPolicyImportStorePort::failing());
+
+        let result = use_case(store)
+            .execute(ImportPoliciesCommand::new(vec![PolicyFile::new(
+                "read.cedar",
+                READ,
+            )]))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ImportPoliciesError::RepositoryError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_empty_import_is_rejected() {
+        let store = Arc::new(MockPolicyImportStorePort::new());
+
+        let result = use_case(store)
+            .execute(ImportPoliciesCommand::new(vec![]))
+            .await;
+
+        assert!(matches!(result, Err(ImportPoliciesError::NothingToImport)));
+    }
+}
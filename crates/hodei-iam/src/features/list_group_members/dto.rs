@@ -0,0 +1,108 @@
+//! Data Transfer Objects for list_group_members feature
+
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use kernel::{HrnVisitor, Page, PageRequest, PrincipalStatus, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Query for one page of a group's members
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListGroupMembersQuery {
+    /// HRN of the group
+    pub group_hrn: String,
+
+    /// Page to return
+    #[serde(default)]
+    pub page: PageRequest,
+
+    /// Also list the users of groups nested inside this one
+    #[serde(default)]
+    pub transitive: bool,
+
+    /// Resolve each member to a [`GroupMemberSummary`]
+    #[serde(default)]
+    pub include_summaries: bool,
+}
+
+impl ListGroupMembersQuery {
+    /// Query the first page of direct members, without summaries
+    pub fn direct(group_hrn: impl Into<String>, page: PageRequest) -> Self {
+        Self {
+            group_hrn: group_hrn.into(),
+            page,
+            transitive: false,
+            include_summaries: false,
+        }
+    }
+
+    /// Query effective members, including those of nested groups
+    pub fn transitive(group_hrn: impl Into<String>, page: PageRequest) -> Self {
+        Self {
+            transitive: true,
+            ..Self::direct(group_hrn, page)
+        }
+    }
+
+    /// Resolve each member to a summary
+    pub fn with_summaries(mut self) -> Self {
+        self.include_summaries = true;
+        self
+    }
+}
+
+impl TenantScoped for ListGroupMembersQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.group_hrn);
+    }
+}
+
+impl ActionTrait for ListGroupMembersQuery {
+    fn name() -> &'static str {
+        "ListGroupMembers"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("iam").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Iam::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Iam::Group".to_string()
+    }
+}
+
+/// Summary information about a member user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupMemberSummary {
+    pub hrn: String,
+    pub name: String,
+    pub email: String,
+    pub status: PrincipalStatus,
+}
+
+/// A user in the group
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupMember {
+    /// HRN of the member user
+    pub user_hrn: String,
+
+    /// Set when summaries were requested and the user could be resolved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<GroupMemberSummary>,
+}
+
+/// Response for listing group members
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListGroupMembersResponse {
+    /// HRN of the group
+    pub group_hrn: String,
+
+    /// Whether nested groups' users were included
+    pub transitive: bool,
+
+    /// Members on this page, ordered by user HRN
+    pub members: Page<GroupMember>,
+}
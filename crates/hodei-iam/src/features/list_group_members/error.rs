@@ -0,0 +1,24 @@
+use kernel::{CrossTenantAccess, PaginationError};
+use thiserror::Error;
+
+/// Errors that can occur when listing a group's members
+#[derive(Debug, Error)]
+pub enum ListGroupMembersError {
+    #[error("Invalid group HRN: {0}")]
+    InvalidGroupHrn(String),
+
+    #[error("Group not found: {0}")]
+    GroupNotFound(String),
+
+    #[error("Group {group} is more than {max_depth} nesting levels below the listed group")]
+    GroupNestingTooDeep { group: String, max_depth: usize },
+
+    #[error(transparent)]
+    Pagination(#[from] PaginationError),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
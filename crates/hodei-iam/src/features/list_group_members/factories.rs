@@ -0,0 +1,38 @@
+//! Factory for creating the ListGroupMembers use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::list_group_members::ports::{
+    GroupMemberFinderPort, ListGroupMembersUseCasePort,
+};
+use crate::features::list_group_members::use_case::ListGroupMembersUseCase;
+
+/// Create the ListGroupMembers use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `finder` - Port for finding group members
+///
+/// # Returns
+///
+/// Arc<dyn ListGroupMembersUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let group_repo = Arc::new(SurrealGroupAdapter::new(db));
+///
+/// let list_group_members = create_list_group_members_use_case(group_repo);
+/// ```
+pub fn create_list_group_members_use_case(
+    finder: Arc<dyn GroupMemberFinderPort>,
+) -> Arc<dyn ListGroupMembersUseCasePort> {
+    info!("Creating ListGroupMembers use case");
+    Arc::new(ListGroupMembersUseCase::new(finder))
+}
@@ -0,0 +1,101 @@
+//! Mock implementations for testing List Group Members feature
+
+use async_trait::async_trait;
+use kernel::{Hrn, PrincipalStatus};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::dto::GroupMemberSummary;
+use super::error::ListGroupMembersError;
+use super::ports::GroupMemberFinderPort;
+
+/// Mock GroupMemberFinderPort for testing
+///
+/// Records which users summaries were requested for, so tests can check that
+/// only the returned page is resolved.
+pub struct MockGroupMemberFinderPort {
+    users: HashMap<Hrn, Vec<Hrn>>,
+    children: HashMap<Hrn, Vec<Hrn>>,
+    summaries: HashMap<Hrn, GroupMemberSummary>,
+    summary_requests: Mutex<Vec<Hrn>>,
+    should_fail: bool,
+}
+
+impl MockGroupMemberFinderPort {
+    /// Create a mock with no groups
+    pub fn new() -> Self {
+        Self {
+            users: HashMap::new(),
+            children: HashMap::new(),
+            summaries: HashMap::new(),
+            summary_requests: Mutex::new(Vec::new()),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose member lookups fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a group with the given users and nested groups
+    pub fn with_group(mut self, group_hrn: Hrn, users: Vec<Hrn>, children: Vec<Hrn>) -> Self {
+        self.users.insert(group_hrn.clone(), users);
+        self.children.insert(group_hrn, children);
+        self
+    }
+
+    /// Make `user_hrn` resolvable to a summary
+    pub fn with_user(mut self, user_hrn: Hrn, name: &str) -> Self {
+        let summary = GroupMemberSummary {
+            hrn: user_hrn.to_string(),
+            name: name.to_string(),
+            email: format!("{}@example.com", name.to_lowercase()),
+            status: PrincipalStatus::Active,
+        };
+        self.summaries.insert(user_hrn, summary);
+        self
+    }
+
+    /// Users whose summaries were requested, in request order
+    pub fn summary_requests(&self) -> Vec<Hrn> {
+        self.summary_requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl GroupMemberFinderPort for MockGroupMemberFinderPort {
+    async fn group_exists(&self, group_hrn: &Hrn) -> Result<bool, ListGroupMembersError> {
+        Ok(self.users.contains_key(group_hrn))
+    }
+
+    async fn find_user_members(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError> {
+        if self.should_fail {
+            return Err(ListGroupMembersError::RepositoryError(
+                "Mock failure".to_string(),
+            ));
+        }
+        Ok(self.users.get(group_hrn).cloned().unwrap_or_default())
+    }
+
+    async fn find_child_groups(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError> {
+        Ok(self.children.get(group_hrn).cloned().unwrap_or_default())
+    }
+
+    async fn find_member_summaries(
+        &self,
+        user_hrns: &[Hrn],
+    ) -> Result<Vec<GroupMemberSummary>, ListGroupMembersError> {
+        self.summary_requests
+            .lock()
+            .unwrap()
+            .extend_from_slice(user_hrns);
+        Ok(user_hrns
+            .iter()
+            .filter_map(|hrn| self.summaries.get(hrn).cloned())
+            .collect())
+    }
+}
@@ -0,0 +1,33 @@
+//! list_group_members Feature (Vertical Slice)
+//!
+//! This module implements listing the users in a group following VSA, e.g.
+//! for access reviews. Members are paged with the kernel's `PageRequest`,
+//! ordered by user HRN, and can be listed in two modes:
+//!
+//! - direct: users added to the group itself
+//! - transitive: the group's effective members, i.e. also the users of every
+//!   group nested inside it, each listed once however many paths reach them
+//!
+//! Structure:
+//! - dto.rs              -> Query & Response DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interface (ISP)
+//! - use_case.rs         -> Core business logic (ListGroupMembersUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{GroupMember, GroupMemberSummary, ListGroupMembersQuery, ListGroupMembersResponse};
+pub use error::ListGroupMembersError;
+pub use ports::{GroupMemberFinderPort, ListGroupMembersUseCasePort};
+pub use use_case::ListGroupMembersUseCase;
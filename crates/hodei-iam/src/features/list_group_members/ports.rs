@@ -0,0 +1,78 @@
+use super::dto::{GroupMemberSummary, ListGroupMembersQuery, ListGroupMembersResponse};
+use super::error::ListGroupMembersError;
+use async_trait::async_trait;
+use kernel::Hrn;
+
+/// Port for finding the members of a group
+///
+/// This port abstracts membership lookup in the member-to-group direction
+/// the domain stores it in: users and nested groups record the groups they
+/// belong to.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the list_group_members feature.
+#[async_trait]
+pub trait GroupMemberFinderPort: Send + Sync {
+    /// Check whether a group exists
+    ///
+    /// # Arguments
+    /// * `group_hrn` - The HRN of the group
+    ///
+    /// # Returns
+    /// * `Ok(true)` if the group exists, `Ok(false)` otherwise
+    /// * `Err(ListGroupMembersError)` if there was an error during lookup
+    async fn group_exists(&self, group_hrn: &Hrn) -> Result<bool, ListGroupMembersError>;
+
+    /// Find the users that are direct members of a group
+    ///
+    /// # Arguments
+    /// * `group_hrn` - The HRN of the group
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Hrn>)` with the member users' HRNs, in any order
+    /// * `Err(ListGroupMembersError)` if there was an error during lookup
+    async fn find_user_members(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError>;
+
+    /// Find the groups nested directly inside a group
+    ///
+    /// # Arguments
+    /// * `group_hrn` - The HRN of the parent group
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Hrn>)` with the child groups' HRNs, in any order
+    /// * `Err(ListGroupMembersError)` if there was an error during lookup
+    async fn find_child_groups(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError>;
+
+    /// Resolve users to summaries
+    ///
+    /// # Arguments
+    /// * `user_hrns` - The HRNs of the users
+    ///
+    /// # Returns
+    /// * `Ok(Vec<GroupMemberSummary>)` for the users that exist, in any order
+    /// * `Err(ListGroupMembersError)` if there was an error during lookup
+    async fn find_member_summaries(
+        &self,
+        user_hrns: &[Hrn],
+    ) -> Result<Vec<GroupMemberSummary>, ListGroupMembersError>;
+}
+
+/// Port for the ListGroupMembers use case
+///
+/// This port defines the contract for executing the list group members use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait ListGroupMembersUseCasePort: Send + Sync {
+    /// Execute the list group members use case
+    ///
+    /// # Arguments
+    /// * `query` - The group HRN, page request and listing mode
+    ///
+    /// # Returns
+    /// * `Ok(ListGroupMembersResponse)` with one page of members
+    /// * `Err(ListGroupMembersError)` if the members could not be listed
+    async fn execute(
+        &self,
+        query: ListGroupMembersQuery,
+    ) -> Result<ListGroupMembersResponse, ListGroupMembersError>;
+}
@@ -0,0 +1,180 @@
+use super::dto::{GroupMember, ListGroupMembersQuery, ListGroupMembersResponse};
+use super::error::ListGroupMembersError;
+use super::ports::{GroupMemberFinderPort, ListGroupMembersUseCasePort};
+use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
+use async_trait::async_trait;
+use kernel::{Hrn, Page, TenantContext};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, instrument, warn};
+
+/// Use case for listing the users in a group
+///
+/// This use case orchestrates the listing:
+/// 1. Validates and parses the group HRN
+/// 2. Checks that the group exists
+/// 3. Collects the member users, walking down nested groups in transitive mode
+/// 4. Pages the members, ordered by user HRN
+/// 5. Resolves summaries for the page, if requested
+///
+/// Paging uses key cursors, so a member added or removed between two
+/// requests does not shift the following pages.
+pub struct ListGroupMembersUseCase {
+    finder: Arc<dyn GroupMemberFinderPort>,
+}
+
+impl ListGroupMembersUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `finder` - Implementation of GroupMemberFinderPort for membership lookup
+    pub fn new(finder: Arc<dyn GroupMemberFinderPort>) -> Self {
+        Self { finder }
+    }
+
+    /// Execute the list group members use case
+    ///
+    /// # Arguments
+    /// * `query` - ListGroupMembersQuery with the group HRN, page and mode
+    ///
+    /// # Returns
+    /// * Ok(ListGroupMembersResponse) with one page of members
+    /// * Err(ListGroupMembersError) if there was an error
+    #[instrument(name = "list_group_members", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        group = %query.group_hrn
+    ))]
+    pub async fn execute(
+        &self,
+        query: ListGroupMembersQuery,
+    ) -> Result<ListGroupMembersResponse, ListGroupMembersError> {
+        let group_hrn = Hrn::from_string(&query.group_hrn)
+            .ok_or_else(|| ListGroupMembersError::InvalidGroupHrn(query.group_hrn.clone()))?;
+
+        if !self.finder.group_exists(&group_hrn).await? {
+            return Err(ListGroupMembersError::GroupNotFound(query.group_hrn));
+        }
+
+        let members = if query.transitive {
+            self.effective_members(&group_hrn).await?
+        } else {
+            self.direct_members(&group_hrn).await?
+        };
+        debug!(
+            group = %query.group_hrn,
+            transitive = query.transitive,
+            member_count = members.len(),
+            "Collected group members"
+        );
+
+        let user_hrns: Vec<String> = members.keys().cloned().collect();
+        let page = Page::from_slice(&user_hrns, &query.page, |hrn| hrn.clone())?;
+
+        let mut summaries = HashMap::new();
+        if query.include_summaries {
+            let page_hrns: Vec<Hrn> = page
+                .items
+                .iter()
+                .filter_map(|hrn| members.get(hrn).cloned())
+                .collect();
+            summaries = self
+                .finder
+                .find_member_summaries(&page_hrns)
+                .await?
+                .into_iter()
+                .map(|summary| (summary.hrn.clone(), summary))
+                .collect();
+        }
+
+        Ok(ListGroupMembersResponse {
+            group_hrn: query.group_hrn,
+            transitive: query.transitive,
+            members: page.map(|user_hrn| GroupMember {
+                summary: summaries.remove(&user_hrn),
+                user_hrn,
+            }),
+        })
+    }
+
+    /// The group's own users, keyed and ordered by HRN
+    async fn direct_members(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<BTreeMap<String, Hrn>, ListGroupMembersError> {
+        let users = self.finder.find_user_members(group_hrn).await?;
+        Ok(users
+            .into_iter()
+            .map(|hrn| (hrn.to_string(), hrn))
+            .collect())
+    }
+
+    /// The users of the group and of every group nested inside it, walking
+    /// down one level at a time
+    ///
+    /// Each nested group is visited once, so a group reachable through
+    /// several paths (or through a cycle in stored data) is only expanded
+    /// once, and the map keeps each user once.
+    async fn effective_members(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<BTreeMap<String, Hrn>, ListGroupMembersError> {
+        let mut members = BTreeMap::new();
+        let mut visited: HashSet<Hrn> = HashSet::from([group_hrn.clone()]);
+        let mut level = vec![group_hrn.clone()];
+        let mut depth = 1;
+
+        while !level.is_empty() {
+            let mut next_level = Vec::new();
+            for group in &level {
+                members.extend(self.direct_members(group).await?);
+                let children = self.finder.find_child_groups(group).await?;
+                next_level.extend(
+                    children
+                        .into_iter()
+                        .filter(|hrn| visited.insert(hrn.clone())),
+                );
+            }
+
+            if depth == MAX_GROUP_NESTING_DEPTH && !next_level.is_empty() {
+                let too_deep = &next_level[0];
+                warn!(
+                    group = %group_hrn,
+                    nested = %too_deep,
+                    max_depth = MAX_GROUP_NESTING_DEPTH,
+                    "Group nesting is too deep"
+                );
+                return Err(ListGroupMembersError::GroupNestingTooDeep {
+                    group: too_deep.to_string(),
+                    max_depth: MAX_GROUP_NESTING_DEPTH,
+                });
+            }
+
+            level = next_level;
+            depth += 1;
+        }
+
+        Ok(members)
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The group must belong to the tenant, otherwise the query fails with
+    /// `CrossTenantAccess` before any lookup.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: ListGroupMembersQuery,
+    ) -> Result<ListGroupMembersResponse, ListGroupMembersError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
+}
+
+#[async_trait]
+impl ListGroupMembersUseCasePort for ListGroupMembersUseCase {
+    async fn execute(
+        &self,
+        query: ListGroupMembersQuery,
+    ) -> Result<ListGroupMembersResponse, ListGroupMembersError> {
+        self.execute(query).await
+    }
+}
@@ -0,0 +1,301 @@
+//! Unit tests for list_group_members use case
+//!
+//! These tests verify the behavior of the ListGroupMembersUseCase in isolation,
+//! using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use kernel::{Hrn, PageRequest, TenantContext};
+
+    use crate::features::list_group_members::{
+        dto::{ListGroupMembersQuery, ListGroupMembersResponse},
+        error::ListGroupMembersError,
+        mocks::MockGroupMemberFinderPort,
+        use_case::ListGroupMembersUseCase,
+    };
+    use crate::internal::domain::group::MAX_GROUP_NESTING_DEPTH;
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn iam(resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn user(id: &str) -> Hrn {
+        iam("User", id)
+    }
+
+    fn group(id: &str) -> Hrn {
+        iam("Group", id)
+    }
+
+    /// engineering has carol and nests backend (alice, bob) and frontend
+    /// (bob, dave); bob is reachable through both
+    fn org() -> MockGroupMemberFinderPort {
+        MockGroupMemberFinderPort::new()
+            .with_group(
+                group("engineering"),
+                vec![user("carol")],
+                vec![group("backend"), group("frontend")],
+            )
+            .with_group(group("backend"), vec![user("bob"), user("alice")], vec![])
+            .with_group(group("frontend"), vec![user("dave"), user("bob")], vec![])
+    }
+
+    fn member_ids(response: &ListGroupMembersResponse) -> Vec<String> {
+        response
+            .members
+            .items
+            .iter()
+            .map(|member| {
+                Hrn::from_string(&member.user_hrn)
+                    .unwrap()
+                    .resource_id()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_direct_mode_lists_only_the_groups_own_users() {
+        let use_case = ListGroupMembersUseCase::new(Arc::new(org()));
+
+        let response = use_case
+            .execute(ListGroupMembersQuery::direct(
+                group("engineering").to_string(),
+                PageRequest::default(),
+            ))
+            .await
+            .unwrap();
+
+        assert!(!response.transitive);
+        assert_eq!(member_ids(&response), ["carol"]);
+        assert_eq!(response.members.total, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_transitive_mode_lists_nested_users_once() {
+        let use_case = ListGroupMembersUseCase::new(Arc::new(org()));
+
+        let response = use_case
+            .execute(ListGroupMembersQuery::transitive(
+                group("engineering").to_string(),
+                PageRequest::default(),
+            ))
+            .await
+            .unwrap();
+
+        assert!(response.transitive);
+        assert_eq!(member_ids(&response), ["alice", "bob", "carol", "dave"]);
+        assert_eq!(response.members.total, Some(4));
+        assert!(!response.members.has_more());
+    }
+
+    #[tokio::test]
+    async fn test_pages_follow_the_cursor() {
+        let use_case = ListGroupMembersUseCase::new(Arc::new(org()));
+        let query =
+            |page| ListGroupMembersQuery::transitive(group("engineering").to_string(), page);
+
+        let first = use_case
+            .execute(query(PageRequest::first(3)))
+            .await
+            .unwrap();
+        assert_eq!(member_ids(&first), ["alice", "bob", "carol"]);
+
+        let cursor = first.members.next_cursor.clone().unwrap();
+        let second = use_case
+            .execute(query(PageRequest::after(3, cursor)))
+            .await
+            .unwrap();
+        assert_eq!(member_ids(&second), ["dave"]);
+        assert!(!second.members.has_more());
+    }
+
+    #[tokio::test]
+    async fn test_summaries_are_resolved_for_the_page_only() {
+        let finder = Arc::new(org().with_user(user("alice"), "Alice"));
+        let use_case = ListGroupMembersUseCase::new(finder.clone());
+
+        let response = use_case
+            .execute(
+                ListGroupMembersQuery::transitive(
+                    group("engineering").to_string(),
+                    PageRequest::first(2),
+                )
+                .with_summaries(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(finder.summary_requests(), [user("alice"), user("bob")]);
+        let alice = response.members.items[0].summary.as_ref().unwrap();
+        assert_eq!(alice.name, "Alice");
+        // bob has no user record to summarize
+        assert!(response.members.items[1].summary.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_no_summaries_unless_requested() {
+        let finder = Arc::new(org().with_user(user("carol"), "Carol"));
+        let use_case = ListGroupMembersUseCase::new(finder.clone());
+
+        let response = use_case
+            .execute(ListGroupMembersQuery::direct(
+                group("engineering").to_string(),
+                PageRequest::default(),
+            ))
+            .await
+            .unwrap();
+
+        assert!(finder.summary_requests().is_empty());
+        assert!(response.members.items[0].summary.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_group_is_not_found() {
+        let use_case = ListGroupMembersUseCase::new(Arc::new(org()));
+
+        let result = use_case
+            .execute(ListGroupMembersQuery::direct(
+                group("missing").to_string(),
+                PageRequest::default(),
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ListGroupMembersError::GroupNotFound(hrn)) if hrn == group("missing").to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stored_cycle_terminates() {
+        let finder = MockGroupMemberFinderPort::new()
+            .with_group(group("a"), vec![user("alice")], vec![group("b")])
+            .with_group(group("b"), vec![user("bob")], vec![group("a")]);
+        let use_case = ListGroupMembersUseCase::new(Arc::new(finder));
+
+        let response = use_case
+            .execute(ListGroupMembersQuery::transitive(
+                group("a").to_string(),
+                PageRequest::default(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(member_ids(&response), ["alice", "bob"]);
+    }
+
+    #[tokio::test]
+    async fn test_nesting_beyond_the_depth_limit_is_rejected() {
+        // g1 nests g2, ... down to g{MAX + 1}
+        let mut finder = MockGroupMemberFinderPort::new();
+        for level in 1..=MAX_GROUP_NESTING_DEPTH + 1 {
+            let children = if level <= MAX_GROUP_NESTING_DEPTH {
+                vec![group(&format!("g{}", level + 1))]
+            } else {
+                vec![]
+            };
+            finder = finder.with_group(group(&format!("g{level}")), vec![], children);
+        }
+        let use_case = ListGroupMembersUseCase::new(Arc::new(finder));
+
+        let result = use_case
+            .execute(ListGroupMembersQuery::transitive(
+                group("g1").to_string(),
+                PageRequest::default(),
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ListGroupMembersError::GroupNestingTooDeep { max_depth, .. })
+                if max_depth == MAX_GROUP_NESTING_DEPTH
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_cursor_is_rejected() {
+        let use_case = ListGroupMembersUseCase::new(Arc::new(org()));
+
+        let result = use_case
+            .execute(ListGroupMembersQuery::direct(
+                group("engineering").to_string(),
+                PageRequest::after(10, "not a cursor"),
+            ))
+            .await;
+
+        assert!(matches!(result, Err(ListGroupMembersError::Pagination(_))));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_group_hrn() {
+        let use_case = ListGroupMembersUseCase::new(Arc::new(org()));
+
+        let result = use_case
+            .execute(ListGroupMembersQuery::direct(
+                "not-an-hrn",
+                PageRequest::default(),
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ListGroupMembersError::InvalidGroupHrn(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_repository_error() {
+        let finder = MockGroupMemberFinderPort::failing().with_group(group("devs"), vec![], vec![]);
+        let use_case = ListGroupMembersUseCase::new(Arc::new(finder));
+
+        let result = use_case
+            .execute(ListGroupMembersQuery::direct(
+                group("devs").to_string(),
+                PageRequest::default(),
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ListGroupMembersError::RepositoryError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_for_tenant_rejects_other_tenants_groups() {
+        let use_case = ListGroupMembersUseCase::new(Arc::new(org()));
+        let tenant = TenantContext::new("other-account");
+
+        let result = use_case
+            .execute_for_tenant(
+                &tenant,
+                ListGroupMembersQuery::direct(
+                    group("engineering").to_string(),
+                    PageRequest::default(),
+                ),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ListGroupMembersError::CrossTenantAccess(_))
+        ));
+    }
+}
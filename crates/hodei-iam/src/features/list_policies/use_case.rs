@@ -63,7 +63,11 @@ impl ListPoliciesUseCase {
     /// - `ListPoliciesError::InvalidPagination` - Invalid pagination parameters
     /// - `ListPoliciesError::RepositoryError` - Database or storage failure
     /// - `ListPoliciesError::InternalError` - Unexpected error
-    #[instrument(skip(self), fields(limit = ?query.limit, offset = ?query.offset))]
+    #[instrument(name = "list_policies", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        limit = ?query.limit,
+        offset = ?query.offset
+    ))]
     pub async fn execute(
         &self,
         query: ListPoliciesQuery,
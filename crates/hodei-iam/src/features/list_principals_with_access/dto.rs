@@ -0,0 +1,121 @@
+//! Data Transfer Objects for list_principals_with_access feature
+
+use chrono::{DateTime, Utc};
+use kernel::{HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Query for the principals who can perform an action on a resource
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListPrincipalsWithAccessQuery {
+    /// HRN of the resource
+    pub resource_hrn: String,
+
+    /// Action, as an action UID (`S3::Action::"s3:Get"`) or an action ID
+    /// (`s3:Get`) to match it in any namespace
+    pub action: String,
+
+    /// HRNs of the entities containing the resource, so policies granting
+    /// access to one of its containers are taken into account
+    #[serde(default)]
+    pub resource_ancestors: Vec<String>,
+}
+
+impl ListPrincipalsWithAccessQuery {
+    pub fn new(resource_hrn: impl Into<String>, action: impl Into<String>) -> Self {
+        Self {
+            resource_hrn: resource_hrn.into(),
+            action: action.into(),
+            resource_ancestors: Vec::new(),
+        }
+    }
+
+    pub fn with_ancestors(mut self, ancestors: impl IntoIterator<Item = String>) -> Self {
+        self.resource_ancestors.extend(ancestors);
+        self
+    }
+}
+
+impl TenantScoped for ListPrincipalsWithAccessQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.resource_hrn);
+        for ancestor in &self.resource_ancestors {
+            visitor.hrn_str(ancestor);
+        }
+    }
+}
+
+/// How a principal can access the resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessLevel {
+    /// A policy permits the access unconditionally and none forbids it
+    Granted,
+    /// The access depends on conditions the index can't evaluate (request
+    /// context, attributes), either of a permit or of a forbid
+    Conditional,
+}
+
+/// A user who can access the resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrincipalAccess {
+    pub principal_hrn: String,
+    pub access: AccessLevel,
+    /// IDs of the policies permitting the access, directly or through groups
+    pub policy_ids: Vec<String>,
+}
+
+/// Whether the index is up to date with the events published so far
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndexStatus {
+    /// Every stored event has been applied
+    Current,
+    /// Stored events are still waiting to be applied
+    Lagging,
+    /// The index has no event store to compare against
+    Unverified,
+}
+
+/// Freshness of the access index at the time of a query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexFreshness {
+    pub status: IndexStatus,
+    /// When the newest event applied to the index occurred
+    pub as_of: Option<DateTime<Utc>>,
+    /// When the index was last rebuilt from the event store
+    pub rebuilt_at: Option<DateTime<Utc>>,
+    /// Stored events the index has not applied yet
+    pub pending_events: usize,
+}
+
+/// Principals who can access a resource, with the freshness of the answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListPrincipalsWithAccessResponse {
+    pub resource_hrn: String,
+    pub action: String,
+    /// Users who can access the resource, ordered by HRN
+    pub principals: Vec<PrincipalAccess>,
+    pub freshness: IndexFreshness,
+}
+
+/// Outcome of rebuilding or catching up the index from the event store
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessIndexReplay {
+    /// Stored events read
+    pub processed: usize,
+    /// Events applied to the index
+    pub applied: usize,
+    /// Events of the index's types whose payload could not be read
+    pub failed: usize,
+}
+
+// ============================================================================
+// Events the index is built from
+// ============================================================================
+
+/// Policy events, shared with the other contexts that react to policy changes
+pub use kernel::{PolicyAttachmentChanged, PolicyDeleted, PolicyStored};
+
+/// Event published when a user or group joins or leaves a group, shared with
+/// the other contexts that react to membership changes
+pub use kernel::GroupMembershipChanged;
@@ -0,0 +1,18 @@
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+/// Errors that can occur when listing the principals with access to a resource
+#[derive(Debug, Error)]
+pub enum ListPrincipalsWithAccessError {
+    #[error("Invalid resource HRN: {0}")]
+    InvalidResourceHrn(String),
+
+    #[error("Invalid action: {0}")]
+    InvalidAction(String),
+
+    #[error("Access index error: {0}")]
+    IndexError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
@@ -0,0 +1,68 @@
+//! Factory for creating the ListPrincipalsWithAccess use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+
+use kernel::application::ports::event_bus::{EventBus, Subscription};
+use tracing::info;
+
+use crate::features::list_principals_with_access::dto::{
+    GroupMembershipChanged, PolicyAttachmentChanged, PolicyDeleted, PolicyStored,
+};
+use crate::features::list_principals_with_access::ports::{
+    AccessIndexPort, ListPrincipalsWithAccessUseCasePort,
+};
+use crate::features::list_principals_with_access::projection::AccessIndexProjection;
+use crate::features::list_principals_with_access::use_case::ListPrincipalsWithAccessUseCase;
+use crate::features::revalidate_policies::dto::PolicyDisabled;
+
+/// Create the ListPrincipalsWithAccess use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `index` - The access index to answer from
+///
+/// # Returns
+///
+/// Arc<dyn ListPrincipalsWithAccessUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let index = Arc::new(AccessIndexProjection::new().with_event_store(store));
+/// let _subscriptions = subscribe_access_index(bus.as_ref(), index.clone()).await?;
+/// index.rebuild().await?;
+///
+/// let list_principals_with_access = create_list_principals_with_access_use_case(index);
+/// ```
+pub fn create_list_principals_with_access_use_case(
+    index: Arc<dyn AccessIndexPort>,
+) -> Arc<dyn ListPrincipalsWithAccessUseCasePort> {
+    info!("Creating ListPrincipalsWithAccess use case");
+    Arc::new(ListPrincipalsWithAccessUseCase::new(index))
+}
+
+/// Subscribe the projection to every event the access index is built from
+///
+/// Subscribe before rebuilding, so events published during the rebuild are
+/// not missed.
+pub async fn subscribe_access_index<B: EventBus>(
+    bus: &B,
+    projection: Arc<AccessIndexProjection>,
+) -> anyhow::Result<Vec<Arc<dyn Subscription>>> {
+    Ok(vec![
+        bus.subscribe::<PolicyStored, _>(projection.clone()).await?,
+        bus.subscribe::<PolicyDeleted, _>(projection.clone())
+            .await?,
+        bus.subscribe::<PolicyDisabled, _>(projection.clone())
+            .await?,
+        bus.subscribe::<PolicyAttachmentChanged, _>(projection.clone())
+            .await?,
+        bus.subscribe::<GroupMembershipChanged, _>(projection)
+            .await?,
+    ])
+}
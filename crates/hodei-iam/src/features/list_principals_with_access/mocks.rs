@@ -0,0 +1,58 @@
+//! Mock implementations for testing List Principals With Access feature
+
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::grant::AccessRequest;
+use std::sync::Mutex;
+
+use super::dto::{IndexFreshness, IndexStatus, PrincipalAccess};
+use super::error::ListPrincipalsWithAccessError;
+use super::ports::AccessIndexPort;
+
+/// Mock AccessIndexPort for testing
+///
+/// Answers every request with the same principals and records the requests.
+pub struct MockAccessIndexPort {
+    principals: Vec<PrincipalAccess>,
+    freshness: IndexFreshness,
+    requests: Mutex<Vec<AccessRequest>>,
+}
+
+impl MockAccessIndexPort {
+    pub fn new(principals: Vec<PrincipalAccess>) -> Self {
+        Self {
+            principals,
+            freshness: IndexFreshness {
+                status: IndexStatus::Current,
+                as_of: None,
+                rebuilt_at: None,
+                pending_events: 0,
+            },
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn with_freshness(mut self, freshness: IndexFreshness) -> Self {
+        self.freshness = freshness;
+        self
+    }
+
+    /// Number of requests the index was asked about
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+}
+
+#[async_trait]
+impl AccessIndexPort for MockAccessIndexPort {
+    async fn principals_with_access(
+        &self,
+        request: &AccessRequest,
+    ) -> Result<Vec<PrincipalAccess>, ListPrincipalsWithAccessError> {
+        self.requests.lock().unwrap().push(request.clone());
+        Ok(self.principals.clone())
+    }
+
+    async fn freshness(&self) -> Result<IndexFreshness, ListPrincipalsWithAccessError> {
+        Ok(self.freshness.clone())
+    }
+}
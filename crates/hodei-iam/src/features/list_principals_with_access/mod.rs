@@ -0,0 +1,41 @@
+//! list_principals_with_access Feature (Vertical Slice)
+//!
+//! This module answers "who can perform this action on this resource"
+//! following VSA, from a read model instead of the authorizer:
+//!
+//! - the access index is a projection maintained from policy, attachment
+//!   and membership events
+//! - it can be rebuilt from scratch by replaying the event store
+//! - every answer says how fresh the index is, so callers can tell when it
+//!   lags behind live state
+//!
+//! Structure:
+//! - dto.rs              -> Query, Response, freshness & the events the index is built from
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interfaces (ISP)
+//! - projection.rs       -> The access index, fed by events and rebuilt by replay
+//! - use_case.rs         -> Core business logic (ListPrincipalsWithAccessUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod projection;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{
+    AccessIndexReplay, AccessLevel, GroupMembershipChanged, IndexFreshness, IndexStatus,
+    ListPrincipalsWithAccessQuery, ListPrincipalsWithAccessResponse, PolicyAttachmentChanged,
+    PolicyDeleted, PolicyStored, PrincipalAccess,
+};
+pub use error::ListPrincipalsWithAccessError;
+pub use ports::{AccessIndexPort, ListPrincipalsWithAccessUseCasePort};
+pub use projection::AccessIndexProjection;
+pub use use_case::ListPrincipalsWithAccessUseCase;
@@ -0,0 +1,42 @@
+use super::dto::{
+    IndexFreshness, ListPrincipalsWithAccessQuery, ListPrincipalsWithAccessResponse,
+    PrincipalAccess,
+};
+use super::error::ListPrincipalsWithAccessError;
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::grant::AccessRequest;
+
+/// Port for the precomputed access index
+///
+/// This port abstracts the read model the query is answered from.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the list_principals_with_access feature.
+#[async_trait]
+pub trait AccessIndexPort: Send + Sync {
+    /// Users who can perform `request`, ordered by HRN
+    async fn principals_with_access(
+        &self,
+        request: &AccessRequest,
+    ) -> Result<Vec<PrincipalAccess>, ListPrincipalsWithAccessError>;
+
+    /// How up to date the index is with the events published so far
+    async fn freshness(&self) -> Result<IndexFreshness, ListPrincipalsWithAccessError>;
+}
+
+/// Port for the ListPrincipalsWithAccess use case
+///
+/// This port defines the contract for executing the list principals with access use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait ListPrincipalsWithAccessUseCasePort: Send + Sync {
+    /// Execute the list principals with access use case
+    ///
+    /// # Returns
+    /// * `Ok(ListPrincipalsWithAccessResponse)` with the principals and the index freshness
+    /// * `Err(ListPrincipalsWithAccessError)` if the query is invalid or the index unreadable
+    async fn execute(
+        &self,
+        query: ListPrincipalsWithAccessQuery,
+    ) -> Result<ListPrincipalsWithAccessResponse, ListPrincipalsWithAccessError>;
+}
@@ -0,0 +1,828 @@
+//! Access index projection
+//!
+//! [`AccessIndexProjection`] keeps a read model of who can access what,
+//! built from IAM events rather than from the repositories: policy content
+//! ([`PolicyStored`], [`PolicyDeleted`], [`PolicyDisabled`]), policy
+//! attachments ([`PolicyAttachmentChanged`]) and group memberships
+//! ([`GroupMembershipChanged`]). Each policy is parsed once into a
+//! [`PolicyGrant`] when it is stored, so answering a query only walks the
+//! grants and the membership graph, without calling the authorizer.
+//!
+//! The projection is subscribed to the event bus for those events and
+//! updates incrementally. With an event store attached it can also:
+//!
+//! - be rebuilt from scratch by replaying the store ([`AccessIndexProjection::rebuild`]),
+//!   which repairs an index that drifted, e.g. because the bus dropped events
+//! - catch up with stored events it missed ([`AccessIndexProjection::catch_up`])
+//! - report how many stored events it has not applied yet, so queries can
+//!   say whether the index is lagging
+//!
+//! Applying an event twice leaves the index as it was, so an event that is
+//! both delivered live and replayed is harmless.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hodei_policies::features::match_policy::dto::PolicyEffect;
+use hodei_policies::features::match_policy::grant::{AccessRequest, GrantCoverage, PolicyGrant};
+use kernel::Hrn;
+use kernel::application::ports::event_bus::{EventEnvelope, EventHandler};
+use kernel::application::ports::event_store::{EventStore, EventStoreError, StoredEvent};
+use serde::de::DeserializeOwned;
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use super::dto::{
+    AccessIndexReplay, AccessLevel, GroupMembershipChanged, IndexFreshness, IndexStatus,
+    PolicyAttachmentChanged, PolicyDeleted, PolicyStored, PrincipalAccess,
+};
+use super::error::ListPrincipalsWithAccessError;
+use super::ports::AccessIndexPort;
+use crate::features::revalidate_policies::dto::PolicyDisabled;
+
+/// Stored events read per batch by default
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// An event the index is built from
+#[derive(Debug, Clone)]
+enum IndexEvent {
+    Stored(PolicyStored),
+    Deleted(PolicyDeleted),
+    Disabled(PolicyDisabled),
+    Attachment(PolicyAttachmentChanged),
+    Membership(GroupMembershipChanged),
+}
+
+impl IndexEvent {
+    const EVENT_TYPES: [&'static str; 5] = [
+        "iam.policy.stored",
+        "iam.policy.deleted",
+        "iam.policy.disabled",
+        "iam.policy.attachment_changed",
+        "iam.group.membership_changed",
+    ];
+
+    fn is_index_event(event_type: &str) -> bool {
+        Self::EVENT_TYPES.contains(&event_type)
+    }
+
+    /// Read a stored event back, if it is one the index is built from
+    fn from_stored(event: &StoredEvent) -> Option<Result<Self, String>> {
+        fn read<E: DeserializeOwned>(event: &StoredEvent) -> Result<E, String> {
+            let payload = event.payload_json().map_err(|e| e.to_string())?;
+            serde_json::from_value(payload).map_err(|e| e.to_string())
+        }
+
+        Some(match event.event_type.as_str() {
+            "iam.policy.stored" => read(event).map(Self::Stored),
+            "iam.policy.deleted" => read(event).map(Self::Deleted),
+            "iam.policy.disabled" => read(event).map(Self::Disabled),
+            "iam.policy.attachment_changed" => read(event).map(Self::Attachment),
+            "iam.group.membership_changed" => read(event).map(Self::Membership),
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct IndexedPolicy {
+    /// `None` if the content could not be parsed; such a policy grants nothing
+    grant: Option<PolicyGrant>,
+    disabled: bool,
+}
+
+#[derive(Debug, Default)]
+struct IndexState {
+    policies: HashMap<String, IndexedPolicy>,
+    /// Principals each policy is attached to
+    attachments: HashMap<String, HashSet<Hrn>>,
+    /// Groups each user or group is a direct member of
+    memberships: HashMap<Hrn, HashSet<Hrn>>,
+    as_of: Option<DateTime<Utc>>,
+    rebuilt_at: Option<DateTime<Utc>>,
+    /// Stored events accounted for; the index reflects every event before it
+    position: usize,
+    /// Events applied live that come after `position` in the store
+    applied_ahead: HashSet<Uuid>,
+}
+
+impl IndexState {
+    fn apply(&mut self, event: IndexEvent, occurred_at: DateTime<Utc>) {
+        match event {
+            IndexEvent::Stored(stored) => {
+                let grant = PolicyGrant::from_policy(&stored.content)
+                    .inspect_err(|e| {
+                        warn!(policy_id = %stored.policy_id, error = %e, "Indexed policy grants nothing, its content could not be parsed");
+                    })
+                    .ok();
+                self.policies.insert(
+                    stored.policy_id,
+                    IndexedPolicy {
+                        grant,
+                        disabled: false,
+                    },
+                );
+            }
+            IndexEvent::Deleted(deleted) => {
+                self.policies.remove(&deleted.policy_id);
+                self.attachments.remove(&deleted.policy_id);
+            }
+            IndexEvent::Disabled(disabled) => {
+                if let Some(policy) = self.policies.get_mut(&disabled.policy_id) {
+                    policy.disabled = true;
+                }
+            }
+            IndexEvent::Attachment(change) => {
+                let principals = self.attachments.entry(change.policy_id).or_default();
+                if change.attached {
+                    principals.insert(change.principal_hrn);
+                } else {
+                    principals.remove(&change.principal_hrn);
+                }
+            }
+            IndexEvent::Membership(change) => {
+                let groups = self.memberships.entry(change.member_hrn).or_default();
+                if change.joined {
+                    groups.insert(change.group_hrn);
+                } else {
+                    groups.remove(&change.group_hrn);
+                }
+            }
+        }
+        self.as_of = self.as_of.max(Some(occurred_at));
+    }
+
+    /// Users that are `principal` or, for a group, are nested below it
+    fn users_of(&self, principal: &Hrn) -> Vec<Hrn> {
+        let mut users = Vec::new();
+        let mut seen = HashSet::from([principal.clone()]);
+        let mut queue = VecDeque::from([principal.clone()]);
+        while let Some(current) = queue.pop_front() {
+            if !is_group(&current) {
+                users.push(current);
+                continue;
+            }
+            for (member, groups) in &self.memberships {
+                if groups.contains(&current) && seen.insert(member.clone()) {
+                    queue.push_back(member.clone());
+                }
+            }
+        }
+        users
+    }
+
+    fn principals_with_access(&self, request: &AccessRequest) -> Vec<PrincipalAccess> {
+        #[derive(Default)]
+        struct Grants {
+            permits: Vec<(String, GrantCoverage)>,
+            forbids: Vec<GrantCoverage>,
+        }
+
+        let mut grants: HashMap<Hrn, Grants> = HashMap::new();
+        for (policy_id, policy) in &self.policies {
+            if policy.disabled {
+                continue;
+            }
+            let Some(grant) = &policy.grant else {
+                continue;
+            };
+            let Some(coverage) = grant.covers(request) else {
+                continue;
+            };
+            for principal in self.attachments.get(policy_id).into_iter().flatten() {
+                for user in self.users_of(principal) {
+                    let user_grants = grants.entry(user).or_default();
+                    match grant.effect() {
+                        PolicyEffect::Permit => {
+                            user_grants.permits.push((policy_id.clone(), coverage))
+                        }
+                        PolicyEffect::Forbid => user_grants.forbids.push(coverage),
+                    }
+                }
+            }
+        }
+
+        let mut principals: Vec<PrincipalAccess> = grants
+            .into_iter()
+            .filter(|(_, grants)| {
+                !grants.permits.is_empty() && !grants.forbids.contains(&GrantCoverage::Full)
+            })
+            .map(|(user, grants)| {
+                let granted = grants.forbids.is_empty()
+                    && grants
+                        .permits
+                        .iter()
+                        .any(|(_, coverage)| *coverage == GrantCoverage::Full);
+                let mut policy_ids: Vec<String> =
+                    grants.permits.into_iter().map(|(id, _)| id).collect();
+                policy_ids.sort();
+                policy_ids.dedup();
+                PrincipalAccess {
+                    principal_hrn: user.to_string(),
+                    access: if granted {
+                        AccessLevel::Granted
+                    } else {
+                        AccessLevel::Conditional
+                    },
+                    policy_ids,
+                }
+            })
+            .collect();
+        principals.sort_by(|a, b| a.principal_hrn.cmp(&b.principal_hrn));
+        principals
+    }
+}
+
+fn is_group(hrn: &Hrn) -> bool {
+    hrn.resource_type().eq_ignore_ascii_case("group")
+}
+
+/// Read model answering "who can access this resource"
+pub struct AccessIndexProjection {
+    state: RwLock<IndexState>,
+    event_store: Option<Arc<dyn EventStore>>,
+    batch_size: usize,
+    /// Serializes catch-ups and rebuilds, which read the store in steps
+    replay: Mutex<()>,
+}
+
+impl Default for AccessIndexProjection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccessIndexProjection {
+    /// Create an empty index, fed only by the events it is subscribed to
+    pub fn new() -> Self {
+        Self {
+            state: RwLock::new(IndexState::default()),
+            event_store: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            replay: Mutex::new(()),
+        }
+    }
+
+    /// Rebuild from, catch up with and compare freshness against `store`
+    pub fn with_event_store(mut self, store: Arc<dyn EventStore>) -> Self {
+        self.event_store = Some(store);
+        self
+    }
+
+    /// Read the store in batches of `batch_size` events
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    fn apply_live(&self, event_id: Uuid, occurred_at: DateTime<Utc>, event: IndexEvent) {
+        debug!(%event_id, "Applying event to access index");
+        let mut state = self.state.write().unwrap();
+        state.apply(event, occurred_at);
+        if self.event_store.is_some() {
+            state.applied_ahead.insert(event_id);
+        }
+    }
+
+    fn store(&self) -> Result<&Arc<dyn EventStore>, EventStoreError> {
+        self.event_store.as_ref().ok_or_else(|| {
+            EventStoreError::Storage("The access index has no event store".to_string())
+        })
+    }
+
+    /// Discard the index and replay every stored event into a new one
+    ///
+    /// Queries keep being answered from the current index until the new
+    /// one is complete. Events published during the rebuild that the replay
+    /// did not reach are left pending for the next catch-up.
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is no event store or it can't be read; events whose
+    /// payload can't be read are counted in the report.
+    pub async fn rebuild(&self) -> Result<AccessIndexReplay, EventStoreError> {
+        let store = self.store()?;
+        let _replay = self.replay.lock().await;
+        info!("Rebuilding access index from the event store");
+
+        let mut rebuilt = IndexState::default();
+        let mut report = AccessIndexReplay::default();
+        loop {
+            let batch = store.read_batch(report.processed, self.batch_size).await?;
+            if batch.is_empty() {
+                break;
+            }
+            for event in &batch {
+                replay_event(&mut rebuilt, event, &mut report);
+            }
+            report.processed += batch.len();
+        }
+        rebuilt.position = report.processed;
+        rebuilt.rebuilt_at = Some(Utc::now());
+
+        *self.state.write().unwrap() = rebuilt;
+        info!(
+            processed = report.processed,
+            applied = report.applied,
+            failed = report.failed,
+            "Access index rebuilt"
+        );
+        Ok(report)
+    }
+
+    /// Apply the stored events the index has not seen yet
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is no event store or it can't be read.
+    pub async fn catch_up(&self) -> Result<AccessIndexReplay, EventStoreError> {
+        let store = self.store()?;
+        let _replay = self.replay.lock().await;
+
+        let mut report = AccessIndexReplay::default();
+        loop {
+            let position = self.state.read().unwrap().position;
+            let batch = store.read_batch(position, self.batch_size).await?;
+            if batch.is_empty() {
+                break;
+            }
+            let mut state = self.state.write().unwrap();
+            for event in &batch {
+                if !state.applied_ahead.remove(&event.event_id) {
+                    replay_event(&mut state, event, &mut report);
+                }
+            }
+            state.position += batch.len();
+            report.processed += batch.len();
+        }
+        if report.applied > 0 {
+            info!(
+                applied = report.applied,
+                "Access index caught up with the event store"
+            );
+        }
+        Ok(report)
+    }
+
+    async fn pending_events(&self, store: &dyn EventStore) -> Result<usize, EventStoreError> {
+        let mut offset = self.state.read().unwrap().position;
+        let mut pending = 0;
+        loop {
+            let batch = store.read_batch(offset, self.batch_size).await?;
+            if batch.is_empty() {
+                return Ok(pending);
+            }
+            let state = self.state.read().unwrap();
+            pending += batch
+                .iter()
+                .filter(|event| {
+                    IndexEvent::is_index_event(&event.event_type)
+                        && !state.applied_ahead.contains(&event.event_id)
+                })
+                .count();
+            offset += batch.len();
+        }
+    }
+}
+
+fn replay_event(state: &mut IndexState, event: &StoredEvent, report: &mut AccessIndexReplay) {
+    match IndexEvent::from_stored(event) {
+        None => {}
+        Some(Ok(index_event)) => {
+            state.apply(index_event, event.occurred_at);
+            report.applied += 1;
+        }
+        Some(Err(error)) => {
+            warn!(
+                event_id = %event.event_id,
+                event_type = %event.event_type,
+                error = %error,
+                "Stored event could not be applied to the access index"
+            );
+            report.failed += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl AccessIndexPort for AccessIndexProjection {
+    async fn principals_with_access(
+        &self,
+        request: &AccessRequest,
+    ) -> Result<Vec<PrincipalAccess>, ListPrincipalsWithAccessError> {
+        Ok(self.state.read().unwrap().principals_with_access(request))
+    }
+
+    async fn freshness(&self) -> Result<IndexFreshness, ListPrincipalsWithAccessError> {
+        let (as_of, rebuilt_at) = {
+            let state = self.state.read().unwrap();
+            (state.as_of, state.rebuilt_at)
+        };
+        let Some(store) = &self.event_store else {
+            return Ok(IndexFreshness {
+                status: IndexStatus::Unverified,
+                as_of,
+                rebuilt_at,
+                pending_events: 0,
+            });
+        };
+
+        let pending_events = self
+            .pending_events(store.as_ref())
+            .await
+            .map_err(|e| ListPrincipalsWithAccessError::IndexError(e.to_string()))?;
+        Ok(IndexFreshness {
+            status: if pending_events == 0 {
+                IndexStatus::Current
+            } else {
+                IndexStatus::Lagging
+            },
+            as_of,
+            rebuilt_at,
+            pending_events,
+        })
+    }
+}
+
+#[async_trait]
+impl EventHandler<PolicyStored> for AccessIndexProjection {
+    fn name(&self) -> &'static str {
+        "access-index-projection"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<PolicyStored>) -> anyhow::Result<()> {
+        self.apply_live(
+            envelope.event_id,
+            envelope.occurred_at,
+            IndexEvent::Stored(envelope.event),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<PolicyDeleted> for AccessIndexProjection {
+    fn name(&self) -> &'static str {
+        "access-index-projection"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<PolicyDeleted>) -> anyhow::Result<()> {
+        self.apply_live(
+            envelope.event_id,
+            envelope.occurred_at,
+            IndexEvent::Deleted(envelope.event),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<PolicyDisabled> for AccessIndexProjection {
+    fn name(&self) -> &'static str {
+        "access-index-projection"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<PolicyDisabled>) -> anyhow::Result<()> {
+        self.apply_live(
+            envelope.event_id,
+            envelope.occurred_at,
+            IndexEvent::Disabled(envelope.event),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<PolicyAttachmentChanged> for AccessIndexProjection {
+    fn name(&self) -> &'static str {
+        "access-index-projection"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<PolicyAttachmentChanged>) -> anyhow::Result<()> {
+        self.apply_live(
+            envelope.event_id,
+            envelope.occurred_at,
+            IndexEvent::Attachment(envelope.event),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler<GroupMembershipChanged> for AccessIndexProjection {
+    fn name(&self) -> &'static str {
+        "access-index-projection"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<GroupMembershipChanged>) -> anyhow::Result<()> {
+        self.apply_live(
+            envelope.event_id,
+            envelope.occurred_at,
+            IndexEvent::Membership(envelope.event),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kernel::application::ports::event_bus::{DomainEvent, EventBus, EventPublisher};
+    use kernel::{InMemoryEventBus, InMemoryEventStore, SerializationFormat};
+
+    fn iam(resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "default".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn get_report() -> AccessRequest {
+        AccessRequest::new("s3:Get", r#"S3::Object::"report""#).unwrap()
+    }
+
+    fn stored(policy_id: &str, content: &str) -> PolicyStored {
+        PolicyStored {
+            policy_id: policy_id.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    fn attached(policy_id: &str, principal_hrn: Hrn) -> PolicyAttachmentChanged {
+        PolicyAttachmentChanged {
+            policy_id: policy_id.to_string(),
+            principal_hrn,
+            attached: true,
+        }
+    }
+
+    async fn apply<E: DomainEvent>(projection: &AccessIndexProjection, event: E)
+    where
+        AccessIndexProjection: EventHandler<E>,
+    {
+        projection.handle(EventEnvelope::new(event)).await.unwrap();
+    }
+
+    async fn store<E: DomainEvent>(store: &InMemoryEventStore, event: E) {
+        let event =
+            StoredEvent::from_envelope(&EventEnvelope::new(event), SerializationFormat::Json)
+                .unwrap();
+        store.append(event).await.unwrap();
+    }
+
+    async fn principals(projection: &AccessIndexProjection) -> Vec<(String, AccessLevel)> {
+        projection
+            .principals_with_access(&get_report())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|access| (access.principal_hrn, access.access))
+            .collect()
+    }
+
+    const READ_OBJECTS: &str =
+        r#"permit(principal, action == S3::Action::"s3:Get", resource is S3::Object);"#;
+
+    #[tokio::test]
+    async fn resolves_users_through_nested_groups() {
+        let projection = AccessIndexProjection::new();
+        let (alice, bob, carol) = (
+            iam("User", "alice"),
+            iam("User", "bob"),
+            iam("User", "carol"),
+        );
+        let (readers, finance) = (iam("Group", "readers"), iam("Group", "finance"));
+
+        apply(&projection, stored("read-objects", READ_OBJECTS)).await;
+        apply(&projection, attached("read-objects", alice.clone())).await;
+        apply(&projection, attached("read-objects", readers.clone())).await;
+        apply(
+            &projection,
+            GroupMembershipChanged::joined(finance.clone(), readers),
+        )
+        .await;
+        apply(
+            &projection,
+            GroupMembershipChanged::joined(bob.clone(), finance),
+        )
+        .await;
+        apply(
+            &projection,
+            GroupMembershipChanged::joined(carol, iam("Group", "other")),
+        )
+        .await;
+
+        let access = projection
+            .principals_with_access(&get_report())
+            .await
+            .unwrap();
+        assert_eq!(
+            access,
+            vec![
+                PrincipalAccess {
+                    principal_hrn: alice.to_string(),
+                    access: AccessLevel::Granted,
+                    policy_ids: vec!["read-objects".to_string()],
+                },
+                PrincipalAccess {
+                    principal_hrn: bob.to_string(),
+                    access: AccessLevel::Granted,
+                    policy_ids: vec!["read-objects".to_string()],
+                },
+            ]
+        );
+        let other_action = AccessRequest::new("s3:Delete", r#"S3::Object::"report""#).unwrap();
+        assert!(
+            projection
+                .principals_with_access(&other_action)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn forbids_and_conditions_narrow_access() {
+        let projection = AccessIndexProjection::new();
+        let users = ["alice", "bob", "carol"].map(|id| iam("User", id));
+
+        apply(&projection, stored("read-objects", READ_OBJECTS)).await;
+        apply(
+            &projection,
+            stored(
+                "deny-reports",
+                r#"forbid(principal, action, resource == S3::Object::"report");"#,
+            ),
+        )
+        .await;
+        apply(
+            &projection,
+            stored(
+                "deny-without-mfa",
+                "forbid(principal, action, resource) unless { context.mfa };",
+            ),
+        )
+        .await;
+        for user in &users {
+            apply(&projection, attached("read-objects", user.clone())).await;
+        }
+        apply(&projection, attached("deny-reports", users[1].clone())).await;
+        apply(&projection, attached("deny-without-mfa", users[2].clone())).await;
+
+        assert_eq!(
+            principals(&projection).await,
+            vec![
+                (users[0].to_string(), AccessLevel::Granted),
+                (users[2].to_string(), AccessLevel::Conditional),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn detaching_leaving_disabling_and_deleting_revoke_access() {
+        let projection = AccessIndexProjection::new();
+        let (alice, bob) = (iam("User", "alice"), iam("User", "bob"));
+        let readers = iam("Group", "readers");
+
+        apply(&projection, stored("read-objects", READ_OBJECTS)).await;
+        apply(
+            &projection,
+            stored("read-all", "permit(principal, action, resource);"),
+        )
+        .await;
+        apply(&projection, attached("read-objects", alice.clone())).await;
+        apply(&projection, attached("read-objects", readers.clone())).await;
+        apply(&projection, attached("read-all", alice.clone())).await;
+        apply(
+            &projection,
+            GroupMembershipChanged::joined(bob.clone(), readers.clone()),
+        )
+        .await;
+        assert_eq!(principals(&projection).await.len(), 2);
+
+        apply(&projection, GroupMembershipChanged::left(bob, readers)).await;
+        apply(
+            &projection,
+            PolicyAttachmentChanged {
+                attached: false,
+                ..attached("read-objects", alice.clone())
+            },
+        )
+        .await;
+        assert_eq!(
+            principals(&projection).await,
+            vec![(alice.to_string(), AccessLevel::Granted)]
+        );
+
+        apply(
+            &projection,
+            PolicyDisabled {
+                policy_id: "read-all".to_string(),
+                reason: "No longer valid".to_string(),
+                schema_version: "v2".to_string(),
+                disabled_at: Utc::now(),
+            },
+        )
+        .await;
+        assert!(principals(&projection).await.is_empty());
+
+        apply(
+            &projection,
+            stored("read-all", "permit(principal, action, resource);"),
+        )
+        .await;
+        assert_eq!(principals(&projection).await.len(), 1);
+        apply(
+            &projection,
+            PolicyDeleted {
+                policy_id: "read-all".to_string(),
+            },
+        )
+        .await;
+        assert!(principals(&projection).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rebuild_replays_the_event_store_and_reports_freshness() {
+        let events = Arc::new(InMemoryEventStore::new());
+        let alice = iam("User", "alice");
+        store(&events, stored("read-objects", READ_OBJECTS)).await;
+        store(&events, attached("read-objects", alice.clone())).await;
+        let projection = AccessIndexProjection::new()
+            .with_event_store(events.clone())
+            .with_batch_size(1);
+
+        let freshness = projection.freshness().await.unwrap();
+        assert_eq!(freshness.status, IndexStatus::Lagging);
+        assert_eq!(freshness.pending_events, 2);
+        assert!(principals(&projection).await.is_empty());
+
+        let report = projection.rebuild().await.unwrap();
+        assert_eq!(report.processed, 2);
+        assert_eq!(report.applied, 2);
+        assert_eq!(
+            principals(&projection).await,
+            vec![(alice.to_string(), AccessLevel::Granted)]
+        );
+        let freshness = projection.freshness().await.unwrap();
+        assert_eq!(freshness.status, IndexStatus::Current);
+        assert!(freshness.rebuilt_at.is_some());
+        assert!(freshness.as_of.is_some());
+    }
+
+    #[tokio::test]
+    async fn catch_up_applies_only_the_events_the_index_missed() {
+        let events = Arc::new(InMemoryEventStore::new());
+        let bus = InMemoryEventBus::new().with_event_store(events.clone());
+        let projection = Arc::new(AccessIndexProjection::new().with_event_store(events.clone()));
+        let (alice, bob) = (iam("User", "alice"), iam("User", "bob"));
+
+        let subscriptions = bus
+            .subscribe::<PolicyAttachmentChanged, _>(projection.clone())
+            .await
+            .unwrap();
+        bus.publish(stored("read-objects", READ_OBJECTS))
+            .await
+            .unwrap();
+        bus.publish(attached("read-objects", alice.clone()))
+            .await
+            .unwrap();
+        bus.publish(attached("read-objects", bob.clone()))
+            .await
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        // The policy itself was never delivered, so nothing is granted yet
+        assert!(principals(&projection).await.is_empty());
+        let freshness = projection.freshness().await.unwrap();
+        assert_eq!(freshness.status, IndexStatus::Lagging);
+        assert_eq!(freshness.pending_events, 1);
+
+        let report = projection.catch_up().await.unwrap();
+        assert_eq!(report.processed, 3);
+        assert_eq!(report.applied, 1);
+        assert_eq!(principals(&projection).await.len(), 2);
+        assert_eq!(
+            projection.freshness().await.unwrap().status,
+            IndexStatus::Current
+        );
+        subscriptions.cancel();
+    }
+
+    #[tokio::test]
+    async fn without_an_event_store_freshness_is_unverified() {
+        let projection = AccessIndexProjection::new();
+        apply(&projection, stored("read-objects", READ_OBJECTS)).await;
+
+        let freshness = projection.freshness().await.unwrap();
+        assert_eq!(freshness.status, IndexStatus::Unverified);
+        assert!(freshness.as_of.is_some());
+        assert!(projection.rebuild().await.is_err());
+    }
+}
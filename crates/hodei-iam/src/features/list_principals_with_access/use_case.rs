@@ -0,0 +1,101 @@
+use super::dto::{ListPrincipalsWithAccessQuery, ListPrincipalsWithAccessResponse};
+use super::error::ListPrincipalsWithAccessError;
+use super::ports::{AccessIndexPort, ListPrincipalsWithAccessUseCasePort};
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::grant::AccessRequest;
+use kernel::{Hrn, TenantContext};
+use std::sync::Arc;
+use tracing::{debug, instrument};
+
+/// Use case for listing the principals who can access a resource
+///
+/// This use case answers from the access index, never from the authorizer:
+/// 1. Validates the resource HRN, its ancestors and the action
+/// 2. Looks up the users the index says can perform the action
+/// 3. Reports how fresh the index is, so callers know whether the answer
+///    may be missing recent changes
+pub struct ListPrincipalsWithAccessUseCase {
+    index: Arc<dyn AccessIndexPort>,
+}
+
+impl ListPrincipalsWithAccessUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `index` - Implementation of AccessIndexPort to answer from
+    pub fn new(index: Arc<dyn AccessIndexPort>) -> Self {
+        Self { index }
+    }
+
+    /// Execute the list principals with access use case
+    ///
+    /// # Arguments
+    /// * `query` - ListPrincipalsWithAccessQuery with the resource and the action
+    ///
+    /// # Returns
+    /// * Ok(ListPrincipalsWithAccessResponse) with the users and the index freshness
+    /// * Err(ListPrincipalsWithAccessError) if there was an error
+    #[instrument(name = "list_principals_with_access", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        resource = %query.resource_hrn,
+        action = %query.action
+    ))]
+    pub async fn execute(
+        &self,
+        query: ListPrincipalsWithAccessQuery,
+    ) -> Result<ListPrincipalsWithAccessResponse, ListPrincipalsWithAccessError> {
+        let resource = entity_uid(&query.resource_hrn)?;
+        let ancestors = query
+            .resource_ancestors
+            .iter()
+            .map(|ancestor| entity_uid(ancestor))
+            .collect::<Result<Vec<_>, _>>()?;
+        let request = AccessRequest::new(&query.action, &resource)
+            .and_then(|request| request.with_ancestors(ancestors.iter().map(String::as_str)))
+            .map_err(|e| ListPrincipalsWithAccessError::InvalidAction(e.to_string()))?;
+
+        let principals = self.index.principals_with_access(&request).await?;
+        let freshness = self.index.freshness().await?;
+        debug!(
+            principals = principals.len(),
+            status = ?freshness.status,
+            "Listed principals with access"
+        );
+
+        Ok(ListPrincipalsWithAccessResponse {
+            resource_hrn: query.resource_hrn,
+            action: query.action,
+            principals,
+            freshness,
+        })
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The resource must belong to the tenant, otherwise the query fails
+    /// with `CrossTenantAccess` before any lookup.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: ListPrincipalsWithAccessQuery,
+    ) -> Result<ListPrincipalsWithAccessResponse, ListPrincipalsWithAccessError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
+}
+
+/// The Cedar entity UID of a resource HRN
+fn entity_uid(hrn: &str) -> Result<String, ListPrincipalsWithAccessError> {
+    Hrn::from_string(hrn)
+        .map(|hrn| hrn.entity_uid_string())
+        .ok_or_else(|| ListPrincipalsWithAccessError::InvalidResourceHrn(hrn.to_string()))
+}
+
+#[async_trait]
+impl ListPrincipalsWithAccessUseCasePort for ListPrincipalsWithAccessUseCase {
+    async fn execute(
+        &self,
+        query: ListPrincipalsWithAccessQuery,
+    ) -> Result<ListPrincipalsWithAccessResponse, ListPrincipalsWithAccessError> {
+        self.execute(query).await
+    }
+}
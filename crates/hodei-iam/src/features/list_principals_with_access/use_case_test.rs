@@ -0,0 +1,123 @@
+//! Unit tests for list_principals_with_access use case
+//!
+//! These tests verify the behavior of the ListPrincipalsWithAccessUseCase in
+//! isolation, using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use kernel::TenantContext;
+
+    use crate::features::list_principals_with_access::{
+        dto::{
+            AccessLevel, IndexFreshness, IndexStatus, ListPrincipalsWithAccessQuery,
+            PrincipalAccess,
+        },
+        error::ListPrincipalsWithAccessError,
+        mocks::MockAccessIndexPort,
+        use_case::ListPrincipalsWithAccessUseCase,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    const REPORT: &str = "hrn:hodei:s3::account123:Object/report";
+
+    fn alice() -> PrincipalAccess {
+        PrincipalAccess {
+            principal_hrn: "hrn:hodei:iam::account123:User/alice".to_string(),
+            access: AccessLevel::Granted,
+            policy_ids: vec!["read-reports".to_string()],
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_returns_the_indexed_principals_with_the_index_freshness() {
+        let freshness = IndexFreshness {
+            status: IndexStatus::Lagging,
+            as_of: None,
+            rebuilt_at: None,
+            pending_events: 3,
+        };
+        let index =
+            Arc::new(MockAccessIndexPort::new(vec![alice()]).with_freshness(freshness.clone()));
+        let use_case = ListPrincipalsWithAccessUseCase::new(index.clone());
+
+        let response = use_case
+            .execute(
+                ListPrincipalsWithAccessQuery::new(REPORT, "s3:Get")
+                    .with_ancestors(["hrn:hodei:s3::account123:Bucket/finance".to_string()]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.resource_hrn, REPORT);
+        assert_eq!(response.action, "s3:Get");
+        assert_eq!(response.principals, vec![alice()]);
+        assert_eq!(response.freshness, freshness);
+        assert_eq!(index.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_queries_are_rejected_before_the_index() {
+        let index = Arc::new(MockAccessIndexPort::new(vec![alice()]));
+        let use_case = ListPrincipalsWithAccessUseCase::new(index.clone());
+
+        let bad_resource = use_case
+            .execute(ListPrincipalsWithAccessQuery::new("report", "s3:Get"))
+            .await;
+        assert!(matches!(
+            bad_resource,
+            Err(ListPrincipalsWithAccessError::InvalidResourceHrn(_))
+        ));
+
+        let bad_ancestor = use_case
+            .execute(
+                ListPrincipalsWithAccessQuery::new(REPORT, "s3:Get")
+                    .with_ancestors(["finance".to_string()]),
+            )
+            .await;
+        assert!(matches!(
+            bad_ancestor,
+            Err(ListPrincipalsWithAccessError::InvalidResourceHrn(_))
+        ));
+
+        let bad_action = use_case
+            .execute(ListPrincipalsWithAccessQuery::new(REPORT, "  "))
+            .await;
+        assert!(matches!(
+            bad_action,
+            Err(ListPrincipalsWithAccessError::InvalidAction(_))
+        ));
+        assert_eq!(index.request_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_execute_for_tenant_rejects_other_tenants_resources() {
+        let index = Arc::new(MockAccessIndexPort::new(vec![alice()]));
+        let use_case = ListPrincipalsWithAccessUseCase::new(index.clone());
+        let tenant = TenantContext::new("account123");
+
+        let result = use_case
+            .execute_for_tenant(
+                &tenant,
+                ListPrincipalsWithAccessQuery::new(
+                    "hrn:hodei:s3::other-account:Object/report",
+                    "s3:Get",
+                ),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ListPrincipalsWithAccessError::CrossTenantAccess(_))
+        ));
+        assert_eq!(index.request_count(), 0);
+    }
+}
@@ -8,14 +8,28 @@
 /// - Adapters (infrastructure implementations)
 /// - Tests (unit and integration)
 ///
+pub mod add_group_to_group;
 pub mod add_user_to_group;
 pub mod create_group;
 pub mod create_policy;
 pub mod create_user;
 pub mod delete_policy;
 pub mod evaluate_iam_policies;
+pub mod export_iam_state;
+pub mod find_dangling_references;
 pub mod get_effective_policies;
+pub mod get_policies;
 pub mod get_policy;
+pub mod import_iam_state;
+pub mod import_policies;
+pub mod list_group_members;
 pub mod list_policies;
+pub mod list_principals_with_access;
+pub mod policy_walk;
 pub mod register_iam_schema;
+pub mod revalidate_policies;
+pub mod search_policies;
+pub mod set_user_status;
+pub mod update_group_attributes;
 pub mod update_policy;
+pub mod update_user_attributes;
@@ -0,0 +1,20 @@
+//! Data Transfer Objects for the policy walk
+
+use kernel::Hrn;
+
+/// A stored policy as read by a walk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredPolicy {
+    /// HRN the store names the policy with
+    pub hrn: Hrn,
+    pub id: String,
+    pub content: String,
+    /// Why the policy is disabled, if it is
+    pub disabled_reason: Option<String>,
+}
+
+impl StoredPolicy {
+    pub fn is_disabled(&self) -> bool {
+        self.disabled_reason.is_some()
+    }
+}
@@ -0,0 +1,8 @@
+use thiserror::Error;
+
+/// Errors that can occur when walking the stored policies
+#[derive(Debug, Error)]
+pub enum PolicyWalkError {
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
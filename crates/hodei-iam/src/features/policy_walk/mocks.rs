@@ -0,0 +1,89 @@
+//! Mock implementations for testing the features walking the policies
+
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::dto::StoredPolicy;
+use super::error::PolicyWalkError;
+use super::ports::PolicyWalkStorePort;
+use crate::infrastructure::hrn_generator::policy_hrn;
+
+/// Mock PolicyWalkStorePort for testing
+///
+/// Counts the pages read, so tests can check the batching.
+pub struct MockPolicyWalkStore {
+    policies: Mutex<BTreeMap<String, StoredPolicy>>,
+    pages: AtomicUsize,
+}
+
+impl MockPolicyWalkStore {
+    pub fn new() -> Self {
+        Self {
+            policies: Mutex::new(BTreeMap::new()),
+            pages: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn with_policy(self, id: &str, content: &str) -> Self {
+        self.insert(id, content, None)
+    }
+
+    pub fn with_disabled_policy(self, id: &str, content: &str) -> Self {
+        self.insert(id, content, Some("disabled for review".to_string()))
+    }
+
+    fn insert(self, id: &str, content: &str, disabled_reason: Option<String>) -> Self {
+        self.policies.lock().unwrap().insert(
+            id.to_string(),
+            StoredPolicy {
+                hrn: policy_hrn(id),
+                id: id.to_string(),
+                content: content.to_string(),
+                disabled_reason,
+            },
+        );
+        self
+    }
+
+    /// Disable the policy `id` with `reason`, if it is stored
+    pub fn disable(&self, id: &str, reason: &str) -> bool {
+        match self.policies.lock().unwrap().get_mut(id) {
+            Some(policy) => {
+                policy.disabled_reason = Some(reason.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Why the policy is disabled, if it is
+    pub fn disabled_reason(&self, id: &str) -> Option<String> {
+        self.policies.lock().unwrap()[id].disabled_reason.clone()
+    }
+
+    pub fn pages(&self) -> usize {
+        self.pages.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl PolicyWalkStorePort for MockPolicyWalkStore {
+    async fn list_policies(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredPolicy>, PolicyWalkError> {
+        self.pages.fetch_add(1, Ordering::SeqCst);
+        Ok(self
+            .policies
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|policy| after.is_none_or(|after| policy.id.as_str() > after))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
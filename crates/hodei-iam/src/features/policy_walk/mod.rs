@@ -0,0 +1,30 @@
+//! policy_walk (shared by the features that scan every stored policy)
+//!
+//! Revalidation, search and the dangling-reference scan all read every
+//! stored policy. They share this one batched walk instead of each reading
+//! the store its own way:
+//!
+//! - the store lists policies in ID order, `limit` at a time, after a cursor
+//! - the store names each policy with its HRN, so features never build one
+//! - `PolicyWalk` pages through the store until it runs out
+//!
+//! Structure:
+//! - dto.rs              -> The stored policy as walked
+//! - error.rs            -> Walk error type
+//! - ports.rs            -> Segregated interface (ISP)
+//! - walk.rs             -> Batched cursor over the store (PolicyWalk)
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod ports;
+pub mod walk;
+
+#[cfg(test)]
+pub(crate) mod mocks;
+
+// Public API
+pub use dto::StoredPolicy;
+pub use error::PolicyWalkError;
+pub use ports::PolicyWalkStorePort;
+pub use walk::PolicyWalk;
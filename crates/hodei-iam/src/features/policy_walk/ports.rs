@@ -0,0 +1,23 @@
+use super::dto::StoredPolicy;
+use super::error::PolicyWalkError;
+use async_trait::async_trait;
+
+/// Port for walking the stored policies
+///
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operation needed to walk the policies.
+#[async_trait]
+pub trait PolicyWalkStorePort: Send + Sync {
+    /// Read up to `limit` policies, disabled ones included, in ID order,
+    /// starting after the policy `after`
+    ///
+    /// # Returns
+    /// * `Ok(Vec<StoredPolicy>)` with the next policies; fewer than `limit`
+    ///   once the end is reached
+    /// * `Err(PolicyWalkError)` if there was an error during lookup
+    async fn list_policies(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredPolicy>, PolicyWalkError>;
+}
@@ -0,0 +1,83 @@
+use super::dto::StoredPolicy;
+use super::error::PolicyWalkError;
+use super::ports::PolicyWalkStorePort;
+
+/// Cursor over the stored policies, `batch_size` at a time in ID order
+///
+/// Never loads the whole store at once: each batch is read when asked for,
+/// after the last policy of the previous one.
+pub struct PolicyWalk<'a, S: PolicyWalkStorePort + ?Sized> {
+    store: &'a S,
+    batch_size: usize,
+    after: Option<String>,
+    done: bool,
+}
+
+impl<'a, S: PolicyWalkStorePort + ?Sized> PolicyWalk<'a, S> {
+    pub fn new(store: &'a S, batch_size: usize) -> Self {
+        Self {
+            store,
+            batch_size,
+            after: None,
+            done: batch_size == 0,
+        }
+    }
+
+    /// Whether every policy was read
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// The next batch of policies, or `None` once every policy was read
+    pub async fn next_batch(&mut self) -> Result<Option<Vec<StoredPolicy>>, PolicyWalkError> {
+        if self.done {
+            return Ok(None);
+        }
+        let batch = self
+            .store
+            .list_policies(self.after.as_deref(), self.batch_size)
+            .await?;
+        self.done = batch.len() < self.batch_size;
+        match batch.last() {
+            Some(last) => self.after = Some(last.id.clone()),
+            None => return Ok(None),
+        }
+        Ok(Some(batch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::policy_walk::mocks::MockPolicyWalkStore;
+
+    async fn batches(store: &MockPolicyWalkStore, batch_size: usize) -> Vec<Vec<String>> {
+        let mut walk = PolicyWalk::new(store, batch_size);
+        let mut batches = Vec::new();
+        while let Some(batch) = walk.next_batch().await.unwrap() {
+            batches.push(batch.into_iter().map(|policy| policy.id).collect());
+        }
+        batches
+    }
+
+    #[tokio::test]
+    async fn test_walks_every_policy_in_batches() {
+        let store = MockPolicyWalkStore::new()
+            .with_policy("a", "permit(principal, action, resource);")
+            .with_disabled_policy("b", "permit(principal, action, resource);")
+            .with_policy("c", "permit(principal, action, resource);");
+
+        assert_eq!(batches(&store, 2).await, vec![vec!["a", "b"], vec!["c"]]);
+        assert_eq!(store.pages(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_a_full_last_batch_takes_one_more_read() {
+        let store = MockPolicyWalkStore::new()
+            .with_policy("a", "permit(principal, action, resource);")
+            .with_policy("b", "permit(principal, action, resource);");
+
+        assert_eq!(batches(&store, 2).await, vec![vec!["a", "b"]]);
+        assert_eq!(store.pages(), 2);
+    }
+}
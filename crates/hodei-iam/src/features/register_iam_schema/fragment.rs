@@ -0,0 +1,43 @@
+//! IAM's contribution to the shared Cedar schema
+
+use crate::internal::domain::actions::{
+    AddUserToGroupAction, CreateGroupAction, CreateUserAction, DeleteGroupAction, DeleteUserAction,
+    RemoveUserFromGroupAction,
+};
+use crate::internal::domain::{Group, User};
+use hodei_policies::register_schema_fragments::dto::SchemaFragment;
+use hodei_policies::register_schema_fragments::ports::SchemaFragmentProvider;
+
+/// Declares the IAM entity types (User, Group) and their actions
+pub struct IamSchemaFragmentProvider;
+
+impl SchemaFragmentProvider for IamSchemaFragmentProvider {
+    fn schema_fragment(&self) -> SchemaFragment {
+        SchemaFragment::new("hodei-iam")
+            .with_entity_type::<User>()
+            .with_entity_type::<Group>()
+            .with_action_type::<CreateUserAction>()
+            .with_action_type::<DeleteUserAction>()
+            .with_action_type::<CreateGroupAction>()
+            .with_action_type::<DeleteGroupAction>()
+            .with_action_type::<AddUserToGroupAction>()
+            .with_action_type::<RemoveUserFromGroupAction>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fragment_declares_iam_types() {
+        let fragment = IamSchemaFragmentProvider.schema_fragment();
+
+        assert_eq!(fragment.source(), "hodei-iam");
+        assert_eq!(
+            fragment.entity_type_names(),
+            vec!["Iam::User", "Iam::Group"]
+        );
+        assert_eq!(fragment.action_type_names().len(), 6);
+    }
+}
@@ -4,7 +4,9 @@
 //! RegisterIamSchemaUseCase, enabling isolated unit testing.
 
 use async_trait::async_trait;
-use hodei_policies::build_schema::dto::{BuildSchemaCommand, BuildSchemaResult};
+use hodei_policies::build_schema::dto::{
+    AddEntityTypeCommand, AddEntityTypeResult, BuildSchemaCommand, BuildSchemaResult,
+};
 use hodei_policies::build_schema::error::BuildSchemaError;
 use hodei_policies::build_schema::ports::BuildSchemaPort;
 use hodei_policies::register_action_type::dto::RegisterActionTypeCommand;
@@ -167,6 +169,25 @@ impl BuildSchemaPort for MockBuildSchemaPort {
             })
         }
     }
+
+    async fn add_entity_type(
+        &self,
+        command: AddEntityTypeCommand,
+    ) -> Result<AddEntityTypeResult, BuildSchemaError> {
+        if self.should_fail {
+            return Err(BuildSchemaError::SchemaBuildError(
+                "Mock schema build failed".to_string(),
+            ));
+        }
+        Ok(AddEntityTypeResult {
+            added_entity_types: vec![],
+            unchanged_entity_types: vec![],
+            entity_count: 2,
+            action_count: 6,
+            version: command.version,
+            schema_id: None,
+        })
+    }
 }
 
 /// Helper function to create default mocks for testing
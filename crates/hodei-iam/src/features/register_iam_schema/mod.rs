@@ -9,6 +9,7 @@
 pub mod dto;
 pub mod error;
 pub mod factories;
+pub mod fragment;
 pub mod ports;
 
 pub mod use_case;
@@ -20,5 +21,6 @@ mod mocks;
 // Re-export for convenience
 pub use dto::{RegisterIamSchemaCommand, RegisterIamSchemaResult};
 pub use error::RegisterIamSchemaError;
+pub use fragment::IamSchemaFragmentProvider;
 pub use ports::RegisterIamSchemaPort;
 pub use use_case::RegisterIamSchemaUseCase;
@@ -93,7 +93,8 @@ impl RegisterIamSchemaUseCase {
     /// let result = use_case.execute(command).await?;
     /// println!("Registered IAM schema version: {}", result.schema_version);
     /// ```
-    #[tracing::instrument(skip(self, command), fields(
+    #[tracing::instrument(name = "register_iam_schema", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
         version = ?command.version,
         validate = command.validate
     ))]
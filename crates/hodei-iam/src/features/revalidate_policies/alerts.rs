@@ -0,0 +1,35 @@
+//! Alerts for policies disabled by a revalidation
+//!
+//! [`EventBusPolicyDisabledAlerts`] publishes each [`PolicyDisabled`] alert
+//! as a domain event, so whoever reviews disabled policies can subscribe to
+//! them like to any other event.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kernel::application::ports::event_bus::EventPublisher;
+use tracing::warn;
+
+use super::dto::PolicyDisabled;
+use super::ports::PolicyDisabledAlertPort;
+
+/// Alert publisher that forwards alerts to an event bus
+pub struct EventBusPolicyDisabledAlerts<P> {
+    publisher: Arc<P>,
+}
+
+impl<P> EventBusPolicyDisabledAlerts<P> {
+    pub fn new(publisher: Arc<P>) -> Self {
+        Self { publisher }
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> PolicyDisabledAlertPort for EventBusPolicyDisabledAlerts<P> {
+    async fn publish(&self, alert: PolicyDisabled) {
+        let policy_id = alert.policy_id.clone();
+        if let Err(e) = self.publisher.publish(alert).await {
+            warn!(%policy_id, error = %e, "Failed to publish policy disabled alert");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Data Transfer Objects for revalidate_policies feature
+
+use chrono::{DateTime, Utc};
+use kernel::application::ports::event_bus::DomainEvent;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Policies read and validated per batch when no size is configured
+pub const DEFAULT_REVALIDATION_BATCH_SIZE: usize = 50;
+
+/// Pause between batches when none is configured
+pub const DEFAULT_REVALIDATION_BATCH_PAUSE: Duration = Duration::from_millis(20);
+
+/// Event published when a new schema version is persisted
+pub use kernel::SchemaChanged;
+
+/// Alert event published when a policy is disabled for no longer validating
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyDisabled {
+    pub policy_id: String,
+    /// Why the policy was disabled, as stored with it
+    pub reason: String,
+    /// Schema version the policy failed to validate against
+    pub schema_version: String,
+    /// Timestamp when the policy was disabled
+    pub disabled_at: DateTime<Utc>,
+}
+
+impl DomainEvent for PolicyDisabled {
+    fn event_type(&self) -> &'static str {
+        "iam.policy.disabled"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.policy_id.clone())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Policy")
+    }
+}
+
+/// How a revalidation walks the stored policies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RevalidationConfig {
+    /// Policies read and validated at a time
+    pub batch_size: usize,
+    /// Pause between batches, so a revalidation does not starve other work
+    pub batch_pause: Duration,
+}
+
+impl Default for RevalidationConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: DEFAULT_REVALIDATION_BATCH_SIZE,
+            batch_pause: DEFAULT_REVALIDATION_BATCH_PAUSE,
+        }
+    }
+}
+
+impl RevalidationConfig {
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn with_batch_pause(mut self, batch_pause: Duration) -> Self {
+        self.batch_pause = batch_pause;
+        self
+    }
+}
+
+/// A stored policy as seen by a revalidation
+pub use crate::features::policy_walk::dto::StoredPolicy;
+
+/// A policy disabled by a revalidation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisabledPolicy {
+    pub policy_id: String,
+    pub reason: String,
+}
+
+/// Result of revalidating every stored policy against a schema
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevalidationReport {
+    pub schema_version: String,
+    /// Enabled policies validated
+    pub checked: usize,
+    /// Policies skipped because they were already disabled
+    pub already_disabled: usize,
+    /// Policies disabled by this revalidation
+    pub disabled: Vec<DisabledPolicy>,
+}
@@ -0,0 +1,26 @@
+use crate::features::policy_walk::error::PolicyWalkError;
+use thiserror::Error;
+
+/// Errors that can occur when revalidating policies
+///
+/// Policies disabled before the error stay disabled; a new revalidation
+/// picks up where this one stopped.
+#[derive(Debug, Error)]
+pub enum RevalidatePoliciesError {
+    #[error("Invalid revalidation config: {0}")]
+    InvalidConfig(String),
+
+    #[error("Policy validation service error: {0}")]
+    ValidationFailed(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<PolicyWalkError> for RevalidatePoliciesError {
+    fn from(e: PolicyWalkError) -> Self {
+        match e {
+            PolicyWalkError::RepositoryError(message) => Self::RepositoryError(message),
+        }
+    }
+}
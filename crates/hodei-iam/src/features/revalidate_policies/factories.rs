@@ -0,0 +1,55 @@
+//! Factory for creating the SchemaChangeRevalidator
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc of the use case, which is both its port and an
+//!   event handler to subscribe
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::revalidate_policies::dto::RevalidationConfig;
+use crate::features::revalidate_policies::ports::{
+    PolicyDisabledAlertPort, PolicyRevalidationStorePort, PolicyValidator,
+};
+use crate::features::revalidate_policies::use_case::SchemaChangeRevalidator;
+
+/// Create the SchemaChangeRevalidator with injected dependencies
+///
+/// # Arguments
+///
+/// * `store` - Port for reading and disabling policies
+/// * `validator` - Port validating policies against the current schema
+/// * `alerts` - Port alerting about disabled policies
+/// * `config` - Batching of the revalidation
+///
+/// # Returns
+///
+/// Arc<SchemaChangeRevalidator> - Usable as `SchemaChangeRevalidatorPort`
+/// and subscribable to `SchemaChanged` events
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let revalidator = create_schema_change_revalidator(
+///     policy_adapter,
+///     validator,
+///     Arc::new(EventBusPolicyDisabledAlerts::new(bus.clone())),
+///     RevalidationConfig::default(),
+/// );
+/// bus.subscribe::<SchemaChanged, _>(revalidator.clone()).await?;
+/// ```
+pub fn create_schema_change_revalidator(
+    store: Arc<dyn PolicyRevalidationStorePort>,
+    validator: Arc<dyn PolicyValidator>,
+    alerts: Arc<dyn PolicyDisabledAlertPort>,
+    config: RevalidationConfig,
+) -> Arc<SchemaChangeRevalidator> {
+    info!("Creating SchemaChangeRevalidator");
+    Arc::new(
+        SchemaChangeRevalidator::new(store, validator)
+            .with_alerts(alerts)
+            .with_config(config),
+    )
+}
@@ -0,0 +1,102 @@
+//! Mock implementations for testing Revalidate Policies feature
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::dto::PolicyDisabled;
+use super::error::RevalidatePoliciesError;
+use super::ports::{PolicyDisabledAlertPort, PolicyRevalidationStorePort};
+use crate::features::create_policy::ports::{
+    PolicyValidationError, PolicyValidator, ValidationResult,
+};
+use crate::features::policy_walk::mocks::MockPolicyWalkStore;
+use hodei_policies::features::validate_policy::dto::ValidatePolicyCommand;
+
+/// Mock PolicyValidator for testing
+///
+/// Rejects policies containing any of the given markers, standing in for
+/// the entity types or actions a new schema dropped.
+pub struct MockPolicyValidator {
+    rejected: Vec<String>,
+    should_fail: bool,
+}
+
+impl MockPolicyValidator {
+    pub fn rejecting(rejected: &[&str]) -> Self {
+        Self {
+            rejected: rejected.iter().map(ToString::to_string).collect(),
+            should_fail: false,
+        }
+    }
+
+    pub fn with_service_error() -> Self {
+        Self {
+            rejected: Vec::new(),
+            should_fail: true,
+        }
+    }
+}
+
+#[async_trait]
+impl PolicyValidator for MockPolicyValidator {
+    async fn validate(
+        &self,
+        command: ValidatePolicyCommand,
+    ) -> Result<ValidationResult, PolicyValidationError> {
+        if self.should_fail {
+            return Err(PolicyValidationError::ValidationError(
+                "Mock validation service error".to_string(),
+            ));
+        }
+        let errors: Vec<String> = self
+            .rejected
+            .iter()
+            .filter(|marker| command.content.contains(marker.as_str()))
+            .map(|marker| format!("unrecognized entity type `{}`", marker))
+            .collect();
+        Ok(ValidationResult {
+            is_valid: errors.is_empty(),
+            errors,
+            annotations: HashMap::new(),
+        })
+    }
+}
+
+/// Disabling for the shared walk mock, which holds the policies
+#[async_trait]
+impl PolicyRevalidationStorePort for MockPolicyWalkStore {
+    async fn disable_policy(
+        &self,
+        policy_id: &str,
+        reason: &str,
+    ) -> Result<(), RevalidatePoliciesError> {
+        if self.disable(policy_id, reason) {
+            Ok(())
+        } else {
+            Err(RevalidatePoliciesError::RepositoryError(format!(
+                "Policy not found: {}",
+                policy_id
+            )))
+        }
+    }
+}
+
+/// Mock PolicyDisabledAlertPort recording every alert
+#[derive(Default)]
+pub struct MockPolicyDisabledAlertPort {
+    alerts: Mutex<Vec<PolicyDisabled>>,
+}
+
+impl MockPolicyDisabledAlertPort {
+    pub fn alerts(&self) -> Vec<PolicyDisabled> {
+        self.alerts.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl PolicyDisabledAlertPort for MockPolicyDisabledAlertPort {
+    async fn publish(&self, alert: PolicyDisabled) {
+        self.alerts.lock().unwrap().push(alert);
+    }
+}
@@ -0,0 +1,42 @@
+//! revalidate_policies Feature (Vertical Slice)
+//!
+//! This module implements revalidating the stored policies when the schema
+//! changes, following VSA. Policies that a new schema makes invalid would
+//! otherwise only fail at evaluation time; instead:
+//!
+//! - a `SchemaChanged` event starts a revalidation in the background
+//! - policies are read and validated in batches, one revalidation at a time
+//! - each policy that no longer validates is disabled with the reason, for
+//!   manual review, and a `PolicyDisabled` alert is published
+//!
+//! Structure:
+//! - dto.rs              -> Events, config & report DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interfaces (ISP)
+//! - use_case.rs         -> Core business logic (SchemaChangeRevalidator)
+//! - alerts.rs           -> Event bus adapter for the alerts
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod alerts;
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use alerts::EventBusPolicyDisabledAlerts;
+pub use dto::{
+    DisabledPolicy, PolicyDisabled, RevalidationConfig, RevalidationReport, SchemaChanged,
+    StoredPolicy,
+};
+pub use error::RevalidatePoliciesError;
+pub use ports::{
+    PolicyDisabledAlertPort, PolicyRevalidationStorePort, SchemaChangeRevalidatorPort,
+};
+pub use use_case::SchemaChangeRevalidator;
@@ -0,0 +1,58 @@
+use super::dto::{PolicyDisabled, RevalidationReport};
+use super::error::RevalidatePoliciesError;
+use async_trait::async_trait;
+
+/// Re-export the policy validation port used by create_policy
+///
+/// Validating against the current schema is the validator's concern.
+pub use crate::features::create_policy::ports::PolicyValidator;
+
+/// Re-export the shared policy walk port, read in batches
+pub use crate::features::policy_walk::ports::PolicyWalkStorePort;
+
+/// Port for walking the stored policies and disabling invalid ones
+///
+/// Following the Interface Segregation Principle (ISP), this port adds
+/// only the operation the revalidate_policies feature needs to the walk.
+#[async_trait]
+pub trait PolicyRevalidationStorePort: PolicyWalkStorePort {
+    /// Mark a policy disabled with `reason`
+    ///
+    /// Disabled policies are kept, for manual review, but no longer apply
+    /// to any principal.
+    async fn disable_policy(
+        &self,
+        policy_id: &str,
+        reason: &str,
+    ) -> Result<(), RevalidatePoliciesError>;
+}
+
+/// Port for alerting about disabled policies
+///
+/// Alerts are fire-and-forget; a failure to deliver one never undoes the
+/// disabling.
+#[async_trait]
+pub trait PolicyDisabledAlertPort: Send + Sync {
+    async fn publish(&self, alert: PolicyDisabled);
+}
+
+/// Port for revalidating every stored policy after a schema change
+///
+/// This port defines the contract for executing the revalidation, on demand
+/// or from a schema-change event.
+#[async_trait]
+pub trait SchemaChangeRevalidatorPort: Send + Sync {
+    /// Validate every enabled policy against the current schema, disabling
+    /// the ones that no longer validate
+    ///
+    /// # Arguments
+    /// * `schema_version` - Version of the schema the policies are checked against, for the report and reasons
+    ///
+    /// # Returns
+    /// * `Ok(RevalidationReport)` with what was checked and disabled
+    /// * `Err(RevalidatePoliciesError)` if the revalidation stopped early
+    async fn revalidate(
+        &self,
+        schema_version: &str,
+    ) -> Result<RevalidationReport, RevalidatePoliciesError>;
+}
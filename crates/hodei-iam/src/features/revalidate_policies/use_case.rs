@@ -0,0 +1,219 @@
+use super::dto::{
+    DisabledPolicy, PolicyDisabled, RevalidationConfig, RevalidationReport, SchemaChanged,
+    StoredPolicy,
+};
+use super::error::RevalidatePoliciesError;
+use super::ports::{
+    PolicyDisabledAlertPort, PolicyRevalidationStorePort, PolicyValidator,
+    SchemaChangeRevalidatorPort,
+};
+use crate::features::policy_walk::walk::PolicyWalk;
+use async_trait::async_trait;
+use chrono::Utc;
+use hodei_policies::features::validate_policy::dto::ValidatePolicyCommand;
+use kernel::application::ports::event_bus::{EventEnvelope, EventHandler};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, info, instrument, warn};
+
+/// Revalidates every stored policy when the schema changes
+///
+/// A schema change can make policies that were valid invalid, and that
+/// would otherwise only show at evaluation time. The revalidator:
+/// 1. Walks the stored policies in batches of `batch_size`, pausing between
+///    batches, so a revalidation never loads the whole store at once
+/// 2. Validates each enabled policy against the current schema
+/// 3. Disables the ones that fail, storing the reason for manual review,
+///    and publishes a [`PolicyDisabled`] alert for each
+///
+/// Disabled policies are never re-enabled automatically, even if a later
+/// schema would accept them again.
+///
+/// Subscribed to [`SchemaChanged`], it runs on the event bus's task. One
+/// revalidation runs at a time; events for a schema version already
+/// revalidated, such as a burst of redeliveries, are skipped.
+pub struct SchemaChangeRevalidator {
+    store: Arc<dyn PolicyRevalidationStorePort>,
+    validator: Arc<dyn PolicyValidator>,
+    alerts: Option<Arc<dyn PolicyDisabledAlertPort>>,
+    config: RevalidationConfig,
+    /// Schema version of the last completed revalidation; held while one runs
+    last_revalidated: Mutex<Option<String>>,
+}
+
+impl SchemaChangeRevalidator {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `store` - Implementation of PolicyRevalidationStorePort for reading and disabling policies
+    /// * `validator` - Implementation of PolicyValidator checking policies against the current schema
+    pub fn new(
+        store: Arc<dyn PolicyRevalidationStorePort>,
+        validator: Arc<dyn PolicyValidator>,
+    ) -> Self {
+        Self {
+            store,
+            validator,
+            alerts: None,
+            config: RevalidationConfig::default(),
+            last_revalidated: Mutex::new(None),
+        }
+    }
+
+    /// Walk the policies with `config` instead of the default batching
+    pub fn with_config(mut self, config: RevalidationConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Publish an alert for every disabled policy through `alerts`
+    pub fn with_alerts(mut self, alerts: Arc<dyn PolicyDisabledAlertPort>) -> Self {
+        self.alerts = Some(alerts);
+        self
+    }
+
+    pub fn config(&self) -> RevalidationConfig {
+        self.config
+    }
+
+    /// Execute the revalidation
+    ///
+    /// # Arguments
+    /// * `schema_version` - Version of the schema the policies are checked against
+    ///
+    /// # Returns
+    /// * Ok(RevalidationReport) with what was checked and disabled
+    /// * Err(RevalidatePoliciesError) if the revalidation stopped early
+    pub async fn execute(
+        &self,
+        schema_version: &str,
+    ) -> Result<RevalidationReport, RevalidatePoliciesError> {
+        let mut last_revalidated = self.last_revalidated.lock().await;
+        let report = self.run(schema_version).await?;
+        *last_revalidated = Some(schema_version.to_string());
+        Ok(report)
+    }
+
+    #[instrument(name = "revalidate_policies", skip(self), fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        batch_size = self.config.batch_size
+    ))]
+    async fn run(
+        &self,
+        schema_version: &str,
+    ) -> Result<RevalidationReport, RevalidatePoliciesError> {
+        if self.config.batch_size == 0 {
+            return Err(RevalidatePoliciesError::InvalidConfig(
+                "Batch size must be at least 1".to_string(),
+            ));
+        }
+
+        let mut report = RevalidationReport {
+            schema_version: schema_version.to_string(),
+            ..Default::default()
+        };
+        let mut walk = PolicyWalk::new(self.store.as_ref(), self.config.batch_size);
+        while let Some(batch) = walk.next_batch().await? {
+            for policy in batch {
+                if policy.is_disabled() {
+                    report.already_disabled += 1;
+                    continue;
+                }
+                report.checked += 1;
+                if let Some(disabled) = self.revalidate_policy(policy, schema_version).await? {
+                    report.disabled.push(disabled);
+                }
+            }
+
+            if walk.is_done() {
+                break;
+            }
+            debug!(checked = report.checked, "Policy revalidation batch done");
+            if !self.config.batch_pause.is_zero() {
+                tokio::time::sleep(self.config.batch_pause).await;
+            }
+        }
+
+        info!(
+            checked = report.checked,
+            disabled = report.disabled.len(),
+            "Policies revalidated"
+        );
+        Ok(report)
+    }
+
+    /// Validate one policy, disabling it if it no longer validates
+    async fn revalidate_policy(
+        &self,
+        policy: StoredPolicy,
+        schema_version: &str,
+    ) -> Result<Option<DisabledPolicy>, RevalidatePoliciesError> {
+        let result = self
+            .validator
+            .validate(ValidatePolicyCommand {
+                content: policy.content,
+            })
+            .await
+            .map_err(|e| RevalidatePoliciesError::ValidationFailed(e.to_string()))?;
+        if result.is_valid && result.errors.is_empty() {
+            return Ok(None);
+        }
+
+        let errors = if result.errors.is_empty() {
+            "policy is invalid".to_string()
+        } else {
+            result.errors.join(", ")
+        };
+        let reason = format!(
+            "Disabled after schema change: invalid against schema {}: {}",
+            schema_version, errors
+        );
+        self.store.disable_policy(&policy.id, &reason).await?;
+        warn!(policy_id = %policy.id, %reason, "Policy disabled");
+
+        if let Some(alerts) = &self.alerts {
+            alerts
+                .publish(PolicyDisabled {
+                    policy_id: policy.id.clone(),
+                    reason: reason.clone(),
+                    schema_version: schema_version.to_string(),
+                    disabled_at: Utc::now(),
+                })
+                .await;
+        }
+        Ok(Some(DisabledPolicy {
+            policy_id: policy.id,
+            reason,
+        }))
+    }
+}
+
+#[async_trait]
+impl SchemaChangeRevalidatorPort for SchemaChangeRevalidator {
+    async fn revalidate(
+        &self,
+        schema_version: &str,
+    ) -> Result<RevalidationReport, RevalidatePoliciesError> {
+        self.execute(schema_version).await
+    }
+}
+
+#[async_trait]
+impl EventHandler<SchemaChanged> for SchemaChangeRevalidator {
+    fn name(&self) -> &'static str {
+        "schema-change-revalidator"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<SchemaChanged>) -> anyhow::Result<()> {
+        let schema_version = &envelope.event.schema_version;
+        let mut last_revalidated = self.last_revalidated.lock().await;
+        if last_revalidated.as_deref() == Some(schema_version.as_str()) {
+            debug!(%schema_version, "Schema version already revalidated, skipping");
+            return Ok(());
+        }
+
+        self.run(schema_version).await?;
+        *last_revalidated = Some(schema_version.clone());
+        Ok(())
+    }
+}
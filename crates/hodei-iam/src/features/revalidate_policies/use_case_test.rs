@@ -0,0 +1,157 @@
+//! Unit tests for revalidate_policies use case
+//!
+//! These tests verify the behavior of the SchemaChangeRevalidator in
+//! isolation, using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use kernel::application::ports::event_bus::{EventEnvelope, EventHandler};
+
+    use crate::features::policy_walk::mocks::MockPolicyWalkStore;
+    use crate::features::revalidate_policies::{
+        dto::{RevalidationConfig, SchemaChanged},
+        error::RevalidatePoliciesError,
+        mocks::{MockPolicyDisabledAlertPort, MockPolicyValidator},
+        use_case::SchemaChangeRevalidator,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn store() -> Arc<MockPolicyWalkStore> {
+        Arc::new(
+            MockPolicyWalkStore::new()
+                .with_policy(
+                    "read-docs",
+                    "permit(principal, action, resource is Docs::Document);",
+                )
+                .with_policy(
+                    "read-legacy",
+                    "permit(principal, action, resource is Legacy::File);",
+                )
+                .with_policy("deny-all", "forbid(principal, action, resource);"),
+        )
+    }
+
+    fn revalidator(store: Arc<MockPolicyWalkStore>) -> SchemaChangeRevalidator {
+        SchemaChangeRevalidator::new(
+            store,
+            Arc::new(MockPolicyValidator::rejecting(&["Legacy::"])),
+        )
+        .with_config(RevalidationConfig::default().with_batch_pause(Duration::ZERO))
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_newly_invalid_policies_are_disabled_with_the_reason() {
+        let store = store();
+        let alerts = Arc::new(MockPolicyDisabledAlertPort::default());
+
+        let report = revalidator(store.clone())
+            .with_alerts(alerts.clone())
+            .execute("v2")
+            .await
+            .unwrap();
+
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.disabled.len(), 1);
+        assert_eq!(report.disabled[0].policy_id, "read-legacy");
+        let reason = store.disabled_reason("read-legacy").unwrap();
+        assert!(reason.contains("schema v2"));
+        assert!(reason.contains("Legacy::"));
+        assert_eq!(store.disabled_reason("read-docs"), None);
+
+        let alerts = alerts.alerts();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].policy_id, "read-legacy");
+        assert_eq!(alerts[0].reason, reason);
+        assert_eq!(alerts[0].schema_version, "v2");
+    }
+
+    #[tokio::test]
+    async fn test_policies_are_walked_in_batches() {
+        let store = Arc::new((0..5).fold(MockPolicyWalkStore::new(), |store, n| {
+            store.with_policy(
+                &format!("policy-{n}"),
+                "permit(principal, action, resource);",
+            )
+        }));
+
+        let report = revalidator(store.clone())
+            .with_config(RevalidationConfig::default().with_batch_size(2))
+            .execute("v2")
+            .await
+            .unwrap();
+
+        assert_eq!(report.checked, 5);
+        assert_eq!(store.pages(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_policies_are_not_revalidated() {
+        let store = store();
+        let revalidator = revalidator(store.clone());
+        revalidator.execute("v2").await.unwrap();
+        let reason = store.disabled_reason("read-legacy");
+
+        let report = revalidator.execute("v3").await.unwrap();
+
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.already_disabled, 1);
+        assert!(report.disabled.is_empty());
+        assert_eq!(store.disabled_reason("read-legacy"), reason);
+    }
+
+    #[tokio::test]
+    async fn test_schema_change_event_runs_once_per_version() {
+        let store = store();
+        let revalidator = revalidator(store.clone());
+
+        for _ in 0..3 {
+            revalidator
+                .handle(EventEnvelope::new(SchemaChanged::new("v2", "schema-2")))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(store.pages(), 1);
+        assert!(store.disabled_reason("read-legacy").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_validation_service_error_stops_the_revalidation() {
+        let store = store();
+        let revalidator = SchemaChangeRevalidator::new(
+            store.clone(),
+            Arc::new(MockPolicyValidator::with_service_error()),
+        );
+
+        let result = revalidator.execute("v2").await;
+
+        assert!(matches!(
+            result,
+            Err(RevalidatePoliciesError::ValidationFailed(_))
+        ));
+        assert_eq!(store.disabled_reason("read-legacy"), None);
+    }
+
+    #[tokio::test]
+    async fn test_zero_batch_size_is_rejected() {
+        let revalidator =
+            revalidator(store()).with_config(RevalidationConfig::default().with_batch_size(0));
+
+        let result = revalidator.execute("v2").await;
+
+        assert!(matches!(
+            result,
+            Err(RevalidatePoliciesError::InvalidConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+//! Data Transfer Objects for search_policies feature
+
+use hodei_policies::features::match_policy::dto::{MatchedFragment, PolicyEffect, PolicyQuery};
+use kernel::Hrn;
+use serde::{Deserialize, Serialize};
+
+/// Policies read and matched per batch
+pub const DEFAULT_SEARCH_BATCH_SIZE: usize = 100;
+
+/// Query to search the stored policies by content
+///
+/// Every criterion set must match; at least one is required. See
+/// [`PolicyQuery`] for how each criterion is matched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchPoliciesQuery {
+    /// Effect of the policy
+    #[serde(default)]
+    pub effect: Option<PolicyEffect>,
+
+    /// Action the policy applies to, e.g. `s3:Delete` or `Iam::Action::"CreateUser"`
+    #[serde(default)]
+    pub action: Option<String>,
+
+    /// Resource type the policy applies to, e.g. `Document` or `Docs::Document`
+    #[serde(default)]
+    pub resource_type: Option<String>,
+
+    /// Entity referenced anywhere in the policy, e.g. `Iam::User::"alice"`
+    #[serde(default)]
+    pub entity: Option<String>,
+
+    /// Whether to search disabled policies too
+    #[serde(default)]
+    pub include_disabled: bool,
+}
+
+impl SearchPoliciesQuery {
+    pub fn with_effect(mut self, effect: PolicyEffect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+
+    pub fn with_action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    pub fn with_resource_type(mut self, resource_type: impl Into<String>) -> Self {
+        self.resource_type = Some(resource_type.into());
+        self
+    }
+
+    pub fn with_entity(mut self, entity: impl Into<String>) -> Self {
+        self.entity = Some(entity.into());
+        self
+    }
+
+    pub fn including_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
+    /// The criteria, as hodei-policies matches them
+    pub fn criteria(&self) -> PolicyQuery {
+        PolicyQuery {
+            effect: self.effect,
+            action: self.action.clone(),
+            resource_type: self.resource_type.clone(),
+            entity: self.entity.clone(),
+        }
+    }
+}
+
+/// A policy matching the query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicySearchMatch {
+    pub policy_hrn: Hrn,
+    pub disabled: bool,
+    /// Fragments of the policy that matched, in Cedar syntax
+    pub fragments: Vec<MatchedFragment>,
+}
+
+/// Result of searching the stored policies
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchPoliciesResponse {
+    /// Matching policies, in ID order
+    pub matches: Vec<PolicySearchMatch>,
+    /// Policies matched against the query
+    pub searched: usize,
+    /// Policies that could not be parsed, and so could not be searched
+    pub unparsable: Vec<Hrn>,
+}
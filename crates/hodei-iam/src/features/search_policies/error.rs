@@ -0,0 +1,23 @@
+use crate::features::policy_walk::error::PolicyWalkError;
+use thiserror::Error;
+
+/// Errors that can occur when searching policies
+#[derive(Debug, Error)]
+pub enum SearchPoliciesError {
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
+    #[error("Schema error: {0}")]
+    SchemaError(String),
+
+    #[error("Repository error: {0}")]
+    RepositoryError(String),
+}
+
+impl From<PolicyWalkError> for SearchPoliciesError {
+    fn from(e: PolicyWalkError) -> Self {
+        match e {
+            PolicyWalkError::RepositoryError(message) => Self::RepositoryError(message),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Factory for creating the SearchPoliciesUseCase
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::search_policies::ports::{
+    PolicyWalkStorePort, SchemaStoragePort, SearchPoliciesPort,
+};
+use crate::features::search_policies::use_case::SearchPoliciesUseCase;
+
+/// Create the SearchPoliciesUseCase with injected dependencies
+///
+/// # Arguments
+///
+/// * `store` - Port for reading the stored policies
+/// * `schema_storage` - Port for the latest schema, to match action groups
+///
+/// # Returns
+///
+/// Arc<dyn SearchPoliciesPort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let search = create_search_policies_use_case(policy_adapter, schema_storage);
+/// let response = search
+///     .search(SearchPoliciesQuery::default().with_action("s3:Delete"))
+///     .await?;
+/// ```
+pub fn create_search_policies_use_case(
+    store: Arc<dyn PolicyWalkStorePort>,
+    schema_storage: Arc<dyn SchemaStoragePort>,
+) -> Arc<dyn SearchPoliciesPort> {
+    info!("Creating SearchPoliciesUseCase");
+    Arc::new(SearchPoliciesUseCase::new(store).with_schema_storage(schema_storage))
+}
@@ -0,0 +1,53 @@
+//! Mock implementations for testing Search Policies feature
+
+use async_trait::async_trait;
+use hodei_policies::build_schema::error::BuildSchemaError;
+
+use super::ports::SchemaStoragePort;
+
+/// Mock SchemaStoragePort holding at most one schema, as the latest
+pub struct MockSchemaStorage {
+    schema: Option<String>,
+}
+
+impl MockSchemaStorage {
+    pub fn with_schema(schema: &str) -> Self {
+        Self {
+            schema: Some(schema.to_string()),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self { schema: None }
+    }
+}
+
+#[async_trait]
+impl SchemaStoragePort for MockSchemaStorage {
+    async fn save_schema(
+        &self,
+        _schema_json: String,
+        _version: Option<String>,
+    ) -> Result<String, BuildSchemaError> {
+        Ok("schema:latest".to_string())
+    }
+
+    async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
+        Ok(self.schema.clone())
+    }
+
+    async fn get_schema_by_version(
+        &self,
+        _version: &str,
+    ) -> Result<Option<String>, BuildSchemaError> {
+        Ok(None)
+    }
+
+    async fn delete_schema(&self, _schema_id: &str) -> Result<bool, BuildSchemaError> {
+        Ok(false)
+    }
+
+    async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,35 @@
+//! search_policies Feature (Vertical Slice)
+//!
+//! This module implements searching the stored policies by content, following
+//! VSA. Audits need to answer questions such as "which policies grant
+//! `s3:Delete`?" across every policy; a text search would miss policies that
+//! spell the action differently or name a group it belongs to. Instead:
+//!
+//! - each policy is parsed and matched on its structure by hodei-policies
+//! - queries combine an effect, an action, a resource type and an entity
+//! - with a schema, actions also match the action groups they belong to
+//! - matches carry the policy HRN and the fragments that matched
+//!
+//! Structure:
+//! - dto.rs              -> Query, match & response DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interfaces (ISP)
+//! - use_case.rs         -> Core business logic (SearchPoliciesUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{PolicySearchMatch, SearchPoliciesQuery, SearchPoliciesResponse};
+pub use error::SearchPoliciesError;
+pub use ports::SearchPoliciesPort;
+pub use use_case::SearchPoliciesUseCase;
@@ -0,0 +1,26 @@
+use super::dto::{SearchPoliciesQuery, SearchPoliciesResponse};
+use super::error::SearchPoliciesError;
+use async_trait::async_trait;
+
+/// Re-export the schema storage port, read for the action groups
+pub use hodei_policies::build_schema::ports::SchemaStoragePort;
+
+/// Re-export the shared policy walk port, read in batches
+pub use crate::features::policy_walk::ports::PolicyWalkStorePort;
+
+/// Port for the SearchPolicies use case
+///
+/// This port defines the contract for searching policies by content.
+#[async_trait]
+pub trait SearchPoliciesPort: Send + Sync {
+    /// Find the policies matching `query`
+    ///
+    /// # Returns
+    /// * `Ok(SearchPoliciesResponse)` with the matches and their fragments
+    /// * `Err(SearchPoliciesError)` if the query is invalid or the search
+    ///   stopped early
+    async fn search(
+        &self,
+        query: SearchPoliciesQuery,
+    ) -> Result<SearchPoliciesResponse, SearchPoliciesError>;
+}
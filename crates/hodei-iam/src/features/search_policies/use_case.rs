@@ -0,0 +1,157 @@
+use super::dto::{
+    DEFAULT_SEARCH_BATCH_SIZE, PolicySearchMatch, SearchPoliciesQuery, SearchPoliciesResponse,
+};
+use super::error::SearchPoliciesError;
+use super::ports::{PolicyWalkStorePort, SchemaStoragePort, SearchPoliciesPort};
+use crate::features::policy_walk::walk::PolicyWalk;
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::error::MatchPolicyError;
+use hodei_policies::features::match_policy::matcher::PolicyMatcher;
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
+
+/// Searches the stored policies by content
+///
+/// Each policy is parsed and matched on its structure, so a query for an
+/// action finds every policy that applies to it, however the policy is
+/// written. The search:
+/// 1. Walks the stored policies in batches of `batch_size`, so a search
+///    never loads the whole store at once
+/// 2. Matches each policy against the query, skipping disabled ones unless
+///    asked for
+/// 3. Returns the HRN of each match with the fragments that matched
+///
+/// With a schema storage, the latest schema is read to also match the
+/// action groups the queried action belongs to. Policies that cannot be
+/// parsed are reported rather than failing the search.
+pub struct SearchPoliciesUseCase {
+    store: Arc<dyn PolicyWalkStorePort>,
+    schema_storage: Option<Arc<dyn SchemaStoragePort>>,
+    batch_size: usize,
+}
+
+impl SearchPoliciesUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `store` - Implementation of PolicyWalkStorePort for reading policies
+    pub fn new(store: Arc<dyn PolicyWalkStorePort>) -> Self {
+        Self {
+            store,
+            schema_storage: None,
+            batch_size: DEFAULT_SEARCH_BATCH_SIZE,
+        }
+    }
+
+    /// Resolve action groups from the latest schema in `schema_storage`
+    pub fn with_schema_storage(mut self, schema_storage: Arc<dyn SchemaStoragePort>) -> Self {
+        self.schema_storage = Some(schema_storage);
+        self
+    }
+
+    /// Read and match `batch_size` policies at a time
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Execute the search
+    ///
+    /// # Arguments
+    /// * `query` - The criteria the policies must match
+    ///
+    /// # Returns
+    /// * Ok(SearchPoliciesResponse) with the matching policies
+    /// * Err(SearchPoliciesError) if the query is invalid or the search stopped early
+    #[instrument(name = "search_policies", skip(self), fields(
+        correlation_id = kernel::current_correlation_id().as_deref()
+    ))]
+    pub async fn execute(
+        &self,
+        query: SearchPoliciesQuery,
+    ) -> Result<SearchPoliciesResponse, SearchPoliciesError> {
+        if self.batch_size == 0 {
+            return Err(SearchPoliciesError::InvalidQuery(
+                "Batch size must be at least 1".to_string(),
+            ));
+        }
+        let criteria = query.criteria();
+        if criteria.is_empty() {
+            return Err(SearchPoliciesError::InvalidQuery(
+                "At least one of effect, action, resource_type or entity is required".to_string(),
+            ));
+        }
+        let matcher = self
+            .matcher(PolicyMatcher::new(&criteria).map_err(match_error)?)
+            .await?;
+
+        let mut response = SearchPoliciesResponse::default();
+        let mut walk = PolicyWalk::new(self.store.as_ref(), self.batch_size);
+        while let Some(batch) = walk.next_batch().await? {
+            for policy in batch {
+                let disabled = policy.is_disabled();
+                if disabled && !query.include_disabled {
+                    continue;
+                }
+                response.searched += 1;
+                match matcher.matches(&policy.content) {
+                    Ok(Some(fragments)) => response.matches.push(PolicySearchMatch {
+                        policy_hrn: policy.hrn,
+                        disabled,
+                        fragments,
+                    }),
+                    Ok(None) => {}
+                    Err(e) => {
+                        warn!(policy_id = %policy.id, error = %e, "Policy could not be searched");
+                        response.unparsable.push(policy.hrn);
+                    }
+                }
+            }
+            debug!(searched = response.searched, "Policy search batch done");
+        }
+
+        info!(
+            searched = response.searched,
+            matches = response.matches.len(),
+            "Policies searched"
+        );
+        Ok(response)
+    }
+
+    /// Add the latest schema's action groups to `matcher`, when there is a
+    /// schema to read them from
+    async fn matcher(&self, matcher: PolicyMatcher) -> Result<PolicyMatcher, SearchPoliciesError> {
+        let Some(schema_storage) = &self.schema_storage else {
+            return Ok(matcher);
+        };
+        let schema = schema_storage
+            .get_latest_schema()
+            .await
+            .map_err(|e| SearchPoliciesError::SchemaError(e.to_string()))?;
+        match schema {
+            Some(schema) => matcher.with_schema(&schema).map_err(match_error),
+            None => {
+                debug!("No schema registered, action groups are not resolved");
+                Ok(matcher)
+            }
+        }
+    }
+}
+
+fn match_error(e: MatchPolicyError) -> SearchPoliciesError {
+    match e {
+        MatchPolicyError::InvalidQuery(message) => SearchPoliciesError::InvalidQuery(message),
+        MatchPolicyError::SchemaError(message) => SearchPoliciesError::SchemaError(message),
+        MatchPolicyError::PolicyError(message) => SearchPoliciesError::RepositoryError(message),
+    }
+}
+
+#[async_trait]
+impl SearchPoliciesPort for SearchPoliciesUseCase {
+    async fn search(
+        &self,
+        query: SearchPoliciesQuery,
+    ) -> Result<SearchPoliciesResponse, SearchPoliciesError> {
+        self.execute(query).await
+    }
+}
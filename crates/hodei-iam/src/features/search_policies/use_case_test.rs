@@ -0,0 +1,221 @@
+//! Unit tests for search_policies use case
+//!
+//! These tests verify the behavior of the SearchPoliciesUseCase in
+//! isolation, using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use hodei_policies::features::match_policy::dto::{PolicyEffect, PolicyPart};
+
+    use crate::features::policy_walk::mocks::MockPolicyWalkStore;
+    use crate::features::search_policies::{
+        dto::{SearchPoliciesQuery, SearchPoliciesResponse},
+        error::SearchPoliciesError,
+        mocks::MockSchemaStorage,
+        use_case::SearchPoliciesUseCase,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    const SCHEMA: &str = r#"
+        namespace S3 {
+            entity Bucket;
+            entity Object in [Bucket];
+            entity User;
+            action "s3:Write";
+            action "s3:Delete" in ["s3:Write"] appliesTo {
+                principal: [User],
+                resource: [Object],
+            };
+            action "s3:Get" appliesTo {
+                principal: [User],
+                resource: [Object],
+            };
+        }
+    "#;
+
+    fn store() -> Arc<MockPolicyWalkStore> {
+        Arc::new(
+            MockPolicyWalkStore::new()
+                .with_policy(
+                    "cleanup",
+                    r#"permit(
+                        principal == S3::User::"janitor",
+                        action in [S3::Action::"s3:Get", S3::Action::"s3:Delete"],
+                        resource is S3::Object
+                    );"#,
+                )
+                .with_policy(
+                    "readers",
+                    r#"permit(principal, action == S3::Action::"s3:Get", resource);"#,
+                )
+                .with_policy(
+                    "writers",
+                    r#"permit(principal, action in S3::Action::"s3:Write", resource)
+                       when { principal == S3::User::"alice" };"#,
+                )
+                .with_policy(
+                    "no-deletes-in-logs",
+                    r#"forbid(principal, action == S3::Action::"s3:Delete", resource in S3::Bucket::"logs");"#,
+                )
+                .with_disabled_policy(
+                    "legacy",
+                    r#"permit(principal, action == S3::Action::"s3:Delete", resource);"#,
+                ),
+        )
+    }
+
+    fn ids(response: &SearchPoliciesResponse) -> Vec<String> {
+        response
+            .matches
+            .iter()
+            .map(|m| m.policy_hrn.resource_id().to_string())
+            .collect()
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_finds_policies_granting_an_action() {
+        let response = SearchPoliciesUseCase::new(store())
+            .execute(
+                SearchPoliciesQuery::default()
+                    .with_effect(PolicyEffect::Permit)
+                    .with_action("s3:Delete"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&response), vec!["cleanup"]);
+        assert_eq!(response.searched, 4);
+        let fragments = &response.matches[0].fragments;
+        assert_eq!(fragments[1].part, PolicyPart::Action);
+        assert_eq!(
+            fragments[1].text,
+            r#"action in [S3::Action::"s3:Get", S3::Action::"s3:Delete"]"#
+        );
+        assert_eq!(
+            response.matches[0].policy_hrn.to_string(),
+            "hrn:hodei:iam::default:Policy/cleanup"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_action_groups_are_matched_with_the_schema() {
+        let response = SearchPoliciesUseCase::new(store())
+            .with_schema_storage(Arc::new(MockSchemaStorage::with_schema(SCHEMA)))
+            .execute(SearchPoliciesQuery::default().with_action("s3:Delete"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ids(&response),
+            vec!["cleanup", "no-deletes-in-logs", "writers"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_without_a_registered_schema_groups_are_not_resolved() {
+        let response = SearchPoliciesUseCase::new(store())
+            .with_schema_storage(Arc::new(MockSchemaStorage::empty()))
+            .execute(SearchPoliciesQuery::default().with_action("s3:Delete"))
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&response), vec!["cleanup", "no-deletes-in-logs"]);
+    }
+
+    #[tokio::test]
+    async fn test_finds_entities_in_conditions() {
+        let response = SearchPoliciesUseCase::new(store())
+            .execute(SearchPoliciesQuery::default().with_entity(r#"S3::User::"alice""#))
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&response), vec!["writers"]);
+        assert_eq!(response.matches[0].fragments[0].part, PolicyPart::Condition);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_policies_are_searched_only_on_request() {
+        let query = SearchPoliciesQuery::default()
+            .with_action(r#"S3::Action::"s3:Delete""#)
+            .with_resource_type("Object");
+
+        let enabled = SearchPoliciesUseCase::new(store())
+            .execute(query.clone())
+            .await
+            .unwrap();
+        assert_eq!(ids(&enabled), vec!["cleanup"]);
+
+        let all = SearchPoliciesUseCase::new(store())
+            .execute(query.including_disabled())
+            .await
+            .unwrap();
+        assert_eq!(ids(&all), vec!["cleanup", "legacy"]);
+        assert!(all.matches[1].disabled);
+    }
+
+    #[tokio::test]
+    async fn test_policies_are_read_in_batches() {
+        let store = store();
+
+        let response = SearchPoliciesUseCase::new(store.clone())
+            .with_batch_size(2)
+            .execute(SearchPoliciesQuery::default().with_effect(PolicyEffect::Forbid))
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&response), vec!["no-deletes-in-logs"]);
+        assert_eq!(store.pages(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_unparsable_policies_are_reported() {
+        let store = Arc::new(
+            MockPolicyWalkStore::new()
+                .with_policy("broken", "permit(principal, action")
+                .with_policy("ok", "forbid(principal, action, resource);"),
+        );
+
+        let response = SearchPoliciesUseCase::new(store)
+            .execute(SearchPoliciesQuery::default().with_effect(PolicyEffect::Forbid))
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&response), vec!["ok"]);
+        assert_eq!(response.unparsable.len(), 1);
+        assert_eq!(response.unparsable[0].resource_id(), "broken");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_queries_are_rejected() {
+        let empty = SearchPoliciesUseCase::new(store())
+            .execute(SearchPoliciesQuery::default())
+            .await;
+        assert!(matches!(empty, Err(SearchPoliciesError::InvalidQuery(_))));
+
+        let bad_entity = SearchPoliciesUseCase::new(store())
+            .execute(SearchPoliciesQuery::default().with_entity("alice"))
+            .await;
+        assert!(matches!(
+            bad_entity,
+            Err(SearchPoliciesError::InvalidQuery(_))
+        ));
+
+        let bad_schema = SearchPoliciesUseCase::new(store())
+            .with_schema_storage(Arc::new(MockSchemaStorage::with_schema("not a schema {")))
+            .execute(SearchPoliciesQuery::default().with_action("s3:Delete"))
+            .await;
+        assert!(matches!(
+            bad_schema,
+            Err(SearchPoliciesError::SchemaError(_))
+        ));
+    }
+}
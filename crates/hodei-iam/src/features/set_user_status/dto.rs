@@ -0,0 +1,51 @@
+//! Data Transfer Objects for set_user_status feature
+
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use kernel::{HrnVisitor, PrincipalStatus, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Command to change the lifecycle status of a user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetUserStatusCommand {
+    pub user_hrn: String,
+    pub status: PrincipalStatus,
+}
+
+impl TenantScoped for SetUserStatusCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.user_hrn);
+    }
+}
+
+impl ActionTrait for SetUserStatusCommand {
+    fn name() -> &'static str {
+        "SetUserStatus"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("iam").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Iam::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Iam::User".to_string()
+    }
+}
+
+/// Event published when a user's status changes, shared with the authorizer
+/// caches that must forget the user's decisions
+pub use kernel::UserStatusChanged;
+
+/// Result of a status change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetUserStatusResponse {
+    pub user_hrn: String,
+    /// Status before the command
+    pub previous_status: PrincipalStatus,
+    /// Status after the command
+    pub status: PrincipalStatus,
+}
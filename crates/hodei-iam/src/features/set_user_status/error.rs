@@ -0,0 +1,18 @@
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+/// Errors that can occur when changing a user's status
+#[derive(Debug, Error)]
+pub enum SetUserStatusError {
+    #[error("Invalid user HRN: {0}")]
+    InvalidUserHrn(String),
+
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+
+    #[error("Failed to save user status: {0}")]
+    PersistenceError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
@@ -0,0 +1,41 @@
+//! Factory for creating the SetUserStatus use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::set_user_status::ports::{
+    SetUserStatusUseCasePort, UserStatusEventPort, UserStatusPort,
+};
+use crate::features::set_user_status::use_case::SetUserStatusUseCase;
+
+/// Create the SetUserStatus use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `status_port` - Port for reading and writing user status
+/// * `events` - Port announcing the changes
+///
+/// # Returns
+///
+/// Arc<dyn SetUserStatusUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let user_repo = Arc::new(SurrealUserAdapter::new(db));
+/// let events = Arc::new(EventBusIamEvents::new(bus.clone()));
+///
+/// let set_user_status = create_set_user_status_use_case(user_repo, events);
+/// ```
+pub fn create_set_user_status_use_case(
+    status_port: Arc<dyn UserStatusPort>,
+    events: Arc<dyn UserStatusEventPort>,
+) -> Arc<dyn SetUserStatusUseCasePort> {
+    info!("Creating SetUserStatus use case");
+    Arc::new(SetUserStatusUseCase::new(status_port).with_events(events))
+}
@@ -0,0 +1,103 @@
+//! Mock implementations for testing Set User Status feature
+
+use async_trait::async_trait;
+use kernel::{Hrn, PrincipalStatus};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::dto::UserStatusChanged;
+use super::error::SetUserStatusError;
+use super::ports::{UserStatusEventPort, UserStatusPort};
+
+/// Mock UserStatusPort for testing
+///
+/// Counts saves so tests can check that no-op changes are not written.
+pub struct MockUserStatusPort {
+    statuses: Mutex<HashMap<Hrn, PrincipalStatus>>,
+    saves: Mutex<usize>,
+    should_fail: bool,
+}
+
+impl MockUserStatusPort {
+    /// Create a mock with no users
+    pub fn new() -> Self {
+        Self {
+            statuses: Mutex::new(HashMap::new()),
+            saves: Mutex::new(0),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose saves fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a user with the given status
+    pub fn with_user(self, user_hrn: Hrn, status: PrincipalStatus) -> Self {
+        self.statuses.lock().unwrap().insert(user_hrn, status);
+        self
+    }
+
+    /// Current status of a user
+    pub fn status(&self, user_hrn: &Hrn) -> Option<PrincipalStatus> {
+        self.statuses.lock().unwrap().get(user_hrn).copied()
+    }
+
+    /// Number of saves made
+    pub fn saves(&self) -> usize {
+        *self.saves.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl UserStatusPort for MockUserStatusPort {
+    async fn find_status(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<PrincipalStatus>, SetUserStatusError> {
+        Ok(self.status(user_hrn))
+    }
+
+    async fn save_status(
+        &self,
+        user_hrn: &Hrn,
+        status: PrincipalStatus,
+    ) -> Result<(), SetUserStatusError> {
+        if self.should_fail {
+            return Err(SetUserStatusError::PersistenceError(
+                "Mock failure".to_string(),
+            ));
+        }
+        *self.saves.lock().unwrap() += 1;
+        match self.statuses.lock().unwrap().get_mut(user_hrn) {
+            Some(current) => {
+                *current = status;
+                Ok(())
+            }
+            None => Err(SetUserStatusError::UserNotFound(user_hrn.to_string())),
+        }
+    }
+}
+
+/// Mock UserStatusEventPort recording every event
+#[derive(Default)]
+pub struct MockUserStatusEventPort {
+    events: Mutex<Vec<UserStatusChanged>>,
+}
+
+impl MockUserStatusEventPort {
+    pub fn events(&self) -> Vec<UserStatusChanged> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl UserStatusEventPort for MockUserStatusEventPort {
+    async fn publish(&self, event: UserStatusChanged) {
+        self.events.lock().unwrap().push(event);
+    }
+}
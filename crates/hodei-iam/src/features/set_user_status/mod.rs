@@ -0,0 +1,32 @@
+//! set_user_status Feature (Vertical Slice)
+//!
+//! This module implements the user lifecycle feature for IAM following VSA.
+//! A user can be suspended (e.g. during an investigation) or deactivated
+//! without being deleted: its group memberships and policy attachments are
+//! kept, but get_effective_policies returns no policies for it, so
+//! authorization denies it until it is reinstated. Every change publishes a
+//! `UserStatusChanged` event so cached decisions for the user are dropped.
+//!
+//! Structure:
+//! - dto.rs              -> Command, Response & event DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interface (ISP)
+//! - use_case.rs         -> Core business logic (SetUserStatusUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{SetUserStatusCommand, SetUserStatusResponse, UserStatusChanged};
+pub use error::SetUserStatusError;
+pub use ports::{SetUserStatusUseCasePort, UserStatusEventPort, UserStatusPort};
+pub use use_case::SetUserStatusUseCase;
@@ -0,0 +1,75 @@
+use super::dto::{SetUserStatusCommand, SetUserStatusResponse, UserStatusChanged};
+use super::error::SetUserStatusError;
+use async_trait::async_trait;
+use kernel::{Hrn, PrincipalStatus};
+
+/// Port for reading and writing a user's lifecycle status
+///
+/// This port abstracts user status persistence.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the set_user_status feature.
+#[async_trait]
+pub trait UserStatusPort: Send + Sync {
+    /// Find the status of a user
+    ///
+    /// # Arguments
+    /// * `user_hrn` - The HRN of the user
+    ///
+    /// # Returns
+    /// * `Ok(Some(PrincipalStatus))` if the user was found
+    /// * `Ok(None)` if no user with that HRN exists
+    /// * `Err(SetUserStatusError)` if there was an error during lookup
+    async fn find_status(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<PrincipalStatus>, SetUserStatusError>;
+
+    /// Save the status of an existing user
+    ///
+    /// Only the status is written; group memberships and every other field
+    /// are left as they are.
+    ///
+    /// # Arguments
+    /// * `user_hrn` - The HRN of the user
+    /// * `status` - The new status
+    ///
+    /// # Returns
+    /// * `Ok(())` if the status was saved
+    /// * `Err(SetUserStatusError::UserNotFound)` if the user does not exist
+    /// * `Err(SetUserStatusError)` if there was an error saving it
+    async fn save_status(
+        &self,
+        user_hrn: &Hrn,
+        status: PrincipalStatus,
+    ) -> Result<(), SetUserStatusError>;
+}
+
+/// Port for announcing status changes
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the change.
+#[async_trait]
+pub trait UserStatusEventPort: Send + Sync {
+    async fn publish(&self, event: UserStatusChanged);
+}
+
+/// Port for the SetUserStatus use case
+///
+/// This port defines the contract for executing the set user status use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait SetUserStatusUseCasePort: Send + Sync {
+    /// Execute the set user status use case
+    ///
+    /// # Arguments
+    /// * `command` - The command with the user HRN and the new status
+    ///
+    /// # Returns
+    /// * `Ok(SetUserStatusResponse)` with the previous and new status
+    /// * `Err(SetUserStatusError)` if the status could not be changed
+    async fn execute(
+        &self,
+        command: SetUserStatusCommand,
+    ) -> Result<SetUserStatusResponse, SetUserStatusError>;
+}
@@ -0,0 +1,119 @@
+use super::dto::{SetUserStatusCommand, SetUserStatusResponse, UserStatusChanged};
+use super::error::SetUserStatusError;
+use super::ports::{SetUserStatusUseCasePort, UserStatusEventPort, UserStatusPort};
+use async_trait::async_trait;
+use kernel::{Hrn, TenantContext};
+use std::sync::Arc;
+use tracing::{Instrument, info, info_span, instrument};
+
+/// Use case for changing the lifecycle status of a user
+///
+/// This use case orchestrates the status change:
+/// 1. Validates and parses the user HRN
+/// 2. Finds the user's current status
+/// 3. Persists the new status, unless it is already set
+/// 4. Publishes a [`UserStatusChanged`] event for an actual change
+/// 5. Returns the previous and new status
+///
+/// Group memberships are not touched, so reinstating a suspended user
+/// restores exactly the access it had.
+pub struct SetUserStatusUseCase {
+    status_port: Arc<dyn UserStatusPort>,
+    events: Option<Arc<dyn UserStatusEventPort>>,
+}
+
+impl SetUserStatusUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `status_port` - Implementation of UserStatusPort for status persistence
+    pub fn new(status_port: Arc<dyn UserStatusPort>) -> Self {
+        Self {
+            status_port,
+            events: None,
+        }
+    }
+
+    /// Publish a change event for every status change
+    pub fn with_events(mut self, events: Arc<dyn UserStatusEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Execute the set user status use case
+    ///
+    /// # Arguments
+    /// * `cmd` - SetUserStatusCommand containing the user HRN and the new status
+    ///
+    /// # Returns
+    /// * Ok(SetUserStatusResponse) with the previous and new status
+    /// * Err(SetUserStatusError) if there was an error
+    #[instrument(name = "set_user_status", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        principal = %cmd.user_hrn,
+        status = %cmd.status
+    ))]
+    pub async fn execute(
+        &self,
+        cmd: SetUserStatusCommand,
+    ) -> Result<SetUserStatusResponse, SetUserStatusError> {
+        let user_hrn = Hrn::from_string(&cmd.user_hrn)
+            .ok_or_else(|| SetUserStatusError::InvalidUserHrn(cmd.user_hrn.clone()))?;
+
+        let previous_status = self
+            .status_port
+            .find_status(&user_hrn)
+            .await?
+            .ok_or_else(|| SetUserStatusError::UserNotFound(cmd.user_hrn.clone()))?;
+
+        if previous_status != cmd.status {
+            self.status_port
+                .save_status(&user_hrn, cmd.status)
+                .instrument(info_span!("persistence"))
+                .await?;
+            info!(
+                user = %cmd.user_hrn,
+                from = %previous_status,
+                to = %cmd.status,
+                "User status changed"
+            );
+            if let Some(events) = &self.events {
+                events
+                    .publish(UserStatusChanged {
+                        user_hrn,
+                        previous_status,
+                        status: cmd.status,
+                    })
+                    .await;
+            }
+        }
+
+        Ok(SetUserStatusResponse {
+            user_hrn: cmd.user_hrn,
+            previous_status,
+            status: cmd.status,
+        })
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The user must belong to the tenant, otherwise the command fails with
+    /// `CrossTenantAccess` before any lookup.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        cmd: SetUserStatusCommand,
+    ) -> Result<SetUserStatusResponse, SetUserStatusError> {
+        tenant.run(cmd, |cmd| self.execute(cmd)).await
+    }
+}
+
+#[async_trait]
+impl SetUserStatusUseCasePort for SetUserStatusUseCase {
+    async fn execute(
+        &self,
+        command: SetUserStatusCommand,
+    ) -> Result<SetUserStatusResponse, SetUserStatusError> {
+        self.execute(command).await
+    }
+}
@@ -0,0 +1,165 @@
+//! Unit tests for set_user_status use case
+//!
+//! These tests verify the behavior of the SetUserStatusUseCase in isolation,
+//! using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use kernel::{Hrn, PrincipalStatus, TenantContext};
+
+    use crate::features::set_user_status::{
+        dto::{SetUserStatusCommand, UserStatusChanged},
+        error::SetUserStatusError,
+        mocks::{MockUserStatusEventPort, MockUserStatusPort},
+        use_case::SetUserStatusUseCase,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn user_hrn(account: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            account.to_string(),
+            "User".to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn alice() -> Hrn {
+        user_hrn("account123", "alice")
+    }
+
+    fn command(user_hrn: &Hrn, status: PrincipalStatus) -> SetUserStatusCommand {
+        SetUserStatusCommand {
+            user_hrn: user_hrn.to_string(),
+            status,
+        }
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_suspend_and_reinstate_user() {
+        let port = Arc::new(MockUserStatusPort::new().with_user(alice(), PrincipalStatus::Active));
+        let use_case = SetUserStatusUseCase::new(port.clone());
+
+        let suspended = use_case
+            .execute(command(&alice(), PrincipalStatus::Suspended))
+            .await
+            .unwrap();
+        assert_eq!(suspended.previous_status, PrincipalStatus::Active);
+        assert_eq!(suspended.status, PrincipalStatus::Suspended);
+        assert_eq!(port.status(&alice()), Some(PrincipalStatus::Suspended));
+
+        let reinstated = use_case
+            .execute(command(&alice(), PrincipalStatus::Active))
+            .await
+            .unwrap();
+        assert_eq!(reinstated.previous_status, PrincipalStatus::Suspended);
+        assert_eq!(port.status(&alice()), Some(PrincipalStatus::Active));
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_status_is_not_saved() {
+        let port =
+            Arc::new(MockUserStatusPort::new().with_user(alice(), PrincipalStatus::Deactivated));
+        let use_case = SetUserStatusUseCase::new(port.clone());
+
+        let response = use_case
+            .execute(command(&alice(), PrincipalStatus::Deactivated))
+            .await
+            .unwrap();
+
+        assert_eq!(response.previous_status, PrincipalStatus::Deactivated);
+        assert_eq!(port.saves(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_status_change_is_published_once() {
+        let port = Arc::new(MockUserStatusPort::new().with_user(alice(), PrincipalStatus::Active));
+        let events = Arc::new(MockUserStatusEventPort::default());
+        let use_case = SetUserStatusUseCase::new(port).with_events(events.clone());
+
+        for _ in 0..2 {
+            use_case
+                .execute(command(&alice(), PrincipalStatus::Suspended))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            events.events(),
+            vec![UserStatusChanged {
+                user_hrn: alice(),
+                previous_status: PrincipalStatus::Active,
+                status: PrincipalStatus::Suspended,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_is_not_found() {
+        let use_case = SetUserStatusUseCase::new(Arc::new(MockUserStatusPort::new()));
+
+        let result = use_case
+            .execute(command(&alice(), PrincipalStatus::Suspended))
+            .await;
+
+        assert!(matches!(result, Err(SetUserStatusError::UserNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_user_hrn() {
+        let use_case = SetUserStatusUseCase::new(Arc::new(MockUserStatusPort::new()));
+
+        let result = use_case
+            .execute(SetUserStatusCommand {
+                user_hrn: "not-an-hrn".to_string(),
+                status: PrincipalStatus::Suspended,
+            })
+            .await;
+
+        assert!(matches!(result, Err(SetUserStatusError::InvalidUserHrn(_))));
+    }
+
+    #[tokio::test]
+    async fn test_persistence_error() {
+        let port = MockUserStatusPort::failing().with_user(alice(), PrincipalStatus::Active);
+        let use_case = SetUserStatusUseCase::new(Arc::new(port));
+
+        let result = use_case
+            .execute(command(&alice(), PrincipalStatus::Suspended))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SetUserStatusError::PersistenceError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_for_tenant_rejects_other_tenants_users() {
+        let bob = user_hrn("other-account", "bob");
+        let port =
+            Arc::new(MockUserStatusPort::new().with_user(bob.clone(), PrincipalStatus::Active));
+        let use_case = SetUserStatusUseCase::new(port.clone());
+        let tenant = TenantContext::new("account123");
+
+        let result = use_case
+            .execute_for_tenant(&tenant, command(&bob, PrincipalStatus::Suspended))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(SetUserStatusError::CrossTenantAccess(_))
+        ));
+        assert_eq!(port.status(&bob), Some(PrincipalStatus::Active));
+    }
+}
@@ -0,0 +1,67 @@
+//! Data Transfer Objects for update_group_attributes feature
+
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use kernel::{HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Events shared with the contexts that consume them
+pub use kernel::{AttributeChange, GroupAttributesChanged};
+
+/// Command to update some of a group's attributes
+///
+/// Attributes left as `None` keep their current value; an empty description
+/// removes it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateGroupAttributesCommand {
+    pub group_hrn: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Replaces the group's tags; order and duplicates don't matter
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+}
+
+impl TenantScoped for UpdateGroupAttributesCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.group_hrn);
+    }
+}
+
+impl ActionTrait for UpdateGroupAttributesCommand {
+    fn name() -> &'static str {
+        "UpdateGroupAttributes"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("iam").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Iam::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Iam::Group".to_string()
+    }
+}
+
+/// The attributes of a group, as stored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupAttributes {
+    pub name: String,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// Result of an attribute update
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateGroupAttributesResponse {
+    pub group_hrn: String,
+    /// Attributes after the update
+    pub attributes: GroupAttributes,
+    /// Keys of the attributes the update changed; empty for a no-op
+    pub changed_keys: Vec<String>,
+}
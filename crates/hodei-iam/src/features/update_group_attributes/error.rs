@@ -0,0 +1,21 @@
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+/// Errors that can occur when updating a group's attributes
+#[derive(Debug, Error)]
+pub enum UpdateGroupAttributesError {
+    #[error("Invalid group HRN: {0}")]
+    InvalidGroupHrn(String),
+
+    #[error("Group not found: {0}")]
+    GroupNotFound(String),
+
+    #[error("Invalid name: {0}")]
+    InvalidName(String),
+
+    #[error("Failed to save group attributes: {0}")]
+    PersistenceError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
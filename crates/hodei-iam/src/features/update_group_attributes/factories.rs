@@ -0,0 +1,41 @@
+//! Factory for creating the UpdateGroupAttributes use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::update_group_attributes::ports::{
+    GroupAttributesEventPort, GroupAttributesPort, UpdateGroupAttributesUseCasePort,
+};
+use crate::features::update_group_attributes::use_case::UpdateGroupAttributesUseCase;
+
+/// Create the UpdateGroupAttributes use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `attributes_port` - Port for reading and writing group attributes
+/// * `events` - Port announcing the changes
+///
+/// # Returns
+///
+/// Arc<dyn UpdateGroupAttributesUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let group_repo = Arc::new(SurrealGroupAdapter::new(db));
+/// let events = Arc::new(EventBusIamEvents::new(bus.clone()));
+///
+/// let update_group_attributes = create_update_group_attributes_use_case(group_repo, events);
+/// ```
+pub fn create_update_group_attributes_use_case(
+    attributes_port: Arc<dyn GroupAttributesPort>,
+    events: Arc<dyn GroupAttributesEventPort>,
+) -> Arc<dyn UpdateGroupAttributesUseCasePort> {
+    info!("Creating UpdateGroupAttributes use case");
+    Arc::new(UpdateGroupAttributesUseCase::new(attributes_port).with_events(events))
+}
@@ -0,0 +1,105 @@
+//! Mock implementations for testing Update Group Attributes feature
+
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::dto::{GroupAttributes, GroupAttributesChanged};
+use super::error::UpdateGroupAttributesError;
+use super::ports::{GroupAttributesEventPort, GroupAttributesPort};
+
+/// Mock GroupAttributesPort for testing
+///
+/// Counts saves so tests can check that no-op updates are not written.
+pub struct MockGroupAttributesPort {
+    groups: Mutex<HashMap<Hrn, GroupAttributes>>,
+    saves: Mutex<usize>,
+    should_fail: bool,
+}
+
+impl MockGroupAttributesPort {
+    /// Create a mock with no groups
+    pub fn new() -> Self {
+        Self {
+            groups: Mutex::new(HashMap::new()),
+            saves: Mutex::new(0),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose saves fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a group with the given attributes
+    pub fn with_group(self, group_hrn: Hrn, attributes: GroupAttributes) -> Self {
+        self.groups.lock().unwrap().insert(group_hrn, attributes);
+        self
+    }
+
+    /// Current attributes of a group
+    pub fn attributes(&self, group_hrn: &Hrn) -> Option<GroupAttributes> {
+        self.groups.lock().unwrap().get(group_hrn).cloned()
+    }
+
+    /// Number of saves made
+    pub fn saves(&self) -> usize {
+        *self.saves.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl GroupAttributesPort for MockGroupAttributesPort {
+    async fn find_attributes(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<GroupAttributes>, UpdateGroupAttributesError> {
+        Ok(self.attributes(group_hrn))
+    }
+
+    async fn save_attributes(
+        &self,
+        group_hrn: &Hrn,
+        attributes: &GroupAttributes,
+    ) -> Result<(), UpdateGroupAttributesError> {
+        if self.should_fail {
+            return Err(UpdateGroupAttributesError::PersistenceError(
+                "Mock failure".to_string(),
+            ));
+        }
+        *self.saves.lock().unwrap() += 1;
+        match self.groups.lock().unwrap().get_mut(group_hrn) {
+            Some(current) => {
+                *current = attributes.clone();
+                Ok(())
+            }
+            None => Err(UpdateGroupAttributesError::GroupNotFound(
+                group_hrn.to_string(),
+            )),
+        }
+    }
+}
+
+/// Mock GroupAttributesEventPort recording every event
+#[derive(Default)]
+pub struct MockGroupAttributesEventPort {
+    events: Mutex<Vec<GroupAttributesChanged>>,
+}
+
+impl MockGroupAttributesEventPort {
+    pub fn events(&self) -> Vec<GroupAttributesChanged> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl GroupAttributesEventPort for MockGroupAttributesEventPort {
+    async fn publish(&self, event: GroupAttributesChanged) {
+        self.events.lock().unwrap().push(event);
+    }
+}
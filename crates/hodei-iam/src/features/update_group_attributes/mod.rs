@@ -0,0 +1,37 @@
+//! update_group_attributes Feature (Vertical Slice)
+//!
+//! This module implements updating a group's attributes (name, description,
+//! tags) following VSA. It is the group counterpart of
+//! update_user_attributes:
+//!
+//! - only the attributes given in the command are updated
+//! - an update that changes nothing is not written and emits nothing
+//! - otherwise a `GroupAttributesChanged` event lists each changed attribute
+//!   with its old and new value
+//!
+//! Structure:
+//! - dto.rs              -> Command, Response & event DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interfaces (ISP)
+//! - use_case.rs         -> Core business logic (UpdateGroupAttributesUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{
+    GroupAttributes, GroupAttributesChanged, UpdateGroupAttributesCommand,
+    UpdateGroupAttributesResponse,
+};
+pub use error::UpdateGroupAttributesError;
+pub use ports::{GroupAttributesEventPort, GroupAttributesPort, UpdateGroupAttributesUseCasePort};
+pub use use_case::UpdateGroupAttributesUseCase;
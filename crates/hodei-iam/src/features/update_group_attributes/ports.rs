@@ -0,0 +1,68 @@
+use super::dto::{
+    GroupAttributes, GroupAttributesChanged, UpdateGroupAttributesCommand,
+    UpdateGroupAttributesResponse,
+};
+use super::error::UpdateGroupAttributesError;
+use async_trait::async_trait;
+use kernel::Hrn;
+
+/// Port for reading and writing a group's attributes
+///
+/// This port abstracts group attribute persistence.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the update_group_attributes feature.
+#[async_trait]
+pub trait GroupAttributesPort: Send + Sync {
+    /// Find the attributes of a group
+    ///
+    /// # Returns
+    /// * `Ok(Some(GroupAttributes))` if the group was found
+    /// * `Ok(None)` if no group with that HRN exists
+    /// * `Err(UpdateGroupAttributesError)` if there was an error during lookup
+    async fn find_attributes(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<GroupAttributes>, UpdateGroupAttributesError>;
+
+    /// Save the attributes of an existing group
+    ///
+    /// Only the attributes are written; parent groups and every other field
+    /// are left as they are.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the attributes were saved
+    /// * `Err(UpdateGroupAttributesError::GroupNotFound)` if the group does not exist
+    /// * `Err(UpdateGroupAttributesError)` if there was an error saving them
+    async fn save_attributes(
+        &self,
+        group_hrn: &Hrn,
+        attributes: &GroupAttributes,
+    ) -> Result<(), UpdateGroupAttributesError>;
+}
+
+/// Port for announcing attribute changes
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the update.
+#[async_trait]
+pub trait GroupAttributesEventPort: Send + Sync {
+    async fn publish(&self, event: GroupAttributesChanged);
+}
+
+/// Port for the UpdateGroupAttributes use case
+///
+/// This port defines the contract for executing the update group attributes use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait UpdateGroupAttributesUseCasePort: Send + Sync {
+    /// Execute the update group attributes use case
+    ///
+    /// # Returns
+    /// * `Ok(UpdateGroupAttributesResponse)` with the attributes and what changed
+    /// * `Err(UpdateGroupAttributesError)` if the attributes could not be updated
+    async fn execute(
+        &self,
+        command: UpdateGroupAttributesCommand,
+    ) -> Result<UpdateGroupAttributesResponse, UpdateGroupAttributesError>;
+}
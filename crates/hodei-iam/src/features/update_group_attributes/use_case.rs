@@ -0,0 +1,153 @@
+use super::dto::{
+    AttributeChange, GroupAttributes, GroupAttributesChanged, UpdateGroupAttributesCommand,
+    UpdateGroupAttributesResponse,
+};
+use super::error::UpdateGroupAttributesError;
+use super::ports::{
+    GroupAttributesEventPort, GroupAttributesPort, UpdateGroupAttributesUseCasePort,
+};
+use async_trait::async_trait;
+use kernel::{Hrn, TenantContext};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{Instrument, info, info_span, instrument};
+
+/// Use case for updating a group's attributes
+///
+/// This use case orchestrates the update:
+/// 1. Validates and parses the group HRN and the new values
+/// 2. Finds the group's current attributes
+/// 3. Persists the new ones, unless nothing changed
+/// 4. Publishes a [`GroupAttributesChanged`] event listing what changed
+pub struct UpdateGroupAttributesUseCase {
+    attributes_port: Arc<dyn GroupAttributesPort>,
+    events: Option<Arc<dyn GroupAttributesEventPort>>,
+}
+
+impl UpdateGroupAttributesUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `attributes_port` - Implementation of GroupAttributesPort for attribute persistence
+    pub fn new(attributes_port: Arc<dyn GroupAttributesPort>) -> Self {
+        Self {
+            attributes_port,
+            events: None,
+        }
+    }
+
+    /// Publish a change event for every update that changes something
+    pub fn with_events(mut self, events: Arc<dyn GroupAttributesEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Execute the update group attributes use case
+    ///
+    /// # Arguments
+    /// * `cmd` - UpdateGroupAttributesCommand with the group HRN and the attributes to set
+    ///
+    /// # Returns
+    /// * Ok(UpdateGroupAttributesResponse) with the attributes and the keys that changed
+    /// * Err(UpdateGroupAttributesError) if there was an error
+    #[instrument(name = "update_group_attributes", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        resource = %cmd.group_hrn
+    ))]
+    pub async fn execute(
+        &self,
+        cmd: UpdateGroupAttributesCommand,
+    ) -> Result<UpdateGroupAttributesResponse, UpdateGroupAttributesError> {
+        let group_hrn = Hrn::from_string(&cmd.group_hrn)
+            .ok_or_else(|| UpdateGroupAttributesError::InvalidGroupHrn(cmd.group_hrn.clone()))?;
+        if let Some(name) = &cmd.name
+            && name.trim().is_empty()
+        {
+            return Err(UpdateGroupAttributesError::InvalidName(
+                "Group name cannot be empty".to_string(),
+            ));
+        }
+
+        let current = self
+            .attributes_port
+            .find_attributes(&group_hrn)
+            .await?
+            .ok_or_else(|| UpdateGroupAttributesError::GroupNotFound(cmd.group_hrn.clone()))?;
+        let updated = GroupAttributes {
+            name: cmd.name.unwrap_or_else(|| current.name.clone()),
+            description: match cmd.description {
+                Some(description) if description.trim().is_empty() => None,
+                Some(description) => Some(description),
+                None => current.description.clone(),
+            },
+            tags: cmd
+                .tags
+                .map(AttributeChange::unique_tags)
+                .unwrap_or_else(|| current.tags.clone()),
+        };
+
+        let changes = changes(&current, &updated);
+        let changed_keys: Vec<String> = changes.iter().map(|change| change.key.clone()).collect();
+        if !changes.is_empty() {
+            self.attributes_port
+                .save_attributes(&group_hrn, &updated)
+                .instrument(info_span!("persistence"))
+                .await?;
+            info!(changed = ?changed_keys, "Group attributes changed");
+            if let Some(events) = &self.events {
+                events
+                    .publish(GroupAttributesChanged::new(group_hrn, changes))
+                    .await;
+            }
+        }
+
+        Ok(UpdateGroupAttributesResponse {
+            group_hrn: cmd.group_hrn,
+            attributes: updated,
+            changed_keys,
+        })
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The group must belong to the tenant, otherwise the command fails with
+    /// `CrossTenantAccess` before any lookup.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        cmd: UpdateGroupAttributesCommand,
+    ) -> Result<UpdateGroupAttributesResponse, UpdateGroupAttributesError> {
+        tenant.run(cmd, |cmd| self.execute(cmd)).await
+    }
+}
+
+/// The attributes that differ between `current` and `updated`
+fn changes(current: &GroupAttributes, updated: &GroupAttributes) -> Vec<AttributeChange> {
+    let mut changes = Vec::new();
+    changes.extend(AttributeChange::between(
+        "name",
+        Some(json!(current.name)),
+        Some(json!(updated.name)),
+    ));
+    changes.extend(AttributeChange::between(
+        "description",
+        current.description.as_ref().map(|d| json!(d)),
+        updated.description.as_ref().map(|d| json!(d)),
+    ));
+    changes.extend(AttributeChange::between_tags(
+        "tags",
+        &current.tags,
+        &updated.tags,
+    ));
+    changes
+}
+
+#[async_trait]
+impl UpdateGroupAttributesUseCasePort for UpdateGroupAttributesUseCase {
+    async fn execute(
+        &self,
+        command: UpdateGroupAttributesCommand,
+    ) -> Result<UpdateGroupAttributesResponse, UpdateGroupAttributesError> {
+        self.execute(command).await
+    }
+}
@@ -0,0 +1,220 @@
+//! Unit tests for update_group_attributes use case
+//!
+//! These tests verify the behavior of the UpdateGroupAttributesUseCase in
+//! isolation, using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use kernel::{Hrn, TenantContext};
+    use serde_json::json;
+
+    use crate::features::update_group_attributes::{
+        dto::{AttributeChange, GroupAttributes, UpdateGroupAttributesCommand},
+        error::UpdateGroupAttributesError,
+        mocks::{MockGroupAttributesEventPort, MockGroupAttributesPort},
+        use_case::UpdateGroupAttributesUseCase,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn group_hrn(account: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            account.to_string(),
+            "Group".to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn developers() -> Hrn {
+        group_hrn("account123", "developers")
+    }
+
+    fn attributes() -> GroupAttributes {
+        GroupAttributes {
+            name: "Developers".to_string(),
+            description: Some("Everyone writing code".to_string()),
+            tags: vec!["engineering".to_string()],
+        }
+    }
+
+    fn command(group_hrn: &Hrn) -> UpdateGroupAttributesCommand {
+        UpdateGroupAttributesCommand {
+            group_hrn: group_hrn.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn use_case(
+        port: Arc<MockGroupAttributesPort>,
+    ) -> (
+        UpdateGroupAttributesUseCase,
+        Arc<MockGroupAttributesEventPort>,
+    ) {
+        let events = Arc::new(MockGroupAttributesEventPort::default());
+        let use_case = UpdateGroupAttributesUseCase::new(port).with_events(events.clone());
+        (use_case, events)
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_changed_attributes_are_saved_and_announced() {
+        let port = Arc::new(MockGroupAttributesPort::new().with_group(developers(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        let response = use_case
+            .execute(UpdateGroupAttributesCommand {
+                tags: Some(vec!["engineering".to_string(), "pci".to_string()]),
+                ..command(&developers())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.changed_keys, vec!["tags"]);
+        assert_eq!(
+            port.attributes(&developers()).unwrap().tags,
+            vec!["engineering", "pci"]
+        );
+
+        let events = events.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].group_hrn, developers());
+        assert_eq!(
+            events[0].changes,
+            vec![AttributeChange {
+                key: "tags".to_string(),
+                old_value: Some(json!(["engineering"])),
+                new_value: Some(json!(["engineering", "pci"])),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blank_description_removes_it() {
+        let port = Arc::new(MockGroupAttributesPort::new().with_group(developers(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        use_case
+            .execute(UpdateGroupAttributesCommand {
+                description: Some(String::new()),
+                ..command(&developers())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(port.attributes(&developers()).unwrap().description, None);
+        let events = events.events();
+        assert_eq!(events[0].changed_keys(), vec!["description"]);
+        assert_eq!(
+            events[0].changes[0].old_value,
+            Some(json!("Everyone writing code"))
+        );
+        assert_eq!(events[0].changes[0].new_value, None);
+    }
+
+    #[tokio::test]
+    async fn test_no_op_updates_are_not_saved_or_announced() {
+        let port = Arc::new(MockGroupAttributesPort::new().with_group(developers(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        let response = use_case
+            .execute(UpdateGroupAttributesCommand {
+                name: Some("Developers".to_string()),
+                description: Some("Everyone writing code".to_string()),
+                tags: Some(vec!["engineering".to_string(), "engineering".to_string()]),
+                ..command(&developers())
+            })
+            .await
+            .unwrap();
+
+        assert!(response.changed_keys.is_empty());
+        assert_eq!(port.saves(), 0);
+        assert!(events.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_empty_name_is_rejected() {
+        let port = Arc::new(MockGroupAttributesPort::new().with_group(developers(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        let result = use_case
+            .execute(UpdateGroupAttributesCommand {
+                name: Some(" ".to_string()),
+                ..command(&developers())
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateGroupAttributesError::InvalidName(_))
+        ));
+        assert_eq!(port.saves(), 0);
+        assert!(events.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_group_is_not_found() {
+        let (use_case, _) = use_case(Arc::new(MockGroupAttributesPort::new()));
+
+        let result = use_case.execute(command(&developers())).await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateGroupAttributesError::GroupNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_save_is_not_announced() {
+        let port =
+            Arc::new(MockGroupAttributesPort::failing().with_group(developers(), attributes()));
+        let (use_case, events) = use_case(port);
+
+        let result = use_case
+            .execute(UpdateGroupAttributesCommand {
+                name: Some("Engineering".to_string()),
+                ..command(&developers())
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateGroupAttributesError::PersistenceError(_))
+        ));
+        assert!(events.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_for_tenant_rejects_other_tenants_groups() {
+        let admins = group_hrn("other-account", "admins");
+        let port =
+            Arc::new(MockGroupAttributesPort::new().with_group(admins.clone(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+        let tenant = TenantContext::new("account123");
+
+        let result = use_case
+            .execute_for_tenant(
+                &tenant,
+                UpdateGroupAttributesCommand {
+                    name: Some("Admins".to_string()),
+                    ..command(&admins)
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateGroupAttributesError::CrossTenantAccess(_))
+        ));
+        assert_eq!(port.attributes(&admins).unwrap().name, "Developers");
+        assert!(events.events().is_empty());
+    }
+}
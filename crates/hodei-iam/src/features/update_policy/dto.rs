@@ -6,9 +6,14 @@
 
 use kernel::Hrn;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
 
+/// Event published when the policy content changes, shared with the contexts
+/// that react to policy changes
+pub use kernel::PolicyStored;
+
 /// Command to update an existing IAM policy
 ///
 /// This command contains the information needed to update a policy.
@@ -162,6 +167,10 @@ pub struct PolicyView {
 
     /// Optional description of the policy
     pub description: Option<String>,
+
+    /// Annotations declared in the policy content, e.g. `@ticket("SEC-1234")`
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
 }
 
 #[cfg(test)]
@@ -254,6 +263,7 @@ mod tests {
             name: "test-policy".to_string(),
             content: "permit(principal, action, resource);".to_string(),
             description: Some("Test".to_string()),
+            annotations: HashMap::new(),
         };
 
         let cloned = view.clone();
@@ -269,6 +279,7 @@ mod tests {
             name: "test-policy".to_string(),
             content: "permit(principal, action, resource);".to_string(),
             description: Some("Test".to_string()),
+            annotations: HashMap::new(),
         };
 
         let json = serde_json::to_string(&view).unwrap();
@@ -5,10 +5,14 @@ use kernel::Hrn;
 use std::collections::HashMap;
 use std::sync::Mutex;
 
-use super::dto::{PolicyView, UpdatePolicyCommand};
+use super::dto::{PolicyStored, PolicyView, UpdatePolicyCommand};
 use super::error::UpdatePolicyError;
-use super::ports::{PolicyValidationError, PolicyValidator, UpdatePolicyPort, ValidationResult};
+use super::ports::{
+    PolicyValidationError, PolicyValidator, UpdatePolicyEventPort, UpdatePolicyPort,
+    ValidationResult,
+};
 use hodei_policies::features::validate_policy::dto::ValidatePolicyCommand;
+use hodei_policies::features::validate_policy::policy_annotations;
 
 /// Mock PolicyValidator for testing
 pub struct MockPolicyValidator {
@@ -67,6 +71,7 @@ impl PolicyValidator for MockPolicyValidator {
         Ok(ValidationResult {
             is_valid,
             errors: self.errors.clone(),
+            annotations: HashMap::new(),
         })
     }
 }
@@ -173,10 +178,30 @@ impl UpdatePolicyPort for MockUpdatePolicyPort {
             name: command.policy_id.clone(),
             content: content.clone(),
             description: description.clone(),
+            annotations: policy_annotations(content).unwrap_or_default(),
         })
     }
 }
 
+/// Mock UpdatePolicyEventPort recording every event
+#[derive(Default)]
+pub struct MockUpdatePolicyEventPort {
+    events: Mutex<Vec<PolicyStored>>,
+}
+
+impl MockUpdatePolicyEventPort {
+    pub fn events(&self) -> Vec<PolicyStored> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl UpdatePolicyEventPort for MockUpdatePolicyEventPort {
+    async fn publish(&self, event: PolicyStored) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
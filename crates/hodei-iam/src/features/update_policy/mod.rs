@@ -37,7 +37,10 @@ mod use_case_test;
 // ---------------------------------------------------------------------------
 pub use dto::{PolicyView, UpdatePolicyCommand};
 pub use error::UpdatePolicyError;
-pub use ports::{PolicyValidationError, PolicyValidator, UpdatePolicyPort, ValidationResult};
+pub use ports::{
+    PolicyValidationError, PolicyValidator, UpdatePolicyEventPort, UpdatePolicyPort,
+    ValidationResult,
+};
 pub use use_case::UpdatePolicyUseCase;
 
 // ---------------------------------------------------------------------------
@@ -15,7 +15,7 @@
 //! This ensures that implementations and consumers of this port are not forced to depend
 //! on operations they don't need.
 
-use crate::features::update_policy::dto::{PolicyView, UpdatePolicyCommand};
+use crate::features::update_policy::dto::{PolicyStored, PolicyView, UpdatePolicyCommand};
 use crate::features::update_policy::error::UpdatePolicyError;
 use async_trait::async_trait;
 
@@ -103,6 +103,15 @@ pub trait UpdatePolicyPort: Send + Sync {
     async fn update(&self, command: UpdatePolicyCommand) -> Result<PolicyView, UpdatePolicyError>;
 }
 
+/// Port for announcing policy content changes
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the change.
+#[async_trait]
+pub trait UpdatePolicyEventPort: Send + Sync {
+    async fn publish(&self, event: PolicyStored);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -8,22 +8,27 @@
 //!
 //! 1. Receive `UpdatePolicyCommand` from the caller
 //! 2. Validate that at least one field is being updated
-//! 3. If policy content is provided, validate it via `PolicyValidator`
+//! 3. If policy content is provided, validate it via `PolicyValidator` and
+//!    store it in Cedar syntax
 //! 4. Update the policy through `UpdatePolicyPort`
-//! 5. Return updated policy view or appropriate error
+//! 5. Publish a `PolicyStored` event if the content changed
+//! 6. Return updated policy view or appropriate error
 //!
 //! # Dependencies
 //!
 //! - `PolicyValidator`: Validates Cedar policy syntax (if content is updated)
 //! - `UpdatePolicyPort`: Abstract port for policy persistence (ISP - only update)
 
-use crate::features::update_policy::dto::{PolicyView, UpdatePolicyCommand};
+use crate::features::update_policy::dto::{PolicyStored, PolicyView, UpdatePolicyCommand};
 use crate::features::update_policy::error::UpdatePolicyError;
-use crate::features::update_policy::ports::{PolicyValidator, UpdatePolicyPort};
+use crate::features::update_policy::ports::{
+    PolicyValidator, UpdatePolicyEventPort, UpdatePolicyPort,
+};
 use async_trait::async_trait;
 use hodei_policies::features::validate_policy::dto::ValidatePolicyCommand;
+use hodei_policies::features::validate_policy::to_cedar_syntax;
 use std::sync::Arc;
-use tracing::{info, instrument, warn};
+use tracing::{Instrument, info, info_span, instrument, warn};
 
 /// Use case for updating IAM policies
 ///
@@ -31,7 +36,8 @@ use tracing::{info, instrument, warn};
 /// 1. Validates the update command
 /// 2. Optionally validates new policy content
 /// 3. Updates the policy through the port
-/// 4. Returns success or appropriate error
+/// 4. Publishes a [`PolicyStored`] event if the content changed
+/// 5. Returns success or appropriate error
 ///
 /// # Example
 ///
@@ -60,6 +66,9 @@ pub struct UpdatePolicyUseCase {
 
     /// Port for updating policies (only update operation)
     policy_port: Arc<dyn UpdatePolicyPort>,
+
+    /// Port announcing policy content changes
+    events: Option<Arc<dyn UpdatePolicyEventPort>>,
 }
 
 impl UpdatePolicyUseCase {
@@ -90,9 +99,19 @@ impl UpdatePolicyUseCase {
         Self {
             validator,
             policy_port,
+            events: None,
         }
     }
 
+    /// Publish an event for every content change
+    ///
+    /// A description-only update does not change what the policy allows, so
+    /// it publishes nothing.
+    pub fn with_events(mut self, events: Arc<dyn UpdatePolicyEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Execute the update policy use case
     ///
     /// This is the main entry point for updating an IAM policy.
@@ -126,10 +145,13 @@ impl UpdatePolicyUseCase {
     /// let result = use_case.execute(command).await?;
     /// println!("Updated policy: {}", result.hrn);
     /// ```
-    #[instrument(skip(self, command), fields(policy_id = %command.policy_id))]
+    #[instrument(name = "update_policy", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        policy_id = %command.policy_id
+    ))]
     pub async fn execute(
         &self,
-        command: UpdatePolicyCommand,
+        mut command: UpdatePolicyCommand,
     ) -> Result<PolicyView, UpdatePolicyError> {
         info!("Updating policy: {}", command.policy_id);
 
@@ -148,7 +170,7 @@ impl UpdatePolicyUseCase {
         }
 
         // Validate policy content if provided
-        if let Some(ref content) = command.policy_content {
+        if let Some(content) = command.policy_content.as_mut() {
             if content.trim().is_empty() {
                 warn!("Update failed: policy content is empty");
                 return Err(UpdatePolicyError::EmptyPolicyContent);
@@ -161,6 +183,7 @@ impl UpdatePolicyUseCase {
             let validation_result = self
                 .validator
                 .validate(validation_command)
+                .instrument(info_span!("validation"))
                 .await
                 .map_err(|e| UpdatePolicyError::ValidationFailed(e.to_string()))?;
 
@@ -174,13 +197,30 @@ impl UpdatePolicyUseCase {
             }
 
             // Note: ValidationResult from hodei-policies doesn't include warnings field
+
+            // Cedar JSON policies are stored in Cedar syntax, like any other
+            *content = to_cedar_syntax(content)
+                .map_err(|e| UpdatePolicyError::InvalidPolicyContent(e.to_string()))?;
         }
 
         // Update the policy through the port
         info!("Persisting policy update");
-        let updated_view = self.policy_port.update(command).await?;
+        let content_changed = command.policy_content.is_some();
+        let updated_view = self
+            .policy_port
+            .update(command)
+            .instrument(info_span!("persistence"))
+            .await?;
 
         info!("Policy updated successfully: {}", updated_view.name);
+        if let Some(events) = self.events.as_ref().filter(|_| content_changed) {
+            events
+                .publish(PolicyStored {
+                    policy_id: updated_view.name.clone(),
+                    content: updated_view.content.clone(),
+                })
+                .await;
+        }
 
         Ok(updated_view)
     }
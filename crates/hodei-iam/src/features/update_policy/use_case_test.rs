@@ -8,13 +8,14 @@ mod tests {
     use std::sync::Arc;
 
     use crate::features::update_policy::{
-        dto::UpdatePolicyCommand,
+        dto::{PolicyStored, UpdatePolicyCommand},
         error::UpdatePolicyError,
-        mocks::{MockPolicyValidator, MockUpdatePolicyPort},
+        mocks::{MockPolicyValidator, MockUpdatePolicyEventPort, MockUpdatePolicyPort},
         use_case::UpdatePolicyUseCase,
     };
 
     use crate::features::update_policy::ports::UpdatePolicyPort;
+    use hodei_policies::features::validate_policy::{PolicySyntax, parse_policy, to_cedar_syntax};
 
     // ============================================================================
     // Helper Functions
@@ -55,7 +56,10 @@ mod tests {
         assert!(result.is_ok(), "Expected successful policy update");
         let view = result.unwrap();
         assert_eq!(view.name, "test-policy");
-        assert_eq!(view.content, "permit(principal, action, resource);");
+        assert_eq!(
+            view.content,
+            to_cedar_syntax("permit(principal, action, resource);").unwrap()
+        );
     }
 
     #[tokio::test]
@@ -94,7 +98,10 @@ mod tests {
         );
         let view = result.unwrap();
         assert_eq!(view.name, "test-policy");
-        assert_eq!(view.content, "permit(principal, action, resource);");
+        assert_eq!(
+            view.content,
+            to_cedar_syntax("permit(principal, action, resource);").unwrap()
+        );
         assert_eq!(view.description, Some("Updated description".to_string()));
     }
 
@@ -389,6 +396,51 @@ mod tests {
         );
         let view = result.unwrap();
         assert_eq!(view.name, "test-policy");
-        assert_eq!(view.content, "  permit(principal, action, resource);  ");
+        // Stored as Cedar renders it, without the surrounding whitespace
+        assert_eq!(
+            view.content,
+            to_cedar_syntax("permit(principal, action, resource);").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_policy_stores_json_policy_in_cedar_syntax() {
+        let validator = Arc::new(MockPolicyValidator::new());
+        let port = Arc::new(MockUpdatePolicyPort::new());
+        let use_case = UpdatePolicyUseCase::new(validator, port);
+        let command = UpdatePolicyCommand::update_content(
+            "test-policy",
+            r#"{"effect":"permit","principal":{"op":"All"},"action":{"op":"All"},"resource":{"op":"All"},"conditions":[]}"#,
+        );
+
+        let view = use_case.execute(command).await.unwrap();
+
+        assert!(view.content.starts_with("permit("));
+        let (_, syntax) = parse_policy(&view.content).unwrap();
+        assert_eq!(syntax, PolicySyntax::Cedar);
+    }
+
+    #[tokio::test]
+    async fn test_update_policy_publishes_content_changes_only() {
+        let events = Arc::new(MockUpdatePolicyEventPort::default());
+        let use_case = UpdatePolicyUseCase::new(
+            Arc::new(MockPolicyValidator::new()),
+            Arc::new(MockUpdatePolicyPort::new()),
+        )
+        .with_events(events.clone());
+
+        use_case
+            .execute(create_test_command_with_description())
+            .await
+            .unwrap();
+        use_case.execute(create_test_command()).await.unwrap();
+
+        assert_eq!(
+            events.events(),
+            vec![PolicyStored {
+                policy_id: "test-policy".to_string(),
+                content: to_cedar_syntax("permit(principal, action, resource);").unwrap(),
+            }]
+        );
     }
 }
@@ -0,0 +1,66 @@
+//! Data Transfer Objects for update_user_attributes feature
+
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use kernel::{HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Events shared with the contexts that consume them
+pub use kernel::{AttributeChange, UserAttributesChanged};
+
+/// Command to update some of a user's attributes
+///
+/// Attributes left as `None` keep their current value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateUserAttributesCommand {
+    pub user_hrn: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Replaces the user's tags; order and duplicates don't matter
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+}
+
+impl TenantScoped for UpdateUserAttributesCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.user_hrn);
+    }
+}
+
+impl ActionTrait for UpdateUserAttributesCommand {
+    fn name() -> &'static str {
+        "UpdateUserAttributes"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("iam").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Iam::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Iam::User".to_string()
+    }
+}
+
+/// The attributes of a user, as stored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserAttributes {
+    pub name: String,
+    pub email: String,
+    pub tags: Vec<String>,
+}
+
+/// Result of an attribute update
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateUserAttributesResponse {
+    pub user_hrn: String,
+    /// Attributes after the update
+    pub attributes: UserAttributes,
+    /// Keys of the attributes the update changed; empty for a no-op
+    pub changed_keys: Vec<String>,
+}
@@ -0,0 +1,24 @@
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+/// Errors that can occur when updating a user's attributes
+#[derive(Debug, Error)]
+pub enum UpdateUserAttributesError {
+    #[error("Invalid user HRN: {0}")]
+    InvalidUserHrn(String),
+
+    #[error("User not found: {0}")]
+    UserNotFound(String),
+
+    #[error("Invalid name: {0}")]
+    InvalidName(String),
+
+    #[error("Invalid email: {0}")]
+    InvalidEmail(String),
+
+    #[error("Failed to save user attributes: {0}")]
+    PersistenceError(String),
+
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
@@ -0,0 +1,41 @@
+//! Factory for creating the UpdateUserAttributes use case
+//!
+//! This module follows a simple pattern for dependency injection:
+//! - Factories receive Arc<dyn Trait> dependencies
+//! - Factories return Arc<dyn Port> for the use case
+//! - No complex generics, just trait objects
+
+use std::sync::Arc;
+use tracing::info;
+
+use crate::features::update_user_attributes::ports::{
+    UpdateUserAttributesUseCasePort, UserAttributesEventPort, UserAttributesPort,
+};
+use crate::features::update_user_attributes::use_case::UpdateUserAttributesUseCase;
+
+/// Create the UpdateUserAttributes use case with injected dependencies
+///
+/// # Arguments
+///
+/// * `attributes_port` - Port for reading and writing user attributes
+/// * `events` - Port announcing the changes
+///
+/// # Returns
+///
+/// Arc<dyn UpdateUserAttributesUseCasePort> - The use case as a trait object
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let user_repo = Arc::new(SurrealUserAdapter::new(db));
+/// let events = Arc::new(EventBusIamEvents::new(bus.clone()));
+///
+/// let update_user_attributes = create_update_user_attributes_use_case(user_repo, events);
+/// ```
+pub fn create_update_user_attributes_use_case(
+    attributes_port: Arc<dyn UserAttributesPort>,
+    events: Arc<dyn UserAttributesEventPort>,
+) -> Arc<dyn UpdateUserAttributesUseCasePort> {
+    info!("Creating UpdateUserAttributes use case");
+    Arc::new(UpdateUserAttributesUseCase::new(attributes_port).with_events(events))
+}
@@ -0,0 +1,105 @@
+//! Mock implementations for testing Update User Attributes feature
+
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::dto::{UserAttributes, UserAttributesChanged};
+use super::error::UpdateUserAttributesError;
+use super::ports::{UserAttributesEventPort, UserAttributesPort};
+
+/// Mock UserAttributesPort for testing
+///
+/// Counts saves so tests can check that no-op updates are not written.
+pub struct MockUserAttributesPort {
+    users: Mutex<HashMap<Hrn, UserAttributes>>,
+    saves: Mutex<usize>,
+    should_fail: bool,
+}
+
+impl MockUserAttributesPort {
+    /// Create a mock with no users
+    pub fn new() -> Self {
+        Self {
+            users: Mutex::new(HashMap::new()),
+            saves: Mutex::new(0),
+            should_fail: false,
+        }
+    }
+
+    /// Create a mock whose saves fail
+    pub fn failing() -> Self {
+        Self {
+            should_fail: true,
+            ..Self::new()
+        }
+    }
+
+    /// Add a user with the given attributes
+    pub fn with_user(self, user_hrn: Hrn, attributes: UserAttributes) -> Self {
+        self.users.lock().unwrap().insert(user_hrn, attributes);
+        self
+    }
+
+    /// Current attributes of a user
+    pub fn attributes(&self, user_hrn: &Hrn) -> Option<UserAttributes> {
+        self.users.lock().unwrap().get(user_hrn).cloned()
+    }
+
+    /// Number of saves made
+    pub fn saves(&self) -> usize {
+        *self.saves.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl UserAttributesPort for MockUserAttributesPort {
+    async fn find_attributes(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<UserAttributes>, UpdateUserAttributesError> {
+        Ok(self.attributes(user_hrn))
+    }
+
+    async fn save_attributes(
+        &self,
+        user_hrn: &Hrn,
+        attributes: &UserAttributes,
+    ) -> Result<(), UpdateUserAttributesError> {
+        if self.should_fail {
+            return Err(UpdateUserAttributesError::PersistenceError(
+                "Mock failure".to_string(),
+            ));
+        }
+        *self.saves.lock().unwrap() += 1;
+        match self.users.lock().unwrap().get_mut(user_hrn) {
+            Some(current) => {
+                *current = attributes.clone();
+                Ok(())
+            }
+            None => Err(UpdateUserAttributesError::UserNotFound(
+                user_hrn.to_string(),
+            )),
+        }
+    }
+}
+
+/// Mock UserAttributesEventPort recording every event
+#[derive(Default)]
+pub struct MockUserAttributesEventPort {
+    events: Mutex<Vec<UserAttributesChanged>>,
+}
+
+impl MockUserAttributesEventPort {
+    pub fn events(&self) -> Vec<UserAttributesChanged> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl UserAttributesEventPort for MockUserAttributesEventPort {
+    async fn publish(&self, event: UserAttributesChanged) {
+        self.events.lock().unwrap().push(event);
+    }
+}
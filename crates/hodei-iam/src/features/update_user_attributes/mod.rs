@@ -0,0 +1,37 @@
+//! update_user_attributes Feature (Vertical Slice)
+//!
+//! This module implements updating a user's attributes (name, email, tags)
+//! following VSA. Attributes feed attribute-based authorization, so caches
+//! and projections holding them must learn when they change:
+//!
+//! - only the attributes given in the command are updated
+//! - an update that changes nothing is not written and emits nothing
+//! - otherwise a `UserAttributesChanged` event lists each changed attribute
+//!   with its old and new value
+//!
+//! Structure:
+//! - dto.rs              -> Command, Response & event DTOs
+//! - error.rs            -> Feature-specific error types
+//! - ports.rs            -> Segregated interfaces (ISP)
+//! - use_case.rs         -> Core business logic (UpdateUserAttributesUseCase)
+//! - factories.rs        -> Dependency Injection helpers
+//! - mocks.rs            -> Test-only mock implementations
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod mocks;
+mod use_case_test;
+
+// Public API
+pub use dto::{
+    AttributeChange, UpdateUserAttributesCommand, UpdateUserAttributesResponse, UserAttributes,
+    UserAttributesChanged,
+};
+pub use error::UpdateUserAttributesError;
+pub use ports::{UpdateUserAttributesUseCasePort, UserAttributesEventPort, UserAttributesPort};
+pub use use_case::UpdateUserAttributesUseCase;
@@ -0,0 +1,68 @@
+use super::dto::{
+    UpdateUserAttributesCommand, UpdateUserAttributesResponse, UserAttributes,
+    UserAttributesChanged,
+};
+use super::error::UpdateUserAttributesError;
+use async_trait::async_trait;
+use kernel::Hrn;
+
+/// Port for reading and writing a user's attributes
+///
+/// This port abstracts user attribute persistence.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the operations needed by the update_user_attributes feature.
+#[async_trait]
+pub trait UserAttributesPort: Send + Sync {
+    /// Find the attributes of a user
+    ///
+    /// # Returns
+    /// * `Ok(Some(UserAttributes))` if the user was found
+    /// * `Ok(None)` if no user with that HRN exists
+    /// * `Err(UpdateUserAttributesError)` if there was an error during lookup
+    async fn find_attributes(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<UserAttributes>, UpdateUserAttributesError>;
+
+    /// Save the attributes of an existing user
+    ///
+    /// Only the attributes are written; status, group memberships and every
+    /// other field are left as they are.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the attributes were saved
+    /// * `Err(UpdateUserAttributesError::UserNotFound)` if the user does not exist
+    /// * `Err(UpdateUserAttributesError)` if there was an error saving them
+    async fn save_attributes(
+        &self,
+        user_hrn: &Hrn,
+        attributes: &UserAttributes,
+    ) -> Result<(), UpdateUserAttributesError>;
+}
+
+/// Port for announcing attribute changes
+///
+/// Publishing is fire-and-forget; a failure to deliver an event never undoes
+/// the update.
+#[async_trait]
+pub trait UserAttributesEventPort: Send + Sync {
+    async fn publish(&self, event: UserAttributesChanged);
+}
+
+/// Port for the UpdateUserAttributes use case
+///
+/// This port defines the contract for executing the update user attributes use case.
+/// Following the Interface Segregation Principle (ISP), this port
+/// contains only the execute method needed by external callers.
+#[async_trait]
+pub trait UpdateUserAttributesUseCasePort: Send + Sync {
+    /// Execute the update user attributes use case
+    ///
+    /// # Returns
+    /// * `Ok(UpdateUserAttributesResponse)` with the attributes and what changed
+    /// * `Err(UpdateUserAttributesError)` if the attributes could not be updated
+    async fn execute(
+        &self,
+        command: UpdateUserAttributesCommand,
+    ) -> Result<UpdateUserAttributesResponse, UpdateUserAttributesError>;
+}
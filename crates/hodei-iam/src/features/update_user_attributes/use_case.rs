@@ -0,0 +1,162 @@
+use super::dto::{
+    AttributeChange, UpdateUserAttributesCommand, UpdateUserAttributesResponse, UserAttributes,
+    UserAttributesChanged,
+};
+use super::error::UpdateUserAttributesError;
+use super::ports::{UpdateUserAttributesUseCasePort, UserAttributesEventPort, UserAttributesPort};
+use crate::internal::domain::email::{normalize_email, validate_email};
+use async_trait::async_trait;
+use kernel::{Hrn, TenantContext};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{Instrument, info, info_span, instrument};
+
+/// Use case for updating a user's attributes
+///
+/// This use case orchestrates the update:
+/// 1. Validates and parses the user HRN and the new values
+/// 2. Finds the user's current attributes
+/// 3. Persists the new ones, unless nothing changed
+/// 4. Publishes a [`UserAttributesChanged`] event listing what changed
+///
+/// The event carries old and new values, except for the email, whose values
+/// are left out so addresses don't end up in the audit log.
+pub struct UpdateUserAttributesUseCase {
+    attributes_port: Arc<dyn UserAttributesPort>,
+    events: Option<Arc<dyn UserAttributesEventPort>>,
+}
+
+impl UpdateUserAttributesUseCase {
+    /// Create a new instance of the use case
+    ///
+    /// # Arguments
+    /// * `attributes_port` - Implementation of UserAttributesPort for attribute persistence
+    pub fn new(attributes_port: Arc<dyn UserAttributesPort>) -> Self {
+        Self {
+            attributes_port,
+            events: None,
+        }
+    }
+
+    /// Publish a change event for every update that changes something
+    pub fn with_events(mut self, events: Arc<dyn UserAttributesEventPort>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Execute the update user attributes use case
+    ///
+    /// # Arguments
+    /// * `cmd` - UpdateUserAttributesCommand with the user HRN and the attributes to set
+    ///
+    /// # Returns
+    /// * Ok(UpdateUserAttributesResponse) with the attributes and the keys that changed
+    /// * Err(UpdateUserAttributesError) if there was an error
+    #[instrument(name = "update_user_attributes", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        principal = %cmd.user_hrn
+    ))]
+    pub async fn execute(
+        &self,
+        cmd: UpdateUserAttributesCommand,
+    ) -> Result<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        let user_hrn = Hrn::from_string(&cmd.user_hrn)
+            .ok_or_else(|| UpdateUserAttributesError::InvalidUserHrn(cmd.user_hrn.clone()))?;
+        if let Some(name) = &cmd.name
+            && name.trim().is_empty()
+        {
+            return Err(UpdateUserAttributesError::InvalidName(
+                "Name cannot be empty".to_string(),
+            ));
+        }
+        let email = cmd
+            .email
+            .as_deref()
+            .map(|email| {
+                validate_email(email)
+                    .map(|()| normalize_email(email))
+                    .map_err(|e| UpdateUserAttributesError::InvalidEmail(e.to_string()))
+            })
+            .transpose()?;
+
+        let current = self
+            .attributes_port
+            .find_attributes(&user_hrn)
+            .await?
+            .ok_or_else(|| UpdateUserAttributesError::UserNotFound(cmd.user_hrn.clone()))?;
+        let updated = UserAttributes {
+            name: cmd.name.unwrap_or_else(|| current.name.clone()),
+            email: email.unwrap_or_else(|| current.email.clone()),
+            tags: cmd
+                .tags
+                .map(AttributeChange::unique_tags)
+                .unwrap_or_else(|| current.tags.clone()),
+        };
+
+        let changes = changes(&current, &updated);
+        let changed_keys: Vec<String> = changes.iter().map(|change| change.key.clone()).collect();
+        if !changes.is_empty() {
+            self.attributes_port
+                .save_attributes(&user_hrn, &updated)
+                .instrument(info_span!("persistence"))
+                .await?;
+            info!(changed = ?changed_keys, "User attributes changed");
+            if let Some(events) = &self.events {
+                events
+                    .publish(UserAttributesChanged::new(user_hrn, changes))
+                    .await;
+            }
+        }
+
+        Ok(UpdateUserAttributesResponse {
+            user_hrn: cmd.user_hrn,
+            attributes: updated,
+            changed_keys,
+        })
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The user must belong to the tenant, otherwise the command fails with
+    /// `CrossTenantAccess` before any lookup.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        cmd: UpdateUserAttributesCommand,
+    ) -> Result<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        tenant.run(cmd, |cmd| self.execute(cmd)).await
+    }
+}
+
+/// The attributes that differ between `current` and `updated`
+fn changes(current: &UserAttributes, updated: &UserAttributes) -> Vec<AttributeChange> {
+    let mut changes = Vec::new();
+    changes.extend(AttributeChange::between(
+        "name",
+        Some(json!(current.name)),
+        Some(json!(updated.name)),
+    ));
+    if current.email != updated.email {
+        changes.push(AttributeChange {
+            key: "email".to_string(),
+            old_value: None,
+            new_value: None,
+        });
+    }
+    changes.extend(AttributeChange::between_tags(
+        "tags",
+        &current.tags,
+        &updated.tags,
+    ));
+    changes
+}
+
+#[async_trait]
+impl UpdateUserAttributesUseCasePort for UpdateUserAttributesUseCase {
+    async fn execute(
+        &self,
+        command: UpdateUserAttributesCommand,
+    ) -> Result<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        self.execute(command).await
+    }
+}
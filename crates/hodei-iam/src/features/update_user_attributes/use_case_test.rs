@@ -0,0 +1,240 @@
+//! Unit tests for update_user_attributes use case
+//!
+//! These tests verify the behavior of the UpdateUserAttributesUseCase in
+//! isolation, using mocks to simulate external dependencies.
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use kernel::{Hrn, TenantContext};
+    use serde_json::json;
+
+    use crate::features::update_user_attributes::{
+        dto::{AttributeChange, UpdateUserAttributesCommand, UserAttributes},
+        error::UpdateUserAttributesError,
+        mocks::{MockUserAttributesEventPort, MockUserAttributesPort},
+        use_case::UpdateUserAttributesUseCase,
+    };
+
+    // ============================================================================
+    // Helper Functions
+    // ============================================================================
+
+    fn user_hrn(account: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            account.to_string(),
+            "User".to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn alice() -> Hrn {
+        user_hrn("account123", "alice")
+    }
+
+    fn attributes() -> UserAttributes {
+        UserAttributes {
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            tags: vec!["engineering".to_string(), "oncall".to_string()],
+        }
+    }
+
+    fn command(user_hrn: &Hrn) -> UpdateUserAttributesCommand {
+        UpdateUserAttributesCommand {
+            user_hrn: user_hrn.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn use_case(
+        port: Arc<MockUserAttributesPort>,
+    ) -> (
+        UpdateUserAttributesUseCase,
+        Arc<MockUserAttributesEventPort>,
+    ) {
+        let events = Arc::new(MockUserAttributesEventPort::default());
+        let use_case = UpdateUserAttributesUseCase::new(port).with_events(events.clone());
+        (use_case, events)
+    }
+
+    // ============================================================================
+    // Tests
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_changed_attributes_are_saved_and_announced() {
+        let port = Arc::new(MockUserAttributesPort::new().with_user(alice(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        let response = use_case
+            .execute(UpdateUserAttributesCommand {
+                name: Some("Alice Smith".to_string()),
+                tags: Some(vec!["finance".to_string()]),
+                ..command(&alice())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.changed_keys, vec!["name", "tags"]);
+        let saved = port.attributes(&alice()).unwrap();
+        assert_eq!(saved.name, "Alice Smith");
+        assert_eq!(saved.email, "alice@example.com");
+        assert_eq!(saved.tags, vec!["finance"]);
+
+        let events = events.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].user_hrn, alice());
+        assert_eq!(
+            events[0].changes,
+            vec![
+                AttributeChange {
+                    key: "name".to_string(),
+                    old_value: Some(json!("Alice")),
+                    new_value: Some(json!("Alice Smith")),
+                },
+                AttributeChange {
+                    key: "tags".to_string(),
+                    old_value: Some(json!(["engineering", "oncall"])),
+                    new_value: Some(json!(["finance"])),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_op_updates_are_not_saved_or_announced() {
+        let port = Arc::new(MockUserAttributesPort::new().with_user(alice(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        let response = use_case
+            .execute(UpdateUserAttributesCommand {
+                name: Some("Alice".to_string()),
+                email: Some("alice@EXAMPLE.com".to_string()),
+                tags: Some(vec![
+                    "oncall".to_string(),
+                    "engineering".to_string(),
+                    "oncall".to_string(),
+                ]),
+                ..command(&alice())
+            })
+            .await
+            .unwrap();
+
+        assert!(response.changed_keys.is_empty());
+        assert_eq!(port.saves(), 0);
+        assert!(events.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_email_change_is_announced_without_the_addresses() {
+        let port = Arc::new(MockUserAttributesPort::new().with_user(alice(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        use_case
+            .execute(UpdateUserAttributesCommand {
+                email: Some("alice@corp.example.com".to_string()),
+                ..command(&alice())
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            port.attributes(&alice()).unwrap().email,
+            "alice@corp.example.com"
+        );
+        let events = events.events();
+        assert_eq!(events[0].changed_keys(), vec!["email"]);
+        assert_eq!(events[0].changes[0].old_value, None);
+        assert_eq!(events[0].changes[0].new_value, None);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_values_are_rejected() {
+        let port = Arc::new(MockUserAttributesPort::new().with_user(alice(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+
+        let empty_name = use_case
+            .execute(UpdateUserAttributesCommand {
+                name: Some("  ".to_string()),
+                ..command(&alice())
+            })
+            .await;
+        assert!(matches!(
+            empty_name,
+            Err(UpdateUserAttributesError::InvalidName(_))
+        ));
+
+        let bad_email = use_case
+            .execute(UpdateUserAttributesCommand {
+                email: Some("not-an-email".to_string()),
+                ..command(&alice())
+            })
+            .await;
+        assert!(matches!(
+            bad_email,
+            Err(UpdateUserAttributesError::InvalidEmail(_))
+        ));
+        assert_eq!(port.saves(), 0);
+        assert!(events.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_is_not_found() {
+        let (use_case, _) = use_case(Arc::new(MockUserAttributesPort::new()));
+
+        let result = use_case.execute(command(&alice())).await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateUserAttributesError::UserNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_save_is_not_announced() {
+        let port = Arc::new(MockUserAttributesPort::failing().with_user(alice(), attributes()));
+        let (use_case, events) = use_case(port);
+
+        let result = use_case
+            .execute(UpdateUserAttributesCommand {
+                name: Some("Alice Smith".to_string()),
+                ..command(&alice())
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateUserAttributesError::PersistenceError(_))
+        ));
+        assert!(events.events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_for_tenant_rejects_other_tenants_users() {
+        let bob = user_hrn("other-account", "bob");
+        let port = Arc::new(MockUserAttributesPort::new().with_user(bob.clone(), attributes()));
+        let (use_case, events) = use_case(port.clone());
+        let tenant = TenantContext::new("account123");
+
+        let result = use_case
+            .execute_for_tenant(
+                &tenant,
+                UpdateUserAttributesCommand {
+                    name: Some("Bob".to_string()),
+                    ..command(&bob)
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(UpdateUserAttributesError::CrossTenantAccess(_))
+        ));
+        assert_eq!(port.attributes(&bob).unwrap().name, "Alice");
+        assert!(events.events().is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+//! Event bus adapter for the IAM change events
+//!
+//! [`EventBusIamEvents`] implements the event ports of the IAM use cases that
+//! change policies, memberships, user status or attributes by publishing
+//! each event on the shared bus, where the access index, the authorizer's
+//! caches and the audit log subscribe to them. A publish failure is logged, not returned: the change
+//! is already persisted, and the caches still expire on their own.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kernel::application::ports::event_bus::{DomainEvent, EventPublisher};
+use kernel::{GroupMembershipChanged, PolicyAttachmentChanged, PolicyDeleted, PolicyStored};
+use tracing::warn;
+
+use crate::features::add_group_to_group::ports::GroupHierarchyEventPort;
+use crate::features::add_user_to_group::ports::UserMembershipEventPort;
+use crate::features::create_policy::ports::CreatePolicyEventPort;
+use crate::features::delete_policy::ports::DeletePolicyEventPort;
+use crate::features::import_iam_state::ports::IamStateEventPort;
+use crate::features::set_user_status::dto::UserStatusChanged;
+use crate::features::set_user_status::ports::UserStatusEventPort;
+use crate::features::update_group_attributes::dto::GroupAttributesChanged;
+use crate::features::update_group_attributes::ports::GroupAttributesEventPort;
+use crate::features::update_policy::ports::UpdatePolicyEventPort;
+use crate::features::update_user_attributes::dto::UserAttributesChanged;
+use crate::features::update_user_attributes::ports::UserAttributesEventPort;
+
+/// Event publisher that forwards IAM changes to an event bus
+pub struct EventBusIamEvents<P> {
+    publisher: Arc<P>,
+}
+
+impl<P> EventBusIamEvents<P> {
+    pub fn new(publisher: Arc<P>) -> Self {
+        Self { publisher }
+    }
+}
+
+impl<P: EventPublisher> EventBusIamEvents<P> {
+    async fn publish_logged<E: DomainEvent>(&self, event: E) {
+        let event_type = event.event_type();
+        let aggregate_id = event.aggregate_id().unwrap_or_default();
+        if let Err(e) = self.publisher.publish(event).await {
+            warn!(event_type, aggregate_id, error = %e, "Failed to publish IAM event");
+        }
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> UserStatusEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: UserStatusChanged) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> UserAttributesEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: UserAttributesChanged) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> GroupAttributesEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: GroupAttributesChanged) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> CreatePolicyEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: PolicyStored) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> UpdatePolicyEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: PolicyStored) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> DeletePolicyEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: PolicyDeleted) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> UserMembershipEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: GroupMembershipChanged) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> GroupHierarchyEventPort for EventBusIamEvents<P> {
+    async fn publish(&self, event: GroupMembershipChanged) {
+        self.publish_logged(event).await;
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> IamStateEventPort for EventBusIamEvents<P> {
+    async fn publish_policy_stored(&self, event: PolicyStored) {
+        self.publish_logged(event).await;
+    }
+
+    async fn publish_attachment_changed(&self, event: PolicyAttachmentChanged) {
+        self.publish_logged(event).await;
+    }
+
+    async fn publish_membership_changed(&self, event: GroupMembershipChanged) {
+        self.publish_logged(event).await;
+    }
+}
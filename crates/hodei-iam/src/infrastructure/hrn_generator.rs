@@ -55,3 +55,17 @@ impl UuidHrnGenerator {
         }
     }
 }
+
+/// HRN the IAM stores name a stored policy with
+///
+/// Policies are stored by ID alone, so every store derives the HRN the same
+/// way, rather than each feature building its own.
+pub(crate) fn policy_hrn(policy_id: &str) -> Hrn {
+    Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        "Policy".to_string(),
+        policy_id.to_string(),
+    )
+}
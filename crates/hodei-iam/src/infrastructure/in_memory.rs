@@ -0,0 +1,1028 @@
+//! In-memory IAM repository
+//!
+//! [`InMemoryIamRepository`] keeps users, groups, policies and policy
+//! attachments in process memory and implements the finder ports of the
+//! get_effective_policies feature, the status port of set_user_status, the
+//! hierarchy port of add_group_to_group, the member finder of
+//! list_group_members, the state ports of export_iam_state and
+//! import_iam_state, the shared policy walk with the disabling port of
+//! revalidate_policies, and the entity existence port of
+//! find_dangling_references. It is meant for tests that need real resolution rather
+//! than canned answers: build the data, then call
+//! [`InMemoryIamRepository::effective_policies_query`] to get the same
+//! [`GetEffectivePoliciesUseCase`] production uses, or
+//! [`InMemoryIamRepository::effective_policies_port`] for the kernel's
+//! `EffectivePoliciesQueryPort`.
+//!
+//! Group membership is recorded on the member (a user or a nested group), as
+//! in the domain model, and policies apply to the principals they are
+//! attached to, unless they are disabled.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::references::EntityReference;
+use kernel::domain::{HodeiPolicy, PolicyId};
+use kernel::{HodeiEntityType, Hrn, PrincipalStatus};
+use tracing::debug;
+
+use crate::features::add_group_to_group::error::AddGroupToGroupError;
+use crate::features::add_group_to_group::ports::GroupHierarchyPort;
+use crate::features::export_iam_state::dto::{GroupRecord, PolicyRecord, UserRecord};
+use crate::features::export_iam_state::error::ExportIamStateError;
+use crate::features::export_iam_state::ports::IamStateReaderPort;
+use crate::features::find_dangling_references::dto::EntityExistence;
+use crate::features::find_dangling_references::error::FindDanglingReferencesError;
+use crate::features::find_dangling_references::ports::EntityExistencePort;
+use crate::features::get_effective_policies::adapter::GetEffectivePoliciesAdapter;
+use crate::features::get_effective_policies::dto::{GroupLookupDto, UserLookupDto};
+use crate::features::get_effective_policies::error::GetEffectivePoliciesError;
+use crate::features::get_effective_policies::ports::{
+    GroupFinderPort, PolicyFinderPort, UserFinderPort,
+};
+use crate::features::get_effective_policies::use_case::GetEffectivePoliciesUseCase;
+use crate::features::import_iam_state::dto::IamStateChanges;
+use crate::features::import_iam_state::error::ImportIamStateError;
+use crate::features::import_iam_state::ports::IamStateStorePort;
+use crate::features::import_policies::error::ImportPoliciesError;
+use crate::features::import_policies::ports::PolicyImportStorePort;
+use crate::features::list_group_members::dto::GroupMemberSummary;
+use crate::features::list_group_members::error::ListGroupMembersError;
+use crate::features::list_group_members::ports::GroupMemberFinderPort;
+use crate::features::policy_walk::dto::StoredPolicy;
+use crate::features::policy_walk::error::PolicyWalkError;
+use crate::features::policy_walk::ports::PolicyWalkStorePort;
+use crate::features::revalidate_policies::error::RevalidatePoliciesError;
+use crate::features::revalidate_policies::ports::PolicyRevalidationStorePort;
+use crate::features::set_user_status::error::SetUserStatusError;
+use crate::features::set_user_status::ports::UserStatusPort;
+use crate::features::update_group_attributes::dto::GroupAttributes;
+use crate::features::update_group_attributes::error::UpdateGroupAttributesError;
+use crate::features::update_group_attributes::ports::GroupAttributesPort;
+use crate::features::update_user_attributes::dto::UserAttributes;
+use crate::features::update_user_attributes::error::UpdateUserAttributesError;
+use crate::features::update_user_attributes::ports::UserAttributesPort;
+use crate::infrastructure::hrn_generator::policy_hrn;
+use crate::infrastructure::state_records::{
+    group_from_record, group_record, parse_hrn, user_from_record, user_record,
+};
+use crate::internal::domain::{Group, User};
+
+#[derive(Debug, Default)]
+struct State {
+    users: HashMap<Hrn, User>,
+    groups: HashMap<Hrn, Group>,
+    policies: HashMap<String, HodeiPolicy>,
+    /// Policy IDs attached to each principal, in attachment order
+    attachments: HashMap<Hrn, Vec<String>>,
+    /// Reason each disabled policy was disabled
+    disabled: HashMap<String, String>,
+}
+
+/// Users, groups and policies held in memory
+///
+/// Clones share the same data, so a test can keep a handle to change
+/// memberships or attachments after wiring the repository into a use case.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryIamRepository {
+    state: Arc<RwLock<State>>,
+}
+
+impl InMemoryIamRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a user
+    pub fn add_user(&self, hrn: Hrn, name: impl Into<String>, email: impl Into<String>) {
+        let user = User::new(hrn.clone(), name.into(), email.into());
+        self.state.write().unwrap().users.insert(hrn, user);
+    }
+
+    /// Add or replace a group
+    pub fn add_group(&self, hrn: Hrn, name: impl Into<String>) {
+        let group = Group::new(hrn.clone(), name.into(), None);
+        self.state.write().unwrap().groups.insert(hrn, group);
+    }
+
+    /// Make the user a member of the group (idempotent)
+    ///
+    /// # Errors
+    ///
+    /// Returns `PrincipalNotFound` or `GroupNotFound` if either is unknown.
+    pub fn add_user_to_group(
+        &self,
+        user_hrn: &Hrn,
+        group_hrn: &Hrn,
+    ) -> Result<(), GetEffectivePoliciesError> {
+        let mut state = self.state.write().unwrap();
+        if !state.groups.contains_key(group_hrn) {
+            return Err(GetEffectivePoliciesError::GroupNotFound(
+                group_hrn.to_string(),
+            ));
+        }
+        let user = state
+            .users
+            .get_mut(user_hrn)
+            .ok_or_else(|| GetEffectivePoliciesError::PrincipalNotFound(user_hrn.to_string()))?;
+        user.add_to_group(group_hrn.clone());
+        Ok(())
+    }
+
+    /// Remove the user from the group; a no-op if it was not a member
+    pub fn remove_user_from_group(&self, user_hrn: &Hrn, group_hrn: &Hrn) {
+        if let Some(user) = self.state.write().unwrap().users.get_mut(user_hrn) {
+            user.remove_from_group(group_hrn);
+        }
+    }
+
+    /// Make the child group a member of the parent group (idempotent)
+    ///
+    /// This writes the membership as given, cycles included, so tests can
+    /// set up data the add_group_to_group use case would refuse; use that
+    /// use case over this repository for checked nesting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GroupNotFound` if either group is unknown.
+    pub fn add_group_to_group(
+        &self,
+        child_hrn: &Hrn,
+        parent_hrn: &Hrn,
+    ) -> Result<(), GetEffectivePoliciesError> {
+        let mut state = self.state.write().unwrap();
+        if !state.groups.contains_key(parent_hrn) {
+            return Err(GetEffectivePoliciesError::GroupNotFound(
+                parent_hrn.to_string(),
+            ));
+        }
+        let child = state
+            .groups
+            .get_mut(child_hrn)
+            .ok_or_else(|| GetEffectivePoliciesError::GroupNotFound(child_hrn.to_string()))?;
+        child.add_to_group(parent_hrn.clone());
+        Ok(())
+    }
+
+    /// Remove the child group from the parent group; a no-op if it was not a member
+    pub fn remove_group_from_group(&self, child_hrn: &Hrn, parent_hrn: &Hrn) {
+        if let Some(child) = self.state.write().unwrap().groups.get_mut(child_hrn) {
+            child.remove_from_group(parent_hrn);
+        }
+    }
+
+    /// Add or replace a policy; replacing keeps its attachments
+    pub fn add_policy(&self, policy: HodeiPolicy) {
+        let id = policy.id().to_string();
+        self.state.write().unwrap().policies.insert(id, policy);
+    }
+
+    /// Attach a stored policy to a user or group (idempotent)
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyNotFound` if no policy with `policy_id` was added.
+    pub fn attach_policy(
+        &self,
+        policy_id: &str,
+        principal_hrn: &Hrn,
+    ) -> Result<(), GetEffectivePoliciesError> {
+        let mut state = self.state.write().unwrap();
+        if !state.policies.contains_key(policy_id) {
+            return Err(GetEffectivePoliciesError::PolicyNotFound(
+                policy_id.to_string(),
+            ));
+        }
+        let attached = state.attachments.entry(principal_hrn.clone()).or_default();
+        if !attached.iter().any(|id| id == policy_id) {
+            attached.push(policy_id.to_string());
+        }
+        Ok(())
+    }
+
+    /// Detach a policy from a user or group; a no-op if it was not attached
+    pub fn detach_policy(&self, policy_id: &str, principal_hrn: &Hrn) {
+        if let Some(attached) = self
+            .state
+            .write()
+            .unwrap()
+            .attachments
+            .get_mut(principal_hrn)
+        {
+            attached.retain(|id| id != policy_id);
+        }
+    }
+
+    /// The effective-policies use case resolving against this repository
+    pub fn effective_policies_query(&self) -> GetEffectivePoliciesUseCase {
+        GetEffectivePoliciesUseCase::new(
+            Arc::new(self.clone()),
+            Arc::new(self.clone()),
+            Arc::new(self.clone()),
+        )
+    }
+
+    /// The kernel's `EffectivePoliciesQueryPort` resolving against this repository
+    pub fn effective_policies_port(&self) -> GetEffectivePoliciesAdapter<Self, Self, Self> {
+        GetEffectivePoliciesAdapter::new(
+            Arc::new(self.clone()),
+            Arc::new(self.clone()),
+            Arc::new(self.clone()),
+        )
+    }
+}
+
+#[async_trait]
+impl UserFinderPort for InMemoryIamRepository {
+    async fn find_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<UserLookupDto>, GetEffectivePoliciesError> {
+        let state = self.state.read().unwrap();
+        Ok(state.users.get(hrn).map(|user| UserLookupDto {
+            hrn: user.hrn.to_string(),
+            name: user.name.clone(),
+            email: user.email.clone(),
+            group_hrns: user.groups().iter().map(|hrn| hrn.to_string()).collect(),
+            tags: user.tags.clone(),
+            status: user.status,
+        }))
+    }
+}
+
+#[async_trait]
+impl GroupFinderPort for InMemoryIamRepository {
+    async fn find_groups_by_user_hrn(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError> {
+        let state = self.state.read().unwrap();
+        let Some(user) = state.users.get(user_hrn) else {
+            return Ok(Vec::new());
+        };
+
+        let groups = lookup_groups(&state, user.groups());
+        debug!(user = %user_hrn, group_count = groups.len(), "Found groups for user");
+        Ok(groups)
+    }
+
+    async fn find_parent_groups(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError> {
+        let state = self.state.read().unwrap();
+        Ok(state
+            .groups
+            .get(group_hrn)
+            .map(|group| lookup_groups(&state, group.parent_groups()))
+            .unwrap_or_default())
+    }
+}
+
+/// Lookup DTOs for the stored groups among `group_hrns`, in order
+fn lookup_groups(state: &State, group_hrns: &[Hrn]) -> Vec<GroupLookupDto> {
+    group_hrns
+        .iter()
+        .filter_map(|group_hrn| state.groups.get(group_hrn))
+        .map(|group| GroupLookupDto {
+            hrn: group.hrn.to_string(),
+            name: group.name.clone(),
+            tags: group.tags.clone(),
+        })
+        .collect()
+}
+
+#[async_trait]
+impl PolicyFinderPort for InMemoryIamRepository {
+    async fn find_policies_by_principal(
+        &self,
+        principal_hrn: &Hrn,
+    ) -> Result<Vec<HodeiPolicy>, GetEffectivePoliciesError> {
+        let state = self.state.read().unwrap();
+        let policies: Vec<HodeiPolicy> = state
+            .attachments
+            .get(principal_hrn)
+            .into_iter()
+            .flatten()
+            .filter(|policy_id| !state.disabled.contains_key(*policy_id))
+            .filter_map(|policy_id| state.policies.get(policy_id).cloned())
+            .collect();
+
+        debug!(
+            principal = %principal_hrn,
+            policy_count = policies.len(),
+            "Found policies for principal"
+        );
+        Ok(policies)
+    }
+}
+
+#[async_trait]
+impl UserStatusPort for InMemoryIamRepository {
+    async fn find_status(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<PrincipalStatus>, SetUserStatusError> {
+        let state = self.state.read().unwrap();
+        Ok(state.users.get(user_hrn).map(|user| user.status))
+    }
+
+    async fn save_status(
+        &self,
+        user_hrn: &Hrn,
+        status: PrincipalStatus,
+    ) -> Result<(), SetUserStatusError> {
+        let mut state = self.state.write().unwrap();
+        let user = state
+            .users
+            .get_mut(user_hrn)
+            .ok_or_else(|| SetUserStatusError::UserNotFound(user_hrn.to_string()))?;
+        user.status = status;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UserAttributesPort for InMemoryIamRepository {
+    async fn find_attributes(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<UserAttributes>, UpdateUserAttributesError> {
+        let state = self.state.read().unwrap();
+        Ok(state.users.get(user_hrn).map(|user| UserAttributes {
+            name: user.name.clone(),
+            email: user.email.clone(),
+            tags: user.tags.clone(),
+        }))
+    }
+
+    async fn save_attributes(
+        &self,
+        user_hrn: &Hrn,
+        attributes: &UserAttributes,
+    ) -> Result<(), UpdateUserAttributesError> {
+        let mut state = self.state.write().unwrap();
+        let user = state
+            .users
+            .get_mut(user_hrn)
+            .ok_or_else(|| UpdateUserAttributesError::UserNotFound(user_hrn.to_string()))?;
+        user.name = attributes.name.clone();
+        user.email = attributes.email.clone();
+        user.tags = attributes.tags.clone();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GroupAttributesPort for InMemoryIamRepository {
+    async fn find_attributes(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<GroupAttributes>, UpdateGroupAttributesError> {
+        let state = self.state.read().unwrap();
+        Ok(state.groups.get(group_hrn).map(|group| GroupAttributes {
+            name: group.name.clone(),
+            description: group.description.clone(),
+            tags: group.tags.clone(),
+        }))
+    }
+
+    async fn save_attributes(
+        &self,
+        group_hrn: &Hrn,
+        attributes: &GroupAttributes,
+    ) -> Result<(), UpdateGroupAttributesError> {
+        let mut state = self.state.write().unwrap();
+        let group = state
+            .groups
+            .get_mut(group_hrn)
+            .ok_or_else(|| UpdateGroupAttributesError::GroupNotFound(group_hrn.to_string()))?;
+        group.name = attributes.name.clone();
+        group.description = attributes.description.clone();
+        group.tags = attributes.tags.clone();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GroupHierarchyPort for InMemoryIamRepository {
+    async fn find_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<Vec<Hrn>>, AddGroupToGroupError> {
+        let state = self.state.read().unwrap();
+        Ok(state
+            .groups
+            .get(group_hrn)
+            .map(|group| group.parent_groups().to_vec()))
+    }
+
+    async fn save_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+        parent_group_hrns: &[Hrn],
+    ) -> Result<(), AddGroupToGroupError> {
+        let mut state = self.state.write().unwrap();
+        let group = state
+            .groups
+            .get_mut(group_hrn)
+            .ok_or_else(|| AddGroupToGroupError::GroupNotFound(group_hrn.to_string()))?;
+        group.parent_group_hrns = parent_group_hrns.to_vec();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GroupMemberFinderPort for InMemoryIamRepository {
+    async fn group_exists(&self, group_hrn: &Hrn) -> Result<bool, ListGroupMembersError> {
+        Ok(self.state.read().unwrap().groups.contains_key(group_hrn))
+    }
+
+    async fn find_user_members(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError> {
+        let state = self.state.read().unwrap();
+        Ok(state
+            .users
+            .values()
+            .filter(|user| user.groups().contains(group_hrn))
+            .map(|user| user.hrn.clone())
+            .collect())
+    }
+
+    async fn find_child_groups(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError> {
+        let state = self.state.read().unwrap();
+        Ok(state
+            .groups
+            .values()
+            .filter(|group| group.parent_groups().contains(group_hrn))
+            .map(|group| group.hrn.clone())
+            .collect())
+    }
+
+    async fn find_member_summaries(
+        &self,
+        user_hrns: &[Hrn],
+    ) -> Result<Vec<GroupMemberSummary>, ListGroupMembersError> {
+        let state = self.state.read().unwrap();
+        Ok(user_hrns
+            .iter()
+            .filter_map(|hrn| state.users.get(hrn))
+            .map(|user| GroupMemberSummary {
+                hrn: user.hrn.to_string(),
+                name: user.name.clone(),
+                email: user.email.clone(),
+                status: user.status,
+            })
+            .collect())
+    }
+}
+
+impl State {
+    fn user_records(&self) -> Vec<UserRecord> {
+        self.users.values().map(user_record).collect()
+    }
+
+    fn group_records(&self) -> Vec<GroupRecord> {
+        self.groups.values().map(group_record).collect()
+    }
+
+    fn policy_records(&self) -> Vec<PolicyRecord> {
+        self.policies
+            .values()
+            .map(|policy| {
+                let id = policy.id().to_string();
+                let attached_principals = self
+                    .attachments
+                    .iter()
+                    .filter(|(_, attached)| attached.contains(&id))
+                    .map(|(principal, _)| principal.to_string())
+                    .collect();
+                PolicyRecord {
+                    content: policy.content().to_string(),
+                    id,
+                    attached_principals,
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl IamStateReaderPort for InMemoryIamRepository {
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ExportIamStateError> {
+        Ok(self.state.read().unwrap().user_records())
+    }
+
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ExportIamStateError> {
+        Ok(self.state.read().unwrap().group_records())
+    }
+
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ExportIamStateError> {
+        Ok(self.state.read().unwrap().policy_records())
+    }
+}
+
+#[async_trait]
+impl IamStateStorePort for InMemoryIamRepository {
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ImportIamStateError> {
+        Ok(self.state.read().unwrap().user_records())
+    }
+
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ImportIamStateError> {
+        Ok(self.state.read().unwrap().group_records())
+    }
+
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportIamStateError> {
+        Ok(self.state.read().unwrap().policy_records())
+    }
+
+    async fn apply_changes(&self, changes: &IamStateChanges) -> Result<(), ImportIamStateError> {
+        // Convert everything before taking the lock, so a bad record leaves
+        // the state untouched
+        let users = changes
+            .users
+            .iter()
+            .map(user_from_record)
+            .collect::<Result<Vec<User>, String>>()
+            .map_err(ImportIamStateError::RepositoryError)?;
+        let groups = changes
+            .groups
+            .iter()
+            .map(group_from_record)
+            .collect::<Result<Vec<Group>, String>>()
+            .map_err(ImportIamStateError::RepositoryError)?;
+        let mut policies = Vec::new();
+        for record in &changes.policies {
+            let principals = record
+                .attached_principals
+                .iter()
+                .map(|hrn| parse_hrn(hrn))
+                .collect::<Result<Vec<Hrn>, String>>()
+                .map_err(ImportIamStateError::RepositoryError)?;
+            let policy = HodeiPolicy::new(PolicyId::new(&record.id), record.content.clone());
+            policies.push((policy, principals));
+        }
+
+        let mut state = self.state.write().unwrap();
+        for user in users {
+            state.users.insert(user.hrn.clone(), user);
+        }
+        for group in groups {
+            state.groups.insert(group.hrn.clone(), group);
+        }
+        for (policy, principals) in policies {
+            let id = policy.id().to_string();
+            for attached in state.attachments.values_mut() {
+                attached.retain(|attached_id| *attached_id != id);
+            }
+            for principal in principals {
+                state
+                    .attachments
+                    .entry(principal)
+                    .or_default()
+                    .push(id.clone());
+            }
+            state.policies.insert(id, policy);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PolicyImportStorePort for InMemoryIamRepository {
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportPoliciesError> {
+        Ok(self.state.read().unwrap().policy_records())
+    }
+
+    async fn write_policies(&self, policies: &[PolicyRecord]) -> Result<(), ImportPoliciesError> {
+        let changes = IamStateChanges {
+            policies: policies.to_vec(),
+            ..Default::default()
+        };
+        IamStateStorePort::apply_changes(self, &changes)
+            .await
+            .map_err(|e| ImportPoliciesError::RepositoryError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl PolicyWalkStorePort for InMemoryIamRepository {
+    async fn list_policies(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredPolicy>, PolicyWalkError> {
+        let state = self.state.read().unwrap();
+        let mut ids: Vec<&String> = state
+            .policies
+            .keys()
+            .filter(|id| after.is_none_or(|after| id.as_str() > after))
+            .collect();
+        ids.sort();
+        Ok(ids
+            .into_iter()
+            .take(limit)
+            .map(|id| StoredPolicy {
+                hrn: policy_hrn(id),
+                id: id.clone(),
+                content: state.policies[id].content().to_string(),
+                disabled_reason: state.disabled.get(id).cloned(),
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl PolicyRevalidationStorePort for InMemoryIamRepository {
+    async fn disable_policy(
+        &self,
+        policy_id: &str,
+        reason: &str,
+    ) -> Result<(), RevalidatePoliciesError> {
+        let mut state = self.state.write().unwrap();
+        if !state.policies.contains_key(policy_id) {
+            return Err(RevalidatePoliciesError::RepositoryError(format!(
+                "Policy not found: {}",
+                policy_id
+            )));
+        }
+        state
+            .disabled
+            .insert(policy_id.to_string(), reason.to_string());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EntityExistencePort for InMemoryIamRepository {
+    async fn check_entities(
+        &self,
+        references: &[EntityReference],
+    ) -> Result<EntityExistence, FindDanglingReferencesError> {
+        let state = self.state.read().unwrap();
+        let user_type = User::entity_type_name();
+        let group_type = Group::entity_type_name();
+        let named = |hrn: &Hrn, reference: &EntityReference| {
+            hrn.entity_type_name() == reference.entity_type
+                && hrn.resource_id() == reference.entity_id
+        };
+
+        let mut existence = EntityExistence::default();
+        for reference in references {
+            let found = if reference.entity_type == user_type {
+                state.users.keys().any(|hrn| named(hrn, reference))
+            } else if reference.entity_type == group_type {
+                state.groups.keys().any(|hrn| named(hrn, reference))
+            } else {
+                existence.unknown.push(reference.clone());
+                continue;
+            };
+            if !found {
+                existence.missing.push(reference.clone());
+            }
+        }
+        Ok(existence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::add_group_to_group::dto::AddGroupToGroupCommand;
+    use crate::features::add_group_to_group::error::AddGroupToGroupError;
+    use crate::features::add_group_to_group::use_case::AddGroupToGroupUseCase;
+    use crate::features::export_iam_state::dto::ExportIamStateQuery;
+    use crate::features::export_iam_state::use_case::ExportIamStateUseCase;
+    use crate::features::find_dangling_references::dto::FindDanglingReferencesQuery;
+    use crate::features::find_dangling_references::use_case::FindDanglingReferencesUseCase;
+    use crate::features::get_effective_policies::dto::GetEffectivePoliciesQuery;
+    use crate::features::import_iam_state::dto::ImportIamStateCommand;
+    use crate::features::import_iam_state::use_case::ImportIamStateUseCase;
+    use crate::features::list_group_members::dto::{
+        ListGroupMembersQuery, ListGroupMembersResponse,
+    };
+    use crate::features::list_group_members::use_case::ListGroupMembersUseCase;
+    use crate::features::set_user_status::dto::SetUserStatusCommand;
+    use crate::features::set_user_status::use_case::SetUserStatusUseCase;
+    use kernel::PageRequest;
+    use kernel::application::ports::{EffectivePoliciesQuery, EffectivePoliciesQueryPort};
+
+    fn hrn(resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "tenant-a".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    fn policy(id: &str) -> HodeiPolicy {
+        HodeiPolicy::new(
+            PolicyId::new(id),
+            "permit(principal, action, resource);".to_string(),
+        )
+    }
+
+    /// alice is in devs; alice has `direct`, devs has `shared` and `team`,
+    /// and alice also has `shared` directly
+    fn repository() -> InMemoryIamRepository {
+        let repository = InMemoryIamRepository::new();
+        repository.add_user(hrn("User", "alice"), "Alice", "alice@example.com");
+        repository.add_group(hrn("Group", "devs"), "Developers");
+        repository
+            .add_user_to_group(&hrn("User", "alice"), &hrn("Group", "devs"))
+            .unwrap();
+        for id in ["direct", "shared", "team"] {
+            repository.add_policy(policy(id));
+        }
+        for (id, principal) in [
+            ("direct", hrn("User", "alice")),
+            ("shared", hrn("User", "alice")),
+            ("shared", hrn("Group", "devs")),
+            ("team", hrn("Group", "devs")),
+        ] {
+            repository.attach_policy(id, &principal).unwrap();
+        }
+        repository
+    }
+
+    async fn effective_ids(repository: &InMemoryIamRepository) -> Vec<String> {
+        let response = repository
+            .effective_policies_query()
+            .execute(GetEffectivePoliciesQuery {
+                principal_hrn: hrn("User", "alice").to_string(),
+            })
+            .await
+            .unwrap();
+        response
+            .policies
+            .policies()
+            .iter()
+            .map(|policy| policy.id().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn resolves_direct_and_group_policies_once_each() {
+        let repository = repository();
+
+        assert_eq!(
+            effective_ids(&repository).await,
+            ["direct", "shared", "team"]
+        );
+    }
+
+    #[tokio::test]
+    async fn changes_apply_to_the_next_resolution() {
+        let repository = repository();
+
+        repository.detach_policy("direct", &hrn("User", "alice"));
+        repository.remove_user_from_group(&hrn("User", "alice"), &hrn("Group", "devs"));
+
+        assert_eq!(effective_ids(&repository).await, ["shared"]);
+    }
+
+    #[tokio::test]
+    async fn disabled_policies_stop_applying_but_stay_listed() {
+        let repository = repository();
+
+        repository
+            .disable_policy("team", "invalid against schema v2")
+            .await
+            .unwrap();
+
+        assert_eq!(effective_ids(&repository).await, ["direct", "shared"]);
+        let listed = PolicyWalkStorePort::list_policies(&repository, Some("shared"), 10)
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(
+            listed[0].disabled_reason.as_deref(),
+            Some("invalid against schema v2")
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_the_kernel_port_with_policy_ids_kept() {
+        let repository = repository();
+
+        let result = repository
+            .effective_policies_port()
+            .get_effective_policies(EffectivePoliciesQuery {
+                principal_hrn: hrn("User", "alice").to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.policy_count, 3);
+        let mut ids: Vec<String> = result
+            .policies
+            .policies()
+            .map(|p| p.id().to_string())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["direct", "shared", "team"]);
+    }
+
+    #[tokio::test]
+    async fn suspended_user_keeps_memberships_but_loses_policies() {
+        let repository = repository();
+        let alice = hrn("User", "alice");
+        let use_case = SetUserStatusUseCase::new(Arc::new(repository.clone()));
+        let port = repository.effective_policies_port();
+        let query = || EffectivePoliciesQuery {
+            principal_hrn: alice.to_string(),
+        };
+
+        use_case
+            .execute(SetUserStatusCommand {
+                user_hrn: alice.to_string(),
+                status: PrincipalStatus::Suspended,
+            })
+            .await
+            .unwrap();
+        let suspended = port.get_effective_policies(query()).await.unwrap();
+        assert_eq!(suspended.policy_count, 0);
+        assert_eq!(suspended.principal_status, PrincipalStatus::Suspended);
+
+        use_case
+            .execute(SetUserStatusCommand {
+                user_hrn: alice.to_string(),
+                status: PrincipalStatus::Active,
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            effective_ids(&repository).await,
+            ["direct", "shared", "team"]
+        );
+    }
+
+    #[tokio::test]
+    async fn nested_groups_pass_policies_down_and_refuse_cycles() {
+        let repository = repository();
+        let engineering = hrn("Group", "engineering");
+        repository.add_group(engineering.clone(), "Engineering");
+        repository.add_policy(policy("org-wide"));
+        repository.attach_policy("org-wide", &engineering).unwrap();
+        let use_case = AddGroupToGroupUseCase::new(Arc::new(repository.clone()));
+        let nest = |child: &Hrn, parent: &Hrn| AddGroupToGroupCommand {
+            child_group_hrn: child.to_string(),
+            parent_group_hrn: parent.to_string(),
+        };
+
+        use_case
+            .execute(nest(&hrn("Group", "devs"), &engineering))
+            .await
+            .unwrap();
+        assert_eq!(
+            effective_ids(&repository).await,
+            ["direct", "shared", "team", "org-wide"]
+        );
+
+        let cycle = use_case
+            .execute(nest(&engineering, &hrn("Group", "devs")))
+            .await;
+        assert!(matches!(
+            cycle,
+            Err(AddGroupToGroupError::CycleDetected { .. })
+        ));
+
+        repository.remove_group_from_group(&hrn("Group", "devs"), &engineering);
+        assert_eq!(
+            effective_ids(&repository).await,
+            ["direct", "shared", "team"]
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_direct_and_nested_members_with_summaries() {
+        let repository = repository();
+        let engineering = hrn("Group", "engineering");
+        repository.add_group(engineering.clone(), "Engineering");
+        repository.add_user(hrn("User", "bob"), "Bob", "bob@example.com");
+        repository
+            .add_user_to_group(&hrn("User", "bob"), &engineering)
+            .unwrap();
+        repository
+            .add_group_to_group(&hrn("Group", "devs"), &engineering)
+            .unwrap();
+        let use_case = ListGroupMembersUseCase::new(Arc::new(repository.clone()));
+
+        let direct = use_case
+            .execute(ListGroupMembersQuery::direct(
+                engineering.to_string(),
+                PageRequest::default(),
+            ))
+            .await
+            .unwrap();
+        let effective = use_case
+            .execute(
+                ListGroupMembersQuery::transitive(engineering.to_string(), PageRequest::default())
+                    .with_summaries(),
+            )
+            .await
+            .unwrap();
+
+        let hrns = |response: &ListGroupMembersResponse| -> Vec<String> {
+            response
+                .members
+                .items
+                .iter()
+                .map(|member| member.user_hrn.clone())
+                .collect()
+        };
+        assert_eq!(hrns(&direct), [hrn("User", "bob").to_string()]);
+        assert_eq!(
+            hrns(&effective),
+            [
+                hrn("User", "alice").to_string(),
+                hrn("User", "bob").to_string()
+            ]
+        );
+        let alice = effective.members.items[0].summary.as_ref().unwrap();
+        assert_eq!(alice.email, "alice@example.com");
+    }
+
+    #[test]
+    fn rejects_unknown_members_and_policies() {
+        let repository = repository();
+
+        assert!(matches!(
+            repository.add_user_to_group(&hrn("User", "bob"), &hrn("Group", "devs")),
+            Err(GetEffectivePoliciesError::PrincipalNotFound(_))
+        ));
+        assert!(matches!(
+            repository.add_user_to_group(&hrn("User", "alice"), &hrn("Group", "ops")),
+            Err(GetEffectivePoliciesError::GroupNotFound(_))
+        ));
+        assert!(matches!(
+            repository.attach_policy("missing", &hrn("User", "alice")),
+            Err(GetEffectivePoliciesError::PolicyNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn exported_state_imports_into_an_empty_repository() {
+        let source = repository();
+        let document = ExportIamStateUseCase::new(Arc::new(source.clone()))
+            .execute(ExportIamStateQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(document.users.len(), 1);
+        assert_eq!(document.policies.len(), 3);
+
+        let target = InMemoryIamRepository::new();
+        let import = ImportIamStateUseCase::new(Arc::new(target.clone()));
+        let dry_run = import
+            .execute(ImportIamStateCommand::new(document.clone()).dry_run())
+            .await
+            .unwrap();
+        assert_eq!(dry_run.policies.created.len(), 3);
+        assert!(target.state.read().unwrap().users.is_empty());
+
+        import
+            .execute(ImportIamStateCommand::new(document.clone()))
+            .await
+            .unwrap();
+        let (mut imported, mut original) =
+            (effective_ids(&target).await, effective_ids(&source).await);
+        imported.sort();
+        original.sort();
+        assert_eq!(imported, original);
+
+        // A second import of the same document changes nothing
+        let again = import
+            .execute(ImportIamStateCommand::new(document))
+            .await
+            .unwrap();
+        assert!(!again.has_changes());
+        assert_eq!(again.users.unchanged, [hrn("User", "alice").to_string()]);
+    }
+
+    #[tokio::test]
+    async fn finds_policies_naming_deleted_users_and_groups() {
+        let repository = repository();
+        repository.add_policy(HodeiPolicy::new(
+            PolicyId::new("former-staff"),
+            r#"permit(principal in Iam::Group::"contractors", action, resource)
+               unless { principal == Iam::User::"alice" };"#
+                .to_string(),
+        ));
+        let finder =
+            FindDanglingReferencesUseCase::new(Arc::new(repository.clone()), Arc::new(repository));
+
+        let report = finder
+            .execute(FindDanglingReferencesQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(report.dangling.len(), 1);
+        assert_eq!(report.dangling[0].policy_hrn.resource_id(), "former-staff");
+        assert_eq!(
+            report.dangling[0].missing,
+            [EntityReference::new("Iam::Group", "contractors")]
+        );
+    }
+}
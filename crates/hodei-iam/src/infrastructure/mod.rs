@@ -1,4 +1,7 @@
 //! Infrastructure implementations for hodei-iam
 
 pub mod surreal;
+pub mod event_bus;
 pub mod hrn_generator;
+pub mod in_memory;
+pub(crate) mod state_records;
@@ -0,0 +1,75 @@
+//! Conversions between the domain entities and the IAM state records
+//!
+//! Shared by the adapters implementing the export_iam_state and
+//! import_iam_state ports, so every store writes the same document.
+
+use kernel::Hrn;
+
+use crate::features::export_iam_state::dto::{GroupRecord, UserRecord};
+use crate::internal::domain::{Group, User};
+
+pub(crate) fn user_record(user: &User) -> UserRecord {
+    UserRecord {
+        hrn: user.hrn.to_string(),
+        name: user.name.clone(),
+        email: user.email.clone(),
+        status: user.status,
+        tags: user.tags.clone(),
+        group_hrns: user.groups().iter().map(|hrn| hrn.to_string()).collect(),
+    }
+}
+
+pub(crate) fn group_record(group: &Group) -> GroupRecord {
+    GroupRecord {
+        hrn: group.hrn.to_string(),
+        name: group.name.clone(),
+        description: group.description.clone(),
+        tags: group.tags.clone(),
+        parent_group_hrns: group
+            .parent_groups()
+            .iter()
+            .map(|hrn| hrn.to_string())
+            .collect(),
+    }
+}
+
+/// The user a record describes
+///
+/// # Errors
+///
+/// Returns the first HRN in the record that does not parse.
+pub(crate) fn user_from_record(record: &UserRecord) -> Result<User, String> {
+    let mut user = User::new(
+        parse_hrn(&record.hrn)?,
+        record.name.clone(),
+        record.email.clone(),
+    );
+    user.status = record.status;
+    user.tags = record.tags.clone();
+    user.group_hrns = parse_hrns(&record.group_hrns)?;
+    Ok(user)
+}
+
+/// The group a record describes
+///
+/// # Errors
+///
+/// Returns the first HRN in the record that does not parse.
+pub(crate) fn group_from_record(record: &GroupRecord) -> Result<Group, String> {
+    let mut group = Group::new(
+        parse_hrn(&record.hrn)?,
+        record.name.clone(),
+        record.description.clone(),
+    );
+    group.tags = record.tags.clone();
+    group.parent_group_hrns = parse_hrns(&record.parent_group_hrns)?;
+    Ok(group)
+}
+
+pub(crate) fn parse_hrn(hrn: &str) -> Result<Hrn, String> {
+    Hrn::from_string(hrn).ok_or_else(|| format!("Invalid HRN: {hrn}"))
+}
+
+fn parse_hrns(hrns: &[String]) -> Result<Vec<Hrn>, String> {
+    hrns.iter().map(|hrn| parse_hrn(hrn)).collect()
+}
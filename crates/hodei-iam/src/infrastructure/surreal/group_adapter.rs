@@ -8,20 +8,28 @@ use surrealdb::engine::local::Db;
 use tracing::{debug, error, info};
 
 // Import the ports from features
+use crate::features::add_group_to_group::ports::GroupHierarchyPort;
 use crate::features::add_user_to_group::dto::GroupLookupDto as AddGroupLookupDto;
 use crate::features::add_user_to_group::ports::GroupFinder;
 use crate::features::create_group::dto::GroupPersistenceDto;
 use crate::features::create_group::ports::CreateGroupPort;
 use crate::features::get_effective_policies::dto::GroupLookupDto;
 use crate::features::get_effective_policies::ports::GroupFinderPort;
+use crate::features::list_group_members::dto::GroupMemberSummary;
+use crate::features::list_group_members::ports::GroupMemberFinderPort;
+use crate::features::update_group_attributes::dto::GroupAttributes;
+use crate::features::update_group_attributes::ports::GroupAttributesPort;
 
 // Import errors from features
+use crate::features::add_group_to_group::error::AddGroupToGroupError;
 use crate::features::add_user_to_group::error::AddUserToGroupError;
 use crate::features::create_group::error::CreateGroupError;
 use crate::features::get_effective_policies::error::GetEffectivePoliciesError;
+use crate::features::list_group_members::error::ListGroupMembersError;
+use crate::features::update_group_attributes::error::UpdateGroupAttributesError;
 
 // Import internal domain entities (for internal use only)
-use crate::internal::domain::Group;
+use crate::internal::domain::{Group, User};
 
 /// SurrealDB adapter for Group persistence operations
 pub struct SurrealGroupAdapter {
@@ -49,6 +57,7 @@ impl CreateGroupPort for SurrealGroupAdapter {
             name: group_dto.name.clone(),
             description: None,
             tags: group_dto.tags.clone(),
+            parent_group_hrns: Vec::new(),
         };
 
         let group_table = "group";
@@ -150,6 +159,205 @@ impl GroupFinderPort for SurrealGroupAdapter {
         info!("Found {} groups for user", group_dtos.len());
         Ok(group_dtos)
     }
+
+    async fn find_parent_groups(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Vec<GroupLookupDto>, GetEffectivePoliciesError> {
+        debug!("Finding parent groups of group: {}", group_hrn);
+
+        let group: Option<Group> = self
+            .db
+            .select(("group", group_hrn.resource_id()))
+            .await
+            .map_err(|e| GetEffectivePoliciesError::RepositoryError(e.to_string()))?;
+        let Some(group) = group else {
+            return Ok(Vec::new());
+        };
+
+        let mut parents = Vec::with_capacity(group.parent_group_hrns.len());
+        for parent_hrn in &group.parent_group_hrns {
+            let parent: Option<Group> =
+                self.db
+                    .select(("group", parent_hrn.resource_id()))
+                    .await
+                    .map_err(|e| GetEffectivePoliciesError::RepositoryError(e.to_string()))?;
+            // A parent deleted since the membership was written no longer applies
+            if let Some(parent) = parent {
+                parents.push(GroupLookupDto {
+                    hrn: parent.hrn.to_string(),
+                    name: parent.name,
+                    tags: parent.tags,
+                });
+            }
+        }
+
+        debug!("Found {} parent groups", parents.len());
+        Ok(parents)
+    }
+}
+
+#[async_trait]
+impl GroupHierarchyPort for SurrealGroupAdapter {
+    async fn find_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<Vec<Hrn>>, AddGroupToGroupError> {
+        debug!("Finding parent group HRNs of group: {}", group_hrn);
+
+        let group: Option<Group> = self
+            .db
+            .select(("group", group_hrn.resource_id()))
+            .await
+            .map_err(|e| AddGroupToGroupError::PersistenceError(e.to_string()))?;
+
+        Ok(group.map(|g| g.parent_group_hrns))
+    }
+
+    async fn save_parent_group_hrns(
+        &self,
+        group_hrn: &Hrn,
+        parent_group_hrns: &[Hrn],
+    ) -> Result<(), AddGroupToGroupError> {
+        info!("Saving parent groups of group: {}", group_hrn);
+
+        // Merge only the parent groups, so the rest of the record is kept
+        let updated: Option<Group> = self
+            .db
+            .update(("group", group_hrn.resource_id()))
+            .merge(serde_json::json!({ "parent_group_hrns": parent_group_hrns }))
+            .await
+            .map_err(|e| {
+                error!("Database error while saving parent groups: {}", e);
+                AddGroupToGroupError::PersistenceError(e.to_string())
+            })?;
+
+        match updated {
+            Some(_) => Ok(()),
+            None => Err(AddGroupToGroupError::GroupNotFound(group_hrn.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl GroupAttributesPort for SurrealGroupAdapter {
+    async fn find_attributes(
+        &self,
+        group_hrn: &Hrn,
+    ) -> Result<Option<GroupAttributes>, UpdateGroupAttributesError> {
+        debug!("Finding attributes of group: {}", group_hrn);
+
+        let group: Option<Group> = self
+            .db
+            .select(("group", group_hrn.resource_id()))
+            .await
+            .map_err(|e| UpdateGroupAttributesError::PersistenceError(e.to_string()))?;
+
+        Ok(group.map(|g| GroupAttributes {
+            name: g.name,
+            description: g.description,
+            tags: g.tags,
+        }))
+    }
+
+    async fn save_attributes(
+        &self,
+        group_hrn: &Hrn,
+        attributes: &GroupAttributes,
+    ) -> Result<(), UpdateGroupAttributesError> {
+        info!("Saving attributes of group: {}", group_hrn);
+
+        // Merge only the attributes, so the parent groups are kept
+        let updated: Option<Group> = self
+            .db
+            .update(("group", group_hrn.resource_id()))
+            .merge(serde_json::json!({
+                "name": attributes.name,
+                "description": attributes.description,
+                "tags": attributes.tags,
+            }))
+            .await
+            .map_err(|e| {
+                error!("Database error while saving group attributes: {}", e);
+                UpdateGroupAttributesError::PersistenceError(e.to_string())
+            })?;
+
+        match updated {
+            Some(_) => Ok(()),
+            None => Err(UpdateGroupAttributesError::GroupNotFound(
+                group_hrn.to_string(),
+            )),
+        }
+    }
+}
+
+/// Membership is stored on the members, so listing a group's members
+/// queries the `user` and `group` tables for records that reference it
+#[async_trait]
+impl GroupMemberFinderPort for SurrealGroupAdapter {
+    async fn group_exists(&self, group_hrn: &Hrn) -> Result<bool, ListGroupMembersError> {
+        let group: Option<Group> = self
+            .db
+            .select(("group", group_hrn.resource_id()))
+            .await
+            .map_err(|e| ListGroupMembersError::RepositoryError(e.to_string()))?;
+        Ok(group.is_some())
+    }
+
+    async fn find_user_members(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError> {
+        debug!("Finding user members of group: {}", group_hrn);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM user WHERE group_hrns CONTAINS $group")
+            .bind(("group", group_hrn.clone()))
+            .await
+            .map_err(|e| ListGroupMembersError::RepositoryError(e.to_string()))?;
+        let users: Vec<User> = result
+            .take(0)
+            .map_err(|e| ListGroupMembersError::RepositoryError(e.to_string()))?;
+
+        Ok(users.into_iter().map(|u| u.hrn).collect())
+    }
+
+    async fn find_child_groups(&self, group_hrn: &Hrn) -> Result<Vec<Hrn>, ListGroupMembersError> {
+        debug!("Finding child groups of group: {}", group_hrn);
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM group WHERE parent_group_hrns CONTAINS $group")
+            .bind(("group", group_hrn.clone()))
+            .await
+            .map_err(|e| ListGroupMembersError::RepositoryError(e.to_string()))?;
+        let groups: Vec<Group> = result
+            .take(0)
+            .map_err(|e| ListGroupMembersError::RepositoryError(e.to_string()))?;
+
+        Ok(groups.into_iter().map(|g| g.hrn).collect())
+    }
+
+    async fn find_member_summaries(
+        &self,
+        user_hrns: &[Hrn],
+    ) -> Result<Vec<GroupMemberSummary>, ListGroupMembersError> {
+        let mut summaries = Vec::with_capacity(user_hrns.len());
+        for user_hrn in user_hrns {
+            let user: Option<User> = self
+                .db
+                .select(("user", user_hrn.resource_id()))
+                .await
+                .map_err(|e| ListGroupMembersError::RepositoryError(e.to_string()))?;
+            if let Some(u) = user {
+                summaries.push(GroupMemberSummary {
+                    hrn: u.hrn.to_string(),
+                    name: u.name,
+                    email: u.email,
+                    status: u.status,
+                });
+            }
+        }
+        Ok(summaries)
+    }
 }
 
 #[cfg(test)]
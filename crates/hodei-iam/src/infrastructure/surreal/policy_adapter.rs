@@ -3,9 +3,12 @@
 //! This adapter implements all policy-related ports for the IAM system:
 //! - CreatePolicyPort: Create new policies
 //! - PolicyReader: Get policies by HRN
+//! - PolicyBatchReader: Get several policies by HRN in one query
 //! - PolicyLister: List policies with pagination
 //! - UpdatePolicyPort: Update existing policies
 //! - DeletePolicyPort: Delete policies
+//! - PolicyWalkStorePort: Walk policies in batches for revalidation, search and reference checks
+//! - PolicyRevalidationStorePort: Disable invalid policies
 
 use async_trait::async_trait;
 use serde::Deserialize;
@@ -18,8 +21,11 @@ use tracing::{debug, error, info, warn};
 use crate::features::create_policy::ports::CreatePolicyPort;
 use crate::features::delete_policy::ports::DeletePolicyPort;
 use crate::features::get_effective_policies::ports::PolicyFinderPort;
+use crate::features::get_policies::ports::PolicyBatchReader;
 use crate::features::get_policy::ports::PolicyReader;
 use crate::features::list_policies::ports::PolicyLister;
+use crate::features::policy_walk::ports::PolicyWalkStorePort;
+use crate::features::revalidate_policies::ports::PolicyRevalidationStorePort;
 use crate::features::update_policy::ports::UpdatePolicyPort;
 
 // Import DTOs and errors from features
@@ -28,16 +34,23 @@ use crate::features::create_policy::error::CreatePolicyError;
 
 use crate::features::delete_policy::error::DeletePolicyError;
 use crate::features::get_effective_policies::error::GetEffectivePoliciesError;
+use crate::features::get_policies::dto::PolicyView as GetPoliciesView;
+use crate::features::get_policies::error::GetPoliciesError;
 use crate::features::get_policy::dto::PolicyView as GetPolicyView;
 use crate::features::get_policy::error::GetPolicyError;
 use crate::features::list_policies::dto::{ListPoliciesQuery, ListPoliciesResponse, PolicySummary};
 use crate::features::list_policies::error::ListPoliciesError;
+use crate::features::policy_walk::dto::StoredPolicy;
+use crate::features::policy_walk::error::PolicyWalkError;
+use crate::features::revalidate_policies::error::RevalidatePoliciesError;
 use crate::features::update_policy::dto::{PolicyView as UpdatePolicyView, UpdatePolicyCommand};
 use crate::features::update_policy::error::UpdatePolicyError;
 
 // Import internal domain entities
+use crate::infrastructure::hrn_generator::policy_hrn;
 
 // Import kernel policy types
+use hodei_policies::features::validate_policy::policy_annotations;
 use kernel::domain::policy::{HodeiPolicy, PolicyId};
 
 /// Intermediate structure for deserializing HodeiPolicy from SurrealDB
@@ -56,6 +69,15 @@ impl From<HodeiPolicyDbRow> for HodeiPolicy {
     }
 }
 
+/// A `policy` row as a policy walk reads it; enabled policies have no reason
+#[derive(Debug, Clone, Deserialize)]
+struct RevalidationPolicyRow {
+    id: surrealdb::sql::Thing,
+    content: String,
+    #[serde(default)]
+    disabled_reason: Option<String>,
+}
+
 /// SurrealDB adapter for Policy persistence operations
 pub struct SurrealPolicyAdapter<C: surrealdb::Connection> {
     db: Arc<Surreal<C>>,
@@ -144,6 +166,7 @@ impl<C: surrealdb::Connection> PolicyReader for SurrealPolicyAdapter<C> {
                     name: policy.id().to_string(),
                     content: policy.content().to_string(),
                     description: None, // HodeiPolicy doesn't have description field
+                    annotations: policy_annotations(policy.content()).unwrap_or_default(),
                 })
             }
             Ok(None) => {
@@ -158,6 +181,54 @@ impl<C: surrealdb::Connection> PolicyReader for SurrealPolicyAdapter<C> {
     }
 }
 
+#[async_trait]
+impl<C: surrealdb::Connection> PolicyBatchReader for SurrealPolicyAdapter<C> {
+    async fn get_by_hrns(&self, hrns: &[Hrn]) -> Result<Vec<GetPoliciesView>, GetPoliciesError> {
+        info!("Getting {} policies by HRN", hrns.len());
+
+        let policy_table = "policy";
+        let record_ids: Vec<surrealdb::sql::Thing> = hrns
+            .iter()
+            .map(|hrn| surrealdb::sql::Thing::from((policy_table, hrn.resource_id())))
+            .collect();
+
+        // Selecting from a list of record IDs fetches them all in one query;
+        // IDs with no record are skipped
+        let rows: Vec<HodeiPolicyDbRow> = self
+            .db
+            .query("SELECT * FROM $ids")
+            .bind(("ids", record_ids))
+            .await
+            .map_err(|e| {
+                error!("Database error while getting policies: {}", e);
+                GetPoliciesError::RepositoryError(e.to_string())
+            })?
+            .take(0)
+            .map_err(|e| GetPoliciesError::RepositoryError(e.to_string()))?;
+
+        let mut views = Vec::with_capacity(rows.len());
+        for row in rows {
+            // Compare the raw record key: its Display form is escaped (`⟨a-b⟩`)
+            let id = match &row.id.id {
+                surrealdb::sql::Id::String(id) => id.clone(),
+                other => other.to_string(),
+            };
+            for hrn in hrns.iter().filter(|hrn| hrn.resource_id() == id) {
+                views.push(GetPoliciesView {
+                    hrn: hrn.clone(),
+                    name: id.clone(),
+                    content: row.content.clone(),
+                    description: None, // HodeiPolicy doesn't have description field
+                    annotations: policy_annotations(&row.content).unwrap_or_default(),
+                });
+            }
+        }
+
+        debug!("Found {} of {} policies", views.len(), hrns.len());
+        Ok(views)
+    }
+}
+
 #[async_trait]
 impl<C: surrealdb::Connection> PolicyLister for SurrealPolicyAdapter<C> {
     async fn list(
@@ -205,13 +276,7 @@ impl<C: surrealdb::Connection> PolicyLister for SurrealPolicyAdapter<C> {
             Ok(policies) => policies
                 .into_iter()
                 .map(|policy| {
-                    let hrn = Hrn::new(
-                        "hodei".to_string(),
-                        "iam".to_string(),
-                        "default".to_string(),
-                        "Policy".to_string(),
-                        policy.id().to_string(),
-                    );
+                    let hrn = policy_hrn(policy.id().as_str());
 
                     PolicySummary {
                         hrn: hrn.clone(),
@@ -266,19 +331,15 @@ impl<C: surrealdb::Connection> UpdatePolicyPort for SurrealPolicyAdapter<C> {
 
                 match updated {
                     Ok(Some(updated_policy)) => {
-                        let hrn = Hrn::new(
-                            "hodei".to_string(),
-                            "iam".to_string(),
-                            "default".to_string(),
-                            "Policy".to_string(),
-                            command.policy_id,
-                        );
+                        let hrn = policy_hrn(&command.policy_id);
                         info!("Policy updated successfully: {}", hrn);
                         Ok(UpdatePolicyView {
                             hrn,
                             name: updated_policy.id().to_string(),
                             content: updated_policy.content().to_string(),
                             description: None, // HodeiPolicy doesn't have description field
+                            annotations: policy_annotations(updated_policy.content())
+                                .unwrap_or_default(),
                         })
                     }
                     Ok(None) => {
@@ -342,7 +403,8 @@ impl<C: surrealdb::Connection> PolicyFinderPort for SurrealPolicyAdapter<C> {
         debug!("Finding policies for principal: {}", principal_hrn);
 
         // This is a graph query in SurrealDB - find all policies attached to the principal
-        let query = "SELECT * FROM policy WHERE $principal_hrn IN attached_principals";
+        // Disabled policies are kept for review but apply to no one
+        let query = "SELECT * FROM policy WHERE $principal_hrn IN attached_principals AND disabled_reason = NONE";
 
         let mut result = self
             .db
@@ -351,39 +413,87 @@ impl<C: surrealdb::Connection> PolicyFinderPort for SurrealPolicyAdapter<C> {
             .await
             .map_err(|e| GetEffectivePoliciesError::RepositoryError(e.to_string()))?;
 
-        let policies: Vec<surrealdb::sql::Object> = result
+        // Rows also carry `attached_principals`, which the row type skips
+        let rows: Vec<HodeiPolicyDbRow> = result
             .take(0)
             .map_err(|e| GetEffectivePoliciesError::RepositoryError(e.to_string()))?;
-
-        // Convert surreal objects to HodeiPolicy
-        let mut hodei_policies = Vec::new();
-        for policy_obj in policies {
-            // Extract and clean the id and content values separately to avoid borrowing issues
-            let id_opt = policy_obj.get("id").map(|v| {
-                let s = v.to_string();
-                s.strip_prefix('\"')
-                    .unwrap_or(&s)
-                    .strip_suffix('\"')
-                    .unwrap_or(&s)
-                    .to_string()
-            });
-            let content_opt = policy_obj.get("content").map(|v| {
-                let s = v.to_string();
-                s.strip_prefix('\"')
-                    .unwrap_or(&s)
-                    .strip_suffix('\"')
-                    .unwrap_or(&s)
-                    .to_string()
-            });
-
-            if let (Some(id), Some(content)) = (id_opt, content_opt) {
-                let policy_id = PolicyId::new(id);
-                let hodei_policy = HodeiPolicy::new(policy_id, content);
-                hodei_policies.push(hodei_policy);
-            }
-        }
+        let hodei_policies: Vec<HodeiPolicy> = rows
+            .into_iter()
+            .map(|row| HodeiPolicy::new(PolicyId::new(row.id.id.to_raw()), row.content))
+            .collect();
 
         info!("Found {} policies for principal", hodei_policies.len());
         Ok(hodei_policies)
     }
 }
+
+#[async_trait]
+impl<C: surrealdb::Connection> PolicyWalkStorePort for SurrealPolicyAdapter<C> {
+    async fn list_policies(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<StoredPolicy>, PolicyWalkError> {
+        debug!(?after, limit, "Listing policies for a policy walk");
+
+        let query = match after {
+            Some(_) => {
+                "SELECT id, content, disabled_reason FROM policy \
+                 WHERE id > type::thing('policy', $after) ORDER BY id LIMIT $limit"
+            }
+            None => "SELECT id, content, disabled_reason FROM policy ORDER BY id LIMIT $limit",
+        };
+        let mut result = self
+            .db
+            .query(query)
+            .bind(("after", after.map(str::to_string)))
+            .bind(("limit", limit))
+            .await
+            .map_err(|e| PolicyWalkError::RepositoryError(e.to_string()))?;
+
+        let rows: Vec<RevalidationPolicyRow> = result
+            .take(0)
+            .map_err(|e| PolicyWalkError::RepositoryError(e.to_string()))?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let id = row.id.id.to_raw();
+                StoredPolicy {
+                    hrn: policy_hrn(&id),
+                    id,
+                    content: row.content,
+                    disabled_reason: row.disabled_reason,
+                }
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl<C: surrealdb::Connection> PolicyRevalidationStorePort for SurrealPolicyAdapter<C> {
+    async fn disable_policy(
+        &self,
+        policy_id: &str,
+        reason: &str,
+    ) -> Result<(), RevalidatePoliciesError> {
+        info!("Disabling policy: {}", policy_id);
+
+        let updated: Option<RevalidationPolicyRow> = self
+            .db
+            .update(("policy", policy_id))
+            .merge(serde_json::json!({ "disabled_reason": reason }))
+            .await
+            .map_err(|e| RevalidatePoliciesError::RepositoryError(e.to_string()))?;
+
+        match updated {
+            Some(_) => Ok(()),
+            None => {
+                warn!("Policy not found for disabling: {}", policy_id);
+                Err(RevalidatePoliciesError::RepositoryError(format!(
+                    "Policy not found: {}",
+                    policy_id
+                )))
+            }
+        }
+    }
+}
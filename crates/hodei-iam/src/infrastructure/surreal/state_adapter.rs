@@ -0,0 +1,287 @@
+//! SurrealDB adapter for exporting and importing the whole IAM state
+//!
+//! Reads the `user`, `group` and `policy` tables as state records, and
+//! writes an import as one transaction, so a failed statement leaves no
+//! partial import behind. Also answers which referenced users and groups
+//! still exist.
+
+use async_trait::async_trait;
+use hodei_policies::features::match_policy::references::EntityReference;
+use kernel::HodeiEntityType;
+use serde::Deserialize;
+use std::sync::Arc;
+use surrealdb::Surreal;
+use surrealdb::engine::local::Db;
+use tracing::{debug, error, info};
+
+// Import the ports from features
+use crate::features::export_iam_state::dto::{GroupRecord, PolicyRecord, UserRecord};
+use crate::features::export_iam_state::ports::IamStateReaderPort;
+use crate::features::find_dangling_references::dto::EntityExistence;
+use crate::features::find_dangling_references::ports::EntityExistencePort;
+use crate::features::import_iam_state::dto::IamStateChanges;
+use crate::features::import_iam_state::ports::IamStateStorePort;
+use crate::features::import_policies::ports::PolicyImportStorePort;
+
+// Import errors from features
+use crate::features::export_iam_state::error::ExportIamStateError;
+use crate::features::find_dangling_references::error::FindDanglingReferencesError;
+use crate::features::import_iam_state::error::ImportIamStateError;
+use crate::features::import_policies::error::ImportPoliciesError;
+
+// Import internal domain entities (for internal use only)
+use crate::infrastructure::state_records::{
+    group_from_record, group_record, user_from_record, user_record,
+};
+use crate::internal::domain::{Group, User};
+
+/// A `policy` row; policies created before attachments were stored have none
+#[derive(Debug, Deserialize)]
+struct PolicyStateRow {
+    id: surrealdb::sql::Thing,
+    content: String,
+    #[serde(default)]
+    attached_principals: Vec<String>,
+}
+
+impl From<PolicyStateRow> for PolicyRecord {
+    fn from(row: PolicyStateRow) -> Self {
+        PolicyRecord {
+            // The raw ID, without the brackets IDs like `read-own` print with
+            id: row.id.id.to_raw(),
+            content: row.content,
+            attached_principals: row.attached_principals,
+        }
+    }
+}
+
+/// SurrealDB adapter for the IAM state as a whole
+pub struct SurrealIamStateAdapter {
+    db: Arc<Surreal<Db>>,
+}
+
+impl SurrealIamStateAdapter {
+    /// Create a new SurrealIamStateAdapter
+    pub fn new(db: Arc<Surreal<Db>>) -> Self {
+        Self { db }
+    }
+
+    async fn select_users(&self) -> Result<Vec<UserRecord>, surrealdb::Error> {
+        let users: Vec<User> = self.db.query("SELECT * FROM user").await?.take(0)?;
+        Ok(users.iter().map(user_record).collect())
+    }
+
+    async fn select_groups(&self) -> Result<Vec<GroupRecord>, surrealdb::Error> {
+        let groups: Vec<Group> = self.db.query("SELECT * FROM group").await?.take(0)?;
+        Ok(groups.iter().map(group_record).collect())
+    }
+
+    async fn select_policies(&self) -> Result<Vec<PolicyRecord>, surrealdb::Error> {
+        let rows: Vec<PolicyStateRow> = self.db.query("SELECT * FROM policy").await?.take(0)?;
+        Ok(rows.into_iter().map(PolicyRecord::from).collect())
+    }
+}
+
+#[async_trait]
+impl IamStateReaderPort for SurrealIamStateAdapter {
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ExportIamStateError> {
+        debug!("Reading all users for export");
+        self.select_users()
+            .await
+            .map_err(|e| ExportIamStateError::RepositoryError(e.to_string()))
+    }
+
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ExportIamStateError> {
+        debug!("Reading all groups for export");
+        self.select_groups()
+            .await
+            .map_err(|e| ExportIamStateError::RepositoryError(e.to_string()))
+    }
+
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ExportIamStateError> {
+        debug!("Reading all policies for export");
+        self.select_policies()
+            .await
+            .map_err(|e| ExportIamStateError::RepositoryError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl IamStateStorePort for SurrealIamStateAdapter {
+    async fn read_users(&self) -> Result<Vec<UserRecord>, ImportIamStateError> {
+        self.select_users()
+            .await
+            .map_err(|e| ImportIamStateError::RepositoryError(e.to_string()))
+    }
+
+    async fn read_groups(&self) -> Result<Vec<GroupRecord>, ImportIamStateError> {
+        self.select_groups()
+            .await
+            .map_err(|e| ImportIamStateError::RepositoryError(e.to_string()))
+    }
+
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportIamStateError> {
+        self.select_policies()
+            .await
+            .map_err(|e| ImportIamStateError::RepositoryError(e.to_string()))
+    }
+
+    async fn apply_changes(&self, changes: &IamStateChanges) -> Result<(), ImportIamStateError> {
+        info!(
+            users = changes.users.len(),
+            groups = changes.groups.len(),
+            policies = changes.policies.len(),
+            "Applying IAM state import"
+        );
+
+        let users = changes
+            .users
+            .iter()
+            .map(user_from_record)
+            .collect::<Result<Vec<User>, String>>()
+            .map_err(ImportIamStateError::RepositoryError)?;
+        let groups = changes
+            .groups
+            .iter()
+            .map(group_from_record)
+            .collect::<Result<Vec<Group>, String>>()
+            .map_err(ImportIamStateError::RepositoryError)?;
+
+        // One UPSERT per record, each with its own bound ID and content
+        let mut statements = vec!["BEGIN TRANSACTION;".to_string()];
+        statements.extend(
+            (0..users.len())
+                .map(|i| format!("UPSERT type::thing('user', $user_id_{i}) CONTENT $user_{i};")),
+        );
+        statements
+            .extend((0..groups.len()).map(|i| {
+                format!("UPSERT type::thing('group', $group_id_{i}) CONTENT $group_{i};")
+            }));
+        statements.extend((0..changes.policies.len()).map(|i| {
+            format!(
+                "UPSERT type::thing('policy', $policy_id_{i}) CONTENT {{ \
+                 content: $policy_content_{i}, attached_principals: $policy_principals_{i} }};"
+            )
+        }));
+        statements.push("COMMIT TRANSACTION;".to_string());
+
+        let mut query = self.db.query(statements.join("\n"));
+        for (i, user) in users.into_iter().enumerate() {
+            query = query
+                .bind((format!("user_id_{i}"), user.hrn.resource_id().to_string()))
+                .bind((format!("user_{i}"), user));
+        }
+        for (i, group) in groups.into_iter().enumerate() {
+            query = query
+                .bind((format!("group_id_{i}"), group.hrn.resource_id().to_string()))
+                .bind((format!("group_{i}"), group));
+        }
+        for (i, policy) in changes.policies.iter().enumerate() {
+            query = query
+                .bind((format!("policy_id_{i}"), policy.id.clone()))
+                .bind((format!("policy_content_{i}"), policy.content.clone()))
+                .bind((
+                    format!("policy_principals_{i}"),
+                    policy.attached_principals.clone(),
+                ));
+        }
+        let rolled_back = |e: surrealdb::Error| {
+            error!("IAM state import rolled back: {}", e);
+            ImportIamStateError::RepositoryError(e.to_string())
+        };
+        query
+            .await
+            .map_err(rolled_back)?
+            .check()
+            .map_err(rolled_back)?;
+
+        info!("IAM state import committed");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PolicyImportStorePort for SurrealIamStateAdapter {
+    async fn read_policies(&self) -> Result<Vec<PolicyRecord>, ImportPoliciesError> {
+        self.select_policies()
+            .await
+            .map_err(|e| ImportPoliciesError::RepositoryError(e.to_string()))
+    }
+
+    async fn write_policies(&self, policies: &[PolicyRecord]) -> Result<(), ImportPoliciesError> {
+        let changes = IamStateChanges {
+            policies: policies.to_vec(),
+            ..Default::default()
+        };
+        IamStateStorePort::apply_changes(self, &changes)
+            .await
+            .map_err(|e| ImportPoliciesError::RepositoryError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl EntityExistencePort for SurrealIamStateAdapter {
+    async fn check_entities(
+        &self,
+        references: &[EntityReference],
+    ) -> Result<EntityExistence, FindDanglingReferencesError> {
+        let user_type = User::entity_type_name();
+        let group_type = Group::entity_type_name();
+        let table = |reference: &EntityReference| {
+            if reference.entity_type == user_type {
+                Some("user")
+            } else if reference.entity_type == group_type {
+                Some("group")
+            } else {
+                None
+            }
+        };
+
+        let mut existence = EntityExistence::default();
+        let mut record_ids = Vec::new();
+        for reference in references {
+            match table(reference) {
+                Some(table) => record_ids.push(surrealdb::sql::Thing::from((
+                    table,
+                    reference.entity_id.as_str(),
+                ))),
+                None => existence.unknown.push(reference.clone()),
+            }
+        }
+        if record_ids.is_empty() {
+            return Ok(existence);
+        }
+
+        // Selecting from a list of record IDs fetches them all in one query;
+        // IDs with no record are skipped
+        let found: Vec<surrealdb::sql::Thing> = self
+            .db
+            .query("SELECT VALUE id FROM $ids")
+            .bind(("ids", record_ids))
+            .await
+            .map_err(|e| {
+                error!("Database error while checking entities: {}", e);
+                FindDanglingReferencesError::RepositoryError(e.to_string())
+            })?
+            .take(0)
+            .map_err(|e| FindDanglingReferencesError::RepositoryError(e.to_string()))?;
+
+        existence.missing = references
+            .iter()
+            .filter(|reference| {
+                table(reference).is_some_and(|table| {
+                    !found
+                        .iter()
+                        .any(|id| id.tb == table && id.id.to_raw() == reference.entity_id)
+                })
+            })
+            .cloned()
+            .collect();
+        debug!(
+            checked = references.len() - existence.unknown.len(),
+            missing = existence.missing.len(),
+            "Checked referenced entities"
+        );
+        Ok(existence)
+    }
+}
@@ -1,8 +1,9 @@
 //! SurrealDB adapter for User persistence operations
 
 use async_trait::async_trait;
-use kernel::Hrn;
+use kernel::{Hrn, PrincipalStatus};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use surrealdb::Surreal;
 use surrealdb::engine::local::Db;
 use tracing::{debug, error, info};
@@ -16,24 +17,53 @@ use crate::features::create_user::dto::UserPersistenceDto as CreateUserPersisten
 use crate::features::create_user::ports::CreateUserPort;
 use crate::features::get_effective_policies::dto::UserLookupDto;
 use crate::features::get_effective_policies::ports::UserFinderPort;
+use crate::features::set_user_status::ports::UserStatusPort;
+use crate::features::update_user_attributes::dto::UserAttributes;
+use crate::features::update_user_attributes::ports::UserAttributesPort;
 
 // Import errors from features
 use crate::features::add_user_to_group::error::AddUserToGroupError;
 use crate::features::create_user::error::CreateUserError;
 use crate::features::get_effective_policies::error::GetEffectivePoliciesError;
+use crate::features::set_user_status::error::SetUserStatusError;
+use crate::features::update_user_attributes::error::UpdateUserAttributesError;
 
 // Import internal domain entities (for internal use only)
 use crate::internal::domain::User;
 
+/// Unique index backing the create_user duplicate-email check
+///
+/// The use case looks the email up before saving, but two concurrent creates
+/// can both pass that check; the index makes the second `CREATE` fail.
+const DEFINE_EMAIL_INDEX: &str =
+    "DEFINE INDEX IF NOT EXISTS user_email_unique ON TABLE user FIELDS email UNIQUE";
+
 /// SurrealDB adapter for User persistence operations
 pub struct SurrealUserAdapter {
     db: Arc<Surreal<Db>>,
+    indexes_defined: AtomicBool,
 }
 
 impl SurrealUserAdapter {
     /// Create a new SurrealUserAdapter
     pub fn new(db: Arc<Surreal<Db>>) -> Self {
-        Self { db }
+        Self {
+            db,
+            indexes_defined: AtomicBool::new(false),
+        }
+    }
+
+    /// Define the `user` table indexes (idempotent)
+    ///
+    /// Called before the first save; may also be called at startup.
+    pub async fn define_indexes(&self) -> Result<(), surrealdb::Error> {
+        if self.indexes_defined.load(Ordering::Acquire) {
+            return Ok(());
+        }
+        self.db.query(DEFINE_EMAIL_INDEX).await?.check()?;
+        self.indexes_defined.store(true, Ordering::Release);
+        debug!("User table indexes defined");
+        Ok(())
     }
 }
 
@@ -42,6 +72,10 @@ impl CreateUserPort for SurrealUserAdapter {
     async fn save_user(&self, user_dto: &CreateUserPersistenceDto) -> Result<(), CreateUserError> {
         info!("Saving user with HRN: {}", user_dto.hrn);
 
+        self.define_indexes()
+            .await
+            .map_err(|e| CreateUserError::PersistenceError(e.to_string()))?;
+
         // Convert DTO to internal domain entity for persistence
         let hrn = Hrn::from_string(&user_dto.hrn)
             .ok_or_else(|| CreateUserError::PersistenceError("Invalid HRN".to_string()))?;
@@ -59,6 +93,7 @@ impl CreateUserPort for SurrealUserAdapter {
             email: user_dto.email.clone(),
             group_hrns,
             tags: user_dto.tags.clone(),
+            status: PrincipalStatus::Active,
         };
 
         let user_table = "user";
@@ -79,11 +114,44 @@ impl CreateUserPort for SurrealUserAdapter {
                 ))
             }
             Err(e) => {
+                // A concurrent create may have taken the email after the
+                // use case checked it; report that as the conflict it is
+                if let Ok(Some(existing)) = self.find_by_email(&user_dto.email).await
+                    && existing.hrn != user_dto.hrn
+                {
+                    info!("Email already used by user {}", existing.hrn);
+                    return Err(CreateUserError::EmailAlreadyExists(existing.hrn));
+                }
                 error!("Database error while saving user: {}", e);
                 Err(CreateUserError::PersistenceError(e.to_string()))
             }
         }
     }
+
+    async fn find_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<CreateUserPersistenceDto>, CreateUserError> {
+        debug!("Finding user by email");
+
+        let mut result = self
+            .db
+            .query("SELECT * FROM user WHERE email = $email LIMIT 1")
+            .bind(("email", email.to_string()))
+            .await
+            .map_err(|e| CreateUserError::PersistenceError(e.to_string()))?;
+        let users: Vec<User> = result
+            .take(0)
+            .map_err(|e| CreateUserError::PersistenceError(e.to_string()))?;
+
+        Ok(users.into_iter().next().map(|u| CreateUserPersistenceDto {
+            hrn: u.hrn.to_string(),
+            name: u.name,
+            email: u.email,
+            group_hrns: u.group_hrns.iter().map(|hrn| hrn.to_string()).collect(),
+            tags: u.tags,
+        }))
+    }
 }
 
 #[async_trait]
@@ -144,19 +212,19 @@ impl UserGroupPersister for SurrealUserAdapter {
             .filter_map(|hrn_str| Hrn::from_string(hrn_str))
             .collect();
 
-        let user = User {
-            hrn: hrn.clone(),
-            name: user_dto.name.clone(),
-            email: user_dto.email.clone(),
-            group_hrns,
-            tags: user_dto.tags.clone(),
-        };
+        // Merge only the fields this feature owns, so the stored status is kept
+        let changes = serde_json::json!({
+            "name": user_dto.name,
+            "email": user_dto.email,
+            "group_hrns": group_hrns,
+            "tags": user_dto.tags,
+        });
 
         let user_table = "user";
         let user_id = hrn.resource_id();
 
         let updated: Result<Option<User>, surrealdb::Error> =
-            self.db.update((user_table, user_id)).content(user).await;
+            self.db.update((user_table, user_id)).merge(changes).await;
 
         match updated {
             Ok(Some(_)) => {
@@ -205,6 +273,7 @@ impl UserFinderPort for SurrealUserAdapter {
                     email: u.email,
                     group_hrns: group_hrn_strings,
                     tags: u.tags.clone(),
+                    status: u.status,
                 }))
             }
             Ok(None) => {
@@ -219,6 +288,100 @@ impl UserFinderPort for SurrealUserAdapter {
     }
 }
 
+#[async_trait]
+impl UserStatusPort for SurrealUserAdapter {
+    async fn find_status(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<PrincipalStatus>, SetUserStatusError> {
+        debug!("Finding status of user: {}", user_hrn);
+
+        let user: Option<User> = self
+            .db
+            .select(("user", user_hrn.resource_id()))
+            .await
+            .map_err(|e| SetUserStatusError::PersistenceError(e.to_string()))?;
+
+        Ok(user.map(|u| u.status))
+    }
+
+    async fn save_status(
+        &self,
+        user_hrn: &Hrn,
+        status: PrincipalStatus,
+    ) -> Result<(), SetUserStatusError> {
+        info!("Setting status of user {} to {}", user_hrn, status);
+
+        // Merge only the status, so memberships and profile are kept
+        let updated: Option<User> = self
+            .db
+            .update(("user", user_hrn.resource_id()))
+            .merge(serde_json::json!({ "status": status }))
+            .await
+            .map_err(|e| {
+                error!("Database error while saving user status: {}", e);
+                SetUserStatusError::PersistenceError(e.to_string())
+            })?;
+
+        match updated {
+            Some(_) => Ok(()),
+            None => Err(SetUserStatusError::UserNotFound(user_hrn.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl UserAttributesPort for SurrealUserAdapter {
+    async fn find_attributes(
+        &self,
+        user_hrn: &Hrn,
+    ) -> Result<Option<UserAttributes>, UpdateUserAttributesError> {
+        debug!("Finding attributes of user: {}", user_hrn);
+
+        let user: Option<User> = self
+            .db
+            .select(("user", user_hrn.resource_id()))
+            .await
+            .map_err(|e| UpdateUserAttributesError::PersistenceError(e.to_string()))?;
+
+        Ok(user.map(|u| UserAttributes {
+            name: u.name,
+            email: u.email,
+            tags: u.tags,
+        }))
+    }
+
+    async fn save_attributes(
+        &self,
+        user_hrn: &Hrn,
+        attributes: &UserAttributes,
+    ) -> Result<(), UpdateUserAttributesError> {
+        info!("Saving attributes of user: {}", user_hrn);
+
+        // Merge only the attributes, so memberships and status are kept
+        let updated: Option<User> = self
+            .db
+            .update(("user", user_hrn.resource_id()))
+            .merge(serde_json::json!({
+                "name": attributes.name,
+                "email": attributes.email,
+                "tags": attributes.tags,
+            }))
+            .await
+            .map_err(|e| {
+                error!("Database error while saving user attributes: {}", e);
+                UpdateUserAttributesError::PersistenceError(e.to_string())
+            })?;
+
+        match updated {
+            Some(_) => Ok(()),
+            None => Err(UpdateUserAttributesError::UserNotFound(
+                user_hrn.to_string(),
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -0,0 +1,211 @@
+//! Email address validation for IAM users
+//!
+//! A pragmatic subset of RFC 5322 (with the RFC 6531 relaxation for UTF-8):
+//! the unquoted dot-atom form `local@domain`. Plus-addressing, subdomains and
+//! internationalized domain names are accepted; quoted local parts, comments
+//! and IP-literal domains are not.
+
+use thiserror::Error;
+
+/// Maximum length of a whole address (RFC 5321 path limit minus the brackets)
+const MAX_EMAIL_LENGTH: usize = 254;
+/// Maximum length of the part before the `@`
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+/// Maximum length of a single domain label
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Special characters allowed in an unquoted local part besides letters and digits
+const LOCAL_PART_SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+
+/// Why an email address was rejected
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub(crate) enum EmailError {
+    #[error("email address is empty")]
+    Empty,
+
+    #[error("email address is longer than {MAX_EMAIL_LENGTH} characters")]
+    TooLong,
+
+    #[error("email address must contain '@'")]
+    MissingAt,
+
+    #[error("local part before '@' is empty")]
+    EmptyLocalPart,
+
+    #[error("local part is longer than {MAX_LOCAL_PART_LENGTH} characters")]
+    LocalPartTooLong,
+
+    #[error("local part contains invalid character '{0}'")]
+    InvalidLocalPartChar(char),
+
+    #[error("local part cannot start or end with '.' or contain '..'")]
+    MisplacedDot,
+
+    #[error("domain after '@' is empty")]
+    EmptyDomain,
+
+    #[error("domain '{0}' must have at least two labels")]
+    SingleLabelDomain(String),
+
+    #[error("domain label '{0}' is invalid")]
+    InvalidDomainLabel(String),
+}
+
+/// Validate `email` as a `local@domain` address
+///
+/// # Errors
+///
+/// Returns the first [`EmailError`] found, checking the address as a whole,
+/// then the local part, then each domain label.
+pub(crate) fn validate_email(email: &str) -> Result<(), EmailError> {
+    if email.is_empty() {
+        return Err(EmailError::Empty);
+    }
+    if email.chars().count() > MAX_EMAIL_LENGTH {
+        return Err(EmailError::TooLong);
+    }
+
+    // `@` may not appear unquoted in the local part, so the last one separates
+    let (local, domain) = email.rsplit_once('@').ok_or(EmailError::MissingAt)?;
+    validate_local_part(local)?;
+    validate_domain(domain)
+}
+
+/// Canonical form of a valid address: the domain lowercased, the local part kept
+///
+/// Domains are case-insensitive, while the local part is interpreted by the
+/// receiving host and may be case-sensitive, so only the domain is folded.
+pub(crate) fn normalize_email(email: &str) -> String {
+    match email.rsplit_once('@') {
+        Some((local, domain)) => format!("{local}@{}", domain.to_lowercase()),
+        None => email.to_string(),
+    }
+}
+
+fn validate_local_part(local: &str) -> Result<(), EmailError> {
+    if local.is_empty() {
+        return Err(EmailError::EmptyLocalPart);
+    }
+    if local.chars().count() > MAX_LOCAL_PART_LENGTH {
+        return Err(EmailError::LocalPartTooLong);
+    }
+    if let Some(c) = local
+        .chars()
+        .find(|&c| !(c == '.' || c.is_alphanumeric() || LOCAL_PART_SPECIALS.contains(c)))
+    {
+        return Err(EmailError::InvalidLocalPartChar(c));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err(EmailError::MisplacedDot);
+    }
+    Ok(())
+}
+
+fn validate_domain(domain: &str) -> Result<(), EmailError> {
+    if domain.is_empty() {
+        return Err(EmailError::EmptyDomain);
+    }
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 {
+        return Err(EmailError::SingleLabelDomain(domain.to_string()));
+    }
+    match labels.into_iter().find(|label| !is_valid_label(label)) {
+        Some(label) => Err(EmailError::InvalidDomainLabel(label.to_string())),
+        None => Ok(()),
+    }
+}
+
+/// Letters (any script, for IDNs), digits and inner hyphens
+fn is_valid_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.chars().count() <= MAX_LABEL_LENGTH
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c == '-' || c.is_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_common_and_unusual_addresses() {
+        for email in [
+            "alice@example.com",
+            "first.last@subdomain.example.com",
+            "user+tag@example.co.uk",
+            "o'brien@example.ie",
+            "x@a.io",
+            "user@xn--bcher-kva.example",
+            "ñandú@correo.españa.es",
+            "用户@例子.中国",
+        ] {
+            assert_eq!(validate_email(email), Ok(()), "{email}");
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_addresses_with_a_reason() {
+        let cases = [
+            ("", EmailError::Empty),
+            ("alice", EmailError::MissingAt),
+            ("@example.com", EmailError::EmptyLocalPart),
+            ("alice@", EmailError::EmptyDomain),
+            ("al ice@example.com", EmailError::InvalidLocalPartChar(' ')),
+            ("a@b@example.com", EmailError::InvalidLocalPartChar('@')),
+            (".alice@example.com", EmailError::MisplacedDot),
+            ("alice..b@example.com", EmailError::MisplacedDot),
+            (
+                "alice@localhost",
+                EmailError::SingleLabelDomain("localhost".to_string()),
+            ),
+            (
+                "alice@example..com",
+                EmailError::InvalidDomainLabel(String::new()),
+            ),
+            (
+                "alice@-example.com",
+                EmailError::InvalidDomainLabel("-example".to_string()),
+            ),
+            (
+                "alice@exa_mple.com",
+                EmailError::InvalidDomainLabel("exa_mple".to_string()),
+            ),
+        ];
+        for (email, expected) in cases {
+            assert_eq!(validate_email(email), Err(expected), "{email}");
+        }
+    }
+
+    #[test]
+    fn normalizes_only_the_domain_case() {
+        assert_eq!(
+            normalize_email("Alice.Smith@Example.COM"),
+            "Alice.Smith@example.com"
+        );
+        assert_eq!(normalize_email("用户@例子.中国"), "用户@例子.中国");
+    }
+
+    #[test]
+    fn enforces_length_limits() {
+        let local = "a".repeat(MAX_LOCAL_PART_LENGTH);
+        assert_eq!(validate_email(&format!("{local}@example.com")), Ok(()));
+        assert_eq!(
+            validate_email(&format!("{local}a@example.com")),
+            Err(EmailError::LocalPartTooLong)
+        );
+
+        let label = "d".repeat(MAX_LABEL_LENGTH + 1);
+        assert_eq!(
+            validate_email(&format!("a@{label}.com")),
+            Err(EmailError::InvalidDomainLabel(label))
+        );
+
+        let label = "d".repeat(60);
+        let domain = format!("{}.com", [label.as_str(); 5].join("."));
+        assert_eq!(
+            validate_email(&format!("a@{domain}")),
+            Err(EmailError::TooLong)
+        );
+    }
+}
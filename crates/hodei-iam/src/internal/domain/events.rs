@@ -28,6 +28,10 @@ impl DomainEvent for UserCreated {
     fn aggregate_id(&self) -> Option<String> {
         Some(self.user_hrn.to_string())
     }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("User")
+    }
 }
 
 /// Event emitted when a new group is created
@@ -49,6 +53,10 @@ impl DomainEvent for GroupCreated {
     fn aggregate_id(&self) -> Option<String> {
         Some(self.group_hrn.to_string())
     }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Group")
+    }
 }
 
 /// Event emitted when a user is added to a group
@@ -70,4 +78,8 @@ impl DomainEvent for UserAddedToGroup {
     fn aggregate_id(&self) -> Option<String> {
         Some(self.group_hrn.to_string())
     }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Group")
+    }
 }
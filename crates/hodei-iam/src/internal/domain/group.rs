@@ -7,6 +7,13 @@ use kernel::{AttributeName, AttributeType, AttributeValue};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Deepest allowed chain of group-in-group memberships
+///
+/// Counted from a group a user belongs to directly (depth 1) up to its most
+/// distant ancestor. Nesting is rejected beyond it, and effective-policy
+/// resolution refuses to walk further.
+pub(crate) const MAX_GROUP_NESTING_DEPTH: usize = 8;
+
 /// Group entity representing an IAM group
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Group {
@@ -18,8 +25,12 @@ pub(crate) struct Group {
     pub description: Option<String>,
     /// Tags for categorization
     pub tags: Vec<String>,
+    /// HRNs of groups this group is a member of
+    #[serde(default)]
+    pub parent_group_hrns: Vec<Hrn>,
 }
 
+#[allow(dead_code)]
 impl Group {
     /// Create a new group
     pub(crate) fn new(hrn: Hrn, name: String, description: Option<String>) -> Self {
@@ -28,8 +39,28 @@ impl Group {
             name,
             description,
             tags: Vec::new(),
+            parent_group_hrns: Vec::new(),
+        }
+    }
+
+    /// Make this group a member of another group (idempotent)
+    ///
+    /// Does not check for cycles; the add_group_to_group use case does.
+    pub(crate) fn add_to_group(&mut self, parent_hrn: Hrn) {
+        if !self.parent_group_hrns.contains(&parent_hrn) {
+            self.parent_group_hrns.push(parent_hrn);
         }
     }
+
+    /// Remove this group from another group
+    pub(crate) fn remove_from_group(&mut self, parent_hrn: &Hrn) {
+        self.parent_group_hrns.retain(|hrn| hrn != parent_hrn);
+    }
+
+    /// Get the groups this group is a member of
+    pub(crate) fn parent_groups(&self) -> &[Hrn] {
+        &self.parent_group_hrns
+    }
 }
 
 // ============================================================================
@@ -102,8 +133,7 @@ impl HodeiEntity for Group {
     }
 
     fn parent_hrns(&self) -> Vec<Hrn> {
-        // Groups don't have parents in this implementation
-        Vec::new()
+        self.parent_group_hrns.clone()
     }
 }
 
@@ -188,6 +218,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_group_nesting_is_idempotent_and_exposed_as_parents() {
+        let hrn = |id: &str| {
+            Hrn::new(
+                "hodei".to_string(),
+                "iam".to_string(),
+                "account123".to_string(),
+                "Group".to_string(),
+                id.to_string(),
+            )
+        };
+        let mut backend = Group::new(hrn("backend"), "Backend".to_string(), None);
+
+        backend.add_to_group(hrn("engineering"));
+        backend.add_to_group(hrn("engineering"));
+        assert_eq!(backend.parent_groups(), [hrn("engineering")]);
+        assert_eq!(backend.parent_hrns(), vec![hrn("engineering")]);
+
+        backend.remove_from_group(&hrn("engineering"));
+        assert!(backend.parent_groups().is_empty());
+    }
+
+    #[test]
+    fn test_group_stored_before_nesting_deserializes() {
+        let hrn = Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "account123".to_string(),
+            "Group".to_string(),
+            "legacy".to_string(),
+        );
+        let mut stored = serde_json::to_value(Group::new(hrn, "Legacy".to_string(), None)).unwrap();
+        stored.as_object_mut().unwrap().remove("parent_group_hrns");
+
+        let group: Group = serde_json::from_value(stored).unwrap();
+        assert!(group.parent_groups().is_empty());
+    }
+
     #[test]
     fn test_group_entity_type_metadata() {
         assert_eq!(Group::service_name().as_str(), "iam");
@@ -1,6 +1,7 @@
 //! Domain models for the IAM bounded context
 
 pub(crate) mod actions;
+pub(crate) mod email;
 pub(crate) mod group;
 pub(crate) mod user;
 
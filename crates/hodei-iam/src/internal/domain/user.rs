@@ -1,9 +1,9 @@
 //! User entity - implements kernel traits for integration with hodei-policies
 
-use kernel::Hrn;
 use kernel::domain::entity::{HodeiEntity, HodeiEntityType, Principal, Resource};
 use kernel::domain::value_objects::{ResourceTypeName, ServiceName};
 use kernel::{AttributeName, AttributeType, AttributeValue};
+use kernel::{Hrn, PrincipalStatus};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -20,6 +20,9 @@ pub(crate) struct User {
     pub group_hrns: Vec<Hrn>,
     /// Tags for categorization
     pub tags: Vec<String>,
+    /// Lifecycle status; records stored before it existed are active
+    #[serde(default)]
+    pub status: PrincipalStatus,
 }
 
 #[allow(dead_code)]
@@ -32,6 +35,7 @@ impl User {
             email,
             group_hrns: Vec::new(),
             tags: Vec::new(),
+            status: PrincipalStatus::Active,
         }
     }
 
@@ -56,6 +60,14 @@ impl User {
     pub(crate) fn email(&self) -> &str {
         &self.email
     }
+
+    /// Whether the user's policies apply
+    ///
+    /// Suspending or deactivating keeps group memberships, so reinstating
+    /// restores exactly the access the user had.
+    pub(crate) fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
 }
 
 // ============================================================================
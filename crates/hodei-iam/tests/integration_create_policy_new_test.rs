@@ -28,6 +28,7 @@ use hodei_iam::features::create_policy::{
     CreatePolicyCommand, CreatePolicyError, PolicyValidationError, PolicyValidator, PolicyView,
     ValidationResult,
 };
+use hodei_policies::features::validate_policy::to_cedar_syntax;
 use std::sync::Arc;
 use surrealdb::{Surreal, engine::local::Mem};
 
@@ -82,7 +83,11 @@ impl PolicyValidator for IntegrationMockValidator {
         let is_valid = self.errors.is_empty();
         let errors = self.errors.clone();
 
-        Ok(ValidationResult { is_valid, errors })
+        Ok(ValidationResult {
+            is_valid,
+            errors,
+            annotations: Default::default(),
+        })
     }
 }
 
@@ -126,7 +131,11 @@ async fn integration_create_policy_success() {
     let view = result.unwrap();
     println!("Generated HRN: {}", view.id.to_string());
     assert!(view.id.to_string().contains("allow-read-documents"));
-    assert_eq!(view.content, "permit(principal, action, resource);");
+    // Stored as Cedar renders it
+    assert_eq!(
+        view.content,
+        to_cedar_syntax("permit(principal, action, resource);").unwrap()
+    );
     assert_eq!(
         view.description,
         Some("Integration test policy".to_string())
@@ -277,12 +286,13 @@ async fn integration_create_policy_with_large_content() {
     let validator = Arc::new(IntegrationMockValidator::new());
     let use_case = build_use_case("test-account-009", validator).await;
 
-    // Generate large policy content (realistic size ~50KB)
-    let base_clause = "permit(principal, action, resource);";
-    let large_content = std::iter::repeat(base_clause)
-        .take(1500)
+    // Generate a single large policy (realistic size ~50KB)
+    let names = (0..4000)
+        .map(|i| format!("\"doc-{i:05}\""))
         .collect::<Vec<_>>()
-        .join("\n");
+        .join(", ");
+    let large_content =
+        format!("permit(principal, action, resource) when {{ [{names}].contains(resource.name) }};");
 
     let command = CreatePolicyCommand {
         policy_id: "large-policy".to_string(),
@@ -298,7 +308,7 @@ async fn integration_create_policy_with_large_content() {
     let view = result.unwrap();
     println!("Large policy HRN: {}", view.id.to_string());
     assert!(view.id.to_string().contains("large-policy"));
-    assert_eq!(view.content.len(), large_content.len());
+    assert_eq!(view.content, to_cedar_syntax(&large_content).unwrap());
 }
 
 #[tokio::test]
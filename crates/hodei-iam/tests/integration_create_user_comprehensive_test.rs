@@ -1,10 +1,21 @@
 /// Comprehensive integration tests for create_user feature
 /// Uses only public API from hodei_iam crate
 use hodei_iam::{
-    features::create_user::{dto::CreateUserCommand, factories, ports::CreateUserUseCasePort},
+    features::create_user::{
+        dto::{CreateUserCommand, UserPersistenceDto},
+        error::CreateUserError,
+        factories,
+        ports::{CreateUserPort, CreateUserUseCasePort},
+    },
+    features::set_user_status::{
+        dto::SetUserStatusCommand, factories::create_set_user_status_use_case,
+        ports::UserStatusPort,
+    },
+    infrastructure::event_bus::EventBusIamEvents,
     infrastructure::hrn_generator::UuidHrnGenerator,
     infrastructure::surreal::SurrealUserAdapter,
 };
+use kernel::{Hrn, InMemoryEventBus, PrincipalStatus};
 use std::sync::Arc;
 use surrealdb::{Surreal, engine::local::Mem};
 
@@ -140,16 +151,22 @@ async fn test_create_user_unique_ids() {
     ));
     let use_case = factories::create_user_use_case(adapter.clone(), hrn_generator.clone());
 
-    let command = CreateUserCommand {
+    let command = |email: &str| CreateUserCommand {
         name: "Same Name".to_string(),
-        email: "same@example.com".to_string(),
+        email: email.to_string(),
         tags: vec![],
     };
 
-    let result1 = use_case.execute(command.clone()).await.unwrap();
-    let result2 = use_case.execute(command.clone()).await.unwrap();
+    let result1 = use_case
+        .execute(command("same1@example.com"))
+        .await
+        .unwrap();
+    let result2 = use_case
+        .execute(command("same2@example.com"))
+        .await
+        .unwrap();
 
-    // Even with same data, HRNs should be different (UUID)
+    // Even with the same name, HRNs should be different (UUID); emails must be unique
     assert_ne!(result1.hrn, result2.hrn);
 }
 
@@ -301,3 +318,111 @@ async fn test_create_user_special_characters_in_name() {
     let view = result.unwrap();
     assert_eq!(view.name, "José García-López O'Brien");
 }
+
+#[tokio::test]
+async fn test_create_user_duplicate_email_rejected() {
+    let db = Arc::new(Surreal::new::<Mem>(()).await.unwrap());
+    db.use_ns("test").use_db("iam").await.unwrap();
+    let adapter = Arc::new(SurrealUserAdapter::new(db));
+    let hrn_generator = Arc::new(UuidHrnGenerator::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "test-account".to_string(),
+    ));
+    let use_case = factories::create_user_use_case(adapter.clone(), hrn_generator.clone());
+
+    let first = use_case
+        .execute(CreateUserCommand {
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            tags: vec![],
+        })
+        .await
+        .unwrap();
+
+    let result = use_case
+        .execute(CreateUserCommand {
+            name: "Alice Again".to_string(),
+            email: "alice@Example.COM".to_string(),
+            tags: vec![],
+        })
+        .await;
+
+    match result {
+        Err(CreateUserError::EmailAlreadyExists(hrn)) => assert_eq!(hrn, first.hrn),
+        other => panic!("Expected EmailAlreadyExists, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_unique_index_rejects_duplicate_email_on_save() {
+    let db = Arc::new(Surreal::new::<Mem>(()).await.unwrap());
+    db.use_ns("test").use_db("iam").await.unwrap();
+    let adapter = SurrealUserAdapter::new(db);
+
+    // Saving directly skips the use case check, as a racing request would
+    adapter
+        .save_user(&UserPersistenceDto::new(
+            "hrn:hodei:iam::test-account:User/first",
+            "First",
+            "same@example.com",
+        ))
+        .await
+        .unwrap();
+    let result = adapter
+        .save_user(&UserPersistenceDto::new(
+            "hrn:hodei:iam::test-account:User/second",
+            "Second",
+            "same@example.com",
+        ))
+        .await;
+
+    match result {
+        Err(CreateUserError::EmailAlreadyExists(hrn)) => {
+            assert_eq!(hrn, "hrn:hodei:iam::test-account:User/first")
+        }
+        other => panic!("Expected EmailAlreadyExists, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_new_user_is_active_and_status_changes_persist() {
+    let db = Arc::new(Surreal::new::<Mem>(()).await.unwrap());
+    db.use_ns("test").use_db("iam").await.unwrap();
+    let adapter = Arc::new(SurrealUserAdapter::new(db));
+    let hrn_generator = Arc::new(UuidHrnGenerator::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "test-account".to_string(),
+    ));
+    let create_user = factories::create_user_use_case(adapter.clone(), hrn_generator);
+    let events = Arc::new(EventBusIamEvents::new(Arc::new(InMemoryEventBus::new())));
+    let set_user_status = create_set_user_status_use_case(adapter.clone(), events);
+
+    let view = create_user
+        .execute(CreateUserCommand {
+            name: "Status User".to_string(),
+            email: "status@example.com".to_string(),
+            tags: vec!["ops".to_string()],
+        })
+        .await
+        .unwrap();
+    let hrn = Hrn::from_string(&view.hrn).unwrap();
+    assert_eq!(
+        adapter.find_status(&hrn).await.unwrap(),
+        Some(PrincipalStatus::Active)
+    );
+
+    let response = set_user_status
+        .execute(SetUserStatusCommand {
+            user_hrn: view.hrn.clone(),
+            status: PrincipalStatus::Suspended,
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.previous_status, PrincipalStatus::Active);
+    assert_eq!(
+        adapter.find_status(&hrn).await.unwrap(),
+        Some(PrincipalStatus::Suspended)
+    );
+}
@@ -0,0 +1,117 @@
+//! Integration tests for `find_dangling_references` feature
+//!
+//! Exercises a scan end to end over the SurrealDB adapters backed by an
+//! in-memory database: policies walked from the policy adapter, users and
+//! groups checked against the state adapter.
+//!
+//! ## Run with
+//!
+//! ```bash
+//! cargo test -p hodei-iam --test integration_find_dangling_references_test
+//! ```
+
+use hodei_iam::features::create_policy::CreatePolicyCommand;
+use hodei_iam::features::create_policy::ports::CreatePolicyPort;
+use hodei_iam::features::create_user::dto::UserPersistenceDto;
+use hodei_iam::features::create_user::ports::CreateUserPort;
+use hodei_iam::features::find_dangling_references::{
+    FindDanglingReferencesQuery, FindDanglingReferencesUseCase,
+};
+use hodei_iam::infrastructure::surreal::{
+    SurrealIamStateAdapter, SurrealPolicyAdapter, SurrealUserAdapter,
+};
+use hodei_policies::features::match_policy::references::EntityReference;
+use std::sync::Arc;
+use surrealdb::{Surreal, engine::local::Mem};
+
+async fn finder_with(users: &[&str], policies: &[(&str, &str)]) -> FindDanglingReferencesUseCase {
+    let db = Arc::new(Surreal::new::<Mem>(()).await.unwrap());
+    db.use_ns("test").use_db("iam").await.unwrap();
+    let user_adapter = SurrealUserAdapter::new(db.clone());
+    for name in users {
+        user_adapter
+            .save_user(&UserPersistenceDto::new(
+                format!("hrn:hodei:iam::default:User/{}", name),
+                *name,
+                format!("{}@example.com", name),
+            ))
+            .await
+            .unwrap();
+    }
+    let policy_adapter = Arc::new(SurrealPolicyAdapter::new(db.clone()));
+    for (id, content) in policies {
+        policy_adapter
+            .create(CreatePolicyCommand {
+                policy_id: id.to_string(),
+                policy_content: content.to_string(),
+                description: None,
+            })
+            .await
+            .unwrap();
+    }
+    FindDanglingReferencesUseCase::new(policy_adapter, Arc::new(SurrealIamStateAdapter::new(db)))
+}
+
+#[tokio::test]
+async fn integration_policies_naming_missing_users_and_groups_dangle() {
+    // Arrange
+    let finder = finder_with(
+        &["alice"],
+        &[
+            (
+                "former-staff",
+                r#"permit(principal in Iam::Group::"contractors", action, resource)
+                   unless { principal == Iam::User::"alice" };"#,
+            ),
+            (
+                "alice-only",
+                r#"permit(principal == Iam::User::"alice", action, resource);"#,
+            ),
+        ],
+    )
+    .await;
+
+    // Act
+    let report = finder
+        .execute(FindDanglingReferencesQuery::default())
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(report.scanned, 2);
+    assert_eq!(report.dangling.len(), 1);
+    assert_eq!(report.dangling[0].policy_hrn.resource_id(), "former-staff");
+    assert_eq!(
+        report.dangling[0].missing,
+        [EntityReference::new("Iam::Group", "contractors")]
+    );
+    assert!(report.unverified.is_empty());
+}
+
+#[tokio::test]
+async fn integration_resources_outside_iam_are_unverified() {
+    // Arrange
+    let finder = finder_with(
+        &[],
+        &[(
+            "read-repo",
+            r#"permit(principal, action, resource == Artifact::Repository::"docs");"#,
+        )],
+    )
+    .await;
+
+    // Act
+    let report = finder
+        .execute(FindDanglingReferencesQuery::default())
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(report.dangling.is_empty());
+    assert_eq!(report.unverified.len(), 1);
+    assert_eq!(report.unverified[0].policy_hrn.resource_id(), "read-repo");
+    assert_eq!(
+        report.unverified[0].unverified,
+        [EntityReference::new("Artifact::Repository", "docs")]
+    );
+}
@@ -0,0 +1,94 @@
+//! Integration tests for `get_policies` feature
+//!
+//! Exercises the batch lookup end to end: the use case on top of the
+//! SurrealDB policy adapter backed by an in-memory database.
+//!
+//! ## Run with
+//!
+//! ```bash
+//! cargo test -p hodei-iam --test integration_get_policies_test
+//! ```
+
+use hodei_iam::features::create_policy::CreatePolicyCommand;
+use hodei_iam::features::create_policy::ports::CreatePolicyPort;
+use hodei_iam::features::get_policies::factories::create_get_policies_use_case;
+use hodei_iam::features::get_policies::GetPoliciesQuery;
+use hodei_iam::infrastructure::surreal::SurrealPolicyAdapter;
+use kernel::Hrn;
+use std::sync::Arc;
+use surrealdb::{Surreal, engine::local::Mem};
+
+async fn adapter_with(
+    policy_ids: &[&str],
+) -> Arc<SurrealPolicyAdapter<surrealdb::engine::local::Db>> {
+    let db = Arc::new(Surreal::new::<Mem>(()).await.unwrap());
+    db.use_ns("test").use_db("iam").await.unwrap();
+    let adapter = Arc::new(SurrealPolicyAdapter::new(db));
+    for id in policy_ids {
+        adapter
+            .create(CreatePolicyCommand {
+                policy_id: id.to_string(),
+                policy_content: format!("@id(\"{}\")\npermit(principal, action, resource);", id),
+                description: None,
+            })
+            .await
+            .unwrap();
+    }
+    adapter
+}
+
+fn policy_hrn(id: &str) -> Hrn {
+    Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        "Policy".to_string(),
+        id.to_string(),
+    )
+}
+
+#[tokio::test]
+async fn integration_get_policies_returns_found_and_missing_in_input_order() {
+    // Arrange
+    let adapter = adapter_with(&["allow-read", "deny-delete", "allow-write"]).await;
+    let use_case = create_get_policies_use_case(adapter);
+    let query = GetPoliciesQuery {
+        policy_hrns: vec![
+            policy_hrn("allow-write"),
+            policy_hrn("missing"),
+            policy_hrn("allow-read"),
+            policy_hrn("allow-write"),
+        ],
+    };
+
+    // Act
+    let response = use_case.execute(query).await.unwrap();
+
+    // Assert
+    let hrns: Vec<Hrn> = response.policies.iter().map(|p| p.hrn.clone()).collect();
+    assert_eq!(
+        hrns,
+        vec![policy_hrn("allow-write"), policy_hrn("allow-read")]
+    );
+    assert_eq!(response.policies[0].name, "allow-write");
+    assert_eq!(response.policies[0].annotations["id"], "allow-write");
+    assert_eq!(response.not_found, vec![policy_hrn("missing")]);
+}
+
+#[tokio::test]
+async fn integration_get_policies_with_none_stored() {
+    // Arrange
+    let use_case = create_get_policies_use_case(adapter_with(&[]).await);
+
+    // Act
+    let response = use_case
+        .execute(GetPoliciesQuery {
+            policy_hrns: vec![policy_hrn("allow-read")],
+        })
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(response.policies.is_empty());
+    assert_eq!(response.not_found, vec![policy_hrn("allow-read")]);
+}
@@ -0,0 +1,205 @@
+//! Integration tests for `revalidate_policies` feature
+//!
+//! Exercises a revalidation end to end: a `SchemaChanged` event published
+//! on the in-memory event bus, the revalidator subscribed to it, and the
+//! SurrealDB policy adapter backed by an in-memory database.
+//!
+//! ## Run with
+//!
+//! ```bash
+//! cargo test -p hodei-iam --test integration_revalidate_policies_test
+//! ```
+
+use async_trait::async_trait;
+use hodei_iam::features::create_policy::CreatePolicyCommand;
+use hodei_iam::features::create_policy::ports::{
+    CreatePolicyPort, PolicyValidationError, PolicyValidator, ValidationResult,
+};
+use hodei_iam::features::get_effective_policies::ports::PolicyFinderPort;
+use hodei_iam::features::revalidate_policies::ports::{
+    PolicyRevalidationStorePort, PolicyWalkStorePort,
+};
+use hodei_iam::features::revalidate_policies::{
+    EventBusPolicyDisabledAlerts, PolicyDisabled, RevalidationConfig, SchemaChangeRevalidator,
+    SchemaChanged,
+};
+use hodei_iam::infrastructure::surreal::SurrealPolicyAdapter;
+use hodei_policies::features::validate_policy::dto::ValidatePolicyCommand;
+use kernel::application::ports::event_bus::{
+    EventBus, EventEnvelope, EventHandler, EventPublisher,
+};
+use kernel::{Hrn, InMemoryEventBus};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use surrealdb::{Surreal, engine::local::Mem};
+
+type Adapter = SurrealPolicyAdapter<surrealdb::engine::local::Db>;
+
+/// Validator for a schema that dropped the `Legacy` namespace
+struct LegacyDroppedValidator;
+
+#[async_trait]
+impl PolicyValidator for LegacyDroppedValidator {
+    async fn validate(
+        &self,
+        command: ValidatePolicyCommand,
+    ) -> Result<ValidationResult, PolicyValidationError> {
+        let errors: Vec<String> = command
+            .content
+            .contains("Legacy::")
+            .then(|| "unrecognized entity type `Legacy::File`".to_string())
+            .into_iter()
+            .collect();
+        Ok(ValidationResult {
+            is_valid: errors.is_empty(),
+            errors,
+            annotations: HashMap::new(),
+        })
+    }
+}
+
+/// Collects the alerts published on the bus
+#[derive(Default)]
+struct AlertCollector {
+    alerts: Mutex<Vec<PolicyDisabled>>,
+}
+
+#[async_trait]
+impl EventHandler<PolicyDisabled> for AlertCollector {
+    fn name(&self) -> &'static str {
+        "alert-collector"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<PolicyDisabled>) -> anyhow::Result<()> {
+        self.alerts.lock().unwrap().push(envelope.event);
+        Ok(())
+    }
+}
+
+fn alice() -> Hrn {
+    Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        "User".to_string(),
+        "alice".to_string(),
+    )
+}
+
+async fn adapter_with(policies: &[(&str, &str)]) -> Arc<Adapter> {
+    let db = Arc::new(Surreal::new::<Mem>(()).await.unwrap());
+    db.use_ns("test").use_db("iam").await.unwrap();
+    let adapter = Arc::new(SurrealPolicyAdapter::new(db.clone()));
+    for (id, content) in policies {
+        adapter
+            .create(CreatePolicyCommand {
+                policy_id: id.to_string(),
+                policy_content: content.to_string(),
+                description: None,
+            })
+            .await
+            .unwrap();
+    }
+    db.query("UPDATE policy SET attached_principals = [$principal]")
+        .bind(("principal", alice().to_string()))
+        .await
+        .unwrap();
+    adapter
+}
+
+async fn effective_ids(adapter: &Adapter) -> Vec<String> {
+    let mut ids: Vec<String> = adapter
+        .find_policies_by_principal(&alice())
+        .await
+        .unwrap()
+        .iter()
+        .map(|policy| policy.id().to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn integration_schema_change_disables_policies_it_invalidates() {
+    // Arrange
+    let adapter = adapter_with(&[
+        (
+            "read-docs",
+            "permit(principal, action, resource is Docs::Document);",
+        ),
+        (
+            "read-legacy",
+            "permit(principal, action, resource is Legacy::File);",
+        ),
+        ("deny-all", "forbid(principal, action, resource);"),
+    ])
+    .await;
+    let bus = Arc::new(InMemoryEventBus::new());
+    let collector = Arc::new(AlertCollector::default());
+    let revalidator = Arc::new(
+        SchemaChangeRevalidator::new(adapter.clone(), Arc::new(LegacyDroppedValidator))
+            .with_alerts(Arc::new(EventBusPolicyDisabledAlerts::new(bus.clone())))
+            .with_config(
+                RevalidationConfig::default()
+                    .with_batch_size(2)
+                    .with_batch_pause(Duration::ZERO),
+            ),
+    );
+    let _alerts = bus
+        .subscribe::<PolicyDisabled, _>(collector.clone())
+        .await
+        .unwrap();
+    let _revalidation = bus
+        .subscribe::<SchemaChanged, _>(revalidator)
+        .await
+        .unwrap();
+
+    // Act
+    bus.publish(SchemaChanged::new("v2", "schema-2"))
+        .await
+        .unwrap();
+
+    // Assert
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while collector.alerts.lock().unwrap().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("policy disabled alert");
+
+    let alerts = collector.alerts.lock().unwrap().clone();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].policy_id, "read-legacy");
+    assert_eq!(effective_ids(&adapter).await, ["deny-all", "read-docs"]);
+
+    let listed = adapter.list_policies(None, 10).await.unwrap();
+    let ids: Vec<&str> = listed.iter().map(|policy| policy.id.as_str()).collect();
+    assert_eq!(ids, ["deny-all", "read-docs", "read-legacy"]);
+    assert_eq!(
+        listed[2].disabled_reason.as_deref(),
+        Some(alerts[0].reason.as_str())
+    );
+}
+
+#[tokio::test]
+async fn integration_policies_are_listed_after_a_cursor() {
+    // Arrange
+    let adapter = adapter_with(&[
+        ("a", "permit(principal, action, resource);"),
+        ("b", "permit(principal, action, resource);"),
+        ("c", "permit(principal, action, resource);"),
+    ])
+    .await;
+
+    // Act
+    let page = adapter.list_policies(Some("a"), 1).await.unwrap();
+    let missing = adapter.disable_policy("missing", "reason").await;
+
+    // Assert
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, "b");
+    assert_eq!(page[0].hrn.resource_id(), "b");
+    assert!(missing.is_err());
+}
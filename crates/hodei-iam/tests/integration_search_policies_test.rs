@@ -0,0 +1,87 @@
+//! Integration tests for `search_policies` feature
+//!
+//! Exercises a search end to end over the SurrealDB policy adapter backed by
+//! an in-memory database.
+//!
+//! ## Run with
+//!
+//! ```bash
+//! cargo test -p hodei-iam --test integration_search_policies_test
+//! ```
+
+use hodei_iam::features::create_policy::CreatePolicyCommand;
+use hodei_iam::features::create_policy::ports::CreatePolicyPort;
+use hodei_iam::features::revalidate_policies::ports::PolicyRevalidationStorePort;
+use hodei_iam::features::search_policies::{SearchPoliciesQuery, SearchPoliciesUseCase};
+use hodei_iam::infrastructure::surreal::SurrealPolicyAdapter;
+use hodei_policies::features::match_policy::dto::PolicyEffect;
+use std::sync::Arc;
+use surrealdb::{Surreal, engine::local::Mem};
+
+type Adapter = SurrealPolicyAdapter<surrealdb::engine::local::Db>;
+
+async fn adapter_with(policies: &[(&str, &str)]) -> Arc<Adapter> {
+    let db = Arc::new(Surreal::new::<Mem>(()).await.unwrap());
+    db.use_ns("test").use_db("iam").await.unwrap();
+    let adapter = Arc::new(SurrealPolicyAdapter::new(db));
+    for (id, content) in policies {
+        adapter
+            .create(CreatePolicyCommand {
+                policy_id: id.to_string(),
+                policy_content: content.to_string(),
+                description: None,
+            })
+            .await
+            .unwrap();
+    }
+    adapter
+}
+
+#[tokio::test]
+async fn integration_search_finds_granting_policies_across_batches() {
+    // Arrange
+    let adapter = adapter_with(&[
+        (
+            "a-cleanup",
+            r#"permit(principal, action in [Action::"s3:Get", Action::"s3:Delete"], resource);"#,
+        ),
+        (
+            "b-readers",
+            r#"permit(principal, action == Action::"s3:Get", resource);"#,
+        ),
+        (
+            "c-deleters",
+            r#"permit(principal, action == Action::"s3:Delete", resource);"#,
+        ),
+        (
+            "d-no-deletes",
+            r#"forbid(principal, action == Action::"s3:Delete", resource);"#,
+        ),
+    ])
+    .await;
+    adapter
+        .disable_policy("c-deleters", "invalid against schema v2")
+        .await
+        .unwrap();
+
+    // Act
+    let response = SearchPoliciesUseCase::new(adapter.clone())
+        .with_batch_size(1)
+        .execute(
+            SearchPoliciesQuery::default()
+                .with_effect(PolicyEffect::Permit)
+                .with_action("s3:Delete")
+                .including_disabled(),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let found: Vec<(&str, bool)> = response
+        .matches
+        .iter()
+        .map(|m| (m.policy_hrn.resource_id(), m.disabled))
+        .collect();
+    assert_eq!(found, [("a-cleanup", false), ("c-deleters", true)]);
+    assert_eq!(response.searched, 4);
+}
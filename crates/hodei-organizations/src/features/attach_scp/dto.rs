@@ -1,3 +1,4 @@
+use kernel::{HrnVisitor, TenantScoped};
 use serde::{Deserialize, Serialize};
 
 /// Command to attach an SCP to an entity (Account or OU)
@@ -9,6 +10,13 @@ pub struct AttachScpCommand {
     pub target_hrn: String,
 }
 
+impl TenantScoped for AttachScpCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.scp_hrn);
+        visitor.hrn_str(&self.target_hrn);
+    }
+}
+
 /// View of the attach SCP operation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttachScpView {
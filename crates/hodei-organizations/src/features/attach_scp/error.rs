@@ -1,4 +1,5 @@
 use thiserror::Error;
+use kernel::CrossTenantAccess;
 use crate::internal::application::ports::scp_repository::ScpRepositoryError;
 use crate::internal::application::ports::account_repository::AccountRepositoryError;
 use crate::internal::application::ports::ou_repository::OuRepositoryError;
@@ -18,4 +19,6 @@ pub enum AttachScpError {
     TargetNotFound(String),
     #[error("Invalid target entity type: {0}")]
     InvalidTargetType(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
@@ -5,9 +5,9 @@ use crate::features::attach_scp::ports::{
 };
 use crate::internal::domain::events::{ScpAttached, ScpTargetType};
 use kernel::EventPublisher;
-use kernel::Hrn;
 use kernel::application::ports::event_bus::EventEnvelope;
 use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use kernel::{Hrn, TenantContext};
 use std::sync::Arc;
 
 /// Use case for attaching an SCP to an entity (Account or OU)
@@ -111,4 +111,15 @@ impl<SRP: ScpRepositoryPort, ARP: AccountRepositoryPort, ORP: OuRepositoryPort>
             target_hrn: target_hrn.to_string(),
         })
     }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// Both the SCP and the target must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: AttachScpCommand,
+    ) -> Result<AttachScpView, AttachScpError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
 }
@@ -1,10 +1,20 @@
 use serde::{Deserialize, Serialize};
-use kernel::Hrn;
+use kernel::{Hrn, HrnVisitor, TenantScoped};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateAccountCommand {
     pub name: String,
     pub parent_hrn: Option<Hrn>,
+    /// Token chosen by the client to make retries safe: a command carrying a
+    /// token already used returns the account created the first time
+    #[serde(default)]
+    pub idempotency_token: Option<String>,
+}
+
+impl TenantScoped for CreateAccountCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.nested(&self.parent_hrn);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,3 +23,33 @@ pub struct AccountView {
     pub name: String,
     pub parent_hrn: Option<Hrn>,
 }
+
+/// Account created for an idempotency token, with the command that created it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdempotentCreation {
+    pub name: String,
+    pub parent_hrn: Option<Hrn>,
+    pub account: AccountView,
+}
+
+impl IdempotentCreation {
+    /// Whether `command` asks for the same account as the one recorded
+    pub fn matches(&self, command: &CreateAccountCommand) -> bool {
+        self.name == command.name && self.parent_hrn == command.parent_hrn
+    }
+}
+
+/// State of an idempotency token when a command tries to reserve it
+#[derive(Debug, Clone)]
+pub enum IdempotencyReservation {
+    /// The token was free: the caller holds it until it records the account
+    /// created or releases the token
+    Reserved,
+    /// Another call holds the token and is still creating the account `name`
+    InProgress {
+        name: String,
+        parent_hrn: Option<Hrn>,
+    },
+    /// The token was already used
+    Completed(IdempotentCreation),
+}
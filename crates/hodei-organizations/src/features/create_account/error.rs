@@ -1,4 +1,5 @@
 use crate::internal::application::ports::account_repository::AccountRepositoryError;
+use kernel::CrossTenantAccess;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -9,4 +10,10 @@ pub enum CreateAccountError {
     InvalidAccountName,
     #[error("Transaction error: {0}")]
     TransactionError(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+    #[error("Idempotency token '{0}' was already used to create a different account")]
+    IdempotencyConflict(String),
+    #[error("A request with idempotency token '{0}' is still in progress")]
+    IdempotencyInProgress(String),
 }
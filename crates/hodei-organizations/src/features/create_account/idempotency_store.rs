@@ -0,0 +1,181 @@
+use crate::features::create_account::dto::{
+    CreateAccountCommand, IdempotencyReservation, IdempotentCreation,
+};
+use crate::features::create_account::ports::CreateAccountIdempotencyStore;
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long a token is remembered unless configured otherwise
+pub const DEFAULT_IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// What a token is held for
+enum TokenState {
+    /// Reserved by a call still creating the account
+    Pending {
+        name: String,
+        parent_hrn: Option<Hrn>,
+    },
+    Completed(IdempotentCreation),
+}
+
+/// In-memory idempotency store whose records expire after a fixed window
+///
+/// Reservations and records share one lock, so only one of the calls racing
+/// for a token reserves it. Expired entries, pending reservations included,
+/// are dropped whenever a token is reserved, so the store only ever holds the
+/// tokens used within the last window. Records are lost on restart.
+pub struct InMemoryIdempotencyStore {
+    window: Duration,
+    records: Mutex<HashMap<String, (Instant, TokenState)>>,
+}
+
+impl InMemoryIdempotencyStore {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            records: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Tokens currently remembered, expired or not
+    pub fn len(&self) -> usize {
+        self.records.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryIdempotencyStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDEMPOTENCY_WINDOW)
+    }
+}
+
+#[async_trait]
+impl CreateAccountIdempotencyStore for InMemoryIdempotencyStore {
+    async fn reserve(&self, token: &str, command: &CreateAccountCommand) -> IdempotencyReservation {
+        let mut records = self.records.lock().unwrap();
+        records.retain(|_, (recorded_at, _)| recorded_at.elapsed() < self.window);
+        match records.get(token) {
+            Some((_, TokenState::Pending { name, parent_hrn })) => {
+                IdempotencyReservation::InProgress {
+                    name: name.clone(),
+                    parent_hrn: parent_hrn.clone(),
+                }
+            }
+            Some((_, TokenState::Completed(creation))) => {
+                IdempotencyReservation::Completed(creation.clone())
+            }
+            None => {
+                let pending = TokenState::Pending {
+                    name: command.name.clone(),
+                    parent_hrn: command.parent_hrn.clone(),
+                };
+                records.insert(token.to_string(), (Instant::now(), pending));
+                IdempotencyReservation::Reserved
+            }
+        }
+    }
+
+    async fn record(&self, token: &str, creation: IdempotentCreation) {
+        let mut records = self.records.lock().unwrap();
+        records.insert(
+            token.to_string(),
+            (Instant::now(), TokenState::Completed(creation)),
+        );
+    }
+
+    async fn release(&self, token: &str) {
+        let mut records = self.records.lock().unwrap();
+        if let Some((_, TokenState::Pending { .. })) = records.get(token) {
+            records.remove(token);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::create_account::dto::AccountView;
+    use kernel::Hrn;
+
+    fn creation(name: &str) -> IdempotentCreation {
+        IdempotentCreation {
+            name: name.to_string(),
+            parent_hrn: None,
+            account: AccountView {
+                hrn: Hrn::new(
+                    "aws".to_string(),
+                    "organizations".to_string(),
+                    "123456789012".to_string(),
+                    "account".to_string(),
+                    name.to_string(),
+                ),
+                name: name.to_string(),
+                parent_hrn: None,
+            },
+        }
+    }
+
+    fn command(name: &str) -> CreateAccountCommand {
+        CreateAccountCommand {
+            name: name.to_string(),
+            parent_hrn: None,
+            idempotency_token: Some("token-1".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_finds_recorded_creation() {
+        let store = InMemoryIdempotencyStore::default();
+        assert!(matches!(
+            store.reserve("token-1", &command("production")).await,
+            IdempotencyReservation::Reserved
+        ));
+        store.record("token-1", creation("production")).await;
+
+        match store.reserve("token-1", &command("production")).await {
+            IdempotencyReservation::Completed(found) => {
+                assert_eq!(found.account.name, "production")
+            }
+            other => panic!("Expected Completed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserved_token_is_held_until_released() {
+        let store = InMemoryIdempotencyStore::default();
+        store.reserve("token-1", &command("production")).await;
+
+        match store.reserve("token-1", &command("production")).await {
+            IdempotencyReservation::InProgress { name, .. } => assert_eq!(name, "production"),
+            other => panic!("Expected InProgress, got {:?}", other),
+        }
+
+        store.release("token-1").await;
+        assert!(store.is_empty());
+        assert!(matches!(
+            store.reserve("token-1", &command("production")).await,
+            IdempotencyReservation::Reserved
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_records_expire_after_the_window() {
+        let store = InMemoryIdempotencyStore::new(Duration::ZERO);
+        store.reserve("token-1", &command("production")).await;
+        store.record("token-1", creation("production")).await;
+
+        // Reserving drops the expired records, so the token is free again
+        assert!(matches!(
+            store.reserve("token-1", &command("production")).await,
+            IdempotencyReservation::Reserved
+        ));
+        assert_eq!(store.len(), 1);
+    }
+}
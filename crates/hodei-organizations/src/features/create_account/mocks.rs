@@ -87,6 +87,15 @@ impl AccountRepository for MockAccountRepository {
         let accounts = self.accounts.lock().unwrap();
         Ok(accounts.get(&hrn.to_string()).cloned())
     }
+
+    async fn delete(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<(), crate::internal::application::ports::account_repository::AccountRepositoryError>
+    {
+        self.accounts.lock().unwrap().remove(&hrn.to_string());
+        Ok(())
+    }
 }
 
 /// Mock UnitOfWork for testing transactional behavior
@@ -162,6 +171,8 @@ impl CreateAccountUnitOfWork for MockCreateAccountUnitOfWork {
 /// Mock UnitOfWorkFactory for testing
 pub struct MockCreateAccountUnitOfWorkFactory {
     pub should_fail_on_save: bool,
+    /// Track calls to create, one per transaction
+    pub create_calls: Arc<Mutex<usize>>,
 }
 
 impl Default for MockCreateAccountUnitOfWorkFactory {
@@ -174,12 +185,14 @@ impl MockCreateAccountUnitOfWorkFactory {
     pub fn new() -> Self {
         Self {
             should_fail_on_save: false,
+            create_calls: Arc::new(Mutex::new(0)),
         }
     }
 
     pub fn with_failure(should_fail: bool) -> Self {
         Self {
             should_fail_on_save: should_fail,
+            create_calls: Arc::new(Mutex::new(0)),
         }
     }
 }
@@ -189,6 +202,7 @@ impl CreateAccountUnitOfWorkFactory for MockCreateAccountUnitOfWorkFactory {
     type UnitOfWork = MockCreateAccountUnitOfWork;
 
     async fn create(&self) -> Result<Self::UnitOfWork, CreateAccountError> {
+        *self.create_calls.lock().unwrap() += 1;
         Ok(MockCreateAccountUnitOfWork::with_failure(
             self.should_fail_on_save,
         ))
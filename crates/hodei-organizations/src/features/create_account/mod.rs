@@ -2,6 +2,7 @@ pub mod adapter;
 pub mod di;
 pub mod dto;
 pub mod error;
+pub mod idempotency_store;
 #[cfg(test)]
 pub mod mocks;
 pub mod ports;
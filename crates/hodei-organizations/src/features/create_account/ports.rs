@@ -1,3 +1,6 @@
+use crate::features::create_account::dto::{
+    CreateAccountCommand, IdempotencyReservation, IdempotentCreation,
+};
 use crate::features::create_account::error::CreateAccountError;
 use crate::internal::application::ports::account_repository::AccountRepository;
 use crate::internal::domain::account::Account;
@@ -42,3 +45,23 @@ pub trait CreateAccountUnitOfWorkFactory: Send + Sync {
     /// Create a new UnitOfWork instance
     async fn create(&self) -> Result<Self::UnitOfWork, CreateAccountError>;
 }
+
+/// Store of the accounts created for each idempotency token
+///
+/// Records are kept for a limited window; once expired, the token can be
+/// used again and creates a new account.
+#[async_trait]
+pub trait CreateAccountIdempotencyStore: Send + Sync {
+    /// Reserve `token` for `command`, unless it is already held or used
+    ///
+    /// Must be atomic: of the calls reserving the same free token at once,
+    /// exactly one gets `Reserved`.
+    async fn reserve(&self, token: &str, command: &CreateAccountCommand) -> IdempotencyReservation;
+
+    /// Record the account created for a reserved `token`
+    async fn record(&self, token: &str, creation: IdempotentCreation);
+
+    /// Release a reserved `token` whose account could not be created, so a
+    /// retry can use it
+    async fn release(&self, token: &str);
+}
@@ -1,14 +1,16 @@
-use crate::features::create_account::dto::{AccountView, CreateAccountCommand};
+use crate::features::create_account::dto::{
+    AccountView, CreateAccountCommand, IdempotencyReservation, IdempotentCreation,
+};
 use crate::features::create_account::error::CreateAccountError;
 use crate::features::create_account::ports::{
-    CreateAccountUnitOfWork, CreateAccountUnitOfWorkFactory,
+    CreateAccountIdempotencyStore, CreateAccountUnitOfWork, CreateAccountUnitOfWorkFactory,
 };
 use crate::internal::domain::account::Account;
 use crate::internal::domain::events::AccountCreated;
 use kernel::EventPublisher;
-use kernel::Hrn;
 use kernel::application::ports::event_bus::EventEnvelope;
 use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use kernel::{Hrn, TenantContext};
 use std::sync::Arc;
 
 /// Use case for creating accounts with transactional guarantees
@@ -16,6 +18,13 @@ use std::sync::Arc;
 /// This implementation uses the UnitOfWork pattern to ensure atomic operations
 /// and consistency. Events are published after successful commit to guarantee
 /// eventual consistency.
+///
+/// With an idempotency store, a command carrying an `idempotency_token`
+/// already used returns the account created the first time instead of
+/// creating it again, so callers can retry safely. Reusing a token for a
+/// different account is a conflict. The token is reserved before the account
+/// is created, so a retry sent while the first call is still running is
+/// refused rather than creating a second account.
 pub struct CreateAccountUseCase<UWF: CreateAccountUnitOfWorkFactory> {
     uow_factory: Arc<UWF>,
     /// Partition for HRN generation (e.g., "aws", "hodei")
@@ -24,6 +33,8 @@ pub struct CreateAccountUseCase<UWF: CreateAccountUnitOfWorkFactory> {
     account_id: String,
     /// Optional event publisher for domain events
     event_publisher: Option<Arc<InMemoryEventBus>>,
+    /// Optional store of the accounts created for each idempotency token
+    idempotency_store: Option<Arc<dyn CreateAccountIdempotencyStore>>,
 }
 
 impl<UWF: CreateAccountUnitOfWorkFactory> CreateAccountUseCase<UWF> {
@@ -33,6 +44,7 @@ impl<UWF: CreateAccountUnitOfWorkFactory> CreateAccountUseCase<UWF> {
             partition,
             account_id,
             event_publisher: None,
+            idempotency_store: None,
         }
     }
 
@@ -41,9 +53,59 @@ impl<UWF: CreateAccountUnitOfWorkFactory> CreateAccountUseCase<UWF> {
         self
     }
 
+    pub fn with_idempotency_store(mut self, store: Arc<dyn CreateAccountIdempotencyStore>) -> Self {
+        self.idempotency_store = Some(store);
+        self
+    }
+
     pub async fn execute(
         &self,
         command: CreateAccountCommand,
+    ) -> Result<AccountView, CreateAccountError> {
+        let (Some(store), Some(token)) = (&self.idempotency_store, &command.idempotency_token)
+        else {
+            return self.create_account(&command).await;
+        };
+
+        // A retried command returns the account created the first time
+        match store.reserve(token, &command).await {
+            IdempotencyReservation::Reserved => {}
+            IdempotencyReservation::InProgress { name, parent_hrn } => {
+                if name != command.name || parent_hrn != command.parent_hrn {
+                    return Err(CreateAccountError::IdempotencyConflict(token.clone()));
+                }
+                return Err(CreateAccountError::IdempotencyInProgress(token.clone()));
+            }
+            IdempotencyReservation::Completed(creation) => {
+                if !creation.matches(&command) {
+                    return Err(CreateAccountError::IdempotencyConflict(token.clone()));
+                }
+                tracing::debug!("Idempotency token already used, returning the account created");
+                return Ok(creation.account);
+            }
+        }
+
+        match self.create_account(&command).await {
+            Ok(view) => {
+                let creation = IdempotentCreation {
+                    name: command.name.clone(),
+                    parent_hrn: command.parent_hrn.clone(),
+                    account: view.clone(),
+                };
+                store.record(token, creation).await;
+                Ok(view)
+            }
+            Err(e) => {
+                // A failed creation does not consume the token
+                store.release(token).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn create_account(
+        &self,
+        command: &CreateAccountCommand,
     ) -> Result<AccountView, CreateAccountError> {
         // Create a new UnitOfWork for this operation
         let mut uow = self.uow_factory.create().await?;
@@ -52,7 +114,7 @@ impl<UWF: CreateAccountUnitOfWorkFactory> CreateAccountUseCase<UWF> {
         uow.begin().await?;
 
         // Execute the business logic within the transaction
-        let result = self.execute_within_transaction(&command, &mut uow).await;
+        let result = self.execute_within_transaction(command, &mut uow).await;
 
         // Commit or rollback based on the result
         match result {
@@ -76,6 +138,17 @@ impl<UWF: CreateAccountUnitOfWorkFactory> CreateAccountUseCase<UWF> {
         }
     }
 
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The parent OU, when given, must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: CreateAccountCommand,
+    ) -> Result<AccountView, CreateAccountError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
+
     async fn execute_within_transaction(
         &self,
         command: &CreateAccountCommand,
@@ -0,0 +1,32 @@
+use crate::features::create_accounts_batch::surreal_adapter::CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter;
+use crate::features::create_accounts_batch::use_case::CreateAccountsBatchUseCase;
+use crate::internal::infrastructure::surreal::SurrealUnitOfWorkFactory;
+use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use std::sync::Arc;
+
+/// Create an instance of the CreateAccountsBatchUseCase with SurrealDB UoW
+pub fn create_accounts_batch_use_case<C>(
+    uow_factory: Arc<SurrealUnitOfWorkFactory<C>>,
+    partition: String,
+    account_id: String,
+) -> CreateAccountsBatchUseCase<CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter<C>>
+where
+    C: surrealdb::Connection,
+{
+    let factory_adapter = CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter::new(uow_factory);
+    CreateAccountsBatchUseCase::new(Arc::new(factory_adapter), partition, account_id)
+}
+
+/// Create an instance of the CreateAccountsBatchUseCase with event bus integration
+pub fn create_accounts_batch_use_case_with_events<C>(
+    uow_factory: Arc<SurrealUnitOfWorkFactory<C>>,
+    partition: String,
+    account_id: String,
+    event_bus: Arc<InMemoryEventBus>,
+) -> CreateAccountsBatchUseCase<CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter<C>>
+where
+    C: surrealdb::Connection,
+{
+    create_accounts_batch_use_case(uow_factory, partition, account_id)
+        .with_event_publisher(event_bus)
+}
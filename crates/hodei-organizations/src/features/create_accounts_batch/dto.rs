@@ -0,0 +1,49 @@
+use crate::features::create_account::dto::AccountView;
+use crate::features::create_accounts_batch::error::AccountItemError;
+use kernel::{Hrn, HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Create many accounts under the same parent OU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAccountsBatchCommand {
+    pub parent_hrn: Hrn,
+    /// Account names, in the order results are reported
+    pub names: Vec<String>,
+}
+
+impl TenantScoped for CreateAccountsBatchCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.parent_hrn);
+    }
+}
+
+/// Outcome for one requested account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountResult {
+    pub name: String,
+    pub result: Result<AccountView, AccountItemError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAccountsBatchResponse {
+    pub parent_hrn: Hrn,
+    /// One result per requested name, in request order
+    pub results: Vec<AccountResult>,
+}
+
+impl CreateAccountsBatchResponse {
+    /// The accounts that were created
+    pub fn created(&self) -> impl Iterator<Item = &AccountView> {
+        self.results
+            .iter()
+            .filter_map(|item| item.result.as_ref().ok())
+    }
+
+    /// Number of requested accounts that were not created
+    pub fn failed_count(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|item| item.result.is_err())
+            .count()
+    }
+}
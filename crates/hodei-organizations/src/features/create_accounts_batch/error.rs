@@ -0,0 +1,32 @@
+use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use kernel::CrossTenantAccess;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Errors that fail the whole batch
+#[derive(Debug, Error)]
+pub enum CreateAccountsBatchError {
+    #[error("OU repository error: {0}")]
+    OuRepositoryError(#[from] OuRepositoryError),
+    #[error("Parent OU not found")]
+    ParentOuNotFound,
+    #[error("Transaction error: {0}")]
+    TransactionError(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
+
+/// Why a single account in the batch was not created
+#[derive(Debug, Clone, PartialEq, Eq, Error, Serialize, Deserialize)]
+pub enum AccountItemError {
+    #[error("Invalid account name")]
+    InvalidAccountName,
+    #[error("Account name appears earlier in the batch")]
+    DuplicateInBatch,
+    #[error("An account with this name already exists")]
+    AlreadyExists,
+    #[error("Account repository error: {0}")]
+    RepositoryError(String),
+    #[error("Rolled back with the rest of its transaction: {0}")]
+    RolledBack(String),
+}
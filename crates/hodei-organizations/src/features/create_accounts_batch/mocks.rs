@@ -0,0 +1,234 @@
+use crate::features::create_accounts_batch::error::CreateAccountsBatchError;
+use crate::features::create_accounts_batch::ports::{
+    CreateAccountsBatchUnitOfWork, CreateAccountsBatchUnitOfWorkFactory,
+};
+use crate::internal::application::ports::account_repository::{
+    AccountRepository, AccountRepositoryError,
+};
+use crate::internal::application::ports::ou_repository::{OuRepository, OuRepositoryError};
+use crate::internal::domain::account::Account;
+use crate::internal::domain::ou::OrganizationalUnit;
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// State shared by every UnitOfWork a mock factory creates
+///
+/// Accounts only land in `accounts` when their transaction commits.
+#[derive(Default)]
+pub struct MockBatchStore {
+    accounts: Mutex<HashMap<String, Account>>,
+    ous: Mutex<HashMap<String, OrganizationalUnit>>,
+    failing_name: Mutex<Option<String>>,
+    active_transactions: AtomicUsize,
+    peak_transactions: AtomicUsize,
+    commits: AtomicUsize,
+    rollbacks: AtomicUsize,
+}
+
+impl MockBatchStore {
+    pub fn add_ou(&self, ou: OrganizationalUnit) {
+        self.ous.lock().unwrap().insert(ou.hrn.to_string(), ou);
+    }
+
+    pub fn add_account(&self, account: Account) {
+        self.accounts
+            .lock()
+            .unwrap()
+            .insert(account.hrn.to_string(), account);
+    }
+
+    /// Make saving the account with this name fail
+    pub fn fail_on(&self, name: &str) {
+        *self.failing_name.lock().unwrap() = Some(name.to_string());
+    }
+
+    pub fn committed_accounts(&self) -> Vec<Account> {
+        self.accounts.lock().unwrap().values().cloned().collect()
+    }
+
+    pub fn peak_transactions(&self) -> usize {
+        self.peak_transactions.load(Ordering::SeqCst)
+    }
+
+    pub fn commits(&self) -> usize {
+        self.commits.load(Ordering::SeqCst)
+    }
+
+    pub fn rollbacks(&self) -> usize {
+        self.rollbacks.load(Ordering::SeqCst)
+    }
+
+    fn finish_transaction(&self) {
+        self.active_transactions.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Mock Account Repository writing to its transaction's staging area
+pub struct MockStagedAccountRepository {
+    store: Arc<MockBatchStore>,
+    staged: Arc<Mutex<Vec<Account>>>,
+}
+
+#[async_trait]
+impl AccountRepository for MockStagedAccountRepository {
+    async fn save(&self, account: &Account) -> Result<(), AccountRepositoryError> {
+        // Give the other transactions a chance to run
+        tokio::task::yield_now().await;
+
+        if self.store.failing_name.lock().unwrap().as_deref() == Some(account.name.as_str()) {
+            return Err(AccountRepositoryError::DatabaseError(
+                "Mock failure".to_string(),
+            ));
+        }
+        self.staged.lock().unwrap().push(account.clone());
+        Ok(())
+    }
+
+    async fn find_by_hrn(&self, hrn: &Hrn) -> Result<Option<Account>, AccountRepositoryError> {
+        let committed = self
+            .store
+            .accounts
+            .lock()
+            .unwrap()
+            .get(&hrn.to_string())
+            .cloned();
+        let staged = || {
+            self.staged
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|account| &account.hrn == hrn)
+                .cloned()
+        };
+        Ok(committed.or_else(staged))
+    }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), AccountRepositoryError> {
+        self.staged.lock().unwrap().retain(|account| &account.hrn != hrn);
+        self.store.accounts.lock().unwrap().remove(&hrn.to_string());
+        Ok(())
+    }
+}
+
+/// Mock OU Repository reading the shared store
+pub struct MockBatchOuRepository {
+    store: Arc<MockBatchStore>,
+}
+
+#[async_trait]
+impl OuRepository for MockBatchOuRepository {
+    async fn save(&self, ou: &OrganizationalUnit) -> Result<(), OuRepositoryError> {
+        self.store.add_ou(ou.clone());
+        Ok(())
+    }
+
+    async fn find_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<OrganizationalUnit>, OuRepositoryError> {
+        Ok(self
+            .store
+            .ous
+            .lock()
+            .unwrap()
+            .get(&hrn.to_string())
+            .cloned())
+    }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), OuRepositoryError> {
+        self.store.ous.lock().unwrap().remove(&hrn.to_string());
+        Ok(())
+    }
+}
+
+/// Mock UnitOfWork staging account writes until commit
+pub struct MockCreateAccountsBatchUnitOfWork {
+    store: Arc<MockBatchStore>,
+    staged: Arc<Mutex<Vec<Account>>>,
+    transaction_active: bool,
+}
+
+#[async_trait]
+impl CreateAccountsBatchUnitOfWork for MockCreateAccountsBatchUnitOfWork {
+    async fn begin(&mut self) -> Result<(), CreateAccountsBatchError> {
+        self.transaction_active = true;
+        let active = self
+            .store
+            .active_transactions
+            .fetch_add(1, Ordering::SeqCst)
+            + 1;
+        self.store
+            .peak_transactions
+            .fetch_max(active, Ordering::SeqCst);
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> Result<(), CreateAccountsBatchError> {
+        if !self.transaction_active {
+            return Err(CreateAccountsBatchError::TransactionError(
+                "No transaction in progress".to_string(),
+            ));
+        }
+        self.transaction_active = false;
+        for account in self.staged.lock().unwrap().drain(..) {
+            self.store.add_account(account);
+        }
+        self.store.commits.fetch_add(1, Ordering::SeqCst);
+        self.store.finish_transaction();
+        Ok(())
+    }
+
+    async fn rollback(&mut self) -> Result<(), CreateAccountsBatchError> {
+        if !self.transaction_active {
+            return Err(CreateAccountsBatchError::TransactionError(
+                "No transaction in progress".to_string(),
+            ));
+        }
+        self.transaction_active = false;
+        self.staged.lock().unwrap().clear();
+        self.store.rollbacks.fetch_add(1, Ordering::SeqCst);
+        self.store.finish_transaction();
+        Ok(())
+    }
+
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        Arc::new(MockStagedAccountRepository {
+            store: self.store.clone(),
+            staged: self.staged.clone(),
+        })
+    }
+
+    fn ous(&self) -> Arc<dyn OuRepository> {
+        Arc::new(MockBatchOuRepository {
+            store: self.store.clone(),
+        })
+    }
+}
+
+/// Mock UnitOfWorkFactory for testing
+#[derive(Default)]
+pub struct MockCreateAccountsBatchUnitOfWorkFactory {
+    pub store: Arc<MockBatchStore>,
+}
+
+impl MockCreateAccountsBatchUnitOfWorkFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CreateAccountsBatchUnitOfWorkFactory for MockCreateAccountsBatchUnitOfWorkFactory {
+    type UnitOfWork = MockCreateAccountsBatchUnitOfWork;
+
+    async fn create(&self) -> Result<Self::UnitOfWork, CreateAccountsBatchError> {
+        Ok(MockCreateAccountsBatchUnitOfWork {
+            store: self.store.clone(),
+            staged: Arc::new(Mutex::new(Vec::new())),
+            transaction_active: false,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use crate::features::create_accounts_batch::error::CreateAccountsBatchError;
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Unit of Work trait for CreateAccountsBatch feature
+///
+/// Each chunk of the batch is written within one of these transactions.
+#[async_trait]
+pub trait CreateAccountsBatchUnitOfWork: Send + Sync {
+    /// Begin a new transaction
+    async fn begin(&mut self) -> Result<(), CreateAccountsBatchError>;
+
+    /// Commit the current transaction
+    async fn commit(&mut self) -> Result<(), CreateAccountsBatchError>;
+
+    /// Rollback the current transaction
+    async fn rollback(&mut self) -> Result<(), CreateAccountsBatchError>;
+
+    /// Get account repository for this transaction
+    fn accounts(&self) -> Arc<dyn AccountRepository>;
+
+    /// Get organizational unit repository for this transaction
+    fn ous(&self) -> Arc<dyn OuRepository>;
+}
+
+/// Factory for creating CreateAccountsBatchUnitOfWork instances
+#[async_trait]
+pub trait CreateAccountsBatchUnitOfWorkFactory: Send + Sync {
+    /// Type of UnitOfWork this factory creates
+    type UnitOfWork: CreateAccountsBatchUnitOfWork;
+
+    /// Create a new UnitOfWork instance
+    async fn create(&self) -> Result<Self::UnitOfWork, CreateAccountsBatchError>;
+}
@@ -0,0 +1,103 @@
+//! Adaptador de SurrealDB para el caso de uso CreateAccountsBatch
+//!
+//! Este adaptador conecta la implementación genérica de SurrealUnitOfWork
+//! con el puerto específico CreateAccountsBatchUnitOfWork de la feature.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::features::create_accounts_batch::error::CreateAccountsBatchError;
+use crate::features::create_accounts_batch::ports::{
+    CreateAccountsBatchUnitOfWork, CreateAccountsBatchUnitOfWorkFactory,
+};
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+use crate::internal::infrastructure::surreal::{SurrealUnitOfWork, SurrealUnitOfWorkFactory};
+use kernel::application::ports::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+
+/// Adaptador que envuelve SurrealUnitOfWork para la feature create_accounts_batch
+pub struct CreateAccountsBatchSurrealUnitOfWorkAdapter<C = surrealdb::engine::any::Any>
+where
+    C: surrealdb::Connection,
+{
+    inner: SurrealUnitOfWork<C>,
+}
+
+impl<C> CreateAccountsBatchSurrealUnitOfWorkAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    pub fn new(inner: SurrealUnitOfWork<C>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<C> CreateAccountsBatchUnitOfWork for CreateAccountsBatchSurrealUnitOfWorkAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    async fn begin(&mut self) -> Result<(), CreateAccountsBatchError> {
+        self.inner
+            .begin()
+            .await
+            .map_err(|e| CreateAccountsBatchError::TransactionError(e.to_string()))
+    }
+
+    async fn commit(&mut self) -> Result<(), CreateAccountsBatchError> {
+        self.inner
+            .commit()
+            .await
+            .map_err(|e| CreateAccountsBatchError::TransactionError(e.to_string()))
+    }
+
+    async fn rollback(&mut self) -> Result<(), CreateAccountsBatchError> {
+        self.inner
+            .rollback()
+            .await
+            .map_err(|e| CreateAccountsBatchError::TransactionError(e.to_string()))
+    }
+
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        self.inner.accounts()
+    }
+
+    fn ous(&self) -> Arc<dyn OuRepository> {
+        self.inner.ous()
+    }
+}
+
+/// Factory que crea instancias de CreateAccountsBatchSurrealUnitOfWorkAdapter
+pub struct CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    inner: Arc<SurrealUnitOfWorkFactory<C>>,
+}
+
+impl<C> CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    pub fn new(inner: Arc<SurrealUnitOfWorkFactory<C>>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<C> CreateAccountsBatchUnitOfWorkFactory
+    for CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    type UnitOfWork = CreateAccountsBatchSurrealUnitOfWorkAdapter<C>;
+
+    async fn create(&self) -> Result<Self::UnitOfWork, CreateAccountsBatchError> {
+        let uow = self
+            .inner
+            .create()
+            .await
+            .map_err(|e| CreateAccountsBatchError::TransactionError(e.to_string()))?;
+        Ok(CreateAccountsBatchSurrealUnitOfWorkAdapter::new(uow))
+    }
+}
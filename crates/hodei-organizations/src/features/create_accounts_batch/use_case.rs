@@ -0,0 +1,235 @@
+use crate::features::create_account::dto::AccountView;
+use crate::features::create_accounts_batch::dto::{
+    AccountResult, CreateAccountsBatchCommand, CreateAccountsBatchResponse,
+};
+use crate::features::create_accounts_batch::error::{AccountItemError, CreateAccountsBatchError};
+use crate::features::create_accounts_batch::ports::{
+    CreateAccountsBatchUnitOfWork, CreateAccountsBatchUnitOfWorkFactory,
+};
+use crate::internal::domain::account::Account;
+use crate::internal::domain::events::AccountsBatchCreated;
+use kernel::EventPublisher;
+use kernel::application::ports::event_bus::EventEnvelope;
+use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use kernel::{Hrn, TenantContext};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Accounts written per transaction unless configured otherwise
+pub const DEFAULT_BATCH_SIZE: usize = 50;
+
+/// Outcome of each account in a chunk, by position in the command
+type ChunkOutcome = Vec<(usize, Result<Account, AccountItemError>)>;
+
+/// Use case for creating many accounts under one parent OU
+///
+/// The parent OU is checked once, then the accounts are written in chunks of
+/// `batch_size`, each chunk in its own transaction. Chunks are written one
+/// after another: the transactions of a unit of work are statements on the
+/// database session its factory shares, so overlapping ones would interleave
+/// their `BEGIN` and `COMMIT`. A chunk whose write fails is rolled
+/// back on its own; the other chunks keep their accounts, so the batch as a
+/// whole is not atomic. Invalid, repeated and already taken names are
+/// reported per account and don't affect the rest of their chunk.
+///
+/// A single `AccountsBatchCreated` event is published for the accounts that
+/// were created, instead of one `AccountCreated` each.
+pub struct CreateAccountsBatchUseCase<UWF: CreateAccountsBatchUnitOfWorkFactory> {
+    uow_factory: Arc<UWF>,
+    /// Partition for HRN generation (e.g., "aws", "hodei")
+    partition: String,
+    /// Account identifier for HRN generation (e.g., "default", account_id)
+    account_id: String,
+    batch_size: usize,
+    /// Optional event publisher for domain events
+    event_publisher: Option<Arc<InMemoryEventBus>>,
+}
+
+impl<UWF: CreateAccountsBatchUnitOfWorkFactory> CreateAccountsBatchUseCase<UWF> {
+    pub fn new(uow_factory: Arc<UWF>, partition: String, account_id: String) -> Self {
+        Self {
+            uow_factory,
+            partition,
+            account_id,
+            batch_size: DEFAULT_BATCH_SIZE,
+            event_publisher: None,
+        }
+    }
+
+    /// Accounts written per transaction (at least 1)
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn with_event_publisher(mut self, publisher: Arc<InMemoryEventBus>) -> Self {
+        self.event_publisher = Some(publisher);
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreateAccountsBatchCommand,
+    ) -> Result<CreateAccountsBatchResponse, CreateAccountsBatchError> {
+        // Validate the parent once for the whole batch
+        let uow = self.uow_factory.create().await?;
+        if uow.ous().find_by_hrn(&command.parent_hrn).await?.is_none() {
+            return Err(CreateAccountsBatchError::ParentOuNotFound);
+        }
+
+        let mut outcomes: Vec<Option<Result<Account, AccountItemError>>> =
+            vec![None; command.names.len()];
+        let mut seen = HashSet::new();
+        let mut pending = Vec::new();
+        for (index, name) in command.names.iter().enumerate() {
+            if name.is_empty() {
+                outcomes[index] = Some(Err(AccountItemError::InvalidAccountName));
+            } else if !seen.insert(name.as_str()) {
+                outcomes[index] = Some(Err(AccountItemError::DuplicateInBatch));
+            } else {
+                let account = Account::new(
+                    self.account_hrn(name),
+                    name.clone(),
+                    Some(command.parent_hrn.clone()),
+                );
+                pending.push((index, account));
+            }
+        }
+
+        for chunk in pending.chunks(self.batch_size) {
+            for (index, outcome) in self.create_chunk(chunk).await {
+                outcomes[index] = Some(outcome);
+            }
+        }
+
+        let mut created = Vec::new();
+        let results = command
+            .names
+            .into_iter()
+            .zip(outcomes)
+            .map(|(name, outcome)| {
+                let result = outcome.expect("every name has an outcome").map(|account| {
+                    let view = AccountView {
+                        hrn: account.hrn.clone(),
+                        name: account.name.clone(),
+                        parent_hrn: account.parent_hrn.clone(),
+                    };
+                    created.push(account.hrn);
+                    view
+                });
+                AccountResult { name, result }
+            })
+            .collect();
+
+        if !created.is_empty() {
+            self.publish_batch_created_event(&command.parent_hrn, created)
+                .await;
+        }
+
+        Ok(CreateAccountsBatchResponse {
+            parent_hrn: command.parent_hrn,
+            results,
+        })
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The parent OU must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: CreateAccountsBatchCommand,
+    ) -> Result<CreateAccountsBatchResponse, CreateAccountsBatchError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
+
+    // Format: hrn:partition:organizations:account_id:account/account_name
+    fn account_hrn(&self, name: &str) -> Hrn {
+        Hrn::new(
+            self.partition.clone(),
+            "organizations".to_string(),
+            self.account_id.clone(),
+            "account".to_string(),
+            name.to_string(),
+        )
+    }
+
+    /// Write one chunk in its own transaction
+    async fn create_chunk(&self, chunk: &[(usize, Account)]) -> ChunkOutcome {
+        let mut uow = match self.uow_factory.create().await {
+            Ok(uow) => uow,
+            Err(e) => return rolled_back(chunk, Vec::new(), &e.to_string()),
+        };
+        if let Err(e) = uow.begin().await {
+            return rolled_back(chunk, Vec::new(), &e.to_string());
+        }
+
+        let accounts = uow.accounts();
+        let mut outcomes = Vec::with_capacity(chunk.len());
+        for (index, account) in chunk {
+            let written = match accounts.find_by_hrn(&account.hrn).await {
+                Ok(Some(_)) => {
+                    outcomes.push((*index, Err(AccountItemError::AlreadyExists)));
+                    continue;
+                }
+                Ok(None) => accounts.save(account).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = written {
+                if let Err(rollback_err) = uow.rollback().await {
+                    tracing::error!("Failed to rollback transaction: {}", rollback_err);
+                }
+                outcomes.push((
+                    *index,
+                    Err(AccountItemError::RepositoryError(e.to_string())),
+                ));
+                return rolled_back(chunk, outcomes, &e.to_string());
+            }
+            outcomes.push((*index, Ok(account.clone())));
+        }
+
+        if let Err(e) = uow.commit().await {
+            return rolled_back(chunk, outcomes, &e.to_string());
+        }
+        outcomes
+    }
+
+    async fn publish_batch_created_event(&self, parent_hrn: &Hrn, account_hrns: Vec<Hrn>) {
+        if let Some(publisher) = &self.event_publisher {
+            let event = AccountsBatchCreated {
+                parent_hrn: parent_hrn.clone(),
+                account_hrns,
+                created_at: chrono::Utc::now(),
+            };
+
+            let envelope = EventEnvelope::new(event)
+                .with_metadata("aggregate_type".to_string(), "Account".to_string());
+
+            if let Err(e) = publisher.publish_with_envelope(envelope).await {
+                tracing::warn!("Failed to publish AccountsBatchCreated event: {}", e);
+            }
+        }
+    }
+}
+
+/// Outcomes for a chunk whose transaction did not commit
+///
+/// Accounts written before the failure, and those never reached, become
+/// `RolledBack`; per-account errors already recorded are kept.
+fn rolled_back(chunk: &[(usize, Account)], outcomes: ChunkOutcome, reason: &str) -> ChunkOutcome {
+    let mut outcomes: ChunkOutcome = outcomes
+        .into_iter()
+        .map(|(index, outcome)| {
+            let outcome = outcome.and(Err(AccountItemError::RolledBack(reason.to_string())));
+            (index, outcome)
+        })
+        .collect();
+    let reached = outcomes.len();
+    outcomes.extend(chunk[reached..].iter().map(|(index, _)| {
+        (
+            *index,
+            Err(AccountItemError::RolledBack(reason.to_string())),
+        )
+    }));
+    outcomes
+}
@@ -0,0 +1,248 @@
+use crate::features::create_accounts_batch::dto::CreateAccountsBatchCommand;
+use crate::features::create_accounts_batch::error::{AccountItemError, CreateAccountsBatchError};
+use crate::features::create_accounts_batch::mocks::MockCreateAccountsBatchUnitOfWorkFactory;
+use crate::features::create_accounts_batch::use_case::CreateAccountsBatchUseCase;
+use crate::internal::domain::account::Account;
+use crate::internal::domain::ou::OrganizationalUnit;
+use kernel::application::ports::event_store::EventStore;
+use kernel::{Hrn, InMemoryEventBus, InMemoryEventStore};
+use std::sync::Arc;
+
+fn root_hrn() -> Hrn {
+    Hrn::new(
+        "aws".to_string(),
+        "organizations".to_string(),
+        "123456789012".to_string(),
+        "ou".to_string(),
+        "root".to_string(),
+    )
+}
+
+/// A factory whose store holds the parent OU, and the parent's HRN
+fn factory_with_parent() -> (Arc<MockCreateAccountsBatchUnitOfWorkFactory>, Hrn) {
+    let factory = Arc::new(MockCreateAccountsBatchUnitOfWorkFactory::new());
+    let ou = OrganizationalUnit::new("Workloads".to_string(), root_hrn());
+    let parent_hrn = ou.hrn.clone();
+    factory.store.add_ou(ou);
+    (factory, parent_hrn)
+}
+
+fn use_case(
+    factory: Arc<MockCreateAccountsBatchUnitOfWorkFactory>,
+) -> CreateAccountsBatchUseCase<MockCreateAccountsBatchUnitOfWorkFactory> {
+    CreateAccountsBatchUseCase::new(factory, "aws".to_string(), "123456789012".to_string())
+}
+
+fn command(parent_hrn: &Hrn, names: &[&str]) -> CreateAccountsBatchCommand {
+    CreateAccountsBatchCommand {
+        parent_hrn: parent_hrn.clone(),
+        names: names.iter().map(|name| name.to_string()).collect(),
+    }
+}
+
+fn names(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("env-{i}")).collect()
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_success() {
+    // Arrange
+    let (factory, parent_hrn) = factory_with_parent();
+    let use_case = use_case(factory.clone());
+
+    // Act
+    let response = use_case
+        .execute(command(&parent_hrn, &["dev", "staging", "prod"]))
+        .await
+        .unwrap();
+
+    // Assert - One result per name, in request order
+    let names: Vec<_> = response.results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["dev", "staging", "prod"]);
+    assert_eq!(response.failed_count(), 0);
+    for view in response.created() {
+        assert_eq!(view.parent_hrn, Some(parent_hrn.clone()));
+        assert!(view.hrn.to_string().contains(&view.name));
+    }
+    assert_eq!(factory.store.committed_accounts().len(), 3);
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_parent_not_found() {
+    // Arrange - No OU in the store
+    let factory = Arc::new(MockCreateAccountsBatchUnitOfWorkFactory::new());
+    let use_case = use_case(factory.clone());
+
+    // Act
+    let result = use_case.execute(command(&root_hrn(), &["dev"])).await;
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(CreateAccountsBatchError::ParentOuNotFound)
+    ));
+    assert!(factory.store.committed_accounts().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_reports_invalid_and_duplicate_names_per_item() {
+    // Arrange
+    let (factory, parent_hrn) = factory_with_parent();
+    let use_case = use_case(factory.clone());
+
+    // Act
+    let response = use_case
+        .execute(command(&parent_hrn, &["dev", "", "dev", "prod"]))
+        .await
+        .unwrap();
+
+    // Assert - The first "dev" is created, the repeat is not
+    assert!(response.results[0].result.is_ok());
+    assert_eq!(
+        response.results[1].result.as_ref().unwrap_err(),
+        &AccountItemError::InvalidAccountName
+    );
+    assert_eq!(
+        response.results[2].result.as_ref().unwrap_err(),
+        &AccountItemError::DuplicateInBatch
+    );
+    assert!(response.results[3].result.is_ok());
+    assert_eq!(factory.store.committed_accounts().len(), 2);
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_existing_account_is_per_item_error() {
+    // Arrange
+    let (factory, parent_hrn) = factory_with_parent();
+    let existing = Hrn::new(
+        "aws".to_string(),
+        "organizations".to_string(),
+        "123456789012".to_string(),
+        "account".to_string(),
+        "prod".to_string(),
+    );
+    factory.store.add_account(Account::new(
+        existing,
+        "prod".to_string(),
+        Some(parent_hrn.clone()),
+    ));
+    let use_case = use_case(factory.clone());
+
+    // Act
+    let response = use_case
+        .execute(command(&parent_hrn, &["dev", "prod", "qa"]))
+        .await
+        .unwrap();
+
+    // Assert - The chunk still commits the other accounts
+    assert_eq!(
+        response.results[1].result.as_ref().unwrap_err(),
+        &AccountItemError::AlreadyExists
+    );
+    assert_eq!(response.created().count(), 2);
+    assert_eq!(factory.store.committed_accounts().len(), 3);
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_commits_one_transaction_per_chunk() {
+    // Arrange
+    let (factory, parent_hrn) = factory_with_parent();
+    let use_case = use_case(factory.clone()).with_batch_size(3);
+
+    // Act
+    let response = use_case
+        .execute(CreateAccountsBatchCommand {
+            parent_hrn,
+            names: names(10),
+        })
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.created().count(), 10);
+    assert_eq!(factory.store.commits(), 4);
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_failure_rolls_back_only_its_chunk() {
+    // Arrange
+    let (factory, parent_hrn) = factory_with_parent();
+    factory.store.fail_on("c");
+    let use_case = use_case(factory.clone()).with_batch_size(2);
+
+    // Act
+    let response = use_case
+        .execute(command(&parent_hrn, &["a", "b", "c", "d", "e"]))
+        .await
+        .unwrap();
+
+    // Assert - "c" fails, "d" goes down with its chunk, the rest commit
+    assert!(response.results[0].result.is_ok());
+    assert!(response.results[1].result.is_ok());
+    assert!(matches!(
+        response.results[2].result,
+        Err(AccountItemError::RepositoryError(_))
+    ));
+    assert!(matches!(
+        response.results[3].result,
+        Err(AccountItemError::RolledBack(_))
+    ));
+    assert!(response.results[4].result.is_ok());
+
+    let mut committed: Vec<_> = factory
+        .store
+        .committed_accounts()
+        .into_iter()
+        .map(|account| account.name)
+        .collect();
+    committed.sort();
+    assert_eq!(committed, ["a", "b", "e"]);
+    assert_eq!(factory.store.rollbacks(), 1);
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_runs_one_transaction_at_a_time() {
+    // Arrange
+    let (factory, parent_hrn) = factory_with_parent();
+    let use_case = use_case(factory.clone()).with_batch_size(2);
+
+    // Act
+    let response = use_case
+        .execute(CreateAccountsBatchCommand {
+            parent_hrn,
+            names: names(20),
+        })
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(response.created().count(), 20);
+    assert_eq!(factory.store.commits(), 10);
+    assert_eq!(factory.store.peak_transactions(), 1);
+}
+
+#[tokio::test]
+async fn test_create_accounts_batch_publishes_single_event() {
+    // Arrange
+    let (factory, parent_hrn) = factory_with_parent();
+    let store = Arc::new(InMemoryEventStore::new());
+    let bus = Arc::new(InMemoryEventBus::new().with_event_store(store.clone()));
+    let use_case = use_case(factory)
+        .with_batch_size(2)
+        .with_event_publisher(bus);
+
+    // Act
+    use_case
+        .execute(CreateAccountsBatchCommand {
+            parent_hrn: parent_hrn.clone(),
+            names: names(5),
+        })
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(store.count().await.unwrap(), 1);
+    let events = store.read_batch(0, 10).await.unwrap();
+    assert_eq!(events[0].event_type, "organizations.account.batch_created");
+    assert_eq!(events[0].aggregate_id, Some(parent_hrn.to_string()));
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use kernel::Hrn;
+use kernel::{Hrn, HrnVisitor, TenantScoped};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateOuCommand {
@@ -7,6 +7,12 @@ pub struct CreateOuCommand {
     pub parent_hrn: Hrn,
 }
 
+impl TenantScoped for CreateOuCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.parent_hrn);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OuView {
     pub hrn: Hrn,
@@ -1,4 +1,5 @@
 use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use kernel::CrossTenantAccess;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -9,4 +10,6 @@ pub enum CreateOuError {
     InvalidOuName,
     #[error("Transaction error: {0}")]
     TransactionError(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
@@ -86,6 +86,14 @@ impl OuRepository for MockOuRepository {
         let ous = self.ous.lock().unwrap();
         Ok(ous.get(&hrn.to_string()).cloned())
     }
+
+    async fn delete(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<(), crate::internal::application::ports::ou_repository::OuRepositoryError> {
+        self.ous.lock().unwrap().remove(&hrn.to_string());
+        Ok(())
+    }
 }
 
 /// Mock UnitOfWork for testing transactional behavior
@@ -2,6 +2,7 @@ use crate::features::create_ou::dto::{CreateOuCommand, OuView};
 use crate::features::create_ou::error::CreateOuError;
 use crate::features::create_ou::ports::{CreateOuUnitOfWork, CreateOuUnitOfWorkFactory};
 use crate::internal::domain::ou::OrganizationalUnit;
+use kernel::TenantContext;
 use std::sync::Arc;
 
 /// Use case for creating organizational units with transactional guarantees
@@ -43,6 +44,17 @@ impl<UWF: CreateOuUnitOfWorkFactory> CreateOuUseCase<UWF> {
         }
     }
 
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The parent OU must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: CreateOuCommand,
+    ) -> Result<OuView, CreateOuError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
+
     async fn execute_within_transaction(
         &self,
         command: &CreateOuCommand,
@@ -1,5 +1,5 @@
 use crate::internal::domain::ServiceControlPolicy;
-use kernel::Hrn;
+use kernel::{Hrn, HrnVisitor, TenantScoped};
 use serde::{Deserialize, Serialize};
 
 /// Command to create a new Service Control Policy
@@ -13,6 +13,12 @@ pub struct CreateScpCommand {
     pub hrn: Hrn,
 }
 
+impl TenantScoped for CreateScpCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.hrn);
+    }
+}
+
 /// Command to delete an existing Service Control Policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeleteScpCommand {
@@ -20,6 +26,12 @@ pub struct DeleteScpCommand {
     pub hrn: Hrn,
 }
 
+impl TenantScoped for DeleteScpCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.hrn);
+    }
+}
+
 /// Command to update an existing Service Control Policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateScpCommand {
@@ -31,6 +43,12 @@ pub struct UpdateScpCommand {
     pub document: Option<String>,
 }
 
+impl TenantScoped for UpdateScpCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.hrn);
+    }
+}
+
 /// Query to get a specific Service Control Policy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetScpQuery {
@@ -38,6 +56,12 @@ pub struct GetScpQuery {
     pub hrn: Hrn,
 }
 
+impl TenantScoped for GetScpQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.hrn);
+    }
+}
+
 /// Query to list Service Control Policies
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListScpsQuery {
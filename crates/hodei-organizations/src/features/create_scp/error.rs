@@ -1,3 +1,4 @@
+use kernel::CrossTenantAccess;
 use thiserror::Error;
 
 /// Error type for SCP creation operations
@@ -13,6 +14,8 @@ pub enum CreateScpError {
     InvalidHrn(String),
     #[error("Validation error: {0}")]
     ValidationError(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
 
 /// Error type for SCP deletion operations
@@ -24,6 +27,8 @@ pub enum DeleteScpError {
     StorageError(String),
     #[error("SCP is currently attached and cannot be deleted")]
     ScpAttached,
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
 
 /// Error type for SCP update operations
@@ -39,6 +44,8 @@ pub enum UpdateScpError {
     NoUpdatesProvided,
     #[error("Validation error: {0}")]
     ValidationError(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
 
 /// Error type for SCP retrieval operations
@@ -50,6 +57,8 @@ pub enum GetScpError {
     StorageError(String),
     #[error("Invalid HRN format: {0}")]
     InvalidHrn(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
 
 /// Error type for SCP listing operations
@@ -5,6 +5,7 @@ use crate::features::create_scp::error::{
     CreateScpError, DeleteScpError, GetScpError, ListScpsError, UpdateScpError,
 };
 use crate::features::create_scp::ports::ScpPersister;
+use kernel::TenantContext;
 use tracing::instrument;
 
 /// Use case for creating a new Service Control Policy
@@ -42,6 +43,17 @@ impl<P: ScpPersister> CreateScpUseCase<P> {
         // Delegate persistence to adapter
         self.persister.create_scp(command).await
     }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The SCP must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: CreateScpCommand,
+    ) -> Result<ScpDto, CreateScpError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
 }
 
 /// Use case for deleting an existing Service Control Policy
@@ -58,6 +70,17 @@ impl<P: ScpPersister> DeleteScpUseCase<P> {
     pub async fn execute(&self, command: DeleteScpCommand) -> Result<(), DeleteScpError> {
         self.persister.delete_scp(command).await
     }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The SCP must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: DeleteScpCommand,
+    ) -> Result<(), DeleteScpError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
 }
 
 /// Use case for updating an existing Service Control Policy
@@ -102,6 +125,17 @@ impl<P: ScpPersister> UpdateScpUseCase<P> {
 
         self.persister.update_scp(command).await
     }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The SCP must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: UpdateScpCommand,
+    ) -> Result<ScpDto, UpdateScpError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
 }
 
 /// Use case for retrieving a specific Service Control Policy
@@ -118,6 +152,17 @@ impl<P: ScpPersister> GetScpUseCase<P> {
     pub async fn execute(&self, query: GetScpQuery) -> Result<ScpDto, GetScpError> {
         self.persister.get_scp(query).await
     }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The SCP must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: GetScpQuery,
+    ) -> Result<ScpDto, GetScpError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
 }
 
 /// Use case for listing Service Control Policies
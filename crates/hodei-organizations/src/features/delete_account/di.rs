@@ -0,0 +1,27 @@
+use crate::features::delete_account::surreal_adapter::DeleteAccountSurrealUnitOfWorkFactoryAdapter;
+use crate::features::delete_account::use_case::DeleteAccountUseCase;
+use crate::internal::infrastructure::surreal::SurrealUnitOfWorkFactory;
+use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use std::sync::Arc;
+
+/// Create an instance of the DeleteAccountUseCase with SurrealDB UoW
+pub fn delete_account_use_case<C>(
+    uow_factory: Arc<SurrealUnitOfWorkFactory<C>>,
+) -> DeleteAccountUseCase<DeleteAccountSurrealUnitOfWorkFactoryAdapter<C>>
+where
+    C: surrealdb::Connection,
+{
+    let factory_adapter = DeleteAccountSurrealUnitOfWorkFactoryAdapter::new(uow_factory);
+    DeleteAccountUseCase::new(Arc::new(factory_adapter))
+}
+
+/// Create an instance of the DeleteAccountUseCase with event bus integration
+pub fn delete_account_use_case_with_events<C>(
+    uow_factory: Arc<SurrealUnitOfWorkFactory<C>>,
+    event_bus: Arc<InMemoryEventBus>,
+) -> DeleteAccountUseCase<DeleteAccountSurrealUnitOfWorkFactoryAdapter<C>>
+where
+    C: surrealdb::Connection,
+{
+    delete_account_use_case(uow_factory).with_event_publisher(event_bus)
+}
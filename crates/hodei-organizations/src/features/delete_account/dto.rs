@@ -0,0 +1,22 @@
+use kernel::{Hrn, HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteAccountCommand {
+    pub account_hrn: Hrn,
+}
+
+impl TenantScoped for DeleteAccountCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.account_hrn);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedAccountView {
+    pub hrn: Hrn,
+    pub name: String,
+    pub parent_hrn: Option<Hrn>,
+    /// SCPs that were attached to the account, sorted by HRN
+    pub detached_scps: Vec<Hrn>,
+}
@@ -0,0 +1,18 @@
+use crate::internal::application::ports::account_repository::AccountRepositoryError;
+use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeleteAccountError {
+    #[error("Account repository error: {0}")]
+    AccountRepositoryError(#[from] AccountRepositoryError),
+    #[error("OU repository error: {0}")]
+    OuRepositoryError(#[from] OuRepositoryError),
+    #[error("Account not found")]
+    AccountNotFound,
+    #[error("Transaction error: {0}")]
+    TransactionError(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
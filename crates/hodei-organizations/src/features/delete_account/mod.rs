@@ -0,0 +1,10 @@
+pub mod di;
+pub mod dto;
+pub mod error;
+#[cfg(test)]
+pub mod mocks;
+pub mod ports;
+pub mod surreal_adapter;
+pub mod use_case;
+#[cfg(test)]
+pub mod use_case_test;
@@ -0,0 +1,37 @@
+use crate::features::delete_account::error::DeleteAccountError;
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Unit of Work trait for DeleteAccount feature
+///
+/// Removing the account and unlinking it from its parent OU happen in one
+/// transaction.
+#[async_trait]
+pub trait DeleteAccountUnitOfWork: Send + Sync {
+    /// Begin a new transaction
+    async fn begin(&mut self) -> Result<(), DeleteAccountError>;
+
+    /// Commit the current transaction
+    async fn commit(&mut self) -> Result<(), DeleteAccountError>;
+
+    /// Rollback the current transaction
+    async fn rollback(&mut self) -> Result<(), DeleteAccountError>;
+
+    /// Get account repository for this transaction
+    fn accounts(&self) -> Arc<dyn AccountRepository>;
+
+    /// Get organizational unit repository for this transaction
+    fn ous(&self) -> Arc<dyn OuRepository>;
+}
+
+/// Factory for creating DeleteAccountUnitOfWork instances
+#[async_trait]
+pub trait DeleteAccountUnitOfWorkFactory: Send + Sync {
+    /// Type of UnitOfWork this factory creates
+    type UnitOfWork: DeleteAccountUnitOfWork;
+
+    /// Create a new UnitOfWork instance
+    async fn create(&self) -> Result<Self::UnitOfWork, DeleteAccountError>;
+}
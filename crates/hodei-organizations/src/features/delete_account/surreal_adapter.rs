@@ -0,0 +1,102 @@
+//! Adaptador de SurrealDB para el caso de uso DeleteAccount
+//!
+//! Este adaptador conecta la implementación genérica de SurrealUnitOfWork
+//! con el puerto específico DeleteAccountUnitOfWork de la feature.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::features::delete_account::error::DeleteAccountError;
+use crate::features::delete_account::ports::{
+    DeleteAccountUnitOfWork, DeleteAccountUnitOfWorkFactory,
+};
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+use crate::internal::infrastructure::surreal::{SurrealUnitOfWork, SurrealUnitOfWorkFactory};
+use kernel::application::ports::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+
+/// Adaptador que envuelve SurrealUnitOfWork para la feature delete_account
+pub struct DeleteAccountSurrealUnitOfWorkAdapter<C = surrealdb::engine::any::Any>
+where
+    C: surrealdb::Connection,
+{
+    inner: SurrealUnitOfWork<C>,
+}
+
+impl<C> DeleteAccountSurrealUnitOfWorkAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    pub fn new(inner: SurrealUnitOfWork<C>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<C> DeleteAccountUnitOfWork for DeleteAccountSurrealUnitOfWorkAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    async fn begin(&mut self) -> Result<(), DeleteAccountError> {
+        self.inner
+            .begin()
+            .await
+            .map_err(|e| DeleteAccountError::TransactionError(e.to_string()))
+    }
+
+    async fn commit(&mut self) -> Result<(), DeleteAccountError> {
+        self.inner
+            .commit()
+            .await
+            .map_err(|e| DeleteAccountError::TransactionError(e.to_string()))
+    }
+
+    async fn rollback(&mut self) -> Result<(), DeleteAccountError> {
+        self.inner
+            .rollback()
+            .await
+            .map_err(|e| DeleteAccountError::TransactionError(e.to_string()))
+    }
+
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        self.inner.accounts()
+    }
+
+    fn ous(&self) -> Arc<dyn OuRepository> {
+        self.inner.ous()
+    }
+}
+
+/// Factory que crea instancias de DeleteAccountSurrealUnitOfWorkAdapter
+pub struct DeleteAccountSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    inner: Arc<SurrealUnitOfWorkFactory<C>>,
+}
+
+impl<C> DeleteAccountSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    pub fn new(inner: Arc<SurrealUnitOfWorkFactory<C>>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<C> DeleteAccountUnitOfWorkFactory for DeleteAccountSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    type UnitOfWork = DeleteAccountSurrealUnitOfWorkAdapter<C>;
+
+    async fn create(&self) -> Result<Self::UnitOfWork, DeleteAccountError> {
+        let uow = self
+            .inner
+            .create()
+            .await
+            .map_err(|e| DeleteAccountError::TransactionError(e.to_string()))?;
+        Ok(DeleteAccountSurrealUnitOfWorkAdapter::new(uow))
+    }
+}
@@ -0,0 +1,133 @@
+use crate::features::delete_account::dto::{DeleteAccountCommand, DeletedAccountView};
+use crate::features::delete_account::error::DeleteAccountError;
+use crate::features::delete_account::ports::{
+    DeleteAccountUnitOfWork, DeleteAccountUnitOfWorkFactory,
+};
+use crate::internal::domain::account::Account;
+use crate::internal::domain::events::{AccountDeleted, ScpDetached, ScpTargetType};
+use kernel::EventPublisher;
+use kernel::application::ports::event_bus::EventEnvelope;
+use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use kernel::{Hrn, TenantContext};
+use std::sync::Arc;
+
+/// Use case for deleting an account
+///
+/// The account is removed from its parent OU and deleted in one transaction.
+/// Its SCP attachments go with it; an `ScpDetached` event is published for
+/// each, followed by `AccountDeleted`, after the commit.
+pub struct DeleteAccountUseCase<UWF: DeleteAccountUnitOfWorkFactory> {
+    uow_factory: Arc<UWF>,
+    /// Optional event publisher for domain events
+    event_publisher: Option<Arc<InMemoryEventBus>>,
+}
+
+impl<UWF: DeleteAccountUnitOfWorkFactory> DeleteAccountUseCase<UWF> {
+    pub fn new(uow_factory: Arc<UWF>) -> Self {
+        Self {
+            uow_factory,
+            event_publisher: None,
+        }
+    }
+
+    pub fn with_event_publisher(mut self, publisher: Arc<InMemoryEventBus>) -> Self {
+        self.event_publisher = Some(publisher);
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        command: DeleteAccountCommand,
+    ) -> Result<DeletedAccountView, DeleteAccountError> {
+        let mut uow = self.uow_factory.create().await?;
+        uow.begin().await?;
+
+        match self.execute_within_transaction(&command, &mut uow).await {
+            Ok(account) => {
+                uow.commit().await?;
+
+                let mut detached_scps: Vec<Hrn> = account.attached_scps.into_iter().collect();
+                detached_scps.sort_by_key(|hrn| hrn.to_string());
+                self.publish_events(&account.hrn, &detached_scps).await;
+
+                Ok(DeletedAccountView {
+                    hrn: account.hrn,
+                    name: account.name,
+                    parent_hrn: account.parent_hrn,
+                    detached_scps,
+                })
+            }
+            Err(e) => {
+                if let Err(rollback_err) = uow.rollback().await {
+                    tracing::error!("Failed to rollback transaction: {}", rollback_err);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The account must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: DeleteAccountCommand,
+    ) -> Result<DeletedAccountView, DeleteAccountError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
+
+    async fn execute_within_transaction(
+        &self,
+        command: &DeleteAccountCommand,
+        uow: &mut UWF::UnitOfWork,
+    ) -> Result<Account, DeleteAccountError> {
+        let accounts = uow.accounts();
+        let account = accounts
+            .find_by_hrn(&command.account_hrn)
+            .await?
+            .ok_or(DeleteAccountError::AccountNotFound)?;
+
+        // Unlink from the parent OU, if it still exists
+        if let Some(parent_hrn) = &account.parent_hrn {
+            let ous = uow.ous();
+            if let Some(mut parent) = ous.find_by_hrn(parent_hrn).await? {
+                parent.remove_child_account(&account.hrn);
+                ous.save(&parent).await?;
+            }
+        }
+
+        accounts.delete(&account.hrn).await?;
+        Ok(account)
+    }
+
+    async fn publish_events(&self, account_hrn: &Hrn, detached_scps: &[Hrn]) {
+        let Some(publisher) = &self.event_publisher else {
+            return;
+        };
+
+        for scp_hrn in detached_scps {
+            let event = ScpDetached {
+                scp_hrn: scp_hrn.clone(),
+                target_hrn: account_hrn.clone(),
+                target_type: ScpTargetType::Account,
+                detached_at: chrono::Utc::now(),
+            };
+            let envelope = EventEnvelope::new(event)
+                .with_metadata("aggregate_type".to_string(), "Scp".to_string());
+            if let Err(e) = publisher.publish_with_envelope(envelope).await {
+                tracing::warn!("Failed to publish ScpDetached event: {}", e);
+            }
+        }
+
+        let event = AccountDeleted {
+            account_hrn: account_hrn.clone(),
+            deleted_at: chrono::Utc::now(),
+        };
+        let envelope = EventEnvelope::new(event)
+            .with_metadata("aggregate_type".to_string(), "Account".to_string());
+        if let Err(e) = publisher.publish_with_envelope(envelope).await {
+            tracing::warn!("Failed to publish AccountDeleted event: {}", e);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::features::delete_account::dto::DeleteAccountCommand;
+use crate::features::delete_account::error::DeleteAccountError;
+use crate::features::delete_account::mocks::MockDeleteAccountUnitOfWorkFactory;
+use crate::features::delete_account::use_case::DeleteAccountUseCase;
+use crate::internal::domain::account::Account;
+use crate::internal::domain::ou::OrganizationalUnit;
+use kernel::application::ports::event_store::EventStore;
+use kernel::{Hrn, InMemoryEventBus, InMemoryEventStore};
+use std::sync::Arc;
+
+fn hrn(resource_type: &str, id: &str) -> Hrn {
+    Hrn::new(
+        "aws".to_string(),
+        "organizations".to_string(),
+        "123456789012".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+}
+
+/// A store with an OU holding one account that has two SCPs attached
+fn factory_with_account() -> (Arc<MockDeleteAccountUnitOfWorkFactory>, Hrn, Hrn) {
+    let factory = Arc::new(MockDeleteAccountUnitOfWorkFactory::new());
+    let ou_hrn = hrn("ou", "workloads");
+    let account_hrn = hrn("account", "prod");
+
+    let mut ou = OrganizationalUnit::new("workloads".to_string(), hrn("ou", "root"));
+    ou.hrn = ou_hrn.clone();
+    ou.add_child_account(account_hrn.clone());
+    factory.store.add_ou(ou);
+
+    let mut account = Account::new(
+        account_hrn.clone(),
+        "prod".to_string(),
+        Some(ou_hrn.clone()),
+    );
+    account.attach_scp(hrn("scp", "deny-regions"));
+    account.attach_scp(hrn("scp", "audit"));
+    factory.store.add_account(account);
+
+    (factory, ou_hrn, account_hrn)
+}
+
+#[tokio::test]
+async fn test_delete_account_success() {
+    // Arrange
+    let (factory, ou_hrn, account_hrn) = factory_with_account();
+    let use_case = DeleteAccountUseCase::new(factory.clone());
+
+    // Act
+    let view = use_case
+        .execute(DeleteAccountCommand {
+            account_hrn: account_hrn.clone(),
+        })
+        .await
+        .unwrap();
+
+    // Assert - Gone, unlinked from its OU, SCPs reported as detached
+    assert_eq!(view.name, "prod");
+    assert_eq!(
+        view.detached_scps,
+        vec![hrn("scp", "audit"), hrn("scp", "deny-regions")]
+    );
+    assert!(factory.store.account(&account_hrn).is_none());
+    assert!(factory.store.ou(&ou_hrn).unwrap().child_accounts.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_account_not_found() {
+    // Arrange
+    let factory = Arc::new(MockDeleteAccountUnitOfWorkFactory::new());
+    let use_case = DeleteAccountUseCase::new(factory);
+
+    // Act
+    let result = use_case
+        .execute(DeleteAccountCommand {
+            account_hrn: hrn("account", "missing"),
+        })
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(DeleteAccountError::AccountNotFound)));
+}
+
+#[tokio::test]
+async fn test_delete_account_rolls_back_on_failure() {
+    // Arrange
+    let (factory, ou_hrn, account_hrn) = factory_with_account();
+    factory.store.fail_deletes();
+    let use_case = DeleteAccountUseCase::new(factory.clone());
+
+    // Act
+    let result = use_case
+        .execute(DeleteAccountCommand {
+            account_hrn: account_hrn.clone(),
+        })
+        .await;
+
+    // Assert - The parent OU still lists the account
+    assert!(matches!(
+        result,
+        Err(DeleteAccountError::AccountRepositoryError(_))
+    ));
+    assert!(factory.store.account(&account_hrn).is_some());
+    assert!(
+        factory
+            .store
+            .ou(&ou_hrn)
+            .unwrap()
+            .child_accounts
+            .contains(&account_hrn)
+    );
+}
+
+#[tokio::test]
+async fn test_delete_account_publishes_detach_and_delete_events() {
+    // Arrange
+    let (factory, _, account_hrn) = factory_with_account();
+    let events = Arc::new(InMemoryEventStore::new());
+    let bus = Arc::new(InMemoryEventBus::new().with_event_store(events.clone()));
+    let use_case = DeleteAccountUseCase::new(factory).with_event_publisher(bus);
+
+    // Act
+    use_case
+        .execute(DeleteAccountCommand { account_hrn })
+        .await
+        .unwrap();
+
+    // Assert
+    let types: Vec<_> = events
+        .read_batch(0, 10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|event| event.event_type)
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "organizations.scp.detached",
+            "organizations.scp.detached",
+            "organizations.account.deleted"
+        ]
+    );
+}
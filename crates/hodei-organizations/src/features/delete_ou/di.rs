@@ -0,0 +1,27 @@
+use crate::features::delete_ou::surreal_adapter::DeleteOuSurrealUnitOfWorkFactoryAdapter;
+use crate::features::delete_ou::use_case::DeleteOuUseCase;
+use crate::internal::infrastructure::surreal::SurrealUnitOfWorkFactory;
+use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use std::sync::Arc;
+
+/// Create an instance of the DeleteOuUseCase with SurrealDB UoW
+pub fn delete_ou_use_case<C>(
+    uow_factory: Arc<SurrealUnitOfWorkFactory<C>>,
+) -> DeleteOuUseCase<DeleteOuSurrealUnitOfWorkFactoryAdapter<C>>
+where
+    C: surrealdb::Connection,
+{
+    let factory_adapter = DeleteOuSurrealUnitOfWorkFactoryAdapter::new(uow_factory);
+    DeleteOuUseCase::new(Arc::new(factory_adapter))
+}
+
+/// Create an instance of the DeleteOuUseCase with event bus integration
+pub fn delete_ou_use_case_with_events<C>(
+    uow_factory: Arc<SurrealUnitOfWorkFactory<C>>,
+    event_bus: Arc<InMemoryEventBus>,
+) -> DeleteOuUseCase<DeleteOuSurrealUnitOfWorkFactoryAdapter<C>>
+where
+    C: surrealdb::Connection,
+{
+    delete_ou_use_case(uow_factory).with_event_publisher(event_bus)
+}
@@ -0,0 +1,32 @@
+use kernel::{Hrn, HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteOuCommand {
+    pub ou_hrn: Hrn,
+    /// Also delete the OU's child OUs and accounts, recursively
+    #[serde(default)]
+    pub force: bool,
+}
+
+impl TenantScoped for DeleteOuCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.ou_hrn);
+    }
+}
+
+/// An SCP attachment removed along with its target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetachedScp {
+    pub scp_hrn: Hrn,
+    pub target_hrn: Hrn,
+}
+
+/// What a deletion removed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeleteOuSummary {
+    /// Deleted OUs, children before their parents; the requested OU is last
+    pub deleted_ous: Vec<Hrn>,
+    pub deleted_accounts: Vec<Hrn>,
+    pub detached_scps: Vec<DetachedScp>,
+}
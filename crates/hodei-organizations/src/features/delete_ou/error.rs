@@ -0,0 +1,27 @@
+use crate::internal::application::ports::account_repository::AccountRepositoryError;
+use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeleteOuError {
+    #[error("Account repository error: {0}")]
+    AccountRepositoryError(#[from] AccountRepositoryError),
+    #[error("OU repository error: {0}")]
+    OuRepositoryError(#[from] OuRepositoryError),
+    #[error("Organizational Unit not found")]
+    OuNotFound,
+    #[error("The root Organizational Unit cannot be deleted")]
+    RootOu,
+    #[error(
+        "Organizational Unit is not empty ({child_ous} child OUs, {child_accounts} accounts); use force to delete them too"
+    )]
+    NotEmpty {
+        child_ous: usize,
+        child_accounts: usize,
+    },
+    #[error("Transaction error: {0}")]
+    TransactionError(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
@@ -0,0 +1,186 @@
+use crate::features::delete_ou::error::DeleteOuError;
+use crate::features::delete_ou::ports::{DeleteOuUnitOfWork, DeleteOuUnitOfWorkFactory};
+use crate::internal::application::ports::account_repository::{
+    AccountRepository, AccountRepositoryError,
+};
+use crate::internal::application::ports::ou_repository::{OuRepository, OuRepositoryError};
+use crate::internal::domain::account::Account;
+use crate::internal::domain::ou::OrganizationalUnit;
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+struct State {
+    accounts: HashMap<String, Account>,
+    ous: HashMap<String, OrganizationalUnit>,
+}
+
+/// Store shared by every UnitOfWork a mock factory creates
+///
+/// Beginning a transaction snapshots the store; rolling back restores it.
+#[derive(Default)]
+pub struct MockDeleteOuStore {
+    state: Mutex<State>,
+    snapshot: Mutex<Option<State>>,
+    fail_on_delete: Mutex<bool>,
+}
+
+impl MockDeleteOuStore {
+    pub fn add_account(&self, account: Account) {
+        let mut state = self.state.lock().unwrap();
+        state.accounts.insert(account.hrn.to_string(), account);
+    }
+
+    pub fn add_ou(&self, ou: OrganizationalUnit) {
+        let mut state = self.state.lock().unwrap();
+        state.ous.insert(ou.hrn.to_string(), ou);
+    }
+
+    pub fn account(&self, hrn: &Hrn) -> Option<Account> {
+        self.state
+            .lock()
+            .unwrap()
+            .accounts
+            .get(&hrn.to_string())
+            .cloned()
+    }
+
+    pub fn ou(&self, hrn: &Hrn) -> Option<OrganizationalUnit> {
+        self.state
+            .lock()
+            .unwrap()
+            .ous
+            .get(&hrn.to_string())
+            .cloned()
+    }
+
+    /// Make every account deletion fail
+    pub fn fail_deletes(&self) {
+        *self.fail_on_delete.lock().unwrap() = true;
+    }
+}
+
+/// Mock Account Repository over the shared store
+pub struct MockAccountRepository {
+    store: Arc<MockDeleteOuStore>,
+}
+
+#[async_trait]
+impl AccountRepository for MockAccountRepository {
+    async fn save(&self, account: &Account) -> Result<(), AccountRepositoryError> {
+        self.store.add_account(account.clone());
+        Ok(())
+    }
+
+    async fn find_by_hrn(&self, hrn: &Hrn) -> Result<Option<Account>, AccountRepositoryError> {
+        Ok(self.store.account(hrn))
+    }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), AccountRepositoryError> {
+        if *self.store.fail_on_delete.lock().unwrap() {
+            return Err(AccountRepositoryError::DatabaseError(
+                "Mock failure".to_string(),
+            ));
+        }
+        let mut state = self.store.state.lock().unwrap();
+        state.accounts.remove(&hrn.to_string());
+        Ok(())
+    }
+}
+
+/// Mock OU Repository over the shared store
+pub struct MockOuRepository {
+    store: Arc<MockDeleteOuStore>,
+}
+
+#[async_trait]
+impl OuRepository for MockOuRepository {
+    async fn save(&self, ou: &OrganizationalUnit) -> Result<(), OuRepositoryError> {
+        self.store.add_ou(ou.clone());
+        Ok(())
+    }
+
+    async fn find_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<OrganizationalUnit>, OuRepositoryError> {
+        Ok(self.store.ou(hrn))
+    }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), OuRepositoryError> {
+        let mut state = self.store.state.lock().unwrap();
+        state.ous.remove(&hrn.to_string());
+        Ok(())
+    }
+}
+
+/// Mock UnitOfWork for testing transactional behavior
+pub struct MockDeleteOuUnitOfWork {
+    store: Arc<MockDeleteOuStore>,
+}
+
+#[async_trait]
+impl DeleteOuUnitOfWork for MockDeleteOuUnitOfWork {
+    async fn begin(&mut self) -> Result<(), DeleteOuError> {
+        let state = self.store.state.lock().unwrap().clone();
+        *self.store.snapshot.lock().unwrap() = Some(state);
+        Ok(())
+    }
+
+    async fn commit(&mut self) -> Result<(), DeleteOuError> {
+        self.store
+            .snapshot
+            .lock()
+            .unwrap()
+            .take()
+            .map(|_| ())
+            .ok_or_else(|| {
+                DeleteOuError::TransactionError("No transaction in progress".to_string())
+            })
+    }
+
+    async fn rollback(&mut self) -> Result<(), DeleteOuError> {
+        let snapshot = self.store.snapshot.lock().unwrap().take().ok_or_else(|| {
+            DeleteOuError::TransactionError("No transaction in progress".to_string())
+        })?;
+        *self.store.state.lock().unwrap() = snapshot;
+        Ok(())
+    }
+
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        Arc::new(MockAccountRepository {
+            store: self.store.clone(),
+        })
+    }
+
+    fn ous(&self) -> Arc<dyn OuRepository> {
+        Arc::new(MockOuRepository {
+            store: self.store.clone(),
+        })
+    }
+}
+
+/// Mock UnitOfWorkFactory for testing
+#[derive(Default)]
+pub struct MockDeleteOuUnitOfWorkFactory {
+    pub store: Arc<MockDeleteOuStore>,
+}
+
+impl MockDeleteOuUnitOfWorkFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DeleteOuUnitOfWorkFactory for MockDeleteOuUnitOfWorkFactory {
+    type UnitOfWork = MockDeleteOuUnitOfWork;
+
+    async fn create(&self) -> Result<Self::UnitOfWork, DeleteOuError> {
+        Ok(MockDeleteOuUnitOfWork {
+            store: self.store.clone(),
+        })
+    }
+}
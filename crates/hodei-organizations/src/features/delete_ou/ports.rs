@@ -0,0 +1,37 @@
+use crate::features::delete_ou::error::DeleteOuError;
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Unit of Work trait for DeleteOu feature
+///
+/// The OU, and with `force` everything under it, is removed in one
+/// transaction.
+#[async_trait]
+pub trait DeleteOuUnitOfWork: Send + Sync {
+    /// Begin a new transaction
+    async fn begin(&mut self) -> Result<(), DeleteOuError>;
+
+    /// Commit the current transaction
+    async fn commit(&mut self) -> Result<(), DeleteOuError>;
+
+    /// Rollback the current transaction
+    async fn rollback(&mut self) -> Result<(), DeleteOuError>;
+
+    /// Get account repository for this transaction
+    fn accounts(&self) -> Arc<dyn AccountRepository>;
+
+    /// Get organizational unit repository for this transaction
+    fn ous(&self) -> Arc<dyn OuRepository>;
+}
+
+/// Factory for creating DeleteOuUnitOfWork instances
+#[async_trait]
+pub trait DeleteOuUnitOfWorkFactory: Send + Sync {
+    /// Type of UnitOfWork this factory creates
+    type UnitOfWork: DeleteOuUnitOfWork;
+
+    /// Create a new UnitOfWork instance
+    async fn create(&self) -> Result<Self::UnitOfWork, DeleteOuError>;
+}
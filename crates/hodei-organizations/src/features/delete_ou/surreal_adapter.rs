@@ -0,0 +1,100 @@
+//! Adaptador de SurrealDB para el caso de uso DeleteOu
+//!
+//! Este adaptador conecta la implementación genérica de SurrealUnitOfWork
+//! con el puerto específico DeleteOuUnitOfWork de la feature.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::features::delete_ou::error::DeleteOuError;
+use crate::features::delete_ou::ports::{DeleteOuUnitOfWork, DeleteOuUnitOfWorkFactory};
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+use crate::internal::infrastructure::surreal::{SurrealUnitOfWork, SurrealUnitOfWorkFactory};
+use kernel::application::ports::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+
+/// Adaptador que envuelve SurrealUnitOfWork para la feature delete_ou
+pub struct DeleteOuSurrealUnitOfWorkAdapter<C = surrealdb::engine::any::Any>
+where
+    C: surrealdb::Connection,
+{
+    inner: SurrealUnitOfWork<C>,
+}
+
+impl<C> DeleteOuSurrealUnitOfWorkAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    pub fn new(inner: SurrealUnitOfWork<C>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<C> DeleteOuUnitOfWork for DeleteOuSurrealUnitOfWorkAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    async fn begin(&mut self) -> Result<(), DeleteOuError> {
+        self.inner
+            .begin()
+            .await
+            .map_err(|e| DeleteOuError::TransactionError(e.to_string()))
+    }
+
+    async fn commit(&mut self) -> Result<(), DeleteOuError> {
+        self.inner
+            .commit()
+            .await
+            .map_err(|e| DeleteOuError::TransactionError(e.to_string()))
+    }
+
+    async fn rollback(&mut self) -> Result<(), DeleteOuError> {
+        self.inner
+            .rollback()
+            .await
+            .map_err(|e| DeleteOuError::TransactionError(e.to_string()))
+    }
+
+    fn accounts(&self) -> Arc<dyn AccountRepository> {
+        self.inner.accounts()
+    }
+
+    fn ous(&self) -> Arc<dyn OuRepository> {
+        self.inner.ous()
+    }
+}
+
+/// Factory que crea instancias de DeleteOuSurrealUnitOfWorkAdapter
+pub struct DeleteOuSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    inner: Arc<SurrealUnitOfWorkFactory<C>>,
+}
+
+impl<C> DeleteOuSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    pub fn new(inner: Arc<SurrealUnitOfWorkFactory<C>>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<C> DeleteOuUnitOfWorkFactory for DeleteOuSurrealUnitOfWorkFactoryAdapter<C>
+where
+    C: surrealdb::Connection,
+{
+    type UnitOfWork = DeleteOuSurrealUnitOfWorkAdapter<C>;
+
+    async fn create(&self) -> Result<Self::UnitOfWork, DeleteOuError> {
+        let uow = self
+            .inner
+            .create()
+            .await
+            .map_err(|e| DeleteOuError::TransactionError(e.to_string()))?;
+        Ok(DeleteOuSurrealUnitOfWorkAdapter::new(uow))
+    }
+}
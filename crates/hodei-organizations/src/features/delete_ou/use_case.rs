@@ -0,0 +1,203 @@
+use crate::features::delete_ou::dto::{DeleteOuCommand, DeleteOuSummary, DetachedScp};
+use crate::features::delete_ou::error::DeleteOuError;
+use crate::features::delete_ou::ports::{DeleteOuUnitOfWork, DeleteOuUnitOfWorkFactory};
+use crate::internal::domain::events::{
+    AccountDeleted, OrganizationalUnitDeleted, ScpDetached, ScpTargetType,
+};
+use kernel::application::ports::event_bus::EventEnvelope;
+use kernel::infrastructure::in_memory_event_bus::InMemoryEventBus;
+use kernel::{DomainEvent, EventPublisher, Hrn, TenantContext};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Use case for deleting an organizational unit
+///
+/// The root OU (the one that is its own parent, or whose parent doesn't
+/// exist) is never deleted. An OU with child OUs or accounts is only deleted
+/// with `force`, which removes the whole subtree in the same transaction as
+/// the OU itself. SCP attachments go with their targets.
+///
+/// After the commit, an `ScpDetached` event is published per attachment,
+/// then `AccountDeleted` per account and `OrganizationalUnitDeleted` per OU.
+pub struct DeleteOuUseCase<UWF: DeleteOuUnitOfWorkFactory> {
+    uow_factory: Arc<UWF>,
+    /// Optional event publisher for domain events
+    event_publisher: Option<Arc<InMemoryEventBus>>,
+}
+
+impl<UWF: DeleteOuUnitOfWorkFactory> DeleteOuUseCase<UWF> {
+    pub fn new(uow_factory: Arc<UWF>) -> Self {
+        Self {
+            uow_factory,
+            event_publisher: None,
+        }
+    }
+
+    pub fn with_event_publisher(mut self, publisher: Arc<InMemoryEventBus>) -> Self {
+        self.event_publisher = Some(publisher);
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        command: DeleteOuCommand,
+    ) -> Result<DeleteOuSummary, DeleteOuError> {
+        let mut uow = self.uow_factory.create().await?;
+        uow.begin().await?;
+
+        match self.execute_within_transaction(&command, &mut uow).await {
+            Ok(summary) => {
+                uow.commit().await?;
+                self.publish_events(&summary).await;
+                Ok(summary)
+            }
+            Err(e) => {
+                if let Err(rollback_err) = uow.rollback().await {
+                    tracing::error!("Failed to rollback transaction: {}", rollback_err);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The OU must belong to the tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: DeleteOuCommand,
+    ) -> Result<DeleteOuSummary, DeleteOuError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
+
+    async fn execute_within_transaction(
+        &self,
+        command: &DeleteOuCommand,
+        uow: &mut UWF::UnitOfWork,
+    ) -> Result<DeleteOuSummary, DeleteOuError> {
+        let ous = uow.ous();
+        let accounts = uow.accounts();
+
+        let ou = ous
+            .find_by_hrn(&command.ou_hrn)
+            .await?
+            .ok_or(DeleteOuError::OuNotFound)?;
+        if ou.is_root() {
+            return Err(DeleteOuError::RootOu);
+        }
+        let Some(mut parent) = ous.find_by_hrn(&ou.parent_hrn).await? else {
+            return Err(DeleteOuError::RootOu);
+        };
+        if ou.has_children() && !command.force {
+            return Err(DeleteOuError::NotEmpty {
+                child_ous: ou.child_ous.len(),
+                child_accounts: ou.child_accounts.len(),
+            });
+        }
+
+        // Collect the subtree top-down, then delete it bottom-up
+        let mut subtree = vec![ou];
+        let mut seen = HashSet::from([command.ou_hrn.clone()]);
+        let mut next = 0;
+        while next < subtree.len() {
+            for child_hrn in sorted(&subtree[next].child_ous) {
+                if !seen.insert(child_hrn.clone()) {
+                    continue;
+                }
+                match ous.find_by_hrn(&child_hrn).await? {
+                    Some(child) => subtree.push(child),
+                    None => tracing::warn!("Child OU {} not found, skipping", child_hrn),
+                }
+            }
+            next += 1;
+        }
+
+        let mut summary = DeleteOuSummary::default();
+        for ou in subtree.iter().rev() {
+            for account_hrn in sorted(&ou.child_accounts) {
+                let Some(account) = accounts.find_by_hrn(&account_hrn).await? else {
+                    tracing::warn!("Child account {} not found, skipping", account_hrn);
+                    continue;
+                };
+                summary
+                    .detached_scps
+                    .extend(detachments(&account.hrn, &account.attached_scps));
+                accounts.delete(&account.hrn).await?;
+                summary.deleted_accounts.push(account.hrn);
+            }
+            summary
+                .detached_scps
+                .extend(detachments(&ou.hrn, &ou.attached_scps));
+            ous.delete(&ou.hrn).await?;
+            summary.deleted_ous.push(ou.hrn.clone());
+        }
+
+        parent.remove_child_ou(&command.ou_hrn);
+        ous.save(&parent).await?;
+
+        Ok(summary)
+    }
+
+    async fn publish_events(&self, summary: &DeleteOuSummary) {
+        let Some(publisher) = &self.event_publisher else {
+            return;
+        };
+        let deleted_at = chrono::Utc::now();
+
+        for detached in &summary.detached_scps {
+            let target_type = if summary.deleted_accounts.contains(&detached.target_hrn) {
+                ScpTargetType::Account
+            } else {
+                ScpTargetType::OrganizationalUnit
+            };
+            let event = ScpDetached {
+                scp_hrn: detached.scp_hrn.clone(),
+                target_hrn: detached.target_hrn.clone(),
+                target_type,
+                detached_at: deleted_at,
+            };
+            publish(publisher, event, "Scp").await;
+        }
+        for account_hrn in &summary.deleted_accounts {
+            let event = AccountDeleted {
+                account_hrn: account_hrn.clone(),
+                deleted_at,
+            };
+            publish(publisher, event, "Account").await;
+        }
+        for ou_hrn in &summary.deleted_ous {
+            let event = OrganizationalUnitDeleted {
+                ou_hrn: ou_hrn.clone(),
+                deleted_at,
+            };
+            publish(publisher, event, "OrganizationalUnit").await;
+        }
+    }
+}
+
+async fn publish<E: DomainEvent>(publisher: &InMemoryEventBus, event: E, aggregate_type: &str) {
+    let event_type = event.event_type();
+    let envelope = EventEnvelope::new(event)
+        .with_metadata("aggregate_type".to_string(), aggregate_type.to_string());
+    if let Err(e) = publisher.publish_with_envelope(envelope).await {
+        tracing::warn!("Failed to publish {} event: {}", event_type, e);
+    }
+}
+
+/// HRNs in a stable order, so summaries don't depend on set iteration
+fn sorted(hrns: &HashSet<Hrn>) -> Vec<Hrn> {
+    let mut hrns: Vec<Hrn> = hrns.iter().cloned().collect();
+    hrns.sort_by_key(|hrn| hrn.to_string());
+    hrns
+}
+
+fn detachments(target_hrn: &Hrn, scps: &HashSet<Hrn>) -> Vec<DetachedScp> {
+    sorted(scps)
+        .into_iter()
+        .map(|scp_hrn| DetachedScp {
+            scp_hrn,
+            target_hrn: target_hrn.clone(),
+        })
+        .collect()
+}
@@ -0,0 +1,246 @@
+use crate::features::delete_ou::dto::{DeleteOuCommand, DetachedScp};
+use crate::features::delete_ou::error::DeleteOuError;
+use crate::features::delete_ou::mocks::MockDeleteOuUnitOfWorkFactory;
+use crate::features::delete_ou::use_case::DeleteOuUseCase;
+use crate::internal::domain::account::Account;
+use crate::internal::domain::ou::OrganizationalUnit;
+use kernel::application::ports::event_store::EventStore;
+use kernel::{Hrn, InMemoryEventBus, InMemoryEventStore};
+use std::sync::Arc;
+
+fn hrn(resource_type: &str, id: &str) -> Hrn {
+    Hrn::new(
+        "aws".to_string(),
+        "organizations".to_string(),
+        "123456789012".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+}
+
+fn ou(id: &str, parent_id: &str) -> OrganizationalUnit {
+    let mut ou = OrganizationalUnit::new(id.to_string(), hrn("ou", parent_id));
+    ou.hrn = hrn("ou", id);
+    ou
+}
+
+/// root → workloads (SCP) → team; `prod` in workloads, `dev` (SCP) in team
+fn factory_with_tree() -> Arc<MockDeleteOuUnitOfWorkFactory> {
+    let factory = Arc::new(MockDeleteOuUnitOfWorkFactory::new());
+
+    let mut root = ou("root", "root");
+    root.add_child_ou(hrn("ou", "workloads"));
+    let mut workloads = ou("workloads", "root");
+    workloads.add_child_ou(hrn("ou", "team"));
+    workloads.add_child_account(hrn("account", "prod"));
+    workloads.attach_scp(hrn("scp", "deny-regions"));
+    let mut team = ou("team", "workloads");
+    team.add_child_account(hrn("account", "dev"));
+    for ou in [root, workloads, team] {
+        factory.store.add_ou(ou);
+    }
+
+    let prod = Account::new(
+        hrn("account", "prod"),
+        "prod".to_string(),
+        Some(hrn("ou", "workloads")),
+    );
+    let mut dev = Account::new(
+        hrn("account", "dev"),
+        "dev".to_string(),
+        Some(hrn("ou", "team")),
+    );
+    dev.attach_scp(hrn("scp", "sandbox"));
+    factory.store.add_account(prod);
+    factory.store.add_account(dev);
+
+    factory
+}
+
+fn command(id: &str, force: bool) -> DeleteOuCommand {
+    DeleteOuCommand {
+        ou_hrn: hrn("ou", id),
+        force,
+    }
+}
+
+#[tokio::test]
+async fn test_delete_empty_ou_success() {
+    // Arrange
+    let factory = factory_with_tree();
+    let use_case = DeleteOuUseCase::new(factory.clone());
+    let mut empty = ou("empty", "root");
+    empty.attach_scp(hrn("scp", "audit"));
+    factory.store.add_ou(empty);
+
+    // Act
+    let summary = use_case.execute(command("empty", false)).await.unwrap();
+
+    // Assert
+    assert_eq!(summary.deleted_ous, vec![hrn("ou", "empty")]);
+    assert!(summary.deleted_accounts.is_empty());
+    assert_eq!(
+        summary.detached_scps,
+        vec![DetachedScp {
+            scp_hrn: hrn("scp", "audit"),
+            target_hrn: hrn("ou", "empty"),
+        }]
+    );
+    assert!(factory.store.ou(&hrn("ou", "empty")).is_none());
+}
+
+#[tokio::test]
+async fn test_delete_non_empty_ou_without_force_is_rejected() {
+    // Arrange
+    let factory = factory_with_tree();
+    let use_case = DeleteOuUseCase::new(factory.clone());
+
+    // Act
+    let result = use_case.execute(command("workloads", false)).await;
+
+    // Assert - Nothing removed
+    assert!(matches!(
+        result,
+        Err(DeleteOuError::NotEmpty {
+            child_ous: 1,
+            child_accounts: 1
+        })
+    ));
+    assert!(factory.store.ou(&hrn("ou", "workloads")).is_some());
+    assert!(factory.store.account(&hrn("account", "prod")).is_some());
+}
+
+#[tokio::test]
+async fn test_force_delete_cascades_and_summarizes() {
+    // Arrange
+    let factory = factory_with_tree();
+    let use_case = DeleteOuUseCase::new(factory.clone());
+
+    // Act
+    let summary = use_case.execute(command("workloads", true)).await.unwrap();
+
+    // Assert - Children before parents, SCPs detached from every target
+    assert_eq!(
+        summary.deleted_ous,
+        vec![hrn("ou", "team"), hrn("ou", "workloads")]
+    );
+    assert_eq!(
+        summary.deleted_accounts,
+        vec![hrn("account", "dev"), hrn("account", "prod")]
+    );
+    assert_eq!(
+        summary.detached_scps,
+        vec![
+            DetachedScp {
+                scp_hrn: hrn("scp", "sandbox"),
+                target_hrn: hrn("account", "dev"),
+            },
+            DetachedScp {
+                scp_hrn: hrn("scp", "deny-regions"),
+                target_hrn: hrn("ou", "workloads"),
+            },
+        ]
+    );
+    for id in ["workloads", "team"] {
+        assert!(factory.store.ou(&hrn("ou", id)).is_none());
+    }
+    for id in ["prod", "dev"] {
+        assert!(factory.store.account(&hrn("account", id)).is_none());
+    }
+    let root = factory.store.ou(&hrn("ou", "root")).unwrap();
+    assert!(root.child_ous.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_root_ou_is_always_rejected() {
+    // Arrange
+    let factory = factory_with_tree();
+    let use_case = DeleteOuUseCase::new(factory.clone());
+
+    // Act
+    let result = use_case.execute(command("root", true)).await;
+
+    // Assert
+    assert!(matches!(result, Err(DeleteOuError::RootOu)));
+    assert!(factory.store.ou(&hrn("ou", "root")).is_some());
+}
+
+#[tokio::test]
+async fn test_delete_ou_without_existing_parent_is_treated_as_root() {
+    // Arrange - An OU whose parent was never stored
+    let factory = factory_with_tree();
+    factory.store.add_ou(ou("detached", "nowhere"));
+    let use_case = DeleteOuUseCase::new(factory);
+
+    // Act
+    let result = use_case.execute(command("detached", false)).await;
+
+    // Assert
+    assert!(matches!(result, Err(DeleteOuError::RootOu)));
+}
+
+#[tokio::test]
+async fn test_delete_ou_not_found() {
+    // Arrange
+    let use_case = DeleteOuUseCase::new(factory_with_tree());
+
+    // Act
+    let result = use_case.execute(command("missing", true)).await;
+
+    // Assert
+    assert!(matches!(result, Err(DeleteOuError::OuNotFound)));
+}
+
+#[tokio::test]
+async fn test_force_delete_rolls_back_on_failure() {
+    // Arrange
+    let factory = factory_with_tree();
+    factory.store.fail_deletes();
+    let use_case = DeleteOuUseCase::new(factory.clone());
+
+    // Act
+    let result = use_case.execute(command("workloads", true)).await;
+
+    // Assert - The whole tree is still there
+    assert!(matches!(
+        result,
+        Err(DeleteOuError::AccountRepositoryError(_))
+    ));
+    for id in ["root", "workloads", "team"] {
+        assert!(factory.store.ou(&hrn("ou", id)).is_some());
+    }
+    let root = factory.store.ou(&hrn("ou", "root")).unwrap();
+    assert!(root.child_ous.contains(&hrn("ou", "workloads")));
+}
+
+#[tokio::test]
+async fn test_force_delete_publishes_events_after_commit() {
+    // Arrange
+    let factory = factory_with_tree();
+    let events = Arc::new(InMemoryEventStore::new());
+    let bus = Arc::new(InMemoryEventBus::new().with_event_store(events.clone()));
+    let use_case = DeleteOuUseCase::new(factory).with_event_publisher(bus);
+
+    // Act
+    use_case.execute(command("workloads", true)).await.unwrap();
+
+    // Assert
+    let types: Vec<_> = events
+        .read_batch(0, 10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|event| event.event_type)
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "organizations.scp.detached",
+            "organizations.scp.detached",
+            "organizations.account.deleted",
+            "organizations.account.deleted",
+            "organizations.ou.deleted",
+            "organizations.ou.deleted",
+        ]
+    );
+}
@@ -0,0 +1,19 @@
+use crate::features::hierarchy_lookup::adapter::{AccountFinderAdapter, OuFinderAdapter};
+use crate::features::get_account_ancestry::use_case::GetAccountAncestryUseCase;
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+
+/// Crea el caso de uso con repositorios concretos Surreal u otros
+pub fn get_account_ancestry_use_case<AR, OR>(
+    account_repository: AR,
+    ou_repository: OR,
+) -> GetAccountAncestryUseCase<AccountFinderAdapter<AR>, OuFinderAdapter<OR>>
+where
+    AR: AccountRepository + Send + Sync,
+    OR: OuRepository + Send + Sync,
+{
+    GetAccountAncestryUseCase::new(
+        AccountFinderAdapter::new(account_repository),
+        OuFinderAdapter::new(ou_repository),
+    )
+}
@@ -0,0 +1,30 @@
+use kernel::{Hrn, HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+
+/// Query to trace an account's path up to the root OU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccountAncestryQuery {
+    pub account_hrn: Hrn,
+}
+
+impl TenantScoped for GetAccountAncestryQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.account_hrn);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountAncestryResponse {
+    pub account_hrn: Hrn,
+    /// The account, then its parent OU, and so on up to the root
+    pub path: Vec<Hrn>,
+    /// True when the account has no parent OU; `path` is then just the account
+    pub detached: bool,
+}
+
+impl AccountAncestryResponse {
+    /// The OUs above the account, nearest first
+    pub fn ancestors(&self) -> &[Hrn] {
+        &self.path[1..]
+    }
+}
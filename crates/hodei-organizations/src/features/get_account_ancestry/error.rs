@@ -0,0 +1,19 @@
+use crate::internal::application::ports::account_repository::AccountRepositoryError;
+use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use kernel::CrossTenantAccess;
+use thiserror::Error;
+
+/// Error type for get account ancestry use case
+#[derive(Debug, Error)]
+pub enum GetAccountAncestryError {
+    #[error("Account repository error: {0}")]
+    AccountRepository(#[from] AccountRepositoryError),
+    #[error("OU repository error: {0}")]
+    OuRepository(#[from] OuRepositoryError),
+    #[error("Account not found: {0}")]
+    AccountNotFound(String),
+    #[error("Cycle detected in OU hierarchy at: {0}")]
+    CycleDetected(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
+}
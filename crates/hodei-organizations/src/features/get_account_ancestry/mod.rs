@@ -0,0 +1,11 @@
+pub mod di;
+pub mod dto;
+pub mod error;
+pub mod use_case;
+#[cfg(test)]
+pub mod use_case_test;
+
+// Re-exports públicos para acceso externo
+pub use dto::{AccountAncestryResponse, GetAccountAncestryQuery};
+pub use error::GetAccountAncestryError;
+pub use use_case::GetAccountAncestryUseCase;
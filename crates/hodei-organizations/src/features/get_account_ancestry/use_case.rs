@@ -0,0 +1,98 @@
+use crate::features::get_account_ancestry::dto::{
+    AccountAncestryResponse, GetAccountAncestryQuery,
+};
+use crate::features::get_account_ancestry::error::GetAccountAncestryError;
+use crate::features::hierarchy_lookup::ports::{AccountFinderPort, OuFinderPort};
+use kernel::{Hrn, TenantContext};
+use std::collections::HashSet;
+use tracing::{error, info, warn};
+
+/// Caso de uso para trazar la ruta de una cuenta hasta la OU raíz
+///
+/// Sigue los `parent_hrn` desde la OU padre de la cuenta hasta una OU que
+/// es su propio padre. Como en el recorrido de límites de SCPs, una OU
+/// referenciada pero no almacenada se toma como situada más allá de la raíz,
+/// así que la ruta termina en la última OU encontrada.
+pub struct GetAccountAncestryUseCase<AF, OF>
+where
+    AF: AccountFinderPort,
+    OF: OuFinderPort,
+{
+    account_finder: AF,
+    ou_finder: OF,
+}
+
+impl<AF, OF> GetAccountAncestryUseCase<AF, OF>
+where
+    AF: AccountFinderPort,
+    OF: OuFinderPort,
+{
+    pub fn new(account_finder: AF, ou_finder: OF) -> Self {
+        Self {
+            account_finder,
+            ou_finder,
+        }
+    }
+
+    /// Ejecuta el recorrido de la jerarquía
+    ///
+    /// Falla con `CycleDetected` si la cadena de OUs vuelve sobre sí misma.
+    pub async fn execute(
+        &self,
+        query: GetAccountAncestryQuery,
+    ) -> Result<AccountAncestryResponse, GetAccountAncestryError> {
+        info!("Tracing ancestry of account: {}", query.account_hrn);
+
+        let account = self
+            .account_finder
+            .find_account_by_hrn(&query.account_hrn)
+            .await?
+            .ok_or_else(|| {
+                GetAccountAncestryError::AccountNotFound(query.account_hrn.to_string())
+            })?;
+
+        let mut path = vec![account.hrn.clone()];
+        let Some(parent_hrn) = account.parent_hrn else {
+            return Ok(AccountAncestryResponse {
+                account_hrn: account.hrn,
+                path,
+                detached: true,
+            });
+        };
+
+        let mut visited = HashSet::new();
+        let mut next: Option<Hrn> = Some(parent_hrn);
+        while let Some(ou_hrn) = next {
+            if !visited.insert(ou_hrn.clone()) {
+                error!("Cycle detected in OU hierarchy at: {}", ou_hrn);
+                return Err(GetAccountAncestryError::CycleDetected(ou_hrn.to_string()));
+            }
+            let Some(ou) = self.ou_finder.find_ou_by_hrn(&ou_hrn).await? else {
+                warn!(
+                    "OU referenced but not found: {}, assuming root reached",
+                    ou_hrn
+                );
+                break;
+            };
+            next = (!ou.is_root()).then(|| ou.parent_hrn.clone());
+            path.push(ou.hrn);
+        }
+
+        Ok(AccountAncestryResponse {
+            account_hrn: account.hrn,
+            path,
+            detached: false,
+        })
+    }
+
+    /// Ejecuta el recorrido en nombre de `tenant`
+    ///
+    /// Falla con `CrossTenantAccess` si la cuenta pertenece a otro tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: GetAccountAncestryQuery,
+    ) -> Result<AccountAncestryResponse, GetAccountAncestryError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
+}
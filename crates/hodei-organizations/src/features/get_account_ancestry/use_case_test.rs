@@ -0,0 +1,135 @@
+use crate::features::get_account_ancestry::dto::GetAccountAncestryQuery;
+use crate::features::get_account_ancestry::error::GetAccountAncestryError;
+use crate::features::hierarchy_lookup::mocks::{MockAccountFinderPort, MockOuFinderPort};
+use crate::features::get_account_ancestry::use_case::GetAccountAncestryUseCase;
+use crate::internal::domain::{Account, OrganizationalUnit};
+use kernel::Hrn;
+
+fn hrn(resource_type: &str, id: &str) -> Hrn {
+    Hrn::new(
+        "aws".to_string(),
+        "organizations".to_string(),
+        "123456789012".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+}
+
+fn ou(id: &str, parent_id: &str) -> OrganizationalUnit {
+    let mut ou = OrganizationalUnit::new(id.to_string(), hrn("ou", parent_id));
+    ou.hrn = hrn("ou", id);
+    ou
+}
+
+fn account(parent_id: Option<&str>) -> Account {
+    Account::new(
+        hrn("account", "prod"),
+        "prod".to_string(),
+        parent_id.map(|id| hrn("ou", id)),
+    )
+}
+
+fn query() -> GetAccountAncestryQuery {
+    GetAccountAncestryQuery {
+        account_hrn: hrn("account", "prod"),
+    }
+}
+
+/// root → workloads → team
+fn tree() -> MockOuFinderPort {
+    MockOuFinderPort::default()
+        .with_ou(ou("root", "root"))
+        .with_ou(ou("workloads", "root"))
+        .with_ou(ou("team", "workloads"))
+}
+
+#[tokio::test]
+async fn test_path_runs_from_account_to_root() {
+    // Arrange
+    let accounts = MockAccountFinderPort::default().with_account(account(Some("team")));
+    let use_case = GetAccountAncestryUseCase::new(accounts, tree());
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert!(!response.detached);
+    assert_eq!(
+        response.path,
+        vec![
+            hrn("account", "prod"),
+            hrn("ou", "team"),
+            hrn("ou", "workloads"),
+            hrn("ou", "root"),
+        ]
+    );
+    assert_eq!(response.ancestors().len(), 3);
+}
+
+#[tokio::test]
+async fn test_detached_account_returns_only_itself() {
+    // Arrange
+    let accounts = MockAccountFinderPort::default().with_account(account(None));
+    let use_case = GetAccountAncestryUseCase::new(accounts, tree());
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert!(response.detached);
+    assert_eq!(response.path, vec![hrn("account", "prod")]);
+    assert!(response.ancestors().is_empty());
+}
+
+#[tokio::test]
+async fn test_missing_parent_ends_path() {
+    // Arrange - team's parent was never stored
+    let ous = MockOuFinderPort::default().with_ou(ou("team", "gone"));
+    let accounts = MockAccountFinderPort::default().with_account(account(Some("team")));
+    let use_case = GetAccountAncestryUseCase::new(accounts, ous);
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert_eq!(
+        response.path,
+        vec![hrn("account", "prod"), hrn("ou", "team")]
+    );
+}
+
+#[tokio::test]
+async fn test_cycle_is_reported() {
+    // Arrange - a and b are each other's parent
+    let ous = MockOuFinderPort::default()
+        .with_ou(ou("a", "b"))
+        .with_ou(ou("b", "a"));
+    let accounts = MockAccountFinderPort::default().with_account(account(Some("a")));
+    let use_case = GetAccountAncestryUseCase::new(accounts, ous);
+
+    // Act
+    let result = use_case.execute(query()).await;
+
+    // Assert
+    match result {
+        Err(GetAccountAncestryError::CycleDetected(at)) => {
+            assert_eq!(at, hrn("ou", "a").to_string())
+        }
+        other => panic!("expected CycleDetected, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_account_not_found() {
+    // Arrange
+    let use_case = GetAccountAncestryUseCase::new(MockAccountFinderPort::default(), tree());
+
+    // Act
+    let result = use_case.execute(query()).await;
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(GetAccountAncestryError::AccountNotFound(_))
+    ));
+}
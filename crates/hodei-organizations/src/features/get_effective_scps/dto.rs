@@ -1,5 +1,8 @@
-use cedar_policy::PolicySet;
+use cedar_policy::{Authorizer, Decision, Effect, Entities, Policy, PolicyId, PolicySet, Request};
+use kernel::{Hrn, HrnVisitor, TenantScoped};
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::str::FromStr;
 
 /// Query to get effective SCPs for a resource
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,15 +11,118 @@ pub struct GetEffectiveScpsQuery {
     pub resource_hrn: String,
 }
 
+impl TenantScoped for GetEffectiveScpsQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.resource_hrn);
+    }
+}
+
+/// Query to preview the SCPs an account would inherit if placed in an OU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewOuScpsQuery {
+    /// HRN of the OU the account would be placed in
+    pub ou_hrn: String,
+}
+
+impl TenantScoped for PreviewOuScpsQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn_str(&self.ou_hrn);
+    }
+}
+
+/// Whether a response holds the SCPs in effect or a preview
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectiveScpsKind {
+    /// The SCPs in effect for the target as it stands, including the
+    /// account's own attachments when the target is an account
+    #[default]
+    Actual,
+    /// The SCPs an account placed in the target OU would inherit
+    ///
+    /// No account exists there yet, so no account attachments are included:
+    /// an account moved in keeps its own SCPs on top of these.
+    OuPreview,
+}
+
+/// How the SCPs of the different hierarchy levels combine into a decision
+///
+/// A level is the target itself or one OU above it. For every level with
+/// at least one SCP, "the level allows" means Cedar's own rule over that
+/// level's SCPs: some `permit` matches and no `forbid` does.
+///
+/// For example, with `permit(principal, action == Action::"Read", resource);`
+/// on the root OU and `permit(principal, action, resource);` on the
+/// account's parent OU, a `Read` request is allowed under both strategies,
+/// while a `Write` request is:
+///
+/// - allowed under [`CedarDefault`](Self::CedarDefault), which only sees
+///   the parent OU's SCPs;
+/// - denied under [`AllowListIntersection`](Self::AllowListIntersection),
+///   because the root OU does not allow it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScpCombinationStrategy {
+    /// The behaviour before strategies existed
+    ///
+    /// Only the SCPs of a single level are returned: those of an OU target,
+    /// or those of an account's parent OU. They form one `PolicySet` and
+    /// Cedar's forbid-overrides-permit rule applies to it as a whole.
+    #[default]
+    CedarDefault,
+    /// AWS-style allow lists: every level must allow the request
+    ///
+    /// The levels run from the target (the account's own SCPs, or the OU's)
+    /// up to the root OU. The request is allowed when each level that has
+    /// SCPs allows it, so a `forbid` at any level denies, and a `permit` at
+    /// one level cannot widen what another level leaves out. Levels without
+    /// SCPs impose no restriction, as if they held a full-access SCP. If no
+    /// level has SCPs the request is denied, as with an empty `PolicySet`
+    /// under Cedar's default.
+    AllowListIntersection,
+}
+
+impl FromStr for ScpCombinationStrategy {
+    type Err = String;
+
+    /// Parse the `snake_case` name used in configuration
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "cedar_default" => Ok(Self::CedarDefault),
+            "allow_list_intersection" => Ok(Self::AllowListIntersection),
+            other => Err(format!("unknown SCP combination strategy '{}'", other)),
+        }
+    }
+}
+
+/// The SCPs attached at one level of the hierarchy
+#[derive(Debug, Clone)]
+pub struct ScpLevel {
+    /// The account or OU the SCPs are attached to
+    pub hrn: Hrn,
+    pub policies: PolicySet,
+}
+
 /// Response containing effective SCPs as a Cedar PolicySet
 /// This is the PUBLIC interface - does not expose internal entities
 #[derive(Debug, Clone)]
 pub struct EffectiveScpsResponse {
     /// Cedar PolicySet containing all effective SCPs
-    /// This can be directly used by the authorization engine
+    ///
+    /// Under [`ScpCombinationStrategy::AllowListIntersection`] this is the
+    /// union of every level, kept for inspection: evaluating it directly
+    /// gives Cedar's default semantics, not the intersection. Use
+    /// [`evaluate`](Self::evaluate) or
+    /// [`effective_policy_set`](Self::effective_policy_set) instead.
     pub policies: PolicySet,
     /// HRN of the target entity (for logging/debugging)
     pub target_hrn: String,
+    /// The strategy the response was built for
+    pub strategy: ScpCombinationStrategy,
+    /// The SCPs per level, nearest first
+    pub levels: Vec<ScpLevel>,
+    /// Whether these are the SCPs in effect or an OU preview
+    pub kind: EffectiveScpsKind,
 }
 
 impl EffectiveScpsResponse {
@@ -24,6 +130,190 @@ impl EffectiveScpsResponse {
         Self {
             policies,
             target_hrn,
+            strategy: ScpCombinationStrategy::default(),
+            levels: Vec::new(),
+            kind: EffectiveScpsKind::default(),
+        }
+    }
+
+    pub fn with_levels(mut self, strategy: ScpCombinationStrategy, levels: Vec<ScpLevel>) -> Self {
+        self.strategy = strategy;
+        self.levels = levels;
+        self
+    }
+
+    /// Mark the response as an OU preview
+    pub fn into_ou_preview(mut self) -> Self {
+        self.kind = EffectiveScpsKind::OuPreview;
+        self
+    }
+
+    pub fn is_preview(&self) -> bool {
+        self.kind == EffectiveScpsKind::OuPreview
+    }
+
+    /// A `PolicySet` that decides under Cedar's default rule as
+    /// [`evaluate`](Self::evaluate) does
+    ///
+    /// Under `CedarDefault` this is `policies`. Under `AllowListIntersection`
+    /// it holds every level's `forbid`s plus one `permit` whose condition
+    /// requires, for each level with SCPs, one of that level's `permit`s to
+    /// match. Consumers that only take a `PolicySet`, such as the kernel's
+    /// `GetEffectiveScpsPort`, must be given this set and not `policies`.
+    ///
+    /// A `permit` that cannot be rewritten as a condition is left out, so
+    /// its level allows less, never more.
+    pub fn effective_policy_set(&self) -> PolicySet {
+        match self.strategy {
+            ScpCombinationStrategy::CedarDefault => self.policies.clone(),
+            ScpCombinationStrategy::AllowListIntersection => self.intersection_policy_set(),
+        }
+    }
+
+    fn intersection_policy_set(&self) -> PolicySet {
+        let mut set = PolicySet::new();
+        let mut level_conditions = Vec::new();
+        for level in &self.levels {
+            if level.policies.policies().next().is_none() {
+                continue;
+            }
+            let mut permits = Vec::new();
+            for policy in level.policies.policies() {
+                match policy.effect() {
+                    // An SCP attached at several levels is one policy: the
+                    // copy already added forbids the same requests
+                    Effect::Forbid => {
+                        let _ = set.add(policy.clone());
+                    }
+                    Effect::Permit => permits.extend(match_condition(policy)),
+                }
+            }
+            level_conditions.push(any_of(permits));
+        }
+        if level_conditions.is_empty() {
+            return set;
+        }
+
+        let permit = json!({
+            "effect": "permit",
+            "principal": { "op": "All" },
+            "action": { "op": "All" },
+            "resource": { "op": "All" },
+            "conditions": [{ "kind": "when", "body": all_of(level_conditions) }],
+        });
+        // Without the permit nothing is allowed, which is the safe outcome
+        if let Ok(permit) = Policy::from_json(Some(PolicyId::new(INTERSECTION_POLICY_ID)), permit) {
+            let _ = set.add(permit);
+        }
+        set
+    }
+
+    /// Decide `request` under the response's strategy
+    pub fn evaluate(&self, request: &Request, entities: &Entities) -> Decision {
+        let authorizer = Authorizer::new();
+        match self.strategy {
+            ScpCombinationStrategy::CedarDefault => authorizer
+                .is_authorized(request, &self.policies, entities)
+                .decision(),
+            ScpCombinationStrategy::AllowListIntersection => {
+                let mut restricting = self
+                    .levels
+                    .iter()
+                    .filter(|level| level.policies.policies().next().is_some())
+                    .peekable();
+                if restricting.peek().is_none() {
+                    return Decision::Deny;
+                }
+                let all_allow = restricting.all(|level| {
+                    authorizer
+                        .is_authorized(request, &level.policies, entities)
+                        .decision()
+                        == Decision::Allow
+                });
+                if all_allow {
+                    Decision::Allow
+                } else {
+                    Decision::Deny
+                }
+            }
+        }
+    }
+}
+
+/// ID of the `permit` joining the levels in
+/// [`EffectiveScpsResponse::effective_policy_set`]
+const INTERSECTION_POLICY_ID: &str = "scp-level-intersection";
+
+/// The condition under which `policy` matches a request, as a Cedar JSON
+/// expression: its scope and its `when`/`unless` clauses
+fn match_condition(policy: &Policy) -> Option<Value> {
+    let policy = policy.to_json().ok()?;
+    let mut parts = vec![
+        scope_condition("principal", policy.get("principal")?)?,
+        scope_condition("action", policy.get("action")?)?,
+        scope_condition("resource", policy.get("resource")?)?,
+    ];
+    for condition in policy.get("conditions")?.as_array()? {
+        let body = condition.get("body")?.clone();
+        parts.push(match condition.get("kind")?.as_str()? {
+            "when" => body,
+            "unless" => json!({ "!": { "arg": body } }),
+            _ => return None,
+        });
+    }
+    Some(all_of(parts))
+}
+
+/// A scope constraint of `var` as a Cedar JSON expression
+///
+/// Template slots have no value to compare against and yield `None`.
+fn scope_condition(var: &str, constraint: &Value) -> Option<Value> {
+    let left = json!({ "Var": var });
+    match constraint.get("op")?.as_str()? {
+        "All" => Some(json!({ "Value": true })),
+        "==" => Some(
+            json!({ "==": { "left": left, "right": entity_literal(constraint.get("entity")?) } }),
+        ),
+        "in" => {
+            let right = match (constraint.get("entity"), constraint.get("entities")) {
+                (Some(entity), _) => entity_literal(entity),
+                (None, Some(entities)) => json!({
+                    "Set": entities.as_array()?.iter().map(entity_literal).collect::<Vec<_>>()
+                }),
+                (None, None) => return None,
+            };
+            Some(json!({ "in": { "left": left, "right": right } }))
+        }
+        "is" => {
+            let mut is = json!({ "left": left, "entity_type": constraint.get("entity_type")? });
+            if let Some(within) = constraint.get("in") {
+                is["in"] = entity_literal(within.get("entity")?);
+            }
+            Some(json!({ "is": is }))
         }
+        _ => None,
     }
 }
+
+/// An entity UID from a policy scope as a Cedar JSON literal
+fn entity_literal(entity: &Value) -> Value {
+    if entity.get("__entity").is_some() {
+        json!({ "Value": entity })
+    } else {
+        json!({ "Value": { "__entity": entity } })
+    }
+}
+
+fn all_of(conditions: Vec<Value>) -> Value {
+    conditions
+        .into_iter()
+        .reduce(|left, right| json!({ "&&": { "left": left, "right": right } }))
+        .unwrap_or(json!({ "Value": true }))
+}
+
+fn any_of(conditions: Vec<Value>) -> Value {
+    conditions
+        .into_iter()
+        .reduce(|left, right| json!({ "||": { "left": left, "right": right } }))
+        .unwrap_or(json!({ "Value": false }))
+}
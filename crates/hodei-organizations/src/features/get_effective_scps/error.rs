@@ -1,4 +1,5 @@
 use thiserror::Error;
+use kernel::CrossTenantAccess;
 use crate::internal::application::ports::scp_repository::ScpRepositoryError;
 use crate::internal::application::ports::account_repository::AccountRepositoryError;
 use crate::internal::application::ports::ou_repository::OuRepositoryError;
@@ -14,6 +15,12 @@ pub enum GetEffectiveScpsError {
     OuRepository(#[from] OuRepositoryError),
     #[error("Target entity not found: {0}")]
     TargetNotFound(String),
+    #[error("OU not found: {0}")]
+    OuNotFound(String),
     #[error("Invalid target entity type: {0}")]
     InvalidTargetType(String),
+    #[error("Cycle detected in OU hierarchy at: {0}")]
+    HierarchyCycle(String),
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
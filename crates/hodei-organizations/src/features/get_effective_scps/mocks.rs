@@ -102,3 +102,46 @@ impl OuRepositoryPort for MockOuRepositoryPort {
         Ok(ous.get(&hrn.to_string()).cloned())
     }
 }
+
+/// Mock exposing both accounts and OUs, as the use case's org repository
+#[derive(Debug, Default)]
+pub struct MockOrgRepositoryPort {
+    accounts: MockAccountRepositoryPort,
+    ous: MockOuRepositoryPort,
+}
+
+impl MockOrgRepositoryPort {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_account(mut self, account: Account) -> Self {
+        self.accounts = self.accounts.with_account(account);
+        self
+    }
+
+    pub fn with_ou(mut self, ou: OrganizationalUnit) -> Self {
+        self.ous = self.ous.with_ou(ou);
+        self
+    }
+}
+
+#[async_trait]
+impl AccountRepositoryPort for MockOrgRepositoryPort {
+    async fn find_account_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        self.accounts.find_account_by_hrn(hrn).await
+    }
+}
+
+#[async_trait]
+impl OuRepositoryPort for MockOrgRepositoryPort {
+    async fn find_ou_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<OrganizationalUnit>, OuRepositoryError> {
+        self.ous.find_ou_by_hrn(hrn).await
+    }
+}
@@ -5,8 +5,13 @@ pub mod error;
 pub mod mocks;
 pub mod ports;
 pub mod use_case;
+#[cfg(test)]
+pub mod use_case_test;
 
 // Re-exports públicos para acceso externo
-pub use dto::{EffectiveScpsResponse, GetEffectiveScpsQuery};
+pub use dto::{
+    EffectiveScpsKind, EffectiveScpsResponse, GetEffectiveScpsQuery, PreviewOuScpsQuery,
+    ScpCombinationStrategy, ScpLevel,
+};
 pub use error::GetEffectiveScpsError;
 pub use use_case::GetEffectiveScpsUseCase;
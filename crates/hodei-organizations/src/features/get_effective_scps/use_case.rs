@@ -1,17 +1,24 @@
-use crate::features::get_effective_scps::dto::{EffectiveScpsResponse, GetEffectiveScpsQuery};
+use crate::features::get_effective_scps::dto::{
+    EffectiveScpsResponse, GetEffectiveScpsQuery, PreviewOuScpsQuery, ScpCombinationStrategy,
+    ScpLevel,
+};
 use crate::features::get_effective_scps::error::GetEffectiveScpsError;
 use crate::features::get_effective_scps::ports::{
     AccountRepositoryPort, OuRepositoryPort, ScpRepositoryPort,
 };
 use crate::internal::domain::scp::ServiceControlPolicy;
-use cedar_policy::PolicySet;
-use kernel::Hrn;
-use tracing::{info, warn};
+use cedar_policy::{Policy, PolicyId, PolicySet};
+use kernel::{Hrn, TenantContext};
+use std::collections::HashSet;
+use tracing::{error, info, warn};
 
 /// Caso de uso para obtener las SCPs efectivas de una entidad (OU o Account)
 ///
 /// Este caso de uso es la ÚNICA forma de que otros crates accedan a las SCPs.
 /// Devuelve un PolicySet de Cedar, NO las entidades internas ServiceControlPolicy.
+///
+/// Cómo se combinan los niveles de la jerarquía lo decide la
+/// [`ScpCombinationStrategy`] configurada; por defecto, `CedarDefault`.
 pub struct GetEffectiveScpsUseCase<SRP, ORP>
 where
     SRP: ScpRepositoryPort + Send + Sync,
@@ -19,6 +26,7 @@ where
 {
     scp_repository: SRP,
     org_repository: ORP,
+    strategy: ScpCombinationStrategy,
 }
 
 impl<SRP, ORP> GetEffectiveScpsUseCase<SRP, ORP>
@@ -30,9 +38,16 @@ where
         Self {
             scp_repository,
             org_repository,
+            strategy: ScpCombinationStrategy::default(),
         }
     }
 
+    /// Configura cómo se combinan las SCPs de los distintos niveles
+    pub fn with_strategy(mut self, strategy: ScpCombinationStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Ejecuta la obtención de SCPs efectivas devolviendo un PolicySet de Cedar
     ///
     /// Este es el método público que otros crates deben usar.
@@ -49,30 +64,189 @@ where
         let target_hrn = Hrn::from_string(&query.resource_hrn)
             .ok_or_else(|| GetEffectiveScpsError::TargetNotFound(query.resource_hrn.clone()))?;
 
-        // Obtener las entidades SCP internas (no expuestas)
-        let scps = match target_hrn.resource_type.as_str() {
-            "ou" => self.collect_from_ou(&target_hrn).await?,
+        let levels = self.levels(&target_hrn).await?;
+        self.build_response(query.resource_hrn, levels)
+    }
+
+    /// Ejecuta la obtención de SCPs efectivas en nombre de `tenant`
+    ///
+    /// Falla con `CrossTenantAccess` si la entidad pertenece a otro tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: GetEffectiveScpsQuery,
+    ) -> Result<EffectiveScpsResponse, GetEffectiveScpsError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
+
+    /// Previsualiza las SCPs que heredaría una cuenta colocada en una OU
+    ///
+    /// Devuelve lo mismo que [`execute`](Self::execute) para una cuenta sin
+    /// SCPs propias cuya OU padre fuese `query.ou_hrn`: con `CedarDefault`,
+    /// las SCPs de la OU; con `AllowListIntersection`, las de la OU y las de
+    /// cada OU por encima hasta la raíz. La respuesta se marca como
+    /// `OuPreview` para distinguirla de las SCPs en vigor de una entidad.
+    ///
+    /// Falla con `OuNotFound` si la OU no existe.
+    pub async fn preview_for_ou(
+        &self,
+        query: PreviewOuScpsQuery,
+    ) -> Result<EffectiveScpsResponse, GetEffectiveScpsError> {
+        info!("Previewing effective SCPs for OU: {}", query.ou_hrn);
+
+        let ou_hrn = Hrn::from_string(&query.ou_hrn)
+            .ok_or_else(|| GetEffectiveScpsError::OuNotFound(query.ou_hrn.clone()))?;
+        if ou_hrn.resource_type != "ou" {
+            return Err(GetEffectiveScpsError::InvalidTargetType(
+                ou_hrn.resource_type.clone(),
+            ));
+        }
+        if self.org_repository.find_ou_by_hrn(&ou_hrn).await?.is_none() {
+            return Err(GetEffectiveScpsError::OuNotFound(query.ou_hrn));
+        }
+
+        let levels = self.levels(&ou_hrn).await?;
+        Ok(self.build_response(query.ou_hrn, levels)?.into_ou_preview())
+    }
+
+    /// Previsualiza las SCPs de una OU en nombre de `tenant`
+    ///
+    /// Falla con `CrossTenantAccess` si la OU pertenece a otro tenant.
+    pub async fn preview_for_ou_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: PreviewOuScpsQuery,
+    ) -> Result<EffectiveScpsResponse, GetEffectiveScpsError> {
+        tenant.run(query, |query| self.preview_for_ou(query)).await
+    }
+
+    /// Los niveles que consulta la estrategia configurada
+    async fn levels(
+        &self,
+        target_hrn: &Hrn,
+    ) -> Result<Vec<(Hrn, Vec<ServiceControlPolicy>)>, GetEffectiveScpsError> {
+        match self.strategy {
+            ScpCombinationStrategy::CedarDefault => self.nearest_level(target_hrn).await,
+            ScpCombinationStrategy::AllowListIntersection => {
+                self.hierarchy_levels(target_hrn).await
+            }
+        }
+    }
+
+    /// Construye la respuesta a partir de las SCPs de cada nivel
+    fn build_response(
+        &self,
+        target_hrn: String,
+        levels: Vec<(Hrn, Vec<ServiceControlPolicy>)>,
+    ) -> Result<EffectiveScpsResponse, GetEffectiveScpsError> {
+        // Convertir las entidades internas a PolicySet de Cedar
+        let mut policies = PolicySet::new();
+        let mut scp_levels = Vec::with_capacity(levels.len());
+        for (hrn, scps) in levels {
+            let level_policies = self.convert_to_policy_set(scps)?;
+            for policy in level_policies.policies() {
+                if let Err(e) = policies.add(policy.clone()) {
+                    warn!("Failed to add SCP policy to set: {}", e);
+                }
+            }
+            scp_levels.push(ScpLevel {
+                hrn,
+                policies: level_policies,
+            });
+        }
+
+        info!(
+            "Found {} effective SCPs across {} levels",
+            policies.policies().count(),
+            scp_levels.len()
+        );
+
+        Ok(EffectiveScpsResponse::new(policies, target_hrn).with_levels(self.strategy, scp_levels))
+    }
+
+    /// El único nivel que consulta `CedarDefault`: la OU destino, o la OU
+    /// padre de una cuenta
+    async fn nearest_level(
+        &self,
+        target_hrn: &Hrn,
+    ) -> Result<Vec<(Hrn, Vec<ServiceControlPolicy>)>, GetEffectiveScpsError> {
+        let ou_hrn = match target_hrn.resource_type.as_str() {
+            "ou" => target_hrn.clone(),
             "account" => {
-                if let Some(account) = self.org_repository.find_account_by_hrn(&target_hrn).await? {
-                    if let Some(parent_hrn) = &account.parent_hrn {
-                        self.collect_from_ou(parent_hrn).await?
-                    } else {
-                        // Account without parent OU: no inherited SCPs
-                        Vec::new()
-                    }
-                } else {
-                    return Err(GetEffectiveScpsError::TargetNotFound(query.resource_hrn));
+                let account = self
+                    .org_repository
+                    .find_account_by_hrn(target_hrn)
+                    .await?
+                    .ok_or_else(|| GetEffectiveScpsError::TargetNotFound(target_hrn.to_string()))?;
+                match account.parent_hrn {
+                    Some(parent_hrn) => parent_hrn,
+                    // Account without parent OU: no inherited SCPs
+                    None => return Ok(Vec::new()),
                 }
             }
             other => return Err(GetEffectiveScpsError::InvalidTargetType(other.to_string())),
         };
+        let scps = self.collect_from_ou(&ou_hrn).await?;
+        Ok(vec![(ou_hrn, scps)])
+    }
 
-        info!("Found {} effective SCPs", scps.len());
+    /// Los niveles que consulta `AllowListIntersection`: el destino y cada
+    /// OU por encima de él hasta la raíz, del más cercano al más lejano
+    ///
+    /// Una OU referenciada pero inexistente se toma como más allá de la raíz,
+    /// igual que en el recorrido del authorizer. Un ciclo es un error: con
+    /// listas de permitidos, cortar el recorrido ampliaría los permisos.
+    async fn hierarchy_levels(
+        &self,
+        target_hrn: &Hrn,
+    ) -> Result<Vec<(Hrn, Vec<ServiceControlPolicy>)>, GetEffectiveScpsError> {
+        let mut levels = Vec::new();
+        let mut next = match target_hrn.resource_type.as_str() {
+            "ou" => {
+                if self
+                    .org_repository
+                    .find_ou_by_hrn(target_hrn)
+                    .await?
+                    .is_none()
+                {
+                    return Err(GetEffectiveScpsError::TargetNotFound(
+                        target_hrn.to_string(),
+                    ));
+                }
+                Some(target_hrn.clone())
+            }
+            "account" => {
+                let account = self
+                    .org_repository
+                    .find_account_by_hrn(target_hrn)
+                    .await?
+                    .ok_or_else(|| GetEffectiveScpsError::TargetNotFound(target_hrn.to_string()))?;
+                let scps = self.load_scps(&account.attached_scps).await?;
+                levels.push((account.hrn, scps));
+                account.parent_hrn
+            }
+            other => return Err(GetEffectiveScpsError::InvalidTargetType(other.to_string())),
+        };
 
-        // Convertir las entidades internas a PolicySet de Cedar
-        let policy_set = self.convert_to_policy_set(scps)?;
+        let mut visited = HashSet::new();
+        while let Some(ou_hrn) = next {
+            if !visited.insert(ou_hrn.clone()) {
+                error!("Cycle detected in OU hierarchy at: {}", ou_hrn);
+                return Err(GetEffectiveScpsError::HierarchyCycle(ou_hrn.to_string()));
+            }
+            let Some(ou) = self.org_repository.find_ou_by_hrn(&ou_hrn).await? else {
+                warn!(
+                    "OU referenced but not found: {}, assuming root reached",
+                    ou_hrn
+                );
+                break;
+            };
+            next = (!ou.is_root()).then(|| ou.parent_hrn.clone());
+            let scps = self.load_scps(&ou.attached_scps).await?;
+            levels.push((ou.hrn, scps));
+        }
 
-        Ok(EffectiveScpsResponse::new(policy_set, query.resource_hrn))
+        Ok(levels)
     }
 
     /// Método interno para recolectar SCPs desde una OU
@@ -86,8 +260,15 @@ where
             .await?
             .ok_or_else(|| GetEffectiveScpsError::TargetNotFound(ou_hrn.to_string()))?;
 
+        self.load_scps(&ou.attached_scps).await
+    }
+
+    async fn load_scps(
+        &self,
+        scp_hrns: &HashSet<Hrn>,
+    ) -> Result<Vec<ServiceControlPolicy>, GetEffectiveScpsError> {
         let mut scps = Vec::new();
-        for scp_hrn in ou.attached_scps.iter() {
+        for scp_hrn in scp_hrns.iter() {
             if let Some(scp) = self.scp_repository.find_scp_by_hrn(scp_hrn).await? {
                 scps.push(scp);
             } else {
@@ -102,6 +283,9 @@ where
     ///
     /// Este método oculta los detalles de las entidades internas y solo
     /// expone el PolicySet que otros crates pueden usar.
+    ///
+    /// Una SCP que no se puede parsear se sustituye por un `forbid` total con
+    /// su mismo ID: su nivel deniega todo en lugar de quedar sin restricción.
     fn convert_to_policy_set(
         &self,
         scps: Vec<ServiceControlPolicy>,
@@ -109,8 +293,10 @@ where
         let mut policy_set = PolicySet::new();
 
         for scp in scps {
-            // Convertir la política Cedar string a Policy
-            match scp.document.parse::<cedar_policy::Policy>() {
+            // Convertir la política Cedar string a Policy, identificada por
+            // su HRN para que varias SCPs puedan convivir en un mismo set
+            let id = PolicyId::new(scp.hrn.to_string());
+            match Policy::parse(Some(id), &scp.document) {
                 Ok(policy) => {
                     if let Err(e) = policy_set.add(policy) {
                         warn!("Failed to add SCP policy to set: {}", e);
@@ -118,8 +304,18 @@ where
                     }
                 }
                 Err(e) => {
-                    warn!("Failed to parse SCP policy document for {}: {}", scp.hrn, e);
-                    // Continuamos con las demás políticas
+                    error!(
+                        "Failed to parse SCP policy document for {}, denying its level: {}",
+                        scp.hrn, e
+                    );
+                    let deny_all = Policy::parse(
+                        Some(PolicyId::new(scp.hrn.to_string())),
+                        "forbid(principal, action, resource);",
+                    )
+                    .expect("static forbid policy parses");
+                    if let Err(e) = policy_set.add(deny_all) {
+                        warn!("Failed to add SCP policy to set: {}", e);
+                    }
                 }
             }
         }
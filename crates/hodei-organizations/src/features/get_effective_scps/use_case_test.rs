@@ -1,89 +1,403 @@
-use crate::features::get_effective_scps::dto::{EffectiveScpsView, GetEffectiveScpsCommand};
-use crate::features::get_effective_scps::mocks::{
-    MockAccountRepositoryPort, MockOuRepositoryPort, MockScpRepositoryPort,
+use crate::features::get_effective_scps::dto::{
+    EffectiveScpsKind, GetEffectiveScpsQuery, PreviewOuScpsQuery, ScpCombinationStrategy,
 };
+use crate::features::get_effective_scps::error::GetEffectiveScpsError;
+use crate::features::get_effective_scps::mocks::{MockOrgRepositoryPort, MockScpRepositoryPort};
 use crate::features::get_effective_scps::use_case::GetEffectiveScpsUseCase;
 use crate::internal::domain::{Account, OrganizationalUnit, ServiceControlPolicy};
+use cedar_policy::{Authorizer, Context, Decision, Entities, EntityUid, Request};
 use kernel::Hrn;
+use std::str::FromStr;
+
+const PERMIT_ALL: &str = "permit(principal, action, resource);";
+const PERMIT_READ: &str = r#"permit(principal, action == Action::"Read", resource);"#;
+const FORBID_DELETE: &str = r#"forbid(principal, action == Action::"Delete", resource);"#;
+
+fn hrn(resource_type: &str, id: &str) -> Hrn {
+    Hrn::new(
+        "aws".to_string(),
+        "organizations".to_string(),
+        "123456789012".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+}
+
+fn scp(id: &str, document: &str) -> ServiceControlPolicy {
+    ServiceControlPolicy::new(hrn("scp", id), id.to_string(), document.to_string())
+}
+
+fn ou(id: &str, parent_id: &str, scps: &[&str]) -> OrganizationalUnit {
+    let mut ou = OrganizationalUnit::new(id.to_string(), hrn("ou", parent_id));
+    ou.hrn = hrn("ou", id);
+    for scp_id in scps {
+        ou.attach_scp(hrn("scp", scp_id));
+    }
+    ou
+}
+
+fn account(parent_id: &str, scps: &[&str]) -> Account {
+    let mut account = Account::new(
+        hrn("account", "prod"),
+        "prod".to_string(),
+        Some(hrn("ou", parent_id)),
+    );
+    for scp_id in scps {
+        account.attach_scp(hrn("scp", scp_id));
+    }
+    account
+}
+
+fn request(action: &str) -> Request {
+    Request::new(
+        EntityUid::from_str(r#"User::"alice""#).unwrap(),
+        EntityUid::from_str(&format!(r#"Action::"{action}""#)).unwrap(),
+        EntityUid::from_str(r#"Bucket::"logs""#).unwrap(),
+        Context::empty(),
+        None,
+    )
+    .unwrap()
+}
+
+fn query() -> GetEffectiveScpsQuery {
+    GetEffectiveScpsQuery {
+        resource_hrn: hrn("account", "prod").to_string(),
+    }
+}
+
+/// root (read only) → workloads (everything) → prod account
+fn setup() -> (MockScpRepositoryPort, MockOrgRepositoryPort) {
+    let scps = MockScpRepositoryPort::new()
+        .with_scp(scp("read-only", PERMIT_READ))
+        .with_scp(scp("full-access", PERMIT_ALL))
+        .with_scp(scp("no-delete", FORBID_DELETE));
+    let org = MockOrgRepositoryPort::new()
+        .with_ou(ou("root", "root", &["read-only"]))
+        .with_ou(ou("workloads", "root", &["full-access"]))
+        .with_account(account("workloads", &[]));
+    (scps, org)
+}
 
 #[tokio::test]
-async fn test_get_effective_scps_for_account() {
+async fn test_cedar_default_only_sees_parent_ou() {
     // Arrange
-    let scp_repository = MockScpRepositoryPort::new();
-    let account_repository = MockAccountRepositoryPort::new();
-    let ou_repository = MockOuRepositoryPort::new();
-
-    // Create test entities
-    let account_hrn = Hrn::new("account", "test-account");
-    let parent_ou_hrn = Hrn::new("ou", "parent-ou");
-    let scp_hrn = Hrn::new("scp", "test-scp");
-
-    let account = Account::new(
-        account_hrn.clone(),
-        "TestAccount".to_string(),
-        parent_ou_hrn.clone(),
-    )
-    .with_attached_scp(scp_hrn.clone());
+    let (scps, org) = setup();
+    let use_case = GetEffectiveScpsUseCase::new(scps, org);
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert_eq!(response.strategy, ScpCombinationStrategy::CedarDefault);
+    assert_eq!(response.levels.len(), 1);
+    assert_eq!(response.levels[0].hrn, hrn("ou", "workloads"));
+    assert_eq!(response.policies.policies().count(), 1);
+    let entities = Entities::empty();
+    assert_eq!(
+        response.evaluate(&request("Read"), &entities),
+        Decision::Allow
+    );
+    assert_eq!(
+        response.evaluate(&request("Write"), &entities),
+        Decision::Allow
+    );
+}
 
-    // Populate mocks
-    account_repository.with_account(account);
+#[tokio::test]
+async fn test_intersection_requires_every_level_to_allow() {
+    // Arrange
+    let (scps, org) = setup();
+    let use_case = GetEffectiveScpsUseCase::new(scps, org)
+        .with_strategy(ScpCombinationStrategy::AllowListIntersection);
 
-    // Create use case
-    let use_case = GetEffectiveScpsUseCase::new(scp_repository, account_repository, ou_repository);
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert - account (no SCPs), workloads, root
+    let level_hrns: Vec<_> = response.levels.iter().map(|l| l.hrn.clone()).collect();
+    assert_eq!(
+        level_hrns,
+        vec![
+            hrn("account", "prod"),
+            hrn("ou", "workloads"),
+            hrn("ou", "root")
+        ]
+    );
+    assert_eq!(response.policies.policies().count(), 2);
+    let entities = Entities::empty();
+    assert_eq!(
+        response.evaluate(&request("Read"), &entities),
+        Decision::Allow
+    );
+    assert_eq!(
+        response.evaluate(&request("Write"), &entities),
+        Decision::Deny
+    );
+}
 
-    // Create command
-    let command = GetEffectiveScpsCommand {
-        target_hrn: account_hrn.to_string(),
-    };
+#[tokio::test]
+async fn test_intersection_forbid_at_any_level_denies() {
+    // Arrange - every level allows everything, and the account also
+    // forbids deletes
+    let scps = MockScpRepositoryPort::new()
+        .with_scp(scp("full-access", PERMIT_ALL))
+        .with_scp(scp("no-delete", FORBID_DELETE));
+    let org = MockOrgRepositoryPort::new()
+        .with_ou(ou("root", "root", &["full-access"]))
+        .with_account(account("root", &["full-access", "no-delete"]));
+    let use_case = GetEffectiveScpsUseCase::new(scps, org)
+        .with_strategy(ScpCombinationStrategy::AllowListIntersection);
 
     // Act
-    let result = use_case.execute(command).await;
+    let response = use_case.execute(query()).await.unwrap();
 
     // Assert
-    assert!(result.is_ok());
-    let effective_scps_view = result.unwrap();
-    assert_eq!(effective_scps_view.target_hrn, account_hrn.to_string());
+    let entities = Entities::empty();
     assert_eq!(
-        effective_scps_view.effective_scps,
-        vec![scp_hrn.to_string()]
+        response.evaluate(&request("Write"), &entities),
+        Decision::Allow
+    );
+    assert_eq!(
+        response.evaluate(&request("Delete"), &entities),
+        Decision::Deny
     );
 }
 
 #[tokio::test]
-async fn test_get_effective_scps_for_ou() {
+async fn test_intersection_without_any_scp_denies() {
     // Arrange
-    let scp_repository = MockScpRepositoryPort::new();
-    let account_repository = MockAccountRepositoryPort::new();
-    let ou_repository = MockOuRepositoryPort::new();
+    let org = MockOrgRepositoryPort::new()
+        .with_ou(ou("root", "root", &[]))
+        .with_account(account("root", &[]));
+    let use_case = GetEffectiveScpsUseCase::new(MockScpRepositoryPort::new(), org)
+        .with_strategy(ScpCombinationStrategy::AllowListIntersection);
 
-    // Create test entities
-    let ou_hrn = Hrn::new("ou", "test-ou");
-    let parent_ou_hrn = Hrn::new("ou", "parent-ou");
-    let scp_hrn = Hrn::new("scp", "test-scp");
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
 
-    let ou = OrganizationalUnit::new(ou_hrn.clone(), "TestOU".to_string(), parent_ou_hrn.clone())
-        .with_attached_scp(scp_hrn.clone());
+    // Assert
+    assert_eq!(
+        response.evaluate(&request("Read"), &Entities::empty()),
+        Decision::Deny
+    );
+}
 
-    // Populate mocks
-    ou_repository.with_ou(ou);
+#[tokio::test]
+async fn test_intersection_reports_hierarchy_cycle() {
+    // Arrange
+    let org = MockOrgRepositoryPort::new()
+        .with_ou(ou("a", "b", &[]))
+        .with_ou(ou("b", "a", &[]))
+        .with_account(account("a", &[]));
+    let use_case = GetEffectiveScpsUseCase::new(MockScpRepositoryPort::new(), org)
+        .with_strategy(ScpCombinationStrategy::AllowListIntersection);
 
-    // Create use case
-    let use_case = GetEffectiveScpsUseCase::new(scp_repository, account_repository, ou_repository);
+    // Act
+    let result = use_case.execute(query()).await;
 
-    // Create command
-    let command = GetEffectiveScpsCommand {
-        target_hrn: ou_hrn.to_string(),
-    };
+    // Assert
+    assert!(matches!(
+        result,
+        Err(GetEffectiveScpsError::HierarchyCycle(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_several_scps_on_one_ou_are_all_kept() {
+    // Arrange
+    let scps = MockScpRepositoryPort::new()
+        .with_scp(scp("full-access", PERMIT_ALL))
+        .with_scp(scp("no-delete", FORBID_DELETE));
+    let org = MockOrgRepositoryPort::new()
+        .with_ou(ou("workloads", "workloads", &["full-access", "no-delete"]))
+        .with_account(account("workloads", &[]));
+    let use_case = GetEffectiveScpsUseCase::new(scps, org);
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert_eq!(response.policies.policies().count(), 2);
+    assert_eq!(
+        response.evaluate(&request("Delete"), &Entities::empty()),
+        Decision::Deny
+    );
+}
+
+fn preview_query(ou_id: &str) -> PreviewOuScpsQuery {
+    PreviewOuScpsQuery {
+        ou_hrn: hrn("ou", ou_id).to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_ou_preview_walks_from_the_ou_to_the_root() {
+    // Arrange
+    let (scps, org) = setup();
+    let use_case = GetEffectiveScpsUseCase::new(scps, org)
+        .with_strategy(ScpCombinationStrategy::AllowListIntersection);
 
     // Act
-    let result = use_case.execute(command).await;
+    let response = use_case
+        .preview_for_ou(preview_query("workloads"))
+        .await
+        .unwrap();
 
     // Assert
-    assert!(result.is_ok());
-    let effective_scps_view = result.unwrap();
-    assert_eq!(effective_scps_view.target_hrn, ou_hrn.to_string());
+    assert!(response.is_preview());
+    let level_hrns: Vec<_> = response.levels.iter().map(|l| l.hrn.clone()).collect();
+    assert_eq!(level_hrns, vec![hrn("ou", "workloads"), hrn("ou", "root")]);
+    let entities = Entities::empty();
     assert_eq!(
-        effective_scps_view.effective_scps,
-        vec![scp_hrn.to_string()]
+        response.evaluate(&request("Read"), &entities),
+        Decision::Allow
     );
+    assert_eq!(
+        response.evaluate(&request("Write"), &entities),
+        Decision::Deny
+    );
+}
+
+#[tokio::test]
+async fn test_ou_preview_excludes_account_attachments() {
+    // Arrange - the account in workloads also forbids deletes
+    let scps = MockScpRepositoryPort::new()
+        .with_scp(scp("full-access", PERMIT_ALL))
+        .with_scp(scp("no-delete", FORBID_DELETE));
+    let org = MockOrgRepositoryPort::new()
+        .with_ou(ou("root", "root", &["full-access"]))
+        .with_ou(ou("workloads", "root", &["full-access"]))
+        .with_account(account("workloads", &["no-delete"]));
+    let use_case = GetEffectiveScpsUseCase::new(scps, org)
+        .with_strategy(ScpCombinationStrategy::AllowListIntersection);
+
+    // Act
+    let actual = use_case.execute(query()).await.unwrap();
+    let preview = use_case
+        .preview_for_ou(preview_query("workloads"))
+        .await
+        .unwrap();
+
+    // Assert
+    let entities = Entities::empty();
+    assert_eq!(actual.kind, EffectiveScpsKind::Actual);
+    assert_eq!(
+        actual.evaluate(&request("Delete"), &entities),
+        Decision::Deny
+    );
+    assert_eq!(preview.kind, EffectiveScpsKind::OuPreview);
+    assert_eq!(
+        preview.evaluate(&request("Delete"), &entities),
+        Decision::Allow
+    );
+}
+
+#[tokio::test]
+async fn test_ou_preview_under_cedar_default_sees_the_ou_only() {
+    // Arrange
+    let (scps, org) = setup();
+    let use_case = GetEffectiveScpsUseCase::new(scps, org);
+
+    // Act
+    let response = use_case
+        .preview_for_ou(preview_query("workloads"))
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(response.is_preview());
+    assert_eq!(response.levels.len(), 1);
+    assert_eq!(response.levels[0].hrn, hrn("ou", "workloads"));
+}
+
+#[tokio::test]
+async fn test_ou_preview_of_unknown_ou_is_ou_not_found() {
+    // Arrange
+    let (scps, org) = setup();
+    let use_case = GetEffectiveScpsUseCase::new(scps, org);
+
+    // Act
+    let missing = use_case.preview_for_ou(preview_query("sandbox")).await;
+    let account = use_case
+        .preview_for_ou(PreviewOuScpsQuery {
+            ou_hrn: hrn("account", "prod").to_string(),
+        })
+        .await;
+
+    // Assert
+    assert!(matches!(missing, Err(GetEffectiveScpsError::OuNotFound(_))));
+    assert!(matches!(
+        account,
+        Err(GetEffectiveScpsError::InvalidTargetType(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_effective_policy_set_applies_the_intersection() {
+    // Arrange - root reads only, workloads allows everything, and the
+    // account forbids deletes
+    let (scps, org) = setup();
+    let org = org.with_account(account("workloads", &["no-delete"]));
+    let use_case = GetEffectiveScpsUseCase::new(scps, org)
+        .with_strategy(ScpCombinationStrategy::AllowListIntersection);
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+    let policies = response.effective_policy_set();
+
+    // Assert - Cedar's default rule over the set decides as `evaluate`
+    let authorizer = Authorizer::new();
+    let entities = Entities::empty();
+    for (action, expected) in [
+        ("Read", Decision::Allow),
+        ("Write", Decision::Deny),
+        ("Delete", Decision::Deny),
+    ] {
+        let request = request(action);
+        assert_eq!(response.evaluate(&request, &entities), expected, "{action}");
+        assert_eq!(
+            authorizer
+                .is_authorized(&request, &policies, &entities)
+                .decision(),
+            expected,
+            "{action}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_unparseable_scp_denies_its_level() {
+    for strategy in [
+        ScpCombinationStrategy::CedarDefault,
+        ScpCombinationStrategy::AllowListIntersection,
+    ] {
+        // Arrange - the parent OU's only SCP is broken
+        let scps = MockScpRepositoryPort::new()
+            .with_scp(scp("full-access", PERMIT_ALL))
+            .with_scp(scp("broken", "permit(principal, action"));
+        let org = MockOrgRepositoryPort::new()
+            .with_ou(ou("root", "root", &["full-access"]))
+            .with_ou(ou("workloads", "root", &["broken"]))
+            .with_account(account("workloads", &[]));
+        let use_case = GetEffectiveScpsUseCase::new(scps, org).with_strategy(strategy);
+
+        // Act
+        let response = use_case.execute(query()).await.unwrap();
+
+        // Assert
+        let request = request("Read");
+        let entities = Entities::empty();
+        assert_eq!(
+            response.evaluate(&request, &entities),
+            Decision::Deny,
+            "{strategy:?}"
+        );
+        assert_eq!(
+            Authorizer::new()
+                .is_authorized(&request, &response.effective_policy_set(), &entities)
+                .decision(),
+            Decision::Deny,
+            "{strategy:?}"
+        );
+    }
 }
@@ -0,0 +1,19 @@
+use crate::features::hierarchy_lookup::adapter::{AccountFinderAdapter, OuFinderAdapter};
+use crate::features::get_effective_tags::use_case::GetEffectiveTagsUseCase;
+use crate::internal::application::ports::account_repository::AccountRepository;
+use crate::internal::application::ports::ou_repository::OuRepository;
+
+/// Crea el caso de uso con repositorios concretos Surreal u otros
+pub fn get_effective_tags_use_case<AR, OR>(
+    account_repository: AR,
+    ou_repository: OR,
+) -> GetEffectiveTagsUseCase<AccountFinderAdapter<AR>, OuFinderAdapter<OR>>
+where
+    AR: AccountRepository + Send + Sync,
+    OR: OuRepository + Send + Sync,
+{
+    GetEffectiveTagsUseCase::new(
+        AccountFinderAdapter::new(account_repository),
+        OuFinderAdapter::new(ou_repository),
+    )
+}
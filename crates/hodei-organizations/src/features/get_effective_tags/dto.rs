@@ -0,0 +1,39 @@
+use kernel::{Hrn, HrnVisitor, TenantScoped};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Query to resolve the effective tags of an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEffectiveTagsQuery {
+    pub account_hrn: Hrn,
+}
+
+impl TenantScoped for GetEffectiveTagsQuery {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.account_hrn);
+    }
+}
+
+/// A resolved tag value and where it came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffectiveTag {
+    pub value: String,
+    /// The account itself, or the OU the value was inherited from
+    pub source_hrn: Hrn,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveTagsResponse {
+    pub account_hrn: Hrn,
+    pub tags: BTreeMap<String, EffectiveTag>,
+}
+
+impl EffectiveTagsResponse {
+    /// The tags without their sources
+    pub fn values(&self) -> BTreeMap<String, String> {
+        self.tags
+            .iter()
+            .map(|(key, tag)| (key.clone(), tag.value.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,131 @@
+use crate::features::get_effective_tags::dto::{
+    EffectiveTag, EffectiveTagsResponse, GetEffectiveTagsQuery,
+};
+use crate::features::get_effective_tags::error::GetEffectiveTagsError;
+use crate::features::hierarchy_lookup::ports::{AccountFinderPort, OuFinderPort};
+use crate::internal::domain::OrganizationalUnit;
+use kernel::TenantContext;
+use std::collections::{BTreeMap, HashSet};
+use tracing::{info, warn};
+
+/// Caso de uso para resolver las etiquetas efectivas de una cuenta
+///
+/// Recorre las OUs ancestro de la cuenta desde su padre hasta la raíz,
+/// siguiendo el `parent_hrn` de cada OU. Precedencia, de mayor a menor:
+///
+/// 1. etiquetas de la propia cuenta;
+/// 2. etiquetas de la OU ancestro más cercana;
+/// 3. etiquetas de cada ancestro siguiente hasta la raíz.
+///
+/// La jerarquía es un árbol, así que se sigue un único camino aunque los
+/// datos estén mal formados: una OU listada como hija de varias OUs sigue
+/// teniendo un solo `parent_hrn`, que es el que cuenta. Un ciclo, o un padre
+/// que no existe, termina el recorrido con un aviso y los ancestros hallados.
+pub struct GetEffectiveTagsUseCase<AF, OF>
+where
+    AF: AccountFinderPort,
+    OF: OuFinderPort,
+{
+    account_finder: AF,
+    ou_finder: OF,
+}
+
+impl<AF, OF> GetEffectiveTagsUseCase<AF, OF>
+where
+    AF: AccountFinderPort,
+    OF: OuFinderPort,
+{
+    pub fn new(account_finder: AF, ou_finder: OF) -> Self {
+        Self {
+            account_finder,
+            ou_finder,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: GetEffectiveTagsQuery,
+    ) -> Result<EffectiveTagsResponse, GetEffectiveTagsError> {
+        info!(
+            "Resolving effective tags for account: {}",
+            query.account_hrn
+        );
+
+        let account = self
+            .account_finder
+            .find_account_by_hrn(&query.account_hrn)
+            .await?
+            .ok_or_else(|| GetEffectiveTagsError::AccountNotFound(query.account_hrn.to_string()))?;
+
+        let mut tags = BTreeMap::new();
+        let ancestors = match &account.parent_hrn {
+            Some(parent_hrn) => self.ancestors(parent_hrn).await?,
+            None => Vec::new(),
+        };
+        // Del más lejano al más cercano, para que los valores cercanos prevalezcan
+        for ou in ancestors.iter().rev() {
+            for (key, value) in &ou.tags {
+                tags.insert(
+                    key.clone(),
+                    EffectiveTag {
+                        value: value.clone(),
+                        source_hrn: ou.hrn.clone(),
+                    },
+                );
+            }
+        }
+        for (key, value) in &account.tags {
+            tags.insert(
+                key.clone(),
+                EffectiveTag {
+                    value: value.clone(),
+                    source_hrn: account.hrn.clone(),
+                },
+            );
+        }
+
+        Ok(EffectiveTagsResponse {
+            account_hrn: account.hrn,
+            tags,
+        })
+    }
+
+    /// Ejecuta la resolución en nombre de `tenant`
+    ///
+    /// Falla con `CrossTenantAccess` si la cuenta pertenece a otro tenant.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        query: GetEffectiveTagsQuery,
+    ) -> Result<EffectiveTagsResponse, GetEffectiveTagsError> {
+        tenant.run(query, |query| self.execute(query)).await
+    }
+
+    /// Las OUs desde `parent_hrn` hasta la raíz, la más cercana primero
+    async fn ancestors(
+        &self,
+        parent_hrn: &kernel::Hrn,
+    ) -> Result<Vec<OrganizationalUnit>, GetEffectiveTagsError> {
+        let mut ancestors = Vec::new();
+        let mut visited = HashSet::new();
+        let mut next = Some(parent_hrn.clone());
+
+        while let Some(ou_hrn) = next {
+            if !visited.insert(ou_hrn.clone()) {
+                warn!("OU hierarchy cycle at {}, stopping tag inheritance", ou_hrn);
+                break;
+            }
+            let Some(ou) = self.ou_finder.find_ou_by_hrn(&ou_hrn).await? else {
+                warn!(
+                    "OU referenced but not found: {}, assuming root reached",
+                    ou_hrn
+                );
+                break;
+            };
+            next = (!ou.is_root()).then(|| ou.parent_hrn.clone());
+            ancestors.push(ou);
+        }
+
+        Ok(ancestors)
+    }
+}
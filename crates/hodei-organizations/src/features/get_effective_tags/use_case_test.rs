@@ -0,0 +1,168 @@
+use crate::features::get_effective_tags::dto::{EffectiveTag, GetEffectiveTagsQuery};
+use crate::features::get_effective_tags::error::GetEffectiveTagsError;
+use crate::features::hierarchy_lookup::mocks::{MockAccountFinderPort, MockOuFinderPort};
+use crate::features::get_effective_tags::use_case::GetEffectiveTagsUseCase;
+use crate::internal::domain::{Account, OrganizationalUnit};
+use kernel::Hrn;
+use std::collections::BTreeMap;
+
+fn hrn(resource_type: &str, id: &str) -> Hrn {
+    Hrn::new(
+        "aws".to_string(),
+        "organizations".to_string(),
+        "123456789012".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+}
+
+fn ou(id: &str, parent_id: &str, tags: &[(&str, &str)]) -> OrganizationalUnit {
+    let mut ou = OrganizationalUnit::new(id.to_string(), hrn("ou", parent_id));
+    ou.hrn = hrn("ou", id);
+    for (key, value) in tags {
+        ou.set_tag(key.to_string(), value.to_string());
+    }
+    ou
+}
+
+fn account(parent_id: Option<&str>, tags: &[(&str, &str)]) -> Account {
+    let mut account = Account::new(
+        hrn("account", "prod"),
+        "prod".to_string(),
+        parent_id.map(|id| hrn("ou", id)),
+    );
+    for (key, value) in tags {
+        account.set_tag(key.to_string(), value.to_string());
+    }
+    account
+}
+
+fn query() -> GetEffectiveTagsQuery {
+    GetEffectiveTagsQuery {
+        account_hrn: hrn("account", "prod"),
+    }
+}
+
+fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// root → workloads → team, each tagging `cost-center` and one tag of its own
+fn tree() -> MockOuFinderPort {
+    MockOuFinderPort::default()
+        .with_ou(ou(
+            "root",
+            "root",
+            &[("cost-center", "cc-root"), ("company", "acme")],
+        ))
+        .with_ou(ou(
+            "workloads",
+            "root",
+            &[("cost-center", "cc-workloads"), ("env", "shared")],
+        ))
+        .with_ou(ou(
+            "team",
+            "workloads",
+            &[("cost-center", "cc-team"), ("team", "payments")],
+        ))
+}
+
+#[tokio::test]
+async fn test_nearest_ancestor_wins_and_account_overrides_all() {
+    // Arrange
+    let accounts =
+        MockAccountFinderPort::default().with_account(account(Some("team"), &[("env", "prod")]));
+    let use_case = GetEffectiveTagsUseCase::new(accounts, tree());
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert_eq!(
+        response.values(),
+        values(&[
+            ("company", "acme"),
+            ("cost-center", "cc-team"),
+            ("env", "prod"),
+            ("team", "payments"),
+        ])
+    );
+    assert_eq!(
+        response.tags["cost-center"],
+        EffectiveTag {
+            value: "cc-team".to_string(),
+            source_hrn: hrn("ou", "team"),
+        }
+    );
+    assert_eq!(response.tags["env"].source_hrn, hrn("account", "prod"));
+    assert_eq!(response.tags["company"].source_hrn, hrn("ou", "root"));
+}
+
+#[tokio::test]
+async fn test_account_without_parent_has_only_its_own_tags() {
+    // Arrange
+    let accounts =
+        MockAccountFinderPort::default().with_account(account(None, &[("env", "sandbox")]));
+    let use_case = GetEffectiveTagsUseCase::new(accounts, tree());
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert_eq!(response.values(), values(&[("env", "sandbox")]));
+}
+
+#[tokio::test]
+async fn test_missing_ancestor_ends_inheritance() {
+    // Arrange - team's parent was never stored
+    let ous = MockOuFinderPort::default().with_ou(ou("team", "gone", &[("team", "payments")]));
+    let accounts = MockAccountFinderPort::default().with_account(account(Some("team"), &[]));
+    let use_case = GetEffectiveTagsUseCase::new(accounts, ous);
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert
+    assert_eq!(response.values(), values(&[("team", "payments")]));
+}
+
+#[tokio::test]
+async fn test_malformed_hierarchy_cycle_is_cut() {
+    // Arrange - a and b are each other's parent, and b is also listed
+    // under root as a second parent
+    let mut root = ou("root", "root", &[("company", "acme")]);
+    root.add_child_ou(hrn("ou", "b"));
+    let ous = MockOuFinderPort::default()
+        .with_ou(root)
+        .with_ou(ou("a", "b", &[("cost-center", "cc-a")]))
+        .with_ou(ou("b", "a", &[("cost-center", "cc-b"), ("env", "b")]));
+    let accounts = MockAccountFinderPort::default().with_account(account(Some("a"), &[]));
+    let use_case = GetEffectiveTagsUseCase::new(accounts, ous);
+
+    // Act
+    let response = use_case.execute(query()).await.unwrap();
+
+    // Assert - Only the parent_hrn path is followed, once around the cycle
+    assert_eq!(
+        response.values(),
+        values(&[("cost-center", "cc-a"), ("env", "b")])
+    );
+}
+
+#[tokio::test]
+async fn test_account_not_found() {
+    // Arrange
+    let use_case = GetEffectiveTagsUseCase::new(MockAccountFinderPort::default(), tree());
+
+    // Act
+    let result = use_case.execute(query()).await;
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(GetEffectiveTagsError::AccountNotFound(_))
+    ));
+}
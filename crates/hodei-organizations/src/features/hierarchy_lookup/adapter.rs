@@ -0,0 +1,52 @@
+use crate::features::hierarchy_lookup::ports::{AccountFinderPort, OuFinderPort};
+use crate::internal::application::ports::account_repository::{
+    AccountRepository, AccountRepositoryError,
+};
+use crate::internal::application::ports::ou_repository::{OuRepository, OuRepositoryError};
+use crate::internal::domain::{Account, OrganizationalUnit};
+use async_trait::async_trait;
+use kernel::Hrn;
+
+/// Adapter that implements the AccountFinderPort trait using the AccountRepository
+pub struct AccountFinderAdapter<AR: AccountRepository + Send + Sync> {
+    repository: AR,
+}
+
+impl<AR: AccountRepository + Send + Sync> AccountFinderAdapter<AR> {
+    /// Create a new adapter instance
+    pub fn new(repository: AR) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<AR: AccountRepository + Send + Sync> AccountFinderPort for AccountFinderAdapter<AR> {
+    async fn find_account_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        self.repository.find_by_hrn(hrn).await
+    }
+}
+
+/// Adapter that implements the OuFinderPort trait using the OuRepository
+pub struct OuFinderAdapter<OR: OuRepository + Send + Sync> {
+    repository: OR,
+}
+
+impl<OR: OuRepository + Send + Sync> OuFinderAdapter<OR> {
+    /// Create a new adapter instance
+    pub fn new(repository: OR) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<OR: OuRepository + Send + Sync> OuFinderPort for OuFinderAdapter<OR> {
+    async fn find_ou_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<OrganizationalUnit>, OuRepositoryError> {
+        self.repository.find_by_hrn(hrn).await
+    }
+}
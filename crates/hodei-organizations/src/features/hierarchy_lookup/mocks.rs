@@ -0,0 +1,58 @@
+use crate::features::hierarchy_lookup::ports::{AccountFinderPort, OuFinderPort};
+use crate::internal::application::ports::account_repository::AccountRepositoryError;
+use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use crate::internal::domain::{Account, OrganizationalUnit};
+use async_trait::async_trait;
+use kernel::Hrn;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Mock implementation of AccountFinderPort for testing
+#[derive(Debug, Default)]
+pub struct MockAccountFinderPort {
+    accounts: RwLock<HashMap<String, Account>>,
+}
+
+impl MockAccountFinderPort {
+    pub fn with_account(self, account: Account) -> Self {
+        let hrn_string = account.hrn.to_string();
+        self.accounts.write().unwrap().insert(hrn_string, account);
+        self
+    }
+}
+
+#[async_trait]
+impl AccountFinderPort for MockAccountFinderPort {
+    async fn find_account_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<Account>, AccountRepositoryError> {
+        let accounts = self.accounts.read().unwrap();
+        Ok(accounts.get(&hrn.to_string()).cloned())
+    }
+}
+
+/// Mock implementation of OuFinderPort for testing
+#[derive(Debug, Default)]
+pub struct MockOuFinderPort {
+    ous: RwLock<HashMap<String, OrganizationalUnit>>,
+}
+
+impl MockOuFinderPort {
+    pub fn with_ou(self, ou: OrganizationalUnit) -> Self {
+        let hrn_string = ou.hrn.to_string();
+        self.ous.write().unwrap().insert(hrn_string, ou);
+        self
+    }
+}
+
+#[async_trait]
+impl OuFinderPort for MockOuFinderPort {
+    async fn find_ou_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<OrganizationalUnit>, OuRepositoryError> {
+        let ous = self.ous.read().unwrap();
+        Ok(ous.get(&hrn.to_string()).cloned())
+    }
+}
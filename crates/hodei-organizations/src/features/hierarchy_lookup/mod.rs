@@ -0,0 +1,13 @@
+//! Puertos de lectura de la jerarquía organizacional
+//!
+//! Los casos de uso que recorren la cadena de OUs de una cuenta
+//! (`get_effective_tags`, `get_account_ancestry`) comparten estos puertos,
+//! sus adaptadores sobre los repositorios y los mocks de sus tests.
+
+pub mod adapter;
+#[cfg(test)]
+pub mod mocks;
+pub mod ports;
+
+pub use adapter::{AccountFinderAdapter, OuFinderAdapter};
+pub use ports::{AccountFinderPort, OuFinderPort};
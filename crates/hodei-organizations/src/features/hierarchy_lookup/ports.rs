@@ -0,0 +1,24 @@
+use crate::internal::application::ports::account_repository::AccountRepositoryError;
+use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use crate::internal::domain::{Account, OrganizationalUnit};
+use kernel::Hrn;
+
+/// Port for retrieving accounts
+#[async_trait::async_trait]
+pub trait AccountFinderPort: Send + Sync {
+    /// Find an account by HRN
+    async fn find_account_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<Account>, AccountRepositoryError>;
+}
+
+/// Port for retrieving organizational units
+#[async_trait::async_trait]
+pub trait OuFinderPort: Send + Sync {
+    /// Find an OU by HRN
+    async fn find_ou_by_hrn(
+        &self,
+        hrn: &Hrn,
+    ) -> Result<Option<OrganizationalUnit>, OuRepositoryError>;
+}
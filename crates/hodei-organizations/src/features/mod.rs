@@ -1,6 +1,12 @@
 pub mod create_account;
+pub mod create_accounts_batch;
 pub mod create_ou;
+pub mod delete_account;
+pub mod delete_ou;
 pub mod move_account;
 pub mod create_scp;
 pub mod attach_scp;
 pub mod get_effective_scps;
+pub mod get_effective_tags;
+pub mod hierarchy_lookup;
+pub mod get_account_ancestry;
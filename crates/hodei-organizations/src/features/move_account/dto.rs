@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use kernel::Hrn;
+use kernel::{Hrn, HrnVisitor, TenantScoped};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MoveAccountCommand {
@@ -8,6 +8,14 @@ pub struct MoveAccountCommand {
     pub target_ou_hrn: Hrn,
 }
 
+impl TenantScoped for MoveAccountCommand {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.account_hrn);
+        visitor.hrn(&self.source_ou_hrn);
+        visitor.hrn(&self.target_ou_hrn);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountView {
     pub hrn: Hrn,
@@ -1,6 +1,7 @@
 use thiserror::Error;
 use crate::internal::application::ports::account_repository::AccountRepositoryError;
 use crate::internal::application::ports::ou_repository::OuRepositoryError;
+use kernel::CrossTenantAccess;
 
 #[derive(Debug, Error)]
 pub enum MoveAccountError {
@@ -14,4 +15,6 @@ pub enum MoveAccountError {
     SourceOuNotFound,
     #[error("Target OU not found")]
     TargetOuNotFound,
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
@@ -135,6 +135,13 @@ impl AccountRepository for MockAccountRepository {
             Ok(())
         }
     }
+    async fn delete(
+        &self,
+        _hrn: &Hrn,
+    ) -> Result<(), crate::internal::application::ports::account_repository::AccountRepositoryError>
+    {
+        Ok(())
+    }
 }
 
 /// Mock OuRepository for testing
@@ -185,6 +192,7 @@ impl OuRepository for MockOuRepository {
                     child_ous: std::collections::HashSet::new(),
                     child_accounts,
                     attached_scps: std::collections::HashSet::new(),
+                    tags: Default::default(),
                 }))
             }
             "target" => {
@@ -203,6 +211,7 @@ impl OuRepository for MockOuRepository {
                     child_ous: std::collections::HashSet::new(),
                     child_accounts: std::collections::HashSet::new(),
                     attached_scps: std::collections::HashSet::new(),
+                    tags: Default::default(),
                 }))
             }
             _ => Ok(None),
@@ -226,6 +235,13 @@ impl OuRepository for MockOuRepository {
             Ok(())
         }
     }
+
+    async fn delete(
+        &self,
+        _hrn: &Hrn,
+    ) -> Result<(), crate::internal::application::ports::ou_repository::OuRepositoryError> {
+        Ok(())
+    }
 }
 
 /// Mock UnitOfWorkFactory for testing
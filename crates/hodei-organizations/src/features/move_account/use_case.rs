@@ -1,6 +1,7 @@
 use crate::features::move_account::dto::MoveAccountCommand;
 use crate::features::move_account::error::MoveAccountError;
 use crate::features::move_account::ports::{MoveAccountUnitOfWork, MoveAccountUnitOfWorkFactory};
+use kernel::TenantContext;
 use std::sync::Arc;
 
 /// Transactional MoveAccountUseCase using UnitOfWork pattern
@@ -42,6 +43,18 @@ impl<UWF: MoveAccountUnitOfWorkFactory> MoveAccountUseCase<UWF> {
         }
     }
 
+    /// Execute the use case on behalf of `tenant`
+    ///
+    /// The account and both OUs must belong to the tenant; an account can't be
+    /// moved into or out of another tenant's organization.
+    pub async fn execute_for_tenant(
+        &self,
+        tenant: &TenantContext,
+        command: MoveAccountCommand,
+    ) -> Result<(), MoveAccountError> {
+        tenant.run(command, |command| self.execute(command)).await
+    }
+
     async fn execute_within_transaction<UOW: MoveAccountUnitOfWork>(
         &self,
         command: &MoveAccountCommand,
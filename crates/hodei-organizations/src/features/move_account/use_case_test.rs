@@ -1,7 +1,8 @@
-use kernel::Hrn;
+use kernel::{Hrn, TenantContext};
 use std::sync::Arc;
 
 use crate::features::move_account::dto::MoveAccountCommand;
+use crate::features::move_account::error::MoveAccountError;
 use crate::features::move_account::mocks::MockMoveAccountUnitOfWorkFactory;
 use crate::features::move_account::use_case::MoveAccountUseCase;
 
@@ -227,3 +228,56 @@ async fn test_transaction_atomicity_failure_rolls_back() {
         _ => panic!("Expected RepositoryError, got: {:?}", error),
     }
 }
+
+#[tokio::test]
+async fn test_move_account_into_another_tenant_is_rejected() {
+    // Arrange
+    let mock_factory = Arc::new(MockMoveAccountUnitOfWorkFactory::new());
+    let use_case = MoveAccountUseCase::new(mock_factory.clone());
+    let tenant = TenantContext::new("123456789012");
+
+    let foreign_ou = Hrn::new(
+        "aws".to_string(),
+        "hodei".to_string(),
+        "999999999999".to_string(),
+        "ou".to_string(),
+        "target".to_string(),
+    );
+    let command = MoveAccountCommand {
+        account_hrn: create_test_hrn("account", "test"),
+        source_ou_hrn: create_test_hrn("ou", "source"),
+        target_ou_hrn: foreign_ou.clone(),
+    };
+
+    // Act
+    let result = use_case.execute_for_tenant(&tenant, command).await;
+
+    // Assert
+    match result {
+        Err(MoveAccountError::CrossTenantAccess(e)) => {
+            assert_eq!(e.hrn, foreign_ou.to_string());
+            assert_eq!(e.tenant_id, "123456789012");
+        }
+        other => panic!("Expected CrossTenantAccess, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_move_account_within_tenant_succeeds() {
+    // Arrange
+    let mock_factory = Arc::new(MockMoveAccountUnitOfWorkFactory::new());
+    let use_case = MoveAccountUseCase::new(mock_factory.clone());
+    let tenant = TenantContext::new("123456789012");
+
+    let command = MoveAccountCommand {
+        account_hrn: create_test_hrn("account", "test"),
+        source_ou_hrn: create_test_hrn("ou", "source"),
+        target_ou_hrn: create_test_hrn("ou", "target"),
+    };
+
+    // Act
+    let result = use_case.execute_for_tenant(&tenant, command).await;
+
+    // Assert
+    assert!(result.is_ok(), "Move within the tenant should succeed");
+}
@@ -15,4 +15,6 @@ pub enum AccountRepositoryError {
 pub trait AccountRepository {
     async fn save(&self, account: &Account) -> Result<(), AccountRepositoryError>;
     async fn find_by_hrn(&self, hrn: &Hrn) -> Result<Option<Account>, AccountRepositoryError>;
+    /// Delete an account; deleting one that doesn't exist is not an error
+    async fn delete(&self, hrn: &Hrn) -> Result<(), AccountRepositoryError>;
 }
@@ -16,4 +16,6 @@ pub trait OuRepository {
     async fn save(&self, ou: &OrganizationalUnit) -> Result<(), OuRepositoryError>;
     async fn find_by_hrn(&self, hrn: &Hrn)
     -> Result<Option<OrganizationalUnit>, OuRepositoryError>;
+    /// Delete an OU; deleting one that doesn't exist is not an error
+    async fn delete(&self, hrn: &Hrn) -> Result<(), OuRepositoryError>;
 }
@@ -1,6 +1,6 @@
 use kernel::Hrn;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use kernel::{
     AttributeName, AttributeType, AttributeValue, HodeiEntity, HodeiEntityType, Resource,
@@ -14,6 +14,9 @@ pub struct Account {
     pub name: String,
     pub parent_hrn: Option<Hrn>,
     pub attached_scps: HashSet<Hrn>,
+    /// Tags set on the account itself; see `get_effective_tags` for inherited ones
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
 }
 
 impl Account {
@@ -23,6 +26,7 @@ impl Account {
             name,
             parent_hrn,
             attached_scps: HashSet::new(),
+            tags: BTreeMap::new(),
         }
     }
 
@@ -41,6 +45,14 @@ impl Account {
     pub fn has_scp(&self, scp_hrn: &Hrn) -> bool {
         self.attached_scps.contains(scp_hrn)
     }
+
+    pub fn set_tag(&mut self, key: String, value: String) {
+        self.tags.insert(key, value);
+    }
+
+    pub fn remove_tag(&mut self, key: &str) -> Option<String> {
+        self.tags.remove(key)
+    }
 }
 
 // ============================================================================
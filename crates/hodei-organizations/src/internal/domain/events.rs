@@ -30,6 +30,27 @@ impl DomainEvent for AccountCreated {
     }
 }
 
+/// Event emitted once for the accounts created by a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountsBatchCreated {
+    /// HRN of the OU the accounts were created under
+    pub parent_hrn: Hrn,
+    /// HRNs of the created accounts
+    pub account_hrns: Vec<Hrn>,
+    /// Timestamp when the batch was created
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl DomainEvent for AccountsBatchCreated {
+    fn event_type(&self) -> &'static str {
+        "organizations.account.batch_created"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.parent_hrn.to_string())
+    }
+}
+
 /// Event emitted when an account is moved between organizational units
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountMoved {
@@ -1,6 +1,6 @@
 use kernel::Hrn;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use kernel::{
     AttributeName, AttributeType, AttributeValue, HodeiEntity, HodeiEntityType, Resource,
@@ -16,6 +16,9 @@ pub struct OrganizationalUnit {
     pub child_ous: HashSet<Hrn>,
     pub child_accounts: HashSet<Hrn>,
     pub attached_scps: HashSet<Hrn>,
+    /// Tags inherited by the accounts below this OU
+    #[serde(default)]
+    pub tags: BTreeMap<String, String>,
 }
 
 impl OrganizationalUnit {
@@ -34,6 +37,7 @@ impl OrganizationalUnit {
             child_ous: HashSet::new(),
             child_accounts: HashSet::new(),
             attached_scps: HashSet::new(),
+            tags: BTreeMap::new(),
         }
     }
 
@@ -60,6 +64,24 @@ impl OrganizationalUnit {
     pub fn detach_scp(&mut self, scp_hrn: &Hrn) {
         self.attached_scps.remove(scp_hrn);
     }
+
+    pub fn set_tag(&mut self, key: String, value: String) {
+        self.tags.insert(key, value);
+    }
+
+    pub fn remove_tag(&mut self, key: &str) -> Option<String> {
+        self.tags.remove(key)
+    }
+
+    /// Whether this is the root OU, whose parent is itself
+    pub fn is_root(&self) -> bool {
+        self.parent_hrn == self.hrn
+    }
+
+    /// Whether the OU has child OUs or accounts
+    pub fn has_children(&self) -> bool {
+        !self.child_ous.is_empty() || !self.child_accounts.is_empty()
+    }
 }
 
 // ============================================================================
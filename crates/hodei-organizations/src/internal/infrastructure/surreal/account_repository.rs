@@ -39,4 +39,14 @@ impl AccountRepository for SurrealAccountRepository {
             .map_err(|e| AccountRepositoryError::DatabaseError(e.to_string()))?;
         Ok(result)
     }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), AccountRepositoryError> {
+        let hrn_str = hrn.to_string();
+        let _: Option<Account> = self
+            .db
+            .delete(("account", &hrn_str))
+            .await
+            .map_err(|e| AccountRepositoryError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
 }
@@ -40,4 +40,14 @@ impl OuRepository for SurrealOuRepository {
             .map_err(|e| OuRepositoryError::DatabaseError(e.to_string()))?;
         Ok(result)
     }
+
+    async fn delete(&self, hrn: &Hrn) -> Result<(), OuRepositoryError> {
+        let hrn_str = hrn.to_string();
+        let _: Option<OrganizationalUnit> = self
+            .db
+            .delete(("ou", &hrn_str))
+            .await
+            .map_err(|e| OuRepositoryError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
 }
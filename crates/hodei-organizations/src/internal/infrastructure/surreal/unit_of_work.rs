@@ -54,6 +54,17 @@ where
             .map_err(|e| crate::internal::application::ports::account_repository::AccountRepositoryError::DatabaseError(e.to_string()))?;
         Ok(result)
     }
+
+    async fn delete(
+        &self,
+        hrn: &kernel::Hrn,
+    ) -> Result<(), crate::internal::application::ports::account_repository::AccountRepositoryError>
+    {
+        let hrn_str = hrn.to_string();
+        let _: Option<crate::internal::domain::account::Account> = self.db.delete(("account", &hrn_str)).await
+            .map_err(|e| crate::internal::application::ports::account_repository::AccountRepositoryError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
 }
 
 /// Transactional organizational unit repository that operates within a UnitOfWork context
@@ -111,6 +122,20 @@ where
             })?;
         Ok(result)
     }
+
+    async fn delete(
+        &self,
+        hrn: &kernel::Hrn,
+    ) -> Result<(), crate::internal::application::ports::ou_repository::OuRepositoryError> {
+        let hrn_str = hrn.to_string();
+        let _: Option<crate::internal::domain::ou::OrganizationalUnit> =
+            self.db.delete(("ou", &hrn_str)).await.map_err(|e| {
+                crate::internal::application::ports::ou_repository::OuRepositoryError::DatabaseError(
+                    e.to_string(),
+                )
+            })?;
+        Ok(())
+    }
 }
 
 /// Transactional service control policy repository that operates within a UnitOfWork context
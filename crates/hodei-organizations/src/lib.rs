@@ -19,11 +19,16 @@
 //! ```rust,ignore
 //! use hodei_organizations::{
 //!     CreateAccountUseCase,
+//!     CreateAccountsBatchUseCase,
 //!     CreateOuUseCase,
 //!     CreateScpUseCase,
 //!     AttachScpUseCase,
 //!     GetEffectiveScpsUseCase,
+//!     GetEffectiveTagsUseCase,
+//!     GetAccountAncestryUseCase,
 //!     MoveAccountUseCase,
+//!     DeleteAccountUseCase,
+//!     DeleteOuUseCase,
 //! };
 //! ```
 //!
@@ -72,11 +77,19 @@ mod internal;
 
 /// Feature: Crear una nueva cuenta
 pub use features::create_account::{
-    dto::{AccountView, CreateAccountCommand},
+    dto::{AccountView, CreateAccountCommand, IdempotencyReservation, IdempotentCreation},
     error::CreateAccountError,
+    idempotency_store::InMemoryIdempotencyStore,
     use_case::CreateAccountUseCase,
 };
 
+/// Feature: Crear cuentas en lote bajo una misma OU
+pub use features::create_accounts_batch::{
+    dto::{AccountResult, CreateAccountsBatchCommand, CreateAccountsBatchResponse},
+    error::{AccountItemError, CreateAccountsBatchError},
+    use_case::CreateAccountsBatchUseCase,
+};
+
 /// Feature: Crear una nueva unidad organizacional (OU)
 pub use features::create_ou::{
     dto::{CreateOuCommand, OuView},
@@ -100,11 +113,28 @@ pub use features::attach_scp::{
 
 /// Feature: Obtener las SCPs efectivas para un recurso
 pub use features::get_effective_scps::{
-    dto::{EffectiveScpsResponse, GetEffectiveScpsQuery},
+    dto::{
+        EffectiveScpsKind, EffectiveScpsResponse, GetEffectiveScpsQuery, PreviewOuScpsQuery,
+        ScpCombinationStrategy, ScpLevel,
+    },
     error::GetEffectiveScpsError,
     use_case::GetEffectiveScpsUseCase,
 };
 
+/// Feature: Obtener las etiquetas efectivas de una cuenta (propias y heredadas de sus OUs)
+pub use features::get_effective_tags::{
+    dto::{EffectiveTag, EffectiveTagsResponse, GetEffectiveTagsQuery},
+    error::GetEffectiveTagsError,
+    use_case::GetEffectiveTagsUseCase,
+};
+
+/// Feature: Trazar la ruta de una cuenta hasta la OU raíz
+pub use features::get_account_ancestry::{
+    dto::{AccountAncestryResponse, GetAccountAncestryQuery},
+    error::GetAccountAncestryError,
+    use_case::GetAccountAncestryUseCase,
+};
+
 /// Feature: Mover una cuenta a una nueva OU
 pub use features::move_account::{
     dto::{AccountView as MoveAccountView, MoveAccountCommand},
@@ -112,6 +142,20 @@ pub use features::move_account::{
     use_case::MoveAccountUseCase,
 };
 
+/// Feature: Eliminar una cuenta
+pub use features::delete_account::{
+    dto::{DeleteAccountCommand, DeletedAccountView},
+    error::DeleteAccountError,
+    use_case::DeleteAccountUseCase,
+};
+
+/// Feature: Eliminar una unidad organizacional (OU), opcionalmente en cascada
+pub use features::delete_ou::{
+    dto::{DeleteOuCommand, DeleteOuSummary, DetachedScp},
+    error::DeleteOuError,
+    use_case::DeleteOuUseCase,
+};
+
 // ============================================================================
 // Public Exports - Domain Events
 // ============================================================================
@@ -120,8 +164,9 @@ pub use features::move_account::{
 /// Públicos para permitir suscripción desde otros contextos.
 pub mod events {
     pub use crate::internal::domain::events::{
-        AccountCreated, AccountDeleted, AccountMoved, OrganizationalUnitCreated,
-        OrganizationalUnitDeleted, ScpAttached, ScpCreated, ScpDeleted, ScpDetached, ScpUpdated,
+        AccountCreated, AccountDeleted, AccountMoved, AccountsBatchCreated,
+        OrganizationalUnitCreated, OrganizationalUnitDeleted, ScpAttached, ScpCreated, ScpDeleted,
+        ScpDetached, ScpUpdated,
     };
 }
 
@@ -137,11 +182,21 @@ pub mod ports {
         AccountRepositoryPort, OuRepositoryPort, ScpRepositoryPort,
     };
 
+    /// Puertos de lectura de la jerarquía, compartidos por get_effective_tags y get_account_ancestry
+    pub use crate::features::hierarchy_lookup::ports::{AccountFinderPort, OuFinderPort};
+
     /// Puertos para features transaccionales
     pub use crate::features::create_account::ports::{
-        CreateAccountUnitOfWork, CreateAccountUnitOfWorkFactory,
+        CreateAccountIdempotencyStore, CreateAccountUnitOfWork, CreateAccountUnitOfWorkFactory,
+    };
+    pub use crate::features::create_accounts_batch::ports::{
+        CreateAccountsBatchUnitOfWork, CreateAccountsBatchUnitOfWorkFactory,
     };
     pub use crate::features::create_ou::ports::{CreateOuUnitOfWork, CreateOuUnitOfWorkFactory};
+    pub use crate::features::delete_account::ports::{
+        DeleteAccountUnitOfWork, DeleteAccountUnitOfWorkFactory,
+    };
+    pub use crate::features::delete_ou::ports::{DeleteOuUnitOfWork, DeleteOuUnitOfWorkFactory};
     pub use crate::features::move_account::ports::{
         MoveAccountUnitOfWork, MoveAccountUnitOfWorkFactory,
     };
@@ -194,9 +249,19 @@ pub mod infrastructure {
     pub use crate::features::create_account::surreal_adapter::{
         CreateAccountSurrealUnitOfWorkAdapter, CreateAccountSurrealUnitOfWorkFactoryAdapter,
     };
+    pub use crate::features::create_accounts_batch::surreal_adapter::{
+        CreateAccountsBatchSurrealUnitOfWorkAdapter,
+        CreateAccountsBatchSurrealUnitOfWorkFactoryAdapter,
+    };
     pub use crate::features::create_ou::surreal_adapter::{
         CreateOuSurrealUnitOfWorkAdapter, CreateOuSurrealUnitOfWorkFactoryAdapter,
     };
+    pub use crate::features::delete_account::surreal_adapter::{
+        DeleteAccountSurrealUnitOfWorkAdapter, DeleteAccountSurrealUnitOfWorkFactoryAdapter,
+    };
+    pub use crate::features::delete_ou::surreal_adapter::{
+        DeleteOuSurrealUnitOfWorkAdapter, DeleteOuSurrealUnitOfWorkFactoryAdapter,
+    };
     pub use crate::features::move_account::surreal_adapter::{
         MoveAccountSurrealUnitOfWorkAdapter, MoveAccountSurrealUnitOfWorkFactoryAdapter,
     };
@@ -24,6 +24,7 @@ fn test_create_account_command_creation() {
             "ou".to_string(),
             "root".to_string(),
         )),
+        idempotency_token: None,
     };
 
     // Assert: Verificar estructura del comando
@@ -124,6 +125,7 @@ fn test_documented_usage_pattern() {
     // let command = CreateAccountCommand {
     //     name: "production".to_string(),
     //     parent_hrn: None,
+    //     idempotency_token: None,
     // };
     //
     // let result = use_case.execute(command).await?;
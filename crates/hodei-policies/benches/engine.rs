@@ -0,0 +1,76 @@
+//! Authorization against policy sets of growing size
+//!
+//! The set is activated once outside the measured loop, so only evaluation
+//! is timed. The principal carries a large `tags` set and a nested record,
+//! the shapes most likely to make evaluation grow faster than the set.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use hodei_policies::bench::{AuthorizationEngine, BenchEntity, EngineRequest, policy_set};
+use kernel::HodeiEntity;
+use std::hint::black_box;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+fn is_authorized(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let principal = BenchEntity::new("User", "alice")
+        .with_flat_attributes(20)
+        .with_nested_record(8, 4)
+        .with_parents(4);
+    let resource = BenchEntity::new("Document", "doc").with_large_set(1_000);
+    let entities: Vec<&dyn HodeiEntity> = vec![&principal, &resource];
+
+    let mut group = c.benchmark_group("AuthorizationEngine::is_authorized/policies");
+    for count in [10, 100, 1_000, 5_000] {
+        let engine = AuthorizationEngine::new();
+        runtime
+            .block_on(engine.activate(policy_set(count), &entities, None))
+            .unwrap();
+        let request = EngineRequest::new(&principal, "read", &resource);
+
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.to_async(&runtime).iter(|| async {
+                let decision = engine.is_authorized(black_box(&request)).await.unwrap();
+                assert!(decision.is_allowed(), "{}", decision.reason());
+            })
+        });
+    }
+    group.finish();
+}
+
+fn activate(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let principal = BenchEntity::new("User", "alice").with_flat_attributes(20);
+    let resource = BenchEntity::new("Document", "doc").with_large_set(1_000);
+    let entities: Vec<&dyn HodeiEntity> = vec![&principal, &resource];
+
+    let mut group = c.benchmark_group("AuthorizationEngine::activate/policies");
+    for count in [10, 100, 1_000] {
+        let engine = AuthorizationEngine::new();
+        let policies = policy_set(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.to_async(&runtime).iter(|| async {
+                engine
+                    .activate(black_box(policies.clone()), &entities, None)
+                    .await
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn config() -> Criterion {
+    Criterion::default()
+        .measurement_time(Duration::from_secs(5))
+        .noise_threshold(0.03)
+}
+
+criterion_group! {
+    name = benches;
+    config = config();
+    targets = is_authorized, activate
+}
+criterion_main!(benches);
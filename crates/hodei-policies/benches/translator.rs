@@ -0,0 +1,57 @@
+//! Translation of entities to Cedar
+//!
+//! Grows one dimension of the entity at a time (flat attributes, record
+//! nesting, set size) so a super-linear cost shows up as a curve.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use hodei_policies::bench::{BenchEntity, translate_to_cedar_entity};
+use std::hint::black_box;
+use std::time::Duration;
+
+fn flat_attributes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("translate_to_cedar_entity/flat_attributes");
+    for count in [1, 10, 100, 1_000] {
+        let entity = BenchEntity::new("User", "alice").with_flat_attributes(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &entity, |b, entity| {
+            b.iter(|| translate_to_cedar_entity(black_box(entity)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn nested_records(c: &mut Criterion) {
+    let mut group = c.benchmark_group("translate_to_cedar_entity/nested_record_depth");
+    for depth in [1, 4, 16, 32] {
+        let entity = BenchEntity::new("User", "alice").with_nested_record(depth, 4);
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &entity, |b, entity| {
+            b.iter(|| translate_to_cedar_entity(black_box(entity)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn large_sets(c: &mut Criterion) {
+    let mut group = c.benchmark_group("translate_to_cedar_entity/set_size");
+    for size in [10, 100, 1_000, 10_000] {
+        let entity = BenchEntity::new("Document", "doc").with_large_set(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &entity, |b, entity| {
+            b.iter(|| translate_to_cedar_entity(black_box(entity)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn config() -> Criterion {
+    Criterion::default()
+        .measurement_time(Duration::from_secs(5))
+        .noise_threshold(0.03)
+}
+
+criterion_group! {
+    name = benches;
+    config = config();
+    targets = flat_attributes, nested_records, large_sets
+}
+criterion_main!(benches);
@@ -25,6 +25,7 @@
 pub mod build_schema {
     // Direct exports for convenience
     pub use crate::features::build_schema::error::BuildSchemaError;
+    pub use crate::features::build_schema::events::EventBusSchemaChangePublisher;
     pub use crate::features::build_schema::use_case::BuildSchemaUseCase;
     
     // Re-export as submodules for path compatibility (hodei_policies::build_schema::dto::*)
@@ -46,7 +47,9 @@ pub mod build_schema {
 // FEATURE: evaluate_policies
 // ============================================================================
 pub mod evaluate_policies {
-    pub use crate::features::evaluate_policies::error::EvaluatePoliciesError;
+    pub use crate::features::evaluate_policies::error::{
+        AttributeResolverError, EvaluatePoliciesError,
+    };
     pub use crate::features::evaluate_policies::use_case::EvaluatePoliciesUseCase;
     
     // Re-export dto, ports and factories as submodules
@@ -61,6 +64,25 @@ pub mod evaluate_policies {
     }
 }
 
+// ============================================================================
+// FEATURE: lint_policy
+// ============================================================================
+pub mod lint_policy {
+    pub use crate::features::lint_policy::error::LintPolicyError;
+    pub use crate::features::lint_policy::use_case::LintPolicyUseCase;
+
+    // Re-export dto, ports and factories as submodules
+    pub mod dto {
+        pub use crate::features::lint_policy::dto::*;
+    }
+    pub mod ports {
+        pub use crate::features::lint_policy::ports::*;
+    }
+    pub mod factories {
+        pub use crate::features::lint_policy::factories::*;
+    }
+}
+
 // ============================================================================
 // FEATURE: load_schema
 // ============================================================================
@@ -80,6 +102,24 @@ pub mod load_schema {
     }
 }
 
+// ============================================================================
+// FEATURE: match_policy
+// ============================================================================
+pub mod match_policy {
+    pub use crate::features::match_policy::broad_grants::{BroadGrant, BroadGrantFinder};
+    pub use crate::features::match_policy::error::MatchPolicyError;
+    pub use crate::features::match_policy::grant::{
+        AccessRequest, GrantCoverage, PolicyGrant, PrincipalScope,
+    };
+    pub use crate::features::match_policy::matcher::PolicyMatcher;
+    pub use crate::features::match_policy::references::{EntityReference, PolicyReferences};
+
+    // Re-export dto as a submodule
+    pub mod dto {
+        pub use crate::features::match_policy::dto::*;
+    }
+}
+
 // ============================================================================
 // FEATURE: playground_evaluate
 // ============================================================================
@@ -134,10 +174,50 @@ pub mod register_entity_type {
     }
 }
 
+// ============================================================================
+// FEATURE: register_schema_fragments
+// ============================================================================
+pub mod register_schema_fragments {
+    pub use crate::features::register_schema_fragments::error::RegisterSchemaFragmentsError;
+    pub use crate::features::register_schema_fragments::registry::SchemaFragmentRegistry;
+    pub use crate::features::register_schema_fragments::use_case::RegisterSchemaFragmentsUseCase;
+
+    // Re-export dto, ports and factories as submodules
+    pub mod dto {
+        pub use crate::features::register_schema_fragments::dto::*;
+    }
+    pub mod ports {
+        pub use crate::features::register_schema_fragments::ports::*;
+    }
+    pub mod factories {
+        pub use crate::features::register_schema_fragments::factories::*;
+    }
+}
+
+// ============================================================================
+// FEATURE: test_policy
+// ============================================================================
+pub mod test_policy {
+    pub use crate::features::test_policy::error::TestPolicyError;
+    pub use crate::features::test_policy::use_case::TestPolicyUseCase;
+
+    // Re-export dto, ports and factories as submodules
+    pub mod dto {
+        pub use crate::features::test_policy::dto::*;
+    }
+    pub mod ports {
+        pub use crate::features::test_policy::ports::*;
+    }
+    pub mod factories {
+        pub use crate::features::test_policy::factories::*;
+    }
+}
+
 // ============================================================================
 // FEATURE: validate_policy
 // ============================================================================
 pub mod validate_policy {
+    pub use crate::features::validate_policy::annotations::{PolicyAnnotations, policy_annotations};
     pub use crate::features::validate_policy::error::ValidatePolicyError;
     
     // Re-export dto, port and factories as submodules
@@ -0,0 +1,142 @@
+//! Benchmark harness
+//!
+//! Engine internals and fixture generators for the criterion benchmarks in
+//! `benches/`. Only compiled with the `bench` feature; not a stable API.
+//!
+//! ```text
+//! cargo bench -p hodei-policies --features bench
+//! ```
+//!
+//! Fixtures are deterministic: the same parameters always generate the same
+//! entities and policies, so runs are comparable from one commit to the next.
+
+use kernel::{AttributeName, AttributeValue, HodeiEntity, Hrn};
+use std::collections::HashMap;
+
+pub use crate::internal::engine::AuthorizationEngine;
+pub use crate::internal::engine::translator::translate_to_cedar_entity;
+pub use crate::internal::engine::types::EngineRequest;
+
+/// Entity with generated attributes
+#[derive(Debug, Clone)]
+pub struct BenchEntity {
+    hrn: Hrn,
+    attributes: HashMap<AttributeName, AttributeValue>,
+    parents: Vec<Hrn>,
+}
+
+impl BenchEntity {
+    /// `resource_type` entity `id`, with no attributes
+    pub fn new(resource_type: &str, id: &str) -> Self {
+        Self {
+            hrn: bench_hrn(resource_type, id),
+            attributes: HashMap::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    /// Add `count` flat attributes, cycling through strings, longs and bools
+    pub fn with_flat_attributes(mut self, count: usize) -> Self {
+        for i in 0..count {
+            let value = match i % 3 {
+                0 => AttributeValue::string(format!("value-{}", i)),
+                1 => AttributeValue::long(i as i64),
+                _ => AttributeValue::bool(i % 2 == 0),
+            };
+            self.attributes
+                .insert(attribute_name(&format!("attr_{}", i)), value);
+        }
+        self
+    }
+
+    /// Add a `nested` record attribute `depth` levels deep, each level with
+    /// `width` leaf fields besides the next level
+    pub fn with_nested_record(mut self, depth: usize, width: usize) -> Self {
+        self.attributes
+            .insert(attribute_name("nested"), nested_record(depth, width));
+        self
+    }
+
+    /// Add a `tags` set attribute of `size` distinct strings
+    pub fn with_large_set(mut self, size: usize) -> Self {
+        let tags = (0..size)
+            .map(|i| AttributeValue::string(format!("tag-{}", i)))
+            .collect();
+        self.attributes
+            .insert(attribute_name("tags"), AttributeValue::set(tags));
+        self
+    }
+
+    /// Make the entity a member of `count` groups
+    pub fn with_parents(mut self, count: usize) -> Self {
+        self.parents = (0..count)
+            .map(|i| bench_hrn("Group", &format!("group-{}", i)))
+            .collect();
+        self
+    }
+}
+
+impl HodeiEntity for BenchEntity {
+    fn hrn(&self) -> &Hrn {
+        &self.hrn
+    }
+
+    fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+        self.attributes.clone()
+    }
+
+    fn parent_hrns(&self) -> Vec<Hrn> {
+        self.parents.clone()
+    }
+}
+
+/// `count` policies as `(id, text)`, in the shapes real policy sets mix
+///
+/// Most are attribute-conditioned permits on other actions, so Cedar has to
+/// consider them without them applying; one in ten is a forbid on the
+/// `tags` set; the last one permits `read` for principals in `group-0`.
+pub fn policy_set(count: usize) -> Vec<(String, String)> {
+    (0..count)
+        .map(|i| {
+            let text = if i + 1 == count {
+                r#"permit(principal in Iam::Group::"group-0", action == Action::"read", resource);"#
+                    .to_string()
+            } else if i % 10 == 0 {
+                format!(
+                    r#"forbid(principal, action, resource) when {{ resource has tags && resource.tags.contains("blocked-{}") }};"#,
+                    i
+                )
+            } else {
+                format!(
+                    r#"permit(principal, action == Action::"action-{}", resource) when {{ principal has attr_0 && principal.attr_0 == "value-{}" }};"#,
+                    i, i
+                )
+            };
+            (format!("policy-{}", i), text)
+        })
+        .collect()
+}
+
+fn nested_record(depth: usize, width: usize) -> AttributeValue {
+    let mut fields: HashMap<String, AttributeValue> = (0..width)
+        .map(|i| (format!("field_{}", i), AttributeValue::long(i as i64)))
+        .collect();
+    if depth > 1 {
+        fields.insert("child".to_string(), nested_record(depth - 1, width));
+    }
+    AttributeValue::record(fields)
+}
+
+fn bench_hrn(resource_type: &str, id: &str) -> Hrn {
+    Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "bench".to_string(),
+        resource_type.to_string(),
+        id.to_string(),
+    )
+}
+
+fn attribute_name(name: &str) -> AttributeName {
+    AttributeName::new(name).expect("generated attribute names are valid")
+}
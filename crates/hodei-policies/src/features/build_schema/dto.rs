@@ -102,3 +102,73 @@ impl BuildSchemaResult {
         }
     }
 }
+
+/// Command to add entity types to the current schema without rebuilding it
+///
+/// The fragment is written in Cedar schema syntax or, if it starts with `{`,
+/// in Cedar's JSON schema format. It may only declare entity types; actions
+/// are registered through a full build.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AddEntityTypeCommand {
+    /// Schema fragment declaring the new entity types
+    pub fragment: String,
+
+    /// Version identifier of the resulting schema (optional)
+    pub version: Option<String>,
+}
+
+impl ActionTrait for AddEntityTypeCommand {
+    fn name() -> &'static str {
+        "AddEntityType"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("policies").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Policies::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Policies::Schema".to_string()
+    }
+}
+
+impl AddEntityTypeCommand {
+    /// Create a command adding the entity types declared in `fragment`
+    pub fn new(fragment: impl Into<String>) -> Self {
+        Self {
+            fragment: fragment.into(),
+            version: None,
+        }
+    }
+
+    /// Set the version of the resulting schema
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+}
+
+/// Result of adding entity types to the current schema
+#[derive(Debug, Clone)]
+pub struct AddEntityTypeResult {
+    /// Entity types the fragment added, e.g. `Plugin::Widget`
+    pub added_entity_types: Vec<String>,
+
+    /// Entity types the fragment declared exactly as they already were
+    pub unchanged_entity_types: Vec<String>,
+
+    /// Number of entity types in the resulting schema
+    pub entity_count: usize,
+
+    /// Number of action types in the resulting schema
+    pub action_count: usize,
+
+    /// Schema version identifier (if provided)
+    pub version: Option<String>,
+
+    /// Schema ID in storage; `None` if nothing was added and no schema was saved
+    pub schema_id: Option<String>,
+}
@@ -19,4 +19,10 @@ pub enum BuildSchemaError {
 
     #[error("An unexpected internal error occurred: {0}")]
     InternalError(String),
+
+    #[error("Invalid schema fragment: {0}")]
+    InvalidFragment(String),
+
+    #[error("Entity type '{0}' is already declared with a different definition")]
+    EntityTypeConflict(String),
 }
@@ -0,0 +1,35 @@
+//! Schema change announcements
+//!
+//! [`EventBusSchemaChangePublisher`] publishes a [`SchemaChanged`] domain
+//! event for every persisted schema, so other bounded contexts can react to
+//! a new schema, e.g. by revalidating the policies they store.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use kernel::SchemaChanged;
+use kernel::application::ports::event_bus::EventPublisher;
+use tracing::warn;
+
+use super::ports::SchemaChangePublisherPort;
+
+/// Schema change publisher that forwards the events to an event bus
+pub struct EventBusSchemaChangePublisher<P> {
+    publisher: Arc<P>,
+}
+
+impl<P> EventBusSchemaChangePublisher<P> {
+    pub fn new(publisher: Arc<P>) -> Self {
+        Self { publisher }
+    }
+}
+
+#[async_trait]
+impl<P: EventPublisher + 'static> SchemaChangePublisherPort for EventBusSchemaChangePublisher<P> {
+    async fn publish(&self, event: SchemaChanged) {
+        let schema_id = event.schema_id.clone();
+        if let Err(e) = self.publisher.publish(event).await {
+            warn!(%schema_id, error = %e, "Failed to publish schema change");
+        }
+    }
+}
@@ -3,7 +3,9 @@
 //! This module provides static factory functions following the Java Config pattern.
 //! Factories receive already-constructed dependencies and assemble use cases.
 
-use crate::features::build_schema::ports::{BuildSchemaPort, SchemaStoragePort};
+use crate::features::build_schema::ports::{
+    BuildSchemaPort, SchemaChangePublisherPort, SchemaStoragePort,
+};
 use crate::features::build_schema::use_case::BuildSchemaUseCase;
 use crate::features::register_action_type::RegisterActionTypeUseCase;
 use crate::features::register_action_type::ports::RegisterActionTypePort;
@@ -53,6 +55,46 @@ pub fn create_schema_registration_components<S: SchemaStoragePort + 'static>(
     Arc<dyn RegisterEntityTypePort>,
     Arc<dyn RegisterActionTypePort>,
     Arc<dyn BuildSchemaPort>,
+) {
+    assemble_schema_registration_components(storage, None)
+}
+
+/// Creates the schema registration bundle announcing every persisted schema
+///
+/// Like [`create_schema_registration_components`], but the schema building
+/// use case publishes a `SchemaChanged` event through `publisher` for each
+/// schema it persists.
+///
+/// # Arguments
+///
+/// * `storage` - Pre-constructed implementation of SchemaStoragePort
+/// * `publisher` - Port announcing the persisted schemas
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let publisher = Arc::new(EventBusSchemaChangePublisher::new(event_bus.clone()));
+/// let (entity_uc, action_uc, schema_uc) =
+///     factories::create_publishing_schema_registration_components(schema_storage, publisher);
+/// ```
+pub fn create_publishing_schema_registration_components<S: SchemaStoragePort + 'static>(
+    storage: Arc<S>,
+    publisher: Arc<dyn SchemaChangePublisherPort>,
+) -> (
+    Arc<dyn RegisterEntityTypePort>,
+    Arc<dyn RegisterActionTypePort>,
+    Arc<dyn BuildSchemaPort>,
+) {
+    assemble_schema_registration_components(storage, Some(publisher))
+}
+
+fn assemble_schema_registration_components<S: SchemaStoragePort + 'static>(
+    storage: Arc<S>,
+    publisher: Option<Arc<dyn SchemaChangePublisherPort>>,
+) -> (
+    Arc<dyn RegisterEntityTypePort>,
+    Arc<dyn RegisterActionTypePort>,
+    Arc<dyn BuildSchemaPort>,
 ) {
     // Create shared EngineBuilder (internal coordination)
     let builder = Arc::new(Mutex::new(EngineBuilder::new()));
@@ -62,7 +104,11 @@ pub fn create_schema_registration_components<S: SchemaStoragePort + 'static>(
         Arc::new(RegisterEntityTypeUseCase::new(builder.clone()));
     let action_uc: Arc<dyn RegisterActionTypePort> =
         Arc::new(RegisterActionTypeUseCase::new(builder.clone()));
-    let schema_uc: Arc<dyn BuildSchemaPort> = Arc::new(BuildSchemaUseCase::new(builder, storage));
+    let mut build_schema = BuildSchemaUseCase::new(builder, storage);
+    if let Some(publisher) = publisher {
+        build_schema = build_schema.with_publisher(publisher);
+    }
+    let schema_uc: Arc<dyn BuildSchemaPort> = Arc::new(build_schema);
 
     (entity_uc, action_uc, schema_uc)
 }
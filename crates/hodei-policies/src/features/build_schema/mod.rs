@@ -1,12 +1,14 @@
 pub mod dto;
 pub mod error;
+pub mod events;
 pub mod factories;
 pub mod ports;
 pub mod use_case;
 #[cfg(test)]
 pub mod use_case_test;
 
-pub use ports::BuildSchemaPort;
+pub use events::EventBusSchemaChangePublisher;
+pub use ports::{BuildSchemaPort, SchemaChangePublisherPort};
 
 // Re-export use case for external consumption
 pub use use_case::BuildSchemaUseCase;
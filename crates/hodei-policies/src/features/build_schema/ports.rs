@@ -5,8 +5,11 @@
 
 use async_trait::async_trait;
 use cedar_policy::Schema;
+use kernel::SchemaChanged;
 
-use crate::features::build_schema::dto::{BuildSchemaCommand, BuildSchemaResult};
+use crate::features::build_schema::dto::{
+    AddEntityTypeCommand, AddEntityTypeResult, BuildSchemaCommand, BuildSchemaResult,
+};
 use crate::features::build_schema::error::BuildSchemaError;
 
 /// Stored schema data retrieved from storage
@@ -67,6 +70,21 @@ pub trait BuildSchemaPort: Send + Sync {
         &self,
         command: BuildSchemaCommand,
     ) -> Result<BuildSchemaResult, BuildSchemaError>;
+
+    /// Add entity types to the current schema without rebuilding it
+    ///
+    /// Existing declarations are kept as they are, so policies valid against
+    /// the current schema stay valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityTypeConflict` if the fragment redeclares an existing
+    /// entity type differently, and an error if the resulting schema is
+    /// invalid or cannot be persisted. The current schema is then unchanged.
+    async fn add_entity_type(
+        &self,
+        command: AddEntityTypeCommand,
+    ) -> Result<AddEntityTypeResult, BuildSchemaError>;
 }
 
 /// Port for schema storage operations
@@ -220,3 +238,12 @@ pub trait SchemaStoragePort: Send + Sync {
         }
     }
 }
+
+/// Port for announcing that a new schema version was persisted
+///
+/// Announcements are fire-and-forget; a failure to deliver one never undoes
+/// the persisted schema.
+#[async_trait]
+pub trait SchemaChangePublisherPort: Send + Sync {
+    async fn publish(&self, event: SchemaChanged);
+}
@@ -1,10 +1,18 @@
-use crate::features::build_schema::dto::{BuildSchemaCommand, BuildSchemaResult};
+use crate::features::build_schema::dto::{
+    AddEntityTypeCommand, AddEntityTypeResult, BuildSchemaCommand, BuildSchemaResult,
+};
 use crate::features::build_schema::error::BuildSchemaError;
-use crate::features::build_schema::ports::{BuildSchemaPort, SchemaStoragePort};
+use crate::features::build_schema::ports::{
+    BuildSchemaPort, SchemaChangePublisherPort, SchemaStoragePort,
+};
 use crate::internal::engine::builder::EngineBuilder;
 use async_trait::async_trait;
+use cedar_policy::{Schema, SchemaFragment};
+use kernel::SchemaChanged;
+use serde_json::{Map, Value};
 use std::sync::{Arc, Mutex};
-use tracing::{info, warn};
+use tokio::sync::Mutex as TokioMutex;
+use tracing::{Instrument, info, info_span, warn};
 
 /// Use case for building and persisting the Cedar schema
 ///
@@ -21,11 +29,26 @@ use tracing::{info, warn};
 /// 4. Persists it via the SchemaStoragePort
 ///
 /// After building, the builder is reset so new registrations can begin.
+///
+/// Schemas are persisted in Cedar's JSON format. The last persisted schema
+/// is kept so entity types can be added to it with
+/// [`add_entity_type`](Self::add_entity_type) without a full rebuild; after
+/// a restart it is read back from storage. A full build replaces it,
+/// including any entity types added that way.
+///
+/// With a publisher set, every persisted schema is announced as a
+/// [`SchemaChanged`] event, versioned by the command's version or, without
+/// one, by the stored schema's ID.
 pub struct BuildSchemaUseCase<S: SchemaStoragePort> {
     /// Internal schema builder for collecting registrations
     builder: Arc<Mutex<EngineBuilder>>,
     /// Storage port for persisting the schema
     storage: Arc<S>,
+    /// Last persisted schema in Cedar's JSON format; held while persisting
+    /// so builds and additions are applied one at a time
+    current: TokioMutex<Option<Value>>,
+    /// Announces every persisted schema
+    publisher: Option<Arc<dyn SchemaChangePublisherPort>>,
 }
 
 impl<S: SchemaStoragePort> BuildSchemaUseCase<S> {
@@ -36,7 +59,18 @@ impl<S: SchemaStoragePort> BuildSchemaUseCase<S> {
     /// * `builder` - Shared reference to the EngineBuilder
     /// * `storage` - Implementation of the schema storage port
     pub fn new(builder: Arc<Mutex<EngineBuilder>>, storage: Arc<S>) -> Self {
-        Self { builder, storage }
+        Self {
+            builder,
+            storage,
+            current: TokioMutex::new(None),
+            publisher: None,
+        }
+    }
+
+    /// Announce every persisted schema through `publisher`
+    pub fn with_publisher(mut self, publisher: Arc<dyn SchemaChangePublisherPort>) -> Self {
+        self.publisher = Some(publisher);
+        self
     }
 
     /// Build and persist the Cedar schema
@@ -74,7 +108,8 @@ impl<S: SchemaStoragePort> BuildSchemaUseCase<S> {
     /// println!("Schema built with {} entities and {} actions",
     ///          result.entity_count, result.action_count);
     /// ```
-    #[tracing::instrument(skip(self, command), fields(
+    #[tracing::instrument(name = "build_schema", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
         version = ?command.version,
         validate = command.validate
     ))]
@@ -119,7 +154,11 @@ impl<S: SchemaStoragePort> BuildSchemaUseCase<S> {
 
         // 4. Build the schema (consumes the builder)
         info!("Building Cedar schema from registered types");
-        let schema = builder
+        let schema_json = builder
+            .to_json()
+            .map_err(|e| BuildSchemaError::SchemaBuildError(e.to_string()))?;
+        // Building checks the schema; what is persisted is its JSON form
+        builder
             .build_schema()
             .map_err(|e| BuildSchemaError::SchemaBuildError(e.to_string()))?;
 
@@ -133,24 +172,25 @@ impl<S: SchemaStoragePort> BuildSchemaUseCase<S> {
             info!("Schema validation passed");
         }
 
-        // 6. Serialize schema to string
-        // Cedar Schema doesn't have a direct serialization method, so we use Debug format
-        // In production, this should be replaced with proper schema persistence
-        let schema_string = format!("{:?}", schema);
+        // 6. Serialize the schema in Cedar's JSON format, so it can be read
+        // back and extended by `add_entity_type`
+        let schema_string = serde_json::to_string(&schema_json)
+            .map_err(|e| BuildSchemaError::SchemaBuildError(e.to_string()))?;
 
-        info!(
-            "Schema serialized as debug string ({} bytes)",
-            schema_string.len()
-        );
+        info!("Schema serialized as JSON ({} bytes)", schema_string.len());
 
         // 7. Persist the schema
         info!("Persisting schema to storage");
+        let mut current = self.current.lock().await;
         let schema_id = self
             .storage
             .save_schema(schema_string, command.version.clone())
+            .instrument(info_span!("persistence"))
             .await?;
+        *current = Some(schema_json);
 
         info!("Schema persisted successfully with ID: {}", schema_id);
+        self.announce(command.version.as_deref(), &schema_id).await;
 
         Ok(BuildSchemaResult::new(
             entity_count,
@@ -161,6 +201,119 @@ impl<S: SchemaStoragePort> BuildSchemaUseCase<S> {
         ))
     }
 
+    /// Add entity types to the current schema without rebuilding it
+    ///
+    /// The fragment's entity types are merged into the last persisted schema
+    /// (read from storage if this instance has not persisted one, an empty
+    /// one if nothing was ever stored), the result is checked by Cedar and
+    /// persisted as a new schema version. Declarations already in
+    /// the schema are never changed, so existing policies stay valid.
+    ///
+    /// Redeclaring an entity type exactly as it is already declared is
+    /// accepted and reported as unchanged; a fragment that adds nothing is
+    /// not persisted. The addition is atomic: on any error, including a
+    /// redeclaration with a different definition, the current schema is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The stored schema can't be read or is not in Cedar's JSON format
+    /// - The fragment does not parse or declares anything but entity types
+    /// - An entity type is redeclared with a different definition
+    /// - The resulting schema is invalid (e.g. it references unknown types)
+    /// - Schema persistence fails
+    #[tracing::instrument(name = "add_entity_type", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        version = ?command.version
+    ))]
+    pub async fn add_entity_type(
+        &self,
+        command: AddEntityTypeCommand,
+    ) -> Result<AddEntityTypeResult, BuildSchemaError> {
+        let fragment = info_span!("validation").in_scope(|| parse_fragment(&command.fragment))?;
+
+        let mut current = self.current.lock().await;
+        let mut candidate = match current.as_ref() {
+            Some(schema) => schema.clone(),
+            None => self.stored_schema().await?,
+        };
+        let (added, unchanged) = merge_entity_types(&mut candidate, fragment)?;
+        let (entity_count, action_count) = declaration_counts(&candidate);
+
+        if added.is_empty() {
+            info!("Fragment adds no entity types; schema left unchanged");
+            return Ok(AddEntityTypeResult {
+                added_entity_types: added,
+                unchanged_entity_types: unchanged,
+                entity_count,
+                action_count,
+                version: command.version,
+                schema_id: None,
+            });
+        }
+
+        Schema::from_json_value(candidate.clone())
+            .map_err(|e| BuildSchemaError::SchemaBuildError(e.to_string()))?;
+        let schema_string = serde_json::to_string(&candidate)
+            .map_err(|e| BuildSchemaError::SchemaBuildError(e.to_string()))?;
+        let schema_id = self
+            .storage
+            .save_schema(schema_string, command.version.clone())
+            .instrument(info_span!("persistence"))
+            .await?;
+        *current = Some(candidate);
+
+        info!(
+            added = ?added,
+            schema_id = %schema_id,
+            "Entity types added to the schema"
+        );
+        self.announce(command.version.as_deref(), &schema_id).await;
+
+        Ok(AddEntityTypeResult {
+            added_entity_types: added,
+            unchanged_entity_types: unchanged,
+            entity_count,
+            action_count,
+            version: command.version,
+            schema_id: Some(schema_id),
+        })
+    }
+
+    /// Publish a [`SchemaChanged`] event for the schema persisted as `schema_id`
+    async fn announce(&self, version: Option<&str>, schema_id: &str) {
+        if let Some(publisher) = &self.publisher {
+            publisher
+                .publish(SchemaChanged::new(version.unwrap_or(schema_id), schema_id))
+                .await;
+        }
+    }
+
+    /// Latest stored schema in Cedar's JSON format, or an empty one if none
+    /// was stored yet
+    ///
+    /// A stored schema that can't be read as a Cedar JSON schema is an error
+    /// rather than a fresh start, so an addition never replaces it with a
+    /// schema holding only the added entity types.
+    async fn stored_schema(&self) -> Result<Value, BuildSchemaError> {
+        let Some(stored) = self.storage.get_latest_schema().await? else {
+            return Ok(Value::Object(Map::new()));
+        };
+        let schema: Value = serde_json::from_str(&stored).map_err(|e| {
+            BuildSchemaError::SchemaStorageError(format!(
+                "Stored schema is not in Cedar's JSON format, run a full build first: {}",
+                e
+            ))
+        })?;
+        Schema::from_json_value(schema.clone()).map_err(|e| {
+            BuildSchemaError::SchemaStorageError(format!(
+                "Stored schema is not a valid Cedar schema, run a full build first: {}",
+                e
+            ))
+        })?;
+        Ok(schema)
+    }
+
     /// Get the current entity count without building
     ///
     /// This is useful for checking if any registrations exist before building.
@@ -217,4 +370,130 @@ impl<S: SchemaStoragePort> BuildSchemaPort for BuildSchemaUseCase<S> {
     ) -> Result<BuildSchemaResult, BuildSchemaError> {
         self.execute(command).await
     }
+
+    async fn add_entity_type(
+        &self,
+        command: AddEntityTypeCommand,
+    ) -> Result<AddEntityTypeResult, BuildSchemaError> {
+        self.add_entity_type(command).await
+    }
+}
+
+/// Parse a fragment that declares only entity types into Cedar's JSON format
+fn parse_fragment(source: &str) -> Result<Value, BuildSchemaError> {
+    let fragment = if source.trim_start().starts_with('{') {
+        SchemaFragment::from_json_str(source)
+            .map_err(|e| BuildSchemaError::InvalidFragment(e.to_string()))?
+    } else {
+        SchemaFragment::from_cedarschema_str(source)
+            .map(|(fragment, _warnings)| fragment)
+            .map_err(|e| BuildSchemaError::InvalidFragment(e.to_string()))?
+    };
+    let json = fragment
+        .to_json_value()
+        .map_err(|e| BuildSchemaError::InvalidFragment(e.to_string()))?;
+
+    let mut declares_entity_types = false;
+    for (namespace, declarations) in json.as_object().into_iter().flatten() {
+        for (kind, items) in declarations.as_object().into_iter().flatten() {
+            let is_empty = items.as_object().is_some_and(Map::is_empty);
+            match kind.as_str() {
+                "entityTypes" => declares_entity_types |= !is_empty,
+                "annotations" => {}
+                _ if is_empty => {}
+                _ => {
+                    return Err(BuildSchemaError::InvalidFragment(format!(
+                        "namespace '{}' declares {}; only entity types can be added",
+                        namespace, kind
+                    )));
+                }
+            }
+        }
+    }
+    if !declares_entity_types {
+        return Err(BuildSchemaError::InvalidFragment(
+            "fragment declares no entity types".to_string(),
+        ));
+    }
+
+    Ok(json)
+}
+
+/// Merge a fragment's entity types into `schema`
+///
+/// Returns the fully qualified names of the added and of the unchanged
+/// entity types. `schema` may be partly merged when this fails.
+fn merge_entity_types(
+    schema: &mut Value,
+    fragment: Value,
+) -> Result<(Vec<String>, Vec<String>), BuildSchemaError> {
+    let (mut added, mut unchanged) = (Vec::new(), Vec::new());
+    let Value::Object(fragment) = fragment else {
+        return Ok((added, unchanged));
+    };
+    let Value::Object(schema) = schema else {
+        return Err(BuildSchemaError::InternalError(
+            "current schema is not a JSON object".to_string(),
+        ));
+    };
+
+    for (namespace, mut declarations) in fragment {
+        let Some(Value::Object(entity_types)) = declarations
+            .as_object_mut()
+            .and_then(|d| d.remove("entityTypes"))
+        else {
+            continue;
+        };
+        let target = schema
+            .entry(namespace.clone())
+            .or_insert_with(|| serde_json::json!({ "entityTypes": {}, "actions": {} }));
+        let existing = target
+            .as_object_mut()
+            .map(|t| {
+                t.entry("entityTypes")
+                    .or_insert_with(|| Value::Object(Map::new()))
+            })
+            .and_then(Value::as_object_mut)
+            .ok_or_else(|| {
+                BuildSchemaError::InternalError(format!(
+                    "namespace '{}' of the current schema is malformed",
+                    namespace
+                ))
+            })?;
+
+        for (name, definition) in entity_types {
+            let qualified = if namespace.is_empty() {
+                name.clone()
+            } else {
+                format!("{}::{}", namespace, name)
+            };
+            match existing.get(&name) {
+                Some(current) if *current == definition => unchanged.push(qualified),
+                Some(_) => {
+                    warn!(entity_type = %qualified, "Conflicting entity type declaration");
+                    return Err(BuildSchemaError::EntityTypeConflict(qualified));
+                }
+                None => {
+                    existing.insert(name, definition);
+                    added.push(qualified);
+                }
+            }
+        }
+    }
+
+    Ok((added, unchanged))
+}
+
+/// Count the entity and action types declared by a JSON schema
+fn declaration_counts(schema: &Value) -> (usize, usize) {
+    let count = |kind: &str| {
+        schema
+            .as_object()
+            .into_iter()
+            .flat_map(|namespaces| namespaces.values())
+            .filter_map(|declarations| declarations.get(kind)?.as_object())
+            .map(Map::len)
+            .sum()
+    };
+    (count("entityTypes"), count("actions"))
 }
@@ -1,16 +1,30 @@
 #[cfg(test)]
 mod tests {
-    use super::super::dto::BuildSchemaCommand;
+    use super::super::dto::{AddEntityTypeCommand, BuildSchemaCommand};
     use super::super::error::BuildSchemaError;
-    use super::super::ports::SchemaStoragePort;
+    use super::super::ports::{SchemaChangePublisherPort, SchemaStoragePort};
     use super::super::use_case::BuildSchemaUseCase;
     use crate::internal::engine::builder::EngineBuilder;
     use async_trait::async_trait;
     use kernel::{
-        ActionTrait, AttributeName, AttributeType, HodeiEntityType, ResourceTypeName, ServiceName,
+        ActionTrait, AttributeName, AttributeType, HodeiEntityType, ResourceTypeName,
+        SchemaChanged, ServiceName,
     };
     use std::sync::{Arc, Mutex};
 
+    /// Publisher recording the announced schema changes
+    #[derive(Default)]
+    struct RecordingPublisher {
+        published: Mutex<Vec<SchemaChanged>>,
+    }
+
+    #[async_trait]
+    impl SchemaChangePublisherPort for RecordingPublisher {
+        async fn publish(&self, event: SchemaChanged) {
+            self.published.lock().unwrap().push(event);
+        }
+    }
+
     // Mock storage implementation for testing
     #[derive(Default)]
     #[allow(clippy::type_complexity)]
@@ -460,8 +474,225 @@ mod tests {
         let last_saved = storage.get_last_saved();
         assert!(last_saved.is_some());
         let (schema_string, _) = last_saved.unwrap();
-        // Verify it's a non-empty string (Cedar schema debug representation)
-        assert!(!schema_string.is_empty());
-        assert!(schema_string.len() > 10);
+        // Stored in Cedar's JSON format, so it can be read back
+        let schema: serde_json::Value = serde_json::from_str(&schema_string).unwrap();
+        assert!(cedar_policy::Schema::from_json_value(schema).is_ok());
+    }
+
+    async fn create_use_case_with_built_schema(
+        storage: Arc<MockSchemaStorage>,
+    ) -> BuildSchemaUseCase<MockSchemaStorage> {
+        let builder = Arc::new(Mutex::new(EngineBuilder::new()));
+        let use_case = BuildSchemaUseCase::new(builder.clone(), storage);
+        {
+            let mut b = builder.lock().unwrap();
+            b.register_entity::<MockUser>().unwrap();
+            b.register_entity::<MockDocument>().unwrap();
+            b.register_action_type::<ReadAction>().unwrap();
+        }
+        use_case
+            .execute(BuildSchemaCommand::new().with_version("v1"))
+            .await
+            .unwrap();
+        use_case
+    }
+
+    #[tokio::test]
+    async fn test_add_entity_type_extends_the_built_schema() {
+        let storage = Arc::new(MockSchemaStorage::new());
+        let use_case = create_use_case_with_built_schema(storage.clone()).await;
+
+        let result = use_case
+            .add_entity_type(
+                AddEntityTypeCommand::new(
+                    "namespace Plugin { entity Widget in [Iam::User] { label: String }; }",
+                )
+                .with_version("v2"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.added_entity_types,
+            vec!["Plugin::Widget".to_string()]
+        );
+        assert_eq!(result.entity_count, 3);
+        assert_eq!(result.action_count, 1);
+        assert_eq!(result.schema_id, Some("schema_2".to_string()));
+        assert_eq!(storage.get_last_saved().unwrap().1, Some("v2".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_add_entity_type_redeclaring_identically_saves_nothing() {
+        let storage = Arc::new(MockSchemaStorage::new());
+        let use_case = create_use_case_with_built_schema(storage.clone()).await;
+
+        let result = use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Storage { entity Document { title: String }; }",
+            ))
+            .await
+            .unwrap();
+
+        assert!(result.added_entity_types.is_empty());
+        assert_eq!(
+            result.unchanged_entity_types,
+            vec!["Storage::Document".to_string()]
+        );
+        assert_eq!(result.schema_id, None);
+        assert_eq!(storage.get_saved_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_entity_type_conflict_leaves_schema_unchanged() {
+        let storage = Arc::new(MockSchemaStorage::new());
+        let use_case = create_use_case_with_built_schema(storage.clone()).await;
+
+        let result = use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Widget; }\nnamespace Storage { entity Document { title: Long }; }",
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(BuildSchemaError::EntityTypeConflict(ref name)) if name == "Storage::Document"
+        ));
+        assert_eq!(storage.get_saved_count(), 1);
+
+        // Nothing from the rejected fragment was kept
+        let result = use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Widget { label: String }; }",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            result.added_entity_types,
+            vec!["Plugin::Widget".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_entity_type_with_invalid_result_is_atomic() {
+        let storage = Arc::new(MockSchemaStorage::new());
+        let use_case = create_use_case_with_built_schema(storage.clone()).await;
+
+        let result = use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Gadget in [Plugin::Missing]; }",
+            ))
+            .await;
+
+        assert!(matches!(result, Err(BuildSchemaError::SchemaBuildError(_))));
+        assert_eq!(storage.get_saved_count(), 1);
+
+        let result = use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Gadget; }",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(result.entity_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_add_entity_type_extends_the_stored_schema_after_a_restart() {
+        let storage = Arc::new(MockSchemaStorage::new());
+        create_use_case_with_built_schema(storage.clone()).await;
+        let restarted =
+            BuildSchemaUseCase::new(Arc::new(Mutex::new(EngineBuilder::new())), storage.clone());
+
+        let result = restarted
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Widget; }",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(result.entity_count, 3);
+        assert_eq!(result.action_count, 1);
+        assert_eq!(storage.get_saved_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_entity_type_over_an_unreadable_stored_schema_fails() {
+        let storage = Arc::new(MockSchemaStorage::new());
+        storage
+            .saved_schemas
+            .lock()
+            .unwrap()
+            .push(("Schema { .. }".to_string(), None));
+        let use_case =
+            BuildSchemaUseCase::new(Arc::new(Mutex::new(EngineBuilder::new())), storage.clone());
+
+        let result = use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Widget; }",
+            ))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(BuildSchemaError::SchemaStorageError(_))
+        ));
+        assert_eq!(storage.get_saved_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_entity_type_rejects_actions() {
+        let use_case = create_use_case_with_built_schema(Arc::new(MockSchemaStorage::new())).await;
+
+        let result = use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                r#"namespace Plugin {
+                    entity Widget;
+                    action "spin" appliesTo { principal: [Widget], resource: [Widget] };
+                }"#,
+            ))
+            .await;
+
+        assert!(matches!(result, Err(BuildSchemaError::InvalidFragment(_))));
+    }
+
+    #[tokio::test]
+    async fn test_every_persisted_schema_is_announced() {
+        let publisher = Arc::new(RecordingPublisher::default());
+        let builder = Arc::new(Mutex::new(EngineBuilder::new()));
+        let use_case = BuildSchemaUseCase::new(builder.clone(), Arc::new(MockSchemaStorage::new()))
+            .with_publisher(publisher.clone());
+        builder
+            .lock()
+            .unwrap()
+            .register_entity::<MockUser>()
+            .unwrap();
+
+        use_case
+            .execute(BuildSchemaCommand::new().with_version("v1"))
+            .await
+            .unwrap();
+        use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Widget; }",
+            ))
+            .await
+            .unwrap();
+        // Adds nothing, so nothing is persisted nor announced
+        use_case
+            .add_entity_type(AddEntityTypeCommand::new(
+                "namespace Plugin { entity Widget; }",
+            ))
+            .await
+            .unwrap();
+
+        let published = publisher.published.lock().unwrap();
+        let announced: Vec<_> = published
+            .iter()
+            .map(|event| (event.schema_version.as_str(), event.schema_id.as_str()))
+            .collect();
+        assert_eq!(
+            announced,
+            vec![("v1", "schema_1"), ("schema_2", "schema_2")]
+        );
     }
 }
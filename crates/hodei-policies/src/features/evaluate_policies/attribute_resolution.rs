@@ -0,0 +1,237 @@
+//! Attributes resolved on demand by an [`AttributeResolver`]
+//!
+//! Before an evaluation, every attribute a policy reads directly off
+//! `principal` or `resource` (`principal.department`, `resource has owner`)
+//! that the entity doesn't carry is asked of the resolver. The values found
+//! are added to the entity for this evaluation only.
+//!
+//! Each attribute of an entity is resolved at most once per evaluation, even
+//! when the principal is also the resource. Nothing is kept from one
+//! evaluation to the next: how fresh the values are is up to the resolver.
+
+use super::dto::AuthorizationRequest;
+use super::error::EvaluatePoliciesError;
+use super::ports::{AttributeResolver, ResolvedAttribute};
+use crate::features::validate_policy::syntax::parse_policy;
+use crate::internal::engine::types::EngineRequest;
+use kernel::{AttributeName, AttributeValue, HodeiEntity, Hrn};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use tracing::debug;
+
+/// Attributes the policies read directly off `principal` and `resource`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct ReferencedAttributes {
+    pub principal: BTreeSet<String>,
+    pub resource: BTreeSet<String>,
+}
+
+impl ReferencedAttributes {
+    /// Collect the attributes read by `policies`, in Cedar or Cedar JSON
+    /// syntax; policies that don't parse are skipped, loading reports them
+    pub fn of<'p>(policies: impl IntoIterator<Item = &'p str>) -> Self {
+        let mut referenced = Self::default();
+        for content in policies {
+            let Ok((policy, _)) = parse_policy(content) else {
+                continue;
+            };
+            if let Ok(est) = policy.to_json() {
+                referenced.collect(&est);
+            }
+        }
+        referenced
+    }
+
+    fn collect(&mut self, expr: &Value) {
+        match expr {
+            Value::Object(fields) => {
+                for (key, value) in fields {
+                    if key == "." || key == "has" {
+                        self.record(value);
+                    }
+                    self.collect(value);
+                }
+            }
+            Value::Array(items) => items.iter().for_each(|item| self.collect(item)),
+            _ => {}
+        }
+    }
+
+    /// Record `left.attr` when `left` is the principal or resource variable
+    fn record(&mut self, access: &Value) {
+        let Some(attribute) = access["attr"].as_str() else {
+            return;
+        };
+        let attributes = match access["left"]["Var"].as_str() {
+            Some("principal") => &mut self.principal,
+            Some("resource") => &mut self.resource,
+            _ => return,
+        };
+        attributes.insert(attribute.to_string());
+    }
+}
+
+/// Entity with the attributes resolved for it added to its own
+#[derive(Debug)]
+struct ResolvedEntity<'a> {
+    entity: &'a dyn HodeiEntity,
+    resolved: HashMap<AttributeName, AttributeValue>,
+}
+
+impl HodeiEntity for ResolvedEntity<'_> {
+    fn hrn(&self) -> &Hrn {
+        self.entity.hrn()
+    }
+
+    fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+        let mut attributes = self.entity.attributes();
+        attributes.extend(self.resolved.clone());
+        attributes
+    }
+
+    fn parent_hrns(&self) -> Vec<Hrn> {
+        self.entity.parent_hrns()
+    }
+}
+
+/// The request's entities with their resolved attributes, for one evaluation
+#[derive(Debug, Default)]
+pub(super) struct ResolvedEntities<'a> {
+    entities: Vec<ResolvedEntity<'a>>,
+}
+
+impl<'a> ResolvedEntities<'a> {
+    /// Resolve the attributes `policies` read off the request's principal and
+    /// resource that they don't carry
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttributeResolutionError` if the resolver fails for any of
+    /// them.
+    pub async fn resolve<'p>(
+        resolver: &dyn AttributeResolver,
+        request: &AuthorizationRequest<'a>,
+        policies: impl IntoIterator<Item = &'p str>,
+    ) -> Result<Self, EvaluatePoliciesError> {
+        let referenced = ReferencedAttributes::of(policies);
+        let mut outcomes: HashMap<(Hrn, AttributeName), ResolvedAttribute> = HashMap::new();
+        let mut resolved = Self::default();
+
+        for (entity, attributes) in [
+            (request.principal, &referenced.principal),
+            (request.resource, &referenced.resource),
+        ] {
+            let carried = entity.attributes();
+            let mut found = HashMap::new();
+            for attribute in attributes {
+                // Cedar can't name what AttributeName rejects, so nothing reads it
+                let Ok(name) = AttributeName::new(attribute.as_str()) else {
+                    continue;
+                };
+                if carried.contains_key(&name) {
+                    continue;
+                }
+
+                let key = (entity.hrn().clone(), name);
+                let outcome = match outcomes.get(&key) {
+                    Some(outcome) => outcome.clone(),
+                    None => {
+                        debug!(hrn = %key.0, attribute = %key.1.as_str(), "Resolving attribute");
+                        let outcome = resolver.resolve(&key.0, &key.1).await.map_err(|e| {
+                            EvaluatePoliciesError::AttributeResolutionError(format!(
+                                "'{}' of {}: {}",
+                                key.1.as_str(),
+                                key.0,
+                                e
+                            ))
+                        })?;
+                        outcomes.insert(key.clone(), outcome.clone());
+                        outcome
+                    }
+                };
+                if let ResolvedAttribute::Present(value) = outcome {
+                    found.insert(key.1, value);
+                }
+            }
+            resolved.add(entity, found);
+        }
+
+        Ok(resolved)
+    }
+
+    fn add(&mut self, entity: &'a dyn HodeiEntity, found: HashMap<AttributeName, AttributeValue>) {
+        if found.is_empty() {
+            return;
+        }
+        match self
+            .entities
+            .iter_mut()
+            .find(|resolved| resolved.hrn() == entity.hrn())
+        {
+            Some(resolved) => resolved.resolved.extend(found),
+            None => self.entities.push(ResolvedEntity {
+                entity,
+                resolved: found,
+            }),
+        }
+    }
+
+    /// `entity` with its resolved attributes, if any were
+    fn entity<'s>(&'s self, entity: &'a dyn HodeiEntity) -> &'s dyn HodeiEntity {
+        self.entities
+            .iter()
+            .find(|resolved| resolved.hrn() == entity.hrn())
+            .map_or(entity, |resolved| resolved as &dyn HodeiEntity)
+    }
+
+    /// `entities` with the resolved attributes added, and the principal or
+    /// resource appended if attributes were resolved for it but it wasn't
+    /// among them
+    pub fn entities<'s>(&'s self, entities: &[&'a dyn HodeiEntity]) -> Vec<&'s dyn HodeiEntity> {
+        let mut all: Vec<&'s dyn HodeiEntity> =
+            entities.iter().map(|entity| self.entity(*entity)).collect();
+        for resolved in &self.entities {
+            if !entities.iter().any(|entity| entity.hrn() == resolved.hrn()) {
+                all.push(resolved);
+            }
+        }
+        all
+    }
+
+    /// Engine request for `request`, its principal and resource carrying
+    /// their resolved attributes
+    pub fn engine_request<'s>(&'s self, request: &AuthorizationRequest<'a>) -> EngineRequest<'s> {
+        EngineRequest::new(
+            self.entity(request.principal),
+            request.action,
+            self.entity(request.resource),
+        )
+        .with_context(request.context.clone().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_attributes_read_off_principal_and_resource() {
+        let referenced = ReferencedAttributes::of([
+            r#"permit(principal, action, resource) when { principal.department == "ops" && resource has owner };"#,
+            r#"forbid(principal, action, resource) unless { principal["clearance"] > 2 || context.mfa };"#,
+            // Attributes of other entities aren't the request's
+            r#"permit(principal, action, resource) when { principal.manager.level > 3 };"#,
+            "not a policy",
+        ]);
+
+        assert_eq!(
+            referenced.principal,
+            BTreeSet::from([
+                "clearance".to_string(),
+                "department".to_string(),
+                "manager".to_string()
+            ])
+        );
+        assert_eq!(referenced.resource, BTreeSet::from(["owner".to_string()]));
+    }
+}
@@ -4,6 +4,15 @@ use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
 use serde::{Deserialize, Serialize};
 
+/// Size limits enforced by the evaluation engine
+pub use crate::internal::engine::types::{EngineLimits, EvaluationLimit};
+
+/// Decision returned when no policy applies to a request
+pub use crate::internal::engine::types::DefaultDecision;
+
+/// Serializable engine state for replaying decisions
+pub use crate::internal::engine::types::{EngineSnapshot, SNAPSHOT_FORMAT_VERSION};
+
 /// Mode for policy evaluation regarding schema usage
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EvaluationMode {
@@ -140,6 +149,82 @@ impl<'a> AuthorizationRequest<'a> {
     }
 }
 
+/// Command for evaluating one action for every principal/resource pair
+///
+/// The policies and entities are loaded once and every cell of the
+/// `principals` × `resources` grid is evaluated against them. Like
+/// [`EvaluatePoliciesCommand`], it borrows its inputs and is not serializable.
+pub struct EvaluateMatrixCommand<'a> {
+    /// Principals, one row of the grid each
+    pub principals: &'a [&'a dyn kernel::HodeiEntity],
+
+    /// The action evaluated in every cell
+    pub action: &'a str,
+
+    /// Resources, one column of the grid each
+    pub resources: &'a [&'a dyn kernel::HodeiEntity],
+
+    /// Optional context shared by every cell
+    pub context: Option<HashMap<String, serde_json::Value>>,
+
+    /// The policy set to evaluate against
+    pub policies: &'a HodeiPolicySet,
+
+    /// Entities involved in the evaluation
+    pub entities: &'a [&'a dyn kernel::HodeiEntity],
+
+    /// Optional specific schema version to use for evaluation
+    pub schema_version: Option<String>,
+
+    /// Evaluation mode regarding schema usage
+    pub evaluation_mode: EvaluationMode,
+}
+
+impl<'a> EvaluateMatrixCommand<'a> {
+    /// Create a new matrix command with default settings (BestEffortNoSchema mode)
+    pub fn new(
+        principals: &'a [&'a dyn kernel::HodeiEntity],
+        action: &'a str,
+        resources: &'a [&'a dyn kernel::HodeiEntity],
+        policies: &'a HodeiPolicySet,
+        entities: &'a [&'a dyn kernel::HodeiEntity],
+    ) -> Self {
+        Self {
+            principals,
+            action,
+            resources,
+            context: None,
+            policies,
+            entities,
+            schema_version: None,
+            evaluation_mode: EvaluationMode::default(),
+        }
+    }
+
+    /// Add context shared by every cell
+    pub fn with_context(mut self, context: HashMap<String, serde_json::Value>) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Set a specific schema version to use
+    pub fn with_schema_version(mut self, version: impl Into<String>) -> Self {
+        self.schema_version = Some(version.into());
+        self
+    }
+
+    /// Set the evaluation mode
+    pub fn with_evaluation_mode(mut self, mode: EvaluationMode) -> Self {
+        self.evaluation_mode = mode;
+        self
+    }
+
+    /// Number of cells in the grid
+    pub fn cell_count(&self) -> usize {
+        self.principals.len().saturating_mul(self.resources.len())
+    }
+}
+
 /// Decision result from policy evaluation
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Decision {
@@ -186,12 +271,19 @@ pub struct EvaluationDecision {
     /// IDs of policies that determined the decision
     pub determining_policies: Vec<String>,
 
+    /// Annotations of the determining policies, keyed by policy ID
+    pub policy_annotations: HashMap<String, HashMap<String, String>>,
+
     /// Reasons and explanations from Cedar
     pub reasons: Vec<String>,
 
     /// Schema version used during evaluation (if any)
     pub used_schema_version: Option<String>,
 
+    /// Version of the policy set that served the evaluation, a content hash
+    /// comparable with snapshot versions
+    pub policy_set_version: Option<String>,
+
     /// IDs of all policies evaluated
     pub policy_ids_evaluated: Vec<String>,
 
@@ -205,8 +297,10 @@ impl EvaluationDecision {
         Self {
             decision,
             determining_policies: vec![],
+            policy_annotations: HashMap::new(),
             reasons: vec![],
             used_schema_version: None,
+            policy_set_version: None,
             policy_ids_evaluated: vec![],
             diagnostics: vec![],
         }
@@ -228,3 +322,39 @@ impl EvaluationDecision {
         self
     }
 }
+
+/// Outcome of one principal/resource pair of a matrix evaluation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixCell {
+    /// The pair was evaluated
+    Decided {
+        /// The decision for the pair
+        decision: Decision,
+        /// IDs of policies that determined the decision
+        determining_policies: Vec<String>,
+    },
+    /// The pair could not be evaluated; the other cells are unaffected
+    Failed {
+        /// Why the pair could not be evaluated
+        error: String,
+    },
+}
+
+/// Result of a matrix evaluation
+#[derive(Debug)]
+pub struct MatrixDecision {
+    /// One row per principal, one cell per resource, in command order
+    pub rows: Vec<Vec<MatrixCell>>,
+
+    /// Schema version used during evaluation (if any)
+    pub used_schema_version: Option<String>,
+
+    /// Version of the policy set every cell was evaluated against
+    pub policy_set_version: Option<String>,
+
+    /// IDs of all policies evaluated
+    pub policy_ids_evaluated: Vec<String>,
+
+    /// Diagnostic information about the evaluation
+    pub diagnostics: Vec<EvaluationDiagnostic>,
+}
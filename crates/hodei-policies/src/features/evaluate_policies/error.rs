@@ -31,4 +31,21 @@ pub enum EvaluatePoliciesError {
 
     #[error("Strict mode requires schema but none was found")]
     StrictModeSchemaRequired,
+
+    #[error("{0}")]
+    LimitExceeded(String),
+
+    #[error("Snapshot error: {0}")]
+    SnapshotError(String),
+
+    #[error("Attribute resolution error: {0}")]
+    AttributeResolutionError(String),
+}
+
+/// Failure of an [`AttributeResolver`](super::ports::AttributeResolver) to
+/// resolve an attribute, as opposed to the attribute being absent
+#[derive(Debug, Clone, Error)]
+pub enum AttributeResolverError {
+    #[error("Attribute source unavailable: {0}")]
+    SourceUnavailable(String),
 }
@@ -4,6 +4,7 @@
 //! Factories receive already-constructed dependencies and assemble use cases.
 
 use crate::features::build_schema::ports::SchemaStoragePort;
+use crate::features::evaluate_policies::dto::{DefaultDecision, EngineLimits};
 use crate::features::evaluate_policies::ports::EvaluatePoliciesPort;
 use crate::features::evaluate_policies::use_case::EvaluatePoliciesUseCase;
 use std::sync::Arc;
@@ -39,3 +40,27 @@ pub fn create_evaluate_policies_use_case(
 ) -> Arc<dyn EvaluatePoliciesPort> {
     Arc::new(EvaluatePoliciesUseCase::new(schema_storage))
 }
+
+/// Creates an EvaluatePoliciesUseCase enforcing custom evaluation limits
+///
+/// Same as [`create_evaluate_policies_use_case`], but evaluations above
+/// `limits` fail with `EvaluatePoliciesError::LimitExceeded` instead of using
+/// the default [`EngineLimits`].
+pub fn create_evaluate_policies_use_case_with_limits(
+    schema_storage: Arc<dyn SchemaStoragePort>,
+    limits: EngineLimits,
+) -> Arc<dyn EvaluatePoliciesPort> {
+    Arc::new(EvaluatePoliciesUseCase::with_limits(schema_storage, limits))
+}
+
+/// Creates an EvaluatePoliciesUseCase with a custom default decision
+///
+/// **Advanced and dangerous.** With [`DefaultDecision::Allow`] every request
+/// no policy applies to is allowed. Use [`create_evaluate_policies_use_case`],
+/// which denies by default, unless the policy set is made of `forbid` rules.
+pub fn create_evaluate_policies_use_case_with_default_decision(
+    schema_storage: Arc<dyn SchemaStoragePort>,
+    default_decision: DefaultDecision,
+) -> Arc<dyn EvaluatePoliciesPort> {
+    Arc::new(EvaluatePoliciesUseCase::new(schema_storage).with_default_decision(default_decision))
+}
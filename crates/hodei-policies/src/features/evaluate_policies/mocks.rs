@@ -2,10 +2,15 @@
 //!
 //! This module provides mock implementations of the ports for testing.
 
-use super::dto::{Decision, EvaluatePoliciesCommand, EvaluationDecision};
-use super::error::EvaluatePoliciesError;
-use super::ports::EvaluatePoliciesPort;
+use super::dto::{
+    Decision, EvaluateMatrixCommand, EvaluatePoliciesCommand, EvaluationDecision, MatrixCell,
+    MatrixDecision,
+};
+use super::error::{AttributeResolverError, EvaluatePoliciesError};
+use super::ports::{AttributeResolver, EvaluatePoliciesPort, ResolvedAttribute};
 use async_trait::async_trait;
+use kernel::{AttributeName, AttributeValue, Hrn};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 /// Mock implementation of EvaluatePoliciesPort for testing
@@ -88,8 +93,34 @@ impl EvaluatePoliciesPort for MockEvaluatePoliciesPort {
         Ok(EvaluationDecision {
             decision,
             determining_policies: vec![],
+            policy_annotations: HashMap::new(),
             reasons: vec![],
             used_schema_version: None,
+            policy_set_version: None,
+            policy_ids_evaluated: vec![],
+            diagnostics: vec![],
+        })
+    }
+
+    async fn evaluate_matrix(
+        &self,
+        command: EvaluateMatrixCommand<'_>,
+    ) -> Result<MatrixDecision, EvaluatePoliciesError> {
+        *self.evaluate_call_count.lock().unwrap() += 1;
+
+        if let Some(error) = self.error.lock().unwrap().as_ref() {
+            return Err(EvaluatePoliciesError::InternalError(error.to_string()));
+        }
+
+        let decision = self.decision.lock().unwrap().clone();
+        let cell = MatrixCell::Decided {
+            decision,
+            determining_policies: vec![],
+        };
+        Ok(MatrixDecision {
+            rows: vec![vec![cell; command.resources.len()]; command.principals.len()],
+            used_schema_version: None,
+            policy_set_version: None,
             policy_ids_evaluated: vec![],
             diagnostics: vec![],
         })
@@ -105,3 +136,62 @@ impl EvaluatePoliciesPort for MockEvaluatePoliciesPort {
         Ok(())
     }
 }
+
+/// Mock attribute resolver answering from a fixed table
+///
+/// Attributes not in the table are reported absent. Every lookup is
+/// recorded, so tests can check what was asked for and how often.
+#[derive(Default)]
+pub struct MockAttributeResolver {
+    attributes: HashMap<(Hrn, String), AttributeValue>,
+    unavailable: bool,
+    lookups: Mutex<Vec<(Hrn, String)>>,
+}
+
+impl MockAttributeResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve `attribute` of `hrn` to `value`
+    pub fn with_attribute(mut self, hrn: &Hrn, attribute: &str, value: AttributeValue) -> Self {
+        self.attributes
+            .insert((hrn.clone(), attribute.to_string()), value);
+        self
+    }
+
+    /// Fail every lookup, as when the attribute source is down
+    pub fn unavailable() -> Self {
+        Self {
+            unavailable: true,
+            ..Self::default()
+        }
+    }
+
+    /// The `(hrn, attribute)` lookups made so far, in order
+    pub fn lookups(&self) -> Vec<(Hrn, String)> {
+        self.lookups.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl AttributeResolver for MockAttributeResolver {
+    async fn resolve(
+        &self,
+        hrn: &Hrn,
+        attribute: &AttributeName,
+    ) -> Result<ResolvedAttribute, AttributeResolverError> {
+        let key = (hrn.clone(), attribute.as_str().to_string());
+        self.lookups.lock().unwrap().push(key.clone());
+        if self.unavailable {
+            return Err(AttributeResolverError::SourceUnavailable(
+                "directory unreachable".to_string(),
+            ));
+        }
+        Ok(self
+            .attributes
+            .get(&key)
+            .cloned()
+            .map_or(ResolvedAttribute::Absent, ResolvedAttribute::Present))
+    }
+}
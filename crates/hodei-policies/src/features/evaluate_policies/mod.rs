@@ -1,3 +1,4 @@
+mod attribute_resolution;
 pub mod dto;
 pub mod error;
 pub mod factories;
@@ -4,9 +4,12 @@
 //! depends on. These traits enable dependency inversion and testability.
 
 use async_trait::async_trait;
+use kernel::{AttributeName, AttributeValue, Hrn};
 
-use crate::features::evaluate_policies::dto::{EvaluatePoliciesCommand, EvaluationDecision};
-use crate::features::evaluate_policies::error::EvaluatePoliciesError;
+use crate::features::evaluate_policies::dto::{
+    EvaluateMatrixCommand, EvaluatePoliciesCommand, EvaluationDecision, MatrixDecision,
+};
+use crate::features::evaluate_policies::error::{AttributeResolverError, EvaluatePoliciesError};
 
 /// Port for policy evaluation operations
 ///
@@ -54,6 +57,21 @@ pub trait EvaluatePoliciesPort: Send + Sync {
         command: EvaluatePoliciesCommand<'_>,
     ) -> Result<EvaluationDecision, EvaluatePoliciesError>;
 
+    /// Evaluate one action for every principal/resource pair
+    ///
+    /// The policies and entities are loaded once for the whole grid. Cells
+    /// that fail to evaluate are reported in the grid instead of failing the
+    /// evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LimitExceeded` if the grid has more cells than allowed, and
+    /// the same loading errors as [`evaluate`](Self::evaluate).
+    async fn evaluate_matrix(
+        &self,
+        command: EvaluateMatrixCommand<'_>,
+    ) -> Result<MatrixDecision, EvaluatePoliciesError>;
+
     /// Clear all cached policies and entities
     ///
     /// This method clears the internal cache of the policy evaluator,
@@ -65,3 +83,57 @@ pub trait EvaluatePoliciesPort: Send + Sync {
     /// Returns an error if cache clearing fails
     async fn clear_cache(&self) -> Result<(), EvaluatePoliciesError>;
 }
+
+/// Outcome of resolving one attribute of an entity
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedAttribute {
+    /// The entity has the attribute, with this value
+    Present(AttributeValue),
+    /// The entity definitively doesn't have the attribute; policies see it
+    /// as missing (`principal has department` is false)
+    Absent,
+}
+
+/// Plugin that resolves entity attributes from an external source (LDAP, a
+/// user database, resource tags...)
+///
+/// Consulted by [`EvaluatePoliciesUseCase`](super::EvaluatePoliciesUseCase)
+/// for each attribute a policy reads off `principal` or `resource` that the
+/// entity doesn't already carry. Each attribute of an entity is asked for at
+/// most once per evaluation.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// struct LdapAttributes { /* ... */ }
+///
+/// #[async_trait]
+/// impl AttributeResolver for LdapAttributes {
+///     async fn resolve(
+///         &self,
+///         hrn: &Hrn,
+///         attribute: &AttributeName,
+///     ) -> Result<ResolvedAttribute, AttributeResolverError> {
+///         match self.lookup(hrn, attribute).await {
+///             Ok(Some(value)) => Ok(ResolvedAttribute::Present(AttributeValue::string(value))),
+///             Ok(None) => Ok(ResolvedAttribute::Absent),
+///             Err(e) => Err(AttributeResolverError::SourceUnavailable(e.to_string())),
+///         }
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait AttributeResolver: Send + Sync {
+    /// Resolve `attribute` of the entity identified by `hrn`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source can't tell whether the entity has the
+    /// attribute. The evaluation then fails instead of treating the attribute
+    /// as absent, since a policy that reads it may be a `forbid`.
+    async fn resolve(
+        &self,
+        hrn: &Hrn,
+        attribute: &AttributeName,
+    ) -> Result<ResolvedAttribute, AttributeResolverError>;
+}
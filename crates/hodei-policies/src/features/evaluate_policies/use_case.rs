@@ -1,13 +1,19 @@
 use crate::features::build_schema::ports::SchemaStoragePort;
 use crate::features::evaluate_policies::dto::{
-    Decision, DiagnosticLevel, EvaluatePoliciesCommand, EvaluationDecision, EvaluationMode,
+    AuthorizationRequest, Decision, DefaultDecision, DiagnosticLevel, EngineSnapshot,
+    EvaluateMatrixCommand, EvaluatePoliciesCommand, EvaluationDecision, EvaluationLimit,
+    EvaluationMode, MatrixCell, MatrixDecision,
 };
+use crate::features::evaluate_policies::attribute_resolution::ResolvedEntities;
 use crate::features::evaluate_policies::error::EvaluatePoliciesError;
-use crate::features::evaluate_policies::ports::EvaluatePoliciesPort;
+use crate::features::evaluate_policies::ports::{AttributeResolver, EvaluatePoliciesPort};
 use crate::internal::engine::AuthorizationEngine;
+use crate::internal::engine::types::{
+    AuthorizationDecision, EngineError, EngineLimits, EngineRequest,
+};
 use async_trait::async_trait;
 use std::sync::Arc;
-use tracing::{debug, info, warn};
+use tracing::{Instrument, debug, info, info_span, warn};
 
 /// Use case for evaluating authorization policies
 ///
@@ -22,6 +28,9 @@ pub struct EvaluatePoliciesUseCase {
 
     /// Schema storage port for loading schemas
     schema_storage: Arc<dyn SchemaStoragePort>,
+
+    /// Source of the attributes policies read that entities don't carry
+    attribute_resolver: Option<Arc<dyn AttributeResolver>>,
 }
 
 impl EvaluatePoliciesUseCase {
@@ -31,12 +40,45 @@ impl EvaluatePoliciesUseCase {
     ///
     /// * `schema_storage` - Port implementation for loading schemas from storage
     pub fn new(schema_storage: Arc<dyn SchemaStoragePort>) -> Self {
+        Self::with_limits(schema_storage, EngineLimits::default())
+    }
+
+    /// Create a policy evaluation use case enforcing `limits`
+    ///
+    /// Evaluations with more policies or entities, or a deeper context, than
+    /// allowed fail with `LimitExceeded` without being evaluated.
+    pub fn with_limits(schema_storage: Arc<dyn SchemaStoragePort>, limits: EngineLimits) -> Self {
         Self {
-            engine: AuthorizationEngine::new(),
+            engine: AuthorizationEngine::with_limits(limits),
             schema_storage,
+            attribute_resolver: None,
         }
     }
 
+    /// Resolve the attributes policies read off the principal or resource
+    /// that the entities don't carry with `resolver`
+    ///
+    /// Only [`execute`](Self::execute) consults the resolver; matrix
+    /// evaluations use the entities as given.
+    pub fn with_attribute_resolver(mut self, resolver: Arc<dyn AttributeResolver>) -> Self {
+        self.attribute_resolver = Some(resolver);
+        self
+    }
+
+    /// Set the decision returned when no policy applies
+    ///
+    /// Evaluations deny by default. [`DefaultDecision::Allow`] is an advanced,
+    /// dangerous setting: any request no policy mentions is allowed.
+    pub fn with_default_decision(mut self, default_decision: DefaultDecision) -> Self {
+        self.engine = self.engine.with_default_decision(default_decision);
+        self
+    }
+
+    /// The decision returned when no policy applies
+    pub fn default_decision(&self) -> DefaultDecision {
+        self.engine.default_decision()
+    }
+
     /// Execute policy evaluation
     ///
     /// This method evaluates an authorization request against loaded policies
@@ -44,10 +86,14 @@ impl EvaluatePoliciesUseCase {
     ///
     /// The evaluation process follows these steps:
     /// 1. Optionally load a Cedar schema based on the evaluation mode
-    /// 2. Load policies into the engine
-    /// 3. Register entities in the engine
+    /// 2. Resolve the missing attributes policies read, if an attribute
+    ///    resolver is set
+    /// 3. Activate the policies, entities and schema version in the engine
     /// 4. Build the authorization request
-    /// 5. Evaluate and return the decision
+    /// 5. Evaluate against the activated set and return the decision
+    ///
+    /// The decision reports the policy set and schema versions it was made
+    /// with, also recorded on the `evaluate_policies` span.
     ///
     /// # Arguments
     ///
@@ -66,14 +112,17 @@ impl EvaluatePoliciesUseCase {
     /// - Invalid policies
     /// - Translation errors
     /// - Cedar evaluation errors
-    #[tracing::instrument(skip(self, command), fields(
+    /// - The attribute resolver failing to resolve an attribute
+    #[tracing::instrument(name = "evaluate_policies", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
         principal = %command.request.principal.hrn(),
         action = command.request.action,
         resource = %command.request.resource.hrn(),
         policy_count = command.policies.policies().len(),
         entity_count = command.entities.len(),
         schema_version = ?command.schema_version,
-        evaluation_mode = ?command.evaluation_mode
+        evaluation_mode = ?command.evaluation_mode,
+        policy_set_version = tracing::field::Empty
     ))]
     pub async fn execute(
         &self,
@@ -87,7 +136,10 @@ impl EvaluatePoliciesUseCase {
         );
 
         // Step 1: Load schema based on evaluation mode
-        let schema_result = self.load_schema_for_evaluation(&command).await;
+        let schema_result = self
+            .load_schema_for_evaluation(command.schema_version.clone(), &command.evaluation_mode)
+            .instrument(info_span!("schema_load"))
+            .await;
         let (used_schema_version, diagnostics) = match schema_result {
             Ok((version, diags)) => (version, diags),
             Err(e) => {
@@ -104,49 +156,59 @@ impl EvaluatePoliciesUseCase {
             }
         };
 
-        // Step 2: Load policies into the engine
-        let policy_texts: Vec<String> = command
+        // Step 2: Resolve the attributes policies read that the principal or
+        // resource don't carry
+        let resolved = match &self.attribute_resolver {
+            Some(resolver) => {
+                ResolvedEntities::resolve(
+                    resolver.as_ref(),
+                    &command.request,
+                    command.policies.policies().iter().map(|p| p.content()),
+                )
+                .instrument(info_span!("attribute_resolution"))
+                .await?
+            }
+            None => ResolvedEntities::default(),
+        };
+        let entities = resolved.entities(command.entities);
+
+        // Step 3: Activate the policies, entities and schema version at once,
+        // so concurrent evaluations never see part of this one's set
+        let policy_texts: Vec<(String, String)> = command
             .policies
             .policies()
             .iter()
-            .map(|policy| policy.content().to_string())
+            .map(|policy| (policy.id().to_string(), policy.content().to_string()))
             .collect();
 
-        self.engine
-            .load_policies(policy_texts)
-            .await
-            .map_err(|e| EvaluatePoliciesError::PolicyLoadError(e.to_string()))?;
-
-        info!(
-            "Successfully loaded {} policies",
-            command.policies.policies().len()
-        );
-
-        // Step 3: Register entities in the engine
-        self.engine
-            .register_entities(command.entities.to_vec())
+        let active = self
+            .engine
+            .activate(policy_texts, &entities, used_schema_version.clone())
             .await
-            .map_err(|e| EvaluatePoliciesError::EntityRegistrationError(e.to_string()))?;
+            .map_err(|e| match e {
+                EngineError::TranslationError(_) => {
+                    engine_error(e, EvaluatePoliciesError::EntityRegistrationError)
+                }
+                other => engine_error(other, EvaluatePoliciesError::PolicyLoadError),
+            })?;
+        tracing::Span::current().record("policy_set_version", active.policy_set_version());
 
         info!(
-            "Successfully registered {} entities",
-            command.entities.len()
+            "Successfully loaded {} policies and {} entities",
+            command.policies.policies().len(),
+            entities.len()
         );
 
         // Step 4: Build engine request
-        let engine_request = crate::internal::engine::types::EngineRequest::new(
-            command.request.principal,
-            command.request.action,
-            command.request.resource,
-        )
-        .with_context(command.request.context.clone().unwrap_or_default());
-
-        // Step 5: Evaluate authorization
+        let engine_request = resolved.engine_request(&command.request);
+
+        // Step 5: Evaluate authorization against the set just activated
         let decision = self
             .engine
-            .is_authorized(&engine_request)
+            .is_authorized_against(&active, &engine_request)
+            .instrument(info_span!("evaluation"))
             .await
-            .map_err(|e| EvaluatePoliciesError::EvaluationError(e.to_string()))?;
+            .map_err(|e| engine_error(e, EvaluatePoliciesError::EvaluationError))?;
 
         debug!(
             decision = decision.is_allowed(),
@@ -168,21 +230,26 @@ impl EvaluatePoliciesUseCase {
             .map(|p| p.id().to_string())
             .collect();
 
+        let policy_set_version = decision.policy_set_version().to_string();
         info!(
             decision = ?mapped_decision,
             schema_version = ?used_schema_version,
+            policy_set_version = %policy_set_version,
             "Policy evaluation completed successfully"
         );
 
         // Step 7: Build and return evaluation decision
         let mut evaluation_decision = EvaluationDecision {
             decision: mapped_decision,
-            determining_policies: vec![],
+            determining_policies: decision.determining_policies().to_vec(),
+            policy_annotations: decision.policy_annotations().clone(),
             reasons: vec![],
-            used_schema_version,
+            used_schema_version: decision.schema_version().map(String::from),
+            policy_set_version: Some(policy_set_version),
             policy_ids_evaluated,
             diagnostics,
         };
+        record_default_applied(&decision, &mut evaluation_decision);
 
         // Add success diagnostic
         evaluation_decision.diagnostics.push(
@@ -199,11 +266,150 @@ impl EvaluatePoliciesUseCase {
         Ok(evaluation_decision)
     }
 
+    /// Evaluate one action for every principal/resource pair
+    ///
+    /// The schema, policies and entities are loaded once, then each cell is
+    /// evaluated against the set just activated. A cell that fails to
+    /// evaluate, e.g. because its principal or resource can't be translated,
+    /// is reported as [`MatrixCell::Failed`] without failing the others.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LimitExceeded` before loading anything if the grid has more
+    /// cells than `max_matrix_cells` or the shared context is too large, and
+    /// the same loading errors as [`execute`](Self::execute).
+    #[tracing::instrument(name = "evaluate_matrix", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        action = command.action,
+        principal_count = command.principals.len(),
+        resource_count = command.resources.len(),
+        policy_count = command.policies.policies().len(),
+        entity_count = command.entities.len(),
+        evaluation_mode = ?command.evaluation_mode,
+        policy_set_version = tracing::field::Empty
+    ))]
+    pub async fn execute_matrix(
+        &self,
+        command: EvaluateMatrixCommand<'_>,
+    ) -> Result<MatrixDecision, EvaluatePoliciesError> {
+        let limits = self.engine.limits();
+        limits
+            .check(EvaluationLimit::MatrixCells, command.cell_count())
+            .map_err(|e| engine_error(e, EvaluatePoliciesError::EvaluationError))?;
+        let context = command.context.clone().unwrap_or_default();
+        if let (Some(principal), Some(resource)) =
+            (command.principals.first(), command.resources.first())
+        {
+            let request = EngineRequest::new(*principal, command.action, *resource)
+                .with_context(context.clone());
+            limits
+                .check_context(&request)
+                .map_err(|e| engine_error(e, EvaluatePoliciesError::EvaluationError))?;
+        }
+
+        let (used_schema_version, mut diagnostics) = match self
+            .load_schema_for_evaluation(command.schema_version.clone(), &command.evaluation_mode)
+            .instrument(info_span!("schema_load"))
+            .await
+        {
+            Ok(loaded) => loaded,
+            Err(e) if command.evaluation_mode == EvaluationMode::Strict => return Err(e),
+            Err(e) => {
+                warn!(
+                    "Schema loading failed but continuing in non-strict mode: {}",
+                    e
+                );
+                (None, vec![])
+            }
+        };
+
+        let policy_texts: Vec<(String, String)> = command
+            .policies
+            .policies()
+            .iter()
+            .map(|policy| (policy.id().to_string(), policy.content().to_string()))
+            .collect();
+        let active = self
+            .engine
+            .activate(policy_texts, command.entities, used_schema_version.clone())
+            .await
+            .map_err(|e| match e {
+                EngineError::TranslationError(_) => {
+                    engine_error(e, EvaluatePoliciesError::EntityRegistrationError)
+                }
+                other => engine_error(other, EvaluatePoliciesError::PolicyLoadError),
+            })?;
+        tracing::Span::current().record("policy_set_version", active.policy_set_version());
+
+        let mut rows = Vec::with_capacity(command.principals.len());
+        let mut failed = 0;
+        for principal in command.principals {
+            let mut row = Vec::with_capacity(command.resources.len());
+            for resource in command.resources {
+                let request = EngineRequest::new(*principal, command.action, *resource)
+                    .with_context(context.clone());
+                let cell = match self.engine.is_authorized_against(&active, &request).await {
+                    Ok(decision) => MatrixCell::Decided {
+                        decision: if decision.is_allowed() {
+                            Decision::Allow
+                        } else {
+                            Decision::Deny
+                        },
+                        determining_policies: decision.determining_policies().to_vec(),
+                    },
+                    Err(e) => {
+                        failed += 1;
+                        MatrixCell::Failed {
+                            error: e.to_string(),
+                        }
+                    }
+                };
+                row.push(cell);
+            }
+            rows.push(row);
+        }
+
+        info!(
+            cells = command.cell_count(),
+            failed, "Matrix evaluation completed"
+        );
+        diagnostics.push(
+            crate::features::evaluate_policies::dto::EvaluationDiagnostic {
+                level: if failed == 0 {
+                    DiagnosticLevel::Info
+                } else {
+                    DiagnosticLevel::Warning
+                },
+                message: format!(
+                    "Evaluated {} cells against {} policies, {} failed",
+                    command.cell_count(),
+                    command.policies.policies().len(),
+                    failed
+                ),
+                policy_id: None,
+            },
+        );
+
+        Ok(MatrixDecision {
+            rows,
+            used_schema_version: active.schema_version().map(String::from),
+            policy_set_version: Some(active.policy_set_version().to_string()),
+            policy_ids_evaluated: command
+                .policies
+                .policies()
+                .iter()
+                .map(|p| p.id().to_string())
+                .collect(),
+            diagnostics,
+        })
+    }
+
     /// Load schema for evaluation based on the command's evaluation mode
     ///
     /// # Arguments
     ///
-    /// * `command` - The evaluation command with schema preferences
+    /// * `schema_version` - The schema version requested, latest if `None`
+    /// * `evaluation_mode` - The evaluation mode of the command
     ///
     /// # Returns
     ///
@@ -216,7 +422,8 @@ impl EvaluatePoliciesUseCase {
     /// - Schema storage encounters an error in strict mode
     async fn load_schema_for_evaluation(
         &self,
-        command: &EvaluatePoliciesCommand<'_>,
+        schema_version: Option<String>,
+        evaluation_mode: &EvaluationMode,
     ) -> Result<
         (
             Option<String>,
@@ -226,7 +433,7 @@ impl EvaluatePoliciesUseCase {
     > {
         let mut diagnostics = vec![];
 
-        match command.evaluation_mode {
+        match evaluation_mode {
             EvaluationMode::NoSchema => {
                 debug!("Evaluation mode is NoSchema, skipping schema loading");
                 diagnostics.push(
@@ -242,7 +449,7 @@ impl EvaluatePoliciesUseCase {
                 // Try to load the schema
                 let schema_load_result = self
                     .schema_storage
-                    .load_schema(command.schema_version.clone())
+                    .load_schema(schema_version)
                     .await;
 
                 match schema_load_result {
@@ -271,7 +478,7 @@ impl EvaluatePoliciesUseCase {
                         Ok((version, diagnostics))
                     }
                     Err(e) => {
-                        if command.evaluation_mode == EvaluationMode::Strict {
+                        if *evaluation_mode == EvaluationMode::Strict {
                             warn!("Schema loading failed in Strict mode: {}", e);
                             Err(EvaluatePoliciesError::StrictModeSchemaRequired)
                         } else {
@@ -298,6 +505,82 @@ impl EvaluatePoliciesUseCase {
         }
     }
 
+    /// Snapshot the policies and entities of the last evaluation
+    ///
+    /// The snapshot can be serialized, handed to support and evaluated again
+    /// with [`replay`](Self::replay) to reproduce the decision. Its
+    /// `policy_set_version` identifies the policy set that was live.
+    pub async fn snapshot(&self) -> Result<EngineSnapshot, EvaluatePoliciesError> {
+        self.engine
+            .snapshot()
+            .await
+            .map_err(|e| EvaluatePoliciesError::SnapshotError(e.to_string()))
+    }
+
+    /// Evaluate `request` against a snapshot instead of the live state
+    ///
+    /// The snapshot is loaded into a separate engine with this use case's
+    /// limits and the snapshot's default decision, so replaying never
+    /// disturbs live evaluations. A snapshot whose policies do not match its
+    /// recorded version is refused.
+    #[tracing::instrument(name = "replay_policies", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        principal = %request.principal.hrn(),
+        action = request.action,
+        resource = %request.resource.hrn(),
+        policy_set_version = %snapshot.policy_set_version
+    ))]
+    pub async fn replay(
+        &self,
+        snapshot: EngineSnapshot,
+        request: AuthorizationRequest<'_>,
+    ) -> Result<EvaluationDecision, EvaluatePoliciesError> {
+        let policy_set_version = snapshot.policy_set_version.clone();
+        let used_schema_version = snapshot.schema_version.clone();
+        let policy_ids_evaluated: Vec<String> =
+            snapshot.policies.iter().map(|p| p.id.clone()).collect();
+
+        let engine = AuthorizationEngine::with_limits(self.engine.limits())
+            .with_default_decision(snapshot.default_decision);
+        engine
+            .restore(snapshot)
+            .await
+            .map_err(|e| engine_error(e, EvaluatePoliciesError::SnapshotError))?;
+
+        let decision = engine
+            .is_authorized(&engine_request(&request))
+            .instrument(info_span!("evaluation"))
+            .await
+            .map_err(|e| engine_error(e, EvaluatePoliciesError::EvaluationError))?;
+
+        let mapped_decision = if decision.is_allowed() {
+            Decision::Allow
+        } else {
+            Decision::Deny
+        };
+        info!(decision = ?mapped_decision, "Snapshot replay completed");
+
+        let mut evaluation_decision = EvaluationDecision {
+            decision: mapped_decision,
+            determining_policies: decision.determining_policies().to_vec(),
+            policy_annotations: decision.policy_annotations().clone(),
+            reasons: vec![],
+            used_schema_version,
+            policy_set_version: Some(policy_set_version.clone()),
+            policy_ids_evaluated,
+            diagnostics: vec![
+                crate::features::evaluate_policies::dto::EvaluationDiagnostic {
+                    level: DiagnosticLevel::Info,
+                    message: format!("Replayed snapshot of policy set {}", policy_set_version),
+                    policy_id: None,
+                },
+            ],
+        };
+        record_default_applied(&decision, &mut evaluation_decision);
+
+        Ok(evaluation_decision)
+    }
+
     /// Clear all cached data in the engine
     ///
     /// This method clears all loaded policies and registered entities,
@@ -319,6 +602,50 @@ impl EvaluatePoliciesUseCase {
     }
 }
 
+/// Make it explicit when the engine's default decided instead of a policy
+fn record_default_applied(
+    decision: &AuthorizationDecision,
+    evaluation_decision: &mut EvaluationDecision,
+) {
+    let Some(default) = decision.default_applied() else {
+        return;
+    };
+    let level = match default {
+        DefaultDecision::Deny => DiagnosticLevel::Info,
+        DefaultDecision::Allow => DiagnosticLevel::Warning,
+    };
+    evaluation_decision
+        .reasons
+        .push(decision.reason().to_string());
+    evaluation_decision.diagnostics.push(
+        crate::features::evaluate_policies::dto::EvaluationDiagnostic {
+            level,
+            message: format!("No policy applied; default decision {:?} used", default),
+            policy_id: None,
+        },
+    );
+}
+
+/// Build the engine request for an authorization request
+fn engine_request<'a>(request: &AuthorizationRequest<'a>) -> EngineRequest<'a> {
+    EngineRequest::new(request.principal, request.action, request.resource)
+        .with_context(request.context.clone().unwrap_or_default())
+}
+
+/// Map an engine error to the use case error, keeping limit violations apart
+fn engine_error(
+    error: EngineError,
+    otherwise: fn(String) -> EvaluatePoliciesError,
+) -> EvaluatePoliciesError {
+    match error {
+        EngineError::LimitExceeded { .. } | EngineError::ContextTooLarge { .. } => {
+            warn!("Policy evaluation refused: {}", error);
+            EvaluatePoliciesError::LimitExceeded(error.to_string())
+        }
+        other => otherwise(other.to_string()),
+    }
+}
+
 /// Implementation of the EvaluatePoliciesPort trait for EvaluatePoliciesUseCase
 ///
 /// This allows the use case to be used via the port abstraction,
@@ -332,6 +659,13 @@ impl EvaluatePoliciesPort for EvaluatePoliciesUseCase {
         self.execute(command).await
     }
 
+    async fn evaluate_matrix(
+        &self,
+        command: EvaluateMatrixCommand<'_>,
+    ) -> Result<MatrixDecision, EvaluatePoliciesError> {
+        self.execute_matrix(command).await
+    }
+
     async fn clear_cache(&self) -> Result<(), EvaluatePoliciesError> {
         self.clear_cache().await
     }
@@ -1,5 +1,9 @@
-use super::dto::{AuthorizationRequest, Decision, EvaluatePoliciesCommand, EvaluationMode};
+use super::dto::{
+    AuthorizationRequest, Decision, DefaultDecision, DiagnosticLevel, EngineLimits,
+    EngineSnapshot, EvaluateMatrixCommand, EvaluatePoliciesCommand, EvaluationMode, MatrixCell,
+};
 use super::error::EvaluatePoliciesError;
+use super::mocks::MockAttributeResolver;
 use super::use_case::EvaluatePoliciesUseCase;
 use crate::features::build_schema::error::BuildSchemaError;
 use crate::features::build_schema::ports::SchemaStoragePort;
@@ -796,3 +800,458 @@ async fn test_clear_cache() {
     let result = use_case.clear_cache().await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_policy_limit_refuses_evaluation() {
+    let schema_storage = Arc::new(MockSchemaStorage::new());
+    let limits = EngineLimits {
+        max_policies: 1,
+        ..EngineLimits::default()
+    };
+    let use_case = EvaluatePoliciesUseCase::with_limits(schema_storage, limits);
+
+    let user = MockUser {
+        hrn: Hrn::new(
+            "aws".to_string(),
+            "iam".to_string(),
+            "hodei-test".to_string(),
+            "user".to_string(),
+            "alice".to_string(),
+        ),
+        name: "Alice".to_string(),
+        active: true,
+        role: "developer".to_string(),
+        department: "engineering".to_string(),
+    };
+
+    let policy_set = HodeiPolicySet::new(vec![
+        HodeiPolicy::new(
+            PolicyId::new("policy1".to_string()),
+            "permit(principal, action, resource);".to_string(),
+        ),
+        HodeiPolicy::new(
+            PolicyId::new("policy2".to_string()),
+            "permit(principal, action, resource);".to_string(),
+        ),
+    ]);
+
+    let entities: Vec<&dyn HodeiEntity> = vec![&user];
+    let request = AuthorizationRequest::new(&user, "read", &user);
+    let command = EvaluatePoliciesCommand::new(request, &policy_set, &entities).no_schema();
+
+    match use_case.execute(command).await {
+        Err(EvaluatePoliciesError::LimitExceeded(message)) => {
+            assert!(message.contains("policy count is 2, at most 1 allowed"));
+        }
+        other => panic!("Expected LimitExceeded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_replayed_snapshot_reproduces_the_decision() {
+    let schema_storage = Arc::new(MockSchemaStorage::with_schema());
+    let use_case = EvaluatePoliciesUseCase::new(schema_storage.clone());
+
+    let user = MockUser {
+        hrn: Hrn::new(
+            "aws".to_string(),
+            "iam".to_string(),
+            "hodei-test".to_string(),
+            "user".to_string(),
+            "alice".to_string(),
+        ),
+        name: "Alice".to_string(),
+        active: true,
+        role: "developer".to_string(),
+        department: "engineering".to_string(),
+    };
+
+    let policy_set = HodeiPolicySet::new(vec![
+        HodeiPolicy::new(
+            PolicyId::new("allow-active".to_string()),
+            "permit(principal, action, resource) when { principal.active };".to_string(),
+        ),
+        HodeiPolicy::new(
+            PolicyId::new("deny-delete".to_string()),
+            r#"forbid(principal, action == Action::"delete", resource);"#.to_string(),
+        ),
+    ]);
+    let entities: Vec<&dyn HodeiEntity> = vec![&user];
+    let command = EvaluatePoliciesCommand::new(
+        AuthorizationRequest::new(&user, "read", &user),
+        &policy_set,
+        &entities,
+    )
+    .with_schema_version("v1.0.0");
+
+    let live = use_case.execute(command).await.unwrap();
+    let snapshot = use_case.snapshot().await.unwrap();
+    assert_eq!(snapshot.schema_version, Some("v1.0.0".to_string()));
+
+    // Replay from the serialized form, on a different use case instance
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let snapshot: EngineSnapshot = serde_json::from_str(&json).unwrap();
+    let replayer = EvaluatePoliciesUseCase::new(schema_storage);
+    let replayed = replayer
+        .replay(
+            snapshot.clone(),
+            AuthorizationRequest::new(&user, "read", &user),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(replayed.decision, live.decision);
+    assert_eq!(replayed.determining_policies, live.determining_policies);
+    assert_eq!(replayed.used_schema_version, live.used_schema_version);
+    assert_eq!(replayed.policy_set_version, live.policy_set_version);
+    assert_eq!(
+        live.policy_set_version.as_deref(),
+        Some(snapshot.policy_set_version.as_str())
+    );
+    assert_eq!(replayed.policy_ids_evaluated, live.policy_ids_evaluated);
+
+    let mut tampered = snapshot;
+    tampered.policies.pop();
+    let result = replayer
+        .replay(tampered, AuthorizationRequest::new(&user, "read", &user))
+        .await;
+    assert!(matches!(
+        result,
+        Err(EvaluatePoliciesError::SnapshotError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_allow_by_default_is_explicit_in_the_decision() {
+    let schema_storage = Arc::new(MockSchemaStorage::new());
+    let use_case =
+        EvaluatePoliciesUseCase::new(schema_storage).with_default_decision(DefaultDecision::Allow);
+
+    let user = MockUser {
+        hrn: Hrn::new(
+            "aws".to_string(),
+            "iam".to_string(),
+            "hodei-test".to_string(),
+            "user".to_string(),
+            "alice".to_string(),
+        ),
+        name: "Alice".to_string(),
+        active: true,
+        role: "developer".to_string(),
+        department: "engineering".to_string(),
+    };
+    let policy_set = HodeiPolicySet::new(vec![HodeiPolicy::new(
+        PolicyId::new("deny-delete".to_string()),
+        r#"forbid(principal, action == Action::"delete", resource);"#.to_string(),
+    )]);
+    let entities: Vec<&dyn HodeiEntity> = vec![&user];
+
+    let command = EvaluatePoliciesCommand::new(
+        AuthorizationRequest::new(&user, "read", &user),
+        &policy_set,
+        &entities,
+    )
+    .with_evaluation_mode(EvaluationMode::NoSchema);
+    let decision = use_case.execute(command).await.unwrap();
+    assert_eq!(decision.decision, Decision::Allow);
+    assert!(decision.determining_policies.is_empty());
+    assert!(decision.diagnostics.iter().any(|d| {
+        d.level == DiagnosticLevel::Warning && d.message.contains("default decision Allow")
+    }));
+
+    let command = EvaluatePoliciesCommand::new(
+        AuthorizationRequest::new(&user, "delete", &user),
+        &policy_set,
+        &entities,
+    )
+    .with_evaluation_mode(EvaluationMode::NoSchema);
+    let decision = use_case.execute(command).await.unwrap();
+    assert_eq!(decision.decision, Decision::Deny);
+    assert!(decision.reasons.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_evaluations_are_decided_by_their_own_policies() {
+    let use_case = Arc::new(EvaluatePoliciesUseCase::new(Arc::new(
+        MockSchemaStorage::new(),
+    )));
+
+    let tasks: Vec<_> = (0..16)
+        .map(|i| {
+            let use_case = use_case.clone();
+            tokio::spawn(async move {
+                let user = MockUser {
+                    hrn: Hrn::new(
+                        "aws".to_string(),
+                        "iam".to_string(),
+                        "hodei-test".to_string(),
+                        "user".to_string(),
+                        format!("user-{}", i),
+                    ),
+                    name: format!("User {}", i),
+                    active: true,
+                    role: "developer".to_string(),
+                    department: "engineering".to_string(),
+                };
+                let allowed = i % 2 == 0;
+                let effect = if allowed { "permit" } else { "forbid" };
+                let policy_id = format!("policy-{}", i);
+                let policy_set = HodeiPolicySet::new(vec![HodeiPolicy::new(
+                    PolicyId::new(policy_id.clone()),
+                    format!("{}(principal, action, resource);", effect),
+                )]);
+                let entities: Vec<&dyn HodeiEntity> = vec![&user];
+
+                let mut versions = Vec::new();
+                for _ in 0..25 {
+                    let command = EvaluatePoliciesCommand::new(
+                        AuthorizationRequest::new(&user, "read", &user),
+                        &policy_set,
+                        &entities,
+                    )
+                    .with_evaluation_mode(EvaluationMode::NoSchema);
+                    let decision = use_case.execute(command).await.unwrap();
+
+                    assert_eq!(decision.decision == Decision::Allow, allowed);
+                    assert_eq!(decision.determining_policies, vec![policy_id.clone()]);
+                    versions.push(decision.policy_set_version.unwrap());
+                }
+                // The same policies are always reported with the same version
+                versions.dedup();
+                assert_eq!(versions.len(), 1);
+                versions.pop().unwrap()
+            })
+        })
+        .collect();
+
+    let mut versions = Vec::new();
+    for task in tasks {
+        versions.push(task.await.unwrap());
+    }
+    versions.sort();
+    versions.dedup();
+    assert_eq!(versions.len(), 16);
+}
+
+fn matrix_user(id: &str, role: &str) -> MockUser {
+    MockUser {
+        hrn: Hrn::new(
+            "aws".to_string(),
+            "iam".to_string(),
+            "hodei-test".to_string(),
+            "user".to_string(),
+            id.to_string(),
+        ),
+        name: id.to_string(),
+        active: true,
+        role: role.to_string(),
+        department: "engineering".to_string(),
+    }
+}
+
+fn matrix_document(resource_type: &str, id: &str) -> MockDocument {
+    MockDocument {
+        hrn: Hrn::new(
+            "aws".to_string(),
+            "storage".to_string(),
+            "hodei-test".to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        ),
+        title: id.to_string(),
+        classification: "internal".to_string(),
+        owner: "alice".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_matrix_evaluates_every_cell_and_reports_failed_ones() {
+    let use_case = EvaluatePoliciesUseCase::new(Arc::new(MockSchemaStorage::new()));
+
+    let admin = matrix_user("alice", "admin");
+    let developer = matrix_user("bob", "developer");
+    let report = matrix_document("document", "report");
+    // Not a `Namespace::Type` name, so cells with it can't be evaluated
+    let broken = matrix_document("Storage::Drafts::Document", "draft");
+
+    let policy_set = HodeiPolicySet::new(vec![HodeiPolicy::new(
+        PolicyId::new("admins".to_string()),
+        r#"permit(principal, action, resource) when { principal.role == "admin" };"#.to_string(),
+    )]);
+    let principals: Vec<&dyn HodeiEntity> = vec![&admin, &developer];
+    let resources: Vec<&dyn HodeiEntity> = vec![&report, &broken];
+    let entities: Vec<&dyn HodeiEntity> = vec![&admin, &developer, &report];
+
+    let command =
+        EvaluateMatrixCommand::new(&principals, "read", &resources, &policy_set, &entities)
+            .with_evaluation_mode(EvaluationMode::NoSchema);
+    let matrix = use_case.execute_matrix(command).await.unwrap();
+
+    assert_eq!(matrix.rows.len(), 2);
+    assert_eq!(
+        matrix.rows[0][0],
+        MatrixCell::Decided {
+            decision: Decision::Allow,
+            determining_policies: vec!["admins".to_string()],
+        }
+    );
+    assert!(matches!(
+        matrix.rows[1][0],
+        MatrixCell::Decided {
+            decision: Decision::Deny,
+            ..
+        }
+    ));
+    for row in &matrix.rows {
+        assert!(matches!(row[1], MatrixCell::Failed { .. }));
+    }
+    assert!(matrix.policy_set_version.is_some());
+    assert_eq!(matrix.policy_ids_evaluated, vec!["admins".to_string()]);
+    assert!(
+        matrix
+            .diagnostics
+            .iter()
+            .any(|d| d.level == DiagnosticLevel::Warning && d.message.contains("2 failed"))
+    );
+}
+
+#[tokio::test]
+async fn test_matrix_above_the_cell_limit_is_refused() {
+    let limits = EngineLimits {
+        max_matrix_cells: 3,
+        ..EngineLimits::default()
+    };
+    let use_case =
+        EvaluatePoliciesUseCase::with_limits(Arc::new(MockSchemaStorage::new()), limits);
+
+    let alice = matrix_user("alice", "admin");
+    let bob = matrix_user("bob", "admin");
+    let report = matrix_document("document", "report");
+    let budget = matrix_document("document", "budget");
+    let policy_set = HodeiPolicySet::new(vec![HodeiPolicy::new(
+        PolicyId::new("all".to_string()),
+        "permit(principal, action, resource);".to_string(),
+    )]);
+    let principals: Vec<&dyn HodeiEntity> = vec![&alice, &bob];
+    let resources: Vec<&dyn HodeiEntity> = vec![&report, &budget];
+
+    let command = EvaluateMatrixCommand::new(&principals, "read", &resources, &policy_set, &[])
+        .with_evaluation_mode(EvaluationMode::NoSchema);
+    match use_case.execute_matrix(command).await {
+        Err(EvaluatePoliciesError::LimitExceeded(message)) => {
+            assert!(message.contains("matrix cell count is 4, at most 3 allowed"));
+        }
+        other => panic!("Expected LimitExceeded, got {:?}", other),
+    }
+}
+
+fn attribute_policy(id: &str, content: &str) -> HodeiPolicySet {
+    HodeiPolicySet::new(vec![HodeiPolicy::new(
+        PolicyId::new(id.to_string()),
+        content.to_string(),
+    )])
+}
+
+async fn evaluate_with_resolver(
+    resolver: Arc<MockAttributeResolver>,
+    policies: &HodeiPolicySet,
+) -> Result<Decision, EvaluatePoliciesError> {
+    let use_case = EvaluatePoliciesUseCase::new(Arc::new(MockSchemaStorage::new()))
+        .with_attribute_resolver(resolver);
+    let user = matrix_user("alice", "developer");
+    let document = matrix_document("document", "doc1");
+    let entities: Vec<&dyn HodeiEntity> = vec![&user, &document];
+    let command = EvaluatePoliciesCommand::new(
+        AuthorizationRequest::new(&user, "read", &document),
+        policies,
+        &entities,
+    )
+    .with_evaluation_mode(EvaluationMode::NoSchema);
+
+    use_case.execute(command).await.map(|result| result.decision)
+}
+
+#[tokio::test]
+async fn test_attribute_resolver_supplies_missing_attributes() {
+    let document = matrix_document("document", "doc1");
+    let resolver = Arc::new(MockAttributeResolver::new().with_attribute(
+        &document.hrn,
+        "sensitivity",
+        AttributeValue::string("low"),
+    ));
+    let policies = attribute_policy(
+        "low-sensitivity",
+        r#"permit(principal, action, resource) when { resource.sensitivity == "low" && principal.role == "developer" };"#,
+    );
+
+    let decision = evaluate_with_resolver(resolver.clone(), &policies).await;
+
+    assert_eq!(decision.unwrap(), Decision::Allow);
+    // `role` is carried by the principal, so only `sensitivity` is asked for
+    assert_eq!(
+        resolver.lookups(),
+        vec![(document.hrn.clone(), "sensitivity".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_absent_attribute_is_seen_as_missing_by_policies() {
+    let resolver = Arc::new(MockAttributeResolver::new());
+    let policies = attribute_policy(
+        "has-sensitivity",
+        "permit(principal, action, resource) when { resource has sensitivity };",
+    );
+
+    let decision = evaluate_with_resolver(resolver.clone(), &policies).await;
+
+    assert_eq!(decision.unwrap(), Decision::Deny);
+    assert_eq!(resolver.lookups().len(), 1);
+}
+
+#[tokio::test]
+async fn test_unresolvable_attribute_fails_the_evaluation() {
+    let resolver = Arc::new(MockAttributeResolver::unavailable());
+    let policies = attribute_policy(
+        "deny-restricted",
+        r#"forbid(principal, action, resource) when { resource.sensitivity == "restricted" };"#,
+    );
+
+    let decision = evaluate_with_resolver(resolver, &policies).await;
+
+    assert!(matches!(
+        decision,
+        Err(EvaluatePoliciesError::AttributeResolutionError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_attributes_are_resolved_once_per_evaluation() {
+    let user = matrix_user("alice", "developer");
+    let resolver = Arc::new(MockAttributeResolver::new().with_attribute(
+        &user.hrn,
+        "clearance",
+        AttributeValue::long(3),
+    ));
+    let use_case = EvaluatePoliciesUseCase::new(Arc::new(MockSchemaStorage::new()))
+        .with_attribute_resolver(resolver.clone());
+    let policies = attribute_policy(
+        "self-service",
+        "permit(principal, action, resource) when { principal.clearance > 2 && resource.clearance > 2 };",
+    );
+    // The principal is also the resource, and isn't among the entities
+    let entities: Vec<&dyn HodeiEntity> = vec![];
+
+    for _ in 0..2 {
+        let command = EvaluatePoliciesCommand::new(
+            AuthorizationRequest::new(&user, "update", &user),
+            &policies,
+            &entities,
+        )
+        .with_evaluation_mode(EvaluationMode::NoSchema);
+        let result = use_case.execute(command).await.unwrap();
+        assert_eq!(result.decision, Decision::Allow);
+    }
+
+    // Once per evaluation, not per variable, and nothing kept in between
+    assert_eq!(resolver.lookups().len(), 2);
+}
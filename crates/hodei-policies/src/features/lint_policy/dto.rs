@@ -0,0 +1,196 @@
+//! Data Transfer Objects for the lint_policy feature
+//!
+//! Linting looks for risky or pointless patterns in policies that are
+//! otherwise valid. Findings are warnings: they are meant to be surfaced in
+//! CI, not to block it.
+
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use serde::{Deserialize, Serialize};
+
+/// Annotation listing the lint rules a policy opts out of
+///
+/// The value is a comma-separated list of rule IDs, for example
+/// `@lint_suppress("overly-permissive, missing-constraint")`.
+pub const SUPPRESS_ANNOTATION: &str = "lint_suppress";
+
+/// Command to lint a set of policies
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintPolicyCommand {
+    /// Policies to lint; each string may hold several Cedar policies, or one
+    /// policy in Cedar's JSON format
+    pub policies: Vec<String>,
+
+    /// Schema to check the policies against, in Cedar schema syntax or in
+    /// Cedar's JSON schema format. With a schema, policies that no request
+    /// the schema allows can match are reported as unreachable.
+    #[serde(default)]
+    pub schema: Option<String>,
+}
+
+impl LintPolicyCommand {
+    /// Create a command that lints without a schema
+    pub fn new(policies: Vec<String>) -> Self {
+        Self {
+            policies,
+            schema: None,
+        }
+    }
+
+    /// Set the schema to check the policies against
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.policies.iter().all(|policy| policy.trim().is_empty()) {
+            return Err("At least one policy is required".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ActionTrait for LintPolicyCommand {
+    fn name() -> &'static str {
+        "LintPolicy"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("policies").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Policies::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Policies::Policy".to_string()
+    }
+}
+
+/// Lint rules, identified in warnings and annotations by their kebab-case ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    /// A `permit` with no scope constraint and no condition
+    OverlyPermissive,
+    /// A `permit` that applies to every action
+    MissingConstraint,
+    /// A policy that can never apply to a request
+    Unreachable,
+    /// A condition that is always true or repeats another constraint
+    RedundantCondition,
+}
+
+impl LintRule {
+    /// Every rule, in the order they are checked
+    pub const ALL: [LintRule; 4] = [
+        LintRule::OverlyPermissive,
+        LintRule::MissingConstraint,
+        LintRule::Unreachable,
+        LintRule::RedundantCondition,
+    ];
+
+    /// The rule's ID, as used in `@lint_suppress`
+    pub fn id(&self) -> &'static str {
+        match self {
+            LintRule::OverlyPermissive => "overly-permissive",
+            LintRule::MissingConstraint => "missing-constraint",
+            LintRule::Unreachable => "unreachable",
+            LintRule::RedundantCondition => "redundant-condition",
+        }
+    }
+
+    /// The rule with the given ID, if any
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rule| rule.id() == id)
+    }
+
+    /// How serious a finding of this rule is
+    pub fn severity(&self) -> LintSeverity {
+        match self {
+            LintRule::OverlyPermissive => LintSeverity::High,
+            LintRule::MissingConstraint | LintRule::Unreachable => LintSeverity::Medium,
+            LintRule::RedundantCondition => LintSeverity::Low,
+        }
+    }
+}
+
+impl std::fmt::Display for LintRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// Severity of a lint warning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// Harmless but worth cleaning up
+    Low,
+    /// Likely a mistake
+    Medium,
+    /// Likely grants more access than intended
+    High,
+}
+
+/// Where a linted policy is
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyLocation {
+    /// Index of the command's policy string holding the policy
+    pub source: usize,
+
+    /// The policy's `@id` annotation or, failing that, its position in the
+    /// string (`policy0`, `policy1`...)
+    pub policy_id: String,
+
+    /// Line where the policy starts, from 1
+    pub line: usize,
+
+    /// Column where the policy starts, from 1
+    pub column: usize,
+}
+
+/// One finding of a lint rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintWarning {
+    /// Rule that produced the warning
+    pub rule: LintRule,
+
+    /// Severity of the rule
+    pub severity: LintSeverity,
+
+    /// Policy the warning is about
+    pub location: PolicyLocation,
+
+    /// What is wrong
+    pub message: String,
+
+    /// How to fix it, when there is an obvious fix
+    pub suggestion: Option<String>,
+}
+
+/// Outcome of linting a set of policies
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LintPolicyResult {
+    /// Warnings, in policy order
+    pub warnings: Vec<LintWarning>,
+
+    /// Number of findings silenced by `@lint_suppress`
+    pub suppressed: usize,
+}
+
+impl LintPolicyResult {
+    /// Whether no warning was reported
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Warnings at or above `severity`
+    pub fn at_least(&self, severity: LintSeverity) -> impl Iterator<Item = &LintWarning> {
+        self.warnings
+            .iter()
+            .filter(move |warning| warning.severity >= severity)
+    }
+}
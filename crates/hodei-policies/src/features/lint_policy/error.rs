@@ -0,0 +1,25 @@
+//! Error types for the lint_policy feature
+//!
+//! Lint findings are never errors; these only reject input that cannot be
+//! linted at all.
+
+use thiserror::Error;
+
+/// Errors that prevent policies from being linted
+#[derive(Debug, Clone, Error)]
+pub enum LintPolicyError {
+    /// Invalid command parameters
+    #[error("Invalid command: {0}")]
+    InvalidCommand(String),
+
+    /// The schema could not be parsed
+    #[error("Schema error: {0}")]
+    SchemaError(String),
+
+    /// A policy could not be parsed
+    #[error("Policy error in source {source_index}: {message}")]
+    PolicyError {
+        source_index: usize,
+        message: String,
+    },
+}
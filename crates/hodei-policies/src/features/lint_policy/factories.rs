@@ -0,0 +1,28 @@
+//! Factory functions for the lint_policy feature
+//!
+//! This module provides static factory functions following the Java Config pattern.
+//! Factories receive already-constructed dependencies and assemble use cases.
+
+use crate::features::lint_policy::ports::LintPolicyPort;
+use crate::features::lint_policy::use_case::LintPolicyUseCase;
+use std::sync::Arc;
+
+/// Creates a LintPolicyUseCase
+///
+/// The use case needs no adapters: the policies and the optional schema come
+/// with the command.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use hodei_policies::features::lint_policy::factories;
+///
+/// let use_case = factories::create_lint_policy_use_case();
+/// let result = use_case.lint(command).await?;
+/// for warning in &result.warnings {
+///     println!("{}: {}", warning.rule, warning.message);
+/// }
+/// ```
+pub fn create_lint_policy_use_case() -> Arc<dyn LintPolicyPort> {
+    Arc::new(LintPolicyUseCase::new())
+}
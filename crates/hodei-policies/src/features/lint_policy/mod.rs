@@ -0,0 +1,25 @@
+//! Lint Policy Feature
+//!
+//! Checks valid policies for risky or pointless patterns: a `permit` that
+//! grants everything, one that applies to every action, a policy that can
+//! never apply, a condition that adds nothing. Findings are warnings with a
+//! severity, meant for CI to surface without failing the build. A policy can
+//! opt out of a rule with `@lint_suppress("<rule-id>")`.
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod use_case_test;
+
+// Re-export for convenience
+pub use dto::{
+    LintPolicyCommand, LintPolicyResult, LintRule, LintSeverity, LintWarning, PolicyLocation,
+    SUPPRESS_ANNOTATION,
+};
+pub use error::LintPolicyError;
+pub use ports::LintPolicyPort;
+pub use use_case::LintPolicyUseCase;
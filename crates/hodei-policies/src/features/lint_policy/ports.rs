@@ -0,0 +1,20 @@
+//! Ports (trait definitions) for the lint_policy feature
+
+use async_trait::async_trait;
+
+use super::dto::{LintPolicyCommand, LintPolicyResult};
+use super::error::LintPolicyError;
+
+/// Port trait for linting policies
+///
+/// This trait represents the use case's public interface.
+#[async_trait]
+pub trait LintPolicyPort: Send + Sync {
+    /// Check every policy of `command` against the lint rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a policy or the schema cannot be parsed. Findings
+    /// are reported in the result, never as errors.
+    async fn lint(&self, command: LintPolicyCommand) -> Result<LintPolicyResult, LintPolicyError>;
+}
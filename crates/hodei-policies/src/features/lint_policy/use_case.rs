@@ -0,0 +1,355 @@
+//! Use case for linting policies
+
+use super::dto::{
+    LintPolicyCommand, LintPolicyResult, LintRule, LintWarning, PolicyLocation, SUPPRESS_ANNOTATION,
+};
+use super::error::LintPolicyError;
+use super::ports::LintPolicyPort;
+use async_trait::async_trait;
+use cedar_policy::{
+    Policy, PolicyId, PolicySet, Schema, ValidationMode, ValidationWarning, Validator,
+};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::str::FromStr;
+use tracing::{debug, info, instrument, warn};
+
+/// Use case for linting policies
+///
+/// Each policy is checked against every [`LintRule`] on its Cedar JSON form,
+/// so both policy syntaxes are linted alike. With a schema, Cedar's validator
+/// also reports policies that no request the schema allows can match.
+///
+/// Findings a policy opts out of with `@lint_suppress` are counted but not
+/// reported.
+pub struct LintPolicyUseCase;
+
+impl LintPolicyUseCase {
+    /// Create a new instance of the use case
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Execute the lint policy use case
+    ///
+    /// # Arguments
+    /// * `command` - LintPolicyCommand with the policies and optional schema
+    ///
+    /// # Returns
+    /// * Ok(LintPolicyResult) with the warnings of every policy
+    /// * Err(LintPolicyError) if a policy or the schema cannot be parsed
+    #[instrument(name = "lint_policy", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        source_count = command.policies.len(),
+        with_schema = command.schema.is_some()
+    ))]
+    pub async fn execute(
+        &self,
+        command: LintPolicyCommand,
+    ) -> Result<LintPolicyResult, LintPolicyError> {
+        command
+            .validate()
+            .map_err(LintPolicyError::InvalidCommand)?;
+
+        let schema = command.schema.as_deref().map(parse_schema).transpose()?;
+
+        let mut result = LintPolicyResult::default();
+        for (source_index, source) in command.policies.iter().enumerate() {
+            if source.trim().is_empty() {
+                continue;
+            }
+            let policies = parse_source(source_index, source)?;
+            let impossible = match &schema {
+                Some(schema) => impossible_policies(&policies, schema),
+                None => HashSet::new(),
+            };
+
+            for (index, (policy, location)) in policies.into_iter().enumerate() {
+                let suppressed = suppressed_rules(&policy);
+                for (rule, message, suggestion) in lint(&policy, impossible.contains(&index)) {
+                    if suppressed.contains(&rule) {
+                        debug!(policy = %location.policy_id, %rule, "Lint finding suppressed");
+                        result.suppressed += 1;
+                        continue;
+                    }
+                    result.warnings.push(LintWarning {
+                        rule,
+                        severity: rule.severity(),
+                        location: location.clone(),
+                        message,
+                        suggestion,
+                    });
+                }
+            }
+        }
+
+        info!(
+            warnings = result.warnings.len(),
+            suppressed = result.suppressed,
+            "Policy lint completed"
+        );
+        Ok(result)
+    }
+}
+
+impl Default for LintPolicyUseCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl LintPolicyPort for LintPolicyUseCase {
+    async fn lint(&self, command: LintPolicyCommand) -> Result<LintPolicyResult, LintPolicyError> {
+        self.execute(command).await
+    }
+}
+
+/// A finding: the rule, what is wrong and, if obvious, how to fix it
+type Finding = (LintRule, String, Option<String>);
+
+/// Parse a schema in Cedar JSON format or, otherwise, in Cedar schema syntax
+fn parse_schema(source: &str) -> Result<Schema, LintPolicyError> {
+    if source.trim_start().starts_with('{') {
+        return Schema::from_json_str(source)
+            .map_err(|e| LintPolicyError::SchemaError(e.to_string()));
+    }
+    Schema::from_cedarschema_str(source)
+        .map(|(schema, _warnings)| schema)
+        .map_err(|e| LintPolicyError::SchemaError(e.to_string()))
+}
+
+/// Parse one policy string into its policies, in source order, with their
+/// location
+///
+/// A string that is JSON holds a single Cedar JSON policy; anything else is
+/// parsed as Cedar policies.
+fn parse_source(
+    source_index: usize,
+    source: &str,
+) -> Result<Vec<(Policy, PolicyLocation)>, LintPolicyError> {
+    let policy_error = |message: String| LintPolicyError::PolicyError {
+        source_index,
+        message,
+    };
+
+    if let Ok(json) = serde_json::from_str::<Value>(source) {
+        let policy = Policy::from_json(None, json).map_err(|e| policy_error(e.to_string()))?;
+        let start = source.len() - source.trim_start().len();
+        let location = location(source_index, source, start, &policy, 0);
+        return Ok(vec![(policy, location)]);
+    }
+
+    let policy_set = PolicySet::from_str(source).map_err(|e| policy_error(e.to_string()))?;
+    if policy_set.templates().next().is_some() {
+        return Err(policy_error(
+            "Policy templates cannot be linted directly".to_string(),
+        ));
+    }
+
+    // Cedar numbers policies in source order; sort by that number, then find
+    // each policy's text after the previous one to locate it
+    let mut policies: Vec<(usize, Policy)> = policy_set
+        .policies()
+        .map(|policy| {
+            let number = policy
+                .id()
+                .to_string()
+                .trim_start_matches("policy")
+                .parse()
+                .unwrap_or(usize::MAX);
+            (number, policy.clone())
+        })
+        .collect();
+    policies.sort_by_key(|(number, _)| *number);
+
+    let mut cursor = 0;
+    Ok(policies
+        .into_iter()
+        .enumerate()
+        .map(|(index, (_, policy))| {
+            let text = policy.to_string();
+            let start = source[cursor..]
+                .find(&text)
+                .map_or(cursor, |offset| cursor + offset);
+            cursor = start + text.len();
+            let location = location(source_index, source, start, &policy, index);
+            (policy, location)
+        })
+        .collect())
+}
+
+fn location(
+    source_index: usize,
+    source: &str,
+    start: usize,
+    policy: &Policy,
+    index: usize,
+) -> PolicyLocation {
+    let before = &source[..start];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    PolicyLocation {
+        source: source_index,
+        policy_id: policy
+            .annotation("id")
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("policy{}", index)),
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+/// Indexes of the policies Cedar's validator finds impossible under `schema`
+fn impossible_policies(policies: &[(Policy, PolicyLocation)], schema: &Schema) -> HashSet<usize> {
+    let mut policy_set = PolicySet::new();
+    for (index, (policy, _)) in policies.iter().enumerate() {
+        if let Err(e) = policy_set.add(policy.new_id(PolicyId::new(index.to_string()))) {
+            warn!("Could not check policy {} against the schema: {}", index, e);
+        }
+    }
+
+    Validator::new(schema.clone())
+        .validate(&policy_set, ValidationMode::default())
+        .validation_warnings()
+        .filter_map(|warning| match warning {
+            ValidationWarning::ImpossiblePolicy(w) => w.policy_id().to_string().parse().ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Rules the policy opts out of with `@lint_suppress`
+fn suppressed_rules(policy: &Policy) -> HashSet<LintRule> {
+    let Some(value) = policy.annotation(SUPPRESS_ANNOTATION) else {
+        return HashSet::new();
+    };
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .filter_map(|id| {
+            let rule = LintRule::from_id(id);
+            if rule.is_none() {
+                warn!(rule = id, "Unknown lint rule in @{}", SUPPRESS_ANNOTATION);
+            }
+            rule
+        })
+        .collect()
+}
+
+/// Check a policy against every rule
+fn lint(policy: &Policy, impossible: bool) -> Vec<Finding> {
+    let est = match policy.to_json() {
+        Ok(est) => est,
+        Err(e) => {
+            warn!("Policy {} could not be linted: {}", policy.id(), e);
+            return vec![];
+        }
+    };
+    let permit = est["effect"] == "permit";
+    let conditions: &[Value] = est["conditions"].as_array().map_or(&[], Vec::as_slice);
+    let unconstrained = |scope: &str| est[scope]["op"] == "All";
+
+    let mut findings = Vec::new();
+
+    if permit
+        && ["principal", "action", "resource"]
+            .into_iter()
+            .all(unconstrained)
+        && conditions.iter().all(is_always_true)
+    {
+        findings.push((
+            LintRule::OverlyPermissive,
+            "Policy permits every principal every action on every resource".to_string(),
+            Some(
+                "Constrain the principal, action or resource, or add a `when` condition"
+                    .to_string(),
+            ),
+        ));
+    } else if permit && unconstrained("action") {
+        findings.push((
+            LintRule::MissingConstraint,
+            "Policy permits every action, including actions added later".to_string(),
+            Some(
+                "Constrain the action, e.g. `action == Action::\"read\"` or `action in [...]`"
+                    .to_string(),
+            ),
+        ));
+    }
+
+    if let Some(condition) = conditions.iter().find(|c| is_always_false(c)) {
+        findings.push((
+            LintRule::Unreachable,
+            format!(
+                "Policy never applies: `{} {{ {} }}` never holds",
+                condition["kind"].as_str().unwrap_or("when"),
+                condition["kind"] == "unless"
+            ),
+            Some("Remove the policy or the condition".to_string()),
+        ));
+    } else if impossible {
+        findings.push((
+            LintRule::Unreachable,
+            "Policy never applies to a request the schema allows".to_string(),
+            Some(
+                "Check the scope and conditions against the schema's entity and action types"
+                    .to_string(),
+            ),
+        ));
+    }
+
+    for (index, condition) in conditions.iter().enumerate() {
+        let message = if is_always_true(condition) {
+            format!(
+                "`{} {{ {} }}` never restricts the policy",
+                condition["kind"].as_str().unwrap_or("when"),
+                condition["kind"] != "unless"
+            )
+        } else if conditions[..index].contains(condition) {
+            format!("Condition {} repeats an earlier condition", index + 1)
+        } else if let Some(scope) = repeated_scope(&est, condition) {
+            format!(
+                "Condition {} repeats the {} scope constraint",
+                index + 1,
+                scope
+            )
+        } else {
+            continue;
+        };
+        findings.push((
+            LintRule::RedundantCondition,
+            message,
+            Some("Remove the condition".to_string()),
+        ));
+    }
+
+    findings
+}
+
+fn literal(body: &Value) -> Option<bool> {
+    body["Value"].as_bool()
+}
+
+fn is_always_true(condition: &Value) -> bool {
+    literal(&condition["body"]) == Some(condition["kind"] != "unless")
+}
+
+fn is_always_false(condition: &Value) -> bool {
+    literal(&condition["body"]) == Some(condition["kind"] == "unless")
+}
+
+/// The scope variable a `when { <var> == <entity> }` condition repeats, if
+/// the scope already has the same `==` constraint
+fn repeated_scope<'a>(est: &Value, condition: &'a Value) -> Option<&'a str> {
+    if condition["kind"] != "when" {
+        return None;
+    }
+    let equality = &condition["body"]["=="];
+    let (var, entity) = match (&equality["left"]["Var"], &equality["right"]["Var"]) {
+        (Value::String(var), _) => (var, &equality["right"]["Value"]["__entity"]),
+        (_, Value::String(var)) => (var, &equality["left"]["Value"]["__entity"]),
+        _ => return None,
+    };
+    let scope = &est[var.as_str()];
+    (scope["op"] == "==" && !entity.is_null() && &scope["entity"] == entity).then_some(var.as_str())
+}
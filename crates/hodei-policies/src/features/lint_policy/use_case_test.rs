@@ -0,0 +1,191 @@
+use super::dto::{LintPolicyCommand, LintRule, LintSeverity};
+use super::error::LintPolicyError;
+use super::use_case::LintPolicyUseCase;
+
+const SCHEMA: &str = r#"
+namespace Iam {
+    entity User;
+}
+namespace Storage {
+    entity Document;
+}
+namespace Api {
+    action "read" appliesTo {
+        principal: [Iam::User],
+        resource: [Storage::Document]
+    };
+}
+"#;
+
+fn rules(result: &super::dto::LintPolicyResult) -> Vec<LintRule> {
+    result.warnings.iter().map(|warning| warning.rule).collect()
+}
+
+#[tokio::test]
+async fn test_well_constrained_policy_is_clean() {
+    let use_case = LintPolicyUseCase::new();
+
+    let result = use_case
+        .execute(LintPolicyCommand::new(vec![
+            r#"permit(principal, action == Api::Action::"read", resource)
+            when { resource.public };"#
+                .to_string(),
+        ]))
+        .await
+        .unwrap();
+
+    assert!(result.is_clean());
+    assert_eq!(result.suppressed, 0);
+}
+
+#[tokio::test]
+async fn test_unconstrained_permit_is_overly_permissive() {
+    let use_case = LintPolicyUseCase::new();
+
+    let result = use_case
+        .execute(LintPolicyCommand::new(vec![
+            "permit(principal, action, resource) when { true };".to_string(),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        rules(&result),
+        vec![LintRule::OverlyPermissive, LintRule::RedundantCondition]
+    );
+    let warning = &result.warnings[0];
+    assert_eq!(warning.severity, LintSeverity::High);
+    assert!(warning.suggestion.is_some());
+    assert_eq!(result.at_least(LintSeverity::Medium).count(), 1);
+}
+
+#[tokio::test]
+async fn test_warnings_locate_the_policy() {
+    let use_case = LintPolicyUseCase::new();
+    let source = r#"
+@id("readers")
+permit(principal, action == Api::Action::"read", resource);
+
+  @id("anything")
+  permit(principal == Iam::User::"alice", action, resource);
+"#;
+
+    let result = use_case
+        .execute(LintPolicyCommand::new(vec![
+            String::new(),
+            source.to_string(),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(rules(&result), vec![LintRule::MissingConstraint]);
+    let location = &result.warnings[0].location;
+    assert_eq!(location.source, 1);
+    assert_eq!(location.policy_id, "anything");
+    assert_eq!((location.line, location.column), (5, 3));
+}
+
+#[tokio::test]
+async fn test_conditions_that_never_hold_or_repeat_are_reported() {
+    let use_case = LintPolicyUseCase::new();
+
+    let result = use_case
+        .execute(LintPolicyCommand::new(vec![
+            r#"forbid(principal, action, resource) unless { true };
+            permit(principal == Iam::User::"alice", action == Api::Action::"read", resource)
+            when { principal == Iam::User::"alice" }
+            when { resource.public }
+            when { resource.public };"#
+                .to_string(),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        rules(&result),
+        vec![
+            LintRule::Unreachable,
+            LintRule::RedundantCondition,
+            LintRule::RedundantCondition,
+        ]
+    );
+    assert_eq!(result.warnings[0].location.policy_id, "policy0");
+    assert!(result.warnings[1].message.contains("principal scope"));
+    assert!(result.warnings[2].message.contains("earlier condition"));
+}
+
+#[tokio::test]
+async fn test_schema_reveals_policies_that_never_apply() {
+    let use_case = LintPolicyUseCase::new();
+
+    let result = use_case
+        .execute(
+            LintPolicyCommand::new(vec![
+                r#"permit(principal is Storage::Document, action == Api::Action::"read", resource);"#
+                    .to_string(),
+            ])
+            .with_schema(SCHEMA),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(rules(&result), vec![LintRule::Unreachable]);
+}
+
+#[tokio::test]
+async fn test_rules_can_be_suppressed_by_annotation() {
+    let use_case = LintPolicyUseCase::new();
+
+    let result = use_case
+        .execute(LintPolicyCommand::new(vec![
+            r#"@lint_suppress("overly-permissive, redundant-condition")
+            permit(principal, action, resource) when { true };"#
+                .to_string(),
+        ]))
+        .await
+        .unwrap();
+
+    assert!(result.is_clean());
+    assert_eq!(result.suppressed, 2);
+}
+
+#[tokio::test]
+async fn test_json_policies_are_linted() {
+    let use_case = LintPolicyUseCase::new();
+    let policy = serde_json::json!({
+        "effect": "permit",
+        "principal": { "op": "All" },
+        "action": { "op": "All" },
+        "resource": { "op": "All" },
+        "conditions": [],
+        "annotations": { "id": "open" }
+    });
+
+    let result = use_case
+        .execute(LintPolicyCommand::new(vec![policy.to_string()]))
+        .await
+        .unwrap();
+
+    assert_eq!(rules(&result), vec![LintRule::OverlyPermissive]);
+    assert_eq!(result.warnings[0].location.policy_id, "open");
+}
+
+#[tokio::test]
+async fn test_invalid_policy_is_an_error_not_a_warning() {
+    let use_case = LintPolicyUseCase::new();
+
+    let result = use_case
+        .execute(LintPolicyCommand::new(vec![
+            "permit(principal, action, resource);".to_string(),
+            "permit(principal".to_string(),
+        ]))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(LintPolicyError::PolicyError {
+            source_index: 1,
+            ..
+        })
+    ));
+}
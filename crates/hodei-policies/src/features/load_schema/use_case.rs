@@ -67,7 +67,8 @@ impl<S: SchemaStoragePort> LoadSchemaUseCase<S> {
     /// let command = LoadSchemaCommand::with_version("v1.0.0");
     /// let result = use_case.execute(command).await?;
     /// ```
-    #[tracing::instrument(skip(self, command), fields(
+    #[tracing::instrument(name = "load_schema", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
         version = ?command.version
     ))]
     pub async fn execute(
@@ -0,0 +1,229 @@
+//! Finding policies that grant an action to every principal
+//!
+//! A security review asks "does any policy grant this action to everyone?".
+//! [`BroadGrantFinder`] answers it by checking each policy against a
+//! universal principal: a `permit` whose action scope covers the action is
+//! a [`BroadGrant`] when its principal scope is unconstrained or only
+//! constrains the principal's type.
+//!
+//! The two are reported apart, since Cedar treats them differently: an
+//! unconstrained `principal` matches any entity at all, including entity
+//! types the schema doesn't declare as principals, whereas
+//! `principal is T` only matches entities of type `T`. A principal scope
+//! the finder can't read is never taken for either.
+//!
+//! With a schema ([`BroadGrantFinder::with_schema`]), a policy also grants
+//! the action where its scope is `action in` a group the action belongs to.
+//!
+//! Only scopes are compared, as for [`PolicyGrant`]: a policy with
+//! conditions is reported with [`GrantCoverage::Conditional`], since whether
+//! it grants depends on the request. `forbid` policies are not weighed
+//! against the grants they would override.
+
+use super::dto::PolicyEffect;
+use super::error::MatchPolicyError;
+use super::grant::{GrantCoverage, PolicyGrant, PrincipalScope, policy_est};
+use super::matcher::{ActionCriterion, parse_action, parse_schema, render_scope};
+use serde::{Deserialize, Serialize};
+
+/// A policy granting the action to every principal, or every principal of
+/// a type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BroadGrant {
+    pub policy_id: String,
+    /// [`PrincipalScope::Any`] or [`PrincipalScope::Type`]
+    pub principal: PrincipalScope,
+    /// `Conditional` if the policy has conditions
+    pub coverage: GrantCoverage,
+    /// The policy's resource scope in Cedar syntax, e.g. `resource in S3::Bucket::"logs"`
+    pub resource: String,
+}
+
+impl BroadGrant {
+    /// Whether the policy grants to any entity, not only principals of a type
+    pub fn grants_to_any_entity(&self) -> bool {
+        self.principal == PrincipalScope::Any
+    }
+}
+
+/// Finds the policies granting one action to every principal
+#[derive(Debug, Clone)]
+pub struct BroadGrantFinder {
+    action: ActionCriterion,
+}
+
+impl BroadGrantFinder {
+    /// Create a finder for `action`, as an action UID (`S3::Action::"Get"`)
+    /// or an action ID (`s3:Get`) in any namespace
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidQuery` if the action is empty or not a valid action UID.
+    pub fn new(action: &str) -> Result<Self, MatchPolicyError> {
+        Ok(Self {
+            action: ActionCriterion::new(parse_action(action)?),
+        })
+    }
+
+    /// Resolve the action groups the action belongs to from `schema`, in
+    /// Cedar JSON schema format or in Cedar schema syntax
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaError` if the schema cannot be parsed.
+    pub fn with_schema(mut self, schema: &str) -> Result<Self, MatchPolicyError> {
+        self.action.resolve_groups(&parse_schema(schema)?)?;
+        Ok(self)
+    }
+
+    /// Check one policy, in Cedar or Cedar JSON syntax
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError` if `content` is not a single valid policy.
+    pub fn check(
+        &self,
+        policy_id: &str,
+        content: &str,
+    ) -> Result<Option<BroadGrant>, MatchPolicyError> {
+        let est = policy_est(content)?;
+        let grant = PolicyGrant::from_est(&est);
+
+        if grant.effect() != PolicyEffect::Permit
+            || !grant.principal().is_broad()
+            || !grant.covers_action(&self.action)
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(BroadGrant {
+            policy_id: policy_id.to_string(),
+            principal: grant.principal().clone(),
+            coverage: if grant.has_conditions() {
+                GrantCoverage::Conditional
+            } else {
+                GrantCoverage::Full
+            },
+            resource: render_scope("resource", &est["resource"]),
+        }))
+    }
+
+    /// Check every policy of a set, given as `(policy_id, content)` pairs
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError` for the first policy that can't be parsed.
+    pub fn find<'a>(
+        &self,
+        policies: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Vec<BroadGrant>, MatchPolicyError> {
+        let mut grants = Vec::new();
+        for (policy_id, content) in policies {
+            grants.extend(self.check(policy_id, content)?);
+        }
+        Ok(grants)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICIES: [(&str, &str); 6] = [
+        ("everyone", "permit(principal, action, resource);"),
+        (
+            "all-users",
+            r#"permit(principal is S3::User, action == S3::Action::"s3:Get", resource in S3::Bucket::"logs");"#,
+        ),
+        (
+            "weekdays",
+            r#"permit(principal, action in [S3::Action::"s3:Get"], resource) when { context.weekday };"#,
+        ),
+        (
+            "ops-only",
+            r#"permit(principal in S3::Group::"ops", action, resource);"#,
+        ),
+        (
+            "other-action",
+            r#"permit(principal, action == S3::Action::"s3:Delete", resource);"#,
+        ),
+        ("deny-all", "forbid(principal, action, resource);"),
+    ];
+
+    #[test]
+    fn finds_grants_to_every_principal() {
+        let grants = BroadGrantFinder::new("s3:Get")
+            .unwrap()
+            .find(POLICIES)
+            .unwrap();
+
+        let ids: Vec<_> = grants.iter().map(|g| g.policy_id.as_str()).collect();
+        assert_eq!(ids, vec!["everyone", "all-users", "weekdays"]);
+
+        assert!(grants[0].grants_to_any_entity());
+        assert_eq!(grants[0].coverage, GrantCoverage::Full);
+        assert_eq!(grants[0].resource, "resource");
+
+        assert!(!grants[1].grants_to_any_entity());
+        assert_eq!(
+            grants[1].principal,
+            PrincipalScope::Type {
+                entity_type: "S3::User".to_string()
+            }
+        );
+        assert_eq!(grants[1].resource, r#"resource in S3::Bucket::"logs""#);
+
+        assert_eq!(grants[2].coverage, GrantCoverage::Conditional);
+    }
+
+    #[test]
+    fn expands_action_groups_from_the_schema() {
+        const SCHEMA: &str = r#"
+            namespace S3 {
+                entity User;
+                action "s3:Read";
+                action "s3:Get" in ["s3:Read"] appliesTo {
+                    principal: [User],
+                    resource: [User],
+                };
+            }
+        "#;
+        let policies = [
+            (
+                "readers",
+                r#"permit(principal, action in S3::Action::"s3:Read", resource);"#,
+            ),
+            (
+                "group-itself",
+                r#"permit(principal, action == S3::Action::"s3:Read", resource);"#,
+            ),
+        ];
+
+        let without_schema = BroadGrantFinder::new("s3:Get").unwrap();
+        assert!(without_schema.find(policies).unwrap().is_empty());
+
+        let grants = BroadGrantFinder::new("s3:Get")
+            .unwrap()
+            .with_schema(SCHEMA)
+            .unwrap()
+            .find(policies)
+            .unwrap();
+        // `==` names the group itself, not its members
+        let ids: Vec<_> = grants.iter().map(|g| g.policy_id.as_str()).collect();
+        assert_eq!(ids, vec!["readers"]);
+    }
+
+    #[test]
+    fn rejects_invalid_actions_and_policies() {
+        assert!(matches!(
+            BroadGrantFinder::new(" "),
+            Err(MatchPolicyError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            BroadGrantFinder::new("s3:Get")
+                .unwrap()
+                .find([("broken", "permit(")]),
+            Err(MatchPolicyError::PolicyError(_))
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+//! Data Transfer Objects for the match_policy feature
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Effect of a policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyEffect {
+    Permit,
+    Forbid,
+}
+
+impl fmt::Display for PolicyEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Permit => write!(f, "permit"),
+            Self::Forbid => write!(f, "forbid"),
+        }
+    }
+}
+
+/// What a policy must contain to match
+///
+/// A policy matches when it satisfies every criterion set; criteria left
+/// unset match any policy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyQuery {
+    /// Effect of the policy
+    #[serde(default)]
+    pub effect: Option<PolicyEffect>,
+
+    /// Action the policy applies to, as an action UID (`S3::Action::"Delete"`)
+    /// or as an action ID (`s3:Delete`) to match it in any namespace
+    ///
+    /// A policy applies to the action if its action scope names it, names
+    /// an action group it belongs to, or is unconstrained.
+    #[serde(default)]
+    pub action: Option<String>,
+
+    /// Resource type the policy applies to, qualified (`Docs::Document`) or
+    /// not (`Document`) to match it in any namespace
+    ///
+    /// A policy applies to the type if its resource scope is that type, an
+    /// entity of that type or, with a schema, an entity that can contain
+    /// it; or if the resource scope is unconstrained.
+    #[serde(default)]
+    pub resource_type: Option<String>,
+
+    /// Entity referenced anywhere in the policy, scope or conditions, as an
+    /// entity UID (`Iam::User::"alice"`)
+    #[serde(default)]
+    pub entity: Option<String>,
+}
+
+impl PolicyQuery {
+    /// Create a query matching every policy
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_effect(mut self, effect: PolicyEffect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+
+    pub fn with_action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    pub fn with_resource_type(mut self, resource_type: impl Into<String>) -> Self {
+        self.resource_type = Some(resource_type.into());
+        self
+    }
+
+    pub fn with_entity(mut self, entity: impl Into<String>) -> Self {
+        self.entity = Some(entity.into());
+        self
+    }
+
+    /// Whether no criterion is set
+    pub fn is_empty(&self) -> bool {
+        self.effect.is_none()
+            && self.action.is_none()
+            && self.resource_type.is_none()
+            && self.entity.is_none()
+    }
+}
+
+/// Part of a policy a fragment comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyPart {
+    Effect,
+    Principal,
+    Action,
+    Resource,
+    Condition,
+}
+
+/// A fragment of a policy that satisfied a criterion of the query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchedFragment {
+    pub part: PolicyPart,
+    /// The fragment in Cedar syntax, e.g. `action in [Action::"s3:Delete"]`
+    pub text: String,
+}
@@ -0,0 +1,19 @@
+//! Error types for the match_policy feature
+
+use thiserror::Error;
+
+/// Errors that prevent matching policies against a query
+#[derive(Debug, Clone, Error)]
+pub enum MatchPolicyError {
+    /// The query could not be understood
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
+    /// The schema used to resolve action groups could not be parsed
+    #[error("Schema error: {0}")]
+    SchemaError(String),
+
+    /// The policy could not be parsed
+    #[error("Policy error: {0}")]
+    PolicyError(String),
+}
@@ -0,0 +1,433 @@
+//! What a policy grants, precomputed from its scope
+//!
+//! [`PolicyMatcher`](super::PolicyMatcher) answers one query over many
+//! policies. Read models that answer many queries over the same policies
+//! (e.g. "who can access this resource") instead parse each policy once
+//! into a [`PolicyGrant`] and check it against every [`AccessRequest`].
+//!
+//! A grant only looks at the policy's scope. Conditions and a constrained
+//! principal can't be decided without the request itself, so a grant whose
+//! scope covers the request but that has either only covers it [`GrantCoverage::Conditional`]ly. Action groups are
+//! only expanded where they were resolved from a schema, as by
+//! [`BroadGrantFinder::with_schema`](super::BroadGrantFinder::with_schema);
+//! otherwise an `action in [..]` scope covers the actions it lists.
+//!
+//! Who the grant applies to is kept as a [`PrincipalScope`], so callers can
+//! tell a grant to one user from a grant to every principal.
+
+use super::dto::PolicyEffect;
+use super::error::MatchPolicyError;
+use super::matcher::{
+    ActionCriterion, entity_of, parse_action, parse_entity, render_scope, type_matches,
+};
+use crate::features::validate_policy::syntax::parse_policy;
+use cedar_policy::EntityUid;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// How a grant covers a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GrantCoverage {
+    /// The policy applies to every such request
+    Full,
+    /// The policy applies depending on the request's context, attributes or
+    /// principal
+    Conditional,
+}
+
+/// Which principals a policy's scope admits
+///
+/// Follows Cedar's semantics: an unconstrained `principal` matches any
+/// entity at all, of any type, while `principal is T` only matches entities
+/// of type `T`. Conditions on the principal are not part of the scope.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PrincipalScope {
+    /// `principal`: any entity, whatever its type
+    Any,
+    /// `principal is T`: every entity of type `T`
+    Type { entity_type: String },
+    /// `principal is T in G`: entities of type `T` within `G`
+    TypeIn {
+        entity_type: String,
+        container: String,
+    },
+    /// `principal in G`: `G` and the entities within it, of any type
+    In { container: String },
+    /// `principal == E`: a single entity
+    Entity { entity: String },
+    /// A scope that names no entity this crate can read, such as a template
+    /// slot; never assumed to admit every principal
+    Unrecognized { scope: String },
+}
+
+impl PrincipalScope {
+    /// Whether the scope admits every principal of some type or more, rather
+    /// than specific entities or the members of a group
+    pub fn is_broad(&self) -> bool {
+        matches!(self, Self::Any | Self::Type { .. })
+    }
+}
+
+/// A request to check grants against: an action on a resource
+#[derive(Debug, Clone)]
+pub struct AccessRequest {
+    action: ActionCriterion,
+    resource: EntityUid,
+    ancestors: Vec<EntityUid>,
+}
+
+impl AccessRequest {
+    /// Create a request for `action`, as an action UID (`S3::Action::"Get"`)
+    /// or an action ID (`s3:Get`), on the `resource` entity UID
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidQuery` if the action or resource is not valid.
+    pub fn new(action: &str, resource: &str) -> Result<Self, MatchPolicyError> {
+        Ok(Self {
+            action: ActionCriterion::new(parse_action(action)?),
+            resource: parse_entity(resource)?,
+            ancestors: Vec::new(),
+        })
+    }
+
+    /// Entities containing the resource, so `resource in ..` scopes naming
+    /// one of them cover the request
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidQuery` if an ancestor is not a valid entity UID.
+    pub fn with_ancestors<'a>(
+        mut self,
+        ancestors: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, MatchPolicyError> {
+        for ancestor in ancestors {
+            self.ancestors.push(parse_entity(ancestor)?);
+        }
+        Ok(self)
+    }
+
+    fn is_within(&self, container: &EntityUid) -> bool {
+        &self.resource == container || self.ancestors.contains(container)
+    }
+}
+
+/// The scope of a policy, reduced to what decides whether it covers a request
+#[derive(Debug, Clone)]
+pub struct PolicyGrant {
+    effect: PolicyEffect,
+    actions: ActionScope,
+    resource: ResourceScope,
+    principal: PrincipalScope,
+    /// Whether the policy has `when` or `unless` conditions
+    has_conditions: bool,
+}
+
+#[derive(Debug, Clone)]
+enum ActionScope {
+    Any,
+    Entity(EntityUid),
+    /// `action in [..]`: the actions listed and, with groups resolved, the
+    /// members of the groups listed
+    In(Vec<EntityUid>),
+}
+
+#[derive(Debug, Clone)]
+enum ResourceScope {
+    Any,
+    Entity(EntityUid),
+    In(EntityUid),
+    Is {
+        entity_type: String,
+        within: Option<EntityUid>,
+    },
+}
+
+impl PolicyGrant {
+    /// Parse the grant of one policy, in Cedar or Cedar JSON syntax
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError` if `content` is not a single valid policy.
+    pub fn from_policy(content: &str) -> Result<Self, MatchPolicyError> {
+        Ok(Self::from_est(&policy_est(content)?))
+    }
+
+    /// The grant of a policy in Cedar's JSON form
+    pub(super) fn from_est(est: &Value) -> Self {
+        let effect = match est["effect"].as_str() {
+            Some("forbid") => PolicyEffect::Forbid,
+            _ => PolicyEffect::Permit,
+        };
+
+        Self {
+            effect,
+            actions: action_scope(&est["action"]),
+            resource: resource_scope(&est["resource"]),
+            principal: principal_scope(&est["principal"]),
+            has_conditions: est["conditions"]
+                .as_array()
+                .is_some_and(|conditions| !conditions.is_empty()),
+        }
+    }
+
+    pub fn effect(&self) -> PolicyEffect {
+        self.effect
+    }
+
+    pub fn principal(&self) -> &PrincipalScope {
+        &self.principal
+    }
+
+    /// Whether the policy has `when` or `unless` conditions
+    pub fn has_conditions(&self) -> bool {
+        self.has_conditions
+    }
+
+    /// Whether the action scope covers `action`, whatever the resource
+    pub(super) fn covers_action(&self, action: &ActionCriterion) -> bool {
+        match &self.actions {
+            ActionScope::Any => true,
+            ActionScope::Entity(uid) => action.is(uid),
+            ActionScope::In(uids) => uids.iter().any(|uid| action.is_within(uid)),
+        }
+    }
+
+    /// How the policy covers `request`, or `None` if its scope excludes it
+    pub fn covers(&self, request: &AccessRequest) -> Option<GrantCoverage> {
+        let action_covered = self.covers_action(&request.action);
+        let resource_covered = match &self.resource {
+            ResourceScope::Any => true,
+            ResourceScope::Entity(uid) => uid == &request.resource,
+            ResourceScope::In(container) => request.is_within(container),
+            ResourceScope::Is {
+                entity_type,
+                within,
+            } => {
+                type_matches(entity_type, &request.resource.type_name().to_string())
+                    && within
+                        .as_ref()
+                        .is_none_or(|container| request.is_within(container))
+            }
+        };
+
+        let conditional = self.has_conditions || self.principal != PrincipalScope::Any;
+        (action_covered && resource_covered).then_some(if conditional {
+            GrantCoverage::Conditional
+        } else {
+            GrantCoverage::Full
+        })
+    }
+}
+
+/// Cedar's JSON form of a single policy, in Cedar or Cedar JSON syntax
+pub(super) fn policy_est(content: &str) -> Result<Value, MatchPolicyError> {
+    let (policy, _) =
+        parse_policy(content).map_err(|e| MatchPolicyError::PolicyError(e.to_string()))?;
+    policy
+        .to_json()
+        .map_err(|e| MatchPolicyError::PolicyError(e.to_string()))
+}
+
+fn principal_scope(scope: &Value) -> PrincipalScope {
+    let target = |value: &Value| entity_of(&value["entity"]).map(|uid| uid.to_string());
+    let unrecognized = || PrincipalScope::Unrecognized {
+        scope: render_scope("principal", scope),
+    };
+    match scope["op"].as_str() {
+        Some("All") => PrincipalScope::Any,
+        Some("==") => {
+            target(scope).map_or_else(unrecognized, |entity| PrincipalScope::Entity { entity })
+        }
+        Some("in") => {
+            target(scope).map_or_else(unrecognized, |container| PrincipalScope::In { container })
+        }
+        Some("is") => {
+            let Some(entity_type) = scope["entity_type"].as_str() else {
+                return unrecognized();
+            };
+            let entity_type = entity_type.to_string();
+            match scope.get("in") {
+                None => PrincipalScope::Type { entity_type },
+                Some(container) => match target(container) {
+                    Some(container) => PrincipalScope::TypeIn {
+                        entity_type,
+                        container,
+                    },
+                    None => unrecognized(),
+                },
+            }
+        }
+        _ => unrecognized(),
+    }
+}
+
+fn action_scope(scope: &Value) -> ActionScope {
+    match scope["op"].as_str() {
+        Some("All") | None => ActionScope::Any,
+        Some("==") => match entity_of(&scope["entity"]) {
+            Some(uid) => ActionScope::Entity(uid),
+            None => ActionScope::In(Vec::new()),
+        },
+        _ => ActionScope::In(match scope["entities"].as_array() {
+            Some(entities) => entities.iter().filter_map(entity_of).collect(),
+            None => entity_of(&scope["entity"]).into_iter().collect(),
+        }),
+    }
+}
+
+fn resource_scope(scope: &Value) -> ResourceScope {
+    let target = |value: &Value| entity_of(&value["entity"]);
+    match scope["op"].as_str() {
+        Some("==") => target(scope).map_or(ResourceScope::Any, ResourceScope::Entity),
+        Some("in") => target(scope).map_or(ResourceScope::Any, ResourceScope::In),
+        Some("is") => {
+            let entity_type = scope["entity_type"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            ResourceScope::Is {
+                entity_type,
+                within: scope.get("in").and_then(target),
+            }
+        }
+        _ => ResourceScope::Any,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(action: &str, resource: &str) -> AccessRequest {
+        AccessRequest::new(action, resource).unwrap()
+    }
+
+    fn covers(policy: &str, request: &AccessRequest) -> Option<GrantCoverage> {
+        PolicyGrant::from_policy(policy).unwrap().covers(request)
+    }
+
+    #[test]
+    fn covers_requests_within_the_scope() {
+        let get = request(r#"S3::Action::"s3:Get""#, r#"S3::Object::"report""#);
+        let delete = request(r#"S3::Action::"s3:Delete""#, r#"S3::Object::"report""#);
+
+        let policy = r#"permit(principal, action == S3::Action::"s3:Get", resource == S3::Object::"report");"#;
+        assert_eq!(covers(policy, &get), Some(GrantCoverage::Full));
+        assert_eq!(covers(policy, &delete), None);
+
+        let any = "permit(principal, action, resource);";
+        assert_eq!(covers(any, &delete), Some(GrantCoverage::Full));
+
+        let listed = r#"forbid(principal, action in [S3::Action::"s3:Put", S3::Action::"s3:Delete"], resource is S3::Object);"#;
+        let grant = PolicyGrant::from_policy(listed).unwrap();
+        assert_eq!(grant.effect(), PolicyEffect::Forbid);
+        assert_eq!(grant.covers(&delete), Some(GrantCoverage::Full));
+        assert_eq!(grant.covers(&get), None);
+
+        let by_id = request("s3:Get", r#"S3::Object::"report""#);
+        assert_eq!(covers(policy, &by_id), Some(GrantCoverage::Full));
+    }
+
+    #[test]
+    fn containers_cover_through_the_requests_ancestors() {
+        let policy = r#"permit(principal, action, resource in S3::Bucket::"finance");"#;
+        let typed =
+            r#"permit(principal, action, resource is S3::Object in S3::Bucket::"finance");"#;
+        let get = request("s3:Get", r#"S3::Object::"report""#);
+        let in_finance = get
+            .clone()
+            .with_ancestors([r#"S3::Bucket::"finance""#])
+            .unwrap();
+
+        assert_eq!(covers(policy, &get), None);
+        assert_eq!(covers(policy, &in_finance), Some(GrantCoverage::Full));
+        assert_eq!(covers(typed, &in_finance), Some(GrantCoverage::Full));
+        let bucket = request("s3:Get", r#"S3::Bucket::"finance""#);
+        assert_eq!(covers(policy, &bucket), Some(GrantCoverage::Full));
+        assert_eq!(covers(typed, &bucket), None);
+    }
+
+    #[test]
+    fn conditions_and_principals_make_coverage_conditional() {
+        let get = request("s3:Get", r#"S3::Object::"report""#);
+
+        let when = r#"permit(principal, action, resource) when { context.mfa == true };"#;
+        let principal = r#"permit(principal == Iam::User::"alice", action, resource);"#;
+        for policy in [when, principal] {
+            assert_eq!(covers(policy, &get), Some(GrantCoverage::Conditional));
+        }
+    }
+
+    #[test]
+    fn keeps_who_the_policy_applies_to() {
+        let principal = |policy: &str| {
+            PolicyGrant::from_policy(policy)
+                .unwrap()
+                .principal()
+                .clone()
+        };
+
+        assert_eq!(
+            principal("permit(principal, action, resource);"),
+            PrincipalScope::Any
+        );
+        let user_type = principal("permit(principal is Iam::User, action, resource);");
+        assert_eq!(
+            user_type,
+            PrincipalScope::Type {
+                entity_type: "Iam::User".to_string()
+            }
+        );
+        assert!(user_type.is_broad());
+
+        let narrow = [
+            principal(r#"permit(principal is Iam::User in Iam::Group::"ops", action, resource);"#),
+            principal(r#"permit(principal in Iam::Group::"ops", action, resource);"#),
+            principal(r#"permit(principal == Iam::User::"alice", action, resource);"#),
+        ];
+        assert_eq!(
+            narrow[1],
+            PrincipalScope::In {
+                container: r#"Iam::Group::"ops""#.to_string()
+            }
+        );
+        assert!(narrow.iter().all(|scope| !scope.is_broad()));
+    }
+
+    #[test]
+    fn unreadable_principals_are_not_read_as_any_principal() {
+        let est = serde_json::json!({
+            "effect": "permit",
+            "principal": { "op": "==", "slot": "?principal" },
+            "action": { "op": "All" },
+            "resource": { "op": "All" },
+            "conditions": []
+        });
+        let grant = PolicyGrant::from_est(&est);
+
+        assert_eq!(
+            grant.principal(),
+            &PrincipalScope::Unrecognized {
+                scope: "principal == ?principal".to_string()
+            }
+        );
+        assert!(!grant.principal().is_broad());
+        let get = request("s3:Get", r#"S3::Object::"report""#);
+        assert_eq!(grant.covers(&get), Some(GrantCoverage::Conditional));
+    }
+
+    #[test]
+    fn rejects_invalid_requests_and_policies() {
+        assert!(matches!(
+            AccessRequest::new("s3:Get", "report"),
+            Err(MatchPolicyError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            PolicyGrant::from_policy("permit("),
+            Err(MatchPolicyError::PolicyError(_))
+        ));
+    }
+}
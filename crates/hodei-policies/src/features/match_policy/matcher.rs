@@ -0,0 +1,646 @@
+//! Matching policies against a query
+//!
+//! Policies are compared on Cedar's JSON form of their AST, so formatting,
+//! comments and the syntax they were written in make no difference.
+
+use super::dto::{MatchedFragment, PolicyEffect, PolicyPart, PolicyQuery};
+use super::error::MatchPolicyError;
+use crate::features::validate_policy::syntax::parse_policy;
+use cedar_policy::{EntityTypeName, EntityUid, Policy, Schema};
+use serde_json::Value;
+use std::str::FromStr;
+
+/// Matches policies against a [`PolicyQuery`]
+///
+/// Build one per query and reuse it for every policy searched. Without a
+/// schema, an action matches where the policy names it; with one, also
+/// where the policy names an action group the action belongs to, and a
+/// resource type also matches where the policy names an entity that can
+/// contain resources of that type.
+#[derive(Debug, Clone)]
+pub struct PolicyMatcher {
+    effect: Option<PolicyEffect>,
+    action: Option<ActionCriterion>,
+    resource_type: Option<ResourceTypeCriterion>,
+    entity: Option<EntityUid>,
+}
+
+/// The action queried and the action groups it belongs to
+#[derive(Debug, Clone)]
+pub(super) struct ActionCriterion {
+    action: ActionPattern,
+    groups: Vec<EntityUid>,
+}
+
+impl ActionCriterion {
+    /// The criterion for `action`, with no groups until a schema resolves them
+    pub(super) fn new(action: ActionPattern) -> Self {
+        Self {
+            action,
+            groups: Vec::new(),
+        }
+    }
+
+    /// Resolve the action groups the action belongs to from `schema`
+    pub(super) fn resolve_groups(&mut self, schema: &Schema) -> Result<(), MatchPolicyError> {
+        let actions = schema
+            .action_entities()
+            .map_err(|e| MatchPolicyError::SchemaError(e.to_string()))?;
+        let mut groups = Vec::new();
+        for action in schema
+            .actions()
+            .filter(|action| self.action.matches(action))
+        {
+            if let Some(ancestors) = actions.ancestors(action) {
+                groups.extend(ancestors.cloned());
+            }
+        }
+        groups.sort_by_key(ToString::to_string);
+        groups.dedup();
+        self.groups = groups;
+        Ok(())
+    }
+
+    /// Whether `uid` is the action itself
+    pub(super) fn is(&self, uid: &EntityUid) -> bool {
+        self.action.matches(uid)
+    }
+
+    /// Whether `uid` is the action or a group it belongs to, as in an
+    /// `action in ..` scope
+    pub(super) fn is_within(&self, uid: &EntityUid) -> bool {
+        self.action.matches(uid) || self.groups.contains(uid)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum ActionPattern {
+    /// A full action UID
+    Uid(EntityUid),
+    /// An action ID, in any namespace
+    Id(String),
+}
+
+impl ActionPattern {
+    pub(super) fn matches(&self, uid: &EntityUid) -> bool {
+        match self {
+            Self::Uid(action) => action == uid,
+            Self::Id(id) => {
+                uid.type_name().basename() == "Action" && uid.id().unescaped() == id.as_str()
+            }
+        }
+    }
+}
+
+/// The resource type queried and the entity types that can contain it
+#[derive(Debug, Clone)]
+struct ResourceTypeCriterion {
+    resource_type: String,
+    containers: Vec<String>,
+}
+
+impl PolicyMatcher {
+    /// Create a matcher for `query`
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidQuery` if a criterion is empty, or the action or
+    /// entity is not a valid Cedar entity UID.
+    pub fn new(query: &PolicyQuery) -> Result<Self, MatchPolicyError> {
+        let action = query
+            .action
+            .as_deref()
+            .map(|action| parse_action(action).map(ActionCriterion::new))
+            .transpose()?;
+        let resource_type = query
+            .resource_type
+            .as_deref()
+            .map(|resource_type| {
+                parse_resource_type(resource_type).map(|resource_type| ResourceTypeCriterion {
+                    resource_type,
+                    containers: Vec::new(),
+                })
+            })
+            .transpose()?;
+        let entity = query.entity.as_deref().map(parse_entity).transpose()?;
+
+        Ok(Self {
+            effect: query.effect,
+            action,
+            resource_type,
+            entity,
+        })
+    }
+
+    /// Resolve action groups and resource containers from `schema`, in
+    /// Cedar JSON schema format or in Cedar schema syntax
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaError` if the schema cannot be parsed.
+    pub fn with_schema(mut self, schema: &str) -> Result<Self, MatchPolicyError> {
+        let schema = parse_schema(schema)?;
+
+        if let Some(criterion) = &mut self.action {
+            criterion.resolve_groups(&schema)?;
+        }
+
+        if let Some(criterion) = &mut self.resource_type {
+            let mut containers: Vec<String> = schema
+                .entity_types()
+                .filter(|ty| type_matches(&criterion.resource_type, &ty.to_string()))
+                .filter_map(|ty| schema.ancestors(ty))
+                .flatten()
+                .map(ToString::to_string)
+                .collect();
+            containers.sort();
+            containers.dedup();
+            criterion.containers = containers;
+        }
+
+        Ok(self)
+    }
+
+    /// Match one policy, in Cedar or Cedar JSON syntax
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(fragments))` if the policy satisfies every criterion, with
+    ///   the fragments that satisfied them
+    /// * `Ok(None)` if it does not
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError` if `content` is not a single valid policy.
+    pub fn matches(&self, content: &str) -> Result<Option<Vec<MatchedFragment>>, MatchPolicyError> {
+        let (policy, _) =
+            parse_policy(content).map_err(|e| MatchPolicyError::PolicyError(e.to_string()))?;
+        self.matches_policy(&policy)
+    }
+
+    fn matches_policy(
+        &self,
+        policy: &Policy,
+    ) -> Result<Option<Vec<MatchedFragment>>, MatchPolicyError> {
+        let est = policy
+            .to_json()
+            .map_err(|e| MatchPolicyError::PolicyError(e.to_string()))?;
+        let mut fragments = Vec::new();
+
+        if let Some(effect) = self.effect {
+            if est["effect"] != effect.to_string() {
+                return Ok(None);
+            }
+            fragments.push(fragment(PolicyPart::Effect, effect.to_string()));
+        }
+
+        if let Some(criterion) = &self.action {
+            if !action_applies(criterion, &est["action"]) {
+                return Ok(None);
+            }
+            fragments.push(fragment(
+                PolicyPart::Action,
+                render_scope("action", &est["action"]),
+            ));
+        }
+
+        if let Some(criterion) = &self.resource_type {
+            if !resource_type_applies(criterion, &est["resource"]) {
+                return Ok(None);
+            }
+            let text = render_scope("resource", &est["resource"]);
+            if !fragments.iter().any(|f| f.text == text) {
+                fragments.push(fragment(PolicyPart::Resource, text));
+            }
+        }
+
+        if let Some(entity) = &self.entity {
+            let mut found = false;
+            for (part, var) in [
+                (PolicyPart::Principal, "principal"),
+                (PolicyPart::Action, "action"),
+                (PolicyPart::Resource, "resource"),
+            ] {
+                if references(&est[var], entity) {
+                    found = true;
+                    let text = render_scope(var, &est[var]);
+                    if !fragments.iter().any(|f| f.text == text) {
+                        fragments.push(fragment(part, text));
+                    }
+                }
+            }
+            for condition in est["conditions"].as_array().into_iter().flatten() {
+                if references(&condition["body"], entity) {
+                    found = true;
+                    fragments.push(fragment(
+                        PolicyPart::Condition,
+                        render_condition(&est, condition),
+                    ));
+                }
+            }
+            if !found {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(fragments))
+    }
+}
+
+fn action_applies(criterion: &ActionCriterion, scope: &Value) -> bool {
+    match scope["op"].as_str() {
+        Some("All") => true,
+        Some("==") => entity_of(&scope["entity"]).is_some_and(|uid| criterion.is(&uid)),
+        Some("in") => match scope["entities"].as_array() {
+            Some(entities) => entities
+                .iter()
+                .filter_map(entity_of)
+                .any(|uid| criterion.is_within(&uid)),
+            None => entity_of(&scope["entity"]).is_some_and(|uid| criterion.is_within(&uid)),
+        },
+        _ => false,
+    }
+}
+
+fn resource_type_applies(criterion: &ResourceTypeCriterion, scope: &Value) -> bool {
+    let contains = |uid: EntityUid| {
+        let ty = uid.type_name().to_string();
+        type_matches(&criterion.resource_type, &ty) || criterion.containers.contains(&ty)
+    };
+    match scope["op"].as_str() {
+        Some("All") => true,
+        Some("==") => entity_of(&scope["entity"]).is_some_and(|uid| {
+            type_matches(&criterion.resource_type, &uid.type_name().to_string())
+        }),
+        Some("in") => entity_of(&scope["entity"]).is_some_and(contains),
+        Some("is") => scope["entity_type"]
+            .as_str()
+            .is_some_and(|ty| type_matches(&criterion.resource_type, ty)),
+        _ => false,
+    }
+}
+
+/// Whether the entity type `ty` is the queried type; an unqualified query
+/// matches the type in any namespace
+pub(super) fn type_matches(query: &str, ty: &str) -> bool {
+    ty == query || (!query.contains("::") && ty.rsplit("::").next() == Some(query))
+}
+
+/// Whether `entity` appears anywhere under `value`
+fn references(value: &Value, entity: &EntityUid) -> bool {
+    match value {
+        Value::Object(map) => {
+            for key in ["entity", "__entity"] {
+                if map.get(key).and_then(entity_of).as_ref() == Some(entity) {
+                    return true;
+                }
+            }
+            map.values().any(|v| references(v, entity))
+        }
+        Value::Array(values) => values.iter().any(|v| references(v, entity)),
+        _ => false,
+    }
+}
+
+pub(super) fn entity_of(value: &Value) -> Option<EntityUid> {
+    if !value.is_object() {
+        return None;
+    }
+    EntityUid::from_json(value.clone()).ok()
+}
+
+fn fragment(part: PolicyPart, text: String) -> MatchedFragment {
+    MatchedFragment { part, text }
+}
+
+fn render_entity(value: &Value) -> String {
+    entity_of(value).map_or_else(|| value.to_string(), |uid| uid.to_string())
+}
+
+/// A scope constraint in Cedar syntax, e.g. `resource is Docs::Document`
+pub(super) fn render_scope(var: &str, scope: &Value) -> String {
+    let target = |value: &Value| match value.get("slot").and_then(Value::as_str) {
+        Some(slot) => slot.to_string(),
+        None => render_entity(&value["entity"]),
+    };
+    match scope["op"].as_str() {
+        Some("All") | None => var.to_string(),
+        Some("is") => {
+            let ty = scope["entity_type"].as_str().unwrap_or_default();
+            match scope.get("in") {
+                Some(container) => format!("{} is {} in {}", var, ty, target(container)),
+                None => format!("{} is {}", var, ty),
+            }
+        }
+        Some(op) => match scope["entities"].as_array() {
+            Some(entities) => format!(
+                "{} {} [{}]",
+                var,
+                op,
+                entities
+                    .iter()
+                    .map(render_entity)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => format!("{} {} {}", var, op, target(scope)),
+        },
+    }
+}
+
+/// A `when` or `unless` clause in Cedar syntax, rendered by Cedar itself
+fn render_condition(est: &Value, condition: &Value) -> String {
+    let clause = serde_json::json!({
+        "effect": est["effect"],
+        "principal": { "op": "All" },
+        "action": { "op": "All" },
+        "resource": { "op": "All" },
+        "conditions": [condition],
+    });
+    let Ok(policy) = Policy::from_json(None, clause) else {
+        return condition.to_string();
+    };
+    // The policy renders as `permit(principal, action, resource) when { .. };`
+    let text = policy.to_string();
+    let clause = text
+        .split_once(") ")
+        .map_or(text.as_str(), |(_, clause)| clause);
+    clause.trim_end().trim_end_matches(';').to_string()
+}
+
+pub(super) fn parse_action(action: &str) -> Result<ActionPattern, MatchPolicyError> {
+    let action = action.trim();
+    if action.is_empty() {
+        return Err(MatchPolicyError::InvalidQuery(
+            "Action cannot be empty".to_string(),
+        ));
+    }
+    if !action.contains("::\"") {
+        return Ok(ActionPattern::Id(action.to_string()));
+    }
+    let uid = EntityUid::from_str(action).map_err(|e| {
+        MatchPolicyError::InvalidQuery(format!("Invalid action '{}': {}", action, e))
+    })?;
+    if uid.type_name().basename() != "Action" {
+        return Err(MatchPolicyError::InvalidQuery(format!(
+            "'{}' is not an action",
+            action
+        )));
+    }
+    Ok(ActionPattern::Uid(uid))
+}
+
+fn parse_resource_type(resource_type: &str) -> Result<String, MatchPolicyError> {
+    let resource_type = resource_type.trim();
+    EntityTypeName::from_str(resource_type)
+        .map(|ty| ty.to_string())
+        .map_err(|e| {
+            MatchPolicyError::InvalidQuery(format!(
+                "Invalid resource type '{}': {}",
+                resource_type, e
+            ))
+        })
+}
+
+pub(super) fn parse_entity(entity: &str) -> Result<EntityUid, MatchPolicyError> {
+    EntityUid::from_str(entity.trim()).map_err(|e| {
+        MatchPolicyError::InvalidQuery(format!(
+            "Invalid entity '{}', expected e.g. Iam::User::\"alice\": {}",
+            entity, e
+        ))
+    })
+}
+
+/// Parse a schema in Cedar JSON format or, otherwise, in Cedar schema syntax
+pub(super) fn parse_schema(source: &str) -> Result<Schema, MatchPolicyError> {
+    if source.trim_start().starts_with('{') {
+        return Schema::from_json_str(source)
+            .map_err(|e| MatchPolicyError::SchemaError(e.to_string()));
+    }
+    Schema::from_cedarschema_str(source)
+        .map(|(schema, _warnings)| schema)
+        .map_err(|e| MatchPolicyError::SchemaError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+        namespace S3 {
+            entity Bucket;
+            entity Object in [Bucket];
+            entity User;
+            action "s3:Write";
+            action "s3:Delete" in ["s3:Write"] appliesTo {
+                principal: [User],
+                resource: [Object],
+            };
+            action "s3:Get" appliesTo {
+                principal: [User],
+                resource: [Object],
+            };
+        }
+    "#;
+
+    fn texts(fragments: Option<Vec<MatchedFragment>>) -> Vec<String> {
+        fragments
+            .expect("policy should match")
+            .into_iter()
+            .map(|f| f.text)
+            .collect()
+    }
+
+    #[test]
+    fn matches_actions_however_the_policy_is_written() {
+        let matcher = PolicyMatcher::new(&PolicyQuery::new().with_action("s3:Delete")).unwrap();
+
+        let compact = r#"permit(principal,action==S3::Action::"s3:Delete",resource);"#;
+        let spread = r#"
+            // Cleanup job
+            permit(
+                principal,
+                action in [
+                    S3::Action::"s3:Get",
+                    S3::Action::"s3:Delete"
+                ],
+                resource
+            );"#;
+        let json = r#"{
+            "effect": "permit",
+            "principal": { "op": "All" },
+            "action": { "op": "==", "entity": { "type": "S3::Action", "id": "s3:Delete" } },
+            "resource": { "op": "All" },
+            "conditions": []
+        }"#;
+
+        assert_eq!(
+            texts(matcher.matches(compact).unwrap()),
+            vec![r#"action == S3::Action::"s3:Delete""#]
+        );
+        assert_eq!(
+            texts(matcher.matches(spread).unwrap()),
+            vec![r#"action in [S3::Action::"s3:Get", S3::Action::"s3:Delete"]"#]
+        );
+        assert!(matcher.matches(json).unwrap().is_some());
+        let mentioned = r#"permit(principal, action == S3::Action::"s3:Get", resource)
+            when { context.note == "s3:Delete" };"#;
+        assert!(matcher.matches(mentioned).unwrap().is_none());
+    }
+
+    #[test]
+    fn matches_action_uids_exactly() {
+        let matcher =
+            PolicyMatcher::new(&PolicyQuery::new().with_action(r#"S3::Action::"s3:Delete""#))
+                .unwrap();
+
+        assert!(
+            matcher
+                .matches(r#"permit(principal, action == S3::Action::"s3:Delete", resource);"#)
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            matcher
+                .matches(r#"permit(principal, action == Other::Action::"s3:Delete", resource);"#)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unconstrained_actions_apply_to_every_action() {
+        let matcher = PolicyMatcher::new(&PolicyQuery::new().with_action("s3:Delete")).unwrap();
+
+        assert_eq!(
+            texts(
+                matcher
+                    .matches("permit(principal, action, resource);")
+                    .unwrap()
+            ),
+            vec!["action"]
+        );
+    }
+
+    #[test]
+    fn action_groups_match_with_a_schema() {
+        let query = PolicyQuery::new().with_action("s3:Delete");
+        let policy = r#"permit(principal, action in S3::Action::"s3:Write", resource);"#;
+
+        let without_schema = PolicyMatcher::new(&query).unwrap();
+        assert!(without_schema.matches(policy).unwrap().is_none());
+
+        let with_schema = PolicyMatcher::new(&query)
+            .unwrap()
+            .with_schema(SCHEMA)
+            .unwrap();
+        assert_eq!(
+            texts(with_schema.matches(policy).unwrap()),
+            vec![r#"action in S3::Action::"s3:Write""#]
+        );
+        // `==` names the group itself, not its members
+        assert!(
+            with_schema
+                .matches(r#"permit(principal, action == S3::Action::"s3:Write", resource);"#)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn matches_effect_and_resource_type() {
+        let matcher = PolicyMatcher::new(
+            &PolicyQuery::new()
+                .with_effect(PolicyEffect::Forbid)
+                .with_resource_type("Object"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            texts(
+                matcher
+                    .matches("forbid(principal, action, resource is S3::Object);")
+                    .unwrap()
+            ),
+            vec!["forbid", "resource is S3::Object"]
+        );
+        assert!(
+            matcher
+                .matches("permit(principal, action, resource is S3::Object);")
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            matcher
+                .matches("forbid(principal, action, resource is S3::Bucket);")
+                .unwrap()
+                .is_none()
+        );
+
+        let in_bucket = r#"forbid(principal, action, resource in S3::Bucket::"logs");"#;
+        assert!(matcher.matches(in_bucket).unwrap().is_none());
+        let with_schema = matcher.with_schema(SCHEMA).unwrap();
+        assert!(with_schema.matches(in_bucket).unwrap().is_some());
+    }
+
+    #[test]
+    fn matches_entities_in_scope_and_conditions() {
+        let matcher =
+            PolicyMatcher::new(&PolicyQuery::new().with_entity(r#"S3::User::"alice""#)).unwrap();
+
+        assert_eq!(
+            texts(
+                matcher
+                    .matches(r#"permit(principal == S3::User::"alice", action, resource);"#)
+                    .unwrap()
+            ),
+            vec![r#"principal == S3::User::"alice""#]
+        );
+        assert_eq!(
+            texts(
+                matcher
+                    .matches(
+                        r#"permit(principal, action, resource)
+                           when { resource.owner == S3::User::"alice" }
+                           unless { context.readonly };"#
+                    )
+                    .unwrap()
+            ),
+            vec![r#"when { resource.owner == S3::User::"alice" }"#]
+        );
+        assert!(
+            matcher
+                .matches(r#"permit(principal == S3::User::"bob", action, resource);"#)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_queries_and_policies() {
+        assert!(matches!(
+            PolicyMatcher::new(&PolicyQuery::new().with_entity("alice")),
+            Err(MatchPolicyError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            PolicyMatcher::new(&PolicyQuery::new().with_action(r#"S3::User::"alice""#)),
+            Err(MatchPolicyError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            PolicyMatcher::new(&PolicyQuery::new().with_action(" ")),
+            Err(MatchPolicyError::InvalidQuery(_))
+        ));
+
+        let matcher = PolicyMatcher::new(&PolicyQuery::new()).unwrap();
+        assert!(matches!(
+            matcher.matches("not a policy"),
+            Err(MatchPolicyError::PolicyError(_))
+        ));
+        assert_eq!(
+            matcher
+                .matches("permit(principal, action, resource);")
+                .unwrap(),
+            Some(vec![])
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! Match Policy Feature
+//!
+//! Answers "does this policy grant X?" from the policy's structure rather
+//! than its text: a policy is parsed and its scope and conditions are
+//! compared against a [`PolicyQuery`], so `Action::"s3:Delete"` matches
+//! however the policy is formatted, written in Cedar or in Cedar JSON.
+//! Matches come with the fragments that satisfied the query, for audits.
+//!
+//! Read models checking the same policies over and over parse each one once
+//! into a [`PolicyGrant`] instead. [`BroadGrantFinder`] uses the same grants
+//! to find policies that give an action to every principal.
+//! [`PolicyReferences`] lists the entities and entity types a policy names.
+
+pub mod broad_grants;
+pub mod dto;
+pub mod error;
+pub mod grant;
+pub mod matcher;
+pub mod references;
+
+// Re-export for convenience
+pub use dto::{MatchedFragment, PolicyEffect, PolicyPart, PolicyQuery};
+pub use error::MatchPolicyError;
+pub use broad_grants::{BroadGrant, BroadGrantFinder};
+pub use grant::{AccessRequest, GrantCoverage, PolicyGrant, PrincipalScope};
+pub use matcher::PolicyMatcher;
+pub use references::{EntityReference, PolicyReferences};
@@ -0,0 +1,137 @@
+//! The entities and entity types a policy names
+//!
+//! A policy names an entity *instance* when its scope or conditions use an
+//! entity literal (`principal == Iam::User::"alice"`,
+//! `resource in Iam::Group::"devs"`), and an entity *type* when it uses `is`
+//! (`principal is Iam::User`). Instances can be deleted from under the
+//! policy, types can't. Action entities are part of the schema rather than
+//! stored data, so they are never reported.
+
+use super::error::MatchPolicyError;
+use super::grant::policy_est;
+use super::matcher::entity_of;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// An entity instance named by a policy
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EntityReference {
+    /// Entity type, e.g. `Iam::User`
+    pub entity_type: String,
+    /// Entity ID within the type, e.g. `alice`
+    pub entity_id: String,
+}
+
+impl EntityReference {
+    pub fn new(entity_type: impl Into<String>, entity_id: impl Into<String>) -> Self {
+        Self {
+            entity_type: entity_type.into(),
+            entity_id: entity_id.into(),
+        }
+    }
+}
+
+impl fmt::Display for EntityReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{:?}", self.entity_type, self.entity_id)
+    }
+}
+
+/// What a policy names, each entity and type once, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyReferences {
+    /// Entity instances, which can dangle
+    pub entities: Vec<EntityReference>,
+    /// Entity types named with `is`, which can't
+    pub entity_types: Vec<String>,
+}
+
+impl PolicyReferences {
+    /// Collect the references of one policy, in Cedar or Cedar JSON syntax
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolicyError` if `content` is not a single valid policy.
+    pub fn from_policy(content: &str) -> Result<Self, MatchPolicyError> {
+        let mut entities = BTreeSet::new();
+        let mut entity_types = BTreeSet::new();
+        collect(&policy_est(content)?, &mut entities, &mut entity_types);
+        Ok(Self {
+            entities: entities.into_iter().collect(),
+            entity_types: entity_types.into_iter().collect(),
+        })
+    }
+}
+
+fn collect(
+    value: &Value,
+    entities: &mut BTreeSet<EntityReference>,
+    entity_types: &mut BTreeSet<String>,
+) {
+    match value {
+        Value::Object(map) => {
+            for key in ["entity", "__entity"] {
+                if let Some(uid) = map.get(key).and_then(entity_of) {
+                    let entity_type = uid.type_name().to_string();
+                    if !is_action_type(&entity_type) {
+                        entities.insert(EntityReference::new(entity_type, uid.id().unescaped()));
+                    }
+                }
+            }
+            if let Some(entity_type) = map.get("entity_type").and_then(Value::as_str) {
+                entity_types.insert(entity_type.to_string());
+            }
+            map.values()
+                .for_each(|v| collect(v, entities, entity_types));
+        }
+        Value::Array(values) => values
+            .iter()
+            .for_each(|v| collect(v, entities, entity_types)),
+        _ => {}
+    }
+}
+
+/// Whether `entity_type` is `Action` or a namespaced `Ns::Action`
+fn is_action_type(entity_type: &str) -> bool {
+    entity_type.rsplit("::").next() == Some("Action")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separates_instances_from_types_and_skips_actions() {
+        let references = PolicyReferences::from_policy(
+            r#"permit(
+                principal in Iam::Group::"devs",
+                action == Iam::Action::"CreateUser",
+                resource is Iam::User
+            ) when {
+                context.approver == Iam::User::"bob" && principal is Iam::User
+                    && resource in [Iam::Group::"ops", Iam::Group::"devs"]
+            };"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            references.entities,
+            vec![
+                EntityReference::new("Iam::Group", "devs"),
+                EntityReference::new("Iam::Group", "ops"),
+                EntityReference::new("Iam::User", "bob"),
+            ]
+        );
+        assert_eq!(references.entity_types, vec!["Iam::User".to_string()]);
+    }
+
+    #[test]
+    fn unconstrained_policy_names_nothing() {
+        let references =
+            PolicyReferences::from_policy("permit(principal, action, resource);").unwrap();
+        assert_eq!(references, PolicyReferences::default());
+        assert!(PolicyReferences::from_policy("permit(").is_err());
+    }
+}
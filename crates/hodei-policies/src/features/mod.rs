@@ -1,7 +1,11 @@
 pub mod build_schema;
 pub mod evaluate_policies;
+pub mod lint_policy;
 pub mod load_schema;
+pub mod match_policy;
 pub mod playground_evaluate;
 pub mod register_action_type;
 pub mod register_entity_type;
+pub mod register_schema_fragments;
+pub mod test_policy;
 pub mod validate_policy;
@@ -4,12 +4,16 @@
 //! authorization engine to evaluate policies against requests.
 
 use super::super::dto::{
-    Decision, DeterminingPolicy, PlaygroundAuthorizationRequest, PolicyEffect,
+    ConditionKind, ConditionResult, ConditionTrace, Decision, DeterminingPolicy,
+    PlaygroundAuthorizationRequest, PolicyBreakdown, PolicyEffect, PolicyOutcome, PolicyTrace,
 };
 use super::super::error::PlaygroundEvaluateError;
 use super::super::ports::PolicyEvaluatorPort;
 use async_trait::async_trait;
-use cedar_policy::{Authorizer, Context, Entities, EntityUid, Policy, PolicySet, Request, Schema};
+use cedar_policy::pst::{Clause, StaticPolicy, Template};
+use cedar_policy::{
+    Authorizer, Context, Entities, EntityUid, Policy, PolicyId, PolicySet, Request, Schema,
+};
 use std::str::FromStr;
 use tracing::{debug, info, warn};
 
@@ -36,6 +40,8 @@ impl PolicyEvaluatorAdapter {
 
     /// Parse policy texts into a Cedar PolicySet
     ///
+    /// Policies are numbered in order (`policy0`, `policy1`...).
+    ///
     /// # Arguments
     ///
     /// * `policy_texts` - List of Cedar policy strings
@@ -56,10 +62,7 @@ impl PolicyEvaluatorAdapter {
         let mut policy_set = PolicySet::new();
 
         for (index, policy_text) in policy_texts.iter().enumerate() {
-            let policy = Policy::from_str(policy_text).map_err(|e| {
-                warn!(policy_index = index, error = %e, "Policy parsing failed");
-                PlaygroundEvaluateError::PolicyError(format!("Policy {} parse error: {}", index, e))
-            })?;
+            let policy = self.parse_policy(index, policy_text)?;
 
             policy_set.add(policy).map_err(|e| {
                 warn!(policy_index = index, error = %e, "Failed to add policy to set");
@@ -77,6 +80,18 @@ impl PolicyEvaluatorAdapter {
         Ok(policy_set)
     }
 
+    /// Parse the policy at `index`, with `policy{index}` as its ID
+    fn parse_policy(
+        &self,
+        index: usize,
+        policy_text: &str,
+    ) -> Result<Policy, PlaygroundEvaluateError> {
+        Policy::parse(Some(PolicyId::new(format!("policy{}", index))), policy_text).map_err(|e| {
+            warn!(policy_index = index, error = %e, "Policy parsing failed");
+            PlaygroundEvaluateError::PolicyError(format!("Policy {} parse error: {}", index, e))
+        })
+    }
+
     /// Convert an HRN to a Cedar EntityUid
     ///
     /// # Arguments
@@ -175,6 +190,112 @@ impl PolicyEvaluatorAdapter {
 
         (decision, determining_policies)
     }
+
+    /// Evaluate `policy` on its own against the request
+    fn evaluate_alone(
+        &self,
+        policy: Policy,
+        request: &Request,
+        entities: &Entities,
+    ) -> PolicyOutcome {
+        let policy_id = policy.id().clone();
+        let policy_set = match PolicySet::from_policies([policy]) {
+            Ok(policy_set) => policy_set,
+            Err(e) => {
+                return PolicyOutcome::Error {
+                    message: e.to_string(),
+                };
+            }
+        };
+
+        let response = Authorizer::new().is_authorized(request, &policy_set, entities);
+        if let Some(error) = response.diagnostics().errors().next() {
+            return PolicyOutcome::Error {
+                message: error.to_string(),
+            };
+        }
+        if response.diagnostics().reason().any(|id| *id == policy_id) {
+            PolicyOutcome::Satisfied
+        } else {
+            PolicyOutcome::NotSatisfied
+        }
+    }
+
+    /// Trace one policy: its outcome, then its scope and conditions
+    fn trace_policy(&self, policy: &Policy, request: &Request, entities: &Entities) -> PolicyTrace {
+        let effect = match policy.effect() {
+            cedar_policy::Effect::Permit => PolicyEffect::Permit,
+            cedar_policy::Effect::Forbid => PolicyEffect::Forbid,
+        };
+
+        let breakdown = self
+            .break_down(policy, request, entities)
+            .unwrap_or_else(|reason| {
+                debug!(policy_id = %policy.id(), reason = %reason, "Policy can't be broken down");
+                PolicyBreakdown::Unavailable { reason }
+            });
+
+        PolicyTrace {
+            policy_id: policy.id().to_string(),
+            effect,
+            outcome: self.evaluate_alone(policy.clone(), request, entities),
+            breakdown,
+            determining: false,
+        }
+    }
+
+    /// Evaluate the scope of `policy`, then each of its conditions on its own
+    ///
+    /// Each step evaluates a copy of the policy with only the scope, or the
+    /// scope and one condition. Fails if those copies can't be built.
+    fn break_down(
+        &self,
+        policy: &Policy,
+        request: &Request,
+        entities: &Entities,
+    ) -> Result<PolicyBreakdown, String> {
+        let template = policy.to_pst().map_err(|e| e.to_string())?.body().clone();
+        let with_clauses = |clauses: Vec<Clause>| -> Result<Policy, String> {
+            let template: Template = template
+                .clone()
+                .try_with_clauses(clauses)
+                .map_err(|e| e.to_string())?;
+            let policy = StaticPolicy::try_from(template).map_err(|e| e.to_string())?;
+            Policy::from_pst(policy.into()).map_err(|e| e.to_string())
+        };
+
+        if self.evaluate_alone(with_clauses(vec![])?, request, entities) != PolicyOutcome::Satisfied
+        {
+            return Ok(PolicyBreakdown::ScopeNotMatched);
+        }
+
+        let conditions = template
+            .clauses()
+            .iter()
+            .map(|clause| {
+                let (kind, expression) = match clause {
+                    Clause::When(expr) => (ConditionKind::When, expr.to_string()),
+                    Clause::Unless(expr) => (ConditionKind::Unless, expr.to_string()),
+                };
+                let result = match self.evaluate_alone(
+                    with_clauses(vec![clause.clone()])?,
+                    request,
+                    entities,
+                ) {
+                    PolicyOutcome::Satisfied => ConditionResult::Passed,
+                    PolicyOutcome::NotSatisfied => ConditionResult::Failed,
+                    PolicyOutcome::Error { message } => ConditionResult::Error { message },
+                };
+                Ok(ConditionTrace {
+                    kind,
+                    expression,
+                    result,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(PolicyBreakdown::ScopeMatched { conditions })
+    }
 }
 
 impl Default for PolicyEvaluatorAdapter {
@@ -225,6 +346,28 @@ impl PolicyEvaluatorPort for PolicyEvaluatorAdapter {
 
         Ok((decision, determining_policies))
     }
+
+    async fn trace(
+        &self,
+        request: &PlaygroundAuthorizationRequest,
+        policy_texts: &[String],
+        _schema: &Schema,
+    ) -> Result<Vec<PolicyTrace>, PlaygroundEvaluateError> {
+        let cedar_request = self.build_cedar_request(request)?;
+        let entities = Entities::empty();
+
+        let trace = policy_texts
+            .iter()
+            .enumerate()
+            .map(|(index, policy_text)| {
+                let policy = self.parse_policy(index, policy_text)?;
+                Ok(self.trace_policy(&policy, &cedar_request, &entities))
+            })
+            .collect::<Result<Vec<_>, PlaygroundEvaluateError>>()?;
+
+        debug!(policy_count = trace.len(), "Traced policies one by one");
+        Ok(trace)
+    }
 }
 
 #[cfg(test)]
@@ -288,22 +431,74 @@ mod tests {
         assert!(result.is_err());
     }
 
-    // TODO: Fix this test - Cedar PolicySet.add() has issues with duplicate IDs
-    // #[tokio::test]
-    // async fn test_evaluate_multiple_policies() {
-    //     let evaluator = PolicyEvaluatorAdapter::new();
-    //     let request = create_test_request();
-    //     let schema = Schema::from_schema_fragments(vec![]).unwrap();
-    //     let policies = vec![
-    //         "permit(principal, action, resource);".to_string(),
-    //         "forbid(principal, action, resource) when { false };".to_string(),
-    //     ];
-    //
-    //     let result = evaluator.evaluate(&request, &policies, &schema).await;
-    //     assert!(result.is_ok());
-    //     let (decision, _determining) = result.unwrap();
-    //     assert_eq!(decision, Decision::Allow);
-    // }
+    #[tokio::test]
+    async fn test_evaluate_multiple_policies() {
+        let evaluator = PolicyEvaluatorAdapter::new();
+        let request = create_test_request();
+        let schema = Schema::from_schema_fragments(vec![]).unwrap();
+        let policies = vec![
+            "permit(principal, action, resource);".to_string(),
+            "forbid(principal, action, resource) when { false };".to_string(),
+        ];
+
+        let result = evaluator.evaluate(&request, &policies, &schema).await;
+        assert!(result.is_ok());
+        let (decision, determining) = result.unwrap();
+        assert_eq!(decision, Decision::Allow);
+        assert_eq!(determining[0].policy_id, "policy0");
+    }
+
+    #[tokio::test]
+    async fn test_trace_breaks_down_each_policy() {
+        let evaluator = PolicyEvaluatorAdapter::new();
+        let request = create_test_request();
+        let schema = Schema::from_schema_fragments(vec![]).unwrap();
+        let policies = vec![
+            "permit(principal, action, resource) when { 1 < 2 } unless { false };".to_string(),
+            "forbid(principal, action, resource) when { context.blocked };".to_string(),
+            r#"permit(principal == Other::User::"bob", action, resource);"#.to_string(),
+            "permit(principal, action, resource) when { 1 < 2 } when { 2 < 1 };".to_string(),
+        ];
+
+        let trace = evaluator.trace(&request, &policies, &schema).await.unwrap();
+        assert_eq!(trace.len(), 4);
+
+        assert_eq!(trace[0].policy_id, "policy0");
+        assert_eq!(trace[0].outcome, PolicyOutcome::Satisfied);
+        let PolicyBreakdown::ScopeMatched { conditions } = &trace[0].breakdown else {
+            panic!("unexpected breakdown: {:?}", trace[0].breakdown);
+        };
+        assert_eq!(conditions.len(), 2);
+        assert_eq!(conditions[0].kind, ConditionKind::When);
+        assert_eq!(conditions[0].expression, "1 < 2");
+        assert_eq!(conditions[0].result, ConditionResult::Passed);
+        assert_eq!(conditions[1].kind, ConditionKind::Unless);
+        assert_eq!(conditions[1].result, ConditionResult::Passed);
+
+        // The context has no `blocked` attribute
+        assert_eq!(trace[1].effect, PolicyEffect::Forbid);
+        assert!(matches!(trace[1].outcome, PolicyOutcome::Error { .. }));
+        let PolicyBreakdown::ScopeMatched { conditions } = &trace[1].breakdown else {
+            panic!("unexpected breakdown: {:?}", trace[1].breakdown);
+        };
+        assert!(matches!(
+            conditions[0].result,
+            ConditionResult::Error { .. }
+        ));
+
+        assert_eq!(trace[2].outcome, PolicyOutcome::NotSatisfied);
+        assert_eq!(trace[2].breakdown, PolicyBreakdown::ScopeNotMatched);
+
+        assert_eq!(trace[3].outcome, PolicyOutcome::NotSatisfied);
+        let PolicyBreakdown::ScopeMatched { conditions } = &trace[3].breakdown else {
+            panic!("unexpected breakdown: {:?}", trace[3].breakdown);
+        };
+        let results: Vec<_> = conditions.iter().map(|c| c.result.clone()).collect();
+        assert_eq!(
+            results,
+            vec![ConditionResult::Passed, ConditionResult::Failed]
+        );
+    }
 
     #[tokio::test]
     async fn test_parse_policies_empty() {
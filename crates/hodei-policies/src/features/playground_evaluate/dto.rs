@@ -275,6 +275,14 @@ pub struct EvaluationDiagnostics {
 
     /// Warnings (if any)
     pub warnings: Vec<String>,
+
+    /// How each policy fared, in the order the policies were given
+    #[serde(default)]
+    pub policy_trace: Vec<PolicyTrace>,
+
+    /// How the policy outcomes combined into the decision (None without a trace)
+    #[serde(default)]
+    pub combination: Option<DecisionCombination>,
 }
 
 impl EvaluationDiagnostics {
@@ -286,6 +294,8 @@ impl EvaluationDiagnostics {
             schema_validated: false,
             validation_errors: vec![],
             warnings: vec![],
+            policy_trace: vec![],
+            combination: None,
         }
     }
 
@@ -295,6 +305,17 @@ impl EvaluationDiagnostics {
         self
     }
 
+    /// Add the per-policy trace and the combination it leads to
+    pub fn with_policy_trace(mut self, mut trace: Vec<PolicyTrace>) -> Self {
+        let combination = DecisionCombination::of(&trace);
+        for policy in &mut trace {
+            policy.determining = combination.is_determined_by(policy);
+        }
+        self.combination = (!trace.is_empty()).then_some(combination);
+        self.policy_trace = trace;
+        self
+    }
+
     /// Add a validation error
     pub fn add_validation_error(&mut self, error: String) {
         self.validation_errors.push(error);
@@ -306,6 +327,155 @@ impl EvaluationDiagnostics {
     }
 }
 
+/// How one policy fared in the evaluation
+///
+/// `outcome` is what Cedar decided for the policy as a whole; `breakdown`
+/// explains it in terms of the policy's scope and each of its conditions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyTrace {
+    /// The policy ID (`policy0`, `policy1`... in the order given)
+    pub policy_id: String,
+
+    /// The effect of the policy (permit or forbid)
+    pub effect: PolicyEffect,
+
+    /// Whether the policy was satisfied by the request
+    pub outcome: PolicyOutcome,
+
+    /// The policy's scope and conditions, evaluated one by one
+    pub breakdown: PolicyBreakdown,
+
+    /// Whether the policy is one of those that determined the decision
+    pub determining: bool,
+}
+
+/// Outcome of one policy for the request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PolicyOutcome {
+    /// Scope matched and every condition held: the policy applies
+    Satisfied,
+    /// Scope didn't match or a condition didn't hold
+    NotSatisfied,
+    /// Evaluation failed; Cedar skips the policy
+    Error { message: String },
+}
+
+/// Evaluation of a policy's scope and conditions one by one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PolicyBreakdown {
+    /// The principal, action or resource scope doesn't match the request;
+    /// the conditions are not evaluated
+    ScopeNotMatched,
+    /// The scope matches; each condition is evaluated on its own, in order
+    ScopeMatched { conditions: Vec<ConditionTrace> },
+    /// The policy couldn't be broken down; only its outcome is known
+    Unavailable { reason: String },
+}
+
+/// A `when` or `unless` clause of a policy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionTrace {
+    /// Whether the clause is a `when` or an `unless`
+    pub kind: ConditionKind,
+
+    /// The clause's expression in Cedar syntax
+    pub expression: String,
+
+    /// Whether the clause lets the policy apply
+    pub result: ConditionResult,
+}
+
+/// Kind of policy condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConditionKind {
+    /// Applies the policy when the expression is true
+    When,
+    /// Applies the policy unless the expression is true
+    Unless,
+}
+
+/// Result of one condition
+///
+/// A `when` passes when its expression is true and an `unless` when it is
+/// false. Every condition is evaluated, even after one that failed, whereas
+/// Cedar stops at the first one that fails.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ConditionResult {
+    /// The condition lets the policy apply
+    Passed,
+    /// The condition keeps the policy from applying
+    Failed,
+    /// The expression couldn't be evaluated
+    Error { message: String },
+}
+
+/// How the policy outcomes combine into the decision
+///
+/// A satisfied `forbid` always wins; otherwise a satisfied `permit` allows,
+/// and without one the request is denied by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionCombination {
+    /// Denied by the satisfied forbid policies, whatever the permits
+    ForbidOverrides,
+    /// Allowed by the satisfied permit policies
+    Permitted,
+    /// Denied because no permit policy is satisfied
+    DefaultDeny,
+}
+
+impl DecisionCombination {
+    /// Combine the outcomes of a trace
+    pub fn of(trace: &[PolicyTrace]) -> Self {
+        let satisfied = |effect| {
+            trace
+                .iter()
+                .any(|policy| policy.effect == effect && policy.outcome == PolicyOutcome::Satisfied)
+        };
+        if satisfied(PolicyEffect::Forbid) {
+            DecisionCombination::ForbidOverrides
+        } else if satisfied(PolicyEffect::Permit) {
+            DecisionCombination::Permitted
+        } else {
+            DecisionCombination::DefaultDeny
+        }
+    }
+
+    /// Whether `policy` is one of the policies behind this combination
+    pub fn is_determined_by(&self, policy: &PolicyTrace) -> bool {
+        let effect = match self {
+            DecisionCombination::ForbidOverrides => PolicyEffect::Forbid,
+            DecisionCombination::Permitted => PolicyEffect::Permit,
+            DecisionCombination::DefaultDeny => return false,
+        };
+        policy.effect == effect && policy.outcome == PolicyOutcome::Satisfied
+    }
+
+    /// The decision this combination leads to
+    pub fn decision(&self) -> Decision {
+        match self {
+            DecisionCombination::Permitted => Decision::Allow,
+            DecisionCombination::ForbidOverrides | DecisionCombination::DefaultDeny => {
+                Decision::Deny
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for DecisionCombination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecisionCombination::ForbidOverrides => write!(f, "forbid_overrides"),
+            DecisionCombination::Permitted => write!(f, "permitted"),
+            DecisionCombination::DefaultDeny => write!(f, "default_deny"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -435,6 +605,53 @@ mod tests {
         assert_eq!(Decision::Deny.to_string(), "DENY");
     }
 
+    #[test]
+    fn test_policy_trace_combines_into_the_decision() {
+        let policy = |policy_id: &str, effect, outcome| PolicyTrace {
+            policy_id: policy_id.to_string(),
+            effect,
+            outcome,
+            breakdown: PolicyBreakdown::ScopeNotMatched,
+            determining: false,
+        };
+
+        let diagnostics = EvaluationDiagnostics::new(3, 0).with_policy_trace(vec![
+            policy("policy0", PolicyEffect::Permit, PolicyOutcome::Satisfied),
+            policy("policy1", PolicyEffect::Forbid, PolicyOutcome::Satisfied),
+            policy("policy2", PolicyEffect::Forbid, PolicyOutcome::NotSatisfied),
+        ]);
+        assert_eq!(
+            diagnostics.combination,
+            Some(DecisionCombination::ForbidOverrides)
+        );
+        let determining: Vec<_> = diagnostics
+            .policy_trace
+            .iter()
+            .map(|policy| policy.determining)
+            .collect();
+        assert_eq!(determining, vec![false, true, false]);
+
+        let errored = vec![policy(
+            "policy0",
+            PolicyEffect::Permit,
+            PolicyOutcome::Error {
+                message: "boom".to_string(),
+            },
+        )];
+        assert_eq!(
+            DecisionCombination::of(&errored),
+            DecisionCombination::DefaultDeny
+        );
+        assert_eq!(DecisionCombination::DefaultDeny.decision(), Decision::Deny);
+
+        assert!(
+            EvaluationDiagnostics::new(0, 0)
+                .with_policy_trace(vec![])
+                .combination
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_authorization_request_with_context() {
         let request = PlaygroundAuthorizationRequest::new(
@@ -75,9 +75,10 @@ mod use_case_test;
 
 // Re-export for convenience
 pub use dto::{
-    AttributeValue, Decision, DeterminingPolicy, EvaluationDiagnostics,
-    PlaygroundAuthorizationRequest, PlaygroundEvaluateCommand, PlaygroundEvaluateResult,
-    PolicyEffect,
+    AttributeValue, ConditionKind, ConditionResult, ConditionTrace, Decision, DecisionCombination,
+    DeterminingPolicy, EvaluationDiagnostics, PlaygroundAuthorizationRequest,
+    PlaygroundEvaluateCommand, PlaygroundEvaluateResult, PolicyBreakdown, PolicyEffect,
+    PolicyOutcome, PolicyTrace,
 };
 pub use error::PlaygroundEvaluateError;
 pub use ports::{
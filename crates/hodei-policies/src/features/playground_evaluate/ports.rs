@@ -9,7 +9,9 @@
 use async_trait::async_trait;
 use cedar_policy::Schema;
 
-use super::dto::{AttributeValue, Decision, DeterminingPolicy, PlaygroundAuthorizationRequest};
+use super::dto::{
+    AttributeValue, Decision, DeterminingPolicy, PlaygroundAuthorizationRequest, PolicyTrace,
+};
 use super::error::PlaygroundEvaluateError;
 
 /// Port for loading Cedar schemas (inline or from storage)
@@ -105,6 +107,24 @@ pub trait PolicyEvaluatorPort: Send + Sync {
         policy_texts: &[String],
         schema: &Schema,
     ) -> Result<(Decision, Vec<DeterminingPolicy>), PlaygroundEvaluateError>;
+
+    /// Trace how each policy fares against the request
+    ///
+    /// Evaluates every policy on its own, then its scope and each of its
+    /// conditions, for the same request as [`evaluate`](Self::evaluate).
+    /// Evaluators that can't break policies down return an empty trace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policies or the request can't be parsed
+    async fn trace(
+        &self,
+        _request: &PlaygroundAuthorizationRequest,
+        _policy_texts: &[String],
+        _schema: &Schema,
+    ) -> Result<Vec<PolicyTrace>, PlaygroundEvaluateError> {
+        Ok(Vec::new())
+    }
 }
 
 /// Port for converting context attributes to Cedar format
@@ -12,7 +12,7 @@ use super::ports::{
 };
 use async_trait::async_trait;
 use std::sync::Arc;
-use tracing::{debug, info, instrument, warn};
+use tracing::{Instrument, debug, info, info_span, instrument, warn};
 
 /// Use case for playground policy evaluation
 ///
@@ -78,6 +78,7 @@ impl PlaygroundEvaluateUseCase {
     /// 3. Validates policies against the schema
     /// 4. Converts the authorization request
     /// 5. Evaluates policies and returns decision with diagnostics
+    /// 6. Traces each policy's scope and conditions into the diagnostics
     ///
     /// # Arguments
     ///
@@ -107,7 +108,8 @@ impl PlaygroundEvaluateUseCase {
     /// let result = use_case.execute(command).await?;
     /// println!("Decision: {}", result.decision);
     /// ```
-    #[instrument(skip(self, command), fields(
+    #[instrument(name = "playground_evaluate", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
         has_inline_schema = command.inline_schema.is_some(),
         schema_version = ?command.schema_version,
         policy_count = command.inline_policies.len()
@@ -145,6 +147,7 @@ impl PlaygroundEvaluateUseCase {
         let validation_errors = self
             .policy_validator
             .validate_policies(&command.inline_policies, &schema)
+            .instrument(info_span!("validation"))
             .await
             .map_err(|e| {
                 warn!("Policy validation failed: {}", e);
@@ -183,6 +186,7 @@ impl PlaygroundEvaluateUseCase {
         let (decision, determining_policies) = self
             .policy_evaluator
             .evaluate(&command.request, &command.inline_policies, &schema)
+            .instrument(info_span!("evaluation"))
             .await
             .map_err(|e| {
                 warn!("Policy evaluation failed: {}", e);
@@ -192,13 +196,27 @@ impl PlaygroundEvaluateUseCase {
         // Update diagnostics with matched policies count
         diagnostics.matched_policies = determining_policies.len();
 
+        // Step 6: Trace each policy on its own; the decision stands without it
+        match self
+            .policy_evaluator
+            .trace(&command.request, &command.inline_policies, &schema)
+            .instrument(info_span!("trace"))
+            .await
+        {
+            Ok(trace) => diagnostics = diagnostics.with_policy_trace(trace),
+            Err(e) => {
+                warn!("Policy trace failed: {}", e);
+                diagnostics.add_warning(format!("Evaluation trace unavailable: {}", e));
+            }
+        }
+
         info!(
             decision = %decision,
             determining_policies = determining_policies.len(),
             "Playground evaluation completed successfully"
         );
 
-        // Step 6: Build and return result
+        // Step 7: Build and return result
         let result = PlaygroundEvaluateResult::new(decision, determining_policies, diagnostics);
 
         // Add validation errors as result errors if any
@@ -5,7 +5,7 @@ use crate::internal::engine::builder::EngineBuilder;
 use async_trait::async_trait;
 use kernel::ActionTrait;
 use std::sync::{Arc, Mutex};
-use tracing::info;
+use tracing::{info, instrument};
 
 /// Use case for registering action types in the Cedar schema
 ///
@@ -130,6 +130,9 @@ impl RegisterActionTypeUseCase {
     /// Execute action type registration from a command
     ///
     /// This is the command-based interface that satisfies the port trait.
+    #[instrument(name = "register_action_type", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref()
+    ))]
     pub async fn execute(
         &self,
         _command: RegisterActionTypeCommand,
@@ -0,0 +1,106 @@
+//! Data Transfer Objects for the register_schema_fragments feature
+
+use crate::features::register_action_type::RegisterActionTypeUseCase;
+use crate::features::register_action_type::error::RegisterActionTypeError;
+use crate::features::register_entity_type::RegisterEntityTypeUseCase;
+use crate::features::register_entity_type::error::RegisterEntityTypeError;
+use kernel::{ActionTrait, HodeiEntityType};
+
+/// Registers one type with its registration use case
+pub(crate) type Registration<U, E> = fn(&U) -> Result<(), E>;
+
+/// A type declared by a fragment, by the name it has in the schema
+pub(crate) struct FragmentEntry<U, E> {
+    pub(crate) name: String,
+    pub(crate) register: Registration<U, E>,
+}
+
+/// The entity and action types one bounded context adds to the schema
+///
+/// Types are added by their Rust type, so a fragment can only declare types
+/// that implement [`HodeiEntityType`] or [`ActionTrait`]:
+///
+/// ```rust,ignore
+/// let fragment = SchemaFragment::new("hodei-artifacts")
+///     .with_entity_type::<Artifact>()
+///     .with_action_type::<UploadArtifactAction>();
+/// ```
+pub struct SchemaFragment {
+    source: String,
+    pub(crate) entity_types: Vec<FragmentEntry<RegisterEntityTypeUseCase, RegisterEntityTypeError>>,
+    pub(crate) action_types: Vec<FragmentEntry<RegisterActionTypeUseCase, RegisterActionTypeError>>,
+}
+
+impl SchemaFragment {
+    /// Create an empty fragment
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Who contributes the fragment, usually the crate name;
+    ///   conflict errors name it
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            entity_types: Vec::new(),
+            action_types: Vec::new(),
+        }
+    }
+
+    /// Declare the entity type `T`
+    pub fn with_entity_type<T: HodeiEntityType>(mut self) -> Self {
+        self.entity_types.push(FragmentEntry {
+            name: T::entity_type_name(),
+            register: RegisterEntityTypeUseCase::register::<T>,
+        });
+        self
+    }
+
+    /// Declare the action type `A`
+    pub fn with_action_type<A: ActionTrait>(mut self) -> Self {
+        self.action_types.push(FragmentEntry {
+            // Actions are built without a namespace, so the bare name is
+            // what two fragments can collide on
+            name: A::name().to_string(),
+            register: RegisterActionTypeUseCase::register::<A>,
+        });
+        self
+    }
+
+    /// Who contributes the fragment
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Full names of the declared entity types, e.g. `Iam::User`
+    pub fn entity_type_names(&self) -> Vec<&str> {
+        self.entity_types.iter().map(|e| e.name.as_str()).collect()
+    }
+
+    /// Names of the declared action types, e.g. `CreateUser`
+    pub fn action_type_names(&self) -> Vec<&str> {
+        self.action_types.iter().map(|a| a.name.as_str()).collect()
+    }
+}
+
+impl std::fmt::Debug for SchemaFragment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SchemaFragment")
+            .field("source", &self.source)
+            .field("entity_types", &self.entity_type_names())
+            .field("action_types", &self.action_type_names())
+            .finish()
+    }
+}
+
+/// Result of registering every fragment of a registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterSchemaFragmentsResult {
+    /// Sources of the registered fragments, in registration order
+    pub sources: Vec<String>,
+
+    /// Number of entity types registered
+    pub entity_types_registered: usize,
+
+    /// Number of action types registered
+    pub action_types_registered: usize,
+}
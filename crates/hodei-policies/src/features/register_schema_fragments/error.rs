@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RegisterSchemaFragmentsError {
+    #[error(
+        "Schema fragment conflict: {kind} {type_name} is defined by both '{first_source}' and '{second_source}'"
+    )]
+    Conflict {
+        /// `entity type` or `action type`
+        kind: &'static str,
+        type_name: String,
+        first_source: String,
+        second_source: String,
+    },
+
+    #[error("Failed to register {type_name} from '{fragment_source}': {message}")]
+    RegistrationError {
+        type_name: String,
+        fragment_source: String,
+        message: String,
+    },
+
+    #[error("{0} is not backed by the schema builder")]
+    UnsupportedRegistrar(&'static str),
+}
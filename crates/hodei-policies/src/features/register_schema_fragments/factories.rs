@@ -0,0 +1,35 @@
+//! Factory functions for the register_schema_fragments feature
+//!
+//! This module provides static factory functions following the Java Config pattern.
+//! Factories receive already-constructed dependencies and assemble use cases.
+
+use crate::features::register_action_type::ports::RegisterActionTypePort;
+use crate::features::register_entity_type::ports::RegisterEntityTypePort;
+use crate::features::register_schema_fragments::ports::RegisterSchemaFragmentsPort;
+use crate::features::register_schema_fragments::use_case::RegisterSchemaFragmentsUseCase;
+use std::sync::Arc;
+
+/// Creates a RegisterSchemaFragmentsUseCase registering through the given ports
+///
+/// The ports must be the ones returned by
+/// `build_schema::factories::create_schema_registration_components`, so the
+/// fragments end up in the schema built there.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (entity_types, action_types, build_schema) =
+///     create_schema_registration_components(storage);
+/// let register_fragments =
+///     create_register_schema_fragments_use_case(entity_types, action_types);
+/// register_fragments.execute(&registry).await?;
+/// ```
+pub fn create_register_schema_fragments_use_case(
+    entity_type_registrar: Arc<dyn RegisterEntityTypePort>,
+    action_type_registrar: Arc<dyn RegisterActionTypePort>,
+) -> Arc<dyn RegisterSchemaFragmentsPort> {
+    Arc::new(RegisterSchemaFragmentsUseCase::new(
+        entity_type_registrar,
+        action_type_registrar,
+    ))
+}
@@ -0,0 +1,24 @@
+//! Register Schema Fragments Feature
+//!
+//! Lets every bounded context contribute its own entity and action types to
+//! the shared Cedar schema. Each crate implements [`SchemaFragmentProvider`],
+//! the composition root collects the providers in a
+//! [`SchemaFragmentRegistry`], and bootstrap registers all of them before the
+//! schema is built, so policies can reference any context's resources.
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod registry;
+pub mod use_case;
+#[cfg(test)]
+pub mod use_case_test;
+
+pub use dto::{RegisterSchemaFragmentsResult, SchemaFragment};
+pub use error::RegisterSchemaFragmentsError;
+pub use ports::{RegisterSchemaFragmentsPort, SchemaFragmentProvider};
+pub use registry::SchemaFragmentRegistry;
+
+// Re-export use case for external consumption
+pub use use_case::RegisterSchemaFragmentsUseCase;
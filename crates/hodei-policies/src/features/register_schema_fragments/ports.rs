@@ -0,0 +1,45 @@
+//! Ports (trait definitions) for the register_schema_fragments feature
+
+use crate::features::register_schema_fragments::dto::{
+    RegisterSchemaFragmentsResult, SchemaFragment,
+};
+use crate::features::register_schema_fragments::error::RegisterSchemaFragmentsError;
+use crate::features::register_schema_fragments::registry::SchemaFragmentRegistry;
+use async_trait::async_trait;
+
+/// Implemented by each bounded context that adds types to the schema
+///
+/// # Example
+///
+/// ```rust,ignore
+/// struct ArtifactsSchemaFragment;
+///
+/// impl SchemaFragmentProvider for ArtifactsSchemaFragment {
+///     fn schema_fragment(&self) -> SchemaFragment {
+///         SchemaFragment::new("hodei-artifacts").with_entity_type::<Artifact>()
+///     }
+/// }
+/// ```
+pub trait SchemaFragmentProvider: Send + Sync {
+    /// The types this context declares
+    fn schema_fragment(&self) -> SchemaFragment;
+}
+
+/// Port trait for registering the fragments of a registry in the schema builder
+///
+/// The registered types are part of the next schema built through
+/// `BuildSchemaPort`.
+#[async_trait]
+pub trait RegisterSchemaFragmentsPort: Send + Sync {
+    /// Register every fragment of `registry`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Two fragments declare the same type; nothing is registered then
+    /// - A type's schema can't be generated
+    async fn execute(
+        &self,
+        registry: &SchemaFragmentRegistry,
+    ) -> Result<RegisterSchemaFragmentsResult, RegisterSchemaFragmentsError>;
+}
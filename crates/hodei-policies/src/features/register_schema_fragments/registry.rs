@@ -0,0 +1,79 @@
+//! Collection of the schema fragment providers of every bounded context
+
+use crate::features::register_schema_fragments::dto::SchemaFragment;
+use crate::features::register_schema_fragments::error::RegisterSchemaFragmentsError;
+use crate::features::register_schema_fragments::ports::SchemaFragmentProvider;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The providers whose fragments make up the shared schema
+#[derive(Clone, Default)]
+pub struct SchemaFragmentRegistry {
+    providers: Vec<Arc<dyn SchemaFragmentProvider>>,
+}
+
+impl SchemaFragmentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `provider`'s fragment to the schema
+    pub fn with_provider(mut self, provider: Arc<dyn SchemaFragmentProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// Number of providers
+    pub fn len(&self) -> usize {
+        self.providers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    /// Every provider's fragment, in registration order
+    ///
+    /// # Errors
+    ///
+    /// Fails on the first type declared by two fragments, naming both of
+    /// their sources.
+    pub fn fragments(&self) -> Result<Vec<SchemaFragment>, RegisterSchemaFragmentsError> {
+        let fragments: Vec<SchemaFragment> = self
+            .providers
+            .iter()
+            .map(|provider| provider.schema_fragment())
+            .collect();
+
+        let mut entity_types: HashMap<&str, &str> = HashMap::new();
+        let mut action_types: HashMap<&str, &str> = HashMap::new();
+        for fragment in &fragments {
+            for name in fragment.entity_type_names() {
+                claim(&mut entity_types, "entity type", name, fragment.source())?;
+            }
+            for name in fragment.action_type_names() {
+                claim(&mut action_types, "action type", name, fragment.source())?;
+            }
+        }
+
+        Ok(fragments)
+    }
+}
+
+/// Record that `source` declares `name`, failing if another fragment did
+fn claim<'a>(
+    declared: &mut HashMap<&'a str, &'a str>,
+    kind: &'static str,
+    name: &'a str,
+    source: &'a str,
+) -> Result<(), RegisterSchemaFragmentsError> {
+    match declared.insert(name, source) {
+        Some(first_source) => Err(RegisterSchemaFragmentsError::Conflict {
+            kind,
+            type_name: name.to_string(),
+            first_source: first_source.to_string(),
+            second_source: source.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,123 @@
+use crate::features::register_action_type::RegisterActionTypeUseCase;
+use crate::features::register_action_type::ports::RegisterActionTypePort;
+use crate::features::register_entity_type::RegisterEntityTypeUseCase;
+use crate::features::register_entity_type::ports::RegisterEntityTypePort;
+use crate::features::register_schema_fragments::dto::RegisterSchemaFragmentsResult;
+use crate::features::register_schema_fragments::error::RegisterSchemaFragmentsError;
+use crate::features::register_schema_fragments::ports::RegisterSchemaFragmentsPort;
+use crate::features::register_schema_fragments::registry::SchemaFragmentRegistry;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+
+/// Use case for registering every bounded context's schema fragment
+///
+/// # Architecture
+///
+/// Registration goes through the entity and action type registration use
+/// cases, so the types land in the same EngineBuilder that `build_schema`
+/// consumes. The generic `register::<T>()` methods are reached by
+/// downcasting the ports, as the fragment's types are only known there.
+pub struct RegisterSchemaFragmentsUseCase {
+    /// Port for registering entity types
+    entity_type_registrar: Arc<dyn RegisterEntityTypePort>,
+
+    /// Port for registering action types
+    action_type_registrar: Arc<dyn RegisterActionTypePort>,
+}
+
+impl RegisterSchemaFragmentsUseCase {
+    /// Create a new schema fragment registration use case
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_type_registrar` - Port for registering entity types
+    /// * `action_type_registrar` - Port for registering action types
+    pub fn new(
+        entity_type_registrar: Arc<dyn RegisterEntityTypePort>,
+        action_type_registrar: Arc<dyn RegisterActionTypePort>,
+    ) -> Self {
+        Self {
+            entity_type_registrar,
+            action_type_registrar,
+        }
+    }
+
+    /// Register every fragment of `registry`
+    ///
+    /// All fragments are checked against each other first, so a conflict
+    /// leaves the builder untouched.
+    #[tracing::instrument(name = "register_schema_fragments", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        providers = registry.len()
+    ))]
+    pub async fn execute(
+        &self,
+        registry: &SchemaFragmentRegistry,
+    ) -> Result<RegisterSchemaFragmentsResult, RegisterSchemaFragmentsError> {
+        let fragments = registry.fragments()?;
+
+        let entity_types = self
+            .entity_type_registrar
+            .as_any()
+            .downcast_ref::<RegisterEntityTypeUseCase>()
+            .ok_or(RegisterSchemaFragmentsError::UnsupportedRegistrar(
+                "RegisterEntityTypePort",
+            ))?;
+        let action_types = self
+            .action_type_registrar
+            .as_any()
+            .downcast_ref::<RegisterActionTypeUseCase>()
+            .ok_or(RegisterSchemaFragmentsError::UnsupportedRegistrar(
+                "RegisterActionTypePort",
+            ))?;
+
+        let mut result = RegisterSchemaFragmentsResult {
+            sources: Vec::with_capacity(fragments.len()),
+            entity_types_registered: 0,
+            action_types_registered: 0,
+        };
+        for fragment in &fragments {
+            for entry in &fragment.entity_types {
+                (entry.register)(entity_types).map_err(|e| {
+                    RegisterSchemaFragmentsError::RegistrationError {
+                        type_name: entry.name.clone(),
+                        fragment_source: fragment.source().to_string(),
+                        message: e.to_string(),
+                    }
+                })?;
+            }
+            for entry in &fragment.action_types {
+                (entry.register)(action_types).map_err(|e| {
+                    RegisterSchemaFragmentsError::RegistrationError {
+                        type_name: entry.name.clone(),
+                        fragment_source: fragment.source().to_string(),
+                        message: e.to_string(),
+                    }
+                })?;
+            }
+
+            info!(
+                source = fragment.source(),
+                entity_types = fragment.entity_types.len(),
+                action_types = fragment.action_types.len(),
+                "Registered schema fragment"
+            );
+            result.sources.push(fragment.source().to_string());
+            result.entity_types_registered += fragment.entity_types.len();
+            result.action_types_registered += fragment.action_types.len();
+        }
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl RegisterSchemaFragmentsPort for RegisterSchemaFragmentsUseCase {
+    async fn execute(
+        &self,
+        registry: &SchemaFragmentRegistry,
+    ) -> Result<RegisterSchemaFragmentsResult, RegisterSchemaFragmentsError> {
+        self.execute(registry).await
+    }
+}
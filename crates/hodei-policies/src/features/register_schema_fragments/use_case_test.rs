@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use super::super::dto::SchemaFragment;
+    use super::super::error::RegisterSchemaFragmentsError;
+    use super::super::ports::SchemaFragmentProvider;
+    use super::super::registry::SchemaFragmentRegistry;
+    use super::super::use_case::RegisterSchemaFragmentsUseCase;
+    use crate::features::register_action_type::RegisterActionTypeUseCase;
+    use crate::features::register_entity_type::RegisterEntityTypeUseCase;
+    use crate::internal::engine::builder::EngineBuilder;
+    use kernel::{ActionTrait, HodeiEntityType, ResourceTypeName, ServiceName};
+    use std::sync::{Arc, Mutex};
+
+    struct MockUser;
+
+    impl HodeiEntityType for MockUser {
+        fn service_name() -> ServiceName {
+            ServiceName::new("iam").unwrap()
+        }
+
+        fn resource_type_name() -> ResourceTypeName {
+            ResourceTypeName::new("User").unwrap()
+        }
+
+        fn is_principal_type() -> bool {
+            true
+        }
+    }
+
+    struct MockArtifact;
+
+    impl HodeiEntityType for MockArtifact {
+        fn service_name() -> ServiceName {
+            ServiceName::new("artifacts").unwrap()
+        }
+
+        fn resource_type_name() -> ResourceTypeName {
+            ResourceTypeName::new("Artifact").unwrap()
+        }
+    }
+
+    struct UploadArtifactAction;
+
+    impl ActionTrait for UploadArtifactAction {
+        fn name() -> &'static str {
+            "UploadArtifact"
+        }
+
+        fn service_name() -> ServiceName {
+            ServiceName::new("artifacts").unwrap()
+        }
+
+        fn applies_to_principal() -> String {
+            "Iam::User".to_string()
+        }
+
+        fn applies_to_resource() -> String {
+            "Artifacts::Artifact".to_string()
+        }
+    }
+
+    struct IamFragment;
+
+    impl SchemaFragmentProvider for IamFragment {
+        fn schema_fragment(&self) -> SchemaFragment {
+            SchemaFragment::new("hodei-iam").with_entity_type::<MockUser>()
+        }
+    }
+
+    struct ArtifactsFragment;
+
+    impl SchemaFragmentProvider for ArtifactsFragment {
+        fn schema_fragment(&self) -> SchemaFragment {
+            SchemaFragment::new("hodei-artifacts")
+                .with_entity_type::<MockArtifact>()
+                .with_action_type::<UploadArtifactAction>()
+        }
+    }
+
+    /// Also declares `Iam::User`
+    struct RogueFragment;
+
+    impl SchemaFragmentProvider for RogueFragment {
+        fn schema_fragment(&self) -> SchemaFragment {
+            SchemaFragment::new("hodei-rogue").with_entity_type::<MockUser>()
+        }
+    }
+
+    fn use_case(builder: &Arc<Mutex<EngineBuilder>>) -> RegisterSchemaFragmentsUseCase {
+        RegisterSchemaFragmentsUseCase::new(
+            Arc::new(RegisterEntityTypeUseCase::new(builder.clone())),
+            Arc::new(RegisterActionTypeUseCase::new(builder.clone())),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fragments_are_merged_into_the_builder() {
+        let builder = Arc::new(Mutex::new(EngineBuilder::new()));
+        let registry = SchemaFragmentRegistry::new()
+            .with_provider(Arc::new(IamFragment))
+            .with_provider(Arc::new(ArtifactsFragment));
+
+        let result = use_case(&builder).execute(&registry).await.unwrap();
+
+        assert_eq!(result.sources, vec!["hodei-iam", "hodei-artifacts"]);
+        assert_eq!(result.entity_types_registered, 2);
+        assert_eq!(result.action_types_registered, 1);
+        let schema = builder.lock().unwrap().to_json().unwrap();
+        assert!(schema["Iam"]["entityTypes"].get("User").is_some());
+        assert!(schema["Artifacts"]["entityTypes"].get("Artifact").is_some());
+        assert!(schema[""]["actions"].get("UploadArtifact").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_fragments_name_both_sources() {
+        let builder = Arc::new(Mutex::new(EngineBuilder::new()));
+        let registry = SchemaFragmentRegistry::new()
+            .with_provider(Arc::new(ArtifactsFragment))
+            .with_provider(Arc::new(IamFragment))
+            .with_provider(Arc::new(RogueFragment));
+
+        let error = use_case(&builder).execute(&registry).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            RegisterSchemaFragmentsError::Conflict { ref type_name, .. } if type_name == "Iam::User"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Schema fragment conflict: entity type Iam::User is defined by both 'hodei-iam' and 'hodei-rogue'"
+        );
+        // Nothing is registered, not even the fragments before the conflict
+        assert_eq!(builder.lock().unwrap().entity_count(), 0);
+    }
+
+    #[test]
+    fn test_fragment_lists_declared_types() {
+        let fragment = ArtifactsFragment.schema_fragment();
+
+        assert_eq!(fragment.source(), "hodei-artifacts");
+        assert_eq!(fragment.entity_type_names(), vec!["Artifacts::Artifact"]);
+        assert_eq!(fragment.action_type_names(), vec!["UploadArtifact"]);
+    }
+}
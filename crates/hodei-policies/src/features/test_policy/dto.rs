@@ -0,0 +1,214 @@
+//! Data Transfer Objects for the test_policy feature
+//!
+//! A policy test suite pairs a policy set with the decisions it is expected
+//! to produce, so policies can be checked in CI like any other code.
+
+use kernel::Hrn;
+use kernel::domain::entity::ActionTrait;
+use kernel::domain::value_objects::ServiceName;
+use serde::{Deserialize, Serialize};
+
+/// Command to run a policy set against its test cases
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestPolicyCommand {
+    /// Cedar policies under test; each string may hold several policies
+    pub policies: Vec<String>,
+
+    /// Schema declaring the entity and action types the test cases use,
+    /// in Cedar schema syntax or in Cedar's JSON schema format
+    pub schema: String,
+
+    /// Entities shared by every test case, in Cedar's entities JSON format
+    #[serde(default)]
+    pub entities: Option<serde_json::Value>,
+
+    /// Requests to evaluate, each with the decision it must produce
+    pub test_cases: Vec<PolicyTestCase>,
+}
+
+impl TestPolicyCommand {
+    /// Create a command without shared entities
+    pub fn new(policies: Vec<String>, schema: String, test_cases: Vec<PolicyTestCase>) -> Self {
+        Self {
+            policies,
+            schema,
+            entities: None,
+            test_cases,
+        }
+    }
+
+    /// Set the entities shared by every test case
+    pub fn with_entities(mut self, entities: serde_json::Value) -> Self {
+        self.entities = Some(entities);
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.policies.iter().all(|policy| policy.trim().is_empty()) {
+            return Err("At least one policy is required".to_string());
+        }
+        if self.test_cases.is_empty() {
+            return Err("At least one test case is required".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ActionTrait for TestPolicyCommand {
+    fn name() -> &'static str {
+        "TestPolicy"
+    }
+
+    fn service_name() -> ServiceName {
+        ServiceName::new("policies").expect("Valid service name")
+    }
+
+    fn applies_to_principal() -> String {
+        "Policies::User".to_string()
+    }
+
+    fn applies_to_resource() -> String {
+        "Policies::Policy".to_string()
+    }
+}
+
+/// One request and the decision the policies must reach for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyTestCase {
+    /// Name reported with the outcome
+    pub name: String,
+
+    /// The principal making the request
+    pub principal: Hrn,
+
+    /// The action being requested
+    pub action: Hrn,
+
+    /// The resource being accessed
+    pub resource: Hrn,
+
+    /// Request context as a JSON object, checked against the schema
+    #[serde(default = "empty_context")]
+    pub context: serde_json::Value,
+
+    /// Decision the policies must produce
+    pub expected: Decision,
+}
+
+fn empty_context() -> serde_json::Value {
+    serde_json::Value::Object(serde_json::Map::new())
+}
+
+impl PolicyTestCase {
+    /// Create a test case with an empty context
+    pub fn new(
+        name: impl Into<String>,
+        principal: Hrn,
+        action: Hrn,
+        resource: Hrn,
+        expected: Decision,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            principal,
+            action,
+            resource,
+            context: empty_context(),
+            expected,
+        }
+    }
+
+    /// Set the request context
+    pub fn with_context(mut self, context: serde_json::Value) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// Authorization decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Decision {
+    /// Access is allowed
+    Allow,
+    /// Access is denied
+    Deny,
+}
+
+impl std::fmt::Display for Decision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Decision::Allow => write!(f, "allow"),
+            Decision::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// Outcome of a whole test suite
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestPolicyResult {
+    /// Cases whose decision matched the expected one
+    pub passed: usize,
+
+    /// Cases whose decision differed from the expected one
+    pub failed: usize,
+
+    /// Cases that could not be evaluated
+    pub errored: usize,
+
+    /// Per-case outcomes, in the order of the command
+    pub cases: Vec<TestCaseResult>,
+}
+
+impl TestPolicyResult {
+    /// Build the summary from the per-case outcomes
+    pub fn from_cases(cases: Vec<TestCaseResult>) -> Self {
+        let count = |wanted: fn(&TestCaseOutcome) -> bool| {
+            cases.iter().filter(|case| wanted(&case.outcome)).count()
+        };
+        Self {
+            passed: count(|outcome| matches!(outcome, TestCaseOutcome::Passed)),
+            failed: count(|outcome| matches!(outcome, TestCaseOutcome::Failed { .. })),
+            errored: count(|outcome| matches!(outcome, TestCaseOutcome::Error { .. })),
+            cases,
+        }
+    }
+
+    /// Whether every case passed
+    pub fn is_success(&self) -> bool {
+        self.failed == 0 && self.errored == 0
+    }
+}
+
+/// Outcome of one test case
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseResult {
+    /// Name of the test case
+    pub name: String,
+
+    /// What happened when it was evaluated
+    pub outcome: TestCaseOutcome,
+}
+
+/// Whether a test case passed, failed its assertion, or could not run
+///
+/// `Error` is a problem with the test itself (an entity type or action the
+/// schema does not declare, a context that does not match it, a policy that
+/// could not be evaluated), never a decision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TestCaseOutcome {
+    /// The policies produced the expected decision
+    Passed,
+
+    /// The policies produced another decision
+    Failed {
+        expected: Decision,
+        actual: Decision,
+        /// Policies that determined the actual decision
+        determining_policies: Vec<String>,
+    },
+
+    /// The request could not be evaluated
+    Error { message: String },
+}
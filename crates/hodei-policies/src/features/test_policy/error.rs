@@ -0,0 +1,30 @@
+//! Error types for the test_policy feature
+//!
+//! These errors reject the whole suite. Problems with a single test case are
+//! reported in its outcome instead, so the other cases still run.
+
+use thiserror::Error;
+
+/// Errors that prevent a policy test suite from running
+#[derive(Debug, Clone, Error)]
+pub enum TestPolicyError {
+    /// Invalid command parameters
+    #[error("Invalid command: {0}")]
+    InvalidCommand(String),
+
+    /// The schema could not be parsed
+    #[error("Schema error: {0}")]
+    SchemaError(String),
+
+    /// A policy could not be parsed
+    #[error("Policy error: {0}")]
+    PolicyError(String),
+
+    /// The policies do not validate against the schema
+    #[error("Policy validation failed: {}", .0.join("; "))]
+    PolicyValidationError(Vec<String>),
+
+    /// The shared entities could not be parsed or do not match the schema
+    #[error("Invalid entities: {0}")]
+    EntitiesError(String),
+}
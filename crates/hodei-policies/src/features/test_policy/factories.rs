@@ -0,0 +1,26 @@
+//! Factory functions for the test_policy feature
+//!
+//! This module provides static factory functions following the Java Config pattern.
+//! Factories receive already-constructed dependencies and assemble use cases.
+
+use crate::features::test_policy::ports::TestPolicyPort;
+use crate::features::test_policy::use_case::TestPolicyUseCase;
+use std::sync::Arc;
+
+/// Creates a TestPolicyUseCase
+///
+/// The use case needs no adapters: the schema, policies and entities all
+/// come with the command.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use hodei_policies::features::test_policy::factories;
+///
+/// let use_case = factories::create_test_policy_use_case();
+/// let result = use_case.run_tests(command).await?;
+/// assert!(result.is_success());
+/// ```
+pub fn create_test_policy_use_case() -> Arc<dyn TestPolicyPort> {
+    Arc::new(TestPolicyUseCase::new())
+}
@@ -0,0 +1,24 @@
+//! Test Policy Feature
+//!
+//! Runs a policy set against test cases that state the decision expected for
+//! a request ("this policy should allow X and deny Y"), so policies can be
+//! tested in CI. Each case passes, fails with the actual decision, or errors
+//! when the case itself is broken, e.g. it names an entity type the schema
+//! does not declare.
+
+pub mod dto;
+pub mod error;
+pub mod factories;
+pub mod ports;
+pub mod use_case;
+
+#[cfg(test)]
+mod use_case_test;
+
+// Re-export for convenience
+pub use dto::{
+    Decision, PolicyTestCase, TestCaseOutcome, TestCaseResult, TestPolicyCommand, TestPolicyResult,
+};
+pub use error::TestPolicyError;
+pub use ports::TestPolicyPort;
+pub use use_case::TestPolicyUseCase;
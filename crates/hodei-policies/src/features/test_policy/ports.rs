@@ -0,0 +1,23 @@
+//! Ports (trait definitions) for the test_policy feature
+
+use async_trait::async_trait;
+
+use super::dto::{TestPolicyCommand, TestPolicyResult};
+use super::error::TestPolicyError;
+
+/// Port trait for running policy test suites
+///
+/// This trait represents the use case's public interface.
+#[async_trait]
+pub trait TestPolicyPort: Send + Sync {
+    /// Evaluate every test case of `command` against its policies
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema, the policies or the shared entities
+    /// are invalid. Failing or broken test cases are reported in the result.
+    async fn run_tests(
+        &self,
+        command: TestPolicyCommand,
+    ) -> Result<TestPolicyResult, TestPolicyError>;
+}
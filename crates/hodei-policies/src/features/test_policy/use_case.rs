@@ -0,0 +1,248 @@
+//! Use case for running policy test suites
+
+use super::dto::{
+    Decision, PolicyTestCase, TestCaseOutcome, TestCaseResult, TestPolicyCommand, TestPolicyResult,
+};
+use super::error::TestPolicyError;
+use super::ports::TestPolicyPort;
+use crate::features::validate_policy::dto::ValidationLevel;
+use async_trait::async_trait;
+use cedar_policy::{
+    Authorizer, Context, Entities, EntityUid, PolicyId, PolicySet, Request, Schema, Validator,
+};
+use std::str::FromStr;
+use tracing::{debug, info, info_span, instrument, warn};
+
+/// Use case for checking policies against expected decisions
+///
+/// This use case orchestrates a test run:
+/// 1. Parses the schema, the policies and the shared entities
+/// 2. Validates the policies against the schema
+/// 3. Evaluates each test case and compares the decision with the expected one
+///
+/// Test cases are checked against the schema before evaluation. A case that
+/// names an entity type or action the schema does not declare, or whose
+/// context does not match it, is reported as an error rather than evaluated,
+/// since Cedar would otherwise deny it and make a broken test look like a
+/// passing deny assertion. Policy evaluation errors are reported the same way.
+pub struct TestPolicyUseCase {
+    /// How strictly the policies are checked against the schema
+    validation_level: ValidationLevel,
+}
+
+impl TestPolicyUseCase {
+    /// Create a new instance of the use case
+    pub fn new() -> Self {
+        Self {
+            validation_level: ValidationLevel::default(),
+        }
+    }
+
+    /// Check the policies against the schema at `level` instead of strictly
+    pub fn with_validation_level(mut self, level: ValidationLevel) -> Self {
+        self.validation_level = level;
+        self
+    }
+
+    /// Execute the test policy use case
+    ///
+    /// # Arguments
+    /// * `command` - TestPolicyCommand with the policies, schema and test cases
+    ///
+    /// # Returns
+    /// * Ok(TestPolicyResult) with the outcome of every test case
+    /// * Err(TestPolicyError) if the suite itself is invalid
+    #[instrument(name = "test_policy", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref(),
+        policy_count = command.policies.len(),
+        test_case_count = command.test_cases.len()
+    ))]
+    pub async fn execute(
+        &self,
+        command: TestPolicyCommand,
+    ) -> Result<TestPolicyResult, TestPolicyError> {
+        command
+            .validate()
+            .map_err(TestPolicyError::InvalidCommand)?;
+
+        let (schema, policy_set, entities) = info_span!("validation").in_scope(|| {
+            let schema = parse_schema(&command.schema)?;
+            let policy_set = parse_policies(&command.policies)?;
+            validate_policies(&policy_set, &schema, self.validation_level)?;
+            let entities = match command.entities {
+                Some(json) => Entities::from_json_value(json, Some(&schema))
+                    .map_err(|e| TestPolicyError::EntitiesError(e.to_string()))?,
+                None => Entities::empty(),
+            };
+            Ok::<_, TestPolicyError>((schema, policy_set, entities))
+        })?;
+
+        let authorizer = Authorizer::new();
+        let cases = info_span!("evaluation").in_scope(|| {
+            command
+                .test_cases
+                .into_iter()
+                .map(|case| {
+                    let outcome = run_case(&authorizer, &case, &schema, &policy_set, &entities);
+                    debug!(case = %case.name, outcome = ?outcome, "Policy test case evaluated");
+                    TestCaseResult {
+                        name: case.name,
+                        outcome,
+                    }
+                })
+                .collect()
+        });
+
+        let result = TestPolicyResult::from_cases(cases);
+        info!(
+            passed = result.passed,
+            failed = result.failed,
+            errored = result.errored,
+            "Policy test suite completed"
+        );
+        Ok(result)
+    }
+}
+
+impl Default for TestPolicyUseCase {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TestPolicyPort for TestPolicyUseCase {
+    async fn run_tests(
+        &self,
+        command: TestPolicyCommand,
+    ) -> Result<TestPolicyResult, TestPolicyError> {
+        self.execute(command).await
+    }
+}
+
+/// Parse a schema in Cedar JSON format or, otherwise, in Cedar schema syntax
+fn parse_schema(source: &str) -> Result<Schema, TestPolicyError> {
+    if source.trim_start().starts_with('{') {
+        return Schema::from_json_str(source)
+            .map_err(|e| TestPolicyError::SchemaError(e.to_string()));
+    }
+    Schema::from_cedarschema_str(source)
+        .map(|(schema, _warnings)| schema)
+        .map_err(|e| TestPolicyError::SchemaError(e.to_string()))
+}
+
+/// Parse every policy into one set
+///
+/// Policies keep their `@id` annotation as ID; the others are numbered in
+/// order (`policy0`, `policy1`...) across all the command's strings.
+fn parse_policies(sources: &[String]) -> Result<PolicySet, TestPolicyError> {
+    let mut policy_set = PolicySet::new();
+    let mut index = 0;
+
+    for source in sources.iter().filter(|source| !source.trim().is_empty()) {
+        let parsed =
+            PolicySet::from_str(source).map_err(|e| TestPolicyError::PolicyError(e.to_string()))?;
+        if parsed.templates().next().is_some() {
+            return Err(TestPolicyError::PolicyError(
+                "Policy templates cannot be tested directly".to_string(),
+            ));
+        }
+
+        for policy in parsed.policies() {
+            let id = policy
+                .annotation("id")
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("policy{}", index));
+            index += 1;
+            policy_set
+                .add(policy.new_id(PolicyId::new(id)))
+                .map_err(|e| TestPolicyError::PolicyError(e.to_string()))?;
+        }
+    }
+
+    Ok(policy_set)
+}
+
+fn validate_policies(
+    policy_set: &PolicySet,
+    schema: &Schema,
+    level: ValidationLevel,
+) -> Result<(), TestPolicyError> {
+    let validator = Validator::new(schema.clone());
+    let result = validator.validate(policy_set, level.mode());
+    if result.validation_passed() {
+        return Ok(());
+    }
+
+    let errors: Vec<String> = result
+        .validation_errors()
+        .map(|error| error.to_string())
+        .collect();
+    warn!(
+        errors = errors.len(),
+        "Policies under test do not match the schema"
+    );
+    Err(TestPolicyError::PolicyValidationError(errors))
+}
+
+fn run_case(
+    authorizer: &Authorizer,
+    case: &PolicyTestCase,
+    schema: &Schema,
+    policy_set: &PolicySet,
+    entities: &Entities,
+) -> TestCaseOutcome {
+    let request = match build_request(case, schema) {
+        Ok(request) => request,
+        Err(message) => return TestCaseOutcome::Error { message },
+    };
+
+    let response = authorizer.is_authorized(&request, policy_set, entities);
+    let errors: Vec<String> = response
+        .diagnostics()
+        .errors()
+        .map(|error| error.to_string())
+        .collect();
+    if !errors.is_empty() {
+        return TestCaseOutcome::Error {
+            message: errors.join("; "),
+        };
+    }
+
+    let actual = match response.decision() {
+        cedar_policy::Decision::Allow => Decision::Allow,
+        cedar_policy::Decision::Deny => Decision::Deny,
+    };
+    if actual == case.expected {
+        return TestCaseOutcome::Passed;
+    }
+
+    let mut determining_policies: Vec<String> = response
+        .diagnostics()
+        .reason()
+        .map(|id| id.to_string())
+        .collect();
+    determining_policies.sort();
+    TestCaseOutcome::Failed {
+        expected: case.expected,
+        actual,
+        determining_policies,
+    }
+}
+
+/// Build the Cedar request for a test case, checked against the schema
+fn build_request(case: &PolicyTestCase, schema: &Schema) -> Result<Request, String> {
+    let principal = entity_uid(&case.principal)?;
+    let action = entity_uid(&case.action)?;
+    let resource = entity_uid(&case.resource)?;
+
+    let context = Context::from_json_value(case.context.clone(), Some((schema, &action)))
+        .map_err(|e| format!("Invalid context: {}", e))?;
+
+    Request::new(principal, action, resource, context, Some(schema)).map_err(|e| e.to_string())
+}
+
+fn entity_uid(hrn: &kernel::Hrn) -> Result<EntityUid, String> {
+    EntityUid::from_str(&hrn.entity_uid_string())
+        .map_err(|e| format!("Invalid HRN '{}': {}", hrn, e))
+}
@@ -0,0 +1,215 @@
+use super::dto::{Decision, PolicyTestCase, TestCaseOutcome, TestPolicyCommand};
+use super::error::TestPolicyError;
+use super::use_case::TestPolicyUseCase;
+use crate::features::validate_policy::dto::ValidationLevel;
+use kernel::Hrn;
+use serde_json::json;
+
+const SCHEMA: &str = r#"
+namespace Iam {
+    entity User;
+}
+namespace Storage {
+    entity Document = { owner: Iam::User };
+}
+namespace Api {
+    action "read" appliesTo {
+        principal: [Iam::User],
+        resource: [Storage::Document],
+        context: { mfa: Bool }
+    };
+}
+"#;
+
+const POLICIES: &str = r#"
+@id("owners-read")
+permit(principal, action == Api::Action::"read", resource)
+when { resource.owner == principal };
+
+@id("require-mfa")
+forbid(principal, action, resource)
+unless { context.mfa };
+"#;
+
+fn user(id: &str) -> Hrn {
+    Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        "User".to_string(),
+        id.to_string(),
+    )
+}
+
+fn document(id: &str) -> Hrn {
+    Hrn::new(
+        "hodei".to_string(),
+        "storage".to_string(),
+        "default".to_string(),
+        "Document".to_string(),
+        id.to_string(),
+    )
+}
+
+fn read_case(name: &str, principal: &str, mfa: bool, expected: Decision) -> PolicyTestCase {
+    PolicyTestCase::new(
+        name,
+        user(principal),
+        Hrn::action("api", "read"),
+        document("doc1"),
+        expected,
+    )
+    .with_context(json!({ "mfa": mfa }))
+}
+
+fn command(test_cases: Vec<PolicyTestCase>) -> TestPolicyCommand {
+    TestPolicyCommand::new(vec![POLICIES.to_string()], SCHEMA.to_string(), test_cases)
+        .with_entities(json!([{
+            "uid": { "type": "Storage::Document", "id": "doc1" },
+            "attrs": { "owner": { "__entity": { "type": "Iam::User", "id": "alice" } } },
+            "parents": []
+        }]))
+}
+
+#[tokio::test]
+async fn test_matching_decisions_pass() {
+    let use_case = TestPolicyUseCase::new();
+
+    let result = use_case
+        .execute(command(vec![
+            read_case("owner reads", "alice", true, Decision::Allow),
+            read_case("stranger is denied", "bob", true, Decision::Deny),
+            read_case(
+                "owner without mfa is denied",
+                "alice",
+                false,
+                Decision::Deny,
+            ),
+        ]))
+        .await
+        .unwrap();
+
+    assert!(result.is_success());
+    assert_eq!(result.passed, 3);
+}
+
+#[tokio::test]
+async fn test_failed_case_reports_the_actual_decision() {
+    let use_case = TestPolicyUseCase::new();
+
+    let result = use_case
+        .execute(command(vec![
+            read_case("owner reads", "alice", true, Decision::Allow),
+            read_case("owner reads without mfa", "alice", false, Decision::Allow),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!((result.passed, result.failed, result.errored), (1, 1, 0));
+    assert_eq!(result.cases[1].name, "owner reads without mfa");
+    assert_eq!(
+        result.cases[1].outcome,
+        TestCaseOutcome::Failed {
+            expected: Decision::Allow,
+            actual: Decision::Deny,
+            determining_policies: vec!["require-mfa".to_string()],
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_undeclared_entity_type_is_an_error_not_a_deny() {
+    let use_case = TestPolicyUseCase::new();
+    let service_account = Hrn::new(
+        "hodei".to_string(),
+        "iam".to_string(),
+        "default".to_string(),
+        "ServiceAccount".to_string(),
+        "ci".to_string(),
+    );
+    let case = PolicyTestCase::new(
+        "service account is denied",
+        service_account,
+        Hrn::action("api", "read"),
+        document("doc1"),
+        Decision::Deny,
+    )
+    .with_context(json!({ "mfa": true }));
+
+    let result = use_case.execute(command(vec![case])).await.unwrap();
+
+    assert_eq!((result.passed, result.failed, result.errored), (0, 0, 1));
+    match &result.cases[0].outcome {
+        TestCaseOutcome::Error { message } => assert!(message.contains("ServiceAccount")),
+        other => panic!("expected an error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_context_not_matching_the_schema_is_an_error() {
+    let use_case = TestPolicyUseCase::new();
+    let case = read_case("owner reads", "alice", true, Decision::Allow)
+        .with_context(json!({ "mfa": "yes" }));
+
+    let result = use_case.execute(command(vec![case])).await.unwrap();
+
+    assert!(matches!(
+        result.cases[0].outcome,
+        TestCaseOutcome::Error { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_policy_not_matching_the_schema_rejects_the_suite() {
+    let use_case = TestPolicyUseCase::new();
+    let mut command = command(vec![read_case(
+        "owner reads",
+        "alice",
+        true,
+        Decision::Allow,
+    )]);
+    command.policies =
+        vec![r#"permit(principal, action, resource) when { resource.title == "x" };"#.to_string()];
+
+    let result = use_case.execute(command).await;
+
+    assert!(matches!(
+        result,
+        Err(TestPolicyError::PolicyValidationError(errors)) if !errors.is_empty()
+    ));
+}
+
+#[tokio::test]
+async fn test_strict_validation_rejects_comparisons_between_types() {
+    let mut command = command(vec![read_case(
+        "owner check never matches",
+        "alice",
+        true,
+        Decision::Deny,
+    )]);
+    command.policies = vec![
+        r#"permit(principal, action, resource) when { resource.owner == "alice" };"#.to_string(),
+    ];
+
+    let strict = TestPolicyUseCase::new().execute(command.clone()).await;
+    assert!(matches!(
+        strict,
+        Err(TestPolicyError::PolicyValidationError(_))
+    ));
+
+    let permissive = TestPolicyUseCase::new()
+        .with_validation_level(ValidationLevel::Permissive)
+        .execute(command)
+        .await
+        .unwrap();
+    assert!(permissive.is_success());
+}
+
+#[tokio::test]
+async fn test_suite_without_test_cases_is_rejected() {
+    let use_case = TestPolicyUseCase::new();
+
+    let result = use_case.execute(command(vec![])).await;
+
+    assert!(matches!(result, Err(TestPolicyError::InvalidCommand(_))));
+}
@@ -0,0 +1,74 @@
+//! Policy annotations
+//!
+//! Cedar policies may carry annotations such as `@ticket("SEC-1234")`. They
+//! have no effect on evaluation but let a decision be traced back to why the
+//! policy exists. Annotations are part of the policy text, so storing the text
+//! verbatim preserves them; this module reads them back out of it.
+
+use crate::features::validate_policy::error::ValidatePolicyError;
+use crate::features::validate_policy::syntax::parse_policy;
+use std::collections::HashMap;
+
+/// Annotations of a policy, keyed by annotation name
+pub type PolicyAnnotations = HashMap<String, String>;
+
+/// Parse `content` and return the annotations of the policy it contains
+///
+/// `content` may be in Cedar or Cedar JSON syntax. An annotation written without a value (`@reviewed`) maps to an empty
+/// string.
+///
+/// # Errors
+///
+/// Returns `ValidationError` if `content` is not a valid policy. Cedar treats
+/// a repeated annotation key as a parse error, so this includes duplicates.
+pub fn policy_annotations(content: &str) -> Result<PolicyAnnotations, ValidatePolicyError> {
+    parse_policy(content).map(|(policy, _)| annotations_of(&policy))
+}
+
+/// Annotations of an already parsed policy
+pub(crate) fn annotations_of(policy: &cedar_policy::Policy) -> PolicyAnnotations {
+    policy
+        .annotations()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_annotations_from_policy_text() {
+        let annotations = policy_annotations(
+            r#"@ticket("SEC-1234")
+            @reviewed
+            permit(principal, action, resource);"#,
+        )
+        .unwrap();
+
+        assert_eq!(annotations.len(), 2);
+        assert_eq!(annotations["ticket"], "SEC-1234");
+        assert_eq!(annotations["reviewed"], "");
+    }
+
+    #[test]
+    fn policy_without_annotations_has_none() {
+        let annotations = policy_annotations("permit(principal, action, resource);").unwrap();
+
+        assert!(annotations.is_empty());
+    }
+
+    #[test]
+    fn duplicate_annotation_keys_are_rejected() {
+        let result = policy_annotations(
+            r#"@ticket("SEC-1")
+            @ticket("SEC-2")
+            permit(principal, action, resource);"#,
+        );
+
+        assert!(matches!(
+            result,
+            Err(ValidatePolicyError::ValidationError(_))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 use kernel::domain::entity::ActionTrait;
 use kernel::domain::value_objects::ServiceName;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // Comando de entrada
 #[derive(Deserialize, Serialize)]
@@ -31,4 +32,33 @@ impl ActionTrait for ValidatePolicyCommand {
 pub struct ValidationResult {
     pub is_valid: bool,
     pub errors: Vec<String>,
+    /// Annotations of the policy; empty unless it is valid
+    pub annotations: HashMap<String, String>,
+}
+
+/// How strictly policies are checked against the schema
+///
+/// The level only applies to validation. Policies are evaluated the same way
+/// whatever level they were validated with, so changing it never changes the
+/// meaning of policies that are already stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationLevel {
+    /// Rejects type errors, and also expressions whose operands may have
+    /// different types, such as comparing an entity with a string
+    #[default]
+    Strict,
+    /// Rejects type errors only; a comparison between different types is
+    /// accepted and is simply never true
+    Permissive,
+}
+
+impl ValidationLevel {
+    /// The Cedar validation mode of this level
+    pub(crate) fn mode(self) -> cedar_policy::ValidationMode {
+        match self {
+            Self::Strict => cedar_policy::ValidationMode::Strict,
+            Self::Permissive => cedar_policy::ValidationMode::Permissive,
+        }
+    }
 }
@@ -4,6 +4,7 @@
 //! Factories receive already-constructed dependencies and assemble use cases.
 
 use crate::features::load_schema::ports::SchemaStoragePort;
+use crate::features::validate_policy::dto::ValidationLevel;
 use crate::features::validate_policy::port::ValidatePolicyPort;
 use crate::features::validate_policy::use_case::ValidatePolicyUseCase;
 use std::sync::Arc;
@@ -34,10 +35,12 @@ pub fn create_validate_policy_use_case_without_schema<S: SchemaStoragePort + 'st
 /// # Arguments
 ///
 /// * `schema_storage` - Pre-constructed implementation of SchemaStoragePort
+/// * `validation_level` - How strictly policies are checked against the schema
 ///
 /// # Example
 ///
 /// ```rust,ignore
+/// use hodei_policies::features::validate_policy::dto::ValidationLevel;
 /// use hodei_policies::features::validate_policy::factories;
 /// use std::sync::Arc;
 ///
@@ -45,11 +48,18 @@ pub fn create_validate_policy_use_case_without_schema<S: SchemaStoragePort + 'st
 /// let schema_adapter = Arc::new(SurrealSchemaStorage::new(db_client));
 ///
 /// // Factory receives the adapter and assembles the use case
-/// let use_case = factories::create_validate_policy_use_case_with_schema(schema_adapter);
+/// let use_case = factories::create_validate_policy_use_case_with_schema(
+///     schema_adapter,
+///     ValidationLevel::Strict,
+/// );
 /// let result = use_case.validate(command).await?;
 /// ```
 pub fn create_validate_policy_use_case_with_schema<S: SchemaStoragePort + 'static>(
     schema_storage: Arc<S>,
+    validation_level: ValidationLevel,
 ) -> Arc<dyn ValidatePolicyPort> {
-    Arc::new(ValidatePolicyUseCase::with_schema_storage(schema_storage))
+    Arc::new(
+        ValidatePolicyUseCase::with_schema_storage(schema_storage)
+            .with_validation_level(validation_level),
+    )
 }
@@ -1,9 +1,13 @@
+pub mod annotations;
 pub mod dto;
 pub mod error;
 pub mod factories;
 pub mod port;
+pub mod syntax;
 pub mod use_case;
 #[cfg(test)]
 pub mod use_case_test;
 
+pub use annotations::{PolicyAnnotations, policy_annotations};
 pub use port::ValidatePolicyPort;
+pub use syntax::{PolicySyntax, parse_policy, to_cedar_syntax};
@@ -0,0 +1,142 @@
+//! Policy syntax detection
+//!
+//! Policies are accepted in Cedar's human-readable syntax or in its JSON
+//! policy format, which is what programmatic tools tend to generate. The
+//! syntax is detected by trying JSON first and falling back to Cedar.
+//!
+//! Whatever the input syntax, policies are stored in Cedar syntax as Cedar
+//! itself renders them, so a policy has one stored form whether it was
+//! written in Cedar or in JSON. Comments and layout of the written policy
+//! are not kept; annotations are.
+
+use crate::features::validate_policy::error::ValidatePolicyError;
+use cedar_policy::Policy;
+
+/// Syntax a policy was written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicySyntax {
+    /// Cedar's human-readable policy language
+    Cedar,
+    /// Cedar's JSON policy format
+    Json,
+}
+
+/// Parse a policy written in either syntax
+///
+/// # Errors
+///
+/// Returns `ValidationError` if `content` is neither a Cedar JSON policy nor
+/// a Cedar policy. The message carries both parse errors, since either may
+/// be the one the author meant to fix.
+pub fn parse_policy(content: &str) -> Result<(Policy, PolicySyntax), ValidatePolicyError> {
+    let json_error = match serde_json::from_str(content) {
+        Ok(json) => match Policy::from_json(None, json) {
+            Ok(policy) => return Ok((policy, PolicySyntax::Json)),
+            Err(e) => e.to_string(),
+        },
+        Err(e) => e.to_string(),
+    };
+
+    Policy::parse(None, content)
+        .map(|policy| (policy, PolicySyntax::Cedar))
+        .map_err(|cedar_error| {
+            ValidatePolicyError::ValidationError(format!(
+                "not a Cedar policy: {}; not a Cedar JSON policy either: {}",
+                cedar_error, json_error
+            ))
+        })
+}
+
+/// Text of a policy in Cedar syntax, the form policies are stored in
+///
+/// The policy is parsed in either syntax and rendered by Cedar, annotations
+/// included, so equivalent Cedar and JSON policies are stored identically.
+///
+/// # Errors
+///
+/// Returns `ValidationError` if `content` is not a policy in either syntax,
+/// or is a linked policy, which has no Cedar rendering.
+pub fn to_cedar_syntax(content: &str) -> Result<String, ValidatePolicyError> {
+    let (policy, _) = parse_policy(content)?;
+    // A policy parsed from Cedar syntax renders as its source text; going
+    // through JSON makes Cedar render it from its structure instead
+    let json = policy
+        .to_json()
+        .map_err(|e| ValidatePolicyError::ValidationError(e.to_string()))?;
+    let canonical = Policy::from_json(None, json)
+        .map_err(|e| ValidatePolicyError::ValidationError(e.to_string()))?;
+    canonical.to_cedar().ok_or_else(|| {
+        ValidatePolicyError::ValidationError(
+            "Linked policies cannot be rendered in Cedar syntax".to_string(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const DSL: &str = r#"
+        // Readers may read
+        @ticket("SEC-1234")
+        permit(principal, action == Action::"read", resource)
+        when { principal.active };"#;
+
+    fn json_policy() -> String {
+        json!({
+            "effect": "permit",
+            "principal": { "op": "All" },
+            "action": { "op": "==", "entity": { "type": "Action", "id": "read" } },
+            "resource": { "op": "All" },
+            "conditions": [{
+                "kind": "when",
+                "body": { ".": { "left": { "Var": "principal" }, "attr": "active" } }
+            }],
+            "annotations": { "ticket": "SEC-1234" }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn detects_the_syntax() {
+        assert_eq!(parse_policy(DSL).unwrap().1, PolicySyntax::Cedar);
+        assert_eq!(parse_policy(&json_policy()).unwrap().1, PolicySyntax::Json);
+    }
+
+    #[test]
+    fn json_policies_are_rendered_in_cedar_syntax() {
+        let rendered = to_cedar_syntax(&json_policy()).unwrap();
+
+        assert!(rendered.contains("@ticket(\"SEC-1234\")"));
+        let (policy, syntax) = parse_policy(&rendered).unwrap();
+        assert_eq!(syntax, PolicySyntax::Cedar);
+        let (written, _) = parse_policy(DSL).unwrap();
+        assert_eq!(policy.to_json().unwrap(), written.to_json().unwrap());
+        assert_eq!(to_cedar_syntax(&json_policy()).unwrap(), rendered);
+    }
+
+    #[test]
+    fn cedar_and_json_policies_are_stored_identically() {
+        let rendered = to_cedar_syntax(DSL).unwrap();
+
+        assert_eq!(rendered, to_cedar_syntax(&json_policy()).unwrap());
+        assert!(!rendered.contains("// Readers may read"));
+        assert_eq!(to_cedar_syntax(&rendered).unwrap(), rendered);
+    }
+
+    #[test]
+    fn invalid_policies_are_not_rendered() {
+        assert!(to_cedar_syntax("permit(principal, action").is_err());
+    }
+
+    #[test]
+    fn invalid_input_reports_both_parse_errors() {
+        let error = parse_policy(r#"{"effect": "permit""#)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("not a Cedar policy"));
+        assert!(error.contains("not a Cedar JSON policy either"));
+    }
+}
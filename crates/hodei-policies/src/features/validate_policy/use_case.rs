@@ -1,11 +1,16 @@
 use crate::features::load_schema::ports::SchemaStoragePort;
-use crate::features::validate_policy::dto::{ValidatePolicyCommand, ValidationResult};
+use crate::features::validate_policy::annotations::annotations_of;
+use crate::features::validate_policy::dto::{
+    ValidatePolicyCommand, ValidationLevel, ValidationResult,
+};
 use crate::features::validate_policy::error::ValidatePolicyError;
 use crate::features::validate_policy::port::ValidatePolicyPort;
+use crate::features::validate_policy::syntax::parse_policy;
 use async_trait::async_trait;
 use cedar_policy::Schema;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{info, warn};
+use tracing::{info, instrument, warn};
 
 /// Use case for validating Cedar policies
 ///
@@ -14,6 +19,8 @@ use tracing::{info, warn};
 pub struct ValidatePolicyUseCase<S: SchemaStoragePort> {
     /// Optional schema storage for schema-based validation
     schema_storage: Option<Arc<S>>,
+    /// How strictly policies are checked against the schema
+    validation_level: ValidationLevel,
 }
 
 impl<S: SchemaStoragePort> Default for ValidatePolicyUseCase<S> {
@@ -27,6 +34,7 @@ impl<S: SchemaStoragePort> ValidatePolicyUseCase<S> {
     pub fn new() -> Self {
         Self {
             schema_storage: None,
+            validation_level: ValidationLevel::default(),
         }
     }
 
@@ -37,9 +45,16 @@ impl<S: SchemaStoragePort> ValidatePolicyUseCase<S> {
     pub fn with_schema_storage(schema_storage: Arc<S>) -> Self {
         Self {
             schema_storage: Some(schema_storage),
+            validation_level: ValidationLevel::default(),
         }
     }
 
+    /// Check policies against the schema at `level` instead of strictly
+    pub fn with_validation_level(mut self, level: ValidationLevel) -> Self {
+        self.validation_level = level;
+        self
+    }
+
     pub async fn execute(
         &self,
         command: ValidatePolicyCommand,
@@ -74,6 +89,9 @@ impl<S: SchemaStoragePort> ValidatePolicyUseCase<S> {
 
 #[async_trait]
 impl<S: SchemaStoragePort> ValidatePolicyPort for ValidatePolicyUseCase<S> {
+    #[instrument(name = "validate_policy", skip_all, fields(
+        correlation_id = kernel::current_correlation_id().as_deref()
+    ))]
     async fn validate(
         &self,
         command: ValidatePolicyCommand,
@@ -87,25 +105,28 @@ impl<S: SchemaStoragePort> ValidatePolicyPort for ValidatePolicyUseCase<S> {
             return Ok(ValidationResult {
                 is_valid: false,
                 errors: vec!["Policy content cannot be empty".to_string()],
+                annotations: HashMap::new(),
             });
         }
 
-        // Parse the policy using Cedar
-        let policy = match cedar_policy::Policy::parse(None, content) {
-            Ok(p) => {
-                info!("Policy syntax is valid");
+        // Parse the policy, in Cedar or Cedar JSON syntax
+        let policy = match parse_policy(content) {
+            Ok((p, syntax)) => {
+                info!(?syntax, "Policy syntax is valid");
                 p
             }
             Err(e) => {
-                warn!("Policy syntax validation failed: {:?}", e);
-                let errors = format_cedar_errors(e);
+                warn!("Policy syntax validation failed: {}", e);
                 return Ok(ValidationResult {
                     is_valid: false,
-                    errors,
+                    errors: vec![e.to_string()],
+                    annotations: HashMap::new(),
                 });
             }
         };
 
+        let annotations = annotations_of(&policy);
+
         // If schema storage is available, validate against schema
         if self.schema_storage.is_some() {
             info!("Attempting schema-based validation");
@@ -116,7 +137,7 @@ impl<S: SchemaStoragePort> ValidatePolicyPort for ValidatePolicyUseCase<S> {
                     .map_err(|e| ValidatePolicyError::ValidationError(e.to_string()))?;
 
                 let validation_result = cedar_policy::Validator::new(schema)
-                    .validate(&policy_set, cedar_policy::ValidationMode::default());
+                    .validate(&policy_set, self.validation_level.mode());
 
                 // Check if there are validation errors
                 let validation_errors: Vec<String> = validation_result
@@ -129,6 +150,7 @@ impl<S: SchemaStoragePort> ValidatePolicyPort for ValidatePolicyUseCase<S> {
                     return Ok(ValidationResult {
                         is_valid: false,
                         errors: validation_errors,
+                        annotations: HashMap::new(),
                     });
                 }
 
@@ -141,16 +163,11 @@ impl<S: SchemaStoragePort> ValidatePolicyPort for ValidatePolicyUseCase<S> {
         Ok(ValidationResult {
             is_valid: true,
             errors: vec![],
+            annotations,
         })
     }
 }
 
-fn format_cedar_errors(error: cedar_policy::ParseErrors) -> Vec<String> {
-    // Cedar errors can be complex; for now, convert to string representation
-    // In a real implementation, you might want to parse the error structure more carefully
-    vec![error.to_string()]
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,6 +237,29 @@ mod tests {
         assert!(result.errors[0].contains("resource") || result.errors[0].contains("missing"));
     }
 
+    #[tokio::test]
+    async fn test_valid_policy_preserves_annotations() {
+        let use_case = ValidatePolicyUseCase::<MockSchemaStorage>::new();
+        let command = ValidatePolicyCommand {
+            content: r#"@ticket("SEC-1234") permit(principal, action, resource);"#.to_string(),
+        };
+        let result = use_case.execute(command).await.unwrap();
+        assert!(result.is_valid);
+        assert_eq!(result.annotations["ticket"], "SEC-1234");
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_annotation_is_invalid() {
+        let use_case = ValidatePolicyUseCase::<MockSchemaStorage>::new();
+        let command = ValidatePolicyCommand {
+            content: r#"@ticket("SEC-1") @ticket("SEC-2") permit(principal, action, resource);"#
+                .to_string(),
+        };
+        let result = use_case.execute(command).await.unwrap();
+        assert!(!result.is_valid);
+        assert!(result.annotations.is_empty());
+    }
+
     #[tokio::test]
     async fn test_empty_policy_is_invalid() {
         let use_case = ValidatePolicyUseCase::<MockSchemaStorage>::new();
@@ -5,6 +5,7 @@
 
 use cedar_policy::{CedarSchemaError, Schema, SchemaError, SchemaFragment};
 use kernel::{HodeiEntity, HodeiEntityType};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -103,6 +104,46 @@ impl EngineBuilder {
         Schema::from_schema_fragments(all_fragments).map_err(Box::new)
     }
 
+    /// Convert the registered types to one schema in Cedar's JSON format
+    ///
+    /// Fragments declaring the same namespace are merged into one namespace
+    /// entry, so the result describes the same schema as [`build_schema`].
+    ///
+    /// [`build_schema`]: Self::build_schema
+    pub fn to_json(&self) -> Result<Value, Box<SchemaError>> {
+        let mut schema = Map::new();
+
+        for fragment in self.entity_fragments.values().chain(&self.action_fragments) {
+            let Value::Object(namespaces) = fragment.clone().to_json_value().map_err(Box::new)?
+            else {
+                continue;
+            };
+            for (namespace, declarations) in namespaces {
+                let Value::Object(declarations) = declarations else {
+                    continue;
+                };
+                let target = schema
+                    .entry(namespace)
+                    .or_insert_with(|| Value::Object(Map::new()));
+                let Value::Object(target) = target else {
+                    continue;
+                };
+                for (kind, items) in declarations {
+                    match (target.get_mut(&kind), items) {
+                        (Some(Value::Object(existing)), Value::Object(items)) => {
+                            existing.extend(items)
+                        }
+                        (_, items) => {
+                            target.insert(kind, items);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Value::Object(schema))
+    }
+
     /// Get the number of registered entity types
     #[allow(dead_code)]
     pub fn entity_count(&self) -> usize {
@@ -281,6 +322,9 @@ fn to_cedar_type(attr_type: &kernel::domain::AttributeType) -> &'static str {
         AttributeType::Set(_) => "Set<String>", // Simplified for now
         AttributeType::Record(_) => "Record",   // Simplified for now
         AttributeType::EntityRef(_) => "__cedar::Entity", // Simplified for now
+        AttributeType::Decimal => "__cedar::decimal",
+        AttributeType::IpAddr => "__cedar::ipaddr",
+        AttributeType::DateTime => "__cedar::datetime",
     }
 }
 
@@ -595,6 +639,85 @@ mod tests {
         assert_eq!(builder.entity_count(), 1);
     }
 
+    #[derive(Debug)]
+    struct TestSession {
+        hrn: kernel::Hrn,
+    }
+
+    impl HodeiEntityType for TestSession {
+        fn service_name() -> ServiceName {
+            ServiceName::new("audit").unwrap()
+        }
+
+        fn resource_type_name() -> ResourceTypeName {
+            ResourceTypeName::new("Session").unwrap()
+        }
+
+        fn is_principal_type() -> bool {
+            false
+        }
+
+        fn attributes_schema() -> Vec<(AttributeName, AttributeType)> {
+            vec![
+                (
+                    AttributeName::new("cost").unwrap(),
+                    AttributeType::decimal(),
+                ),
+                (
+                    AttributeName::new("source").unwrap(),
+                    AttributeType::ip_addr(),
+                ),
+                (
+                    AttributeName::new("started").unwrap(),
+                    AttributeType::datetime(),
+                ),
+            ]
+        }
+    }
+
+    impl HodeiEntity for TestSession {
+        fn hrn(&self) -> &kernel::Hrn {
+            &self.hrn
+        }
+
+        fn attributes(&self) -> HashMap<AttributeName, kernel::AttributeValue> {
+            let mut attrs = HashMap::new();
+            attrs.insert(
+                AttributeName::new("cost").unwrap(),
+                kernel::AttributeValue::decimal("0.25").unwrap(),
+            );
+            attrs.insert(
+                AttributeName::new("source").unwrap(),
+                kernel::AttributeValue::ip_addr("::1").unwrap(),
+            );
+            attrs.insert(
+                AttributeName::new("started").unwrap(),
+                kernel::AttributeValue::datetime("2024-10-15").unwrap(),
+            );
+            attrs
+        }
+    }
+
+    #[test]
+    fn extension_attributes_map_to_cedar_extension_types() {
+        let mut builder = EngineBuilder::new();
+        builder.register_entity::<TestSession>().unwrap();
+        assert!(builder.build_schema().is_ok());
+
+        let session = TestSession {
+            hrn: kernel::Hrn::new(
+                "aws".to_string(),
+                "audit".to_string(),
+                "123".to_string(),
+                "Session".to_string(),
+                "s-1".to_string(),
+            ),
+        };
+        let mut builder = EngineBuilder::new();
+        builder.register_entity_instance(&session).unwrap();
+        assert!(builder.build_schema().is_ok());
+    }
+
     // ============================================================================
     // Action Registration Tests
     // ============================================================================
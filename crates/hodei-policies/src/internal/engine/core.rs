@@ -4,13 +4,80 @@
 //! with the current Cedar API and compiles successfully.
 
 use super::translator;
-use super::types::{AuthorizationDecision, EngineError, EngineRequest};
-use cedar_policy::{Authorizer, Context, Entities, Policy, PolicySet, Request};
+use super::types::{
+    AuthorizationDecision, DefaultDecision, EngineError, EngineLimits, EngineRequest,
+    EngineSnapshot, EvaluationLimit, PolicyDocument, SNAPSHOT_FORMAT_VERSION, policy_set_version,
+};
+use crate::features::validate_policy::annotations::annotations_of;
+use arc_swap::ArcSwap;
+use cedar_policy::{Authorizer, Context, Effect, Entities, Policy, PolicyId, PolicySet, Request};
 use kernel::HodeiEntity;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
-use tokio::sync::RwLock as TokioRwLock;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+/// Policies parsed into a Cedar set, with what snapshots and decisions need
+#[derive(Debug, Clone)]
+struct LoadedPolicies {
+    /// Parsed policies, under engine IDs
+    set: PolicySet,
+    /// Caller-supplied ID of each policy, keyed by its engine ID
+    ids: HashMap<PolicyId, String>,
+    /// Policies as given, in load order, for snapshots
+    sources: Vec<PolicyDocument>,
+    /// Content hash of `sources`
+    version: String,
+}
+
+impl LoadedPolicies {
+    fn empty() -> Self {
+        Self::parse(Vec::new()).expect("an empty policy set always parses")
+    }
+
+    fn parse(sources: Vec<PolicyDocument>) -> Result<Self, EngineError> {
+        let (set, ids) = build_policy_set(&sources)?;
+        let version = policy_set_version(&sources);
+        Ok(Self {
+            set,
+            ids,
+            sources,
+            version,
+        })
+    }
+}
+
+/// The policies, entities and schema version evaluations run against
+///
+/// The engine swaps its active set as a whole. An evaluation reads the set
+/// once and completes against it, so replacing the set never blocks or fails
+/// in-flight evaluations, and none of them sees part of an update.
+#[derive(Debug, Clone)]
+pub struct ActiveSet {
+    policies: Arc<LoadedPolicies>,
+    entities: Arc<Entities>,
+    schema_version: Option<String>,
+}
+
+impl ActiveSet {
+    fn empty() -> Self {
+        Self {
+            policies: Arc::new(LoadedPolicies::empty()),
+            entities: Arc::new(Entities::empty()),
+            schema_version: None,
+        }
+    }
+
+    /// Version of the policy set, see [`policy_set_version`]
+    pub fn policy_set_version(&self) -> &str {
+        &self.policies.version
+    }
+
+    /// Schema version the policies are evaluated under
+    pub fn schema_version(&self) -> Option<&str> {
+        self.schema_version.as_deref()
+    }
+}
 
 /// Simple Authorization Engine
 ///
@@ -35,25 +102,65 @@ use tracing::{debug, info};
 /// 3. Pass the schema explicitly during evaluation
 ///
 /// For most use cases, schema-less operation is sufficient and more flexible.
+///
+/// ## Hot Reload
+///
+/// Policies, entities and schema version form an [`ActiveSet`] held in an
+/// `ArcSwap`. Every change publishes a new set atomically; evaluations
+/// already running finish against the set they started with, and each
+/// decision reports the policy set and schema versions that served it.
 pub struct AuthorizationEngine {
     /// Cedar authorizer
     authorizer: Authorizer,
-    /// Loaded policies
-    policies: Arc<TokioRwLock<PolicySet>>,
-    /// Entity store
-    entities: Arc<TokioRwLock<Entities>>,
+    /// Policies, entities and schema version currently evaluated against
+    active: ArcSwap<ActiveSet>,
+    /// Size limits enforced before loading or evaluating
+    limits: EngineLimits,
+    /// Decision returned when no policy applies
+    default_decision: DefaultDecision,
 }
 
 impl AuthorizationEngine {
-    /// Create a new authorization engine
+    /// Create a new authorization engine with the default limits
     pub fn new() -> Self {
+        Self::with_limits(EngineLimits::default())
+    }
+
+    /// Create a new authorization engine enforcing `limits`
+    pub fn with_limits(limits: EngineLimits) -> Self {
         Self {
             authorizer: Authorizer::new(),
-            policies: Arc::new(TokioRwLock::new(PolicySet::new())),
-            entities: Arc::new(TokioRwLock::new(Entities::empty())),
+            active: ArcSwap::from_pointee(ActiveSet::empty()),
+            limits,
+            default_decision: DefaultDecision::Deny,
         }
     }
 
+    /// Set the decision returned when no policy applies
+    ///
+    /// Engines deny by default. [`DefaultDecision::Allow`] is an advanced,
+    /// dangerous setting: every request no policy mentions is allowed. It can
+    /// only be chosen when the engine is created.
+    pub fn with_default_decision(mut self, default_decision: DefaultDecision) -> Self {
+        self.default_decision = default_decision;
+        self
+    }
+
+    /// The limits this engine enforces
+    pub fn limits(&self) -> EngineLimits {
+        self.limits
+    }
+
+    /// The decision this engine returns when no policy applies
+    pub fn default_decision(&self) -> DefaultDecision {
+        self.default_decision
+    }
+
+    /// The set evaluations currently run against
+    pub fn active(&self) -> Arc<ActiveSet> {
+        self.active.load_full()
+    }
+
     /// Evaluate an authorization request in schema-less mode
     ///
     /// This method evaluates policies without Cedar schema validation.
@@ -62,11 +169,35 @@ impl AuthorizationEngine {
     ///
     /// This approach provides maximum flexibility while maintaining
     /// Cedar's powerful policy evaluation capabilities.
+    ///
+    /// The request is evaluated against the set active when it starts, even
+    /// if another one is activated meanwhile.
+    ///
+    /// A context above the key count, depth or size limits fails with
+    /// `ContextTooLarge` before anything is evaluated.
     pub async fn is_authorized<'a>(
         &self,
         request: &EngineRequest<'a>,
     ) -> Result<AuthorizationDecision, EngineError> {
-        debug!("Starting authorization evaluation");
+        let active = self.active();
+        self.is_authorized_against(&active, request).await
+    }
+
+    /// Evaluate an authorization request against a given set
+    ///
+    /// Used to evaluate against a set just activated, whatever other
+    /// activations happen in between.
+    pub async fn is_authorized_against<'a>(
+        &self,
+        active: &ActiveSet,
+        request: &EngineRequest<'a>,
+    ) -> Result<AuthorizationDecision, EngineError> {
+        debug!(
+            policy_set_version = %active.policy_set_version(),
+            "Starting authorization evaluation"
+        );
+
+        self.limits.check_context(request)?;
 
         // 1. Translate entities to Cedar
         let principal_cedar = translator::translate_to_cedar_entity(request.principal)
@@ -115,58 +246,267 @@ impl AuthorizationEngine {
         )
         .map_err(|e| EngineError::EvaluationFailed(format!("Failed to build request: {}", e)))?;
 
-        // 5. Get policies and entities for evaluation
-        let policies = self.policies.read().await;
-        let entities = self.entities.read().await;
-
-        // 6. Evaluate with Cedar
-        let response = self
-            .authorizer
-            .is_authorized(&cedar_request, &policies, &entities);
+        // 5. Evaluate with Cedar
+        let policies = &active.policies;
+        let response =
+            self.authorizer
+                .is_authorized(&cedar_request, &policies.set, &active.entities);
         debug!("Cedar evaluation complete: {:?}", response.decision());
 
-        // 7. Map response to decision
+        // 6. Map response to decision. Cedar denies when no policy applies;
+        // the engine's default decides instead, unless a policy failed to
+        // evaluate, as that policy may have been a forbid.
+        let no_policy_applied = response.diagnostics().reason().next().is_none();
         let decision = match response.decision() {
             cedar_policy::Decision::Allow => {
                 info!("Authorization ALLOWED");
                 AuthorizationDecision::allow()
             }
+            cedar_policy::Decision::Deny if no_policy_applied => {
+                let failed_policies = response.diagnostics().errors().count();
+                if self.default_decision == DefaultDecision::Allow && failed_policies == 0 {
+                    warn!("No policy applied; authorization ALLOWED by default");
+                    AuthorizationDecision::allow_with_reason(
+                        "No policy applied; allowed by default".to_string(),
+                    )
+                    .with_default_applied(DefaultDecision::Allow)
+                } else {
+                    info!("No policy applied; authorization DENIED by default");
+                    AuthorizationDecision::deny_with_reason(
+                        "No policy applied; denied by default".to_string(),
+                    )
+                    .with_default_applied(DefaultDecision::Deny)
+                }
+            }
             cedar_policy::Decision::Deny => {
                 info!("Authorization DENIED");
                 AuthorizationDecision::deny()
             }
         };
 
-        Ok(decision)
+        // 7. Report the determining policies under the IDs they were loaded
+        // with. Cedar reports them in no particular order; sort them so the
+        // same request always gives the same list: forbids first, as they
+        // override permits, then by ID.
+        let mut determining_policies = Vec::new();
+        let mut policy_annotations = HashMap::new();
+        for engine_id in response.diagnostics().reason() {
+            let Some(id) = policies.ids.get(engine_id) else {
+                continue;
+            };
+            if determining_policies.iter().any(|(_, known)| known == id) {
+                continue;
+            }
+            let policy = policies.set.policy(engine_id);
+            if let Some(policy) = policy {
+                policy_annotations.insert(id.clone(), annotations_of(policy));
+            }
+            let is_permit = policy.is_some_and(|policy| policy.effect() == Effect::Permit);
+            determining_policies.push((is_permit, id.clone()));
+        }
+        determining_policies.sort();
+
+        Ok(decision
+            .with_policies(determining_policies.into_iter().map(|(_, id)| id).collect())
+            .with_policy_annotations(policy_annotations)
+            .with_versions(
+                active.policy_set_version().to_string(),
+                active.schema_version().map(String::from),
+            ))
+    }
+
+    /// Publish a change to the active set
+    ///
+    /// `update` may run more than once if another change is published
+    /// concurrently; it is given the latest set each time, so no change is
+    /// lost.
+    fn update(&self, update: impl Fn(&ActiveSet) -> ActiveSet) {
+        self.active.rcu(|current| Arc::new(update(current)));
+    }
+
+    /// Parse policies given as `(id, text)`, enforcing `max_policies`
+    fn parse_policies(
+        &self,
+        policy_texts: Vec<(String, String)>,
+    ) -> Result<Arc<LoadedPolicies>, EngineError> {
+        self.limits
+            .check(EvaluationLimit::Policies, policy_texts.len())?;
+
+        let sources: Vec<PolicyDocument> = policy_texts
+            .into_iter()
+            .map(|(id, content)| PolicyDocument { id, content })
+            .collect();
+        LoadedPolicies::parse(sources).map(Arc::new)
+    }
+
+    /// Translate entities to a Cedar store, enforcing `max_entities`
+    fn parse_entities(&self, entities: &[&dyn HodeiEntity]) -> Result<Entities, EngineError> {
+        self.limits
+            .check(EvaluationLimit::Entities, entities.len())?;
+
+        // Schema-less mode: entities are created without type checking
+        // Cedar will validate entity structure at policy evaluation time
+        let cedar_entities = entities
+            .iter()
+            .map(|entity| translator::translate_to_cedar_entity(*entity))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| EngineError::TranslationError(e.to_string()))?;
+
+        Entities::from_entities(cedar_entities, None)
+            .map_err(|e| EngineError::TranslationError(format!("Failed to create entities: {}", e)))
     }
 
     /// Load policies from Cedar DSL strings with IDs
-    pub async fn load_policies(&self, policy_texts: Vec<String>) -> Result<usize, EngineError> {
+    ///
+    /// Each policy is given as `(id, text)`. The ID is what decisions report
+    /// in their determining policies; it need not be unique, so the same
+    /// policy reaching the set through several paths is loaded harmlessly.
+    ///
+    /// More than `max_policies` fails with `LimitExceeded` and keeps the
+    /// previously loaded set. Outside tests, policies are loaded together
+    /// with their entities through [`activate`](Self::activate).
+    #[cfg(test)]
+    pub async fn load_policies(
+        &self,
+        policy_texts: Vec<(String, String)>,
+    ) -> Result<usize, EngineError> {
         info!("Loading {} policies", policy_texts.len());
+        let policies = self.parse_policies(policy_texts)?;
+        let count = policies.sources.len();
 
-        let mut new_policy_set = PolicySet::new();
+        self.update(|current| ActiveSet {
+            policies: policies.clone(),
+            ..current.clone()
+        });
 
-        for (idx, policy_text) in policy_texts.iter().enumerate() {
-            // Parse policy with unique ID based on index to avoid duplicates
-            let policy_id = cedar_policy::PolicyId::new(format!("auto_policy_{}", idx));
-            let policy = Policy::parse(Some(policy_id), policy_text).map_err(|e| {
-                EngineError::InvalidPolicy(format!("Policy {} parse error: {}", idx, e))
-            })?;
+        info!("Successfully loaded {} policies", count);
+        Ok(count)
+    }
 
-            new_policy_set
-                .add(policy)
-                .map_err(|e| EngineError::InvalidPolicy(format!("Failed to add policy: {}", e)))?;
+    /// Atomically replace the policies, entities and schema version
+    ///
+    /// This is the hot reload after a schema or policy rebuild: evaluations
+    /// started before the swap complete against the previous set, later
+    /// ones use the new one, and none sees the new policies with the old
+    /// entities or schema version. Nothing is changed unless the policies
+    /// and entities all load; invalid policies fail with `InvalidPolicy`,
+    /// untranslatable entities with `TranslationError`.
+    ///
+    /// Returns the set activated, to evaluate against with
+    /// [`is_authorized_against`](Self::is_authorized_against).
+    pub async fn activate(
+        &self,
+        policy_texts: Vec<(String, String)>,
+        entities: &[&dyn HodeiEntity],
+        schema_version: Option<String>,
+    ) -> Result<Arc<ActiveSet>, EngineError> {
+        let policies = self.parse_policies(policy_texts)?;
+        let entities = Arc::new(self.parse_entities(entities)?);
+
+        let active = Arc::new(ActiveSet {
+            policies,
+            entities,
+            schema_version,
+        });
+        self.active.store(active.clone());
+        Ok(active)
+    }
 
-            debug!("Loaded policy {}: {} bytes", idx, policy_text.len());
-        }
+    /// Version of the loaded policy set
+    ///
+    /// A content hash of the policies, their IDs and their order; see
+    /// [`policy_set_version`](super::types::policy_set_version).
+    #[cfg(test)]
+    pub async fn policy_set_version(&self) -> String {
+        self.active().policy_set_version().to_string()
+    }
 
-        // Update policies
-        let mut policies = self.policies.write().await;
+    /// Record the schema version the loaded policies are evaluated under
+    ///
+    /// The engine itself evaluates schema-less; the version is carried into
+    /// decisions and snapshots.
+    #[cfg(test)]
+    pub async fn set_schema_version(&self, version: Option<String>) {
+        self.update(|current| ActiveSet {
+            schema_version: version.clone(),
+            ..current.clone()
+        });
+    }
 
-        *policies = new_policy_set;
+    /// Export the policies, entities and schema version to a snapshot
+    ///
+    /// Loading the snapshot with [`restore`](Self::restore) into any engine
+    /// gives the same decisions as this one.
+    pub async fn snapshot(&self) -> Result<EngineSnapshot, EngineError> {
+        // One read of the active set, so everything comes from the same point
+        let active = self.active();
+        let mut entities = active.entities.to_json_value().map_err(|e| {
+            EngineError::TranslationError(format!("Failed to export entities: {}", e))
+        })?;
+        // The store has no stable order; sort so equal states give equal snapshots
+        if let serde_json::Value::Array(list) = &mut entities {
+            list.sort_by_key(|entity| entity["uid"].to_string());
+        }
 
-        info!("Successfully loaded {} policies", policy_texts.len());
-        Ok(policy_texts.len())
+        let snapshot = EngineSnapshot {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            policy_set_version: active.policy_set_version().to_string(),
+            schema_version: active.schema_version.clone(),
+            default_decision: self.default_decision,
+            policies: active.policies.sources.clone(),
+            entities,
+        };
+        debug!(
+            policy_set_version = %snapshot.policy_set_version,
+            "Engine snapshot taken"
+        );
+        Ok(snapshot)
+    }
+
+    /// Replace the policies, entities and schema version with a snapshot's
+    ///
+    /// The snapshot is rejected with `InvalidSnapshot` if its format is not
+    /// supported, its default decision is not this engine's, or its policies
+    /// do not hash to its `policy_set_version`, so a replayed decision always
+    /// comes from the policy set and default that were live.
+    /// Nothing is changed unless the whole snapshot loads.
+    pub async fn restore(&self, snapshot: EngineSnapshot) -> Result<(), EngineError> {
+        if snapshot.format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(EngineError::InvalidSnapshot(format!(
+                "unsupported format version {}, expected {}",
+                snapshot.format_version, SNAPSHOT_FORMAT_VERSION
+            )));
+        }
+        if snapshot.default_decision != self.default_decision {
+            return Err(EngineError::InvalidSnapshot(format!(
+                "snapshot was taken with default decision {:?}, this engine uses {:?}",
+                snapshot.default_decision, self.default_decision
+            )));
+        }
+        let version = policy_set_version(&snapshot.policies);
+        if version != snapshot.policy_set_version {
+            return Err(EngineError::InvalidSnapshot(format!(
+                "policies hash to {}, but the snapshot records {}",
+                version, snapshot.policy_set_version
+            )));
+        }
+        self.limits
+            .check(EvaluationLimit::Policies, snapshot.policies.len())?;
+
+        let policies = LoadedPolicies::parse(snapshot.policies)?;
+        let new_entities = Entities::from_json_value(snapshot.entities, None)
+            .map_err(|e| EngineError::InvalidSnapshot(format!("invalid entities: {}", e)))?;
+        self.limits
+            .check(EvaluationLimit::Entities, new_entities.iter().count())?;
+
+        self.active.store(Arc::new(ActiveSet {
+            policies: Arc::new(policies),
+            entities: Arc::new(new_entities),
+            schema_version: snapshot.schema_version,
+        }));
+
+        info!(policy_set_version = %version, "Engine restored from snapshot");
+        Ok(())
     }
 
     /// Register an entity in the entity store
@@ -179,14 +519,15 @@ impl AuthorizationEngine {
             .map_err(|e| EngineError::TranslationError(e.to_string()))?;
 
         // Create new entity store with the new entity
-        let new_entities = Entities::from_entities(vec![cedar_entity], None).map_err(|e| {
-            EngineError::TranslationError(format!("Failed to create entities: {}", e))
-        })?;
+        let new_entities = Arc::new(Entities::from_entities(vec![cedar_entity], None).map_err(
+            |e| EngineError::TranslationError(format!("Failed to create entities: {}", e)),
+        )?);
 
         // Update entities
-        let mut entities = self.entities.write().await;
-
-        *entities = new_entities;
+        self.update(|current| ActiveSet {
+            entities: new_entities.clone(),
+            ..current.clone()
+        });
 
         debug!("Entity registered successfully");
         Ok(())
@@ -206,6 +547,10 @@ impl AuthorizationEngine {
     ///
     /// This means policies can reference any action (as a string) and Cedar will
     /// evaluate them based on the policy conditions and entity data.
+    ///
+    /// More than `max_entities` fails with `LimitExceeded` and keeps the
+    /// previously registered entities.
+    #[allow(dead_code)]
     pub async fn register_entities(
         &self,
         entities: Vec<&dyn HodeiEntity>,
@@ -214,27 +559,12 @@ impl AuthorizationEngine {
             "Registering {} entities in schema-less mode",
             entities.len()
         );
+        let new_entities = Arc::new(self.parse_entities(&entities)?);
 
-        // 1. Translate all entities to Cedar entities
-        let cedar_entities: Result<Vec<_>, _> = entities
-            .iter()
-            .map(|entity| translator::translate_to_cedar_entity(*entity))
-            .collect();
-
-        let cedar_entities =
-            cedar_entities.map_err(|e| EngineError::TranslationError(e.to_string()))?;
-
-        // 2. Create new Entities without schema validation
-        // Schema-less mode: entities are created without type checking
-        // Cedar will validate entity structure at policy evaluation time
-        let new_entities = Entities::from_entities(cedar_entities, None).map_err(|e| {
-            EngineError::TranslationError(format!("Failed to create entities: {}", e))
-        })?;
-
-        // 3. Update entity store
-        let mut entity_store = self.entities.write().await;
-
-        *entity_store = new_entities;
+        self.update(|current| ActiveSet {
+            entities: new_entities.clone(),
+            ..current.clone()
+        });
 
         info!(
             "Successfully registered {} entities (schema-less)",
@@ -247,9 +577,11 @@ impl AuthorizationEngine {
     pub async fn clear_policies(&self) -> Result<(), EngineError> {
         info!("Clearing all policies");
 
-        let mut policies = self.policies.write().await;
-
-        *policies = PolicySet::new();
+        let empty = Arc::new(LoadedPolicies::empty());
+        self.update(|current| ActiveSet {
+            policies: empty.clone(),
+            ..current.clone()
+        });
 
         Ok(())
     }
@@ -258,9 +590,11 @@ impl AuthorizationEngine {
     pub async fn clear_entities(&self) -> Result<(), EngineError> {
         info!("Clearing all entities");
 
-        let mut entities = self.entities.write().await;
-
-        *entities = Entities::empty();
+        let empty = Arc::new(Entities::empty());
+        self.update(|current| ActiveSet {
+            entities: empty.clone(),
+            ..current.clone()
+        });
 
         Ok(())
     }
@@ -268,13 +602,13 @@ impl AuthorizationEngine {
     /// Get the number of loaded policies
     #[allow(dead_code)]
     pub async fn policy_count(&self) -> usize {
-        self.policies.read().await.policies().count()
+        self.active().policies.set.policies().count()
     }
 
     /// Get the number of registered entities
     #[allow(dead_code)]
     pub async fn entity_count(&self) -> usize {
-        self.entities.read().await.iter().count()
+        self.active().entities.iter().count()
     }
 }
 
@@ -284,6 +618,31 @@ impl Default for AuthorizationEngine {
     }
 }
 
+/// Parse policies into a set, mapping engine IDs back to the given IDs
+fn build_policy_set(
+    sources: &[PolicyDocument],
+) -> Result<(PolicySet, HashMap<PolicyId, String>), EngineError> {
+    let mut policy_set = PolicySet::new();
+    let mut policy_ids = HashMap::new();
+
+    for (idx, source) in sources.iter().enumerate() {
+        // Parse policy with unique ID based on index to avoid duplicates
+        let policy_id = PolicyId::new(format!("auto_policy_{}", idx));
+        policy_ids.insert(policy_id.clone(), source.id.clone());
+        let policy = Policy::parse(Some(policy_id), &source.content).map_err(|e| {
+            EngineError::InvalidPolicy(format!("Policy {} parse error: {}", idx, e))
+        })?;
+
+        policy_set
+            .add(policy)
+            .map_err(|e| EngineError::InvalidPolicy(format!("Failed to add policy: {}", e)))?;
+
+        debug!("Loaded policy {}: {} bytes", idx, source.content.len());
+    }
+
+    Ok((policy_set, policy_ids))
+}
+
 /// Helper function to convert serde_json::Value to Cedar RestrictedExpression
 fn json_value_to_restricted_expr(
     value: &serde_json::Value,
@@ -382,12 +741,103 @@ mod tests {
         let engine = AuthorizationEngine::new();
         let policy = "permit(principal, action, resource);".to_string();
 
-        let result = engine.load_policies(vec![policy]).await;
+        let result = engine
+            .load_policies(vec![("simple".to_string(), policy)])
+            .await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 1);
         assert_eq!(engine.policy_count().await, 1);
     }
 
+    #[tokio::test]
+    async fn decision_reports_determining_policies_with_annotations() {
+        let engine = AuthorizationEngine::new();
+        let alice = TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "alice".to_string(),
+            ),
+            name: "Alice".to_string(),
+        };
+        let permit = r#"@ticket("SEC-1234") permit(principal, action, resource);"#;
+        engine
+            .load_policies(vec![
+                ("allow-all".to_string(), permit.to_string()),
+                ("allow-all".to_string(), permit.to_string()),
+                (
+                    "deny-delete".to_string(),
+                    r#"forbid(principal, action == Action::"Delete", resource);"#.to_string(),
+                ),
+            ])
+            .await
+            .unwrap();
+        engine.register_entity(&alice).await.unwrap();
+
+        let decision = engine
+            .is_authorized(&EngineRequest::new(&alice, "Read", &alice))
+            .await
+            .unwrap();
+
+        assert!(decision.is_allowed());
+        assert_eq!(decision.determining_policies(), ["allow-all".to_string()]);
+        assert_eq!(
+            decision.policy_annotations()["allow-all"]["ticket"],
+            "SEC-1234"
+        );
+    }
+
+    #[tokio::test]
+    async fn determining_policies_are_sorted_forbids_first_then_by_id() {
+        let engine = AuthorizationEngine::new();
+        let alice = TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "alice".to_string(),
+            ),
+            name: "Alice".to_string(),
+        };
+        let permit = "permit(principal, action, resource);";
+        let forbid = r#"forbid(principal, action == Action::"Delete", resource);"#;
+        engine
+            .load_policies(
+                ["permit-c", "forbid-b", "permit-a", "forbid-a", "permit-b"]
+                    .into_iter()
+                    .map(|id| {
+                        let content = if id.starts_with("forbid") {
+                            forbid
+                        } else {
+                            permit
+                        };
+                        (id.to_string(), content.to_string())
+                    })
+                    .collect(),
+            )
+            .await
+            .unwrap();
+        engine.register_entity(&alice).await.unwrap();
+
+        let read = engine
+            .is_authorized(&EngineRequest::new(&alice, "Read", &alice))
+            .await
+            .unwrap();
+        let delete = engine
+            .is_authorized(&EngineRequest::new(&alice, "Delete", &alice))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            read.determining_policies(),
+            ["permit-a", "permit-b", "permit-c"]
+        );
+        assert_eq!(delete.determining_policies(), ["forbid-a", "forbid-b"]);
+    }
+
     #[tokio::test]
     async fn register_entity() {
         let engine = AuthorizationEngine::new();
@@ -411,7 +861,10 @@ mod tests {
     async fn clear_policies() {
         let engine = AuthorizationEngine::new();
         engine
-            .load_policies(vec!["permit(principal, action, resource);".to_string()])
+            .load_policies(vec![(
+                "permit-all".to_string(),
+                "permit(principal, action, resource);".to_string(),
+            )])
             .await
             .unwrap();
 
@@ -441,4 +894,335 @@ mod tests {
         engine.clear_entities().await.unwrap();
         assert_eq!(engine.entity_count().await, 0);
     }
+
+    #[tokio::test]
+    async fn snapshot_round_trips_to_the_same_decision() {
+        let engine = AuthorizationEngine::new();
+        let user = |id: &str, name: &str| TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                id.to_string(),
+            ),
+            name: name.to_string(),
+        };
+        let (alice, bob) = (user("alice", "Alice"), user("bob", "Bob"));
+        engine
+            .load_policies(vec![(
+                "alice-only".to_string(),
+                r#"permit(principal, action, resource) when { principal.name == "Alice" };"#
+                    .to_string(),
+            )])
+            .await
+            .unwrap();
+        engine.register_entities(vec![&alice, &bob]).await.unwrap();
+        engine.set_schema_version(Some("v7".to_string())).await;
+
+        let snapshot = engine.snapshot().await.unwrap();
+        assert_eq!(
+            snapshot.policy_set_version,
+            engine.policy_set_version().await
+        );
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: EngineSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, snapshot);
+
+        let replay = AuthorizationEngine::new();
+        replay.restore(restored).await.unwrap();
+        assert_eq!(replay.snapshot().await.unwrap(), snapshot);
+        for principal in [&alice, &bob] {
+            let request = EngineRequest::new(principal, "Read", &alice);
+            assert_eq!(
+                replay.is_authorized(&request).await.unwrap(),
+                engine.is_authorized(&request).await.unwrap()
+            );
+        }
+
+        let mut tampered = snapshot;
+        tampered.policies[0].content = "permit(principal, action, resource);".to_string();
+        let error = replay.restore(tampered).await.unwrap_err();
+        assert!(matches!(error, EngineError::InvalidSnapshot(_)));
+        assert_eq!(
+            replay.policy_set_version().await,
+            engine.policy_set_version().await
+        );
+    }
+
+    #[tokio::test]
+    async fn default_decision_applies_only_when_no_policy_does() {
+        let alice = TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "alice".to_string(),
+            ),
+            name: "Alice".to_string(),
+        };
+        let forbid_delete = (
+            "deny-delete".to_string(),
+            r#"forbid(principal, action == Action::"Delete", resource);"#.to_string(),
+        );
+
+        let secure = AuthorizationEngine::new();
+        assert_eq!(secure.default_decision(), DefaultDecision::Deny);
+        secure
+            .load_policies(vec![forbid_delete.clone()])
+            .await
+            .unwrap();
+        let decision = secure
+            .is_authorized(&EngineRequest::new(&alice, "Read", &alice))
+            .await
+            .unwrap();
+        assert!(!decision.is_allowed());
+        assert_eq!(decision.default_applied(), Some(DefaultDecision::Deny));
+
+        let open = AuthorizationEngine::new().with_default_decision(DefaultDecision::Allow);
+        open.load_policies(vec![forbid_delete]).await.unwrap();
+        let decision = open
+            .is_authorized(&EngineRequest::new(&alice, "Read", &alice))
+            .await
+            .unwrap();
+        assert!(decision.is_allowed());
+        assert_eq!(decision.default_applied(), Some(DefaultDecision::Allow));
+
+        let decision = open
+            .is_authorized(&EngineRequest::new(&alice, "Delete", &alice))
+            .await
+            .unwrap();
+        assert!(!decision.is_allowed());
+        assert_eq!(decision.default_applied(), None);
+        assert_eq!(decision.determining_policies(), ["deny-delete".to_string()]);
+
+        // A snapshot only replays on an engine with the same default
+        let snapshot = open.snapshot().await.unwrap();
+        assert!(matches!(
+            secure.restore(snapshot).await,
+            Err(EngineError::InvalidSnapshot(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn limits_are_enforced_before_evaluation() {
+        let engine = AuthorizationEngine::with_limits(EngineLimits {
+            max_policies: 1,
+            max_entities: 1,
+            max_context_depth: 2,
+            ..EngineLimits::default()
+        });
+        let user = |id: &str| TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                id.to_string(),
+            ),
+            name: id.to_string(),
+        };
+        let (alice, bob) = (user("alice"), user("bob"));
+        let permit = "permit(principal, action, resource);".to_string();
+
+        engine
+            .load_policies(vec![("allow".to_string(), permit.clone())])
+            .await
+            .unwrap();
+        let error = engine
+            .load_policies(vec![
+                ("a".to_string(), permit.clone()),
+                ("b".to_string(), permit),
+            ])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            EngineError::LimitExceeded {
+                limit: EvaluationLimit::Policies,
+                observed: 2,
+                allowed: 1,
+            }
+        ));
+        assert_eq!(engine.policy_count().await, 1);
+
+        let error = engine
+            .register_entities(vec![&alice, &bob])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            EngineError::LimitExceeded {
+                limit: EvaluationLimit::Entities,
+                ..
+            }
+        ));
+
+        let shallow = HashMap::from([("tags".to_string(), serde_json::json!(["a"]))]);
+        let request = EngineRequest::new(&alice, "read", &alice).with_context(shallow);
+        assert!(engine.is_authorized(&request).await.unwrap().is_allowed());
+
+        let deep = HashMap::from([("tags".to_string(), serde_json::json!([["a"]]))]);
+        let request = EngineRequest::new(&alice, "read", &alice).with_context(deep);
+        let error = engine.is_authorized(&request).await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Context too large: context depth is 3, at most 2 allowed"
+        );
+    }
+
+    #[tokio::test]
+    async fn in_flight_evaluations_keep_the_set_they_started_with() {
+        let engine = AuthorizationEngine::new();
+        let alice = TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "alice".to_string(),
+            ),
+            name: "Alice".to_string(),
+        };
+        let request = EngineRequest::new(&alice, "Read", &alice);
+
+        let before = engine
+            .activate(
+                vec![(
+                    "allow".to_string(),
+                    "permit(principal, action, resource);".to_string(),
+                )],
+                &[&alice],
+                Some("v1".to_string()),
+            )
+            .await
+            .unwrap();
+        let after = engine
+            .activate(
+                vec![(
+                    "deny".to_string(),
+                    "forbid(principal, action, resource);".to_string(),
+                )],
+                &[&alice],
+                Some("v2".to_string()),
+            )
+            .await
+            .unwrap();
+        assert_ne!(before.policy_set_version(), after.policy_set_version());
+
+        // An evaluation holding the previous set completes against it
+        let decision = engine
+            .is_authorized_against(&before, &request)
+            .await
+            .unwrap();
+        assert!(decision.is_allowed());
+        assert_eq!(decision.policy_set_version(), before.policy_set_version());
+        assert_eq!(decision.schema_version(), Some("v1"));
+
+        let decision = engine.is_authorized(&request).await.unwrap();
+        assert!(!decision.is_allowed());
+        assert_eq!(decision.determining_policies(), ["deny".to_string()]);
+        assert_eq!(decision.policy_set_version(), after.policy_set_version());
+        assert_eq!(decision.schema_version(), Some("v2"));
+
+        // A failed activation leaves the active set untouched
+        let error = engine
+            .activate(
+                vec![("broken".to_string(), "permit(".to_string())],
+                &[&alice],
+                Some("v3".to_string()),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(error, EngineError::InvalidPolicy(_)));
+        assert_eq!(engine.active().schema_version(), Some("v2"));
+        assert_eq!(
+            engine.policy_set_version().await,
+            after.policy_set_version()
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn swaps_are_atomic_under_concurrent_evaluation() {
+        let engine = Arc::new(AuthorizationEngine::new());
+        let alice = || TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "alice".to_string(),
+            ),
+            name: "Alice".to_string(),
+        };
+        // Each set pairs an effect with a schema version
+        let sets = [("permit", "allow-v1"), ("forbid", "deny-v2")];
+        let user = alice();
+        let mut versions = HashMap::new();
+        for (effect, schema_version) in sets {
+            let active = engine
+                .activate(
+                    vec![(
+                        effect.to_string(),
+                        format!("{}(principal, action, resource);", effect),
+                    )],
+                    &[&user],
+                    Some(schema_version.to_string()),
+                )
+                .await
+                .unwrap();
+            versions.insert(
+                active.policy_set_version().to_string(),
+                (effect, schema_version),
+            );
+        }
+
+        let swapper = {
+            let engine = engine.clone();
+            tokio::spawn(async move {
+                let user = alice();
+                for round in 0..200 {
+                    let (effect, schema_version) = sets[round % 2];
+                    engine
+                        .activate(
+                            vec![(
+                                effect.to_string(),
+                                format!("{}(principal, action, resource);", effect),
+                            )],
+                            &[&user],
+                            Some(schema_version.to_string()),
+                        )
+                        .await
+                        .unwrap();
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+        let evaluators: Vec<_> = (0..4)
+            .map(|_| {
+                let engine = engine.clone();
+                let versions = versions.clone();
+                tokio::spawn(async move {
+                    let user = alice();
+                    for _ in 0..200 {
+                        let decision = engine
+                            .is_authorized(&EngineRequest::new(&user, "Read", &user))
+                            .await
+                            .unwrap();
+                        let (effect, schema_version) = versions[decision.policy_set_version()];
+                        assert_eq!(decision.is_allowed(), effect == "permit");
+                        assert_eq!(decision.determining_policies(), [effect.to_string()]);
+                        assert_eq!(decision.schema_version(), Some(schema_version));
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+
+        swapper.await.unwrap();
+        for evaluator in evaluators {
+            evaluator.await.unwrap();
+        }
+    }
 }
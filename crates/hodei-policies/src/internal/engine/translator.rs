@@ -2,14 +2,21 @@
 //!
 //! This module provides translation functions to convert kernel agnostic types
 //! to Cedar-specific types used internally by the authorization engine.
+//!
+//! With the `translator-metrics` feature, the [`metrics`] module records the
+//! count, duration and errors of every entity and attribute value
+//! translation. Without it nothing is recorded.
 
 use cedar_policy::{
     Entity, EntityId, EntityTypeName, EntityUid, Policy, PolicyId, PolicySet, RestrictedExpression,
 };
-use kernel::{AttributeValue, HodeiEntity, Hrn};
+use kernel::{AttributeName, AttributeType, AttributeValue, HodeiEntity, HodeiEntityType, Hrn};
 use std::collections::HashMap;
 use std::str::FromStr;
 
+#[cfg(feature = "translator-metrics")]
+pub mod metrics;
+
 // ============================================================================
 // Entity Translation
 // ============================================================================
@@ -80,19 +87,80 @@ pub fn translate_to_cedar_euid(hrn: &Hrn) -> Result<EntityUid, TranslationError>
 /// # Errors
 ///
 /// Returns an error if the entity cannot be translated to Cedar format.
+///
+/// Attributes missing from the entity are omitted and Cedar treats them as
+/// absent. Use [`translate_to_cedar_entity_strict`] to refuse such entities.
 pub fn translate_to_cedar_entity(entity: &dyn HodeiEntity) -> Result<Entity, TranslationError> {
+    #[cfg(feature = "translator-metrics")]
+    {
+        metrics::record(metrics::Operation::Entity, || entity_to_cedar(entity, &[]))
+    }
+    #[cfg(not(feature = "translator-metrics"))]
+    {
+        entity_to_cedar(entity, &[])
+    }
+}
+
+/// Translate a HodeiEntity to a Cedar Entity, requiring its schema attributes
+///
+/// Every attribute in `T::attributes_schema()` is required, as in the Cedar
+/// schema generated from it; attributes the schema doesn't declare stay
+/// optional. An entity missing a required attribute would otherwise reach
+/// Cedar without it and be denied by any policy reading it, which hides the
+/// data problem behind a confusing decision.
+///
+/// # Errors
+///
+/// Returns `MissingRequiredAttribute` naming the first missing attribute, or
+/// any error of [`translate_to_cedar_entity`].
+#[allow(dead_code)]
+pub fn translate_to_cedar_entity_strict<T>(entity: &T) -> Result<Entity, TranslationError>
+where
+    T: HodeiEntityType + HodeiEntity,
+{
+    let required = T::attributes_schema();
+
+    #[cfg(feature = "translator-metrics")]
+    {
+        metrics::record(metrics::Operation::Entity, || {
+            entity_to_cedar(entity, &required)
+        })
+    }
+    #[cfg(not(feature = "translator-metrics"))]
+    {
+        entity_to_cedar(entity, &required)
+    }
+}
+
+/// Body of the entity translations, requiring the `required` attributes
+fn entity_to_cedar(
+    entity: &dyn HodeiEntity,
+    required: &[(AttributeName, AttributeType)],
+) -> Result<Entity, TranslationError> {
+    let attributes = entity.attributes();
+    if let Some((name, _)) = required
+        .iter()
+        .find(|(name, _)| !attributes.contains_key(name))
+    {
+        return Err(TranslationError::MissingRequiredAttribute(name.to_string()));
+    }
+
     // Translate HRN to EntityUid
     let uid = translate_to_cedar_euid(entity.hrn())?;
 
     // Translate attributes
     let mut attrs = HashMap::new();
-    for (name, value) in entity.attributes() {
-        let cedar_value = translate_attribute_value(&value)?;
+    for (name, value) in attributes {
+        let cedar_value = attribute_value_to_cedar(&value)?;
         attrs.insert(name.as_str().to_string(), cedar_value);
     }
 
-    // Create Cedar Entity (no parents for now)
-    let parents = std::collections::HashSet::new();
+    // Parents let `principal in Group::"x"` / `resource in Repo::"x"` match
+    let parents = entity
+        .parent_hrns()
+        .iter()
+        .map(translate_to_cedar_euid)
+        .collect::<Result<_, _>>()?;
 
     Entity::new(uid, attrs, parents).map_err(|e| {
         TranslationError::EntityCreationFailed(format!("Failed to create entity: {}", e))
@@ -114,9 +182,28 @@ pub fn translate_to_cedar_entity(entity: &dyn HodeiEntity) -> Result<Entity, Tra
 ///
 /// # Errors
 ///
-/// Returns an error if the value type is not supported.
+/// Returns an error if the value type is not supported, or
+/// `InvalidAttributeValue` if a decimal, IP address or datetime is malformed.
+#[allow(dead_code)]
 pub fn translate_attribute_value(
     value: &AttributeValue,
+) -> Result<RestrictedExpression, TranslationError> {
+    #[cfg(feature = "translator-metrics")]
+    {
+        metrics::record(metrics::Operation::AttributeValue, || {
+            attribute_value_to_cedar(value)
+        })
+    }
+    #[cfg(not(feature = "translator-metrics"))]
+    {
+        attribute_value_to_cedar(value)
+    }
+}
+
+/// Recursive body of [`translate_attribute_value`], so nested values are not
+/// recorded as translations of their own
+fn attribute_value_to_cedar(
+    value: &AttributeValue,
 ) -> Result<RestrictedExpression, TranslationError> {
     use AttributeValue;
 
@@ -127,7 +214,7 @@ pub fn translate_attribute_value(
         AttributeValue::Set(values) => {
             // Recursively translate each value in the set
             let cedar_values: Result<Vec<_>, _> =
-                values.iter().map(translate_attribute_value).collect();
+                values.iter().map(attribute_value_to_cedar).collect();
             let cedar_values = cedar_values.map_err(|_| {
                 TranslationError::UnsupportedType("Set contains unsupported type".to_string())
             })?;
@@ -137,7 +224,7 @@ pub fn translate_attribute_value(
             // Recursively translate each value in the record
             let mut cedar_map: HashMap<String, RestrictedExpression> = HashMap::new();
             for (key, value) in map {
-                let cedar_value = translate_attribute_value(value)?;
+                let cedar_value = attribute_value_to_cedar(value)?;
                 cedar_map.insert(key.to_string(), cedar_value);
             }
             RestrictedExpression::new_record(cedar_map)
@@ -147,9 +234,29 @@ pub fn translate_attribute_value(
             let uid = translate_to_cedar_euid_from_str(&hrn.to_string())?;
             Ok(RestrictedExpression::new_entity_uid(uid))
         }
+        // Cedar only checks extension values when the entity is used, so
+        // they are validated here to fail at translation time instead
+        AttributeValue::Decimal(s) => {
+            validate_extension_value(value)?;
+            Ok(RestrictedExpression::new_decimal(s.clone()))
+        }
+        AttributeValue::IpAddr(s) => {
+            validate_extension_value(value)?;
+            Ok(RestrictedExpression::new_ip(s.clone()))
+        }
+        AttributeValue::DateTime(s) => {
+            validate_extension_value(value)?;
+            Ok(RestrictedExpression::new_datetime(s.clone()))
+        }
     }
 }
 
+fn validate_extension_value(value: &AttributeValue) -> Result<(), TranslationError> {
+    value
+        .validate()
+        .map_err(|e| TranslationError::InvalidAttributeValue(e.to_string()))
+}
+
 /// Parse HRN string to Cedar EntityUid
 /// Helper function for EntityRef translation
 fn translate_to_cedar_euid_from_str(hrn_str: &str) -> Result<EntityUid, TranslationError> {
@@ -226,6 +333,10 @@ pub enum TranslationError {
     #[error("Unsupported type: {0}")]
     UnsupportedType(String),
 
+    /// Malformed decimal, IP address or datetime value
+    #[error("Invalid attribute value: {0}")]
+    InvalidAttributeValue(String),
+
     /// Policy parsing error
     #[error("Policy parse error: {0}")]
     PolicyParseError(String),
@@ -233,6 +344,11 @@ pub enum TranslationError {
     /// Failed to add policy to policy set
     #[error("Policy add error: {0}")]
     PolicyAddError(String),
+
+    /// Strict translation found an attribute of the entity type's schema
+    /// missing from the entity
+    #[error("Missing required attribute: {0}")]
+    MissingRequiredAttribute(String),
 }
 
 // ============================================================================
@@ -315,6 +431,16 @@ mod tests {
         assert_eq!(euid.id().escaped(), "alice");
     }
 
+    #[test]
+    fn translate_hrn_without_region_or_account_to_euid() {
+        let global = Hrn::from_string("hrn:aws:s3:::Bucket/logs").unwrap();
+        let euid = translate_to_cedar_euid(&global).unwrap();
+        assert_eq!(euid.to_string(), r#"S3::Bucket::"logs""#);
+
+        let reference = AttributeValue::entity_ref("hrn:hodei:iam::default:User/alice");
+        assert!(translate_attribute_value(&reference).is_ok());
+    }
+
     #[test]
     fn translate_entity_to_cedar() {
         let user = TestUser {
@@ -340,6 +466,124 @@ mod tests {
         assert_eq!(cedar_entity.uid().type_name().to_string(), "Iam::User");
     }
 
+    #[derive(Debug)]
+    struct TestArtifact {
+        hrn: Hrn,
+        parents: Vec<Hrn>,
+    }
+
+    impl HodeiEntity for TestArtifact {
+        fn hrn(&self) -> &Hrn {
+            &self.hrn
+        }
+
+        fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+            HashMap::new()
+        }
+
+        fn parent_hrns(&self) -> Vec<Hrn> {
+            self.parents.clone()
+        }
+    }
+
+    #[test]
+    fn translate_entity_parents() {
+        let hrn = |resource_type: &str, id: &str| {
+            Hrn::new(
+                "aws".to_string(),
+                "artifact".to_string(),
+                "123".to_string(),
+                resource_type.to_string(),
+                id.to_string(),
+            )
+        };
+        let repository = hrn("Repository", "libs");
+        let artifact = TestArtifact {
+            hrn: hrn("Package", "lodash"),
+            parents: vec![repository.clone()],
+        };
+
+        let cedar_entity = translate_to_cedar_entity(&artifact).unwrap();
+        let artifact_uid = cedar_entity.uid();
+        let entities = cedar_policy::Entities::from_entities(vec![cedar_entity], None).unwrap();
+
+        let repository_uid = translate_to_cedar_euid(&repository).unwrap();
+        assert!(entities.is_ancestor_of(&repository_uid, &artifact_uid));
+    }
+
+    /// A user whose data lacks the `name` its schema declares
+    #[derive(Debug)]
+    struct UserWithoutName {
+        hrn: Hrn,
+    }
+
+    impl HodeiEntityType for UserWithoutName {
+        fn service_name() -> ServiceName {
+            ServiceName::new("iam").unwrap()
+        }
+
+        fn resource_type_name() -> ResourceTypeName {
+            ResourceTypeName::new("User").unwrap()
+        }
+
+        fn attributes_schema() -> Vec<(AttributeName, AttributeType)> {
+            TestUser::attributes_schema()
+        }
+    }
+
+    impl HodeiEntity for UserWithoutName {
+        fn hrn(&self) -> &Hrn {
+            &self.hrn
+        }
+
+        fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+            let mut attrs = HashMap::new();
+            attrs.insert(
+                AttributeName::new("active").unwrap(),
+                AttributeValue::bool(true),
+            );
+            attrs
+        }
+    }
+
+    #[test]
+    fn strict_translation_requires_schema_attributes() {
+        let user = UserWithoutName {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "alice".to_string(),
+            ),
+        };
+
+        // Lenient translation omits the attribute and lets Cedar handle it
+        assert!(translate_to_cedar_entity(&user).is_ok());
+
+        assert!(matches!(
+            translate_to_cedar_entity_strict(&user),
+            Err(TranslationError::MissingRequiredAttribute(name)) if name == "name"
+        ));
+    }
+
+    #[test]
+    fn strict_translation_accepts_complete_entities() {
+        let user = TestUser {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "alice".to_string(),
+            ),
+            name: "Alice".to_string(),
+            active: true,
+        };
+
+        assert!(translate_to_cedar_entity_strict(&user).is_ok());
+    }
+
     #[test]
     fn translate_attribute_values() {
         // String
@@ -366,6 +610,89 @@ mod tests {
         assert!(cedar_expr.is_ok());
     }
 
+    #[derive(Debug)]
+    struct TestRequestLog {
+        hrn: Hrn,
+        attributes: HashMap<AttributeName, AttributeValue>,
+    }
+
+    impl HodeiEntity for TestRequestLog {
+        fn hrn(&self) -> &Hrn {
+            &self.hrn
+        }
+
+        fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+            self.attributes.clone()
+        }
+    }
+
+    #[test]
+    fn translate_extension_values_usable_in_policies() {
+        let attributes = [
+            ("cost", AttributeValue::decimal("12.75").unwrap()),
+            ("source", AttributeValue::ip_addr("10.1.2.3").unwrap()),
+            (
+                "at",
+                AttributeValue::datetime("2024-10-15T11:35:00Z").unwrap(),
+            ),
+        ]
+        .into_iter()
+        .map(|(name, value)| (AttributeName::new(name).unwrap(), value))
+        .collect();
+        let log = TestRequestLog {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "audit".to_string(),
+                "123".to_string(),
+                "Log".to_string(),
+                "req-1".to_string(),
+            ),
+            attributes,
+        };
+        let cedar_entity = translate_to_cedar_entity(&log).unwrap();
+        let uid = cedar_entity.uid();
+        let entities = cedar_policy::Entities::from_entities(vec![cedar_entity], None).unwrap();
+
+        let policies: PolicySet = r#"permit(principal, action, resource) when {
+            resource.cost.lessThan(decimal("20.0")) &&
+            resource.source.isInRange(ip("10.0.0.0/8")) &&
+            resource.at > datetime("2024-01-01")
+        };"#
+        .parse()
+        .unwrap();
+        let request = cedar_policy::Request::new(
+            uid.clone(),
+            r#"Audit::Action::"Read""#.parse().unwrap(),
+            uid,
+            cedar_policy::Context::empty(),
+            None,
+        )
+        .unwrap();
+
+        let response =
+            cedar_policy::Authorizer::new().is_authorized(&request, &policies, &entities);
+        assert_eq!(response.decision(), cedar_policy::Decision::Allow);
+    }
+
+    #[test]
+    fn translate_malformed_extension_values_fails() {
+        let malformed = [
+            AttributeValue::Decimal("12".to_string()),
+            AttributeValue::IpAddr("10.0.0.300".to_string()),
+            AttributeValue::DateTime("2024-02-30".to_string()),
+        ];
+        for value in &malformed {
+            assert!(
+                matches!(
+                    translate_attribute_value(value),
+                    Err(TranslationError::InvalidAttributeValue(_))
+                ),
+                "{} should be rejected",
+                value
+            );
+        }
+    }
+
     #[test]
     fn translate_policy_set() {
         use kernel::domain::policy::{HodeiPolicy, HodeiPolicySet, PolicyId};
@@ -0,0 +1,237 @@
+//! Translator Metrics
+//!
+//! Counters and duration histograms for the translation functions, compiled
+//! only with the `translator-metrics` feature. Without it the translator has
+//! no instrumentation at all, not even an atomic increment.
+//!
+//! Each [`Operation`] records how many translations ran, how long they took
+//! and how many failed, broken down by [`TranslationError`] variant. Only
+//! calls to the public functions are recorded: the attribute values
+//! translated as part of an entity, or nested in a set or record, count
+//! towards the enclosing translation.
+//!
+//! ```rust,ignore
+//! use hodei_policies::translator_metrics::{self, Operation};
+//!
+//! let snapshot = translator_metrics::snapshot(Operation::Entity);
+//! println!("{} entities, {:?} in total", snapshot.count, snapshot.total_duration);
+//! ```
+
+use super::TranslationError;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Upper bounds of the duration histogram buckets, in microseconds
+///
+/// Durations above the last bound fall in an extra overflow bucket.
+pub const DURATION_BUCKETS_MICROS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1_000, 5_000];
+
+const BUCKET_COUNT: usize = DURATION_BUCKETS_MICROS.len() + 1;
+const ERROR_KIND_COUNT: usize = 7;
+
+/// A public translation function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// `translate_to_cedar_entity` and `translate_to_cedar_entity_strict`
+    Entity,
+    /// `translate_attribute_value`
+    AttributeValue,
+}
+
+/// Failed translations by [`TranslationError`] variant
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ErrorCounts {
+    pub invalid_entity_type_name: u64,
+    pub entity_creation_failed: u64,
+    pub unsupported_type: u64,
+    pub invalid_attribute_value: u64,
+    pub policy_parse_error: u64,
+    pub policy_add_error: u64,
+    pub missing_required_attribute: u64,
+}
+
+impl ErrorCounts {
+    /// Failed translations of any variant
+    pub fn total(&self) -> u64 {
+        self.invalid_entity_type_name
+            + self.entity_creation_failed
+            + self.unsupported_type
+            + self.invalid_attribute_value
+            + self.policy_parse_error
+            + self.policy_add_error
+            + self.missing_required_attribute
+    }
+}
+
+/// Metrics of one operation at a point in time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// Translations run, failed or not
+    pub count: u64,
+    /// Time spent in all of them
+    pub total_duration: Duration,
+    /// Translations per duration bucket, see [`DURATION_BUCKETS_MICROS`]
+    pub duration_buckets: [u64; BUCKET_COUNT],
+    /// Failed translations by error variant
+    pub errors: ErrorCounts,
+}
+
+impl MetricsSnapshot {
+    /// Share of translations that failed, `0.0` before any ran
+    pub fn error_rate(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.errors.total() as f64 / self.count as f64
+    }
+}
+
+struct OperationMetrics {
+    count: AtomicU64,
+    total_nanos: AtomicU64,
+    buckets: [AtomicU64; BUCKET_COUNT],
+    errors: [AtomicU64; ERROR_KIND_COUNT],
+}
+
+impl OperationMetrics {
+    const fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            total_nanos: AtomicU64::new(0),
+            buckets: [const { AtomicU64::new(0) }; BUCKET_COUNT],
+            errors: [const { AtomicU64::new(0) }; ERROR_KIND_COUNT],
+        }
+    }
+
+    fn record(&self, elapsed: Duration, error: Option<&TranslationError>) {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.total_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.buckets[bucket_of(elapsed)].fetch_add(1, Ordering::Relaxed);
+        if let Some(error) = error {
+            self.errors[error_kind(error)].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn snapshot(&self) -> MetricsSnapshot {
+        let load = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        MetricsSnapshot {
+            count: load(&self.count),
+            total_duration: Duration::from_nanos(load(&self.total_nanos)),
+            duration_buckets: std::array::from_fn(|i| load(&self.buckets[i])),
+            errors: ErrorCounts {
+                invalid_entity_type_name: load(&self.errors[0]),
+                entity_creation_failed: load(&self.errors[1]),
+                unsupported_type: load(&self.errors[2]),
+                invalid_attribute_value: load(&self.errors[3]),
+                policy_parse_error: load(&self.errors[4]),
+                policy_add_error: load(&self.errors[5]),
+                missing_required_attribute: load(&self.errors[6]),
+            },
+        }
+    }
+
+    fn reset(&self) {
+        let counters = [&self.count, &self.total_nanos]
+            .into_iter()
+            .chain(&self.buckets)
+            .chain(&self.errors);
+        for counter in counters {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+static ENTITY: OperationMetrics = OperationMetrics::new();
+static ATTRIBUTE_VALUE: OperationMetrics = OperationMetrics::new();
+
+fn metrics_of(operation: Operation) -> &'static OperationMetrics {
+    match operation {
+        Operation::Entity => &ENTITY,
+        Operation::AttributeValue => &ATTRIBUTE_VALUE,
+    }
+}
+
+fn bucket_of(elapsed: Duration) -> usize {
+    let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+    DURATION_BUCKETS_MICROS
+        .iter()
+        .position(|bound| micros <= *bound)
+        .unwrap_or(DURATION_BUCKETS_MICROS.len())
+}
+
+fn error_kind(error: &TranslationError) -> usize {
+    match error {
+        TranslationError::InvalidEntityTypeName(_) => 0,
+        TranslationError::EntityCreationFailed(_) => 1,
+        TranslationError::UnsupportedType(_) => 2,
+        TranslationError::InvalidAttributeValue(_) => 3,
+        TranslationError::PolicyParseError(_) => 4,
+        TranslationError::PolicyAddError(_) => 5,
+        TranslationError::MissingRequiredAttribute(_) => 6,
+    }
+}
+
+/// Run one translation of `operation`, recording its duration and outcome
+pub(super) fn record<T>(
+    operation: Operation,
+    translate: impl FnOnce() -> Result<T, TranslationError>,
+) -> Result<T, TranslationError> {
+    let started = Instant::now();
+    let result = translate();
+    metrics_of(operation).record(started.elapsed(), result.as_ref().err());
+    result
+}
+
+/// Current metrics of `operation`
+pub fn snapshot(operation: Operation) -> MetricsSnapshot {
+    metrics_of(operation).snapshot()
+}
+
+/// Set every metric of `operation` back to zero
+pub fn reset(operation: Operation) {
+    metrics_of(operation).reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_fall_in_the_first_bucket_that_holds_them() {
+        assert_eq!(bucket_of(Duration::from_nanos(500)), 0);
+        assert_eq!(bucket_of(Duration::from_micros(5)), 1);
+        assert_eq!(bucket_of(Duration::from_micros(75)), 4);
+        assert_eq!(bucket_of(Duration::from_secs(1)), BUCKET_COUNT - 1);
+    }
+
+    #[test]
+    fn records_count_duration_and_errors_by_variant() {
+        let metrics = OperationMetrics::new();
+
+        metrics.record(Duration::from_micros(3), None);
+        metrics.record(
+            Duration::from_micros(40),
+            Some(&TranslationError::InvalidAttributeValue("bad".to_string())),
+        );
+        metrics.record(
+            Duration::from_micros(40),
+            Some(&TranslationError::InvalidAttributeValue(
+                "worse".to_string(),
+            )),
+        );
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.count, 3);
+        assert_eq!(snapshot.total_duration, Duration::from_micros(83));
+        assert_eq!(snapshot.duration_buckets[1], 1);
+        assert_eq!(snapshot.duration_buckets[3], 2);
+        assert_eq!(snapshot.errors.invalid_attribute_value, 2);
+        assert_eq!(snapshot.errors.total(), 2);
+        assert!((snapshot.error_rate() - 2.0 / 3.0).abs() < f64::EPSILON);
+
+        metrics.reset();
+        assert_eq!(metrics.snapshot().count, 0);
+        assert_eq!(metrics.snapshot().error_rate(), 0.0);
+    }
+}
@@ -4,6 +4,8 @@
 //! All types are agnostic and do not expose Cedar implementation details.
 
 use kernel::HodeiEntity;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 
 // ============================================================================
@@ -20,8 +22,17 @@ pub struct AuthorizationDecision {
     decision: Decision,
     /// Reason for the decision (for debugging)
     reason: String,
-    /// IDs of policies that determined the decision
+    /// IDs of policies that determined the decision, forbids first, then
+    /// by ID
     determining_policies: Vec<String>,
+    /// Annotations of the determining policies, keyed by policy ID
+    policy_annotations: HashMap<String, HashMap<String, String>>,
+    /// The default that decided, if no policy did
+    default_applied: Option<DefaultDecision>,
+    /// Version of the policy set that served the request
+    policy_set_version: String,
+    /// Schema version the policies were evaluated under
+    schema_version: Option<String>,
 }
 
 impl AuthorizationDecision {
@@ -31,6 +42,10 @@ impl AuthorizationDecision {
             decision: Decision::Allow,
             reason: "Access granted".to_string(),
             determining_policies: Vec::new(),
+            policy_annotations: HashMap::new(),
+            default_applied: None,
+            policy_set_version: String::new(),
+            schema_version: None,
         }
     }
 
@@ -41,6 +56,10 @@ impl AuthorizationDecision {
             decision: Decision::Allow,
             reason,
             determining_policies: Vec::new(),
+            policy_annotations: HashMap::new(),
+            default_applied: None,
+            policy_set_version: String::new(),
+            schema_version: None,
         }
     }
 
@@ -50,6 +69,10 @@ impl AuthorizationDecision {
             decision: Decision::Deny,
             reason: "Access denied".to_string(),
             determining_policies: Vec::new(),
+            policy_annotations: HashMap::new(),
+            default_applied: None,
+            policy_set_version: String::new(),
+            schema_version: None,
         }
     }
 
@@ -60,16 +83,45 @@ impl AuthorizationDecision {
             decision: Decision::Deny,
             reason,
             determining_policies: Vec::new(),
+            policy_annotations: HashMap::new(),
+            default_applied: None,
+            policy_set_version: String::new(),
+            schema_version: None,
         }
     }
 
     /// Add determining policies to the decision
-    #[allow(dead_code)]
     pub fn with_policies(mut self, policies: Vec<String>) -> Self {
         self.determining_policies = policies;
         self
     }
 
+    /// Add the annotations of the determining policies to the decision
+    pub fn with_policy_annotations(
+        mut self,
+        annotations: HashMap<String, HashMap<String, String>>,
+    ) -> Self {
+        self.policy_annotations = annotations;
+        self
+    }
+
+    /// Record that no policy applied and `default` decided
+    pub fn with_default_applied(mut self, default: DefaultDecision) -> Self {
+        self.default_applied = Some(default);
+        self
+    }
+
+    /// Record the policy set and schema versions that served the request
+    pub fn with_versions(
+        mut self,
+        policy_set_version: String,
+        schema_version: Option<String>,
+    ) -> Self {
+        self.policy_set_version = policy_set_version;
+        self.schema_version = schema_version;
+        self
+    }
+
     /// Check if the decision is allow
     pub fn is_allowed(&self) -> bool {
         matches!(self.decision, Decision::Allow)
@@ -88,10 +140,29 @@ impl AuthorizationDecision {
     }
 
     /// Get the determining policies
-    #[allow(dead_code)]
     pub fn determining_policies(&self) -> &[String] {
         &self.determining_policies
     }
+
+    /// Get the annotations of the determining policies, keyed by policy ID
+    pub fn policy_annotations(&self) -> &HashMap<String, HashMap<String, String>> {
+        &self.policy_annotations
+    }
+
+    /// The default that decided, or `None` if a policy decided
+    pub fn default_applied(&self) -> Option<DefaultDecision> {
+        self.default_applied
+    }
+
+    /// Version of the policy set that served the request
+    pub fn policy_set_version(&self) -> &str {
+        &self.policy_set_version
+    }
+
+    /// Schema version the policies were evaluated under
+    pub fn schema_version(&self) -> Option<&str> {
+        self.schema_version.as_deref()
+    }
 }
 
 /// Simple decision enum
@@ -103,6 +174,24 @@ pub enum Decision {
     Deny,
 }
 
+/// Decision an engine returns when no policy applies to a request
+///
+/// The default is [`Deny`](Self::Deny), Cedar's own behaviour. Changing it
+/// is an advanced and dangerous setting, see [`Allow`](Self::Allow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DefaultDecision {
+    /// Deny unless a policy permits (deny-by-default)
+    #[default]
+    Deny,
+    /// **Dangerous.** Allow unless a policy forbids (allow-by-default)
+    ///
+    /// Every request no policy mentions is allowed, including requests for
+    /// actions and resources nobody wrote a policy for yet. Only meant for
+    /// internal tooling whose policies are exclusively `forbid` rules.
+    Allow,
+}
+
 /// Authorization Engine Error
 ///
 /// Represents all possible errors that can occur during authorization.
@@ -128,6 +217,161 @@ pub enum EngineError {
     /// Internal error (should not happen)
     #[error("Internal error: {0}")]
     InternalError(String),
+
+    /// An evaluation limit was exceeded; nothing was evaluated
+    #[error("Evaluation limit exceeded: {limit} is {observed}, at most {allowed} allowed")]
+    LimitExceeded {
+        /// The limit that was hit
+        limit: EvaluationLimit,
+        /// The value found in the input
+        observed: usize,
+        /// The configured maximum
+        allowed: usize,
+    },
+
+    /// The request context is above a context limit; nothing was evaluated
+    #[error("Context too large: {limit} is {observed}, at most {allowed} allowed")]
+    ContextTooLarge {
+        /// The context limit that was hit
+        limit: EvaluationLimit,
+        /// The value found in the context
+        observed: usize,
+        /// The configured maximum
+        allowed: usize,
+    },
+
+    /// A snapshot could not be loaded; the engine state was left unchanged
+    #[error("Invalid snapshot: {0}")]
+    InvalidSnapshot(String),
+}
+
+// ============================================================================
+// Evaluation Limits
+// ============================================================================
+
+/// Guardrails on the size of an evaluation
+///
+/// Cedar evaluation time grows with the policy set, the entity store and the
+/// shape of the context, so the engine refuses inputs above these limits with
+/// [`EngineError::LimitExceeded`], or [`EngineError::ContextTooLarge`] for the
+/// context limits, instead of evaluating them. The defaults are far above what
+/// normal requests use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngineLimits {
+    /// Maximum number of policies loaded at once
+    pub max_policies: usize,
+    /// Maximum number of entities registered at once
+    pub max_entities: usize,
+    /// Maximum nesting depth of the request context; a flat context has depth 1
+    pub max_context_depth: usize,
+    /// Maximum number of record keys in the request context, at any depth
+    pub max_context_keys: usize,
+    /// Maximum size of the request context serialized as JSON, in bytes
+    pub max_context_bytes: usize,
+    /// Maximum number of principal/resource pairs in one matrix evaluation
+    pub max_matrix_cells: usize,
+}
+
+impl EngineLimits {
+    pub const DEFAULT_MAX_POLICIES: usize = 10_000;
+    pub const DEFAULT_MAX_ENTITIES: usize = 100_000;
+    pub const DEFAULT_MAX_CONTEXT_DEPTH: usize = 32;
+    pub const DEFAULT_MAX_CONTEXT_KEYS: usize = 10_000;
+    pub const DEFAULT_MAX_CONTEXT_BYTES: usize = 1024 * 1024;
+    pub const DEFAULT_MAX_MATRIX_CELLS: usize = 10_000;
+
+    /// Fail with [`EngineError::LimitExceeded`] (or
+    /// [`EngineError::ContextTooLarge`] for a context limit) if `observed` is
+    /// above the maximum configured for `limit`
+    pub fn check(&self, limit: EvaluationLimit, observed: usize) -> Result<(), EngineError> {
+        let allowed = match limit {
+            EvaluationLimit::Policies => self.max_policies,
+            EvaluationLimit::Entities => self.max_entities,
+            EvaluationLimit::ContextDepth => self.max_context_depth,
+            EvaluationLimit::ContextKeys => self.max_context_keys,
+            EvaluationLimit::ContextBytes => self.max_context_bytes,
+            EvaluationLimit::MatrixCells => self.max_matrix_cells,
+        };
+        if observed <= allowed {
+            return Ok(());
+        }
+        if limit.is_context_limit() {
+            Err(EngineError::ContextTooLarge {
+                limit,
+                observed,
+                allowed,
+            })
+        } else {
+            Err(EngineError::LimitExceeded {
+                limit,
+                observed,
+                allowed,
+            })
+        }
+    }
+
+    /// Check the request context against the key count, depth and size limits
+    ///
+    /// The serialized size is only measured once the cheaper checks pass.
+    pub fn check_context(&self, request: &EngineRequest<'_>) -> Result<(), EngineError> {
+        self.check(EvaluationLimit::ContextKeys, request.context_key_count())?;
+        self.check(EvaluationLimit::ContextDepth, request.context_depth())?;
+        self.check(EvaluationLimit::ContextBytes, request.context_size_bytes())
+    }
+}
+
+impl Default for EngineLimits {
+    fn default() -> Self {
+        Self {
+            max_policies: Self::DEFAULT_MAX_POLICIES,
+            max_entities: Self::DEFAULT_MAX_ENTITIES,
+            max_context_depth: Self::DEFAULT_MAX_CONTEXT_DEPTH,
+            max_context_keys: Self::DEFAULT_MAX_CONTEXT_KEYS,
+            max_context_bytes: Self::DEFAULT_MAX_CONTEXT_BYTES,
+            max_matrix_cells: Self::DEFAULT_MAX_MATRIX_CELLS,
+        }
+    }
+}
+
+/// The quantities bounded by [`EngineLimits`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvaluationLimit {
+    /// Number of policies in the set
+    Policies,
+    /// Number of registered entities
+    Entities,
+    /// Nesting depth of the request context
+    ContextDepth,
+    /// Number of record keys in the request context
+    ContextKeys,
+    /// Serialized size of the request context
+    ContextBytes,
+    /// Number of principal/resource pairs in a matrix evaluation
+    MatrixCells,
+}
+
+impl EvaluationLimit {
+    /// Whether the limit bounds the request context
+    pub fn is_context_limit(&self) -> bool {
+        matches!(
+            self,
+            Self::ContextDepth | Self::ContextKeys | Self::ContextBytes
+        )
+    }
+}
+
+impl std::fmt::Display for EvaluationLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Policies => "policy count",
+            Self::Entities => "entity count",
+            Self::ContextDepth => "context depth",
+            Self::ContextKeys => "context key count",
+            Self::ContextBytes => "context size in bytes",
+            Self::MatrixCells => "matrix cell count",
+        };
+        f.write_str(name)
+    }
 }
 
 // ============================================================================
@@ -170,6 +414,62 @@ impl<'a> EngineRequest<'a> {
         self
     }
 
+    /// Nesting depth of the context: 0 when empty, 1 for scalar values only,
+    /// plus one for each level of nested records or sets
+    pub fn context_depth(&self) -> usize {
+        fn depth(value: &serde_json::Value) -> usize {
+            match value {
+                serde_json::Value::Array(items) => 1 + items.iter().map(depth).max().unwrap_or(0),
+                serde_json::Value::Object(fields) => {
+                    1 + fields.values().map(depth).max().unwrap_or(0)
+                }
+                _ => 0,
+            }
+        }
+
+        self.context
+            .values()
+            .map(|value| 1 + depth(value))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Number of record keys in the context, counting nested records at any depth
+    pub fn context_key_count(&self) -> usize {
+        fn keys(value: &serde_json::Value) -> usize {
+            match value {
+                serde_json::Value::Array(items) => items.iter().map(keys).sum(),
+                serde_json::Value::Object(fields) => {
+                    fields.len() + fields.values().map(keys).sum::<usize>()
+                }
+                _ => 0,
+            }
+        }
+
+        self.context.len() + self.context.values().map(keys).sum::<usize>()
+    }
+
+    /// Size of the context serialized as JSON, measured without buffering it
+    pub fn context_size_bytes(&self) -> usize {
+        struct ByteCounter(usize);
+
+        impl std::io::Write for ByteCounter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0 += buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut counter = ByteCounter(0);
+        // Serializing a map of JSON values into a counter cannot fail
+        let _ = serde_json::to_writer(&mut counter, &self.context);
+        counter.0
+    }
+
     /// Get the principal's HRN
     #[allow(dead_code)]
     pub fn principal_hrn(&self) -> &kernel::Hrn {
@@ -191,8 +491,7 @@ impl<'a> EngineRequest<'a> {
 ///
 /// Represents a policy with its ID and content.
 /// The content is stored as a Cedar DSL string.
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PolicyDocument {
     /// Policy identifier
     pub id: String,
@@ -218,6 +517,52 @@ impl PolicyDocument {
     }
 }
 
+// ============================================================================
+// Snapshots
+// ============================================================================
+
+/// Format version written into every [`EngineSnapshot`]
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Serializable copy of everything an engine evaluates with
+///
+/// Loading a snapshot into another engine reproduces its decisions: the
+/// policies are kept exactly as they were loaded, in order and with their
+/// IDs, and the entity store in Cedar's entities JSON format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EngineSnapshot {
+    /// Version of the snapshot format
+    pub format_version: u32,
+    /// Content hash of the policy set, see [`policy_set_version`]
+    pub policy_set_version: String,
+    /// Schema version the engine was evaluating under, if any
+    pub schema_version: Option<String>,
+    /// Decision the engine returned when no policy applied
+    #[serde(default)]
+    pub default_decision: DefaultDecision,
+    /// Loaded policies, in load order
+    pub policies: Vec<PolicyDocument>,
+    /// Registered entities in Cedar's entities JSON format
+    pub entities: serde_json::Value,
+}
+
+/// Version identifying a policy set by its content
+///
+/// The same policies with the same IDs in the same order always get the
+/// same version, so a snapshot's version can be compared with the version
+/// an engine reported while live.
+pub fn policy_set_version(policies: &[PolicyDocument]) -> String {
+    let mut hasher = Sha256::new();
+    for policy in policies {
+        // Length prefixes keep ("ab", "c") and ("a", "bc") apart
+        for part in [&policy.id, &policy.content] {
+            hasher.update((part.len() as u64).to_be_bytes());
+            hasher.update(part.as_bytes());
+        }
+    }
+    format!("sha256:{:x}", hasher.finalize())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -333,4 +678,103 @@ mod tests {
         assert_eq!(policy.id(), "policy1");
         assert_eq!(policy.content(), "permit(principal, action, resource);");
     }
+
+    #[test]
+    fn engine_request_context_depth() {
+        let entity = TestEntity {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "test".to_string(),
+                "123".to_string(),
+                "Entity".to_string(),
+                "test".to_string(),
+            ),
+        };
+        let request = |context: serde_json::Value| {
+            let context = serde_json::from_value(context).unwrap();
+            EngineRequest::new(&entity, "read", &entity)
+                .with_context(context)
+                .context_depth()
+        };
+
+        assert_eq!(request(serde_json::json!({})), 0);
+        assert_eq!(
+            request(serde_json::json!({"ip": "10.0.0.1", "mfa": true})),
+            1
+        );
+        assert_eq!(request(serde_json::json!({"tags": ["a"], "ip": "x"})), 2);
+        assert_eq!(request(serde_json::json!({"a": {"b": [{"c": 1}]}})), 4);
+    }
+
+    #[test]
+    fn oversized_context_reports_observed_size() {
+        let entity = TestEntity {
+            hrn: Hrn::new(
+                "aws".to_string(),
+                "iam".to_string(),
+                "123".to_string(),
+                "User".to_string(),
+                "test".to_string(),
+            ),
+        };
+        let context: HashMap<String, serde_json::Value> = serde_json::from_value(
+            serde_json::json!({"ip": "10.0.0.1", "device": {"os": "linux", "trusted": true}}),
+        )
+        .unwrap();
+        let request = EngineRequest::new(&entity, "read", &entity).with_context(context);
+
+        assert_eq!(request.context_key_count(), 4);
+        let size = request.context_size_bytes();
+        assert_eq!(size, serde_json::to_vec(&request.context).unwrap().len());
+        assert!(EngineLimits::default().check_context(&request).is_ok());
+
+        let keys = EngineLimits {
+            max_context_keys: 3,
+            ..EngineLimits::default()
+        };
+        assert!(matches!(
+            keys.check_context(&request),
+            Err(EngineError::ContextTooLarge {
+                limit: EvaluationLimit::ContextKeys,
+                observed: 4,
+                allowed: 3,
+            })
+        ));
+
+        let bytes = EngineLimits {
+            max_context_bytes: size - 1,
+            ..EngineLimits::default()
+        };
+        match bytes.check_context(&request) {
+            Err(EngineError::ContextTooLarge {
+                limit: EvaluationLimit::ContextBytes,
+                observed,
+                ..
+            }) => assert_eq!(observed, size),
+            other => panic!("Expected ContextTooLarge, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn engine_limits_report_the_limit_hit() {
+        let limits = EngineLimits {
+            max_policies: 2,
+            ..EngineLimits::default()
+        };
+
+        assert!(limits.check(EvaluationLimit::Policies, 2).is_ok());
+        let error = limits.check(EvaluationLimit::Policies, 3).unwrap_err();
+        assert!(matches!(
+            error,
+            EngineError::LimitExceeded {
+                limit: EvaluationLimit::Policies,
+                observed: 3,
+                allowed: 2,
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Evaluation limit exceeded: policy count is 3, at most 2 allowed"
+        );
+    }
 }
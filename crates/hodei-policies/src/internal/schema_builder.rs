@@ -134,5 +134,8 @@ fn attribute_value_to_cedar_type(value: &AttributeValue) -> String {
         }
         AttributeValue::Record(_) => "Record".to_string(),
         AttributeValue::EntityRef(_) => "__cedar::Entity".to_string(),
+        AttributeValue::Decimal(_) => "__cedar::decimal".to_string(),
+        AttributeValue::IpAddr(_) => "__cedar::ipaddr".to_string(),
+        AttributeValue::DateTime(_) => "__cedar::datetime".to_string(),
     }
 }
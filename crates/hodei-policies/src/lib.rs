@@ -19,3 +19,12 @@ pub use api::*;
 // Re-export EngineBuilder for dependency injection purposes
 // This is required for composition roots to wire up the registration use cases
 pub use internal::engine::builder::EngineBuilder;
+
+// Translation counters and duration histograms
+#[cfg(feature = "translator-metrics")]
+pub use internal::engine::translator::metrics as translator_metrics;
+
+// Engine internals and fixtures for the criterion benchmarks
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench;
@@ -0,0 +1,322 @@
+//! Domain events shared across bounded contexts
+//!
+//! An event published by one context and consumed by others is defined here
+//! once, so every subscriber deserializes the same payload for its event
+//! type instead of keeping a private copy that can drift from the publisher.
+
+use crate::application::ports::event_bus::DomainEvent;
+use crate::domain::{Hrn, PrincipalStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Event published when a user or group joins or leaves a group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupMembershipChanged {
+    /// The user or nested group that joined or left
+    pub member_hrn: Hrn,
+    pub group_hrn: Hrn,
+    /// `true` if the member joined, `false` if it left
+    pub joined: bool,
+}
+
+impl GroupMembershipChanged {
+    /// `member_hrn` joined `group_hrn`
+    pub fn joined(member_hrn: Hrn, group_hrn: Hrn) -> Self {
+        Self {
+            member_hrn,
+            group_hrn,
+            joined: true,
+        }
+    }
+
+    /// `member_hrn` left `group_hrn`
+    pub fn left(member_hrn: Hrn, group_hrn: Hrn) -> Self {
+        Self {
+            member_hrn,
+            group_hrn,
+            joined: false,
+        }
+    }
+}
+
+impl DomainEvent for GroupMembershipChanged {
+    fn event_type(&self) -> &'static str {
+        "iam.group.membership_changed"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.group_hrn.to_string())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Group")
+    }
+}
+
+/// Event published when a policy is created or its content changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolicyStored {
+    pub policy_id: String,
+    /// Policy content, in Cedar syntax
+    pub content: String,
+}
+
+impl DomainEvent for PolicyStored {
+    fn event_type(&self) -> &'static str {
+        "iam.policy.stored"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.policy_id.clone())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Policy")
+    }
+}
+
+/// Event published when a policy is deleted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolicyDeleted {
+    pub policy_id: String,
+}
+
+impl DomainEvent for PolicyDeleted {
+    fn event_type(&self) -> &'static str {
+        "iam.policy.deleted"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.policy_id.clone())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Policy")
+    }
+}
+
+/// Event published when a policy is attached to or detached from a user or
+/// group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolicyAttachmentChanged {
+    pub policy_id: String,
+    pub principal_hrn: Hrn,
+    /// `true` if the policy was attached, `false` if it was detached
+    pub attached: bool,
+}
+
+impl DomainEvent for PolicyAttachmentChanged {
+    fn event_type(&self) -> &'static str {
+        "iam.policy.attachment_changed"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.policy_id.clone())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Policy")
+    }
+}
+
+/// Event published when a user's lifecycle status changes
+///
+/// Decisions and policy sets cached for the user were computed under the old
+/// status, so consumers drop them: a suspended user must be denied on the
+/// next request, not once a cache entry expires.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserStatusChanged {
+    pub user_hrn: Hrn,
+    pub previous_status: PrincipalStatus,
+    pub status: PrincipalStatus,
+}
+
+impl DomainEvent for UserStatusChanged {
+    fn event_type(&self) -> &'static str {
+        "iam.user.status_changed"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.user_hrn.to_string())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("User")
+    }
+}
+
+/// A changed attribute, with its values before and after the change
+///
+/// Values are JSON; an attribute that had or has no value is `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttributeChange {
+    pub key: String,
+    #[serde(default)]
+    pub old_value: Option<Value>,
+    #[serde(default)]
+    pub new_value: Option<Value>,
+}
+
+impl AttributeChange {
+    /// The change from `old_value` to `new_value`, if they differ
+    pub fn between(key: &str, old_value: Option<Value>, new_value: Option<Value>) -> Option<Self> {
+        (old_value != new_value).then(|| Self {
+            key: key.to_string(),
+            old_value,
+            new_value,
+        })
+    }
+
+    /// The change between two tag lists, if they hold different tags
+    ///
+    /// Tags are a set to policies, so reordering them is not a change.
+    pub fn between_tags(key: &str, old_tags: &[String], new_tags: &[String]) -> Option<Self> {
+        let mut old_set = old_tags.to_vec();
+        let mut new_set = new_tags.to_vec();
+        old_set.sort();
+        new_set.sort();
+        (old_set != new_set).then(|| Self {
+            key: key.to_string(),
+            old_value: Some(json!(old_tags)),
+            new_value: Some(json!(new_tags)),
+        })
+    }
+
+    /// Tags without duplicates, in the order first given
+    pub fn unique_tags(tags: Vec<String>) -> Vec<String> {
+        let mut unique: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            if !unique.contains(&tag) {
+                unique.push(tag);
+            }
+        }
+        unique
+    }
+}
+
+/// Event published when a user's attributes change
+///
+/// Carries every changed attribute, so caches of attribute-based decisions
+/// can be invalidated for exactly this user and these attributes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserAttributesChanged {
+    pub user_hrn: Hrn,
+    pub changes: Vec<AttributeChange>,
+    /// Timestamp when the attributes changed
+    pub changed_at: DateTime<Utc>,
+}
+
+impl UserAttributesChanged {
+    pub fn new(user_hrn: Hrn, changes: Vec<AttributeChange>) -> Self {
+        Self {
+            user_hrn,
+            changes,
+            changed_at: Utc::now(),
+        }
+    }
+
+    /// Keys of the changed attributes
+    pub fn changed_keys(&self) -> Vec<&str> {
+        self.changes
+            .iter()
+            .map(|change| change.key.as_str())
+            .collect()
+    }
+}
+
+impl DomainEvent for UserAttributesChanged {
+    fn event_type(&self) -> &'static str {
+        "iam.user.attributes_changed"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.user_hrn.to_string())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("User")
+    }
+}
+
+/// Event published when a group's attributes change
+///
+/// Carries every changed attribute, so caches of attribute-based decisions
+/// can be invalidated for exactly this group and these attributes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupAttributesChanged {
+    pub group_hrn: Hrn,
+    pub changes: Vec<AttributeChange>,
+    /// Timestamp when the attributes changed
+    pub changed_at: DateTime<Utc>,
+}
+
+impl GroupAttributesChanged {
+    pub fn new(group_hrn: Hrn, changes: Vec<AttributeChange>) -> Self {
+        Self {
+            group_hrn,
+            changes,
+            changed_at: Utc::now(),
+        }
+    }
+
+    /// Keys of the changed attributes
+    pub fn changed_keys(&self) -> Vec<&str> {
+        self.changes
+            .iter()
+            .map(|change| change.key.as_str())
+            .collect()
+    }
+}
+
+impl DomainEvent for GroupAttributesChanged {
+    fn event_type(&self) -> &'static str {
+        "iam.group.attributes_changed"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.group_hrn.to_string())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Group")
+    }
+}
+
+/// Event published when a new schema version is persisted
+///
+/// Policies stored under the old schema may no longer validate; the IAM
+/// context revalidates them when it receives this event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaChanged {
+    /// Version of the new schema
+    pub schema_version: String,
+    /// Identifier of the stored schema
+    pub schema_id: String,
+    /// Timestamp when the schema was registered
+    pub changed_at: DateTime<Utc>,
+}
+
+impl SchemaChanged {
+    pub fn new(schema_version: impl Into<String>, schema_id: impl Into<String>) -> Self {
+        Self {
+            schema_version: schema_version.into(),
+            schema_id: schema_id.into(),
+            changed_at: Utc::now(),
+        }
+    }
+}
+
+impl DomainEvent for SchemaChanged {
+    fn event_type(&self) -> &'static str {
+        "iam.schema.changed"
+    }
+
+    fn aggregate_id(&self) -> Option<String> {
+        Some(self.schema_id.clone())
+    }
+
+    fn aggregate_type(&self) -> Option<&'static str> {
+        Some("Schema")
+    }
+}
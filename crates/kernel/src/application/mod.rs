@@ -2,7 +2,18 @@
 //!
 //! This module contains application-level abstractions and contracts
 //! that are shared across different bounded contexts.
+pub mod events;
+pub mod observability;
+pub mod pagination;
 pub mod ports;
 
 // Re-export commonly used types
+pub use events::{
+    AttributeChange, GroupAttributesChanged, GroupMembershipChanged, PolicyAttachmentChanged,
+    PolicyDeleted, PolicyStored, SchemaChanged, UserAttributesChanged, UserStatusChanged,
+};
+pub use observability::{
+    CORRELATION_ID_HEADER, Redacted, current_correlation_id, with_correlation_id,
+};
+pub use pagination::{Cursor, Page, PageRequest, PaginationError};
 pub use ports::{UnitOfWork, UnitOfWorkError, UnitOfWorkFactory};
@@ -0,0 +1,80 @@
+//! Tracing conventions shared by every use case
+//!
+//! Each use case's `execute` opens a span named after the use case with
+//! `skip_all`, listing its key identifiers (principal, resource, policy...)
+//! and the request's correlation ID as explicit fields. Major steps
+//! (validation, persistence, event publish, evaluation) get child spans.
+//!
+//! Command payloads are never recorded wholesale: policy content, context
+//! values and personal data either stay out of the span or are recorded
+//! through [`Redacted`], so span attributes are safe to export.
+//!
+//! The correlation ID is set once per request by the HTTP layer with
+//! [`with_correlation_id`] and read back with [`current_correlation_id`]
+//! anywhere inside that request's task.
+
+use std::fmt;
+use std::future::Future;
+
+/// Header carrying the correlation ID across services
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// Run `future` with `correlation_id` as the current correlation ID
+pub async fn with_correlation_id<F: Future>(correlation_id: String, future: F) -> F::Output {
+    CORRELATION_ID.scope(correlation_id, future).await
+}
+
+/// Correlation ID of the request being served, if any
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(Clone::clone).ok()
+}
+
+/// Wrapper that hides a sensitive value in spans and logs
+///
+/// Records that the field was present without exposing it:
+/// `email = %Redacted(&command.email)` shows as `[REDACTED]`.
+#[derive(Clone, Copy)]
+pub struct Redacted<T>(pub T);
+
+impl<T> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl<T> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_correlation_id_is_scoped_to_the_request() {
+        assert_eq!(current_correlation_id(), None);
+
+        let inside = with_correlation_id("corr-1".to_string(), async {
+            tokio::task::yield_now().await;
+            current_correlation_id()
+        })
+        .await;
+
+        assert_eq!(inside, Some("corr-1".to_string()));
+        assert_eq!(current_correlation_id(), None);
+    }
+
+    #[test]
+    fn test_redacted_hides_the_value() {
+        let email = Redacted("alice@example.com");
+
+        assert_eq!(email.to_string(), "[REDACTED]");
+        assert_eq!(format!("{:?}", email), "[REDACTED]");
+    }
+}
@@ -0,0 +1,322 @@
+//! Shared pagination types
+//!
+//! Every bounded context pages its listings with [`PageRequest`] and returns a
+//! [`Page`], so clients see the same semantics everywhere. Cursors are opaque,
+//! URL-safe strings that encode either an offset or the key of the last item
+//! returned; callers should pass them back unchanged.
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Page size used when the client doesn't ask for one
+pub const DEFAULT_PAGE_LIMIT: usize = 50;
+
+/// Largest page size a client may request
+pub const MAX_PAGE_LIMIT: usize = 1000;
+
+const OFFSET_PREFIX: &str = "o:";
+const KEY_PREFIX: &str = "k:";
+
+/// Error types for pagination
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PaginationError {
+    #[error("Invalid page cursor: {0}")]
+    InvalidCursor(String),
+}
+
+/// Decoded form of a page cursor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cursor {
+    /// Skip this many items (offset-style paging)
+    Offset(usize),
+    /// Resume after the item with this key (cursor-style paging)
+    After(String),
+}
+
+impl Cursor {
+    /// Encode as an opaque, URL-safe string
+    pub fn encode(&self) -> String {
+        let raw = match self {
+            Cursor::Offset(offset) => format!("{}{}", OFFSET_PREFIX, offset),
+            Cursor::After(key) => format!("{}{}", KEY_PREFIX, key),
+        };
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    /// Decode a cursor produced by [`Cursor::encode`]
+    pub fn decode(encoded: &str) -> Result<Self, PaginationError> {
+        let invalid = || PaginationError::InvalidCursor(encoded.to_string());
+
+        let bytes = URL_SAFE_NO_PAD.decode(encoded).map_err(|_| invalid())?;
+        let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+        if let Some(offset) = raw.strip_prefix(OFFSET_PREFIX) {
+            return offset.parse().map(Cursor::Offset).map_err(|_| invalid());
+        }
+        if let Some(key) = raw.strip_prefix(KEY_PREFIX) {
+            return Ok(Cursor::After(key.to_string()));
+        }
+        Err(invalid())
+    }
+}
+
+/// A client's request for one page of results
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageRequest {
+    /// Maximum number of items to return
+    pub limit: usize,
+    /// Cursor returned as `next_cursor` by the previous page; `None` for the first page
+    pub cursor: Option<String>,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self::first(DEFAULT_PAGE_LIMIT)
+    }
+}
+
+impl PageRequest {
+    /// Request the first page; `limit` is clamped to `1..=MAX_PAGE_LIMIT`
+    pub fn first(limit: usize) -> Self {
+        Self {
+            limit: limit.clamp(1, MAX_PAGE_LIMIT),
+            cursor: None,
+        }
+    }
+
+    /// Request the page that follows `cursor`
+    pub fn after(limit: usize, cursor: impl Into<String>) -> Self {
+        Self {
+            cursor: Some(cursor.into()),
+            ..Self::first(limit)
+        }
+    }
+
+    /// Limit clamped to `1..=MAX_PAGE_LIMIT`, whatever was deserialized
+    pub fn effective_limit(&self) -> usize {
+        self.limit.clamp(1, MAX_PAGE_LIMIT)
+    }
+
+    /// Decoded cursor, or `None` for the first page
+    pub fn decoded_cursor(&self) -> Result<Option<Cursor>, PaginationError> {
+        self.cursor.as_deref().map(Cursor::decode).transpose()
+    }
+
+    /// Number of items to skip for offset-style stores
+    ///
+    /// Key cursors can't be turned into an offset and are rejected.
+    pub fn offset(&self) -> Result<usize, PaginationError> {
+        match self.decoded_cursor()? {
+            None => Ok(0),
+            Some(Cursor::Offset(offset)) => Ok(offset),
+            Some(Cursor::After(_)) => Err(PaginationError::InvalidCursor(
+                "key cursor used where an offset was expected".to_string(),
+            )),
+        }
+    }
+}
+
+/// One page of results
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Total number of items across all pages, when the store can count cheaply
+    pub total: Option<u64>,
+    /// Cursor for the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, total: Option<u64>, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            total,
+            next_cursor,
+        }
+    }
+
+    /// A page with no items and no further pages
+    pub fn empty() -> Self {
+        Self::new(Vec::new(), Some(0), None)
+    }
+
+    /// Whether another page can be requested
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    /// Convert the items while keeping the paging information
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+impl<T: Clone> Page<T> {
+    /// Page through an in-memory, stably ordered slice
+    ///
+    /// `key_of` extracts the key written into the next cursor. Both cursor
+    /// styles are accepted on input: offsets skip items, keys resume after the
+    /// matching item. A key that no longer matches any item is rejected.
+    pub fn from_slice<K>(
+        items: &[T],
+        request: &PageRequest,
+        key_of: impl Fn(&T) -> K,
+    ) -> Result<Self, PaginationError>
+    where
+        K: ToString,
+    {
+        let start = match request.decoded_cursor()? {
+            None => 0,
+            Some(Cursor::Offset(offset)) => offset.min(items.len()),
+            Some(Cursor::After(key)) => items
+                .iter()
+                .position(|item| key_of(item).to_string() == key)
+                .map(|index| index + 1)
+                .ok_or_else(|| {
+                    PaginationError::InvalidCursor(format!("no item with key '{}'", key))
+                })?,
+        };
+
+        let end = start
+            .saturating_add(request.effective_limit())
+            .min(items.len());
+        let page = &items[start..end];
+        let next_cursor = (end < items.len())
+            .then(|| page.last())
+            .flatten()
+            .map(|last| Cursor::After(key_of(last).to_string()).encode());
+
+        Ok(Self::new(
+            page.to_vec(),
+            Some(items.len() as u64),
+            next_cursor,
+        ))
+    }
+
+    /// Page through an in-memory slice using offset cursors
+    pub fn from_slice_with_offsets(
+        items: &[T],
+        request: &PageRequest,
+    ) -> Result<Self, PaginationError> {
+        let start = request.offset()?.min(items.len());
+        let end = start
+            .saturating_add(request.effective_limit())
+            .min(items.len());
+        let next_cursor = (end < items.len()).then(|| Cursor::Offset(end).encode());
+
+        Ok(Self::new(
+            items[start..end].to_vec(),
+            Some(items.len() as u64),
+            next_cursor,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Item {
+        id: String,
+    }
+
+    fn items(count: usize) -> Vec<Item> {
+        (0..count)
+            .map(|i| Item {
+                id: format!("item-{}", i),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cursor_round_trips_and_is_url_safe() {
+        for cursor in [
+            Cursor::Offset(42),
+            Cursor::After("hrn:hodei:iam::acme:user/a b".to_string()),
+        ] {
+            let encoded = cursor.encode();
+            assert!(
+                encoded
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            );
+            assert_eq!(Cursor::decode(&encoded).unwrap(), cursor);
+        }
+    }
+
+    #[test]
+    fn rejects_garbage_cursors() {
+        assert!(matches!(
+            Cursor::decode("not base64!"),
+            Err(PaginationError::InvalidCursor(_))
+        ));
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode("x:1")).is_err());
+        assert!(Cursor::decode(&URL_SAFE_NO_PAD.encode("o:abc")).is_err());
+    }
+
+    #[test]
+    fn walks_all_pages_with_key_cursors() {
+        let items = items(5);
+        let mut request = PageRequest::first(2);
+        let mut seen = Vec::new();
+
+        loop {
+            let page = Page::from_slice(&items, &request, |item| item.id.clone()).unwrap();
+            assert_eq!(page.total, Some(5));
+            seen.extend(page.items.iter().map(|item| item.id.clone()));
+            match page.next_cursor {
+                Some(cursor) => request = PageRequest::after(2, cursor),
+                None => break,
+            }
+        }
+
+        assert_eq!(
+            seen,
+            items.iter().map(|item| item.id.clone()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn accepts_offset_cursors() {
+        let items = items(5);
+
+        let first = Page::from_slice_with_offsets(&items, &PageRequest::first(3)).unwrap();
+        assert_eq!(first.items.len(), 3);
+
+        let request = PageRequest::after(3, first.next_cursor.unwrap());
+        let second = Page::from_slice(&items, &request, |item| item.id.clone()).unwrap();
+        assert_eq!(second.items, items[3..].to_vec());
+        assert!(!second.has_more());
+    }
+
+    #[test]
+    fn unknown_key_cursor_is_an_error() {
+        let request = PageRequest::after(2, Cursor::After("missing".to_string()).encode());
+        let result = Page::from_slice(&items(3), &request, |item| item.id.clone());
+        assert!(matches!(result, Err(PaginationError::InvalidCursor(_))));
+    }
+
+    #[test]
+    fn limit_is_clamped() {
+        assert_eq!(PageRequest::first(0).limit, 1);
+        assert_eq!(PageRequest::first(usize::MAX).limit, MAX_PAGE_LIMIT);
+
+        let deserialized: PageRequest =
+            serde_json::from_str(r#"{"limit": 0, "cursor": null}"#).unwrap();
+        assert_eq!(deserialized.effective_limit(), 1);
+    }
+
+    #[test]
+    fn map_keeps_paging_information() {
+        let page = Page::new(vec![1, 2], Some(10), Some("next".to_string())).map(|n| n * 10);
+        assert_eq!(page.items, vec![10, 20]);
+        assert_eq!(page.total, Some(10));
+        assert!(page.has_more());
+    }
+}
@@ -1,6 +1,7 @@
-use crate::domain::Hrn;
+use crate::domain::{CrossTenantAccess, Hrn, HrnVisitor, PrincipalStatus, TenantScoped};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use thiserror::Error;
 
 /// Request para evaluación de políticas
@@ -14,6 +15,46 @@ pub struct EvaluationRequest {
     pub principal_hrn: Hrn,
     pub action_name: String,
     pub resource_hrn: Hrn,
+    /// Ancestors of the resource (the containers it lives in, transitively)
+    ///
+    /// Evaluators register them as Cedar parents of the resource so that
+    /// `resource in Type::"id"` matches children of a container.
+    pub resource_ancestors: Vec<Hrn>,
+    /// Attributes exposed to policies as `context.*`
+    ///
+    /// Filled once per evaluation so that every evaluator sees the same values
+    /// (notably `current_time`).
+    pub context: HashMap<String, serde_json::Value>,
+}
+
+impl EvaluationRequest {
+    pub fn new(principal_hrn: Hrn, action_name: impl Into<String>, resource_hrn: Hrn) -> Self {
+        Self {
+            principal_hrn,
+            action_name: action_name.into(),
+            resource_hrn,
+            resource_ancestors: Vec::new(),
+            context: HashMap::new(),
+        }
+    }
+
+    pub fn with_resource_ancestors(mut self, ancestors: Vec<Hrn>) -> Self {
+        self.resource_ancestors = ancestors;
+        self
+    }
+
+    pub fn with_context(mut self, context: HashMap<String, serde_json::Value>) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+impl TenantScoped for EvaluationRequest {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(&self.principal_hrn);
+        visitor.hrn(&self.resource_hrn);
+        visitor.nested(&self.resource_ancestors);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +64,18 @@ pub struct EvaluationDecision {
     pub resource_hrn: Hrn,
     pub decision: bool,
     pub reason: String,
+    /// Identifiers of the policies that determined the decision
+    #[serde(default)]
+    pub determining_policies: Vec<String>,
+    /// Annotations of the determining policies, keyed by policy identifier
+    #[serde(default)]
+    pub policy_annotations: HashMap<String, HashMap<String, String>>,
+    /// Lifecycle status of the principal
+    ///
+    /// A suspended or deactivated principal is denied without evaluating
+    /// policies; this lets callers tell that apart from a policy denial.
+    #[serde(default)]
+    pub principal_status: PrincipalStatus,
 }
 
 #[derive(Debug, Error)]
@@ -33,6 +86,8 @@ pub enum AuthorizationError {
     PolicyNotFound,
     #[error("Invalid policy format")]
     InvalidPolicyFormat,
+    #[error(transparent)]
+    CrossTenantAccess(#[from] CrossTenantAccess),
 }
 
 #[async_trait]
@@ -0,0 +1,142 @@
+//! Distributed lock abstraction for operations that must run on one instance
+//!
+//! ## Contract
+//!
+//! - **Acquire with TTL**: `try_acquire` grants a [`LockLease`] only if the
+//!   lock is free or its previous lease has expired. Contention is not an
+//!   error: the caller gets `None` and should skip or retry later.
+//! - **Auto-expiry**: a lease is valid until `expires_at`. A holder that
+//!   crashes never releases, so the lock frees itself once the TTL passes.
+//! - **Renew**: long-running holders must call `renew` before the lease
+//!   expires. Renewing an expired or superseded lease fails with
+//!   [`DistributedLockError::NotHeld`]; the holder must stop working.
+//! - **Release**: frees the lock early. Releasing a lease that is no longer
+//!   held is a no-op, so it is always safe in cleanup paths.
+//! - **Fencing token**: every grant carries a [`FencingToken`] that is
+//!   strictly greater than any token previously issued for the same lock.
+//!   Expiry alone cannot stop a holder that paused (GC, network partition)
+//!   past its TTL, so resources written under the lock should remember the
+//!   highest token they accepted and reject writes carrying a lower one.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Error types for distributed lock operations
+#[derive(Debug, Error)]
+pub enum DistributedLockError {
+    #[error("Lock '{0}' is not held by this lease")]
+    NotHeld(String),
+
+    #[error("Stale fencing token {presented}, lock '{lock}' already accepted {current}")]
+    StaleToken {
+        lock: String,
+        presented: FencingToken,
+        current: FencingToken,
+    },
+
+    #[error("Lock backend error: {0}")]
+    Backend(String),
+}
+
+/// Monotonic token issued with each lease of a lock
+///
+/// Tokens of the same lock are totally ordered: a later grant always
+/// carries a greater token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FencingToken(pub u64);
+
+impl FencingToken {
+    /// Reject `self` if a newer token was already accepted for `lock`
+    ///
+    /// Resources guarded by the lock call this with the highest token they
+    /// have accepted before applying a write.
+    pub fn check_against(
+        self,
+        lock: &str,
+        highest_accepted: Option<FencingToken>,
+    ) -> Result<(), DistributedLockError> {
+        match highest_accepted {
+            Some(current) if current > self => Err(DistributedLockError::StaleToken {
+                lock: lock.to_string(),
+                presented: self,
+                current,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for FencingToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Proof of holding a lock until `expires_at`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockLease {
+    /// Name of the lock (e.g. "search.index-optimizer")
+    pub lock_name: String,
+
+    /// Identifier of the holder (e.g. the instance ID)
+    pub holder_id: String,
+
+    /// Token to present to resources written under the lock
+    pub fencing_token: FencingToken,
+
+    /// When the lease lapses unless renewed
+    pub expires_at: DateTime<Utc>,
+}
+
+impl LockLease {
+    /// Whether the lease has lapsed at `now`
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Coordination primitive guarding single-instance operations
+#[async_trait]
+pub trait DistributedLock: Send + Sync {
+    /// Acquire `lock_name` for `ttl`, or `None` if another holder has a live lease
+    async fn try_acquire(
+        &self,
+        lock_name: &str,
+        holder_id: &str,
+        ttl: Duration,
+    ) -> Result<Option<LockLease>, DistributedLockError>;
+
+    /// Extend a live lease by `ttl` from now, keeping its fencing token
+    async fn renew(
+        &self,
+        lease: &LockLease,
+        ttl: Duration,
+    ) -> Result<LockLease, DistributedLockError>;
+
+    /// Release a lease; a no-op if it is no longer held
+    async fn release(&self, lease: &LockLease) -> Result<(), DistributedLockError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_older_fencing_token_is_rejected() {
+        let stale = FencingToken(3);
+
+        assert!(stale.check_against("outbox-relay", None).is_ok());
+        assert!(
+            stale
+                .check_against("outbox-relay", Some(FencingToken(3)))
+                .is_ok()
+        );
+        assert!(matches!(
+            stale.check_against("outbox-relay", Some(FencingToken(4))),
+            Err(DistributedLockError::StaleToken { current, .. }) if current == FencingToken(4)
+        ));
+    }
+}
@@ -22,10 +22,31 @@ pub trait DomainEvent:
     /// Returns the event type identifier for routing and filtering
     fn event_type(&self) -> &'static str;
 
+    /// Current version of this event's serialized shape
+    ///
+    /// Bump it whenever the payload changes incompatibly and register an
+    /// upcaster from the previous version in the `DomainEventRegistry`, so
+    /// events persisted with the old shape can still be read.
+    const SCHEMA_VERSION: u32 = INITIAL_SCHEMA_VERSION;
+
     /// Returns the aggregate ID that this event relates to (optional)
     fn aggregate_id(&self) -> Option<String> {
         None
     }
+
+    /// Returns the type of the aggregate this event relates to (e.g. "User")
+    ///
+    /// System events that belong to no aggregate keep the default `None`.
+    fn aggregate_type(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Schema version of events that never declared one
+pub const INITIAL_SCHEMA_VERSION: u32 = 1;
+
+pub(crate) fn initial_schema_version() -> u32 {
+    INITIAL_SCHEMA_VERSION
 }
 
 /// Envelope wrapper for domain events with metadata.
@@ -41,6 +62,11 @@ pub struct EventEnvelope<T: DomainEvent> {
     /// Unique identifier for this event instance
     pub event_id: uuid::Uuid,
 
+    /// Version of the event's shape when it was emitted (`T::SCHEMA_VERSION`).
+    /// Envelopes persisted before versioning existed read as version 1.
+    #[serde(default = "initial_schema_version")]
+    pub schema_version: u32,
+
     /// Timestamp when the event occurred
     pub occurred_at: chrono::DateTime<chrono::Utc>,
 
@@ -50,6 +76,15 @@ pub struct EventEnvelope<T: DomainEvent> {
     /// Causation ID - the ID of the command/event that caused this event
     pub causation_id: Option<String>,
 
+    /// Type of the aggregate the event relates to, taken from the event.
+    /// `None` for system events and for envelopes persisted before it existed.
+    #[serde(default)]
+    pub aggregate_type: Option<String>,
+
+    /// ID of the aggregate the event relates to, taken from the event
+    #[serde(default)]
+    pub aggregate_id: Option<String>,
+
     /// Optional metadata (e.g., user context, tenant ID, etc.)
     pub metadata: std::collections::HashMap<String, String>,
 }
@@ -58,8 +93,11 @@ impl<T: DomainEvent> EventEnvelope<T> {
     /// Create a new event envelope with default metadata
     pub fn new(event: T) -> Self {
         Self {
+            aggregate_type: event.aggregate_type().map(str::to_string),
+            aggregate_id: event.aggregate_id(),
             event,
             event_id: uuid::Uuid::new_v4(),
+            schema_version: T::SCHEMA_VERSION,
             occurred_at: chrono::Utc::now(),
             correlation_id: None,
             causation_id: None,
@@ -70,8 +108,11 @@ impl<T: DomainEvent> EventEnvelope<T> {
     /// Create an event envelope with correlation tracking
     pub fn with_correlation(event: T, correlation_id: String) -> Self {
         Self {
+            aggregate_type: event.aggregate_type().map(str::to_string),
+            aggregate_id: event.aggregate_id(),
             event,
             event_id: uuid::Uuid::new_v4(),
+            schema_version: T::SCHEMA_VERSION,
             occurred_at: chrono::Utc::now(),
             correlation_id: Some(correlation_id),
             causation_id: None,
@@ -86,6 +127,81 @@ impl<T: DomainEvent> EventEnvelope<T> {
     }
 }
 
+/// Which events a subscription receives, by the aggregate they relate to
+///
+/// Projections usually care about one aggregate type (or one aggregate);
+/// subscribing with a filter keeps every other event away from them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AggregateFilter {
+    /// Every event, whether it relates to an aggregate or not
+    Any,
+    /// Events of aggregates of this type
+    Type(String),
+    /// Events of a single aggregate
+    Instance {
+        aggregate_type: String,
+        aggregate_id: String,
+    },
+    /// System events, which relate to no aggregate
+    None,
+}
+
+impl AggregateFilter {
+    /// Events of aggregates of `aggregate_type`
+    pub fn of_type(aggregate_type: impl Into<String>) -> Self {
+        Self::Type(aggregate_type.into())
+    }
+
+    /// Events of the aggregate `aggregate_id` of `aggregate_type`
+    pub fn instance(aggregate_type: impl Into<String>, aggregate_id: impl Into<String>) -> Self {
+        Self::Instance {
+            aggregate_type: aggregate_type.into(),
+            aggregate_id: aggregate_id.into(),
+        }
+    }
+
+    /// Whether `envelope` passes the filter
+    pub fn matches<T: DomainEvent>(&self, envelope: &EventEnvelope<T>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Type(wanted) => envelope.aggregate_type.as_ref() == Some(wanted),
+            Self::Instance {
+                aggregate_type,
+                aggregate_id,
+            } => {
+                envelope.aggregate_type.as_ref() == Some(aggregate_type)
+                    && envelope.aggregate_id.as_ref() == Some(aggregate_id)
+            }
+            Self::None => envelope.aggregate_type.is_none(),
+        }
+    }
+}
+
+/// A handler that only sees the events passing an [`AggregateFilter`]
+struct AggregateScoped<H> {
+    filter: AggregateFilter,
+    handler: Arc<H>,
+}
+
+#[async_trait]
+impl<E, H> EventHandler<E> for AggregateScoped<H>
+where
+    E: DomainEvent,
+    H: EventHandler<E>,
+{
+    fn name(&self) -> &'static str {
+        self.handler.name()
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<E>) -> anyhow::Result<()> {
+        self.handler.handle(envelope).await
+    }
+
+    fn should_handle(&self, envelope: &EventEnvelope<E>) -> bool {
+        self.filter.matches(envelope) && self.handler.should_handle(envelope)
+    }
+}
+
 /// Trait for publishing domain events to the event bus.
 ///
 /// Implementations should be non-blocking and handle failures gracefully.
@@ -135,6 +251,106 @@ pub trait EventHandler<E: DomainEvent>: Send + Sync {
     }
 }
 
+/// Settings for an acknowledged (at-least-once) subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AckSubscriptionConfig {
+    /// How long a delivered event may stay unacknowledged before it is
+    /// delivered again
+    pub visibility_timeout: std::time::Duration,
+
+    /// Deliveries attempted before the event is dead-lettered
+    pub max_deliveries: u32,
+}
+
+impl Default for AckSubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            visibility_timeout: std::time::Duration::from_secs(30),
+            max_deliveries: 5,
+        }
+    }
+}
+
+/// Receives acknowledgments for delivered events
+///
+/// Implemented by event bus adapters; handlers use it through [`Delivery::ack`].
+pub trait Acknowledger: Send + Sync {
+    /// Mark the event as durably processed so it is not delivered again
+    fn ack(&self, event_id: uuid::Uuid);
+}
+
+/// An event delivered to an [`AckEventHandler`]
+///
+/// Until [`ack`](Self::ack) is called the bus considers the event in flight
+/// and delivers it again once the visibility timeout expires.
+pub struct Delivery<T: DomainEvent> {
+    pub envelope: EventEnvelope<T>,
+
+    /// 1 on the first delivery, incremented on each redelivery
+    pub delivery_count: u32,
+
+    acknowledger: Arc<dyn Acknowledger>,
+}
+
+impl<T: DomainEvent> Delivery<T> {
+    pub fn new(
+        envelope: EventEnvelope<T>,
+        delivery_count: u32,
+        acknowledger: Arc<dyn Acknowledger>,
+    ) -> Self {
+        Self {
+            envelope,
+            delivery_count,
+            acknowledger,
+        }
+    }
+
+    /// Acknowledge the event; call only after it has been durably processed
+    pub fn ack(&self) {
+        self.acknowledger.ack(self.envelope.event_id);
+    }
+
+    /// Whether this is a redelivery of an event that was not acknowledged
+    pub fn is_redelivery(&self) -> bool {
+        self.delivery_count > 1
+    }
+}
+
+impl<T: DomainEvent> Debug for Delivery<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Delivery")
+            .field("envelope", &self.envelope)
+            .field("delivery_count", &self.delivery_count)
+            .finish()
+    }
+}
+
+/// Handler for acknowledged, at-least-once delivery.
+///
+/// Unlike [`EventHandler`], returning from `handle` does not complete the
+/// event: the handler must call [`Delivery::ack`] once its work is durable.
+/// Events that are not acknowledged (including those whose handler failed)
+/// are delivered again, so handlers must be idempotent.
+#[async_trait]
+pub trait AckEventHandler<E: DomainEvent>: Send + Sync {
+    /// Logical name for this handler (used for tracing and metrics)
+    fn name(&self) -> &'static str;
+
+    /// Handle a delivered event, acknowledging it when done
+    async fn handle(&self, delivery: Delivery<E>) -> anyhow::Result<()>;
+
+    /// Optional: filter to determine if this handler should process the event
+    ///
+    /// Filtered-out events are never tracked and need no acknowledgment.
+    fn should_handle(&self, _envelope: &EventEnvelope<E>) -> bool {
+        true
+    }
+
+    /// Called once an event reached `max_deliveries` without being
+    /// acknowledged (a poison message). The event is not delivered again.
+    async fn dead_letter(&self, _envelope: EventEnvelope<E>, _delivery_count: u32) {}
+}
+
 /// Represents an active subscription to events.
 ///
 /// Subscriptions can be cancelled and provide observability.
@@ -179,6 +395,48 @@ pub trait EventBus: EventPublisher {
         E: DomainEvent,
         H: EventHandler<E> + 'static;
 
+    /// Subscribe a handler to the events of a specific type that pass
+    /// `filter`
+    ///
+    /// Events filtered out never reach the handler. The default
+    /// implementation filters on the envelope's aggregate before calling
+    /// the handler's own `should_handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be established.
+    async fn subscribe_to_aggregate<E, H>(
+        &self,
+        filter: AggregateFilter,
+        handler: Arc<H>,
+    ) -> anyhow::Result<Arc<dyn Subscription>>
+    where
+        E: DomainEvent,
+        H: EventHandler<E> + 'static,
+    {
+        self.subscribe::<E, _>(Arc::new(AggregateScoped { filter, handler }))
+            .await
+    }
+
+    /// Subscribe a handler with acknowledged, at-least-once delivery
+    ///
+    /// Events the handler does not acknowledge within the visibility timeout
+    /// are redelivered with an incremented delivery count, and dead-lettered
+    /// after `max_deliveries` attempts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription cannot be established or the
+    /// configuration is invalid.
+    async fn subscribe_with_ack<E, H>(
+        &self,
+        handler: Arc<H>,
+        config: AckSubscriptionConfig,
+    ) -> anyhow::Result<Arc<dyn Subscription>>
+    where
+        E: DomainEvent,
+        H: AckEventHandler<E> + 'static;
+
     /// Get count of active subscriptions (for monitoring)
     fn subscription_count(&self) -> usize;
 
@@ -250,4 +508,85 @@ mod tests {
         assert_eq!(envelope.metadata.get("user_id").unwrap(), "user-123");
         assert_eq!(envelope.metadata.get("tenant_id").unwrap(), "tenant-456");
     }
+
+    #[test]
+    fn test_event_envelope_schema_version() {
+        let envelope = EventEnvelope::new(TestEvent {
+            message: "test".to_string(),
+        });
+        assert_eq!(envelope.schema_version, TestEvent::SCHEMA_VERSION);
+
+        // Envelopes persisted before versioning carry no schema_version
+        let mut json = serde_json::to_value(&envelope).unwrap();
+        json.as_object_mut().unwrap().remove("schema_version");
+        let legacy: EventEnvelope<TestEvent> = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.schema_version, INITIAL_SCHEMA_VERSION);
+    }
+
+    #[derive(Debug, Clone, Serialize, serde::Deserialize)]
+    struct UserRenamed {
+        user_id: String,
+    }
+
+    impl DomainEvent for UserRenamed {
+        fn event_type(&self) -> &'static str {
+            "test.user.renamed"
+        }
+
+        fn aggregate_id(&self) -> Option<String> {
+            Some(self.user_id.clone())
+        }
+
+        fn aggregate_type(&self) -> Option<&'static str> {
+            Some("User")
+        }
+    }
+
+    fn user_renamed(user_id: &str) -> EventEnvelope<UserRenamed> {
+        EventEnvelope::new(UserRenamed {
+            user_id: user_id.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_event_envelope_takes_aggregate_from_event() {
+        let envelope = user_renamed("user-1");
+        assert_eq!(envelope.aggregate_type.as_deref(), Some("User"));
+        assert_eq!(envelope.aggregate_id.as_deref(), Some("user-1"));
+
+        let system = EventEnvelope::new(TestEvent {
+            message: "test".to_string(),
+        });
+        assert!(system.aggregate_type.is_none());
+        assert!(system.aggregate_id.is_none());
+
+        // Envelopes persisted before aggregates were tagged carry neither
+        let mut json = serde_json::to_value(&envelope).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.remove("aggregate_type");
+        object.remove("aggregate_id");
+        let legacy: EventEnvelope<UserRenamed> = serde_json::from_value(json).unwrap();
+        assert!(legacy.aggregate_type.is_none());
+    }
+
+    #[test]
+    fn test_aggregate_filter_matches() {
+        let alice = user_renamed("alice");
+        let system = EventEnvelope::new(TestEvent {
+            message: "test".to_string(),
+        });
+
+        assert!(AggregateFilter::Any.matches(&alice));
+        assert!(AggregateFilter::Any.matches(&system));
+
+        assert!(AggregateFilter::of_type("User").matches(&alice));
+        assert!(!AggregateFilter::of_type("Group").matches(&alice));
+        assert!(!AggregateFilter::of_type("User").matches(&system));
+
+        assert!(AggregateFilter::instance("User", "alice").matches(&alice));
+        assert!(!AggregateFilter::instance("User", "bob").matches(&alice));
+
+        assert!(AggregateFilter::None.matches(&system));
+        assert!(!AggregateFilter::None.matches(&alice));
+    }
 }
@@ -0,0 +1,215 @@
+//! Serialization formats for event envelopes
+//!
+//! Envelopes can be encoded as JSON, which is easy to inspect, as
+//! MessagePack, which is smaller and faster to produce, or as bincode, the
+//! cheapest of the three but readable only into the concrete event type.
+//! Every encoded envelope starts with a one-byte tag naming its format, so
+//! consumers decode it correctly whatever format the publisher was
+//! configured with, and streams or stores holding several formats stay
+//! readable.
+
+use super::event_bus::{DomainEvent, EventEnvelope};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+/// Error types for event serialization
+#[derive(Debug, Error)]
+pub enum EventSerializationError {
+    #[error("JSON serialization failed: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("MessagePack serialization failed: {0}")]
+    MessagePackEncode(#[from] rmp_serde::encode::Error),
+
+    #[error("MessagePack deserialization failed: {0}")]
+    MessagePackDecode(#[from] rmp_serde::decode::Error),
+
+    #[error("Bincode serialization failed: {0}")]
+    Bincode(#[from] bincode::Error),
+
+    #[error("Encoded envelope is empty")]
+    Empty,
+
+    #[error("Unknown serialization format tag: {0:#04x}")]
+    UnknownFormat(u8),
+}
+
+/// Format event envelopes and payloads are serialized with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SerializationFormat {
+    /// Human-readable; the default
+    #[default]
+    Json,
+    /// Compact binary, for high-throughput publishing
+    MessagePack,
+    /// Binary without field names; needs the concrete type to be read back
+    Bincode,
+}
+
+impl SerializationFormat {
+    /// Tag byte that prefixes envelopes encoded in this format
+    pub fn tag(self) -> u8 {
+        match self {
+            SerializationFormat::Json => 0x01,
+            SerializationFormat::MessagePack => 0x02,
+            SerializationFormat::Bincode => 0x03,
+        }
+    }
+
+    /// The format a tag byte names
+    pub fn from_tag(tag: u8) -> Result<Self, EventSerializationError> {
+        match tag {
+            0x01 => Ok(SerializationFormat::Json),
+            0x02 => Ok(SerializationFormat::MessagePack),
+            0x03 => Ok(SerializationFormat::Bincode),
+            other => Err(EventSerializationError::UnknownFormat(other)),
+        }
+    }
+
+    /// Whether values can be read back without knowing their type
+    ///
+    /// Only self-describing formats can be read into a JSON value, or into
+    /// types with fields that hold one.
+    pub fn is_self_describing(self) -> bool {
+        match self {
+            SerializationFormat::Json | SerializationFormat::MessagePack => true,
+            SerializationFormat::Bincode => false,
+        }
+    }
+
+    /// Serialize a value in this format
+    ///
+    /// MessagePack values are written as maps with field names, so
+    /// `#[serde(default)]` fields keep working when payloads evolve.
+    pub fn serialize<T: Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Vec<u8>, EventSerializationError> {
+        match self {
+            SerializationFormat::Json => Ok(serde_json::to_vec(value)?),
+            SerializationFormat::MessagePack => Ok(rmp_serde::to_vec_named(value)?),
+            SerializationFormat::Bincode => Ok(bincode::serialize(value)?),
+        }
+    }
+
+    /// Deserialize a value written in this format
+    pub fn deserialize<T: DeserializeOwned>(
+        self,
+        bytes: &[u8],
+    ) -> Result<T, EventSerializationError> {
+        match self {
+            SerializationFormat::Json => Ok(serde_json::from_slice(bytes)?),
+            SerializationFormat::MessagePack => Ok(rmp_serde::from_slice(bytes)?),
+            SerializationFormat::Bincode => Ok(bincode::deserialize(bytes)?),
+        }
+    }
+}
+
+/// Encode an envelope in `format`, prefixed with the format's tag
+pub fn encode_envelope<E: DomainEvent>(
+    envelope: &EventEnvelope<E>,
+    format: SerializationFormat,
+) -> Result<Vec<u8>, EventSerializationError> {
+    let mut bytes = vec![format.tag()];
+    bytes.extend(format.serialize(envelope)?);
+    Ok(bytes)
+}
+
+/// Decode an envelope produced by [`encode_envelope`] in any format
+pub fn decode_envelope<E: DomainEvent>(
+    bytes: &[u8],
+) -> Result<EventEnvelope<E>, EventSerializationError> {
+    let (tag, body) = bytes.split_first().ok_or(EventSerializationError::Empty)?;
+    SerializationFormat::from_tag(*tag)?.deserialize(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct PolicyUpdated {
+        policy_id: String,
+        statements: Vec<String>,
+    }
+
+    impl DomainEvent for PolicyUpdated {
+        fn event_type(&self) -> &'static str {
+            "iam.policy.updated"
+        }
+    }
+
+    fn envelope() -> EventEnvelope<PolicyUpdated> {
+        EventEnvelope::with_correlation(
+            PolicyUpdated {
+                policy_id: "policy-1".to_string(),
+                statements: vec!["permit(principal, action, resource);".to_string(); 20],
+            },
+            "corr-1".to_string(),
+        )
+        .with_metadata("tenant_id".to_string(), "acme".to_string())
+    }
+
+    #[test]
+    fn test_round_trip_in_every_format() {
+        let original = envelope();
+        for format in [
+            SerializationFormat::Json,
+            SerializationFormat::MessagePack,
+            SerializationFormat::Bincode,
+        ] {
+            let bytes = encode_envelope(&original, format).unwrap();
+            assert_eq!(bytes[0], format.tag());
+
+            let decoded: EventEnvelope<PolicyUpdated> = decode_envelope(&bytes).unwrap();
+            assert_eq!(decoded.event, original.event);
+            assert_eq!(decoded.event_id, original.event_id);
+            assert_eq!(decoded.occurred_at, original.occurred_at);
+            assert_eq!(decoded.correlation_id, original.correlation_id);
+            assert_eq!(decoded.metadata, original.metadata);
+        }
+    }
+
+    #[test]
+    fn test_message_pack_is_smaller_than_json() {
+        let original = envelope();
+        let json = encode_envelope(&original, SerializationFormat::Json).unwrap();
+        let msgpack = encode_envelope(&original, SerializationFormat::MessagePack).unwrap();
+        assert!(msgpack.len() < json.len());
+    }
+
+    #[test]
+    fn test_bincode_cannot_read_json_values() {
+        let value = serde_json::json!({ "department": "engineering" });
+        let bytes = SerializationFormat::Bincode.serialize(&value).unwrap();
+
+        assert!(!SerializationFormat::Bincode.is_self_describing());
+        assert!(
+            SerializationFormat::Bincode
+                .deserialize::<serde_json::Value>(&bytes)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_decoding_rejects_untagged_bytes() {
+        assert!(matches!(
+            decode_envelope::<PolicyUpdated>(&[]),
+            Err(EventSerializationError::Empty)
+        ));
+        assert!(matches!(
+            decode_envelope::<PolicyUpdated>(b"{}"),
+            Err(EventSerializationError::UnknownFormat(b'{'))
+        ));
+    }
+
+    #[test]
+    fn test_format_names() {
+        assert_eq!(
+            serde_json::to_string(&SerializationFormat::MessagePack).unwrap(),
+            "\"message_pack\""
+        );
+        assert_eq!(SerializationFormat::default(), SerializationFormat::Json);
+    }
+}
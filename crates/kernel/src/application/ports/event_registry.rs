@@ -0,0 +1,516 @@
+//! Registry of domain event types for typed deserialization
+//!
+//! Persisted events (e.g. in the audit log) only keep an event type name and
+//! an untyped JSON payload. Bounded contexts register their event types here
+//! under those names so generic consumers such as projections and replay can
+//! rebuild strongly-typed events without knowing every context's types.
+//!
+//! Payloads persisted with an older schema version are migrated on read by
+//! upcasters, each of which turns version `n` of a payload into version
+//! `n + 1`. The registry chains them up to the event's current
+//! [`DomainEvent::SCHEMA_VERSION`] before deserializing.
+
+use super::event_bus::DomainEvent;
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Error types for the event registry
+#[derive(Debug, Error)]
+pub enum EventRegistryError {
+    /// No event type is registered under this name. Consumers built before the
+    /// event type existed see this and can skip the event.
+    #[error("Unknown event type: {0}")]
+    UnknownEventType(String),
+
+    #[error("Event type already registered: {0}")]
+    AlreadyRegistered(String),
+
+    #[error("Failed to deserialize event '{event_type}': {source}")]
+    Deserialization {
+        event_type: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Upcaster already registered for '{event_type}' version {from_version}")]
+    DuplicateUpcaster {
+        event_type: String,
+        from_version: u32,
+    },
+
+    /// The stored version is newer than the version this consumer knows
+    #[error("Unsupported schema version {version} for '{event_type}' (current: {current})")]
+    UnsupportedSchemaVersion {
+        event_type: String,
+        version: u32,
+        current: u32,
+    },
+
+    #[error("No upcaster registered for '{event_type}' version {from_version}")]
+    MissingUpcaster {
+        event_type: String,
+        from_version: u32,
+    },
+
+    #[error("Failed to upcast '{event_type}' from version {from_version}: {source}")]
+    Upcast {
+        event_type: String,
+        from_version: u32,
+        #[source]
+        source: anyhow::Error,
+    },
+}
+
+/// Object-safe view of a [`DomainEvent`]
+///
+/// `DomainEvent` can't be used as a trait object, so the registry hands out
+/// `Box<dyn AnyDomainEvent>`. Every `DomainEvent` implements this trait;
+/// use `downcast_ref` to get the concrete type back.
+pub trait AnyDomainEvent: Send + Sync + Debug {
+    /// Same as [`DomainEvent::event_type`]
+    fn event_type_name(&self) -> &'static str;
+
+    /// Same as [`DomainEvent::aggregate_id`]
+    fn event_aggregate_id(&self) -> Option<String>;
+
+    /// Same as [`DomainEvent::aggregate_type`]
+    fn event_aggregate_type(&self) -> Option<&'static str>;
+
+    /// Same as [`DomainEvent::SCHEMA_VERSION`]
+    fn event_schema_version(&self) -> u32;
+
+    /// Serialize the event back to JSON
+    fn to_json(&self) -> Result<serde_json::Value, serde_json::Error>;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<E: DomainEvent> AnyDomainEvent for E {
+    fn event_type_name(&self) -> &'static str {
+        self.event_type()
+    }
+
+    fn event_aggregate_id(&self) -> Option<String> {
+        self.aggregate_id()
+    }
+
+    fn event_aggregate_type(&self) -> Option<&'static str> {
+        self.aggregate_type()
+    }
+
+    fn event_schema_version(&self) -> u32 {
+        E::SCHEMA_VERSION
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl dyn AnyDomainEvent {
+    /// Borrow the concrete event if it is of type `E`
+    pub fn downcast_ref<E: DomainEvent>(&self) -> Option<&E> {
+        self.as_any().downcast_ref::<E>()
+    }
+
+    pub fn is<E: DomainEvent>(&self) -> bool {
+        self.as_any().is::<E>()
+    }
+}
+
+type Deserializer = Arc<
+    dyn Fn(serde_json::Value) -> Result<Box<dyn AnyDomainEvent>, serde_json::Error> + Send + Sync,
+>;
+
+type Upcaster = Arc<dyn Fn(serde_json::Value) -> anyhow::Result<serde_json::Value> + Send + Sync>;
+
+#[derive(Clone)]
+struct RegisteredEvent {
+    current_version: u32,
+    deserializer: Deserializer,
+}
+
+/// Maps event type names to deserializers for their concrete types
+#[derive(Clone, Default)]
+pub struct DomainEventRegistry {
+    events: HashMap<String, RegisteredEvent>,
+    /// Upcasters per event type, keyed by the version they upgrade from
+    upcasters: HashMap<String, BTreeMap<u32, Upcaster>>,
+}
+
+impl DomainEventRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `E` under `event_type`, the name stored alongside its payload
+    ///
+    /// Registering the same name twice is an error, so two contexts can't
+    /// silently claim the same event type.
+    pub fn register<E: DomainEvent>(
+        &mut self,
+        event_type: impl Into<String>,
+    ) -> Result<(), EventRegistryError> {
+        let event_type = event_type.into();
+        if self.events.contains_key(&event_type) {
+            return Err(EventRegistryError::AlreadyRegistered(event_type));
+        }
+
+        let deserializer: Deserializer = Arc::new(|json| {
+            serde_json::from_value::<E>(json)
+                .map(|event| Box::new(event) as Box<dyn AnyDomainEvent>)
+        });
+        self.events.insert(
+            event_type,
+            RegisteredEvent {
+                current_version: E::SCHEMA_VERSION,
+                deserializer,
+            },
+        );
+        Ok(())
+    }
+
+    /// Register a migration of `event_type` payloads from `from_version` to
+    /// `from_version + 1`
+    pub fn register_upcaster<F>(
+        &mut self,
+        event_type: impl Into<String>,
+        from_version: u32,
+        upcaster: F,
+    ) -> Result<(), EventRegistryError>
+    where
+        F: Fn(serde_json::Value) -> anyhow::Result<serde_json::Value> + Send + Sync + 'static,
+    {
+        let event_type = event_type.into();
+        let upcasters = self.upcasters.entry(event_type.clone()).or_default();
+        if upcasters.contains_key(&from_version) {
+            return Err(EventRegistryError::DuplicateUpcaster {
+                event_type,
+                from_version,
+            });
+        }
+
+        upcasters.insert(from_version, Arc::new(upcaster));
+        Ok(())
+    }
+
+    /// Builder-style variant of [`register_upcaster`](Self::register_upcaster)
+    pub fn with_upcaster<F>(
+        mut self,
+        event_type: impl Into<String>,
+        from_version: u32,
+        upcaster: F,
+    ) -> Result<Self, EventRegistryError>
+    where
+        F: Fn(serde_json::Value) -> anyhow::Result<serde_json::Value> + Send + Sync + 'static,
+    {
+        self.register_upcaster(event_type, from_version, upcaster)?;
+        Ok(self)
+    }
+
+    /// Builder-style variant of [`register`](Self::register)
+    pub fn with_event<E: DomainEvent>(
+        mut self,
+        event_type: impl Into<String>,
+    ) -> Result<Self, EventRegistryError> {
+        self.register::<E>(event_type)?;
+        Ok(self)
+    }
+
+    pub fn is_registered(&self, event_type: &str) -> bool {
+        self.events.contains_key(event_type)
+    }
+
+    /// Names of all registered event types
+    pub fn event_types(&self) -> impl Iterator<Item = &str> {
+        self.events.keys().map(String::as_str)
+    }
+
+    /// Current schema version of a registered event type
+    pub fn current_version(&self, event_type: &str) -> Option<u32> {
+        self.events
+            .get(event_type)
+            .map(|event| event.current_version)
+    }
+
+    /// Rebuild a typed event from its stored type name and a JSON payload in
+    /// the current schema version
+    pub fn deserialize(
+        &self,
+        event_type: &str,
+        json: serde_json::Value,
+    ) -> Result<Box<dyn AnyDomainEvent>, EventRegistryError> {
+        let registered = self.registered(event_type)?;
+
+        (registered.deserializer)(json).map_err(|source| EventRegistryError::Deserialization {
+            event_type: event_type.to_string(),
+            source,
+        })
+    }
+
+    /// Rebuild a typed event from a payload stored with `schema_version`,
+    /// upcasting it to the current version first
+    pub fn deserialize_versioned(
+        &self,
+        event_type: &str,
+        schema_version: u32,
+        json: serde_json::Value,
+    ) -> Result<Box<dyn AnyDomainEvent>, EventRegistryError> {
+        let json = self.upcast(event_type, schema_version, json)?;
+        self.deserialize(event_type, json)
+    }
+
+    /// Migrate a payload stored with `schema_version` to the current version
+    /// of `event_type` by applying its upcasters in sequence
+    pub fn upcast(
+        &self,
+        event_type: &str,
+        schema_version: u32,
+        mut json: serde_json::Value,
+    ) -> Result<serde_json::Value, EventRegistryError> {
+        let current = self.registered(event_type)?.current_version;
+        if schema_version > current {
+            return Err(EventRegistryError::UnsupportedSchemaVersion {
+                event_type: event_type.to_string(),
+                version: schema_version,
+                current,
+            });
+        }
+
+        let upcasters = self.upcasters.get(event_type);
+        for from_version in schema_version..current {
+            let upcaster = upcasters
+                .and_then(|upcasters| upcasters.get(&from_version))
+                .ok_or_else(|| EventRegistryError::MissingUpcaster {
+                    event_type: event_type.to_string(),
+                    from_version,
+                })?;
+
+            json = upcaster(json).map_err(|source| EventRegistryError::Upcast {
+                event_type: event_type.to_string(),
+                from_version,
+                source,
+            })?;
+        }
+
+        Ok(json)
+    }
+
+    fn registered(&self, event_type: &str) -> Result<&RegisteredEvent, EventRegistryError> {
+        self.events
+            .get(event_type)
+            .ok_or_else(|| EventRegistryError::UnknownEventType(event_type.to_string()))
+    }
+}
+
+impl Debug for DomainEventRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DomainEventRegistry")
+            .field("event_types", &self.events.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct UserCreated {
+        user_id: String,
+    }
+
+    impl DomainEvent for UserCreated {
+        fn event_type(&self) -> &'static str {
+            "iam.user.created"
+        }
+
+        fn aggregate_id(&self) -> Option<String> {
+            Some(self.user_id.clone())
+        }
+    }
+
+    /// Version 3: `name` was split into `first_name`/`last_name` (v2), then
+    /// `email` became mandatory (v3)
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct UserRenamed {
+        first_name: String,
+        last_name: String,
+        email: String,
+    }
+
+    impl DomainEvent for UserRenamed {
+        const SCHEMA_VERSION: u32 = 3;
+
+        fn event_type(&self) -> &'static str {
+            "iam.user.renamed"
+        }
+    }
+
+    fn split_name(mut json: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        let name = json["name"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("missing name"))?
+            .to_string();
+        let (first, last) = name.split_once(' ').unwrap_or((&name, ""));
+        json["first_name"] = json!(first);
+        json["last_name"] = json!(last);
+        Ok(json)
+    }
+
+    fn add_email(mut json: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        json["email"] = json!("unknown@example.com");
+        Ok(json)
+    }
+
+    fn versioned_registry() -> DomainEventRegistry {
+        DomainEventRegistry::new()
+            .with_event::<UserRenamed>("iam.user.renamed")
+            .unwrap()
+            .with_upcaster("iam.user.renamed", 2, add_email)
+            .unwrap()
+            .with_upcaster("iam.user.renamed", 1, split_name)
+            .unwrap()
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct AccountDeleted {
+        account_id: String,
+    }
+
+    impl DomainEvent for AccountDeleted {
+        fn event_type(&self) -> &'static str {
+            "organizations.account.deleted"
+        }
+    }
+
+    fn registry() -> DomainEventRegistry {
+        DomainEventRegistry::new()
+            .with_event::<UserCreated>("iam.user.created")
+            .unwrap()
+            .with_event::<AccountDeleted>("organizations.account.deleted")
+            .unwrap()
+    }
+
+    #[test]
+    fn deserializes_registered_event_types() {
+        let event = registry()
+            .deserialize("iam.user.created", json!({ "user_id": "u-1" }))
+            .unwrap();
+
+        assert_eq!(event.event_type_name(), "iam.user.created");
+        assert_eq!(event.event_aggregate_id(), Some("u-1".to_string()));
+        assert!(!event.is::<AccountDeleted>());
+        assert_eq!(
+            event.downcast_ref::<UserCreated>(),
+            Some(&UserCreated {
+                user_id: "u-1".to_string()
+            })
+        );
+        assert_eq!(event.to_json().unwrap(), json!({ "user_id": "u-1" }));
+    }
+
+    #[test]
+    fn unknown_event_type_is_a_distinct_error() {
+        let result = registry().deserialize("billing.invoice.paid", json!({}));
+        assert!(matches!(
+            result,
+            Err(EventRegistryError::UnknownEventType(name)) if name == "billing.invoice.paid"
+        ));
+    }
+
+    #[test]
+    fn malformed_payload_is_a_deserialization_error() {
+        let result = registry().deserialize("iam.user.created", json!({ "wrong": 1 }));
+        assert!(matches!(
+            result,
+            Err(EventRegistryError::Deserialization { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicate_registrations() {
+        let mut registry = registry();
+        assert!(matches!(
+            registry.register::<AccountDeleted>("iam.user.created"),
+            Err(EventRegistryError::AlreadyRegistered(_))
+        ));
+        assert!(registry.is_registered("iam.user.created"));
+        assert_eq!(registry.event_types().count(), 2);
+    }
+
+    #[test]
+    fn upcasts_old_payloads_in_sequence() {
+        let registry = versioned_registry();
+        assert_eq!(registry.current_version("iam.user.renamed"), Some(3));
+
+        let event = registry
+            .deserialize_versioned("iam.user.renamed", 1, json!({ "name": "Ada Lovelace" }))
+            .unwrap();
+
+        assert_eq!(event.event_schema_version(), 3);
+        assert_eq!(
+            event.downcast_ref::<UserRenamed>(),
+            Some(&UserRenamed {
+                first_name: "Ada".to_string(),
+                last_name: "Lovelace".to_string(),
+                email: "unknown@example.com".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn current_version_payloads_are_not_upcast() {
+        let payload = json!({ "first_name": "Ada", "last_name": "L", "email": "ada@example.com" });
+        let upcast = versioned_registry()
+            .upcast("iam.user.renamed", 3, payload.clone())
+            .unwrap();
+        assert_eq!(upcast, payload);
+    }
+
+    #[test]
+    fn rejects_versions_that_cannot_be_upcast() {
+        let registry = versioned_registry();
+        assert!(matches!(
+            registry.upcast("iam.user.renamed", 4, json!({})),
+            Err(EventRegistryError::UnsupportedSchemaVersion {
+                version: 4,
+                current: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            registry.upcast("iam.user.renamed", 1, json!({})),
+            Err(EventRegistryError::Upcast {
+                from_version: 1,
+                ..
+            })
+        ));
+
+        let mut missing = DomainEventRegistry::new()
+            .with_event::<UserRenamed>("iam.user.renamed")
+            .unwrap()
+            .with_upcaster("iam.user.renamed", 2, add_email)
+            .unwrap();
+        assert!(matches!(
+            missing.upcast("iam.user.renamed", 1, json!({ "name": "Ada" })),
+            Err(EventRegistryError::MissingUpcaster {
+                from_version: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            missing.register_upcaster("iam.user.renamed", 2, add_email),
+            Err(EventRegistryError::DuplicateUpcaster { .. })
+        ));
+    }
+}
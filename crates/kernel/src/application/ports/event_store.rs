@@ -0,0 +1,258 @@
+//! Event store abstraction for persisting published domain events
+//!
+//! The store keeps every envelope in its untyped form, so consumers that
+//! did not exist when an event was published (e.g. a new projection or the
+//! audit log) can read the history back and catch up.
+
+use super::event_bus::{DomainEvent, EventEnvelope};
+use super::event_format::{EventSerializationError, SerializationFormat};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Error types for event store operations
+#[derive(Debug, Error)]
+pub enum EventStoreError {
+    #[error("Failed to serialize event: {0}")]
+    Serialization(#[from] EventSerializationError),
+
+    #[error("Event store error: {0}")]
+    Storage(String),
+}
+
+/// An [`EventEnvelope`] as persisted, with the event kept serialized
+///
+/// Holds everything the envelope carried, plus the type the event reported
+/// when it was published. Each event records the format of its own payload,
+/// so a store may hold events written in different formats. The payload
+/// must be in a self-describing format for [`payload_json`](Self::payload_json)
+/// to read it.
+///
+/// Events stored before payloads were kept serialized hold the event as a
+/// JSON value and no format; they are read as the JSON encoding of that
+/// value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredEvent {
+    /// Unique identifier of the event instance
+    pub event_id: Uuid,
+
+    /// Type of the event (e.g., "iam.user.created")
+    pub event_type: String,
+
+    /// Aggregate ID that the event relates to
+    pub aggregate_id: Option<String>,
+
+    /// Type of the aggregate that the event relates to
+    #[serde(default)]
+    pub aggregate_type: Option<String>,
+
+    /// Schema version of `payload`
+    #[serde(default = "super::event_bus::initial_schema_version")]
+    pub schema_version: u32,
+
+    /// When the event originally occurred
+    pub occurred_at: DateTime<Utc>,
+
+    /// Correlation ID for tracing related events
+    pub correlation_id: Option<String>,
+
+    /// Causation ID - the ID of the command/event that caused this event
+    pub causation_id: Option<String>,
+
+    /// Envelope metadata
+    pub metadata: HashMap<String, String>,
+
+    /// Format of `payload`; events stored before formats existed are JSON
+    #[serde(default)]
+    pub format: SerializationFormat,
+
+    /// The event itself, serialized in `format`
+    #[serde(deserialize_with = "payload_bytes")]
+    pub payload: Vec<u8>,
+}
+
+impl StoredEvent {
+    /// Capture an envelope for storage, serializing the event in `format`
+    pub fn from_envelope<E: DomainEvent>(
+        envelope: &EventEnvelope<E>,
+        format: SerializationFormat,
+    ) -> Result<Self, EventStoreError> {
+        Ok(Self {
+            event_id: envelope.event_id,
+            event_type: envelope.event.event_type().to_string(),
+            aggregate_id: envelope.aggregate_id.clone(),
+            aggregate_type: envelope.aggregate_type.clone(),
+            schema_version: envelope.schema_version,
+            occurred_at: envelope.occurred_at,
+            correlation_id: envelope.correlation_id.clone(),
+            causation_id: envelope.causation_id.clone(),
+            metadata: envelope.metadata.clone(),
+            format,
+            payload: format.serialize(&envelope.event)?,
+        })
+    }
+
+    /// The payload as a JSON value, whatever format it was stored in
+    pub fn payload_json(&self) -> Result<serde_json::Value, EventSerializationError> {
+        self.format.deserialize(&self.payload)
+    }
+}
+
+/// `payload` as written by any version of the store
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredPayload {
+    Serialized(Vec<u8>),
+    /// The event as a JSON value, as stored before formats existed
+    Value(serde_json::Value),
+}
+
+fn payload_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    match StoredPayload::deserialize(deserializer)? {
+        StoredPayload::Serialized(bytes) => Ok(bytes),
+        StoredPayload::Value(value) => serde_json::to_vec(&value).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Append-only log of published events
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Append an event at the end of the log
+    async fn append(&self, event: StoredEvent) -> Result<(), EventStoreError>;
+
+    /// Read up to `limit` events in append order, skipping the first `offset`
+    ///
+    /// An empty batch means the end of the log was reached.
+    async fn read_batch(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<StoredEvent>, EventStoreError>;
+
+    /// Number of events in the log
+    async fn count(&self) -> Result<usize, EventStoreError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct UserCreated {
+        user_id: String,
+    }
+
+    impl DomainEvent for UserCreated {
+        fn event_type(&self) -> &'static str {
+            "iam.user.created"
+        }
+
+        fn aggregate_id(&self) -> Option<String> {
+            Some(self.user_id.clone())
+        }
+
+        fn aggregate_type(&self) -> Option<&'static str> {
+            Some("User")
+        }
+    }
+
+    #[test]
+    fn test_stored_event_keeps_the_envelope() {
+        let envelope = EventEnvelope::with_correlation(
+            UserCreated {
+                user_id: "user-1".to_string(),
+            },
+            "corr-1".to_string(),
+        )
+        .with_metadata("tenant_id".to_string(), "acme".to_string());
+
+        let stored = StoredEvent::from_envelope(&envelope, SerializationFormat::Json).unwrap();
+
+        assert_eq!(stored.event_id, envelope.event_id);
+        assert_eq!(stored.event_type, "iam.user.created");
+        assert_eq!(stored.aggregate_id, Some("user-1".to_string()));
+        assert_eq!(stored.aggregate_type, Some("User".to_string()));
+        assert_eq!(stored.schema_version, envelope.schema_version);
+        assert_eq!(stored.occurred_at, envelope.occurred_at);
+        assert_eq!(stored.correlation_id, Some("corr-1".to_string()));
+        assert_eq!(stored.metadata.get("tenant_id").unwrap(), "acme");
+        assert_eq!(
+            stored.payload_json().unwrap(),
+            serde_json::json!({ "user_id": "user-1" })
+        );
+    }
+
+    #[test]
+    fn test_mixed_formats_read_back_alike() {
+        let envelope = EventEnvelope::new(UserCreated {
+            user_id: "user-1".to_string(),
+        });
+
+        let json = StoredEvent::from_envelope(&envelope, SerializationFormat::Json).unwrap();
+        let msgpack =
+            StoredEvent::from_envelope(&envelope, SerializationFormat::MessagePack).unwrap();
+
+        assert_ne!(json.payload, msgpack.payload);
+        assert_eq!(
+            json.payload_json().unwrap(),
+            msgpack.payload_json().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_events_stored_with_a_json_value_payload_are_read() {
+        // As written by the store before payloads were kept serialized
+        let record = r#"{
+            "event_id": "5b0e1f9c-3a1d-4c8e-9f65-2d7c1a8b4e10",
+            "event_type": "iam.user.created",
+            "aggregate_id": "user-1",
+            "schema_version": 1,
+            "occurred_at": "2026-10-16T15:45:52.123456Z",
+            "correlation_id": "corr-1",
+            "causation_id": null,
+            "metadata": { "tenant_id": "acme" },
+            "payload": { "user_id": "user-1" }
+        }"#;
+
+        let stored: StoredEvent = serde_json::from_str(record).unwrap();
+
+        assert_eq!(stored.event_type, "iam.user.created");
+        assert_eq!(stored.aggregate_type, None);
+        assert_eq!(stored.format, SerializationFormat::Json);
+        assert_eq!(stored.payload, br#"{"user_id":"user-1"}"#.to_vec());
+        assert_eq!(
+            stored.payload_json().unwrap(),
+            serde_json::json!({ "user_id": "user-1" })
+        );
+
+        // Written back in the current layout, it reads the same
+        let rewritten: StoredEvent =
+            serde_json::from_str(&serde_json::to_string(&stored).unwrap()).unwrap();
+        assert_eq!(rewritten, stored);
+    }
+
+    #[test]
+    fn test_events_stored_without_format_are_json() {
+        let json = serde_json::json!({
+            "event_id": uuid::Uuid::new_v4(),
+            "event_type": "iam.user.created",
+            "aggregate_id": "user-1",
+            "occurred_at": Utc::now(),
+            "correlation_id": null,
+            "causation_id": null,
+            "metadata": {},
+            "payload": br#"{"user_id":"user-1"}"#.to_vec(),
+        });
+
+        let stored: StoredEvent = serde_json::from_value(json).unwrap();
+
+        assert_eq!(stored.format, SerializationFormat::Json);
+        assert_eq!(
+            stored.payload_json().unwrap(),
+            serde_json::json!({ "user_id": "user-1" })
+        );
+    }
+}
@@ -4,10 +4,16 @@
 //! the interfaces between the application layer and infrastructure layer.
 pub mod auth_context;
 pub mod authorization;
+pub mod distributed_lock;
 pub mod event_bus;
+pub mod event_format;
+pub mod event_registry;
+pub mod event_store;
 pub mod unit_of_work;
 // Cross-context (shared kernel) ports for IAM and Organizations
 pub mod iam {
+    use crate::domain::policy::HodeiPolicySet;
+    use crate::domain::{HrnVisitor, PrincipalStatus, TenantScoped};
     use async_trait::async_trait;
     use cedar_policy::PolicySet;
     use serde::{Deserialize, Serialize};
@@ -18,11 +24,51 @@ pub mod iam {
         pub principal_hrn: String,
     }
 
+    impl TenantScoped for EffectivePoliciesQuery {
+        fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+            visitor.hrn_str(&self.principal_hrn);
+        }
+    }
+
     /// Result DTO containing the resolved policy set and metadata
     #[derive(Debug, Clone)]
     pub struct EffectivePoliciesResult {
         pub policies: PolicySet,
         pub policy_count: usize,
+        /// Lifecycle status of the principal; only an active principal has
+        /// policies, so an empty set can be told apart from a suspended user
+        pub principal_status: PrincipalStatus,
+    }
+
+    impl EffectivePoliciesResult {
+        /// Build the result from resolved policies, keeping each policy's ID
+        ///
+        /// # Errors
+        ///
+        /// Fails if a policy does not parse or two policies share an ID.
+        pub fn from_policy_set(
+            policies: &HodeiPolicySet,
+        ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+            let mut policy_set = PolicySet::new();
+            for policy in policies.policies() {
+                let id = cedar_policy::PolicyId::new(policy.id().as_str());
+                policy_set.add(cedar_policy::Policy::parse(Some(id), policy.content())?)?;
+            }
+            Ok(Self {
+                policies: policy_set,
+                policy_count: policies.len(),
+                principal_status: PrincipalStatus::Active,
+            })
+        }
+
+        /// Result for a principal whose policies do not apply: no policies
+        pub fn inactive(principal_status: PrincipalStatus) -> Self {
+            Self {
+                policies: PolicySet::new(),
+                policy_count: 0,
+                principal_status,
+            }
+        }
     }
 
     /// Cross-context abstraction to obtain effective identity-based policies.
@@ -37,9 +83,22 @@ pub mod iam {
             query: EffectivePoliciesQuery,
         ) -> Result<EffectivePoliciesResult, Box<dyn std::error::Error + Send + Sync>>;
     }
+
+    /// Blanket implementation for Arc-wrapped ports, so decorators can wrap a
+    /// shared instance
+    #[async_trait]
+    impl<T: EffectivePoliciesQueryPort + ?Sized> EffectivePoliciesQueryPort for std::sync::Arc<T> {
+        async fn get_effective_policies(
+            &self,
+            query: EffectivePoliciesQuery,
+        ) -> Result<EffectivePoliciesResult, Box<dyn std::error::Error + Send + Sync>> {
+            (**self).get_effective_policies(query).await
+        }
+    }
 }
 
 pub mod organizations {
+    use crate::domain::{HrnVisitor, TenantScoped};
     use async_trait::async_trait;
     use cedar_policy::PolicySet;
 
@@ -49,6 +108,12 @@ pub mod organizations {
         pub resource_hrn: String,
     }
 
+    impl TenantScoped for GetEffectiveScpsQuery {
+        fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+            visitor.hrn_str(&self.resource_hrn);
+        }
+    }
+
     /// Cross-context abstraction to obtain effective SCP constraints
     /// for a given resource (account / OU).
     #[async_trait]
@@ -65,9 +130,16 @@ pub use auth_context::{AuthContextError, AuthContextProvider, SessionMetadata};
 pub use authorization::{
     AuthorizationError, EvaluationDecision, EvaluationRequest, IamPolicyEvaluator, ScpEvaluator,
 };
+pub use distributed_lock::{DistributedLock, DistributedLockError, FencingToken, LockLease};
 pub use event_bus::{
-    DomainEvent, EventBus, EventEnvelope, EventHandler, EventPublisher, Subscription,
+    AckEventHandler, AckSubscriptionConfig, Acknowledger, AggregateFilter, Delivery, DomainEvent,
+    EventBus, EventEnvelope, EventHandler, EventPublisher, Subscription,
+};
+pub use event_format::{
+    EventSerializationError, SerializationFormat, decode_envelope, encode_envelope,
 };
+pub use event_registry::{AnyDomainEvent, DomainEventRegistry, EventRegistryError};
+pub use event_store::{EventStore, EventStoreError, StoredEvent};
 pub use iam::{EffectivePoliciesQuery, EffectivePoliciesQueryPort, EffectivePoliciesResult};
 pub use organizations::{GetEffectiveScpsPort, GetEffectiveScpsQuery};
 pub use unit_of_work::{UnitOfWork, UnitOfWorkError, UnitOfWorkFactory};
@@ -34,11 +34,20 @@
 //! address.insert("city".to_string(), AttributeValue::String("Madrid".to_string()));
 //! address.insert("postal_code".to_string(), AttributeValue::String("28001".to_string()));
 //! let address_record = AttributeValue::Record(address);
+//!
+//! // Tipos de extensión: validados al construirlos
+//! let price = AttributeValue::decimal("19.99").unwrap();
+//! let source_ip = AttributeValue::ip_addr("10.0.0.0/8").unwrap();
+//! let expires = AttributeValue::datetime("2024-10-15T11:35:00Z").unwrap();
+//! assert!(AttributeValue::ip_addr("10.0.0.300").is_err());
 //! ```
 
+use chrono::{NaiveDate, NaiveTime};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::net::IpAddr;
+use thiserror::Error;
 
 // ============================================================================
 // AttributeValue - Representación agnóstica de valores
@@ -58,6 +67,14 @@ use std::fmt;
 /// - `Set`: Conjunto (lista ordenada) de valores del mismo tipo
 /// - `Record`: Mapa clave-valor (objeto anidado)
 /// - `EntityRef`: Referencia a otra entidad por su identificador
+/// - `Decimal`: Número decimal con hasta 4 dígitos fraccionarios
+/// - `IpAddr`: Dirección IPv4/IPv6, opcionalmente con prefijo CIDR
+/// - `DateTime`: Fecha, u fecha y hora, en formato ISO 8601
+///
+/// Los tres últimos se corresponden con los tipos de extensión de los motores
+/// de políticas y guardan su representación textual. Sus constructores
+/// validan el formato; un valor construido directamente (o deserializado)
+/// se valida al traducirlo, ver [`AttributeValue::validate`].
 ///
 /// # Notas sobre Serialización
 ///
@@ -68,6 +85,7 @@ use std::fmt;
 /// - `Set` → array JSON
 /// - `Record` → objeto JSON
 /// - `EntityRef` → string con formato especial
+/// - `Decimal`, `IpAddr`, `DateTime` → string con su representación textual
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "value")]
 pub enum AttributeValue {
@@ -100,6 +118,34 @@ pub enum AttributeValue {
     /// (por ejemplo, un HRN serializado)
     #[serde(rename = "entity_ref")]
     EntityRef(String),
+
+    /// Número decimal, por ejemplo `"12.3456"`
+    ///
+    /// Siempre lleva punto y entre 1 y 4 dígitos fraccionarios, y su valor
+    /// escalado por 10^4 debe caber en un entero de 64 bits con signo.
+    #[serde(rename = "decimal")]
+    Decimal(String),
+
+    /// Dirección IPv4 o IPv6, opcionalmente con prefijo CIDR (`"10.0.0.0/8"`)
+    #[serde(rename = "ip_addr")]
+    IpAddr(String),
+
+    /// Fecha (`"2024-10-15"`) o fecha y hora con zona (`"2024-10-15T11:35:00Z"`,
+    /// `"2024-10-15T11:35:00.000+0100"`)
+    #[serde(rename = "datetime")]
+    DateTime(String),
+}
+
+/// Error al construir o validar un valor de un tipo de extensión
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid {type_name} value '{value}': {reason}")]
+pub struct InvalidAttributeValue {
+    /// Nombre del tipo, ver [`AttributeValue::type_name`]
+    pub type_name: &'static str,
+    /// Valor rechazado
+    pub value: String,
+    /// Motivo del rechazo
+    pub reason: String,
 }
 
 impl AttributeValue {
@@ -143,6 +189,47 @@ impl AttributeValue {
         Self::EntityRef(id.into())
     }
 
+    /// Crea un AttributeValue::Decimal validando su formato
+    pub fn decimal(value: impl Into<String>) -> Result<Self, InvalidAttributeValue> {
+        let value = Self::Decimal(value.into());
+        value.validate()?;
+        Ok(value)
+    }
+
+    /// Crea un AttributeValue::IpAddr validando su formato
+    pub fn ip_addr(value: impl Into<String>) -> Result<Self, InvalidAttributeValue> {
+        let value = Self::IpAddr(value.into());
+        value.validate()?;
+        Ok(value)
+    }
+
+    /// Crea un AttributeValue::DateTime validando su formato
+    pub fn datetime(value: impl Into<String>) -> Result<Self, InvalidAttributeValue> {
+        let value = Self::DateTime(value.into());
+        value.validate()?;
+        Ok(value)
+    }
+
+    /// Valida los valores de tipos de extensión, incluidos los anidados en
+    /// Sets y Records
+    ///
+    /// El resto de variantes son válidas por construcción.
+    pub fn validate(&self) -> Result<(), InvalidAttributeValue> {
+        let (value, checked) = match self {
+            Self::Decimal(v) => (v, check_decimal(v)),
+            Self::IpAddr(v) => (v, check_ip_addr(v)),
+            Self::DateTime(v) => (v, check_datetime(v)),
+            Self::Set(values) => return values.iter().try_for_each(Self::validate),
+            Self::Record(map) => return map.values().try_for_each(Self::validate),
+            Self::Bool(_) | Self::Long(_) | Self::String(_) | Self::EntityRef(_) => return Ok(()),
+        };
+        checked.map_err(|reason| InvalidAttributeValue {
+            type_name: self.type_name(),
+            value: value.clone(),
+            reason: reason.to_string(),
+        })
+    }
+
     /// Verifica si es un Bool
     pub fn is_bool(&self) -> bool {
         matches!(self, Self::Bool(_))
@@ -173,6 +260,21 @@ impl AttributeValue {
         matches!(self, Self::EntityRef(_))
     }
 
+    /// Verifica si es un Decimal
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Self::Decimal(_))
+    }
+
+    /// Verifica si es un IpAddr
+    pub fn is_ip_addr(&self) -> bool {
+        matches!(self, Self::IpAddr(_))
+    }
+
+    /// Verifica si es un DateTime
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Self::DateTime(_))
+    }
+
     /// Intenta obtener el valor como Bool
     pub fn as_bool(&self) -> Option<bool> {
         if let Self::Bool(v) = self {
@@ -227,6 +329,33 @@ impl AttributeValue {
         }
     }
 
+    /// Intenta obtener el valor como Decimal, en su representación textual
+    pub fn as_decimal(&self) -> Option<&str> {
+        if let Self::Decimal(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Intenta obtener el valor como IpAddr, en su representación textual
+    pub fn as_ip_addr(&self) -> Option<&str> {
+        if let Self::IpAddr(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    /// Intenta obtener el valor como DateTime, en su representación textual
+    pub fn as_datetime(&self) -> Option<&str> {
+        if let Self::DateTime(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
+
     /// Retorna el nombre del tipo como string (útil para debugging)
     pub fn type_name(&self) -> &'static str {
         match self {
@@ -236,6 +365,9 @@ impl AttributeValue {
             Self::Set(_) => "Set",
             Self::Record(_) => "Record",
             Self::EntityRef(_) => "EntityRef",
+            Self::Decimal(_) => "Decimal",
+            Self::IpAddr(_) => "IpAddr",
+            Self::DateTime(_) => "DateTime",
         }
     }
 }
@@ -267,10 +399,99 @@ impl fmt::Display for AttributeValue {
                 write!(f, "}}")
             }
             Self::EntityRef(id) => write!(f, "EntityRef(\"{}\")", id),
+            Self::Decimal(v) => write!(f, "decimal(\"{}\")", v),
+            Self::IpAddr(v) => write!(f, "ip(\"{}\")", v),
+            Self::DateTime(v) => write!(f, "datetime(\"{}\")", v),
         }
     }
 }
 
+// ============================================================================
+// Validación de tipos de extensión
+// ============================================================================
+
+/// Comprueba que `value` sigue `shape`, donde cada `d` es un dígito ASCII y
+/// el resto de caracteres deben coincidir literalmente
+fn has_shape(value: &str, shape: &str) -> bool {
+    value.len() == shape.len()
+        && value.bytes().zip(shape.bytes()).all(|(v, s)| match s {
+            b'd' => v.is_ascii_digit(),
+            _ => v == s,
+        })
+}
+
+fn check_decimal(value: &str) -> Result<(), &'static str> {
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    let (integer, fraction) = unsigned.split_once('.').ok_or("expected a decimal point")?;
+    let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(integer) || !all_digits(fraction) {
+        return Err("expected digits on both sides of the decimal point");
+    }
+    if fraction.len() > 4 {
+        return Err("at most 4 fractional digits are allowed");
+    }
+
+    // Escalado por 10^4 debe caber en un i64
+    let scaled: i128 = format!("{}{:0<4}", integer, fraction)
+        .parse()
+        .map_err(|_| "out of range")?;
+    let scaled = if negative { -scaled } else { scaled };
+    i64::try_from(scaled)
+        .map(|_| ())
+        .map_err(|_| "out of range")
+}
+
+fn check_ip_addr(value: &str) -> Result<(), &'static str> {
+    let (address, prefix) = match value.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (value, None),
+    };
+    let address: IpAddr = address.parse().map_err(|_| "not an IPv4 or IPv6 address")?;
+    if let Some(prefix) = prefix {
+        let max = if address.is_ipv4() { 32 } else { 128 };
+        let valid = !prefix.is_empty()
+            && prefix.bytes().all(|b| b.is_ascii_digit())
+            && prefix.parse::<u8>().is_ok_and(|p| p <= max);
+        if !valid {
+            return Err("invalid CIDR prefix length");
+        }
+    }
+    Ok(())
+}
+
+fn check_datetime(value: &str) -> Result<(), &'static str> {
+    const FORMAT: &str = "expected YYYY-MM-DD or YYYY-MM-DDThh:mm:ss[.SSS](Z|+hhmm|-hhmm)";
+
+    let (date, rest) = value.split_at_checked(10).ok_or(FORMAT)?;
+    if !has_shape(date, "dddd-dd-dd") {
+        return Err(FORMAT);
+    }
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| "invalid calendar date")?;
+    if rest.is_empty() {
+        return Ok(());
+    }
+
+    let time = rest.strip_prefix('T').ok_or(FORMAT)?;
+    let (clock, zone) = time.split_at_checked(8).ok_or(FORMAT)?;
+    if !has_shape(clock, "dd:dd:dd") {
+        return Err(FORMAT);
+    }
+    NaiveTime::parse_from_str(clock, "%H:%M:%S").map_err(|_| "invalid time of day")?;
+
+    let zone = match zone.split_at_checked(4) {
+        Some((millis, zone)) if has_shape(millis, ".ddd") => zone,
+        _ => zone,
+    };
+    let valid_zone = zone == "Z"
+        || ((has_shape(zone, "+dddd") || has_shape(zone, "-dddd"))
+            && zone[1..3] < *"24"
+            && zone[3..5] < *"60");
+    if valid_zone { Ok(()) } else { Err(FORMAT) }
+}
+
 // ============================================================================
 // Conversiones convenientes desde tipos Rust nativos
 // ============================================================================
@@ -429,6 +650,91 @@ mod tests {
         assert_eq!(AttributeValue::string("test").to_string(), "\"test\"");
     }
 
+    #[test]
+    fn attribute_value_extension_types_accept_valid_values() {
+        for value in ["0.0", "-12.3456", "922337203685477.5807"] {
+            assert!(
+                AttributeValue::decimal(value).unwrap().is_decimal(),
+                "{}",
+                value
+            );
+        }
+        for value in ["192.168.1.1", "10.0.0.0/8", "::1", "2001:db8::/32"] {
+            assert!(
+                AttributeValue::ip_addr(value).unwrap().is_ip_addr(),
+                "{}",
+                value
+            );
+        }
+        for value in [
+            "2024-02-29",
+            "2024-10-15T11:35:00Z",
+            "2024-10-15T11:35:00.123Z",
+            "2024-10-15T11:35:00-0530",
+            "2024-10-15T11:35:00.123+0100",
+        ] {
+            assert!(
+                AttributeValue::datetime(value).unwrap().is_datetime(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn attribute_value_extension_types_reject_malformed_values() {
+        for value in ["12", "1.23456", ".5", "1.", "abc", "922337203685477.5808"] {
+            assert!(AttributeValue::decimal(value).is_err(), "{}", value);
+        }
+        for value in [
+            "10.0.0.300",
+            "10.0.0.0/33",
+            "::1/129",
+            "10.0.0.0/",
+            "localhost",
+        ] {
+            assert!(AttributeValue::ip_addr(value).is_err(), "{}", value);
+        }
+        for value in [
+            "2023-02-29",
+            "2024-10-15 11:35:00Z",
+            "2024-10-15T25:00:00Z",
+            "2024-10-15T11:35:00",
+            "2024-10-15T11:35:00.1Z",
+            "2024-10-15T11:35:00+01:00",
+        ] {
+            assert!(AttributeValue::datetime(value).is_err(), "{}", value);
+        }
+
+        let error = AttributeValue::ip_addr("10.0.0.300").unwrap_err();
+        assert_eq!(error.type_name, "IpAddr");
+        assert_eq!(error.value, "10.0.0.300");
+    }
+
+    #[test]
+    fn attribute_value_validate_checks_nested_values() {
+        let mut map = HashMap::new();
+        map.insert(
+            "sources".to_string(),
+            AttributeValue::set(vec![AttributeValue::IpAddr("not-an-ip".to_string())]),
+        );
+        assert!(AttributeValue::record(map).validate().is_err());
+        assert!(AttributeValue::string("not-an-ip").validate().is_ok());
+    }
+
+    #[test]
+    fn attribute_value_extension_types_display_and_serialization() {
+        let value = AttributeValue::ip_addr("10.0.0.1").unwrap();
+        assert_eq!(value.to_string(), "ip(\"10.0.0.1\")");
+        assert_eq!(value.as_ip_addr(), Some("10.0.0.1"));
+        assert_eq!(value.as_decimal(), None);
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"type":"ip_addr","value":"10.0.0.1"}"#);
+        let deserialized: AttributeValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, deserialized);
+    }
+
     #[test]
     fn attribute_value_from_conversions() {
         let _: AttributeValue = true.into();
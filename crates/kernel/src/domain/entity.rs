@@ -92,6 +92,12 @@ pub enum AttributeType {
     /// Referencia a otra entidad por su tipo
     /// El &'static str debe ser el nombre del tipo de entidad (ej: "User", "Group")
     EntityRef(&'static str),
+    /// Número decimal (tipo de extensión `decimal`)
+    Decimal,
+    /// Dirección IP o rango CIDR (tipo de extensión `ipaddr`)
+    IpAddr,
+    /// Fecha y hora (tipo de extensión `datetime`)
+    DateTime,
 }
 
 impl AttributeType {
@@ -125,6 +131,21 @@ impl AttributeType {
         Self::EntityRef(entity_type)
     }
 
+    /// Crea un AttributeType::Decimal
+    pub const fn decimal() -> Self {
+        Self::Decimal
+    }
+
+    /// Crea un AttributeType::IpAddr
+    pub const fn ip_addr() -> Self {
+        Self::IpAddr
+    }
+
+    /// Crea un AttributeType::DateTime
+    pub const fn datetime() -> Self {
+        Self::DateTime
+    }
+
     /// Retorna una representación en string del tipo (útil para debugging y schemas)
     pub fn type_name(&self) -> String {
         match self {
@@ -134,6 +155,9 @@ impl AttributeType {
             Self::Set(inner) => format!("Set<{}>", inner.type_name()),
             Self::Record(_) => "Record".to_string(),
             Self::EntityRef(ty) => format!("EntityRef<{}>", ty),
+            Self::Decimal => "Decimal".to_string(),
+            Self::IpAddr => "IpAddr".to_string(),
+            Self::DateTime => "DateTime".to_string(),
         }
     }
 
@@ -146,6 +170,9 @@ impl AttributeType {
             Self::Set(inner) => format!("Set<{}>", inner.to_cedar_decl()),
             Self::Record(_) => "Record".to_string(),
             Self::EntityRef(ty) => format!("EntityRef<{}>", ty),
+            Self::Decimal => "decimal".to_string(),
+            Self::IpAddr => "ipaddr".to_string(),
+            Self::DateTime => "datetime".to_string(),
         }
     }
 }
@@ -324,6 +351,9 @@ pub trait HodeiEntity: std::fmt::Debug + Send + Sync {
                             AttributeValue::Set(_) => crate::domain::AttributeType::string(), // Anidado, usar String
                             AttributeValue::Record(_) => crate::domain::AttributeType::string(),
                             AttributeValue::EntityRef(_) => crate::domain::AttributeType::string(),
+                            AttributeValue::Decimal(_) => crate::domain::AttributeType::decimal(),
+                            AttributeValue::IpAddr(_) => crate::domain::AttributeType::ip_addr(),
+                            AttributeValue::DateTime(_) => crate::domain::AttributeType::datetime(),
                         };
                         crate::domain::AttributeType::set(element_type)
                     } else {
@@ -332,6 +362,9 @@ pub trait HodeiEntity: std::fmt::Debug + Send + Sync {
                 }
                 AttributeValue::Record(_) => crate::domain::AttributeType::string(), // Simplificado
                 AttributeValue::EntityRef(_) => crate::domain::AttributeType::string(), // Simplificado
+                AttributeValue::Decimal(_) => crate::domain::AttributeType::decimal(),
+                AttributeValue::IpAddr(_) => crate::domain::AttributeType::ip_addr(),
+                AttributeValue::DateTime(_) => crate::domain::AttributeType::datetime(),
             };
             cedar_attrs.push((name.as_str().to_string(), cedar_type));
         }
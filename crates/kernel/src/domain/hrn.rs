@@ -1,27 +1,94 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use thiserror::Error;
+
+/// Errores al parsear un HRN desde su representación en string
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum HrnParseError {
+    /// No empieza por `hrn:`
+    #[error("HRN '{0}' must start with 'hrn:'")]
+    MissingPrefix(String),
+
+    /// No tiene los seis segmentos separados por `:`
+    #[error("HRN '{input}' must have 6 ':'-separated segments, found {found}")]
+    InvalidSegmentCount { input: String, found: usize },
+
+    /// El último segmento no es `<resource_type>/<resource_id>`
+    #[error("HRN '{0}' must end with '<resource_type>/<resource_id>'")]
+    MissingResourceId(String),
+}
 
 /// Hrn (Hodei Resource Name)
 ///
 /// Formato inspirado en ARN de AWS con la siguiente convención:
-/// hrn:<partition>:<service>::<account_id>:<resource_type>/<resource_id>
+/// hrn:<partition>:<service>:<region>:<account_id>:<resource_type>/<resource_id>
 ///
-/// Ejemplo:
+/// Ejemplos:
 /// hrn:aws:iam::123456789012:User/alice
+/// hrn:hodei:iam::default:User/alice (sin región)
+/// hrn:aws:s3:::Bucket/logs (recurso global: sin región ni cuenta)
 ///
 /// Notas:
-/// - El segmento de región se omite (doble `::`)
+/// - Los segmentos de región y cuenta son opcionales; se dejan vacíos si no
+///   aplican (recursos globales) y se representan como `None`. La forma
+///   canónica conserva los segmentos vacíos, de modo que parsear y volver a
+///   renderizar reproduce el HRN original
+/// - Un segmento vacío y `None` son equivalentes a todos los efectos
+///   (igualdad, hash y serialización)
+/// - La igualdad (`PartialEq`) es estricta e incluye la región; para
+///   comparar entre regiones usar [`Hrn::matches_ignoring_region`]
 /// - `service` actúa como namespace lógico (se normaliza a lowercase)
 /// - `resource_type` puede mapear a un tipo Cedar namespaced (ServicePascalCase::Type)
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hrn {
     pub partition: String,
     pub service: String,
-    pub account_id: String,
+    #[serde(
+        default,
+        deserialize_with = "empty_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub region: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "empty_as_none",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub account_id: Option<String>,
     pub resource_type: String,
     pub resource_id: String,
 }
 
+/// Deserializa un segmento opcional tratando `""` como ausente
+fn empty_as_none<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|segment| !segment.is_empty()))
+}
+
+/// `None` para un segmento vacío
+fn non_empty(segment: String) -> Option<String> {
+    (!segment.is_empty()).then_some(segment)
+}
+
+impl PartialEq for Hrn {
+    fn eq(&self, other: &Self) -> bool {
+        self.region() == other.region() && self.matches_ignoring_region(other)
+    }
+}
+
+impl Eq for Hrn {}
+
+impl Hash for Hrn {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.partition.hash(state);
+        self.service.hash(state);
+        self.region().hash(state);
+        self.account().hash(state);
+        self.resource_type.hash(state);
+        self.resource_id.hash(state);
+    }
+}
+
 impl Hrn {
     /// Acceso al campo service
     pub fn service(&self) -> &str {
@@ -43,9 +110,51 @@ impl Hrn {
         &self.partition
     }
 
-    /// Acceso al campo account_id
+    /// Acceso al campo account_id; vacío si el recurso no pertenece a una cuenta
     pub fn account_id(&self) -> &str {
-        &self.account_id
+        self.account().unwrap_or_default()
+    }
+
+    /// Cuenta del recurso, `None` si se omite (recursos globales)
+    pub fn account(&self) -> Option<&str> {
+        self.account_id
+            .as_deref()
+            .filter(|account| !account.is_empty())
+    }
+
+    /// Acceso al campo region
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref().filter(|region| !region.is_empty())
+    }
+
+    /// Devuelve el HRN en la región indicada
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = non_empty(region.into());
+        self
+    }
+
+    /// Forma normalizada sin región, para comparaciones entre regiones
+    ///
+    /// Dos HRNs con la misma forma normalizada se refieren al mismo recurso
+    /// en (posiblemente) distintas regiones.
+    pub fn without_region(&self) -> Self {
+        Self {
+            region: None,
+            ..self.clone()
+        }
+    }
+
+    /// Compara con `other` ignorando únicamente la región
+    ///
+    /// Partition, service, account, tipo e id del recurso deben coincidir
+    /// exactamente. A diferencia de `==`, `hrn:aws:s3:us-east-1:...` y
+    /// `hrn:aws:s3:eu-west-1:...` con el resto igual se consideran iguales.
+    pub fn matches_ignoring_region(&self, other: &Hrn) -> bool {
+        self.partition == other.partition
+            && self.service == other.service
+            && self.account() == other.account()
+            && self.resource_type == other.resource_type
+            && self.resource_id == other.resource_id
     }
 
     /// Convención: nombre de servicio siempre en minúsculas (puede contener dígitos y '-')
@@ -80,7 +189,8 @@ impl Hrn {
         Self {
             partition,
             service: Self::normalize_service_name(&service),
-            account_id,
+            region: None,
+            account_id: non_empty(account_id),
             resource_type,
             resource_id,
         }
@@ -106,7 +216,8 @@ impl Hrn {
         Self {
             partition,
             service: Self::normalize_service_name(service_name.as_str()),
-            account_id,
+            region: None,
+            account_id: non_empty(account_id),
             resource_type: resource_type_name.as_str().to_string(),
             resource_id,
         }
@@ -114,20 +225,32 @@ impl Hrn {
 
     /// Parse HRN desde su representación en string
     pub fn from_string(hrn_str: &str) -> Option<Self> {
+        Self::parse(hrn_str).ok()
+    }
+
+    /// Parse HRN desde su representación en string, indicando por qué falla
+    pub fn parse(hrn_str: &str) -> Result<Self, HrnParseError> {
         let parts: Vec<&str> = hrn_str.split(':').collect();
-        if parts.len() != 6 || parts[0] != "hrn" {
-            return None;
+        if parts[0] != "hrn" {
+            return Err(HrnParseError::MissingPrefix(hrn_str.to_string()));
+        }
+        if parts.len() != 6 {
+            return Err(HrnParseError::InvalidSegmentCount {
+                input: hrn_str.to_string(),
+                found: parts.len(),
+            });
         }
 
         let resource_parts: Vec<&str> = parts[5].splitn(2, '/').collect();
         if resource_parts.len() != 2 {
-            return None;
+            return Err(HrnParseError::MissingResourceId(hrn_str.to_string()));
         }
 
-        Some(Hrn {
+        Ok(Hrn {
             partition: parts[1].to_string(),
             service: Self::normalize_service_name(parts[2]),
-            account_id: parts[4].to_string(), // (region) se omite
+            region: non_empty(parts[3].to_string()),
+            account_id: non_empty(parts[4].to_string()),
             resource_type: resource_parts[0].to_string(),
             resource_id: resource_parts[1].to_string(),
         })
@@ -196,19 +319,48 @@ impl Hrn {
         Self {
             partition: "aws".to_string(),
             service: Self::normalize_service_name(&service.into()),
-            account_id: String::new(),
+            region: None,
+            account_id: None,
             resource_type: "Action".to_string(),
             resource_id: name.into(),
         }
     }
 }
 
+/// Parsea una lista de HRNs de una sola vez
+///
+/// Devuelve todos los HRNs en el orden de entrada, o bien todos los fallos
+/// (no solo el primero) junto con su posición en `inputs`, para que el
+/// cliente pueda corregirlos en una sola petición.
+pub fn validate_hrns(inputs: &[&str]) -> Result<Vec<Hrn>, Vec<(usize, HrnParseError)>> {
+    let mut hrns = Vec::with_capacity(inputs.len());
+    let mut errors = Vec::new();
+
+    for (index, input) in inputs.iter().enumerate() {
+        match Hrn::parse(input) {
+            Ok(hrn) => hrns.push(hrn),
+            Err(error) => errors.push((index, error)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(hrns)
+    } else {
+        Err(errors)
+    }
+}
+
 impl fmt::Display for Hrn {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "hrn:{}:{}::{}:{}/{}",
-            self.partition, self.service, self.account_id, self.resource_type, self.resource_id
+            "hrn:{}:{}:{}:{}:{}/{}",
+            self.partition,
+            self.service,
+            self.region().unwrap_or_default(),
+            self.account_id(),
+            self.resource_type,
+            self.resource_id
         )
     }
 }
@@ -223,7 +375,7 @@ mod tests {
         let hrn = Hrn::from_string(s).expect("parse hrn");
         assert_eq!(hrn.partition, "aws");
         assert_eq!(hrn.service, "hodei");
-        assert_eq!(hrn.account_id, "123456789012");
+        assert_eq!(hrn.account_id(), "123456789012");
         assert_eq!(hrn.resource_type, "User");
         assert_eq!(hrn.resource_id, "alice");
         let rendered = hrn.to_string();
@@ -290,6 +442,137 @@ mod tests {
         assert!(type_name.contains("Iam::Action"));
     }
 
+    #[test]
+    fn parse_reports_why_an_hrn_is_invalid() {
+        assert_eq!(
+            Hrn::parse("arn:aws:iam::123:User/alice"),
+            Err(HrnParseError::MissingPrefix(
+                "arn:aws:iam::123:User/alice".to_string()
+            ))
+        );
+        assert_eq!(
+            Hrn::parse("hrn:aws:iam:123:User/alice"),
+            Err(HrnParseError::InvalidSegmentCount {
+                input: "hrn:aws:iam:123:User/alice".to_string(),
+                found: 5,
+            })
+        );
+        assert_eq!(
+            Hrn::parse("hrn:aws:iam::123:User"),
+            Err(HrnParseError::MissingResourceId(
+                "hrn:aws:iam::123:User".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_hrns_keeps_input_order() {
+        let hrns = validate_hrns(&[
+            "hrn:aws:iam::123:User/alice",
+            "hrn:aws:iam::123:Group/admins",
+        ])
+        .expect("all valid");
+        assert_eq!(hrns[0].resource_id(), "alice");
+        assert_eq!(hrns[1].resource_id(), "admins");
+        assert_eq!(validate_hrns(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn validate_hrns_reports_every_failure_with_its_index() {
+        let errors = validate_hrns(&[
+            "not-an-hrn",
+            "hrn:aws:iam::123:User/alice",
+            "hrn:aws:iam::123:User",
+        ])
+        .expect_err("two invalid");
+        let indexes: Vec<usize> = errors.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indexes, vec![0, 2]);
+        assert!(matches!(errors[1].1, HrnParseError::MissingResourceId(_)));
+    }
+
+    #[test]
+    fn region_is_parsed_and_rendered() {
+        let hrn = Hrn::from_string("hrn:aws:s3:eu-west-1:123:Bucket/logs").expect("parse hrn");
+        assert_eq!(hrn.region(), Some("eu-west-1"));
+        assert_eq!(hrn.to_string(), "hrn:aws:s3:eu-west-1:123:Bucket/logs");
+
+        let global = Hrn::from_string("hrn:aws:s3::123:Bucket/logs").expect("parse hrn");
+        assert_eq!(global.region(), None);
+        assert_eq!(global.to_string(), "hrn:aws:s3::123:Bucket/logs");
+    }
+
+    #[test]
+    fn matches_ignoring_region_is_looser_than_equality() {
+        let us = Hrn::from_string("hrn:aws:s3:us-east-1:123:Bucket/logs").expect("parse hrn");
+        let eu = Hrn::from_string("hrn:aws:s3:eu-west-1:123:Bucket/logs").expect("parse hrn");
+        assert_ne!(us, eu);
+        assert!(us.matches_ignoring_region(&eu));
+        assert_eq!(us.without_region(), eu.without_region());
+
+        let other_account =
+            Hrn::from_string("hrn:aws:s3:eu-west-1:456:Bucket/logs").expect("parse hrn");
+        assert!(!us.matches_ignoring_region(&other_account));
+        let other_bucket =
+            Hrn::from_string("hrn:aws:s3:us-east-1:123:Bucket/audit").expect("parse hrn");
+        assert!(!us.matches_ignoring_region(&other_bucket));
+    }
+
+    #[test]
+    fn omitted_region_and_account_are_none_and_roundtrip() {
+        for s in [
+            "hrn:hodei:iam::default:user/alice",
+            "hrn:aws:s3:::Bucket/logs",
+            "hrn:aws:s3:eu-west-1::Bucket/logs",
+        ] {
+            let hrn = Hrn::parse(s).expect("parse hrn");
+            assert_eq!(hrn.to_string(), s);
+            assert_eq!(Hrn::parse(&hrn.to_string()), Ok(hrn));
+        }
+
+        let global = Hrn::parse("hrn:aws:s3:::Bucket/logs").expect("parse hrn");
+        assert_eq!(global.region(), None);
+        assert_eq!(global.account(), None);
+        assert_eq!(global.account_id(), "");
+        assert_eq!(
+            Hrn::new(
+                "aws".to_string(),
+                "s3".to_string(),
+                String::new(),
+                "Bucket".to_string(),
+                "logs".to_string(),
+            ),
+            global
+        );
+    }
+
+    #[test]
+    fn empty_segments_are_equivalent_to_omitted_ones() {
+        let omitted = Hrn::parse("hrn:aws:s3:::Bucket/logs").expect("parse hrn");
+        let empty = Hrn {
+            region: Some(String::new()),
+            account_id: Some(String::new()),
+            ..omitted.clone()
+        };
+        assert_eq!(empty, omitted);
+        assert_eq!(empty.to_string(), omitted.to_string());
+
+        let hash = |hrn: &Hrn| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            hrn.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&empty), hash(&omitted));
+
+        let json = r#"{"partition":"aws","service":"s3","region":"","account_id":"","resource_type":"Bucket","resource_id":"logs"}"#;
+        let deserialized: Hrn = serde_json::from_str(json).expect("deserialize hrn");
+        assert_eq!(deserialized.region, None);
+        assert_eq!(deserialized.account_id, None);
+        assert_eq!(
+            serde_json::to_string(&deserialized).unwrap(),
+            r#"{"partition":"aws","service":"s3","resource_type":"Bucket","resource_id":"logs"}"#
+        );
+    }
+
     #[test]
     fn accessor_methods() {
         let hrn = Hrn::new(
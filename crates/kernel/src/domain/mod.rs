@@ -18,6 +18,7 @@
 //! - `entity`: Traits y tipos para describir entidades, acciones y almacenamiento de políticas.
 //! - `value_objects`: Value Objects tipados del dominio (ServiceName, ResourceTypeName, etc.)
 //! - `attributes`: Tipos agnósticos para representar valores de atributos
+//! - `tenancy`: Aislamiento de tenants sobre los HRNs de comandos y queries
 //!
 //! Re-exports clave para ergonomía:
 //! - `Hrn`
@@ -25,12 +26,14 @@
 //! - `ActionTrait`, `AttributeType`
 //! - `PolicyStorage`, `PolicyStorageError`
 //! - `ServiceName`, `ResourceTypeName`, `AttributeName`, `ValidationError`
+//! - `PrincipalStatus`
 //! - `AttributeValue`
 
 pub mod attributes;
 pub mod entity;
 pub mod hrn;
 pub mod policy;
+pub mod tenancy;
 pub mod value_objects;
 
 #[cfg(test)]
@@ -41,13 +44,18 @@ pub use entity::{
     ActionTrait, AttributeType, HodeiEntity, HodeiEntityType, PolicyStorage, PolicyStorageError,
     Principal, Resource,
 };
-pub use hrn::Hrn;
+pub use hrn::{Hrn, HrnParseError, validate_hrns};
 
 // Re-export de Value Objects para uso ergonómico
-pub use value_objects::{AttributeName, ResourceTypeName, ServiceName, ValidationError};
+pub use value_objects::{
+    AttributeName, PrincipalStatus, ResourceTypeName, ServiceName, ValidationError,
+};
 
 // Re-export de tipos de atributos agnósticos
-pub use attributes::AttributeValue;
+pub use attributes::{AttributeValue, InvalidAttributeValue};
 
 // Re-export de tipos de políticas agnósticos
 pub use policy::{HodeiPolicy, HodeiPolicySet, PolicyId};
+
+// Re-export del aislamiento de tenants
+pub use tenancy::{CrossTenantAccess, HrnVisitor, TenantContext, TenantScoped};
@@ -0,0 +1,312 @@
+//! Tenant isolation for use-case inputs
+//!
+//! Every resource belongs to the tenant named by the `account_id` segment of
+//! its [`Hrn`]. A use case running on behalf of a tenant receives a
+//! [`TenantContext`] and calls [`TenantContext::ensure_owns`] on its command or
+//! query before touching any port; the check fails with [`CrossTenantAccess`]
+//! if any HRN the input carries belongs to another tenant. Use cases expose
+//! the checked entry point as `execute_for_tenant`, built on
+//! [`TenantContext::run`].
+//!
+//! Inputs describe their HRNs by implementing [`TenantScoped`]. Nested DTOs
+//! implement it too and are visited through [`HrnVisitor::nested`], so the
+//! check reaches HRNs at any depth. HRNs held as strings are parsed; a string
+//! that is not a valid HRN cannot be shown to belong to the tenant and is
+//! rejected like a foreign one.
+
+use super::Hrn;
+use std::future::Future;
+use thiserror::Error;
+
+/// The tenant a request is executed for
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TenantContext {
+    tenant_id: String,
+}
+
+/// An input referenced an HRN outside the requesting tenant
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Cross-tenant access denied: '{hrn}' does not belong to tenant '{tenant_id}'")]
+pub struct CrossTenantAccess {
+    /// Tenant the request was executed for
+    pub tenant_id: String,
+    /// The offending HRN, as given
+    pub hrn: String,
+}
+
+impl TenantContext {
+    pub fn new(tenant_id: impl Into<String>) -> Self {
+        Self {
+            tenant_id: tenant_id.into(),
+        }
+    }
+
+    /// Context for the tenant that owns `principal`
+    pub fn for_principal(principal: &Hrn) -> Self {
+        Self::new(principal.account_id())
+    }
+
+    pub fn tenant_id(&self) -> &str {
+        &self.tenant_id
+    }
+
+    /// Whether `hrn` belongs to this tenant
+    pub fn owns(&self, hrn: &Hrn) -> bool {
+        hrn.account_id() == self.tenant_id
+    }
+
+    /// Check that every HRN carried by `value` belongs to this tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CrossTenantAccess`] naming the first HRN that does not.
+    pub fn ensure_owns<T: TenantScoped + ?Sized>(
+        &self,
+        value: &T,
+    ) -> Result<(), CrossTenantAccess> {
+        let mut visitor = HrnVisitor {
+            tenant: self,
+            violation: None,
+        };
+        value.visit_hrns(&mut visitor);
+        match visitor.violation {
+            Some(violation) => Err(violation),
+            None => Ok(()),
+        }
+    }
+
+    /// Run `operation` on `input` once the input is shown to belong to this
+    /// tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`CrossTenantAccess`] from [`ensure_owns`](Self::ensure_owns)
+    /// converted into the operation's error, without running the operation;
+    /// otherwise whatever the operation returns.
+    pub async fn run<I, T, E, F, Fut>(&self, input: I, operation: F) -> Result<T, E>
+    where
+        I: TenantScoped,
+        E: From<CrossTenantAccess>,
+        F: FnOnce(I) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        self.ensure_owns(&input)?;
+        operation(input).await
+    }
+}
+
+/// A value whose HRNs can be checked against a [`TenantContext`]
+///
+/// Implementations must report every HRN the value carries, including those
+/// of nested values; an HRN that is not reported is not checked.
+pub trait TenantScoped {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>);
+}
+
+/// Receives the HRNs of a [`TenantScoped`] value and records the first one
+/// outside the tenant
+pub struct HrnVisitor<'a> {
+    tenant: &'a TenantContext,
+    violation: Option<CrossTenantAccess>,
+}
+
+impl HrnVisitor<'_> {
+    pub fn hrn(&mut self, hrn: &Hrn) {
+        if !self.tenant.owns(hrn) {
+            self.reject(hrn.to_string());
+        }
+    }
+
+    /// An HRN held as a string
+    pub fn hrn_str(&mut self, hrn: &str) {
+        match Hrn::from_string(hrn) {
+            Some(parsed) if self.tenant.owns(&parsed) => {}
+            _ => self.reject(hrn.to_string()),
+        }
+    }
+
+    /// HRNs held as strings
+    pub fn hrn_strs<S: AsRef<str>>(&mut self, hrns: &[S]) {
+        for hrn in hrns {
+            self.hrn_str(hrn.as_ref());
+        }
+    }
+
+    /// A nested value carrying HRNs of its own
+    pub fn nested<T: TenantScoped + ?Sized>(&mut self, value: &T) {
+        value.visit_hrns(self);
+    }
+
+    fn reject(&mut self, hrn: String) {
+        if self.violation.is_none() {
+            self.violation = Some(CrossTenantAccess {
+                tenant_id: self.tenant.tenant_id.clone(),
+                hrn,
+            });
+        }
+    }
+}
+
+impl TenantScoped for Hrn {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        visitor.hrn(self);
+    }
+}
+
+impl<T: TenantScoped + ?Sized> TenantScoped for &T {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        (**self).visit_hrns(visitor);
+    }
+}
+
+impl<T: TenantScoped + ?Sized> TenantScoped for Box<T> {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        (**self).visit_hrns(visitor);
+    }
+}
+
+impl<T: TenantScoped> TenantScoped for Option<T> {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        if let Some(value) = self {
+            value.visit_hrns(visitor);
+        }
+    }
+}
+
+impl<T: TenantScoped> TenantScoped for [T] {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        for value in self {
+            value.visit_hrns(visitor);
+        }
+    }
+}
+
+impl<T: TenantScoped> TenantScoped for Vec<T> {
+    fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+        self.as_slice().visit_hrns(visitor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hrn(account_id: &str, resource_type: &str, id: &str) -> Hrn {
+        Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            account_id.to_string(),
+            resource_type.to_string(),
+            id.to_string(),
+        )
+    }
+
+    struct Membership {
+        group_hrn: String,
+        members: Vec<Member>,
+    }
+
+    struct Member {
+        user: Hrn,
+        manager: Option<Hrn>,
+    }
+
+    impl TenantScoped for Membership {
+        fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+            visitor.hrn_str(&self.group_hrn);
+            visitor.nested(&self.members);
+        }
+    }
+
+    impl TenantScoped for Member {
+        fn visit_hrns(&self, visitor: &mut HrnVisitor<'_>) {
+            visitor.hrn(&self.user);
+            visitor.nested(&self.manager);
+        }
+    }
+
+    fn membership(manager: Hrn) -> Membership {
+        Membership {
+            group_hrn: hrn("tenant-a", "Group", "devs").to_string(),
+            members: vec![
+                Member {
+                    user: hrn("tenant-a", "User", "alice"),
+                    manager: None,
+                },
+                Member {
+                    user: hrn("tenant-a", "User", "bob"),
+                    manager: Some(manager),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn accepts_hrns_of_the_tenant() {
+        let tenant = TenantContext::new("tenant-a");
+        let input = membership(hrn("tenant-a", "User", "carol"));
+
+        assert!(tenant.ensure_owns(&input).is_ok());
+    }
+
+    #[test]
+    fn rejects_foreign_hrns_in_nested_values() {
+        let tenant = TenantContext::new("tenant-a");
+        let foreign = hrn("tenant-b", "User", "mallory");
+
+        let error = tenant
+            .ensure_owns(&membership(foreign.clone()))
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            CrossTenantAccess {
+                tenant_id: "tenant-a".to_string(),
+                hrn: foreign.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unparseable_hrn_strings() {
+        let tenant = TenantContext::new("tenant-a");
+        let mut input = membership(hrn("tenant-a", "User", "carol"));
+        input.group_hrn = "devs".to_string();
+
+        assert_eq!(tenant.ensure_owns(&input).unwrap_err().hrn, "devs");
+    }
+
+    #[tokio::test]
+    async fn run_executes_the_operation_only_for_owned_inputs() {
+        let tenant = TenantContext::new("tenant-a");
+        let mut calls = 0;
+
+        let owned: Result<usize, CrossTenantAccess> = tenant
+            .run(membership(hrn("tenant-a", "User", "carol")), |input| {
+                calls += 1;
+                async move { Ok(input.members.len()) }
+            })
+            .await;
+        assert_eq!(owned, Ok(2));
+
+        let mallory = hrn("tenant-b", "User", "mallory");
+        let foreign: Result<usize, CrossTenantAccess> = tenant
+            .run(membership(mallory.clone()), |input| {
+                calls += 1;
+                async move { Ok(input.members.len()) }
+            })
+            .await;
+        assert_eq!(foreign.unwrap_err().hrn, mallory.to_string());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn context_for_principal_uses_its_account() {
+        let alice = hrn("tenant-a", "User", "alice");
+        let tenant = TenantContext::for_principal(&alice);
+
+        assert_eq!(tenant.tenant_id(), "tenant-a");
+        assert!(tenant.owns(&alice));
+        assert!(!tenant.owns(&hrn("tenant-b", "User", "alice")));
+    }
+}
@@ -430,6 +430,54 @@ impl fmt::Display for AttributeName {
     }
 }
 
+// ============================================================================
+// PrincipalStatus - Estado del ciclo de vida de un principal
+// ============================================================================
+
+/// Estado del ciclo de vida de un principal (usuario, cuenta de servicio)
+///
+/// Solo un principal `Active` obtiene políticas efectivas. Uno `Suspended`
+/// o `Deactivated` se conserva (con sus grupos y adjuntos) pero la
+/// autorización lo deniega sin evaluar políticas.
+///
+/// # Ejemplos
+///
+/// ```
+/// use kernel::domain::value_objects::PrincipalStatus;
+///
+/// assert!(PrincipalStatus::default().is_active());
+/// assert!(!PrincipalStatus::Suspended.is_active());
+/// assert_eq!(PrincipalStatus::Deactivated.to_string(), "deactivated");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrincipalStatus {
+    /// Operativo; sus políticas se aplican
+    #[default]
+    Active,
+    /// Bloqueado temporalmente (p. ej. durante una investigación)
+    Suspended,
+    /// Dado de baja; se conserva solo por trazabilidad
+    Deactivated,
+}
+
+impl PrincipalStatus {
+    /// Indica si las políticas del principal deben aplicarse
+    pub fn is_active(self) -> bool {
+        self == Self::Active
+    }
+}
+
+impl fmt::Display for PrincipalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Active => "active",
+            Self::Suspended => "suspended",
+            Self::Deactivated => "deactivated",
+        })
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -996,4 +1044,17 @@ mod tests {
         let name = AttributeName::new("created_at_timestamp").unwrap();
         assert_eq!(format!("{}", name), "created_at_timestamp");
     }
+
+    // ========================================================================
+    // Tests de PrincipalStatus
+    // ========================================================================
+
+    #[test]
+    fn principal_status_serializes_lowercase_and_defaults_to_active() {
+        let json = serde_json::to_string(&PrincipalStatus::Suspended).unwrap();
+        assert_eq!(json, "\"suspended\"");
+        let status: PrincipalStatus = serde_json::from_str("\"deactivated\"").unwrap();
+        assert_eq!(status, PrincipalStatus::Deactivated);
+        assert_eq!(PrincipalStatus::default(), PrincipalStatus::Active);
+    }
 }
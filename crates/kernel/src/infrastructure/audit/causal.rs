@@ -0,0 +1,286 @@
+//! Causal chains of audit log entries
+//!
+//! Every event records the event that caused it in `causation_id`. Following
+//! those links down from one entry rebuilds what it set off, in causal order,
+//! where [`AuditQuery`](super::AuditQuery) only gives a flat list.
+//!
+//! The chain is built from the entries sharing the root's correlation ID.
+//! Among them, an entry whose cause is not in the store — never captured, or
+//! evicted — starts a chain of its own instead of being dropped. A root
+//! without a correlation ID is linked to nothing but its descendants, so its
+//! chain holds only those. Causation links should never loop, but if they
+//! do, an entry is only placed once and the loop is reported. Chains deeper
+//! than [`MAX_CHAIN_DEPTH`] are cut there, and the cut reported.
+
+use super::{AuditLog, AuditLogStore};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Levels of causation followed below a root before the chain is cut
+pub const MAX_CHAIN_DEPTH: usize = 256;
+
+/// An entry and the entries it caused
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CausalNode {
+    pub log: AuditLog,
+
+    /// Entries caused by `log`, in the order they occurred
+    pub children: Vec<CausalNode>,
+}
+
+impl CausalNode {
+    /// The entries of this subtree, depth-first, each after its cause
+    pub fn flatten(&self) -> Vec<&AuditLog> {
+        let mut logs = vec![&self.log];
+        for child in &self.children {
+            logs.extend(child.flatten());
+        }
+        logs
+    }
+}
+
+/// What a root entry set off
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CausalChain {
+    /// The tree under the requested entry, first, then the trees under
+    /// related entries whose cause is missing from the store
+    pub roots: Vec<CausalNode>,
+
+    /// Entries reached again through a causation loop, not placed twice
+    pub cycles: Vec<Uuid>,
+
+    /// Entries at [`MAX_CHAIN_DEPTH`] whose children were not followed
+    #[serde(default)]
+    pub truncated: Vec<Uuid>,
+}
+
+impl CausalChain {
+    /// Number of entries in the chain
+    pub fn len(&self) -> usize {
+        self.roots.iter().map(|root| root.flatten().len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Every entry, each after its cause
+    pub fn flatten(&self) -> Vec<&AuditLog> {
+        self.roots.iter().flat_map(CausalNode::flatten).collect()
+    }
+}
+
+impl AuditLogStore {
+    /// Rebuild the chain of entries descending from `root_event_id`
+    ///
+    /// Returns `None` if the store has no entry with that ID.
+    pub async fn get_causal_chain(&self, root_event_id: Uuid) -> Option<CausalChain> {
+        let logs = self.logs.read().await;
+        let root = logs.entries.iter().find(|log| log.id == root_event_id)?;
+        let related: Vec<&AuditLog> = match &root.correlation_id {
+            Some(correlation_id) => logs
+                .entries
+                .iter()
+                .filter(|log| log.correlation_id.as_ref() == Some(correlation_id))
+                .collect(),
+            None => logs.entries.iter().collect(),
+        };
+
+        let stored: HashSet<String> = logs.entries.iter().map(|log| log.id.to_string()).collect();
+        let mut caused: HashMap<&str, Vec<&AuditLog>> = HashMap::new();
+        for log in &related {
+            if let Some(cause) = &log.causation_id {
+                caused.entry(cause.as_str()).or_default().push(log);
+            }
+        }
+        for children in caused.values_mut() {
+            children.sort_by_key(|log| log.occurred_at);
+        }
+
+        let mut builder = ChainBuilder {
+            caused,
+            placed: HashSet::new(),
+            cycles: Vec::new(),
+            truncated: Vec::new(),
+        };
+        let mut roots = vec![builder.node(root, 0)];
+        // Only the correlation ID links other entries to the root
+        let mut orphans: Vec<&AuditLog> = match root.correlation_id {
+            Some(_) => related
+                .iter()
+                .copied()
+                .filter(|log| {
+                    log.causation_id
+                        .as_ref()
+                        .is_some_and(|cause| !stored.contains(cause))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        orphans.sort_by_key(|log| log.occurred_at);
+        for orphan in orphans {
+            if !builder.placed.contains(&orphan.id) {
+                roots.push(builder.node(orphan, 0));
+            }
+        }
+
+        Some(CausalChain {
+            roots,
+            cycles: builder.cycles,
+            truncated: builder.truncated,
+        })
+    }
+}
+
+struct ChainBuilder<'a> {
+    /// Entries by the ID of the entry that caused them
+    caused: HashMap<&'a str, Vec<&'a AuditLog>>,
+    placed: HashSet<Uuid>,
+    cycles: Vec<Uuid>,
+    truncated: Vec<Uuid>,
+}
+
+impl<'a> ChainBuilder<'a> {
+    /// The node of `log`, `depth` levels below its root
+    fn node(&mut self, log: &'a AuditLog, depth: usize) -> CausalNode {
+        self.placed.insert(log.id);
+        let children = self
+            .caused
+            .get(log.id.to_string().as_str())
+            .cloned()
+            .unwrap_or_default();
+        if depth == MAX_CHAIN_DEPTH && !children.is_empty() {
+            self.truncated.push(log.id);
+            return CausalNode {
+                log: log.clone(),
+                children: Vec::new(),
+            };
+        }
+        let mut nodes = Vec::with_capacity(children.len());
+        for child in children {
+            if self.placed.contains(&child.id) {
+                self.cycles.push(child.id);
+            } else {
+                nodes.push(self.node(child, depth + 1));
+            }
+        }
+        CausalNode {
+            log: log.clone(),
+            children: nodes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn log(id: Uuid, causation_id: Option<Uuid>, seconds: i64) -> AuditLog {
+        AuditLog {
+            id,
+            event_type: "iam.user.created".to_string(),
+            aggregate_id: None,
+            aggregate_type: None,
+            event_data: serde_json::json!({}),
+            schema_version: 1,
+            occurred_at: Utc::now() + Duration::seconds(seconds),
+            correlation_id: Some("request-1".to_string()),
+            causation_id: causation_id.map(|id| id.to_string()),
+            metadata: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain_follows_causation_in_order() {
+        let store = AuditLogStore::new();
+        let [root, first, second, nested] = [(); 4].map(|_| Uuid::new_v4());
+        // Added out of order: the chain orders by causation, then time
+        store.add(log(nested, Some(first), 3)).await;
+        store.add(log(second, Some(root), 2)).await;
+        store.add(log(first, Some(root), 1)).await;
+        store.add(log(root, None, 0)).await;
+
+        let chain = store.get_causal_chain(root).await.unwrap();
+
+        let ids: Vec<Uuid> = chain.flatten().iter().map(|log| log.id).collect();
+        assert_eq!(ids, vec![root, first, nested, second]);
+        assert_eq!(chain.roots.len(), 1);
+        assert_eq!(chain.roots[0].children.len(), 2);
+        assert!(chain.cycles.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_entries_with_missing_cause_become_roots() {
+        let store = AuditLogStore::new();
+        let [root, child, orphan, orphan_child] = [(); 4].map(|_| Uuid::new_v4());
+        store.add(log(root, None, 0)).await;
+        store.add(log(child, Some(root), 1)).await;
+        store.add(log(orphan, Some(Uuid::new_v4()), 2)).await;
+        store.add(log(orphan_child, Some(orphan), 3)).await;
+
+        let chain = store.get_causal_chain(root).await.unwrap();
+
+        assert_eq!(chain.roots.len(), 2);
+        assert_eq!(chain.roots[1].log.id, orphan);
+        assert_eq!(chain.roots[1].children[0].log.id, orphan_child);
+        assert_eq!(chain.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_uncorrelated_root_chains_only_its_descendants() {
+        let store = AuditLogStore::new();
+        let [root, child, unrelated] = [(); 3].map(|_| Uuid::new_v4());
+        let mut uncorrelated = log(root, None, 0);
+        uncorrelated.correlation_id = None;
+        store.add(uncorrelated).await;
+        store.add(log(child, Some(root), 1)).await;
+        store.add(log(unrelated, Some(Uuid::new_v4()), 2)).await;
+
+        let chain = store.get_causal_chain(root).await.unwrap();
+
+        let ids: Vec<Uuid> = chain.flatten().iter().map(|log| log.id).collect();
+        assert_eq!(ids, vec![root, child]);
+        assert_eq!(chain.roots.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deep_chain_is_cut_at_the_depth_bound() {
+        let store = AuditLogStore::new();
+        let ids: Vec<Uuid> = (0..MAX_CHAIN_DEPTH + 2).map(|_| Uuid::new_v4()).collect();
+        store.add(log(ids[0], None, 0)).await;
+        for (seconds, pair) in ids.windows(2).enumerate() {
+            store
+                .add(log(pair[1], Some(pair[0]), seconds as i64 + 1))
+                .await;
+        }
+
+        let chain = store.get_causal_chain(ids[0]).await.unwrap();
+
+        assert_eq!(chain.len(), MAX_CHAIN_DEPTH + 1);
+        assert_eq!(chain.truncated, vec![ids[MAX_CHAIN_DEPTH]]);
+    }
+
+    #[tokio::test]
+    async fn test_causation_loop_is_reported_not_followed() {
+        let store = AuditLogStore::new();
+        let [a, b] = [(); 2].map(|_| Uuid::new_v4());
+        store.add(log(a, Some(b), 0)).await;
+        store.add(log(b, Some(a), 1)).await;
+
+        let chain = store.get_causal_chain(a).await.unwrap();
+
+        let ids: Vec<Uuid> = chain.flatten().iter().map(|log| log.id).collect();
+        assert_eq!(ids, vec![a, b]);
+        assert_eq!(chain.cycles, vec![a]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_root_has_no_chain() {
+        let store = AuditLogStore::new();
+        store.add(log(Uuid::new_v4(), None, 0)).await;
+
+        assert!(store.get_causal_chain(Uuid::new_v4()).await.is_none());
+    }
+}
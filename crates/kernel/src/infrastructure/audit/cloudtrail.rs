@@ -0,0 +1,353 @@
+//! Export of audit logs as AWS CloudTrail records
+//!
+//! SIEM pipelines built for CloudTrail can ingest hodei audit logs unchanged:
+//! each [`AuditLog`] becomes a record with the fields CloudTrail consumers
+//! key on, and [`CloudTrailExporter::export`] wraps them in the
+//! `{"Records": [...]}` envelope of a CloudTrail log file.
+//!
+//! | CloudTrail field      | From                                            |
+//! |-----------------------|-------------------------------------------------|
+//! | `eventID`             | `id`                                            |
+//! | `eventTime`           | `occurred_at`, RFC 3339 in UTC                  |
+//! | `eventSource`         | first `event_type` segment, e.g. `iam.hodei`    |
+//! | `eventName`           | remaining segments, e.g. `UserCreated`          |
+//! | `userIdentity`        | the principal HRN in metadata or event data     |
+//! | `recipientAccountId`  | account of the principal HRN                    |
+//! | `requestParameters`   | `event_data`                                    |
+//! | `hodeiCorrelationId`  | `correlation_id`                                |
+//! | `additionalEventData` | everything else, so nothing is lost             |
+
+use super::AuditLog;
+use crate::domain::Hrn;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+/// CloudTrail record format version the records follow
+pub const CLOUDTRAIL_EVENT_VERSION: &str = "1.08";
+
+/// Metadata key, or top-level `event_data` field, holding the acting
+/// principal's HRN
+pub const PRINCIPAL_FIELD: &str = "principal_hrn";
+
+/// Top-level `event_data` fields naming the principal of an event, in the
+/// order they are looked up when the metadata names none
+///
+/// Domain events don't record who triggered them, so the principal they are
+/// about stands in for it: the user whose status changed, the member who
+/// joined a group.
+pub const PRINCIPAL_DATA_FIELDS: [&str; 3] = [PRINCIPAL_FIELD, "user_hrn", "member_hrn"];
+
+/// Who performed an audited action, in CloudTrail's `userIdentity` shape
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudTrailUserIdentity {
+    /// `IAMUser` for users, the HRN resource type otherwise, `Unknown`
+    /// when the entry names no principal
+    #[serde(rename = "type")]
+    pub identity_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+
+    /// The principal HRN, in the place CloudTrail puts the ARN
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+}
+
+impl CloudTrailUserIdentity {
+    fn unknown() -> Self {
+        Self {
+            identity_type: "Unknown".to_string(),
+            principal_id: None,
+            arn: None,
+            account_id: None,
+        }
+    }
+
+    fn from_hrn(hrn: &Hrn) -> Self {
+        let identity_type = match hrn.resource_type() {
+            "User" => "IAMUser".to_string(),
+            other => other.to_string(),
+        };
+        Self {
+            identity_type,
+            principal_id: Some(hrn.resource_id().to_string()),
+            arn: Some(hrn.to_string()),
+            account_id: hrn.account().map(str::to_string),
+        }
+    }
+}
+
+/// One audit log entry as a CloudTrail record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudTrailRecord {
+    pub event_version: String,
+    #[serde(rename = "eventID")]
+    pub event_id: String,
+    pub event_time: String,
+    pub event_source: String,
+    pub event_name: String,
+    pub user_identity: CloudTrailUserIdentity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_account_id: Option<String>,
+    pub request_parameters: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hodei_correlation_id: Option<String>,
+    /// Fields of the entry with no CloudTrail counterpart
+    #[serde(skip_serializing_if = "Map::is_empty", default)]
+    pub additional_event_data: Map<String, Value>,
+}
+
+/// Maps audit logs to CloudTrail records
+#[derive(Debug, Clone)]
+pub struct CloudTrailExporter {
+    source_domain: String,
+}
+
+impl CloudTrailExporter {
+    /// Exporter naming event sources `<service>.hodei`
+    pub fn new() -> Self {
+        Self {
+            source_domain: "hodei".to_string(),
+        }
+    }
+
+    /// Name event sources `<service>.<domain>` instead, e.g. to match the
+    /// host name the SIEM knows the deployment by
+    pub fn with_source_domain(mut self, domain: impl Into<String>) -> Self {
+        self.source_domain = domain.into();
+        self
+    }
+
+    /// Map one entry
+    pub fn to_record(&self, log: &AuditLog) -> CloudTrailRecord {
+        let mut additional = Map::new();
+        let (service, name) = match log.event_type.split_once('.') {
+            Some((service, name)) => (service, name),
+            None => ("hodei", log.event_type.as_str()),
+        };
+        if !log.event_type.contains('.') {
+            additional.insert("eventType".to_string(), json!(log.event_type));
+        }
+
+        let principal = log
+            .metadata
+            .get(PRINCIPAL_FIELD)
+            .map(|hrn| Value::String(hrn.clone()))
+            .or_else(|| {
+                PRINCIPAL_DATA_FIELDS
+                    .iter()
+                    .find_map(|field| log.event_data.get(*field))
+                    .cloned()
+            });
+        let principal_hrn = principal.as_ref().and_then(principal_hrn);
+        let user_identity = match (&principal, &principal_hrn) {
+            (_, Some(hrn)) => CloudTrailUserIdentity::from_hrn(hrn),
+            (Some(unparsed), None) => {
+                additional.insert("principal".to_string(), unparsed.clone());
+                CloudTrailUserIdentity::unknown()
+            }
+            (None, None) => CloudTrailUserIdentity::unknown(),
+        };
+
+        if let Some(aggregate_id) = &log.aggregate_id {
+            additional.insert("aggregateId".to_string(), json!(aggregate_id));
+        }
+        if let Some(aggregate_type) = &log.aggregate_type {
+            additional.insert("aggregateType".to_string(), json!(aggregate_type));
+        }
+        if let Some(causation_id) = &log.causation_id {
+            additional.insert("causationId".to_string(), json!(causation_id));
+        }
+        additional.insert("schemaVersion".to_string(), json!(log.schema_version));
+        let metadata: Map<String, Value> = log
+            .metadata
+            .iter()
+            .filter(|(key, _)| key.as_str() != PRINCIPAL_FIELD || principal_hrn.is_none())
+            .map(|(key, value)| (key.clone(), json!(value)))
+            .collect();
+        if !metadata.is_empty() {
+            additional.insert("metadata".to_string(), Value::Object(metadata));
+        }
+
+        CloudTrailRecord {
+            event_version: CLOUDTRAIL_EVENT_VERSION.to_string(),
+            event_id: log.id.to_string(),
+            event_time: log
+                .occurred_at
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            event_source: format!("{}.{}", service, self.source_domain),
+            event_name: event_name(name),
+            recipient_account_id: user_identity.account_id.clone(),
+            user_identity,
+            request_parameters: log.event_data.clone(),
+            hodei_correlation_id: log.correlation_id.clone(),
+            additional_event_data: additional,
+        }
+    }
+
+    /// Map `logs` into a CloudTrail log file body
+    pub fn export(&self, logs: &[AuditLog]) -> Value {
+        let records: Vec<CloudTrailRecord> = logs.iter().map(|log| self.to_record(log)).collect();
+        json!({ "Records": records })
+    }
+}
+
+impl Default for CloudTrailExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A principal HRN given as a string or as a serialized [`Hrn`]
+fn principal_hrn(value: &Value) -> Option<Hrn> {
+    match value {
+        Value::String(hrn) => Hrn::from_string(hrn),
+        other => serde_json::from_value(other.clone()).ok(),
+    }
+}
+
+/// `user.created` → `UserCreated`, `policy.attached_to_group` →
+/// `PolicyAttachedToGroup`
+fn event_name(name: &str) -> String {
+    name.split('.').map(Hrn::to_pascal_case).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::UserStatusChanged;
+    use crate::application::ports::event_bus::{EventEnvelope, EventHandler};
+    use crate::domain::PrincipalStatus;
+    use crate::infrastructure::audit::{AuditEventHandler, AuditLogStore};
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn log(event_data: Value, metadata: HashMap<String, String>) -> AuditLog {
+        AuditLog {
+            id: Uuid::nil(),
+            event_type: "iam.user.created".to_string(),
+            aggregate_id: Some("alice".to_string()),
+            aggregate_type: Some("User".to_string()),
+            event_data,
+            schema_version: 2,
+            occurred_at: Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap(),
+            correlation_id: Some("request-1".to_string()),
+            causation_id: None,
+            metadata,
+        }
+    }
+
+    #[test]
+    fn test_maps_entry_to_cloudtrail_fields() {
+        let metadata = HashMap::from([
+            (
+                PRINCIPAL_FIELD.to_string(),
+                "hrn:hodei:iam::123:User/admin".to_string(),
+            ),
+            ("tenant_id".to_string(), "acme".to_string()),
+        ]);
+        let record = CloudTrailExporter::new().to_record(&log(json!({"name": "alice"}), metadata));
+
+        assert_eq!(record.event_time, "2024-05-01T12:30:00Z");
+        assert_eq!(record.event_source, "iam.hodei");
+        assert_eq!(record.event_name, "UserCreated");
+        assert_eq!(record.request_parameters, json!({"name": "alice"}));
+        assert_eq!(record.hodei_correlation_id.as_deref(), Some("request-1"));
+        assert_eq!(
+            record.user_identity,
+            CloudTrailUserIdentity {
+                identity_type: "IAMUser".to_string(),
+                principal_id: Some("admin".to_string()),
+                arn: Some("hrn:hodei:iam::123:User/admin".to_string()),
+                account_id: Some("123".to_string()),
+            }
+        );
+        assert_eq!(record.recipient_account_id.as_deref(), Some("123"));
+        assert_eq!(record.additional_event_data["aggregateId"], json!("alice"));
+        assert_eq!(record.additional_event_data["schemaVersion"], json!(2));
+        assert_eq!(
+            record.additional_event_data["metadata"],
+            json!({"tenant_id": "acme"})
+        );
+    }
+
+    #[test]
+    fn test_principal_from_event_data_as_serialized_hrn() {
+        let hrn = Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "123".to_string(),
+            "ServiceAccount".to_string(),
+            "ci".to_string(),
+        );
+        let data = json!({ PRINCIPAL_FIELD: serde_json::to_value(&hrn).unwrap() });
+
+        let record = CloudTrailExporter::new().to_record(&log(data, HashMap::new()));
+
+        assert_eq!(record.user_identity.identity_type, "ServiceAccount");
+        assert_eq!(record.user_identity.arn, Some(hrn.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_user_identity_of_a_captured_domain_event() {
+        let store = Arc::new(AuditLogStore::new());
+        let handler = AuditEventHandler::new(store.clone());
+        let user = Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "123".to_string(),
+            "User".to_string(),
+            "alice".to_string(),
+        );
+        let event = UserStatusChanged {
+            user_hrn: user.clone(),
+            previous_status: PrincipalStatus::Active,
+            status: PrincipalStatus::Suspended,
+        };
+        handler.handle(EventEnvelope::new(event)).await.unwrap();
+
+        let record = CloudTrailExporter::new().to_record(&store.all().await[0]);
+
+        assert_eq!(record.event_name, "UserStatusChanged");
+        assert_eq!(
+            record.user_identity,
+            CloudTrailUserIdentity::from_hrn(&user)
+        );
+        assert_eq!(record.recipient_account_id.as_deref(), Some("123"));
+    }
+
+    #[test]
+    fn test_unparsable_principal_is_kept_aside() {
+        let metadata = HashMap::from([(PRINCIPAL_FIELD.to_string(), "admin".to_string())]);
+
+        let record = CloudTrailExporter::new()
+            .with_source_domain("hodei.example.com")
+            .to_record(&log(json!({}), metadata));
+
+        assert_eq!(record.event_source, "iam.hodei.example.com");
+        assert_eq!(record.user_identity, CloudTrailUserIdentity::unknown());
+        assert_eq!(record.additional_event_data["principal"], json!("admin"));
+        assert_eq!(
+            record.additional_event_data["metadata"][PRINCIPAL_FIELD],
+            json!("admin")
+        );
+    }
+
+    #[test]
+    fn test_export_wraps_records() {
+        let exported = CloudTrailExporter::new().export(&[log(json!({}), HashMap::new())]);
+
+        let records = exported["Records"].as_array().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0]["eventID"], json!(Uuid::nil().to_string()));
+        assert_eq!(records[0]["userIdentity"]["type"], json!("Unknown"));
+        assert_eq!(records[0]["hodeiCorrelationId"], json!("request-1"));
+    }
+}
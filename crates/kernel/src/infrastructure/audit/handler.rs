@@ -5,6 +5,8 @@
 
 use super::{AuditLog, AuditLogStore};
 use crate::application::ports::event_bus::{DomainEvent, EventEnvelope, EventHandler};
+use crate::application::ports::event_format::EventSerializationError;
+use crate::application::ports::event_store::StoredEvent;
 use async_trait::async_trait;
 use std::sync::Arc;
 
@@ -22,6 +24,60 @@ impl AuditEventHandler {
         Self { store }
     }
 
+    /// Capture a persisted event, e.g. one replayed from the event store
+    ///
+    /// The entry keeps the event's original `occurred_at`. Returns whether
+    /// it was added; an event already in the audit log is skipped.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the stored payload can't be decoded.
+    pub async fn handle_stored(
+        &self,
+        event: &StoredEvent,
+    ) -> Result<bool, EventSerializationError> {
+        let audit_log = AuditLog {
+            id: event.event_id,
+            event_type: event.event_type.clone(),
+            aggregate_id: event.aggregate_id.clone(),
+            aggregate_type: event
+                .aggregate_type
+                .clone()
+                .or_else(|| event.metadata.get("aggregate_type").cloned()),
+            event_data: event.payload_json()?,
+            schema_version: event.schema_version,
+            occurred_at: event.occurred_at,
+            correlation_id: event.correlation_id.clone(),
+            causation_id: event.causation_id.clone(),
+            metadata: event.metadata.clone(),
+        };
+
+        Ok(self.capture(audit_log).await)
+    }
+
+    /// Store an entry unless its event is already in the audit log
+    async fn capture(&self, audit_log: AuditLog) -> bool {
+        let added = self.store.add_if_absent(audit_log.clone()).await;
+
+        // Log to tracing for operational visibility
+        if added {
+            tracing::info!(
+                event_type = %audit_log.event_type,
+                event_id = %audit_log.id,
+                aggregate_id = ?audit_log.aggregate_id,
+                aggregate_type = ?audit_log.aggregate_type,
+                "Domain event captured in audit log"
+            );
+        } else {
+            tracing::debug!(
+                event_id = %audit_log.id,
+                "Domain event already in audit log, skipped"
+            );
+        }
+
+        added
+    }
+
     /// Get the underlying store (useful for testing)
     #[cfg(test)]
     pub fn store(&self) -> Arc<AuditLogStore> {
@@ -43,34 +99,30 @@ impl<E: DomainEvent> EventHandler<E> for AuditEventHandler {
         // Serialize the event to JSON
         let event_data = serde_json::to_value(&envelope.event)?;
 
-        // Extract aggregate type from metadata
-        let aggregate_type = envelope.metadata.get("aggregate_type").cloned();
+        // Envelopes from before aggregates were tagged carry the type in metadata
+        let aggregate_type = envelope
+            .aggregate_type
+            .clone()
+            .or_else(|| envelope.metadata.get("aggregate_type").cloned());
 
         // Create audit log entry
         let audit_log = AuditLog {
             id: envelope.event_id,
             event_type: envelope.event.event_type().to_string(),
-            aggregate_id: envelope.event.aggregate_id(),
+            aggregate_id: envelope
+                .aggregate_id
+                .clone()
+                .or_else(|| envelope.event.aggregate_id()),
             aggregate_type,
             event_data,
+            schema_version: envelope.schema_version,
             occurred_at: envelope.occurred_at,
             correlation_id: envelope.correlation_id.clone(),
             causation_id: envelope.causation_id.clone(),
             metadata: envelope.metadata.clone(),
         };
 
-        // Store the audit log
-        self.store.add(audit_log.clone()).await;
-
-        // Log to tracing for operational visibility
-        tracing::info!(
-            event_type = %audit_log.event_type,
-            event_id = %audit_log.id,
-            aggregate_id = ?audit_log.aggregate_id,
-            aggregate_type = ?audit_log.aggregate_type,
-            "Domain event captured in audit log"
-        );
-
+        self.capture(audit_log).await;
         Ok(())
     }
 
@@ -125,6 +177,40 @@ mod tests {
         assert_eq!(log.aggregate_type, Some("TestAggregate".to_string()));
     }
 
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct GroupRenamed {
+        group_id: String,
+    }
+
+    impl DomainEvent for GroupRenamed {
+        fn event_type(&self) -> &'static str {
+            "test.group.renamed"
+        }
+
+        fn aggregate_id(&self) -> Option<String> {
+            Some(self.group_id.clone())
+        }
+
+        fn aggregate_type(&self) -> Option<&'static str> {
+            Some("Group")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_handler_takes_aggregate_from_envelope() {
+        let store = Arc::new(AuditLogStore::new());
+        let handler = AuditEventHandler::new(store.clone());
+
+        let envelope = EventEnvelope::new(GroupRenamed {
+            group_id: "group-1".to_string(),
+        });
+        handler.handle(envelope).await.unwrap();
+
+        let log = &store.all().await[0];
+        assert_eq!(log.aggregate_id, Some("group-1".to_string()));
+        assert_eq!(log.aggregate_type, Some("Group".to_string()));
+    }
+
     #[tokio::test]
     async fn test_audit_handler_multiple_events() {
         let store = Arc::new(AuditLogStore::new());
@@ -142,6 +228,20 @@ mod tests {
         assert_eq!(logs.len(), 5);
     }
 
+    #[tokio::test]
+    async fn test_audit_handler_ignores_redelivered_events() {
+        let store = Arc::new(AuditLogStore::new());
+        let handler = AuditEventHandler::new(store.clone());
+
+        let envelope = EventEnvelope::new(TestEvent {
+            message: "Test".to_string(),
+        });
+        handler.handle(envelope.clone()).await.unwrap();
+        handler.handle(envelope).await.unwrap();
+
+        assert_eq!(store.count_all().await, 1);
+    }
+
     #[tokio::test]
     async fn test_audit_handler_should_handle_all() {
         let store = Arc::new(AuditLogStore::new());
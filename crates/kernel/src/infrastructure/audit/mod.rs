@@ -5,13 +5,17 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+pub mod causal;
+pub mod cloudtrail;
 pub mod handler;
 pub mod query;
+pub mod redact;
+pub mod replay;
 
 #[cfg(test)]
 mod handler_test;
@@ -19,8 +23,15 @@ mod handler_test;
 mod query_test;
 
 // Re-export key types for convenience
+pub use causal::{CausalChain, CausalNode};
+pub use cloudtrail::{CloudTrailExporter, CloudTrailRecord, CloudTrailUserIdentity};
 pub use handler::AuditEventHandler;
 pub use query::AuditQuery;
+pub use redact::{
+    AuditLogsRedacted, RedactAuditLogsCommand, RedactAuditLogsError, RedactAuditLogsUseCase,
+    RedactionReport,
+};
+pub use replay::{ReplayEventsUseCase, ReplayFailure, ReplayProgress, ReplayReport};
 
 /// An audit log entry representing a captured domain event
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +51,10 @@ pub struct AuditLog {
     /// The event data as JSON
     pub event_data: serde_json::Value,
 
+    /// Schema version of `event_data`, needed to upcast it on replay
+    #[serde(default = "crate::application::ports::event_bus::initial_schema_version")]
+    pub schema_version: u32,
+
     /// When the event occurred
     pub occurred_at: DateTime<Utc>,
 
@@ -53,49 +68,204 @@ pub struct AuditLog {
     pub metadata: HashMap<String, String>,
 }
 
+/// How much an [`AuditLogStore`] keeps
+///
+/// A bounded store evicts the entries that occurred first to make room, so
+/// what it holds is always the most recent history. Evicted entries are only
+/// gone from memory: events persisted in an event store can still be
+/// replayed with [`ReplayEventsUseCase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "limit", rename_all = "snake_case")]
+pub enum AuditRetention {
+    /// Keep every entry
+    #[default]
+    Unbounded,
+    /// Keep at most this many entries
+    MaxEntries(usize),
+    /// Keep entries up to this many bytes, measured as serialized JSON
+    MaxBytes(usize),
+}
+
+impl AuditLog {
+    /// Size of the entry serialized as JSON, what [`AuditRetention::MaxBytes`]
+    /// counts
+    pub fn size_bytes(&self) -> usize {
+        serde_json::to_vec(self).map_or(0, |json| json.len())
+    }
+}
+
+/// The entries a store holds, with what bounding it needs
+#[derive(Debug, Default)]
+struct RetainedLogs {
+    /// Ordered by `occurred_at`, so the entry to evict is always the first
+    entries: VecDeque<AuditLog>,
+    /// IDs of `entries`, so duplicates are found without scanning them
+    ids: HashSet<Uuid>,
+    /// Entries per minute of `occurred_at`, kept as entries come and go so
+    /// rates are read without scanning `entries`
+    per_minute: BTreeMap<i64, usize>,
+    /// Combined size of `entries`, tracked only under `MaxBytes`
+    bytes: usize,
+    /// Entries evicted since the store was created
+    evicted: u64,
+}
+
+impl RetainedLogs {
+    fn push(&mut self, log: AuditLog, retention: AuditRetention) {
+        if let AuditRetention::MaxBytes(_) = retention {
+            self.bytes += log.size_bytes();
+        }
+        *self
+            .per_minute
+            .entry(minute_of(log.occurred_at))
+            .or_insert(0) += 1;
+        self.ids.insert(log.id);
+        // Entries mostly arrive in order, so this is usually the end
+        let position = self
+            .entries
+            .partition_point(|entry| entry.occurred_at <= log.occurred_at);
+        self.entries.insert(position, log);
+        self.evict(retention);
+    }
+
+    /// Evict the entries that occurred first until `retention` is met
+    fn evict(&mut self, retention: AuditRetention) {
+        let over = |logs: &Self| match retention {
+            AuditRetention::Unbounded => false,
+            AuditRetention::MaxEntries(max) => logs.entries.len() > max,
+            AuditRetention::MaxBytes(max) => logs.bytes > max,
+        };
+        while over(self) {
+            let Some(evicted) = self.entries.pop_front() else {
+                break;
+            };
+            self.ids.remove(&evicted.id);
+            let minute = minute_of(evicted.occurred_at);
+            if let Some(count) = self.per_minute.get_mut(&minute) {
+                *count -= 1;
+                if *count == 0 {
+                    self.per_minute.remove(&minute);
+                }
+            }
+            if let AuditRetention::MaxBytes(_) = retention {
+                self.bytes = self.bytes.saturating_sub(evicted.size_bytes());
+            }
+            self.evicted += 1;
+            tracing::debug!(event_id = %evicted.id, "Audit log entry evicted");
+        }
+    }
+}
+
+/// Whole minutes since the Unix epoch
+fn minute_of(time: DateTime<Utc>) -> i64 {
+    time.timestamp().div_euclid(60)
+}
+
 /// In-memory store for audit logs (production would use a database)
+///
+/// Unbounded by default; [`with_retention`](Self::with_retention) bounds it
+/// for long-running instances. Queries and [`stats`](Self::stats) only see
+/// the entries still retained, and [`evicted_count`](Self::evicted_count)
+/// tells how many were dropped.
 #[derive(Clone)]
 pub struct AuditLogStore {
-    logs: Arc<RwLock<Vec<AuditLog>>>,
+    logs: Arc<RwLock<RetainedLogs>>,
+    retention: AuditRetention,
 }
 
 impl AuditLogStore {
     /// Create a new empty audit log store
     pub fn new() -> Self {
         Self {
-            logs: Arc::new(RwLock::new(Vec::new())),
+            logs: Arc::new(RwLock::new(RetainedLogs::default())),
+            retention: AuditRetention::Unbounded,
+        }
+    }
+
+    /// Create an empty store keeping entries within `retention`
+    pub fn with_retention(retention: AuditRetention) -> Self {
+        Self {
+            retention,
+            ..Self::new()
         }
     }
 
+    pub fn retention(&self) -> AuditRetention {
+        self.retention
+    }
+
     /// Add a new audit log entry
+    ///
+    /// In a bounded store this may evict older entries, or the entry itself
+    /// if it occurred before everything retained.
     pub async fn add(&self, log: AuditLog) {
         let mut logs = self.logs.write().await;
-        logs.push(log);
+        logs.push(log, self.retention);
+    }
+
+    /// Add an audit log entry unless one with the same ID exists
+    ///
+    /// Returns whether the entry was added. Keeps the log free of duplicates
+    /// when an event is delivered or replayed more than once; an entry
+    /// evicted earlier is not remembered, and is added again.
+    pub async fn add_if_absent(&self, log: AuditLog) -> bool {
+        let mut logs = self.logs.write().await;
+        if logs.ids.contains(&log.id) {
+            return false;
+        }
+        logs.push(log, self.retention);
+        true
     }
 
-    /// Get all audit logs (use query() for filtering)
+    /// Get all audit logs, in the order they occurred (use query() for filtering)
     pub async fn all(&self) -> Vec<AuditLog> {
         let logs = self.logs.read().await;
-        logs.clone()
+        logs.entries.iter().cloned().collect()
     }
 
     /// Get a specific audit log by ID
     pub async fn get_by_id(&self, id: Uuid) -> Option<AuditLog> {
         let logs = self.logs.read().await;
-        logs.iter().find(|log| log.id == id).cloned()
+        logs.entries.iter().find(|log| log.id == id).cloned()
     }
 
     /// Count total audit logs
     pub async fn count_all(&self) -> usize {
         let logs = self.logs.read().await;
-        logs.len()
+        logs.entries.len()
+    }
+
+    /// Number of entries evicted to stay within the retention
+    ///
+    /// Non-zero means the store no longer holds the complete history.
+    pub async fn evicted_count(&self) -> u64 {
+        self.logs.read().await.evicted
+    }
+
+    /// Apply `change` to every entry, returning how many it changed
+    ///
+    /// `change` returns whether it modified the entry.
+    pub(super) async fn modify(&self, mut change: impl FnMut(&mut AuditLog) -> bool) -> usize {
+        let mut logs = self.logs.write().await;
+        let mut changed = 0;
+        for log in logs.entries.iter_mut() {
+            if change(log) {
+                changed += 1;
+            }
+        }
+        if changed > 0
+            && let AuditRetention::MaxBytes(_) = self.retention
+        {
+            logs.bytes = logs.entries.iter().map(AuditLog::size_bytes).sum();
+        }
+        changed
     }
 
     /// Clear all logs (useful for testing)
     #[cfg(test)]
     pub async fn clear(&self) {
         let mut logs = self.logs.write().await;
-        logs.clear();
+        *logs = RetainedLogs::default();
     }
 }
 
@@ -106,6 +276,8 @@ impl Default for AuditLogStore {
 }
 
 /// Statistics about audit logs
+///
+/// Computed over the entries the store retains.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditStats {
     pub total_events: usize,
@@ -113,11 +285,58 @@ pub struct AuditStats {
     pub events_by_aggregate_type: HashMap<String, usize>,
     pub oldest_event: Option<DateTime<Utc>>,
     pub newest_event: Option<DateTime<Utc>>,
+    /// Entries evicted to stay within the retention, not counted above
+    #[serde(default)]
+    pub evicted_events: u64,
+    /// Events per window over the last windows, oldest first, ending with
+    /// the window holding the current minute
+    #[serde(default)]
+    pub event_rate: Vec<RateWindow>,
+}
+
+/// Windows [`AuditStats::event_rate`] is computed over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateWindows {
+    /// Length of each window, in whole minutes
+    pub minutes: u32,
+    /// Number of windows
+    pub count: usize,
+}
+
+impl Default for RateWindows {
+    /// The last hour, minute by minute
+    fn default() -> Self {
+        Self {
+            minutes: 1,
+            count: 60,
+        }
+    }
+}
+
+/// Events that occurred within one window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RateWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub events: usize,
+    pub events_per_minute: f64,
 }
 
 impl AuditLogStore {
     /// Get statistics about the audit logs
+    ///
+    /// The event rate covers the last hour, minute by minute; see
+    /// [`stats_with_rate`](Self::stats_with_rate) for other windows.
     pub async fn stats(&self) -> AuditStats {
+        self.stats_with_rate(RateWindows::default()).await
+    }
+
+    /// Get statistics, with the event rate over `windows`
+    pub async fn stats_with_rate(&self, windows: RateWindows) -> AuditStats {
+        self.stats_at(Utc::now(), windows).await
+    }
+
+    async fn stats_at(&self, now: DateTime<Utc>, windows: RateWindows) -> AuditStats {
         let logs = self.logs.read().await;
 
         let mut events_by_type: HashMap<String, usize> = HashMap::new();
@@ -125,7 +344,7 @@ impl AuditLogStore {
         let mut oldest: Option<DateTime<Utc>> = None;
         let mut newest: Option<DateTime<Utc>> = None;
 
-        for log in logs.iter() {
+        for log in logs.entries.iter() {
             // Count by event type
             *events_by_type.entry(log.event_type.clone()).or_insert(0) += 1;
 
@@ -146,11 +365,44 @@ impl AuditLogStore {
         }
 
         AuditStats {
-            total_events: logs.len(),
+            total_events: logs.entries.len(),
             events_by_type,
             events_by_aggregate_type,
             oldest_event: oldest,
             newest_event: newest,
+            evicted_events: logs.evicted,
+            event_rate: event_rate(&logs.per_minute, now, windows),
         }
     }
 }
+
+/// Count the events of each window from the per-minute counts
+fn event_rate(
+    per_minute: &BTreeMap<i64, usize>,
+    now: DateTime<Utc>,
+    windows: RateWindows,
+) -> Vec<RateWindow> {
+    let width = i64::from(windows.minutes.max(1));
+    let end = minute_of(now) + 1;
+    (0..windows.count as i64)
+        .rev()
+        .map(|before| {
+            let window_end = end - before * width;
+            let window_start = window_end - width;
+            let events = per_minute
+                .range(window_start..window_end)
+                .map(|(_, n)| n)
+                .sum();
+            RateWindow {
+                start: minute_start(window_start),
+                end: minute_start(window_end),
+                events,
+                events_per_minute: events as f64 / width as f64,
+            }
+        })
+        .collect()
+}
+
+fn minute_start(minute: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(minute * 60, 0).unwrap_or_default()
+}
@@ -137,6 +137,7 @@ impl AuditLogStore {
         let logs = self.logs.read().await;
 
         let mut results: Vec<AuditLog> = logs
+            .entries
             .iter()
             .filter(|log| query.matches(log))
             .cloned()
@@ -155,13 +156,17 @@ impl AuditLogStore {
     /// Count audit logs matching the query
     pub async fn count(&self, query: AuditQuery) -> usize {
         let logs = self.logs.read().await;
-        logs.iter().filter(|log| query.matches(log)).count()
+        logs.entries
+            .iter()
+            .filter(|log| query.matches(log))
+            .count()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::audit::{AuditRetention, RateWindows};
     use chrono::Duration;
     use uuid::Uuid;
 
@@ -177,6 +182,7 @@ mod tests {
             aggregate_id: Some(aggregate_id.to_string()),
             aggregate_type: Some(aggregate_type.to_string()),
             event_data: serde_json::json!({}),
+            schema_version: 1,
             occurred_at,
             correlation_id: None,
             causation_id: None,
@@ -360,4 +366,168 @@ mod tests {
         assert_eq!(results.len(), 2);
         assert!(results.iter().all(|r| r.event_type == "user.created"));
     }
+
+    #[tokio::test]
+    async fn test_max_entries_evicts_earliest_occurred() {
+        let store = AuditLogStore::with_retention(AuditRetention::MaxEntries(2));
+        let now = Utc::now();
+
+        store
+            .add(create_test_log("user.updated", "user-1", "User", now))
+            .await;
+        // Arrives late but occurred first, so it is the one evicted
+        store
+            .add(create_test_log(
+                "user.created",
+                "user-1",
+                "User",
+                now - Duration::hours(1),
+            ))
+            .await;
+        store
+            .add(create_test_log(
+                "group.created",
+                "group-1",
+                "Group",
+                now + Duration::minutes(1),
+            ))
+            .await;
+
+        let stats = store.stats().await;
+        assert_eq!(stats.total_events, 2);
+        assert_eq!(stats.evicted_events, 1);
+        assert_eq!(stats.oldest_event, Some(now));
+        assert!(!stats.events_by_type.contains_key("user.created"));
+        assert_eq!(store.evicted_count().await, 1);
+        assert_eq!(store.count(AuditQuery::new()).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_max_bytes_keeps_most_recent_within_budget() {
+        let now = Utc::now();
+        let logs: Vec<AuditLog> = (0..4)
+            .map(|i| {
+                create_test_log(
+                    "user.created",
+                    &format!("user-{}", i),
+                    "User",
+                    now + Duration::seconds(i),
+                )
+            })
+            .collect();
+        let budget = logs[2].size_bytes() + logs[3].size_bytes();
+        let store = AuditLogStore::with_retention(AuditRetention::MaxBytes(budget));
+
+        for log in logs.iter().cloned() {
+            store.add(log).await;
+        }
+
+        let kept: Vec<Option<String>> = store
+            .all()
+            .await
+            .into_iter()
+            .map(|log| log.aggregate_id)
+            .collect();
+        assert_eq!(
+            kept,
+            vec![Some("user-2".to_string()), Some("user-3".to_string())]
+        );
+        assert_eq!(store.evicted_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_if_absent_forgets_evicted_ids() {
+        let store = AuditLogStore::with_retention(AuditRetention::MaxEntries(1));
+        let now = Utc::now();
+        let first = create_test_log("user.created", "user-1", "User", now);
+
+        assert!(store.add_if_absent(first.clone()).await);
+        assert!(!store.add_if_absent(first.clone()).await);
+
+        store
+            .add(create_test_log(
+                "user.updated",
+                "user-1",
+                "User",
+                now + Duration::minutes(1),
+            ))
+            .await;
+        assert!(store.get_by_id(first.id).await.is_none());
+
+        // Evicted, so no longer a duplicate; it occurred first and goes again
+        assert!(store.add_if_absent(first.clone()).await);
+        assert_eq!(store.count_all().await, 1);
+        assert_eq!(store.evicted_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_store_never_evicts() {
+        let store = AuditLogStore::new();
+        for i in 0..50 {
+            store
+                .add(create_test_log(
+                    "user.created",
+                    &format!("user-{}", i),
+                    "User",
+                    Utc::now(),
+                ))
+                .await;
+        }
+
+        assert_eq!(store.retention(), AuditRetention::Unbounded);
+        assert_eq!(store.count_all().await, 50);
+        assert_eq!(store.stats().await.evicted_events, 0);
+    }
+
+    #[tokio::test]
+    async fn test_stats_event_rate_per_window() {
+        let store = AuditLogStore::new();
+        let now = Utc::now();
+        for minutes_ago in [0, 0, 1, 4, 5, 90] {
+            store
+                .add(create_test_log(
+                    "user.created",
+                    "user-1",
+                    "User",
+                    now - Duration::minutes(minutes_ago),
+                ))
+                .await;
+        }
+
+        let windows = RateWindows {
+            minutes: 2,
+            count: 3,
+        };
+        let stats = store.stats_at(now, windows).await;
+
+        let events: Vec<usize> = stats.event_rate.iter().map(|w| w.events).collect();
+        assert_eq!(events, vec![2, 0, 3]);
+        assert_eq!(stats.event_rate[2].events_per_minute, 1.5);
+        assert!(stats.event_rate[2].start <= now && now < stats.event_rate[2].end);
+        assert_eq!(stats.event_rate[0].end, stats.event_rate[1].start);
+        assert_eq!(stats.total_events, 6);
+    }
+
+    #[tokio::test]
+    async fn test_event_rate_forgets_evicted_entries() {
+        let store = AuditLogStore::with_retention(AuditRetention::MaxEntries(1));
+        let now = Utc::now();
+        store
+            .add(create_test_log(
+                "user.created",
+                "user-1",
+                "User",
+                now - Duration::minutes(1),
+            ))
+            .await;
+        store
+            .add(create_test_log("user.created", "user-2", "User", now))
+            .await;
+
+        let stats = store.stats_at(now, RateWindows::default()).await;
+
+        assert_eq!(stats.event_rate.len(), 60);
+        assert_eq!(stats.event_rate.iter().map(|w| w.events).sum::<usize>(), 1);
+        assert_eq!(stats.event_rate[59].events, 1);
+    }
 }
@@ -0,0 +1,451 @@
+//! Erasure of a data subject's personal data from the audit log
+//!
+//! Redaction keeps the audit trail intact: every entry stays, with its ID,
+//! event type, timestamps, correlation and structure, but the values that
+//! identify the subject are overwritten with [`TOMBSTONE`]. The originals are
+//! not kept anywhere, so redaction can't be undone.
+//!
+//! Only the audit log is redacted. The event store still holds the original
+//! payloads and must be purged separately; until then, replaying it restores
+//! entries this store no longer holds, e.g. after eviction.
+
+use super::{AuditLog, AuditLogStore};
+use crate::application::current_correlation_id;
+use crate::application::ports::event_bus::{DomainEvent, EventEnvelope, EventPublisher};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// What redacted values are replaced with
+pub const TOMBSTONE: &str = "[REDACTED]";
+
+/// Event type of the entry recording a redaction
+pub const REDACTION_EVENT_TYPE: &str = "audit.logs.redacted";
+
+/// Metadata key set on redacted entries, to when they were redacted
+pub const REDACTED_AT_METADATA_KEY: &str = "redacted_at";
+
+/// Fields holding personal data whatever their value, in entries that
+/// name the subject
+pub const DEFAULT_PII_FIELDS: &[&str] = &[
+    "email",
+    "name",
+    "username",
+    "display_name",
+    "full_name",
+    "phone",
+    "address",
+    "ip_address",
+];
+
+/// Erase one data subject from the audit log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactAuditLogsCommand {
+    /// Identifier of the subject, e.g. a user ID or HRN
+    pub subject: String,
+
+    /// Other values identifying the same subject, e.g. their email
+    #[serde(default)]
+    pub aliases: Vec<String>,
+
+    /// Why the data is erased, e.g. the erasure request reference
+    pub reason: String,
+
+    /// Who asked for the erasure
+    pub requested_by: Option<String>,
+}
+
+impl RedactAuditLogsCommand {
+    pub fn new(subject: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            aliases: Vec::new(),
+            reason: reason.into(),
+            requested_by: None,
+        }
+    }
+
+    pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    pub fn with_requested_by(mut self, requested_by: impl Into<String>) -> Self {
+        self.requested_by = Some(requested_by.into());
+        self
+    }
+}
+
+/// Event recording a redaction, published like any other so the audit log
+/// captures it; it says how many entries were affected and why, but not who
+/// the subject was
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogsRedacted {
+    pub affected_entries: usize,
+    pub reason: String,
+    pub requested_by: Option<String>,
+}
+
+impl DomainEvent for AuditLogsRedacted {
+    fn event_type(&self) -> &'static str {
+        REDACTION_EVENT_TYPE
+    }
+}
+
+/// Outcome of a redaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionReport {
+    /// Entries that named the subject and were redacted
+    pub affected_entries: usize,
+
+    /// ID of the [`AuditLogsRedacted`] event recording this redaction
+    pub redaction_event_id: Uuid,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RedactAuditLogsError {
+    #[error("Redaction needs a non-empty subject identifier")]
+    EmptySubject,
+
+    #[error("Redaction needs a reason")]
+    MissingReason,
+
+    /// The entries were redacted, but the event recording it was not published
+    #[error("Audit logs redacted, but the redaction could not be recorded: {0}")]
+    NotRecorded(String),
+}
+
+/// Redacts a data subject from the audit log, recording that it did
+///
+/// An entry names the subject when its aggregate ID, a metadata value or a
+/// string anywhere in its event data is one of the subject's identifiers, or
+/// holds one as a whole segment, like the `User/alice` of an HRN. An
+/// identifier that is only part of a longer value doesn't count: erasing
+/// `user-1` leaves `user-10` alone. In those entries, every such string and
+/// every value under a PII field is replaced with [`TOMBSTONE`]. The
+/// redaction is then published as an [`AuditLogsRedacted`] event.
+pub struct RedactAuditLogsUseCase<P> {
+    store: Arc<AuditLogStore>,
+    publisher: Arc<P>,
+    pii_fields: Vec<String>,
+}
+
+impl<P: EventPublisher> RedactAuditLogsUseCase<P> {
+    pub fn new(store: Arc<AuditLogStore>, publisher: Arc<P>) -> Self {
+        Self {
+            store,
+            publisher,
+            pii_fields: DEFAULT_PII_FIELDS.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// Also treat `field` as personal data
+    pub fn with_pii_field(mut self, field: impl Into<String>) -> Self {
+        self.pii_fields.push(field.into());
+        self
+    }
+
+    /// Redact the subject of `command`
+    ///
+    /// # Errors
+    ///
+    /// Fails, without changing anything, if the subject or reason is empty.
+    /// Fails with [`RedactAuditLogsError::NotRecorded`] if the entries were
+    /// redacted but the event recording it could not be published.
+    pub async fn execute(
+        &self,
+        command: RedactAuditLogsCommand,
+    ) -> Result<RedactionReport, RedactAuditLogsError> {
+        if command.subject.trim().is_empty() {
+            return Err(RedactAuditLogsError::EmptySubject);
+        }
+        if command.reason.trim().is_empty() {
+            return Err(RedactAuditLogsError::MissingReason);
+        }
+
+        let identifiers: Vec<&str> = std::iter::once(command.subject.as_str())
+            .chain(command.aliases.iter().map(String::as_str))
+            .map(str::trim)
+            .filter(|identifier| !identifier.is_empty())
+            .collect();
+        let redacted_at = Utc::now();
+        let affected_entries = self
+            .store
+            .modify(|log| {
+                if !names_subject(log, &identifiers) {
+                    return false;
+                }
+                self.redact(log, &identifiers);
+                log.metadata.insert(
+                    REDACTED_AT_METADATA_KEY.to_string(),
+                    redacted_at.to_rfc3339(),
+                );
+                true
+            })
+            .await;
+
+        let event = AuditLogsRedacted {
+            affected_entries,
+            reason: command.reason,
+            requested_by: command.requested_by,
+        };
+        let envelope = match current_correlation_id() {
+            Some(correlation_id) => EventEnvelope::with_correlation(event, correlation_id),
+            None => EventEnvelope::new(event),
+        };
+        let redaction_event_id = envelope.event_id;
+        self.publisher
+            .publish_with_envelope(envelope)
+            .await
+            .map_err(|e| RedactAuditLogsError::NotRecorded(e.to_string()))?;
+
+        tracing::info!(
+            affected_entries,
+            %redaction_event_id,
+            "Audit logs redacted"
+        );
+        Ok(RedactionReport {
+            affected_entries,
+            redaction_event_id,
+        })
+    }
+
+    fn redact(&self, log: &mut AuditLog, identifiers: &[&str]) {
+        if let Some(aggregate_id) = &mut log.aggregate_id
+            && names_any(aggregate_id, identifiers)
+        {
+            *aggregate_id = TOMBSTONE.to_string();
+        }
+        for value in log.metadata.values_mut() {
+            if names_any(value, identifiers) {
+                *value = TOMBSTONE.to_string();
+            }
+        }
+        self.redact_value(&mut log.event_data, identifiers);
+    }
+
+    fn redact_value(&self, value: &mut Value, identifiers: &[&str]) {
+        match value {
+            Value::String(text) if names_any(text, identifiers) => {
+                *text = TOMBSTONE.to_string();
+            }
+            Value::Object(fields) => {
+                for (field, value) in fields.iter_mut() {
+                    if self.pii_fields.iter().any(|pii| pii == field) {
+                        *value = tombstone_of(value);
+                    } else {
+                        self.redact_value(value, identifiers);
+                    }
+                }
+            }
+            Value::Array(values) => values
+                .iter_mut()
+                .for_each(|value| self.redact_value(value, identifiers)),
+            _ => {}
+        }
+    }
+}
+
+/// Whether the subject appears anywhere in `log`
+fn names_subject(log: &AuditLog, identifiers: &[&str]) -> bool {
+    log.aggregate_id
+        .as_deref()
+        .is_some_and(|id| names_any(id, identifiers))
+        || log
+            .metadata
+            .values()
+            .any(|value| names_any(value, identifiers))
+        || value_names_subject(&log.event_data, identifiers)
+}
+
+fn value_names_subject(value: &Value, identifiers: &[&str]) -> bool {
+    match value {
+        Value::String(text) => names_any(text, identifiers),
+        Value::Object(fields) => fields
+            .values()
+            .any(|value| value_names_subject(value, identifiers)),
+        Value::Array(values) => values
+            .iter()
+            .any(|value| value_names_subject(value, identifiers)),
+        _ => false,
+    }
+}
+
+/// Whether `text` is one of `identifiers` or holds one as a whole segment
+fn names_any(text: &str, identifiers: &[&str]) -> bool {
+    identifiers.iter().any(|identifier| {
+        text.match_indices(identifier).any(|(start, _)| {
+            let before = text[..start].chars().next_back();
+            let after = text[start + identifier.len()..].chars().next();
+            before.is_none_or(ends_segment) && after.is_none_or(ends_segment)
+        })
+    })
+}
+
+/// Whether `c` separates segments, as the `:` and `/` of an HRN do; the
+/// characters IDs and emails are made of don't
+fn ends_segment(c: char) -> bool {
+    !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '@' | '+'))
+}
+
+/// Tombstone keeping the shape of `value`: nested fields and list lengths
+/// stay, every leaf is redacted
+fn tombstone_of(value: &Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(field, value)| (field.clone(), tombstone_of(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.iter().map(tombstone_of).collect()),
+        Value::Null => Value::Null,
+        _ => Value::String(TOMBSTONE.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::event_bus::EventBus;
+    use crate::infrastructure::InMemoryEventBus;
+    use crate::infrastructure::audit::{AuditEventHandler, AuditQuery};
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn log(aggregate_id: &str, event_data: Value) -> AuditLog {
+        AuditLog {
+            id: Uuid::new_v4(),
+            event_type: "iam.user.created".to_string(),
+            aggregate_id: Some(aggregate_id.to_string()),
+            aggregate_type: Some("User".to_string()),
+            event_data,
+            schema_version: 1,
+            occurred_at: Utc::now(),
+            correlation_id: Some("request-1".to_string()),
+            causation_id: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_redacts_subject_and_keeps_structure() {
+        let store = Arc::new(AuditLogStore::new());
+        let original = log(
+            "hrn:hodei:iam::123:User/alice",
+            json!({
+                "name": "Alice Doe",
+                "email": "alice@example.com",
+                "groups": ["hrn:hodei:iam::123:Group/devs"],
+                "profile": {"phone": {"mobile": "555"}, "created_by": "User/alice"},
+            }),
+        );
+        store.add(original.clone()).await;
+        store
+            .add(log("hrn:hodei:iam::123:User/bob", json!({"name": "Bob"})))
+            .await;
+
+        let report = RedactAuditLogsUseCase::new(store.clone(), Arc::new(InMemoryEventBus::new()))
+            .execute(RedactAuditLogsCommand::new(
+                "User/alice",
+                "erasure request 42",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(report.affected_entries, 1);
+        let redacted = store.get_by_id(original.id).await.unwrap();
+        assert_eq!(redacted.event_type, original.event_type);
+        assert_eq!(redacted.occurred_at, original.occurred_at);
+        assert_eq!(redacted.correlation_id, original.correlation_id);
+        assert_eq!(redacted.aggregate_id.as_deref(), Some(TOMBSTONE));
+        assert_eq!(
+            redacted.event_data,
+            json!({
+                "name": TOMBSTONE,
+                "email": TOMBSTONE,
+                "groups": ["hrn:hodei:iam::123:Group/devs"],
+                "profile": {"phone": {"mobile": TOMBSTONE}, "created_by": TOMBSTONE},
+            })
+        );
+        assert!(redacted.metadata.contains_key(REDACTED_AT_METADATA_KEY));
+
+        let bob = store
+            .query(AuditQuery::new().with_aggregate_id("hrn:hodei:iam::123:User/bob"))
+            .await;
+        assert_eq!(bob[0].event_data, json!({"name": "Bob"}));
+    }
+
+    #[tokio::test]
+    async fn test_longer_identifiers_are_not_redacted() {
+        let store = Arc::new(AuditLogStore::new());
+        let user_1 = log("hrn:hodei:iam::123:User/user-1", json!({"by": "user-1"}));
+        let user_10 = log("hrn:hodei:iam::123:User/user-10", json!({"by": "user-10"}));
+        store.add(user_1.clone()).await;
+        store.add(user_10.clone()).await;
+
+        let report = RedactAuditLogsUseCase::new(store.clone(), Arc::new(InMemoryEventBus::new()))
+            .execute(RedactAuditLogsCommand::new("user-1", "erasure request 9"))
+            .await
+            .unwrap();
+
+        assert_eq!(report.affected_entries, 1);
+        let redacted = store.get_by_id(user_1.id).await.unwrap();
+        assert_eq!(redacted.event_data, json!({"by": TOMBSTONE}));
+        let untouched = store.get_by_id(user_10.id).await.unwrap();
+        assert_eq!(untouched.aggregate_id, user_10.aggregate_id);
+        assert_eq!(untouched.event_data, user_10.event_data);
+    }
+
+    #[tokio::test]
+    async fn test_redaction_is_recorded_without_the_subject() {
+        let store = Arc::new(AuditLogStore::new());
+        store
+            .add(log("carol", json!({"email": "carol@example.com"})))
+            .await;
+        store
+            .add(log("other", json!({"invited": "carol@example.com"})))
+            .await;
+        let bus = Arc::new(InMemoryEventBus::new());
+        let _subscription = bus
+            .subscribe::<AuditLogsRedacted, _>(Arc::new(AuditEventHandler::new(store.clone())))
+            .await
+            .unwrap();
+
+        let report = RedactAuditLogsUseCase::new(store.clone(), bus.clone())
+            .execute(
+                RedactAuditLogsCommand::new("carol", "erasure request 7")
+                    .with_alias("carol@example.com")
+                    .with_requested_by("dpo"),
+            )
+            .await
+            .unwrap();
+        assert!(bus.settle(Duration::from_secs(1)).await);
+
+        assert_eq!(report.affected_entries, 2);
+        let record = store.get_by_id(report.redaction_event_id).await.unwrap();
+        assert_eq!(record.event_type, REDACTION_EVENT_TYPE);
+        assert_eq!(record.event_data["affected_entries"], json!(2));
+        assert_eq!(record.event_data["requested_by"], json!("dpo"));
+        assert!(!record.event_data.to_string().contains("carol"));
+        assert_eq!(store.count_all().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_empty_subject() {
+        let store = Arc::new(AuditLogStore::new());
+        let use_case =
+            RedactAuditLogsUseCase::new(store.clone(), Arc::new(InMemoryEventBus::new()));
+
+        let result = use_case
+            .execute(RedactAuditLogsCommand::new("  ", "erasure"))
+            .await;
+
+        assert_eq!(result, Err(RedactAuditLogsError::EmptySubject));
+        assert_eq!(store.count_all().await, 0);
+    }
+}
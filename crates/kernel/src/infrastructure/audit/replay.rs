@@ -0,0 +1,355 @@
+//! Replay of persisted events into the audit log
+//!
+//! Events published before the audit handler was subscribed never reached
+//! the audit log. Replaying the event store feeds them through the
+//! [`AuditEventHandler`] to backfill that history. Entries are keyed by
+//! event ID, so replaying again (or replaying events the handler already
+//! captured live) adds no duplicates.
+
+use super::AuditEventHandler;
+use crate::application::ports::event_registry::DomainEventRegistry;
+use crate::application::ports::event_store::{EventStore, EventStoreError, StoredEvent};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Events read from the store per batch by default
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// An event that could not be replayed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayFailure {
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub error: String,
+}
+
+/// Progress of a running replay, reported after every batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayProgress {
+    /// Events read from the store so far
+    pub processed: usize,
+
+    /// Events in the store when the replay started
+    pub total: usize,
+}
+
+/// Outcome of a replay
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// Events read from the store
+    pub processed: usize,
+
+    /// Events added to the audit log
+    pub replayed: usize,
+
+    /// Events already in the audit log
+    pub skipped: usize,
+
+    /// Events that could not be replayed
+    pub failed: Vec<ReplayFailure>,
+}
+
+/// Rebuilds the audit log from the event store
+pub struct ReplayEventsUseCase {
+    events: Arc<dyn EventStore>,
+    handler: Arc<AuditEventHandler>,
+    registry: Option<DomainEventRegistry>,
+    batch_size: usize,
+}
+
+impl ReplayEventsUseCase {
+    /// Create a replay of `events` into the audit log behind `handler`
+    pub fn new(events: Arc<dyn EventStore>, handler: Arc<AuditEventHandler>) -> Self {
+        Self {
+            events,
+            handler,
+            registry: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Check every payload against its registered type before replaying it
+    ///
+    /// Events whose type is unknown to the registry, or whose payload can't
+    /// be upcast and deserialized, are reported as failures instead of
+    /// being added to the audit log.
+    pub fn with_registry(mut self, registry: DomainEventRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Read the store in batches of `batch_size` events
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Replay every stored event
+    ///
+    /// # Errors
+    ///
+    /// Fails only if the event store can't be read; events that fail to
+    /// replay are listed in the report.
+    pub async fn execute(&self) -> Result<ReplayReport, EventStoreError> {
+        self.execute_with_progress(|_| {}).await
+    }
+
+    /// Replay every stored event, calling `on_progress` after each batch
+    pub async fn execute_with_progress(
+        &self,
+        on_progress: impl Fn(ReplayProgress) + Send + Sync,
+    ) -> Result<ReplayReport, EventStoreError> {
+        let total = self.events.count().await?;
+        tracing::info!(total, "Replaying stored events into the audit log");
+
+        let mut report = ReplayReport::default();
+        loop {
+            let batch = self
+                .events
+                .read_batch(report.processed, self.batch_size)
+                .await?;
+            if batch.is_empty() {
+                break;
+            }
+
+            for event in &batch {
+                self.replay(event, &mut report).await;
+            }
+            report.processed += batch.len();
+
+            let progress = ReplayProgress {
+                processed: report.processed,
+                total,
+            };
+            tracing::info!(
+                processed = progress.processed,
+                total = progress.total,
+                "Audit replay progress"
+            );
+            on_progress(progress);
+        }
+
+        tracing::info!(
+            processed = report.processed,
+            replayed = report.replayed,
+            skipped = report.skipped,
+            failed = report.failed.len(),
+            "Audit replay finished"
+        );
+        Ok(report)
+    }
+
+    async fn replay(&self, event: &StoredEvent, report: &mut ReplayReport) {
+        match self.try_replay(event).await {
+            Ok(true) => report.replayed += 1,
+            Ok(false) => report.skipped += 1,
+            Err(error) => {
+                tracing::warn!(
+                    event_id = %event.event_id,
+                    event_type = %event.event_type,
+                    error = %error,
+                    "Stored event could not be replayed"
+                );
+                report.failed.push(ReplayFailure {
+                    event_id: event.event_id,
+                    event_type: event.event_type.clone(),
+                    error,
+                });
+            }
+        }
+    }
+
+    /// Whether the event was added to the audit log
+    async fn try_replay(&self, event: &StoredEvent) -> Result<bool, String> {
+        if let Some(registry) = &self.registry {
+            let payload = event.payload_json().map_err(|e| e.to_string())?;
+            registry
+                .deserialize_versioned(&event.event_type, event.schema_version, payload)
+                .map_err(|e| e.to_string())?;
+        }
+
+        self.handler
+            .handle_stored(event)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::event_bus::{DomainEvent, EventEnvelope, EventHandler};
+    use crate::application::ports::event_format::SerializationFormat;
+    use crate::infrastructure::audit::AuditLogStore;
+    use crate::infrastructure::in_memory_event_store::InMemoryEventStore;
+    use chrono::{Duration, Utc};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct UserCreated {
+        user_id: String,
+    }
+
+    impl DomainEvent for UserCreated {
+        fn event_type(&self) -> &'static str {
+            "iam.user.created"
+        }
+
+        fn aggregate_id(&self) -> Option<String> {
+            Some(self.user_id.clone())
+        }
+    }
+
+    fn envelope(user_id: &str, days_ago: i64) -> EventEnvelope<UserCreated> {
+        let mut envelope = EventEnvelope::new(UserCreated {
+            user_id: user_id.to_string(),
+        })
+        .with_metadata("aggregate_type".to_string(), "User".to_string());
+        envelope.occurred_at = Utc::now() - Duration::days(days_ago);
+        envelope
+    }
+
+    async fn store_with(envelopes: &[EventEnvelope<UserCreated>]) -> Arc<InMemoryEventStore> {
+        let store = Arc::new(InMemoryEventStore::new());
+        for envelope in envelopes {
+            store
+                .append(StoredEvent::from_envelope(envelope, SerializationFormat::Json).unwrap())
+                .await
+                .unwrap();
+        }
+        store
+    }
+
+    fn audit() -> (Arc<AuditLogStore>, Arc<AuditEventHandler>) {
+        let logs = Arc::new(AuditLogStore::new());
+        let handler = Arc::new(AuditEventHandler::new(logs.clone()));
+        (logs, handler)
+    }
+
+    #[tokio::test]
+    async fn test_replay_backfills_audit_log_with_original_timestamps() {
+        let old = envelope("user-1", 30);
+        let events = store_with(&[old.clone(), envelope("user-2", 7)]).await;
+        let (logs, handler) = audit();
+
+        let report = ReplayEventsUseCase::new(events, handler)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(report.processed, 2);
+        assert_eq!(report.replayed, 2);
+        assert!(report.failed.is_empty());
+
+        let log = logs.get_by_id(old.event_id).await.unwrap();
+        assert_eq!(log.occurred_at, old.occurred_at);
+        assert_eq!(log.event_type, "iam.user.created");
+        assert_eq!(log.aggregate_id, Some("user-1".to_string()));
+        assert_eq!(log.aggregate_type, Some("User".to_string()));
+        assert_eq!(log.event_data, serde_json::json!({ "user_id": "user-1" }));
+    }
+
+    #[tokio::test]
+    async fn test_replay_is_idempotent() {
+        let captured_live = envelope("user-1", 1);
+        let events = store_with(&[captured_live.clone(), envelope("user-2", 1)]).await;
+        let (logs, handler) = audit();
+        handler.handle(captured_live).await.unwrap();
+
+        let replay = ReplayEventsUseCase::new(events, handler);
+        let first = replay.execute().await.unwrap();
+        let second = replay.execute().await.unwrap();
+
+        assert_eq!((first.replayed, first.skipped), (1, 1));
+        assert_eq!((second.replayed, second.skipped), (0, 2));
+        assert_eq!(logs.count_all().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_replay_reports_progress_per_batch() {
+        let envelopes: Vec<_> = (0..5).map(|i| envelope(&format!("user-{i}"), 1)).collect();
+        let events = store_with(&envelopes).await;
+        let (_, handler) = audit();
+        let progress = Mutex::new(Vec::new());
+
+        ReplayEventsUseCase::new(events, handler)
+            .with_batch_size(2)
+            .execute_with_progress(|p| progress.lock().unwrap().push(p))
+            .await
+            .unwrap();
+
+        let processed: Vec<_> = progress
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|p| (p.processed, p.total))
+            .collect();
+        assert_eq!(processed, vec![(2, 5), (4, 5), (5, 5)]);
+    }
+
+    #[tokio::test]
+    async fn test_replay_reports_events_that_fail_validation() {
+        let valid = envelope("user-1", 1);
+        let events = store_with(std::slice::from_ref(&valid)).await;
+        let mut broken =
+            StoredEvent::from_envelope(&envelope("user-2", 1), SerializationFormat::Json).unwrap();
+        broken.payload = br#"{"unexpected":true}"#.to_vec();
+        let mut unknown =
+            StoredEvent::from_envelope(&envelope("user-3", 1), SerializationFormat::Json).unwrap();
+        unknown.event_type = "iam.user.renamed".to_string();
+        events.append(broken.clone()).await.unwrap();
+        events.append(unknown.clone()).await.unwrap();
+
+        let registry = DomainEventRegistry::new()
+            .with_event::<UserCreated>("iam.user.created")
+            .unwrap();
+        let (logs, handler) = audit();
+
+        let report = ReplayEventsUseCase::new(events, handler)
+            .with_registry(registry)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(report.processed, 3);
+        assert_eq!(report.replayed, 1);
+        let failed: Vec<_> = report.failed.iter().map(|f| f.event_id).collect();
+        assert_eq!(failed, vec![broken.event_id, unknown.event_id]);
+        assert!(report.failed[1].error.contains("Unknown event type"));
+        assert_eq!(logs.count_all().await, 1);
+        assert!(logs.get_by_id(valid.event_id).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_replay_reads_mixed_format_stores() {
+        let json = envelope("user-1", 2);
+        let msgpack = envelope("user-2", 1);
+        let events = Arc::new(InMemoryEventStore::new());
+        events
+            .append(StoredEvent::from_envelope(&json, SerializationFormat::Json).unwrap())
+            .await
+            .unwrap();
+        events
+            .append(StoredEvent::from_envelope(&msgpack, SerializationFormat::MessagePack).unwrap())
+            .await
+            .unwrap();
+        let mut corrupt =
+            StoredEvent::from_envelope(&envelope("user-3", 1), SerializationFormat::MessagePack)
+                .unwrap();
+        corrupt.payload = vec![0xc1];
+        events.append(corrupt.clone()).await.unwrap();
+        let (logs, handler) = audit();
+
+        let report = ReplayEventsUseCase::new(events, handler)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(report.replayed, 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].event_id, corrupt.event_id);
+        let log = logs.get_by_id(msgpack.event_id).await.unwrap();
+        assert_eq!(log.event_data, serde_json::json!({ "user_id": "user-2" }));
+    }
+}
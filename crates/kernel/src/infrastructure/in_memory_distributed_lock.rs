@@ -0,0 +1,210 @@
+//! In-memory distributed lock implementation
+//!
+//! Only coordinates callers inside one process, so it is suitable for
+//! single-node deployments, development and tests. Clustered deployments
+//! need a lock backed by shared storage.
+
+use crate::application::ports::distributed_lock::{
+    DistributedLock, DistributedLockError, FencingToken, LockLease,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, Default)]
+struct LockState {
+    /// Current lease, possibly expired
+    lease: Option<LockLease>,
+    /// Last token issued for this lock; kept after release so tokens never repeat
+    last_token: u64,
+}
+
+/// Process-local [`DistributedLock`]
+#[derive(Clone, Default)]
+pub struct InMemoryDistributedLock {
+    locks: Arc<Mutex<HashMap<String, LockState>>>,
+}
+
+impl InMemoryDistributedLock {
+    /// Create a lock registry with no locks held
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn expiry_after(ttl: Duration) -> Result<chrono::DateTime<Utc>, DistributedLockError> {
+    let ttl = chrono::Duration::from_std(ttl)
+        .map_err(|e| DistributedLockError::Backend(format!("Invalid lock TTL: {e}")))?;
+    Ok(Utc::now() + ttl)
+}
+
+fn is_current(state: &LockState, lease: &LockLease) -> bool {
+    state.lease.as_ref().is_some_and(|current| {
+        current.fencing_token == lease.fencing_token && !current.is_expired_at(Utc::now())
+    })
+}
+
+#[async_trait]
+impl DistributedLock for InMemoryDistributedLock {
+    async fn try_acquire(
+        &self,
+        lock_name: &str,
+        holder_id: &str,
+        ttl: Duration,
+    ) -> Result<Option<LockLease>, DistributedLockError> {
+        let expires_at = expiry_after(ttl)?;
+        let mut locks = self.locks.lock().unwrap();
+        let state = locks.entry(lock_name.to_string()).or_default();
+
+        if let Some(current) = &state.lease
+            && !current.is_expired_at(Utc::now())
+        {
+            return Ok(None);
+        }
+
+        state.last_token += 1;
+        let lease = LockLease {
+            lock_name: lock_name.to_string(),
+            holder_id: holder_id.to_string(),
+            fencing_token: FencingToken(state.last_token),
+            expires_at,
+        };
+        state.lease = Some(lease.clone());
+        tracing::debug!(
+            lock = lock_name,
+            holder = holder_id,
+            token = state.last_token,
+            "Lock acquired"
+        );
+        Ok(Some(lease))
+    }
+
+    async fn renew(
+        &self,
+        lease: &LockLease,
+        ttl: Duration,
+    ) -> Result<LockLease, DistributedLockError> {
+        let expires_at = expiry_after(ttl)?;
+        let mut locks = self.locks.lock().unwrap();
+        let state = locks
+            .get_mut(&lease.lock_name)
+            .filter(|state| is_current(state, lease))
+            .ok_or_else(|| DistributedLockError::NotHeld(lease.lock_name.clone()))?;
+
+        let renewed = LockLease {
+            expires_at,
+            ..lease.clone()
+        };
+        state.lease = Some(renewed.clone());
+        Ok(renewed)
+    }
+
+    async fn release(&self, lease: &LockLease) -> Result<(), DistributedLockError> {
+        let mut locks = self.locks.lock().unwrap();
+        if let Some(state) = locks.get_mut(&lease.lock_name)
+            && is_current(state, lease)
+        {
+            state.lease = None;
+            tracing::debug!(lock = %lease.lock_name, holder = %lease.holder_id, "Lock released");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(30);
+
+    #[tokio::test]
+    async fn test_only_one_holder_at_a_time() {
+        let lock = InMemoryDistributedLock::new();
+
+        let lease = lock
+            .try_acquire("index-optimizer", "node-a", TTL)
+            .await
+            .unwrap();
+        let contended = lock
+            .try_acquire("index-optimizer", "node-b", TTL)
+            .await
+            .unwrap();
+
+        assert!(lease.is_some());
+        assert!(contended.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_release_frees_the_lock_with_a_newer_token() {
+        let lock = InMemoryDistributedLock::new();
+
+        let first = lock
+            .try_acquire("outbox-relay", "node-a", TTL)
+            .await
+            .unwrap()
+            .unwrap();
+        lock.release(&first).await.unwrap();
+        let second = lock
+            .try_acquire("outbox-relay", "node-b", TTL)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(second.fencing_token > first.fencing_token);
+    }
+
+    #[tokio::test]
+    async fn test_expired_lease_can_be_taken_over_but_not_renewed() {
+        let lock = InMemoryDistributedLock::new();
+
+        let crashed = lock
+            .try_acquire("outbox-relay", "node-a", Duration::from_millis(20))
+            .await
+            .unwrap()
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let takeover = lock
+            .try_acquire("outbox-relay", "node-b", TTL)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(takeover.fencing_token > crashed.fencing_token);
+        assert!(matches!(
+            lock.renew(&crashed, TTL).await,
+            Err(DistributedLockError::NotHeld(_))
+        ));
+        // A late release from the stale holder must not free the new lease
+        lock.release(&crashed).await.unwrap();
+        assert!(
+            lock.try_acquire("outbox-relay", "node-c", TTL)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_renew_extends_the_lease_and_keeps_the_token() {
+        let lock = InMemoryDistributedLock::new();
+
+        let lease = lock
+            .try_acquire("index-optimizer", "node-a", Duration::from_millis(20))
+            .await
+            .unwrap()
+            .unwrap();
+        let renewed = lock.renew(&lease, TTL).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert_eq!(renewed.fencing_token, lease.fencing_token);
+        assert!(
+            lock.try_acquire("index-optimizer", "node-b", TTL)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
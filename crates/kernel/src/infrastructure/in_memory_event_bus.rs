@@ -8,14 +8,22 @@
 //! For distributed systems, use a message broker adapter (NATS, Kafka, etc.)
 
 use crate::application::ports::event_bus::{
-    DomainEvent, EventBus, EventEnvelope, EventHandler, EventPublisher, Subscription,
+    AckEventHandler, AckSubscriptionConfig, Acknowledger, Delivery, DomainEvent, EventBus,
+    EventEnvelope, EventHandler, EventPublisher, Subscription,
 };
+use crate::application::ports::event_format::{
+    EventSerializationError, SerializationFormat, decode_envelope, encode_envelope,
+};
+use crate::application::ports::event_store::{EventStore, StoredEvent};
 use async_trait::async_trait;
 use std::any::TypeId;
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use tokio::sync::broadcast;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::{Notify, Semaphore, broadcast, mpsc};
 use tokio::task::JoinHandle;
+use tokio::time::Instant;
 use tracing::{debug, error, info, warn};
 
 /// Internal representation of a channel for a specific event type
@@ -47,6 +55,38 @@ pub struct InMemoryEventBus {
 
     /// Channel capacity per event type
     channel_capacity: usize,
+
+    /// Store every published event is appended to, if any
+    event_store: Option<Arc<dyn EventStore>>,
+
+    /// Format published envelopes are serialized with
+    format: SerializationFormat,
+
+    /// Event types bincode failed to read back, published as JSON instead
+    json_fallback: RwLock<HashSet<TypeId>>,
+
+    /// Pool handler invocations run on, if any
+    worker_pool: Option<WorkerPool>,
+
+    /// Deliveries broadcast but not yet handled
+    pending: Arc<PendingDeliveries>,
+
+    /// Cleared by [`drain`](Self::drain); publishing fails afterwards
+    accepting: AtomicBool,
+
+    /// Outcome of the first drain, returned by any later one
+    drained: tokio::sync::Mutex<Option<DrainReport>>,
+}
+
+/// Outcome of [`InMemoryEventBus::drain`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrainReport {
+    /// Deliveries handled during the drain
+    pub completed: usize,
+    /// Deliveries still unhandled when the timeout expired
+    pub lost: usize,
+    /// How long the drain waited
+    pub waited: Duration,
 }
 
 impl InMemoryEventBus {
@@ -72,9 +112,182 @@ impl InMemoryEventBus {
             channels: RwLock::new(HashMap::new()),
             subscription_count: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
             channel_capacity: capacity,
+            event_store: None,
+            format: SerializationFormat::Bincode,
+            json_fallback: RwLock::new(HashSet::new()),
+            worker_pool: None,
+            pending: Arc::new(PendingDeliveries::default()),
+            accepting: AtomicBool::new(true),
+            drained: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Serialize published envelopes with `format` (bincode by default)
+    ///
+    /// Bincode and MessagePack cut serialization cost for large, frequent
+    /// events; JSON keeps envelopes readable. Subscribers decode each
+    /// envelope by its own format tag, so the choice never affects them.
+    ///
+    /// Stored events must be readable without their type, so with bincode
+    /// the event store receives JSON payloads. An event type bincode can't
+    /// read back, such as one holding JSON values, is published as JSON.
+    pub fn with_format(mut self, format: SerializationFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Format published envelopes are serialized with
+    pub fn format(&self) -> SerializationFormat {
+        self.format
+    }
+
+    /// Encode an envelope for the subscribers of its type
+    ///
+    /// Bincode output is decoded again before it is sent, since only the
+    /// concrete type tells whether bincode can read it: fields holding JSON
+    /// values need a self-describing format. An envelope that fails is sent
+    /// as JSON, and so is every later envelope of its type.
+    fn encode<E: DomainEvent>(
+        &self,
+        envelope: &EventEnvelope<E>,
+    ) -> Result<Vec<u8>, EventSerializationError> {
+        let type_id = TypeId::of::<E>();
+        if self.format.is_self_describing() || self.json_fallback.read().unwrap().contains(&type_id)
+        {
+            return encode_envelope(envelope, self.self_describing_format());
+        }
+
+        let bytes = encode_envelope(envelope, self.format)?;
+        if decode_envelope::<E>(&bytes).is_ok() {
+            return Ok(bytes);
+        }
+        warn!(
+            event_type = envelope.event.event_type(),
+            "Event type can't be read back from bincode, publishing it as JSON"
+        );
+        self.json_fallback.write().unwrap().insert(type_id);
+        encode_envelope(envelope, SerializationFormat::Json)
+    }
+
+    /// The bus format if events can be read in it without their type,
+    /// JSON otherwise
+    fn self_describing_format(&self) -> SerializationFormat {
+        if self.format.is_self_describing() {
+            self.format
+        } else {
+            SerializationFormat::Json
+        }
+    }
+
+    /// Run handler invocations on a pool of `size` workers
+    ///
+    /// By default each subscription runs its handler inline, one event at a
+    /// time, however many handlers are busy. With a pool, each handler gets
+    /// its own queue, drained in order, and at most `size` handlers run at
+    /// once across the bus: slow or CPU-heavy handlers neither hold up the
+    /// subscription reading their events nor crowd out each other beyond
+    /// the pool. Events still reach each handler in publication order.
+    ///
+    /// Only plain subscriptions use the pool; acknowledged subscriptions
+    /// keep running inline, where their redelivery bookkeeping lives.
+    pub fn with_worker_pool(mut self, size: usize) -> Self {
+        let size = size.max(1);
+        info!("InMemoryEventBus dispatching handlers on {} workers", size);
+        self.worker_pool = Some(WorkerPool {
+            permits: Arc::new(Semaphore::new(size)),
+            size,
+        });
+        self
+    }
+
+    /// Number of pool workers, `None` if handlers run inline
+    pub fn worker_pool_size(&self) -> Option<usize> {
+        self.worker_pool.as_ref().map(|pool| pool.size)
+    }
+
+    /// Stop accepting events and wait for handlers to finish the ones
+    /// already published
+    ///
+    /// Call on shutdown, after the last producer is stopped, so events
+    /// published just before it still reach their handlers (the audit log
+    /// among them). From the first call on, publishing fails. Waits up to
+    /// `timeout` for every delivery to be handled; deliveries still pending
+    /// then are counted as lost and logged. Later calls return the first
+    /// call's report without waiting again.
+    pub async fn drain(&self, timeout: Duration) -> DrainReport {
+        let mut drained = self.drained.lock().await;
+        if let Some(report) = *drained {
+            return report;
+        }
+
+        self.accepting.store(false, Ordering::SeqCst);
+        let started = Instant::now();
+        let deadline = started + timeout;
+        let pending_at_start = self.pending.count();
+        info!(
+            pending = pending_at_start,
+            timeout_ms = timeout.as_millis() as u64,
+            "Draining event bus"
+        );
+
+        self.wait_settled(deadline).await;
+
+        let lost = self.pending.count();
+        let report = DrainReport {
+            completed: pending_at_start.saturating_sub(lost),
+            lost,
+            waited: started.elapsed(),
+        };
+        if lost > 0 {
+            warn!(
+                lost,
+                completed = report.completed,
+                "Event bus drain timed out, unhandled events were lost"
+            );
+        } else {
+            info!(
+                completed = report.completed,
+                waited_ms = report.waited.as_millis() as u64,
+                "Event bus drained"
+            );
+        }
+        *drained = Some(report);
+        report
+    }
+
+    /// Wait until every event published so far has been handled
+    ///
+    /// Unlike [`drain`](Self::drain) the bus keeps accepting events. Returns
+    /// whether everything was handled within `timeout`.
+    pub async fn settle(&self, timeout: Duration) -> bool {
+        self.wait_settled(Instant::now() + timeout).await
+    }
+
+    /// Wait until no delivery is pending or `deadline` passes; returns
+    /// whether none is pending
+    async fn wait_settled(&self, deadline: Instant) -> bool {
+        loop {
+            let settled = self.pending.settled.notified();
+            tokio::pin!(settled);
+            settled.as_mut().enable();
+            if self.pending.count() == 0 {
+                return true;
+            }
+            if tokio::time::timeout_at(deadline, settled).await.is_err() {
+                return self.pending.count() == 0;
+            }
         }
     }
 
+    /// Persist every published event to `store` before it is broadcast
+    ///
+    /// The store keeps the history that later consumers (such as an audit
+    /// log replay) can read back.
+    pub fn with_event_store(mut self, store: Arc<dyn EventStore>) -> Self {
+        self.event_store = Some(store);
+        self
+    }
+
     /// Get or create a broadcast channel for a specific event type
     fn get_or_create_channel<E: DomainEvent>(&self) -> broadcast::Sender<Vec<u8>> {
         let type_id = TypeId::of::<E>();
@@ -127,6 +340,13 @@ impl EventPublisher for InMemoryEventBus {
         envelope: EventEnvelope<E>,
     ) -> anyhow::Result<()> {
         let event_type = envelope.event.event_type();
+        if !self.accepting.load(Ordering::SeqCst) {
+            anyhow::bail!(
+                "Event bus is draining, {} event {} rejected",
+                event_type,
+                envelope.event_id
+            );
+        }
 
         debug!(
             event_type = event_type,
@@ -134,8 +354,18 @@ impl EventPublisher for InMemoryEventBus {
             "Publishing event"
         );
 
+        // Persist first, so every broadcast event is also in the store
+        if let Some(store) = &self.event_store {
+            let format = self.self_describing_format();
+            store
+                .append(StoredEvent::from_envelope(&envelope, format)?)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to persist event envelope: {}", e))?;
+        }
+
         // Serialize the envelope
-        let bytes = bincode::serialize(&envelope)
+        let bytes = self
+            .encode(&envelope)
             .map_err(|e| anyhow::anyhow!("Failed to serialize event envelope: {}", e))?;
 
         // Get the channel and send
@@ -149,8 +379,16 @@ impl EventPublisher for InMemoryEventBus {
             );
         }
 
+        // Counted before sending, so a fast handler can't settle a delivery
+        // that is not counted yet
+        self.pending.add(receiver_count);
         // Send returns error only if there are no receivers (which is fine)
-        let _ = sender.send(bytes);
+        let delivered = sender.send(bytes).unwrap_or(0);
+        if delivered >= receiver_count {
+            self.pending.add(delivered - receiver_count);
+        } else {
+            self.pending.settle(receiver_count - delivered);
+        }
 
         debug!(
             event_type = event_type,
@@ -191,6 +429,14 @@ impl EventBus for InMemoryEventBus {
             .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         let sub_count_clone = self.subscription_count.clone();
 
+        let pending = self.pending.clone();
+
+        // With a worker pool, events go through the handler's own queue
+        let queue = self
+            .worker_pool
+            .clone()
+            .map(|pool| spawn_handler_queue(handler.clone(), pool, self.channel_capacity));
+
         // Spawn task to handle incoming events
         let task: JoinHandle<()> = tokio::spawn(async move {
             let mut processed_count = 0u64;
@@ -217,8 +463,11 @@ impl EventBus for InMemoryEventBus {
                     msg = receiver.recv() => {
                         match msg {
                             Ok(bytes) => {
+                                // Settled when dropped, once the event is handled
+                                let delivery = pending.token();
+
                                 // Deserialize envelope
-                                match bincode::deserialize::<EventEnvelope<E>>(&bytes) {
+                                match decode_envelope::<E>(&bytes) {
                                     Ok(envelope) => {
                                         // Check if handler wants to process this event
                                         if !handler.should_handle(&envelope) {
@@ -230,26 +479,26 @@ impl EventBus for InMemoryEventBus {
                                             continue;
                                         }
 
-                                        // Handle the event
-                                        match handler.handle(envelope.clone()).await {
-                                            Ok(_) => {
-                                                processed_count += 1;
-                                                debug!(
-                                                    handler = handler_name,
-                                                    event_id = %envelope.event_id,
-                                                    processed = processed_count,
-                                                    "Event handled successfully"
-                                                );
+                                        // Handle the event, or queue it for the pool
+                                        match &queue {
+                                            Some(queue) => {
+                                                if queue.send((envelope, delivery)).await.is_err() {
+                                                    error_count += 1;
+                                                    error!(
+                                                        handler = handler_name,
+                                                        "Handler queue closed, event dropped"
+                                                    );
+                                                }
                                             }
-                                            Err(e) => {
-                                                error_count += 1;
-                                                error!(
-                                                    handler = handler_name,
-                                                    event_id = %envelope.event_id,
-                                                    error = %e,
-                                                    errors = error_count,
-                                                    "Handler failed to process event"
-                                                );
+                                            None => {
+                                                invoke_handler(
+                                                    &*handler,
+                                                    envelope,
+                                                    &mut processed_count,
+                                                    &mut error_count,
+                                                )
+                                                .await;
+                                                drop(delivery);
                                             }
                                         }
                                     }
@@ -265,6 +514,7 @@ impl EventBus for InMemoryEventBus {
                             }
                             Err(broadcast::error::RecvError::Lagged(skipped)) => {
                                 lagged_count += skipped;
+                                pending.settle(skipped as usize);
                                 warn!(
                                     handler = handler_name,
                                     skipped = skipped,
@@ -284,6 +534,9 @@ impl EventBus for InMemoryEventBus {
                 }
             }
 
+            // Events left in the channel will never be handled
+            pending.settle(receiver.len());
+
             // Mark as inactive when task completes
             is_active_clone.store(false, std::sync::atomic::Ordering::Relaxed);
             sub_count_clone.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
@@ -302,6 +555,153 @@ impl EventBus for InMemoryEventBus {
         Ok(subscription as Arc<dyn Subscription>)
     }
 
+    async fn subscribe_with_ack<E, H>(
+        &self,
+        handler: Arc<H>,
+        config: AckSubscriptionConfig,
+    ) -> anyhow::Result<Arc<dyn Subscription>>
+    where
+        E: DomainEvent,
+        H: AckEventHandler<E> + 'static,
+    {
+        if config.max_deliveries == 0 || config.visibility_timeout.is_zero() {
+            anyhow::bail!(
+                "Invalid ack subscription config: max_deliveries and visibility_timeout must be non-zero"
+            );
+        }
+
+        let sender = self.get_or_create_channel::<E>();
+        let mut receiver = sender.subscribe();
+        let handler_name = handler.name();
+        let event_type_name = std::any::type_name::<E>();
+
+        info!(
+            handler = handler_name,
+            event_type = event_type_name,
+            visibility_timeout_ms = config.visibility_timeout.as_millis() as u64,
+            max_deliveries = config.max_deliveries,
+            "Subscribing acknowledged handler to event type"
+        );
+
+        let (cancel_tx, mut cancel_rx) = tokio::sync::oneshot::channel::<()>();
+        let subscription_id = format!("{}-{}", handler_name, uuid::Uuid::new_v4());
+        let is_active = Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let is_active_clone = is_active.clone();
+
+        self.subscription_count
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let sub_count_clone = self.subscription_count.clone();
+
+        let pending = self.pending.clone();
+        let in_flight = Arc::new(InFlightEvents::<E>::new());
+        let acknowledger: Arc<dyn Acknowledger> = in_flight.clone();
+        let mut redelivery_tick =
+            tokio::time::interval(redelivery_check_interval(config.visibility_timeout));
+
+        let task: JoinHandle<()> = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    biased;
+
+                    _ = &mut cancel_rx => {
+                        info!(
+                            handler = handler_name,
+                            in_flight = in_flight.len(),
+                            "Acknowledged subscription cancelled"
+                        );
+                        break;
+                    }
+
+                    _ = redelivery_tick.tick() => {
+                        let (redeliveries, dead_letters) =
+                            in_flight.take_expired(config.visibility_timeout, config.max_deliveries);
+
+                        for (envelope, delivery_count) in dead_letters {
+                            error!(
+                                handler = handler_name,
+                                event_id = %envelope.event_id,
+                                deliveries = delivery_count,
+                                "Event not acknowledged after max deliveries, dead-lettering"
+                            );
+                            handler.dead_letter(envelope, delivery_count).await;
+                        }
+
+                        for (envelope, delivery_count) in redeliveries {
+                            warn!(
+                                handler = handler_name,
+                                event_id = %envelope.event_id,
+                                delivery_count = delivery_count,
+                                "Redelivering unacknowledged event"
+                            );
+                            deliver(&*handler, Delivery::new(envelope, delivery_count, acknowledger.clone())).await;
+                        }
+                    }
+
+                    msg = receiver.recv() => {
+                        match msg {
+                            Ok(bytes) => match decode_envelope::<E>(&bytes) {
+                                // The first delivery settles the event; redeliveries
+                                // are not waited for on drain
+                                Ok(envelope) => {
+                                    let _delivery = pending.token();
+                                    if !handler.should_handle(&envelope) {
+                                        debug!(
+                                            handler = handler_name,
+                                            event_id = %envelope.event_id,
+                                            "Handler filtered out event"
+                                        );
+                                        continue;
+                                    }
+
+                                    in_flight.track(envelope.clone(), config.visibility_timeout);
+                                    deliver(&*handler, Delivery::new(envelope, 1, acknowledger.clone())).await;
+                                }
+                                Err(e) => {
+                                    pending.settle(1);
+                                    error!(
+                                        handler = handler_name,
+                                        error = %e,
+                                        "Failed to deserialize event envelope"
+                                    );
+                                }
+                            },
+                            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                                pending.settle(skipped as usize);
+                                warn!(
+                                    handler = handler_name,
+                                    skipped = skipped,
+                                    "Acknowledged handler lagged behind, events were skipped"
+                                );
+                            }
+                            Err(broadcast::error::RecvError::Closed) => {
+                                info!(
+                                    handler = handler_name,
+                                    "Event channel closed, stopping handler"
+                                );
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            pending.settle(receiver.len());
+            is_active_clone.store(false, std::sync::atomic::Ordering::Relaxed);
+            sub_count_clone.fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+        });
+
+        let subscription = Arc::new(InMemorySubscription {
+            id: subscription_id,
+            event_type: event_type_name,
+            handler_name,
+            cancel_tx: tokio::sync::Mutex::new(Some(cancel_tx)),
+            is_active,
+            _task: task,
+        });
+
+        Ok(subscription as Arc<dyn Subscription>)
+    }
+
     fn subscription_count(&self) -> usize {
         self.subscription_count
             .load(std::sync::atomic::Ordering::Relaxed)
@@ -312,6 +712,252 @@ impl EventBus for InMemoryEventBus {
     }
 }
 
+/// Run a handler on one event, counting the outcome
+async fn invoke_handler<E, H>(
+    handler: &H,
+    envelope: EventEnvelope<E>,
+    processed_count: &mut u64,
+    error_count: &mut u64,
+) where
+    E: DomainEvent,
+    H: EventHandler<E> + ?Sized,
+{
+    let handler_name = handler.name();
+    let event_id = envelope.event_id;
+    match handler.handle(envelope).await {
+        Ok(_) => {
+            *processed_count += 1;
+            debug!(
+                handler = handler_name,
+                event_id = %event_id,
+                processed = *processed_count,
+                "Event handled successfully"
+            );
+        }
+        Err(e) => {
+            *error_count += 1;
+            error!(
+                handler = handler_name,
+                event_id = %event_id,
+                error = %e,
+                errors = *error_count,
+                "Handler failed to process event"
+            );
+        }
+    }
+}
+
+/// Bounded pool handler invocations run on
+///
+/// Holds one permit per worker; an invocation runs only while it holds one,
+/// so at most `size` handlers run at a time across the whole bus.
+#[derive(Clone)]
+struct WorkerPool {
+    permits: Arc<Semaphore>,
+    size: usize,
+}
+
+/// An event waiting in a handler queue, with its pending delivery
+type QueuedEvent<E> = (EventEnvelope<E>, DeliveryToken);
+
+/// Deliveries broadcast to subscriptions and not yet handled
+///
+/// Counts one per subscription an event was sent to. The count may dip
+/// below zero for a moment, when a handler settles a delivery before the
+/// publisher has added it.
+#[derive(Default)]
+struct PendingDeliveries {
+    count: AtomicIsize,
+    /// Notified whenever the count drops to zero
+    settled: Notify,
+}
+
+impl PendingDeliveries {
+    fn add(&self, deliveries: usize) {
+        self.count.fetch_add(deliveries as isize, Ordering::SeqCst);
+    }
+
+    fn settle(&self, deliveries: usize) {
+        if deliveries == 0 {
+            return;
+        }
+        let left =
+            self.count.fetch_sub(deliveries as isize, Ordering::SeqCst) - deliveries as isize;
+        if left <= 0 {
+            self.settled.notify_waiters();
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst).max(0) as usize
+    }
+
+    /// A delivery settled when the token is dropped
+    fn token(self: &Arc<Self>) -> DeliveryToken {
+        DeliveryToken(self.clone())
+    }
+}
+
+/// One pending delivery, settled on drop whatever path the event takes
+struct DeliveryToken(Arc<PendingDeliveries>);
+
+impl Drop for DeliveryToken {
+    fn drop(&mut self) {
+        self.0.settle(1);
+    }
+}
+
+/// Start the sequential queue of one handler, fed by its subscription
+///
+/// A single task drains the queue in order, taking a pool worker for each
+/// event, so the handler never sees two events at once or out of order.
+/// The queue holds up to `capacity` events; when it is full the
+/// subscription waits, and the broadcast channel buffers behind it as it
+/// does for an inline handler. Dropping the sender lets the queue drain and
+/// the task end.
+fn spawn_handler_queue<E, H>(
+    handler: Arc<H>,
+    pool: WorkerPool,
+    capacity: usize,
+) -> mpsc::Sender<QueuedEvent<E>>
+where
+    E: DomainEvent,
+    H: EventHandler<E> + 'static,
+{
+    let (queue, mut events) = mpsc::channel::<QueuedEvent<E>>(capacity.max(1));
+    tokio::spawn(async move {
+        let mut processed_count = 0u64;
+        let mut error_count = 0u64;
+        while let Some((envelope, delivery)) = events.recv().await {
+            let Ok(_worker) = pool.permits.acquire().await else {
+                break;
+            };
+            invoke_handler(&*handler, envelope, &mut processed_count, &mut error_count).await;
+            drop(delivery);
+        }
+        debug!(
+            handler = handler.name(),
+            processed = processed_count,
+            errors = error_count,
+            "Handler queue drained"
+        );
+    });
+    queue
+}
+
+/// How often an acknowledged subscription looks for expired deliveries
+fn redelivery_check_interval(visibility_timeout: Duration) -> Duration {
+    (visibility_timeout / 4).max(Duration::from_millis(1))
+}
+
+/// Hand a delivery to an acknowledged handler
+///
+/// A handler error leaves the event unacknowledged, so it is redelivered once
+/// its visibility timeout expires.
+async fn deliver<E, H>(handler: &H, delivery: Delivery<E>)
+where
+    E: DomainEvent,
+    H: AckEventHandler<E> + ?Sized,
+{
+    let event_id = delivery.envelope.event_id;
+    let delivery_count = delivery.delivery_count;
+
+    if let Err(e) = handler.handle(delivery).await {
+        error!(
+            handler = handler.name(),
+            event_id = %event_id,
+            delivery_count = delivery_count,
+            error = %e,
+            "Handler failed to process event, it will be redelivered"
+        );
+    }
+}
+
+/// An event delivered but not yet acknowledged
+struct InFlightEvent<E: DomainEvent> {
+    envelope: EventEnvelope<E>,
+    delivery_count: u32,
+    visible_at: Instant,
+}
+
+/// An event to hand back to the handler, with its delivery count
+type PendingDelivery<E> = (EventEnvelope<E>, u32);
+
+/// Unacknowledged events of one acknowledged subscription
+struct InFlightEvents<E: DomainEvent> {
+    events: Mutex<HashMap<uuid::Uuid, InFlightEvent<E>>>,
+}
+
+impl<E: DomainEvent> InFlightEvents<E> {
+    fn new() -> Self {
+        Self {
+            events: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    /// Start tracking a first delivery
+    fn track(&self, envelope: EventEnvelope<E>, visibility_timeout: Duration) {
+        self.events.lock().unwrap().insert(
+            envelope.event_id,
+            InFlightEvent {
+                envelope,
+                delivery_count: 1,
+                visible_at: Instant::now() + visibility_timeout,
+            },
+        );
+    }
+
+    /// Collect events whose visibility timeout expired
+    ///
+    /// Returns the events to redeliver (with their new delivery count, and
+    /// still tracked) and the events that exhausted `max_deliveries` (no
+    /// longer tracked).
+    fn take_expired(
+        &self,
+        visibility_timeout: Duration,
+        max_deliveries: u32,
+    ) -> (Vec<PendingDelivery<E>>, Vec<PendingDelivery<E>>) {
+        let now = Instant::now();
+        let mut events = self.events.lock().unwrap();
+        let mut redeliveries = Vec::new();
+        let mut dead_letters = Vec::new();
+
+        let expired: Vec<uuid::Uuid> = events
+            .iter()
+            .filter(|(_, event)| event.visible_at <= now)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in expired {
+            let Some(event) = events.get_mut(&id) else {
+                continue;
+            };
+
+            if event.delivery_count >= max_deliveries {
+                if let Some(event) = events.remove(&id) {
+                    dead_letters.push((event.envelope, event.delivery_count));
+                }
+            } else {
+                event.delivery_count += 1;
+                event.visible_at = now + visibility_timeout;
+                redeliveries.push((event.envelope.clone(), event.delivery_count));
+            }
+        }
+
+        (redeliveries, dead_letters)
+    }
+}
+
+impl<E: DomainEvent> Acknowledger for InFlightEvents<E> {
+    fn ack(&self, event_id: uuid::Uuid) {
+        self.events.lock().unwrap().remove(&event_id);
+    }
+}
+
 /// Implementation of Subscription for in-memory subscriptions
 struct InMemorySubscription {
     id: String,
@@ -364,6 +1010,7 @@ impl Subscription for InMemorySubscription {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::ports::event_bus::AggregateFilter;
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -523,4 +1170,493 @@ mod tests {
 
         assert_eq!(bus.subscription_count(), 0);
     }
+
+    /// Acknowledges from the `ack_on`-th delivery onwards (never if `None`)
+    struct AckTestHandler {
+        ack_on: Option<u32>,
+        only_message: Option<&'static str>,
+        deliveries: Mutex<Vec<u32>>,
+        dead_letters: AtomicUsize,
+    }
+
+    impl AckTestHandler {
+        fn new(ack_on: Option<u32>) -> Self {
+            Self {
+                ack_on,
+                only_message: None,
+                deliveries: Mutex::new(Vec::new()),
+                dead_letters: AtomicUsize::new(0),
+            }
+        }
+
+        fn deliveries(&self) -> Vec<u32> {
+            self.deliveries.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl AckEventHandler<TestEvent> for AckTestHandler {
+        fn name(&self) -> &'static str {
+            "ack_test_handler"
+        }
+
+        async fn handle(&self, delivery: Delivery<TestEvent>) -> anyhow::Result<()> {
+            self.deliveries
+                .lock()
+                .unwrap()
+                .push(delivery.delivery_count);
+            if self.ack_on.is_some_and(|n| delivery.delivery_count >= n) {
+                delivery.ack();
+            }
+            Ok(())
+        }
+
+        fn should_handle(&self, envelope: &EventEnvelope<TestEvent>) -> bool {
+            self.only_message
+                .is_none_or(|message| envelope.event.message == message)
+        }
+
+        async fn dead_letter(&self, _envelope: EventEnvelope<TestEvent>, _delivery_count: u32) {
+            self.dead_letters.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn ack_config(max_deliveries: u32) -> AckSubscriptionConfig {
+        AckSubscriptionConfig {
+            visibility_timeout: Duration::from_millis(20),
+            max_deliveries,
+        }
+    }
+
+    async fn publish_message(bus: &InMemoryEventBus, message: &str) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        bus.publish(TestEvent {
+            message: message.to_string(),
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_acked_event_is_delivered_once() {
+        let bus = InMemoryEventBus::new();
+        let handler = Arc::new(AckTestHandler::new(Some(1)));
+
+        let _sub = bus
+            .subscribe_with_ack::<TestEvent, _>(handler.clone(), ack_config(5))
+            .await
+            .unwrap();
+        publish_message(&bus, "once").await;
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        assert_eq!(handler.deliveries(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_unacked_event_is_redelivered_with_delivery_count() {
+        let bus = InMemoryEventBus::new();
+        let handler = Arc::new(AckTestHandler::new(Some(3)));
+
+        let _sub = bus
+            .subscribe_with_ack::<TestEvent, _>(handler.clone(), ack_config(5))
+            .await
+            .unwrap();
+        publish_message(&bus, "retry").await;
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+
+        assert_eq!(handler.deliveries(), vec![1, 2, 3]);
+        assert_eq!(handler.dead_letters.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_poison_event_is_dead_lettered_after_max_deliveries() {
+        let bus = InMemoryEventBus::new();
+        let handler = Arc::new(AckTestHandler::new(None));
+
+        let _sub = bus
+            .subscribe_with_ack::<TestEvent, _>(handler.clone(), ack_config(2))
+            .await
+            .unwrap();
+        publish_message(&bus, "poison").await;
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+
+        assert_eq!(handler.deliveries(), vec![1, 2]);
+        assert_eq!(handler.dead_letters.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_ack_subscription_respects_filter() {
+        let bus = InMemoryEventBus::new();
+        let handler = Arc::new(AckTestHandler {
+            only_message: Some("wanted"),
+            ..AckTestHandler::new(None)
+        });
+
+        let _sub = bus
+            .subscribe_with_ack::<TestEvent, _>(handler.clone(), ack_config(3))
+            .await
+            .unwrap();
+        publish_message(&bus, "ignored").await;
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        assert!(handler.deliveries().is_empty());
+        assert_eq!(handler.dead_letters.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ack_subscription_rejects_invalid_config() {
+        let bus = InMemoryEventBus::new();
+        let handler = Arc::new(AckTestHandler::new(Some(1)));
+
+        let result = bus
+            .subscribe_with_ack::<TestEvent, _>(handler, ack_config(0))
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(bus.subscription_count(), 0);
+    }
+
+    /// Relates to an account when it has one, otherwise a system event
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct AccountEvent {
+        account_id: Option<String>,
+    }
+
+    impl DomainEvent for AccountEvent {
+        fn event_type(&self) -> &'static str {
+            "test.account"
+        }
+
+        fn aggregate_id(&self) -> Option<String> {
+            self.account_id.clone()
+        }
+
+        fn aggregate_type(&self) -> Option<&'static str> {
+            self.account_id.as_ref().map(|_| "Account")
+        }
+    }
+
+    #[derive(Default)]
+    struct AccountHandler {
+        received: Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<AccountEvent> for AccountHandler {
+        fn name(&self) -> &'static str {
+            "account_handler"
+        }
+
+        async fn handle(&self, envelope: EventEnvelope<AccountEvent>) -> anyhow::Result<()> {
+            self.received.lock().unwrap().push(envelope.aggregate_id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_subscriptions_only_receive_their_events() {
+        let bus = InMemoryEventBus::new();
+        let accounts = Arc::new(AccountHandler::default());
+        let one_account = Arc::new(AccountHandler::default());
+        let system = Arc::new(AccountHandler::default());
+
+        let _subs = [
+            bus.subscribe_to_aggregate::<AccountEvent, _>(
+                AggregateFilter::of_type("Account"),
+                accounts.clone(),
+            )
+            .await
+            .unwrap(),
+            bus.subscribe_to_aggregate::<AccountEvent, _>(
+                AggregateFilter::instance("Account", "acme"),
+                one_account.clone(),
+            )
+            .await
+            .unwrap(),
+            bus.subscribe_to_aggregate::<AccountEvent, _>(AggregateFilter::None, system.clone())
+                .await
+                .unwrap(),
+        ];
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        for account_id in [Some("acme"), Some("globex"), None] {
+            bus.publish(AccountEvent {
+                account_id: account_id.map(str::to_string),
+            })
+            .await
+            .unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let received = |handler: &AccountHandler| handler.received.lock().unwrap().clone();
+        assert_eq!(
+            received(&accounts),
+            vec![Some("acme".to_string()), Some("globex".to_string())]
+        );
+        assert_eq!(received(&one_account), vec![Some("acme".to_string())]);
+        assert_eq!(received(&system), vec![None]);
+    }
+
+    #[tokio::test]
+    async fn test_published_events_are_persisted() {
+        let store = Arc::new(crate::infrastructure::InMemoryEventStore::new());
+        let bus = InMemoryEventBus::new().with_event_store(store.clone());
+
+        publish_message(&bus, "first").await;
+        publish_message(&bus, "second").await;
+
+        let events = store.read_batch(0, 10).await.unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type, "test.event");
+        // The bus encodes with bincode, but stored payloads stay readable
+        assert_eq!(events[0].format, SerializationFormat::Json);
+        assert_eq!(
+            events[0].payload_json().unwrap(),
+            serde_json::json!({ "message": "first" })
+        );
+        assert_eq!(
+            events[1].payload_json().unwrap(),
+            serde_json::json!({ "message": "second" })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_message_pack_bus_delivers_and_persists() {
+        let store = Arc::new(crate::infrastructure::InMemoryEventStore::new());
+        let bus = InMemoryEventBus::new()
+            .with_format(SerializationFormat::MessagePack)
+            .with_event_store(store.clone());
+        let counter = Arc::new(AtomicUsize::new(0));
+        let handler = Arc::new(TestHandler {
+            name: "msgpack_handler",
+            counter: counter.clone(),
+        });
+        let _subscription = bus.subscribe::<TestEvent, _>(handler).await.unwrap();
+
+        publish_message(&bus, "packed").await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        let events = store.read_batch(0, 10).await.unwrap();
+        assert_eq!(events[0].format, SerializationFormat::MessagePack);
+        assert_eq!(
+            events[0].payload_json().unwrap(),
+            serde_json::json!({ "message": "packed" })
+        );
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    struct AttributeSet {
+        value: serde_json::Value,
+    }
+
+    impl DomainEvent for AttributeSet {
+        fn event_type(&self) -> &'static str {
+            "test.attribute_set"
+        }
+    }
+
+    #[derive(Default)]
+    struct AttributeHandler {
+        seen: Mutex<Vec<serde_json::Value>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<AttributeSet> for AttributeHandler {
+        fn name(&self) -> &'static str {
+            "attribute_handler"
+        }
+
+        async fn handle(&self, envelope: EventEnvelope<AttributeSet>) -> anyhow::Result<()> {
+            self.seen.lock().unwrap().push(envelope.event.value);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bincode_bus_sends_json_values_as_json() {
+        let bus = InMemoryEventBus::new();
+        assert_eq!(bus.format(), SerializationFormat::Bincode);
+        let handler = Arc::new(AttributeHandler::default());
+        let _subscription = bus
+            .subscribe::<AttributeSet, _>(handler.clone())
+            .await
+            .unwrap();
+
+        for department in ["engineering", "sales"] {
+            bus.publish(AttributeSet {
+                value: serde_json::json!({ "department": department }),
+            })
+            .await
+            .unwrap();
+        }
+        bus.drain(Duration::from_secs(1)).await;
+
+        assert_eq!(
+            *handler.seen.lock().unwrap(),
+            vec![
+                serde_json::json!({ "department": "engineering" }),
+                serde_json::json!({ "department": "sales" }),
+            ]
+        );
+    }
+
+    /// Records the order it sees events in and how many of its kind run at once
+    struct SlowOrderedHandler {
+        name: &'static str,
+        seen: Mutex<Vec<String>>,
+        running: Arc<AtomicUsize>,
+        max_running: Arc<AtomicUsize>,
+    }
+
+    impl SlowOrderedHandler {
+        fn new(
+            name: &'static str,
+            running: Arc<AtomicUsize>,
+            max_running: Arc<AtomicUsize>,
+        ) -> Self {
+            Self {
+                name,
+                seen: Mutex::new(Vec::new()),
+                running,
+                max_running,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EventHandler<TestEvent> for SlowOrderedHandler {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn handle(&self, envelope: EventEnvelope<TestEvent>) -> anyhow::Result<()> {
+            let now = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(now, Ordering::SeqCst);
+            // Later events are quicker, so any reordering would show
+            let index: u64 = envelope.event.message.parse().unwrap();
+            tokio::time::sleep(Duration::from_millis(20 - index * 2)).await;
+            self.seen.lock().unwrap().push(envelope.event.message);
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_worker_pool_keeps_per_handler_order_and_bounds_concurrency() {
+        let bus = InMemoryEventBus::new().with_worker_pool(2);
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let handlers: Vec<Arc<SlowOrderedHandler>> = ["first", "second", "third"]
+            .into_iter()
+            .map(|name| {
+                Arc::new(SlowOrderedHandler::new(
+                    name,
+                    running.clone(),
+                    max_running.clone(),
+                ))
+            })
+            .collect();
+        let mut subscriptions = Vec::new();
+        for handler in &handlers {
+            subscriptions.push(
+                bus.subscribe::<TestEvent, _>(handler.clone())
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        let messages: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        for message in &messages {
+            bus.publish(TestEvent {
+                message: message.clone(),
+            })
+            .await
+            .unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(400)).await;
+
+        assert_eq!(bus.worker_pool_size(), Some(2));
+        for handler in &handlers {
+            assert_eq!(*handler.seen.lock().unwrap(), messages);
+        }
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+
+    async fn slow_bus_with_published(
+        bus: InMemoryEventBus,
+    ) -> (
+        InMemoryEventBus,
+        Arc<SlowOrderedHandler>,
+        Arc<dyn Subscription>,
+    ) {
+        let handler = Arc::new(SlowOrderedHandler::new(
+            "slow",
+            Arc::new(AtomicUsize::new(0)),
+            Arc::new(AtomicUsize::new(0)),
+        ));
+        let subscription = bus
+            .subscribe::<TestEvent, _>(handler.clone())
+            .await
+            .unwrap();
+        for i in 0..5 {
+            bus.publish(TestEvent {
+                message: i.to_string(),
+            })
+            .await
+            .unwrap();
+        }
+        (bus, handler, subscription)
+    }
+
+    #[tokio::test]
+    async fn test_drain_waits_for_in_flight_handlers_and_stops_publishing() {
+        let (bus, handler, _subscription) = slow_bus_with_published(InMemoryEventBus::new()).await;
+
+        let report = bus.drain(Duration::from_secs(5)).await;
+
+        assert_eq!(report.completed, 5);
+        assert_eq!(report.lost, 0);
+        assert_eq!(handler.seen.lock().unwrap().len(), 5);
+        let rejected = bus
+            .publish(TestEvent {
+                message: "late".to_string(),
+            })
+            .await;
+        assert!(rejected.is_err());
+        // Draining again reports the first drain, without waiting
+        assert_eq!(bus.drain(Duration::from_secs(5)).await, report);
+    }
+
+    #[tokio::test]
+    async fn test_drain_timeout_reports_lost_events() {
+        let (bus, _handler, _subscription) =
+            slow_bus_with_published(InMemoryEventBus::new().with_worker_pool(1)).await;
+
+        let report = bus.drain(Duration::from_millis(5)).await;
+
+        assert!(report.lost > 0);
+        assert_eq!(report.completed + report.lost, 5);
+        assert!(report.waited < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_settle_waits_for_handlers_and_keeps_accepting() {
+        let (bus, handler, _subscription) = slow_bus_with_published(InMemoryEventBus::new()).await;
+
+        assert!(bus.settle(Duration::from_secs(5)).await);
+
+        assert_eq!(handler.seen.lock().unwrap().len(), 5);
+        bus.publish(TestEvent {
+            message: "5".to_string(),
+        })
+        .await
+        .unwrap();
+        assert!(bus.settle(Duration::from_secs(5)).await);
+        assert_eq!(handler.seen.lock().unwrap().len(), 6);
+    }
 }
@@ -0,0 +1,94 @@
+//! In-memory event store implementation
+//!
+//! Suitable for development and testing; events are lost on restart.
+//! Production deployments should use a durable store.
+
+use crate::application::ports::event_store::{EventStore, EventStoreError, StoredEvent};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory, append-only event log
+#[derive(Clone, Default)]
+pub struct InMemoryEventStore {
+    events: Arc<RwLock<Vec<StoredEvent>>>,
+}
+
+impl InMemoryEventStore {
+    /// Create a new empty event store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, event: StoredEvent) -> Result<(), EventStoreError> {
+        self.events.write().await.push(event);
+        Ok(())
+    }
+
+    async fn read_batch(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<StoredEvent>, EventStoreError> {
+        let events = self.events.read().await;
+        Ok(events.iter().skip(offset).take(limit).cloned().collect())
+    }
+
+    async fn count(&self) -> Result<usize, EventStoreError> {
+        Ok(self.events.read().await.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::event_format::SerializationFormat;
+    use chrono::Utc;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn stored_event(event_type: &str) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            event_type: event_type.to_string(),
+            aggregate_id: None,
+            aggregate_type: None,
+            schema_version: 1,
+            occurred_at: Utc::now(),
+            correlation_id: None,
+            causation_id: None,
+            metadata: HashMap::new(),
+            format: SerializationFormat::Json,
+            payload: b"{}".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reads_batches_in_append_order() {
+        let store = InMemoryEventStore::new();
+        for i in 0..5 {
+            store
+                .append(stored_event(&format!("event.{i}")))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(store.count().await.unwrap(), 5);
+
+        let first = store.read_batch(0, 2).await.unwrap();
+        let last = store.read_batch(4, 2).await.unwrap();
+        assert_eq!(
+            first
+                .iter()
+                .map(|e| e.event_type.as_str())
+                .collect::<Vec<_>>(),
+            vec!["event.0", "event.1"]
+        );
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0].event_type, "event.4");
+        assert!(store.read_batch(5, 2).await.unwrap().is_empty());
+    }
+}
@@ -2,10 +2,22 @@
 
 pub mod audit;
 pub mod hrn_generator;
+pub mod in_memory_distributed_lock;
 pub mod in_memory_event_bus;
+pub mod in_memory_event_store;
 pub mod surrealdb_adapter;
+pub mod webhook;
 
 // Re-export commonly used infrastructure types
-pub use audit::{AuditEventHandler, AuditLog, AuditLogStore, AuditStats};
+pub use audit::{
+    AuditEventHandler, AuditLog, AuditLogStore, AuditRetention, AuditStats, CausalChain,
+    CausalNode, CloudTrailExporter, RateWindow, RateWindows, RedactAuditLogsUseCase,
+};
 pub use hrn_generator::HrnGenerator;
-pub use in_memory_event_bus::InMemoryEventBus;
+pub use in_memory_distributed_lock::InMemoryDistributedLock;
+pub use in_memory_event_bus::{DrainReport, InMemoryEventBus};
+pub use in_memory_event_store::InMemoryEventStore;
+pub use webhook::{
+    DeadLetter, DeadLetterSink, WebhookDeliveryConfig, WebhookEndpoint, WebhookEventHandler,
+    WebhookSecret,
+};
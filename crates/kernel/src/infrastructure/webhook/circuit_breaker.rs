@@ -0,0 +1,153 @@
+//! Per-endpoint circuit breaker for webhook deliveries
+//!
+//! After `failure_threshold` consecutive failures the circuit opens and
+//! deliveries to the endpoint are rejected without a request. Once
+//! `open_duration` has passed a single trial delivery is let through: its
+//! success closes the circuit, its failure opens it again.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// State of an endpoint's circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Deliveries go through
+    Closed,
+    /// Deliveries are rejected until the open duration has passed
+    Open,
+    /// One trial delivery may go through
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    trial_in_flight: bool,
+}
+
+/// Circuit breaker guarding a single webhook endpoint
+#[derive(Debug)]
+pub struct EndpointCircuitBreaker {
+    failure_threshold: u32,
+    open_duration: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl EndpointCircuitBreaker {
+    /// Create a closed circuit breaker
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            state: Mutex::new(BreakerState {
+                consecutive_failures: 0,
+                opened_at: None,
+                trial_in_flight: false,
+            }),
+        }
+    }
+
+    fn current_state(&self, state: &BreakerState) -> CircuitState {
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Current state of the circuit
+    pub fn state(&self) -> CircuitState {
+        self.current_state(&self.state.lock().unwrap())
+    }
+
+    /// Whether a delivery may be attempted now
+    ///
+    /// In the half-open state only the first caller is let through, until
+    /// its outcome is recorded.
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        match self.current_state(&state) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen if state.trial_in_flight => false,
+            CircuitState::HalfOpen => {
+                state.trial_in_flight = true;
+                true
+            }
+        }
+    }
+
+    /// Record a successful delivery, closing the circuit
+    pub fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = 0;
+        state.opened_at = None;
+        state.trial_in_flight = false;
+    }
+
+    /// Record a failed delivery, opening the circuit at the threshold
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.trial_in_flight || state.consecutive_failures >= self.failure_threshold {
+            state.opened_at = Some(Instant::now());
+        }
+        state.trial_in_flight = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_after_consecutive_failures() {
+        let breaker = EndpointCircuitBreaker::new(3, Duration::from_secs(60));
+
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.try_acquire());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.try_acquire());
+    }
+
+    #[test]
+    fn test_success_resets_failure_count() {
+        let breaker = EndpointCircuitBreaker::new(2, Duration::from_secs(60));
+
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_allows_a_single_trial() {
+        let breaker = EndpointCircuitBreaker::new(1, Duration::ZERO);
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.try_acquire());
+        assert!(!breaker.try_acquire());
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_failed_trial_reopens_the_circuit() {
+        let breaker = EndpointCircuitBreaker::new(1, Duration::from_millis(20));
+
+        breaker.record_failure();
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(breaker.try_acquire());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.try_acquire());
+    }
+}
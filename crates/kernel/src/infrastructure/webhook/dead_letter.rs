@@ -0,0 +1,73 @@
+//! Sink for webhook deliveries that could not be completed
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// A delivery given up on, with everything needed to replay it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetter {
+    /// Name of the endpoint the event was meant for
+    pub endpoint: String,
+
+    /// URL the event was POSTed to
+    pub url: String,
+
+    /// ID of the undelivered event (also the `webhook-id` sent)
+    pub event_id: Uuid,
+
+    /// Type of the undelivered event
+    pub event_type: String,
+
+    /// The serialized envelope that was sent
+    pub payload: String,
+
+    /// Attempts made; 0 when the endpoint's circuit was open
+    pub attempts: u32,
+
+    /// Why the last attempt failed
+    pub last_error: String,
+
+    /// When the delivery was given up on
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Destination of deliveries that exhausted their retries
+#[async_trait]
+pub trait DeadLetterSink: Send + Sync {
+    /// Keep an undelivered event for inspection or replay
+    async fn dead_letter(&self, letter: DeadLetter);
+}
+
+/// In-memory dead letter sink (production would use durable storage)
+#[derive(Clone, Default)]
+pub struct InMemoryDeadLetterSink {
+    letters: Arc<RwLock<Vec<DeadLetter>>>,
+}
+
+impl InMemoryDeadLetterSink {
+    /// Create a new empty sink
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get all dead letters, oldest first
+    pub async fn all(&self) -> Vec<DeadLetter> {
+        self.letters.read().await.clone()
+    }
+
+    /// Remove and return all dead letters, e.g. to replay them
+    pub async fn drain(&self) -> Vec<DeadLetter> {
+        std::mem::take(&mut *self.letters.write().await)
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for InMemoryDeadLetterSink {
+    async fn dead_letter(&self, letter: DeadLetter) {
+        self.letters.write().await.push(letter);
+    }
+}
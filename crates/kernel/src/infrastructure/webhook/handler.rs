@@ -0,0 +1,533 @@
+//! Event handler that delivers domain events to webhook endpoints
+
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+use super::circuit_breaker::{CircuitState, EndpointCircuitBreaker};
+use super::dead_letter::{DeadLetter, DeadLetterSink};
+use super::signing::{WEBHOOK_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER, sign};
+use super::transport::{WebhookRequest, WebhookTransport};
+use super::{WebhookDeliveryConfig, WebhookEndpoint};
+use crate::application::ports::event_bus::{DomainEvent, EventEnvelope, EventHandler};
+
+/// An endpoint together with its circuit breaker
+struct EndpointDelivery {
+    endpoint: WebhookEndpoint,
+    breaker: EndpointCircuitBreaker,
+}
+
+/// What a single event delivery is made of
+#[derive(Clone)]
+struct Payload {
+    event_id: Uuid,
+    event_type: &'static str,
+    body: Arc<[u8]>,
+}
+
+/// Settings and collaborators shared by every delivery task
+struct Deliverer {
+    config: WebhookDeliveryConfig,
+    transport: Arc<dyn WebhookTransport>,
+    dead_letters: Arc<dyn DeadLetterSink>,
+}
+
+/// Event handler that POSTs matching domain events to webhook endpoints
+///
+/// Generic over any DomainEvent type, like the audit handler, so one
+/// instance can be subscribed to every event type. Each matching endpoint is
+/// delivered to on its own task, so a slow or retrying receiver neither holds
+/// up the bus nor the other endpoints. Delivery failures are retried and then
+/// dead-lettered rather than returned, so the event bus never redelivers an
+/// event to endpoints that already received it.
+pub struct WebhookEventHandler {
+    endpoints: Vec<Arc<EndpointDelivery>>,
+    deliverer: Arc<Deliverer>,
+    in_flight: Mutex<JoinSet<()>>,
+}
+
+impl WebhookEventHandler {
+    /// Create a handler delivering to `endpoints`
+    pub fn new(
+        endpoints: Vec<WebhookEndpoint>,
+        config: WebhookDeliveryConfig,
+        transport: Arc<dyn WebhookTransport>,
+        dead_letters: Arc<dyn DeadLetterSink>,
+    ) -> Self {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|endpoint| {
+                Arc::new(EndpointDelivery {
+                    endpoint,
+                    breaker: EndpointCircuitBreaker::new(
+                        config.failure_threshold,
+                        config.open_duration,
+                    ),
+                })
+            })
+            .collect();
+        Self {
+            endpoints,
+            deliverer: Arc::new(Deliverer {
+                config,
+                transport,
+                dead_letters,
+            }),
+            in_flight: Mutex::new(JoinSet::new()),
+        }
+    }
+
+    /// State of the named endpoint's circuit, if the endpoint exists
+    pub fn circuit_state(&self, endpoint_name: &str) -> Option<CircuitState> {
+        self.endpoints
+            .iter()
+            .find(|delivery| delivery.endpoint.name == endpoint_name)
+            .map(|delivery| delivery.breaker.state())
+    }
+
+    /// Wait until every delivery started so far has been delivered or
+    /// dead-lettered
+    pub async fn flush(&self) {
+        let mut in_flight = std::mem::take(&mut *self.in_flight.lock().unwrap());
+        while in_flight.join_next().await.is_some() {}
+    }
+}
+
+impl Deliverer {
+    fn request(&self, endpoint: &WebhookEndpoint, payload: &Payload) -> WebhookRequest {
+        let message_id = payload.event_id.to_string();
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(&endpoint.secret, &message_id, timestamp, &payload.body);
+        WebhookRequest {
+            url: endpoint.url.clone(),
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                (WEBHOOK_ID_HEADER.to_string(), message_id),
+                (WEBHOOK_TIMESTAMP_HEADER.to_string(), timestamp.to_string()),
+                (WEBHOOK_SIGNATURE_HEADER.to_string(), signature),
+            ],
+            body: payload.body.to_vec(),
+        }
+    }
+
+    /// Deliver one event to one endpoint, retrying and then dead-lettering
+    ///
+    /// The circuit breaker sees the delivery as a whole: it is asked once
+    /// before the first attempt and told a single outcome, so one event
+    /// exhausting its retries counts as one failure, not `max_attempts`.
+    async fn deliver(&self, delivery: &EndpointDelivery, payload: &Payload) {
+        let endpoint = &delivery.endpoint;
+        let mut attempts = 0;
+        let mut last_error = String::new();
+
+        if delivery.breaker.try_acquire() {
+            while attempts < self.config.max_attempts {
+                attempts += 1;
+
+                // Signed per attempt, so the timestamp is always current
+                let request = self.request(endpoint, payload);
+                match self.transport.send(&request).await {
+                    Ok(()) => {
+                        delivery.breaker.record_success();
+                        tracing::debug!(
+                            endpoint = %endpoint.name,
+                            event_id = %payload.event_id,
+                            attempts,
+                            "Webhook delivered"
+                        );
+                        return;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            endpoint = %endpoint.name,
+                            event_id = %payload.event_id,
+                            attempt = attempts,
+                            error = %e,
+                            "Webhook delivery attempt failed"
+                        );
+                        last_error = e.to_string();
+                    }
+                }
+
+                if attempts < self.config.max_attempts {
+                    tokio::time::sleep(self.config.backoff(attempts)).await;
+                }
+            }
+            delivery.breaker.record_failure();
+        } else {
+            last_error = "Circuit breaker is open".to_string();
+        }
+
+        tracing::error!(
+            endpoint = %endpoint.name,
+            event_id = %payload.event_id,
+            attempts,
+            error = %last_error,
+            "Webhook delivery failed, dead-lettering event"
+        );
+        self.dead_letters
+            .dead_letter(DeadLetter {
+                endpoint: endpoint.name.clone(),
+                url: endpoint.url.clone(),
+                event_id: payload.event_id,
+                event_type: payload.event_type.to_string(),
+                payload: String::from_utf8_lossy(&payload.body).into_owned(),
+                attempts,
+                last_error,
+                failed_at: chrono::Utc::now(),
+            })
+            .await;
+    }
+}
+
+#[async_trait]
+impl<E: DomainEvent> EventHandler<E> for WebhookEventHandler {
+    fn name(&self) -> &'static str {
+        "WebhookEventHandler"
+    }
+
+    async fn handle(&self, envelope: EventEnvelope<E>) -> anyhow::Result<()> {
+        let event_type = envelope.event.event_type();
+        let payload = Payload {
+            event_id: envelope.event_id,
+            event_type,
+            body: serde_json::to_vec(&envelope)?.into(),
+        };
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+        // Reap finished deliveries so the set only holds running ones
+        while in_flight.try_join_next().is_some() {}
+        for delivery in self
+            .endpoints
+            .iter()
+            .filter(|delivery| delivery.endpoint.matches(event_type))
+        {
+            let delivery = delivery.clone();
+            let deliverer = self.deliverer.clone();
+            let payload = payload.clone();
+            in_flight.spawn(async move { deliverer.deliver(&delivery, &payload).await });
+        }
+
+        Ok(())
+    }
+
+    fn should_handle(&self, envelope: &EventEnvelope<E>) -> bool {
+        let event_type = envelope.event.event_type();
+        self.endpoints
+            .iter()
+            .any(|delivery| delivery.endpoint.matches(event_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::webhook::WebhookSecret;
+    use crate::infrastructure::webhook::dead_letter::InMemoryDeadLetterSink;
+    use crate::infrastructure::webhook::signing::verify;
+    use crate::infrastructure::webhook::transport::WebhookTransportError;
+    use serde::{Deserialize, Serialize};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct UserCreated {
+        user_id: String,
+    }
+
+    impl DomainEvent for UserCreated {
+        fn event_type(&self) -> &'static str {
+            "iam.user.created"
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct PolicyDeleted {
+        policy_id: String,
+    }
+
+    impl DomainEvent for PolicyDeleted {
+        fn event_type(&self) -> &'static str {
+            "iam.policy.deleted"
+        }
+    }
+
+    /// Transport that fails a number of times before succeeding
+    struct MockTransport {
+        failures_left: Mutex<u32>,
+        requests: Mutex<Vec<WebhookRequest>>,
+    }
+
+    impl MockTransport {
+        fn failing(times: u32) -> Arc<Self> {
+            Arc::new(Self {
+                failures_left: Mutex::new(times),
+                requests: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn requests(&self) -> Vec<WebhookRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl WebhookTransport for MockTransport {
+        async fn send(&self, request: &WebhookRequest) -> Result<(), WebhookTransportError> {
+            self.requests.lock().unwrap().push(request.clone());
+            let mut failures_left = self.failures_left.lock().unwrap();
+            if *failures_left > 0 {
+                *failures_left -= 1;
+                return Err(WebhookTransportError::Status(500));
+            }
+            Ok(())
+        }
+    }
+
+    /// Transport that holds every request until released
+    #[derive(Default)]
+    struct BlockedTransport {
+        release: tokio::sync::Notify,
+        delivered: AtomicBool,
+    }
+
+    #[async_trait]
+    impl WebhookTransport for BlockedTransport {
+        async fn send(&self, _request: &WebhookRequest) -> Result<(), WebhookTransportError> {
+            self.release.notified().await;
+            self.delivered.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn header<'a>(request: &'a WebhookRequest, name: &str) -> &'a str {
+        request
+            .headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+            .unwrap()
+    }
+
+    fn endpoint(name: &str, patterns: &[&str]) -> WebhookEndpoint {
+        WebhookEndpoint::new(
+            name,
+            format!("http://localhost/{name}"),
+            patterns.iter().map(|p| p.to_string()).collect(),
+            WebhookSecret::new("test-secret").unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn config(max_attempts: u32, failure_threshold: u32) -> WebhookDeliveryConfig {
+        WebhookDeliveryConfig {
+            max_attempts,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+            failure_threshold,
+            ..Default::default()
+        }
+    }
+
+    fn webhook_handler(
+        endpoints: Vec<WebhookEndpoint>,
+        config: WebhookDeliveryConfig,
+        transport: Arc<MockTransport>,
+        sink: &InMemoryDeadLetterSink,
+    ) -> WebhookEventHandler {
+        WebhookEventHandler::new(endpoints, config, transport, Arc::new(sink.clone()))
+    }
+
+    fn user_created() -> EventEnvelope<UserCreated> {
+        EventEnvelope::new(UserCreated {
+            user_id: "user-1".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_delivers_signed_envelope_to_matching_endpoint() {
+        let transport = MockTransport::failing(0);
+        let sink = InMemoryDeadLetterSink::new();
+        let handler = webhook_handler(
+            vec![endpoint("users", &["iam.user.*"])],
+            config(3, 5),
+            transport.clone(),
+            &sink,
+        );
+        let envelope = user_created();
+
+        handler.handle(envelope.clone()).await.unwrap();
+        handler.flush().await;
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.url, "http://localhost/users");
+
+        let sent: EventEnvelope<UserCreated> = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(sent.event_id, envelope.event_id);
+        assert_eq!(sent.event.user_id, "user-1");
+
+        let message_id = header(request, WEBHOOK_ID_HEADER);
+        let timestamp: i64 = header(request, WEBHOOK_TIMESTAMP_HEADER).parse().unwrap();
+        assert_eq!(message_id, envelope.event_id.to_string());
+        assert!(verify(
+            &WebhookSecret::new("test-secret").unwrap(),
+            message_id,
+            timestamp,
+            &request.body,
+            header(request, WEBHOOK_SIGNATURE_HEADER),
+        ));
+        assert!(sink.all().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_only_matching_endpoints_receive_events() {
+        let transport = MockTransport::failing(0);
+        let sink = InMemoryDeadLetterSink::new();
+        let handler = webhook_handler(
+            vec![
+                endpoint("users", &["iam.user.*"]),
+                endpoint("policies", &["iam.policy.deleted"]),
+            ],
+            config(3, 5),
+            transport.clone(),
+            &sink,
+        );
+
+        let policy_deleted = EventEnvelope::new(PolicyDeleted {
+            policy_id: "policy-1".to_string(),
+        });
+        assert!(handler.should_handle(&policy_deleted));
+        handler.handle(policy_deleted).await.unwrap();
+        handler.flush().await;
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "http://localhost/policies");
+
+        let no_subscribers = webhook_handler(
+            vec![endpoint("users", &["iam.user.*"])],
+            config(3, 5),
+            transport,
+            &sink,
+        );
+        assert!(!EventHandler::<PolicyDeleted>::should_handle(
+            &no_subscribers,
+            &EventEnvelope::new(PolicyDeleted {
+                policy_id: "policy-1".to_string(),
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_delivered() {
+        let transport = MockTransport::failing(2);
+        let sink = InMemoryDeadLetterSink::new();
+        let handler = webhook_handler(
+            vec![endpoint("users", &["*"])],
+            config(3, 5),
+            transport.clone(),
+            &sink,
+        );
+
+        handler.handle(user_created()).await.unwrap();
+        handler.flush().await;
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        // Every retry carries the same message ID, so receivers can dedupe
+        assert!(
+            requests
+                .iter()
+                .all(|request| header(request, WEBHOOK_ID_HEADER)
+                    == header(&requests[0], WEBHOOK_ID_HEADER))
+        );
+        assert!(sink.all().await.is_empty());
+        assert_eq!(handler.circuit_state("users"), Some(CircuitState::Closed));
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_are_dead_lettered() {
+        let transport = MockTransport::failing(u32::MAX);
+        let sink = InMemoryDeadLetterSink::new();
+        let handler = webhook_handler(
+            vec![endpoint("users", &["*"])],
+            config(3, 10),
+            transport.clone(),
+            &sink,
+        );
+        let envelope = user_created();
+
+        // Delivery failures do not fail the handler
+        handler.handle(envelope.clone()).await.unwrap();
+        handler.flush().await;
+
+        assert_eq!(transport.requests().len(), 3);
+        let letters = sink.all().await;
+        assert_eq!(letters.len(), 1);
+        let letter = &letters[0];
+        assert_eq!(letter.endpoint, "users");
+        assert_eq!(letter.event_id, envelope.event_id);
+        assert_eq!(letter.event_type, "iam.user.created");
+        assert_eq!(letter.attempts, 3);
+        assert!(letter.last_error.contains("500"));
+        let payload: EventEnvelope<UserCreated> = serde_json::from_str(&letter.payload).unwrap();
+        assert_eq!(payload.event_id, envelope.event_id);
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_skips_endpoint() {
+        let transport = MockTransport::failing(u32::MAX);
+        let sink = InMemoryDeadLetterSink::new();
+        let handler = webhook_handler(
+            vec![endpoint("users", &["*"])],
+            config(3, 2),
+            transport.clone(),
+            &sink,
+        );
+
+        // Exhausting the retries of one event is a single failure
+        handler.handle(user_created()).await.unwrap();
+        handler.flush().await;
+        assert_eq!(transport.requests().len(), 3);
+        assert_eq!(handler.circuit_state("users"), Some(CircuitState::Closed));
+
+        handler.handle(user_created()).await.unwrap();
+        handler.flush().await;
+        assert_eq!(transport.requests().len(), 6);
+        assert_eq!(handler.circuit_state("users"), Some(CircuitState::Open));
+
+        handler.handle(user_created()).await.unwrap();
+        handler.flush().await;
+        assert_eq!(transport.requests().len(), 6);
+
+        let letters = sink.all().await;
+        assert_eq!(letters.len(), 3);
+        assert_eq!(letters[1].attempts, 3);
+        assert_eq!(letters[2].attempts, 0);
+        assert!(letters[2].last_error.contains("Circuit breaker"));
+        assert_eq!(handler.circuit_state("unknown"), None);
+    }
+
+    #[tokio::test]
+    async fn test_handle_does_not_wait_for_delivery() {
+        let transport = Arc::new(BlockedTransport::default());
+        let sink = InMemoryDeadLetterSink::new();
+        let handler = WebhookEventHandler::new(
+            vec![endpoint("users", &["*"])],
+            config(3, 5),
+            transport.clone(),
+            Arc::new(sink.clone()),
+        );
+
+        handler.handle(user_created()).await.unwrap();
+        assert!(!transport.delivered.load(Ordering::SeqCst));
+
+        transport.release.notify_one();
+        handler.flush().await;
+        assert!(transport.delivered.load(Ordering::SeqCst));
+        assert!(sink.all().await.is_empty());
+    }
+}
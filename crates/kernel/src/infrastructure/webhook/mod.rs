@@ -0,0 +1,268 @@
+//! Webhook delivery of domain events to external HTTP endpoints
+//!
+//! A [`WebhookEventHandler`] POSTs the JSON-serialized `EventEnvelope` of
+//! every event whose type matches an endpoint's patterns. Payloads are signed
+//! following the Standard Webhooks scheme (`webhook-id`, `webhook-timestamp`
+//! and `webhook-signature` headers), each delivery runs on its own task and is
+//! retried with exponential backoff, and each endpoint has its own circuit
+//! breaker so an unreachable receiver does not slow down the others.
+//! Deliveries that still fail end up in a [`DeadLetterSink`].
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use std::fmt;
+use std::time::Duration;
+
+pub mod circuit_breaker;
+pub mod dead_letter;
+pub mod handler;
+pub mod signing;
+pub mod transport;
+
+// Re-export key types for convenience
+pub use circuit_breaker::{CircuitState, EndpointCircuitBreaker};
+pub use dead_letter::{DeadLetter, DeadLetterSink, InMemoryDeadLetterSink};
+pub use handler::WebhookEventHandler;
+pub use transport::{
+    ReqwestWebhookTransport, WebhookRequest, WebhookTransport, WebhookTransportError,
+};
+
+/// Prefix of base64-encoded secrets, as issued by Standard Webhooks tooling
+const SECRET_PREFIX: &str = "whsec_";
+
+/// Errors building webhook endpoints
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WebhookConfigError {
+    #[error("Webhook secret cannot be empty")]
+    EmptySecret,
+
+    #[error("Webhook secret has the whsec_ prefix but is not valid base64")]
+    InvalidSecretEncoding,
+
+    #[error("Invalid event type pattern '{0}': use an exact type, a prefix ending in '.*', or '*'")]
+    InvalidEventTypePattern(String),
+}
+
+/// Key used to sign the payloads sent to one endpoint
+///
+/// Either a `whsec_`-prefixed base64 key or any other string, whose UTF-8
+/// bytes are used as is. The key never appears in `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct WebhookSecret(Vec<u8>);
+
+impl WebhookSecret {
+    /// Parse a secret as read from configuration
+    pub fn new(secret: &str) -> Result<Self, WebhookConfigError> {
+        let key = match secret.strip_prefix(SECRET_PREFIX) {
+            Some(encoded) => STANDARD
+                .decode(encoded)
+                .map_err(|_| WebhookConfigError::InvalidSecretEncoding)?,
+            None => secret.as_bytes().to_vec(),
+        };
+        if key.is_empty() {
+            return Err(WebhookConfigError::EmptySecret);
+        }
+        Ok(Self(key))
+    }
+
+    pub(crate) fn key(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WebhookSecret(<redacted>)")
+    }
+}
+
+/// Whether `pattern` is a valid event type pattern
+///
+/// Valid patterns are an exact event type (`iam.user.created`), a prefix
+/// followed by `.*` (`iam.user.*`), or `*` for every event.
+pub fn is_valid_event_type_pattern(pattern: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    let prefix = pattern.strip_suffix(".*").unwrap_or(pattern);
+    !prefix.is_empty() && !prefix.contains('*')
+}
+
+fn pattern_matches(pattern: &str, event_type: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => event_type.starts_with(prefix),
+        None => pattern == event_type,
+    }
+}
+
+/// An external receiver of domain events
+#[derive(Debug, Clone)]
+pub struct WebhookEndpoint {
+    /// Name used in logs, dead letters and circuit breaker lookups
+    pub name: String,
+
+    /// URL the events are POSTed to
+    pub url: String,
+
+    /// Patterns of the event types sent to this endpoint
+    pub event_types: Vec<String>,
+
+    /// Key the payloads are signed with
+    pub secret: WebhookSecret,
+}
+
+impl WebhookEndpoint {
+    /// Create an endpoint that receives events matching any of `event_types`
+    pub fn new(
+        name: impl Into<String>,
+        url: impl Into<String>,
+        event_types: Vec<String>,
+        secret: WebhookSecret,
+    ) -> Result<Self, WebhookConfigError> {
+        if let Some(invalid) = event_types
+            .iter()
+            .find(|pattern| !is_valid_event_type_pattern(pattern))
+        {
+            return Err(WebhookConfigError::InvalidEventTypePattern(invalid.clone()));
+        }
+        Ok(Self {
+            name: name.into(),
+            url: url.into(),
+            event_types,
+            secret,
+        })
+    }
+
+    /// Whether events of `event_type` are sent to this endpoint
+    pub fn matches(&self, event_type: &str) -> bool {
+        self.event_types
+            .iter()
+            .any(|pattern| pattern_matches(pattern, event_type))
+    }
+}
+
+/// Retry, timeout and circuit breaker settings shared by all endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookDeliveryConfig {
+    /// Attempts per event and endpoint before the event is dead-lettered
+    pub max_attempts: u32,
+
+    /// Wait after the first failed attempt; doubled after each further one
+    pub initial_backoff: Duration,
+
+    /// Upper bound of the wait between attempts
+    pub max_backoff: Duration,
+
+    /// Timeout of a single HTTP request
+    pub request_timeout: Duration,
+
+    /// Consecutive deliveries failing all their attempts that open an
+    /// endpoint's circuit
+    pub failure_threshold: u32,
+
+    /// How long an open circuit rejects deliveries before letting one through
+    pub open_duration: Duration,
+}
+
+impl WebhookDeliveryConfig {
+    /// Wait before the attempt following failed attempt number `attempt`
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for WebhookDeliveryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            request_timeout: Duration::from_secs(10),
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(60),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(patterns: &[&str]) -> WebhookEndpoint {
+        WebhookEndpoint::new(
+            "test",
+            "http://localhost/hook",
+            patterns.iter().map(|p| p.to_string()).collect(),
+            WebhookSecret::new("secret").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_endpoint_matches_patterns() {
+        let exact = endpoint(&["iam.user.created"]);
+        assert!(exact.matches("iam.user.created"));
+        assert!(!exact.matches("iam.user.created.v2"));
+
+        let prefix = endpoint(&["iam.user.*"]);
+        assert!(prefix.matches("iam.user.created"));
+        assert!(prefix.matches("iam.user.status_changed"));
+        assert!(!prefix.matches("iam.users.created"));
+        assert!(!prefix.matches("iam.group.created"));
+
+        let all = endpoint(&["*"]);
+        assert!(all.matches("anything"));
+
+        assert!(!endpoint(&[]).matches("iam.user.created"));
+    }
+
+    #[test]
+    fn test_invalid_patterns_are_rejected() {
+        for pattern in ["", "iam.*.created", "iam*", ".*"] {
+            assert!(!is_valid_event_type_pattern(pattern), "{pattern}");
+        }
+        let result = WebhookEndpoint::new(
+            "test",
+            "http://localhost/hook",
+            vec!["iam.*.created".to_string()],
+            WebhookSecret::new("secret").unwrap(),
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            WebhookConfigError::InvalidEventTypePattern("iam.*.created".to_string())
+        );
+    }
+
+    #[test]
+    fn test_secret_parsing() {
+        assert_eq!(WebhookSecret::new("plain").unwrap().key(), b"plain");
+        assert_eq!(
+            WebhookSecret::new("whsec_aGVsbG8=").unwrap().key(),
+            b"hello"
+        );
+        assert_eq!(
+            WebhookSecret::new("whsec_!!!").unwrap_err(),
+            WebhookConfigError::InvalidSecretEncoding
+        );
+        assert_eq!(
+            WebhookSecret::new("").unwrap_err(),
+            WebhookConfigError::EmptySecret
+        );
+        assert!(!format!("{:?}", WebhookSecret::new("plain").unwrap()).contains("plain"));
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_maximum() {
+        let config = WebhookDeliveryConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(350),
+            ..Default::default()
+        };
+        assert_eq!(config.backoff(1), Duration::from_millis(100));
+        assert_eq!(config.backoff(2), Duration::from_millis(200));
+        assert_eq!(config.backoff(3), Duration::from_millis(350));
+        assert_eq!(config.backoff(40), Duration::from_millis(350));
+    }
+}
@@ -0,0 +1,160 @@
+//! HTTP transport used to POST webhook payloads
+
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// A signed webhook request, ready to be sent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Errors sending a webhook request
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum WebhookTransportError {
+    #[error("Webhook request failed: {0}")]
+    Request(String),
+
+    #[error("Webhook endpoint responded with status {0}")]
+    Status(u16),
+}
+
+/// Sends webhook requests; only a 2xx response counts as delivered
+#[async_trait]
+pub trait WebhookTransport: Send + Sync {
+    async fn send(&self, request: &WebhookRequest) -> Result<(), WebhookTransportError>;
+}
+
+/// Webhook transport backed by a `reqwest` client
+#[derive(Debug, Clone)]
+pub struct ReqwestWebhookTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestWebhookTransport {
+    /// Create a transport whose requests time out after `timeout`
+    pub fn new(timeout: Duration) -> Result<Self, WebhookTransportError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| WebhookTransportError::Request(e.to_string()))?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl WebhookTransport for ReqwestWebhookTransport {
+    async fn send(&self, request: &WebhookRequest) -> Result<(), WebhookTransportError> {
+        let mut builder = self.client.post(&request.url).body(request.body.clone());
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| WebhookTransportError::Request(e.to_string()))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(WebhookTransportError::Status(response.status().as_u16()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Answer one request with `status` and return the raw request received
+    async fn serve_once(status: &'static str) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            let mut buffer = [0u8; 4096];
+            // Read until the headers and the whole body have arrived
+            loop {
+                let read = socket.read(&mut buffer).await.unwrap();
+                received.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&received).to_string();
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length: ")
+                                .map(str::to_string)
+                        })
+                        .and_then(|value| value.trim().parse::<usize>().ok())
+                        .unwrap_or(0);
+                    if body.len() >= length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+            let response =
+                format!("HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n");
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&received).to_string()
+        });
+        (url, server)
+    }
+
+    fn request(url: String) -> WebhookRequest {
+        WebhookRequest {
+            url,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("webhook-id".to_string(), "msg_1".to_string()),
+            ],
+            body: br#"{"hello":"world"}"#.to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_posts_body_and_headers() {
+        let (url, server) = serve_once("204 No Content").await;
+        let transport = ReqwestWebhookTransport::new(Duration::from_secs(5)).unwrap();
+
+        transport.send(&request(url)).await.unwrap();
+
+        let received = server.await.unwrap();
+        assert!(received.starts_with("POST /hook HTTP/1.1"));
+        assert!(received.contains("webhook-id: msg_1"));
+        assert!(received.contains("content-type: application/json"));
+        assert!(received.ends_with(r#"{"hello":"world"}"#));
+    }
+
+    #[tokio::test]
+    async fn test_non_success_status_is_an_error() {
+        let (url, server) = serve_once("503 Service Unavailable").await;
+        let transport = ReqwestWebhookTransport::new(Duration::from_secs(5)).unwrap();
+
+        let result = transport.send(&request(url)).await;
+
+        assert_eq!(result, Err(WebhookTransportError::Status(503)));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_endpoint_is_an_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        drop(listener);
+        let transport = ReqwestWebhookTransport::new(Duration::from_secs(5)).unwrap();
+
+        let result = transport.send(&request(url)).await;
+
+        assert!(matches!(result, Err(WebhookTransportError::Request(_))));
+    }
+}
@@ -7,16 +7,32 @@ pub mod domain;
 pub mod infrastructure;
 
 // Re-export application types for ergonomic use
-pub use application::{UnitOfWork, UnitOfWorkError, UnitOfWorkFactory};
+pub use application::{
+    AttributeChange, CORRELATION_ID_HEADER, Cursor, GroupAttributesChanged, GroupMembershipChanged,
+    Page, PageRequest, PaginationError, PolicyAttachmentChanged, PolicyDeleted, PolicyStored,
+    Redacted, SchemaChanged, UnitOfWork, UnitOfWorkError, UnitOfWorkFactory, UserAttributesChanged,
+    UserStatusChanged, current_correlation_id, with_correlation_id,
+};
 
 // Re-export application ports for ergonomic use
 pub use application::ports::{
+    // Event bus
+    AckEventHandler,
+    AckSubscriptionConfig,
+    Acknowledger,
+    AggregateFilter,
+    // Event registry
+    AnyDomainEvent,
     // Authentication and authorization
     AuthContextError,
     AuthContextProvider,
     AuthorizationError,
-    // Event bus
+    Delivery,
+    // Coordination
+    DistributedLock,
+    DistributedLockError,
     DomainEvent,
+    DomainEventRegistry,
     // Cross-context IAM ports
     EffectivePoliciesQuery,
     EffectivePoliciesQueryPort,
@@ -27,20 +43,34 @@ pub use application::ports::{
     EventEnvelope,
     EventHandler,
     EventPublisher,
+    EventRegistryError,
+    // Event serialization
+    EventSerializationError,
+    // Event store
+    EventStore,
+    EventStoreError,
+    FencingToken,
     // Cross-context Organizations ports
     GetEffectiveScpsPort,
     GetEffectiveScpsQuery,
     IamPolicyEvaluator,
+    LockLease,
     ScpEvaluator,
+    SerializationFormat,
     SessionMetadata,
+    StoredEvent,
     Subscription,
 };
 
 // Re-export infrastructure implementations
-pub use infrastructure::{HrnGenerator, InMemoryEventBus};
+pub use infrastructure::{
+    HrnGenerator, InMemoryDistributedLock, InMemoryEventBus, InMemoryEventStore,
+};
 
 // Re-export shared domain (kernel) symbols
 pub use domain::{
-    ActionTrait, AttributeName, AttributeType, AttributeValue, HodeiEntity, HodeiEntityType, Hrn,
-    PolicyStorage, PolicyStorageError, Principal, Resource, ResourceTypeName, ServiceName,
+    ActionTrait, AttributeName, AttributeType, AttributeValue, CrossTenantAccess, HodeiEntity,
+    HodeiEntityType, Hrn, HrnParseError, HrnVisitor, InvalidAttributeValue, PolicyStorage,
+    PolicyStorageError, Principal, PrincipalStatus, Resource, ResourceTypeName, ServiceName,
+    TenantContext, TenantScoped, validate_hrns,
 };
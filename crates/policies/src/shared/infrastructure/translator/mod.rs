@@ -88,6 +88,7 @@ pub enum TranslatorError {
 /// - The value contains unsupported types
 /// - Nested structures are malformed
 /// - Entity references have invalid HRN format
+/// - Decimals, IP addresses or datetimes are malformed
 ///
 /// # Examples
 ///
@@ -145,9 +146,32 @@ pub fn translate_attribute_value(
 
             Ok(RestrictedExpression::new_entity_uid(uid))
         }
+
+        // Extension values are validated here: Cedar only parses them once
+        // the entity is evaluated
+        AttributeValue::Decimal(s) => {
+            validate_extension_value(value)?;
+            Ok(RestrictedExpression::new_decimal(s.clone()))
+        }
+
+        AttributeValue::IpAddr(s) => {
+            validate_extension_value(value)?;
+            Ok(RestrictedExpression::new_ip(s.clone()))
+        }
+
+        AttributeValue::DateTime(s) => {
+            validate_extension_value(value)?;
+            Ok(RestrictedExpression::new_datetime(s.clone()))
+        }
     }
 }
 
+fn validate_extension_value(value: &AttributeValue) -> Result<(), TranslatorError> {
+    value
+        .validate()
+        .map_err(|e| TranslatorError::InvalidAttributeValue(e.to_string()))
+}
+
 // ============================================================================
 // Entity Translation
 // ============================================================================
@@ -364,6 +388,24 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn translate_extension_values() {
+        let values = [
+            AttributeValue::Decimal("12.5".to_string()),
+            AttributeValue::IpAddr("192.168.0.0/16".to_string()),
+            AttributeValue::DateTime("2024-10-15T11:35:00Z".to_string()),
+        ];
+        for value in &values {
+            assert!(translate_attribute_value(value).is_ok(), "{}", value);
+        }
+
+        let malformed = AttributeValue::set(vec![AttributeValue::IpAddr("10.0.0.300".to_string())]);
+        assert!(matches!(
+            translate_attribute_value(&malformed),
+            Err(TranslatorError::InvalidAttributeValue(_))
+        ));
+    }
+
     #[test]
     fn translate_empty_record() {
         let value = AttributeValue::empty_record();
@@ -12,6 +12,12 @@ pub enum RepositoryError {
     #[error("Repository already exists: {0}")]
     RepositoryAlreadyExists(String),
     
+    #[error("Repository was deleted: {0}")]
+    RepositoryDeleted(String),
+    
+    #[error("Repository can no longer be restored: {0}")]
+    RestoreWindowExpired(String),
+    
     #[error("Organization not found: {0}")]
     OrganizationNotFound(String),
     
@@ -21,6 +27,9 @@ pub enum RepositoryError {
     #[error("Invalid repository configuration: {0}")]
     InvalidConfiguration(String),
     
+    #[error("Unsupported repository format: {0}")]
+    UnsupportedFormat(String),
+    
     #[error("Repository type mismatch")]
     RepositoryTypeMismatch,
     
@@ -30,6 +39,9 @@ pub enum RepositoryError {
     #[error("Cannot delete repository with artifacts: {0}")]
     RepositoryNotEmpty(String),
     
+    #[error("Repository quota exceeded: {0}")]
+    QuotaExceeded(String),
+    
     #[error("Storage backend not found: {0}")]
     StorageBackendNotFound(String),
     
@@ -47,6 +59,9 @@ pub enum RepositoryError {
 
     #[error("HRN error: {0}")]
     HrnError(String),
+
+    #[error("Search index error: {0}")]
+    SearchIndexError(String),
 }
 
 
@@ -12,6 +12,8 @@ pub enum RepositoryEvent {
     RepositoryCreated(RepositoryCreated),
     RepositoryUpdated(RepositoryUpdated),
     RepositoryDeleted(RepositoryDeleted),
+    RepositoryRestored(RepositoryRestored),
+    RepositoryPurged(RepositoryPurged),
     RetentionPolicyApplied(RetentionPolicyApplied),
 }
 
@@ -43,6 +45,20 @@ pub struct RepositoryDeleted {
     pub at: OffsetDateTime,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryRestored {
+    pub hrn: RepositoryId,
+    pub restored_by: Hrn,
+    pub at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryPurged {
+    pub hrn: RepositoryId,
+    pub artifacts_deleted: u64,
+    pub at: OffsetDateTime,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetentionPolicyApplied {
     pub repository_hrn: RepositoryId,
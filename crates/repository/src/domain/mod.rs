@@ -3,6 +3,7 @@
 pub mod error;
 pub mod events;
 pub mod policy;
+pub mod quota;
 pub mod repository;
 pub mod storage;
 
@@ -0,0 +1,81 @@
+// crates/repository/src/domain/quota.rs
+
+use serde::{Serialize, Deserialize};
+use crate::domain::{RepositoryResult, RepositoryError};
+use crate::domain::repository::RepositoryType;
+
+/// Cuota de almacenamiento de un repositorio.
+///
+/// Convención: un límite a `0` significa "sin límite", no "rechazar todo".
+/// Así la cuota por defecto (todo a cero) deja el repositorio sin restricciones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryQuota {
+    /// Tamaño máximo total en bytes (`0` = ilimitado).
+    pub max_size_bytes: u64,
+    /// Número máximo de artefactos (`0` = ilimitado).
+    pub max_artifact_count: u64,
+}
+
+/// Uso de almacenamiento de un repositorio.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryUsage {
+    pub size_bytes: u64,
+    pub artifact_count: u64,
+}
+
+impl RepositoryQuota {
+    /// Cuota sin ningún límite.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.max_size_bytes == 0 && self.max_artifact_count == 0
+    }
+
+    /// Valida que la cuota tenga sentido para el tipo de repositorio.
+    /// Los repositorios Virtual no almacenan artefactos propios, así que no
+    /// admiten límites.
+    pub fn validate_for(&self, repo_type: RepositoryType) -> RepositoryResult<()> {
+        if repo_type == RepositoryType::Virtual && !self.is_unlimited() {
+            return Err(RepositoryError::InvalidConfiguration(
+                "Virtual repositories do not store artifacts and cannot have a quota".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Comprueba si cabe un artefacto de `incoming_bytes` sobre el uso actual
+    /// (que debe incluir las subidas en curso).
+    pub fn check(&self, usage: &RepositoryUsage, incoming_bytes: u64) -> RepositoryResult<()> {
+        if self.max_artifact_count > 0 && usage.artifact_count.saturating_add(1) > self.max_artifact_count {
+            return Err(RepositoryError::QuotaExceeded(format!(
+                "artifact count limit of {} reached",
+                self.max_artifact_count
+            )));
+        }
+        if self.max_size_bytes > 0 && usage.size_bytes.saturating_add(incoming_bytes) > self.max_size_bytes {
+            return Err(RepositoryError::QuotaExceeded(format!(
+                "{} bytes would exceed the size limit of {} bytes ({} bytes in use)",
+                incoming_bytes, self.max_size_bytes, usage.size_bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl RepositoryUsage {
+    pub fn add(&self, other: &RepositoryUsage) -> RepositoryUsage {
+        RepositoryUsage {
+            size_bytes: self.size_bytes.saturating_add(other.size_bytes),
+            artifact_count: self.artifact_count.saturating_add(other.artifact_count),
+        }
+    }
+
+    pub fn saturating_sub(&self, other: &RepositoryUsage) -> RepositoryUsage {
+        RepositoryUsage {
+            size_bytes: self.size_bytes.saturating_sub(other.size_bytes),
+            artifact_count: self.artifact_count.saturating_sub(other.artifact_count),
+        }
+    }
+}
@@ -1,12 +1,18 @@
 // crates/repository/src/domain/repository.rs
 
-use shared::hrn::{Hrn, OrganizationId, RepositoryId};
+use shared::hrn::{Hrn, OrganizationId, RepositoryId, UserId};
 use shared::lifecycle::Lifecycle;
 use shared::enums::Ecosystem;
+use crate::domain::quota::RepositoryQuota;
 use serde::{Serialize, Deserialize};
 use url::Url;
 use std::str::FromStr;
 use std::collections::HashMap;
+use time::{Duration, OffsetDateTime};
+
+/// Tiempo durante el que un repositorio borrado puede restaurarse si no se
+/// configura otro.
+pub const DEFAULT_DELETION_RETENTION: Duration = Duration::days(30);
 
 /// Representa un contenedor para artefactos que define políticas de acceso y almacenamiento.
 /// Es el Agregado Raíz principal de este Bounded Context.
@@ -37,10 +43,49 @@ pub struct Repository {
     /// HRN del backend de almacenamiento donde se guardarán los binarios.
     pub storage_backend_hrn: Hrn,
 
+    /// Cuota de almacenamiento. Un límite a `0` significa ilimitado.
+    #[serde(default)]
+    pub quota: RepositoryQuota,
+
+    /// Marca de borrado lógico. Un repositorio borrado conserva sus artefactos
+    /// y puede restaurarse hasta `RepositoryDeletion::purge_after`.
+    #[serde(default)]
+    pub deletion: Option<RepositoryDeletion>,
+
     /// Información de auditoría y ciclo de vida.
     pub lifecycle: Lifecycle,
 }
 
+impl Repository {
+    pub fn is_deleted(&self) -> bool {
+        self.deletion.is_some()
+    }
+}
+
+/// Borrado lógico de un repositorio.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryDeletion {
+    pub deleted_at: OffsetDateTime,
+    pub deleted_by: UserId,
+    /// A partir de este instante el repositorio ya no se puede restaurar y
+    /// la purga lo elimina definitivamente junto con sus artefactos.
+    pub purge_after: OffsetDateTime,
+}
+
+impl RepositoryDeletion {
+    pub fn new(deleted_by: UserId, deleted_at: OffsetDateTime, retention: Duration) -> Self {
+        Self {
+            deleted_at,
+            deleted_by,
+            purge_after: deleted_at + retention,
+        }
+    }
+
+    pub fn is_restorable(&self, now: OffsetDateTime) -> bool {
+        now < self.purge_after
+    }
+}
+
 /// Configuración específica según el tipo de repositorio.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RepositoryConfig {
@@ -27,6 +27,8 @@ pub struct CreateRepositoryRequest {
     pub storage_backend_hrn: Option<String>,
     pub is_public: bool,
     pub metadata: Option<std::collections::HashMap<String, String>>,
+    #[serde(default)]
+    pub quota: crate::domain::quota::RepositoryQuota,
 }
 
 /// Response DTO para errores del endpoint
@@ -88,6 +90,7 @@ impl CreateRepositoryEndpoint {
             storage_backend_hrn: request.storage_backend_hrn,
             is_public: request.is_public,
             metadata: request.metadata,
+            quota: request.quota,
         };
 
         // Ejecutar el caso de uso
@@ -110,6 +113,9 @@ impl CreateRepositoryEndpoint {
             RepositoryError::RepositoryAlreadyExists(name) => {
                 CreateRepositoryErrorResponse::conflict(format!("Repository '{}' already exists", name))
             },
+            RepositoryError::RepositoryDeleted(message) => {
+                CreateRepositoryErrorResponse::conflict(message)
+            },
             RepositoryError::OrganizationNotFound(org_id) => {
                 CreateRepositoryErrorResponse::not_found(format!("Organization '{}' not found", org_id))
             },
@@ -119,6 +125,9 @@ impl CreateRepositoryEndpoint {
             RepositoryError::InvalidConfiguration(message) => {
                 CreateRepositoryErrorResponse::validation_error(format!("Invalid configuration: {}", message))
             },
+            RepositoryError::UnsupportedFormat(format) => {
+                CreateRepositoryErrorResponse::validation_error(format!("Unsupported repository format: {}", format))
+            },
             RepositoryError::RepositoryTypeMismatch => {
                 CreateRepositoryErrorResponse::validation_error(
                     format!("Repository type mismatch")
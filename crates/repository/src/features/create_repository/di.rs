@@ -4,12 +4,16 @@
 use std::sync::Arc;
 use mongodb::Database;
 
+use search::features::index_text_documents::IndexDocumentUseCase;
+
 use crate::infrastructure::mongodb_adapter::MongoDbRepositoryAdapter;
+use crate::infrastructure::search_index_adapter::SearchIndexRepositoryAdapter;
 use super::ports::{
     OrganizationExistsPort, RepositoryExistsPort, RepositoryCreatorPort,
-    StorageBackendExistsPort, EventPublisherPort
+    StorageBackendExistsPort, EventPublisherPort, RepositorySearchIndexPort
 };
 use super::use_case::CreateRepositoryUseCase;
+use super::format_validators::FormatValidatorRegistry;
 use super::api::CreateRepositoryEndpoint;
 
 // EventPublisherAdapter will be defined elsewhere or mocked
@@ -38,6 +42,7 @@ impl CreateRepositoryDIContainer {
     pub fn new(
         db: Database,
         event_publisher_port: Arc<dyn EventPublisherPort>,
+        search_index_port: Arc<dyn RepositorySearchIndexPort>,
     ) -> Self {
         let mongo_adapter = Arc::new(MongoDbRepositoryAdapter::new(db));
         
@@ -47,6 +52,8 @@ impl CreateRepositoryDIContainer {
             mongo_adapter.clone(),
             mongo_adapter,
             event_publisher_port,
+            search_index_port,
+            Arc::new(FormatValidatorRegistry::with_defaults()),
         ));
         
         let endpoint = CreateRepositoryEndpoint::new(use_case);
@@ -54,12 +61,15 @@ impl CreateRepositoryDIContainer {
         Self { endpoint }
     }
 
-    /// Constructor para producción con implementaciones MongoDB
-    pub fn for_production(db: Database) -> Self {
+    /// Constructor para producción con implementaciones MongoDB; los repositorios
+    /// creados se indexan con `index_use_case`, el mismo índice que los artefactos
+    pub fn for_production(db: Database, index_use_case: Arc<IndexDocumentUseCase>) -> Self {
         let event_publisher_port: Arc<dyn EventPublisherPort> = 
             Arc::new(EventPublisherAdapter::new());
+        let search_index_port: Arc<dyn RepositorySearchIndexPort> =
+            Arc::new(SearchIndexRepositoryAdapter::new(index_use_case));
 
-        Self::new(db, event_publisher_port)
+        Self::new(db, event_publisher_port, search_index_port)
     }
 
     /// Constructor para testing con mocks
@@ -68,7 +78,9 @@ impl CreateRepositoryDIContainer {
         let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
         let event_publisher_port = Arc::new(test_adapter::MockEventPublisherPort::new());
 
-        let container = Self::new(db, event_publisher_port.clone());
+        let search_index_port = Arc::new(test_adapter::MockRepositorySearchIndexPort::new());
+
+        let container = Self::new(db, event_publisher_port.clone(), search_index_port);
         (container, event_publisher_port)
     }
 }
@@ -107,4 +119,23 @@ pub mod test_adapter {
             Ok(())
         }
     }
+
+    /// Mock para RepositorySearchIndexPort que registra los repositorios indexados
+    pub struct MockRepositorySearchIndexPort {
+        pub indexed: Mutex<Vec<String>>,
+    }
+
+    impl MockRepositorySearchIndexPort {
+        pub fn new() -> Self {
+            Self { indexed: Mutex::new(Vec::new()) }
+        }
+    }
+
+    #[async_trait]
+    impl RepositorySearchIndexPort for MockRepositorySearchIndexPort {
+        async fn index_repository(&self, repository: &crate::domain::repository::Repository) -> RepositoryResult<()> {
+            self.indexed.lock().unwrap().push(repository.hrn.to_string());
+            Ok(())
+        }
+    }
 }
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use shared::enums::Ecosystem;
 use crate::domain::repository::{RepositoryType, HostedConfig, ProxyConfig, VirtualConfig};
+use crate::domain::quota::RepositoryQuota;
 use url::Url;
 
 /// Comando para crear un nuevo repositorio
@@ -29,6 +30,10 @@ pub struct CreateRepositoryCommand {
     /// Indica si el repositorio es público
     pub is_public: bool,
     
+    /// Cuota de almacenamiento (por defecto ilimitada; `0` = sin límite)
+    #[serde(default)]
+    pub quota: RepositoryQuota,
+    
     /// Metadatos personalizados adicionales
     pub metadata: Option<std::collections::HashMap<String, String>>,
 }
@@ -122,6 +127,9 @@ pub struct CreateRepositoryResponse {
     /// Ecosistema
     pub format: Ecosystem,
     
+    /// Cuota de almacenamiento aplicada
+    pub quota: RepositoryQuota,
+    
     /// Fecha de creación
     pub created_at: time::OffsetDateTime,
 }
@@ -0,0 +1,120 @@
+// crates/repository/src/features/create_repository/format_validators.rs
+
+//! Validación específica por formato de repositorio.
+//!
+//! Para añadir un formato basta con implementar `RepositoryFormatValidatorPort`
+//! y registrarlo; un formato sin validador registrado se rechaza.
+
+use std::sync::Arc;
+use shared::enums::Ecosystem;
+use tracing::debug;
+
+use crate::domain::{RepositoryResult, RepositoryError};
+use crate::domain::repository::{RepositoryConfig, RepositoryType, DeploymentPolicy};
+use super::ports::RepositoryFormatValidatorPort;
+
+/// Registro de validadores por formato
+pub struct FormatValidatorRegistry {
+    validators: Vec<Arc<dyn RepositoryFormatValidatorPort>>,
+}
+
+impl FormatValidatorRegistry {
+    /// Registro vacío: rechaza cualquier formato
+    pub fn new() -> Self {
+        Self { validators: Vec::new() }
+    }
+
+    /// Registro con los formatos soportados de serie
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register(Arc::new(MavenFormatValidator));
+        registry.register(Arc::new(NpmFormatValidator));
+        registry.register(Arc::new(DockerFormatValidator));
+        registry
+    }
+
+    /// Registra un validador, sustituyendo al existente para el mismo formato
+    pub fn register(&mut self, validator: Arc<dyn RepositoryFormatValidatorPort>) {
+        self.validators.retain(|v| v.format() != validator.format());
+        self.validators.push(validator);
+    }
+
+    /// Valida el repositorio con las reglas de su formato
+    pub fn validate(&self, format: Ecosystem, name: &str, repo_type: RepositoryType, config: &RepositoryConfig) -> RepositoryResult<()> {
+        debug!("Validating repository '{}' against {:?} rules", name, format);
+        let validator = self.validators.iter()
+            .find(|v| v.format() == format)
+            .ok_or_else(|| RepositoryError::UnsupportedFormat(format!("{:?}", format)))?;
+        validator.validate(name, repo_type, config)
+    }
+}
+
+impl Default for FormatValidatorRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+/// Las políticas de SNAPSHOT solo tienen sentido en Maven
+fn reject_snapshot_policies(format: Ecosystem, config: &RepositoryConfig) -> RepositoryResult<()> {
+    if let RepositoryConfig::Hosted(hosted) = config {
+        if matches!(hosted.deployment_policy, DeploymentPolicy::AllowSnapshots | DeploymentPolicy::BlockSnapshots) {
+            return Err(RepositoryError::InvalidConfiguration(format!(
+                "{:?} repositories have no SNAPSHOT versions; use AllowRedeploy or BlockRedeploy",
+                format
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn require_lowercase_name(format: Ecosystem, name: &str) -> RepositoryResult<()> {
+    if name.chars().any(|c| c.is_uppercase()) {
+        return Err(RepositoryError::InvalidRepositoryName(format!(
+            "{:?} repository names must be lowercase",
+            format
+        )));
+    }
+    Ok(())
+}
+
+/// Reglas para repositorios Maven: admite todas las políticas de despliegue
+pub struct MavenFormatValidator;
+
+impl RepositoryFormatValidatorPort for MavenFormatValidator {
+    fn format(&self) -> Ecosystem {
+        Ecosystem::Maven
+    }
+
+    fn validate(&self, _name: &str, _repo_type: RepositoryType, _config: &RepositoryConfig) -> RepositoryResult<()> {
+        Ok(())
+    }
+}
+
+/// Reglas para repositorios npm: versiones semver sin SNAPSHOT y nombres en minúscula
+pub struct NpmFormatValidator;
+
+impl RepositoryFormatValidatorPort for NpmFormatValidator {
+    fn format(&self) -> Ecosystem {
+        Ecosystem::Npm
+    }
+
+    fn validate(&self, name: &str, _repo_type: RepositoryType, config: &RepositoryConfig) -> RepositoryResult<()> {
+        require_lowercase_name(Ecosystem::Npm, name)?;
+        reject_snapshot_policies(Ecosystem::Npm, config)
+    }
+}
+
+/// Reglas para repositorios Docker: la referencia de imagen exige minúsculas
+pub struct DockerFormatValidator;
+
+impl RepositoryFormatValidatorPort for DockerFormatValidator {
+    fn format(&self) -> Ecosystem {
+        Ecosystem::Docker
+    }
+
+    fn validate(&self, name: &str, _repo_type: RepositoryType, config: &RepositoryConfig) -> RepositoryResult<()> {
+        require_lowercase_name(Ecosystem::Docker, name)?;
+        reject_snapshot_policies(Ecosystem::Docker, config)
+    }
+}
@@ -3,6 +3,7 @@
 pub mod dto;
 pub mod ports;
 pub mod use_case;
+pub mod format_validators;
 
 pub mod api;
 pub mod di;
@@ -14,4 +15,5 @@ mod use_case_test;
 // Public exports
 pub use dto::{CreateRepositoryCommand, CreateRepositoryResponse};
 pub use api::CreateRepositoryEndpoint;
-pub use di::CreateRepositoryDIContainer;
\ No newline at end of file
+pub use di::CreateRepositoryDIContainer;
+pub use format_validators::FormatValidatorRegistry;
\ No newline at end of file
@@ -16,6 +16,9 @@ pub trait OrganizationExistsPort: Send + Sync {
 pub trait RepositoryExistsPort: Send + Sync {
     /// Verifica si un repositorio existe con el nombre dado en la organización
     async fn repository_exists(&self, organization_id: &OrganizationId, name: &str) -> RepositoryResult<bool>;
+
+    /// Busca un repositorio borrado (pendiente de purga) con el nombre dado en la organización
+    async fn find_deleted_repository(&self, organization_id: &OrganizationId, name: &str) -> RepositoryResult<Option<Repository>>;
 }
 
 /// Puerto para guardar repositorios
@@ -39,12 +42,31 @@ pub trait EventPublisherPort: Send + Sync {
     async fn publish_repository_created(&self, repository: &Repository) -> RepositoryResult<()>;
 }
 
+/// Puerto para indexar repositorios en la búsqueda unificada
+#[async_trait]
+pub trait RepositorySearchIndexPort: Send + Sync {
+    /// Indexa (o reindexa) un repositorio para que aparezca junto a los artefactos
+    async fn index_repository(&self, repository: &Repository) -> RepositoryResult<()>;
+}
+
 /// Puerto para validar nombres de repositorio
 pub trait RepositoryNameValidatorPort: Send + Sync {
     /// Valida que el nombre del repositorio sea válido
     fn validate_repository_name(&self, name: &str) -> RepositoryResult<()>;
 }
 
+/// Reglas de validación propias de un formato de repositorio (Maven, npm, ...).
+///
+/// Cada formato aporta su implementación y se registra en
+/// `FormatValidatorRegistry`; el caso de uso no conoce las reglas concretas.
+pub trait RepositoryFormatValidatorPort: Send + Sync {
+    /// Formato al que aplican estas reglas
+    fn format(&self) -> shared::enums::Ecosystem;
+
+    /// Valida el nombre, el tipo y la configuración de un repositorio de este formato
+    fn validate(&self, name: &str, repo_type: crate::domain::repository::RepositoryType, config: &crate::domain::repository::RepositoryConfig) -> RepositoryResult<()>;
+}
+
 /// Puerto para validar configuraciones de repositorio
 pub trait RepositoryConfigValidatorPort: Send + Sync {
     /// Valida la configuración del repositorio según su tipo
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use shared::hrn::{OrganizationId, RepositoryId, UserId, Hrn};
 use shared::lifecycle::Lifecycle;
 use time::OffsetDateTime;
-use tracing::{info, error, instrument, debug};
+use tracing::{info, error, instrument, debug, warn};
 
 use crate::domain::{RepositoryResult, RepositoryError};
 use crate::domain::repository::{Repository, RepositoryConfig, RepositoryType};
@@ -13,8 +13,9 @@ use crate::domain::events::{RepositoryEvent, RepositoryCreated};
 use super::dto::{CreateRepositoryCommand, CreateRepositoryResponse};
 use super::ports::{
     OrganizationExistsPort, RepositoryExistsPort, RepositoryCreatorPort, 
-    StorageBackendExistsPort, EventPublisherPort
+    StorageBackendExistsPort, EventPublisherPort, RepositorySearchIndexPort
 };
+use super::format_validators::FormatValidatorRegistry;
 
 /// Caso de uso para crear un nuevo repositorio
 pub struct CreateRepositoryUseCase {
@@ -23,6 +24,8 @@ pub struct CreateRepositoryUseCase {
     pub repository_creator_port: Arc<dyn RepositoryCreatorPort>,
     pub storage_backend_exists_port: Arc<dyn StorageBackendExistsPort>,
     pub event_publisher_port: Arc<dyn EventPublisherPort>,
+    pub search_index_port: Arc<dyn RepositorySearchIndexPort>,
+    pub format_validators: Arc<FormatValidatorRegistry>,
 }
 
 impl CreateRepositoryUseCase {
@@ -32,6 +35,8 @@ impl CreateRepositoryUseCase {
         repository_creator_port: Arc<dyn RepositoryCreatorPort>,
         storage_backend_exists_port: Arc<dyn StorageBackendExistsPort>,
         event_publisher_port: Arc<dyn EventPublisherPort>,
+        search_index_port: Arc<dyn RepositorySearchIndexPort>,
+        format_validators: Arc<FormatValidatorRegistry>,
     ) -> Self {
         Self {
             organization_exists_port,
@@ -39,6 +44,8 @@ impl CreateRepositoryUseCase {
             repository_creator_port,
             storage_backend_exists_port,
             event_publisher_port,
+            search_index_port,
+            format_validators,
         }
     }
 
@@ -80,6 +87,19 @@ impl CreateRepositoryUseCase {
         }
     }
 
+    fn deleted_repository_error(deleted: &Repository, now: OffsetDateTime) -> RepositoryError {
+        match &deleted.deletion {
+            Some(deletion) if deletion.is_restorable(now) => RepositoryError::RepositoryDeleted(format!(
+                "a repository named '{}' was deleted and can be restored until {} (POST /repositories/{}/restore); restore it instead of creating a new one",
+                deleted.name, deletion.purge_after, deleted.hrn
+            )),
+            _ => RepositoryError::RepositoryDeleted(format!(
+                "a repository named '{}' was deleted and is pending purge; the name will be available once it is purged",
+                deleted.name
+            )),
+        }
+    }
+
     #[instrument(skip(self, command, organization_id, user_id))]
     pub async fn execute(
         &self,
@@ -88,6 +108,7 @@ impl CreateRepositoryUseCase {
         user_id: UserId,
     ) -> RepositoryResult<CreateRepositoryResponse> {
         info!("Creating repository '{}' for organization '{}'", command.name, organization_id);
+        let now = OffsetDateTime::now_utc();
 
         // 1. Validar que la organización existe
         if !self.organization_exists_port.organization_exists(&organization_id).await? {
@@ -98,7 +119,12 @@ impl CreateRepositoryUseCase {
         // 2. Validar que el nombre del repositorio es válido
         self.validate_repository_name(&command.name)?;
 
-        // 3. Verificar que no existe un repositorio con el mismo nombre en la organización
+        // 3. Verificar que no existe un repositorio con el mismo nombre en la organización,
+        //    ni uno borrado que aún no se haya purgado
+        if let Some(deleted) = self.repository_exists_port.find_deleted_repository(&organization_id, &command.name).await? {
+            error!("Repository '{}' in organization '{}' is deleted but not yet purged", command.name, organization_id);
+            return Err(Self::deleted_repository_error(&deleted, now));
+        }
         if self.repository_exists_port.repository_exists(&organization_id, &command.name).await? {
             error!("Repository '{}' already exists in organization '{}'", command.name, organization_id);
             return Err(RepositoryError::RepositoryAlreadyExists(command.name));
@@ -107,8 +133,11 @@ impl CreateRepositoryUseCase {
         // 4. Convertir la configuración del DTO al modelo de dominio
         let domain_config: RepositoryConfig = command.config.into();
 
-        // 5. Validar la configuración según el tipo de repositorio
+        // 5. Validar la configuración y la cuota según el tipo de repositorio,
+        //    y las reglas propias de su formato (Maven, npm, Docker...)
         self.validate_repository_config(&command.repo_type, &domain_config)?;
+        command.quota.validate_for(command.repo_type)?;
+        self.format_validators.validate(command.format, &command.name, command.repo_type, &domain_config)?;
 
         // 6. Para repositorios Hosted, verificar que el backend de almacenamiento existe
         if let Some(storage_backend_hrn) = &command.storage_backend_hrn {
@@ -122,7 +151,6 @@ impl CreateRepositoryUseCase {
         let repository_id = RepositoryId::new(&organization_id.to_string(), &command.name)?;
 
         // 8. Crear el modelo de dominio del repositorio
-        let now = OffsetDateTime::now_utc();
         let repository = Repository {
             hrn: repository_id.clone(),
             organization_hrn: organization_id.clone(),
@@ -132,6 +160,8 @@ impl CreateRepositoryUseCase {
             format: command.format,
             config: domain_config,
             storage_backend_hrn: command.storage_backend_hrn.unwrap_or_else(|| Hrn::new(&format!("hrn:hodei:storage:::{}:default", organization_id)).unwrap()),
+            quota: command.quota,
+            deletion: None,
             lifecycle: Lifecycle::new(user_id.clone()),
         };
 
@@ -150,14 +180,20 @@ impl CreateRepositoryUseCase {
         
         self.event_publisher_port.publish_repository_created(&event).await?;
 
+        // 11. Indexar el repositorio para la búsqueda; un fallo no deshace la creación
+        if let Err(e) = self.search_index_port.index_repository(&repository).await {
+            warn!("Repository {} created but not indexed for search: {}", repository_id, e);
+        }
+
         info!("Repository '{}' created successfully with HRN: {}", command.name, repository_id);
 
-        // 11. Retornar la respuesta
+        // 12. Retornar la respuesta
         Ok(CreateRepositoryResponse {
             hrn: repository_id.to_string(),
             name: command.name,
             repo_type: command.repo_type,
             format: command.format,
+            quota: command.quota,
             created_at: now,
         })
     }
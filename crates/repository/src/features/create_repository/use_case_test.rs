@@ -48,5 +48,83 @@ async fn test_create_repository_success() {
     assert!(published_events[0].contains("test-repo"));
 }
 
+#[tokio::test]
+async fn test_created_repository_is_indexed_for_search() {
+    use super::di::test_adapter::{MockEventPublisherPort, MockRepositorySearchIndexPort};
+
+    // Arrange
+    let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
+    let search_index_port = Arc::new(MockRepositorySearchIndexPort::new());
+    let container = CreateRepositoryDIContainer::new(
+        db,
+        Arc::new(MockEventPublisherPort::new()),
+        search_index_port.clone(),
+    );
+
+    let command = CreateRepositoryCommand {
+        name: "indexed-repo".to_string(),
+        repo_type: RepositoryType::Hosted,
+        format: Ecosystem::Maven,
+        config: RepositoryConfigDto::Hosted(HostedConfigDto {
+            deployment_policy: DeploymentPolicy::AllowSnapshots,
+        }),
+        storage_backend_hrn: Some(Hrn::new("hrn:hodei:storage:::{}:default").unwrap()),
+    };
+
+    // Act
+    let response = container.endpoint.use_case
+        .execute(command, OrganizationId::new("test-org").unwrap(), UserId::new_system_user())
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(*search_index_port.indexed.lock().unwrap(), vec![response.hrn]);
+}
+
 // Add other tests similarly, using the DI container to get the use case
-// and mocking the necessary ports.
\ No newline at end of file
+// and mocking the necessary ports.
+mod format_validation {
+    use super::*;
+    use crate::features::create_repository::format_validators::FormatValidatorRegistry;
+
+    fn hosted(deployment_policy: DeploymentPolicy) -> RepositoryConfig {
+        RepositoryConfig::Hosted(HostedConfig { deployment_policy })
+    }
+
+    #[test]
+    fn test_maven_accepts_snapshot_policies() {
+        let registry = FormatValidatorRegistry::with_defaults();
+
+        let result = registry.validate(Ecosystem::Maven, "Libs-Release", RepositoryType::Hosted, &hosted(DeploymentPolicy::AllowSnapshots));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_npm_rejects_snapshot_policies() {
+        let registry = FormatValidatorRegistry::with_defaults();
+
+        let result = registry.validate(Ecosystem::Npm, "npm-internal", RepositoryType::Hosted, &hosted(DeploymentPolicy::AllowSnapshots));
+
+        assert!(matches!(result, Err(RepositoryError::InvalidConfiguration(_))));
+        assert!(registry.validate(Ecosystem::Npm, "npm-internal", RepositoryType::Hosted, &hosted(DeploymentPolicy::BlockRedeploy)).is_ok());
+    }
+
+    #[test]
+    fn test_docker_requires_lowercase_names() {
+        let registry = FormatValidatorRegistry::with_defaults();
+
+        let result = registry.validate(Ecosystem::Docker, "Docker-Images", RepositoryType::Hosted, &hosted(DeploymentPolicy::AllowRedeploy));
+
+        assert!(matches!(result, Err(RepositoryError::InvalidRepositoryName(_))));
+    }
+
+    #[test]
+    fn test_format_without_validator_is_rejected() {
+        let registry = FormatValidatorRegistry::new();
+
+        let result = registry.validate(Ecosystem::Maven, "maven-releases", RepositoryType::Hosted, &hosted(DeploymentPolicy::AllowRedeploy));
+
+        assert!(matches!(result, Err(RepositoryError::UnsupportedFormat(_))));
+    }
+}
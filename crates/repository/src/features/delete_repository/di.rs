@@ -3,15 +3,20 @@
 
 use std::sync::Arc;
 use mongodb::Database;
+use time::Duration;
 
 use crate::infrastructure::mongodb_adapter::MongoDbRepositoryAdapter;
 use super::ports::{
     RepositoryDeleterPort, RepositoryDeleteAuthorizationPort, ArtifactDeleterPort,
     RepositoryDeleteEventPublisherPort
 };
-use super::use_case::DeleteRepositoryUseCase;
+use crate::domain::repository::DEFAULT_DELETION_RETENTION;
+use super::use_case::{DeleteRepositoryUseCase, PurgeDeletedRepositoriesUseCase};
 use super::api::DeleteRepositoryEndpoint;
 
+/// Intervalo entre ejecuciones de la purga de repositorios borrados en producción
+pub const DEFAULT_PURGE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
 // Mock implementations for ports that are not yet fully implemented
 
 pub struct RepositoryDeleteAuthorizationAdapter;
@@ -54,12 +59,18 @@ impl RepositoryDeleteEventPublisherPort for RepositoryDeleteEventPublisherAdapte
     async fn publish_repository_deleted(&self, _repository_id: &shared::hrn::RepositoryId, _deleted_by: &shared::hrn::UserId, _artifact_count: u64, _total_size_bytes: u64) -> crate::domain::RepositoryResult<()> {
         Ok(())
     }
+    async fn publish_repository_purged(&self, _repository_id: &shared::hrn::RepositoryId, _artifacts_deleted: u64) -> crate::domain::RepositoryResult<()> {
+        Ok(())
+    }
 }
 
 
 /// Contenedor de inyección de dependencias para la feature delete_repository
 pub struct DeleteRepositoryDIContainer {
     pub endpoint: DeleteRepositoryEndpoint,
+    pub purge_use_case: Arc<PurgeDeletedRepositoriesUseCase>,
+    /// Tarea que ejecuta la purga periódicamente; solo se lanza en producción
+    pub purge_schedule: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl DeleteRepositoryDIContainer {
@@ -68,21 +79,32 @@ impl DeleteRepositoryDIContainer {
         authorization_port: Arc<dyn RepositoryDeleteAuthorizationPort>,
         artifact_deleter_port: Arc<dyn ArtifactDeleterPort>,
         event_publisher_port: Arc<dyn RepositoryDeleteEventPublisherPort>,
+        retention: Duration,
     ) -> Self {
         let mongo_adapter = Arc::new(MongoDbRepositoryAdapter::new(db));
         
         let use_case = Arc::new(DeleteRepositoryUseCase::new(
-            mongo_adapter,
+            mongo_adapter.clone(),
             authorization_port,
+            artifact_deleter_port.clone(),
+            event_publisher_port.clone(),
+            retention,
+        ));
+        
+        let purge_use_case = Arc::new(PurgeDeletedRepositoriesUseCase::new(
+            mongo_adapter,
             artifact_deleter_port,
             event_publisher_port,
         ));
         
         let endpoint = DeleteRepositoryEndpoint::new(use_case);
         
-        Self { endpoint }
+        Self { endpoint, purge_use_case, purge_schedule: None }
     }
 
+    /// Constructor para producción; los repositorios borrados se conservan
+    /// `DEFAULT_DELETION_RETENTION` y se purgan cada `DEFAULT_PURGE_INTERVAL`.
+    /// Debe llamarse dentro de un runtime de Tokio, que ejecuta la purga.
     pub fn for_production(db: Database) -> Self {
         let authorization_adapter: Arc<dyn RepositoryDeleteAuthorizationPort> = 
             Arc::new(RepositoryDeleteAuthorizationAdapter::new());
@@ -93,12 +115,15 @@ impl DeleteRepositoryDIContainer {
         let event_publisher_adapter: Arc<dyn RepositoryDeleteEventPublisherPort> = 
             Arc::new(RepositoryDeleteEventPublisherAdapter::new());
 
-        Self::new(
+        let mut container = Self::new(
             db,
             authorization_adapter,
             artifact_deleter_adapter,
             event_publisher_adapter,
-        )
+            DEFAULT_DELETION_RETENTION,
+        );
+        container.purge_schedule = Some(container.purge_use_case.clone().spawn_schedule(DEFAULT_PURGE_INTERVAL));
+        container
     }
 
     #[cfg(test)]
@@ -113,6 +138,7 @@ impl DeleteRepositoryDIContainer {
             authorization_port,
             artifact_deleter_port,
             event_publisher_port,
+            DEFAULT_DELETION_RETENTION,
         )
     }
 }
@@ -143,15 +169,24 @@ pub mod test_adapter {
         }
     }
 
-    pub struct MockArtifactDeleterPort;
+    pub struct MockArtifactDeleterPort {
+        pub should_fail: Mutex<bool>,
+    }
 
     impl MockArtifactDeleterPort {
-        pub fn new() -> Self { Self }
+        pub fn new() -> Self {
+            Self { should_fail: Mutex::new(false) }
+        }
     }
 
     #[async_trait]
     impl ArtifactDeleterPort for MockArtifactDeleterPort {
-        async fn delete_repository_artifacts(&self, _repository_id: &RepositoryId) -> RepositoryResult<u64> { Ok(0) }
+        async fn delete_repository_artifacts(&self, repository_id: &RepositoryId) -> RepositoryResult<u64> {
+            if *self.should_fail.lock().unwrap() {
+                return Err(RepositoryError::DatabaseError(format!("Failed to delete artifacts of {}", repository_id)));
+            }
+            Ok(0)
+        }
         async fn count_repository_artifacts(&self, _repository_id: &RepositoryId) -> RepositoryResult<u64> { Ok(0) }
     }
 
@@ -166,5 +201,8 @@ pub mod test_adapter {
         async fn publish_repository_deleted(&self, _repository_id: &RepositoryId, _deleted_by: &UserId, _artifact_count: u64, _total_size_bytes: u64) -> RepositoryResult<()> {
             Ok(())
         }
+        async fn publish_repository_purged(&self, _repository_id: &RepositoryId, _artifacts_deleted: u64) -> RepositoryResult<()> {
+            Ok(())
+        }
     }
 }
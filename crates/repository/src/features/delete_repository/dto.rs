@@ -41,6 +41,19 @@ pub struct DeleteRepositoryResponse {
     /// Información de auditoría
     pub deleted_by: String,
     pub deleted_at: time::OffsetDateTime,
+    
+    /// Fecha límite para restaurar el repositorio antes de que se purgue
+    pub restorable_until: time::OffsetDateTime,
+}
+
+/// Resultado de una purga de repositorios borrados
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PurgeDeletedRepositoriesResponse {
+    /// HRNs de los repositorios eliminados definitivamente
+    pub purged_repositories: Vec<String>,
+    
+    /// Número total de artefactos eliminados
+    pub artifacts_deleted: u64,
 }
 
 /// Estadísticas del repositorio antes de eliminación
@@ -77,6 +90,9 @@ impl From<crate::domain::repository::Repository> for DeleteRepositoryResponse {
             },
             deleted_by: "system".to_string(), // TODO: Obtener del contexto
             deleted_at: time::OffsetDateTime::now_utc(),
+            restorable_until: repository.deletion.as_ref()
+                .map(|deletion| deletion.purge_after)
+                .unwrap_or_else(time::OffsetDateTime::now_utc),
         }
     }
 }
\ No newline at end of file
@@ -14,4 +14,5 @@ mod use_case_test;
 // Public exports
 pub use dto::{DeleteRepositoryCommand, DeleteRepositoryResponse};
 pub use api::DeleteRepositoryEndpoint;
-pub use di::DeleteRepositoryDIContainer;
\ No newline at end of file
+pub use di::DeleteRepositoryDIContainer;
+pub use use_case::PurgeDeletedRepositoriesUseCase;
\ No newline at end of file
@@ -2,17 +2,24 @@
 
 use async_trait::async_trait;
 use shared::hrn::{RepositoryId, UserId};
-use crate::domain::{RepositoryResult, repository::Repository};
+use crate::domain::{RepositoryResult, repository::{Repository, RepositoryDeletion}};
 
 /// Puerto para eliminar repositorios
 #[async_trait]
 pub trait RepositoryDeleterPort: Send + Sync {
-    /// Elimina un repositorio existente
+    /// Marca un repositorio como borrado, conservando sus artefactos
+    async fn mark_repository_deleted(&self, repository_id: &RepositoryId, deletion: &RepositoryDeletion) -> RepositoryResult<()>;
+    
+    /// Elimina definitivamente un repositorio marcado como borrado.
+    /// Devuelve `RepositoryNotFound` si ya no está marcado (p. ej. se restauró).
     async fn delete_repository(&self, repository_id: &RepositoryId) -> RepositoryResult<()>;
     
     /// Obtiene un repositorio antes de eliminarlo
     async fn get_repository_for_deletion(&self, repository_id: &RepositoryId) -> RepositoryResult<Option<Repository>>;
     
+    /// Lista los repositorios marcados como borrados
+    async fn list_deleted_repositories(&self) -> RepositoryResult<Vec<Repository>>;
+    
     /// Verifica si un repositorio está vacío (sin artefactos)
     async fn is_repository_empty(&self, repository_id: &RepositoryId) -> RepositoryResult<bool>;
 }
@@ -40,4 +47,7 @@ pub trait RepositoryDeleteEventPublisherPort: Send + Sync {
     /// Publica un evento indicando que un repositorio fue eliminado
     async fn publish_repository_deleted(&self, repository_id: &RepositoryId, deleted_by: &UserId, 
                                        artifact_count: u64, total_size_bytes: u64) -> RepositoryResult<()>;
+    
+    /// Publica un evento indicando que un repositorio borrado fue purgado definitivamente
+    async fn publish_repository_purged(&self, repository_id: &RepositoryId, artifacts_deleted: u64) -> RepositoryResult<()>;
 }
\ No newline at end of file
@@ -2,22 +2,28 @@
 
 use std::sync::Arc;
 use shared::hrn::{RepositoryId, UserId};
+use time::{Duration, OffsetDateTime};
 use tracing::{info, error, instrument, warn};
 
 use crate::domain::{RepositoryResult, RepositoryError};
-use crate::domain::repository::Repository;
-use super::dto::{DeleteRepositoryCommand, DeleteRepositoryResponse};
+use crate::domain::repository::{Repository, RepositoryDeletion};
+use super::dto::{DeleteRepositoryCommand, DeleteRepositoryResponse, PurgeDeletedRepositoriesResponse};
 use super::ports::{
     RepositoryDeleterPort, RepositoryDeleteAuthorizationPort, ArtifactDeleterPort,
     RepositoryDeleteEventPublisherPort
 };
 
-/// Caso de uso para eliminar un repositorio
+/// Caso de uso para eliminar un repositorio.
+///
+/// El borrado es lógico: el repositorio y sus artefactos se conservan durante
+/// `retention` y pueden recuperarse con `RestoreRepositoryUseCase`. Pasado ese
+/// plazo, `PurgeDeletedRepositoriesUseCase` los elimina definitivamente.
 pub struct DeleteRepositoryUseCase {
     pub repository_deleter_port: Arc<dyn RepositoryDeleterPort>,
     pub authorization_port: Arc<dyn RepositoryDeleteAuthorizationPort>,
     pub artifact_deleter_port: Arc<dyn ArtifactDeleterPort>,
     pub event_publisher_port: Arc<dyn RepositoryDeleteEventPublisherPort>,
+    pub retention: Duration,
 }
 
 impl DeleteRepositoryUseCase {
@@ -26,12 +32,14 @@ impl DeleteRepositoryUseCase {
         authorization_port: Arc<dyn RepositoryDeleteAuthorizationPort>,
         artifact_deleter_port: Arc<dyn ArtifactDeleterPort>,
         event_publisher_port: Arc<dyn RepositoryDeleteEventPublisherPort>,
+        retention: Duration,
     ) -> Self {
         Self {
             repository_deleter_port,
             authorization_port,
             artifact_deleter_port,
             event_publisher_port,
+            retention,
         }
     }
 
@@ -92,28 +100,21 @@ impl DeleteRepositoryUseCase {
         info!("Repository {} has {} artifacts, total size: {} bytes", 
               repository.name, artifact_count, total_size_bytes);
 
-        // 6. Eliminar artefactos asociados (si hay alguno)
-        if artifact_count > 0 {
-            info!("Deleting {} artifacts from repository {}", artifact_count, repository.name);
-            let deleted_count = self.artifact_deleter_port.delete_repository_artifacts(&repository_id).await?;
-            info!("Successfully deleted {} artifacts", deleted_count);
-            
-            if deleted_count != artifact_count {
-                warn!("Expected to delete {} artifacts but only deleted {}", artifact_count, deleted_count);
-            }
-        }
-
-        // 7. Eliminar el repositorio
-        self.repository_deleter_port.delete_repository(&repository_id).await?;
-        info!("Repository deleted successfully: {}", repository_id.as_str());
+        // 6. Marcar el repositorio como borrado; los artefactos se conservan hasta la purga
+        let now = OffsetDateTime::now_utc();
+        let deletion = RepositoryDeletion::new(user_id.clone(), now, self.retention);
+        self.repository_deleter_port.mark_repository_deleted(&repository_id, &deletion).await?;
+        info!("Repository {} marked as deleted, restorable until {}", repository_id.as_str(), deletion.purge_after);
 
-        // 8. Publicar evento de eliminación
+        // 7. Publicar evento de eliminación
         self.event_publisher_port.publish_repository_deleted(&repository_id, &user_id, artifact_count, total_size_bytes).await?;
 
-        // 9. Construir la respuesta
+        // 8. Construir la respuesta
         let mut response = DeleteRepositoryResponse::from(repository);
+        response.message = format!("Repository '{}' deleted; it can be restored until {}", response.name, deletion.purge_after);
         response.deleted_by = user_id.as_str().to_string();
-        response.deleted_at = time::OffsetDateTime::now_utc();
+        response.deleted_at = now;
+        response.restorable_until = deletion.purge_after;
         response.final_stats.artifact_count = artifact_count;
         response.final_stats.total_size_bytes = total_size_bytes;
         
@@ -121,4 +122,80 @@ impl DeleteRepositoryUseCase {
 
         Ok(response)
     }
-}
\ No newline at end of file
+}
+
+/// Caso de uso para purgar los repositorios borrados cuyo plazo de retención ha vencido.
+///
+/// Se ejecuta periódicamente con `spawn_schedule`. Elimina los artefactos antes
+/// que el repositorio: si el borrado de artefactos falla, el repositorio sigue
+/// marcado como borrado y la siguiente ejecución lo reintenta, en lugar de
+/// dejar artefactos huérfanos sin repositorio que los referencie.
+pub struct PurgeDeletedRepositoriesUseCase {
+    pub repository_deleter_port: Arc<dyn RepositoryDeleterPort>,
+    pub artifact_deleter_port: Arc<dyn ArtifactDeleterPort>,
+    pub event_publisher_port: Arc<dyn RepositoryDeleteEventPublisherPort>,
+}
+
+impl PurgeDeletedRepositoriesUseCase {
+    pub fn new(
+        repository_deleter_port: Arc<dyn RepositoryDeleterPort>,
+        artifact_deleter_port: Arc<dyn ArtifactDeleterPort>,
+        event_publisher_port: Arc<dyn RepositoryDeleteEventPublisherPort>,
+    ) -> Self {
+        Self {
+            repository_deleter_port,
+            artifact_deleter_port,
+            event_publisher_port,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, now: OffsetDateTime) -> RepositoryResult<PurgeDeletedRepositoriesResponse> {
+        let mut response = PurgeDeletedRepositoriesResponse::default();
+
+        for repository in self.repository_deleter_port.list_deleted_repositories().await? {
+            let Some(deletion) = &repository.deletion else { continue };
+            if deletion.is_restorable(now) {
+                continue;
+            }
+
+            let artifacts_deleted = self.artifact_deleter_port.delete_repository_artifacts(&repository.hrn).await?;
+
+            match self.repository_deleter_port.delete_repository(&repository.hrn).await {
+                Ok(()) => {},
+                Err(RepositoryError::RepositoryNotFound(_)) => {
+                    warn!("Repository {} was purged concurrently, skipping", repository.hrn.as_str());
+                    continue;
+                },
+                Err(e) => return Err(e),
+            }
+            info!("Purged repository {} and {} artifacts", repository.hrn.as_str(), artifacts_deleted);
+
+            self.event_publisher_port.publish_repository_purged(&repository.hrn, artifacts_deleted).await?;
+
+            response.purged_repositories.push(repository.hrn.as_str().to_string());
+            response.artifacts_deleted += artifacts_deleted;
+        }
+
+        Ok(response)
+    }
+
+    /// Lanza una tarea que ejecuta la purga cada `interval`.
+    ///
+    /// Un fallo en una ejecución se registra y no detiene la planificación: los
+    /// repositorios pendientes se reintentan en la siguiente.
+    pub fn spawn_schedule(self: Arc<Self>, interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match self.execute(OffsetDateTime::now_utc()).await {
+                    Ok(response) => info!("Purge run removed {} repositories and {} artifacts",
+                                          response.purged_repositories.len(), response.artifacts_deleted),
+                    Err(e) => error!("Purge of deleted repositories failed: {}", e),
+                }
+            }
+        })
+    }
+}
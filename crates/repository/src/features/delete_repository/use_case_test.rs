@@ -5,6 +5,7 @@ use shared::hrn::{OrganizationId, UserId, RepositoryId, Hrn};
 use shared::enums::Ecosystem;
 use crate::domain::repository::{Repository, RepositoryType, DeploymentPolicy, HostedConfig, RepositoryConfig};
 use crate::domain::RepositoryError;
+use crate::domain::quota::RepositoryQuota;
 use super::dto::DeleteRepositoryCommand;
 use super::use_case::DeleteRepositoryUseCase;
 use super::di::DeleteRepositoryDIContainer;
@@ -38,6 +39,8 @@ async fn test_delete_repository_success() {
             }
         ),
         storage_backend_hrn: Hrn::new("hrn:hodei:storage:::{}:default").unwrap(),
+        quota: RepositoryQuota::unlimited(),
+        deletion: None,
         lifecycle: shared::lifecycle::Lifecycle::new(Hrn::new("hrn:hodei:iam::system:user/system").unwrap()),
     };
 
@@ -61,4 +64,99 @@ async fn test_delete_repository_success() {
 }
 
 // Add other tests similarly, using the DI container to get the use case
-// and mocking the necessary ports.
\ No newline at end of file
+// and mocking the necessary ports.
+#[tokio::test]
+async fn test_purge_only_removes_repositories_past_retention() {
+    use std::sync::Arc;
+    use time::{Duration, OffsetDateTime};
+    use crate::domain::repository::{RepositoryDeletion, DEFAULT_DELETION_RETENTION};
+    use crate::features::create_repository::ports::RepositoryCreatorPort;
+    use super::di::test_adapter::*;
+
+    // Arrange
+    let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
+    let container = DeleteRepositoryDIContainer::new(
+        db.clone(),
+        Arc::new(MockRepositoryDeleteAuthorizationPort::new()),
+        Arc::new(MockArtifactDeleterPort::new()),
+        Arc::new(MockRepositoryDeleteEventPublisherPort::new()),
+        DEFAULT_DELETION_RETENTION,
+    );
+    let adapter = MongoDbRepositoryAdapter::new(db);
+    let organization_id = OrganizationId::new("test-org").unwrap();
+    let now = OffsetDateTime::now_utc();
+
+    let mut ids = Vec::new();
+    for (name, deleted_ago) in [("expired-repo", Duration::days(31)), ("recent-repo", Duration::days(1))] {
+        let repository_id = RepositoryId::new(&organization_id.to_string(), name).unwrap();
+        adapter.create_repository(&Repository {
+            hrn: repository_id.clone(),
+            organization_hrn: organization_id.clone(),
+            name: name.to_string(),
+            region: "us-east-1".to_string(),
+            repo_type: RepositoryType::Hosted,
+            format: Ecosystem::Maven,
+            config: RepositoryConfig::Hosted(HostedConfig { deployment_policy: DeploymentPolicy::AllowRedeploy }),
+            storage_backend_hrn: Hrn::new("hrn:hodei:storage:::{}:default").unwrap(),
+            quota: RepositoryQuota::unlimited(),
+            deletion: Some(RepositoryDeletion::new(UserId::new_system_user(), now - deleted_ago, DEFAULT_DELETION_RETENTION)),
+            lifecycle: shared::lifecycle::Lifecycle::new(Hrn::new("hrn:hodei:iam::system:user/system").unwrap()),
+        }).await.unwrap();
+        ids.push(repository_id);
+    }
+
+    // Act
+    let result = container.purge_use_case.execute(now).await.unwrap();
+
+    // Assert
+    assert_eq!(result.purged_repositories, vec![ids[0].to_string()]);
+    let remaining = adapter.list_deleted_repositories().await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].name, "recent-repo");
+}
+
+#[tokio::test]
+async fn test_purge_keeps_repository_when_artifact_deletion_fails() {
+    use time::{Duration, OffsetDateTime};
+    use crate::domain::repository::{RepositoryDeletion, DEFAULT_DELETION_RETENTION};
+    use crate::features::create_repository::ports::RepositoryCreatorPort;
+    use super::di::test_adapter::*;
+
+    // Arrange
+    let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
+    let artifact_deleter = Arc::new(MockArtifactDeleterPort::new());
+    *artifact_deleter.should_fail.lock().unwrap() = true;
+    let container = DeleteRepositoryDIContainer::new(
+        db.clone(),
+        Arc::new(MockRepositoryDeleteAuthorizationPort::new()),
+        artifact_deleter,
+        Arc::new(MockRepositoryDeleteEventPublisherPort::new()),
+        DEFAULT_DELETION_RETENTION,
+    );
+    let adapter = MongoDbRepositoryAdapter::new(db);
+    let organization_id = OrganizationId::new("test-org").unwrap();
+    let repository_id = RepositoryId::new(&organization_id.to_string(), "expired-repo").unwrap();
+    let now = OffsetDateTime::now_utc();
+    adapter.create_repository(&Repository {
+        hrn: repository_id.clone(),
+        organization_hrn: organization_id.clone(),
+        name: "expired-repo".to_string(),
+        region: "us-east-1".to_string(),
+        repo_type: RepositoryType::Hosted,
+        format: Ecosystem::Maven,
+        config: RepositoryConfig::Hosted(HostedConfig { deployment_policy: DeploymentPolicy::AllowRedeploy }),
+        storage_backend_hrn: Hrn::new("hrn:hodei:storage:::{}:default").unwrap(),
+        quota: RepositoryQuota::unlimited(),
+        deletion: Some(RepositoryDeletion::new(UserId::new_system_user(), now - Duration::days(31), DEFAULT_DELETION_RETENTION)),
+        lifecycle: shared::lifecycle::Lifecycle::new(Hrn::new("hrn:hodei:iam::system:user/system").unwrap()),
+    }).await.unwrap();
+
+    // Act
+    let result = container.purge_use_case.execute(now).await;
+
+    // Assert: el repositorio sigue marcado como borrado para reintentar la purga
+    assert!(matches!(result, Err(RepositoryError::DatabaseError(_))));
+    let remaining = adapter.list_deleted_repositories().await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].hrn.to_string(), repository_id.to_string());
+}
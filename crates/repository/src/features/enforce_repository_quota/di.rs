@@ -0,0 +1,92 @@
+// crates/repository/src/features/enforce_repository_quota/di.rs
+
+use std::sync::Arc;
+use mongodb::Database;
+
+use crate::infrastructure::mongodb_adapter::MongoDbRepositoryAdapter;
+use super::ports::{RepositoryQuotaReaderPort, RepositoryUsagePort};
+use super::use_case::EnforceRepositoryQuotaUseCase;
+
+/// Contenedor de inyección de dependencias para la feature enforce_repository_quota
+pub struct EnforceRepositoryQuotaDIContainer {
+    pub use_case: Arc<EnforceRepositoryQuotaUseCase>,
+}
+
+impl EnforceRepositoryQuotaDIContainer {
+    pub fn new(
+        quota_reader_port: Arc<dyn RepositoryQuotaReaderPort>,
+        usage_port: Arc<dyn RepositoryUsagePort>,
+    ) -> Self {
+        let use_case = Arc::new(EnforceRepositoryQuotaUseCase::new(
+            quota_reader_port,
+            usage_port,
+        ));
+        
+        Self { use_case }
+    }
+
+    pub fn for_production(db: Database) -> Self {
+        let mongo_adapter = Arc::new(MongoDbRepositoryAdapter::new(db));
+
+        Self::new(
+            mongo_adapter.clone(),
+            mongo_adapter,
+        )
+    }
+
+    #[cfg(test)]
+    pub fn for_testing() -> (Self, Arc<test_adapter::MockRepositoryQuotaReaderPort>, Arc<test_adapter::MockRepositoryUsagePort>) {
+        let quota_reader_port = Arc::new(test_adapter::MockRepositoryQuotaReaderPort::new());
+        let usage_port = Arc::new(test_adapter::MockRepositoryUsagePort::new());
+
+        (
+            Self::new(quota_reader_port.clone(), usage_port.clone()),
+            quota_reader_port,
+            usage_port,
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod test_adapter {
+    use std::sync::Mutex;
+    use async_trait::async_trait;
+    use shared::hrn::RepositoryId;
+    use crate::domain::RepositoryResult;
+    use crate::domain::quota::{RepositoryQuota, RepositoryUsage};
+    use super::super::ports::*;
+
+    pub struct MockRepositoryQuotaReaderPort {
+        pub quota: Mutex<Option<RepositoryQuota>>,
+    }
+
+    impl MockRepositoryQuotaReaderPort {
+        pub fn new() -> Self {
+            Self { quota: Mutex::new(Some(RepositoryQuota::unlimited())) }
+        }
+    }
+
+    #[async_trait]
+    impl RepositoryQuotaReaderPort for MockRepositoryQuotaReaderPort {
+        async fn get_repository_quota(&self, _repository_id: &RepositoryId) -> RepositoryResult<Option<RepositoryQuota>> {
+            Ok(*self.quota.lock().unwrap())
+        }
+    }
+
+    pub struct MockRepositoryUsagePort {
+        pub usage: Mutex<RepositoryUsage>,
+    }
+
+    impl MockRepositoryUsagePort {
+        pub fn new() -> Self {
+            Self { usage: Mutex::new(RepositoryUsage::default()) }
+        }
+    }
+
+    #[async_trait]
+    impl RepositoryUsagePort for MockRepositoryUsagePort {
+        async fn get_repository_usage(&self, _repository_id: &RepositoryId) -> RepositoryResult<RepositoryUsage> {
+            Ok(*self.usage.lock().unwrap())
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// crates/repository/src/features/enforce_repository_quota/dto.rs
+
+use serde::{Deserialize, Serialize};
+
+/// Comando para reservar cuota antes de subir un artefacto
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReserveQuotaCommand {
+    /// HRN del repositorio destino
+    pub repository_hrn: String,
+    
+    /// Tamaño del artefacto que se va a subir, en bytes
+    pub size_bytes: u64,
+}
+
+/// Reserva de cuota para una subida en curso.
+///
+/// Debe cerrarse con `complete` o `cancel`; mientras tanto cuenta como uso del
+/// repositorio.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaReservation {
+    /// Identificador de la reserva
+    pub id: u64,
+    
+    /// HRN del repositorio destino
+    pub repository_hrn: String,
+    
+    /// Bytes reservados
+    pub size_bytes: u64,
+}
@@ -0,0 +1,24 @@
+// crates/repository/src/features/enforce_repository_quota/mod.rs
+
+//! Aplicación de la cuota de un repositorio antes de aceptar una subida.
+//!
+//! El flujo de subida reserva el tamaño del artefacto con `reserve` antes de
+//! escribir nada y, al terminar, confirma la reserva con `complete` (artefacto
+//! persistido) o la libera con `cancel` (subida fallida). Las reservas vivas
+//! cuentan como uso, así que dos subidas concurrentes no pueden superar juntas
+//! la cuota.
+
+pub mod dto;
+pub mod ports;
+pub mod use_case;
+
+pub mod di;
+
+// Tests unitarios
+#[cfg(test)]
+mod use_case_test;
+
+// Public exports
+pub use dto::{ReserveQuotaCommand, QuotaReservation};
+pub use use_case::EnforceRepositoryQuotaUseCase;
+pub use di::EnforceRepositoryQuotaDIContainer;
@@ -0,0 +1,20 @@
+// crates/repository/src/features/enforce_repository_quota/ports.rs
+
+use async_trait::async_trait;
+use shared::hrn::RepositoryId;
+use crate::domain::RepositoryResult;
+use crate::domain::quota::{RepositoryQuota, RepositoryUsage};
+
+/// Puerto para leer la cuota configurada de un repositorio
+#[async_trait]
+pub trait RepositoryQuotaReaderPort: Send + Sync {
+    /// Obtiene la cuota del repositorio, o `None` si el repositorio no existe
+    async fn get_repository_quota(&self, repository_id: &RepositoryId) -> RepositoryResult<Option<RepositoryQuota>>;
+}
+
+/// Puerto para obtener el uso ya persistido de un repositorio
+#[async_trait]
+pub trait RepositoryUsagePort: Send + Sync {
+    /// Obtiene el tamaño total y el número de artefactos almacenados
+    async fn get_repository_usage(&self, repository_id: &RepositoryId) -> RepositoryResult<RepositoryUsage>;
+}
@@ -0,0 +1,122 @@
+// crates/repository/src/features/enforce_repository_quota/use_case.rs
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use shared::hrn::RepositoryId;
+use tracing::{info, warn, instrument, debug};
+
+use crate::domain::{RepositoryResult, RepositoryError};
+use crate::domain::quota::RepositoryUsage;
+use super::dto::{ReserveQuotaCommand, QuotaReservation};
+use super::ports::{RepositoryQuotaReaderPort, RepositoryUsagePort};
+
+/// Subidas en curso y subidas confirmadas de un repositorio.
+#[derive(Debug, Default)]
+struct RepositoryLedger {
+    /// Reservas vivas: id -> bytes
+    in_flight: HashMap<u64, u64>,
+    /// Total acumulado de reservas confirmadas. Solo crece; se usa para saber
+    /// qué subidas se confirmaron mientras se leía el uso persistido.
+    completed: RepositoryUsage,
+}
+
+impl RepositoryLedger {
+    fn in_flight_usage(&self) -> RepositoryUsage {
+        RepositoryUsage {
+            size_bytes: self.in_flight.values().sum(),
+            artifact_count: self.in_flight.len() as u64,
+        }
+    }
+}
+
+/// Caso de uso para aplicar la cuota de un repositorio a las subidas.
+///
+/// Las reservas se llevan en memoria, por lo que solo protegen frente a las
+/// subidas concurrentes que pasan por esta misma instancia.
+pub struct EnforceRepositoryQuotaUseCase {
+    pub quota_reader_port: Arc<dyn RepositoryQuotaReaderPort>,
+    pub usage_port: Arc<dyn RepositoryUsagePort>,
+    ledgers: Mutex<HashMap<String, RepositoryLedger>>,
+    next_reservation_id: AtomicU64,
+}
+
+impl EnforceRepositoryQuotaUseCase {
+    pub fn new(
+        quota_reader_port: Arc<dyn RepositoryQuotaReaderPort>,
+        usage_port: Arc<dyn RepositoryUsagePort>,
+    ) -> Self {
+        Self {
+            quota_reader_port,
+            usage_port,
+            ledgers: Mutex::new(HashMap::new()),
+            next_reservation_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Reserva cuota para una subida. Falla con `QuotaExceeded` si el uso
+    /// persistido, más las subidas en curso, más esta subida supera la cuota.
+    #[instrument(skip(self, command))]
+    pub async fn reserve(&self, command: ReserveQuotaCommand) -> RepositoryResult<QuotaReservation> {
+        let repository_id: RepositoryId = command.repository_hrn.parse()?;
+        let key = repository_id.to_string();
+
+        let quota = self.quota_reader_port.get_repository_quota(&repository_id).await?
+            .ok_or_else(|| RepositoryError::RepositoryNotFound(key.clone()))?;
+
+        // Las confirmaciones que lleguen mientras se lee el uso pueden estar o no
+        // incluidas en él; se suman igualmente para no quedarse nunca por debajo.
+        let completed_before = self.completed_usage(&key);
+        let persisted = self.usage_port.get_repository_usage(&repository_id).await?;
+
+        let mut ledgers = self.ledgers.lock().unwrap();
+        let ledger = ledgers.entry(key.clone()).or_default();
+        let completed_meanwhile = ledger.completed.saturating_sub(&completed_before);
+        let usage = persisted.add(&completed_meanwhile).add(&ledger.in_flight_usage());
+
+        debug!("Usage for repository {}: {} bytes, {} artifacts (including in-flight uploads)",
+               key, usage.size_bytes, usage.artifact_count);
+
+        if let Err(e) = quota.check(&usage, command.size_bytes) {
+            warn!("Rejected upload of {} bytes to repository {}: {}", command.size_bytes, key, e);
+            return Err(e);
+        }
+
+        let id = self.next_reservation_id.fetch_add(1, Ordering::Relaxed);
+        ledger.in_flight.insert(id, command.size_bytes);
+
+        info!("Reserved {} bytes in repository {} (reservation {})", command.size_bytes, key, id);
+
+        Ok(QuotaReservation {
+            id,
+            repository_hrn: key,
+            size_bytes: command.size_bytes,
+        })
+    }
+
+    /// Confirma una reserva una vez el artefacto está persistido y ya cuenta
+    /// en el uso del repositorio.
+    pub fn complete(&self, reservation: &QuotaReservation) {
+        let mut ledgers = self.ledgers.lock().unwrap();
+        if let Some(ledger) = ledgers.get_mut(&reservation.repository_hrn) {
+            if let Some(size_bytes) = ledger.in_flight.remove(&reservation.id) {
+                ledger.completed = ledger.completed.add(&RepositoryUsage { size_bytes, artifact_count: 1 });
+            }
+        }
+    }
+
+    /// Libera una reserva cuya subida no llegó a persistirse.
+    pub fn cancel(&self, reservation: &QuotaReservation) {
+        let mut ledgers = self.ledgers.lock().unwrap();
+        if let Some(ledger) = ledgers.get_mut(&reservation.repository_hrn) {
+            ledger.in_flight.remove(&reservation.id);
+        }
+    }
+
+    fn completed_usage(&self, key: &str) -> RepositoryUsage {
+        self.ledgers.lock().unwrap()
+            .get(key)
+            .map(|ledger| ledger.completed)
+            .unwrap_or_default()
+    }
+}
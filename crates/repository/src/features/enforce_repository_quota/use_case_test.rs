@@ -0,0 +1,158 @@
+// crates/repository/src/features/enforce_repository_quota/use_case_test.rs
+
+use shared::hrn::{OrganizationId, RepositoryId};
+use crate::domain::RepositoryError;
+use crate::domain::quota::{RepositoryQuota, RepositoryUsage};
+use crate::domain::repository::RepositoryType;
+use super::dto::ReserveQuotaCommand;
+use super::di::EnforceRepositoryQuotaDIContainer;
+use tokio;
+
+fn reserve_command(size_bytes: u64) -> ReserveQuotaCommand {
+    let organization_id = OrganizationId::new("test-org").unwrap();
+    let repository_id = RepositoryId::new(&organization_id.to_string(), "test-repo").unwrap();
+    ReserveQuotaCommand {
+        repository_hrn: repository_id.to_string(),
+        size_bytes,
+    }
+}
+
+#[tokio::test]
+async fn test_zero_quota_means_unlimited() {
+    // Arrange
+    let (container, quota_port, usage_port) = EnforceRepositoryQuotaDIContainer::for_testing();
+    *quota_port.quota.lock().unwrap() = Some(RepositoryQuota { max_size_bytes: 0, max_artifact_count: 0 });
+    *usage_port.usage.lock().unwrap() = RepositoryUsage { size_bytes: u64::MAX / 2, artifact_count: 1_000_000 };
+
+    // Act
+    let result = container.use_case.reserve(reserve_command(u64::MAX / 4)).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_reserve_rejects_upload_over_size_quota() {
+    // Arrange
+    let (container, quota_port, usage_port) = EnforceRepositoryQuotaDIContainer::for_testing();
+    *quota_port.quota.lock().unwrap() = Some(RepositoryQuota { max_size_bytes: 100, max_artifact_count: 0 });
+    *usage_port.usage.lock().unwrap() = RepositoryUsage { size_bytes: 90, artifact_count: 3 };
+
+    // Act & Assert
+    assert!(matches!(
+        container.use_case.reserve(reserve_command(11)).await,
+        Err(RepositoryError::QuotaExceeded(_))
+    ));
+    assert!(container.use_case.reserve(reserve_command(10)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_reserve_rejects_upload_over_artifact_count_quota() {
+    // Arrange
+    let (container, quota_port, usage_port) = EnforceRepositoryQuotaDIContainer::for_testing();
+    *quota_port.quota.lock().unwrap() = Some(RepositoryQuota { max_size_bytes: 0, max_artifact_count: 3 });
+    *usage_port.usage.lock().unwrap() = RepositoryUsage { size_bytes: 0, artifact_count: 3 };
+
+    // Act
+    let result = container.use_case.reserve(reserve_command(1)).await;
+
+    // Assert
+    assert!(matches!(result, Err(RepositoryError::QuotaExceeded(_))));
+}
+
+#[tokio::test]
+async fn test_in_flight_uploads_count_towards_quota() {
+    // Arrange
+    let (container, quota_port, _usage_port) = EnforceRepositoryQuotaDIContainer::for_testing();
+    *quota_port.quota.lock().unwrap() = Some(RepositoryQuota { max_size_bytes: 100, max_artifact_count: 0 });
+    let use_case = container.use_case;
+
+    // Act
+    let first = use_case.reserve(reserve_command(60)).await.unwrap();
+    let second = use_case.reserve(reserve_command(60)).await;
+
+    // Assert: la segunda subida no cabe mientras la primera sigue en curso
+    assert!(matches!(second, Err(RepositoryError::QuotaExceeded(_))));
+
+    // Si la primera falla, su reserva se libera
+    use_case.cancel(&first);
+    assert!(use_case.reserve(reserve_command(60)).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_completed_upload_is_not_counted_twice() {
+    // Arrange
+    let (container, quota_port, usage_port) = EnforceRepositoryQuotaDIContainer::for_testing();
+    *quota_port.quota.lock().unwrap() = Some(RepositoryQuota { max_size_bytes: 100, max_artifact_count: 0 });
+    let use_case = container.use_case;
+
+    // Act: la subida se persiste y pasa a formar parte del uso del repositorio
+    let reservation = use_case.reserve(reserve_command(60)).await.unwrap();
+    *usage_port.usage.lock().unwrap() = RepositoryUsage { size_bytes: 60, artifact_count: 1 };
+    use_case.complete(&reservation);
+
+    // Assert
+    assert!(use_case.reserve(reserve_command(40)).await.is_ok());
+    assert!(matches!(
+        use_case.reserve(reserve_command(1)).await,
+        Err(RepositoryError::QuotaExceeded(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_reserve_fails_for_unknown_repository() {
+    // Arrange
+    let (container, quota_port, _usage_port) = EnforceRepositoryQuotaDIContainer::for_testing();
+    *quota_port.quota.lock().unwrap() = None;
+
+    // Act
+    let result = container.use_case.reserve(reserve_command(1)).await;
+
+    // Assert
+    assert!(matches!(result, Err(RepositoryError::RepositoryNotFound(_))));
+}
+
+#[test]
+fn test_virtual_repositories_cannot_have_a_quota() {
+    let quota = RepositoryQuota { max_size_bytes: 1024, max_artifact_count: 0 };
+
+    assert!(quota.validate_for(RepositoryType::Hosted).is_ok());
+    assert!(matches!(
+        quota.validate_for(RepositoryType::Virtual),
+        Err(RepositoryError::InvalidConfiguration(_))
+    ));
+    assert!(RepositoryQuota::unlimited().validate_for(RepositoryType::Virtual).is_ok());
+}
+
+#[tokio::test]
+async fn test_mongo_usage_sums_artifact_sizes_of_package_versions() {
+    use mongodb::bson::{doc, Document};
+    use crate::features::enforce_repository_quota::ports::RepositoryUsagePort;
+    use crate::infrastructure::mongodb_adapter::MongoDbRepositoryAdapter;
+
+    // Arrange: versiones de paquete con la forma que escribe la subida de artefactos
+    let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
+    let adapter = MongoDbRepositoryAdapter::new(db.clone());
+    let repository_id: RepositoryId = reserve_command(0).repository_hrn.parse().unwrap();
+    let other_repository = RepositoryId::new(&OrganizationId::new("test-org").unwrap().to_string(), "other-repo").unwrap();
+    let package_version = |repository_id: &RepositoryId, sizes: &[i64]| doc! {
+        "hrn": format!("{}/package-version/{}", repository_id, sizes.len()),
+        "repository_hrn": repository_id.to_string(),
+        "artifacts": sizes.iter().map(|size| doc! {
+            "physical_artifact_hrn": format!("hrn:hodei:artifact::physical/{}", size),
+            "size_in_bytes": *size,
+            "content_hash": { "algorithm": "Sha256", "value": format!("{:064}", size) },
+        }).collect::<Vec<Document>>(),
+    };
+    db.collection::<Document>("package_versions").insert_many(vec![
+        package_version(&repository_id, &[100]),
+        package_version(&repository_id, &[20, 30]),
+        package_version(&other_repository, &[1_000]),
+    ], None).await.unwrap();
+
+    // Act
+    let usage = adapter.get_repository_usage(&repository_id).await.unwrap();
+
+    // Assert
+    assert_eq!(usage, RepositoryUsage { size_bytes: 150, artifact_count: 3 });
+}
@@ -5,6 +5,7 @@ use shared::hrn::{OrganizationId, UserId, RepositoryId, Hrn};
 use shared::enums::Ecosystem;
 use crate::domain::repository::{Repository, RepositoryType, DeploymentPolicy, HostedConfig, RepositoryConfig};
 use crate::domain::RepositoryError;
+use crate::domain::quota::RepositoryQuota;
 use super::dto::GetRepositoryQuery;
 use super::use_case::GetRepositoryUseCase;
 use super::di::GetRepositoryDIContainer;
@@ -38,6 +39,8 @@ async fn test_get_repository_success() {
             }
         ),
         storage_backend_hrn: Hrn::new("hrn:hodei:storage:::{}:default").unwrap(),
+        quota: RepositoryQuota::unlimited(),
+        deletion: None,
         lifecycle: shared::lifecycle::Lifecycle::new(Hrn::new("hrn:hodei:iam::system:user/system").unwrap()),
     };
 
@@ -3,4 +3,6 @@
 pub mod create_repository;
 pub mod get_repository;
 pub mod update_repository;
-pub mod delete_repository;
\ No newline at end of file
+pub mod delete_repository;
+pub mod restore_repository;
+pub mod enforce_repository_quota;
\ No newline at end of file
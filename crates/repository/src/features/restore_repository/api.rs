@@ -0,0 +1,116 @@
+// crates/repository/src/features/restore_repository/api.rs
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, error, instrument};
+
+use shared::hrn::UserId;
+
+use super::dto::{RestoreRepositoryCommand, RestoreRepositoryResponse};
+use super::use_case::RestoreRepositoryUseCase;
+
+/// Response DTO para errores del endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreRepositoryErrorResponse {
+    pub error: String,
+    pub message: String,
+    pub details: Option<String>,
+}
+
+impl RestoreRepositoryErrorResponse {
+    pub fn new(error: String, message: String, details: Option<String>) -> Self {
+        Self { error, message, details }
+    }
+
+    pub fn not_found(message: String) -> Self {
+        Self::new("NotFound".to_string(), message, None)
+    }
+
+    pub fn unauthorized(message: String) -> Self {
+        Self::new("Unauthorized".to_string(), message, None)
+    }
+
+    pub fn gone(message: String) -> Self {
+        Self::new("Gone".to_string(), message, None)
+    }
+
+    pub fn internal_error(message: String, details: Option<String>) -> Self {
+        Self::new("InternalError".to_string(), message, details)
+    }
+}
+
+/// Punto de entrada de la API para restaurar repositorios borrados
+pub struct RestoreRepositoryEndpoint {
+    pub use_case: Arc<RestoreRepositoryUseCase>,
+}
+
+impl RestoreRepositoryEndpoint {
+    pub fn new(use_case: Arc<RestoreRepositoryUseCase>) -> Self {
+        Self { use_case }
+    }
+
+    #[instrument(skip(self, repository_hrn, user_id))]
+    pub async fn restore_repository(
+        &self,
+        repository_hrn: String,
+        user_id: UserId,
+    ) -> Result<RestoreRepositoryResponse, RestoreRepositoryErrorResponse> {
+        info!("Restoring repository with HRN: {}", repository_hrn);
+
+        let command = RestoreRepositoryCommand { repository_hrn };
+
+        match self.use_case.execute(command, user_id).await {
+            Ok(response) => {
+                info!("Successfully restored repository: {}", response.hrn);
+                Ok(response)
+            },
+            Err(error) => {
+                error!("Failed to restore repository: {}", error);
+                Err(Self::map_error_to_response(error))
+            }
+        }
+    }
+
+    fn map_error_to_response(error: crate::domain::RepositoryError) -> RestoreRepositoryErrorResponse {
+        use crate::domain::RepositoryError;
+
+        match error {
+            RepositoryError::RepositoryNotFound(repo_id) => {
+                RestoreRepositoryErrorResponse::not_found(format!("No deleted repository '{}' to restore", repo_id))
+            },
+            RepositoryError::Unauthorized(message) => {
+                RestoreRepositoryErrorResponse::unauthorized(message)
+            },
+            RepositoryError::RestoreWindowExpired(message) => {
+                RestoreRepositoryErrorResponse::gone(message)
+            },
+            _ => RestoreRepositoryErrorResponse::internal_error("An unexpected error occurred".to_string(), Some(error.to_string())),
+        }
+    }
+}
+
+/// Handler de Axum para `POST /repositories/{repository_hrn}/restore`
+pub async fn restore_repository_handler(
+    Extension(endpoint): Extension<Arc<RestoreRepositoryEndpoint>>,
+    Extension(user_id): Extension<UserId>,
+    Path(repository_hrn): Path<String>,
+) -> impl IntoResponse {
+    match endpoint.restore_repository(repository_hrn, user_id).await {
+        Ok(response) => (StatusCode::OK, Json(response)).into_response(),
+        Err(error_response) => {
+            let status_code = match error_response.error.as_str() {
+                "NotFound" => StatusCode::NOT_FOUND,
+                "Unauthorized" => StatusCode::FORBIDDEN,
+                "Gone" => StatusCode::GONE,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (status_code, Json(error_response)).into_response()
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// crates/repository/src/features/restore_repository/di.rs
+
+use std::sync::Arc;
+use mongodb::Database;
+
+use crate::infrastructure::mongodb_adapter::MongoDbRepositoryAdapter;
+use super::ports::{
+    RepositoryRestorerPort, RepositoryRestoreAuthorizationPort, RepositoryRestoreEventPublisherPort
+};
+use super::use_case::RestoreRepositoryUseCase;
+use super::api::RestoreRepositoryEndpoint;
+
+// Mock implementations for ports that are not yet fully implemented
+
+pub struct RepositoryRestoreAuthorizationAdapter;
+
+impl RepositoryRestoreAuthorizationAdapter {
+    pub fn new() -> Self { Self }
+}
+
+#[async_trait::async_trait]
+impl RepositoryRestoreAuthorizationPort for RepositoryRestoreAuthorizationAdapter {
+    async fn can_restore_repository(&self, _user_id: &shared::hrn::UserId, _repository_id: &shared::hrn::RepositoryId) -> crate::domain::RepositoryResult<bool> {
+        Ok(true) // Default to authorized for now
+    }
+}
+
+pub struct RepositoryRestoreEventPublisherAdapter;
+
+impl RepositoryRestoreEventPublisherAdapter {
+    pub fn new() -> Self { Self }
+}
+
+#[async_trait::async_trait]
+impl RepositoryRestoreEventPublisherPort for RepositoryRestoreEventPublisherAdapter {
+    async fn publish_repository_restored(&self, _repository_id: &shared::hrn::RepositoryId, _restored_by: &shared::hrn::UserId) -> crate::domain::RepositoryResult<()> {
+        Ok(())
+    }
+}
+
+
+/// Contenedor de inyección de dependencias para la feature restore_repository
+pub struct RestoreRepositoryDIContainer {
+    pub endpoint: RestoreRepositoryEndpoint,
+}
+
+impl RestoreRepositoryDIContainer {
+    pub fn new(
+        db: Database,
+        authorization_port: Arc<dyn RepositoryRestoreAuthorizationPort>,
+        event_publisher_port: Arc<dyn RepositoryRestoreEventPublisherPort>,
+    ) -> Self {
+        let mongo_adapter = Arc::new(MongoDbRepositoryAdapter::new(db));
+        
+        let use_case = Arc::new(RestoreRepositoryUseCase::new(
+            mongo_adapter,
+            authorization_port,
+            event_publisher_port,
+        ));
+        
+        let endpoint = RestoreRepositoryEndpoint::new(use_case);
+        
+        Self { endpoint }
+    }
+
+    pub fn for_production(db: Database) -> Self {
+        let authorization_adapter: Arc<dyn RepositoryRestoreAuthorizationPort> = 
+            Arc::new(RepositoryRestoreAuthorizationAdapter::new());
+        
+        let event_publisher_adapter: Arc<dyn RepositoryRestoreEventPublisherPort> = 
+            Arc::new(RepositoryRestoreEventPublisherAdapter::new());
+
+        Self::new(
+            db,
+            authorization_adapter,
+            event_publisher_adapter,
+        )
+    }
+
+    /// Constructor para testing sobre una base de datos ya preparada
+    #[cfg(test)]
+    pub fn for_testing(db: Database) -> Self {
+        Self::new(
+            db,
+            Arc::new(RepositoryRestoreAuthorizationAdapter::new()),
+            Arc::new(RepositoryRestoreEventPublisherAdapter::new()),
+        )
+    }
+}
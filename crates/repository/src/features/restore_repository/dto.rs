@@ -0,0 +1,32 @@
+// crates/repository/src/features/restore_repository/dto.rs
+
+use serde::{Deserialize, Serialize};
+use shared::enums::Ecosystem;
+use crate::domain::repository::RepositoryType;
+
+/// Comando para restaurar un repositorio borrado
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreRepositoryCommand {
+    /// HRN del repositorio a restaurar
+    pub repository_hrn: String,
+}
+
+/// Respuesta de restauración de repositorio
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreRepositoryResponse {
+    /// HRN del repositorio restaurado
+    pub hrn: String,
+    
+    /// Nombre del repositorio
+    pub name: String,
+    
+    /// Tipo de repositorio
+    pub repo_type: RepositoryType,
+    
+    /// Ecosistema de paquetes
+    pub format: Ecosystem,
+    
+    /// Información de auditoría
+    pub restored_by: String,
+    pub restored_at: time::OffsetDateTime,
+}
@@ -0,0 +1,17 @@
+// crates/repository/src/features/restore_repository/mod.rs
+
+pub mod dto;
+pub mod ports;
+pub mod use_case;
+
+pub mod api;
+pub mod di;
+
+// Tests unitarios
+#[cfg(test)]
+mod use_case_test;
+
+// Public exports
+pub use dto::{RestoreRepositoryCommand, RestoreRepositoryResponse};
+pub use api::RestoreRepositoryEndpoint;
+pub use di::RestoreRepositoryDIContainer;
@@ -0,0 +1,30 @@
+// crates/repository/src/features/restore_repository/ports.rs
+
+use async_trait::async_trait;
+use shared::hrn::{RepositoryId, UserId};
+use crate::domain::{RepositoryResult, repository::Repository};
+
+/// Puerto para restaurar repositorios borrados
+#[async_trait]
+pub trait RepositoryRestorerPort: Send + Sync {
+    /// Obtiene un repositorio marcado como borrado
+    async fn get_deleted_repository(&self, repository_id: &RepositoryId) -> RepositoryResult<Option<Repository>>;
+    
+    /// Quita la marca de borrado de un repositorio.
+    /// Devuelve `RepositoryNotFound` si ya no está marcado (p. ej. se purgó).
+    async fn restore_repository(&self, repository_id: &RepositoryId) -> RepositoryResult<()>;
+}
+
+/// Puerto para verificar autorización de restauración
+#[async_trait]
+pub trait RepositoryRestoreAuthorizationPort: Send + Sync {
+    /// Verifica si un usuario tiene permiso para restaurar un repositorio
+    async fn can_restore_repository(&self, user_id: &UserId, repository_id: &RepositoryId) -> RepositoryResult<bool>;
+}
+
+/// Puerto para publicar eventos de restauración
+#[async_trait]
+pub trait RepositoryRestoreEventPublisherPort: Send + Sync {
+    /// Publica un evento indicando que un repositorio fue restaurado
+    async fn publish_repository_restored(&self, repository_id: &RepositoryId, restored_by: &UserId) -> RepositoryResult<()>;
+}
@@ -0,0 +1,82 @@
+// crates/repository/src/features/restore_repository/use_case.rs
+
+use std::sync::Arc;
+use shared::hrn::{RepositoryId, UserId};
+use time::OffsetDateTime;
+use tracing::{info, error, instrument};
+
+use crate::domain::{RepositoryResult, RepositoryError};
+use super::dto::{RestoreRepositoryCommand, RestoreRepositoryResponse};
+use super::ports::{
+    RepositoryRestorerPort, RepositoryRestoreAuthorizationPort, RepositoryRestoreEventPublisherPort
+};
+
+/// Caso de uso para restaurar un repositorio borrado dentro de su plazo de retención
+pub struct RestoreRepositoryUseCase {
+    pub repository_restorer_port: Arc<dyn RepositoryRestorerPort>,
+    pub authorization_port: Arc<dyn RepositoryRestoreAuthorizationPort>,
+    pub event_publisher_port: Arc<dyn RepositoryRestoreEventPublisherPort>,
+}
+
+impl RestoreRepositoryUseCase {
+    pub fn new(
+        repository_restorer_port: Arc<dyn RepositoryRestorerPort>,
+        authorization_port: Arc<dyn RepositoryRestoreAuthorizationPort>,
+        event_publisher_port: Arc<dyn RepositoryRestoreEventPublisherPort>,
+    ) -> Self {
+        Self {
+            repository_restorer_port,
+            authorization_port,
+            event_publisher_port,
+        }
+    }
+
+    #[instrument(skip(self, command, user_id))]
+    pub async fn execute(
+        &self,
+        command: RestoreRepositoryCommand,
+        user_id: UserId,
+    ) -> RepositoryResult<RestoreRepositoryResponse> {
+        info!("Restoring repository with HRN: {}", command.repository_hrn);
+
+        let repository_id: RepositoryId = command.repository_hrn.parse()?;
+
+        if !self.authorization_port.can_restore_repository(&user_id, &repository_id).await? {
+            error!("User {} is not authorized to restore repository {}", user_id, repository_id);
+            return Err(RepositoryError::Unauthorized(
+                format!("You don't have permission to restore repository '{}'", repository_id)
+            ));
+        }
+
+        let repository = self.repository_restorer_port.get_deleted_repository(&repository_id).await?
+            .ok_or_else(|| {
+                error!("No deleted repository found to restore: {}", repository_id);
+                RepositoryError::RepositoryNotFound(repository_id.to_string())
+            })?;
+
+        let now = OffsetDateTime::now_utc();
+        if let Some(deletion) = &repository.deletion {
+            if !deletion.is_restorable(now) {
+                error!("Retention window for repository {} ended at {}", repository_id, deletion.purge_after);
+                return Err(RepositoryError::RestoreWindowExpired(format!(
+                    "repository '{}' could only be restored until {}",
+                    repository.name, deletion.purge_after
+                )));
+            }
+        }
+
+        self.repository_restorer_port.restore_repository(&repository_id).await?;
+        info!("Repository restored successfully: {}", repository_id);
+
+        self.event_publisher_port.publish_repository_restored(&repository_id, &user_id).await?;
+
+        Ok(RestoreRepositoryResponse {
+            hrn: repository_id.to_string(),
+            name: repository.name,
+            repo_type: repository.repo_type,
+            format: repository.format,
+            restored_by: user_id.to_string(),
+            restored_at: now,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+// crates/repository/src/features/restore_repository/use_case_test.rs
+
+use shared::hrn::{OrganizationId, UserId, RepositoryId, Hrn};
+use shared::enums::Ecosystem;
+use time::{Duration, OffsetDateTime};
+use crate::domain::repository::{Repository, RepositoryDeletion, RepositoryType, DeploymentPolicy, HostedConfig, RepositoryConfig};
+use crate::domain::RepositoryError;
+use crate::domain::quota::RepositoryQuota;
+use crate::features::create_repository::ports::RepositoryCreatorPort;
+use crate::features::get_repository::ports::RepositoryReaderPort;
+use crate::infrastructure::mongodb_adapter::MongoDbRepositoryAdapter;
+use super::dto::RestoreRepositoryCommand;
+use super::di::RestoreRepositoryDIContainer;
+use tokio;
+
+fn deleted_repository(repository_id: &RepositoryId, organization_id: OrganizationId, deleted_ago: Duration) -> Repository {
+    Repository {
+        hrn: repository_id.clone(),
+        organization_hrn: organization_id,
+        name: "test-repo".to_string(),
+        region: "us-east-1".to_string(),
+        repo_type: RepositoryType::Hosted,
+        format: Ecosystem::Maven,
+        config: RepositoryConfig::Hosted(
+            HostedConfig {
+                deployment_policy: DeploymentPolicy::AllowSnapshots,
+            }
+        ),
+        storage_backend_hrn: Hrn::new("hrn:hodei:storage:::{}:default").unwrap(),
+        quota: RepositoryQuota::unlimited(),
+        deletion: Some(RepositoryDeletion::new(
+            UserId::new_system_user(),
+            OffsetDateTime::now_utc() - deleted_ago,
+            Duration::days(30),
+        )),
+        lifecycle: shared::lifecycle::Lifecycle::new(Hrn::new("hrn:hodei:iam::system:user/system").unwrap()),
+    }
+}
+
+#[tokio::test]
+async fn test_restore_repository_within_retention_window() {
+    // Arrange
+    let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
+    let use_case = RestoreRepositoryDIContainer::for_testing(db.clone()).endpoint.use_case;
+    let adapter = MongoDbRepositoryAdapter::new(db);
+
+    let organization_id = OrganizationId::new("test-org").unwrap();
+    let repository_id = RepositoryId::new(&organization_id.to_string(), "test-repo").unwrap();
+    adapter.create_repository(&deleted_repository(&repository_id, organization_id, Duration::days(1))).await.unwrap();
+
+    // Un repositorio borrado no es visible por las lecturas normales
+    assert!(adapter.get_repository(&repository_id).await.unwrap().is_none());
+
+    // Act
+    let result = use_case.execute(
+        RestoreRepositoryCommand { repository_hrn: repository_id.to_string() },
+        UserId::new_system_user(),
+    ).await;
+
+    // Assert
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().name, "test-repo");
+    assert!(adapter.get_repository(&repository_id).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_restore_repository_after_retention_window_fails() {
+    // Arrange
+    let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
+    let use_case = RestoreRepositoryDIContainer::for_testing(db.clone()).endpoint.use_case;
+    let adapter = MongoDbRepositoryAdapter::new(db);
+
+    let organization_id = OrganizationId::new("test-org").unwrap();
+    let repository_id = RepositoryId::new(&organization_id.to_string(), "test-repo").unwrap();
+    adapter.create_repository(&deleted_repository(&repository_id, organization_id, Duration::days(31))).await.unwrap();
+
+    // Act
+    let result = use_case.execute(
+        RestoreRepositoryCommand { repository_hrn: repository_id.to_string() },
+        UserId::new_system_user(),
+    ).await;
+
+    // Assert
+    assert!(matches!(result, Err(RepositoryError::RestoreWindowExpired(_))));
+}
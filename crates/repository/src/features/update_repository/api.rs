@@ -33,6 +33,9 @@ pub struct UpdateRepositoryRequest {
     
     /// Nuevos metadatos (opcional)
     pub metadata: Option<std::collections::HashMap<String, String>>,
+    
+    /// Nueva cuota de almacenamiento (opcional; `0` = sin límite)
+    pub quota: Option<crate::domain::quota::RepositoryQuota>,
 }
 
 /// Response DTO para errores del endpoint
@@ -96,6 +99,7 @@ impl UpdateRepositoryEndpoint {
             storage_backend_hrn: request.storage_backend_hrn,
             is_public: request.is_public,
             metadata: request.metadata,
+            quota: request.quota,
         };
 
         // Ejecutar el caso de uso
@@ -124,6 +128,9 @@ impl UpdateRepositoryEndpoint {
             RepositoryError::InvalidRepositoryName(message) => {
                 UpdateRepositoryErrorResponse::invalid_request(format!("Invalid repository name: {}", message))
             },
+            RepositoryError::InvalidConfiguration(message) => {
+                UpdateRepositoryErrorResponse::invalid_request(format!("Invalid configuration: {}", message))
+            },
             _ => UpdateRepositoryErrorResponse::internal_error("An unexpected error occurred".to_string(), Some(error.to_string())),
         }
     }
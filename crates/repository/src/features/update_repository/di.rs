@@ -4,9 +4,13 @@
 use std::sync::Arc;
 use mongodb::Database;
 
+use search::features::index_text_documents::IndexDocumentUseCase;
+
 use crate::infrastructure::mongodb_adapter::MongoDbRepositoryAdapter;
+use crate::infrastructure::search_index_adapter::SearchIndexRepositoryAdapter;
 use super::ports::{
-    RepositoryUpdaterPort, RepositoryUpdateAuthorizationPort, RepositoryUpdateEventPublisherPort
+    RepositoryUpdaterPort, RepositoryUpdateAuthorizationPort, RepositoryUpdateEventPublisherPort,
+    RepositorySearchIndexPort
 };
 use super::use_case::UpdateRepositoryUseCase;
 use super::api::UpdateRepositoryEndpoint;
@@ -50,6 +54,7 @@ impl UpdateRepositoryDIContainer {
         db: Database,
         authorization_port: Arc<dyn RepositoryUpdateAuthorizationPort>,
         event_publisher_port: Arc<dyn RepositoryUpdateEventPublisherPort>,
+        search_index_port: Arc<dyn RepositorySearchIndexPort>,
     ) -> Self {
         let mongo_adapter = Arc::new(MongoDbRepositoryAdapter::new(db));
         
@@ -57,6 +62,7 @@ impl UpdateRepositoryDIContainer {
             mongo_adapter,
             authorization_port,
             event_publisher_port,
+            search_index_port,
         ));
         
         let endpoint = UpdateRepositoryEndpoint::new(use_case);
@@ -64,17 +70,23 @@ impl UpdateRepositoryDIContainer {
         Self { endpoint }
     }
 
-    pub fn for_production(db: Database) -> Self {
+    /// Constructor para producción; los repositorios actualizados se reindexan
+    /// con `index_use_case`, el mismo índice que los artefactos
+    pub fn for_production(db: Database, index_use_case: Arc<IndexDocumentUseCase>) -> Self {
         let authorization_adapter: Arc<dyn RepositoryUpdateAuthorizationPort> = 
             Arc::new(RepositoryUpdateAuthorizationAdapter::new());
         
         let event_publisher_adapter: Arc<dyn RepositoryUpdateEventPublisherPort> = 
             Arc::new(RepositoryUpdateEventPublisherAdapter::new());
 
+        let search_index_adapter: Arc<dyn RepositorySearchIndexPort> =
+            Arc::new(SearchIndexRepositoryAdapter::new(index_use_case));
+
         Self::new(
             db,
             authorization_adapter,
             event_publisher_adapter,
+            search_index_adapter,
         )
     }
 
@@ -83,11 +95,13 @@ impl UpdateRepositoryDIContainer {
         let db = crate::infrastructure::tests::setup_test_database().await.unwrap();
         let authorization_port = Arc::new(test_adapter::MockRepositoryUpdateAuthorizationPort::new());
         let event_publisher_port = Arc::new(test_adapter::MockRepositoryUpdateEventPublisherPort::new());
+        let search_index_port = Arc::new(test_adapter::MockRepositorySearchIndexPort::new());
 
         Self::new(
             db,
             authorization_port,
             event_publisher_port,
+            search_index_port,
         )
     }
 }
@@ -130,4 +144,22 @@ pub mod test_adapter {
             Ok(())
         }
     }
+
+    pub struct MockRepositorySearchIndexPort {
+        pub indexed: Mutex<Vec<String>>,
+    }
+
+    impl MockRepositorySearchIndexPort {
+        pub fn new() -> Self {
+            Self { indexed: Mutex::new(Vec::new()) }
+        }
+    }
+
+    #[async_trait]
+    impl RepositorySearchIndexPort for MockRepositorySearchIndexPort {
+        async fn index_repository(&self, repository: &Repository) -> RepositoryResult<()> {
+            self.indexed.lock().unwrap().push(repository.hrn.to_string());
+            Ok(())
+        }
+    }
 }
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 use shared::enums::Ecosystem;
 use crate::domain::repository::{RepositoryType, DeploymentPolicy, CacheSettings, ProxyAuth, ResolutionOrder};
+use crate::domain::quota::RepositoryQuota;
 
 /// Comando para actualizar un repositorio
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +25,10 @@ pub struct UpdateRepositoryCommand {
     
     /// Nuevos metadatos (opcional)
     pub metadata: Option<std::collections::HashMap<String, String>>,
+    
+    /// Nueva cuota de almacenamiento (opcional; `0` = sin límite)
+    #[serde(default)]
+    pub quota: Option<RepositoryQuota>,
 }
 
 /// Configuración de actualización del repositorio
@@ -109,6 +114,9 @@ pub struct UpdateRepositoryResponse {
     /// Backend de almacenamiento actualizado
     pub storage_backend_hrn: Option<String>,
     
+    /// Cuota de almacenamiento vigente
+    pub quota: RepositoryQuota,
+    
     /// Información de ciclo de vida actualizada
     pub lifecycle: LifecycleResponse,
     
@@ -294,6 +302,7 @@ impl From<crate::domain::repository::Repository> for UpdateRepositoryResponse {
             format: repository.format,
             config: repository.config.into(),
             storage_backend_hrn: Some(repository.storage_backend_hrn.clone()),
+            quota: repository.quota,
             lifecycle: repository.lifecycle.into(),
             is_public: false, // TODO: Agregar campo al modelo de dominio
             metadata: None,   // TODO: Agregar campo al modelo de dominio
@@ -39,4 +39,11 @@ pub trait RepositoryUpdateEventPublisherPort: Send + Sync {
     /// Publica un evento indicando que un repositorio fue actualizado
     async fn publish_repository_updated(&self, repository_id: &RepositoryId, updated_by: &UserId, 
                                        changes: Vec<String>) -> RepositoryResult<()>;
-}
\ No newline at end of file
+}
+
+/// Puerto para indexar repositorios en la búsqueda unificada
+#[async_trait]
+pub trait RepositorySearchIndexPort: Send + Sync {
+    /// Indexa (o reindexa) un repositorio para que aparezca junto a los artefactos
+    async fn index_repository(&self, repository: &Repository) -> RepositoryResult<()>;
+}
@@ -9,7 +9,8 @@ use crate::domain::{RepositoryResult, RepositoryError};
 use crate::domain::repository::{Repository, RepositoryConfig, RepositoryType, CacheSettings, ProxyAuth, DeploymentPolicy, ResolutionOrder};
 use super::dto::{UpdateRepositoryCommand, UpdateRepositoryResponse};
 use super::ports::{
-    RepositoryUpdaterPort, RepositoryUpdateAuthorizationPort, RepositoryUpdateEventPublisherPort
+    RepositoryUpdaterPort, RepositoryUpdateAuthorizationPort, RepositoryUpdateEventPublisherPort,
+    RepositorySearchIndexPort
 };
 
 /// Caso de uso para actualizar un repositorio
@@ -17,6 +18,7 @@ pub struct UpdateRepositoryUseCase {
     pub repository_updater_port: Arc<dyn RepositoryUpdaterPort>,
     pub authorization_port: Arc<dyn RepositoryUpdateAuthorizationPort>,
     pub event_publisher_port: Arc<dyn RepositoryUpdateEventPublisherPort>,
+    pub search_index_port: Arc<dyn RepositorySearchIndexPort>,
 }
 
 impl UpdateRepositoryUseCase {
@@ -24,11 +26,13 @@ impl UpdateRepositoryUseCase {
         repository_updater_port: Arc<dyn RepositoryUpdaterPort>,
         authorization_port: Arc<dyn RepositoryUpdateAuthorizationPort>,
         event_publisher_port: Arc<dyn RepositoryUpdateEventPublisherPort>,
+        search_index_port: Arc<dyn RepositorySearchIndexPort>,
     ) -> Self {
         Self {
             repository_updater_port,
             authorization_port,
             event_publisher_port,
+            search_index_port,
         }
     }
     
@@ -86,6 +90,13 @@ impl UpdateRepositoryUseCase {
             changes.push("storage backend updated".to_string());
         }
 
+        if let Some(quota) = command.quota {
+            quota.validate_for(repository.repo_type)?;
+            // Reducir la cuota por debajo del uso actual no borra nada: solo bloquea nuevas subidas
+            repository.quota = quota;
+            changes.push("quota updated".to_string());
+        }
+
         repository.lifecycle.updated_at = time::OffsetDateTime::now_utc();
         repository.lifecycle.updated_by = user_id.clone();
 
@@ -94,6 +105,11 @@ impl UpdateRepositoryUseCase {
 
         self.event_publisher_port.publish_repository_updated(&repository_id, &user_id, changes).await?;
 
+        // Reindexar para que la búsqueda refleje el repositorio actualizado; un fallo no deshace la actualización
+        if let Err(e) = self.search_index_port.index_repository(&repository).await {
+            warn!("Repository {} updated but not reindexed for search: {}", repository_id, e);
+        }
+
         let response = UpdateRepositoryResponse::from(repository);
         
         info!("Successfully updated repository: {}", repository_id);
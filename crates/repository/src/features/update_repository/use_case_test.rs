@@ -5,6 +5,7 @@ use shared::hrn::{OrganizationId, UserId, RepositoryId, Hrn};
 use shared::enums::Ecosystem;
 use crate::domain::repository::{Repository, RepositoryType, DeploymentPolicy, HostedConfig, RepositoryConfig};
 use crate::domain::RepositoryError;
+use crate::domain::quota::RepositoryQuota;
 use super::dto::{UpdateRepositoryCommand, RepositoryConfigUpdateDto, HostedConfigUpdateDto, DeploymentPolicyUpdateDto};
 use super::use_case::UpdateRepositoryUseCase;
 use super::di::UpdateRepositoryDIContainer;
@@ -38,6 +39,8 @@ async fn test_update_repository_success() {
             }
         ),
         storage_backend_hrn: Hrn::new("hrn:hodei:storage:::{}:default").unwrap(),
+        quota: RepositoryQuota::unlimited(),
+        deletion: None,
         lifecycle: shared::lifecycle::Lifecycle::new(Hrn::new("hrn:hodei:iam::system:user/system").unwrap()),
     };
 
@@ -66,4 +69,42 @@ async fn test_update_repository_success() {
 }
 
 // Add other tests similarly, using the DI container to get the use case
-// and mocking the necessary ports.
\ No newline at end of file
+// and mocking the necessary ports.
+
+#[tokio::test]
+async fn test_update_deleted_repository_is_not_found() {
+    use time::OffsetDateTime;
+    use crate::domain::repository::{RepositoryDeletion, DEFAULT_DELETION_RETENTION};
+    use crate::features::create_repository::ports::RepositoryCreatorPort;
+
+    // Arrange
+    let db = MongoDbRepositoryAdapter::new(crate::infrastructure::tests::setup_test_database().await.unwrap());
+
+    let organization_id = OrganizationId::new("test-org").unwrap();
+    let repository_id = RepositoryId::new(&organization_id.to_string(), "deleted-repo").unwrap();
+    let mut repository = Repository {
+        hrn: repository_id.clone(),
+        organization_hrn: organization_id,
+        name: "deleted-repo".to_string(),
+        region: "us-east-1".to_string(),
+        repo_type: RepositoryType::Hosted,
+        format: Ecosystem::Maven,
+        config: RepositoryConfig::Hosted(
+            HostedConfig {
+                deployment_policy: DeploymentPolicy::AllowSnapshots,
+            }
+        ),
+        storage_backend_hrn: Hrn::new("hrn:hodei:storage:::{}:default").unwrap(),
+        quota: RepositoryQuota::unlimited(),
+        deletion: Some(RepositoryDeletion::new(UserId::new_system_user(), OffsetDateTime::now_utc(), DEFAULT_DELETION_RETENTION)),
+        lifecycle: shared::lifecycle::Lifecycle::new(Hrn::new("hrn:hodei:iam::system:user/system").unwrap()),
+    };
+    db.create_repository(&repository).await.unwrap();
+
+    // Act
+    repository.region = "eu-west-1".to_string();
+    let result = db.update_repository(&repository).await;
+
+    // Assert
+    assert!(matches!(result, Err(RepositoryError::RepositoryNotFound(_))));
+}
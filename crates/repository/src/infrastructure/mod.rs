@@ -2,6 +2,7 @@
 // crates/repository/src/infrastructure/mod.rs
 
 pub mod mongodb_adapter;
+pub mod search_index_adapter;
 
 #[cfg(test)]
 pub mod tests {
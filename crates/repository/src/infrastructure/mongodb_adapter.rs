@@ -9,7 +9,8 @@ use tracing::{debug, error, info, warn, instrument};
 use shared::hrn::{RepositoryId, OrganizationId, UserId, Hrn};
 use shared::enums::Ecosystem;
 use crate::domain::{RepositoryResult, RepositoryError};
-use crate::domain::repository::{Repository, RepositoryType, RepositoryConfig, HostedConfig, ProxyConfig, VirtualConfig, DeploymentPolicy, CacheSettings, ProxyAuth, ResolutionOrder};
+use crate::domain::quota::{RepositoryQuota, RepositoryUsage};
+use crate::domain::repository::{Repository, RepositoryDeletion, RepositoryType, RepositoryConfig, HostedConfig, ProxyConfig, VirtualConfig, DeploymentPolicy, CacheSettings, ProxyAuth, ResolutionOrder};
 
 // Import all ports from all features
 use crate::features::create_repository::ports::{
@@ -18,6 +19,8 @@ use crate::features::create_repository::ports::{
 use crate::features::get_repository::ports::{RepositoryReaderPort, RepositoryStats, RepositoryStatsPort};
 use crate::features::update_repository::ports::RepositoryUpdaterPort;
 use crate::features::delete_repository::ports::{RepositoryDeleterPort, ArtifactDeleterPort};
+use crate::features::enforce_repository_quota::ports::{RepositoryQuotaReaderPort, RepositoryUsagePort};
+use crate::features::restore_repository::ports::RepositoryRestorerPort;
 
 
 /// Adaptador MongoDB unificado para todas las operaciones CRUD de repositorios
@@ -42,6 +45,10 @@ impl MongoDbRepositoryAdapter {
         self.db.collection("storage_backends")
     }
 
+    fn package_versions_collection(&self) -> Collection<Document> {
+        self.db.collection("package_versions")
+    }
+
     /// Convierte un Repository al formato de documento MongoDB
     fn repository_to_document(&self, repository: &Repository) -> RepositoryDocument {
         RepositoryDocument {
@@ -54,6 +61,8 @@ impl MongoDbRepositoryAdapter {
             format: format!("{:?}", repository.format),
             config: self.config_to_document(&repository.config),
             storage_backend_hrn: repository.storage_backend_hrn.to_string(),
+            quota: repository.quota,
+            deletion: repository.deletion.clone(),
             lifecycle: LifecycleDocument {
                 created_at: repository.lifecycle.created_at,
                 created_by: repository.lifecycle.created_by.to_string(),
@@ -128,6 +137,8 @@ impl MongoDbRepositoryAdapter {
             format,
             config,
             storage_backend_hrn: doc.storage_backend_hrn.parse()?,
+            quota: doc.quota,
+            deletion: doc.deletion,
             lifecycle: shared::lifecycle::Lifecycle {
                 created_at: doc.lifecycle.created_at,
                 created_by: doc.lifecycle.created_by.parse()?,
@@ -193,10 +204,17 @@ impl OrganizationExistsPort for MongoDbRepositoryAdapter {
 impl RepositoryExistsPort for MongoDbRepositoryAdapter {
     async fn repository_exists(&self, organization_id: &OrganizationId, name: &str) -> RepositoryResult<bool> {
         debug!("Checking if repository '{}' exists in organization '{}'", name, organization_id);
-        let filter = doc! { "organization_hrn": organization_id.to_string(), "name": name };
+        let filter = doc! { "organization_hrn": organization_id.to_string(), "name": name, "deletion": Bson::Null };
         let count = self.repositories_collection().count_documents(filter, None).await?;
         Ok(count > 0)
     }
+
+    async fn find_deleted_repository(&self, organization_id: &OrganizationId, name: &str) -> RepositoryResult<Option<Repository>> {
+        let filter = doc! { "organization_hrn": organization_id.to_string(), "name": name, "deletion": { "$ne": Bson::Null } };
+        self.repositories_collection().find_one(filter, None).await?
+            .map(|doc| self.document_to_repository(doc))
+            .transpose()
+    }
 }
 
 #[async_trait]
@@ -224,7 +242,8 @@ impl StorageBackendExistsPort for MongoDbRepositoryAdapter {
 impl RepositoryReaderPort for MongoDbRepositoryAdapter {
     async fn get_repository(&self, repository_id: &RepositoryId) -> RepositoryResult<Option<Repository>> {
         debug!("Getting repository: {}", repository_id);
-        let filter = doc! { "_id": repository_id.to_string() };
+        // Los repositorios borrados quedan fuera de las lecturas normales
+        let filter = doc! { "_id": repository_id.to_string(), "deletion": Bson::Null };
         if let Some(doc) = self.repositories_collection().find_one(filter, None).await? {
             let repository = self.document_to_repository(doc)?;
             info!("Found repository: {}", repository.name);
@@ -251,7 +270,8 @@ impl RepositoryReaderPort for MongoDbRepositoryAdapter {
 impl RepositoryUpdaterPort for MongoDbRepositoryAdapter {
     async fn update_repository(&self, repository: &Repository) -> RepositoryResult<()> {
         info!("Updating repository: {}", repository.hrn);
-        let filter = doc! { "_id": repository.hrn.to_string() };
+        // Un repositorio borrado solo puede modificarse tras restaurarlo
+        let filter = doc! { "_id": repository.hrn.to_string(), "deletion": Bson::Null };
         let update_doc = self.repository_to_document(repository);
         let result = self.repositories_collection().replace_one(filter, update_doc, None).await?;
         if result.matched_count == 0 {
@@ -269,9 +289,22 @@ impl RepositoryUpdaterPort for MongoDbRepositoryAdapter {
 
 #[async_trait]
 impl RepositoryDeleterPort for MongoDbRepositoryAdapter {
+    async fn mark_repository_deleted(&self, repository_id: &RepositoryId, deletion: &RepositoryDeletion) -> RepositoryResult<()> {
+        info!("Marking repository as deleted: {}", repository_id);
+        let filter = doc! { "_id": repository_id.to_string(), "deletion": Bson::Null };
+        let deletion = mongodb::bson::to_bson(deletion)
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let result = self.repositories_collection().update_one(filter, doc! { "$set": { "deletion": deletion } }, None).await?;
+        if result.matched_count == 0 {
+            Err(RepositoryError::RepositoryNotFound(repository_id.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
     async fn delete_repository(&self, repository_id: &RepositoryId) -> RepositoryResult<()> {
-        info!("Deleting repository: {}", repository_id);
-        let filter = doc! { "_id": repository_id.to_string() };
+        info!("Purging repository: {}", repository_id);
+        let filter = doc! { "_id": repository_id.to_string(), "deletion": { "$ne": Bson::Null } };
         let result = self.repositories_collection().delete_one(filter, None).await?;
         if result.deleted_count == 0 {
             Err(RepositoryError::RepositoryNotFound(repository_id.to_string()))
@@ -285,6 +318,16 @@ impl RepositoryDeleterPort for MongoDbRepositoryAdapter {
         self.get_repository(repository_id).await
     }
     
+    async fn list_deleted_repositories(&self) -> RepositoryResult<Vec<Repository>> {
+        let filter = doc! { "deletion": { "$ne": Bson::Null } };
+        let mut cursor = self.repositories_collection().find(filter, None).await?;
+        let mut repositories = Vec::new();
+        while cursor.advance().await? {
+            repositories.push(self.document_to_repository(cursor.deserialize_current()?)?);
+        }
+        Ok(repositories)
+    }
+    
     async fn is_repository_empty(&self, repository_id: &RepositoryId) -> RepositoryResult<bool> {
         // Placeholder
         debug!("Checking if repository is empty: {}", repository_id);
@@ -292,6 +335,27 @@ impl RepositoryDeleterPort for MongoDbRepositoryAdapter {
     }
 }
 
+#[async_trait]
+impl RepositoryRestorerPort for MongoDbRepositoryAdapter {
+    async fn get_deleted_repository(&self, repository_id: &RepositoryId) -> RepositoryResult<Option<Repository>> {
+        let filter = doc! { "_id": repository_id.to_string(), "deletion": { "$ne": Bson::Null } };
+        self.repositories_collection().find_one(filter, None).await?
+            .map(|doc| self.document_to_repository(doc))
+            .transpose()
+    }
+
+    async fn restore_repository(&self, repository_id: &RepositoryId) -> RepositoryResult<()> {
+        info!("Restoring repository: {}", repository_id);
+        let filter = doc! { "_id": repository_id.to_string(), "deletion": { "$ne": Bson::Null } };
+        let result = self.repositories_collection().update_one(filter, doc! { "$unset": { "deletion": "" } }, None).await?;
+        if result.matched_count == 0 {
+            Err(RepositoryError::RepositoryNotFound(repository_id.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 #[async_trait]
 impl ArtifactDeleterPort for MongoDbRepositoryAdapter {
     async fn delete_repository_artifacts(&self, repository_id: &RepositoryId) -> RepositoryResult<u64> {
@@ -307,6 +371,49 @@ impl ArtifactDeleterPort for MongoDbRepositoryAdapter {
     }
 }
 
+#[async_trait]
+impl RepositoryQuotaReaderPort for MongoDbRepositoryAdapter {
+    async fn get_repository_quota(&self, repository_id: &RepositoryId) -> RepositoryResult<Option<RepositoryQuota>> {
+        // Un repositorio borrado no acepta subidas: se trata como inexistente
+        let filter = doc! { "_id": repository_id.to_string(), "deletion": Bson::Null };
+        let doc = self.repositories_collection().find_one(filter, None).await?;
+        Ok(doc.map(|doc| doc.quota))
+    }
+}
+
+#[async_trait]
+impl RepositoryUsagePort for MongoDbRepositoryAdapter {
+    async fn get_repository_usage(&self, repository_id: &RepositoryId) -> RepositoryResult<RepositoryUsage> {
+        debug!("Computing usage for repository: {}", repository_id);
+        // El tamaño está en cada fichero de `artifacts`, no en la versión de paquete
+        let pipeline = vec![
+            doc! { "$match": { "repository_hrn": repository_id.to_string() } },
+            doc! { "$unwind": "$artifacts" },
+            doc! { "$group": {
+                "_id": Bson::Null,
+                "artifact_count": { "$sum": 1 },
+                "size_bytes": { "$sum": "$artifacts.size_in_bytes" },
+            } },
+        ];
+        let mut cursor = self.package_versions_collection().aggregate(pipeline, None).await?;
+        if !cursor.advance().await? {
+            return Ok(RepositoryUsage::default());
+        }
+        let totals = cursor.deserialize_current()?;
+        // `$sum` devuelve Int32, Int64 o Double según la magnitud
+        let as_u64 = |key: &str| match totals.get(key) {
+            Some(Bson::Int32(n)) => *n as u64,
+            Some(Bson::Int64(n)) => *n as u64,
+            Some(Bson::Double(n)) => *n as u64,
+            _ => 0,
+        };
+        Ok(RepositoryUsage {
+            size_bytes: as_u64("size_bytes"),
+            artifact_count: as_u64("artifact_count"),
+        })
+    }
+}
+
 
 /// Documento MongoDB para repositorios
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -321,6 +428,12 @@ struct RepositoryDocument {
     pub format: String,
     pub config: Document,
     pub storage_backend_hrn: String,
+    /// Ausente en documentos anteriores a las cuotas: se leen como ilimitados
+    #[serde(default)]
+    pub quota: RepositoryQuota,
+    /// Presente solo en repositorios borrados pendientes de purga
+    #[serde(default)]
+    pub deletion: Option<RepositoryDeletion>,
     pub lifecycle: LifecycleDocument,
 }
 
@@ -0,0 +1,51 @@
+// crates/repository/src/infrastructure/search_index_adapter.rs
+
+use std::sync::Arc;
+use async_trait::async_trait;
+use search::features::index_text_documents::IndexDocumentUseCase;
+use search::IndexDocumentCommand;
+use tracing::debug;
+
+use crate::domain::{RepositoryError, RepositoryResult};
+use crate::domain::repository::Repository;
+use crate::features::create_repository::ports::RepositorySearchIndexPort as CreateSearchIndexPort;
+use crate::features::update_repository::ports::RepositorySearchIndexPort as UpdateSearchIndexPort;
+
+/// Adaptador que indexa repositorios en el índice de texto compartido con los artefactos
+pub struct SearchIndexRepositoryAdapter {
+    index_use_case: Arc<IndexDocumentUseCase>,
+}
+
+impl SearchIndexRepositoryAdapter {
+    pub fn new(index_use_case: Arc<IndexDocumentUseCase>) -> Self {
+        Self { index_use_case }
+    }
+
+    async fn index(&self, repository: &Repository) -> RepositoryResult<()> {
+        debug!("Indexing repository for search: {}", repository.hrn);
+        let command = IndexDocumentCommand::for_repository(
+            repository.hrn.to_string(),
+            repository.name.clone(),
+            None,
+            format!("{:?}", repository.format),
+            Vec::new(),
+        );
+        self.index_use_case.execute(command).await
+            .map(|_| ())
+            .map_err(|e| RepositoryError::SearchIndexError(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl CreateSearchIndexPort for SearchIndexRepositoryAdapter {
+    async fn index_repository(&self, repository: &Repository) -> RepositoryResult<()> {
+        self.index(repository).await
+    }
+}
+
+#[async_trait]
+impl UpdateSearchIndexPort for SearchIndexRepositoryAdapter {
+    async fn index_repository(&self, repository: &Repository) -> RepositoryResult<()> {
+        self.index(repository).await
+    }
+}
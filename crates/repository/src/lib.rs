@@ -24,3 +24,13 @@ pub use features::delete_repository::{
     DeleteRepositoryDIContainer as DeleteRepositoryFeature,
     DeleteRepositoryCommand, DeleteRepositoryResponse
 };
+
+pub use features::restore_repository::{
+    RestoreRepositoryDIContainer as RestoreRepositoryFeature,
+    RestoreRepositoryCommand, RestoreRepositoryResponse
+};
+
+pub use features::enforce_repository_quota::{
+    EnforceRepositoryQuotaDIContainer as EnforceRepositoryQuotaFeature,
+    ReserveQuotaCommand, QuotaReservation
+};
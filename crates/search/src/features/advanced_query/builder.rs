@@ -0,0 +1,563 @@
+//! Fluent builder for advanced queries
+//!
+//! Programmatic callers build the same [`QueryNode`] tree the string parser
+//! produces, without concatenating user input into query strings. Values are
+//! quoted when rendered, so a value can never turn into an operator or a field.
+//!
+//! ```ignore
+//! let query = AdvancedQueryBuilder::new()
+//!     .term("name", "foo")
+//!     .and()
+//!     .range("size", 0..1000)
+//!     .not(AdvancedQueryBuilder::new().term("package_type", "npm"))
+//!     .build()?;
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeToInclusive};
+
+use crate::features::advanced_query::{
+    error::AdvancedQueryError,
+    parser::{ParsedQuery, QueryNode},
+};
+
+/// How a field is indexed, which decides the operations it supports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Tokenized full text; no ranges
+    Text,
+    /// Exact-match string
+    Keyword,
+    /// Number; supports ranges
+    Numeric,
+    /// Date or timestamp; supports ranges
+    Date,
+}
+
+impl FieldKind {
+    fn supports_ranges(self) -> bool {
+        matches!(self, FieldKind::Numeric | FieldKind::Date)
+    }
+}
+
+/// Fields known to the artifact index
+const DEFAULT_FIELDS: &[(&str, FieldKind)] = &[
+    ("id", FieldKind::Keyword),
+    ("name", FieldKind::Text),
+    ("description", FieldKind::Text),
+    ("version", FieldKind::Keyword),
+    ("package_type", FieldKind::Keyword),
+    ("repository", FieldKind::Keyword),
+    ("size", FieldKind::Numeric),
+    ("created_at", FieldKind::Date),
+    ("updated_at", FieldKind::Date),
+];
+
+/// Open bound in a rendered range
+const UNBOUNDED: &str = "*";
+
+/// Range argument accepted by [`AdvancedQueryBuilder::range`]
+///
+/// Range queries are inclusive on both ends, so exclusive integer ends are
+/// converted (`0..1000` becomes `[0 TO 999]`).
+pub trait QueryRange {
+    /// Inclusive `(start, end)` bounds, or `None` if the range is empty
+    fn bounds(&self) -> Option<(String, String)>;
+}
+
+macro_rules! impl_query_range {
+    ($($t:ty),*) => {$(
+        impl QueryRange for Range<$t> {
+            fn bounds(&self) -> Option<(String, String)> {
+                (self.start < self.end).then(|| (self.start.to_string(), (self.end - 1).to_string()))
+            }
+        }
+
+        impl QueryRange for RangeInclusive<$t> {
+            fn bounds(&self) -> Option<(String, String)> {
+                (self.start() <= self.end()).then(|| (self.start().to_string(), self.end().to_string()))
+            }
+        }
+
+        impl QueryRange for RangeFrom<$t> {
+            fn bounds(&self) -> Option<(String, String)> {
+                Some((self.start.to_string(), UNBOUNDED.to_string()))
+            }
+        }
+
+        impl QueryRange for RangeToInclusive<$t> {
+            fn bounds(&self) -> Option<(String, String)> {
+                Some((UNBOUNDED.to_string(), self.end.to_string()))
+            }
+        }
+    )*};
+}
+
+impl_query_range!(i32, i64, u32, u64, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operator {
+    And,
+    Or,
+}
+
+/// Fluent builder producing a validated [`ParsedQuery`]
+///
+/// Clauses written one after another are combined with AND, like juxtaposed
+/// terms in a query string. AND binds tighter than OR. The first invalid
+/// operation is remembered and returned by [`build`](Self::build).
+#[derive(Debug)]
+pub struct AdvancedQueryBuilder {
+    fields: HashMap<String, FieldKind>,
+    clauses: Vec<QueryNode>,
+    operators: Vec<Operator>,
+    pending: Option<Operator>,
+    error: Option<AdvancedQueryError>,
+}
+
+impl Default for AdvancedQueryBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdvancedQueryBuilder {
+    /// Create a builder that knows the default artifact index fields
+    pub fn new() -> Self {
+        Self {
+            fields: DEFAULT_FIELDS
+                .iter()
+                .map(|(name, kind)| (name.to_string(), *kind))
+                .collect(),
+            clauses: Vec::new(),
+            operators: Vec::new(),
+            pending: None,
+            error: None,
+        }
+    }
+
+    /// Declare an additional field, or change the kind of a known one
+    pub fn with_field(mut self, name: impl Into<String>, kind: FieldKind) -> Self {
+        self.fields.insert(name.into(), kind);
+        self
+    }
+
+    /// Match documents whose `field` equals `value`
+    pub fn term(self, field: &str, value: impl Display) -> Self {
+        let value = value.to_string();
+        match self.field_kind(field).and_then(|kind| check_value(field, kind, &value)) {
+            Ok(()) => self.push(QueryNode::Field(field.to_string(), value)),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Match free text in any field
+    pub fn text(self, value: impl Display) -> Self {
+        let value = value.to_string();
+        match check_literal(&value) {
+            Ok(()) => self.push(QueryNode::Term(value)),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Match documents whose `field` falls within `range` (both ends inclusive)
+    pub fn range(self, field: &str, range: impl QueryRange) -> Self {
+        match range.bounds() {
+            Some((start, end)) => self.range_between(field, start, end),
+            None => self.fail(AdvancedQueryError::InvalidRangeError(format!(
+                "Empty range for field '{}'",
+                field
+            ))),
+        }
+    }
+
+    /// Range with explicit bounds, e.g. dates; use `*` for an open end
+    pub fn range_between(self, field: &str, start: impl Display, end: impl Display) -> Self {
+        let (start, end) = (start.to_string(), end.to_string());
+        match self.check_range(field, &start, &end) {
+            Ok(()) => self.push(QueryNode::Range(field.to_string(), start, end)),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Free-text wildcard pattern using `*` and `?`
+    pub fn wildcard(self, pattern: impl Display) -> Self {
+        let pattern = pattern.to_string();
+        if !pattern.contains(['*', '?']) {
+            return self.fail(AdvancedQueryError::QueryParseError(format!(
+                "Wildcard pattern '{}' contains no '*' or '?'",
+                pattern
+            )));
+        }
+        match check_bare_word(&pattern) {
+            Ok(()) => self.push(QueryNode::Wildcard(None, pattern)),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Free-text approximate match within `distance` edits
+    pub fn fuzzy(self, term: impl Display, distance: u8) -> Self {
+        let term = term.to_string();
+        if term.contains(['*', '?', '~']) {
+            return self.fail(AdvancedQueryError::QueryParseError(format!(
+                "Fuzzy term '{}' must not contain '*', '?' or '~'",
+                term
+            )));
+        }
+        match check_bare_word(&term) {
+            Ok(()) => self.push(QueryNode::Fuzzy(None, term, distance)),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Exclude documents matching `query`
+    pub fn not(self, query: AdvancedQueryBuilder) -> Self {
+        match query.build_node() {
+            Ok(node) => self.push(QueryNode::Not(Box::new(wrap_compound(node)))),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Add `query` as a parenthesized sub-query
+    pub fn group(self, query: AdvancedQueryBuilder) -> Self {
+        match query.build_node() {
+            Ok(node) => self.push(QueryNode::Group(Box::new(node))),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Join the previous clause and the next one with AND
+    pub fn and(self) -> Self {
+        self.operator(Operator::And)
+    }
+
+    /// Join the previous clause and the next one with OR
+    pub fn or(self) -> Self {
+        self.operator(Operator::Or)
+    }
+
+    /// Validate and produce the query tree
+    pub fn build(self) -> Result<ParsedQuery, AdvancedQueryError> {
+        self.build_node().map(ParsedQuery::new)
+    }
+
+    fn build_node(self) -> Result<QueryNode, AdvancedQueryError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if let Some(operator) = self.pending {
+            return Err(AdvancedQueryError::InvalidBooleanOperatorError(format!(
+                "{} is missing a right-hand clause",
+                operator.name()
+            )));
+        }
+
+        let mut clauses = self.clauses.into_iter();
+        let first = clauses
+            .next()
+            .ok_or_else(|| AdvancedQueryError::QueryParseError("Query has no clauses".to_string()))?;
+
+        // AND binds tighter than OR: fold AND runs first, then OR them together
+        let mut alternatives = Vec::new();
+        let mut current = first;
+        for (operator, clause) in self.operators.into_iter().zip(clauses) {
+            match operator {
+                Operator::And => current = QueryNode::And(Box::new(current), Box::new(clause)),
+                Operator::Or => alternatives.push(std::mem::replace(&mut current, clause)),
+            }
+        }
+        alternatives.push(current);
+
+        let mut alternatives = alternatives.into_iter();
+        let first = alternatives.next().expect("at least one clause");
+        Ok(alternatives.fold(first, |left, right| QueryNode::Or(Box::new(left), Box::new(right))))
+    }
+
+    fn push(mut self, node: QueryNode) -> Self {
+        if !self.clauses.is_empty() {
+            self.operators.push(self.pending.take().unwrap_or(Operator::And));
+        }
+        self.clauses.push(node);
+        self
+    }
+
+    fn operator(mut self, operator: Operator) -> Self {
+        if self.clauses.is_empty() {
+            return self.fail(AdvancedQueryError::InvalidBooleanOperatorError(format!(
+                "{} is missing a left-hand clause",
+                operator.name()
+            )));
+        }
+        if let Some(previous) = self.pending {
+            return self.fail(AdvancedQueryError::InvalidBooleanOperatorError(format!(
+                "{} cannot directly follow {}",
+                operator.name(),
+                previous.name()
+            )));
+        }
+        self.pending = Some(operator);
+        self
+    }
+
+    fn fail(mut self, error: AdvancedQueryError) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
+
+    fn field_kind(&self, field: &str) -> Result<FieldKind, AdvancedQueryError> {
+        self.fields
+            .get(field)
+            .copied()
+            .ok_or_else(|| AdvancedQueryError::InvalidFieldError(format!("Unknown field '{}'", field)))
+    }
+
+    fn check_range(&self, field: &str, start: &str, end: &str) -> Result<(), AdvancedQueryError> {
+        let kind = self.field_kind(field)?;
+        if !kind.supports_ranges() {
+            return Err(AdvancedQueryError::InvalidRangeError(format!(
+                "Range queries are not supported on {:?} field '{}'",
+                kind, field
+            )));
+        }
+
+        for bound in [start, end] {
+            if bound != UNBOUNDED {
+                check_value(field, kind, bound)?;
+                check_bare_word(bound)?;
+            }
+        }
+
+        let numeric_bounds = (kind == FieldKind::Numeric)
+            .then(|| start.parse::<f64>().ok().zip(end.parse::<f64>().ok()))
+            .flatten();
+        if let Some((start, end)) = numeric_bounds.filter(|(start, end)| start > end) {
+            return Err(AdvancedQueryError::InvalidRangeError(format!(
+                "Range start {} is greater than its end {} for field '{}'",
+                start, end, field
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Operator {
+    fn name(self) -> &'static str {
+        match self {
+            Operator::And => "AND",
+            Operator::Or => "OR",
+        }
+    }
+}
+
+/// Multi-clause sub-queries need parentheses to keep their meaning under NOT
+fn wrap_compound(node: QueryNode) -> QueryNode {
+    match node {
+        QueryNode::And(..) | QueryNode::Or(..) => QueryNode::Group(Box::new(node)),
+        other => other,
+    }
+}
+
+fn check_value(field: &str, kind: FieldKind, value: &str) -> Result<(), AdvancedQueryError> {
+    check_literal(value)?;
+    if kind == FieldKind::Numeric && value.parse::<f64>().is_err() {
+        return Err(AdvancedQueryError::InvalidFieldError(format!(
+            "Field '{}' is numeric but '{}' is not a number",
+            field, value
+        )));
+    }
+    Ok(())
+}
+
+/// Any value that can be rendered inside quotes
+fn check_literal(value: &str) -> Result<(), AdvancedQueryError> {
+    if value.trim().is_empty() {
+        return Err(AdvancedQueryError::QueryParseError("Query values cannot be empty".to_string()));
+    }
+    if value.contains('"') {
+        return Err(AdvancedQueryError::QueryParseError(format!(
+            "Value '{}' contains a double quote",
+            value
+        )));
+    }
+    Ok(())
+}
+
+/// Values that must be rendered unquoted (range bounds, wildcards, fuzzy terms)
+fn check_bare_word(value: &str) -> Result<(), AdvancedQueryError> {
+    check_literal(value)?;
+    if is_reserved(value) || value.chars().any(|c| is_structural(c) || c == '~') {
+        return Err(AdvancedQueryError::QueryParseError(format!(
+            "Value '{}' cannot contain whitespace, operators or query syntax",
+            value
+        )));
+    }
+    Ok(())
+}
+
+fn is_structural(c: char) -> bool {
+    c.is_whitespace() || "()[]:\"".contains(c)
+}
+
+fn is_reserved(value: &str) -> bool {
+    matches!(value, "AND" | "OR" | "NOT" | "TO")
+}
+
+fn needs_quotes(value: &str) -> bool {
+    value.is_empty() || is_reserved(value) || value.chars().any(|c| is_structural(c) || "*?~".contains(c))
+}
+
+fn render_value(value: &str) -> String {
+    if needs_quotes(value) {
+        format!("\"{}\"", value)
+    } else {
+        value.to_string()
+    }
+}
+
+impl QueryNode {
+    /// Render the tree as a query string that parses back to the same tree
+    pub fn to_query_string(&self) -> String {
+        match self {
+            QueryNode::Term(value) => render_value(value),
+            QueryNode::Field(field, value) => format!("{}:{}", field, render_value(value)),
+            QueryNode::And(left, right) => format!("{} AND {}", left.to_query_string(), right.to_query_string()),
+            QueryNode::Or(left, right) => format!("{} OR {}", left.to_query_string(), right.to_query_string()),
+            QueryNode::Not(inner) => format!("NOT {}", inner.to_query_string()),
+            QueryNode::Group(inner) => format!("({})", inner.to_query_string()),
+            QueryNode::Range(field, start, end) => format!("{}:[{} TO {}]", field, start, end),
+            QueryNode::Wildcard(field, pattern) => format!("{}{}", field_prefix(field), pattern),
+            QueryNode::Fuzzy(field, term, distance) => {
+                format!("{}{}~{}", field_prefix(field), term, distance)
+            }
+        }
+    }
+}
+
+fn field_prefix(field: &Option<String>) -> String {
+    field.as_ref().map(|f| format!("{}:", f)).unwrap_or_default()
+}
+
+impl ParsedQuery {
+    /// Render as a query string, e.g. for `AdvancedSearchQuery::q`
+    pub fn to_query_string(&self) -> String {
+        self.ast.to_query_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::advanced_query::parser::AdvancedQueryParser;
+
+    fn assert_round_trips(query: &ParsedQuery) {
+        let reparsed = AdvancedQueryParser::new().parse(&query.to_query_string()).unwrap();
+        assert_eq!(&reparsed, query, "rendered as {}", query.to_query_string());
+    }
+
+    #[test]
+    fn builds_same_tree_as_parser() {
+        let built = AdvancedQueryBuilder::new()
+            .term("name", "foo")
+            .and()
+            .range("size", 0..1000)
+            .or()
+            .text("legacy")
+            .not(AdvancedQueryBuilder::new().term("package_type", "npm"))
+            .build()
+            .unwrap();
+
+        let parsed = AdvancedQueryParser::new()
+            .parse("name:foo AND size:[0 TO 999] OR legacy NOT package_type:npm")
+            .unwrap();
+
+        assert_eq!(built, parsed);
+        assert_round_trips(&built);
+    }
+
+    #[test]
+    fn quotes_values_so_they_cannot_inject_syntax() {
+        let built = AdvancedQueryBuilder::new()
+            .term("name", "foo OR version:*")
+            .text("AND")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            built.ast,
+            QueryNode::And(
+                Box::new(QueryNode::Field("name".into(), "foo OR version:*".into())),
+                Box::new(QueryNode::Term("AND".into())),
+            )
+        );
+        assert_round_trips(&built);
+    }
+
+    #[test]
+    fn negated_compound_queries_are_grouped() {
+        let built = AdvancedQueryBuilder::new()
+            .text("logging")
+            .not(AdvancedQueryBuilder::new().term("version", "1.0").or().wildcard("snap*"))
+            .fuzzy("lodash", 1)
+            .build()
+            .unwrap();
+
+        assert_round_trips(&built);
+    }
+
+    #[test]
+    fn rejects_range_on_text_field() {
+        let result = AdvancedQueryBuilder::new().range("name", 0..10).build();
+
+        match result {
+            Err(AdvancedQueryError::InvalidRangeError(message)) => assert!(message.contains("'name'")),
+            other => panic!("expected range error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_fields_and_non_numeric_values() {
+        assert!(matches!(
+            AdvancedQueryBuilder::new().term("colour", "red").build(),
+            Err(AdvancedQueryError::InvalidFieldError(_))
+        ));
+        assert!(matches!(
+            AdvancedQueryBuilder::new().term("size", "large").build(),
+            Err(AdvancedQueryError::InvalidFieldError(_))
+        ));
+        assert!(AdvancedQueryBuilder::new()
+            .with_field("colour", FieldKind::Keyword)
+            .term("colour", "red")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn rejects_dangling_operators_and_empty_ranges() {
+        assert!(matches!(
+            AdvancedQueryBuilder::new().and().text("a").build(),
+            Err(AdvancedQueryError::InvalidBooleanOperatorError(_))
+        ));
+        assert!(matches!(
+            AdvancedQueryBuilder::new().text("a").or().build(),
+            Err(AdvancedQueryError::InvalidBooleanOperatorError(_))
+        ));
+        assert!(matches!(
+            AdvancedQueryBuilder::new().range("size", 5..5).build(),
+            Err(AdvancedQueryError::InvalidRangeError(_))
+        ));
+        assert!(AdvancedQueryBuilder::new().build().is_err());
+    }
+
+    #[test]
+    fn supports_open_and_date_ranges() {
+        let built = AdvancedQueryBuilder::new()
+            .range("size", 100..)
+            .range_between("created_at", "2024-01-01", "*")
+            .build()
+            .unwrap();
+
+        assert_round_trips(&built);
+    }
+}
@@ -15,6 +15,10 @@ pub enum AdvancedQueryError {
     #[error("Invalid boolean operator error: {0}")]
     InvalidBooleanOperatorError(String),
     
+    /// Syntax error with the char offset (not byte offset) of the problem in the query
+    #[error("Syntax error at position {position}: {message}")]
+    SyntaxError { position: usize, message: String },
+
     #[error("Unmatched parentheses error")]
     UnmatchedParenthesesError,
     
@@ -1,4 +1,5 @@
 pub mod parser;
+pub mod builder;
 pub mod integration;
 pub mod di;
 pub mod dto;
@@ -12,4 +13,6 @@ pub mod advanced_query_test;
 // Expose only the public parts of the feature.
 pub use di::AdvancedQueryDIContainer;
 pub use dto::{AdvancedSearchQuery, AdvancedSearchResults, ParsedQueryInfo};
-pub use error::AdvancedQueryError;
\ No newline at end of file
+pub use error::AdvancedQueryError;
+pub use parser::AdvancedQueryParser;
+pub use builder::{AdvancedQueryBuilder, FieldKind};
\ No newline at end of file
@@ -1,4 +1,10 @@
-use std::fmt;
+use async_trait::async_trait;
+
+use crate::features::advanced_query::{
+    dto::ParsedQueryInfo,
+    error::AdvancedQueryError,
+    ports::{QueryParserPort, QueryParsingStats},
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum QueryNode {
@@ -8,9 +14,9 @@ pub enum QueryNode {
     Or(Box<QueryNode>, Box<QueryNode>),
     Not(Box<QueryNode>),
     Group(Box<QueryNode>),
-    Range(String, String, String), // field, start, end
-    Wildcard(String),
-    Fuzzy(String, u8), // term, distance
+    Range(String, String, String),     // field, start, end
+    Wildcard(Option<String>, String),  // field, pattern
+    Fuzzy(Option<String>, String, u8), // field, term, distance
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,36 +28,328 @@ impl ParsedQuery {
     pub fn new(ast: QueryNode) -> Self {
         Self { ast }
     }
+
+    /// Summarize the query tree for callers that don't need the full AST
+    pub fn to_info(&self, original_query: &str) -> ParsedQueryInfo {
+        let mut info = ParsedQueryInfo {
+            original_query: original_query.to_string(),
+            parsed_fields: vec![],
+            boolean_operators: vec![],
+            has_wildcards: false,
+            has_fuzzy: false,
+            has_ranges: false,
+        };
+        collect_info(&self.ast, &mut info);
+        info
+    }
+}
+
+fn collect_info(node: &QueryNode, info: &mut ParsedQueryInfo) {
+    match node {
+        QueryNode::Term(_) => {}
+        QueryNode::Field(field, value) => {
+            info.parsed_fields.push(field.clone());
+            info.has_wildcards |= value.contains('*') || value.contains('?');
+        }
+        QueryNode::And(left, right) | QueryNode::Or(left, right) => {
+            let op = if matches!(node, QueryNode::And(..)) { "AND" } else { "OR" };
+            info.boolean_operators.push(op.to_string());
+            collect_info(left, info);
+            collect_info(right, info);
+        }
+        QueryNode::Not(inner) => {
+            info.boolean_operators.push("NOT".to_string());
+            collect_info(inner, info);
+        }
+        QueryNode::Group(inner) => collect_info(inner, info),
+        QueryNode::Range(field, _, _) => {
+            info.parsed_fields.push(field.clone());
+            info.has_ranges = true;
+        }
+        QueryNode::Wildcard(field, _) => {
+            info.parsed_fields.extend(field.clone());
+            info.has_wildcards = true;
+        }
+        QueryNode::Fuzzy(field, _, _) => {
+            info.parsed_fields.extend(field.clone());
+            info.has_fuzzy = true;
+        }
+    }
 }
 
+/// Lexical token with its position (in chars) in the original input
 #[derive(Debug, Clone, PartialEq)]
-pub enum AdvancedQueryError {
-    QueryParseError(String),
-    InvalidFieldError(String),
-    InvalidRangeError(String),
-    InvalidBooleanOperatorError(String),
-    UnmatchedParenthesesError,
-    QueryTooComplexError,
-    QueryTimeoutError,
-    InternalError(String),
+enum Token {
+    Word(String),
+    Quoted(String),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Colon,
+}
+
+#[derive(Debug, Clone)]
+struct Spanned {
+    token: Token,
+    position: usize,
 }
 
-impl fmt::Display for AdvancedQueryError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AdvancedQueryError::QueryParseError(msg) => write!(f, "Query parse error: {}", msg),
-            AdvancedQueryError::InvalidFieldError(field) => write!(f, "Invalid field: {}", field),
-            AdvancedQueryError::InvalidRangeError(range) => write!(f, "Invalid range: {}", range),
-            AdvancedQueryError::InvalidBooleanOperatorError(op) => write!(f, "Invalid boolean operator: {}", op),
-            AdvancedQueryError::UnmatchedParenthesesError => write!(f, "Unmatched parentheses"),
-            AdvancedQueryError::QueryTooComplexError => write!(f, "Query too complex"),
-            AdvancedQueryError::QueryTimeoutError => write!(f, "Query timeout"),
-            AdvancedQueryError::InternalError(msg) => write!(f, "Internal error: {}", msg),
+fn syntax_error(position: usize, message: impl Into<String>) -> AdvancedQueryError {
+    AdvancedQueryError::SyntaxError {
+        position,
+        message: message.into(),
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Spanned>, AdvancedQueryError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let single = match c {
+            '(' => Some(Token::LParen),
+            ')' => Some(Token::RParen),
+            '[' => Some(Token::LBracket),
+            ']' => Some(Token::RBracket),
+            ':' => Some(Token::Colon),
+            _ => None,
+        };
+
+        if let Some(token) = single {
+            tokens.push(Spanned { token, position: i });
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '"' {
+            let close = chars[i + 1..]
+                .iter()
+                .position(|&ch| ch == '"')
+                .ok_or_else(|| syntax_error(i, "Unterminated quoted phrase"))?;
+            let text: String = chars[i + 1..i + 1 + close].iter().collect();
+            tokens.push(Spanned { token: Token::Quoted(text), position: i });
+            i += close + 2;
+        } else {
+            let start = i;
+            while i < chars.len() && !chars[i].is_whitespace() && !"()[]:\"".contains(chars[i]) {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Spanned { token: Token::Word(text), position: start });
         }
     }
+
+    Ok(tokens)
 }
 
-impl std::error::Error for AdvancedQueryError {}
+/// Recursive-descent parser over the token stream.
+///
+/// Reports the first syntax problem found; positions are char offsets into the input.
+struct Parser {
+    tokens: Vec<Spanned>,
+    pos: usize,
+    input_len: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Spanned> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Spanned> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek_operator(&self) -> Option<(&'static str, usize)> {
+        match self.peek() {
+            Some(Spanned { token: Token::Word(w), position }) => match w.as_str() {
+                "AND" => Some(("AND", *position)),
+                "OR" => Some(("OR", *position)),
+                "NOT" => Some(("NOT", *position)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn end_position(&self) -> usize {
+        self.input_len
+    }
+
+    fn parse_or(&mut self) -> Result<QueryNode, AdvancedQueryError> {
+        let mut left = self.parse_and()?;
+
+        while let Some(("OR", position)) = self.peek_operator() {
+            self.pos += 1;
+            if self.at_operand_boundary() {
+                return Err(syntax_error(position, "Operator 'OR' is missing a right-hand operand"));
+            }
+            let right = self.parse_and()?;
+            left = QueryNode::Or(Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<QueryNode, AdvancedQueryError> {
+        let mut left = self.parse_unary()?;
+
+        loop {
+            match self.peek_operator() {
+                Some(("AND", position)) => {
+                    self.pos += 1;
+                    if self.at_operand_boundary() {
+                        return Err(syntax_error(position, "Operator 'AND' is missing a right-hand operand"));
+                    }
+                }
+                Some(("OR", _)) => break,
+                _ => {
+                    // Juxtaposed clauses are an implicit AND
+                    if self.at_operand_boundary() {
+                        break;
+                    }
+                }
+            }
+            let right = self.parse_unary()?;
+            left = QueryNode::And(Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    /// True when no operand can start at the current token
+    fn at_operand_boundary(&self) -> bool {
+        match self.peek() {
+            None => true,
+            Some(Spanned { token: Token::RParen, .. }) => true,
+            Some(Spanned { token: Token::Word(w), .. }) => w == "AND" || w == "OR",
+            _ => false,
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<QueryNode, AdvancedQueryError> {
+        if let Some(("NOT", position)) = self.peek_operator() {
+            self.pos += 1;
+            if self.at_operand_boundary() {
+                return Err(syntax_error(position, "Operator 'NOT' is missing an operand"));
+            }
+            let inner = self.parse_unary()?;
+            return Ok(QueryNode::Not(Box::new(inner)));
+        }
+
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<QueryNode, AdvancedQueryError> {
+        let Some(Spanned { token, position }) = self.next() else {
+            return Err(syntax_error(self.end_position(), "Unexpected end of query"));
+        };
+
+        match token {
+            Token::LParen => {
+                if matches!(self.peek(), Some(Spanned { token: Token::RParen, .. })) {
+                    return Err(syntax_error(position, "Empty parentheses"));
+                }
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some(Spanned { token: Token::RParen, .. }) => Ok(QueryNode::Group(Box::new(inner))),
+                    _ => Err(syntax_error(position, "Unclosed parenthesis")),
+                }
+            }
+            Token::RParen => Err(syntax_error(position, "Unmatched closing parenthesis")),
+            Token::RBracket => Err(syntax_error(position, "Unmatched closing bracket")),
+            Token::LBracket => Err(syntax_error(position, "Range must be preceded by a field name")),
+            Token::Colon => Err(syntax_error(position, "Missing field name before ':'")),
+            Token::Quoted(text) => Ok(QueryNode::Term(text)),
+            Token::Word(word) => {
+                if matches!(word.as_str(), "AND" | "OR") {
+                    return Err(syntax_error(
+                        position,
+                        format!("Operator '{}' is missing a left-hand operand", word),
+                    ));
+                }
+                if matches!(self.peek(), Some(Spanned { token: Token::Colon, .. })) {
+                    let colon = self.next().map(|t| t.position).unwrap_or(position);
+                    return self.parse_field_value(word, colon);
+                }
+                Self::term_node(word, position)
+            }
+        }
+    }
+
+    fn parse_field_value(&mut self, field: String, colon: usize) -> Result<QueryNode, AdvancedQueryError> {
+        match self.next() {
+            Some(Spanned { token: Token::Word(value), position }) if !matches!(value.as_str(), "AND" | "OR" | "NOT") => {
+                // Wildcard and fuzzy values stay scoped to the field
+                match Self::term_node(value, position)? {
+                    QueryNode::Term(value) => Ok(QueryNode::Field(field, value)),
+                    QueryNode::Wildcard(_, pattern) => {
+                        Ok(QueryNode::Wildcard(Some(field), pattern))
+                    }
+                    QueryNode::Fuzzy(_, term, distance) => {
+                        Ok(QueryNode::Fuzzy(Some(field), term, distance))
+                    }
+                    other => Ok(other),
+                }
+            }
+            Some(Spanned { token: Token::Quoted(value), .. }) => Ok(QueryNode::Field(field, value)),
+            Some(Spanned { token: Token::LBracket, position }) => self.parse_range(field, position),
+            _ => Err(syntax_error(colon + 1, format!("Missing value for field '{}'", field))),
+        }
+    }
+
+    fn parse_range(&mut self, field: String, open: usize) -> Result<QueryNode, AdvancedQueryError> {
+        let start = match self.next() {
+            Some(Spanned { token: Token::Word(w), .. }) if w != "TO" => w,
+            Some(Spanned { position, .. }) => return Err(syntax_error(position, "Missing range start")),
+            None => return Err(syntax_error(open, "Unclosed range bracket")),
+        };
+
+        match self.next() {
+            Some(Spanned { token: Token::Word(w), .. }) if w == "TO" => {}
+            Some(Spanned { position, .. }) => return Err(syntax_error(position, "Expected 'TO' in range")),
+            None => return Err(syntax_error(open, "Unclosed range bracket")),
+        }
+
+        let end = match self.next() {
+            Some(Spanned { token: Token::Word(w), .. }) => w,
+            Some(Spanned { position, .. }) => return Err(syntax_error(position, "Missing range end")),
+            None => return Err(syntax_error(open, "Unclosed range bracket")),
+        };
+
+        match self.next() {
+            Some(Spanned { token: Token::RBracket, .. }) => Ok(QueryNode::Range(field, start, end)),
+            _ => Err(syntax_error(open, "Unclosed range bracket")),
+        }
+    }
+
+    fn term_node(word: String, position: usize) -> Result<QueryNode, AdvancedQueryError> {
+        if let Some((term, distance)) = word.split_once('~') {
+            let distance = if distance.is_empty() {
+                2
+            } else {
+                distance.parse::<u8>().map_err(|_| {
+                    let offset = term.chars().count() + 1;
+                    syntax_error(position + offset, format!("Invalid fuzzy distance '{}'", distance))
+                })?
+            };
+            if term.is_empty() {
+                return Err(syntax_error(position, "Fuzzy operator '~' requires a term"));
+            }
+            return Ok(QueryNode::Fuzzy(None, term.to_string(), distance));
+        }
+
+        if word.contains('*') || word.contains('?') {
+            return Ok(QueryNode::Wildcard(None, word));
+        }
+
+        Ok(QueryNode::Term(word))
+    }
+}
 
 pub struct AdvancedQueryParser;
 
@@ -60,35 +358,134 @@ impl AdvancedQueryParser {
         Self
     }
 
+    /// Parse a query string, reporting the first syntax error with its char position
     pub fn parse(&self, input: &str) -> Result<ParsedQuery, AdvancedQueryError> {
-        // For simplicity, we'll just parse a basic field:value query
-        // A real implementation would be much more complex
-        if input.is_empty() {
+        if input.trim().is_empty() {
             return Ok(ParsedQuery::new(
                 QueryNode::Term("".to_string()),
             ));
         }
 
-        // Try to parse as field:value
-        if let Some(pos) = input.find(':') {
-            let field = &input[..pos];
-            let value = &input[pos + 1..];
-            
-            // Handle quoted values
-            let value = if value.starts_with('"') && value.ends_with('"') && value.len() >= 2 {
-                &value[1..value.len() - 1]
-            } else {
-                value
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            input_len: input.chars().count(),
+        };
+
+        let ast = parser.parse_or()?;
+
+        if let Some(Spanned { token, position }) = parser.peek() {
+            let message = match token {
+                Token::RParen => "Unmatched closing parenthesis",
+                _ => "Unexpected token",
             };
-            
-            return Ok(ParsedQuery::new(
-                QueryNode::Field(field.to_string(), value.to_string()),
-            ));
+            return Err(syntax_error(*position, message));
+        }
+
+        Ok(ParsedQuery::new(ast))
+    }
+}
+
+impl Default for AdvancedQueryParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl QueryParserPort for AdvancedQueryParser {
+    async fn parse(&self, query: &str) -> Result<ParsedQueryInfo, AdvancedQueryError> {
+        let parsed = AdvancedQueryParser::parse(self, query)?;
+        Ok(parsed.to_info(query))
+    }
+
+    async fn validate(&self, query: &str) -> Result<bool, AdvancedQueryError> {
+        Ok(AdvancedQueryParser::parse(self, query).is_ok())
+    }
+
+    async fn get_stats(&self) -> Result<QueryParsingStats, AdvancedQueryError> {
+        Ok(QueryParsingStats {
+            total_parsed: 0,
+            parse_errors: 0,
+            avg_parse_time_ms: 0.0,
+            max_parse_time_ms: 0,
+            min_parse_time_ms: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_position(input: &str) -> (usize, String) {
+        match AdvancedQueryParser::new().parse(input) {
+            Err(AdvancedQueryError::SyntaxError { position, message }) => (position, message),
+            other => panic!("expected syntax error for {:?}, got {:?}", input, other),
         }
+    }
+
+    #[test]
+    fn parses_boolean_field_query() {
+        let parsed = AdvancedQueryParser::new()
+            .parse("name:foo AND (type:npm OR NOT legacy)")
+            .unwrap();
+
+        let info = parsed.to_info("name:foo AND (type:npm OR NOT legacy)");
+        assert_eq!(info.parsed_fields, vec!["name", "type"]);
+        assert!(info.boolean_operators.contains(&"AND".to_string()));
+        assert!(info.boolean_operators.contains(&"NOT".to_string()));
+    }
 
-        // Handle simple term
-        Ok(ParsedQuery::new(
-            QueryNode::Term(input.to_string()),
-        ))
+    #[test]
+    fn parses_range_wildcard_and_fuzzy() {
+        let parsed = AdvancedQueryParser::new().parse("size:[0 TO 10] log* lodash~1").unwrap();
+        let info = parsed.to_info("");
+        assert!(info.has_ranges && info.has_wildcards && info.has_fuzzy);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn wildcard_and_fuzzy_field_values_keep_their_field() {
+        let parsed = AdvancedQueryParser::new().parse("name:foo* AND group:lodsh~1").unwrap();
+
+        assert_eq!(
+            parsed.ast,
+            QueryNode::And(
+                Box::new(QueryNode::Wildcard(Some("name".to_string()), "foo*".to_string())),
+                Box::new(QueryNode::Fuzzy(Some("group".to_string()), "lodsh".to_string(), 1)),
+            )
+        );
+        assert_eq!(parsed.to_info("").parsed_fields, vec!["name", "group"]);
+    }
+
+    #[test]
+    fn reports_unclosed_parenthesis_position() {
+        let (position, message) = error_position("name:foo AND (bar OR baz");
+        assert_eq!(position, 13);
+        assert_eq!(message, "Unclosed parenthesis");
+    }
+
+    #[test]
+    fn reports_dangling_operator_position() {
+        let (position, message) = error_position("foo AND");
+        assert_eq!(position, 4);
+        assert!(message.contains("right-hand operand"));
+
+        let (position, _) = error_position("OR foo");
+        assert_eq!(position, 0);
+    }
+
+    #[test]
+    fn reports_unmatched_closing_parenthesis() {
+        let (position, _) = error_position("foo) bar");
+        assert_eq!(position, 3);
+    }
+
+    #[test]
+    fn positions_are_char_offsets_for_multibyte_input() {
+        // "café" is 4 chars but 5 bytes
+        let (position, _) = error_position("café ñandú AND");
+        assert_eq!(position, 11);
+    }
+}
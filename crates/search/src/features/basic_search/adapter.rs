@@ -7,6 +7,7 @@ use crate::features::basic_search::{
     dto::{SearchQuery, SearchResults, ArtifactDocument},
     error::BasicSearchError,
     ports::SearchIndexPort,
+    spelling::TermSuggestion,
     infrastructure::tantivy_index::TantivySearchIndex,
 };
 
@@ -105,4 +106,12 @@ impl SearchIndexPort for TantivySearchAdapter {
         debug!(page = page, page_size = page_size, "Getting all artifacts in Tantivy adapter");
         self.index.get_all_artifacts(page, page_size).await
     }
+
+    async fn similar_terms(
+        &self,
+        word: &str,
+        max_distance: u8,
+    ) -> Result<Vec<TermSuggestion>, BasicSearchError> {
+        self.index.similar_terms(word, max_distance).await
+    }
 }
\ No newline at end of file
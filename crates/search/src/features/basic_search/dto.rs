@@ -23,6 +23,9 @@ pub struct SearchResults {
     pub page: usize,
     pub page_size: usize,
     pub total_pages: usize,
+    /// "Did you mean" correction, only set when it would return more results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
 }
 
 impl SearchResults {
@@ -39,6 +42,12 @@ impl SearchResults {
             page,
             page_size,
             total_pages,
+            suggestion: None,
         }
     }
+
+    pub fn with_suggestion(mut self, suggestion: Option<String>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
 }
\ No newline at end of file
@@ -9,6 +9,7 @@ use tracing::{debug, info, error};
 use crate::features::basic_search::{
     dto::{SearchQuery, SearchResults, ArtifactDocument},
     error::BasicSearchError,
+    spelling::{edit_distance, TermSuggestion},
 };
 
 use super::{
@@ -148,4 +149,54 @@ impl TantivySearchIndex {
         
         Ok(SearchResults::new(Vec::new(), 0, page, page_size))
     }
-}
\ No newline at end of file
+    
+    /// Scan the name term dictionary for terms close to `word`, summing doc
+    /// frequencies across segments.
+    pub async fn similar_terms(&self, word: &str, max_distance: u8) -> Result<Vec<TermSuggestion>, BasicSearchError> {
+        let index_reader = {
+            let index = self.index.read()
+                .map_err(|e| BasicSearchError::SearchIndexError(format!("Failed to acquire index read lock: {}", e)))?;
+            index
+                .reader_builder()
+                .reload_policy(ReloadPolicy::OnCommitWithDelay)
+                .try_into()
+                .map_err(|e| BasicSearchError::SearchIndexError(format!("Failed to create index reader: {}", e)))?
+        };
+
+        let searcher = index_reader.searcher();
+        let word = word.to_lowercase();
+        let mut frequencies: std::collections::HashMap<String, u64> = std::collections::HashMap::new();
+
+        for segment_reader in searcher.segment_readers() {
+            let inverted_index = segment_reader
+                .inverted_index(self.schema.name_field())
+                .map_err(|e| BasicSearchError::SearchIndexError(format!("Failed to open term dictionary: {}", e)))?;
+            let mut terms = inverted_index
+                .terms()
+                .stream()
+                .map_err(|e| BasicSearchError::SearchIndexError(format!("Failed to stream terms: {}", e)))?;
+
+            while terms.advance() {
+                let Ok(term) = std::str::from_utf8(terms.key()) else {
+                    continue;
+                };
+                // Cheap length check before computing the full distance
+                if term.chars().count().abs_diff(word.chars().count()) > max_distance as usize {
+                    continue;
+                }
+                if edit_distance(&word, term) <= max_distance as usize {
+                    *frequencies.entry(term.to_string()).or_insert(0) += terms.value().doc_freq as u64;
+                }
+            }
+        }
+
+        Ok(frequencies
+            .into_iter()
+            .map(|(term, doc_freq)| TermSuggestion {
+                distance: edit_distance(&word, &term) as u8,
+                term,
+                doc_freq,
+            })
+            .collect())
+    }
+}
@@ -5,6 +5,7 @@ use crate::features::basic_search::{
     dto::{SearchQuery, SearchResults, ArtifactDocument},
     error::BasicSearchError,
     ports::{SearchIndexPort, EventPublisherPort},
+    spelling::{edit_distance, TermSuggestion},
 };
 
 // Mock search index adapter for testing
@@ -97,6 +98,32 @@ impl SearchIndexPort for MockSearchIndexAdapter {
         
         Ok(SearchResults::new(paginated, total_count, page, page_size))
     }
+
+    async fn similar_terms(
+        &self,
+        word: &str,
+        max_distance: u8,
+    ) -> Result<Vec<TermSuggestion>, BasicSearchError> {
+        let artifacts = self.artifacts.read().unwrap();
+        let mut frequencies: std::collections::BTreeMap<String, u64> = std::collections::BTreeMap::new();
+        for artifact in artifacts.iter() {
+            for term in artifact.name.to_lowercase().split_whitespace() {
+                *frequencies.entry(term.to_string()).or_insert(0) += 1;
+            }
+        }
+
+        Ok(frequencies
+            .into_iter()
+            .filter_map(|(term, doc_freq)| {
+                let distance = edit_distance(word, &term);
+                (distance <= max_distance as usize).then(|| TermSuggestion {
+                    term,
+                    doc_freq,
+                    distance: distance as u8,
+                })
+            })
+            .collect())
+    }
 }
 
 // Mock event publisher adapter for testing
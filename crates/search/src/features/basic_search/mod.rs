@@ -5,6 +5,7 @@ pub mod error;
 pub mod event_adapter;
 mod infrastructure;
 pub mod ports;
+pub mod spelling;
 pub mod mock;
 // Backward compatibility for existing tests expecting test_utils
 pub mod test_utils { pub use super::mock::*; }
@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use crate::features::basic_search::{
     dto::{SearchQuery, SearchResults, ArtifactDocument},
     error::BasicSearchError,
+    spelling::TermSuggestion,
 };
 
 #[async_trait]
@@ -21,6 +22,17 @@ pub trait SearchIndexPort: Send + Sync {
         page: usize,
         page_size: usize,
     ) -> Result<SearchResults, BasicSearchError>;
+
+    /// Indexed terms within `max_distance` edits of `word`, used for "did you mean".
+    ///
+    /// Indexes without a term dictionary return no candidates.
+    async fn similar_terms(
+        &self,
+        _word: &str,
+        _max_distance: u8,
+    ) -> Result<Vec<TermSuggestion>, BasicSearchError> {
+        Ok(Vec::new())
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,85 @@
+//! "Did you mean" support for basic search
+//!
+//! Candidates come from the index term dictionary; this module only decides
+//! which candidate (if any) is a plausible correction for a query word.
+
+/// Largest edit distance considered a typo
+pub const MAX_SUGGESTION_DISTANCE: u8 = 2;
+
+/// An indexed term close to a query word
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermSuggestion {
+    pub term: String,
+    /// Number of documents containing the term
+    pub doc_freq: u64,
+    /// Edit distance from the query word
+    pub distance: u8,
+}
+
+/// Levenshtein distance between two strings, counted in chars
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Pick the best correction for `word` among `candidates`.
+///
+/// Only terms that have hits, are within [`MAX_SUGGESTION_DISTANCE`] and differ
+/// from the word itself qualify; closer terms win, then more frequent ones.
+pub fn best_correction<'a>(word: &str, candidates: &'a [TermSuggestion]) -> Option<&'a TermSuggestion> {
+    candidates
+        .iter()
+        .filter(|c| c.doc_freq > 0 && c.distance > 0 && c.distance <= MAX_SUGGESTION_DISTANCE && c.term != word)
+        .min_by(|a, b| {
+            a.distance
+                .cmp(&b.distance)
+                .then(b.doc_freq.cmp(&a.doc_freq))
+                .then(a.term.cmp(&b.term))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(term: &str, doc_freq: u64, distance: u8) -> TermSuggestion {
+        TermSuggestion { term: term.to_string(), doc_freq, distance }
+    }
+
+    #[test]
+    fn computes_edit_distance() {
+        assert_eq!(edit_distance("lodash", "lodash"), 0);
+        assert_eq!(edit_distance("lodahs", "lodash"), 2);
+        assert_eq!(edit_distance("reqest", "request"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn prefers_closest_then_most_frequent() {
+        let candidates = vec![
+            candidate("react", 5, 2),
+            candidate("reach", 1, 1),
+            candidate("ready", 9, 1),
+        ];
+        assert_eq!(best_correction("reacy", &candidates).unwrap().term, "ready");
+    }
+
+    #[test]
+    fn never_suggests_the_same_word_or_terms_without_hits() {
+        let candidates = vec![candidate("lodash", 10, 0), candidate("lodesh", 0, 1)];
+        assert!(best_correction("lodash", &candidates).is_none());
+    }
+}
@@ -4,11 +4,17 @@ use crate::features::basic_search::{
     dto::{SearchQuery, SearchResults},
     error::BasicSearchError,
     ports::{SearchIndexPort, EventPublisherPort},
+    spelling::{best_correction, MAX_SUGGESTION_DISTANCE},
 };
 
+/// Queries returning fewer results than this are candidates for a "did you mean"
+const DEFAULT_SUGGESTION_THRESHOLD: usize = 3;
+
 pub struct BasicSearchUseCase {
     search_index: Arc<dyn SearchIndexPort>,
     event_publisher: Arc<dyn EventPublisherPort>,
+    enable_suggestions: bool,
+    suggestion_threshold: usize,
 }
 
 impl BasicSearchUseCase {
@@ -19,9 +25,18 @@ impl BasicSearchUseCase {
         Self {
             search_index,
             event_publisher,
+            enable_suggestions: true,
+            suggestion_threshold: DEFAULT_SUGGESTION_THRESHOLD,
         }
     }
 
+    /// Configure "did you mean" suggestions for low-result queries
+    pub fn with_suggestions(mut self, enabled: bool, threshold: usize) -> Self {
+        self.enable_suggestions = enabled;
+        self.suggestion_threshold = threshold;
+        self
+    }
+
     pub async fn execute(&self, query: SearchQuery) -> Result<SearchResults, BasicSearchError> {
         info!(query = %query.q, "Executing basic search");
         
@@ -41,7 +56,9 @@ impl BasicSearchUseCase {
             self.search_index.get_all_artifacts(page, page_size).await?
         } else {
             debug!(query = %normalized_query, "Performing search with query");
-            self.search_index.search(&search_query).await?
+            let results = self.search_index.search(&search_query).await?;
+            let suggestion = self.suggest(&search_query, results.total_count).await;
+            results.with_suggestion(suggestion)
         };
         
         // Publish search event
@@ -56,4 +73,106 @@ impl BasicSearchUseCase {
         info!(result_count = results.total_count, "Search completed successfully");
         Ok(results)
     }
+
+    /// Build a corrected query when the original returned few results.
+    ///
+    /// The correction is only returned if it actually finds more documents and
+    /// differs from what the user typed. Failures here never fail the search.
+    async fn suggest(&self, query: &SearchQuery, result_count: usize) -> Option<String> {
+        if !self.enable_suggestions || result_count >= self.suggestion_threshold {
+            return None;
+        }
+
+        let mut corrected_words = Vec::new();
+        let mut changed = false;
+
+        for word in query.q.split_whitespace() {
+            let candidates = match self.search_index.similar_terms(word, MAX_SUGGESTION_DISTANCE).await {
+                Ok(candidates) => candidates,
+                Err(e) => {
+                    debug!(error = %e, "Failed to look up similar terms");
+                    return None;
+                }
+            };
+
+            match best_correction(word, &candidates) {
+                Some(correction) => {
+                    corrected_words.push(correction.term.clone());
+                    changed = true;
+                }
+                None => corrected_words.push(word.to_string()),
+            }
+        }
+
+        let suggestion = corrected_words.join(" ");
+        if !changed || suggestion == query.q {
+            return None;
+        }
+
+        let corrected_query = SearchQuery {
+            q: suggestion.clone(),
+            page: Some(1),
+            page_size: Some(1),
+        };
+        match self.search_index.search(&corrected_query).await {
+            Ok(corrected) if corrected.total_count > result_count => {
+                debug!(query = %query.q, suggestion = %suggestion, "Suggesting corrected query");
+                Some(suggestion)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::basic_search::dto::ArtifactDocument;
+    use crate::features::basic_search::mock::{MockEventPublisherAdapter, MockSearchIndexAdapter};
+
+    async fn use_case_with(names: &[&str]) -> BasicSearchUseCase {
+        let index = Arc::new(MockSearchIndexAdapter::new());
+        for (i, name) in names.iter().enumerate() {
+            index.add_test_artifact(ArtifactDocument {
+                id: format!("artifact-{}", i),
+                name: name.to_string(),
+                version: "1.0.0".to_string(),
+                package_type: "npm".to_string(),
+                repository: "npm-public".to_string(),
+            }).await;
+        }
+        BasicSearchUseCase::new(index, Arc::new(MockEventPublisherAdapter::new()))
+    }
+
+    fn query(q: &str) -> SearchQuery {
+        SearchQuery { q: q.to_string(), page: None, page_size: None }
+    }
+
+    #[tokio::test]
+    async fn suggests_known_term_for_typo() {
+        let use_case = use_case_with(&["lodash", "express"]).await;
+
+        let results = use_case.execute(query("lodahs")).await.unwrap();
+
+        assert_eq!(results.total_count, 0);
+        assert_eq!(results.suggestion.as_deref(), Some("lodash"));
+    }
+
+    #[tokio::test]
+    async fn no_suggestion_when_query_has_enough_results() {
+        let use_case = use_case_with(&["lodash", "lodash", "lodash"]).await;
+
+        let results = use_case.execute(query("lodash")).await.unwrap();
+
+        assert!(results.suggestion.is_none());
+    }
+
+    #[tokio::test]
+    async fn no_suggestion_for_unrelated_terms() {
+        let use_case = use_case_with(&["lodash"]).await;
+
+        let results = use_case.execute(query("kubernetes")).await.unwrap();
+
+        assert!(results.suggestion.is_none());
+    }
 }
\ No newline at end of file
@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use std::sync::{Arc, RwLock};
 use tantivy::{
     collector::TopDocs,
+    directory::MmapDirectory,
     doc,
     query::{Query, QueryParser},
     schema::*,
@@ -19,12 +20,39 @@ use serde_json;
 use super::ports::*;
 use super::dto::*;
 use super::error::{IndexDocumentError, ToIndexDocumentError};
+use super::language::{
+    analyze, analyzer_for_language, analyzer_marker, register_language_analyzers,
+    LanguageAnalysisConfig, NO_STEMMING_ANALYZER,
+};
+
+/// Open the index at `path`, creating it if missing.
+///
+/// An index built with a different schema (e.g. before the language-analyzed
+/// fields existed) cannot take new documents, so it is recreated empty; the
+/// index only holds derived data and is refilled by reindexing.
+pub fn open_or_create_index_in_dir(path: &std::path::Path, schema: &Schema) -> tantivy::Result<Index> {
+    std::fs::create_dir_all(path)?;
+    if Index::exists(&MmapDirectory::open(path)?)? {
+        let index = Index::open_in_dir(path)?;
+        if index.schema() == *schema {
+            return Ok(index);
+        }
+
+        warn!(path = %path.display(), "Index schema is outdated, recreating the index; documents must be reindexed");
+        drop(index);
+        std::fs::remove_dir_all(path)?;
+        std::fs::create_dir_all(path)?;
+    }
+
+    Index::create_in_dir(path, schema.clone())
+}
 
 /// Tantivy-based document indexer adapter
 pub struct TantivyDocumentIndexer {
     index: Arc<RwLock<Index>>,
     index_writer: Arc<RwLock<IndexWriter>>,
     schema: Arc<DocumentIndexSchema>,
+    language_config: LanguageAnalysisConfig,
 }
 
 impl TantivyDocumentIndexer {
@@ -34,23 +62,14 @@ impl TantivyDocumentIndexer {
         let schema = Arc::new(DocumentIndexSchema::new());
         
         let index = match index_path {
-            Some(path) => {
-                if path.exists() {
-                    Index::open_in_dir(path)
-                        .map_err(|e| IndexDocumentError::Indexing { 
-                            source: IndexError::StorageError(format!("Failed to open index: {}", e)) 
-                        })?
-                } else {
-                    std::fs::create_dir_all(path)
-                        .map_err(|e| IndexDocumentError::storage(format!("Failed to create index directory: {}", e)))?;
-                    Index::create_in_dir(path, schema.schema.clone())
-                        .map_err(|e| IndexDocumentError::Indexing { 
-                            source: IndexError::StorageError(format!("Failed to create index: {}", e)) 
-                        })?
-                }
-            }
+            Some(path) => open_or_create_index_in_dir(path, &schema.schema)
+                .map_err(|e| IndexDocumentError::Indexing { 
+                    source: IndexError::StorageError(format!("Failed to open index: {}", e)) 
+                })?,
             None => Index::create_in_ram(schema.schema.clone()),
         };
+
+        register_language_analyzers(index.tokenizers());
         
         let index_writer = index
             .writer(50_000_000) // 50MB buffer
@@ -62,8 +81,23 @@ impl TantivyDocumentIndexer {
             index: Arc::new(RwLock::new(index)),
             index_writer: Arc::new(RwLock::new(index_writer)),
             schema,
+            language_config: LanguageAnalysisConfig::default(),
         })
     }
+
+    /// Select per-document / per-field language analysis at index time
+    pub fn with_language_analysis(mut self, config: LanguageAnalysisConfig) -> Self {
+        self.language_config = config;
+        self
+    }
+
+    fn build_document(&self, command: &IndexDocumentCommand) -> Result<TantivyDocument, IndexError> {
+        let tokenizers = self.index.read()
+            .map_err(|e| IndexError::StorageError(format!("Failed to acquire index read lock: {}", e)))?
+            .tokenizers()
+            .clone();
+        Ok(self.schema.to_document_with_analysis(command, &self.language_config, &tokenizers))
+    }
     /// Get a clone of the underlying Tantivy Index Arc for health monitor wiring
     pub fn index_arc(&self) -> Arc<RwLock<Index>> {
         self.index.clone()
@@ -91,7 +125,7 @@ impl DocumentIndexerPort for TantivyDocumentIndexer {
         
         let start_time = std::time::Instant::now();
         
-        let doc = self.schema.to_document(&command);
+        let doc = self.build_document(&command)?;
         
         {
             let mut writer = self.index_writer.write()
@@ -128,6 +162,7 @@ impl DocumentIndexerPort for TantivyDocumentIndexer {
         
         let start_time = std::time::Instant::now();
         let mut results = Vec::new();
+        let mut failures = Vec::new();
         let mut success_count = 0;
         let mut failure_count = 0;
         
@@ -138,7 +173,18 @@ impl DocumentIndexerPort for TantivyDocumentIndexer {
             for doc_command in command.documents {
                 let doc_start_time = std::time::Instant::now();
                 
-                let doc = self.schema.to_document(&doc_command);
+                let doc = match self.build_document(&doc_command) {
+                    Ok(doc) => doc,
+                    Err(e) => {
+                        error!(artifact_id = %doc_command.artifact_id, error = %e, "Failed to build document");
+                        failure_count += 1;
+                        failures.push(BatchItemFailure {
+                            document_id: doc_command.artifact_id,
+                            error: e.to_string(),
+                        });
+                        continue;
+                    }
+                };
                 
                 match writer.add_document(doc) {
                     Ok(_) => {
@@ -157,6 +203,10 @@ impl DocumentIndexerPort for TantivyDocumentIndexer {
                     Err(e) => {
                         error!(artifact_id = %doc_command.artifact_id, error = %e, "Failed to index document");
                         failure_count += 1;
+                        failures.push(BatchItemFailure {
+                            document_id: doc_command.artifact_id,
+                            error: e.to_string(),
+                        });
                     }
                 }
             }
@@ -187,6 +237,7 @@ impl DocumentIndexerPort for TantivyDocumentIndexer {
             total_time_ms,
             success_count,
             failure_count,
+            failures,
         })
     }
     
@@ -507,6 +558,14 @@ pub struct DocumentIndexSchema {
     pub tags_field: Field,
     pub language_field: Field,
     pub indexed_at_field: Field,
+    /// Kind of entity the document describes (see `DocumentType`)
+    pub doc_type_field: Field,
+    /// Language-analyzed copies of content, title and description (indexed only)
+    pub content_stemmed_field: Field,
+    pub title_stemmed_field: Field,
+    pub description_stemmed_field: Field,
+    /// Records `field:analyzer` for each language-analyzed field of a document
+    pub analyzer_field: Field,
 }
 
 impl DocumentIndexSchema {
@@ -523,6 +582,18 @@ impl DocumentIndexSchema {
         let tags_field = schema_builder.add_text_field("tags", TEXT | STORED);
         let language_field = schema_builder.add_text_field("language", STRING | STORED);
         let indexed_at_field = schema_builder.add_date_field("indexed_at", INDEXED | STORED);
+        let doc_type_field = schema_builder.add_text_field("doc_type", STRING | STORED);
+
+        // Stemmed fields receive pre-tokenized text, the tokenizer below is only a fallback
+        let stemmed_options = TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(NO_STEMMING_ANALYZER)
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        );
+        let content_stemmed_field = schema_builder.add_text_field("content_stemmed", stemmed_options.clone());
+        let title_stemmed_field = schema_builder.add_text_field("title_stemmed", stemmed_options.clone());
+        let description_stemmed_field = schema_builder.add_text_field("description_stemmed", stemmed_options);
+        let analyzer_field = schema_builder.add_text_field("analyzer", STRING | STORED);
         
         let schema = schema_builder.build();
         
@@ -537,6 +608,11 @@ impl DocumentIndexSchema {
             tags_field,
             language_field,
             indexed_at_field,
+            doc_type_field,
+            content_stemmed_field,
+            title_stemmed_field,
+            description_stemmed_field,
+            analyzer_field,
         }
     }
     /// Convenience alias used by DI
@@ -559,9 +635,56 @@ impl DocumentIndexSchema {
             self.tags_field => command.metadata.tags.join(" "),
             self.language_field => command.language.clone().unwrap_or_else(|| "en".to_string()),
             self.indexed_at_field => tantivy::DateTime::from_utc(time::OffsetDateTime::now_utc()),
+            self.doc_type_field => command.doc_type.as_str(),
         }
     }
     
+    /// Language-analyzed fields as `(name, stemmed field)` pairs
+    pub fn stemmed_fields(&self) -> [(&'static str, Field); 3] {
+        [
+            ("content", self.content_stemmed_field),
+            ("title", self.title_stemmed_field),
+            ("description", self.description_stemmed_field),
+        ]
+    }
+
+    /// Build a document whose text fields are also analyzed for their language.
+    ///
+    /// The analyzer chosen for each field is recorded so queries can be analyzed
+    /// with the same chain.
+    pub fn to_document_with_analysis(
+        &self,
+        command: &IndexDocumentCommand,
+        config: &LanguageAnalysisConfig,
+        tokenizers: &TokenizerManager,
+    ) -> TantivyDocument {
+        let mut doc = self.to_document(command);
+
+        let sources = [
+            command.content.clone(),
+            command.metadata.title.clone().unwrap_or_default(),
+            command.metadata.description.clone().unwrap_or_default(),
+        ];
+
+        for ((field_name, stemmed_field), text) in self.stemmed_fields().into_iter().zip(sources) {
+            let language = config.language_for_field(field_name, command.language.as_deref());
+            let analyzer = analyzer_for_language(language);
+            if analyzer == NO_STEMMING_ANALYZER && language.is_some() {
+                debug!(
+                    artifact_id = %command.artifact_id,
+                    field = field_name,
+                    language = ?language,
+                    "Unsupported language, indexing without stemming"
+                );
+            }
+
+            doc.add_pre_tokenized_text(stemmed_field, analyze(tokenizers, analyzer, &text));
+            doc.add_text(self.analyzer_field, analyzer_marker(field_name, analyzer));
+        }
+
+        doc
+    }
+    
     pub fn from_document(&self, doc: &TantivyDocument) -> Option<IndexedDocumentInfo> {
         let artifact_id = doc.get_first(self.artifact_id_field)
             .and_then(|v| v.as_str())
@@ -591,6 +714,10 @@ impl DocumentIndexSchema {
             .and_then(|v| v.as_str())
             .map(|s| s.split(' ').map(|t| t.to_string()).collect())
             .unwrap_or_default();
+
+        let doc_type = DocumentType::from_stored(
+            doc.get_first(self.doc_type_field).and_then(|v| v.as_str()),
+        );
             
         let metadata = ArtifactMetadata {
             title,
@@ -610,6 +737,7 @@ impl DocumentIndexSchema {
             token_count: 0, // TODO: Calculate actual token count
             status: IndexingStatus::Completed,
             last_indexed_at: chrono::Utc::now(),
+            doc_type,
         })
     }
 }
@@ -675,6 +803,7 @@ pub mod test {
                 total_time_ms: 100,
                 success_count,
                 failure_count: 0,
+                failures: Vec::new(),
             })
         }
         
@@ -703,6 +832,7 @@ pub mod test {
                     token_count: cmd.content.split_whitespace().count(),
                     status: IndexingStatus::Completed,
                     last_indexed_at: chrono::Utc::now(),
+                    doc_type: cmd.doc_type,
                 })
                 .collect();
             
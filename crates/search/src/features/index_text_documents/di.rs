@@ -9,6 +9,7 @@ use super::use_case::*;
 use super::adapter::*;
 // no REST exposure from features
 use super::error::*;
+use super::language::LanguageAnalysisConfig;
 
 /// Dependency injection container for index text documents feature
 pub struct IndexTextDocumentsDIContainer {
@@ -25,6 +26,21 @@ impl IndexTextDocumentsDIContainer {
         document_indexer: Arc<dyn DocumentIndexerPort>,
         text_analyzer: Arc<dyn TextAnalyzerPort>,
         health_monitor: Arc<dyn IndexHealthMonitorPort>,
+    ) -> Self {
+        Self::new_with_batch_limits(
+            document_indexer,
+            text_analyzer,
+            health_monitor,
+            BatchIndexingLimits::default(),
+        )
+    }
+
+    /// Create a new container whose batch use case applies the given limits
+    pub fn new_with_batch_limits(
+        document_indexer: Arc<dyn DocumentIndexerPort>,
+        text_analyzer: Arc<dyn TextAnalyzerPort>,
+        health_monitor: Arc<dyn IndexHealthMonitorPort>,
+        batch_limits: BatchIndexingLimits,
     ) -> Self {
         // No-op implementations for missing ports
         struct NoopIndexSchemaManager;
@@ -124,7 +140,7 @@ impl IndexTextDocumentsDIContainer {
             health_monitor.clone(),
             schema_manager.clone(),
             validator.clone(),
-        ));
+        ).with_batch_limits(batch_limits));
         
         let state = IndexTextDocumentsState {
             document_use_case: document_use_case.clone(),
@@ -145,8 +161,11 @@ impl IndexTextDocumentsDIContainer {
     /// Create container for production environment with file-based index
     pub fn for_production_with_file_index(
         index_path: &std::path::Path,
+        language_analysis: LanguageAnalysisConfig,
     ) -> Result<Self, IndexDocumentError> {
-        let document_indexer = Arc::new(TantivyDocumentIndexer::new(Some(index_path))?);
+        let document_indexer = Arc::new(
+            TantivyDocumentIndexer::new(Some(index_path))?.with_language_analysis(language_analysis),
+        );
         let text_analyzer = Arc::new(BasicTextAnalyzer::new());
         let health_monitor = Arc::new(BasicIndexHealthMonitor::new(document_indexer.index_arc()));
         
@@ -154,8 +173,10 @@ impl IndexTextDocumentsDIContainer {
     }
     
     /// Create container for production environment with in-memory index
-    pub fn for_production_with_memory_index() -> Result<Self, IndexDocumentError> {
-        let document_indexer = Arc::new(TantivyDocumentIndexer::new(None)?);
+    pub fn for_production_with_memory_index(
+        language_analysis: LanguageAnalysisConfig,
+    ) -> Result<Self, IndexDocumentError> {
+        let document_indexer = Arc::new(TantivyDocumentIndexer::new(None)?.with_language_analysis(language_analysis));
         let text_analyzer = Arc::new(BasicTextAnalyzer::new());
         let health_monitor = Arc::new(BasicIndexHealthMonitor::new(document_indexer.index_arc()));
         
@@ -218,6 +239,7 @@ pub struct IndexTextDocumentsDIContainerBuilder {
     document_indexer: Option<Arc<dyn DocumentIndexerPort>>,
     text_analyzer: Option<Arc<dyn TextAnalyzerPort>>,
     health_monitor: Option<Arc<dyn IndexHealthMonitorPort>>,
+    language_analysis: LanguageAnalysisConfig,
 }
 
 impl IndexTextDocumentsDIContainerBuilder {
@@ -227,6 +249,7 @@ impl IndexTextDocumentsDIContainerBuilder {
             document_indexer: None,
             text_analyzer: None,
             health_monitor: None,
+            language_analysis: LanguageAnalysisConfig::default(),
         }
     }
     
@@ -248,6 +271,12 @@ impl IndexTextDocumentsDIContainerBuilder {
         self
     }
     
+    /// Set the language analysis used by the default document indexer
+    pub fn with_language_analysis(mut self, config: LanguageAnalysisConfig) -> Self {
+        self.language_analysis = config;
+        self
+    }
+    
     /// Build the container
     pub fn build(self) -> Result<IndexTextDocumentsDIContainer, IndexDocumentError> {
         let document_indexer = self.document_indexer.ok_or_else(|| {
@@ -272,7 +301,13 @@ impl IndexTextDocumentsDIContainerBuilder {
     /// Build with production defaults (file-based index)
     pub fn build_with_production_defaults(self, index_path: &std::path::Path) -> Result<IndexTextDocumentsDIContainer, IndexDocumentError> {
         let document_indexer = self.document_indexer
-            .unwrap_or_else(|| Arc::new(TantivyDocumentIndexer::new(Some(index_path)).unwrap()));
+            .unwrap_or_else(|| {
+                Arc::new(
+                    TantivyDocumentIndexer::new(Some(index_path))
+                        .unwrap()
+                        .with_language_analysis(self.language_analysis.clone()),
+                )
+            });
         
         let text_analyzer = self.text_analyzer
             .unwrap_or_else(|| Arc::new(BasicTextAnalyzer::new()));
@@ -290,7 +325,13 @@ impl IndexTextDocumentsDIContainerBuilder {
     /// Build with production defaults (in-memory index)
     pub fn build_with_memory_defaults(self) -> Result<IndexTextDocumentsDIContainer, IndexDocumentError> {
         let document_indexer = self.document_indexer
-            .unwrap_or_else(|| Arc::new(TantivyDocumentIndexer::new(None).unwrap()));
+            .unwrap_or_else(|| {
+                Arc::new(
+                    TantivyDocumentIndexer::new(None)
+                        .unwrap()
+                        .with_language_analysis(self.language_analysis.clone()),
+                )
+            });
         
         let text_analyzer = self.text_analyzer
             .unwrap_or_else(|| Arc::new(BasicTextAnalyzer::new()));
@@ -338,8 +379,10 @@ impl Default for IndexTextDocumentsDIContainerBuilder {
 pub struct IndexTextDocumentsConfig {
     /// Path to the index directory (None for in-memory index)
     pub index_path: Option<std::path::PathBuf>,
-    /// Maximum number of concurrent indexing operations
+    /// Maximum number of concurrent indexing operations (zero is rejected)
     pub max_concurrent_operations: Option<usize>,
+    /// Number of documents written per index commit during batch indexing
+    pub batch_commit_size: usize,
     /// Index buffer size in bytes
     pub index_buffer_size: usize,
     /// Whether to enable text analysis features
@@ -348,6 +391,9 @@ pub struct IndexTextDocumentsConfig {
     pub enable_health_monitoring: bool,
     /// Timeout for indexing operations in milliseconds
     pub indexing_timeout_ms: u64,
+    /// Language analysis applied at index time; must match the search side's
+    /// `SearchFeatureConfig::language_analysis`
+    pub language_analysis: LanguageAnalysisConfig,
 }
 
 impl Default for IndexTextDocumentsConfig {
@@ -355,10 +401,12 @@ impl Default for IndexTextDocumentsConfig {
         Self {
             index_path: None,
             max_concurrent_operations: Some(10),
+            batch_commit_size: DEFAULT_BATCH_COMMIT_SIZE,
             index_buffer_size: 50_000_000, // 50MB
             enable_text_analysis: true,
             enable_health_monitoring: true,
             indexing_timeout_ms: 30000, // 30 seconds
+            language_analysis: LanguageAnalysisConfig::default(),
         }
     }
 }
@@ -385,18 +433,31 @@ impl IndexTextDocumentsConfig {
         Self {
             index_path: None,
             max_concurrent_operations: Some(2),
+            batch_commit_size: 10,
             index_buffer_size: 1_000_000, // 1MB
             enable_text_analysis: true,
             enable_health_monitoring: true,
             indexing_timeout_ms: 1000, // 1 second
+            language_analysis: LanguageAnalysisConfig::default(),
         }
     }
     
+    /// Batch indexing limits described by this configuration
+    pub fn batch_limits(&self) -> Result<BatchIndexingLimits, IndexDocumentError> {
+        BatchIndexingLimits::new(
+            self.max_concurrent_operations.unwrap_or(DEFAULT_BATCH_CONCURRENCY),
+            self.batch_commit_size,
+        )
+    }
+
     /// Create DI container from this configuration
     pub fn create_container(self) -> Result<IndexTextDocumentsDIContainer, IndexDocumentError> {
-        let document_indexer = Arc::new(TantivyDocumentIndexer::new(
-            self.index_path.as_deref()
-        )?);
+        let batch_limits = self.batch_limits()?;
+        
+        let document_indexer = Arc::new(
+            TantivyDocumentIndexer::new(self.index_path.as_deref())?
+                .with_language_analysis(self.language_analysis.clone()),
+        );
         
         let text_analyzer = if self.enable_text_analysis {
             Arc::new(BasicTextAnalyzer::new()) as Arc<dyn TextAnalyzerPort>
@@ -412,10 +473,11 @@ impl IndexTextDocumentsConfig {
             todo!("Implement no-op health monitor")
         };
         
-        Ok(IndexTextDocumentsDIContainer::new(
+        Ok(IndexTextDocumentsDIContainer::new_with_batch_limits(
             document_indexer,
             text_analyzer,
             health_monitor,
+            batch_limits,
         ))
     }
 }
@@ -463,6 +525,39 @@ mod tests {
         assert!(config.enable_health_monitoring);
     }
     
+    #[test]
+    fn test_config_rejects_zero_concurrency() {
+        let config = IndexTextDocumentsConfig {
+            max_concurrent_operations: Some(0),
+            ..IndexTextDocumentsConfig::testing()
+        };
+        
+        assert!(matches!(config.batch_limits(), Err(IndexDocumentError::Configuration(_))));
+        assert!(config.create_container().is_err());
+    }
+    
+    #[test]
+    fn test_config_batch_limits() {
+        let limits = IndexTextDocumentsConfig::testing().batch_limits().unwrap();
+        assert_eq!(limits.max_concurrency, 2);
+        assert_eq!(limits.commit_batch_size, 10);
+    }
+    
+    #[test]
+    fn test_file_index_with_outdated_schema_is_recreated() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut outdated = tantivy::schema::Schema::builder();
+        outdated.add_text_field("content", tantivy::schema::TEXT);
+        tantivy::Index::create_in_dir(temp_dir.path(), outdated.build()).unwrap();
+        
+        let expected = DocumentIndexSchema::create_tantivy_schema();
+        let index = open_or_create_index_in_dir(temp_dir.path(), &expected).unwrap();
+        assert!(index.schema() == expected);
+        
+        let reopened = open_or_create_index_in_dir(temp_dir.path(), &expected).unwrap();
+        assert!(reopened.schema() == expected);
+    }
+    
     #[test]
     fn test_config_testing() {
         let config = IndexTextDocumentsConfig::testing();
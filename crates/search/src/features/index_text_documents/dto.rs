@@ -20,6 +20,44 @@ pub struct IndexDocumentCommand {
     pub language: Option<String>,
     /// Whether to force re-indexing if already exists
     pub force_reindex: bool,
+    /// Kind of entity the document describes
+    #[serde(default)]
+    pub doc_type: DocumentType,
+}
+
+/// Kind of entity an indexed document describes.
+///
+/// Artifacts and repositories share the same index and text fields, so a single
+/// query ranks both kinds with the same BM25 statistics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentType {
+    /// Artifact metadata and content (documents indexed without a type are artifacts)
+    #[default]
+    Artifact,
+    /// Repository name and description
+    Repository,
+}
+
+impl DocumentType {
+    /// All document types, in display order
+    pub const ALL: [DocumentType; 2] = [DocumentType::Artifact, DocumentType::Repository];
+
+    /// Value stored in the `doc_type` index field
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentType::Artifact => "artifact",
+            DocumentType::Repository => "repository",
+        }
+    }
+
+    /// Parse a stored `doc_type` value; unknown or missing values are artifacts
+    pub fn from_stored(value: Option<&str>) -> Self {
+        match value {
+            Some("repository") => DocumentType::Repository,
+            _ => DocumentType::Artifact,
+        }
+    }
 }
 
 /// Response after successful document indexing
@@ -61,6 +99,18 @@ pub struct BatchIndexResponse {
     pub success_count: usize,
     /// Number of failed documents
     pub failure_count: usize,
+    /// Documents that could not be indexed and why
+    #[serde(default)]
+    pub failures: Vec<BatchItemFailure>,
+}
+
+/// A document rejected during a batch operation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchItemFailure {
+    /// Identifier of the document that failed
+    pub document_id: String,
+    /// Reason the document was not indexed
+    pub error: String,
 }
 
 /// Command to remove a document from index
@@ -147,6 +197,9 @@ pub struct IndexedDocumentInfo {
     pub status: IndexingStatus,
     /// Last indexed timestamp
     pub last_indexed_at: chrono::DateTime<chrono::Utc>,
+    /// Kind of entity the document describes
+    #[serde(default)]
+    pub doc_type: DocumentType,
 }
 
 /// Status of indexing operations
@@ -298,6 +351,39 @@ impl IndexDocumentCommand {
             metadata: ArtifactMetadata::test_data(),
             language: Some("en".to_string()),
             force_reindex: false,
+            doc_type: DocumentType::Artifact,
+        }
+    }
+
+    /// Build the command that indexes a repository for unified search.
+    ///
+    /// The repository name goes to the title field and its description to both
+    /// description and content, so repositories are matched and ranked through
+    /// the same fields as artifacts.
+    pub fn for_repository(
+        repository_hrn: impl Into<String>,
+        name: impl Into<String>,
+        description: Option<String>,
+        format: impl Into<String>,
+        tags: Vec<String>,
+    ) -> Self {
+        let now = chrono::Utc::now();
+        Self {
+            artifact_id: repository_hrn.into(),
+            content: description.clone().unwrap_or_default(),
+            metadata: ArtifactMetadata {
+                title: Some(name.into()),
+                description,
+                tags,
+                artifact_type: format.into(),
+                version: String::new(),
+                custom_metadata: HashMap::new(),
+                created_at: now,
+                updated_at: now,
+            },
+            language: None,
+            force_reindex: true,
+            doc_type: DocumentType::Repository,
         }
     }
 }
\ No newline at end of file
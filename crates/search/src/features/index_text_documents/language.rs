@@ -0,0 +1,181 @@
+//! Language-aware text analysis
+//!
+//! Each supported language gets its own Tantivy analyzer chain
+//! (tokenize -> drop long tokens -> lowercase -> stem). Documents are analyzed
+//! with the chain selected for their language and the chosen analyzer is stored
+//! alongside the document, so queries can be analyzed the same way. Unknown or
+//! unsupported language codes fall back to a chain without stemming.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use tantivy::tokenizer::{
+    Language, LowerCaser, PreTokenizedString, RemoveLongFilter, SimpleTokenizer, Stemmer,
+    TextAnalyzer, Token, TokenizerManager,
+};
+
+/// Analyzer used when no stemmer is available for a language
+pub const NO_STEMMING_ANALYZER: &str = "lang_none";
+
+/// Tokens longer than this are dropped (usually hashes or encoded blobs)
+const MAX_TOKEN_LENGTH: usize = 40;
+
+/// Supported language codes and their stemmers
+const STEMMED_LANGUAGES: &[(&str, &str, Language)] = &[
+    ("ar", "lang_ar", Language::Arabic),
+    ("da", "lang_da", Language::Danish),
+    ("de", "lang_de", Language::German),
+    ("el", "lang_el", Language::Greek),
+    ("en", "lang_en", Language::English),
+    ("es", "lang_es", Language::Spanish),
+    ("fi", "lang_fi", Language::Finnish),
+    ("fr", "lang_fr", Language::French),
+    ("hu", "lang_hu", Language::Hungarian),
+    ("it", "lang_it", Language::Italian),
+    ("nl", "lang_nl", Language::Dutch),
+    ("no", "lang_no", Language::Norwegian),
+    ("pt", "lang_pt", Language::Portuguese),
+    ("ro", "lang_ro", Language::Romanian),
+    ("ru", "lang_ru", Language::Russian),
+    ("sv", "lang_sv", Language::Swedish),
+    ("ta", "lang_ta", Language::Tamil),
+    ("tr", "lang_tr", Language::Turkish),
+];
+
+/// Language settings for indexing and query analysis
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LanguageAnalysisConfig {
+    /// Language used when a document doesn't declare one
+    pub default_language: Option<String>,
+    /// Fixed language per field (e.g. "title" -> "en"), overriding the document language
+    pub field_languages: HashMap<String, String>,
+}
+
+impl LanguageAnalysisConfig {
+    /// Language that applies to `field` of a document declaring `document_language`
+    pub fn language_for_field<'a>(&'a self, field: &str, document_language: Option<&'a str>) -> Option<&'a str> {
+        self.field_languages
+            .get(field)
+            .map(String::as_str)
+            .or(document_language)
+            .or(self.default_language.as_deref())
+    }
+}
+
+/// Name of the analyzer registered for a language code
+///
+/// Region subtags are ignored (`es-MX` uses the Spanish analyzer); anything
+/// unsupported maps to [`NO_STEMMING_ANALYZER`].
+pub fn analyzer_for_language(code: Option<&str>) -> &'static str {
+    let Some(code) = code else {
+        return NO_STEMMING_ANALYZER;
+    };
+
+    let primary = code
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    STEMMED_LANGUAGES
+        .iter()
+        .find(|(lang, _, _)| *lang == primary)
+        .map(|(_, analyzer, _)| *analyzer)
+        .unwrap_or(NO_STEMMING_ANALYZER)
+}
+
+/// All analyzer names, including the no-stemming fallback
+pub fn all_analyzers() -> impl Iterator<Item = &'static str> {
+    STEMMED_LANGUAGES
+        .iter()
+        .map(|(_, analyzer, _)| *analyzer)
+        .chain(std::iter::once(NO_STEMMING_ANALYZER))
+}
+
+/// Register every language analyzer with the index tokenizer manager
+pub fn register_language_analyzers(manager: &TokenizerManager) {
+    for (_, name, language) in STEMMED_LANGUAGES {
+        let analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(MAX_TOKEN_LENGTH))
+            .filter(LowerCaser)
+            .filter(Stemmer::new(*language))
+            .build();
+        manager.register(name, analyzer);
+    }
+
+    let fallback = TextAnalyzer::builder(SimpleTokenizer::default())
+        .filter(RemoveLongFilter::limit(MAX_TOKEN_LENGTH))
+        .filter(LowerCaser)
+        .build();
+    manager.register(NO_STEMMING_ANALYZER, fallback);
+}
+
+/// Run `text` through the named analyzer
+///
+/// Falls back to the no-stemming analyzer when `analyzer` isn't registered.
+pub fn analyze(manager: &TokenizerManager, analyzer: &str, text: &str) -> PreTokenizedString {
+    let mut text_analyzer = manager
+        .get(analyzer)
+        .or_else(|| manager.get(NO_STEMMING_ANALYZER))
+        .unwrap_or_else(|| TextAnalyzer::from(SimpleTokenizer::default()));
+
+    let mut tokens = Vec::new();
+    let mut stream = text_analyzer.token_stream(text);
+    stream.process(&mut |token: &Token| tokens.push(token.clone()));
+
+    PreTokenizedString {
+        text: text.to_string(),
+        tokens,
+    }
+}
+
+/// Value stored in the analyzer field recording which analyzer indexed a field
+pub fn analyzer_marker(field: &str, analyzer: &str) -> String {
+    format!("{}:{}", field, analyzer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(analyzer: &str, text: &str) -> Vec<String> {
+        let manager = TokenizerManager::default();
+        register_language_analyzers(&manager);
+        analyze(&manager, analyzer, text)
+            .tokens
+            .into_iter()
+            .map(|t| t.text)
+            .collect()
+    }
+
+    #[test]
+    fn selects_analyzer_by_language_code() {
+        assert_eq!(analyzer_for_language(Some("es")), "lang_es");
+        assert_eq!(analyzer_for_language(Some("es-MX")), "lang_es");
+        assert_eq!(analyzer_for_language(Some("EN")), "lang_en");
+    }
+
+    #[test]
+    fn unknown_language_falls_back_to_no_stemming() {
+        assert_eq!(analyzer_for_language(Some("xx")), NO_STEMMING_ANALYZER);
+        assert_eq!(analyzer_for_language(None), NO_STEMMING_ANALYZER);
+        assert_eq!(terms(NO_STEMMING_ANALYZER, "Running Libraries"), vec!["running", "libraries"]);
+    }
+
+    #[test]
+    fn spanish_stemming_matches_inflections() {
+        assert_eq!(terms("lang_es", "bibliotecas"), terms("lang_es", "biblioteca"));
+    }
+
+    #[test]
+    fn field_language_overrides_document_language() {
+        let config = LanguageAnalysisConfig {
+            default_language: Some("en".to_string()),
+            field_languages: HashMap::from([("title".to_string(), "en".to_string())]),
+        };
+
+        assert_eq!(config.language_for_field("title", Some("es")), Some("en"));
+        assert_eq!(config.language_for_field("content", Some("es")), Some("es"));
+        assert_eq!(config.language_for_field("content", None), Some("en"));
+    }
+}
@@ -9,6 +9,8 @@ pub mod error;
 pub mod use_case;
 pub mod adapter;
 pub mod di;
+pub mod synonyms;
+pub mod language;
 
 // Re-export commonly used types and structures
 pub use dto::*;
@@ -16,7 +18,7 @@ pub use error::*;
 pub use di::*;
 
 // Re-export use cases
-pub use use_case::IndexDocumentUseCase;
+pub use use_case::{IndexDocumentUseCase, BatchIndexingLimits};
 
 // Re-export DI container and configuration
 pub use di::{IndexTextDocumentsDIContainer, IndexTextDocumentsDIContainerBuilder, IndexTextDocumentsConfig};
@@ -24,12 +26,20 @@ pub use di::{IndexTextDocumentsDIContainer, IndexTextDocumentsDIContainerBuilder
 // Re-export ports
 pub use ports::*;
 
+// Re-export query-time synonym expansion
+pub use synonyms::SynonymMap;
+
+// Re-export language analysis configuration
+pub use language::LanguageAnalysisConfig;
+
 // Feature initialization function
 pub fn initialize_feature() -> Result<IndexTextDocumentsDIContainer, IndexDocumentError> {
     tracing::info!("Initializing Index Text Documents feature");
     
     // Use in-memory index by default
-    let container = IndexTextDocumentsDIContainer::for_production_with_memory_index()?;
+    let container = IndexTextDocumentsDIContainer::for_production_with_memory_index(
+        LanguageAnalysisConfig::default(),
+    )?;
     
     tracing::info!("Index Text Documents feature initialized successfully");
     Ok(container)
@@ -0,0 +1,207 @@
+//! Query-time synonym expansion
+//!
+//! Synonyms are applied when a query is executed rather than when documents are
+//! indexed, so changing the synonym map never requires a reindex. Expansion walks
+//! the synonym graph breadth-first and visits each term once, which keeps cyclic
+//! mappings (`auth -> authentication -> auth`) from expanding forever.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Default weight applied to a direct synonym relative to the original term
+pub const DEFAULT_SYNONYM_BOOST: f32 = 0.5;
+
+/// Default number of hops followed through the synonym graph
+pub const DEFAULT_MAX_EXPANSION_DEPTH: usize = 2;
+
+/// A query term produced by synonym expansion
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpandedTerm {
+    /// Words of the term; more than one word means it must be matched as a phrase
+    pub words: Vec<String>,
+    /// Relative weight; the original term is always 1.0
+    pub boost: f32,
+    /// Whether this is the term the user typed
+    pub is_original: bool,
+}
+
+impl ExpandedTerm {
+    pub fn text(&self) -> String {
+        self.words.join(" ")
+    }
+}
+
+/// A run of query tokens together with all of its expansions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpandedClause {
+    pub original: String,
+    pub alternatives: Vec<ExpandedTerm>,
+}
+
+/// Case-insensitive synonym graph
+#[derive(Debug, Clone)]
+pub struct SynonymMap {
+    entries: HashMap<String, Vec<String>>,
+    boost: f32,
+    max_depth: usize,
+    longest_key_words: usize,
+}
+
+impl Default for SynonymMap {
+    fn default() -> Self {
+        Self::new(HashMap::new())
+    }
+}
+
+impl SynonymMap {
+    /// Build a synonym map; keys and values may contain several words
+    pub fn new(entries: HashMap<String, Vec<String>>) -> Self {
+        let entries: HashMap<String, Vec<String>> = entries
+            .into_iter()
+            .map(|(key, values)| {
+                let values = values.iter().map(|v| normalize(v)).filter(|v| !v.is_empty()).collect();
+                (normalize(&key), values)
+            })
+            .filter(|(key, _)| !key.is_empty())
+            .collect();
+
+        let longest_key_words = entries
+            .keys()
+            .map(|k| k.split(' ').count())
+            .max()
+            .unwrap_or(1);
+
+        Self {
+            entries,
+            boost: DEFAULT_SYNONYM_BOOST,
+            max_depth: DEFAULT_MAX_EXPANSION_DEPTH,
+            longest_key_words,
+        }
+    }
+
+    /// Weight of a direct synonym; each further hop multiplies it again
+    pub fn with_boost(mut self, boost: f32) -> Self {
+        self.boost = boost.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Expand a single (possibly multi-word) term into itself plus its synonyms
+    pub fn expand(&self, term: &str) -> Vec<ExpandedTerm> {
+        let start = normalize(term);
+        let mut visited: HashSet<String> = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([(start.clone(), 0usize)]);
+        let mut expanded = vec![ExpandedTerm {
+            words: split_words(&start),
+            boost: 1.0,
+            is_original: true,
+        }];
+
+        while let Some((current, depth)) = queue.pop_front() {
+            if depth >= self.max_depth {
+                continue;
+            }
+            let Some(synonyms) = self.entries.get(&current) else {
+                continue;
+            };
+
+            for synonym in synonyms {
+                if !visited.insert(synonym.clone()) {
+                    continue;
+                }
+                expanded.push(ExpandedTerm {
+                    words: split_words(synonym),
+                    boost: self.boost.powi(depth as i32 + 1),
+                    is_original: false,
+                });
+                queue.push_back((synonym.clone(), depth + 1));
+            }
+        }
+
+        expanded
+    }
+
+    /// Expand a tokenized query, matching the longest multi-word key first
+    pub fn expand_query(&self, tokens: &[String]) -> Vec<ExpandedClause> {
+        let mut clauses = Vec::new();
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let max_len = self.longest_key_words.min(tokens.len() - i);
+            let matched_len = (1..=max_len)
+                .rev()
+                .find(|len| self.entries.contains_key(&normalize(&tokens[i..i + len].join(" "))))
+                .unwrap_or(1);
+
+            let original = tokens[i..i + matched_len].join(" ");
+            clauses.push(ExpandedClause {
+                alternatives: self.expand(&original),
+                original,
+            });
+            i += matched_len;
+        }
+
+        clauses
+    }
+}
+
+fn normalize(text: &str) -> String {
+    split_words(text).join(" ")
+}
+
+fn split_words(text: &str) -> Vec<String> {
+    text.split_whitespace().map(|w| w.to_lowercase()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(pairs: &[(&str, &[&str])]) -> SynonymMap {
+        SynonymMap::new(
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.iter().map(|s| s.to_string()).collect()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn original_term_scores_higher_than_synonyms() {
+        let synonyms = map(&[("auth", &["authentication"])]);
+        let expanded = synonyms.expand("Auth");
+
+        assert_eq!(expanded[0].text(), "auth");
+        assert!(expanded[0].is_original);
+        assert_eq!(expanded[1].text(), "authentication");
+        assert!(expanded[1].boost < expanded[0].boost);
+    }
+
+    #[test]
+    fn cycles_do_not_expand_forever() {
+        let synonyms = map(&[("auth", &["authentication"]), ("authentication", &["auth", "login"])])
+            .with_max_depth(10);
+        let texts: Vec<String> = synonyms.expand("auth").iter().map(ExpandedTerm::text).collect();
+
+        assert_eq!(texts, vec!["auth", "authentication", "login"]);
+    }
+
+    #[test]
+    fn multi_word_keys_and_values_are_supported() {
+        let synonyms = map(&[("k8s", &["kubernetes cluster"]), ("access token", &["bearer"])]);
+        let tokens: Vec<String> = ["access", "token", "k8s"].iter().map(|s| s.to_string()).collect();
+        let clauses = synonyms.expand_query(&tokens);
+
+        assert_eq!(clauses.len(), 2);
+        assert_eq!(clauses[0].original, "access token");
+        assert_eq!(clauses[0].alternatives[1].text(), "bearer");
+        assert_eq!(clauses[1].alternatives[1].words, vec!["kubernetes", "cluster"]);
+    }
+}
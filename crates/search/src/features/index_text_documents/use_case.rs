@@ -13,7 +13,7 @@ use crate::features::index_text_documents::{
         IndexDocumentCommand, DocumentIndexedResponse, BatchIndexCommand, BatchIndexResponse,
         RemoveDocumentCommand, DocumentRemovedResponse, GetIndexedDocumentsQuery,
         IndexedDocumentsResponse, AnalyzeTextCommand, TextAnalysisResponse,
-        IndexingStatus, BatchOperationStatus, RemovalStatus,
+        IndexingStatus, BatchOperationStatus, BatchItemFailure, RemovalStatus,
     },
     ports::{
         DocumentIndexerPort, TextAnalyzerPort, IndexHealthMonitorPort,
@@ -22,6 +22,47 @@ use crate::features::index_text_documents::{
     error::{IndexDocumentError, IndexDocumentResult, WithContext, ErrorContext, ToIndexDocumentError},
 };
 
+/// Default number of documents prepared concurrently in a batch
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+/// Default number of documents written per index commit in a batch
+pub const DEFAULT_BATCH_COMMIT_SIZE: usize = 1000;
+
+/// Backpressure settings for batch indexing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchIndexingLimits {
+    /// Maximum number of documents processed at the same time
+    pub max_concurrency: usize,
+    /// Number of documents written before each index commit
+    pub commit_batch_size: usize,
+}
+
+impl BatchIndexingLimits {
+    /// Create limits, rejecting zero for either setting
+    pub fn new(max_concurrency: usize, commit_batch_size: usize) -> IndexDocumentResult<Self> {
+        if max_concurrency == 0 {
+            return Err(IndexDocumentError::configuration(
+                "Batch indexing concurrency must be greater than zero",
+            ));
+        }
+        if commit_batch_size == 0 {
+            return Err(IndexDocumentError::configuration(
+                "Batch commit size must be greater than zero",
+            ));
+        }
+        Ok(Self { max_concurrency, commit_batch_size })
+    }
+}
+
+impl Default for BatchIndexingLimits {
+    fn default() -> Self {
+        Self {
+            max_concurrency: DEFAULT_BATCH_CONCURRENCY,
+            commit_batch_size: DEFAULT_BATCH_COMMIT_SIZE,
+        }
+    }
+}
+
 /// Use case for managing document indexing operations
 /// 
 /// This use case orchestrates the indexing process by coordinating
@@ -37,6 +78,8 @@ pub struct IndexDocumentUseCase {
     schema_manager: Arc<dyn IndexSchemaManagerPort>,
     /// Document validator for input validation
     validator: Arc<dyn DocumentValidatorPort>,
+    /// Concurrency and commit size used by batch indexing
+    batch_limits: BatchIndexingLimits,
 }
 
 impl IndexDocumentUseCase {
@@ -54,9 +97,21 @@ impl IndexDocumentUseCase {
             health_monitor,
             schema_manager,
             validator,
+            batch_limits: BatchIndexingLimits::default(),
         }
     }
 
+    /// Set the concurrency and commit size used by batch indexing
+    pub fn with_batch_limits(mut self, batch_limits: BatchIndexingLimits) -> Self {
+        self.batch_limits = batch_limits;
+        self
+    }
+
+    /// Concurrency and commit size used by batch indexing
+    pub fn batch_limits(&self) -> BatchIndexingLimits {
+        self.batch_limits
+    }
+
     /// Execute document indexing with full processing pipeline
     #[instrument(skip(self, command), fields(artifact_id = %command.artifact_id, language = ?command.language))]
     pub async fn execute(&self, command: IndexDocumentCommand) -> IndexDocumentResult<DocumentIndexedResponse> {
@@ -153,6 +208,11 @@ impl IndexDocumentUseCase {
     }
 
     /// Execute batch indexing of multiple documents
+    ///
+    /// Documents are validated and analyzed with at most `max_concurrency` in
+    /// flight, then written and committed every `commit_batch_size` documents so
+    /// progress survives a failure later in the batch. A failing document is
+    /// reported in the response and never aborts the rest of the batch.
     #[instrument(skip(self, command), fields(document_count = command.documents.len()))]
     pub async fn execute_batch(&self, command: BatchIndexCommand) -> IndexDocumentResult<BatchIndexResponse> {
         info!("Starting batch document indexing");
@@ -160,60 +220,37 @@ impl IndexDocumentUseCase {
         let span = span!(Level::INFO, "batch_index_documents", document_count = command.documents.len());
         let _enter = span.enter();
 
+        // A request may lower the configured concurrency but never raise it
+        let max_concurrency = if command.parallel_processing {
+            command
+                .max_concurrency
+                .unwrap_or(self.batch_limits.max_concurrency)
+                .min(self.batch_limits.max_concurrency)
+        } else {
+            1
+        };
+        if max_concurrency == 0 {
+            return Err(IndexDocumentError::configuration(
+                "Batch indexing concurrency must be greater than zero",
+            ));
+        }
+
         let start_time = std::time::Instant::now();
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(max_concurrency));
         let mut results = Vec::new();
+        let mut failures = Vec::new();
         let mut success_count = 0;
         let mut failure_count = 0;
 
-        // Process documents in parallel if requested
-        if command.parallel_processing {
-            let max_concurrency = command.max_concurrency.unwrap_or(4);
-            let semaphore = Arc::new(tokio::sync::Semaphore::new(max_concurrency));
-            
-            let tasks: Vec<_> = command.documents.into_iter().map(|doc| {
-                let semaphore = semaphore.clone();
-                let indexer = self.indexer.clone();
-                let analyzer = self.analyzer.clone();
-                let validator = self.validator.clone();
-                
-                tokio::spawn(async move {
-                    let _permit = semaphore.acquire().await.unwrap();
-                    Self::process_single_document(doc, indexer, analyzer, validator).await
-                })
-            }).collect();
+        let mut documents = command.documents.into_iter().peekable();
+        while documents.peek().is_some() {
+            let chunk: Vec<_> = documents.by_ref().take(self.batch_limits.commit_batch_size).collect();
+            let outcome = self.index_chunk(chunk, semaphore.clone()).await;
 
-            for task in tasks {
-                match task.await {
-                    Ok(result) => match result {
-                        Ok(response) => {
-                            success_count += 1;
-                            results.push(response);
-                        },
-                        Err(e) => {
-                            failure_count += 1;
-                            error!("Failed to process document in batch: {}", e);
-                        }
-                    },
-                    Err(e) => {
-                        failure_count += 1;
-                        error!("Task execution failed: {}", e);
-                    }
-                }
-            }
-        } else {
-            // Sequential processing
-            for doc in command.documents {
-                match Self::process_single_document(doc, self.indexer.clone(), self.analyzer.clone(), self.validator.clone()).await {
-                    Ok(response) => {
-                        success_count += 1;
-                        results.push(response);
-                    },
-                    Err(e) => {
-                        failure_count += 1;
-                        error!("Failed to process document in batch: {}", e);
-                    }
-                }
-            }
+            success_count += outcome.success_count;
+            failure_count += outcome.failure_count;
+            results.extend(outcome.results);
+            failures.extend(outcome.failures);
         }
 
         let total_time_ms = start_time.elapsed().as_millis() as u64;
@@ -231,6 +268,7 @@ impl IndexDocumentUseCase {
             total_time_ms,
             success_count,
             failure_count,
+            failures,
         };
 
         info!(
@@ -244,6 +282,103 @@ impl IndexDocumentUseCase {
         Ok(response)
     }
 
+    /// Prepare one commit-sized chunk concurrently and write it in a single commit
+    async fn index_chunk(
+        &self,
+        chunk: Vec<IndexDocumentCommand>,
+        semaphore: Arc<tokio::sync::Semaphore>,
+    ) -> BatchIndexResponse {
+        let chunk_size = chunk.len();
+        let mut ready = Vec::with_capacity(chunk_size);
+        let mut token_counts = std::collections::HashMap::with_capacity(chunk_size);
+        let mut failures = Vec::new();
+
+        let tasks: Vec<_> = chunk.into_iter().map(|doc| {
+            let semaphore = semaphore.clone();
+            let analyzer = self.analyzer.clone();
+            let validator = self.validator.clone();
+            let document_id = doc.artifact_id.clone();
+
+            let task = tokio::spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| IndexDocumentError::concurrency(e.to_string()))?;
+                Self::prepare_document(doc, analyzer, validator).await
+            });
+            (document_id, task)
+        }).collect();
+
+        for (document_id, task) in tasks {
+            let outcome = task
+                .await
+                .unwrap_or_else(|e| Err(IndexDocumentError::concurrency(format!("Task execution failed: {}", e))));
+            match outcome {
+                Ok((doc, token_count)) => {
+                    token_counts.insert(doc.artifact_id.clone(), token_count);
+                    ready.push(doc);
+                }
+                Err(e) => {
+                    error!(artifact_id = %document_id, error = %e, "Failed to process document in batch");
+                    failures.push(BatchItemFailure { document_id, error: e.to_string() });
+                }
+            }
+        }
+
+        let mut outcome = if ready.is_empty() {
+            BatchIndexResponse {
+                results: Vec::new(),
+                batch_status: BatchOperationStatus::Failed,
+                total_time_ms: 0,
+                success_count: 0,
+                failure_count: 0,
+                failures: Vec::new(),
+            }
+        } else {
+            let document_ids: Vec<String> = ready.iter().map(|doc| doc.artifact_id.clone()).collect();
+            let write = BatchIndexCommand {
+                documents: ready,
+                parallel_processing: false,
+                max_concurrency: None,
+            };
+
+            match self.indexer.batch_index_documents(write).await {
+                Ok(response) => response,
+                Err(e) => {
+                    error!(document_count = document_ids.len(), error = %e, "Failed to commit batch chunk");
+                    BatchIndexResponse {
+                        results: Vec::new(),
+                        batch_status: BatchOperationStatus::Failed,
+                        total_time_ms: 0,
+                        success_count: 0,
+                        failure_count: document_ids.len(),
+                        failures: document_ids
+                            .into_iter()
+                            .map(|document_id| BatchItemFailure { document_id, error: e.to_string() })
+                            .collect(),
+                    }
+                }
+            }
+        };
+
+        for result in &mut outcome.results {
+            if let Some(token_count) = token_counts.get(&result.document_id) {
+                result.token_count = *token_count;
+            }
+        }
+        outcome.failure_count += failures.len();
+        outcome.failures.extend(failures);
+
+        debug!(
+            chunk_size = chunk_size,
+            success_count = outcome.success_count,
+            failure_count = outcome.failure_count,
+            "Batch chunk committed"
+        );
+
+        outcome
+    }
+
     /// Execute document removal from index
     #[instrument(skip(self, command), fields(document_id = %command.document_id))]
     pub async fn execute_remove(&self, command: RemoveDocumentCommand) -> IndexDocumentResult<DocumentRemovedResponse> {
@@ -309,13 +444,14 @@ impl IndexDocumentUseCase {
         Ok(result)
     }
 
-    /// Process a single document for batch indexing
-    async fn process_single_document(
+    /// Validate and analyze a single document ahead of a batch write
+    ///
+    /// Returns the document together with its token count.
+    async fn prepare_document(
         command: IndexDocumentCommand,
-        indexer: Arc<dyn DocumentIndexerPort>,
         analyzer: Arc<dyn TextAnalyzerPort>,
         validator: Arc<dyn DocumentValidatorPort>,
-    ) -> IndexDocumentResult<DocumentIndexedResponse> {
+    ) -> IndexDocumentResult<(IndexDocumentCommand, usize)> {
         // Validate document
         let validation_result = validator.validate_document(&command).await?;
         if !validation_result.is_valid {
@@ -342,13 +478,7 @@ impl IndexDocumentUseCase {
         };
         let analysis_result = analyzer.analyze_text(analyze_command).await?;
 
-        // Index document
-        let start_time = std::time::Instant::now();
-        let mut result = indexer.index_document(command).await?;
-        result.indexing_time_ms = start_time.elapsed().as_millis() as u64;
-        result.token_count = analysis_result.token_count;
-
-        Ok(result)
+        Ok((command, analysis_result.token_count))
     }
 
     /// Get index health status
@@ -424,6 +554,7 @@ impl IndexDocumentUseCaseBuilder {
             health_monitor: self.health_monitor.ok_or("Health monitor is required")?,
             schema_manager: self.schema_manager.ok_or("Schema manager is required")?,
             validator: self.validator.ok_or("Document validator is required")?,
+            batch_limits: BatchIndexingLimits::default(),
         })
     }
 }
@@ -443,7 +574,11 @@ mod tests {
     use crate::features::index_text_documents::ports;
 
     // Mock implementations for testing
-    struct MockDocumentIndexer;
+    #[derive(Default)]
+    struct MockDocumentIndexer {
+        /// Size of every batch write, one entry per commit
+        commits: std::sync::Mutex<Vec<usize>>,
+    }
     
     #[async_trait]
     impl DocumentIndexerPort for MockDocumentIndexer {
@@ -457,13 +592,23 @@ mod tests {
             })
         }
         
-        async fn batch_index_documents(&self, _command: BatchIndexCommand) -> Result<BatchIndexResponse, IndexError> {
+        async fn batch_index_documents(&self, command: BatchIndexCommand) -> Result<BatchIndexResponse, IndexError> {
+            self.commits.lock().unwrap().push(command.documents.len());
+            let results: Vec<_> = command.documents.into_iter().map(|doc| DocumentIndexedResponse {
+                document_id: doc.artifact_id,
+                indexing_time_ms: 10,
+                status: IndexingStatus::Completed,
+                token_count: 5,
+                operation_id: Uuid::new_v4().to_string(),
+            }).collect();
+
             Ok(BatchIndexResponse {
-                results: vec![],
+                success_count: results.len(),
+                results,
                 batch_status: BatchOperationStatus::Completed,
                 total_time_ms: 100,
-                success_count: 1,
                 failure_count: 0,
+                failures: vec![],
             })
         }
         
@@ -645,11 +790,22 @@ mod tests {
         }
     }
 
-    struct MockDocumentValidator;
+    /// Validator that records how many validations ran at the same time
+    #[derive(Default)]
+    struct MockDocumentValidator {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
     
     #[async_trait]
     impl DocumentValidatorPort for MockDocumentValidator {
         async fn validate_document(&self, _command: &IndexDocumentCommand) -> Result<ports::ValidationResult, ValidationError> {
+            use std::sync::atomic::Ordering;
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(current, Ordering::SeqCst);
+            tokio::task::yield_now().await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
             Ok(ports::ValidationResult {
                 is_valid: true,
                 errors: vec![],
@@ -678,19 +834,19 @@ mod tests {
             })
         }
         
-        async fn check_duplicate_content(&self, _content: &str) -> Result<bool, DuplicateError> {
-            Ok(false)
+        async fn check_duplicate_content(&self, content: &str) -> Result<bool, DuplicateError> {
+            Ok(content == "duplicate")
         }
     }
 
     #[tokio::test]
     async fn test_index_document_use_case() {
         let use_case = IndexDocumentUseCase::new(
-            Arc::new(MockDocumentIndexer),
+            Arc::new(MockDocumentIndexer::default()),
             Arc::new(MockTextAnalyzer),
             Arc::new(MockHealthMonitor),
             Arc::new(MockSchemaManager),
-            Arc::new(MockDocumentValidator),
+            Arc::new(MockDocumentValidator::default()),
         );
 
         let command = IndexDocumentCommand::test_data();
@@ -703,11 +859,11 @@ mod tests {
     #[tokio::test]
     async fn test_batch_index_documents() {
         let use_case = IndexDocumentUseCase::new(
-            Arc::new(MockDocumentIndexer),
+            Arc::new(MockDocumentIndexer::default()),
             Arc::new(MockTextAnalyzer),
             Arc::new(MockHealthMonitor),
             Arc::new(MockSchemaManager),
-            Arc::new(MockDocumentValidator),
+            Arc::new(MockDocumentValidator::default()),
         );
 
         let command = BatchIndexCommand {
@@ -722,4 +878,100 @@ mod tests {
         assert_eq!(result.success_count, 1);
         assert_eq!(result.failure_count, 0);
     }
-}
\ No newline at end of file
+
+    fn batch_use_case(indexer: Arc<MockDocumentIndexer>, limits: BatchIndexingLimits) -> IndexDocumentUseCase {
+        batch_use_case_with_validator(indexer, Arc::new(MockDocumentValidator::default()), limits)
+    }
+
+    fn batch_use_case_with_validator(
+        indexer: Arc<MockDocumentIndexer>,
+        validator: Arc<MockDocumentValidator>,
+        limits: BatchIndexingLimits,
+    ) -> IndexDocumentUseCase {
+        IndexDocumentUseCase::new(
+            indexer,
+            Arc::new(MockTextAnalyzer),
+            Arc::new(MockHealthMonitor),
+            Arc::new(MockSchemaManager),
+            validator,
+        )
+        .with_batch_limits(limits)
+    }
+
+    #[tokio::test]
+    async fn test_batch_commits_every_n_documents() {
+        let indexer = Arc::new(MockDocumentIndexer::default());
+        let use_case = batch_use_case(indexer.clone(), BatchIndexingLimits::new(2, 3).unwrap());
+
+        let command = BatchIndexCommand {
+            documents: (0..7).map(|_| IndexDocumentCommand::test_data()).collect(),
+            parallel_processing: true,
+            max_concurrency: None,
+        };
+
+        let result = use_case.execute_batch(command).await.unwrap();
+
+        assert_eq!(result.success_count, 7);
+        assert_eq!(*indexer.commits.lock().unwrap(), vec![3, 3, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_reports_document_errors_without_aborting() {
+        let indexer = Arc::new(MockDocumentIndexer::default());
+        let use_case = batch_use_case(indexer, BatchIndexingLimits::new(4, 10).unwrap());
+
+        let mut rejected = IndexDocumentCommand::test_data();
+        rejected.content = "duplicate".to_string();
+        let rejected_id = rejected.artifact_id.clone();
+
+        let command = BatchIndexCommand {
+            documents: vec![IndexDocumentCommand::test_data(), rejected, IndexDocumentCommand::test_data()],
+            parallel_processing: true,
+            max_concurrency: None,
+        };
+
+        let result = use_case.execute_batch(command).await.unwrap();
+
+        assert_eq!(result.batch_status, BatchOperationStatus::PartialSuccess);
+        assert_eq!(result.success_count, 2);
+        assert_eq!(result.failure_count, 1);
+        assert_eq!(result.failures[0].document_id, rejected_id);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_batch_request_cannot_exceed_configured_concurrency() {
+        let validator = Arc::new(MockDocumentValidator::default());
+        let use_case = batch_use_case_with_validator(
+            Arc::new(MockDocumentIndexer::default()),
+            validator.clone(),
+            BatchIndexingLimits::new(2, 50).unwrap(),
+        );
+
+        let command = BatchIndexCommand {
+            documents: (0..20).map(|_| IndexDocumentCommand::test_data()).collect(),
+            parallel_processing: true,
+            max_concurrency: Some(1_000),
+        };
+
+        let result = use_case.execute_batch(command).await.unwrap();
+
+        assert_eq!(result.success_count, 20);
+        assert!(validator.peak.load(std::sync::atomic::Ordering::SeqCst) <= 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_rejects_zero_concurrency() {
+        assert!(BatchIndexingLimits::new(0, 10).is_err());
+        assert!(BatchIndexingLimits::new(1, 0).is_err());
+
+        let use_case = batch_use_case(Arc::new(MockDocumentIndexer::default()), BatchIndexingLimits::default());
+        let command = BatchIndexCommand {
+            documents: vec![IndexDocumentCommand::test_data()],
+            parallel_processing: true,
+            max_concurrency: Some(0),
+        };
+
+        let result = use_case.execute_batch(command).await;
+        assert!(matches!(result, Err(IndexDocumentError::Configuration(_))));
+    }
+}
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use std::sync::{Arc, RwLock};
 use tantivy::{
     collector::{TopDocs, Count},
-    query::{Query, QueryParser, BooleanQuery, Occur, PhraseQuery, TermQuery},
+    query::{Query, QueryParser, BooleanQuery, BoostQuery, ConstScoreQuery, Occur, PhraseQuery, TermQuery},
     schema::*,
     tokenizer::{TokenizerManager, SimpleTokenizer},
     Index, IndexReader, Searcher, ReloadPolicy, TantivyDocument, DocAddress,
@@ -23,7 +23,12 @@ use super::error::{
     RebuildError, ClearError, ValidationError, 
     MaintenanceError, SegmentError, MergeError
 };
+use super::query_syntax::{parse_query_clauses, find_phrase, QueryClause};
 use crate::features::index_text_documents::adapter::DocumentIndexSchema;
+use crate::features::index_text_documents::synonyms::SynonymMap;
+use crate::features::index_text_documents::language::{
+    all_analyzers, analyze, analyzer_for_language, analyzer_marker, LanguageAnalysisConfig,
+};
 
 /// Tantivy-based full-text search adapter
 pub struct TantivyFullTextSearchAdapter {
@@ -31,6 +36,8 @@ pub struct TantivyFullTextSearchAdapter {
     schema: Arc<DocumentIndexSchema>,
     tokenizer_manager: TokenizerManager,
     index_reader: Arc<RwLock<Option<IndexReader>>>,
+    synonyms: SynonymMap,
+    language_analysis: LanguageAnalysisConfig,
 }
 
 impl TantivyFullTextSearchAdapter {
@@ -42,8 +49,22 @@ impl TantivyFullTextSearchAdapter {
             schema,
             tokenizer_manager,
             index_reader: Arc::new(RwLock::new(None)),
+            synonyms: SynonymMap::default(),
+            language_analysis: LanguageAnalysisConfig::default(),
         }
     }
+
+    /// Analyze queries with the same language chains used at index time
+    pub fn with_language_analysis(mut self, config: LanguageAnalysisConfig) -> Self {
+        self.language_analysis = config;
+        self
+    }
+
+    /// Expand plain query terms with synonyms at query time
+    pub fn with_synonyms(mut self, synonyms: SynonymMap) -> Self {
+        self.synonyms = synonyms;
+        self
+    }
     
     async fn get_reader(&self) -> Result<IndexReader, FullTextSearchError> {
         // Check if we already have a reader
@@ -80,7 +101,8 @@ impl TantivyFullTextSearchAdapter {
         Ok(reader)
     }
     
-    fn parse_search_query(&self, query: &FullTextSearchQuery, searcher: &Searcher) -> Result<Box<dyn Query>, FullTextSearchError> {
+    /// Build the text and field clauses of a query, without the document type restriction
+    fn parse_search_query(&self, query: &FullTextSearchQuery, searcher: &Searcher) -> Result<Vec<(Occur, Box<dyn Query>)>, FullTextSearchError> {
         let mut query_parts = Vec::new();
         
         // Parse the main query string
@@ -94,12 +116,43 @@ impl TantivyFullTextSearchAdapter {
             ],
         );
         
-        let main_query = query_parser.parse_query(&query.q)
-            .map_err(|e| FullTextSearchError::Search { 
-                source: SearchError::QueryParseFailed(format!("Failed to parse query '{}': {}", query.q, e)) 
-            })?;
-        
-        query_parts.push((Occur::Must, main_query));
+        // Quoted phrases become positional queries; everything else goes through the query parser
+        let clauses = parse_query_clauses(&query.q, query.phrase_slop.unwrap_or(0));
+        let stemming = query.enable_stemming.unwrap_or(true);
+        let mut plain_terms = Vec::new();
+
+        for clause in clauses {
+            match clause {
+                QueryClause::Term(term) => plain_terms.push(term),
+                QueryClause::Phrase { words, slop } => {
+                    let phrase = words.join(" ");
+                    let phrase_query = self.build_analyzed_query(&phrase, searcher.index().tokenizers(), stemming, slop);
+                    query_parts.push((Occur::Must, phrase_query));
+                }
+            }
+        }
+
+        if !plain_terms.is_empty() && !self.synonyms.is_empty() {
+            let synonym_query = self.build_synonym_query(&plain_terms, searcher.index().tokenizers(), stemming);
+            query_parts.push((Occur::Must, synonym_query));
+        } else if !plain_terms.is_empty() {
+            let terms_query = plain_terms.join(" ");
+            let main_query = query_parser.parse_query(&terms_query)
+                .map_err(|e| FullTextSearchError::Search {
+                    source: SearchError::QueryParseFailed(format!("Failed to parse query '{}': {}", query.q, e))
+                })?;
+
+            if stemming {
+                let stemmed_query = self.build_stemmed_query(&terms_query, searcher.index().tokenizers());
+                let either: Vec<(Occur, Box<dyn Query>)> = vec![
+                    (Occur::Should, main_query),
+                    (Occur::Should, stemmed_query),
+                ];
+                query_parts.push((Occur::Must, Box::new(BooleanQuery::new(either))));
+            } else {
+                query_parts.push((Occur::Must, main_query));
+            }
+        }
         
         // Add field filters
         if let Some(artifact_type) = &query.artifact_type {
@@ -120,9 +173,200 @@ impl TantivyFullTextSearchAdapter {
             // For now, we'll skip applying a date range filter here to avoid type conversion issues.
         }
         
-        // Combine all query parts
-        let boolean_query = BooleanQuery::new(query_parts);
-        Ok(Box::new(boolean_query))
+        Ok(query_parts)
+    }
+
+    /// Combine query clauses with a restriction to the given document types.
+    ///
+    /// The restriction scores zero so artifacts and repositories keep the scores
+    /// of their text matches and rank against each other directly. Documents
+    /// indexed without a type count as artifacts, so an artifact restriction
+    /// excludes the other types rather than requiring the `artifact` term.
+    fn restrict_to_doc_types(
+        &self,
+        clauses: &[(Occur, Box<dyn Query>)],
+        doc_types: Option<&[DocumentType]>,
+    ) -> Box<dyn Query> {
+        let mut query_parts: Vec<(Occur, Box<dyn Query>)> = clauses
+            .iter()
+            .map(|(occur, query)| (*occur, query.box_clone()))
+            .collect();
+
+        let doc_type_query = |doc_type: &DocumentType| -> Box<dyn Query> {
+            let term = Term::from_field_text(self.schema.doc_type_field, doc_type.as_str());
+            Box::new(TermQuery::new(term, IndexRecordOption::Basic))
+        };
+
+        if let Some(doc_types) = doc_types.filter(|types| !types.is_empty()) {
+            if doc_types.contains(&DocumentType::Artifact) {
+                for excluded in DocumentType::ALL.iter().filter(|t| !doc_types.contains(t)) {
+                    query_parts.push((Occur::MustNot, doc_type_query(excluded)));
+                }
+            } else {
+                let any_of: Vec<(Occur, Box<dyn Query>)> = doc_types
+                    .iter()
+                    .map(|doc_type| (Occur::Should, doc_type_query(doc_type)))
+                    .collect();
+                let filter = ConstScoreQuery::new(Box::new(BooleanQuery::new(any_of)), 0.0);
+                query_parts.push((Occur::Must, Box::new(filter)));
+            }
+        }
+
+        Box::new(BooleanQuery::new(query_parts))
+    }
+
+    /// Count the matches of each document type, ignoring any type restriction in the query
+    fn count_by_doc_type(
+        &self,
+        searcher: &Searcher,
+        clauses: &[(Occur, Box<dyn Query>)],
+    ) -> Result<Vec<FacetCount>, SearchError> {
+        let mut counts = Vec::with_capacity(DocumentType::ALL.len());
+        for doc_type in DocumentType::ALL {
+            let query = self.restrict_to_doc_types(clauses, Some(&[doc_type]));
+            let count = searcher
+                .search(&query, &Count)
+                .map_err(|e| SearchError::QueryExecutionFailed(format!("Document type count failed: {}", e)))?;
+            counts.push((doc_type, count));
+        }
+        Ok(FacetCount::for_doc_types(&counts))
+    }
+
+    /// Build a query where each term matches itself or any of its synonyms.
+    ///
+    /// Every alternative goes through the analyzer of each field it is matched
+    /// against, so expansions hit the same tokens and stems that were indexed.
+    /// Synonym matches are down-weighted so documents containing the exact term rank first.
+    fn build_synonym_query(&self, terms: &[String], tokenizers: &TokenizerManager, stemming: bool) -> Box<dyn Query> {
+        let clauses: Vec<(Occur, Box<dyn Query>)> = self
+            .synonyms
+            .expand_query(terms)
+            .into_iter()
+            .map(|clause| {
+                let alternatives: Vec<(Occur, Box<dyn Query>)> = clause
+                    .alternatives
+                    .iter()
+                    .map(|alternative| {
+                        let text = alternative.words.join(" ");
+                        let query = self.build_analyzed_query(&text, tokenizers, stemming, 0);
+                        let boosted: Box<dyn Query> = Box::new(BoostQuery::new(query, alternative.boost));
+                        (Occur::Should, boosted)
+                    })
+                    .collect();
+                (Occur::Should, Box::new(BooleanQuery::new(alternatives)) as Box<dyn Query>)
+            })
+            .collect();
+
+        Box::new(BooleanQuery::new(clauses))
+    }
+
+    /// Match the language-analyzed fields, analyzing the query with the same chain
+    /// that was recorded for each document field at index time.
+    fn build_stemmed_query(&self, text: &str, tokenizers: &TokenizerManager) -> Box<dyn Query> {
+        let mut per_analyzer: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+
+        for (field_name, stemmed_field) in self.schema.stemmed_fields() {
+            for analyzer in self.query_analyzers(field_name) {
+                let token_queries: Vec<(Occur, Box<dyn Query>)> = analyze(tokenizers, analyzer, text)
+                    .tokens
+                    .into_iter()
+                    .map(|token| {
+                        let term = Term::from_field_text(stemmed_field, &token.text);
+                        (Occur::Should, Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs)) as Box<dyn Query>)
+                    })
+                    .collect();
+                if token_queries.is_empty() {
+                    continue;
+                }
+
+                let query = Box::new(BooleanQuery::new(token_queries));
+                per_analyzer.push((Occur::Should, self.scoped_to_analyzer(field_name, analyzer, query)));
+            }
+        }
+
+        Box::new(BooleanQuery::new(per_analyzer))
+    }
+
+    /// Match `text` as a whole in any of the text fields, its tokens at most `slop` positions apart.
+    ///
+    /// The text is run through each field's own analyzer, and through the
+    /// recorded language analyzers for the stemmed fields when `stemming` is on,
+    /// so quoted phrases split and normalize the same way the indexed text did.
+    fn build_analyzed_query(&self, text: &str, tokenizers: &TokenizerManager, stemming: bool, slop: u32) -> Box<dyn Query> {
+        let fields = [
+            self.schema.content_field,
+            self.schema.title_field,
+            self.schema.description_field,
+            self.schema.tags_field,
+        ];
+
+        let mut per_field: Vec<(Occur, Box<dyn Query>)> = fields
+            .iter()
+            .filter_map(|field| {
+                let tokens = analyze(tokenizers, &self.field_analyzer(*field), text).tokens;
+                Self::tokens_query(*field, tokens.into_iter().map(|token| token.text).collect(), slop)
+            })
+            .map(|query| (Occur::Should, query))
+            .collect();
+
+        if stemming {
+            for (field_name, stemmed_field) in self.schema.stemmed_fields() {
+                for analyzer in self.query_analyzers(field_name) {
+                    let tokens = analyze(tokenizers, analyzer, text).tokens.into_iter().map(|token| token.text).collect();
+                    if let Some(query) = Self::tokens_query(stemmed_field, tokens, slop) {
+                        per_field.push((Occur::Should, self.scoped_to_analyzer(field_name, analyzer, query)));
+                    }
+                }
+            }
+        }
+
+        Box::new(BooleanQuery::new(per_field))
+    }
+
+    /// Analyzers a query against `field_name` has to try: the configured one, or all of them
+    fn query_analyzers(&self, field_name: &str) -> Vec<&'static str> {
+        match self.language_analysis.field_languages.get(field_name) {
+            Some(language) => vec![analyzer_for_language(Some(language))],
+            None => all_analyzers().collect(),
+        }
+    }
+
+    /// Restrict `query` to documents whose `field_name` was indexed with `analyzer`
+    fn scoped_to_analyzer(&self, field_name: &str, analyzer: &str, query: Box<dyn Query>) -> Box<dyn Query> {
+        let marker = Term::from_field_text(self.schema.analyzer_field, &analyzer_marker(field_name, analyzer));
+        let scoped: Vec<(Occur, Box<dyn Query>)> = vec![
+            (Occur::Must, Box::new(TermQuery::new(marker, IndexRecordOption::Basic))),
+            (Occur::Must, query),
+        ];
+        Box::new(BooleanQuery::new(scoped))
+    }
+
+    /// Name of the tokenizer a text field is indexed with
+    fn field_analyzer(&self, field: Field) -> String {
+        match self.schema.schema.get_field_entry(field).field_type() {
+            FieldType::Str(options) => options
+                .get_indexing_options()
+                .map(|indexing| indexing.tokenizer().to_string())
+                .unwrap_or_else(|| "default".to_string()),
+            _ => "default".to_string(),
+        }
+    }
+
+    /// Term query for a single token, phrase query with `slop` for several
+    fn tokens_query(field: Field, tokens: Vec<String>, slop: u32) -> Option<Box<dyn Query>> {
+        match tokens.len() {
+            0 => None,
+            1 => {
+                let term = Term::from_field_text(field, &tokens[0]);
+                Some(Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs)))
+            }
+            _ => {
+                let terms: Vec<Term> = tokens.iter().map(|token| Term::from_field_text(field, token)).collect();
+                let mut phrase_query = PhraseQuery::new(terms);
+                phrase_query.set_slop(slop);
+                Some(Box::new(phrase_query))
+            }
+        }
     }
     
     fn convert_tantivy_doc_to_search_result(&self, doc: &TantivyDocument, score: f32, query: &FullTextSearchQuery) -> Result<SearchResult, FullTextSearchError> {
@@ -155,6 +399,10 @@ impl TantivyFullTextSearchAdapter {
         
         let language = doc.get_first(self.schema.language_field)
             .and_then(|v| v.as_str());
+
+        let doc_type = DocumentType::from_stored(
+            doc.get_first(self.schema.doc_type_field).and_then(|v| v.as_str()),
+        );
         
         let indexed_at = if let Some(dt) = doc.get_first(self.schema.indexed_at_field)
             .and_then(|v| v.as_datetime()) {
@@ -190,6 +438,7 @@ impl TantivyFullTextSearchAdapter {
         
         Ok(SearchResult {
             document_id: document_id.to_string(),
+            doc_type,
             metadata,
             score,
             highlights: Vec::new(), // Will be populated by highlighter
@@ -223,9 +472,10 @@ impl FullTextSearchPort for TantivyFullTextSearchAdapter {
         let searcher = reader.searcher();
         
         // Parse the query
-        let search_query = self
+        let clauses = self
             .parse_search_query(&query, &searcher)
             .map_err(|e| SearchError::QueryParseFailed(e.to_string()))?;
+        let search_query = self.restrict_to_doc_types(&clauses, query.doc_types.as_deref());
         
         // Set up pagination
         let page = query.page.unwrap_or(1);
@@ -264,6 +514,8 @@ impl FullTextSearchPort for TantivyFullTextSearchAdapter {
             }
         }
         
+        let doc_type_counts = self.count_by_doc_type(&searcher, &clauses)?;
+
         let query_time_ms = start_time.elapsed().as_millis() as u64;
         let total_count = count;
         
@@ -298,6 +550,7 @@ impl FullTextSearchPort for TantivyFullTextSearchAdapter {
             metadata,
             facets: None,
             suggestions: None,
+            doc_type_counts,
         })
     }
     
@@ -369,6 +622,9 @@ impl FullTextSearchPort for TantivyFullTextSearchAdapter {
             fuzziness: None,
             enable_stemming: None,
             enable_phonetic: None,
+            phrase_slop: None,
+            doc_types: None,
+            principal: None,
         };
         
         self.search(query).await
@@ -457,22 +713,27 @@ impl QueryAnalyzerPort for SimpleQueryAnalyzer {
     }
     
     async fn parse_query(&self, query: &str, mode: SearchMode) -> Result<ParsedQuery, ParseError> {
-        let terms: Vec<QueryTerm> = query
-            .split_whitespace()
-            .map(|term| QueryTerm {
-                term: term.to_string(),
+        let clauses = parse_query_clauses(query, 0);
+        let has_phrase = clauses.iter().any(QueryClause::is_phrase);
+
+        // Phrases are kept as a single term so they are highlighted as a unit
+        let terms: Vec<QueryTerm> = clauses
+            .iter()
+            .map(|clause| QueryTerm {
+                term: clause.text(),
                 field: None,
                 boost: None,
                 fuzzy: None,
             })
             .collect();
-        
+
         Ok(ParsedQuery {
             original_query: query.to_string(),
             parsed_terms: terms,
             operators: Vec::new(),
             filters: Vec::new(),
             query_type: match mode {
+                SearchMode::Simple if has_phrase => QueryType::Phrase,
                 SearchMode::Simple => QueryType::SimpleKeyword,
                 SearchMode::Phrase => QueryType::Phrase,
                 SearchMode::Boolean => QueryType::Boolean,
@@ -867,15 +1128,34 @@ impl HighlighterPort for SimpleHighlighter {
     }
     
     async fn generate_snippets(&self, request: SnippetRequest) -> Result<Vec<TextSnippet>, SnippetError> {
-        // Simple implementation - return a snippet of the content
+        let content: Vec<char> = request.content.chars().collect();
+
+        // Anchor the window on the first matching term or phrase; a phrase match is
+        // never cut at the snippet boundary, the window grows to contain it instead.
+        let matched = request.query_terms.iter().find_map(|term| {
+            let words: Vec<String> = term.split_whitespace().map(|w| w.to_string()).collect();
+            find_phrase(&request.content, &words)
+        });
+
+        let (start_pos, end_pos) = match matched {
+            Some((match_start, match_end)) => {
+                let match_len = match_end - match_start;
+                let padding = request.snippet_length.saturating_sub(match_len) / 2;
+                let start = match_start.saturating_sub(padding);
+                let end = (start + request.snippet_length.max(match_len)).min(content.len());
+                (start.min(match_start), end.max(match_end))
+            }
+            None => (0, request.snippet_length.min(content.len())),
+        };
+
         let snippet = TextSnippet {
-            text: request.content.chars().take(request.snippet_length).collect(),
+            text: content[start_pos..end_pos].iter().collect(),
             field: "content".to_string(),
-            start_pos: 0,
-            end_pos: request.snippet_length.min(request.content.len()),
+            start_pos,
+            end_pos,
             score: 0.8,
         };
-        
+
         Ok(vec![snippet])
     }
     
@@ -1077,6 +1357,7 @@ pub mod test {
                 metadata: SearchMetadata::default(),
                 facets: None,
                 suggestions: None,
+                doc_type_counts: Vec::new(),
             })
         }
         
@@ -1278,6 +1559,20 @@ pub mod test {
         }
     }
     
+    /// Authorization that lets every caller see every document
+    pub struct AllowAllSearchAuthorization;
+    
+    #[async_trait]
+    impl SearchAuthorizationPort for AllowAllSearchAuthorization {
+        async fn authorized_documents(&self, _principal: &shared::hrn::Hrn, document_ids: &[String]) -> Result<std::collections::HashSet<String>, AuthorizationError> {
+            Ok(document_ids.iter().cloned().collect())
+        }
+        
+        async fn public_documents(&self, document_ids: &[String]) -> Result<std::collections::HashSet<String>, AuthorizationError> {
+            Ok(document_ids.iter().cloned().collect())
+        }
+    }
+    
     pub struct MockSearchIndexManager {
         pub should_fail: bool,
     }
@@ -1413,4 +1708,44 @@ pub mod test {
             Ok(QueryPerformanceAnalysis::default())
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_snippet_contains_phrase_spanning_boundary() {
+        let highlighter = SimpleHighlighter::new();
+        let content = format!("{} connection pool exhausted", "x".repeat(40));
+
+        let snippets = highlighter.generate_snippets(SnippetRequest {
+            document_id: "doc-1".to_string(),
+            content: content.clone(),
+            query_terms: vec!["connection pool".to_string()],
+            max_snippets: 1,
+            snippet_length: 10,
+            language: None,
+        }).await.unwrap();
+
+        assert!(snippets[0].text.contains("connection pool"));
+        assert!(snippets[0].end_pos <= content.chars().count());
+    }
+    #[test]
+    fn test_punctuated_phrase_matches_the_indexed_tokens() {
+        let schema = Arc::new(DocumentIndexSchema::new());
+        let index = Index::create_in_ram(schema.schema.clone());
+        crate::features::index_text_documents::language::register_language_analyzers(index.tokenizers());
+        let mut writer: tantivy::IndexWriter = index.writer(15_000_000).unwrap();
+        writer.add_document(tantivy::doc!(
+            schema.content_field => "Fixed a connection pool leak in the HTTP client",
+        )).unwrap();
+        writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let adapter = TantivyFullTextSearchAdapter::new(Arc::new(RwLock::new(index)), schema);
+
+        for q in ["\"connection-pool leak\"", "\"connection pool.\"", "\"Connection pool\"~1"] {
+            let query = FullTextSearchQuery { q: q.to_string(), ..Default::default() };
+            let clauses = adapter.parse_search_query(&query, &searcher).unwrap();
+
+            let count = searcher.search(&BooleanQuery::new(clauses), &Count).unwrap();
+
+            assert_eq!(count, 1, "{}", q);
+        }
+    }
+}
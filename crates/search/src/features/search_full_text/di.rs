@@ -13,7 +13,7 @@ use super::use_case::*;
 use super::adapter::*;
 use super::ports::{
     FullTextSearchPort, QueryAnalyzerPort, RelevanceScorerPort, HighlighterPort, 
-    SearchPerformanceMonitorPort, SearchIndexManagerPort, HealthStatus,
+    SearchPerformanceMonitorPort, SearchIndexManagerPort, SearchAuthorizationPort, HealthStatus,
     QueryPatterns, TimeRange, IndexError, ConfigError,
     IndexConfig as PortsIndexConfig, ConfigUpdateResult, 
     MaintenanceResult, SegmentInfo, MergeResult, MaintenanceTask, MergePolicy,
@@ -22,7 +22,10 @@ use super::ports::{
 };
 use crate::features::index_text_documents::ports::IndexStats;
 use super::dto::*;
-use crate::features::index_text_documents::adapter::DocumentIndexSchema;
+use crate::features::index_text_documents::adapter::{open_or_create_index_in_dir, DocumentIndexSchema};
+use crate::features::index_text_documents::synonyms::SynonymMap;
+use crate::features::index_text_documents::language::register_language_analyzers;
+use super::SearchFeatureConfig;
 
 /// Main DI container for the search_full_text feature
 pub struct SearchFullTextDIContainer {
@@ -40,20 +43,47 @@ impl SearchFullTextDIContainer {
         highlighter: Arc<dyn HighlighterPort>,
         performance_monitor: Arc<dyn SearchPerformanceMonitorPort>,
         index_manager: Arc<dyn SearchIndexManagerPort>,
+    ) -> Self {
+        Self::new_with_authorization(
+            search_adapter,
+            query_analyzer,
+            relevance_scorer,
+            highlighter,
+            performance_monitor,
+            index_manager,
+            None,
+        )
+    }
+    
+    /// Create a new DI container whose searches and suggestions only expose
+    /// documents the caller is authorized to view
+    pub fn new_with_authorization(
+        search_adapter: Arc<dyn FullTextSearchPort>,
+        query_analyzer: Arc<dyn QueryAnalyzerPort>,
+        relevance_scorer: Arc<dyn RelevanceScorerPort>,
+        highlighter: Arc<dyn HighlighterPort>,
+        performance_monitor: Arc<dyn SearchPerformanceMonitorPort>,
+        index_manager: Arc<dyn SearchIndexManagerPort>,
+        authorization: Option<Arc<dyn SearchAuthorizationPort>>,
     ) -> Self {
         // Create use cases
-        let search_use_case = Arc::new(FullTextSearchUseCase::new(
+        let mut search_use_case = FullTextSearchUseCase::new(
             search_adapter.clone(),
             query_analyzer.clone(),
             relevance_scorer.clone(),
             highlighter.clone(),
             performance_monitor.clone(),
-        ));
-        
-        let suggestions_use_case = Arc::new(SearchSuggestionsUseCase::new(
+        );
+        let mut suggestions_use_case = SearchSuggestionsUseCase::new(
             search_adapter.clone(),
             query_analyzer.clone(),
-        ));
+        );
+        if let Some(authorization) = authorization {
+            search_use_case = search_use_case.with_authorization(authorization.clone());
+            suggestions_use_case = suggestions_use_case.with_authorization(authorization);
+        }
+        let search_use_case = Arc::new(search_use_case);
+        let suggestions_use_case = Arc::new(suggestions_use_case);
         
         let query_analysis_use_case = Arc::new(QueryPerformanceUseCase::new(
             query_analyzer,
@@ -68,16 +98,39 @@ impl SearchFullTextDIContainer {
     }
     
     /// Create a production-ready container with Tantivy implementations
-    pub fn for_production(index_path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn for_production(
+        index_path: &str,
+        authorization: Arc<dyn SearchAuthorizationPort>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::for_production_with_config(
+            &SearchFeatureConfig {
+                index_path: index_path.to_string(),
+                ..Default::default()
+            },
+            authorization,
+        )
+    }
+
+    /// Create a production-ready container from the feature configuration
+    ///
+    /// Production searches always go through `authorization`, so results never
+    /// include documents the caller can't view.
+    pub fn for_production_with_config(
+        config: &SearchFeatureConfig,
+        authorization: Arc<dyn SearchAuthorizationPort>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         // Load or create Tantivy index
-        let index = Self::load_or_create_index(index_path)?;
+        let index = Self::load_or_create_index(&config.index_path)?;
         let schema = Arc::new(DocumentIndexSchema::create());
         
         // Create adapters
+        let synonyms = SynonymMap::new(config.synonyms.clone()).with_boost(config.synonym_boost);
         let search_adapter = Arc::new(TantivyFullTextSearchAdapter::new(
             Arc::new(std::sync::RwLock::new(index.clone())),
             schema.clone(),
-        ));
+        )
+        .with_synonyms(synonyms)
+        .with_language_analysis(config.language_analysis.clone()));
         
         let query_analyzer = Arc::new(SimpleQueryAnalyzer::new());
         let relevance_scorer = Arc::new(SimpleRelevanceScorer::new());
@@ -88,13 +141,14 @@ impl SearchFullTextDIContainer {
             schema.clone(),
         ));
         
-        Ok(Self::new(
+        Ok(Self::new_with_authorization(
             search_adapter,
             query_analyzer,
             relevance_scorer,
             highlighter,
             performance_monitor,
             index_manager,
+            Some(authorization),
         ))
     }
     
@@ -124,17 +178,11 @@ impl SearchFullTextDIContainer {
     fn load_or_create_index(index_path: &str) -> Result<Index, Box<dyn std::error::Error>> {
         let path = std::path::Path::new(index_path);
         
-        if path.exists() {
-            let index = Index::open_in_dir(path)?;
-            info!("Loaded existing Tantivy index from: {}", index_path);
-            Ok(index)
-        } else {
-            std::fs::create_dir_all(path)?;
-            let schema = DocumentIndexSchema::create_tantivy_schema();
-            let index = Index::create_in_dir(path, schema)?;
-            info!("Created new Tantivy index at: {}", index_path);
-            Ok(index)
-        }
+        let index = open_or_create_index_in_dir(path, &DocumentIndexSchema::create_tantivy_schema())?;
+        info!("Opened Tantivy index at: {}", index_path);
+
+        register_language_analyzers(index.tokenizers());
+        Ok(index)
     }
     
     /// Get the search use case
@@ -161,6 +209,7 @@ pub struct SearchFullTextDIContainerBuilder {
     highlighter: Option<Arc<dyn HighlighterPort>>,
     performance_monitor: Option<Arc<dyn SearchPerformanceMonitorPort>>,
     index_manager: Option<Arc<dyn SearchIndexManagerPort>>,
+    authorization: Option<Arc<dyn SearchAuthorizationPort>>,
 }
 
 impl SearchFullTextDIContainerBuilder {
@@ -172,6 +221,7 @@ impl SearchFullTextDIContainerBuilder {
             highlighter: None,
             performance_monitor: None,
             index_manager: None,
+            authorization: None,
         }
     }
     
@@ -205,6 +255,11 @@ impl SearchFullTextDIContainerBuilder {
         self
     }
     
+    pub fn with_authorization(mut self, authorization: Arc<dyn SearchAuthorizationPort>) -> Self {
+        self.authorization = Some(authorization);
+        self
+    }
+    
     pub fn build(self) -> Result<SearchFullTextDIContainer, &'static str> {
         let search_adapter = self.search_adapter.ok_or("search_adapter is required")?;
         let query_analyzer = self.query_analyzer.ok_or("query_analyzer is required")?;
@@ -213,13 +268,14 @@ impl SearchFullTextDIContainerBuilder {
         let performance_monitor = self.performance_monitor.ok_or("performance_monitor is required")?;
         let index_manager = self.index_manager.ok_or("index_manager is required")?;
         
-        Ok(SearchFullTextDIContainer::new(
+        Ok(SearchFullTextDIContainer::new_with_authorization(
             search_adapter,
             query_analyzer,
             relevance_scorer,
             highlighter,
             performance_monitor,
             index_manager,
+            self.authorization,
         ))
     }
 }
@@ -391,7 +447,10 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let index_path = temp_dir.path().to_str().unwrap();
         
-        let result = SearchFullTextDIContainer::for_production(index_path);
+        let result = SearchFullTextDIContainer::for_production(
+            index_path,
+            Arc::new(crate::features::search_full_text::adapter::test::AllowAllSearchAuthorization),
+        );
         assert!(result.is_ok());
         
         let container = result.unwrap();
@@ -6,6 +6,7 @@
 use serde::{Serialize, Deserialize};
 use uuid::Uuid;
 use std::collections::HashMap;
+use shared::hrn::Hrn;
 
 /// Query for full-text search
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +43,15 @@ pub struct FullTextSearchQuery {
     pub enable_stemming: Option<bool>,
     /// Whether to enable phonetic matching
     pub enable_phonetic: Option<bool>,
+    /// Default proximity for quoted phrases without an explicit `~N` (0 = adjacent)
+    pub phrase_slop: Option<u32>,
+    /// Restrict results to these document types (`None` searches all of them)
+    #[serde(default)]
+    pub doc_types: Option<Vec<DocumentType>>,
+    /// Principal performing the search; `None` for unauthenticated callers.
+    /// Set from the authenticated context, never from the request body.
+    #[serde(skip)]
+    pub principal: Option<Hrn>,
 }
 
 /// Response for full-text search
@@ -65,6 +75,10 @@ pub struct FullTextSearchResults {
     pub facets: Option<SearchFacets>,
     /// Suggestions for query refinement
     pub suggestions: Option<Vec<SearchSuggestion>>,
+    /// Matches per document type, ignoring the `doc_types` restriction so
+    /// callers can show how many results each type would have
+    #[serde(default)]
+    pub doc_type_counts: Vec<FacetCount>,
 }
 
 /// Individual search result
@@ -72,6 +86,9 @@ pub struct FullTextSearchResults {
 pub struct SearchResult {
     /// Document ID
     pub document_id: String,
+    /// Kind of entity the result describes
+    #[serde(default)]
+    pub doc_type: DocumentType,
     /// Artifact metadata
     pub metadata: ArtifactMetadata,
     /// Relevance score
@@ -163,6 +180,18 @@ pub struct SearchFacets {
     pub date_range: Option<DateRangeFacet>,
 }
 
+impl SearchFacets {
+    /// Facets of the given results; date buckets are left out
+    pub fn for_results(results: &[SearchResult]) -> Self {
+        Self {
+            artifact_types: FacetCount::tally(results.iter().map(|r| r.metadata.artifact_type.as_str())),
+            languages: FacetCount::tally(results.iter().filter_map(|r| r.language.as_deref())),
+            tags: FacetCount::tally(results.iter().flat_map(|r| r.metadata.tags.iter().map(String::as_str))),
+            date_range: None,
+        }
+    }
+}
+
 /// Facet count
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FacetCount {
@@ -174,6 +203,42 @@ pub struct FacetCount {
     pub percentage: f32,
 }
 
+impl FacetCount {
+    /// Occurrences of each distinct value, most frequent first, with
+    /// percentages of the combined total
+    pub fn tally<'a>(values: impl Iterator<Item = &'a str>) -> Vec<FacetCount> {
+        let mut by_value: HashMap<&str, usize> = HashMap::new();
+        for value in values {
+            *by_value.entry(value).or_default() += 1;
+        }
+        let mut counts: Vec<(&str, usize)> = by_value.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        
+        let total: usize = counts.iter().map(|(_, count)| count).sum();
+        counts
+            .into_iter()
+            .map(|(value, count)| FacetCount {
+                value: value.to_string(),
+                count,
+                percentage: if total == 0 { 0.0 } else { count as f32 * 100.0 / total as f32 },
+            })
+            .collect()
+    }
+    
+    /// Per-type counts, with percentages of the combined total
+    pub fn for_doc_types(counts: &[(DocumentType, usize)]) -> Vec<FacetCount> {
+        let total: usize = counts.iter().map(|(_, count)| count).sum();
+        counts
+            .iter()
+            .map(|(doc_type, count)| FacetCount {
+                value: doc_type.as_str().to_string(),
+                count: *count,
+                percentage: if total == 0 { 0.0 } else { *count as f32 * 100.0 / total as f32 },
+            })
+            .collect()
+    }
+}
+
 /// Date range facet
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DateRangeFacet {
@@ -289,6 +354,10 @@ pub struct SearchSuggestionsQuery {
     pub context: Option<String>,
     /// Language for suggestions
     pub language: Option<String>,
+    /// Principal asking for suggestions; `None` for unauthenticated callers.
+    /// Set from the authenticated context, never from the request body.
+    #[serde(skip)]
+    pub principal: Option<Hrn>,
 }
 
 /// Response for search suggestions
@@ -448,8 +517,8 @@ pub struct ExecutionPlanNode {
     pub children: Vec<ExecutionPlanNode>,
 }
 
-/// Reuse artifact metadata from index_text_documents
-pub use crate::features::index_text_documents::dto::ArtifactMetadata;
+/// Reuse artifact metadata and document types from index_text_documents
+pub use crate::features::index_text_documents::dto::{ArtifactMetadata, DocumentType};
 
 /// Reuse index stats from index_text_documents
 pub use crate::features::index_text_documents::ports::IndexStats;
@@ -473,6 +542,9 @@ impl Default for FullTextSearchQuery {
             fuzziness: None,
             enable_stemming: Some(true),
             enable_phonetic: Some(false),
+            phrase_slop: None,
+            doc_types: None,
+            principal: None,
         }
     }
 }
@@ -500,6 +572,9 @@ impl FullTextSearchQuery {
             fuzziness: Some(1),
             enable_stemming: Some(true),
             enable_phonetic: Some(false),
+            phrase_slop: None,
+            doc_types: None,
+            principal: None,
         }
     }
 }
@@ -533,6 +608,7 @@ impl FullTextSearchResults {
             },
             facets: None,
             suggestions: None,
+            doc_type_counts: Vec::new(),
         }
     }
 }
\ No newline at end of file
@@ -170,6 +170,13 @@ pub enum FullTextSearchError {
         source: MergeError,
     },
     
+    /// Errors related to filtering results the caller may not view
+    #[error("Search authorization error: {source}")]
+    Authorization {
+        #[from]
+        source: AuthorizationError,
+    },
+    
     /// Configuration errors
     #[error("Configuration error: {0}")]
     InvalidConfiguration(String),
@@ -247,6 +254,7 @@ pub use super::ports::{
     HealthError,
     PatternError,
     IndexError,
+    AuthorizationError,
     // ConfigError is defined in ports.rs to avoid conflicts
     // MaintenanceError, SegmentError, MergeError are defined in error.rs
 };
@@ -565,6 +573,15 @@ impl FullTextSearchError {
                 component: "search_full_text".to_string(),
             },
             
+            // Authorization errors fail closed, so nothing leaks but searches stop working
+            FullTextSearchError::Authorization { .. } => ErrorCategory {
+                name: "Authorization".to_string(),
+                severity: ErrorSeverity::Error,
+                should_alert: true,
+                error_type: "authorization_error".to_string(),
+                component: "search_full_text".to_string(),
+            },
+            
             // Unexpected errors
             FullTextSearchError::Unexpected(_) => ErrorCategory {
                 name: "Unexpected".to_string(),
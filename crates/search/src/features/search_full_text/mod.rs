@@ -7,6 +7,7 @@ pub mod dto;
 pub mod ports;
 pub mod error;
 pub mod use_case;
+pub mod query_syntax;
 pub mod adapter;
 pub mod di;
 
@@ -16,8 +17,11 @@ pub use error::*;
 pub use ports::*;
 pub use di::*;
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::features::index_text_documents::LanguageAnalysisConfig;
+
 /// Feature initialization and configuration
 pub struct SearchFullTextFeature {
     pub di_container: Arc<SearchFullTextDIContainer>,
@@ -116,6 +120,12 @@ pub struct SearchFeatureConfig {
     pub enable_suggestions: bool,
     pub cache_size_mb: usize,
     pub optimization_interval_seconds: u64,
+    /// Query-time synonyms; keys and values may be multi-word (e.g. "k8s" -> ["kubernetes"])
+    pub synonyms: HashMap<String, Vec<String>>,
+    /// Weight of a synonym match relative to the original term (0.0 - 1.0)
+    pub synonym_boost: f32,
+    /// Per-document / per-field language used to pick the stemming analyzer
+    pub language_analysis: LanguageAnalysisConfig,
 }
 
 impl Default for SearchFeatureConfig {
@@ -128,14 +138,20 @@ impl Default for SearchFeatureConfig {
             enable_suggestions: true,
             cache_size_mb: 128,
             optimization_interval_seconds: 3600, // 1 hour
+            synonyms: HashMap::new(),
+            synonym_boost: crate::features::index_text_documents::synonyms::DEFAULT_SYNONYM_BOOST,
+            language_analysis: LanguageAnalysisConfig::default(),
         }
     }
 }
 
 impl SearchFullTextFeature {
     /// Create a search feature with custom configuration
-    pub fn with_config(config: SearchFeatureConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let di_container = Arc::new(SearchFullTextDIContainer::for_production(&config.index_path)?);
+    pub fn with_config(
+        config: SearchFeatureConfig,
+        authorization: Arc<dyn SearchAuthorizationPort>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let di_container = Arc::new(SearchFullTextDIContainer::for_production_with_config(&config, authorization)?);
         Ok(Self::new(di_container))
     }
 }
@@ -171,7 +187,10 @@ mod tests {
             ..Default::default()
         };
         
-        let result = SearchFullTextFeature::with_config(config);
+        let result = SearchFullTextFeature::with_config(
+            config,
+            Arc::new(adapter::test::AllowAllSearchAuthorization),
+        );
         
         // This might fail if we can't create the index directory
         // In a real test, we'd use a temporary directory
@@ -4,7 +4,9 @@
 //! for full-text search operations. Each port has a single, well-defined responsibility.
 
 use async_trait::async_trait;
+use std::collections::HashSet;
 use std::sync::Arc;
+use shared::hrn::Hrn;
 use super::dto::*;
 use super::error::*;
 
@@ -163,6 +165,19 @@ pub trait SearchIndexManagerPort: Send + Sync {
     async fn merge_segments(&self, merge_policy: MergePolicy) -> Result<MergeResult, MergeError>;
 }
 
+/// Port for document-level access checks on search results
+///
+/// Implementations delegate to the authorizer. Both methods receive the ids of
+/// candidate documents and return the subset the caller is allowed to see.
+#[async_trait]
+pub trait SearchAuthorizationPort: Send + Sync {
+    /// Ids of the documents `principal` is authorized to view
+    async fn authorized_documents(&self, principal: &Hrn, document_ids: &[String]) -> Result<HashSet<String>, AuthorizationError>;
+    
+    /// Ids of the documents visible to unauthenticated callers
+    async fn public_documents(&self, document_ids: &[String]) -> Result<HashSet<String>, AuthorizationError>;
+}
+
 /// Error types for search operations
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum SearchError {
@@ -410,6 +425,16 @@ pub enum IndexError {
     },
 }
 
+/// Error types for search result authorization
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AuthorizationError {
+    #[error("Authorization check failed: {0}")]
+    CheckFailed(String),
+    
+    #[error("Authorizer unavailable: {0}")]
+    AuthorizerUnavailable(String),
+}
+
 // Error types (MaintenanceError, SegmentError, MergeError) are defined in error.rs
 
 /// Error types for configuration
@@ -0,0 +1,206 @@
+//! Query syntax helpers for Full Text Search Feature
+//!
+//! Splits a raw query string into independent terms and quoted phrases.
+//! Phrases may carry an explicit proximity (`"connection pool"~3`); phrases
+//! without one use the default slop supplied by the caller. An unbalanced
+//! quote is never an error: the text after it is treated as literal terms.
+
+/// A single clause of a full-text query
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryClause {
+    /// Independent keyword
+    Term(String),
+    /// Ordered words that must appear within `slop` positions of each other
+    Phrase { words: Vec<String>, slop: u32 },
+}
+
+impl QueryClause {
+    /// Text used for highlighting this clause
+    pub fn text(&self) -> String {
+        match self {
+            QueryClause::Term(term) => term.clone(),
+            QueryClause::Phrase { words, .. } => words.join(" "),
+        }
+    }
+
+    pub fn is_phrase(&self) -> bool {
+        matches!(self, QueryClause::Phrase { .. })
+    }
+}
+
+/// Parse a raw query into terms and phrases
+pub fn parse_query_clauses(input: &str, default_slop: u32) -> Vec<QueryClause> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut clauses = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '"' {
+            let closing = chars[i + 1..].iter().position(|&ch| ch == '"').map(|p| i + 1 + p);
+
+            let Some(end) = closing else {
+                // Unbalanced quote: drop it and keep reading the rest as plain terms
+                push_term(&mut clauses, &mut current);
+                i += 1;
+                continue;
+            };
+
+            push_term(&mut clauses, &mut current);
+            let body: String = chars[i + 1..end].iter().collect();
+            i = end + 1;
+
+            let (slop, consumed) = parse_slop(&chars[i..]);
+            i += consumed;
+
+            let words: Vec<String> = body.split_whitespace().map(|w| w.to_string()).collect();
+            match words.len() {
+                0 => {}
+                1 => clauses.push(QueryClause::Term(words[0].clone())),
+                _ => clauses.push(QueryClause::Phrase {
+                    words,
+                    slop: slop.unwrap_or(default_slop),
+                }),
+            }
+            continue;
+        }
+
+        if c.is_whitespace() {
+            push_term(&mut clauses, &mut current);
+        } else {
+            current.push(c);
+        }
+        i += 1;
+    }
+
+    push_term(&mut clauses, &mut current);
+    clauses
+}
+
+/// Parse an optional `~N` suffix, returning the slop and the number of chars consumed
+fn parse_slop(rest: &[char]) -> (Option<u32>, usize) {
+    if rest.first() != Some(&'~') {
+        return (None, 0);
+    }
+
+    let digits: String = rest[1..].iter().take_while(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        // A bare `~` is consumed and ignored
+        return (None, 1);
+    }
+
+    (digits.parse().ok(), 1 + digits.len())
+}
+
+fn push_term(clauses: &mut Vec<QueryClause>, current: &mut String) {
+    if !current.is_empty() {
+        clauses.push(QueryClause::Term(std::mem::take(current)));
+    }
+}
+
+/// Locate a phrase in `text` (case-insensitive, any whitespace between words).
+///
+/// Returns the char range `(start, end)` of the first occurrence.
+pub fn find_phrase(text: &str, words: &[String]) -> Option<(usize, usize)> {
+    if words.is_empty() {
+        return None;
+    }
+
+    let lowered: Vec<char> = text.chars().flat_map(|c| c.to_lowercase()).collect();
+    // Only use the lowered text when lowercasing preserved char count, otherwise
+    // positions would no longer map back onto the original text.
+    if lowered.len() != text.chars().count() {
+        return None;
+    }
+
+    let targets: Vec<Vec<char>> = words
+        .iter()
+        .map(|w| w.to_lowercase().chars().collect())
+        .collect();
+
+    'outer: for start in 0..lowered.len() {
+        let mut pos = start;
+        for (idx, word) in targets.iter().enumerate() {
+            if idx > 0 {
+                let ws = lowered[pos..].iter().take_while(|c| c.is_whitespace()).count();
+                if ws == 0 {
+                    continue 'outer;
+                }
+                pos += ws;
+            }
+            if lowered.len() < pos + word.len() || lowered[pos..pos + word.len()] != word[..] {
+                continue 'outer;
+            }
+            pos += word.len();
+        }
+        return Some((start, pos));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phrase(words: &[&str], slop: u32) -> QueryClause {
+        QueryClause::Phrase {
+            words: words.iter().map(|w| w.to_string()).collect(),
+            slop,
+        }
+    }
+
+    #[test]
+    fn parses_plain_terms() {
+        let clauses = parse_query_clauses("connection pool", 0);
+        assert_eq!(
+            clauses,
+            vec![
+                QueryClause::Term("connection".to_string()),
+                QueryClause::Term("pool".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_quoted_phrase_with_default_slop() {
+        let clauses = parse_query_clauses("\"connection pool\" timeout", 1);
+        assert_eq!(
+            clauses,
+            vec![phrase(&["connection", "pool"], 1), QueryClause::Term("timeout".to_string())]
+        );
+    }
+
+    #[test]
+    fn parses_explicit_slop() {
+        let clauses = parse_query_clauses("\"connection pool\"~3", 0);
+        assert_eq!(clauses, vec![phrase(&["connection", "pool"], 3)]);
+    }
+
+    #[test]
+    fn unbalanced_quote_is_treated_as_literal_terms() {
+        let clauses = parse_query_clauses("\"connection pool", 0);
+        assert_eq!(
+            clauses,
+            vec![
+                QueryClause::Term("connection".to_string()),
+                QueryClause::Term("pool".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn single_word_phrase_becomes_term() {
+        let clauses = parse_query_clauses("\"pool\"~2", 0);
+        assert_eq!(clauses, vec![QueryClause::Term("pool".to_string())]);
+    }
+
+    #[test]
+    fn finds_phrase_across_whitespace() {
+        let words = vec!["connection".to_string(), "pool".to_string()];
+        assert_eq!(find_phrase("A Connection\n pool leak", &words), Some((2, 18)));
+        assert_eq!(find_phrase("pool connection", &words), None);
+    }
+}
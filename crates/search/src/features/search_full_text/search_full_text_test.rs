@@ -14,6 +14,7 @@ use super::use_case::*;
 use api::*;
 use di::*;
 use super::ports::*;
+use shared::hrn::Hrn;
 
 // Mock implementations for testing
 pub struct MockFullTextSearchAdapter {
@@ -49,16 +50,19 @@ impl FullTextSearchPort for MockFullTextSearchAdapter {
             });
         }
         
+        let page = query.page.unwrap_or(1);
+        let page_size = query.page_size.unwrap_or(20);
         Ok(FullTextSearchResults {
-            results: self.search_results.clone(),
+            results: self.search_results.iter().skip((page - 1) * page_size).take(page_size).cloned().collect(),
             total_count: self.search_results.len(),
-            page: query.page.unwrap_or(1),
-            page_size: query.page_size.unwrap_or(20),
+            page,
+            page_size,
             query_time_ms: 10,
             max_score: 1.0,
             metadata: SearchMetadata::default(),
-            facets: None,
+            facets: Some(SearchFacets::for_results(&self.search_results)),
             suggestions: None,
+            doc_type_counts: Vec::new(),
         })
     }
     
@@ -306,10 +310,47 @@ impl SearchIndexManagerPort for MockSearchIndexManager {
     }
 }
 
+pub struct MockSearchAuthorization {
+    pub public: std::collections::HashSet<String>,
+    pub granted: std::collections::HashSet<String>,
+}
+
+impl MockSearchAuthorization {
+    pub fn new(public: &[&str], granted: &[&str]) -> Self {
+        Self {
+            public: public.iter().map(|id| id.to_string()).collect(),
+            granted: granted.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl SearchAuthorizationPort for MockSearchAuthorization {
+    async fn authorized_documents(&self, _principal: &Hrn, document_ids: &[String]) -> Result<std::collections::HashSet<String>, AuthorizationError> {
+        Ok(document_ids
+            .iter()
+            .filter(|id| self.public.contains(*id) || self.granted.contains(*id))
+            .cloned()
+            .collect())
+    }
+    
+    async fn public_documents(&self, document_ids: &[String]) -> Result<std::collections::HashSet<String>, AuthorizationError> {
+        Ok(document_ids.iter().filter(|id| self.public.contains(*id)).cloned().collect())
+    }
+}
+
 // Test data helpers
+fn create_test_search_result_with_id(document_id: &str) -> SearchResult {
+    SearchResult {
+        document_id: document_id.to_string(),
+        ..create_test_search_result()
+    }
+}
+
 fn create_test_search_result() -> SearchResult {
     SearchResult {
         document_id: "test-doc-1".to_string(),
+        doc_type: DocumentType::Artifact,
         metadata: ArtifactMetadata {
             title: Some("Test Document".to_string()),
             description: Some("A test document for testing".to_string()),
@@ -347,6 +388,9 @@ fn create_test_search_query() -> FullTextSearchQuery {
         fuzziness: None,
         enable_stemming: Some(true),
         enable_phonetic: None,
+        phrase_slop: None,
+        doc_types: None,
+        principal: None,
     }
 }
 
@@ -377,6 +421,164 @@ async fn test_full_text_search_use_case_success() {
     assert_eq!(search_results.total_count, 1);
 }
 
+fn create_authorized_use_case(authorization: MockSearchAuthorization) -> FullTextSearchUseCase {
+    let results = ["public-1", "private-1", "public-2", "private-2", "public-3"]
+        .iter()
+        .map(|id| create_test_search_result_with_id(id))
+        .collect();
+    
+    FullTextSearchUseCase::new(
+        Arc::new(MockFullTextSearchAdapter::new().with_results(results)),
+        Arc::new(MockQueryAnalyzer::new()),
+        Arc::new(MockRelevanceScorer::new()),
+        Arc::new(MockHighlighter::new()),
+        Arc::new(MockSearchPerformanceMonitor::new()),
+    )
+    .with_authorization(Arc::new(authorization))
+}
+
+#[tokio::test]
+async fn test_anonymous_search_only_sees_public_documents() {
+    let use_case = create_authorized_use_case(
+        MockSearchAuthorization::new(&["public-1", "public-2", "public-3"], &["private-1"]),
+    );
+    
+    let results = use_case.execute_search(FullTextSearchQuery {
+        q: "test".to_string(),
+        ..Default::default()
+    }).await.unwrap();
+    
+    assert_eq!(results.total_count, 3);
+    assert!(results.results.iter().all(|r| r.document_id.starts_with("public-")));
+}
+
+#[tokio::test]
+async fn test_principal_sees_granted_documents() {
+    let use_case = create_authorized_use_case(
+        MockSearchAuthorization::new(&["public-1"], &["private-1"]),
+    );
+    
+    let results = use_case.execute_search(FullTextSearchQuery {
+        q: "test".to_string(),
+        principal: Some(Hrn::new("hrn:hodei:iam:global:acme:user/alice").unwrap()),
+        ..Default::default()
+    }).await.unwrap();
+    
+    let ids: Vec<&str> = results.results.iter().map(|r| r.document_id.as_str()).collect();
+    assert_eq!(results.total_count, 2);
+    assert!(ids.contains(&"public-1"));
+    assert!(ids.contains(&"private-1"));
+}
+
+#[tokio::test]
+async fn test_pagination_counts_only_visible_documents() {
+    let use_case = create_authorized_use_case(
+        MockSearchAuthorization::new(&["public-1", "public-2", "public-3"], &[]),
+    );
+    
+    let results = use_case.execute_search(FullTextSearchQuery {
+        q: "test".to_string(),
+        page: Some(2),
+        page_size: Some(2),
+        ..Default::default()
+    }).await.unwrap();
+    
+    assert_eq!(results.total_count, 3);
+    assert_eq!(results.page, 2);
+    assert_eq!(results.results.len(), 1);
+}
+
+#[tokio::test]
+async fn test_authorized_search_pages_past_the_batch_size() {
+    // Every visible document ranks below a full batch of hidden ones
+    let results = (0..5)
+        .map(|i| create_test_search_result_with_id(&format!("private-{}", i)))
+        .chain((0..3).map(|i| create_test_search_result_with_id(&format!("public-{}", i))))
+        .collect();
+    let use_case = FullTextSearchUseCase::new(
+        Arc::new(MockFullTextSearchAdapter::new().with_results(results)),
+        Arc::new(MockQueryAnalyzer::new()),
+        Arc::new(MockRelevanceScorer::new()),
+        Arc::new(MockHighlighter::new()),
+        Arc::new(MockSearchPerformanceMonitor::new()),
+    )
+    .with_authorization(Arc::new(MockSearchAuthorization::new(&["public-0", "public-1", "public-2"], &[])))
+    .with_authorization_batch_size(2);
+    
+    let results = use_case.execute_search(FullTextSearchQuery {
+        q: "test".to_string(),
+        ..Default::default()
+    }).await.unwrap();
+    
+    assert_eq!(results.total_count, 3);
+    assert_eq!(results.results.len(), 3);
+    let facets = results.facets.unwrap();
+    assert_eq!(facets.artifact_types.iter().map(|f| f.count).sum::<usize>(), 3);
+}
+
+#[tokio::test]
+async fn test_doc_type_counts_only_include_visible_documents() {
+    let results = vec![
+        create_test_search_result_with_id("public-1"),
+        SearchResult {
+            doc_type: DocumentType::Repository,
+            ..create_test_search_result_with_id("public-repo")
+        },
+        SearchResult {
+            doc_type: DocumentType::Repository,
+            ..create_test_search_result_with_id("private-repo")
+        },
+    ];
+    let use_case = FullTextSearchUseCase::new(
+        Arc::new(MockFullTextSearchAdapter::new().with_results(results)),
+        Arc::new(MockQueryAnalyzer::new()),
+        Arc::new(MockRelevanceScorer::new()),
+        Arc::new(MockHighlighter::new()),
+        Arc::new(MockSearchPerformanceMonitor::new()),
+    )
+    .with_authorization(Arc::new(MockSearchAuthorization::new(&["public-1", "public-repo"], &["private-repo"])));
+    
+    let results = use_case.execute_search(FullTextSearchQuery {
+        q: "test".to_string(),
+        ..Default::default()
+    }).await.unwrap();
+    
+    let counts: Vec<(&str, usize)> = results.doc_type_counts.iter().map(|c| (c.value.as_str(), c.count)).collect();
+    assert_eq!(counts, vec![("artifact", 1), ("repository", 1)]);
+}
+
+#[tokio::test]
+async fn test_doc_type_counts_cover_every_type_when_restricted_and_only_visible_documents() {
+    let results = vec![
+        create_test_search_result_with_id("public-1"),
+        create_test_search_result_with_id("private-1"),
+        SearchResult {
+            doc_type: DocumentType::Repository,
+            ..create_test_search_result_with_id("public-repo")
+        },
+    ];
+    let use_case = FullTextSearchUseCase::new(
+        Arc::new(MockFullTextSearchAdapter::new().with_results(results)),
+        Arc::new(MockQueryAnalyzer::new()),
+        Arc::new(MockRelevanceScorer::new()),
+        Arc::new(MockHighlighter::new()),
+        Arc::new(MockSearchPerformanceMonitor::new()),
+    )
+    .with_authorization(Arc::new(MockSearchAuthorization::new(&["public-1", "public-repo"], &["private-1"])));
+    
+    let results = use_case.execute_search(FullTextSearchQuery {
+        q: "test".to_string(),
+        doc_types: Some(vec![DocumentType::Repository]),
+        ..Default::default()
+    }).await.unwrap();
+    
+    let ids: Vec<&str> = results.results.iter().map(|r| r.document_id.as_str()).collect();
+    assert_eq!(ids, vec!["public-repo"]);
+    assert_eq!(results.total_count, 1);
+    let counts: Vec<(&str, usize)> = results.doc_type_counts.iter().map(|c| (c.value.as_str(), c.count)).collect();
+    assert_eq!(counts, vec![("artifact", 1), ("repository", 1)]);
+}
+
 #[tokio::test]
 async fn test_full_text_search_use_case_failure() {
     let search_adapter = Arc::new(MockFullTextSearchAdapter::new().failing());
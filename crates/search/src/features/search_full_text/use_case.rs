@@ -9,11 +9,110 @@ use tokio::sync::Semaphore;
 use futures::future::try_join_all;
 use tracing::{debug, info, warn, error, instrument};
 use async_trait::async_trait;
+use shared::hrn::Hrn;
 
 use super::dto::*;
 use super::ports::*;
 use super::error::{FullTextSearchError, ToFullTextSearchError, WithContext};
 
+/// Default number of matches fetched and checked per round when filtering by principal
+pub const DEFAULT_AUTHORIZATION_BATCH_SIZE: usize = 1000;
+
+/// Narrows search matches to the documents the caller is allowed to view
+///
+/// Matches are fetched from the engine a batch at a time until it runs out, so
+/// what a caller sees never depends on how many hidden documents rank higher.
+#[derive(Clone)]
+pub struct SearchAccessFilter {
+    search_engine: Arc<dyn FullTextSearchPort>,
+    authorization: Arc<dyn SearchAuthorizationPort>,
+    batch_size: usize,
+}
+
+impl SearchAccessFilter {
+    pub fn new(search_engine: Arc<dyn FullTextSearchPort>, authorization: Arc<dyn SearchAuthorizationPort>) -> Self {
+        Self {
+            search_engine,
+            authorization,
+            batch_size: DEFAULT_AUTHORIZATION_BATCH_SIZE,
+        }
+    }
+    
+    /// Number of matches fetched and checked per round trip to the authorizer
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+    
+    /// Matches of `query` visible to its principal, in engine order
+    ///
+    /// Stops early once `limit` visible matches were found. The returned
+    /// `total_count` is the number of visible matches collected.
+    pub async fn visible_matches(
+        &self,
+        query: &FullTextSearchQuery,
+        limit: Option<usize>,
+    ) -> Result<FullTextSearchResults, FullTextSearchError> {
+        let mut batch_query = FullTextSearchQuery {
+            page: Some(1),
+            page_size: Some(self.batch_size),
+            ..query.clone()
+        };
+        let (mut results, mut exhausted) = self.visible_batch(&batch_query).await?;
+        
+        while !exhausted && limit.is_none_or(|limit| results.results.len() < limit) {
+            batch_query.page = batch_query.page.map(|page| page + 1);
+            let (batch, batch_exhausted) = self.visible_batch(&batch_query).await?;
+            results.results.extend(batch.results);
+            exhausted = batch_exhausted;
+        }
+        
+        if let Some(limit) = limit {
+            results.results.truncate(limit);
+        }
+        results.total_count = results.results.len();
+        Ok(results)
+    }
+    
+    /// Whether `q` matches at least one document visible to `principal`
+    pub async fn has_visible_match(&self, q: &str, principal: Option<&Hrn>) -> Result<bool, FullTextSearchError> {
+        let query = FullTextSearchQuery {
+            q: q.to_string(),
+            principal: principal.cloned(),
+            ..Default::default()
+        };
+        Ok(!self.visible_matches(&query, Some(1)).await?.results.is_empty())
+    }
+    
+    /// Fetch one batch and drop what the principal can't view; also reports
+    /// whether the engine has no further matches
+    async fn visible_batch(&self, query: &FullTextSearchQuery) -> Result<(FullTextSearchResults, bool), FullTextSearchError> {
+        let mut batch = self.search_engine
+            .search(query.clone())
+            .await
+            .map_err(|e| FullTextSearchError::Search { source: e })?;
+        
+        let fetched = batch.results.len();
+        let fetched_so_far = query.page.unwrap_or(1).saturating_mul(self.batch_size);
+        let exhausted = fetched < self.batch_size || fetched_so_far >= batch.total_count;
+        
+        let document_ids: Vec<String> = batch.results.iter().map(|r| r.document_id.clone()).collect();
+        let visible = match &query.principal {
+            Some(principal) => self.authorization.authorized_documents(principal, &document_ids).await?,
+            None => self.authorization.public_documents(&document_ids).await?,
+        };
+        batch.results.retain(|r| visible.contains(&r.document_id));
+        debug!(
+            candidates = fetched,
+            visible = batch.results.len(),
+            authenticated = query.principal.is_some(),
+            "Filtered search results by access"
+        );
+        
+        Ok((batch, exhausted))
+    }
+}
+
 /// Use case for executing full-text searches
 pub struct FullTextSearchUseCase {
     search_engine: Arc<dyn FullTextSearchPort>,
@@ -22,6 +121,8 @@ pub struct FullTextSearchUseCase {
     highlighter: Arc<dyn HighlighterPort>,
     performance_monitor: Arc<dyn SearchPerformanceMonitorPort>,
     max_concurrent_queries: usize,
+    authorization: Option<Arc<dyn SearchAuthorizationPort>>,
+    authorization_batch_size: usize,
 }
 
 impl FullTextSearchUseCase {
@@ -39,6 +140,8 @@ impl FullTextSearchUseCase {
             highlighter,
             performance_monitor,
             max_concurrent_queries: 10,
+            authorization: None,
+            authorization_batch_size: DEFAULT_AUTHORIZATION_BATCH_SIZE,
         }
     }
     
@@ -47,6 +150,25 @@ impl FullTextSearchUseCase {
         self
     }
     
+    /// Only return documents the query's principal is authorized to view
+    pub fn with_authorization(mut self, authorization: Arc<dyn SearchAuthorizationPort>) -> Self {
+        self.authorization = Some(authorization);
+        self
+    }
+    
+    /// Number of matches fetched and checked per round when filtering results
+    pub fn with_authorization_batch_size(mut self, batch_size: usize) -> Self {
+        self.authorization_batch_size = batch_size.max(1);
+        self
+    }
+    
+    fn access_filter(&self) -> Option<SearchAccessFilter> {
+        self.authorization.as_ref().map(|authorization| {
+            SearchAccessFilter::new(self.search_engine.clone(), authorization.clone())
+                .with_batch_size(self.authorization_batch_size)
+        })
+    }
+    
     /// Execute a full-text search query
     #[instrument(skip(self))]
     pub async fn execute_search(&self, query: FullTextSearchQuery) -> Result<FullTextSearchResults, FullTextSearchError> {
@@ -73,11 +195,14 @@ impl FullTextSearchUseCase {
         
         debug!("Query optimized with estimated cost: {}", optimized_query.estimated_cost);
         
-        // Execute the search
-        let mut search_results = self.search_engine
-            .search(query.clone())
-            .await
-            .map_err(|e| FullTextSearchError::Search { source: e })?;
+        // Execute the search, filtering out documents the principal can't view
+        let mut search_results = match self.access_filter() {
+            Some(access_filter) => self.search_authorized(&query, &access_filter).await?,
+            None => self.search_engine
+                .search(query.clone())
+                .await
+                .map_err(|e| FullTextSearchError::Search { source: e })?,
+        };
         
         // Apply additional processing if needed
         if query.include_highlights || query.include_snippets {
@@ -158,6 +283,60 @@ impl FullTextSearchUseCase {
         Ok(analysis)
     }
     
+    /// Collect every match the principal can view, then paginate
+    ///
+    /// Totals, document type counts, facets and suggestions are derived from the
+    /// visible documents only, so none of them reveal that hidden documents matched.
+    async fn search_authorized(
+        &self,
+        query: &FullTextSearchQuery,
+        access_filter: &SearchAccessFilter,
+    ) -> Result<FullTextSearchResults, FullTextSearchError> {
+        let page = query.page.unwrap_or(1).max(1);
+        let page_size = query.page_size.unwrap_or(20);
+        
+        // Fetch every type so the per-type counts ignore the restriction, as the
+        // engine's do, but only count documents the principal can view
+        let unrestricted = FullTextSearchQuery {
+            doc_types: None,
+            ..query.clone()
+        };
+        let mut results = access_filter.visible_matches(&unrestricted, None).await?;
+        
+        let visible_counts: Vec<(DocumentType, usize)> = DocumentType::ALL
+            .into_iter()
+            .map(|doc_type| (doc_type, results.results.iter().filter(|r| r.doc_type == doc_type).count()))
+            .collect();
+        results.doc_type_counts = FacetCount::for_doc_types(&visible_counts);
+        if let Some(doc_types) = query.doc_types.as_deref().filter(|types| !types.is_empty()) {
+            results.results.retain(|r| doc_types.contains(&r.doc_type));
+            results.total_count = results.results.len();
+        }
+        if results.facets.is_some() {
+            results.facets = Some(SearchFacets::for_results(&results.results));
+        }
+        if let Some(suggestions) = results.suggestions.take() {
+            let mut visible_suggestions = Vec::with_capacity(suggestions.len());
+            for suggestion in suggestions {
+                if access_filter.has_visible_match(&suggestion.text, query.principal.as_ref()).await? {
+                    visible_suggestions.push(suggestion);
+                }
+            }
+            results.suggestions = Some(visible_suggestions);
+        }
+        
+        results.results = results.results
+            .into_iter()
+            .skip((page - 1).saturating_mul(page_size))
+            .take(page_size)
+            .collect();
+        results.page = page;
+        results.page_size = page_size;
+        results.max_score = results.results.iter().map(|r| r.score).fold(0.0, f32::max);
+        
+        Ok(results)
+    }
+    
     /// Validate search query
     async fn validate_query(&self, query: &FullTextSearchQuery) -> Result<(), FullTextSearchError> {
         if query.q.trim().is_empty() {
@@ -358,6 +537,7 @@ pub struct SearchSuggestionsUseCase {
     search_engine: Arc<dyn FullTextSearchPort>,
     query_analyzer: Arc<dyn QueryAnalyzerPort>,
     cache: Arc<SuggestionCache>,
+    access_filter: Option<SearchAccessFilter>,
 }
 
 impl SearchSuggestionsUseCase {
@@ -369,16 +549,30 @@ impl SearchSuggestionsUseCase {
             search_engine,
             query_analyzer,
             cache: Arc::new(SuggestionCache::new()),
+            access_filter: None,
         }
     }
     
+    /// Only suggest queries that match a document the caller is authorized to view
+    pub fn with_authorization(mut self, authorization: Arc<dyn SearchAuthorizationPort>) -> Self {
+        self.access_filter = Some(SearchAccessFilter::new(self.search_engine.clone(), authorization));
+        self
+    }
+    
     /// Get search suggestions with caching
     #[instrument(skip(self))]
     pub async fn get_suggestions(&self, query: SearchSuggestionsQuery) -> Result<SearchSuggestionsResponse, FullTextSearchError> {
         debug!("Getting suggestions for query: {}", query.partial_query);
         
+        // Suggestions depend on what the caller can see, so cache them per principal
+        let cache_key = match (&self.access_filter, &query.principal) {
+            (Some(_), Some(principal)) => format!("{}|{}", principal, query.partial_query),
+            (Some(_), None) => format!("|{}", query.partial_query),
+            (None, _) => query.partial_query.clone(),
+        };
+        
         // Check cache first
-        if let Some(cached) = self.cache.get(&query.partial_query).await {
+        if let Some(cached) = self.cache.get(&cache_key).await {
             debug!("Returning cached suggestions for: {}", query.partial_query);
             return Ok(cached);
         }
@@ -404,13 +598,23 @@ impl SearchSuggestionsUseCase {
             suggestions.suggestions.extend(additional_suggestions);
         }
         
+        if let Some(access_filter) = &self.access_filter {
+            let mut visible = Vec::with_capacity(suggestions.suggestions.len());
+            for suggestion in suggestions.suggestions {
+                if access_filter.has_visible_match(&suggestion.text, query.principal.as_ref()).await? {
+                    visible.push(suggestion);
+                }
+            }
+            suggestions.suggestions = visible;
+        }
+        
         // Sort by score and limit results
         suggestions.suggestions.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
         suggestions.suggestions.truncate(query.limit.unwrap_or(10));
         suggestions.total_count = suggestions.suggestions.len();
         
         // Cache the results
-        self.cache.put(&query.partial_query, suggestions.clone()).await;
+        self.cache.put(&cache_key, suggestions.clone()).await;
         
         info!(
             partial_query = %query.partial_query,
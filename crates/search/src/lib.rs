@@ -12,7 +12,7 @@ pub use features::basic_search::{
     BasicSearchDIContainer, SearchQuery, SearchResults, ArtifactDocument, BasicSearchError
 };
 pub use features::index_text_documents::{
-    IndexTextDocumentsDIContainer, IndexDocumentCommand, DocumentIndexedResponse, IndexDocumentError
+    IndexTextDocumentsDIContainer, IndexDocumentCommand, DocumentIndexedResponse, IndexDocumentError, DocumentType
 };
 pub use features::search_full_text::{
     SearchFullTextDIContainer, SearchRequest, FullTextSearchResults, FullTextSearchError,
@@ -51,6 +51,7 @@ import type {
 
 // ===== CONFIGURACIÓN DEL CLIENTE =====
 const API_BASE_URL = 'http://localhost:8080/v2';
+const DEFAULT_PRESIGNED_URL_EXPIRY_SECONDS = 3600; // 1 hora
 const API_VERSION = 'v2.1.0';
 
 // Interfaz para el cliente API
@@ -72,6 +73,7 @@ export interface OpenAPIClient {
   getArtifact(params: {
     id: string;
     presigned?: boolean;
+    expiresIn?: number;
   }): Promise<Blob | PresignedUrlResponse>;
 
   // SEARCH
@@ -294,14 +296,17 @@ class OpenAPIClientImpl implements OpenAPIClient {
   async getArtifact(params: {
     id: string;
     presigned?: boolean;
+    expiresIn?: number;
   }): Promise<Blob | PresignedUrlResponse> {
     await this.mockDelay(200);
 
     if (params.presigned) {
-      // Retornar URL presignada
+      // Retornar URL presignada; el recorte al máximo lo hace el servidor
+      const expiresIn = params.expiresIn ?? DEFAULT_PRESIGNED_URL_EXPIRY_SECONDS;
       return {
         url: `${API_BASE_URL}/artifacts/${params.id}/download?token=mock-presigned-token`,
-        expiresAt: new Date(Date.now() + 3600000).toISOString(), // 1 hora
+        expiresAt: new Date(Date.now() + expiresIn * 1000).toISOString(),
+        expiresIn,
       };
     } else {
       // Retornar blob binario
@@ -62,7 +62,8 @@ export class ArtifactService {
    */
   async getArtifact(
     id: string,
-    presigned: boolean = false
+    presigned: boolean = false,
+    expiresIn?: number
   ): Promise<Blob | PresignedUrlResponse> {
     if (!id || id.trim().length === 0) {
       throw new Error('Artifact ID is required');
@@ -72,6 +73,7 @@ export class ArtifactService {
       const params: GetArtifactParams = {
         id,
         presigned,
+        expiresIn,
       };
 
       return await this.artifactPort.getArtifact(params);
@@ -82,10 +84,16 @@ export class ArtifactService {
   }
 
   /**
-   * Get a presigned URL to download an artifact
+   * Get a download-only presigned URL for an artifact
+   *
+   * `expiresIn` (seconds) is clamped by the server to its configured maximum;
+   * the effective value comes back in the response.
    */
-  async getPresignedUrl(id: string): Promise<PresignedUrlResponse> {
-    const result = await this.getArtifact(id, true);
+  async getPresignedUrl(
+    id: string,
+    expiresIn?: number
+  ): Promise<PresignedUrlResponse> {
+    const result = await this.getArtifact(id, true, expiresIn);
 
     if (result instanceof Blob) {
       throw new Error('Expected a presigned URL, but received a blob');
@@ -37,6 +37,7 @@ export interface ArtifactUploadResponse {
 export interface PresignedUrlResponse {
   url?: string; // uri
   expiresAt?: string; // date-time
+  expiresIn?: number; // int64, validez efectiva en segundos
 }
 
 // ===== ESQUEMAS DE SEARCH =====
@@ -296,6 +297,7 @@ export interface UploadArtifactBody {
 export interface GetArtifactParams {
   id: string;
   presigned?: boolean;
+  expiresIn?: number; // int64, segundos; el servidor la recorta a su máximo
 }
 
 // GET /v1/search
@@ -33,6 +33,7 @@ export interface ArtifactUploadResponse {
 export interface PresignedUrlResponse {
   url: string;
   expiresAt: string;
+  expiresIn?: number;
 }
 
 // User Types
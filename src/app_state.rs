@@ -12,6 +12,7 @@
 //! - Es clonado e inyectado en cada handler de Axum
 
 use crate::composition_root::CompositionRoot;
+use crate::readiness::EngineReadinessCheck;
 use hodei_iam::register_iam_schema::ports::RegisterIamSchemaPort;
 use hodei_policies::build_schema::ports::BuildSchemaPort;
 use hodei_policies::evaluate_policies::ports::EvaluatePoliciesPort;
@@ -20,6 +21,11 @@ use hodei_policies::load_schema::ports::LoadSchemaPort;
 use hodei_policies::register_action_type::ports::RegisterActionTypePort;
 use hodei_policies::register_entity_type::ports::RegisterEntityTypePort;
 use hodei_policies::validate_policy::port::ValidatePolicyPort;
+use hodei_iam::set_user_status::SetUserStatusUseCasePort;
+use hodei_iam::update_group_attributes::UpdateGroupAttributesUseCasePort;
+use hodei_iam::update_user_attributes::UpdateUserAttributesUseCasePort;
+use kernel::infrastructure::audit::AuditLogStore;
+use kernel::{InMemoryEventBus, Subscription};
 use std::sync::Arc;
 
 /// Application state containing all use case ports
@@ -74,7 +80,11 @@ pub struct AppState {
     pub create_policy: Arc<dyn hodei_iam::features::create_policy::ports::CreatePolicyUseCasePort>,
 
     /// Port for getting IAM policies
-    pub get_policy: Arc<dyn hodei_iam::features::get_policy::ports::PolicyReader>,
+    pub get_policy: Arc<dyn hodei_iam::features::get_policy::ports::GetPolicyUseCasePort>,
+
+    /// Port for getting several IAM policies in one call
+    pub get_policies:
+        Arc<dyn hodei_iam::features::get_policies::ports::GetPoliciesUseCasePort>,
 
     /// Port for listing IAM policies
     pub list_policies: Arc<dyn hodei_iam::features::list_policies::ports::PolicyLister>,
@@ -84,6 +94,38 @@ pub struct AppState {
 
     /// Port for deleting IAM policies
     pub delete_policy: Arc<dyn hodei_iam::features::delete_policy::ports::DeletePolicyPort>,
+
+    /// Port for suspending, deactivating and reinstating users
+    #[allow(dead_code)]
+    pub set_user_status: Arc<dyn SetUserStatusUseCasePort>,
+
+    /// Port for updating user attributes
+    #[allow(dead_code)]
+    pub update_user_attributes: Arc<dyn UpdateUserAttributesUseCasePort>,
+
+    /// Port for updating group attributes
+    #[allow(dead_code)]
+    pub update_group_attributes: Arc<dyn UpdateGroupAttributesUseCasePort>,
+
+    // ============================================================
+    // Health
+    // ============================================================
+    /// Readiness check of the authorization engine
+    pub engine_readiness: Arc<EngineReadinessCheck>,
+
+    // ============================================================
+    // Events
+    // ============================================================
+    /// Domain event bus, drained on shutdown
+    pub event_bus: Arc<InMemoryEventBus>,
+
+    /// Audit log of the domain events published on the bus
+    #[allow(dead_code)]
+    pub audit_log: Arc<AuditLogStore>,
+
+    /// Subscriptions of the bus handlers, kept for as long as the server runs
+    #[allow(dead_code)]
+    pub event_subscriptions: Vec<Arc<dyn Subscription>>,
 }
 
 impl AppState {
@@ -103,6 +145,13 @@ impl AppState {
     /// * `evaluate_policies` - Port for evaluating policies
     /// * `playground_evaluate` - Port for playground evaluation
     /// * `register_iam_schema` - Port for IAM schema registration
+    /// * `set_user_status` - Port for changing a user's status
+    /// * `update_user_attributes` - Port for updating user attributes
+    /// * `update_group_attributes` - Port for updating group attributes
+    /// * `engine_readiness` - Readiness check of the authorization engine
+    /// * `event_bus` - Domain event bus
+    /// * `audit_log` - Audit log of the domain events
+    /// * `event_subscriptions` - Subscriptions of the bus handlers
     ///
     /// # Example
     ///
@@ -136,10 +185,18 @@ impl AppState {
         playground_evaluate: Arc<dyn PlaygroundEvaluatePort>,
         register_iam_schema: Arc<dyn RegisterIamSchemaPort>,
         create_policy: Arc<dyn hodei_iam::features::create_policy::ports::CreatePolicyUseCasePort>,
-        get_policy: Arc<dyn hodei_iam::features::get_policy::ports::PolicyReader>,
+        get_policy: Arc<dyn hodei_iam::features::get_policy::ports::GetPolicyUseCasePort>,
+        get_policies: Arc<dyn hodei_iam::features::get_policies::ports::GetPoliciesUseCasePort>,
         list_policies: Arc<dyn hodei_iam::features::list_policies::ports::PolicyLister>,
         update_policy: Arc<dyn hodei_iam::features::update_policy::ports::UpdatePolicyPort>,
         delete_policy: Arc<dyn hodei_iam::features::delete_policy::ports::DeletePolicyPort>,
+        set_user_status: Arc<dyn SetUserStatusUseCasePort>,
+        update_user_attributes: Arc<dyn UpdateUserAttributesUseCasePort>,
+        update_group_attributes: Arc<dyn UpdateGroupAttributesUseCasePort>,
+        engine_readiness: Arc<EngineReadinessCheck>,
+        event_bus: Arc<InMemoryEventBus>,
+        audit_log: Arc<AuditLogStore>,
+        event_subscriptions: Vec<Arc<dyn Subscription>>,
     ) -> Self {
         Self {
             schema_version,
@@ -153,9 +210,17 @@ impl AppState {
             register_iam_schema,
             create_policy,
             get_policy,
+            get_policies,
             list_policies,
             update_policy,
             delete_policy,
+            set_user_status,
+            update_user_attributes,
+            update_group_attributes,
+            engine_readiness,
+            event_bus,
+            audit_log,
+            event_subscriptions,
         }
     }
 
@@ -173,6 +238,13 @@ impl AppState {
     /// let app_state = AppState::from_composition_root("v1.0.0".to_string(), root);
     /// ```
     pub fn from_composition_root(schema_version: String, root: CompositionRoot) -> Self {
+        let engine_readiness = Arc::new(EngineReadinessCheck::new(
+            root.policy_ports.load_schema.clone(),
+            root.iam_ports.list_policies.clone(),
+            root.iam_ports.get_policies.clone(),
+            root.policy_ports.readiness_evaluator,
+        ));
+
         Self {
             schema_version,
             register_entity_type: root.policy_ports.register_entity_type,
@@ -185,9 +257,17 @@ impl AppState {
             register_iam_schema: root.iam_ports.register_iam_schema,
             create_policy: root.iam_ports.create_policy,
             get_policy: root.iam_ports.get_policy,
+            get_policies: root.iam_ports.get_policies,
             list_policies: root.iam_ports.list_policies,
             update_policy: root.iam_ports.update_policy,
             delete_policy: root.iam_ports.delete_policy,
+            set_user_status: root.iam_ports.set_user_status,
+            update_user_attributes: root.iam_ports.update_user_attributes,
+            update_group_attributes: root.iam_ports.update_group_attributes,
+            engine_readiness,
+            event_bus: root.event_bus,
+            audit_log: root.audit_log,
+            event_subscriptions: root.event_subscriptions,
         }
     }
 }
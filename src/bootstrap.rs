@@ -1,25 +1,30 @@
 //! Bootstrap module for Hodei Artifacts API
 //!
-//! This module handles the initialization of the application, including:
+//! This module handles the initialization of the application, in named and
+//! individually timed phases, including:
 //! - RocksDB database connection setup
-//! - Infrastructure adapter creation
+//! - Infrastructure adapter creation, including the configured schema storage
 //! - Use case composition via CompositionRoot
-//! - Optional IAM schema registration
+//! - Optional IAM schema registration, preceded by every bounded context's
+//!   schema fragments
 
 use crate::app_state::AppState;
 use crate::composition_root::CompositionRoot;
 use crate::config::AppConfig;
-use async_trait::async_trait;
+use crate::schema_storage::SchemaStorage;
 
 use hodei_iam::register_iam_schema::dto::{
     RegisterIamSchemaCommand, RegisterIamSchemaResult,
 };
 use hodei_iam::infrastructure::surreal::policy_adapter::SurrealPolicyAdapter;
-use hodei_policies::build_schema::error::BuildSchemaError;
-use hodei_policies::build_schema::ports::SchemaStoragePort;
+use hodei_policies::build_schema::dto::{BuildSchemaCommand, BuildSchemaResult};
+use hodei_policies::register_schema_fragments::dto::RegisterSchemaFragmentsResult;
+use hodei_iam::infrastructure::surreal::{SurrealGroupAdapter, SurrealUserAdapter};
+use kernel::infrastructure::webhook::WebhookEndpoint;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use surrealdb::Surreal;
-use surrealdb::engine::local::RocksDb;
+use surrealdb::engine::local::{Db, RocksDb};
 use tracing::{error, info, warn};
 
 /// Configuration for the bootstrap process
@@ -54,49 +59,323 @@ pub enum BootstrapError {
 
     #[error("Failed to register IAM schema: {0}")]
     SchemaRegistration(String),
+
+    #[error("Bootstrap phase '{phase}' failed: {source}")]
+    PhaseFailed {
+        phase: BootstrapPhase,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+/// Whether startup can go on without a phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Criticality {
+    /// The service can't run without it; failing aborts startup
+    Critical,
+    /// Failing is logged and startup continues
+    NonCritical,
+}
+
+/// Named step of the bootstrap, in the order they run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapPhase {
+    /// Validating the configuration
+    Configuration,
+    /// Opening the database
+    Storage,
+    /// Setting up the configured schema storage backend
+    SchemaStorage,
+    /// Wiring the use cases together
+    Composition,
+    /// Running a no-op through the authorization path of the wiring
+    SelfCheck,
+    /// Subscribing the configured webhook endpoints to the event bus
+    Webhooks,
+    /// Registering every bounded context's schema fragment
+    SchemaFragments,
+    /// Registering the IAM schema
+    IamSchema,
+    /// Checking the engine once, so the first probe finds it warm
+    WarmUp,
+}
+
+impl BootstrapPhase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Configuration => "configuration",
+            Self::Storage => "storage",
+            Self::SchemaStorage => "schema_storage",
+            Self::Composition => "composition",
+            Self::SelfCheck => "self_check",
+            Self::Webhooks => "webhooks",
+            Self::SchemaFragments => "schema_fragments",
+            Self::IamSchema => "iam_schema",
+            Self::WarmUp => "warm_up",
+        }
+    }
+
+    pub fn criticality(self) -> Criticality {
+        match self {
+            Self::Configuration
+            | Self::Storage
+            | Self::SchemaStorage
+            | Self::Composition
+            | Self::SelfCheck
+            | Self::Webhooks
+            | Self::SchemaFragments
+            | Self::IamSchema => Criticality::Critical,
+            Self::WarmUp => Criticality::NonCritical,
+        }
+    }
+}
+
+impl std::fmt::Display for BootstrapPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How a phase ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhaseOutcome {
+    Succeeded,
+    Skipped,
+    Failed(String),
+}
+
+/// Timing and outcome of one phase
+#[derive(Debug, Clone)]
+pub struct PhaseTiming {
+    pub phase: BootstrapPhase,
+    pub duration: Duration,
+    pub outcome: PhaseOutcome,
+}
+
+/// Runs the phases, timing each and deciding what a failure means
+#[derive(Debug, Default)]
+struct PhaseRunner {
+    timings: Vec<PhaseTiming>,
+}
+
+impl PhaseRunner {
+    /// Run a phase, handling its failure as the phase's criticality says
+    ///
+    /// A critical phase's failure is the bootstrap's failure. A non-critical
+    /// phase's failure is logged and gives `Ok(None)`, so startup goes on.
+    async fn run<T, E>(
+        &mut self,
+        phase: BootstrapPhase,
+        run: impl Future<Output = Result<T, E>>,
+    ) -> Result<Option<T>, BootstrapError>
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let started = Instant::now();
+        let source = match run.await {
+            Ok(value) => {
+                self.record(phase, started, PhaseOutcome::Succeeded);
+                return Ok(Some(value));
+            }
+            Err(e) => e.into(),
+        };
+        self.record(phase, started, PhaseOutcome::Failed(source.to_string()));
+        match phase.criticality() {
+            Criticality::Critical => {
+                error!(phase = %phase, error = %source, "❌ Critical bootstrap phase failed");
+                Err(BootstrapError::PhaseFailed { phase, source })
+            }
+            Criticality::NonCritical => {
+                warn!(phase = %phase, error = %source, "⚠️  Non-critical bootstrap phase failed, continuing");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Run a phase whose value startup needs
+    ///
+    /// Only a non-critical phase can fail without failing the bootstrap, and
+    /// then there is no value to go on with.
+    async fn value<T, E>(
+        &mut self,
+        phase: BootstrapPhase,
+        run: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, BootstrapError>
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        self.run(phase, run)
+            .await?
+            .ok_or_else(|| BootstrapError::PhaseFailed {
+                phase,
+                source: "its value is needed to go on".into(),
+            })
+    }
+
+    fn skip(&mut self, phase: BootstrapPhase) {
+        self.timings.push(PhaseTiming {
+            phase,
+            duration: Duration::ZERO,
+            outcome: PhaseOutcome::Skipped,
+        });
+    }
+
+    fn record(&mut self, phase: BootstrapPhase, started: Instant, outcome: PhaseOutcome) {
+        let duration = started.elapsed();
+        info!(
+            phase = %phase,
+            elapsed_ms = duration.as_millis() as u64,
+            "⏱️  Bootstrap phase '{}' finished in {:?}",
+            phase,
+            duration
+        );
+        self.timings.push(PhaseTiming {
+            phase,
+            duration,
+            outcome,
+        });
+    }
+
+    fn total(&self) -> Duration {
+        self.timings.iter().map(|timing| timing.duration).sum()
+    }
+
+    /// One line per run: `storage=12ms, iam_schema=skipped, warm_up=failed`
+    fn summary(&self) -> String {
+        self.timings
+            .iter()
+            .map(|timing| match &timing.outcome {
+                PhaseOutcome::Succeeded => {
+                    format!("{}={}ms", timing.phase, timing.duration.as_millis())
+                }
+                PhaseOutcome::Skipped => format!("{}=skipped", timing.phase),
+                PhaseOutcome::Failed(_) => format!("{}=failed", timing.phase),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 /// Bootstrap the application with the given configuration
 ///
-/// This function:
-/// 1. Validates configuration and fails explicitly on any issues
-/// 2. Initializes the RocksDB database connection and storage adapters
-/// 3. Creates the CompositionRoot with all use case ports
-/// 4. Optionally registers the IAM schema
-/// 5. Returns the configured AppState ready for Axum
+/// Startup runs as named phases, each timed and logged:
+/// 1. `configuration` - validates configuration and fails explicitly on any issues
+/// 2. `storage` - opens the RocksDB database
+/// 3. `schema_storage` - sets up the configured schema storage backend
+/// 4. `composition` - creates the CompositionRoot, failing if any port has
+///    no implementation, and subscribes the policy revalidation to schema
+///    changes and the audit log to domain events
+/// 5. `self_check` - runs a no-op evaluation through the wiring
+/// 6. `webhooks` - subscribes the configured webhook endpoints, if any
+/// 7. `schema_fragments` - registers every bounded context's schema
+///    fragment, and builds the schema from them if IAM registration is
+///    disabled
+/// 8. `iam_schema` - registers the IAM schema, unless disabled
+/// 9. `warm_up` - runs the engine readiness check once
+///
+/// Every phase but `warm_up` is critical: its failure aborts startup with a
+/// [`BootstrapError::PhaseFailed`] naming it. A failed warm-up is logged and
+/// the service starts anyway, reporting unready until the engine recovers.
 pub async fn bootstrap(
     config: &AppConfig,
     bootstrap_config: BootstrapConfig,
 ) -> Result<AppState, Box<dyn std::error::Error + Send + Sync>> {
     info!("🚀 Starting Hodei Artifacts API bootstrap");
+    let mut phases = PhaseRunner::default();
 
-    // Step 0: Validate configuration and fail explicitly on any issues
     info!("🔍 Validating application configuration");
-    validate_bootstrap_configuration(config)
-        .map_err(|e| BootstrapError::Initialization(e.to_string()))?;
+    let webhook_endpoints = phases
+        .value(BootstrapPhase::Configuration, async {
+            validate_bootstrap_configuration(config)
+        })
+        .await?;
 
-    // Step 1: Initialize infrastructure with RocksDB
     info!("📦 Initializing infrastructure adapters");
-    let schema_storage = initialize_schema_storage(config).await?;
+    let db = phases
+        .value(BootstrapPhase::Storage, initialize_database(config))
+        .await?;
 
-    // Initialize policy adapter with the same DB client
-    let policy_adapter = Arc::new(SurrealPolicyAdapter::new(
-        schema_storage.db().clone().into(),
-    ));
+    // Schema storage backend selected in the configuration
+    let schema_storage = Arc::new(
+        phases
+            .value(
+                BootstrapPhase::SchemaStorage,
+                SchemaStorage::from_config(&config.schema, db.clone()),
+            )
+            .await?,
+    );
 
-    // Step 2: Use Composition Root to create all use case ports
     info!("🏗️  Creating use cases via CompositionRoot");
-    let root = CompositionRoot::production(schema_storage.clone(), policy_adapter);
+    let mut root = phases
+        .value(BootstrapPhase::Composition, async {
+            // Initialize the IAM adapters with the same DB client
+            let db = Arc::new(db);
+            let policy_adapter = Arc::new(SurrealPolicyAdapter::new(db.clone()));
+            let user_adapter = Arc::new(SurrealUserAdapter::new(db.clone()));
+            let group_adapter = Arc::new(SurrealGroupAdapter::new(db));
+            let mut root = CompositionRoot::production(
+                schema_storage.clone(),
+                policy_adapter,
+                user_adapter,
+                group_adapter,
+                config.schema.validation_level,
+                config.audit.retention(),
+                config.events.worker_pool(),
+            )?;
+            // Before any schema is registered, so no change goes unrevalidated
+            root.subscribe_policy_revalidation().await?;
+            root.subscribe_audit().await?;
+            Ok::<_, anyhow::Error>(root)
+        })
+        .await?;
 
-    // Step 3: Determine schema version
-    let schema_version = if bootstrap_config.register_iam_schema {
-        info!("📝 Registering IAM schema");
-        let result = register_iam_schema(
-            &*root.iam_ports.register_iam_schema,
-            bootstrap_config.schema_version.clone(),
-            bootstrap_config.validate_schemas,
+    phases
+        .run(BootstrapPhase::SelfCheck, root.self_check())
+        .await?;
+
+    if webhook_endpoints.is_empty() {
+        phases.skip(BootstrapPhase::Webhooks);
+    } else {
+        phases
+            .run(
+                BootstrapPhase::Webhooks,
+                root.subscribe_webhooks(webhook_endpoints, config.webhooks.delivery()),
+            )
+            .await?;
+    }
+
+    // Every bounded context's types go into the schema whether or not the
+    // IAM schema is registered; without that registration to build the
+    // schema from them, the phase builds it itself
+    info!(
+        "🧩 Registering schema fragments from {} provider(s)",
+        root.schema_fragments.len()
+    );
+    let (fragments, fragments_schema) = phases
+        .value(
+            BootstrapPhase::SchemaFragments,
+            register_schema_fragments(&root, &bootstrap_config),
         )
         .await?;
+    info!(
+        "✅ Schema fragments registered (sources: {}, entities: {}, actions: {})",
+        fragments.sources.join(", "),
+        fragments.entity_types_registered,
+        fragments.action_types_registered
+    );
+
+    let schema_version = if bootstrap_config.register_iam_schema {
+        info!("📝 Registering IAM schema");
+        let result = phases
+            .value(
+                BootstrapPhase::IamSchema,
+                register_iam_schema(
+                    &*root.iam_ports.register_iam_schema,
+                    bootstrap_config.schema_version.clone(),
+                    bootstrap_config.validate_schemas,
+                ),
+            )
+            .await?;
 
         info!(
             "✅ IAM schema registered successfully (version: {}, entities: {}, actions: {})",
@@ -106,129 +385,40 @@ pub async fn bootstrap(
         result.schema_version
     } else {
         warn!("⚠️  Skipping IAM schema registration");
-        bootstrap_config
-            .schema_version
+        phases.skip(BootstrapPhase::IamSchema);
+        fragments_schema
+            .and_then(|schema| schema.version)
             .unwrap_or_else(|| "unregistered".to_string())
     };
 
-    // Step 4: Create AppState from CompositionRoot
     info!("🎯 Creating application state");
     let app_state = AppState::from_composition_root(schema_version.clone(), root);
 
+    let engine_readiness = app_state.engine_readiness.clone();
+    phases
+        .run(BootstrapPhase::WarmUp, async move {
+            let readiness = engine_readiness.check().await;
+            match readiness.error {
+                Some(error) => Err(error),
+                None => Ok(readiness.policy_count),
+            }
+        })
+        .await?;
+
     info!(
-        "✅ Bootstrap completed successfully (schema version: {})",
-        schema_version
+        "✅ Bootstrap completed successfully in {:?} (schema version: {}; {})",
+        phases.total(),
+        schema_version,
+        phases.summary()
     );
 
     Ok(app_state)
 }
 
-/// SurrealDB adapter for schema storage
-///
-/// This adapter implements the SchemaStoragePort trait for SurrealDB with RocksDB.
-#[derive(Clone)]
-pub struct SurrealSchemaAdapter {
-    db: Surreal<surrealdb::engine::local::Db>,
-}
-
-impl SurrealSchemaAdapter {
-    /// Get a reference to the underlying database client
-    pub fn db(&self) -> &Surreal<surrealdb::engine::local::Db> {
-        &self.db
-    }
-}
-
-impl SurrealSchemaAdapter {
-    /// Create a new SurrealDB schema adapter
-    pub fn new(db: Surreal<surrealdb::engine::local::Db>) -> Self {
-        Self { db }
-    }
-}
-
-#[async_trait]
-impl SchemaStoragePort for SurrealSchemaAdapter {
-    async fn save_schema(
-        &self,
-        schema_json: String,
-        version: Option<String>,
-    ) -> Result<String, BuildSchemaError> {
-        // Generate a unique schema ID
-        let schema_id = version.clone().unwrap_or_else(|| "latest".to_string());
-
-        // Save the schema to SurrealDB
-        let _: Option<serde_json::Value> = self
-            .db
-            .create(("schema", schema_id.as_str()))
-            .content(serde_json::json!({
-                "content": schema_json,
-                "version": version,
-                "created_at": chrono::Utc::now().to_rfc3339(),
-            }))
-            .await
-            .map_err(|e| BuildSchemaError::SchemaStorageError(e.to_string()))?;
-
-        Ok(format!("schema:{}", schema_id))
-    }
-
-    async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
-        let result: Option<serde_json::Value> = self
-            .db
-            .select(("schema", "latest"))
-            .await
-            .map_err(|e| BuildSchemaError::SchemaStorageError(e.to_string()))?;
-
-        Ok(result.and_then(|v| v.get("content").and_then(|c| c.as_str().map(String::from))))
-    }
-
-    async fn get_schema_by_version(
-        &self,
-        version: &str,
-    ) -> Result<Option<String>, BuildSchemaError> {
-        let result: Option<serde_json::Value> = self
-            .db
-            .select(("schema", version))
-            .await
-            .map_err(|e| BuildSchemaError::SchemaStorageError(e.to_string()))?;
-
-        Ok(result.and_then(|v| v.get("content").and_then(|c| c.as_str().map(String::from))))
-    }
-
-    async fn delete_schema(&self, schema_id: &str) -> Result<bool, BuildSchemaError> {
-        let result: Option<serde_json::Value> = self
-            .db
-            .delete(("schema", schema_id))
-            .await
-            .map_err(|e| BuildSchemaError::SchemaStorageError(e.to_string()))?;
-
-        Ok(result.is_some())
-    }
-
-    async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
-        // Query all schemas
-        let results: Vec<serde_json::Value> = self
-            .db
-            .query("SELECT version FROM schema")
-            .await
-            .map_err(|e| BuildSchemaError::SchemaStorageError(e.to_string()))?
-            .take(0)
-            .map_err(|e| BuildSchemaError::SchemaStorageError(e.to_string()))?;
-
-        let versions = results
-            .into_iter()
-            .filter_map(|v| {
-                v.get("version")
-                    .and_then(|ver| ver.as_str().map(String::from))
-            })
-            .collect();
-
-        Ok(versions)
-    }
-}
-
-/// Initialize the SurrealDB schema storage adapter with RocksDB
-async fn initialize_schema_storage(
+/// Initialize the SurrealDB database with RocksDB
+async fn initialize_database(
     config: &AppConfig,
-) -> Result<Arc<SurrealSchemaAdapter>, Box<dyn std::error::Error + Send + Sync>> {
+) -> Result<Surreal<Db>, Box<dyn std::error::Error + Send + Sync>> {
     let rocksdb_config = &config.rocksdb;
     
     info!("💎 Initializing SurrealDB with RocksDB: {}", rocksdb_config.path);
@@ -254,14 +444,17 @@ async fn initialize_schema_storage(
         .await
         .map_err(|e| BootstrapError::Initialization(e.to_string()))?;
 
-    Ok(Arc::new(SurrealSchemaAdapter::new(db)))
+    Ok(db)
 }
 
 /// Validate bootstrap configuration and fail explicitly on any issues
 ///
 /// This function performs additional validation beyond what's in AppConfig::validate()
-/// to ensure the application fails fast on configuration problems.
-fn validate_bootstrap_configuration(config: &AppConfig) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// to ensure the application fails fast on configuration problems. Returns
+/// the webhook endpoints, with their secrets already resolved.
+fn validate_bootstrap_configuration(
+    config: &AppConfig,
+) -> Result<Vec<WebhookEndpoint>, Box<dyn std::error::Error + Send + Sync>> {
     // Validate database configuration
     if config.database.namespace.is_none() || config.database.namespace.as_ref().unwrap().is_empty() {
         return Err(Box::new(BootstrapError::Initialization(
@@ -303,11 +496,57 @@ fn validate_bootstrap_configuration(config: &AppConfig) -> Result<(), Box<dyn st
         }
     }
 
+    // Resolve webhook secrets now, so a missing one fails startup
+    let webhook_endpoints = config
+        .webhooks
+        .endpoints()
+        .map_err(|e| BootstrapError::Initialization(e.to_string()))?;
+    if !webhook_endpoints.is_empty() {
+        info!(
+            "🔔 {} webhook endpoint(s) configured, up to {} attempts per delivery",
+            webhook_endpoints.len(),
+            config.webhooks.delivery().max_attempts
+        );
+    }
+
     info!("✅ Configuration validation passed");
-    Ok(())
+    Ok(webhook_endpoints)
 }
 
 /// Register the IAM schema using the provided use case
+/// Register every bounded context's schema fragment
+///
+/// When the IAM schema is not registered, nothing else builds the schema
+/// from the fragments, so this builds and persists it too and returns the
+/// build's result.
+async fn register_schema_fragments(
+    root: &CompositionRoot,
+    bootstrap_config: &BootstrapConfig,
+) -> Result<
+    (RegisterSchemaFragmentsResult, Option<BuildSchemaResult>),
+    Box<dyn std::error::Error + Send + Sync>,
+> {
+    let fragments = root
+        .policy_ports
+        .register_schema_fragments
+        .execute(&root.schema_fragments)
+        .await?;
+    if bootstrap_config.register_iam_schema {
+        return Ok((fragments, None));
+    }
+
+    let schema = root
+        .policy_ports
+        .build_schema
+        .execute(BuildSchemaCommand {
+            version: bootstrap_config.schema_version.clone(),
+            validate: bootstrap_config.validate_schemas,
+        })
+        .await
+        .map_err(|e| BootstrapError::SchemaRegistration(e.to_string()))?;
+    Ok((fragments, Some(schema)))
+}
+
 async fn register_iam_schema(
     use_case: &dyn hodei_iam::features::register_iam_schema::ports::RegisterIamSchemaPort,
     version: Option<String>,
@@ -385,6 +624,28 @@ mod tests {
         drop(temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_fragments_are_built_without_iam_schema_registration() {
+        let temp_dir = tempdir().unwrap();
+        let mut config = AppConfig::default();
+        config.rocksdb.path = temp_dir
+            .path()
+            .join("test_fragments.rocksdb")
+            .to_string_lossy()
+            .to_string();
+        let bootstrap_config = BootstrapConfig {
+            register_iam_schema: false,
+            schema_version: Some("v-fragments".to_string()),
+            validate_schemas: true,
+        };
+
+        let app_state = bootstrap(&config, bootstrap_config).await.unwrap();
+
+        assert_eq!(app_state.schema_version, "v-fragments");
+        let readiness = app_state.engine_readiness.check().await;
+        assert!(readiness.ready, "{:?}", readiness.error);
+    }
+
     #[tokio::test]
     async fn test_bootstrap_with_custom_schema_version() {
         let temp_dir = tempdir().unwrap();
@@ -409,4 +670,84 @@ mod tests {
         // Clean up
         drop(temp_dir);
     }
+
+    #[tokio::test]
+    async fn test_critical_phase_failure_names_the_phase() {
+        let mut phases = PhaseRunner::default();
+
+        let result = phases
+            .run(BootstrapPhase::Storage, async {
+                Err::<(), _>(BootstrapError::DatabaseConnection("lock held".to_string()))
+            })
+            .await;
+
+        let error = result.unwrap_err();
+        assert!(matches!(
+            error,
+            BootstrapError::PhaseFailed {
+                phase: BootstrapPhase::Storage,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Bootstrap phase 'storage' failed: Failed to connect to database: lock held"
+        );
+        assert!(matches!(phases.timings[0].outcome, PhaseOutcome::Failed(_)));
+    }
+
+    #[tokio::test]
+    async fn test_non_critical_phase_failure_does_not_abort() {
+        let mut phases = PhaseRunner::default();
+
+        let warmed = phases
+            .run(BootstrapPhase::WarmUp, async { Err::<(), _>("engine cold") })
+            .await
+            .unwrap();
+        phases.skip(BootstrapPhase::IamSchema);
+
+        assert_eq!(warmed, None);
+        assert_eq!(
+            phases.timings[0].outcome,
+            PhaseOutcome::Failed("engine cold".to_string())
+        );
+        assert_eq!(phases.timings[1].outcome, PhaseOutcome::Skipped);
+    }
+
+    #[tokio::test]
+    async fn test_value_of_failed_non_critical_phase_is_an_error() {
+        let mut phases = PhaseRunner::default();
+
+        let result = phases
+            .value(BootstrapPhase::WarmUp, async { Err::<usize, _>("engine cold") })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(BootstrapError::PhaseFailed {
+                phase: BootstrapPhase::WarmUp,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_only_warm_up_is_non_critical() {
+        let phases = [
+            BootstrapPhase::Configuration,
+            BootstrapPhase::Storage,
+            BootstrapPhase::SchemaStorage,
+            BootstrapPhase::Composition,
+            BootstrapPhase::SelfCheck,
+            BootstrapPhase::Webhooks,
+            BootstrapPhase::SchemaFragments,
+            BootstrapPhase::IamSchema,
+            BootstrapPhase::WarmUp,
+        ];
+        let non_critical: Vec<_> = phases
+            .into_iter()
+            .filter(|phase| phase.criticality() == Criticality::NonCritical)
+            .collect();
+        assert_eq!(non_critical, vec![BootstrapPhase::WarmUp]);
+    }
 }
@@ -10,8 +10,12 @@
 //! 2. **Inyección vía puertos**: Los casos de uso se ensamblan usando traits (puertos)
 //! 3. **Resolución en compilación**: Uso de generics para zero-cost abstractions
 //! 4. **Desacoplamiento**: Los handlers solo conocen los puertos, no las implementaciones
+//! 5. **Fallo temprano**: Los puertos se registran en un [`Container`] y se
+//!    resuelven al arrancar; un puerto sin implementación detiene el arranque
 
+use hodei_iam::register_iam_schema::IamSchemaFragmentProvider;
 use hodei_iam::register_iam_schema::factories as iam_factories;
+use hodei_policies::build_schema::EventBusSchemaChangePublisher;
 use hodei_policies::build_schema::factories as policy_factories;
 use hodei_policies::build_schema::ports::{BuildSchemaPort, SchemaStoragePort};
 use hodei_policies::evaluate_policies::ports::EvaluatePoliciesPort;
@@ -20,7 +24,62 @@ use hodei_policies::playground_evaluate::ports::PlaygroundEvaluatePort;
 use hodei_policies::load_schema::ports::LoadSchemaPort;
 use hodei_policies::register_action_type::ports::RegisterActionTypePort;
 use hodei_policies::register_entity_type::ports::RegisterEntityTypePort;
+use hodei_policies::register_schema_fragments::SchemaFragmentRegistry;
+use hodei_policies::register_schema_fragments::factories as fragment_factories;
+use hodei_policies::register_schema_fragments::ports::RegisterSchemaFragmentsPort;
+use hodei_policies::validate_policy::dto::ValidationLevel;
 use hodei_policies::validate_policy::port::ValidatePolicyPort;
+use crate::container::{Container, ContainerError};
+use crate::readiness::CanaryEntity;
+use hodei_iam::features::create_policy::ports::CreatePolicyUseCasePort;
+use hodei_iam::features::create_policy::use_case::CreatePolicyUseCase;
+use hodei_iam::features::delete_policy::ports::DeletePolicyPort;
+use hodei_iam::features::delete_policy::use_case::DeletePolicyUseCase;
+use hodei_iam::features::get_policies::ports::GetPoliciesUseCasePort;
+use hodei_iam::features::get_policy::ports::GetPolicyUseCasePort;
+use hodei_iam::features::list_policies::ports::PolicyLister;
+use hodei_iam::features::register_iam_schema::ports::RegisterIamSchemaPort;
+use hodei_iam::features::update_policy::ports::UpdatePolicyPort;
+use hodei_iam::features::update_policy::use_case::UpdatePolicyUseCase;
+use hodei_policies::evaluate_policies::dto::{
+    AuthorizationRequest, Decision, EvaluatePoliciesCommand,
+};
+use hodei_iam::features::revalidate_policies::dto::{
+    PolicyDisabled, RevalidationConfig, SchemaChanged,
+};
+use hodei_iam::features::revalidate_policies::factories::create_schema_change_revalidator;
+use hodei_iam::features::revalidate_policies::ports::PolicyRevalidationStorePort;
+use hodei_iam::features::revalidate_policies::{
+    EventBusPolicyDisabledAlerts, SchemaChangeRevalidator,
+};
+use hodei_iam::list_principals_with_access::{
+    PolicyAttachmentChanged, PolicyDeleted, PolicyStored,
+};
+use hodei_iam::infrastructure::event_bus::EventBusIamEvents;
+use hodei_iam::set_user_status::factories::create_set_user_status_use_case;
+use hodei_iam::set_user_status::{SetUserStatusUseCasePort, UserStatusPort};
+use hodei_iam::update_group_attributes::factories::create_update_group_attributes_use_case;
+use hodei_iam::update_group_attributes::{
+    GroupAttributesChanged, GroupAttributesPort, UpdateGroupAttributesUseCasePort,
+};
+use hodei_iam::update_user_attributes::factories::create_update_user_attributes_use_case;
+use hodei_iam::update_user_attributes::{
+    UpdateUserAttributesUseCasePort, UserAttributesChanged, UserAttributesPort,
+};
+use kernel::infrastructure::audit::{
+    AuditEventHandler, AuditLogStore, AuditLogsRedacted, AuditRetention,
+};
+use kernel::infrastructure::webhook::{
+    InMemoryDeadLetterSink, ReqwestWebhookTransport, WebhookDeliveryConfig, WebhookEndpoint,
+    WebhookEventHandler,
+};
+use kernel::{
+    EventBus, GroupMembershipChanged, HodeiEntity, InMemoryEventBus, Subscription,
+    UserStatusChanged,
+};
+use kernel::domain::policy::HodeiPolicySet;
+#[cfg(test)]
+use hodei_iam::infrastructure::in_memory::InMemoryIamRepository;
 use std::sync::Arc;
 use tracing::info;
 
@@ -31,10 +90,12 @@ use tracing::info;
 pub struct PolicyPorts {
     pub register_entity_type: Arc<dyn RegisterEntityTypePort>,
     pub register_action_type: Arc<dyn RegisterActionTypePort>,
+    pub register_schema_fragments: Arc<dyn RegisterSchemaFragmentsPort>,
     pub build_schema: Arc<dyn BuildSchemaPort>,
     pub load_schema: Arc<dyn LoadSchemaPort>,
     pub validate_policy: Arc<dyn ValidatePolicyPort>,
     pub evaluate_policies: Arc<dyn EvaluatePoliciesPort>,
+    pub readiness_evaluator: Arc<dyn EvaluatePoliciesPort>,
     pub playground_evaluate: Arc<dyn PlaygroundEvaluatePort>,
 }
 
@@ -46,10 +107,34 @@ pub struct IamPorts {
     pub register_iam_schema:
         Arc<dyn hodei_iam::features::register_iam_schema::ports::RegisterIamSchemaPort>,
     pub create_policy: Arc<dyn hodei_iam::features::create_policy::ports::CreatePolicyUseCasePort>,
-    pub get_policy: Arc<dyn hodei_iam::features::get_policy::ports::PolicyReader>,
+    pub get_policy: Arc<dyn hodei_iam::features::get_policy::ports::GetPolicyUseCasePort>,
+    pub get_policies:
+        Arc<dyn hodei_iam::features::get_policies::ports::GetPoliciesUseCasePort>,
     pub list_policies: Arc<dyn hodei_iam::features::list_policies::ports::PolicyLister>,
     pub update_policy: Arc<dyn hodei_iam::features::update_policy::ports::UpdatePolicyPort>,
     pub delete_policy: Arc<dyn hodei_iam::features::delete_policy::ports::DeletePolicyPort>,
+    pub set_user_status: Arc<dyn SetUserStatusUseCasePort>,
+    pub update_user_attributes: Arc<dyn UpdateUserAttributesUseCasePort>,
+    pub update_group_attributes: Arc<dyn UpdateGroupAttributesUseCasePort>,
+}
+
+/// Evaluador propio del readiness check
+///
+/// Implementa el mismo puerto que el evaluador de las peticiones reales, así
+/// que se registra en el contenedor con su propio tipo.
+struct ReadinessEvaluator(Arc<dyn EvaluatePoliciesPort>);
+
+/// Acción de la autocomprobación; ninguna política debería mencionarla
+const SELF_CHECK_ACTION: &str = "WiringSelfCheck";
+
+/// Fallo de la autocomprobación del Composition Root
+#[derive(Debug, thiserror::Error)]
+pub enum SelfCheckError {
+    #[error("Authorization path failed during self-check: {0}")]
+    Evaluation(String),
+
+    #[error("Self-check expected the default deny, got {0:?}")]
+    UnexpectedDecision(Decision),
 }
 
 /// Composition Root - Punto de ensamblaje de toda la aplicación
@@ -59,6 +144,16 @@ pub struct IamPorts {
 pub struct CompositionRoot {
     pub policy_ports: PolicyPorts,
     pub iam_ports: IamPorts,
+    /// Fragmentos de esquema de cada bounded context, registrados al arrancar
+    pub schema_fragments: Arc<SchemaFragmentRegistry>,
+    /// Revalida las políticas almacenadas cuando cambia el esquema
+    pub policy_revalidator: Arc<SchemaChangeRevalidator>,
+    /// Bus de eventos de dominio, vaciado al apagar el servidor
+    pub event_bus: Arc<InMemoryEventBus>,
+    /// Registro de auditoría de los eventos de dominio publicados en el bus
+    pub audit_log: Arc<AuditLogStore>,
+    /// Suscripciones de los handlers del bus; soltarlas detiene los handlers
+    pub event_subscriptions: Vec<Arc<dyn Subscription>>,
 }
 
 impl CompositionRoot {
@@ -71,31 +166,112 @@ impl CompositionRoot {
     ///
     /// * `schema_storage` - Adaptador concreto para almacenamiento de esquemas
     /// * `policy_adapter` - Adaptador concreto para gestión de políticas IAM
+    /// * `user_adapter` - Adaptador concreto para el estado y los atributos de usuarios
+    /// * `group_adapter` - Adaptador concreto para los atributos de grupos
+    /// * `validation_level` - Rigor con el que se validan las políticas contra el esquema
+    /// * `audit_retention` - Cuánto conserva el registro de auditoría en memoria
+    /// * `event_workers` - Workers del bus de eventos para los handlers; `None`
+    ///   los ejecuta en línea
     ///
     /// # Retorna
     ///
-    /// Una instancia de CompositionRoot con todos los puertos listos para inyección
-    pub fn production<S, P>(schema_storage: Arc<S>, policy_adapter: Arc<P>) -> Self
+    /// Una instancia de CompositionRoot con todos los puertos listos para
+    /// inyección, o el error que nombra los puertos sin implementación
+    pub fn production<S, P, U, G>(
+        schema_storage: Arc<S>,
+        policy_adapter: Arc<P>,
+        user_adapter: Arc<U>,
+        group_adapter: Arc<G>,
+        validation_level: ValidationLevel,
+        audit_retention: AuditRetention,
+        event_workers: Option<usize>,
+    ) -> Result<Self, ContainerError>
     where
         S: SchemaStoragePort + Clone + 'static,
         P: hodei_iam::features::create_policy::ports::CreatePolicyPort
             + hodei_iam::features::get_policy::ports::PolicyReader
+            + hodei_iam::features::get_policies::ports::PolicyBatchReader
             + hodei_iam::features::list_policies::ports::PolicyLister
             + hodei_iam::features::update_policy::ports::UpdatePolicyPort
             + hodei_iam::features::delete_policy::ports::DeletePolicyPort
+            + PolicyRevalidationStorePort
             + 'static,
+        U: UserStatusPort + UserAttributesPort + 'static,
+        G: GroupAttributesPort + 'static,
     {
         info!("🏗️  Initializing Composition Root (Production)");
+        let container = Self::production_container(
+            schema_storage,
+            policy_adapter,
+            user_adapter,
+            group_adapter,
+            validation_level,
+            audit_retention,
+            event_workers,
+        );
+        let root = Self::from_container(&container)?;
+
+        info!("✅ Composition Root initialized successfully");
+        Ok(root)
+    }
+
+    /// Registra en un contenedor las implementaciones de producción de
+    /// todos los puertos
+    fn production_container<S, P, U, G>(
+        schema_storage: Arc<S>,
+        policy_adapter: Arc<P>,
+        user_adapter: Arc<U>,
+        group_adapter: Arc<G>,
+        validation_level: ValidationLevel,
+        audit_retention: AuditRetention,
+        event_workers: Option<usize>,
+    ) -> Container
+    where
+        S: SchemaStoragePort + Clone + 'static,
+        P: hodei_iam::features::create_policy::ports::CreatePolicyPort
+            + hodei_iam::features::get_policy::ports::PolicyReader
+            + hodei_iam::features::get_policies::ports::PolicyBatchReader
+            + hodei_iam::features::list_policies::ports::PolicyLister
+            + hodei_iam::features::update_policy::ports::UpdatePolicyPort
+            + hodei_iam::features::delete_policy::ports::DeletePolicyPort
+            + PolicyRevalidationStorePort
+            + 'static,
+        U: UserStatusPort + UserAttributesPort + 'static,
+        G: GroupAttributesPort + 'static,
+    {
+        let mut container = Container::new();
+
+        // ============================================================
+        // PASO 0: Bus de eventos
+        // ============================================================
+        // Se crea antes que los casos de uso que publican en él
+        info!("📦 Creating event bus...");
+        let event_bus = match event_workers {
+            Some(workers) => InMemoryEventBus::new().with_worker_pool(workers),
+            None => InMemoryEventBus::new(),
+        };
+        let event_bus = Arc::new(event_bus);
+        container.register(event_bus.clone());
+        container.register(Arc::new(AuditLogStore::with_retention(audit_retention)));
 
         // ============================================================
         // PASO 1: Crear puertos de hodei-policies
         // ============================================================
         info!("📦 Creating hodei-policies ports...");
 
-        // 1.1. Bundle de registro de esquemas (entity, action, build)
+        // 1.1. Bundle de registro de esquemas (entity, action, build); cada
+        // esquema persistido se anuncia en el bus como `SchemaChanged`
         info!("  ├─ Schema registration bundle");
         let (register_entity_type, register_action_type, build_schema) =
-            policy_factories::create_schema_registration_components(schema_storage.clone());
+            policy_factories::create_publishing_schema_registration_components(
+                schema_storage.clone(),
+                Arc::new(EventBusSchemaChangePublisher::new(event_bus.clone())),
+            );
+        let register_schema_fragments =
+            fragment_factories::create_register_schema_fragments_use_case(
+                register_entity_type.clone(),
+                register_action_type.clone(),
+            );
 
         // 1.2. Load schema
         info!("  ├─ LoadSchemaPort");
@@ -104,10 +280,11 @@ impl CompositionRoot {
         );
 
         // 1.3. Validate policy
-        info!("  ├─ ValidatePolicyPort");
+        info!("  ├─ ValidatePolicyPort ({:?})", validation_level);
         let validate_policy =
             hodei_policies::validate_policy::factories::create_validate_policy_use_case_with_schema(
                 schema_storage.clone(),
+                validation_level,
             );
 
         // 1.4. Evaluate policies
@@ -118,18 +295,27 @@ impl CompositionRoot {
             );
 
         // 1.5. Playground evaluate
-        info!("  └─ PlaygroundEvaluatePort");
+        info!("  ├─ PlaygroundEvaluatePort");
         let playground_evaluate = Self::create_playground_evaluate_port(schema_storage.clone());
 
-        let policy_ports = PolicyPorts {
-            register_entity_type,
-            register_action_type,
-            build_schema,
-            load_schema,
-            validate_policy,
-            evaluate_policies,
-            playground_evaluate,
-        };
+        // 1.6. Evaluador del readiness check: instancia propia para que la
+        // evaluación canario no sustituya las políticas de las peticiones reales
+        info!("  └─ Readiness EvaluatePoliciesPort");
+        let readiness_evaluator =
+            hodei_policies::evaluate_policies::factories::create_evaluate_policies_use_case(
+                schema_storage.clone(),
+            );
+
+        container
+            .register::<dyn RegisterEntityTypePort>(register_entity_type.clone())
+            .register::<dyn RegisterActionTypePort>(register_action_type.clone())
+            .register::<dyn RegisterSchemaFragmentsPort>(register_schema_fragments)
+            .register::<dyn BuildSchemaPort>(build_schema.clone())
+            .register::<dyn LoadSchemaPort>(load_schema)
+            .register::<dyn ValidatePolicyPort>(validate_policy.clone())
+            .register::<dyn EvaluatePoliciesPort>(evaluate_policies)
+            .register(Arc::new(ReadinessEvaluator(readiness_evaluator)))
+            .register::<dyn PlaygroundEvaluatePort>(playground_evaluate);
 
         // ============================================================
         // PASO 2: Crear puertos de hodei-iam usando puertos de policies
@@ -139,53 +325,280 @@ impl CompositionRoot {
         // 2.1. Register IAM schema (orquesta los puertos de policies)
         info!("  ├─ RegisterIamSchemaPort");
         let register_iam_schema = iam_factories::create_register_iam_schema_use_case(
-            policy_ports.register_entity_type.clone(),
-            policy_ports.register_action_type.clone(),
-            policy_ports.build_schema.clone(),
+            register_entity_type,
+            register_action_type,
+            build_schema,
         );
 
+        // Cada cambio de políticas, pertenencias, estado o atributos se
+        // publica en el bus para el índice de accesos, las cachés de
+        // autorización y la auditoría
+        let iam_events = Arc::new(EventBusIamEvents::new(event_bus.clone()));
+
         // 2.2. Create policy use case
         info!("  ├─ CreatePolicyPort");
-        let create_policy = hodei_iam::features::create_policy::factories::create_policy_use_case(
-            policy_adapter.clone(),
-            policy_ports.validate_policy.clone(),
+        let create_policy: Arc<dyn CreatePolicyUseCasePort> = Arc::new(
+            CreatePolicyUseCase::new(policy_adapter.clone(), validate_policy.clone())
+                .with_events(iam_events.clone()),
         );
 
-        // 2.3. Get policy port
+        // 2.3. Get policy use case
         info!("  ├─ GetPolicyPort");
-        let get_policy: Arc<dyn hodei_iam::features::get_policy::ports::PolicyReader> =
-            policy_adapter.clone();
+        let get_policy = hodei_iam::features::get_policy::factories::create_get_policy_use_case(
+            policy_adapter.clone(),
+        );
+
+        // 2.4. Get policies (batch) use case
+        info!("  ├─ GetPoliciesPort");
+        let get_policies =
+            hodei_iam::features::get_policies::factories::create_get_policies_use_case(
+                policy_adapter.clone(),
+            );
 
-        // 2.4. List policies port
+        // 2.5. List policies port
         info!("  ├─ ListPoliciesPort");
         let list_policies: Arc<dyn hodei_iam::features::list_policies::ports::PolicyLister> =
             policy_adapter.clone();
 
-        // 2.5. Update policy port
+        // 2.6. Update policy use case
         info!("  ├─ UpdatePolicyPort");
-        let update_policy: Arc<dyn hodei_iam::features::update_policy::ports::UpdatePolicyPort> =
-            policy_adapter.clone();
+        let update_policy: Arc<dyn UpdatePolicyPort> = Arc::new(
+            UpdatePolicyUseCase::new(validate_policy.clone(), policy_adapter.clone())
+                .with_events(iam_events.clone()),
+        );
+
+        // 2.7. Delete policy use case
+        info!("  ├─ DeletePolicyPort");
+        let delete_policy: Arc<dyn DeletePolicyPort> = Arc::new(
+            DeletePolicyUseCase::new(policy_adapter.clone()).with_events(iam_events.clone()),
+        );
+
+        // 2.8. Revalidación de políticas tras un cambio de esquema
+        info!("  ├─ SchemaChangeRevalidator");
+        let policy_revalidator = create_schema_change_revalidator(
+            policy_adapter,
+            validate_policy,
+            Arc::new(EventBusPolicyDisabledAlerts::new(event_bus.clone())),
+            RevalidationConfig::default(),
+        );
+
+        // 2.9. Estado y atributos de usuarios y grupos
+        info!("  ├─ SetUserStatusUseCasePort");
+        let set_user_status =
+            create_set_user_status_use_case(user_adapter.clone(), iam_events.clone());
+        info!("  ├─ UpdateUserAttributesUseCasePort");
+        let update_user_attributes =
+            create_update_user_attributes_use_case(user_adapter, iam_events.clone());
+        info!("  └─ UpdateGroupAttributesUseCasePort");
+        let update_group_attributes =
+            create_update_group_attributes_use_case(group_adapter, iam_events);
+
+        container
+            .register::<dyn RegisterIamSchemaPort>(register_iam_schema)
+            .register::<dyn CreatePolicyUseCasePort>(create_policy)
+            .register::<dyn GetPolicyUseCasePort>(get_policy)
+            .register::<dyn GetPoliciesUseCasePort>(get_policies)
+            .register::<dyn PolicyLister>(list_policies)
+            .register::<dyn UpdatePolicyPort>(update_policy)
+            .register::<dyn DeletePolicyPort>(delete_policy)
+            .register::<dyn SetUserStatusUseCasePort>(set_user_status)
+            .register::<dyn UpdateUserAttributesUseCasePort>(update_user_attributes)
+            .register::<dyn UpdateGroupAttributesUseCasePort>(update_group_attributes)
+            .register(policy_revalidator);
+
+        // ============================================================
+        // PASO 3: Fragmentos de esquema
+        // ============================================================
+        // Cada bounded context que aporte tipos al esquema añade aquí su
+        // proveedor; el arranque los registra todos antes de construirlo
+        info!("📦 Collecting schema fragments...");
+        let schema_fragments =
+            SchemaFragmentRegistry::new().with_provider(Arc::new(IamSchemaFragmentProvider));
+        container.register(Arc::new(schema_fragments));
+
+        container
+    }
+
+    /// Resuelve del contenedor todos los puertos que necesita la aplicación
+    ///
+    /// Falla con todos los puertos sin implementación a la vez, en lugar de
+    /// descubrirlos uno a uno cuando se ejecuta el código que los usa.
+    pub fn from_container(container: &Container) -> Result<Self, ContainerError> {
+        let mut ports = container.resolver();
+        let register_entity_type = ports.port::<dyn RegisterEntityTypePort>();
+        let register_action_type = ports.port::<dyn RegisterActionTypePort>();
+        let register_schema_fragments = ports.port::<dyn RegisterSchemaFragmentsPort>();
+        let build_schema = ports.port::<dyn BuildSchemaPort>();
+        let load_schema = ports.port::<dyn LoadSchemaPort>();
+        let validate_policy = ports.port::<dyn ValidatePolicyPort>();
+        let evaluate_policies = ports.port::<dyn EvaluatePoliciesPort>();
+        let readiness_evaluator = ports.port::<ReadinessEvaluator>();
+        let playground_evaluate = ports.port::<dyn PlaygroundEvaluatePort>();
+        let register_iam_schema = ports.port::<dyn RegisterIamSchemaPort>();
+        let create_policy = ports.port::<dyn CreatePolicyUseCasePort>();
+        let get_policy = ports.port::<dyn GetPolicyUseCasePort>();
+        let get_policies = ports.port::<dyn GetPoliciesUseCasePort>();
+        let list_policies = ports.port::<dyn PolicyLister>();
+        let update_policy = ports.port::<dyn UpdatePolicyPort>();
+        let delete_policy = ports.port::<dyn DeletePolicyPort>();
+        let set_user_status = ports.port::<dyn SetUserStatusUseCasePort>();
+        let update_user_attributes = ports.port::<dyn UpdateUserAttributesUseCasePort>();
+        let update_group_attributes = ports.port::<dyn UpdateGroupAttributesUseCasePort>();
+        let policy_revalidator = ports.port::<SchemaChangeRevalidator>();
+        let schema_fragments = ports.port::<SchemaFragmentRegistry>();
+        let event_bus = ports.port::<InMemoryEventBus>();
+        let audit_log = ports.port::<AuditLogStore>();
 
-        // 2.6. Delete policy port
-        info!("  └─ DeletePolicyPort");
-        let delete_policy: Arc<dyn hodei_iam::features::delete_policy::ports::DeletePolicyPort> =
-            policy_adapter;
-
-        let iam_ports = IamPorts {
-            register_iam_schema,
-            create_policy,
-            get_policy,
-            list_policies,
-            update_policy,
-            delete_policy,
+        // Sólo se ensambla si se resolvieron todos; si no, `finish` nombra
+        // los que faltan
+        let assemble = || {
+            Some(Self {
+                policy_ports: PolicyPorts {
+                    register_entity_type: register_entity_type?,
+                    register_action_type: register_action_type?,
+                    register_schema_fragments: register_schema_fragments?,
+                    build_schema: build_schema?,
+                    load_schema: load_schema?,
+                    validate_policy: validate_policy?,
+                    evaluate_policies: evaluate_policies?,
+                    readiness_evaluator: readiness_evaluator?.0.clone(),
+                    playground_evaluate: playground_evaluate?,
+                },
+                iam_ports: IamPorts {
+                    register_iam_schema: register_iam_schema?,
+                    create_policy: create_policy?,
+                    get_policy: get_policy?,
+                    get_policies: get_policies?,
+                    list_policies: list_policies?,
+                    update_policy: update_policy?,
+                    delete_policy: delete_policy?,
+                    set_user_status: set_user_status?,
+                    update_user_attributes: update_user_attributes?,
+                    update_group_attributes: update_group_attributes?,
+                },
+                schema_fragments: schema_fragments?,
+                policy_revalidator: policy_revalidator?,
+                event_bus: event_bus?,
+                audit_log: audit_log?,
+                event_subscriptions: Vec::new(),
+            })
         };
+        ports.finish(assemble())
+    }
 
-        info!("✅ Composition Root initialized successfully");
+    /// Suscribe la revalidación de políticas a los cambios de esquema
+    ///
+    /// Cada `SchemaChanged` que publica la construcción del esquema revalida
+    /// las políticas almacenadas en la tarea propia de la suscripción, así
+    /// que persistir un esquema nunca espera a la revalidación. Debe
+    /// suscribirse antes de registrar el esquema para no perder su evento.
+    pub async fn subscribe_policy_revalidation(&mut self) -> anyhow::Result<()> {
+        info!("📦 Subscribing policy revalidation to schema changes...");
+        let subscription = self
+            .event_bus
+            .subscribe::<SchemaChanged, _>(self.policy_revalidator.clone())
+            .await?;
+        self.event_subscriptions.push(subscription);
+        Ok(())
+    }
 
-        Self {
-            policy_ports,
-            iam_ports,
+    /// Suscribe la auditoría a los eventos de dominio del servidor
+    ///
+    /// Cada evento de IAM y de esquema publicado en el bus queda en
+    /// [`audit_log`](Self::audit_log), con la correlación de su sobre.
+    pub async fn subscribe_audit(&mut self) -> anyhow::Result<()> {
+        info!("📦 Subscribing the audit log to domain events...");
+        let handler = Arc::new(AuditEventHandler::new(self.audit_log.clone()));
+
+        let bus = &self.event_bus;
+        self.event_subscriptions.extend([
+            bus.subscribe::<PolicyStored, _>(handler.clone()).await?,
+            bus.subscribe::<PolicyDeleted, _>(handler.clone()).await?,
+            bus.subscribe::<PolicyDisabled, _>(handler.clone()).await?,
+            bus.subscribe::<PolicyAttachmentChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<GroupMembershipChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<UserStatusChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<UserAttributesChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<GroupAttributesChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<SchemaChanged, _>(handler.clone()).await?,
+            bus.subscribe::<AuditLogsRedacted, _>(handler).await?,
+        ]);
+        Ok(())
+    }
+
+    /// Suscribe la entrega de webhooks a los eventos de dominio del servidor
+    ///
+    /// Un único [`WebhookEventHandler`] se suscribe a cada tipo de evento
+    /// publicado en el bus y cada endpoint filtra los suyos por patrón. Las
+    /// entregas fallidas que agotan sus reintentos quedan en memoria como
+    /// dead letters.
+    pub async fn subscribe_webhooks(
+        &mut self,
+        endpoints: Vec<WebhookEndpoint>,
+        delivery: WebhookDeliveryConfig,
+    ) -> anyhow::Result<()> {
+        info!("📦 Subscribing {} webhook endpoint(s)...", endpoints.len());
+        let transport = Arc::new(ReqwestWebhookTransport::new(delivery.request_timeout)?);
+        let handler = Arc::new(WebhookEventHandler::new(
+            endpoints,
+            delivery,
+            transport,
+            Arc::new(InMemoryDeadLetterSink::new()),
+        ));
+
+        let bus = &self.event_bus;
+        self.event_subscriptions.extend([
+            bus.subscribe::<PolicyStored, _>(handler.clone()).await?,
+            bus.subscribe::<PolicyDeleted, _>(handler.clone()).await?,
+            bus.subscribe::<PolicyDisabled, _>(handler.clone()).await?,
+            bus.subscribe::<PolicyAttachmentChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<GroupMembershipChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<UserStatusChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<UserAttributesChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<GroupAttributesChanged, _>(handler.clone())
+                .await?,
+            bus.subscribe::<SchemaChanged, _>(handler).await?,
+        ]);
+        Ok(())
+    }
+
+    /// Autocomprobación del grafo de dependencias
+    ///
+    /// Recorre sin efectos el camino crítico de autorización: evalúa una
+    /// petición sintética, sin políticas ni esquema, con el evaluador del
+    /// readiness check, y espera la denegación por defecto. No toca el
+    /// almacenamiento ni las políticas cargadas para las peticiones reales.
+    pub async fn self_check(&self) -> Result<(), SelfCheckError> {
+        let canary = CanaryEntity::new();
+        let entities: Vec<&dyn HodeiEntity> = vec![&canary];
+        let policies = HodeiPolicySet::new(Vec::new());
+        let command = EvaluatePoliciesCommand::new(
+            AuthorizationRequest::new(&canary, SELF_CHECK_ACTION, &canary),
+            &policies,
+            &entities,
+        )
+        .no_schema();
+
+        let decision = self
+            .policy_ports
+            .readiness_evaluator
+            .evaluate(command)
+            .await
+            .map_err(|e| SelfCheckError::Evaluation(e.to_string()))?;
+        if decision.decision != Decision::Deny {
+            return Err(SelfCheckError::UnexpectedDecision(decision.decision));
         }
+        info!("✅ Composition Root self-check passed");
+        Ok(())
     }
 
     /// Crea el puerto de playground evaluate con todas sus dependencias
@@ -226,13 +639,26 @@ impl CompositionRoot {
         S: SchemaStoragePort + Clone + 'static,
         P: hodei_iam::features::create_policy::ports::CreatePolicyPort
             + hodei_iam::features::get_policy::ports::PolicyReader
+            + hodei_iam::features::get_policies::ports::PolicyBatchReader
             + hodei_iam::features::list_policies::ports::PolicyLister
             + hodei_iam::features::update_policy::ports::UpdatePolicyPort
             + hodei_iam::features::delete_policy::ports::DeletePolicyPort
+            + PolicyRevalidationStorePort
             + 'static,
     {
-        // En tests, podemos usar implementaciones mock
-        Self::production(schema_storage, policy_adapter)
+        // En tests, podemos usar implementaciones mock; usuarios y grupos
+        // viven en memoria
+        let principals = Arc::new(InMemoryIamRepository::new());
+        Self::production(
+            schema_storage,
+            policy_adapter,
+            principals.clone(),
+            principals,
+            ValidationLevel::default(),
+            AuditRetention::default(),
+            None,
+        )
+        .expect("production wiring is complete")
     }
 }
 
@@ -240,6 +666,7 @@ impl CompositionRoot {
 mod tests {
     use super::*;
     use async_trait::async_trait;
+    use hodei_iam::update_user_attributes::UpdateUserAttributesCommand;
     use hodei_policies::build_schema::dto::BuildSchemaCommand;
     use hodei_policies::build_schema::error::BuildSchemaError;
 
@@ -278,7 +705,11 @@ mod tests {
     }
 
     /// Mock simple de todos los puertos de políticas IAM
-    struct MockPolicyAdapter;
+    #[derive(Default)]
+    struct MockPolicyAdapter {
+        /// Recorridos de las políticas para revalidarlas
+        revalidation_reads: std::sync::atomic::AtomicUsize,
+    }
 
     #[async_trait]
     impl hodei_iam::features::create_policy::ports::CreatePolicyPort for MockPolicyAdapter {
@@ -317,10 +748,24 @@ mod tests {
                 name: "test-policy".to_string(),
                 content: "permit(principal, action, resource);".to_string(),
                 description: Some("Test policy".to_string()),
+                annotations: Default::default(),
             })
         }
     }
 
+    #[async_trait]
+    impl hodei_iam::features::get_policies::ports::PolicyBatchReader for MockPolicyAdapter {
+        async fn get_by_hrns(
+            &self,
+            _hrns: &[kernel::Hrn],
+        ) -> Result<
+            Vec<hodei_iam::features::get_policies::dto::PolicyView>,
+            hodei_iam::features::get_policies::error::GetPoliciesError,
+        > {
+            Ok(vec![])
+        }
+    }
+
     #[async_trait]
     impl hodei_iam::features::list_policies::ports::PolicyLister for MockPolicyAdapter {
         async fn list(
@@ -368,11 +813,49 @@ mod tests {
         }
     }
 
+    #[async_trait]
+    impl hodei_iam::features::policy_walk::ports::PolicyWalkStorePort for MockPolicyAdapter {
+        async fn list_policies(
+            &self,
+            _after: Option<&str>,
+            _limit: usize,
+        ) -> Result<
+            Vec<hodei_iam::features::policy_walk::dto::StoredPolicy>,
+            hodei_iam::features::policy_walk::error::PolicyWalkError,
+        > {
+            self.revalidation_reads
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(vec![])
+        }
+    }
+
+    #[async_trait]
+    impl PolicyRevalidationStorePort for MockPolicyAdapter {
+        async fn disable_policy(
+            &self,
+            _policy_id: &str,
+            _reason: &str,
+        ) -> Result<(), hodei_iam::features::revalidate_policies::error::RevalidatePoliciesError>
+        {
+            Ok(())
+        }
+    }
+
     #[test]
     fn test_composition_root_creates_all_ports() {
         let storage = Arc::new(MockSchemaStorage);
-        let policy_adapter = Arc::new(MockPolicyAdapter);
-        let root = CompositionRoot::production(storage, policy_adapter);
+        let policy_adapter = Arc::new(MockPolicyAdapter::default());
+        let principals = Arc::new(InMemoryIamRepository::new());
+        let root = CompositionRoot::production(
+            storage,
+            policy_adapter,
+            principals.clone(),
+            principals,
+            ValidationLevel::default(),
+            AuditRetention::default(),
+            None,
+        )
+        .unwrap();
 
         // Verificar que todos los puertos fueron creados
         assert!(Arc::strong_count(&root.policy_ports.register_entity_type) >= 1);
@@ -381,20 +864,35 @@ mod tests {
         assert!(Arc::strong_count(&root.policy_ports.load_schema) >= 1);
         assert!(Arc::strong_count(&root.policy_ports.validate_policy) >= 1);
         assert!(Arc::strong_count(&root.policy_ports.evaluate_policies) >= 1);
+        assert!(Arc::strong_count(&root.policy_ports.readiness_evaluator) >= 1);
         assert!(Arc::strong_count(&root.policy_ports.playground_evaluate) >= 1);
         assert!(Arc::strong_count(&root.iam_ports.register_iam_schema) >= 1);
         assert!(Arc::strong_count(&root.iam_ports.create_policy) >= 1);
         assert!(Arc::strong_count(&root.iam_ports.get_policy) >= 1);
+        assert!(Arc::strong_count(&root.iam_ports.get_policies) >= 1);
         assert!(Arc::strong_count(&root.iam_ports.list_policies) >= 1);
         assert!(Arc::strong_count(&root.iam_ports.update_policy) >= 1);
         assert!(Arc::strong_count(&root.iam_ports.delete_policy) >= 1);
+        assert!(Arc::strong_count(&root.iam_ports.set_user_status) >= 1);
+        assert!(Arc::strong_count(&root.iam_ports.update_user_attributes) >= 1);
+        assert!(Arc::strong_count(&root.iam_ports.update_group_attributes) >= 1);
     }
 
     #[tokio::test]
     async fn test_ports_are_usable() {
         let storage = Arc::new(MockSchemaStorage);
-        let policy_adapter = Arc::new(MockPolicyAdapter);
-        let root = CompositionRoot::production(storage, policy_adapter);
+        let policy_adapter = Arc::new(MockPolicyAdapter::default());
+        let principals = Arc::new(InMemoryIamRepository::new());
+        let root = CompositionRoot::production(
+            storage,
+            policy_adapter,
+            principals.clone(),
+            principals,
+            ValidationLevel::default(),
+            AuditRetention::default(),
+            None,
+        )
+        .unwrap();
 
         // Verificar que el puerto de build_schema es usable
         let command = BuildSchemaCommand {
@@ -413,8 +911,176 @@ mod tests {
     #[test]
     fn test_composition_root_for_testing() {
         let storage = Arc::new(MockSchemaStorage);
-        let policy_adapter = Arc::new(MockPolicyAdapter);
+        let policy_adapter = Arc::new(MockPolicyAdapter::default());
         let _root = CompositionRoot::test(storage, policy_adapter);
         // Si compila y se crea, el test pasa
     }
+
+    #[test]
+    fn test_from_container_names_every_missing_port() {
+        let storage = Arc::new(MockSchemaStorage);
+        let policy_adapter = Arc::new(MockPolicyAdapter::default());
+        let principals = Arc::new(InMemoryIamRepository::new());
+        let complete = CompositionRoot::production_container(
+            storage,
+            policy_adapter.clone(),
+            principals.clone(),
+            principals,
+            ValidationLevel::default(),
+            AuditRetention::default(),
+            None,
+        );
+        let mut container = Container::new();
+        container.register::<dyn PolicyLister>(policy_adapter);
+        container.register::<dyn LoadSchemaPort>(complete.resolver().port::<dyn LoadSchemaPort>().unwrap());
+
+        let error = CompositionRoot::from_container(&container).err().unwrap();
+
+        let ContainerError::MissingPorts(missing) = &error;
+        assert_eq!(missing.len(), 21);
+        assert!(missing.contains(&"EvaluatePoliciesPort"));
+        assert!(!missing.contains(&"PolicyLister"));
+        assert!(
+            error
+                .to_string()
+                .contains("port RegisterEntityTypePort has no implementation")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_self_check_runs_the_authorization_path() {
+        let storage = Arc::new(MockSchemaStorage);
+        let policy_adapter = Arc::new(MockPolicyAdapter::default());
+        let root = CompositionRoot::test(storage, policy_adapter);
+
+        root.self_check().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_registering_the_schema_revalidates_the_policies() {
+        let storage = Arc::new(MockSchemaStorage);
+        let policy_adapter = Arc::new(MockPolicyAdapter::default());
+        let mut root = CompositionRoot::test(storage, policy_adapter.clone());
+        root.subscribe_policy_revalidation().await.unwrap();
+        root.policy_ports
+            .register_schema_fragments
+            .execute(&root.schema_fragments)
+            .await
+            .unwrap();
+
+        root.iam_ports
+            .register_iam_schema
+            .register(
+                hodei_iam::features::register_iam_schema::RegisterIamSchemaCommand::new()
+                    .with_version("v1"),
+            )
+            .await
+            .unwrap();
+        let report = root.event_bus.drain(std::time::Duration::from_secs(5)).await;
+
+        assert_eq!(report.lost, 0);
+        assert_eq!(
+            policy_adapter
+                .revalidation_reads
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_attribute_changes_reach_the_audit_log() {
+        let storage = Arc::new(MockSchemaStorage);
+        let policy_adapter = Arc::new(MockPolicyAdapter::default());
+        let principals = Arc::new(InMemoryIamRepository::new());
+        let alice = kernel::Hrn::new(
+            "hodei".to_string(),
+            "iam".to_string(),
+            "default".to_string(),
+            "User".to_string(),
+            "alice".to_string(),
+        );
+        principals.add_user(alice.clone(), "alice", "alice@old.example.com");
+        let mut root = CompositionRoot::production(
+            storage,
+            policy_adapter,
+            principals.clone(),
+            principals,
+            ValidationLevel::default(),
+            AuditRetention::default(),
+            None,
+        )
+        .unwrap();
+        root.subscribe_audit().await.unwrap();
+
+        root.iam_ports
+            .update_user_attributes
+            .execute(UpdateUserAttributesCommand {
+                user_hrn: alice.to_string(),
+                email: Some("alice@example.com".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(root.event_bus.settle(std::time::Duration::from_secs(5)).await);
+
+        let logs = root.audit_log.all().await;
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].event_type, "iam.user.attributes_changed");
+        assert_eq!(logs[0].aggregate_id, Some(alice.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_policy_changes_reach_the_audit_log() {
+        let storage = Arc::new(MockSchemaStorage);
+        let principals = Arc::new(InMemoryIamRepository::new());
+        let mut root = CompositionRoot::production(
+            storage,
+            Arc::new(MockPolicyAdapter::default()),
+            principals.clone(),
+            principals,
+            ValidationLevel::default(),
+            AuditRetention::default(),
+            None,
+        )
+        .unwrap();
+        root.subscribe_audit().await.unwrap();
+
+        root.iam_ports
+            .delete_policy
+            .delete("allow-all")
+            .await
+            .unwrap();
+        assert!(root.event_bus.settle(std::time::Duration::from_secs(5)).await);
+
+        let logs = root.audit_log.all().await;
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].event_type, "iam.policy.deleted");
+        assert_eq!(logs[0].aggregate_id, Some("allow-all".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drain_delivers_pending_audit_events() {
+        let storage = Arc::new(MockSchemaStorage);
+        let principals = Arc::new(InMemoryIamRepository::new());
+        let mut root = CompositionRoot::production(
+            storage,
+            Arc::new(MockPolicyAdapter::default()),
+            principals.clone(),
+            principals,
+            ValidationLevel::default(),
+            AuditRetention::default(),
+            Some(2),
+        )
+        .unwrap();
+        root.subscribe_audit().await.unwrap();
+        // El bus que drena el apagado es el de AppState
+        let state =
+            crate::app_state::AppState::from_composition_root("v1".to_string(), root);
+
+        state.delete_policy.delete("allow-all").await.unwrap();
+        let report = state.event_bus.drain(std::time::Duration::from_secs(5)).await;
+
+        assert_eq!(report.lost, 0);
+        assert_eq!(state.audit_log.all().await.len(), 1);
+    }
 }
@@ -3,9 +3,30 @@
 //! This module handles loading and managing application configuration
 //! from multiple sources with hierarchical precedence and validation.
 
-use ::config::{Config, ConfigError, Environment, File};
+use ::config::{Config, ConfigError, Environment, File, Map, Source, Value, ValueKind};
+use hodei_policies::validate_policy::dto::ValidationLevel;
+use kernel::infrastructure::AuditRetention;
+use kernel::infrastructure::webhook::{
+    WebhookDeliveryConfig, WebhookEndpoint, WebhookSecret, is_valid_event_type_pattern,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Prefix of environment variables that override configuration values
+const ENV_PREFIX: &str = "HODEI";
+
+/// Separator between nested keys in environment variable names
+const ENV_SEPARATOR: &str = "__";
+
+/// Environment variable naming a configuration file to load
+pub const CONFIG_FILE_ENV: &str = "HODEI_CONFIG_FILE";
+
+/// Command line flag naming a configuration file to load
+pub const CONFIG_FILE_FLAG: &str = "--config";
 
 /// Main application configuration
 ///
@@ -32,6 +53,22 @@ pub struct AppConfig {
 
     /// RocksDB specific configuration
     pub rocksdb: RocksDbConfig,
+
+    /// Outgoing webhook configuration
+    #[serde(default)]
+    pub webhooks: WebhooksConfig,
+
+    /// Organizations configuration
+    #[serde(default)]
+    pub organizations: OrganizationsConfig,
+
+    /// Audit log configuration
+    #[serde(default)]
+    pub audit: AuditConfig,
+
+    /// Event bus configuration
+    #[serde(default)]
+    pub events: EventsConfig,
 }
 
 /// Server configuration
@@ -48,6 +85,22 @@ pub struct ServerConfig {
 
     /// Maximum request body size in bytes (default: 10MB)
     pub max_body_size: usize,
+
+    /// Routes answered with 503 instead of being served (default: none)
+    ///
+    /// An entry disables the path itself and everything below it, e.g.
+    /// `/api/v1/playground` also disables `/api/v1/playground/evaluate`.
+    /// Checked on every request, so a configuration reload applies at once.
+    #[serde(default)]
+    pub disabled_routes: BTreeSet<String>,
+
+    /// `Retry-After` sent with responses for disabled routes, in seconds (default: 300)
+    #[serde(default = "default_disabled_route_retry_after_secs")]
+    pub disabled_route_retry_after_secs: u64,
+
+    /// How long shutdown waits for event handlers to finish, in seconds (default: 10)
+    #[serde(default = "default_shutdown_drain_timeout_secs")]
+    pub shutdown_drain_timeout_secs: u64,
 }
 
 /// Database configuration
@@ -78,8 +131,53 @@ pub struct SchemaConfig {
     /// Whether to validate schemas after building (default: true)
     pub validate: bool,
 
-    /// Schema storage type (default: "rocksdb")
+    /// How strictly policies are validated against the schema (default: "strict")
+    /// Valid values: "strict" or "permissive". Evaluation is not affected, so
+    /// switching levels never changes what stored policies mean
+    #[serde(default)]
+    pub validation_level: ValidationLevel,
+
+    /// Schema storage backend (default: "rocksdb")
+    /// Valid values: "rocksdb" or "surrealdb" (the application's SurrealDB
+    /// database), "file" (a local directory), "s3" (a bucket shared by replicas)
     pub storage_type: String,
+
+    /// Directory of the "file" backend (default: "./data/schemas")
+    #[serde(default = "default_schema_file_path")]
+    pub file_path: String,
+
+    /// Bucket of the "s3" backend
+    #[serde(default)]
+    pub s3: SchemaS3Config,
+}
+
+/// S3 schema storage configuration
+///
+/// Credentials come from the standard AWS sources (environment, profile,
+/// instance role), never from this configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchemaS3Config {
+    /// Bucket the schemas are stored in
+    pub bucket: String,
+
+    /// Key prefix of the schema objects (default: "schemas/")
+    pub prefix: String,
+
+    /// Region of the bucket (default: from the AWS environment)
+    pub region: Option<String>,
+
+    /// Endpoint of an S3-compatible service such as MinIO (default: AWS)
+    pub endpoint: Option<String>,
+
+    /// Address buckets by path instead of subdomain, as MinIO needs (default: false)
+    pub force_path_style: bool,
+
+    /// Extra reads of a schema that isn't visible yet before it is reported missing (default: 3)
+    pub read_retries: u32,
+
+    /// Wait between those reads in milliseconds (default: 100)
+    pub read_retry_delay_ms: u64,
 }
 
 /// Logging configuration
@@ -119,6 +217,83 @@ pub struct RocksDbConfig {
     pub write_buffer_size: usize,
 }
 
+/// Outgoing webhook configuration
+///
+/// Endpoints are listed as `[[webhooks.endpoints]]` tables. Their signing
+/// secrets are never part of the configuration itself: each endpoint names
+/// the environment variable holding its secret in `secret_env`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhooksConfig {
+    /// Endpoints domain events are delivered to (default: none)
+    pub endpoints: Vec<WebhookEndpointConfig>,
+
+    /// Attempts per event and endpoint before it is dead-lettered (default: 5)
+    pub max_attempts: u32,
+
+    /// Wait after the first failed attempt in milliseconds, doubled after each further one (default: 500)
+    pub initial_backoff_ms: u64,
+
+    /// Upper bound of the wait between attempts in milliseconds (default: 30000)
+    pub max_backoff_ms: u64,
+
+    /// Timeout of a single delivery request in seconds (default: 10)
+    pub request_timeout_secs: u64,
+
+    /// Consecutive deliveries failing all their attempts that open an endpoint's circuit breaker (default: 5)
+    pub failure_threshold: u32,
+
+    /// How long an open circuit rejects deliveries in seconds (default: 60)
+    pub open_duration_secs: u64,
+}
+
+/// Organizations configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrganizationsConfig {
+    /// How the SCPs of the hierarchy levels combine (default: "cedar_default")
+    /// Valid values: "cedar_default", "allow_list_intersection"
+    pub scp_combination_strategy: String,
+}
+
+/// Audit log configuration
+///
+/// The audit log is kept in memory. At most one of the limits may be set;
+/// without either it keeps every entry.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    /// Entries kept before the earliest are evicted (default: 0, no limit)
+    pub max_entries: usize,
+
+    /// Bytes of entries, as serialized JSON, kept before the earliest are evicted (default: 0, no limit)
+    pub max_bytes: usize,
+}
+
+/// Event bus configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventsConfig {
+    /// Workers running the event handlers, at most this many at once (default: 0, each subscription runs its handler inline)
+    pub worker_pool_size: usize,
+}
+
+/// A webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEndpointConfig {
+    /// Unique name of the endpoint, used in logs and dead letters
+    pub name: String,
+
+    /// http(s) URL the events are POSTed to
+    pub url: String,
+
+    /// Event types delivered: exact types, prefixes ending in `.*`, or `*`
+    pub event_types: Vec<String>,
+
+    /// Environment variable holding the signing secret
+    pub secret_env: String,
+}
+
 // Default derived for AppConfig
 
 impl Default for ServerConfig {
@@ -128,10 +303,34 @@ impl Default for ServerConfig {
             port: 3000,
             request_timeout_secs: 30,
             max_body_size: 10 * 1024 * 1024, // 10MB
+            disabled_routes: BTreeSet::new(),
+            disabled_route_retry_after_secs: default_disabled_route_retry_after_secs(),
+            shutdown_drain_timeout_secs: default_shutdown_drain_timeout_secs(),
         }
     }
 }
 
+fn default_disabled_route_retry_after_secs() -> u64 {
+    300
+}
+
+fn default_shutdown_drain_timeout_secs() -> u64 {
+    10
+}
+
+/// Paths the orchestrator probes; they can never be disabled
+pub const PROBE_PATHS: [&str; 3] = ["/health", "/health/ready", "/health/live"];
+
+/// Whether the disabled-route entry `route` covers `path`
+pub fn route_covers_path(route: &str, path: &str) -> bool {
+    let route = route.trim_end_matches('/');
+    route.is_empty()
+        || path == route
+        || path
+            .strip_prefix(route)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
 impl Default for DatabaseConfig {
     fn default() -> Self {
         Self {
@@ -149,7 +348,28 @@ impl Default for SchemaConfig {
             register_iam_on_startup: false,
             version: None,
             validate: true,
+            validation_level: ValidationLevel::default(),
             storage_type: "rocksdb".to_string(),
+            file_path: default_schema_file_path(),
+            s3: SchemaS3Config::default(),
+        }
+    }
+}
+
+fn default_schema_file_path() -> String {
+    "./data/schemas".to_string()
+}
+
+impl Default for SchemaS3Config {
+    fn default() -> Self {
+        Self {
+            bucket: String::new(),
+            prefix: "schemas/".to_string(),
+            region: None,
+            endpoint: None,
+            force_path_style: false,
+            read_retries: 3,
+            read_retry_delay_ms: 100,
         }
     }
 }
@@ -177,6 +397,28 @@ impl Default for RocksDbConfig {
     }
 }
 
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            endpoints: Vec::new(),
+            max_attempts: 5,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            request_timeout_secs: 10,
+            failure_threshold: 5,
+            open_duration_secs: 60,
+        }
+    }
+}
+
+impl Default for OrganizationsConfig {
+    fn default() -> Self {
+        Self {
+            scp_combination_strategy: "cedar_default".to_string(),
+        }
+    }
+}
+
 impl AppConfig {
     /// Load configuration from multiple sources with hierarchical precedence
     ///
@@ -201,7 +443,7 @@ impl AppConfig {
             // Local overrides (higher precedence)
             .add_source(File::with_name("config/local").required(false))
             // Environment variables with HODEI_ prefix (highest precedence)
-            .add_source(Environment::with_prefix("HODEI").separator("__"))
+            .add_source(environment_source(None))
             .build()?;
 
         let app_config: AppConfig = s.try_deserialize()?;
@@ -212,16 +454,67 @@ impl AppConfig {
         Ok(app_config)
     }
 
+    /// Load configuration from a single JSON or TOML file
+    ///
+    /// Precedence (highest first):
+    /// 1. Environment variables (HODEI_ prefix)
+    /// 2. The given file
+    /// 3. Hardcoded defaults
+    ///
+    /// Unknown keys in the file are reported as warnings on stderr (logging
+    /// isn't set up yet while configuration loads) instead of failing, so a
+    /// file written for a newer version still loads. Validation errors name
+    /// the source the offending value came from. Keys set by environment
+    /// variables are listed as well, since they silently win over the file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let loaded = LoadedConfig::from_file(path)?;
+
+        for key in &loaded.unknown_keys {
+            eprintln!(
+                "⚠️  Ignoring unknown configuration key '{}' in {}",
+                key,
+                loaded.path.display()
+            );
+        }
+        for (key, source) in &loaded.sources {
+            if let ConfigSource::Env(_) = source {
+                eprintln!("ℹ️  Configuration key '{}' set from {}", key, source);
+            }
+        }
+
+        Ok(loaded.config)
+    }
+
+    /// Load configuration, from a file when one is named on the command line
+    /// (`--config <path>`) or in `HODEI_CONFIG_FILE`, otherwise as [`AppConfig::new`]
+    pub fn load() -> Result<Self, ConfigError> {
+        match config_file_from_args(env::args())
+            .or_else(|| env::var_os(CONFIG_FILE_ENV).map(PathBuf::from))
+        {
+            Some(path) => Self::from_file(path),
+            None => Self::new(),
+        }
+    }
+
     /// Validate the entire configuration
     ///
     /// # Returns
     ///
     /// Ok(()) if configuration is valid, ConfigError with clear message if invalid
     pub fn validate(&self) -> Result<(), ConfigError> {
-        self.server.validate()?;
-        self.database.validate()?;
-        self.rocksdb.validate()?;
-        self.logging.validate()?;
+        self.check().map_err(Into::into)
+    }
+
+    /// Validate the entire configuration, keeping the key of the invalid value
+    fn check(&self) -> Result<(), InvalidValue> {
+        self.server.check()?;
+        self.database.check()?;
+        self.rocksdb.check()?;
+        self.schema.check()?;
+        self.logging.check()?;
+        self.webhooks.check()?;
+        self.organizations.check()?;
+        self.audit.check()?;
         Ok(())
     }
 
@@ -234,62 +527,94 @@ impl AppConfig {
 }
 
 impl ServerConfig {
-    /// Validate server configuration
-    pub fn validate(&self) -> Result<(), ConfigError> {
+    fn check(&self) -> Result<(), InvalidValue> {
         if self.port == 0 {
-            return Err(ConfigError::Message(
-                "Server port cannot be 0. Please set HODEI_SERVER__PORT to a valid port number (1-65535)".to_string()
+            return Err(InvalidValue::new(
+                "server.port",
+                "Server port cannot be 0. Please set HODEI_SERVER__PORT to a valid port number (1-65535)".to_string(),
             ));
         }
 
         if self.host.is_empty() {
-            return Err(ConfigError::Message(
+            return Err(InvalidValue::new(
+                "server.host",
                 "Server host cannot be empty. Please set HODEI_SERVER__HOST".to_string(),
             ));
         }
 
         if self.request_timeout_secs == 0 {
-            return Err(ConfigError::Message(
-                "Request timeout cannot be 0. Please set HODEI_SERVER__REQUEST_TIMEOUT_SECS to a positive value".to_string()
+            return Err(InvalidValue::new(
+                "server.request_timeout_secs",
+                "Request timeout cannot be 0. Please set HODEI_SERVER__REQUEST_TIMEOUT_SECS to a positive value".to_string(),
             ));
         }
 
         if self.max_body_size == 0 {
-            return Err(ConfigError::Message(
-                "Max body size cannot be 0. Please set HODEI_SERVER__MAX_BODY_SIZE to a positive value".to_string()
+            return Err(InvalidValue::new(
+                "server.max_body_size",
+                "Max body size cannot be 0. Please set HODEI_SERVER__MAX_BODY_SIZE to a positive value".to_string(),
             ));
         }
 
+        for route in &self.disabled_routes {
+            if !route.starts_with('/') {
+                return Err(InvalidValue::new(
+                    "server.disabled_routes",
+                    format!(
+                        "Disabled route '{}' must be an absolute path starting with '/'",
+                        route
+                    ),
+                ));
+            }
+            if let Some(probe) = PROBE_PATHS
+                .iter()
+                .find(|probe| route_covers_path(route, probe))
+            {
+                return Err(InvalidValue::new(
+                    "server.disabled_routes",
+                    format!(
+                        "Disabled route '{}' would disable the {} probe endpoint, which must stay available",
+                        route, probe
+                    ),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
 
 impl DatabaseConfig {
-    /// Validate database configuration
-    pub fn validate(&self) -> Result<(), ConfigError> {
+    fn check(&self) -> Result<(), InvalidValue> {
         if self.db_type != "rocksdb" {
-            return Err(ConfigError::Message(format!(
-                "Unsupported database type '{}'. Only 'rocksdb' is supported. Please set HODEI_DATABASE__DB_TYPE to 'rocksdb'",
-                self.db_type
-            )));
+            return Err(InvalidValue::new(
+                "database.db_type",
+                format!(
+                    "Unsupported database type '{}'. Only 'rocksdb' is supported. Please set HODEI_DATABASE__DB_TYPE to 'rocksdb'",
+                    self.db_type
+                ),
+            ));
         }
 
         if self.namespace.is_none() || self.namespace.as_ref().unwrap().is_empty() {
-            return Err(ConfigError::Message(
+            return Err(InvalidValue::new(
+                "database.namespace",
                 "Database namespace cannot be empty. Please set HODEI_DATABASE__NAMESPACE"
                     .to_string(),
             ));
         }
 
         if self.database.is_none() || self.database.as_ref().unwrap().is_empty() {
-            return Err(ConfigError::Message(
+            return Err(InvalidValue::new(
+                "database.database",
                 "Database name cannot be empty. Please set HODEI_DATABASE__DATABASE".to_string(),
             ));
         }
 
         if self.pool_size == 0 {
-            return Err(ConfigError::Message(
-                "Database pool size cannot be 0. Please set HODEI_DATABASE__POOL_SIZE to a positive value".to_string()
+            return Err(InvalidValue::new(
+                "database.pool_size",
+                "Database pool size cannot be 0. Please set HODEI_DATABASE__POOL_SIZE to a positive value".to_string(),
             ));
         }
 
@@ -298,10 +623,10 @@ impl DatabaseConfig {
 }
 
 impl RocksDbConfig {
-    /// Validate RocksDB configuration
-    pub fn validate(&self) -> Result<(), ConfigError> {
+    fn check(&self) -> Result<(), InvalidValue> {
         if self.path.is_empty() {
-            return Err(ConfigError::Message(
+            return Err(InvalidValue::new(
+                "rocksdb.path",
                 "RocksDB path cannot be empty. Please set HODEI_ROCKSDB__PATH to a valid file path"
                     .to_string(),
             ));
@@ -314,10 +639,13 @@ impl RocksDbConfig {
             // Case 1: Path already exists
             // It must be a directory (RocksDB creates and uses directories)
             if !path.is_dir() {
-                return Err(ConfigError::Message(format!(
-                    "RocksDB path '{}' exists but is not a directory. RocksDB requires a directory path. Please remove this file or choose a different path.",
-                    self.path
-                )));
+                return Err(InvalidValue::new(
+                    "rocksdb.path",
+                    format!(
+                        "RocksDB path '{}' exists but is not a directory. RocksDB requires a directory path. Please remove this file or choose a different path.",
+                        self.path
+                    ),
+                ));
             }
 
             // Verify the directory is writable
@@ -328,11 +656,14 @@ impl RocksDbConfig {
                     let _ = std::fs::remove_file(test_file);
                 }
                 Err(e) => {
-                    return Err(ConfigError::Message(format!(
-                        "RocksDB directory '{}' exists but is not writable: {}. Please check permissions.",
-                        path.display(),
-                        e
-                    )));
+                    return Err(InvalidValue::new(
+                        "rocksdb.path",
+                        format!(
+                            "RocksDB directory '{}' exists but is not writable: {}. Please check permissions.",
+                            path.display(),
+                            e
+                        ),
+                    ));
                 }
             }
         } else {
@@ -344,17 +675,23 @@ impl RocksDbConfig {
                 // Parent doesn't exist, try to create it
                 if self.create_if_missing {
                     if let Err(e) = std::fs::create_dir_all(parent) {
-                        return Err(ConfigError::Message(format!(
-                            "Cannot create parent directory '{}' for RocksDB: {}. Please check permissions or set HODEI_ROCKSDB__PATH to a writable location.",
-                            parent.display(),
-                            e
-                        )));
+                        return Err(InvalidValue::new(
+                            "rocksdb.path",
+                            format!(
+                                "Cannot create parent directory '{}' for RocksDB: {}. Please check permissions or set HODEI_ROCKSDB__PATH to a writable location.",
+                                parent.display(),
+                                e
+                            ),
+                        ));
                     }
                 } else {
-                    return Err(ConfigError::Message(format!(
-                        "Parent directory '{}' does not exist and create_if_missing is false. Please create the directory manually or set create_if_missing=true.",
-                        parent.display()
-                    )));
+                    return Err(InvalidValue::new(
+                        "rocksdb.path",
+                        format!(
+                            "Parent directory '{}' does not exist and create_if_missing is false. Please create the directory manually or set create_if_missing=true.",
+                            parent.display()
+                        ),
+                    ));
                 }
             } else {
                 // Parent exists, verify it's writable
@@ -365,25 +702,30 @@ impl RocksDbConfig {
                         let _ = std::fs::remove_file(test_file);
                     }
                     Err(e) => {
-                        return Err(ConfigError::Message(format!(
-                            "Parent directory '{}' is not writable: {}. Please check permissions.",
-                            parent.display(),
-                            e
-                        )));
+                        return Err(InvalidValue::new(
+                            "rocksdb.path",
+                            format!(
+                                "Parent directory '{}' is not writable: {}. Please check permissions.",
+                                parent.display(),
+                                e
+                            ),
+                        ));
                     }
                 }
             }
         }
 
         if self.max_open_files <= 0 {
-            return Err(ConfigError::Message(
-                "RocksDB max_open_files must be positive. Please set HODEI_ROCKSDB__MAX_OPEN_FILES to a value > 0".to_string()
+            return Err(InvalidValue::new(
+                "rocksdb.max_open_files",
+                "RocksDB max_open_files must be positive. Please set HODEI_ROCKSDB__MAX_OPEN_FILES to a value > 0".to_string(),
             ));
         }
 
         if self.write_buffer_size == 0 {
-            return Err(ConfigError::Message(
-                "RocksDB write_buffer_size cannot be 0. Please set HODEI_ROCKSDB__WRITE_BUFFER_SIZE to a positive value".to_string()
+            return Err(InvalidValue::new(
+                "rocksdb.write_buffer_size",
+                "RocksDB write_buffer_size cannot be 0. Please set HODEI_ROCKSDB__WRITE_BUFFER_SIZE to a positive value".to_string(),
             ));
         }
 
@@ -391,29 +733,436 @@ impl RocksDbConfig {
     }
 }
 
+/// Schema storage backends accepted in `schema.storage_type`
+pub const SCHEMA_STORAGE_TYPES: [&str; 4] = ["rocksdb", "surrealdb", "file", "s3"];
+
+impl SchemaConfig {
+    fn check(&self) -> Result<(), InvalidValue> {
+        match self.storage_type.as_str() {
+            "rocksdb" | "surrealdb" => Ok(()),
+            "file" if self.file_path.is_empty() => Err(InvalidValue::new(
+                "schema.file_path",
+                "Schema file path cannot be empty with the 'file' storage. Please set HODEI_SCHEMA__FILE_PATH",
+            )),
+            "file" => Ok(()),
+            "s3" if self.s3.bucket.is_empty() => Err(InvalidValue::new(
+                "schema.s3.bucket",
+                "Schema bucket cannot be empty with the 's3' storage. Please set HODEI_SCHEMA__S3__BUCKET",
+            )),
+            "s3" => Ok(()),
+            other => Err(InvalidValue::new(
+                "schema.storage_type",
+                format!(
+                    "Unsupported schema storage '{}'. Valid values: {}. Please set HODEI_SCHEMA__STORAGE_TYPE to one of these",
+                    other,
+                    SCHEMA_STORAGE_TYPES.join(", ")
+                ),
+            )),
+        }
+    }
+}
+
 impl LoggingConfig {
-    /// Validate logging configuration
-    pub fn validate(&self) -> Result<(), ConfigError> {
+    fn check(&self) -> Result<(), InvalidValue> {
         let valid_levels = ["trace", "debug", "info", "warn", "error"];
         if !valid_levels.contains(&self.level.as_str()) {
-            return Err(ConfigError::Message(format!(
-                "Invalid log level '{}'. Valid values: {}. Please set HODEI_LOGGING__LEVEL to one of these",
-                self.level,
-                valid_levels.join(", ")
-            )));
+            return Err(InvalidValue::new(
+                "logging.level",
+                format!(
+                    "Invalid log level '{}'. Valid values: {}. Please set HODEI_LOGGING__LEVEL to one of these",
+                    self.level,
+                    valid_levels.join(", ")
+                ),
+            ));
         }
 
         let valid_formats = ["pretty", "json", "compact"];
         if !valid_formats.contains(&self.format.as_str()) {
-            return Err(ConfigError::Message(format!(
-                "Invalid log format '{}'. Valid values: {}. Please set HODEI_LOGGING__FORMAT to one of these",
-                self.format,
-                valid_formats.join(", ")
-            )));
+            return Err(InvalidValue::new(
+                "logging.format",
+                format!(
+                    "Invalid log format '{}'. Valid values: {}. Please set HODEI_LOGGING__FORMAT to one of these",
+                    self.format,
+                    valid_formats.join(", ")
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl OrganizationsConfig {
+    fn check(&self) -> Result<(), InvalidValue> {
+        let valid_strategies = ["cedar_default", "allow_list_intersection"];
+        if !valid_strategies.contains(&self.scp_combination_strategy.as_str()) {
+            return Err(InvalidValue::new(
+                "organizations.scp_combination_strategy",
+                format!(
+                    "Invalid SCP combination strategy '{}'. Valid values: {}. Please set HODEI_ORGANIZATIONS__SCP_COMBINATION_STRATEGY to one of these",
+                    self.scp_combination_strategy,
+                    valid_strategies.join(", ")
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl AuditConfig {
+    fn check(&self) -> Result<(), InvalidValue> {
+        if self.max_entries > 0 && self.max_bytes > 0 {
+            return Err(InvalidValue::new(
+                "audit.max_bytes",
+                "Audit retention can be limited by entries or by bytes, not both. Please unset HODEI_AUDIT__MAX_ENTRIES or HODEI_AUDIT__MAX_BYTES",
+            ));
         }
 
         Ok(())
     }
+
+    /// How much the audit log keeps
+    pub fn retention(&self) -> AuditRetention {
+        if self.max_entries > 0 {
+            AuditRetention::MaxEntries(self.max_entries)
+        } else if self.max_bytes > 0 {
+            AuditRetention::MaxBytes(self.max_bytes)
+        } else {
+            AuditRetention::Unbounded
+        }
+    }
+}
+
+impl EventsConfig {
+    /// Size of the event bus worker pool, `None` if handlers run inline
+    pub fn worker_pool(&self) -> Option<usize> {
+        (self.worker_pool_size > 0).then_some(self.worker_pool_size)
+    }
+}
+
+impl WebhooksConfig {
+    /// Validate webhook configuration
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.check().map_err(Into::into)
+    }
+
+    fn check(&self) -> Result<(), InvalidValue> {
+        if self.max_attempts == 0 {
+            return Err(InvalidValue::new(
+                "webhooks.max_attempts",
+                "Webhook max attempts cannot be 0. Please set HODEI_WEBHOOKS__MAX_ATTEMPTS to a positive value",
+            ));
+        }
+
+        if self.initial_backoff_ms > self.max_backoff_ms {
+            return Err(InvalidValue::new(
+                "webhooks.initial_backoff_ms",
+                format!(
+                    "Webhook initial backoff ({}ms) cannot exceed the max backoff ({}ms)",
+                    self.initial_backoff_ms, self.max_backoff_ms
+                ),
+            ));
+        }
+
+        if self.request_timeout_secs == 0 {
+            return Err(InvalidValue::new(
+                "webhooks.request_timeout_secs",
+                "Webhook request timeout cannot be 0. Please set HODEI_WEBHOOKS__REQUEST_TIMEOUT_SECS to a positive value",
+            ));
+        }
+
+        if self.failure_threshold == 0 {
+            return Err(InvalidValue::new(
+                "webhooks.failure_threshold",
+                "Webhook failure threshold cannot be 0. Please set HODEI_WEBHOOKS__FAILURE_THRESHOLD to a positive value",
+            ));
+        }
+
+        let mut names = BTreeSet::new();
+        for endpoint in &self.endpoints {
+            let invalid = |message: String| InvalidValue::new("webhooks.endpoints", message);
+
+            if endpoint.name.is_empty() {
+                return Err(invalid("Webhook endpoint name cannot be empty".to_string()));
+            }
+            if !names.insert(endpoint.name.as_str()) {
+                return Err(invalid(format!(
+                    "Webhook endpoint '{}' is configured more than once",
+                    endpoint.name
+                )));
+            }
+            if !(endpoint.url.starts_with("http://") || endpoint.url.starts_with("https://")) {
+                return Err(invalid(format!(
+                    "Webhook endpoint '{}' has URL '{}'; it must start with http:// or https://",
+                    endpoint.name, endpoint.url
+                )));
+            }
+            if endpoint.event_types.is_empty() {
+                return Err(invalid(format!(
+                    "Webhook endpoint '{}' must list at least one event type",
+                    endpoint.name
+                )));
+            }
+            if let Some(pattern) = endpoint
+                .event_types
+                .iter()
+                .find(|pattern| !is_valid_event_type_pattern(pattern))
+            {
+                return Err(invalid(format!(
+                    "Webhook endpoint '{}' has invalid event type '{}'. Use an exact type, a prefix ending in '.*', or '*'",
+                    endpoint.name, pattern
+                )));
+            }
+            if endpoint.secret_env.is_empty() {
+                return Err(invalid(format!(
+                    "Webhook endpoint '{}' must name the environment variable holding its secret in secret_env",
+                    endpoint.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retry, timeout and circuit breaker settings for webhook delivery
+    pub fn delivery(&self) -> WebhookDeliveryConfig {
+        WebhookDeliveryConfig {
+            max_attempts: self.max_attempts,
+            initial_backoff: Duration::from_millis(self.initial_backoff_ms),
+            max_backoff: Duration::from_millis(self.max_backoff_ms),
+            request_timeout: Duration::from_secs(self.request_timeout_secs),
+            failure_threshold: self.failure_threshold,
+            open_duration: Duration::from_secs(self.open_duration_secs),
+        }
+    }
+
+    /// The configured endpoints, with their secrets read from the environment
+    ///
+    /// # Errors
+    ///
+    /// Fails if the webhook configuration is invalid, or if a secret
+    /// variable is unset or holds an invalid secret.
+    pub fn endpoints(&self) -> Result<Vec<WebhookEndpoint>, ConfigError> {
+        self.endpoints_with_env(|name| env::var(name).ok())
+    }
+
+    fn endpoints_with_env(
+        &self,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Vec<WebhookEndpoint>, ConfigError> {
+        self.validate()?;
+        self.endpoints
+            .iter()
+            .map(|endpoint| {
+                let secret = lookup(&endpoint.secret_env).ok_or_else(|| {
+                    ConfigError::Message(format!(
+                        "Webhook endpoint '{}' reads its secret from {}, which is not set",
+                        endpoint.name, endpoint.secret_env
+                    ))
+                })?;
+                let secret = WebhookSecret::new(&secret).map_err(|e| {
+                    ConfigError::Message(format!(
+                        "Webhook endpoint '{}' has an invalid secret in {}: {}",
+                        endpoint.name, endpoint.secret_env, e
+                    ))
+                })?;
+                WebhookEndpoint::new(
+                    endpoint.name.clone(),
+                    endpoint.url.clone(),
+                    endpoint.event_types.clone(),
+                    secret,
+                )
+                .map_err(|e| ConfigError::Message(e.to_string()))
+            })
+            .collect()
+    }
+}
+
+/// A configuration value that failed validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InvalidValue {
+    /// Dotted key of the value (e.g. "server.port")
+    key: &'static str,
+    message: String,
+}
+
+impl InvalidValue {
+    fn new(key: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            key,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<InvalidValue> for ConfigError {
+    fn from(invalid: InvalidValue) -> Self {
+        ConfigError::Message(invalid.message)
+    }
+}
+
+/// Where a configuration value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// Hardcoded default
+    Default,
+    /// Configuration file
+    File(PathBuf),
+    /// Environment variable with this name
+    Env(String),
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "the default value"),
+            ConfigSource::File(path) => write!(f, "config file '{}'", path.display()),
+            ConfigSource::Env(name) => write!(f, "environment variable {}", name),
+        }
+    }
+}
+
+/// Result of loading configuration from a file
+#[derive(Debug, Clone)]
+pub struct LoadedConfig {
+    /// The validated configuration
+    pub config: AppConfig,
+
+    /// File the configuration was loaded from
+    pub path: PathBuf,
+
+    /// Source of every known key, by dotted key
+    pub sources: BTreeMap<String, ConfigSource>,
+
+    /// Keys present in the file that the application doesn't know about
+    pub unknown_keys: Vec<String>,
+}
+
+impl LoadedConfig {
+    /// Load defaults, then `path`, then HODEI_ environment variables
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        Self::from_file_with_env(path, env::vars().collect())
+    }
+
+    fn from_file_with_env(
+        path: impl AsRef<Path>,
+        env_vars: HashMap<String, String>,
+    ) -> Result<Self, ConfigError> {
+        let path = path.as_ref().to_path_buf();
+
+        let defaults = Config::try_from(&AppConfig::default())?;
+        let file = Config::builder()
+            .add_source(File::from(path.as_path()).required(true))
+            .build()?;
+
+        let merged = Config::builder()
+            .add_source(defaults.clone())
+            .add_source(file.clone())
+            .add_source(environment_source(Some(env_vars.clone())))
+            .build()?;
+
+        let known_keys = leaf_keys(defaults.collect()?);
+        let file_keys = leaf_keys(file.collect()?);
+
+        let sources = known_keys
+            .iter()
+            .map(|key| {
+                let env_name = env_var_name(key);
+                let source = if env_vars.contains_key(&env_name) {
+                    ConfigSource::Env(env_name)
+                } else if file_keys.contains(key) {
+                    ConfigSource::File(path.clone())
+                } else {
+                    ConfigSource::Default
+                };
+                (key.clone(), source)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let unknown_keys = file_keys.difference(&known_keys).cloned().collect();
+
+        let config: AppConfig = merged.try_deserialize()?;
+        config.check().map_err(|invalid| {
+            let source = sources
+                .get(invalid.key)
+                .cloned()
+                .unwrap_or(ConfigSource::Default);
+            ConfigError::Message(format!(
+                "{} (value of '{}' from {})",
+                invalid.message, invalid.key, source
+            ))
+        })?;
+
+        Ok(Self {
+            config,
+            path,
+            sources,
+            unknown_keys,
+        })
+    }
+}
+
+/// Environment variable source for HODEI_ variables (e.g. HODEI_SERVER__PORT)
+///
+/// `vars` replaces the process environment, which keeps tests hermetic.
+fn environment_source(vars: Option<HashMap<String, String>>) -> Environment {
+    Environment::with_prefix(ENV_PREFIX)
+        .prefix_separator("_")
+        .separator(ENV_SEPARATOR)
+        .list_separator(",")
+        .with_list_parse_key("server.disabled_routes")
+        .try_parsing(true)
+        .source(vars.map(|vars| vars.into_iter().collect()))
+}
+
+/// Name of the environment variable overriding a dotted key
+fn env_var_name(key: &str) -> String {
+    format!(
+        "{}_{}",
+        ENV_PREFIX,
+        key.replace('.', ENV_SEPARATOR).to_uppercase()
+    )
+}
+
+/// Path following `--config` (or given as `--config=<path>`) in `args`
+pub fn config_file_from_args(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == CONFIG_FILE_FLAG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg
+            .strip_prefix(CONFIG_FILE_FLAG)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
+}
+
+/// Dotted keys of all leaf values in a configuration table
+fn leaf_keys(table: Map<String, Value>) -> BTreeSet<String> {
+    fn collect(prefix: &str, table: Map<String, Value>, keys: &mut BTreeSet<String>) {
+        for (key, value) in table {
+            let key = if prefix.is_empty() {
+                key
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value.kind {
+                ValueKind::Table(table) => collect(&key, table, keys),
+                _ => {
+                    keys.insert(key);
+                }
+            }
+        }
+    }
+
+    let mut keys = BTreeSet::new();
+    collect("", table, &mut keys);
+    keys
 }
 
 #[cfg(test)]
@@ -463,18 +1212,438 @@ mod tests {
     #[test]
     fn test_rocksdb_validation() {
         let config = RocksDbConfig::default();
-        assert!(config.validate().is_ok());
+        assert!(config.check().is_ok());
 
         let invalid_config = RocksDbConfig {
             path: "".to_string(),
             ..Default::default()
         };
-        assert!(invalid_config.validate().is_err());
+        assert!(invalid_config.check().is_err());
 
         let invalid_config = RocksDbConfig {
             max_open_files: 0,
             ..Default::default()
         };
-        assert!(invalid_config.validate().is_err());
+        assert!(invalid_config.check().is_err());
+    }
+
+    fn write_config(extension: &str, contents: &str) -> tempfile::TempPath {
+        let mut file = tempfile::Builder::new()
+            .suffix(&format!(".{}", extension))
+            .tempfile()
+            .unwrap();
+        std::io::Write::write_all(&mut file, contents.as_bytes()).unwrap();
+        file.into_temp_path()
+    }
+
+    fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_file_overrides_defaults_and_env_overrides_file() {
+        let path = write_config(
+            "toml",
+            r#"
+            [server]
+            port = 8080
+            host = "127.0.0.1"
+
+            [logging]
+            level = "debug"
+            "#,
+        );
+
+        let loaded =
+            LoadedConfig::from_file_with_env(&path, env(&[("HODEI_SERVER__PORT", "9090")]))
+                .unwrap();
+
+        assert_eq!(loaded.config.server.port, 9090);
+        assert_eq!(loaded.config.server.host, "127.0.0.1");
+        assert_eq!(loaded.config.logging.level, "debug");
+        assert_eq!(loaded.config.database.pool_size, 10);
+
+        assert_eq!(
+            loaded.sources["server.port"],
+            ConfigSource::Env("HODEI_SERVER__PORT".to_string())
+        );
+        assert_eq!(
+            loaded.sources["server.host"],
+            ConfigSource::File(path.to_path_buf())
+        );
+        assert_eq!(loaded.sources["database.pool_size"], ConfigSource::Default);
+    }
+
+    #[test]
+    fn test_loads_json_files() {
+        let path = write_config("json", r#"{ "server": { "port": 4000 } }"#);
+
+        let loaded = LoadedConfig::from_file_with_env(&path, HashMap::new()).unwrap();
+
+        assert_eq!(loaded.config.server.port, 4000);
+        assert!(loaded.unknown_keys.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_keys_are_reported_not_rejected() {
+        let path = write_config(
+            "toml",
+            r#"
+            [server]
+            port = 4000
+            tls = true
+
+            [metrics]
+            enabled = true
+            "#,
+        );
+
+        let loaded = LoadedConfig::from_file_with_env(&path, HashMap::new()).unwrap();
+
+        assert_eq!(loaded.config.server.port, 4000);
+        assert_eq!(loaded.unknown_keys, vec!["metrics.enabled", "server.tls"]);
+    }
+
+    #[test]
+    fn test_validation_errors_name_the_value_source() {
+        let path = write_config("toml", "[logging]\nlevel = \"loud\"\n");
+
+        let from_file = LoadedConfig::from_file_with_env(&path, HashMap::new())
+            .unwrap_err()
+            .to_string();
+        assert!(from_file.contains("logging.level"));
+        assert!(from_file.contains("config file"));
+
+        let from_env = LoadedConfig::from_file_with_env(
+            &path,
+            env(&[
+                ("HODEI_SERVER__PORT", "0"),
+                ("HODEI_LOGGING__LEVEL", "info"),
+            ]),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(from_env.contains("environment variable HODEI_SERVER__PORT"));
+    }
+
+    #[test]
+    fn test_disabled_routes_cover_subpaths() {
+        assert!(route_covers_path(
+            "/api/v1/playground",
+            "/api/v1/playground"
+        ));
+        assert!(route_covers_path(
+            "/api/v1/playground/",
+            "/api/v1/playground/evaluate"
+        ));
+        assert!(!route_covers_path(
+            "/api/v1/playground",
+            "/api/v1/playgrounds"
+        ));
+        assert!(route_covers_path("/", "/health"));
+    }
+
+    #[test]
+    fn test_probe_routes_cannot_be_disabled() {
+        let mut config = AppConfig::default();
+        config.server.disabled_routes = ["/api/v1/playground".to_string()].into();
+        assert!(config.validate().is_ok());
+
+        for route in ["/health", "/health/ready", "/", "api/v1/policies"] {
+            let mut config = AppConfig::default();
+            config.server.disabled_routes = [route.to_string()].into();
+            assert!(config.validate().is_err(), "{} should be rejected", route);
+        }
+    }
+
+    #[test]
+    fn test_disabled_routes_from_env() {
+        let path = write_config("toml", "[server]\nport = 4000\n");
+
+        let loaded = LoadedConfig::from_file_with_env(
+            &path,
+            env(&[(
+                "HODEI_SERVER__DISABLED_ROUTES",
+                "/api/v1/playground,/api/v1/policies/evaluate",
+            )]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            loaded.config.server.disabled_routes,
+            BTreeSet::from([
+                "/api/v1/playground".to_string(),
+                "/api/v1/policies/evaluate".to_string(),
+            ])
+        );
+        assert_eq!(loaded.config.server.disabled_route_retry_after_secs, 300);
+    }
+
+    #[test]
+    fn test_webhook_endpoints_from_file() {
+        let path = write_config(
+            "toml",
+            r#"
+[webhooks]
+max_attempts = 3
+
+[[webhooks.endpoints]]
+name = "siem"
+url = "https://siem.example.com/hooks"
+event_types = ["iam.user.*", "iam.policy.deleted"]
+secret_env = "SIEM_WEBHOOK_SECRET"
+"#,
+        );
+
+        let loaded = LoadedConfig::from_file_with_env(&path, HashMap::new()).unwrap();
+        let webhooks = &loaded.config.webhooks;
+        assert_eq!(webhooks.delivery().max_attempts, 3);
+        assert_eq!(webhooks.delivery().request_timeout, Duration::from_secs(10));
+
+        let secrets = env(&[("SIEM_WEBHOOK_SECRET", "s3cr3t")]);
+        let endpoints = webhooks
+            .endpoints_with_env(|name| secrets.get(name).cloned())
+            .unwrap();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].name, "siem");
+        assert!(endpoints[0].matches("iam.user.created"));
+        assert!(!endpoints[0].matches("iam.group.created"));
+
+        let missing = webhooks.endpoints_with_env(|_| None).unwrap_err();
+        assert!(missing.to_string().contains("SIEM_WEBHOOK_SECRET"));
+
+        let invalid = WebhooksConfig {
+            max_attempts: 0,
+            ..webhooks.clone()
+        };
+        let error = invalid
+            .endpoints_with_env(|name| secrets.get(name).cloned())
+            .unwrap_err();
+        assert!(error.to_string().contains("max attempts"));
+    }
+
+    #[test]
+    fn test_schema_storage_from_file_and_env() {
+        let path = write_config(
+            "toml",
+            r#"
+[schema]
+register_iam_on_startup = false
+validate = true
+storage_type = "s3"
+
+[schema.s3]
+bucket = "hodei-schemas"
+endpoint = "http://localhost:9000"
+force_path_style = true
+"#,
+        );
+
+        let loaded = LoadedConfig::from_file_with_env(
+            &path,
+            env(&[("HODEI_SCHEMA__S3__PREFIX", "staging/schemas/")]),
+        )
+        .unwrap();
+
+        let schema = &loaded.config.schema;
+        assert_eq!(schema.storage_type, "s3");
+        assert_eq!(schema.s3.bucket, "hodei-schemas");
+        assert_eq!(schema.s3.prefix, "staging/schemas/");
+        assert!(schema.s3.force_path_style);
+        assert_eq!(schema.s3.read_retries, 3);
+        assert_eq!(schema.file_path, "./data/schemas");
+    }
+
+    #[test]
+    fn test_schema_validation_level_from_file_and_env() {
+        let path = write_config(
+            "toml",
+            r#"
+[schema]
+register_iam_on_startup = false
+validate = true
+storage_type = "rocksdb"
+"#,
+        );
+
+        let loaded = LoadedConfig::from_file_with_env(&path, env(&[])).unwrap();
+        assert_eq!(
+            loaded.config.schema.validation_level,
+            ValidationLevel::Strict
+        );
+
+        let loaded = LoadedConfig::from_file_with_env(
+            &path,
+            env(&[("HODEI_SCHEMA__VALIDATION_LEVEL", "permissive")]),
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.config.schema.validation_level,
+            ValidationLevel::Permissive
+        );
+
+        assert!(
+            LoadedConfig::from_file_with_env(
+                &path,
+                env(&[("HODEI_SCHEMA__VALIDATION_LEVEL", "lenient")]),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_schema_storage_validation() {
+        let valid = |storage_type: &str| SchemaConfig {
+            storage_type: storage_type.to_string(),
+            s3: SchemaS3Config {
+                bucket: "hodei-schemas".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for storage_type in SCHEMA_STORAGE_TYPES {
+            assert!(valid(storage_type).check().is_ok(), "{}", storage_type);
+        }
+
+        assert!(valid("mongodb").check().is_err());
+        let no_bucket = SchemaConfig {
+            s3: SchemaS3Config::default(),
+            ..valid("s3")
+        };
+        assert_eq!(no_bucket.check().unwrap_err().key, "schema.s3.bucket");
+        let no_path = SchemaConfig {
+            file_path: String::new(),
+            ..valid("file")
+        };
+        assert_eq!(no_path.check().unwrap_err().key, "schema.file_path");
+    }
+
+    #[test]
+    fn test_webhook_validation() {
+        let endpoint = WebhookEndpointConfig {
+            name: "siem".to_string(),
+            url: "https://siem.example.com/hooks".to_string(),
+            event_types: vec!["*".to_string()],
+            secret_env: "SIEM_WEBHOOK_SECRET".to_string(),
+        };
+        let webhooks = WebhooksConfig {
+            endpoints: vec![endpoint.clone()],
+            ..Default::default()
+        };
+        assert!(webhooks.validate().is_ok());
+
+        let invalid = [
+            WebhookEndpointConfig {
+                url: "ftp://siem.example.com".to_string(),
+                ..endpoint.clone()
+            },
+            WebhookEndpointConfig {
+                event_types: vec![],
+                ..endpoint.clone()
+            },
+            WebhookEndpointConfig {
+                event_types: vec!["iam.*.created".to_string()],
+                ..endpoint.clone()
+            },
+            WebhookEndpointConfig {
+                secret_env: String::new(),
+                ..endpoint.clone()
+            },
+        ];
+        for invalid_endpoint in invalid {
+            let webhooks = WebhooksConfig {
+                endpoints: vec![invalid_endpoint.clone()],
+                ..Default::default()
+            };
+            assert!(webhooks.validate().is_err(), "{:?}", invalid_endpoint);
+        }
+
+        let duplicated = WebhooksConfig {
+            endpoints: vec![endpoint.clone(), endpoint],
+            ..Default::default()
+        };
+        assert!(duplicated.validate().is_err());
+
+        let no_attempts = WebhooksConfig {
+            max_attempts: 0,
+            ..Default::default()
+        };
+        assert!(no_attempts.validate().is_err());
+    }
+
+    #[test]
+    fn test_scp_combination_strategy_validation() {
+        assert!(OrganizationsConfig::default().check().is_ok());
+        let intersection = OrganizationsConfig {
+            scp_combination_strategy: "allow_list_intersection".to_string(),
+        };
+        assert!(intersection.check().is_ok());
+
+        let unknown = OrganizationsConfig {
+            scp_combination_strategy: "deny_list".to_string(),
+        };
+        assert_eq!(
+            unknown.check().unwrap_err().key,
+            "organizations.scp_combination_strategy"
+        );
+    }
+
+    #[test]
+    fn test_audit_retention_from_file_and_env() {
+        let path = write_config("toml", "[audit]\nmax_entries = 1000\n");
+
+        let loaded = LoadedConfig::from_file_with_env(&path, HashMap::new()).unwrap();
+        assert_eq!(
+            loaded.config.audit.retention(),
+            AuditRetention::MaxEntries(1000)
+        );
+        assert_eq!(
+            AppConfig::default().audit.retention(),
+            AuditRetention::Unbounded
+        );
+
+        let both = LoadedConfig::from_file_with_env(&path, env(&[("HODEI_AUDIT__MAX_BYTES", "1")]))
+            .unwrap_err()
+            .to_string();
+        assert!(both.contains("environment variable HODEI_AUDIT__MAX_BYTES"));
+    }
+
+    #[test]
+    fn test_event_worker_pool_from_file_and_env() {
+        let path = write_config("toml", "[events]\nworker_pool_size = 8\n");
+
+        let loaded = LoadedConfig::from_file_with_env(&path, HashMap::new()).unwrap();
+        assert_eq!(loaded.config.events.worker_pool(), Some(8));
+        assert_eq!(AppConfig::default().events.worker_pool(), None);
+
+        let inline = LoadedConfig::from_file_with_env(
+            &path,
+            env(&[("HODEI_EVENTS__WORKER_POOL_SIZE", "0")]),
+        )
+        .unwrap();
+        assert_eq!(inline.config.events.worker_pool(), None);
+    }
+
+    #[test]
+    fn test_missing_file_is_an_error() {
+        let result = LoadedConfig::from_file_with_env("/nonexistent/hodei.toml", HashMap::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_config_file_from_args() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            config_file_from_args(args(&["hodei", "--config", "local.toml"])),
+            Some(PathBuf::from("local.toml"))
+        );
+        assert_eq!(
+            config_file_from_args(args(&["hodei", "--config=local.json"])),
+            Some(PathBuf::from("local.json"))
+        );
+        assert_eq!(config_file_from_args(args(&["hodei"])), None);
     }
 }
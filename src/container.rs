@@ -0,0 +1,165 @@
+//! Port container checked at startup
+//!
+//! The composition root registers every port implementation it builds here,
+//! keyed by the port trait, and then resolves the ports the application
+//! needs back out of it. Resolving checks that an implementation was
+//! registered for exactly that trait, and every missing port is reported at
+//! once, so a wiring mistake fails startup with "port X has no
+//! implementation" instead of surfacing later on the code path that needs it.
+
+use std::any::{Any, TypeId, type_name};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Wiring error found while resolving ports
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ContainerError {
+    #[error("{}", describe_missing(.0))]
+    MissingPorts(Vec<&'static str>),
+}
+
+fn describe_missing(ports: &[&'static str]) -> String {
+    ports
+        .iter()
+        .map(|port| format!("port {} has no implementation", port))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Port implementations, keyed by the port they implement
+#[derive(Default)]
+pub struct Container {
+    ports: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl Container {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `implementation` as the port `P`, e.g. `dyn LoadSchemaPort`
+    ///
+    /// Registering a port again replaces the previous implementation.
+    pub fn register<P>(&mut self, implementation: Arc<P>) -> &mut Self
+    where
+        P: ?Sized + Send + Sync + 'static,
+    {
+        self.ports
+            .insert(TypeId::of::<P>(), Box::new(implementation));
+        self
+    }
+
+    /// Resolve several ports, collecting every missing one
+    pub fn resolver(&self) -> Resolver<'_> {
+        Resolver {
+            container: self,
+            missing: Vec::new(),
+        }
+    }
+
+    fn lookup<P>(&self) -> Option<Arc<P>>
+    where
+        P: ?Sized + Send + Sync + 'static,
+    {
+        self.ports
+            .get(&TypeId::of::<P>())
+            .and_then(|port| port.downcast_ref::<Arc<P>>())
+            .cloned()
+    }
+}
+
+/// Resolves ports one by one and reports all the missing ones together
+pub struct Resolver<'a> {
+    container: &'a Container,
+    missing: Vec<&'static str>,
+}
+
+impl Resolver<'_> {
+    /// The implementation of port `P`, or `None` if it is missing, in which
+    /// case [`finish`](Self::finish) fails
+    pub fn port<P>(&mut self) -> Option<Arc<P>>
+    where
+        P: ?Sized + Send + Sync + 'static,
+    {
+        let port = self.container.lookup::<P>();
+        if port.is_none() {
+            self.missing.push(port_name::<P>());
+        }
+        port
+    }
+
+    /// `assembled`, built from the resolved ports, or the error naming every
+    /// port asked for that was missing
+    pub fn finish<T>(self, assembled: Option<T>) -> Result<T, ContainerError> {
+        match assembled {
+            Some(value) if self.missing.is_empty() => Ok(value),
+            _ => Err(ContainerError::MissingPorts(self.missing)),
+        }
+    }
+}
+
+/// `dyn hodei_policies::...::LoadSchemaPort` → `LoadSchemaPort`
+fn port_name<P: ?Sized>() -> &'static str {
+    let name = type_name::<P>();
+    let name = name.strip_prefix("dyn ").unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    trait Greeter: Send + Sync {
+        fn greet(&self) -> &'static str;
+    }
+
+    trait Counter: Send + Sync {}
+
+    struct English;
+
+    impl Greeter for English {
+        fn greet(&self) -> &'static str {
+            "hello"
+        }
+    }
+
+    #[test]
+    fn test_resolves_registered_port() {
+        let mut container = Container::new();
+        container.register::<dyn Greeter>(Arc::new(English));
+
+        let mut resolver = container.resolver();
+        let greeter = resolver.port::<dyn Greeter>();
+        let greeter = resolver.finish(greeter).unwrap();
+
+        assert_eq!(greeter.greet(), "hello");
+    }
+
+    #[test]
+    fn test_missing_port_is_named() {
+        let container = Container::new();
+
+        let mut resolver = container.resolver();
+        let greeter = resolver.port::<dyn Greeter>();
+        let error = resolver.finish(greeter).err().unwrap();
+
+        assert_eq!(error.to_string(), "port Greeter has no implementation");
+    }
+
+    #[test]
+    fn test_resolver_reports_every_missing_port() {
+        let mut container = Container::new();
+        container.register::<dyn Greeter>(Arc::new(English));
+
+        let mut resolver = container.resolver();
+        assert!(resolver.port::<dyn Greeter>().is_some());
+        assert!(resolver.port::<dyn Counter>().is_none());
+        // Registered under the concrete type, not the port
+        assert!(resolver.port::<English>().is_none());
+
+        assert_eq!(
+            resolver.finish(Some(())),
+            Err(ContainerError::MissingPorts(vec!["Counter", "English"]))
+        );
+    }
+}
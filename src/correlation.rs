@@ -0,0 +1,128 @@
+//! Request correlation IDs
+//!
+//! Every request carries a correlation ID: the one sent by the client in the
+//! `x-correlation-id` header, or a fresh UUID when the header is missing or
+//! unusable. The ID is written back onto the request so the HTTP trace span
+//! records it, made current for the use cases through
+//! [`kernel::with_correlation_id`], and echoed on the response so clients can
+//! quote it when reporting a problem.
+
+use axum::{
+    extract::Request,
+    http::{self, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use kernel::{CORRELATION_ID_HEADER, with_correlation_id};
+use tracing::Span;
+use uuid::Uuid;
+
+/// Longest client-supplied correlation ID that is accepted as is
+const MAX_CORRELATION_ID_LEN: usize = 128;
+
+/// Middleware assigning and propagating the request's correlation ID
+///
+/// Must be layered outside the `TraceLayer` so the header is in place when
+/// the request span is created.
+pub async fn propagate_correlation_id(mut request: Request, next: Next) -> Response {
+    let correlation_id = request
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| !id.is_empty() && id.len() <= MAX_CORRELATION_ID_LEN)
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let header_value =
+        HeaderValue::from_str(&correlation_id).expect("correlation IDs are visible ASCII");
+
+    request
+        .headers_mut()
+        .insert(CORRELATION_ID_HEADER, header_value.clone());
+
+    let mut response = with_correlation_id(correlation_id, next.run(request)).await;
+    response
+        .headers_mut()
+        .insert(CORRELATION_ID_HEADER, header_value);
+    response
+}
+
+/// Span for an incoming request, carrying its correlation ID
+///
+/// Records the same fields as tower-http's default span plus
+/// `correlation_id`, so use-case spans nested under it can be matched with
+/// the request.
+pub fn make_request_span<B>(request: &http::Request<B>) -> Span {
+    let correlation_id = request
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        version = ?request.version(),
+        correlation_id = correlation_id,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/whoami",
+                get(|| async { kernel::current_correlation_id().unwrap_or_default() }),
+            )
+            .layer(middleware::from_fn(propagate_correlation_id))
+    }
+
+    async fn send(request: Request) -> (String, String) {
+        let response = app().oneshot(request).await.unwrap();
+        let header = response.headers()[CORRELATION_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (header, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn client_correlation_id_reaches_the_handler_and_the_response() {
+        let request = Request::get("/whoami")
+            .header(CORRELATION_ID_HEADER, "req-42")
+            .body(Body::empty())
+            .unwrap();
+
+        let (header, seen_by_handler) = send(request).await;
+
+        assert_eq!(header, "req-42");
+        assert_eq!(seen_by_handler, "req-42");
+    }
+
+    #[tokio::test]
+    async fn missing_or_oversized_correlation_id_is_replaced() {
+        let missing = Request::get("/whoami").body(Body::empty()).unwrap();
+        let oversized = Request::get("/whoami")
+            .header(
+                CORRELATION_ID_HEADER,
+                "x".repeat(MAX_CORRELATION_ID_LEN + 1),
+            )
+            .body(Body::empty())
+            .unwrap();
+
+        for request in [missing, oversized] {
+            let (header, seen_by_handler) = send(request).await;
+
+            assert!(Uuid::parse_str(&header).is_ok());
+            assert_eq!(seen_by_handler, header);
+        }
+    }
+}
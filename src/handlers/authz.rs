@@ -0,0 +1,320 @@
+//! Bulk authorization handlers
+//!
+//! This module provides HTTP handlers that answer many authorization
+//! questions in one request:
+//! - Evaluating one action for every principal/resource pair (a matrix)
+
+use super::error::ApiError;
+use super::policies::DiagnosticInfo;
+use crate::app_state::AppState;
+use axum::{Json, extract::State};
+use hodei_policies::evaluate_policies::dto::{
+    Decision, DiagnosticLevel, EvaluateMatrixCommand, MatrixCell, MatrixDecision,
+};
+use kernel::domain::policy::{HodeiPolicy, HodeiPolicySet, PolicyId};
+use kernel::{AttributeName, AttributeValue, HodeiEntity, Hrn};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Request to evaluate an action for every principal/resource pair
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct AuthorizationMatrixRequest {
+    /// Principal HRNs, one row of the grid each
+    pub principals: Vec<String>,
+    /// Action evaluated in every cell (e.g., "ReadArtifact")
+    pub action: String,
+    /// Resource HRNs, one column of the grid each
+    pub resources: Vec<String>,
+    /// Cedar policy content (inline policies)
+    pub policies: Vec<String>,
+    /// Optional context shared by every cell
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub context: HashMap<String, serde_json::Value>,
+    /// Optional schema version to use
+    pub schema_version: Option<String>,
+}
+
+/// Decision grid of a matrix evaluation
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct AuthorizationMatrixResponse {
+    /// The action evaluated
+    pub action: String,
+    /// Principal HRNs, in row order
+    pub principals: Vec<String>,
+    /// Resource HRNs, in column order
+    pub resources: Vec<String>,
+    /// One row per principal, one cell per resource
+    pub rows: Vec<Vec<MatrixCellDto>>,
+    /// Version of the policy set every cell was evaluated against
+    pub policy_set_version: Option<String>,
+    /// Schema version used (if any)
+    pub used_schema_version: Option<String>,
+    /// Policy IDs that were evaluated
+    pub policy_ids_evaluated: Vec<String>,
+    /// Diagnostic information
+    pub diagnostics: Vec<DiagnosticInfo>,
+}
+
+/// One principal/resource pair of the grid
+///
+/// Either `decision` is set, or `error` says why the pair couldn't be evaluated.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct MatrixCellDto {
+    /// Decision: "Allow" or "Deny"
+    pub decision: Option<String>,
+    /// Policies that determined the decision
+    pub determining_policies: Vec<String>,
+    /// Why the pair couldn't be evaluated
+    pub error: Option<String>,
+}
+
+/// Principal or resource of the grid, known only by its HRN
+///
+/// Policies can match on its identity and type; it has no attributes or
+/// parents.
+#[derive(Debug)]
+struct HrnEntity {
+    hrn: Hrn,
+}
+
+impl HodeiEntity for HrnEntity {
+    fn hrn(&self) -> &Hrn {
+        &self.hrn
+    }
+
+    fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+        HashMap::new()
+    }
+}
+
+/// Handler to evaluate an action for every principal/resource pair
+///
+/// The policies and entities are loaded once and every cell is evaluated
+/// against them. A pair that can't be evaluated is reported in its cell
+/// instead of failing the request; a grid above the engine's cell limit is
+/// refused with `413` before anything is evaluated.
+///
+/// # Example Request
+///
+/// ```json
+/// {
+///   "principals": ["hrn:hodei:iam::default:User/alice", "hrn:hodei:iam::default:User/bob"],
+///   "action": "ReadArtifact",
+///   "resources": ["hrn:hodei:artifact::default:Artifact/report"],
+///   "policies": [
+///     "permit(principal == Iam::User::\"alice\", action, resource);"
+///   ]
+/// }
+/// ```
+///
+/// # Example Response
+///
+/// ```json
+/// {
+///   "action": "ReadArtifact",
+///   "principals": ["hrn:hodei:iam::default:User/alice", "hrn:hodei:iam::default:User/bob"],
+///   "resources": ["hrn:hodei:artifact::default:Artifact/report"],
+///   "rows": [
+///     [{"decision": "Allow", "determining_policies": ["policy_0"], "error": null}],
+///     [{"decision": "Deny", "determining_policies": [], "error": null}]
+///   ],
+///   "policy_set_version": "…",
+///   "used_schema_version": null,
+///   "policy_ids_evaluated": ["policy_0"],
+///   "diagnostics": []
+/// }
+/// ```
+#[utoipa::path(
+    post,
+    path = "/api/v1/authz/matrix",
+    tag = "authz",
+    request_body = AuthorizationMatrixRequest,
+    responses(
+        (status = 200, description = "Every cell evaluated", body = AuthorizationMatrixResponse),
+        (status = 400, description = "Invalid principal or resource HRN"),
+        (status = 413, description = "Grid larger than the cell limit"),
+        (status = 422, description = "Invalid policies"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn authorization_matrix(
+    State(state): State<AppState>,
+    Json(request): Json<AuthorizationMatrixRequest>,
+) -> Result<Json<AuthorizationMatrixResponse>, ApiError> {
+    let principals = parse_entities("principal", &request.principals)?;
+    let resources = parse_entities("resource", &request.resources)?;
+
+    let policies = HodeiPolicySet::new(
+        request
+            .policies
+            .iter()
+            .enumerate()
+            .map(|(i, content)| {
+                HodeiPolicy::new(PolicyId::new(format!("policy_{}", i)), content.clone())
+            })
+            .collect(),
+    );
+
+    let principal_refs: Vec<&dyn HodeiEntity> =
+        principals.iter().map(|e| e as &dyn HodeiEntity).collect();
+    let resource_refs: Vec<&dyn HodeiEntity> =
+        resources.iter().map(|e| e as &dyn HodeiEntity).collect();
+    // The entity store holds each entity once, however often it appears
+    let mut seen = HashSet::new();
+    let entities: Vec<&dyn HodeiEntity> = principal_refs
+        .iter()
+        .chain(&resource_refs)
+        .copied()
+        .filter(|entity| seen.insert(entity.hrn().to_string()))
+        .collect();
+
+    let mut command = EvaluateMatrixCommand::new(
+        &principal_refs,
+        &request.action,
+        &resource_refs,
+        &policies,
+        &entities,
+    )
+    .with_context(request.context.clone());
+    if let Some(version) = &request.schema_version {
+        command = command.with_schema_version(version.clone());
+    }
+
+    let matrix = state.evaluate_policies.evaluate_matrix(command).await?;
+
+    Ok(Json(convert_to_response(request, matrix)))
+}
+
+/// Parse the HRNs of one side of the grid
+fn parse_entities(side: &str, hrns: &[String]) -> Result<Vec<HrnEntity>, ApiError> {
+    hrns.iter()
+        .map(|hrn| {
+            Hrn::from_string(hrn)
+                .map(|hrn| HrnEntity { hrn })
+                .ok_or_else(|| ApiError::bad_request(format!("Invalid {} HRN: {}", side, hrn)))
+        })
+        .collect()
+}
+
+/// Convert the decision grid to the HTTP response
+fn convert_to_response(
+    request: AuthorizationMatrixRequest,
+    matrix: MatrixDecision,
+) -> AuthorizationMatrixResponse {
+    let rows = matrix
+        .rows
+        .into_iter()
+        .map(|row| row.into_iter().map(convert_cell).collect())
+        .collect();
+
+    let diagnostics = matrix
+        .diagnostics
+        .into_iter()
+        .map(|diagnostic| DiagnosticInfo {
+            level: match diagnostic.level {
+                DiagnosticLevel::Info => "info",
+                DiagnosticLevel::Warning => "warning",
+                DiagnosticLevel::Error => "error",
+            }
+            .to_string(),
+            message: diagnostic.message,
+            policy_id: diagnostic.policy_id,
+        })
+        .collect();
+
+    AuthorizationMatrixResponse {
+        action: request.action,
+        principals: request.principals,
+        resources: request.resources,
+        rows,
+        policy_set_version: matrix.policy_set_version,
+        used_schema_version: matrix.used_schema_version,
+        policy_ids_evaluated: matrix.policy_ids_evaluated,
+        diagnostics,
+    }
+}
+
+fn convert_cell(cell: MatrixCell) -> MatrixCellDto {
+    match cell {
+        MatrixCell::Decided {
+            decision,
+            determining_policies,
+        } => MatrixCellDto {
+            decision: Some(
+                match decision {
+                    Decision::Allow => "Allow",
+                    Decision::Deny => "Deny",
+                }
+                .to_string(),
+            ),
+            determining_policies,
+            error: None,
+        },
+        MatrixCell::Failed { error } => MatrixCellDto {
+            decision: None,
+            determining_policies: vec![],
+            error: Some(error),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> AuthorizationMatrixRequest {
+        serde_json::from_value(serde_json::json!({
+            "principals": ["hrn:hodei:iam::default:User/alice"],
+            "action": "ReadArtifact",
+            "resources": ["hrn:hodei:artifact::default:Artifact/a", "hrn:hodei:artifact::default:Artifact/b"],
+            "policies": ["permit(principal, action, resource);"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_matrix_request_defaults_to_an_empty_context() {
+        let request = request();
+        assert!(request.context.is_empty());
+        assert!(request.schema_version.is_none());
+    }
+
+    #[test]
+    fn test_invalid_hrn_is_a_bad_request() {
+        let error = parse_entities("principal", &["alice".to_string()]).unwrap_err();
+        let response = axum::response::IntoResponse::into_response(error);
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_failed_cells_keep_their_place_in_the_grid() {
+        let matrix = MatrixDecision {
+            rows: vec![vec![
+                MatrixCell::Decided {
+                    decision: Decision::Allow,
+                    determining_policies: vec!["policy_0".to_string()],
+                },
+                MatrixCell::Failed {
+                    error: "Translation error: bad type".to_string(),
+                },
+            ]],
+            used_schema_version: None,
+            policy_set_version: Some("v1".to_string()),
+            policy_ids_evaluated: vec!["policy_0".to_string()],
+            diagnostics: vec![],
+        };
+
+        let response = convert_to_response(request(), matrix);
+
+        assert_eq!(response.rows.len(), 1);
+        assert_eq!(response.rows[0][0].decision.as_deref(), Some("Allow"));
+        assert_eq!(response.rows[0][0].determining_policies, vec!["policy_0"]);
+        assert!(response.rows[0][1].decision.is_none());
+        assert_eq!(
+            response.rows[0][1].error.as_deref(),
+            Some("Translation error: bad type")
+        );
+        assert_eq!(response.resources.len(), 2);
+    }
+}
@@ -0,0 +1,498 @@
+//! HTTP error mapping shared by all handlers
+//!
+//! Every feature error a handler can surface implements [`HttpError`], which
+//! decides its status code and a stable, machine-readable error code. The
+//! handlers turn any of them into an [`ApiError`] with `?`, so the same kind
+//! of failure answers with the same status on every endpoint:
+//!
+//! | Failure                                  | Status |
+//! |------------------------------------------|--------|
+//! | Resource not found                       | 404    |
+//! | Invalid input rejected by a use case     | 422    |
+//! | Conflict with the current state          | 409    |
+//! | Caller not allowed to perform the action | 403    |
+//! | Anything unexpected (storage, services)  | 500    |
+//!
+//! Requests the handler can't even turn into a command (malformed HRNs and
+//! the like) stay `400 Bad Request`. A 500 logs the underlying error and
+//! answers with a generic message, so storage details never reach clients.
+
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use tracing::error;
+
+use hodei_iam::features::{
+    create_policy::error::CreatePolicyError, delete_policy::error::DeletePolicyError,
+    get_policies::error::GetPoliciesError, get_policy::error::GetPolicyError,
+    list_policies::error::ListPoliciesError, register_iam_schema::error::RegisterIamSchemaError,
+    update_policy::error::UpdatePolicyError,
+};
+use hodei_policies::features::{
+    build_schema::error::BuildSchemaError, evaluate_policies::error::EvaluatePoliciesError,
+    playground_evaluate::error::PlaygroundEvaluateError,
+    validate_policy::error::ValidatePolicyError,
+};
+
+/// Message returned in place of the detail of an internal error
+const INTERNAL_ERROR_MESSAGE: &str = "An internal error occurred";
+
+/// An error that knows how it is answered over HTTP
+pub trait HttpError: std::error::Error {
+    /// Status code of the response
+    fn status_code(&self) -> StatusCode;
+
+    /// Stable error code clients can match on, in `snake_case`
+    fn error_code(&self) -> &str;
+}
+
+/// Error response of every handler
+///
+/// Serialized as `{"error": message, "code": error_code, "status": u16}`.
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    code: String,
+    message: String,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    /// A request that couldn't be turned into a command
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "bad_request", message)
+    }
+
+    /// A conditional request whose precondition doesn't hold
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        Self::new(
+            StatusCode::PRECONDITION_FAILED,
+            "precondition_failed",
+            message,
+        )
+    }
+}
+
+impl<E: HttpError> From<E> for ApiError {
+    fn from(error: E) -> Self {
+        let status = error.status_code();
+        if status.is_server_error() {
+            error!(
+                error_code = error.error_code(),
+                error = %error,
+                "Internal error while handling request"
+            );
+            return Self::new(status, error.error_code(), INTERNAL_ERROR_MESSAGE);
+        }
+        Self::new(status, error.error_code(), error.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = Json(serde_json::json!({
+            "error": self.message,
+            "code": self.code,
+            "status": self.status.as_u16(),
+        }));
+
+        (self.status, body).into_response()
+    }
+}
+
+// ============================================================================
+// IAM FEATURE ERRORS
+// ============================================================================
+
+impl HttpError for CreatePolicyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidPolicyContent(_)
+            | Self::InvalidHrn(_)
+            | Self::InvalidPolicyId(_)
+            | Self::EmptyPolicyContent => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::PolicyAlreadyExists(_) => StatusCode::CONFLICT,
+            Self::Unauthorized => StatusCode::FORBIDDEN,
+            Self::StorageError(_) | Self::ValidationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::StorageError(_) => "storage_error",
+            Self::InvalidPolicyContent(_) => "invalid_policy_content",
+            Self::ValidationFailed(_) => "validation_service_error",
+            Self::PolicyAlreadyExists(_) => "policy_already_exists",
+            Self::InvalidHrn(_) => "invalid_hrn",
+            Self::InvalidPolicyId(_) => "invalid_policy_id",
+            Self::EmptyPolicyContent => "empty_policy_content",
+            Self::Unauthorized => "forbidden",
+        }
+    }
+}
+
+impl HttpError for GetPolicyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            // Answered as if the policy didn't exist so other tenants' HRNs can't be probed
+            Self::PolicyNotFound(_) | Self::CrossTenantAccess(_) => StatusCode::NOT_FOUND,
+            Self::InvalidHrn(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::RepositoryError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::PolicyNotFound(_) | Self::CrossTenantAccess(_) => "policy_not_found",
+            Self::InvalidHrn(_) => "invalid_hrn",
+            Self::RepositoryError(_) => "repository_error",
+        }
+    }
+}
+
+impl HttpError for GetPoliciesError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::CrossTenantAccess(_) => StatusCode::NOT_FOUND,
+            Self::InvalidHrn(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::RepositoryError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::CrossTenantAccess(_) => "policy_not_found",
+            Self::InvalidHrn(_) => "invalid_hrn",
+            Self::RepositoryError(_) => "repository_error",
+        }
+    }
+}
+
+impl HttpError for ListPoliciesError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidQuery(_) | Self::InvalidPagination(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Database(_) | Self::RepositoryError(_) | Self::Internal(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::Database(_) => "database_error",
+            Self::InvalidQuery(_) => "invalid_query",
+            Self::InvalidPagination(_) => "invalid_pagination",
+            Self::RepositoryError(_) => "repository_error",
+            Self::Internal(_) => "internal_error",
+        }
+    }
+}
+
+impl HttpError for UpdatePolicyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::PolicyNotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidPolicyContent(_)
+            | Self::InvalidHrn(_)
+            | Self::InvalidPolicyId(_)
+            | Self::NoUpdatesProvided
+            | Self::EmptyPolicyContent => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::VersionConflict | Self::PolicyInUseConflict(_) => StatusCode::CONFLICT,
+            Self::Unauthorized | Self::SystemPolicyProtected(_) => StatusCode::FORBIDDEN,
+            Self::StorageError(_) | Self::ValidationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::StorageError(_) => "storage_error",
+            Self::InvalidPolicyContent(_) => "invalid_policy_content",
+            Self::ValidationFailed(_) => "validation_service_error",
+            Self::PolicyNotFound(_) => "policy_not_found",
+            Self::InvalidHrn(_) => "invalid_hrn",
+            Self::InvalidPolicyId(_) => "invalid_policy_id",
+            Self::NoUpdatesProvided => "no_updates_provided",
+            Self::EmptyPolicyContent => "empty_policy_content",
+            Self::Unauthorized => "forbidden",
+            Self::SystemPolicyProtected(_) => "system_policy_protected",
+            Self::VersionConflict => "version_conflict",
+            Self::PolicyInUseConflict(_) => "policy_in_use",
+        }
+    }
+}
+
+impl HttpError for DeletePolicyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::PolicyNotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidPolicyId(_) | Self::InvalidHrn(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::PolicyInUse(_) => StatusCode::CONFLICT,
+            Self::Unauthorized | Self::SystemPolicyProtected(_) => StatusCode::FORBIDDEN,
+            Self::StorageError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::StorageError(_) => "storage_error",
+            Self::PolicyNotFound(_) => "policy_not_found",
+            Self::InvalidPolicyId(_) => "invalid_policy_id",
+            Self::PolicyInUse(_) => "policy_in_use",
+            Self::InvalidHrn(_) => "invalid_hrn",
+            Self::Unauthorized => "forbidden",
+            Self::SystemPolicyProtected(_) => "system_policy_protected",
+        }
+    }
+}
+
+impl HttpError for RegisterIamSchemaError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::SchemaValidationError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            // The IAM types are built in, so failing to register them is a server fault
+            Self::EntityTypeRegistrationError(_)
+            | Self::ActionTypeRegistrationError(_)
+            | Self::SchemaBuildError(_)
+            | Self::NoTypesRegistered
+            | Self::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::EntityTypeRegistrationError(_) => "entity_type_registration_error",
+            Self::ActionTypeRegistrationError(_) => "action_type_registration_error",
+            Self::SchemaBuildError(_) => "schema_build_error",
+            Self::SchemaValidationError(_) => "schema_validation_error",
+            Self::NoTypesRegistered => "no_types_registered",
+            Self::InternalError(_) => "internal_error",
+        }
+    }
+}
+
+// ============================================================================
+// POLICIES FEATURE ERRORS
+// ============================================================================
+
+impl HttpError for ValidatePolicyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ValidationError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::ValidationError(_) => "validation_error",
+            Self::InternalError(_) => "internal_error",
+        }
+    }
+}
+
+impl HttpError for BuildSchemaError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::SchemaBuildError(_)
+            | Self::EmptySchema
+            | Self::SchemaValidationError(_)
+            | Self::InvalidFragment(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::EntityTypeConflict(_) => StatusCode::CONFLICT,
+            Self::SchemaStorageError(_) | Self::BuilderLockError(_) | Self::InternalError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::SchemaBuildError(_) => "schema_build_error",
+            Self::SchemaStorageError(_) => "schema_storage_error",
+            Self::EmptySchema => "empty_schema",
+            Self::SchemaValidationError(_) => "schema_validation_error",
+            Self::BuilderLockError(_) => "builder_lock_error",
+            Self::InternalError(_) => "internal_error",
+            Self::InvalidFragment(_) => "invalid_fragment",
+            Self::EntityTypeConflict(_) => "entity_type_conflict",
+        }
+    }
+}
+
+impl HttpError for PlaygroundEvaluateError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::SchemaNotFound(_) => StatusCode::NOT_FOUND,
+            Self::InvalidCommand(_)
+            | Self::SchemaError(_)
+            | Self::PolicyError(_)
+            | Self::SchemaValidationError(_)
+            | Self::PolicyValidationError(_)
+            | Self::InvalidRequest(_)
+            | Self::InvalidContextAttribute(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::EvaluationError(_) | Self::SchemaStorageError(_) | Self::InternalError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::InvalidCommand(_) => "invalid_command",
+            Self::SchemaError(_) => "schema_error",
+            Self::PolicyError(_) => "policy_error",
+            Self::EvaluationError(_) => "evaluation_error",
+            Self::SchemaValidationError(_) => "schema_validation_error",
+            Self::PolicyValidationError(_) => "policy_validation_error",
+            Self::InvalidRequest(_) => "invalid_request",
+            Self::InvalidContextAttribute(_) => "invalid_context_attribute",
+            Self::SchemaStorageError(_) => "schema_storage_error",
+            Self::SchemaNotFound(_) => "schema_not_found",
+            Self::InternalError(_) => "internal_error",
+        }
+    }
+}
+
+impl HttpError for EvaluatePoliciesError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::LimitExceeded(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::SchemaNotFound(_) => StatusCode::NOT_FOUND,
+            Self::PolicyLoadError(_)
+            | Self::EntityRegistrationError(_)
+            | Self::TranslationError(_)
+            | Self::StrictModeSchemaRequired => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::EvaluationError(_)
+            | Self::CacheClearError(_)
+            | Self::InternalError(_)
+            | Self::SchemaError(_)
+            | Self::SchemaLoadError(_)
+            | Self::SnapshotError(_)
+            | Self::AttributeResolutionError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_code(&self) -> &str {
+        match self {
+            Self::PolicyLoadError(_) => "policy_load_error",
+            Self::EntityRegistrationError(_) => "entity_registration_error",
+            Self::EvaluationError(_) => "evaluation_error",
+            Self::CacheClearError(_) => "cache_clear_error",
+            Self::InternalError(_) => "internal_error",
+            Self::TranslationError(_) => "translation_error",
+            Self::SchemaError(_) => "schema_error",
+            Self::SchemaNotFound(_) => "schema_not_found",
+            Self::SchemaLoadError(_) => "schema_load_error",
+            Self::StrictModeSchemaRequired => "schema_required",
+            Self::LimitExceeded(_) => "limit_exceeded",
+            Self::SnapshotError(_) => "snapshot_error",
+            Self::AttributeResolutionError(_) => "attribute_resolution_error",
+        }
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn body(error: ApiError) -> serde_json::Value {
+        let response = error.into_response();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_errors_map_to_status_by_kind() {
+        let cases: Vec<(ApiError, StatusCode)> = vec![
+            (
+                UpdatePolicyError::PolicyNotFound("p".to_string()).into(),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                CreatePolicyError::InvalidPolicyContent("p".to_string()).into(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                DeletePolicyError::PolicyInUse("p".to_string()).into(),
+                StatusCode::CONFLICT,
+            ),
+            (
+                CreatePolicyError::Unauthorized.into(),
+                StatusCode::FORBIDDEN,
+            ),
+            (
+                ListPoliciesError::Database("p".to_string()).into(),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                EvaluatePoliciesError::LimitExceeded("p".to_string()).into(),
+                StatusCode::PAYLOAD_TOO_LARGE,
+            ),
+        ];
+
+        for (error, status) in cases {
+            assert_eq!(error.status, status, "{}", error.code);
+        }
+    }
+
+    #[test]
+    fn test_cross_tenant_access_is_answered_as_not_found() {
+        let error: ApiError = GetPolicyError::CrossTenantAccess(kernel::CrossTenantAccess {
+            tenant_id: "acme".to_string(),
+            hrn: "hrn:hodei:iam::other:policy/p".to_string(),
+        })
+        .into();
+
+        assert_eq!(error.status, StatusCode::NOT_FOUND);
+        assert_eq!(error.code, "policy_not_found");
+    }
+
+    #[tokio::test]
+    async fn test_client_error_body_carries_detail_and_code() {
+        let error: ApiError =
+            PlaygroundEvaluateError::InvalidCommand("no schema".to_string()).into();
+
+        let body = body(error).await;
+
+        assert_eq!(body["status"], 422);
+        assert_eq!(body["code"], "invalid_command");
+        assert_eq!(body["error"], "Invalid command: no schema");
+    }
+
+    #[tokio::test]
+    async fn test_internal_error_body_hides_detail() {
+        let error: ApiError =
+            BuildSchemaError::SchemaStorageError("connection refused at 10.0.0.3".to_string())
+                .into();
+
+        let body = body(error).await;
+
+        assert_eq!(body["status"], 500);
+        assert_eq!(body["code"], "schema_storage_error");
+        assert_eq!(body["error"], INTERNAL_ERROR_MESSAGE);
+    }
+
+    #[test]
+    fn test_precondition_failed_response() {
+        let error = ApiError::precondition_failed("stale");
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::PRECONDITION_FAILED
+        );
+    }
+}
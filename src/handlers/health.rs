@@ -4,7 +4,9 @@
 //! Health checks are used by load balancers, Kubernetes, and monitoring systems
 //! to determine if the service is healthy and ready to accept traffic.
 
-use axum::{Json, http::StatusCode, response::IntoResponse};
+use crate::app_state::AppState;
+use crate::readiness::EngineReadiness;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -66,6 +68,44 @@ pub async fn health_check() -> impl IntoResponse {
     (StatusCode::OK, Json(response))
 }
 
+/// Readiness check handler
+///
+/// Reports ready only when the authorization engine works: the current
+/// schema loads, every stored policy compiles and a canary evaluation
+/// succeeds. Otherwise it answers 503 with the step that failed and its
+/// error. Results are cached for a few seconds, so probes are cheap.
+///
+/// # Example Response
+///
+/// ```json
+/// {
+///   "ready": true,
+///   "policy_count": 12,
+///   "failed_step": null,
+///   "error": null,
+///   "checked_at": "2024-01-15T10:30:00Z"
+/// }
+/// ```
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Authorization engine is ready", body = EngineReadiness),
+        (status = 503, description = "Authorization engine is not ready", body = EngineReadiness)
+    )
+)]
+pub async fn readiness_check(State(state): State<AppState>) -> impl IntoResponse {
+    let readiness = state.engine_readiness.check().await;
+    let status = if readiness.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(readiness))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
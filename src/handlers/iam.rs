@@ -3,15 +3,19 @@
 //! This module provides HTTP handlers for IAM policy management operations.
 //! All handlers are fully implemented with proper use case calls and error mapping.
 
+use super::error::ApiError;
 use crate::app_state::AppState;
+use crate::tenant::RequestTenant;
 use axum::{
     Json,
     extract::{Query, State},
-    http::StatusCode,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
     response::{IntoResponse, Response},
 };
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
 
 // ============================================================================
 // HTTP DTOs (Request/Response types for the HTTP API)
@@ -32,6 +36,8 @@ pub struct CreatePolicyResponse {
     pub hrn: String,
     pub content: String,
     pub description: Option<String>,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -49,10 +55,28 @@ pub struct GetPolicyResponse {
     pub name: String,
     pub content: String,
     pub description: Option<String>,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Request to get several policies by HRN
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct GetPoliciesRequest {
+    pub policy_hrns: Vec<String>,
+}
+
+/// Response from getting several policies
+///
+/// `policies` and `not_found` keep the order in which each HRN first appears
+/// in the request; duplicated HRNs are returned once.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct GetPoliciesResponse {
+    pub policies: Vec<GetPolicyResponse>,
+    pub not_found: Vec<String>,
+}
+
 /// Query parameters for listing policies
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct ListPoliciesQueryParams {
@@ -106,6 +130,8 @@ pub struct UpdatePolicyResponse {
     pub hrn: String,
     pub content: String,
     pub description: Option<String>,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -135,103 +161,163 @@ pub struct DeletePolicyResponse {
     request_body = CreatePolicyRequest,
     responses(
         (status = 200, description = "Policy created successfully", body = CreatePolicyResponse),
-        (status = 400, description = "Invalid policy content"),
+        (status = 403, description = "Not allowed to create policies"),
+        (status = 409, description = "Policy already exists"),
+        (status = 422, description = "Invalid policy content or ID"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn create_policy(
     State(state): State<AppState>,
     Json(request): Json<CreatePolicyRequest>,
-) -> Result<Json<CreatePolicyResponse>, IamApiError> {
+) -> Result<Json<CreatePolicyResponse>, ApiError> {
     let command = hodei_iam::features::create_policy::dto::CreatePolicyCommand {
         policy_id: request.policy_id,
         policy_content: request.policy_content,
         description: request.description,
     };
 
-    let policy_view = state
-        .create_policy
-        .execute(command)
-        .await
-        .map_err(|e| match e {
-            hodei_iam::features::create_policy::error::CreatePolicyError::EmptyPolicyContent => {
-                IamApiError::BadRequest("Policy content cannot be empty".to_string())
-            }
-            hodei_iam::features::create_policy::error::CreatePolicyError::InvalidPolicyId(msg) => {
-                IamApiError::BadRequest(format!("Invalid policy ID: {}", msg))
-            }
-            hodei_iam::features::create_policy::error::CreatePolicyError::InvalidPolicyContent(
-                msg,
-            ) => IamApiError::BadRequest(format!("Invalid policy content: {}", msg)),
-            hodei_iam::features::create_policy::error::CreatePolicyError::PolicyAlreadyExists(
-                id,
-            ) => IamApiError::Conflict(format!("Policy already exists: {}", id)),
-            hodei_iam::features::create_policy::error::CreatePolicyError::ValidationFailed(msg) => {
-                IamApiError::InternalServerError(format!("Validation service error: {}", msg))
-            }
-            hodei_iam::features::create_policy::error::CreatePolicyError::StorageError(msg) => {
-                IamApiError::InternalServerError(format!("Storage error: {}", msg))
-            }
-            hodei_iam::features::create_policy::error::CreatePolicyError::InvalidHrn(msg) => {
-                IamApiError::InternalServerError(format!("Invalid HRN: {}", msg))
-            }
-            hodei_iam::features::create_policy::error::CreatePolicyError::Unauthorized => {
-                IamApiError::Unauthorized("Insufficient permissions".to_string())
-            }
-        })?;
+    let policy_view = state.create_policy.execute(command).await?;
 
     Ok(Json(CreatePolicyResponse {
         hrn: policy_view.id.to_string(),
         content: policy_view.content,
         description: policy_view.description,
+        annotations: policy_view.annotations,
         created_at: policy_view.created_at,
         updated_at: policy_view.updated_at,
     }))
 }
 
 /// Handler to get a policy by HRN
+///
+/// Responses carry the policy's `ETag` (see [`policy_etag`]). A request whose
+/// `If-None-Match` lists that ETag gets `304 Not Modified` without a body;
+/// the lookup is safe, so it is answered like a conditional GET. A policy of
+/// another tenant is answered as not found.
 #[utoipa::path(
     post,
     path = "/api/v1/iam/policies/get",
     tag = "iam",
     request_body = GetPolicyRequest,
+    params(
+        ("If-None-Match" = Option<String>, Header, description = "ETags the client already has"),
+        ("x-hodei-tenant" = Option<String>, Header, description = "Tenant the request is executed for")
+    ),
     responses(
-        (status = 200, description = "Policy retrieved successfully", body = GetPolicyResponse),
+        (status = 200, description = "Policy retrieved successfully", body = GetPolicyResponse,
+            headers(("ETag" = String, description = "Entity tag of the policy"))),
+        (status = 304, description = "Policy unchanged since the ETag in If-None-Match"),
         (status = 400, description = "Invalid HRN format"),
         (status = 404, description = "Policy not found"),
+        (status = 422, description = "The HRN does not name a policy"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_policy(
     State(state): State<AppState>,
+    RequestTenant(tenant): RequestTenant,
+    headers: HeaderMap,
     Json(request): Json<GetPolicyRequest>,
-) -> Result<Json<GetPolicyResponse>, IamApiError> {
+) -> Result<Response, ApiError> {
     let policy_hrn = kernel::Hrn::from_string(&request.policy_hrn)
-        .ok_or_else(|| IamApiError::BadRequest("Invalid HRN format".to_string()))?;
+        .ok_or_else(|| ApiError::bad_request("Invalid HRN format"))?;
 
     let policy_view = state
         .get_policy
-        .get_by_hrn(&policy_hrn)
-        .await
-        .map_err(|e| match e {
-            hodei_iam::features::get_policy::error::GetPolicyError::PolicyNotFound(msg) => {
-                IamApiError::NotFound(format!("Policy not found: {}", msg))
-            }
-            hodei_iam::features::get_policy::error::GetPolicyError::InvalidHrn(msg) => {
-                IamApiError::BadRequest(format!("Invalid HRN: {}", msg))
-            }
-            hodei_iam::features::get_policy::error::GetPolicyError::RepositoryError(msg) => {
-                IamApiError::InternalServerError(format!("Repository error: {}", msg))
-            }
-        })?;
-
-    Ok(Json(GetPolicyResponse {
+        .execute_for_tenant(
+            &tenant,
+            hodei_iam::features::get_policy::dto::GetPolicyQuery { policy_hrn },
+        )
+        .await?;
+
+    let etag = policy_etag(
+        &policy_view.hrn.to_string(),
+        &policy_view.name,
+        &policy_view.content,
+        policy_view.description.as_deref(),
+        &policy_view.annotations,
+    );
+
+    if headers
+        .get(header::IF_NONE_MATCH)
+        .is_some_and(|value| if_none_match_matches(value, &etag))
+    {
+        return Ok(with_etag(StatusCode::NOT_MODIFIED.into_response(), &etag));
+    }
+
+    let response = Json(GetPolicyResponse {
         hrn: policy_view.hrn.to_string(),
         name: policy_view.name,
         content: policy_view.content,
         description: policy_view.description,
+        annotations: policy_view.annotations,
         created_at: chrono::Utc::now(), // TODO: Add timestamps to domain PolicyView
         updated_at: chrono::Utc::now(),
+    });
+    Ok(with_etag(response.into_response(), &etag))
+}
+
+/// Handler to get several policies by HRN in a single lookup
+///
+/// If any of the policies belongs to another tenant, none is returned and the
+/// request is answered as not found.
+#[utoipa::path(
+    post,
+    path = "/api/v1/iam/policies/batch-get",
+    tag = "iam",
+    request_body = GetPoliciesRequest,
+    params(
+        ("x-hodei-tenant" = Option<String>, Header, description = "Tenant the request is executed for")
+    ),
+    responses(
+        (status = 200, description = "Policies retrieved; missing HRNs listed in not_found", body = GetPoliciesResponse),
+        (status = 400, description = "Invalid HRN format"),
+        (status = 404, description = "A policy belongs to another tenant"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_policies(
+    State(state): State<AppState>,
+    RequestTenant(tenant): RequestTenant,
+    Json(request): Json<GetPoliciesRequest>,
+) -> Result<Json<GetPoliciesResponse>, ApiError> {
+    let policy_hrns = request
+        .policy_hrns
+        .iter()
+        .map(|hrn| {
+            kernel::Hrn::from_string(hrn)
+                .ok_or_else(|| ApiError::bad_request(format!("Invalid HRN format: {}", hrn)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let response = state
+        .get_policies
+        .execute_for_tenant(
+            &tenant,
+            hodei_iam::features::get_policies::dto::GetPoliciesQuery { policy_hrns },
+        )
+        .await?;
+
+    Ok(Json(GetPoliciesResponse {
+        policies: response
+            .policies
+            .into_iter()
+            .map(|policy_view| GetPolicyResponse {
+                hrn: policy_view.hrn.to_string(),
+                name: policy_view.name,
+                content: policy_view.content,
+                description: policy_view.description,
+                annotations: policy_view.annotations,
+                created_at: chrono::Utc::now(), // TODO: Add timestamps to domain PolicyView
+                updated_at: chrono::Utc::now(),
+            })
+            .collect(),
+        not_found: response
+            .not_found
+            .iter()
+            .map(|hrn| hrn.to_string())
+            .collect(),
     }))
 }
 
@@ -246,39 +332,20 @@ pub async fn get_policy(
     ),
     responses(
         (status = 200, description = "Policies listed successfully", body = ListPoliciesResponse),
+        (status = 422, description = "Invalid pagination parameters"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn list_policies(
     State(state): State<AppState>,
     Query(query): Query<ListPoliciesQueryParams>,
-) -> Result<Json<ListPoliciesResponse>, IamApiError> {
+) -> Result<Json<ListPoliciesResponse>, ApiError> {
     let list_query = hodei_iam::features::list_policies::dto::ListPoliciesQuery {
         limit: query.limit,
         offset: query.offset,
     };
 
-    let list_result = state
-        .list_policies
-        .list(list_query)
-        .await
-        .map_err(|e| match e {
-            hodei_iam::features::list_policies::error::ListPoliciesError::Database(msg) => {
-                IamApiError::InternalServerError(format!("Database error: {}", msg))
-            }
-            hodei_iam::features::list_policies::error::ListPoliciesError::InvalidQuery(msg) => {
-                IamApiError::BadRequest(format!("Invalid query: {}", msg))
-            }
-            hodei_iam::features::list_policies::error::ListPoliciesError::InvalidPagination(
-                msg,
-            ) => IamApiError::BadRequest(format!("Invalid pagination: {}", msg)),
-            hodei_iam::features::list_policies::error::ListPoliciesError::RepositoryError(msg) => {
-                IamApiError::InternalServerError(format!("Repository error: {}", msg))
-            }
-            hodei_iam::features::list_policies::error::ListPoliciesError::Internal(msg) => {
-                IamApiError::InternalServerError(format!("Internal error: {}", msg))
-            }
-        })?;
+    let list_result = state.list_policies.list(list_query).await?;
 
     // Map domain PolicySummary to HTTP PolicySummary (adding timestamps)
     let policies: Vec<PolicySummary> = list_result
@@ -304,78 +371,116 @@ pub async fn list_policies(
 }
 
 /// Handler to update an existing policy
+///
+/// With `If-Match`, the update only goes ahead while the policy's current
+/// ETag is one of those listed (or the policy exists, for `*`); otherwise
+/// the answer is `412 Precondition Failed`. The check runs just before the
+/// update rather than in the same storage operation, so it catches stale
+/// clients but not two writers racing within that window. The response
+/// carries the ETag of the updated policy.
 #[utoipa::path(
     put,
     path = "/api/v1/iam/policies/update",
     tag = "iam",
     request_body = UpdatePolicyRequest,
+    params(
+        ("If-Match" = Option<String>, Header, description = "Update only if the policy still has one of these ETags"),
+        ("x-hodei-tenant" = Option<String>, Header, description = "Tenant the If-Match lookup is executed for")
+    ),
     responses(
-        (status = 200, description = "Policy updated successfully", body = UpdatePolicyResponse),
-        (status = 400, description = "Invalid policy content"),
+        (status = 200, description = "Policy updated successfully", body = UpdatePolicyResponse,
+            headers(("ETag" = String, description = "Entity tag of the updated policy"))),
+        (status = 400, description = "Invalid HRN format"),
+        (status = 403, description = "Not allowed to update the policy"),
         (status = 404, description = "Policy not found"),
+        (status = 409, description = "Policy in use or modified concurrently"),
+        (status = 412, description = "The policy's ETag no longer matches If-Match"),
+        (status = 422, description = "Invalid policy content"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn update_policy(
     State(state): State<AppState>,
+    RequestTenant(tenant): RequestTenant,
+    headers: HeaderMap,
     Json(request): Json<UpdatePolicyRequest>,
-) -> Result<Json<UpdatePolicyResponse>, IamApiError> {
+) -> Result<Response, ApiError> {
+    if let Some(if_match) = headers.get(header::IF_MATCH) {
+        check_if_match(&state, &tenant, &request.policy_hrn, if_match).await?;
+    }
+
     let command = hodei_iam::features::update_policy::dto::UpdatePolicyCommand {
-        policy_id: request.policy_hrn.to_string(),
+        policy_id: policy_id_of(&request.policy_hrn)?,
         policy_content: Some(request.policy_content),
         description: request.description,
     };
 
-    let policy_view = state
-        .update_policy
-        .update(command)
-        .await
-        .map_err(|e| match e {
-            hodei_iam::features::update_policy::error::UpdatePolicyError::PolicyNotFound(msg) => {
-                IamApiError::NotFound(format!("Policy not found: {}", msg))
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::InvalidPolicyContent(
-                msg,
-            ) => IamApiError::BadRequest(format!("Invalid policy content: {}", msg)),
-            hodei_iam::features::update_policy::error::UpdatePolicyError::InvalidPolicyId(msg) => {
-                IamApiError::BadRequest(format!("Invalid policy ID: {}", msg))
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::InvalidHrn(msg) => {
-                IamApiError::BadRequest(format!("Invalid HRN: {}", msg))
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::NoUpdatesProvided => {
-                IamApiError::BadRequest("No updates provided".to_string())
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::EmptyPolicyContent => {
-                IamApiError::BadRequest("Policy content cannot be empty".to_string())
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::VersionConflict => {
-                IamApiError::Conflict("Policy was modified by another process".to_string())
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::PolicyInUseConflict(
-                msg,
-            ) => IamApiError::Conflict(format!("Policy in use: {}", msg)),
-            hodei_iam::features::update_policy::error::UpdatePolicyError::SystemPolicyProtected(
-                msg,
-            ) => IamApiError::BadRequest(format!("System policy protected: {}", msg)),
-            hodei_iam::features::update_policy::error::UpdatePolicyError::ValidationFailed(msg) => {
-                IamApiError::InternalServerError(format!("Validation service error: {}", msg))
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::StorageError(msg) => {
-                IamApiError::InternalServerError(format!("Storage error: {}", msg))
-            }
-            hodei_iam::features::update_policy::error::UpdatePolicyError::Unauthorized => {
-                IamApiError::Unauthorized("Insufficient permissions".to_string())
-            }
-        })?;
-
-    Ok(Json(UpdatePolicyResponse {
+    let policy_view = state.update_policy.update(command).await?;
+
+    let etag = policy_etag(
+        &policy_view.hrn.to_string(),
+        &policy_view.name,
+        &policy_view.content,
+        policy_view.description.as_deref(),
+        &policy_view.annotations,
+    );
+
+    let response = Json(UpdatePolicyResponse {
         hrn: policy_view.hrn.to_string(),
         content: policy_view.content,
         description: policy_view.description,
+        annotations: policy_view.annotations,
         created_at: chrono::Utc::now(), // TODO: Add timestamps to domain PolicyView
         updated_at: chrono::Utc::now(),
-    }))
+    });
+    Ok(with_etag(response.into_response(), &etag))
+}
+
+/// The ID a policy is stored under, from its HRN
+fn policy_id_of(policy_hrn: &str) -> Result<String, ApiError> {
+    kernel::Hrn::from_string(policy_hrn)
+        .map(|hrn| hrn.resource_id().to_string())
+        .ok_or_else(|| ApiError::bad_request("Invalid HRN format"))
+}
+
+/// Fail with 412 unless the stored policy matches the `If-Match` header
+async fn check_if_match(
+    state: &AppState,
+    tenant: &kernel::TenantContext,
+    policy_hrn: &str,
+    if_match: &HeaderValue,
+) -> Result<(), ApiError> {
+    let policy_hrn = kernel::Hrn::from_string(policy_hrn)
+        .ok_or_else(|| ApiError::bad_request("Invalid HRN format"))?;
+
+    let current = match state
+        .get_policy
+        .execute_for_tenant(
+            tenant,
+            hodei_iam::features::get_policy::dto::GetPolicyQuery { policy_hrn },
+        )
+        .await
+    {
+        Ok(view) => Some(policy_etag(
+            &view.hrn.to_string(),
+            &view.name,
+            &view.content,
+            view.description.as_deref(),
+            &view.annotations,
+        )),
+        Err(e @ hodei_iam::features::get_policy::error::GetPolicyError::RepositoryError(_)) => {
+            return Err(e.into());
+        }
+        Err(_) => None,
+    };
+
+    if if_match_matches(if_match, current.as_deref()) {
+        Ok(())
+    } else {
+        Err(ApiError::precondition_failed(
+            "Policy does not match If-Match; fetch it again before updating",
+        ))
+    }
 }
 
 /// Handler to delete a policy by HRN
@@ -386,45 +491,21 @@ pub async fn update_policy(
     request_body = DeletePolicyRequest,
     responses(
         (status = 200, description = "Policy deleted successfully", body = DeletePolicyResponse),
+        (status = 403, description = "Not allowed to delete the policy"),
         (status = 404, description = "Policy not found"),
+        (status = 409, description = "Policy in use"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_policy(
     State(state): State<AppState>,
     Json(request): Json<DeletePolicyRequest>,
-) -> Result<Json<DeletePolicyResponse>, IamApiError> {
+) -> Result<Json<DeletePolicyResponse>, ApiError> {
     let command = hodei_iam::features::delete_policy::dto::DeletePolicyCommand {
-        policy_id: request.policy_hrn.to_string(),
+        policy_id: policy_id_of(&request.policy_hrn)?,
     };
 
-    state
-        .delete_policy
-        .delete(&command.policy_id)
-        .await
-        .map_err(|e| match e {
-            hodei_iam::features::delete_policy::error::DeletePolicyError::PolicyNotFound(msg) => {
-                IamApiError::NotFound(format!("Policy not found: {}", msg))
-            }
-            hodei_iam::features::delete_policy::error::DeletePolicyError::InvalidPolicyId(msg) => {
-                IamApiError::BadRequest(format!("Invalid policy ID: {}", msg))
-            }
-            hodei_iam::features::delete_policy::error::DeletePolicyError::InvalidHrn(msg) => {
-                IamApiError::BadRequest(format!("Invalid HRN: {}", msg))
-            }
-            hodei_iam::features::delete_policy::error::DeletePolicyError::PolicyInUse(msg) => {
-                IamApiError::Conflict(format!("Policy in use: {}", msg))
-            }
-            hodei_iam::features::delete_policy::error::DeletePolicyError::SystemPolicyProtected(
-                msg,
-            ) => IamApiError::BadRequest(format!("System policy protected: {}", msg)),
-            hodei_iam::features::delete_policy::error::DeletePolicyError::StorageError(msg) => {
-                IamApiError::InternalServerError(format!("Storage error: {}", msg))
-            }
-            hodei_iam::features::delete_policy::error::DeletePolicyError::Unauthorized => {
-                IamApiError::Unauthorized("Insufficient permissions".to_string())
-            }
-        })?;
+    state.delete_policy.delete(&command.policy_id).await?;
 
     Ok(Json(DeletePolicyResponse {
         deleted_hrn: request.policy_hrn,
@@ -433,36 +514,70 @@ pub async fn delete_policy(
 }
 
 // ============================================================================
-// ERROR HANDLING
+// CONDITIONAL REQUESTS
 // ============================================================================
 
-/// IAM API Error type for handler responses
-#[derive(Debug)]
-pub enum IamApiError {
-    BadRequest(String),
-    Unauthorized(String),
-    NotFound(String),
-    Conflict(String),
-    InternalServerError(String),
+/// Entity tag of a policy
+///
+/// A SHA-256 over the policy's HRN, name, content, description and
+/// annotations, so it is the same for identical policies and changes with
+/// any of them. The `created_at`/`updated_at` values in responses are not
+/// stored yet and are left out.
+///
+/// The tag is strong (no `W/` prefix): `If-Match` only accepts strong tags,
+/// and two policies with the same tag are the same policy in every field a
+/// client can act on. `If-None-Match` accepts weak tags as well.
+pub fn policy_etag(
+    hrn: &str,
+    name: &str,
+    content: &str,
+    description: Option<&str>,
+    annotations: &HashMap<String, String>,
+) -> String {
+    // Sorted annotations keep the hash independent of map iteration order
+    let annotations: BTreeMap<_, _> = annotations.iter().collect();
+    let state = serde_json::json!({
+        "hrn": hrn,
+        "name": name,
+        "content": content,
+        "description": description,
+        "annotations": annotations,
+    });
+    let digest = Sha256::digest(state.to_string().as_bytes());
+    format!("\"{}\"", hex::encode(digest))
 }
 
-impl IntoResponse for IamApiError {
-    fn into_response(self) -> Response {
-        let (status, message) = match self {
-            IamApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
-            IamApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
-            IamApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            IamApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
-            IamApiError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-        };
+/// Entity tags listed in an `If-Match` or `If-None-Match` header
+fn entity_tags(value: &HeaderValue) -> Vec<&str> {
+    value
+        .to_str()
+        .map(|value| value.split(',').map(str::trim).collect())
+        .unwrap_or_default()
+}
+
+/// Weak comparison (RFC 9110 §8.8.3.2), as `If-None-Match` requires
+fn if_none_match_matches(value: &HeaderValue, etag: &str) -> bool {
+    entity_tags(value)
+        .into_iter()
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
 
-        let body = Json(serde_json::json!({
-            "error": message,
-            "status": status.as_u16(),
-        }));
+/// Strong comparison, as `If-Match` requires; `current` is `None` when the
+/// policy doesn't exist, which no tag (not even `*`) matches
+fn if_match_matches(value: &HeaderValue, current: Option<&str>) -> bool {
+    let Some(current) = current else {
+        return false;
+    };
+    entity_tags(value)
+        .into_iter()
+        .any(|tag| tag == "*" || tag == current)
+}
 
-        (status, body).into_response()
+fn with_etag(mut response: Response, etag: &str) -> Response {
+    if let Ok(value) = HeaderValue::from_str(etag) {
+        response.headers_mut().insert(header::ETAG, value);
     }
+    response
 }
 
 // ============================================================================
@@ -493,9 +608,82 @@ mod tests {
         assert_eq!(query.offset, 0);
     }
 
+    fn etag(content: &str, description: Option<&str>, annotations: &[(&str, &str)]) -> String {
+        let annotations = annotations
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        policy_etag(
+            "hrn:hodei:iam::acme:policy/read-only",
+            "read-only",
+            content,
+            description,
+            &annotations,
+        )
+    }
+
+    #[test]
+    fn test_policy_etag_is_stable_for_identical_policies() {
+        let content = "permit(principal, action, resource);";
+        let annotations = [("ticket", "SEC-1"), ("owner", "security")];
+        let reordered = [("owner", "security"), ("ticket", "SEC-1")];
+
+        let tag = etag(content, Some("Read only"), &annotations);
+        assert_eq!(tag, etag(content, Some("Read only"), &reordered));
+        assert!(tag.starts_with('"') && tag.ends_with('"'));
+    }
+
+    #[test]
+    fn test_policy_etag_changes_with_content_and_metadata() {
+        let content = "permit(principal, action, resource);";
+        let tag = etag(content, Some("Read only"), &[]);
+
+        assert_ne!(
+            tag,
+            etag(
+                "forbid(principal, action, resource);",
+                Some("Read only"),
+                &[]
+            )
+        );
+        assert_ne!(tag, etag(content, Some("Read-only access"), &[]));
+        assert_ne!(tag, etag(content, None, &[]));
+        assert_ne!(
+            tag,
+            etag(content, Some("Read only"), &[("ticket", "SEC-1")])
+        );
+    }
+
+    #[test]
+    fn test_if_none_match_uses_weak_comparison() {
+        let tag = "\"abc\"";
+        let header = |value: &'static str| HeaderValue::from_static(value);
+
+        assert!(if_none_match_matches(&header("\"abc\""), tag));
+        assert!(if_none_match_matches(&header("W/\"abc\""), tag));
+        assert!(if_none_match_matches(&header("\"old\", \"abc\""), tag));
+        assert!(if_none_match_matches(&header("*"), tag));
+        assert!(!if_none_match_matches(&header("\"old\""), tag));
+    }
+
+    #[test]
+    fn test_if_match_uses_strong_comparison() {
+        let header = |value: &'static str| HeaderValue::from_static(value);
+
+        assert!(if_match_matches(&header("\"abc\""), Some("\"abc\"")));
+        assert!(if_match_matches(
+            &header("\"old\", \"abc\""),
+            Some("\"abc\"")
+        ));
+        assert!(if_match_matches(&header("*"), Some("\"abc\"")));
+        assert!(!if_match_matches(&header("W/\"abc\""), Some("\"abc\"")));
+        assert!(!if_match_matches(&header("\"old\""), Some("\"abc\"")));
+        assert!(!if_match_matches(&header("*"), None));
+    }
+
     #[test]
     fn test_iam_api_error_response() {
-        let error = IamApiError::BadRequest("Invalid input".to_string());
+        let error = ApiError::bad_request("Invalid input");
         let response = error.into_response();
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
@@ -7,6 +7,8 @@
 //! - Mapping results to HTTP responses
 //! - Error handling and logging
 
+pub mod authz;
+pub mod error;
 pub mod health;
 pub mod iam;
 pub mod playground;
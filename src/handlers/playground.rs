@@ -3,16 +3,13 @@
 //! This module provides HTTP handlers for the policy playground feature,
 //! allowing ad-hoc policy evaluation and testing without persistence.
 
+use super::error::ApiError;
 use crate::app_state::AppState;
-use axum::{
-    Json,
-    extract::State,
-    http::StatusCode,
-    response::{IntoResponse, Response},
-};
+use axum::{Json, extract::State};
 
 use hodei_policies::playground_evaluate::dto::{
-    AttributeValue, PlaygroundAuthorizationRequest, PlaygroundEvaluateResult,
+    AttributeValue, ConditionKind, ConditionResult, ConditionTrace, PlaygroundAuthorizationRequest,
+    PlaygroundEvaluateResult, PolicyBreakdown, PolicyOutcome, PolicyTrace,
 };
 use kernel::Hrn;
 use serde::{Deserialize, Serialize};
@@ -118,6 +115,56 @@ pub struct EvaluationDiagnosticsDto {
 
     /// Warnings (if any)
     pub warnings: Vec<String>,
+
+    /// How each policy fared, in the order the policies were given
+    pub policy_trace: Vec<PolicyTraceDto>,
+
+    /// How the outcomes combined: "forbid_overrides", "permitted" or "default_deny"
+    pub combination: Option<String>,
+}
+
+/// Trace of one policy
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PolicyTraceDto {
+    /// The policy ID
+    pub policy_id: String,
+
+    /// The effect of the policy (permit or forbid)
+    pub effect: String,
+
+    /// Outcome: "satisfied", "not_satisfied" or "error"
+    pub outcome: String,
+
+    /// Why the policy couldn't be evaluated (if outcome is "error")
+    pub error: Option<String>,
+
+    /// Whether the scope matched the request (None if it couldn't be broken down)
+    pub scope_matched: Option<bool>,
+
+    /// Each condition evaluated on its own (empty if the scope didn't match)
+    pub conditions: Vec<ConditionTraceDto>,
+
+    /// Why the policy couldn't be broken down into scope and conditions
+    pub breakdown_unavailable: Option<String>,
+
+    /// Whether the policy determined the decision
+    pub determining: bool,
+}
+
+/// Trace of one `when` or `unless` condition
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ConditionTraceDto {
+    /// Kind of condition: "when" or "unless"
+    pub kind: String,
+
+    /// The condition's expression in Cedar syntax
+    pub expression: String,
+
+    /// Result: "passed", "failed" or "error"
+    pub result: String,
+
+    /// Why the expression couldn't be evaluated (if result is "error")
+    pub error: Option<String>,
 }
 
 /// Handler for playground policy evaluation
@@ -164,7 +211,7 @@ pub struct EvaluationDiagnosticsDto {
 ///   "decision": "ALLOW",
 ///   "determining_policies": [
 ///     {
-///       "policy_id": "policy_0",
+///       "policy_id": "policy0",
 ///       "effect": "permit",
 ///       "policy_text": "permit(principal, action, resource);"
 ///     }
@@ -174,7 +221,20 @@ pub struct EvaluationDiagnosticsDto {
 ///     "matched_policies": 1,
 ///     "schema_validated": true,
 ///     "validation_errors": [],
-///     "warnings": []
+///     "warnings": [],
+///     "policy_trace": [
+///       {
+///         "policy_id": "policy0",
+///         "effect": "permit",
+///         "outcome": "satisfied",
+///         "error": null,
+///         "scope_matched": true,
+///         "conditions": [],
+///         "breakdown_unavailable": null,
+///         "determining": true
+///       }
+///     ],
+///     "combination": "permitted"
 ///   },
 ///   "errors": []
 /// }
@@ -187,6 +247,8 @@ pub struct EvaluationDiagnosticsDto {
     responses(
         (status = 200, description = "Policy evaluation completed successfully", body = PlaygroundEvaluateResponse),
         (status = 400, description = "Invalid request parameters"),
+        (status = 404, description = "Schema version not found"),
+        (status = 422, description = "Invalid schema, policies or request"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -196,16 +258,10 @@ pub async fn playground_evaluate(
 ) -> Result<Json<PlaygroundEvaluateResponse>, ApiError> {
     // Convert HTTP DTO to domain DTO
     let command = convert_to_command(request)
-        .map_err(|e| ApiError::BadRequest(format!("Invalid request: {}", e)))?;
+        .map_err(|e| ApiError::bad_request(format!("Invalid request: {}", e)))?;
 
     // Execute the playground evaluation use case
-    let result = state
-        .playground_evaluate
-        .evaluate(command)
-        .await
-        .map_err(|e| {
-            ApiError::InternalServerError(format!("Playground evaluation failed: {}", e))
-        })?;
+    let result = state.playground_evaluate.evaluate(command).await?;
 
     // Convert domain result to HTTP response
     let response = convert_to_response(result);
@@ -299,6 +355,16 @@ fn convert_to_response(result: PlaygroundEvaluateResult) -> PlaygroundEvaluateRe
         schema_validated: result.diagnostics.schema_validated,
         validation_errors: result.diagnostics.validation_errors,
         warnings: result.diagnostics.warnings,
+        policy_trace: result
+            .diagnostics
+            .policy_trace
+            .into_iter()
+            .map(convert_policy_trace)
+            .collect(),
+        combination: result
+            .diagnostics
+            .combination
+            .map(|combination| combination.to_string()),
     };
 
     PlaygroundEvaluateResponse {
@@ -309,26 +375,53 @@ fn convert_to_response(result: PlaygroundEvaluateResult) -> PlaygroundEvaluateRe
     }
 }
 
-/// API Error type for handler responses
-#[derive(Debug)]
-pub enum ApiError {
-    BadRequest(String),
-    InternalServerError(String),
-}
+fn convert_policy_trace(policy: PolicyTrace) -> PolicyTraceDto {
+    let (outcome, error) = match policy.outcome {
+        PolicyOutcome::Satisfied => ("satisfied", None),
+        PolicyOutcome::NotSatisfied => ("not_satisfied", None),
+        PolicyOutcome::Error { message } => ("error", Some(message)),
+    };
+    let (scope_matched, conditions, breakdown_unavailable) = match policy.breakdown {
+        PolicyBreakdown::ScopeNotMatched => (Some(false), vec![], None),
+        PolicyBreakdown::ScopeMatched { conditions } => (
+            Some(true),
+            conditions
+                .into_iter()
+                .map(convert_condition_trace)
+                .collect(),
+            None,
+        ),
+        PolicyBreakdown::Unavailable { reason } => (None, vec![], Some(reason)),
+    };
 
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, message) = match self {
-            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
-            ApiError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-        };
+    PolicyTraceDto {
+        policy_id: policy.policy_id,
+        effect: policy.effect.to_string(),
+        outcome: outcome.to_string(),
+        error,
+        scope_matched,
+        conditions,
+        breakdown_unavailable,
+        determining: policy.determining,
+    }
+}
 
-        let body = Json(serde_json::json!({
-            "error": message,
-            "status": status.as_u16(),
-        }));
+fn convert_condition_trace(condition: ConditionTrace) -> ConditionTraceDto {
+    let (result, error) = match condition.result {
+        ConditionResult::Passed => ("passed", None),
+        ConditionResult::Failed => ("failed", None),
+        ConditionResult::Error { message } => ("error", Some(message)),
+    };
 
-        (status, body).into_response()
+    ConditionTraceDto {
+        kind: match condition.kind {
+            ConditionKind::When => "when",
+            ConditionKind::Unless => "unless",
+        }
+        .to_string(),
+        expression: condition.expression,
+        result: result.to_string(),
+        error,
     }
 }
 
@@ -448,4 +541,56 @@ mod tests {
         assert!(response.diagnostics.schema_validated);
         assert!(response.errors.is_empty());
     }
+
+    #[test]
+    fn test_convert_policy_trace() {
+        let trace = vec![
+            PolicyTrace {
+                policy_id: "policy0".to_string(),
+                effect: hodei_policies::playground_evaluate::dto::PolicyEffect::Permit,
+                outcome: PolicyOutcome::Error {
+                    message: "missing attribute".to_string(),
+                },
+                breakdown: PolicyBreakdown::ScopeMatched {
+                    conditions: vec![ConditionTrace {
+                        kind: ConditionKind::Unless,
+                        expression: "context.blocked".to_string(),
+                        result: ConditionResult::Error {
+                            message: "missing attribute".to_string(),
+                        },
+                    }],
+                },
+                determining: false,
+            },
+            PolicyTrace {
+                policy_id: "policy1".to_string(),
+                effect: hodei_policies::playground_evaluate::dto::PolicyEffect::Forbid,
+                outcome: PolicyOutcome::NotSatisfied,
+                breakdown: PolicyBreakdown::Unavailable {
+                    reason: "unsupported".to_string(),
+                },
+                determining: false,
+            },
+        ];
+        let domain_result = PlaygroundEvaluateResult::new(
+            hodei_policies::playground_evaluate::dto::Decision::Deny,
+            vec![],
+            hodei_policies::playground_evaluate::dto::EvaluationDiagnostics::new(2, 0)
+                .with_policy_trace(trace),
+        );
+
+        let response = convert_to_response(domain_result);
+
+        let diagnostics = response.diagnostics;
+        assert_eq!(diagnostics.combination.as_deref(), Some("default_deny"));
+        assert_eq!(diagnostics.policy_trace[0].outcome, "error");
+        assert_eq!(diagnostics.policy_trace[0].scope_matched, Some(true));
+        assert_eq!(diagnostics.policy_trace[0].conditions[0].kind, "unless");
+        assert_eq!(diagnostics.policy_trace[0].conditions[0].result, "error");
+        assert_eq!(diagnostics.policy_trace[1].scope_matched, None);
+        assert_eq!(
+            diagnostics.policy_trace[1].breakdown_unavailable.as_deref(),
+            Some("unsupported")
+        );
+    }
 }
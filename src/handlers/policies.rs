@@ -4,13 +4,9 @@
 //! - Validating Cedar policies (syntax and schema checking)
 //! - Evaluating policies against authorization requests
 
+use super::error::ApiError;
 use crate::app_state::AppState;
-use axum::{
-    Json,
-    extract::State,
-    http::StatusCode,
-    response::{IntoResponse, Response},
-};
+use axum::{Json, extract::State};
 
 use serde::{Deserialize, Serialize};
 
@@ -125,7 +121,7 @@ pub struct DiagnosticInfo {
     request_body = ValidatePolicyRequest,
     responses(
         (status = 200, description = "Policy validated successfully", body = ValidatePolicyResponse),
-        (status = 400, description = "Invalid policy content"),
+        (status = 422, description = "Invalid policy content"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -137,10 +133,7 @@ pub async fn validate_policy(
         content: request.content,
     };
 
-    let result =
-        state.validate_policy.validate(command).await.map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to validate policy: {}", e))
-        })?;
+    let result = state.validate_policy.validate(command).await?;
 
     Ok(Json(ValidatePolicyResponse {
         is_valid: result.is_valid,
@@ -227,27 +220,6 @@ pub async fn evaluate_policies(
     }))
 }
 
-/// API Error type for handler responses
-#[derive(Debug)]
-pub enum ApiError {
-    InternalServerError(String),
-}
-
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, message) = match self {
-            ApiError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-        };
-
-        let body = Json(serde_json::json!({
-            "error": message,
-            "status": status.as_u16(),
-        }));
-
-        (status, body).into_response()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
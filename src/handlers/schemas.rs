@@ -5,13 +5,9 @@
 //! - Loading schemas from storage
 //! - Registering IAM schemas
 
+use super::error::ApiError;
 use crate::app_state::AppState;
-use axum::{
-    Json,
-    extract::State,
-    http::StatusCode,
-    response::{IntoResponse, Response},
-};
+use axum::{Json, extract::State};
 
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -90,6 +86,8 @@ pub struct RegisterIamSchemaResponse {
     request_body = BuildSchemaRequest,
     responses(
         (status = 200, description = "Schema built successfully", body = BuildSchemaResponse),
+        (status = 409, description = "Conflicting entity type definitions"),
+        (status = 422, description = "Invalid or empty schema"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -102,11 +100,7 @@ pub async fn build_schema(
         validate: request.validate,
     };
 
-    let result = state
-        .build_schema
-        .execute(command)
-        .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to build schema: {}", e)))?;
+    let result = state.build_schema.execute(command).await?;
 
     Ok(Json(BuildSchemaResponse {
         entity_count: result.entity_count,
@@ -184,13 +178,7 @@ pub async fn register_iam_schema(
         command
     };
 
-    let result = state
-        .register_iam_schema
-        .register(command)
-        .await
-        .map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to register IAM schema: {}", e))
-        })?;
+    let result = state.register_iam_schema.register(command).await?;
 
     Ok(Json(RegisterIamSchemaResponse {
         entity_types_registered: result.entity_types_registered,
@@ -201,27 +189,6 @@ pub async fn register_iam_schema(
     }))
 }
 
-/// API Error type for handler responses
-#[derive(Debug)]
-pub enum ApiError {
-    InternalServerError(String),
-}
-
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, message) = match self {
-            ApiError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-        };
-
-        let body = Json(serde_json::json!({
-            "error": message,
-            "status": status.as_u16(),
-        }));
-
-        (status, body).into_response()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
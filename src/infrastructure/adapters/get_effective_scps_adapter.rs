@@ -3,8 +3,9 @@
 //! Este adaptador es parte de la composition root y NO debe estar en la API pública
 //! del bounded context hodei-organizations.
 
+use crate::config::OrganizationsConfig;
 use async_trait::async_trait;
-use hodei_organizations::{GetEffectiveScpsQuery, GetEffectiveScpsUseCase, EffectiveScpsResponse};
+use hodei_organizations::{GetEffectiveScpsQuery, GetEffectiveScpsUseCase, ScpCombinationStrategy};
 use kernel::{GetEffectiveScpsPort, GetEffectiveScpsQuery as KernelQuery};
 
 /// Adaptador que implementa GetEffectiveScpsPort del kernel wrapeando el caso de uso
@@ -35,6 +36,19 @@ where
     pub fn new(use_case: GetEffectiveScpsUseCase<ScpRepo, OrgRepo>) -> Self {
         Self { inner: use_case }
     }
+
+    /// Crea el adaptador con la estrategia de combinación configurada en
+    /// `organizations.scp_combination_strategy`
+    pub fn from_config(
+        scp_repository: ScpRepo,
+        org_repository: OrgRepo,
+        config: &OrganizationsConfig,
+    ) -> Result<Self, String> {
+        let strategy: ScpCombinationStrategy = config.scp_combination_strategy.parse()?;
+        Ok(Self::new(
+            GetEffectiveScpsUseCase::new(scp_repository, org_repository).with_strategy(strategy),
+        ))
+    }
 }
 
 #[async_trait]
@@ -62,8 +76,9 @@ where
             .await
             .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
 
-        // Retornar el PolicySet
-        Ok(response.policies)
+        // Retornar un PolicySet que aplique la estrategia bajo la regla por
+        // defecto de Cedar; `policies` es la unión de los niveles
+        Ok(response.effective_policy_set())
     }
 }
 
@@ -12,22 +12,35 @@ mod app_state;
 mod bootstrap;
 mod composition_root;
 mod config;
+mod container;
+mod correlation;
 mod handlers;
 mod openapi;
+mod readiness;
+mod request_validation;
+mod route_guard;
+mod schema_storage;
+mod tenant;
 
 use crate::bootstrap::{BootstrapConfig, bootstrap};
 use crate::config::AppConfig;
-use crate::handlers::health::health_check;
+use crate::correlation::{make_request_span, propagate_correlation_id};
+use crate::handlers::health::{health_check, readiness_check};
 use crate::openapi::create_api_doc;
+use crate::request_validation::{RequestValidator, validate_request_body};
+use crate::route_guard::{DisabledRoutes, reject_disabled_routes};
 use axum::{
     Router,
+    http::Method,
+    middleware,
     routing::{delete, get, post, put},
 };
+use std::sync::Arc;
 use std::time::Duration;
 use tower_http::{
     cors::CorsLayer,
     timeout::TimeoutLayer,
-    trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer},
+    trace::{DefaultOnResponse, TraceLayer},
 };
 use tracing::{Level, info, warn};
 use tracing_subscriber::{EnvFilter, fmt, prelude::*};
@@ -36,13 +49,14 @@ use utoipa_swagger_ui::SwaggerUi;
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 1. Load configuration with config-rs
-    let config = match AppConfig::new() {
+    let config = match AppConfig::load() {
         Ok(config) => config,
         Err(e) => {
             eprintln!("❌ Configuration error: {}", e);
             eprintln!();
             eprintln!("💡 Configuration tips:");
             eprintln!("   - Set environment variables with HODEI_ prefix");
+            eprintln!("   - Pass --config <file> (or set HODEI_CONFIG_FILE) to load a JSON/TOML file");
             eprintln!("   - Create config/default.toml for default values");
             eprintln!("   - Use RUN_MODE=development for development settings");
             eprintln!();
@@ -64,6 +78,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("   Database: {}", config.database.db_type);
     info!("   RocksDB path: {}", config.rocksdb.path);
     info!("   Schema storage: {}", config.schema.storage_type);
+    info!(
+        "   Policy validation level: {:?}",
+        config.schema.validation_level
+    );
     info!(
         "   IAM schema registration: {}",
         config.schema.register_iam_on_startup
@@ -82,7 +100,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     })?;
 
     // 4. Build Axum router
-    let app = build_router(app_state, &config);
+    let disabled_routes = DisabledRoutes::from_config(&config.server);
+    spawn_config_reload(disabled_routes.clone());
+    // The playground's bodies carry schemas and policies defined per request
+    let request_validator = RequestValidator::from_openapi(&create_api_doc())?
+        .skip(Method::POST, "/api/v1/playground/evaluate");
+    let event_bus = app_state.event_bus.clone();
+    let app = build_router(app_state, &config, disabled_routes, request_validator);
 
     // 5. Start server
     let listener = tokio::net::TcpListener::bind(config.server_address()).await?;
@@ -97,6 +121,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_graceful_shutdown(shutdown_signal())
         .await?;
 
+    // 6. Let event handlers finish what the last requests published
+    let drain = event_bus
+        .drain(Duration::from_secs(
+            config.server.shutdown_drain_timeout_secs,
+        ))
+        .await;
+    if drain.lost > 0 {
+        warn!(
+            "⚠️  {} event deliveries lost after waiting {:?}",
+            drain.lost, drain.waited
+        );
+    } else {
+        info!(
+            "📭 Event bus drained ({} deliveries completed)",
+            drain.completed
+        );
+    }
+
     info!("👋 Hodei Artifacts API shut down gracefully");
     Ok(())
 }
@@ -136,22 +178,39 @@ fn initialize_logging(config: &AppConfig) -> Result<(), Box<dyn std::error::Erro
 }
 
 /// Build the Axum router with all routes and middleware
-fn build_router(app_state: crate::app_state::AppState, config: &AppConfig) -> Router {
+fn build_router(
+    app_state: crate::app_state::AppState,
+    config: &AppConfig,
+    disabled_routes: DisabledRoutes,
+    request_validator: RequestValidator,
+) -> Router {
     Router::new()
         // Health check endpoint
         .route("/health", get(health_check))
-        .route("/health/ready", get(health_check))
+        .route(
+            "/health/ready",
+            get(readiness_check).with_state(app_state.clone()),
+        )
         .route("/health/live", get(health_check))
         // API v1 routes
         .nest("/api/v1", api_v1_routes(app_state))
         // Swagger UI - serve at /swagger-ui
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", create_api_doc()))
         // Middleware layers (applied in reverse order)
+        .layer(middleware::from_fn_with_state(
+            Arc::new(request_validator),
+            validate_request_body,
+        ))
+        .layer(middleware::from_fn_with_state(
+            disabled_routes,
+            reject_disabled_routes,
+        ))
         .layer(
             TraceLayer::new_for_http()
-                .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
+                .make_span_with(make_request_span)
                 .on_response(DefaultOnResponse::new().level(Level::INFO)),
         )
+        .layer(middleware::from_fn(propagate_correlation_id))
         .layer(TimeoutLayer::new(Duration::from_secs(
             config.server.request_timeout_secs,
         )))
@@ -177,10 +236,16 @@ fn api_v1_routes(app_state: crate::app_state::AppState) -> Router {
             "/policies/evaluate",
             post(handlers::policies::evaluate_policies),
         )
+        // Bulk authorization
+        .route(
+            "/authz/matrix",
+            post(handlers::authz::authorization_matrix),
+        )
         // IAM Policy Management
         .route("/iam/policies", post(handlers::iam::create_policy))
         .route("/iam/policies", get(handlers::iam::list_policies))
         .route("/iam/policies/get", post(handlers::iam::get_policy))
+        .route("/iam/policies/batch-get", post(handlers::iam::get_policies))
         .route("/iam/policies/update", put(handlers::iam::update_policy))
         .route("/iam/policies/delete", delete(handlers::iam::delete_policy))
         // Playground routes
@@ -195,6 +260,37 @@ fn api_v1_routes(app_state: crate::app_state::AppState) -> Router {
         .with_state(app_state)
 }
 
+/// Reload the configuration on SIGHUP and apply the runtime-adjustable parts
+///
+/// Only `server.disabled_routes` (and its `Retry-After`) is applied without a
+/// restart. A configuration that fails to load or validate is logged and the
+/// previous settings stay in effect.
+fn spawn_config_reload(disabled_routes: DisabledRoutes) {
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                warn!("Failed to install SIGHUP handler, config reload disabled: {}", e);
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            info!("Received SIGHUP signal, reloading configuration");
+            match AppConfig::load() {
+                Ok(config) => disabled_routes.update(&config.server),
+                Err(e) => warn!("Configuration reload failed, keeping previous settings: {}", e),
+            }
+        }
+    });
+
+    #[cfg(not(unix))]
+    let _ = disabled_routes;
+}
+
 /// Graceful shutdown signal handler
 ///
 /// This function listens for shutdown signals (SIGTERM, SIGINT/Ctrl+C)
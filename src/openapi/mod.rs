@@ -33,12 +33,14 @@ use utoipa::OpenApi;
         (name = "health", description = "Health check endpoints"),
         (name = "schemas", description = "Cedar schema management"),
         (name = "policies", description = "Policy validation and evaluation"),
+        (name = "authz", description = "Bulk authorization checks"),
         (name = "iam", description = "IAM policy management (CRUD)"),
         (name = "playground", description = "Policy playground for ad-hoc testing")
     ),
     paths(
         // Health endpoints
         crate::handlers::health::health_check,
+        crate::handlers::health::readiness_check,
 
         // Schema management endpoints
         crate::handlers::schemas::build_schema,
@@ -49,9 +51,13 @@ use utoipa::OpenApi;
         crate::handlers::policies::validate_policy,
         crate::handlers::policies::evaluate_policies,
 
+        // Bulk authorization endpoints
+        crate::handlers::authz::authorization_matrix,
+
         // IAM policy management endpoints
         crate::handlers::iam::create_policy,
         crate::handlers::iam::get_policy,
+        crate::handlers::iam::get_policies,
         crate::handlers::iam::list_policies,
         crate::handlers::iam::update_policy,
         crate::handlers::iam::delete_policy,
@@ -63,6 +69,8 @@ use utoipa::OpenApi;
         schemas(
             // Health schemas
             crate::handlers::health::HealthResponse,
+            crate::readiness::EngineReadiness,
+            crate::readiness::ReadinessStep,
 
             // Schema management schemas
             crate::handlers::schemas::BuildSchemaRequest,
@@ -75,12 +83,20 @@ use utoipa::OpenApi;
             crate::handlers::policies::ValidatePolicyResponse,
             crate::handlers::policies::EvaluatePoliciesRequest,
             crate::handlers::policies::EvaluatePoliciesResponse,
+            crate::handlers::policies::DiagnosticInfo,
+
+            // Bulk authorization schemas
+            crate::handlers::authz::AuthorizationMatrixRequest,
+            crate::handlers::authz::AuthorizationMatrixResponse,
+            crate::handlers::authz::MatrixCellDto,
 
             // IAM policy management schemas
             crate::handlers::iam::CreatePolicyRequest,
             crate::handlers::iam::CreatePolicyResponse,
             crate::handlers::iam::GetPolicyRequest,
             crate::handlers::iam::GetPolicyResponse,
+            crate::handlers::iam::GetPoliciesRequest,
+            crate::handlers::iam::GetPoliciesResponse,
             crate::handlers::iam::ListPoliciesQueryParams,
             crate::handlers::iam::ListPoliciesResponse,
             crate::handlers::iam::PolicySummary,
@@ -97,6 +113,8 @@ use utoipa::OpenApi;
             crate::handlers::playground::AttributeValueDto,
             crate::handlers::playground::DeterminingPolicyDto,
             crate::handlers::playground::EvaluationDiagnosticsDto,
+            crate::handlers::playground::PolicyTraceDto,
+            crate::handlers::playground::ConditionTraceDto,
         )
     )
 )]
@@ -132,6 +150,7 @@ mod tests {
         assert!(tags.iter().any(|t| t.name == "playground"));
         assert!(tags.iter().any(|t| t.name == "schemas"));
         assert!(tags.iter().any(|t| t.name == "policies"));
+        assert!(tags.iter().any(|t| t.name == "authz"));
     }
 
     #[test]
@@ -0,0 +1,514 @@
+//! Readiness check for the authorization engine
+//!
+//! A running process is not necessarily able to authorize requests: the
+//! stored schema may not load, or a deploy may have shipped a policy Cedar
+//! cannot compile. The readiness check loads the current schema, compiles
+//! every stored IAM policy and runs a canary evaluation against synthetic
+//! entities under the schema it loaded. The first step that fails makes the service unready and its
+//! error is reported, so broken deploys are caught before traffic arrives.
+//!
+//! Results are cached for a short time so frequent probes do not recompile
+//! the policy set on every request.
+
+use cedar_policy::{Policy, PolicyId as CedarPolicyId, PolicySet};
+use hodei_iam::features::get_policies::dto::GetPoliciesQuery;
+use hodei_iam::features::get_policies::ports::GetPoliciesUseCasePort;
+use hodei_iam::features::list_policies::dto::ListPoliciesQuery;
+use hodei_iam::features::list_policies::ports::PolicyLister;
+use hodei_policies::evaluate_policies::dto::{AuthorizationRequest, EvaluatePoliciesCommand};
+use hodei_policies::evaluate_policies::ports::EvaluatePoliciesPort;
+use hodei_policies::load_schema::dto::LoadSchemaCommand;
+use hodei_policies::load_schema::ports::LoadSchemaPort;
+use kernel::domain::policy::{HodeiPolicy, HodeiPolicySet, PolicyId};
+use kernel::{AttributeName, AttributeValue, HodeiEntity, Hrn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+use utoipa::ToSchema;
+
+/// How long a readiness result is reused before the engine is checked again
+pub const DEFAULT_READINESS_TTL: Duration = Duration::from_secs(5);
+
+/// Action evaluated by the canary; no policy is expected to mention it
+const CANARY_ACTION: &str = "HealthCheckCanary";
+
+/// Page size used to list the stored policies
+const POLICY_PAGE_SIZE: usize = 100;
+
+/// Step of the readiness check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessStep {
+    /// Loading the current schema
+    Schema,
+    /// Fetching and compiling the stored policies
+    Policies,
+    /// Evaluating a synthetic request
+    Canary,
+}
+
+/// Outcome of a readiness check
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "ready": false,
+    "policy_count": 12,
+    "failed_step": "policies",
+    "error": "Policy 'hrn:hodei:iam::default:Policy/deny-all' does not compile: unexpected token",
+    "checked_at": "2024-01-15T10:30:00Z"
+}))]
+pub struct EngineReadiness {
+    /// Whether the engine can authorize requests
+    pub ready: bool,
+    /// Number of policies compiled; 0 if the check failed before compiling
+    pub policy_count: usize,
+    /// The step that failed, if any
+    pub failed_step: Option<ReadinessStep>,
+    /// Error of the failed step
+    pub error: Option<String>,
+    /// When the check ran (RFC 3339); cached results keep their original time
+    pub checked_at: String,
+}
+
+impl EngineReadiness {
+    fn ready(policy_count: usize) -> Self {
+        Self {
+            ready: true,
+            policy_count,
+            failed_step: None,
+            error: None,
+            checked_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    fn failed(step: ReadinessStep, policy_count: usize, error: String) -> Self {
+        Self {
+            ready: false,
+            policy_count,
+            failed_step: Some(step),
+            error: Some(error),
+            checked_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Synthetic principal and resource of the canary evaluation
+///
+/// It has no attributes or parents and an ID no real entity uses, so the
+/// canary does not depend on stored data.
+#[derive(Debug)]
+pub(crate) struct CanaryEntity {
+    hrn: Hrn,
+}
+
+impl CanaryEntity {
+    pub(crate) fn new() -> Self {
+        Self {
+            hrn: Hrn::new(
+                "hodei".to_string(),
+                "health".to_string(),
+                "default".to_string(),
+                "Canary".to_string(),
+                "readiness-probe".to_string(),
+            ),
+        }
+    }
+}
+
+impl HodeiEntity for CanaryEntity {
+    fn hrn(&self) -> &Hrn {
+        &self.hrn
+    }
+
+    fn attributes(&self) -> HashMap<AttributeName, AttributeValue> {
+        HashMap::new()
+    }
+}
+
+/// Readiness check of the authorization engine, with a short-lived cache
+pub struct EngineReadinessCheck {
+    load_schema: Arc<dyn LoadSchemaPort>,
+    list_policies: Arc<dyn PolicyLister>,
+    get_policies: Arc<dyn GetPoliciesUseCasePort>,
+    /// Evaluator reserved for the canary, so it never replaces the policies
+    /// loaded for live requests
+    evaluator: Arc<dyn EvaluatePoliciesPort>,
+    ttl: Duration,
+    cached: Mutex<Option<(Instant, EngineReadiness)>>,
+}
+
+impl EngineReadinessCheck {
+    /// Create a readiness check caching results for [`DEFAULT_READINESS_TTL`]
+    pub fn new(
+        load_schema: Arc<dyn LoadSchemaPort>,
+        list_policies: Arc<dyn PolicyLister>,
+        get_policies: Arc<dyn GetPoliciesUseCasePort>,
+        evaluator: Arc<dyn EvaluatePoliciesPort>,
+    ) -> Self {
+        Self {
+            load_schema,
+            list_policies,
+            get_policies,
+            evaluator,
+            ttl: DEFAULT_READINESS_TTL,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Reuse results for `ttl` instead of the default
+    #[cfg(test)]
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Check the engine, or return the cached result if it is recent enough
+    ///
+    /// Concurrent probes wait for a single check instead of each compiling
+    /// the policy set.
+    pub async fn check(&self) -> EngineReadiness {
+        let mut cached = self.cached.lock().await;
+        if let Some((checked_at, readiness)) = cached.as_ref()
+            && checked_at.elapsed() < self.ttl
+        {
+            return readiness.clone();
+        }
+
+        let readiness = self.run().await;
+        if let Some(error) = &readiness.error {
+            warn!(step = ?readiness.failed_step, error = %error, "Engine is not ready");
+        } else {
+            debug!(policies = readiness.policy_count, "Engine is ready");
+        }
+        *cached = Some((Instant::now(), readiness.clone()));
+        readiness
+    }
+
+    async fn run(&self) -> EngineReadiness {
+        let schema = match self.load_schema.execute(LoadSchemaCommand::latest()).await {
+            Ok(schema) => schema,
+            Err(e) => return EngineReadiness::failed(ReadinessStep::Schema, 0, e.to_string()),
+        };
+
+        let policies = match self.compile_policies().await {
+            Ok(policies) => policies,
+            Err(error) => return EngineReadiness::failed(ReadinessStep::Policies, 0, error),
+        };
+        let policy_count = policies.policies().len();
+
+        let canary = CanaryEntity::new();
+        let entities: Vec<&dyn HodeiEntity> = vec![&canary];
+        // Evaluate under the schema just loaded, as live requests are
+        let mut command = EvaluatePoliciesCommand::new(
+            AuthorizationRequest::new(&canary, CANARY_ACTION, &canary),
+            &policies,
+            &entities,
+        )
+        .strict_schema();
+        if let Some(version) = schema.version {
+            command = command.with_schema_version(version);
+        }
+        match self.evaluator.evaluate(command).await {
+            Ok(_) => EngineReadiness::ready(policy_count),
+            Err(e) => EngineReadiness::failed(ReadinessStep::Canary, policy_count, e.to_string()),
+        }
+    }
+
+    /// Fetch every stored policy and check that each one compiles
+    ///
+    /// Policies are compiled, not validated against the schema: evaluation
+    /// runs schema-less, so a policy the schema would reject still evaluates.
+    async fn compile_policies(&self) -> Result<HodeiPolicySet, String> {
+        let mut compiled = PolicySet::new();
+        let mut policies = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let page = self
+                .list_policies
+                .list(ListPoliciesQuery::with_pagination(POLICY_PAGE_SIZE, offset))
+                .await
+                .map_err(|e| format!("Failed to list policies: {}", e))?;
+            offset += page.policies.len();
+
+            if !page.policies.is_empty() {
+                let query = GetPoliciesQuery {
+                    policy_hrns: page.policies.into_iter().map(|p| p.hrn).collect(),
+                };
+                let found = self
+                    .get_policies
+                    .execute(query)
+                    .await
+                    .map_err(|e| format!("Failed to fetch policies: {}", e))?;
+
+                for view in found.policies {
+                    let id = view.hrn.to_string();
+                    let policy = Policy::parse(Some(CedarPolicyId::new(&id)), &view.content)
+                        .map_err(|e| format!("Policy '{}' does not compile: {}", id, e))?;
+                    compiled
+                        .add(policy)
+                        .map_err(|e| format!("Policy '{}' does not compile: {}", id, e))?;
+                    policies.push(HodeiPolicy::new(PolicyId::new(id), view.content));
+                }
+            }
+
+            if !page.has_next_page {
+                break;
+            }
+        }
+
+        Ok(HodeiPolicySet::new(policies))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use hodei_iam::features::get_policies::dto::{GetPoliciesResponse, PolicyView};
+    use hodei_iam::features::get_policies::error::GetPoliciesError;
+    use hodei_iam::features::list_policies::dto::{ListPoliciesResponse, PolicySummary};
+    use hodei_iam::features::list_policies::error::ListPoliciesError;
+    use hodei_policies::build_schema::error::BuildSchemaError;
+    use hodei_policies::build_schema::ports::SchemaStoragePort;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone)]
+    struct MockSchemaStorage {
+        has_schema: bool,
+    }
+
+    #[async_trait]
+    impl SchemaStoragePort for MockSchemaStorage {
+        async fn save_schema(
+            &self,
+            _schema_json: String,
+            _version: Option<String>,
+        ) -> Result<String, BuildSchemaError> {
+            Ok("schema_1".to_string())
+        }
+
+        async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
+            Ok(self.has_schema.then(|| "{}".to_string()))
+        }
+
+        async fn get_schema_by_version(
+            &self,
+            _version: &str,
+        ) -> Result<Option<String>, BuildSchemaError> {
+            Ok(None)
+        }
+
+        async fn delete_schema(&self, _schema_id: &str) -> Result<bool, BuildSchemaError> {
+            Ok(false)
+        }
+
+        async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
+            Ok(vec![])
+        }
+    }
+
+    /// In-memory policy store that counts how often it is listed
+    struct MockPolicies {
+        policies: Vec<(Hrn, String)>,
+        lists: AtomicUsize,
+    }
+
+    impl MockPolicies {
+        fn new(contents: &[&str]) -> Arc<Self> {
+            let policies = contents
+                .iter()
+                .enumerate()
+                .map(|(i, content)| {
+                    let hrn = Hrn::new(
+                        "hodei".to_string(),
+                        "iam".to_string(),
+                        "default".to_string(),
+                        "Policy".to_string(),
+                        format!("policy-{}", i),
+                    );
+                    (hrn, content.to_string())
+                })
+                .collect();
+            Arc::new(Self {
+                policies,
+                lists: AtomicUsize::new(0),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl PolicyLister for MockPolicies {
+        async fn list(
+            &self,
+            query: ListPoliciesQuery,
+        ) -> Result<ListPoliciesResponse, ListPoliciesError> {
+            self.lists.fetch_add(1, Ordering::SeqCst);
+            let page: Vec<PolicySummary> = self
+                .policies
+                .iter()
+                .skip(query.offset)
+                .take(query.limit)
+                .map(|(hrn, _)| PolicySummary {
+                    hrn: hrn.clone(),
+                    name: hrn.resource_id().to_string(),
+                    description: None,
+                })
+                .collect();
+            let has_next_page = query.offset + page.len() < self.policies.len();
+            Ok(ListPoliciesResponse::new(
+                page,
+                self.policies.len(),
+                has_next_page,
+                query.offset > 0,
+            ))
+        }
+    }
+
+    #[async_trait]
+    impl GetPoliciesUseCasePort for MockPolicies {
+        async fn execute(
+            &self,
+            query: GetPoliciesQuery,
+        ) -> Result<GetPoliciesResponse, GetPoliciesError> {
+            let policies = self
+                .policies
+                .iter()
+                .filter(|(hrn, _)| query.policy_hrns.contains(hrn))
+                .map(|(hrn, content)| PolicyView {
+                    hrn: hrn.clone(),
+                    name: hrn.resource_id().to_string(),
+                    content: content.clone(),
+                    description: None,
+                    annotations: HashMap::new(),
+                })
+                .collect();
+            Ok(GetPoliciesResponse {
+                policies,
+                not_found: vec![],
+            })
+        }
+    }
+
+    /// Schema storage whose latest schema disappears after the first read
+    struct VanishingSchemaStorage {
+        reads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl SchemaStoragePort for VanishingSchemaStorage {
+        async fn save_schema(
+            &self,
+            _schema_json: String,
+            _version: Option<String>,
+        ) -> Result<String, BuildSchemaError> {
+            Ok("schema_1".to_string())
+        }
+
+        async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
+            let first_read = self.reads.fetch_add(1, Ordering::SeqCst) == 0;
+            Ok(first_read.then(|| "{}".to_string()))
+        }
+
+        async fn get_schema_by_version(
+            &self,
+            _version: &str,
+        ) -> Result<Option<String>, BuildSchemaError> {
+            Ok(None)
+        }
+
+        async fn delete_schema(&self, _schema_id: &str) -> Result<bool, BuildSchemaError> {
+            Ok(false)
+        }
+
+        async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
+            Ok(vec![])
+        }
+    }
+
+    fn readiness_check(has_schema: bool, policies: Arc<MockPolicies>) -> EngineReadinessCheck {
+        let storage = Arc::new(MockSchemaStorage { has_schema });
+        EngineReadinessCheck::new(
+            hodei_policies::load_schema::factories::create_load_schema_use_case(storage.clone()),
+            policies.clone(),
+            policies,
+            hodei_policies::evaluate_policies::factories::create_evaluate_policies_use_case(
+                storage,
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ready_when_schema_and_policies_load() {
+        let policies = MockPolicies::new(&[
+            "permit(principal, action, resource);",
+            r#"forbid(principal, action == Action::"Delete", resource);"#,
+        ]);
+
+        let readiness = readiness_check(true, policies).check().await;
+
+        assert!(readiness.ready, "{:?}", readiness.error);
+        assert_eq!(readiness.policy_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_missing_schema_is_not_ready() {
+        let readiness = readiness_check(false, MockPolicies::new(&[])).check().await;
+
+        assert!(!readiness.ready);
+        assert_eq!(readiness.failed_step, Some(ReadinessStep::Schema));
+    }
+
+    #[tokio::test]
+    async fn test_canary_evaluates_under_the_schema() {
+        let storage = Arc::new(VanishingSchemaStorage {
+            reads: AtomicUsize::new(0),
+        });
+        let policies = MockPolicies::new(&["permit(principal, action, resource);"]);
+        let check = EngineReadinessCheck::new(
+            hodei_policies::load_schema::factories::create_load_schema_use_case(storage.clone()),
+            policies.clone(),
+            policies,
+            hodei_policies::evaluate_policies::factories::create_evaluate_policies_use_case(
+                storage.clone(),
+            ),
+        );
+
+        let readiness = check.check().await;
+
+        assert!(!readiness.ready);
+        assert_eq!(readiness.failed_step, Some(ReadinessStep::Canary));
+        assert_eq!(storage.reads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_policy_that_does_not_compile_is_reported() {
+        let policies = MockPolicies::new(&[
+            "permit(principal, action, resource);",
+            "permit(principal, action, resource) when { principal. };",
+        ]);
+
+        let readiness = readiness_check(true, policies).check().await;
+
+        assert!(!readiness.ready);
+        assert_eq!(readiness.failed_step, Some(ReadinessStep::Policies));
+        assert!(readiness.error.unwrap().contains("policy-1"));
+    }
+
+    #[tokio::test]
+    async fn test_result_is_cached_until_the_ttl_expires() {
+        let policies = MockPolicies::new(&["permit(principal, action, resource);"]);
+        let check = readiness_check(true, policies.clone());
+
+        check.check().await;
+        check.check().await;
+        assert_eq!(policies.lists.load(Ordering::SeqCst), 1);
+
+        let check = check.with_ttl(Duration::ZERO);
+        check.check().await;
+        check.check().await;
+        assert_eq!(policies.lists.load(Ordering::SeqCst), 3);
+    }
+}
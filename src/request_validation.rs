@@ -0,0 +1,451 @@
+//! Request body validation against the OpenAPI spec
+//!
+//! Every route documented with a JSON `request_body` gets its body checked
+//! against the schema `utoipa` generated for it before the handler runs. A
+//! body that doesn't match answers `422 Unprocessable Entity` listing every
+//! offending field; a body that isn't JSON at all answers `400 Bad Request`.
+//!
+//! The schemas come from the same `ToSchema` derives as the request DTOs and
+//! follow their serde attributes: `Option` and `#[serde(default)]` fields are
+//! optional and unknown fields are accepted, as the deserializers do. Routes
+//! whose bodies can't be described by a static schema (the playground) are
+//! left to their handler with [`RequestValidator::skip`].
+
+use axum::{
+    Json,
+    body::{Body, Bytes},
+    extract::{Request, State},
+    http::{Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use jsonschema::{ValidationError, Validator, error::ValidationErrorKind};
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::debug;
+
+/// Largest body buffered for validation, the same as axum's `Json` default
+const BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// A field of the request body that failed validation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    /// JSON pointer to the field (`""` is the whole body)
+    pub field: String,
+    pub message: String,
+}
+
+/// A documented route with the validator for its request body
+struct RouteSchema {
+    method: Method,
+    path: String,
+    validator: Validator,
+}
+
+/// Validators for the request bodies of every documented route
+pub struct RequestValidator {
+    routes: Vec<RouteSchema>,
+}
+
+impl RequestValidator {
+    /// Compile a validator for each JSON request body in `openapi`
+    ///
+    /// Fails if a generated schema isn't valid JSON Schema, which is a bug in
+    /// the DTO annotations rather than something to recover from.
+    pub fn from_openapi(openapi: &utoipa::openapi::OpenApi) -> anyhow::Result<Self> {
+        let spec = serde_json::to_value(openapi)?;
+        let components = spec.get("components").cloned().unwrap_or(Value::Null);
+        let mut routes = Vec::new();
+
+        let paths = spec["paths"].as_object().into_iter().flatten();
+        for (path, item) in paths {
+            let operations = item.as_object().into_iter().flatten();
+            for (method, operation) in operations {
+                let Ok(method) = method.to_uppercase().parse::<Method>() else {
+                    continue;
+                };
+                let Some(schema) = operation
+                    .pointer("/requestBody/content/application~1json/schema")
+                    .cloned()
+                else {
+                    continue;
+                };
+
+                // `$ref`s point into the spec's components, so they travel with the schema
+                let mut schema = schema;
+                if let Some(schema) = schema.as_object_mut() {
+                    schema.insert("components".to_string(), components.clone());
+                }
+                let validator = jsonschema::draft202012::new(&schema).map_err(|e| {
+                    anyhow::anyhow!("Invalid request schema for {} {}: {}", method, path, e)
+                })?;
+                routes.push(RouteSchema {
+                    method,
+                    path: path.clone(),
+                    validator,
+                });
+            }
+        }
+
+        debug!(routes = routes.len(), "Request body validators compiled");
+        Ok(Self { routes })
+    }
+
+    /// Leave the body of `method path` to its handler
+    pub fn skip(mut self, method: Method, path: &str) -> Self {
+        self.routes
+            .retain(|route| route.method != method || route.path != path);
+        self
+    }
+
+    /// Check `body` against the schema of `method path`
+    ///
+    /// Routes without a documented body (or skipped) accept anything.
+    pub fn validate(&self, method: &Method, path: &str, body: &Value) -> Vec<FieldError> {
+        let Some(route) = self.route(method, path) else {
+            return Vec::new();
+        };
+        route.validator.iter_errors(body).map(field_error).collect()
+    }
+
+    fn route(&self, method: &Method, path: &str) -> Option<&RouteSchema> {
+        self.routes
+            .iter()
+            .find(|route| route.method == *method && path_matches(&route.path, path))
+    }
+}
+
+/// Whether `path` matches an OpenAPI path template such as `/users/{id}`
+fn path_matches(template: &str, path: &str) -> bool {
+    let mut template = template.split('/');
+    let mut path = path.trim_end_matches('/').split('/');
+    loop {
+        match (template.next(), path.next()) {
+            (None, None) => return true,
+            (Some(expected), Some(actual)) => {
+                let is_param = expected.starts_with('{') && expected.ends_with('}');
+                let matches = if is_param {
+                    !actual.is_empty()
+                } else {
+                    expected == actual
+                };
+                if !matches {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+}
+
+fn field_error(error: ValidationError<'_>) -> FieldError {
+    let mut field = error.instance_path.to_string();
+    // A missing property is reported on its parent object
+    if let ValidationErrorKind::Required { property } = &error.kind
+        && let Some(property) = property.as_str()
+    {
+        field = format!(
+            "{}/{}",
+            field,
+            property.replace('~', "~0").replace('/', "~1")
+        );
+    }
+    FieldError {
+        field,
+        message: error.to_string(),
+    }
+}
+
+fn is_json(request: &Request) -> bool {
+    request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|mime| {
+            let mime = mime.trim();
+            mime == "application/json" || mime.ends_with("+json")
+        })
+        .unwrap_or(false)
+}
+
+fn error_response(
+    status: StatusCode,
+    code: &str,
+    message: String,
+    fields: &[FieldError],
+) -> Response {
+    let mut body = serde_json::json!({
+        "error": message,
+        "code": code,
+        "status": status.as_u16(),
+    });
+    if !fields.is_empty() {
+        body["fields"] = serde_json::json!(fields);
+    }
+    (status, Json(body)).into_response()
+}
+
+/// Middleware validating JSON request bodies before they reach a handler
+///
+/// Requests without a JSON `Content-Type` go through untouched; the handler's
+/// extractor answers those.
+pub async fn validate_request_body(
+    State(validator): State<Arc<RequestValidator>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !is_json(&request)
+        || validator
+            .route(request.method(), request.uri().path())
+            .is_none()
+    {
+        return next.run(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes: Bytes = match axum::body::to_bytes(body, BODY_LIMIT).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return error_response(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "payload_too_large",
+                format!("Request body exceeds {} bytes", BODY_LIMIT),
+                &[],
+            );
+        }
+    };
+
+    let value: Value = match serde_json::from_slice(&bytes) {
+        Ok(value) => value,
+        Err(e) => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "malformed_body",
+                format!("Request body is not valid JSON: {}", e),
+                &[],
+            );
+        }
+    };
+
+    let fields = validator.validate(&parts.method, parts.uri.path(), &value);
+    if !fields.is_empty() {
+        debug!(path = %parts.uri.path(), errors = fields.len(), "Request body rejected");
+        return error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "invalid_request_body",
+            "Request body does not match the schema".to_string(),
+            &fields,
+        );
+    }
+
+    next.run(Request::from_parts(parts, Body::from(bytes)))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::{authz, iam, policies, schemas};
+    use crate::openapi::create_api_doc;
+    use axum::{Router, middleware, routing::post};
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    fn validator() -> RequestValidator {
+        RequestValidator::from_openapi(&create_api_doc()).unwrap()
+    }
+
+    /// Asserts the schema of `method path` accepts exactly the objects `T`
+    /// deserializes from
+    fn assert_matches_serde<T: DeserializeOwned>(method: Method, path: &str, bodies: &[Value]) {
+        let validator = validator();
+        assert!(
+            validator.route(&method, path).is_some(),
+            "{} {}",
+            method,
+            path
+        );
+        for body in bodies {
+            let accepted = validator.validate(&method, path, body).is_empty();
+            let deserialized = serde_json::from_value::<T>(body.clone()).is_ok();
+            assert_eq!(accepted, deserialized, "{} {}: {}", method, path, body);
+        }
+    }
+
+    #[test]
+    fn schemas_agree_with_deserialization() {
+        assert_matches_serde::<iam::CreatePolicyRequest>(
+            Method::POST,
+            "/api/v1/iam/policies",
+            &[
+                json!({"policy_id": "p", "policy_content": "permit(principal, action, resource);"}),
+                json!({"policy_id": "p", "policy_content": "c", "description": null}),
+                json!({"policy_id": "p", "policy_content": "c", "extra": 1}),
+                json!({"policy_id": "p"}),
+                json!({"policy_id": 7, "policy_content": "c"}),
+            ],
+        );
+        assert_matches_serde::<iam::GetPoliciesRequest>(
+            Method::POST,
+            "/api/v1/iam/policies/batch-get",
+            &[
+                json!({"policy_hrns": []}),
+                json!({"policy_hrns": ["a", "b"]}),
+                json!({"policy_hrns": ["a", 1]}),
+                json!({}),
+            ],
+        );
+        assert_matches_serde::<iam::UpdatePolicyRequest>(
+            Method::PUT,
+            "/api/v1/iam/policies/update",
+            &[
+                json!({"policy_hrn": "h", "policy_content": "c"}),
+                json!({"policy_hrn": "h", "policy_content": "c", "description": "d"}),
+                json!({"policy_hrn": "h", "description": "d"}),
+            ],
+        );
+        assert_matches_serde::<iam::DeletePolicyRequest>(
+            Method::DELETE,
+            "/api/v1/iam/policies/delete",
+            &[json!({"policy_hrn": "h"}), json!({"policy_hrn": null})],
+        );
+        assert_matches_serde::<policies::ValidatePolicyRequest>(
+            Method::POST,
+            "/api/v1/policies/validate",
+            &[
+                json!({"content": "c"}),
+                json!({"content": "c", "use_schema": false}),
+                json!({"content": "c", "use_schema": "yes"}),
+                json!({"use_schema": true}),
+            ],
+        );
+        assert_matches_serde::<authz::AuthorizationMatrixRequest>(
+            Method::POST,
+            "/api/v1/authz/matrix",
+            &[
+                json!({"principals": ["p"], "action": "a", "resources": ["r"], "policies": []}),
+                json!({"principals": [], "action": "a", "resources": [], "policies": [], "context": {"mfa": true}}),
+                json!({"principals": "p", "action": "a", "resources": [], "policies": []}),
+                json!({"principals": [], "resources": [], "policies": []}),
+            ],
+        );
+        assert_matches_serde::<schemas::BuildSchemaRequest>(
+            Method::POST,
+            "/api/v1/schemas/build",
+            &[
+                json!({}),
+                json!({"version": "v1", "validate": true}),
+                json!({"version": 1}),
+            ],
+        );
+    }
+
+    #[test]
+    fn structs_are_only_accepted_as_objects() {
+        // serde also reads a struct from an array of its fields; the schema
+        // doesn't, and since it runs first such bodies never reach a handler
+        let body = json!(["p", "permit(principal, action, resource);"]);
+
+        let errors = validator().validate(&Method::POST, "/api/v1/iam/policies", &body);
+
+        assert!(serde_json::from_value::<iam::CreatePolicyRequest>(body).is_ok());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "");
+    }
+
+    #[test]
+    fn field_errors_point_at_the_offending_fields() {
+        let errors = validator().validate(
+            &Method::POST,
+            "/api/v1/iam/policies",
+            &json!({"policy_id": 7}),
+        );
+
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"/policy_id"), "{:?}", errors);
+        assert!(fields.contains(&"/policy_content"), "{:?}", errors);
+    }
+
+    #[test]
+    fn skipped_routes_accept_any_body() {
+        let path = "/api/v1/playground/evaluate";
+        let validator = validator();
+        assert!(
+            !validator
+                .validate(&Method::POST, path, &json!({}))
+                .is_empty()
+        );
+
+        let validator = validator.skip(Method::POST, path);
+        assert!(
+            validator
+                .validate(&Method::POST, path, &json!({}))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn path_templates_match_parameters() {
+        assert!(path_matches("/users/{id}", "/users/42"));
+        assert!(path_matches("/users/{id}", "/users/42/"));
+        assert!(!path_matches("/users/{id}", "/users"));
+        assert!(!path_matches("/users/{id}", "/users/42/groups"));
+        assert!(path_matches("/health", "/health"));
+    }
+
+    async fn send(body: &str) -> Response {
+        let app = Router::new()
+            .route(
+                "/api/v1/iam/policies",
+                post(|body: String| async move { body }),
+            )
+            .layer(middleware::from_fn_with_state(
+                Arc::new(validator()),
+                validate_request_body,
+            ));
+        app.oneshot(
+            Request::post("/api/v1/iam/policies")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn json_body(response: Response) -> Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn invalid_bodies_are_rejected_before_the_handler() {
+        let response = send(r#"{"policy_id": "p"}"#).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = json_body(response).await;
+        assert_eq!(body["code"], "invalid_request_body");
+        assert_eq!(body["fields"][0]["field"], "/policy_content");
+
+        let response = send("{not json").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(json_body(response).await["code"], "malformed_body");
+    }
+
+    #[tokio::test]
+    async fn valid_bodies_reach_the_handler_intact() {
+        let body = r#"{"policy_id": "p", "policy_content": "c"}"#;
+
+        let response = send(body).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(bytes, body.as_bytes());
+    }
+}
@@ -0,0 +1,153 @@
+//! Runtime route disabling
+//!
+//! Routes listed in `server.disabled_routes` answer `503 Service Unavailable`
+//! with a `Retry-After` header instead of reaching their handler. The set is
+//! read on every request from a shared [`DisabledRoutes`] handle, so a
+//! configuration reload takes effect without restarting the server.
+
+use crate::config::{ServerConfig, route_covers_path};
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::{HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::BTreeSet;
+use std::sync::{Arc, RwLock};
+use tracing::{debug, info};
+
+#[derive(Debug, Default)]
+struct Settings {
+    routes: BTreeSet<String>,
+    retry_after_secs: u64,
+}
+
+/// Shared, reloadable set of disabled routes
+#[derive(Debug, Clone, Default)]
+pub struct DisabledRoutes {
+    settings: Arc<RwLock<Settings>>,
+}
+
+impl DisabledRoutes {
+    pub fn from_config(config: &ServerConfig) -> Self {
+        let routes = Self::default();
+        routes.update(config);
+        routes
+    }
+
+    /// Replace the disabled routes with those of a freshly loaded config
+    pub fn update(&self, config: &ServerConfig) {
+        let mut settings = self.settings.write().unwrap();
+        if settings.routes != config.disabled_routes {
+            info!(routes = ?config.disabled_routes, "Disabled routes updated");
+        }
+        settings.routes = config.disabled_routes.clone();
+        settings.retry_after_secs = config.disabled_route_retry_after_secs;
+    }
+
+    /// The disabled-route entry covering `path`, with the `Retry-After` to send
+    fn matching(&self, path: &str) -> Option<(String, u64)> {
+        let settings = self.settings.read().unwrap();
+        settings
+            .routes
+            .iter()
+            .find(|route| route_covers_path(route, path))
+            .map(|route| (route.clone(), settings.retry_after_secs))
+    }
+}
+
+/// Middleware rejecting requests for disabled routes with a JSON 503
+pub async fn reject_disabled_routes(
+    State(disabled): State<DisabledRoutes>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((route, retry_after_secs)) = disabled.matching(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    debug!(path = %request.uri().path(), route = %route, "Request to disabled route rejected");
+
+    let status = StatusCode::SERVICE_UNAVAILABLE;
+    let body = Json(serde_json::json!({
+        "error": format!("Route {} is temporarily disabled", route),
+        "status": status.as_u16(),
+    }));
+
+    let mut response = (status, body).into_response();
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    fn server_config(routes: &[&str]) -> ServerConfig {
+        ServerConfig {
+            disabled_routes: routes.iter().map(|route| route.to_string()).collect(),
+            disabled_route_retry_after_secs: 120,
+            ..ServerConfig::default()
+        }
+    }
+
+    fn app(disabled: DisabledRoutes) -> Router {
+        Router::new()
+            .route("/health", get(|| async { "ok" }))
+            .route("/api/v1/playground/evaluate", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(
+                disabled,
+                reject_disabled_routes,
+            ))
+    }
+
+    async fn send(app: &Router, path: &str) -> Response {
+        app.clone()
+            .oneshot(Request::get(path).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn disabled_routes_answer_503_with_retry_after() {
+        let app = app(DisabledRoutes::from_config(&server_config(&[
+            "/api/v1/playground",
+        ])));
+
+        let response = send(&app, "/api/v1/playground/evaluate").await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "120");
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["status"], 503);
+
+        assert_eq!(send(&app, "/health").await.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn updates_apply_to_the_next_request() {
+        let disabled = DisabledRoutes::default();
+        let app = app(disabled.clone());
+        let path = "/api/v1/playground/evaluate";
+
+        assert_eq!(send(&app, path).await.status(), StatusCode::OK);
+
+        disabled.update(&server_config(&["/api/v1/playground/evaluate"]));
+        assert_eq!(
+            send(&app, path).await.status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        disabled.update(&server_config(&[]));
+        assert_eq!(send(&app, path).await.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,263 @@
+//! File-based schema storage, for local development
+//!
+//! Each version is kept as `{version}.json` in the configured directory and
+//! the last schema saved as `latest.json`. Files are written to a temporary
+//! name and renamed into place, so a reader never sees a half-written schema.
+
+use super::{LATEST_SCHEMA_ID, check_version, version_of};
+use async_trait::async_trait;
+use hodei_policies::build_schema::error::BuildSchemaError;
+use hodei_policies::build_schema::ports::SchemaStoragePort;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const EXTENSION: &str = "json";
+
+/// Schema storage in a local directory
+#[derive(Clone)]
+pub struct FileSchemaStorage {
+    dir: Arc<PathBuf>,
+}
+
+impl FileSchemaStorage {
+    /// Use `dir` for the schemas, creating it if needed
+    pub async fn new(dir: impl AsRef<Path>) -> Result<Self, BuildSchemaError> {
+        let dir = dir.as_ref().to_path_buf();
+        tokio::fs::create_dir_all(&dir).await.map_err(|e| {
+            storage_error(
+                format!("Cannot create schema directory {}", dir.display()),
+                e,
+            )
+        })?;
+        Ok(Self { dir: Arc::new(dir) })
+    }
+
+    /// Directory holding the schemas
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path_of(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.{}", id, EXTENSION))
+    }
+
+    async fn write(&self, id: &str, content: &str) -> Result<(), BuildSchemaError> {
+        let path = self.path_of(id);
+        let tmp = self
+            .dir
+            .join(format!(".{}.{}.tmp", id, uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, content)
+            .await
+            .map_err(|e| storage_error(format!("Cannot write {}", tmp.display()), e))?;
+        if let Err(e) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(storage_error(format!("Cannot write {}", path.display()), e));
+        }
+        Ok(())
+    }
+
+    async fn read(&self, id: &str) -> Result<Option<String>, BuildSchemaError> {
+        let path = self.path_of(id);
+        match tokio::fs::read_to_string(&path).await {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(storage_error(format!("Cannot read {}", path.display()), e)),
+        }
+    }
+}
+
+fn storage_error(context: String, e: std::io::Error) -> BuildSchemaError {
+    BuildSchemaError::SchemaStorageError(format!("{}: {}", context, e))
+}
+
+#[async_trait]
+impl SchemaStoragePort for FileSchemaStorage {
+    async fn save_schema(
+        &self,
+        schema_json: String,
+        version: Option<String>,
+    ) -> Result<String, BuildSchemaError> {
+        let schema_id = match version {
+            Some(version) => {
+                check_version(&version)?;
+                self.write(&version, &schema_json).await?;
+                version
+            }
+            None => LATEST_SCHEMA_ID.to_string(),
+        };
+        self.write(LATEST_SCHEMA_ID, &schema_json).await?;
+
+        Ok(format!("schema:{}", schema_id))
+    }
+
+    async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
+        self.read(LATEST_SCHEMA_ID).await
+    }
+
+    async fn get_schema_by_version(
+        &self,
+        version: &str,
+    ) -> Result<Option<String>, BuildSchemaError> {
+        if check_version(version).is_err() {
+            return Ok(None);
+        }
+        self.read(version).await
+    }
+
+    /// Deleting a version leaves `latest.json` untouched, even if it is a copy
+    /// of that version
+    async fn delete_schema(&self, schema_id: &str) -> Result<bool, BuildSchemaError> {
+        let id = version_of(schema_id);
+        if id != LATEST_SCHEMA_ID && check_version(id).is_err() {
+            return Ok(false);
+        }
+        let path = self.path_of(id);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(storage_error(
+                format!("Cannot delete {}", path.display()),
+                e,
+            )),
+        }
+    }
+
+    async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
+        let mut entries = tokio::fs::read_dir(self.dir.as_path())
+            .await
+            .map_err(|e| storage_error(format!("Cannot list {}", self.dir.display()), e))?;
+
+        let mut versions = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| storage_error(format!("Cannot list {}", self.dir.display()), e))?
+        {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(EXTENSION) {
+                continue;
+            }
+            if let Some(version) = path.file_stem().and_then(|stem| stem.to_str())
+                && check_version(version).is_ok()
+            {
+                versions.push(version.to_string());
+            }
+        }
+        versions.sort();
+
+        Ok(versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_save_versions_and_latest() {
+        let dir = tempdir().unwrap();
+        let storage = FileSchemaStorage::new(dir.path().join("schemas"))
+            .await
+            .unwrap();
+
+        let id = storage
+            .save_schema("{\"v\":2}".to_string(), Some("v2".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(id, "schema:v2");
+        storage
+            .save_schema("{\"v\":1}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage
+                .get_schema_by_version("v2")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("{\"v\":2}")
+        );
+        assert_eq!(
+            storage.get_latest_schema().await.unwrap().as_deref(),
+            Some("{\"v\":1}")
+        );
+        assert_eq!(
+            storage.list_schema_versions().await.unwrap(),
+            vec!["v1", "v2"]
+        );
+        assert!(storage.get_schema_by_version("v3").await.unwrap().is_none());
+
+        // Only the schema files are left behind
+        let mut names: Vec<String> = std::fs::read_dir(storage.dir())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["latest.json", "v1.json", "v2.json"]);
+    }
+
+    #[tokio::test]
+    async fn test_unsafe_versions_stay_inside_the_directory() {
+        let dir = tempdir().unwrap();
+        let storage = FileSchemaStorage::new(dir.path().join("schemas"))
+            .await
+            .unwrap();
+
+        assert!(
+            storage
+                .save_schema("{}".to_string(), Some("../escape".to_string()))
+                .await
+                .is_err()
+        );
+        assert!(!dir.path().join("escape.json").exists());
+        assert!(
+            storage
+                .get_schema_by_version("../schemas/latest")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(!storage.delete_schema("../schemas/latest").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_id_or_version() {
+        let dir = tempdir().unwrap();
+        let storage = FileSchemaStorage::new(dir.path()).await.unwrap();
+        storage
+            .save_schema("{}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+        storage
+            .save_schema("{}".to_string(), Some("v2".to_string()))
+            .await
+            .unwrap();
+
+        assert!(storage.delete_schema("schema:v1").await.unwrap());
+        assert!(storage.delete_schema("v2").await.unwrap());
+        assert!(!storage.delete_schema("v2").await.unwrap());
+        assert!(storage.list_schema_versions().await.unwrap().is_empty());
+        assert!(storage.get_latest_schema().await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_schemas_survive_a_restart() {
+        let dir = tempdir().unwrap();
+        FileSchemaStorage::new(dir.path())
+            .await
+            .unwrap()
+            .save_schema("{}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+
+        let reopened = FileSchemaStorage::new(dir.path()).await.unwrap();
+        assert_eq!(reopened.list_schema_versions().await.unwrap(), vec!["v1"]);
+        assert_eq!(
+            reopened.get_latest_schema().await.unwrap().as_deref(),
+            Some("{}")
+        );
+    }
+}
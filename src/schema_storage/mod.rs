@@ -0,0 +1,175 @@
+//! Schema storage backends
+//!
+//! The Cedar schema can live in one of three places, chosen per environment
+//! with `schema.storage_type`:
+//! - `rocksdb`/`surrealdb`: the application's SurrealDB database, written
+//!   transactionally ([`SurrealSchemaAdapter`])
+//! - `file`: a local directory, for development ([`FileSchemaStorage`])
+//! - `s3`: a bucket shared by every replica ([`S3SchemaStorage`])
+//!
+//! All of them keep schemas by version: saving a schema stores it under its
+//! version (when it has one) and makes it the latest, so it can be loaded
+//! either way right after being built. Saving a version that already exists
+//! replaces it.
+
+pub mod file;
+pub mod s3;
+pub mod surreal;
+
+pub use file::FileSchemaStorage;
+pub use s3::S3SchemaStorage;
+pub use surreal::SurrealSchemaAdapter;
+
+use crate::config::SchemaConfig;
+use async_trait::async_trait;
+use hodei_policies::build_schema::error::BuildSchemaError;
+use hodei_policies::build_schema::ports::SchemaStoragePort;
+use surrealdb::Surreal;
+use surrealdb::engine::local::Db;
+use tracing::info;
+
+/// Identifier of the schema saved without a version
+pub const LATEST_SCHEMA_ID: &str = "latest";
+
+/// The schema storage backend selected in the configuration
+#[derive(Clone)]
+pub enum SchemaStorage {
+    Surreal(SurrealSchemaAdapter),
+    File(FileSchemaStorage),
+    S3(S3SchemaStorage),
+}
+
+impl SchemaStorage {
+    /// Create the backend named by `config.storage_type`
+    ///
+    /// `db` is the application's database, used by the SurrealDB backend.
+    pub async fn from_config(
+        config: &SchemaConfig,
+        db: Surreal<Db>,
+    ) -> Result<Self, BuildSchemaError> {
+        let storage = match config.storage_type.as_str() {
+            "rocksdb" | "surrealdb" => Self::Surreal(SurrealSchemaAdapter::new(db)),
+            "file" => Self::File(FileSchemaStorage::new(&config.file_path).await?),
+            "s3" => Self::S3(S3SchemaStorage::from_config(&config.s3).await),
+            other => {
+                return Err(BuildSchemaError::SchemaStorageError(format!(
+                    "Unsupported schema storage '{}'",
+                    other
+                )));
+            }
+        };
+        info!("📚 Schema storage: {}", storage.describe());
+        Ok(storage)
+    }
+
+    /// Where the schemas are kept, for logs
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Surreal(_) => "SurrealDB".to_string(),
+            Self::File(storage) => format!("directory {}", storage.dir().display()),
+            Self::S3(storage) => format!("S3 {}", storage.location()),
+        }
+    }
+
+    fn backend(&self) -> &dyn SchemaStoragePort {
+        match self {
+            Self::Surreal(storage) => storage,
+            Self::File(storage) => storage,
+            Self::S3(storage) => storage,
+        }
+    }
+}
+
+#[async_trait]
+impl SchemaStoragePort for SchemaStorage {
+    async fn save_schema(
+        &self,
+        schema_json: String,
+        version: Option<String>,
+    ) -> Result<String, BuildSchemaError> {
+        self.backend().save_schema(schema_json, version).await
+    }
+
+    async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
+        self.backend().get_latest_schema().await
+    }
+
+    async fn get_schema_by_version(
+        &self,
+        version: &str,
+    ) -> Result<Option<String>, BuildSchemaError> {
+        self.backend().get_schema_by_version(version).await
+    }
+
+    async fn delete_schema(&self, schema_id: &str) -> Result<bool, BuildSchemaError> {
+        self.backend().delete_schema(schema_id).await
+    }
+
+    async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
+        self.backend().list_schema_versions().await
+    }
+}
+
+/// Checks a schema version is usable as a file name or object key
+fn check_version(version: &str) -> Result<(), BuildSchemaError> {
+    let valid = !version.is_empty()
+        && version != LATEST_SCHEMA_ID
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        && !version.starts_with('.');
+    if valid {
+        Ok(())
+    } else {
+        Err(BuildSchemaError::SchemaStorageError(format!(
+            "Invalid schema version '{}': use letters, digits, '.', '-' and '_', not starting with '.'",
+            version
+        )))
+    }
+}
+
+/// The version a schema id refers to (`schema:v1` and `v1` both mean `v1`)
+fn version_of(schema_id: &str) -> &str {
+    schema_id.strip_prefix("schema:").unwrap_or(schema_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealdb::engine::local::Mem;
+
+    #[test]
+    fn test_versions_must_be_safe_names() {
+        for version in ["v1", "v1.0.0", "2024-01-15_rc.1"] {
+            assert!(check_version(version).is_ok(), "{}", version);
+        }
+        for version in ["", "latest", "../v1", "v1/other", ".hidden", "v 1"] {
+            assert!(check_version(version).is_err(), "{}", version);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backend_is_selected_from_config() {
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        let file = SchemaConfig {
+            storage_type: "file".to_string(),
+            file_path: dir.path().join("schemas").to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let storage = SchemaStorage::from_config(&file, db.clone()).await.unwrap();
+        assert!(matches!(storage, SchemaStorage::File(_)));
+
+        let surreal = SchemaStorage::from_config(&SchemaConfig::default(), db.clone())
+            .await
+            .unwrap();
+        assert!(matches!(surreal, SchemaStorage::Surreal(_)));
+
+        let unknown = SchemaConfig {
+            storage_type: "mongodb".to_string(),
+            ..Default::default()
+        };
+        assert!(SchemaStorage::from_config(&unknown, db).await.is_err());
+    }
+}
@@ -0,0 +1,690 @@
+//! S3 schema storage, shared by every replica
+//!
+//! Each version is stored as `{prefix}{version}.json` and the last schema
+//! saved as `{prefix}latest.json`. Objects hold the schema together with its
+//! version and save time.
+//!
+//! Object stores may serve a stale or missing object for a short while after
+//! a write (S3-compatible services and caching proxies do; S3 itself lists
+//! lazily). So that a schema is loadable as soon as it is built:
+//! - the instance that saves a schema keeps a copy, used whenever the store
+//!   returns nothing or something older
+//! - a schema the store doesn't return yet, `latest` included, is read again
+//!   a few times before it is reported missing (`read_retries`,
+//!   `read_retry_delay_ms`)
+//!
+//! Replicas saving at the same time race on `latest`. It is written
+//! conditionally on the object it replaces (its ETag, or its absence), and
+//! never over a schema saved later, so the last schema saved stays latest
+//! whichever replica writes last.
+
+use super::{LATEST_SCHEMA_ID, check_version, version_of};
+use crate::config::SchemaS3Config;
+use async_trait::async_trait;
+use aws_sdk_s3::Client;
+use aws_sdk_s3::error::{DisplayErrorContext, ProvideErrorMetadata};
+use aws_sdk_s3::primitives::ByteStream;
+use chrono::{DateTime, Utc};
+use hodei_policies::build_schema::error::BuildSchemaError;
+use hodei_policies::build_schema::ports::SchemaStoragePort;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tracing::debug;
+
+/// Attempts at replacing `latest` while other replicas keep replacing it
+const LATEST_WRITE_ATTEMPTS: u32 = 5;
+
+/// Precondition of a conditional write
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WriteCondition {
+    /// The object doesn't exist
+    Absent,
+    /// The object still has this ETag
+    Matches(String),
+}
+
+/// Minimal object store operations the schema storage needs
+#[async_trait]
+trait ObjectStore: Send + Sync {
+    async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), BuildSchemaError>;
+    /// Write only if `condition` holds; returns whether it did
+    async fn put_if(
+        &self,
+        key: &str,
+        body: Vec<u8>,
+        condition: WriteCondition,
+    ) -> Result<bool, BuildSchemaError>;
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BuildSchemaError> {
+        Ok(self.get_tagged(key).await?.map(|(body, _etag)| body))
+    }
+    /// The object together with its ETag
+    async fn get_tagged(&self, key: &str) -> Result<Option<(Vec<u8>, String)>, BuildSchemaError>;
+    /// Returns whether the object existed
+    async fn delete(&self, key: &str) -> Result<bool, BuildSchemaError>;
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, BuildSchemaError>;
+}
+
+fn storage_error(context: &str, e: impl std::error::Error) -> BuildSchemaError {
+    BuildSchemaError::SchemaStorageError(format!("{}: {}", context, DisplayErrorContext(e)))
+}
+
+/// An S3 bucket accessed through the AWS SDK
+struct S3Bucket {
+    client: Client,
+    bucket: String,
+}
+
+#[async_trait]
+impl ObjectStore for S3Bucket {
+    async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), BuildSchemaError> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type("application/json")
+            .body(ByteStream::from(body))
+            .send()
+            .await
+            .map_err(|e| storage_error(&format!("Cannot write s3://{}/{}", self.bucket, key), e))?;
+        Ok(())
+    }
+
+    async fn put_if(
+        &self,
+        key: &str,
+        body: Vec<u8>,
+        condition: WriteCondition,
+    ) -> Result<bool, BuildSchemaError> {
+        let request = self
+            .client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type("application/json")
+            .body(ByteStream::from(body));
+        let request = match condition {
+            WriteCondition::Absent => request.if_none_match("*"),
+            WriteCondition::Matches(etag) => request.if_match(etag),
+        };
+        match request.send().await {
+            Ok(_) => Ok(true),
+            // Another write got there first
+            Err(e)
+                if matches!(
+                    e.as_service_error().and_then(|e| e.code()),
+                    Some("PreconditionFailed" | "ConditionalRequestConflict")
+                ) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(storage_error(
+                &format!("Cannot write s3://{}/{}", self.bucket, key),
+                e,
+            )),
+        }
+    }
+
+    async fn get_tagged(&self, key: &str) -> Result<Option<(Vec<u8>, String)>, BuildSchemaError> {
+        let context = format!("Cannot read s3://{}/{}", self.bucket, key);
+        let output = match self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_no_such_key()) => {
+                return Ok(None);
+            }
+            Err(e) => return Err(storage_error(&context, e)),
+        };
+        let etag = output.e_tag().unwrap_or_default().to_string();
+        let body = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| storage_error(&context, e))?;
+        Ok(Some((body.into_bytes().to_vec(), etag)))
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, BuildSchemaError> {
+        let context = format!("Cannot delete s3://{}/{}", self.bucket, key);
+        match self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(_) => {}
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_not_found()) => {
+                return Ok(false);
+            }
+            Err(e) => return Err(storage_error(&context, e)),
+        }
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| storage_error(&context, e))?;
+        Ok(true)
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, BuildSchemaError> {
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(prefix)
+            .into_paginator()
+            .send();
+
+        let mut keys = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|e| {
+                storage_error(&format!("Cannot list s3://{}/{}", self.bucket, prefix), e)
+            })?;
+            keys.extend(
+                page.contents()
+                    .iter()
+                    .filter_map(|o| o.key().map(String::from)),
+            );
+        }
+        Ok(keys)
+    }
+}
+
+/// What a schema object holds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredSchema {
+    version: Option<String>,
+    content: String,
+    saved_at: DateTime<Utc>,
+}
+
+/// Schema storage in an S3 bucket
+#[derive(Clone)]
+pub struct S3SchemaStorage {
+    store: Arc<dyn ObjectStore>,
+    location: String,
+    prefix: String,
+    /// Schemas saved by this instance, by id
+    written: Arc<RwLock<HashMap<String, StoredSchema>>>,
+    read_retries: u32,
+    read_retry_delay: Duration,
+}
+
+impl S3SchemaStorage {
+    /// Connect to the configured bucket
+    ///
+    /// Credentials and, unless configured, the region come from the standard
+    /// AWS sources.
+    pub async fn from_config(config: &SchemaS3Config) -> Self {
+        let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+        if let Some(region) = &config.region {
+            loader = loader.region(aws_config::Region::new(region.clone()));
+        }
+        if let Some(endpoint) = &config.endpoint {
+            loader = loader.endpoint_url(endpoint);
+        }
+        let sdk_config = loader.load().await;
+        let s3_config = aws_sdk_s3::config::Builder::from(&sdk_config)
+            .force_path_style(config.force_path_style)
+            .build();
+
+        let store = S3Bucket {
+            client: Client::from_conf(s3_config),
+            bucket: config.bucket.clone(),
+        };
+        Self::with_store(
+            Arc::new(store),
+            format!("s3://{}/{}", config.bucket, config.prefix),
+            config,
+        )
+    }
+
+    fn with_store(store: Arc<dyn ObjectStore>, location: String, config: &SchemaS3Config) -> Self {
+        Self {
+            store,
+            location,
+            prefix: config.prefix.clone(),
+            written: Arc::new(RwLock::new(HashMap::new())),
+            read_retries: config.read_retries,
+            read_retry_delay: Duration::from_millis(config.read_retry_delay_ms),
+        }
+    }
+
+    /// Bucket and prefix of the schemas, for logs
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    fn key_of(&self, id: &str) -> String {
+        format!("{}{}.json", self.prefix, id)
+    }
+
+    async fn put(&self, id: &str, schema: &StoredSchema) -> Result<(), BuildSchemaError> {
+        self.store.put(&self.key_of(id), encode(schema)?).await?;
+        self.remember(id, schema);
+        Ok(())
+    }
+
+    /// Make `schema` the latest unless a schema saved after it already is
+    ///
+    /// Each write is conditional on the `latest` object just read; when
+    /// another replica replaces it in between, it is read and compared again.
+    async fn put_latest(&self, schema: &StoredSchema) -> Result<(), BuildSchemaError> {
+        let key = self.key_of(LATEST_SCHEMA_ID);
+        let body = encode(schema)?;
+        for _ in 0..LATEST_WRITE_ATTEMPTS {
+            let condition = match self.store.get_tagged(&key).await? {
+                Some((current, etag)) => {
+                    // An unreadable object is replaced like an older one
+                    let newer = serde_json::from_slice::<StoredSchema>(&current)
+                        .is_ok_and(|current| current.saved_at > schema.saved_at);
+                    if newer {
+                        debug!("A schema saved later is already latest, keeping it");
+                        return Ok(());
+                    }
+                    WriteCondition::Matches(etag)
+                }
+                None => WriteCondition::Absent,
+            };
+            if self.store.put_if(&key, body.clone(), condition).await? {
+                self.remember(LATEST_SCHEMA_ID, schema);
+                return Ok(());
+            }
+            debug!("Latest schema replaced concurrently, comparing again");
+        }
+        Err(BuildSchemaError::SchemaStorageError(format!(
+            "Cannot write {}: replaced concurrently {} times",
+            key, LATEST_WRITE_ATTEMPTS
+        )))
+    }
+
+    fn remember(&self, id: &str, schema: &StoredSchema) {
+        self.written
+            .write()
+            .unwrap()
+            .insert(id.to_string(), schema.clone());
+    }
+
+    async fn fetch(&self, id: &str) -> Result<Option<StoredSchema>, BuildSchemaError> {
+        let key = self.key_of(id);
+        match self.store.get(&key).await? {
+            Some(body) => serde_json::from_slice(&body).map(Some).map_err(|e| {
+                BuildSchemaError::SchemaStorageError(format!(
+                    "Invalid schema object {}: {}",
+                    key, e
+                ))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// The newest of what the store returns and what this instance saved
+    async fn read(&self, id: &str, retry: bool) -> Result<Option<String>, BuildSchemaError> {
+        let written = self.written.read().unwrap().get(id).cloned();
+        let mut stored = self.fetch(id).await?;
+        if retry && written.is_none() {
+            for attempt in 1..=self.read_retries {
+                if stored.is_some() {
+                    break;
+                }
+                debug!("Schema {} not visible yet, reading again ({})", id, attempt);
+                tokio::time::sleep(self.read_retry_delay).await;
+                stored = self.fetch(id).await?;
+            }
+        }
+
+        let newest = match (stored, written) {
+            (Some(stored), Some(written)) if written.saved_at > stored.saved_at => Some(written),
+            (Some(stored), _) => Some(stored),
+            (None, written) => written,
+        };
+        Ok(newest.map(|schema| schema.content))
+    }
+}
+
+#[async_trait]
+impl SchemaStoragePort for S3SchemaStorage {
+    async fn save_schema(
+        &self,
+        schema_json: String,
+        version: Option<String>,
+    ) -> Result<String, BuildSchemaError> {
+        if let Some(version) = &version {
+            check_version(version)?;
+        }
+        let schema = StoredSchema {
+            version: version.clone(),
+            content: schema_json,
+            saved_at: Utc::now(),
+        };
+
+        let schema_id = match version {
+            Some(version) => {
+                self.put(&version, &schema).await?;
+                version
+            }
+            None => LATEST_SCHEMA_ID.to_string(),
+        };
+        self.put_latest(&schema).await?;
+
+        Ok(format!("schema:{}", schema_id))
+    }
+
+    async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
+        self.read(LATEST_SCHEMA_ID, true).await
+    }
+
+    async fn get_schema_by_version(
+        &self,
+        version: &str,
+    ) -> Result<Option<String>, BuildSchemaError> {
+        if check_version(version).is_err() {
+            return Ok(None);
+        }
+        self.read(version, true).await
+    }
+
+    /// Deleting a version leaves `latest` untouched, even if it is a copy of
+    /// that version
+    async fn delete_schema(&self, schema_id: &str) -> Result<bool, BuildSchemaError> {
+        let id = version_of(schema_id);
+        if id != LATEST_SCHEMA_ID && check_version(id).is_err() {
+            return Ok(false);
+        }
+        let cached = self.written.write().unwrap().remove(id).is_some();
+        let deleted = self.store.delete(&self.key_of(id)).await?;
+        Ok(deleted || cached)
+    }
+
+    async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
+        let keys = self.store.list(&self.prefix).await?;
+        let mut versions: Vec<String> = keys
+            .iter()
+            .filter_map(|key| {
+                key.strip_prefix(self.prefix.as_str())?
+                    .strip_suffix(".json")
+            })
+            .map(String::from)
+            .chain(self.written.read().unwrap().keys().cloned())
+            .filter(|id| check_version(id).is_ok())
+            .collect();
+        versions.sort();
+        versions.dedup();
+
+        Ok(versions)
+    }
+}
+
+fn encode(schema: &StoredSchema) -> Result<Vec<u8>, BuildSchemaError> {
+    serde_json::to_vec(schema).map_err(|e| BuildSchemaError::SchemaStorageError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    /// Reads left to answer with the previous object, and that object
+    type Lag = (u32, Option<Vec<u8>>);
+
+    /// In-memory store whose writes only become visible after a number of reads
+    #[derive(Default)]
+    struct LaggingStore {
+        objects: Mutex<BTreeMap<String, Vec<u8>>>,
+        lag: Mutex<HashMap<String, Lag>>,
+        reads_behind: u32,
+        /// Object another replica writes just before the next conditional write
+        racing_write: Mutex<Option<Vec<u8>>>,
+    }
+
+    /// ETag of an object, its content hash like S3's
+    fn etag_of(body: &[u8]) -> String {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        format!("{:x}", hasher.finish())
+    }
+
+    impl LaggingStore {
+        fn behind(reads_behind: u32) -> Self {
+            Self {
+                reads_behind,
+                ..Default::default()
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for LaggingStore {
+        async fn put(&self, key: &str, body: Vec<u8>) -> Result<(), BuildSchemaError> {
+            let previous = self.objects.lock().unwrap().insert(key.to_string(), body);
+            if self.reads_behind > 0 {
+                self.lag
+                    .lock()
+                    .unwrap()
+                    .insert(key.to_string(), (self.reads_behind, previous));
+            }
+            Ok(())
+        }
+
+        async fn put_if(
+            &self,
+            key: &str,
+            body: Vec<u8>,
+            condition: WriteCondition,
+        ) -> Result<bool, BuildSchemaError> {
+            if let Some(racing) = self.racing_write.lock().unwrap().take() {
+                self.objects.lock().unwrap().insert(key.to_string(), racing);
+            }
+            let current = self.objects.lock().unwrap().get(key).map(|b| etag_of(b));
+            let holds = match condition {
+                WriteCondition::Absent => current.is_none(),
+                WriteCondition::Matches(etag) => current == Some(etag),
+            };
+            if holds {
+                self.put(key, body).await?;
+            }
+            Ok(holds)
+        }
+
+        async fn get_tagged(
+            &self,
+            key: &str,
+        ) -> Result<Option<(Vec<u8>, String)>, BuildSchemaError> {
+            let mut lag = self.lag.lock().unwrap();
+            let body = match lag.get_mut(key) {
+                Some((remaining, previous)) if *remaining > 0 => {
+                    *remaining -= 1;
+                    previous.clone()
+                }
+                _ => {
+                    lag.remove(key);
+                    self.objects.lock().unwrap().get(key).cloned()
+                }
+            };
+            Ok(body.map(|body| {
+                let etag = etag_of(&body);
+                (body, etag)
+            }))
+        }
+
+        async fn delete(&self, key: &str) -> Result<bool, BuildSchemaError> {
+            Ok(self.objects.lock().unwrap().remove(key).is_some())
+        }
+
+        async fn list(&self, prefix: &str) -> Result<Vec<String>, BuildSchemaError> {
+            let lag = self.lag.lock().unwrap();
+            Ok(self
+                .objects
+                .lock()
+                .unwrap()
+                .keys()
+                .filter(|key| key.starts_with(prefix) && !lag.contains_key(*key))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn storage(store: Arc<LaggingStore>) -> S3SchemaStorage {
+        let config = SchemaS3Config {
+            bucket: "schemas".to_string(),
+            read_retry_delay_ms: 1,
+            ..Default::default()
+        };
+        S3SchemaStorage::with_store(store, "test".to_string(), &config)
+    }
+
+    #[tokio::test]
+    async fn test_saved_schema_is_readable_immediately() {
+        let store = Arc::new(LaggingStore::behind(10));
+        let storage = storage(store);
+
+        storage
+            .save_schema("{\"v\":1}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage
+                .get_schema_by_version("v1")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("{\"v\":1}")
+        );
+        assert_eq!(
+            storage.get_latest_schema().await.unwrap().as_deref(),
+            Some("{\"v\":1}")
+        );
+        assert_eq!(storage.list_schema_versions().await.unwrap(), vec!["v1"]);
+    }
+
+    #[tokio::test]
+    async fn test_stale_latest_does_not_hide_a_newer_save() {
+        let store = Arc::new(LaggingStore::behind(0));
+        let storage = storage(store.clone());
+        storage
+            .save_schema("{\"v\":1}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+
+        // From here on the store keeps serving v1 as latest for a while
+        let lagging = S3SchemaStorage {
+            store: Arc::new(LaggingStore {
+                objects: Mutex::new(store.objects.lock().unwrap().clone()),
+                ..LaggingStore::behind(10)
+            }),
+            ..storage
+        };
+        lagging
+            .save_schema("{\"v\":2}".to_string(), Some("v2".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            lagging.get_latest_schema().await.unwrap().as_deref(),
+            Some("{\"v\":2}")
+        );
+        assert_eq!(
+            lagging.list_schema_versions().await.unwrap(),
+            vec!["v1", "v2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_other_replica_writes_are_read_after_retrying() {
+        let store = Arc::new(LaggingStore::behind(2));
+        let writer = storage(store.clone());
+        let reader = storage(store.clone());
+
+        writer
+            .save_schema("{}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+
+        // Two stale reads, then the object shows up within the three retries
+        assert_eq!(
+            reader.get_schema_by_version("v1").await.unwrap().as_deref(),
+            Some("{}")
+        );
+        assert!(reader.get_schema_by_version("v2").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_other_replica_latest_is_read_after_retrying() {
+        let store = Arc::new(LaggingStore::behind(2));
+        let writer = storage(store.clone());
+        let reader = storage(store);
+
+        writer.save_schema("{}".to_string(), None).await.unwrap();
+
+        assert_eq!(
+            reader.get_latest_schema().await.unwrap().as_deref(),
+            Some("{}")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_latest_is_not_replaced_by_an_older_save() {
+        let store = Arc::new(LaggingStore::behind(0));
+        let storage = storage(store.clone());
+        let older = StoredSchema {
+            version: Some("v1".to_string()),
+            content: "{\"v\":1}".to_string(),
+            saved_at: Utc::now(),
+        };
+        // Another replica saves v2 while this one is about to write v1
+        let newer = StoredSchema {
+            version: Some("v2".to_string()),
+            content: "{\"v\":2}".to_string(),
+            saved_at: older.saved_at + chrono::Duration::seconds(1),
+        };
+        *store.racing_write.lock().unwrap() = Some(encode(&newer).unwrap());
+
+        storage.put_latest(&older).await.unwrap();
+
+        let latest = store.objects.lock().unwrap()["schemas/latest.json"].clone();
+        assert_eq!(latest, encode(&newer).unwrap());
+        assert_eq!(
+            storage.get_latest_schema().await.unwrap().as_deref(),
+            Some("{\"v\":2}")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_id_or_version() {
+        let store = Arc::new(LaggingStore::behind(0));
+        let storage = storage(store.clone());
+        storage
+            .save_schema("{}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+        storage
+            .save_schema("{}".to_string(), Some("v2".to_string()))
+            .await
+            .unwrap();
+
+        assert!(storage.delete_schema("schema:v1").await.unwrap());
+        assert!(storage.delete_schema("v2").await.unwrap());
+        assert!(!storage.delete_schema("v2").await.unwrap());
+        assert!(storage.list_schema_versions().await.unwrap().is_empty());
+        assert!(storage.get_schema_by_version("v1").await.unwrap().is_none());
+        assert!(storage.get_latest_schema().await.unwrap().is_some());
+        assert_eq!(
+            store.objects.lock().unwrap().keys().collect::<Vec<_>>(),
+            vec!["schemas/latest.json"]
+        );
+    }
+}
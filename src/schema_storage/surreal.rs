@@ -0,0 +1,237 @@
+//! SurrealDB schema storage
+//!
+//! Schemas are records of the `schema` table keyed by version, plus the
+//! `schema:latest` record holding a copy of the last one saved. Both are
+//! written in a single transaction, so readers never see a new version without
+//! it being the latest (or the other way round).
+
+use super::{LATEST_SCHEMA_ID, version_of};
+use async_trait::async_trait;
+use hodei_policies::build_schema::error::BuildSchemaError;
+use hodei_policies::build_schema::ports::SchemaStoragePort;
+use serde::Deserialize;
+use surrealdb::Surreal;
+use surrealdb::engine::local::Db;
+
+/// SurrealDB adapter for schema storage
+///
+/// This adapter implements the SchemaStoragePort trait for SurrealDB.
+#[derive(Clone)]
+pub struct SurrealSchemaAdapter {
+    db: Surreal<Db>,
+}
+
+impl SurrealSchemaAdapter {
+    /// Create a new SurrealDB schema adapter
+    pub fn new(db: Surreal<Db>) -> Self {
+        Self { db }
+    }
+
+    async fn select_content(&self, id: &str) -> Result<Option<String>, BuildSchemaError> {
+        let result: Option<SchemaRecord> = self
+            .db
+            .select(("schema", id))
+            .await
+            .map_err(storage_error)?;
+
+        Ok(result.map(|record| record.content))
+    }
+}
+
+/// The part of a schema record read back (the record id doesn't deserialize
+/// into JSON)
+#[derive(Deserialize)]
+struct SchemaRecord {
+    content: String,
+}
+
+fn storage_error(e: surrealdb::Error) -> BuildSchemaError {
+    BuildSchemaError::SchemaStorageError(e.to_string())
+}
+
+#[async_trait]
+impl SchemaStoragePort for SurrealSchemaAdapter {
+    async fn save_schema(
+        &self,
+        schema_json: String,
+        version: Option<String>,
+    ) -> Result<String, BuildSchemaError> {
+        if version.as_deref() == Some(LATEST_SCHEMA_ID) {
+            return Err(BuildSchemaError::SchemaStorageError(format!(
+                "'{}' is reserved and cannot be used as a schema version",
+                LATEST_SCHEMA_ID
+            )));
+        }
+        let schema_id = version
+            .clone()
+            .unwrap_or_else(|| LATEST_SCHEMA_ID.to_string());
+        let record = serde_json::json!({
+            "content": schema_json,
+            "version": version,
+            "created_at": chrono::Utc::now().to_rfc3339(),
+        });
+
+        self.db
+            .query(
+                "BEGIN TRANSACTION;
+                 UPSERT type::thing('schema', $id) CONTENT $record;
+                 UPSERT type::thing('schema', $latest) CONTENT $record;
+                 COMMIT TRANSACTION;",
+            )
+            .bind(("id", schema_id.clone()))
+            .bind(("latest", LATEST_SCHEMA_ID))
+            .bind(("record", record))
+            .await
+            .map_err(storage_error)?
+            .check()
+            .map_err(storage_error)?;
+
+        Ok(format!("schema:{}", schema_id))
+    }
+
+    async fn get_latest_schema(&self) -> Result<Option<String>, BuildSchemaError> {
+        self.select_content(LATEST_SCHEMA_ID).await
+    }
+
+    async fn get_schema_by_version(
+        &self,
+        version: &str,
+    ) -> Result<Option<String>, BuildSchemaError> {
+        self.select_content(version).await
+    }
+
+    /// Deleting a version leaves `latest` untouched, even if it is a copy of
+    /// that version
+    async fn delete_schema(&self, schema_id: &str) -> Result<bool, BuildSchemaError> {
+        let result: Option<SchemaRecord> = self
+            .db
+            .delete(("schema", version_of(schema_id)))
+            .await
+            .map_err(storage_error)?;
+
+        Ok(result.is_some())
+    }
+
+    async fn list_schema_versions(&self) -> Result<Vec<String>, BuildSchemaError> {
+        let results: Vec<serde_json::Value> = self
+            .db
+            .query("SELECT version FROM schema WHERE id != type::thing('schema', $latest)")
+            .bind(("latest", LATEST_SCHEMA_ID))
+            .await
+            .map_err(storage_error)?
+            .take(0)
+            .map_err(storage_error)?;
+
+        let mut versions: Vec<String> = results
+            .into_iter()
+            .filter_map(|v| {
+                v.get("version")
+                    .and_then(|ver| ver.as_str().map(String::from))
+            })
+            .collect();
+        versions.sort();
+        versions.dedup();
+
+        Ok(versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealdb::engine::local::Mem;
+
+    async fn adapter() -> SurrealSchemaAdapter {
+        let db = Surreal::new::<Mem>(()).await.unwrap();
+        db.use_ns("test").use_db("test").await.unwrap();
+        SurrealSchemaAdapter::new(db)
+    }
+
+    #[tokio::test]
+    async fn test_save_versions_and_latest() {
+        let storage = adapter().await;
+
+        let id = storage
+            .save_schema("{\"v\":1}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(id, "schema:v1");
+        storage
+            .save_schema("{\"v\":2}".to_string(), Some("v2".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage
+                .get_schema_by_version("v1")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("{\"v\":1}")
+        );
+        assert_eq!(
+            storage.get_latest_schema().await.unwrap().as_deref(),
+            Some("{\"v\":2}")
+        );
+        assert_eq!(
+            storage.list_schema_versions().await.unwrap(),
+            vec!["v1", "v2"]
+        );
+
+        // Saving an existing version replaces it
+        storage
+            .save_schema("{\"v\":3}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(
+            storage
+                .get_schema_by_version("v1")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("{\"v\":3}")
+        );
+        assert_eq!(
+            storage.list_schema_versions().await.unwrap(),
+            vec!["v1", "v2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unversioned_schema_is_only_latest() {
+        let storage = adapter().await;
+
+        let id = storage.save_schema("{}".to_string(), None).await.unwrap();
+        assert_eq!(id, "schema:latest");
+        assert_eq!(
+            storage.get_latest_schema().await.unwrap().as_deref(),
+            Some("{}")
+        );
+        assert!(storage.list_schema_versions().await.unwrap().is_empty());
+        assert!(
+            storage
+                .save_schema("{}".to_string(), Some("latest".to_string()))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_id_or_version() {
+        let storage = adapter().await;
+        storage
+            .save_schema("{}".to_string(), Some("v1".to_string()))
+            .await
+            .unwrap();
+        storage
+            .save_schema("{}".to_string(), Some("v2".to_string()))
+            .await
+            .unwrap();
+
+        assert!(storage.delete_schema("schema:v1").await.unwrap());
+        assert!(storage.delete_schema("v2").await.unwrap());
+        assert!(!storage.delete_schema("v2").await.unwrap());
+        assert!(storage.list_schema_versions().await.unwrap().is_empty());
+        assert!(storage.get_latest_schema().await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,77 @@
+//! Tenant of an incoming request
+//!
+//! Handlers that read tenant-owned resources take a [`RequestTenant`] and
+//! call the use cases' `execute_for_tenant`, so an HRN from another tenant is
+//! rejected with `CrossTenantAccess` (answered as not found) before any port
+//! is touched. The tenant is the one named in the `x-hodei-tenant` header,
+//! set by the gateway that authenticated the caller; requests without it act
+//! for [`DEFAULT_TENANT`], the account policies are created under.
+
+use crate::handlers::error::ApiError;
+use axum::{extract::FromRequestParts, http::request::Parts};
+use kernel::TenantContext;
+
+/// Header naming the tenant a request is executed for
+pub const TENANT_HEADER: &str = "x-hodei-tenant";
+
+/// Tenant of requests that do not name one
+pub const DEFAULT_TENANT: &str = "default";
+
+/// Extractor for the [`TenantContext`] of the request
+#[derive(Debug, Clone)]
+pub struct RequestTenant(pub TenantContext);
+
+impl<S: Send + Sync> FromRequestParts<S> for RequestTenant {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let tenant_id = match parts.headers.get(TENANT_HEADER) {
+            None => DEFAULT_TENANT,
+            Some(value) => value
+                .to_str()
+                .ok()
+                .filter(|tenant_id| !tenant_id.is_empty())
+                .ok_or_else(|| ApiError::bad_request(format!("Invalid {TENANT_HEADER} header")))?,
+        };
+        Ok(Self(TenantContext::new(tenant_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{Request, StatusCode};
+    use axum::response::IntoResponse;
+
+    async fn extract(request: Request<()>) -> Result<RequestTenant, ApiError> {
+        let (mut parts, ()) = request.into_parts();
+        RequestTenant::from_request_parts(&mut parts, &()).await
+    }
+
+    #[tokio::test]
+    async fn tenant_comes_from_the_header_or_defaults() {
+        let named = Request::builder()
+            .header(TENANT_HEADER, "tenant-a")
+            .body(())
+            .unwrap();
+        let unnamed = Request::builder().body(()).unwrap();
+
+        assert_eq!(extract(named).await.unwrap().0.tenant_id(), "tenant-a");
+        assert_eq!(
+            extract(unnamed).await.unwrap().0.tenant_id(),
+            DEFAULT_TENANT
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_tenant_header_is_a_bad_request() {
+        let request = Request::builder()
+            .header(TENANT_HEADER, "")
+            .body(())
+            .unwrap();
+
+        let error = extract(request).await.unwrap_err();
+
+        assert_eq!(error.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -293,8 +293,8 @@ async fn test_playground_evaluate_missing_fields() {
 
     let response = client.post("/api/v1/playground/evaluate", request).await;
 
-    // Should return 400 Bad Request for missing schema
-    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+    // Should return 422 Unprocessable Entity for missing schema
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
 }
 
 /// Test playground evaluation with empty policies
@@ -315,8 +315,8 @@ async fn test_playground_evaluate_empty_policies() {
 
     let response = client.post("/api/v1/playground/evaluate", request).await;
 
-    // Should return 400 Bad Request for empty policies
-    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+    // Should return 422 Unprocessable Entity for empty policies
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
 }
 
 /// Test playground evaluation with entity reference in context